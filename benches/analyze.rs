@@ -0,0 +1,68 @@
+//! Throughput of the security analysis on multi-megabyte inputs
+//!
+//! Run with `cargo bench --bench analyze`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unicode_rs::security::{analyze_text, sanitize_text};
+
+/// Size of the generated inputs in bytes
+const INPUT_LEN: usize = 4 << 20;
+
+const ASCII_SOURCE: &str = "\
+fn parse_header(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once(':')?;
+    if name.is_empty() || value.len() > 8 * 1024 {
+        return None;
+    }
+    Some((name.trim(), value.trim()))
+}
+";
+
+const MIXED_TEXT: &str = "\
+// Grüße aus München, привет, שלום, 你好 👨\u{200D}👩\u{200D}👧
+let greeting = \"Hello, world!\";
+";
+
+/// Repeat `unit` up to about `INPUT_LEN` bytes
+fn repeat(unit: &str) -> String {
+    unit.repeat(INPUT_LEN / unit.len())
+}
+
+fn bench_analyze_text(c: &mut Criterion) {
+    let inputs = [
+        ("ascii", repeat(ASCII_SOURCE)),
+        // Mostly ASCII with a single zero width space at the end
+        ("ascii_with_finding", repeat(ASCII_SOURCE) + "\u{200B}"),
+        ("mixed", repeat(MIXED_TEXT)),
+    ];
+
+    let mut group = c.benchmark_group("analyze_text");
+    group.sample_size(20);
+    for (name, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), input, |b, input| {
+            b.iter(|| analyze_text(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_sanitize_text(c: &mut Criterion) {
+    let inputs = [
+        ("ascii", repeat(ASCII_SOURCE)),
+        ("mixed", repeat(MIXED_TEXT)),
+    ];
+
+    let mut group = c.benchmark_group("sanitize_text");
+    group.sample_size(20);
+    for (name, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), input, |b, input| {
+            b.iter(|| sanitize_text(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_analyze_text, bench_sanitize_text);
+criterion_main!(benches);
@@ -71,7 +71,7 @@ fn main() {
 
         for file in &files {
             let icon = if file.is_directory {
-                FileType::Directory.get_char(theme)
+                classify_directory(&file.name).get_char(theme)
             } else if let Some(ext) = &file.extension {
                 let ft = get_file_type_from_extension(ext);
                 ft.get_char(theme)
@@ -81,7 +81,7 @@ fn main() {
             };
 
             let file_type_name = if file.is_directory {
-                "Directory".to_string()
+                format!("{:?}", classify_directory(&file.name))
             } else if let Some(ext) = &file.extension {
                 let ft = get_file_type_from_extension(ext);
                 format!("{:?}", ft)
@@ -102,7 +102,8 @@ fn main() {
     let theme = UnicodeTheme::Rich;
     let languages = vec![
         ("Rust", LanguageType::Rust),
-        ("JavaScript/TypeScript", LanguageType::JavaScript),
+        ("JavaScript", LanguageType::JavaScript),
+        ("TypeScript", LanguageType::TypeScript),
         ("Python", LanguageType::Python),
         ("Go", LanguageType::Go),
         ("HTML", LanguageType::Html),
@@ -124,14 +125,17 @@ fn main() {
 
     set_global_config(UnicodeConfig::with_theme(UnicodeTheme::Rich));
 
-    println!("📁 /home/user/project");
-    println!("├── {} src/", get_char(&FileType::Directory, None));
+    println!(
+        "{} /home/user/project",
+        get_char(&DirectoryKind::Generic.open(), None)
+    );
+    println!("├── {} src/", get_char(&DirectoryKind::Source, None));
     println!("│   ├── {} main.rs", get_char(&LanguageType::Rust, None));
     println!("│   ├── {} lib.rs", get_char(&LanguageType::Rust, None));
     println!("│   └── {} mod.rs", get_char(&LanguageType::Rust, None));
-    println!("├── {} examples/", get_char(&FileType::Directory, None));
+    println!("├── {} examples/", get_char(&DirectoryKind::Examples, None));
     println!("│   └── {} basic.rs", get_char(&LanguageType::Rust, None));
-    println!("├── {} target/", get_char(&FileType::Directory, None));
+    println!("├── {} target/", get_char(&DirectoryKind::Build, None));
     println!("├── {} Cargo.toml", get_char(&FileType::Config, None));
     println!("├── {} README.md", get_char(&LanguageType::Markdown, None));
     println!("├── {} package.json", get_char(&LanguageType::Json, None));
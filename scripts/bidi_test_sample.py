@@ -0,0 +1,185 @@
+#!/usr/bin/env python3
+"""Generate a BidiCharacterTest.txt style sample with ICU's UBA implementation.
+
+Usage:
+
+    python3 scripts/bidi_test_sample.py [--icu LIBRARY] [--count N]
+
+The conformance test in src/unicode/security/bidi.rs runs against the official
+BidiCharacterTest.txt when scripts/unicode.py has downloaded it into
+`target/ucd-<version>/`. This sample is the fallback for offline builds: random
+strings over representative characters of every Bidi_Class, paired brackets and
+explicit formatting characters, resolved by ICU's ubidi for each paragraph
+direction. Only BMP characters whose Bidi_Class did not change between Unicode
+15.0 and 16.0 are used, so an ICU built for Unicode 15.0 or later gives the same
+results as the Unicode 16.0 reference implementation.
+
+ICU does not resolve the levels of a paragraph whose display it can tell
+without them, such as a lone Arabic digit in a left-to-right paragraph, and
+reports it as UBIDI_LTR or UBIDI_RTL. Only paragraphs ICU resolves in full
+(UBIDI_MIXED) are kept, so that levels can be compared as well as the order.
+"""
+
+import argparse
+import ctypes
+import ctypes.util
+import os
+import random
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUT = os.path.join(ROOT, "src", "unicode", "security", "testdata", "bidi_character_test.txt")
+
+# Representative characters by Bidi_Class
+CHARS = {
+    "L": [0x0061, 0x00E9, 0x3042],
+    "R": [0x05D0, 0x05EA, 0x07C0],
+    "AL": [0x0627, 0x0710, 0xFE70],
+    "EN": [0x0031, 0x00B2, 0x06F1],
+    "ES": [0x002B, 0x002D],
+    "ET": [0x0024, 0x0025, 0x00B0, 0x20AC],
+    "AN": [0x0661, 0x0600, 0x066B],
+    "CS": [0x002C, 0x002E, 0x003A, 0x00A0],
+    "NSM": [0x0300, 0x0591, 0x064B],
+    "BN": [0x00AD, 0x200B],
+    "S": [0x0009],
+    "WS": [0x0020, 0x2000],
+    "ON": [0x0021, 0x0022, 0x0026, 0x002A],
+    "LRE": [0x202A],
+    "RLE": [0x202B],
+    "PDF": [0x202C],
+    "LRO": [0x202D],
+    "RLO": [0x202E],
+    "LRI": [0x2066],
+    "RLI": [0x2067],
+    "FSI": [0x2068],
+    "PDI": [0x2069],
+    "LRM": [0x200E],
+    "RLM": [0x200F],
+    "ALM": [0x061C],
+    "BRACKET": [0x0028, 0x0029, 0x005B, 0x005D, 0x007B, 0x007D, 0x2329, 0x232A, 0x3008, 0x3009],
+}
+
+# Removed by rule X9, shown as "x" in the levels field
+X9_REMOVED = {0x00AD, 0x200B, 0x202A, 0x202B, 0x202C, 0x202D, 0x202E}
+
+UBIDI_DEFAULT_LTR = 0xFE
+UBIDI_MIXED = 2
+DIRECTIONS = [(0, 0), (1, 1), (2, UBIDI_DEFAULT_LTR)]
+
+
+def load_icu(path):
+    lib = ctypes.CDLL(path or ctypes.util.find_library("icuuc"))
+    version = None
+    # ICU exports versioned symbols such as ubidi_open_72
+    for candidate in range(60, 100):
+        try:
+            getattr(lib, "ubidi_open_%d" % candidate)
+            version = candidate
+            break
+        except AttributeError:
+            continue
+    if version is None:
+        sys.exit("no ubidi symbols in %s" % path)
+
+    def fn(name, restype, *argtypes):
+        f = getattr(lib, "%s_%d" % (name, version))
+        f.restype = restype
+        f.argtypes = argtypes
+        return f
+
+    err = ctypes.POINTER(ctypes.c_int)
+    return version, {
+        "open": fn("ubidi_open", ctypes.c_void_p),
+        "close": fn("ubidi_close", None, ctypes.c_void_p),
+        "setPara": fn(
+            "ubidi_setPara",
+            None,
+            ctypes.c_void_p,
+            ctypes.c_void_p,
+            ctypes.c_int32,
+            ctypes.c_uint8,
+            ctypes.c_void_p,
+            err,
+        ),
+        "getParaLevel": fn("ubidi_getParaLevel", ctypes.c_uint8, ctypes.c_void_p),
+        "getDirection": fn("ubidi_getDirection", ctypes.c_int, ctypes.c_void_p),
+        "getLevels": fn("ubidi_getLevels", ctypes.POINTER(ctypes.c_uint8), ctypes.c_void_p, err),
+        "getVisualMap": fn(
+            "ubidi_getVisualMap", None, ctypes.c_void_p, ctypes.POINTER(ctypes.c_int32), err
+        ),
+    }
+
+
+def resolve(icu, cps, direction):
+    bidi = icu["open"]()
+    text = (ctypes.c_uint16 * len(cps))(*cps)
+    status = ctypes.c_int(0)
+    icu["setPara"](bidi, text, len(cps), direction, None, ctypes.byref(status))
+    if status.value > 0:
+        sys.exit("ubidi_setPara failed: %d" % status.value)
+    para_level = icu["getParaLevel"](bidi)
+    if icu["getDirection"](bidi) != UBIDI_MIXED:
+        icu["close"](bidi)
+        return None
+    levels = icu["getLevels"](bidi, ctypes.byref(status))
+    levels = [levels[i] for i in range(len(cps))]
+    visual = (ctypes.c_int32 * len(cps))()
+    icu["getVisualMap"](bidi, visual, ctypes.byref(status))
+    if status.value > 0:
+        sys.exit("ubidi_getVisualMap failed: %d" % status.value)
+    icu["close"](bidi)
+    order = [i for i in visual if cps[i] not in X9_REMOVED]
+    shown = ["x" if cp in X9_REMOVED else str(level) for cp, level in zip(cps, levels)]
+    return para_level, shown, order
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n")[0])
+    parser.add_argument("--icu", help="path of the ICU common library (libicuuc)")
+    parser.add_argument("--count", type=int, default=1000, help="number of strings")
+    args = parser.parse_args()
+
+    version, icu = load_icu(args.icu)
+    rng = random.Random(9)
+    classes = sorted(CHARS)
+    # Favor strong characters and brackets, which drive most rules
+    frequent = ("L", "R", "AL", "EN", "AN", "BRACKET", "WS", "ON", "NSM")
+    weights = [4 if c in frequent else 1 for c in classes]
+
+    lines = []
+    for _ in range(args.count):
+        length = rng.randint(1, 16)
+        cps = [rng.choice(CHARS[c]) for c in rng.choices(classes, weights, k=length)]
+        for field, direction in DIRECTIONS:
+            resolved = resolve(icu, cps, direction)
+            if resolved is None:
+                continue
+            para_level, levels, order = resolved
+            lines.append(
+                "%s;%d;%d;%s;%s"
+                % (
+                    " ".join("%04X" % cp for cp in cps),
+                    field,
+                    para_level,
+                    " ".join(levels),
+                    " ".join(str(i) for i in order),
+                )
+            )
+
+    os.makedirs(os.path.dirname(OUT), exist_ok=True)
+    with open(OUT, "w", encoding="utf-8", newline="\n") as f:
+        f.write("# BidiCharacterTest.txt format sample, generated by\n")
+        f.write("#\n#   python3 scripts/bidi_test_sample.py\n#\n")
+        f.write("# from ICU %d; see the script for how characters are chosen.\n" % version)
+        f.write("# Only paragraphs ICU resolves in full are kept, so levels are\n")
+        f.write("# checked along with the paragraph level and the order.\n")
+        f.write("# Fields: code points; paragraph direction (0 LTR, 1 RTL, 2 auto);\n")
+        f.write("# resolved paragraph level; levels; visual order\n\n")
+        for line in lines:
+            f.write(line + "\n")
+    print("wrote %s" % os.path.relpath(OUT, ROOT), file=sys.stderr)
+
+
+if __name__ == "__main__":
+    main()
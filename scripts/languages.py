@@ -0,0 +1,530 @@
+#!/usr/bin/env python3
+"""Generate the language table in src/unicode/file_types/languages.rs.
+
+Usage:
+
+    python3 scripts/languages.py [--languages-yml FILE]
+
+The data comes from GitHub Linguist's languages.yml: canonical names and
+colors, aliases, extensions, file names and interpreters. MIME types come
+from MIME_TYPES below, which Linguist does not carry. Without
+--languages-yml the file is downloaded into `target/linguist-<version>/` and
+reused on later runs. Requires PyYAML.
+
+Only the languages listed in LANGUAGES become `LanguageType` variants; each
+may merge several Linguist languages (e.g. TypeScript and TSX). Glyphs not
+given explicitly are derived from the canonical color.
+"""
+
+import argparse
+import colorsys
+import os
+import subprocess
+import sys
+import urllib.request
+
+try:
+    import yaml
+except ImportError:
+    sys.exit("scripts/languages.py requires PyYAML (pip install pyyaml)")
+
+LINGUIST_VERSION = "v9.2.0"
+LANGUAGES_URL = (
+    "https://raw.githubusercontent.com/github-linguist/linguist/%s/lib/linguist/languages.yml"
+    % LINGUIST_VERSION
+)
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUT_FILE = os.path.join(ROOT, "src", "unicode", "file_types", "languages.rs")
+
+HEADER = """// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/languages.py
+//
+// Linguist version: %s.
+""" % LINGUIST_VERSION
+
+# Variant, Linguist languages merged into it (the first one names it), and
+# glyphs for the Minimal, Basic, Rich and Fancy themes. Missing glyphs are
+# derived: the first letter for Minimal and Basic, a circle and a square in
+# the canonical color for Rich and Fancy. The first variants keep the
+# glyphs they had before the table was generated.
+LANGUAGES = [
+    ("Rust", ["Rust"], "RR🦀⚙"),
+    ("JavaScript", ["JavaScript"], "JJ⚡📜"),
+    ("Python", ["Python"], "PP🐍🐍"),
+    ("C", ["C"], "CC⚡🔧"),
+    ("Java", ["Java"], "JJ☕☕"),
+    ("Go", ["Go", "Go Module", "Go Checksums", "Go Workspace"], "GG🐹🚀"),
+    ("Html", ["HTML"], "H<🌐📄"),
+    ("Css", ["CSS"], "S#🎨✨"),
+    ("Json", ["JSON", "JSON with Comments", "JSON5"], "{{📋🗂"),
+    ("Xml", ["XML"], "<<📄🗃"),
+    ("Yaml", ["YAML"], "Y:📝⚙"),
+    ("Toml", ["TOML"], "T=⚙🔧"),
+    ("Markdown", ["Markdown"], "M#📝📖"),
+    ("Shell", ["Shell"], "$$🐚⚡"),
+    ("Sql", ["SQL"], "QQ🗄💾"),
+    ("Docker", ["Dockerfile"], "D□🐳📦"),
+    ("Git", ["Git Config", "Git Attributes", "Ignore List"], "G*🌿🔀"),
+    ("Ada", ["Ada"], None),
+    ("Agda", ["Agda"], None),
+    ("AsciiDoc", ["AsciiDoc"], "A=📝📖"),
+    ("Assembly", ["Assembly"], "A;⚙🔩"),
+    ("Astro", ["Astro"], "A<🚀🌠"),
+    ("Awk", ["Awk"], None),
+    ("Batchfile", ["Batchfile"], "B>🦇🦇"),
+    ("CMake", ["CMake"], "C▲🔺🏗"),
+    ("CSharp", ["C#"], "C#🎼🎼"),
+    ("Clojure", ["Clojure"], "Cλ🟢🌀"),
+    ("CoffeeScript", ["CoffeeScript"], "C~☕☕"),
+    ("CommonLisp", ["Common Lisp"], "L(🟢👽"),
+    ("Cpp", ["C++"], "C+⚙🔩"),
+    ("Crystal", ["Crystal"], "C◆🔮🔮"),
+    ("Csv", ["CSV"], "C,📊📈"),
+    ("Cuda", ["CUDA"], None),
+    ("Cue", ["Cue"], None),
+    ("D", ["D"], None),
+    ("Dart", ["Dart"], "D◎🎯🎯"),
+    ("Dhall", ["Dhall"], None),
+    ("EditorConfig", ["EditorConfig"], "E=⚙🔧"),
+    ("Elixir", ["Elixir"], "E◇💧🧪"),
+    ("Elm", ["Elm"], "E◇🌳🌳"),
+    ("EmacsLisp", ["Emacs Lisp"], "E(🟣🐃"),
+    ("Erlang", ["Erlang"], "E%📞📞"),
+    ("FSharp", ["F#"], "F#🔷🎵"),
+    ("Fortran", ["Fortran", "Fortran Free Form"], None),
+    ("Gherkin", ["Gherkin"], "G~🥒🥒"),
+    ("Gleam", ["Gleam"], "G★⭐🌟"),
+    ("Glsl", ["GLSL"], "G◐🔵🌈"),
+    ("GraphQl", ["GraphQL"], "G◈🔺🕸"),
+    ("Groovy", ["Groovy"], None),
+    ("Handlebars", ["Handlebars"], "H{🟠👨"),
+    ("Haskell", ["Haskell"], "Hλ🟣🎩"),
+    ("Haxe", ["Haxe"], None),
+    ("Hcl", ["HCL"], "T◇🏗🌍"),
+    ("Hlsl", ["HLSL"], "H◐🟢🌈"),
+    ("Idris", ["Idris"], None),
+    ("Ini", ["INI"], "I=⚙🔧"),
+    ("Jsonnet", ["Jsonnet"], None),
+    ("Julia", ["Julia"], "J∴🟣🔮"),
+    ("JupyterNotebook", ["Jupyter Notebook"], "J◎📓📓"),
+    ("Just", ["Just"], "J>🤖🤖"),
+    ("Kotlin", ["Kotlin"], "K◆🟣🏝"),
+    ("Less", ["Less"], "L#🎨✨"),
+    ("Lua", ["Lua"], "L☾🌙🌙"),
+    ("Makefile", ["Makefile"], "M>🔨🏗"),
+    ("Matlab", ["MATLAB"], "M∑📐📈"),
+    ("Mdx", ["MDX"], "M#📝📖"),
+    ("Meson", ["Meson"], None),
+    ("Mojo", ["Mojo"], "M*🔥🔥"),
+    ("Nginx", ["Nginx"], "N=🟢🌐"),
+    ("Nim", ["Nim"], "N♛👑👑"),
+    ("Nix", ["Nix"], "N*❄❄"),
+    ("Nushell", ["Nushell"], "N>🐚⚡"),
+    ("ObjectiveC", ["Objective-C"], "O@🍎🍏"),
+    ("ObjectiveCpp", ["Objective-C++"], "O@🍎🍏"),
+    ("Ocaml", ["OCaml"], "O~🐫🐫"),
+    ("Odin", ["Odin"], None),
+    ("Org", ["Org"], "O*🦄🦄"),
+    ("Pascal", ["Pascal"], None),
+    ("Perl", ["Perl"], "P$🐪🐪"),
+    ("Php", ["PHP"], "P$🐘🐘"),
+    ("PowerShell", ["PowerShell"], "P>🔷⚡"),
+    ("Prisma", ["Prisma"], "P◭🔺💎"),
+    ("Prolog", ["Prolog"], None),
+    ("Protobuf", ["Protocol Buffer"], "P{📦📨"),
+    ("Pug", ["Pug"], "P<🐶🐶"),
+    ("Puppet", ["Puppet"], "P◇🎭🎭"),
+    ("PureScript", ["PureScript"], None),
+    ("R", ["R"], "R®📊📈"),
+    ("Racket", ["Racket"], "Rλ🎾🎾"),
+    ("Raku", ["Raku"], "R»🦋🦋"),
+    ("ReStructuredText", ["reStructuredText"], "R=📝📖"),
+    ("Ruby", ["Ruby"], "R◆💎💎"),
+    ("Sass", ["Sass"], "S#🎨✨"),
+    ("Scala", ["Scala"], "S≡🔴🪜"),
+    ("Scheme", ["Scheme"], "Sλ🔵🌀"),
+    ("Scss", ["SCSS"], "S#🎨✨"),
+    ("Solidity", ["Solidity"], "S◆💠💠"),
+    ("Starlark", ["Starlark"], "S★🌟🌟"),
+    ("Svelte", ["Svelte"], "S<🔥🧡"),
+    ("Svg", ["SVG"], "S<🖼🎨"),
+    ("Swift", ["Swift"], "S»🐦🕊"),
+    ("SystemVerilog", ["SystemVerilog"], None),
+    ("Tcl", ["Tcl"], "T>🪶🪶"),
+    ("Tex", ["TeX"], "T∫📐📜"),
+    ("Thrift", ["Thrift"], None),
+    ("TypeScript", ["TypeScript", "TSX"], "T◆🔷📘"),
+    ("Typst", ["Typst"], "T∫📐📜"),
+    ("V", ["V"], None),
+    ("Vala", ["Vala"], None),
+    ("Verilog", ["Verilog"], None),
+    ("Vhdl", ["VHDL"], None),
+    ("VimScript", ["Vim Script"], "V:📗📝"),
+    ("VisualBasic", ["Visual Basic .NET"], None),
+    ("Vue", ["Vue"], "V▼🟢💚"),
+    ("WebAssembly", ["WebAssembly"], "W◇🟦🧩"),
+    ("Xslt", ["XSLT"], "X<📄🗃"),
+    ("Zig", ["Zig"], "Z⚡⚡🦎"),
+]
+
+# Mappings kept from before the table was generated that Linguist files
+# under other languages or not at all
+EXTRA = {
+    "Rust": {"interpreters": ["cargo"]},
+    "JavaScript": {"extensions": ["min.js"]},
+    "TypeScript": {"extensions": ["d.ts", "d.mts", "d.cts"]},
+    "Css": {"extensions": ["min.css"]},
+    "Python": {"extensions": ["pyc", "pyo", "pyd"]},
+    "Java": {"extensions": ["class", "jar"]},
+    "Go": {"extensions": ["mod", "sum"]},
+    "Shell": {
+        "extensions": ["fish", "csh", "tcsh"],
+        "interpreters": ["fish", "csh", "tcsh"],
+    },
+    "Sql": {"extensions": ["pgsql", "sqlite"]},
+    "Git": {"extensions": ["gitattributes", "gitmodules"]},
+}
+
+# Extensions claimed by several languages, none of which lists it first
+EXTENSION_OVERRIDES = {
+    "h": "C",
+    "pl": "Perl",
+}
+
+# MIME types as named by shared-mime-info, or IANA where it registers one;
+# languages without an entry are served as text/plain
+MIME_TYPES = {
+    "Rust": "text/rust",
+    "JavaScript": "text/javascript",
+    "Python": "text/x-python",
+    "C": "text/x-csrc",
+    "Java": "text/x-java",
+    "Go": "text/x-go",
+    "Html": "text/html",
+    "Css": "text/css",
+    "Json": "application/json",
+    "Xml": "application/xml",
+    "Yaml": "application/yaml",
+    "Toml": "application/toml",
+    "Markdown": "text/markdown",
+    "Shell": "application/x-shellscript",
+    "Sql": "application/sql",
+    "Docker": "text/x-dockerfile",
+    "Ada": "text/x-adasrc",
+    "Assembly": "text/x-asm",
+    "Awk": "application/x-awk",
+    "Batchfile": "application/x-bat",
+    "CMake": "text/x-cmake",
+    "CSharp": "text/x-csharp",
+    "Clojure": "text/x-clojure",
+    "CoffeeScript": "application/vnd.coffeescript",
+    "CommonLisp": "text/x-common-lisp",
+    "Cpp": "text/x-c++src",
+    "Crystal": "text/x-crystal",
+    "Csv": "text/csv",
+    "D": "text/x-dsrc",
+    "Dart": "application/vnd.dart",
+    "Elixir": "text/x-elixir",
+    "Elm": "text/x-elm",
+    "EmacsLisp": "text/x-emacs-lisp",
+    "Erlang": "text/x-erlang",
+    "FSharp": "text/x-fsharp",
+    "Fortran": "text/x-fortran",
+    "Gherkin": "text/x-gherkin",
+    "Glsl": "text/x-glsl",
+    "GraphQl": "application/graphql",
+    "Groovy": "text/x-groovy",
+    "Handlebars": "text/x-handlebars-template",
+    "Haskell": "text/x-haskell",
+    "Haxe": "text/x-haxe",
+    "Julia": "text/x-julia",
+    "JupyterNotebook": "application/x-ipynb+json",
+    "Kotlin": "text/x-kotlin",
+    "Less": "text/x-less",
+    "Lua": "text/x-lua",
+    "Makefile": "text/x-makefile",
+    "Matlab": "text/x-matlab",
+    "Mdx": "text/mdx",
+    "Meson": "text/x-meson",
+    "Nix": "text/x-nix",
+    "ObjectiveC": "text/x-objcsrc",
+    "ObjectiveCpp": "text/x-objc++src",
+    "Ocaml": "text/x-ocaml",
+    "Org": "text/org",
+    "Pascal": "text/x-pascal",
+    "Perl": "application/x-perl",
+    "Php": "application/x-php",
+    "PowerShell": "application/x-powershell",
+    "Protobuf": "text/x-protobuf",
+    "Pug": "text/x-pug",
+    "Puppet": "text/x-puppet",
+    "R": "text/x-rsrc",
+    "ReStructuredText": "text/x-rst",
+    "Ruby": "application/x-ruby",
+    "Sass": "text/x-sass",
+    "Scala": "text/x-scala",
+    "Scheme": "text/x-scheme",
+    "Scss": "text/x-scss",
+    "Solidity": "text/x-solidity",
+    "Svg": "image/svg+xml",
+    "Swift": "text/x-swift",
+    "SystemVerilog": "text/x-systemverilog",
+    "Tcl": "text/x-tcl",
+    "Tex": "text/x-tex",
+    "TypeScript": "application/typescript",
+    "Vala": "text/x-vala",
+    "Verilog": "text/x-verilog",
+    "Vhdl": "text/x-vhdl",
+    "VisualBasic": "text/x-vb",
+    "Vue": "text/x-vue",
+    "Xslt": "application/xslt+xml",
+}
+
+# Variant for unknown files, not part of Linguist
+CODE = ("Code", "Unknown/generic code file", "C<💻⌨")
+
+
+def fetch(path):
+    if not os.path.exists(path):
+        print("downloading %s" % LANGUAGES_URL, file=sys.stderr)
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        urllib.request.urlretrieve(LANGUAGES_URL, path)
+    with open(path, encoding="utf-8") as f:
+        return yaml.safe_load(f)
+
+
+# Rich and Fancy glyphs by color family
+COLOR_GLYPHS = {
+    "red": ("🔴", "🟥"),
+    "orange": ("🟠", "🟧"),
+    "yellow": ("🟡", "🟨"),
+    "green": ("🟢", "🟩"),
+    "blue": ("🔵", "🟦"),
+    "purple": ("🟣", "🟪"),
+    "brown": ("🟤", "🟫"),
+    "black": ("⚫", "⬛"),
+    "white": ("⚪", "⬜"),
+}
+
+
+def color_family(color):
+    if color is None:
+        return "white"
+    r, g, b = (int(color[i : i + 2], 16) / 255 for i in (1, 3, 5))
+    h, l, s = colorsys.rgb_to_hls(r, g, b)
+    hue = h * 360
+    if l < 0.12:
+        return "black"
+    if s < 0.15:
+        return "white" if l > 0.75 else "black"
+    if 15 <= hue < 45 and l < 0.35:
+        return "brown"
+    if hue < 15 or hue >= 330:
+        return "red"
+    if hue < 45:
+        return "orange"
+    if hue < 70:
+        return "yellow"
+    if hue < 170:
+        return "green"
+    if hue < 260:
+        return "blue"
+    return "purple"
+
+
+def glyphs(variant, color, given):
+    if given is None:
+        letter = variant[0].upper()
+        rich, fancy = COLOR_GLYPHS[color_family(color)]
+        given = letter + letter + rich + fancy
+    chars = list(given)
+    if len(chars) != 4:
+        sys.exit("%s: glyphs must be 4 code points, got %r" % (variant, given))
+    return chars
+
+
+def rust_char(c):
+    if c == "'" or c == "\\":
+        return "'\\%s'" % c
+    return "'%s'" % c
+
+
+def rust_str(s):
+    return '"%s"' % s.replace("\\", "\\\\").replace('"', '\\"')
+
+
+def rust_strs(items):
+    return "&[%s]" % ", ".join(rust_str(s) for s in items)
+
+
+def dedup(items):
+    seen = []
+    for item in items:
+        if item not in seen:
+            seen.append(item)
+    return seen
+
+
+def collect(linguist):
+    languages = []
+    for variant, names, given in LANGUAGES:
+        if not names:
+            continue
+        data = [linguist[name] for name in names]
+        extra = EXTRA.get(variant, {})
+
+        def merged(key, strip_dot=False):
+            items = [item for d in data for item in d.get(key, [])]
+            if strip_dot:
+                items = [item[1:] if item.startswith(".") else item for item in items]
+            return dedup([item.lower() for item in items] + extra.get(key, []))
+
+        color = data[0].get("color")
+        languages.append(
+            {
+                "variant": variant,
+                "name": names[0],
+                "doc": " and ".join(", ".join(names).rsplit(", ", 1)),
+                "color": color,
+                "aliases": dedup(
+                    [a.lower() for d in data for a in d.get("aliases", [])]
+                ),
+                "extensions": merged("extensions", strip_dot=True),
+                "primary": {
+                    d["extensions"][0][1:].lower() for d in data if d.get("extensions")
+                },
+                "filenames": merged("filenames"),
+                "interpreters": merged("interpreters"),
+                "mime_type": MIME_TYPES.get(variant, "text/plain"),
+                "glyphs": glyphs(variant, color, given),
+            }
+        )
+    return languages
+
+
+def extension_table(languages):
+    claims = {}
+    for lang in languages:
+        for ext in lang["extensions"]:
+            claims.setdefault(ext, []).append(lang)
+
+    table = []
+    for ext, langs in claims.items():
+        primary = [lang for lang in langs if ext in lang["primary"]]
+        if ext in EXTENSION_OVERRIDES:
+            table.append((ext, EXTENSION_OVERRIDES[ext]))
+        elif len(primary) == 1:
+            table.append((ext, primary[0]["variant"]))
+        elif len(langs) == 1:
+            table.append((ext, langs[0]["variant"]))
+        # Otherwise ambiguous: left to other rules
+    return sorted(table)
+
+
+def first_claim_table(languages, key):
+    table = {}
+    for lang in languages:
+        for item in lang[key]:
+            table.setdefault(item, lang["variant"])
+    return sorted(table.items())
+
+
+def name_table(languages):
+    table = {}
+    for lang in languages:
+        names = [lang["name"].lower(), lang["variant"].lower()] + lang["aliases"]
+        for name in names:
+            table.setdefault(name, lang["variant"])
+    table.setdefault(CODE[0].lower(), CODE[0])
+    return sorted(table.items())
+
+
+def write_lookup(f, const, table, doc):
+    f.write("\n/// %s\n" % doc)
+    f.write("pub(super) const %s: &[(&str, LanguageType)] = &[\n" % const)
+    for key, variant in table:
+        f.write("    (%s, LanguageType::%s),\n" % (rust_str(key), variant))
+    f.write("];\n")
+
+
+def gen_languages(path):
+    linguist = fetch(path)
+    languages = collect(linguist)
+
+    with open(OUT_FILE, "w", encoding="utf-8", newline="\n") as f:
+        f.write(HEADER)
+        f.write("\nuse super::LanguageInfo;\n\n")
+
+        f.write("/// Programming language file types\n")
+        f.write("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n")
+        f.write("pub enum LanguageType {\n")
+        for lang in languages:
+            f.write("    /// %s files\n" % lang["doc"])
+            f.write("    %s,\n" % lang["variant"])
+        f.write("    /// %s\n" % CODE[1])
+        f.write("    %s,\n" % CODE[0])
+        f.write("}\n\n")
+
+        f.write("/// Language data, indexed by `LanguageType as usize`\n")
+        f.write("pub(super) const LANGUAGES: &[LanguageInfo] = &[\n")
+        for lang in languages:
+            color = "Some(%s)" % rust_str(lang["color"]) if lang["color"] else "None"
+            f.write("    LanguageInfo {\n")
+            f.write("        language: LanguageType::%s,\n" % lang["variant"])
+            f.write("        name: %s,\n" % rust_str(lang["name"]))
+            f.write("        color: %s,\n" % color)
+            for key in ("aliases", "extensions", "filenames", "interpreters"):
+                f.write("        %s: %s,\n" % (key, rust_strs(lang[key])))
+            f.write("        mime_type: %s,\n" % rust_str(lang["mime_type"]))
+            f.write(
+                "        glyphs: [%s],\n" % ", ".join(rust_char(c) for c in lang["glyphs"])
+            )
+            f.write("    },\n")
+        f.write("    LanguageInfo {\n")
+        f.write("        language: LanguageType::%s,\n" % CODE[0])
+        f.write("        name: %s,\n" % rust_str(CODE[0]))
+        f.write("        color: None,\n")
+        for key in ("aliases", "extensions", "filenames", "interpreters"):
+            f.write("        %s: &[],\n" % key)
+        f.write("        mime_type: \"text/plain\",\n")
+        f.write("        glyphs: [%s],\n" % ", ".join(rust_char(c) for c in CODE[2]))
+        f.write("    },\n")
+        f.write("];\n")
+
+        write_lookup(
+            f, "EXTENSIONS", extension_table(languages), "Lowercase extension to language"
+        )
+        write_lookup(
+            f,
+            "FILENAMES",
+            first_claim_table(languages, "filenames"),
+            "Lowercase file name to language",
+        )
+        write_lookup(
+            f,
+            "INTERPRETERS",
+            first_claim_table(languages, "interpreters"),
+            "Interpreter name to language",
+        )
+        write_lookup(
+            f, "NAMES", name_table(languages), "Lowercase name or alias to language"
+        )
+    subprocess.run(["rustfmt", "--edition", "2021", OUT_FILE], check=True)
+    print("wrote %s" % os.path.relpath(OUT_FILE, ROOT), file=sys.stderr)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n")[0])
+    parser.add_argument(
+        "--languages-yml",
+        default=os.path.join(ROOT, "target", "linguist-" + LINGUIST_VERSION, "languages.yml"),
+        help="Linguist languages.yml to read (or cache the download in)",
+    )
+    args = parser.parse_args()
+    gen_languages(args.languages_yml)
+
+
+if __name__ == "__main__":
+    main()
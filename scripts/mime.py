@@ -0,0 +1,207 @@
+#!/usr/bin/env python3
+"""Generate the MIME type tables in src/unicode/file_types/mime_types.rs.
+
+Usage:
+
+    python3 scripts/mime.py [--mime-dir DIR]
+
+The data comes from shared-mime-info's compiled database (`globs2` and
+`aliases`, as written by update-mime-database), by default the one installed
+in /usr/share/mime, with `subclasses` to tell text formats apart. Only `*.ext` globs are used; extensions the language table
+claims are left to `LanguageType::mime_type`, so run scripts/languages.py
+first. Where a language's MIME type is a shared-mime-info alias, or
+shared-mime-info names the language's primary extension as another text
+format, the
+language's name becomes canonical and the shared-mime-info name an alias of it.
+"""
+
+import argparse
+import os
+import re
+import subprocess
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUT_FILE = os.path.join(ROOT, "src", "unicode", "file_types", "mime_types.rs")
+LANGUAGES_FILE = os.path.join(ROOT, "src", "unicode", "file_types", "languages.rs")
+
+HEADER = """// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/mime.py
+//
+// shared-mime-info version: %s.
+"""
+
+# Aliases seen in the wild that shared-mime-info does not list
+EXTRA_ALIASES = {
+    "application/x-toml": "application/toml",
+    "application/x-typescript": "application/typescript",
+    "audio/mp3": "audio/mpeg",
+    "audio/x-mp3": "audio/mpeg",
+    "image/jpg": "image/jpeg",
+    "image/x-png": "image/png",
+    "text/typescript": "application/typescript",
+    "text/x-python3": "text/x-python",
+    "text/x-rustsrc": "text/rust",
+    "text/x-typescript": "application/typescript",
+}
+
+# Formats shared-mime-info 2.2 has no glob for
+EXTRA_TYPES = {
+    "info": "text/plain",
+    "rdoc": "text/plain",
+    "sqlite": "application/vnd.sqlite3",
+    "text": "text/plain",
+    "textile": "text/plain",
+    "wasm": "application/wasm",
+}
+
+# Compiled artifacts and databases the language table maps to a language,
+# which are served as what they are rather than as source
+ARTIFACTS = {"class", "jar", "pyc", "pyo", "pyd", "sqlite"}
+
+SIMPLE_GLOB = re.compile(r"^\*\.([A-Za-z0-9][A-Za-z0-9_.+~-]*)$")
+
+
+def read_languages():
+    """Extensions the language table claims, and each language's MIME type
+    with its primary extension."""
+    with open(LANGUAGES_FILE, encoding="utf-8") as f:
+        source = f.read()
+    table = source.split("pub(super) const EXTENSIONS", 1)[1].split("];", 1)[0]
+    extensions = set(re.findall(r'\("([^"]+)", LanguageType::', table))
+    mime_types = {}
+    for info in source.split("LanguageInfo {")[1:]:
+        mime = re.search(r'mime_type: "([^"]+)"', info).group(1)
+        primary = re.search(r'extensions: &\[\s*"([^"]+)"', info)
+        if mime != "text/plain":
+            mime_types[mime] = primary.group(1) if primary else None
+    return extensions, mime_types
+
+
+def read_globs(mime_dir):
+    """Lowercase extension to the MIME types of its highest-weight globs."""
+    best = {}
+    with open(os.path.join(mime_dir, "globs2"), encoding="utf-8") as f:
+        for line in f:
+            if line.startswith("#") or not line.strip():
+                continue
+            fields = line.rstrip("\n").split(":")
+            weight, mime, glob = int(fields[0]), fields[1], fields[2]
+            flags = fields[3].split(",") if len(fields) > 3 else []
+            m = SIMPLE_GLOB.match(glob)
+            # Case-sensitive globs such as *.C tell files apart by case only
+            if not m or "cs" in flags:
+                continue
+            ext = m.group(1).lower()
+            if ext not in best or weight > best[ext][0]:
+                best[ext] = (weight, [mime])
+            elif weight == best[ext][0] and mime not in best[ext][1]:
+                best[ext][1].append(mime)
+    return {ext: mimes for ext, (_, mimes) in best.items()}
+
+
+def read_aliases(mime_dir):
+    aliases = {}
+    with open(os.path.join(mime_dir, "aliases"), encoding="utf-8") as f:
+        for line in f:
+            alias, canonical = line.split()
+            aliases[alias.lower()] = canonical.lower()
+    return aliases
+
+
+def is_text(mime, parents):
+    seen = set()
+    while mime not in seen:
+        if mime.startswith("text/"):
+            return True
+        seen.add(mime)
+        mime = parents.get(mime, mime)
+    return False
+
+
+def read_parents(mime_dir):
+    parents = {}
+    with open(os.path.join(mime_dir, "subclasses"), encoding="utf-8") as f:
+        for line in f:
+            child, parent = line.split()
+            parents.setdefault(child.lower(), parent.lower())
+    return parents
+
+
+def gen_mime_types(mime_dir):
+    with open(os.path.join(mime_dir, "version"), encoding="utf-8") as f:
+        version = f.read().strip()
+    language_extensions, language_mimes = read_languages()
+    aliases = read_aliases(mime_dir)
+    globs = read_globs(mime_dir)
+    parents = read_parents(mime_dir)
+
+    # A language's MIME type takes over the shared-mime-info type it aliases,
+    # or the text format of its primary extension if that is unambiguous, so
+    # that console ROMs sharing an extension with a language are left alone
+    renamed = {}
+    for mime, primary in language_mimes.items():
+        if mime in aliases:
+            renamed[aliases.pop(mime)] = mime
+        elif len(globs.get(primary, [])) == 1:
+            found = globs[primary][0]
+            if found != mime and is_text(found, parents):
+                renamed[found] = mime
+    for old, new in renamed.items():
+        aliases[old] = new
+    for alias, canonical in EXTRA_ALIASES.items():
+        aliases.setdefault(alias, canonical)
+
+    def resolve(mime):
+        seen = set()
+        while mime in aliases and mime not in seen:
+            seen.add(mime)
+            mime = aliases[mime]
+        return mime
+
+    for ext, mime in EXTRA_TYPES.items():
+        globs.setdefault(ext, [mime])
+
+    # The first glob of the highest weight wins ties
+    types = sorted(
+        (ext, resolve(mimes[0]))
+        for ext, mimes in globs.items()
+        if ext not in language_extensions or ext in ARTIFACTS
+    )
+    canonical = set(language_mimes) | {mime for _, mime in types}
+    alias_table = sorted(
+        (alias, resolve(alias))
+        for alias in aliases
+        if alias not in canonical and resolve(alias) != alias
+    )
+
+    with open(OUT_FILE, "w", encoding="utf-8", newline="\n") as f:
+        f.write(HEADER % version)
+        f.write("\n/// Lowercase extension to MIME type, for formats that are not languages\n")
+        f.write("pub(super) const MIME_TYPES: &[(&str, &str)] = &[\n")
+        for ext, mime in types:
+            f.write('    ("%s", "%s"),\n' % (ext, mime))
+        f.write("];\n")
+        f.write("\n/// Alias to canonical MIME type\n")
+        f.write("pub(super) const MIME_ALIASES: &[(&str, &str)] = &[\n")
+        for alias, mime in alias_table:
+            f.write('    ("%s", "%s"),\n' % (alias, mime))
+        f.write("];\n")
+    subprocess.run(["rustfmt", "--edition", "2021", OUT_FILE], check=True)
+    print("wrote %s" % os.path.relpath(OUT_FILE, ROOT), file=sys.stderr)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n")[0])
+    parser.add_argument(
+        "--mime-dir",
+        default="/usr/share/mime",
+        help="shared-mime-info database directory with globs2 and aliases",
+    )
+    args = parser.parse_args()
+    gen_mime_types(args.mime_dir)
+
+
+if __name__ == "__main__":
+    main()
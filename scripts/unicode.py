@@ -0,0 +1,738 @@
+#!/usr/bin/env python3
+"""Generate the Unicode Character Database tables in src/unicode/tables/.
+
+Usage:
+
+    python3 scripts/unicode.py [--ucd-dir DIR]
+
+Without --ucd-dir the required files are downloaded from unicode.org into
+`target/ucd-<version>/` and reused on later runs. With --ucd-dir the files are
+read from DIR instead, which must contain all files listed in UCD_FILES as
+plain file names (no sub-directories).
+"""
+
+import argparse
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = (16, 0, 0)
+VERSION_STR = "%d.%d.%d" % UNICODE_VERSION
+
+UCD_URL = "https://www.unicode.org/Public/%s/ucd/" % VERSION_STR
+
+# File name -> URL, relative to UCD_URL unless absolute
+UCD_FILES = {
+    "UnicodeData.txt": "UnicodeData.txt",
+    "DerivedAge.txt": "DerivedAge.txt",
+    "Scripts.txt": "Scripts.txt",
+    "ScriptExtensions.txt": "ScriptExtensions.txt",
+    "PropertyValueAliases.txt": "PropertyValueAliases.txt",
+    "PropList.txt": "PropList.txt",
+    "DerivedCoreProperties.txt": "DerivedCoreProperties.txt",
+    "NameAliases.txt": "NameAliases.txt",
+    "CaseFolding.txt": "CaseFolding.txt",
+    "DerivedNormalizationProps.txt": "DerivedNormalizationProps.txt",
+    "GraphemeBreakProperty.txt": "auxiliary/GraphemeBreakProperty.txt",
+    "DerivedBidiClass.txt": "extracted/DerivedBidiClass.txt",
+    "BidiBrackets.txt": "BidiBrackets.txt",
+    "BidiCharacterTest.txt": "BidiCharacterTest.txt",
+    "emoji-data.txt": "emoji/emoji-data.txt",
+    "emoji-sequences.txt": "https://www.unicode.org/Public/emoji/%d.%d/emoji-sequences.txt"
+    % UNICODE_VERSION[:2],
+    "emoji-zwj-sequences.txt": "https://www.unicode.org/Public/emoji/%d.%d/emoji-zwj-sequences.txt"
+    % UNICODE_VERSION[:2],
+    "confusables.txt": "https://www.unicode.org/Public/security/%s/confusables.txt"
+    % VERSION_STR,
+    "IdentifierStatus.txt": "https://www.unicode.org/Public/security/%s/IdentifierStatus.txt"
+    % VERSION_STR,
+    "IdentifierType.txt": "https://www.unicode.org/Public/security/%s/IdentifierType.txt"
+    % VERSION_STR,
+}
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUT_DIR = os.path.join(ROOT, "src", "unicode", "tables")
+
+HEADER = """// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: %s.
+""" % VERSION_STR
+
+
+def fetch(ucd_dir, name):
+    path = os.path.join(ucd_dir, name)
+    if not os.path.exists(path):
+        url = UCD_FILES[name]
+        if not url.startswith("https://"):
+            url = UCD_URL + url
+        print("downloading %s" % url, file=sys.stderr)
+        os.makedirs(ucd_dir, exist_ok=True)
+        urllib.request.urlretrieve(url, path)
+    with open(path, encoding="utf-8") as f:
+        return f.read()
+
+
+def parse_ranges(text):
+    """Yield (first, last, fields) for each data line of a UCD property file."""
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        fields = [f.strip() for f in line.split(";")]
+        cps = fields[0].split("..")
+        first = int(cps[0], 16)
+        last = int(cps[1], 16) if len(cps) > 1 else first
+        yield first, last, fields[1:]
+
+
+def merge(ranges):
+    """Sort (first, last, value) triples and merge adjacent equal values."""
+    out = []
+    for first, last, value in sorted(ranges):
+        if out and out[-1][1] + 1 == first and out[-1][2] == value:
+            out[-1] = (out[-1][0], last, value)
+        else:
+            out.append((first, last, value))
+    return out
+
+
+def ch(cp):
+    return "'\\u{%X}'" % cp
+
+
+def write_table(f, name, ranges, fmt=None, ty=None):
+    if fmt is None:
+        f.write("pub(crate) const %s: &[(char, char)] = &[\n" % name)
+        for first, last, _ in ranges:
+            f.write("    (%s, %s),\n" % (ch(first), ch(last)))
+    else:
+        f.write("pub(crate) const %s: &[(char, char, %s)] = &[\n" % (name, ty))
+        for first, last, value in ranges:
+            f.write("    (%s, %s, %s),\n" % (ch(first), ch(last), fmt(value)))
+    f.write("];\n")
+
+
+def emit(name, body):
+    path = os.path.join(OUT_DIR, name)
+    with open(path, "w", encoding="utf-8", newline="\n") as f:
+        f.write(HEADER)
+        f.write("\n")
+        body(f)
+    print("wrote %s" % os.path.relpath(path, ROOT), file=sys.stderr)
+
+
+# --- UnicodeData.txt ---------------------------------------------------------
+
+GENERAL_CATEGORIES = {
+    "Lu": "UppercaseLetter",
+    "Ll": "LowercaseLetter",
+    "Lt": "TitlecaseLetter",
+    "Lm": "ModifierLetter",
+    "Lo": "OtherLetter",
+    "Mn": "NonspacingMark",
+    "Mc": "SpacingMark",
+    "Me": "EnclosingMark",
+    "Nd": "DecimalNumber",
+    "Nl": "LetterNumber",
+    "No": "OtherNumber",
+    "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation",
+    "Ps": "OpenPunctuation",
+    "Pe": "ClosePunctuation",
+    "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation",
+    "Po": "OtherPunctuation",
+    "Sm": "MathSymbol",
+    "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol",
+    "So": "OtherSymbol",
+    "Zs": "SpaceSeparator",
+    "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator",
+    "Cc": "Control",
+    "Cf": "Format",
+    "Cs": "Surrogate",
+    "Co": "PrivateUse",
+}
+
+
+def parse_unicode_data(text):
+    """Return a list of (first, last, fields) with First/Last ranges folded."""
+    out = []
+    pending = None
+    for line in text.splitlines():
+        if not line:
+            continue
+        fields = line.split(";")
+        cp = int(fields[0], 16)
+        name = fields[1]
+        if name.endswith(", First>"):
+            pending = cp
+            continue
+        if name.endswith(", Last>"):
+            out.append((pending, cp, fields))
+            pending = None
+            continue
+        out.append((cp, cp, fields))
+    return out
+
+
+def gen_general_category(ucd_dir):
+    data = parse_unicode_data(fetch(ucd_dir, "UnicodeData.txt"))
+    ranges = merge(
+        (first, last, f[2])
+        for first, last, f in data
+        # Surrogates can never be a `char`.
+        if f[2] != "Cs"
+    )
+
+    def body(f):
+        f.write("use crate::unicode::security::GeneralCategory::{self, *};\n\n")
+        write_table(
+            f,
+            "GENERAL_CATEGORY",
+            ranges,
+            fmt=lambda v: GENERAL_CATEGORIES[v],
+            ty="GeneralCategory",
+        )
+
+    emit("general_category.rs", body)
+
+
+# --- Names -------------------------------------------------------------------
+
+# UnicodeData.txt range labels whose names are derived by rule NR2
+RANGE_NAME_PREFIXES = {
+    "CJK Ideograph": "CJK UNIFIED IDEOGRAPH-",
+    "Tangut Ideograph": "TANGUT IDEOGRAPH-",
+}
+
+HEX_NAME = re.compile(r"^(.*-)([0-9A-F]{4,6})$")
+
+
+def write_bytes(f, name, data, ty="u8"):
+    f.write("pub(crate) const %s: &[%s] = &[\n" % (name, ty))
+    per_line = 16 if ty == "u8" else 8
+    for i in range(0, len(data), per_line):
+        f.write("    %s,\n" % ", ".join(str(b) for b in data[i : i + per_line]))
+    f.write("];\n")
+
+
+def gen_names(ucd_dir):
+    """Word-compressed Name property table.
+
+    Every name is split into space-separated words. Words are numbered by
+    descending frequency and each name is stored as a length byte followed by
+    its word numbers: numbers below 0x80 take one byte, the rest two bytes
+    with the high bit of the first byte set. Names that embed their own code point in
+    hex (rule NR2) are stored as prefix ranges instead, and Hangul syllable
+    names (rule NR1) are computed at runtime.
+    """
+    algorithmic = []
+    explicit = []
+    for first, last, f in parse_unicode_data(fetch(ucd_dir, "UnicodeData.txt")):
+        name = f[1]
+        if name.startswith("<"):
+            label = name[1:].split(",")[0]
+            for key, prefix in RANGE_NAME_PREFIXES.items():
+                if label.startswith(key):
+                    algorithmic.append((first, last, prefix))
+            continue
+        m = HEX_NAME.match(name)
+        if m and int(m.group(2), 16) == first:
+            algorithmic.append((first, last, m.group(1)))
+            continue
+        explicit.append((first, name))
+    algorithmic = merge(algorithmic)
+
+    freq = {}
+    for _, name in explicit:
+        for word in name.split(" "):
+            freq[word] = freq.get(word, 0) + 1
+    words = sorted(freq, key=lambda w: (-freq[w], w))
+    assert len(words) < 0x8000
+    number = {w: i for i, w in enumerate(words)}
+
+    lexicon_offsets = [0]
+    for word in words:
+        lexicon_offsets.append(lexicon_offsets[-1] + len(word))
+
+    # Each name is stored as a length byte followed by its word numbers, so
+    # a name is found by skipping names from the start of its range.
+    name_bytes = []
+    name_ranges = []
+    for cp, name in explicit:
+        if name_ranges and name_ranges[-1][1] + 1 == cp:
+            name_ranges[-1] = (name_ranges[-1][0], cp, name_ranges[-1][2])
+        else:
+            name_ranges.append((cp, cp, len(name_bytes)))
+        encoded = []
+        for word in name.split(" "):
+            n = number[word]
+            if n < 0x80:
+                encoded.append(n)
+            else:
+                encoded.extend([0x80 | (n >> 8), n & 0xFF])
+        assert len(encoded) < 0x100
+        name_bytes.append(len(encoded))
+        name_bytes.extend(encoded)
+
+    aliases = []
+    for first, _, fields in parse_ranges(fetch(ucd_dir, "NameAliases.txt")):
+        aliases.append((first, fields[0], fields[1]))
+
+    def body(f):
+        write_table(
+            f,
+            "ALGORITHMIC_NAMES",
+            algorithmic,
+            fmt=lambda v: '"%s"' % v,
+            ty="&str",
+        )
+        f.write("\n")
+        f.write("pub(crate) const LEXICON: &str = concat!(\n")
+        for word in words:
+            f.write('    "%s",\n' % word)
+        f.write(");\n\n")
+        write_bytes(f, "LEXICON_OFFSETS", lexicon_offsets, ty="u32")
+        f.write("\n")
+        write_table(f, "NAME_RANGES", name_ranges, fmt=str, ty="u32")
+        f.write("\n")
+        f.write(
+            'pub(crate) const NAME_BYTES: &[u8] = include_bytes!("names.bin");\n'
+        )
+        f.write("\n")
+        f.write("pub(crate) const NAME_ALIASES: &[(char, &str, &str)] = &[\n")
+        for cp, alias, kind in aliases:
+            f.write('    (%s, "%s", "%s"),\n' % (ch(cp), alias, kind))
+        f.write("];\n")
+
+    emit("names.rs", body)
+    with open(os.path.join(OUT_DIR, "names.bin"), "wb") as f:
+        f.write(bytes(name_bytes))
+
+
+# --- Normalization -----------------------------------------------------------
+
+
+def chars(cps):
+    return "&[%s]" % ", ".join(ch(cp) for cp in cps)
+
+
+def gen_normalization(ucd_dir):
+    data = parse_unicode_data(fetch(ucd_dir, "UnicodeData.txt"))
+    excluded = set()
+    for first, last, fields in parse_ranges(
+        fetch(ucd_dir, "DerivedNormalizationProps.txt")
+    ):
+        if fields[0] == "Full_Composition_Exclusion":
+            excluded.update(range(first, last + 1))
+
+    ccc = []
+    decompositions = []
+    compositions = []
+    for first, last, f in data:
+        if int(f[3]):
+            ccc.append((first, last, int(f[3])))
+        if not f[5]:
+            continue
+        parts = f[5].split(" ")
+        compat = parts[0].startswith("<")
+        if compat:
+            parts = parts[1:]
+        mapping = [int(p, 16) for p in parts]
+        decompositions.append((first, compat, mapping))
+        if not compat and len(mapping) == 2 and first not in excluded:
+            compositions.append((mapping[0], mapping[1], first))
+    ccc = merge(ccc)
+    compositions.sort()
+
+    def body(f):
+        write_table(f, "CANONICAL_COMBINING_CLASS", ccc, fmt=str, ty="u8")
+        f.write("\n")
+        f.write(
+            "/// Single-level decomposition mappings: (character, is compatibility mapping, mapping)\n"
+        )
+        f.write("pub(crate) const DECOMPOSITION: &[(char, bool, &[char])] = &[\n")
+        for cp, compat, mapping in decompositions:
+            f.write(
+                "    (%s, %s, %s),\n"
+                % (ch(cp), "true" if compat else "false", chars(mapping))
+            )
+        f.write("];\n\n")
+        f.write("/// Primary composites, sorted by the pair they compose from\n")
+        f.write("pub(crate) const COMPOSITION: &[((char, char), char)] = &[\n")
+        for a, b, c in compositions:
+            f.write("    ((%s, %s), %s),\n" % (ch(a), ch(b), ch(c)))
+        f.write("];\n")
+
+    emit("normalization.rs", body)
+
+
+# --- Grapheme clusters -------------------------------------------------------
+
+
+def gen_grapheme(ucd_dir):
+    ranges = merge(
+        (first, last, fields[0])
+        for first, last, fields in parse_ranges(
+            fetch(ucd_dir, "GraphemeBreakProperty.txt")
+        )
+    )
+
+    def body(f):
+        f.write(
+            "use crate::unicode::security::grapheme::GraphemeClusterBreak::{self, *};\n\n"
+        )
+        write_table(
+            f,
+            "GRAPHEME_CLUSTER_BREAK",
+            ranges,
+            fmt=lambda v: v.replace("_", ""),
+            ty="GraphemeClusterBreak",
+        )
+
+    emit("grapheme.rs", body)
+
+
+# --- Bidirectional classes ---------------------------------------------------
+
+BIDI_CLASSES = {
+    "L": "LeftToRight",
+    "R": "RightToLeft",
+    "AL": "ArabicLetter",
+    "EN": "EuropeanNumber",
+    "ES": "EuropeanSeparator",
+    "ET": "EuropeanTerminator",
+    "AN": "ArabicNumber",
+    "CS": "CommonSeparator",
+    "NSM": "NonspacingMark",
+    "BN": "BoundaryNeutral",
+    "B": "ParagraphSeparator",
+    "S": "SegmentSeparator",
+    "WS": "WhiteSpace",
+    "ON": "OtherNeutral",
+    "LRE": "LeftToRightEmbedding",
+    "LRO": "LeftToRightOverride",
+    "RLE": "RightToLeftEmbedding",
+    "RLO": "RightToLeftOverride",
+    "PDF": "PopDirectionalFormat",
+    "LRI": "LeftToRightIsolate",
+    "RLI": "RightToLeftIsolate",
+    "FSI": "FirstStrongIsolate",
+    "PDI": "PopDirectionalIsolate",
+}
+
+MISSING = re.compile(r"^# @missing: ([0-9A-F]+)\.\.([0-9A-F]+); (\w+)")
+
+
+def gen_bidi(ucd_dir):
+    """Bidi_Class with the file's @missing defaults for unassigned code points.
+
+    Left_To_Right is the lookup fallback and is left out of the table.
+    """
+    text = fetch(ucd_dir, "DerivedBidiClass.txt")
+    # @missing lines use long value names, e.g. Right_To_Left
+    short_names = {v: k for k, v in BIDI_CLASSES.items()}
+    values = ["L"] * 0x110000
+    for line in text.splitlines():
+        m = MISSING.match(line)
+        if m:
+            first, last = int(m.group(1), 16), int(m.group(2), 16)
+            value = short_names[m.group(3).replace("_", "")]
+            values[first : last + 1] = [value] * (last - first + 1)
+    for first, last, fields in parse_ranges(text):
+        values[first : last + 1] = [fields[0]] * (last - first + 1)
+    ranges = merge(
+        (cp, cp, v)
+        for cp, v in enumerate(values)
+        if v != "L" and not (0xD800 <= cp <= 0xDFFF)
+    )
+
+    brackets = []
+    for first, _, fields in parse_ranges(fetch(ucd_dir, "BidiBrackets.txt")):
+        brackets.append((first, int(fields[0], 16), fields[1] == "o"))
+    brackets.sort()
+
+    def body(f):
+        f.write("use crate::unicode::security::BidiClass::{self, *};\n\n")
+        write_table(
+            f, "BIDI_CLASS", ranges, fmt=lambda v: BIDI_CLASSES[v], ty="BidiClass"
+        )
+        f.write("\n")
+        f.write("/// Bidi paired brackets: (bracket, paired bracket, is opening)\n")
+        f.write("pub(crate) const BIDI_BRACKETS: &[(char, char, bool)] = &[\n")
+        for cp, pair, is_open in brackets:
+            f.write(
+                "    (%s, %s, %s),\n" % (ch(cp), ch(pair), "true" if is_open else "false")
+            )
+        f.write("];\n")
+
+    emit("bidi.rs", body)
+    # Not a table: cached for the conformance test in src/unicode/security/bidi.rs
+    fetch(ucd_dir, "BidiCharacterTest.txt")
+
+
+# --- Identifier_Type -----------------------------------------------------------
+
+IDENTIFIER_TYPES = {
+    "Not_Character": "NotCharacter",
+    "Deprecated": "Deprecated",
+    "Default_Ignorable": "DefaultIgnorable",
+    "Not_NFKC": "NotNfkc",
+    "Not_XID": "NotXid",
+    "Exclusion": "Exclusion",
+    "Obsolete": "Obsolete",
+    "Technical": "Technical",
+    "Uncommon_Use": "UncommonUse",
+    "Limited_Use": "LimitedUse",
+    "Inclusion": "Inclusion",
+    "Recommended": "Recommended",
+}
+
+
+def gen_identifier_type(ucd_dir):
+    """UTS #39 Identifier_Type, a set of values per code point.
+
+    Not_Character is the @missing default and the lookup fallback, so it is
+    left out of the table.
+    """
+    ranges = merge(
+        (first, last, tuple(fields[0].split()))
+        for first, last, fields in parse_ranges(fetch(ucd_dir, "IdentifierType.txt"))
+        if fields[0] != "Not_Character"
+    )
+
+    def body(f):
+        f.write("use crate::unicode::security::IdentifierType::{self, *};\n\n")
+        write_table(
+            f,
+            "IDENTIFIER_TYPE",
+            ranges,
+            fmt=lambda v: "&[%s]" % ", ".join(IDENTIFIER_TYPES[t] for t in v),
+            ty="&[IdentifierType]",
+        )
+
+    emit("identifier.rs", body)
+
+
+# --- Scripts ---------------------------------------------------------------
+
+
+def gen_scripts(ucd_dir):
+    """Script and Script_Extensions by ISO 15924 code, with the script names.
+
+    Unknown (Zzzz) is the @missing default and the lookup fallback, so it is
+    left out of the tables. Script_Extensions only lists the code points
+    whose extensions differ from their Script.
+    """
+    names = {}
+    for line in fetch(ucd_dir, "PropertyValueAliases.txt").splitlines():
+        fields = [f.strip() for f in line.split("#", 1)[0].split(";")]
+        if fields[0] == "sc":
+            names[fields[2]] = fields[1]
+    script = merge(
+        (first, last, names[fields[0]])
+        for first, last, fields in parse_ranges(fetch(ucd_dir, "Scripts.txt"))
+    )
+    extensions = merge(
+        (first, last, tuple(sorted(fields[0].split())))
+        for first, last, fields in parse_ranges(fetch(ucd_dir, "ScriptExtensions.txt"))
+    )
+
+    def body(f):
+        f.write("pub(crate) const SCRIPT_NAMES: &[(&[u8; 4], &str)] = &[\n")
+        for code, name in sorted((code, name) for name, code in names.items()):
+            f.write('    (b"%s", "%s"),\n' % (code, name.replace("_", " ")))
+        f.write("];\n\n")
+        write_table(f, "SCRIPT", script, fmt=lambda v: 'b"%s"' % v, ty="&[u8; 4]")
+        f.write("\n")
+        write_table(
+            f,
+            "SCRIPT_EXTENSIONS",
+            extensions,
+            fmt=lambda v: "&[%s]" % ", ".join('*b"%s"' % code for code in v),
+            ty="&[[u8; 4]]",
+        )
+
+    emit("script.rs", body)
+
+
+# --- CaseFolding.txt ---------------------------------------------------------
+
+
+def gen_case_folding(ucd_dir):
+    """Full case folding: the C and F mappings of CaseFolding.txt."""
+    mappings = []
+    for first, _, fields in parse_ranges(fetch(ucd_dir, "CaseFolding.txt")):
+        if fields[0] in ("C", "F"):
+            mappings.append((first, [int(p, 16) for p in fields[1].split(" ")]))
+    mappings.sort()
+
+    def body(f):
+        f.write("/// Full case folding: (character, folded form)\n")
+        f.write("pub(crate) const CASE_FOLDING: &[(char, &str)] = &[\n")
+        for cp, mapping in mappings:
+            f.write("    (%s, %s),\n" % (ch(cp), rust_str(mapping)))
+        f.write("];\n")
+
+    emit("case_folding.rs", body)
+
+
+# --- Confusables -------------------------------------------------------------
+
+
+def rust_str(cps):
+    return '"%s"' % "".join("\\u{%X}" % cp for cp in cps)
+
+
+def gen_confusables(ucd_dir):
+    prototypes = []
+    for first, _, fields in parse_ranges(fetch(ucd_dir, "confusables.txt")):
+        prototypes.append((first, [int(p, 16) for p in fields[0].split(" ")]))
+    prototypes.sort()
+
+    def body(f):
+        f.write("/// UTS #39 confusable prototypes: (source, prototype)\n")
+        f.write("pub(crate) const CONFUSABLES: &[(char, &str)] = &[\n")
+        for cp, mapping in prototypes:
+            f.write("    (%s, %s),\n" % (ch(cp), rust_str(mapping)))
+        f.write("];\n")
+
+    emit("confusables.rs", body)
+
+
+# --- Emoji sequences -------------------------------------------------------
+
+
+def parse_sequences(text, prop):
+    """Yield the code points of each sequence of an emoji-*sequences.txt property."""
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        fields = [f.strip() for f in line.split(";")]
+        if fields[1] == prop:
+            yield [int(cp, 16) for cp in fields[0].split(" ")]
+
+
+def gen_emoji_sequences(ucd_dir):
+    # Minimally-qualified forms are matched too, so FE0F is left out
+    zwj = sorted(
+        {
+            tuple(cp for cp in cps if cp != 0xFE0F)
+            for cps in parse_sequences(
+                fetch(ucd_dir, "emoji-zwj-sequences.txt"), "RGI_Emoji_ZWJ_Sequence"
+            )
+        }
+    )
+    tag = sorted(
+        parse_sequences(fetch(ucd_dir, "emoji-sequences.txt"), "RGI_Emoji_Tag_Sequence")
+    )
+
+    def body(f):
+        for i, (doc, const, sequences) in enumerate(
+            [
+                (
+                    "UTS #51 RGI emoji ZWJ sequences without U+FE0F",
+                    "RGI_EMOJI_ZWJ_SEQUENCES",
+                    zwj,
+                ),
+                ("UTS #51 RGI emoji tag sequences", "RGI_EMOJI_TAG_SEQUENCES", tag),
+            ]
+        ):
+            if i:
+                f.write("\n")
+            f.write("/// %s, sorted\n" % doc)
+            f.write("pub(crate) const %s: &[&str] = &[\n" % const)
+            for cps in sequences:
+                f.write("    %s,\n" % rust_str(cps))
+            f.write("];\n")
+
+    emit("emoji.rs", body)
+
+
+# --- DerivedAge.txt ----------------------------------------------------------
+
+
+def gen_age(ucd_dir):
+    ranges = []
+    for first, last, fields in parse_ranges(fetch(ucd_dir, "DerivedAge.txt")):
+        major, minor = fields[0].split(".")
+        ranges.append((first, last, (int(major), int(minor))))
+    ranges = merge(r for r in ranges if not (0xD800 <= r[0] <= 0xDFFF))
+
+    def body(f):
+        f.write(
+            "pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (%d, %d, %d);\n\n"
+            % UNICODE_VERSION
+        )
+        write_table(f, "AGE", ranges, fmt=lambda v: "(%d, %d)" % v, ty="(u8, u8)")
+
+    emit("age.rs", body)
+
+
+# --- Binary properties -------------------------------------------------------
+
+# (file, property, Rust constant)
+BOOL_PROPERTIES = [
+    ("PropList.txt", "Deprecated", "DEPRECATED"),
+    ("emoji-data.txt", "Extended_Pictographic", "EXTENDED_PICTOGRAPHIC"),
+    ("DerivedCoreProperties.txt", "XID_Start", "XID_START"),
+    ("DerivedCoreProperties.txt", "XID_Continue", "XID_CONTINUE"),
+    (
+        "DerivedCoreProperties.txt",
+        "Default_Ignorable_Code_Point",
+        "DEFAULT_IGNORABLE_CODE_POINT",
+    ),
+    ("IdentifierStatus.txt", "Allowed", "IDENTIFIER_ALLOWED"),
+]
+
+
+def gen_property_bool(ucd_dir):
+    tables = []
+    for file, prop, const in BOOL_PROPERTIES:
+        ranges = [
+            (first, last, None)
+            for first, last, fields in parse_ranges(fetch(ucd_dir, file))
+            if fields[0] == prop
+        ]
+        tables.append((const, merge(ranges)))
+
+    def body(f):
+        for i, (const, ranges) in enumerate(tables):
+            if i:
+                f.write("\n")
+            write_table(f, const, ranges)
+
+    emit("property_bool.rs", body)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n")[0])
+    parser.add_argument(
+        "--ucd-dir",
+        default=os.path.join(ROOT, "target", "ucd-" + VERSION_STR),
+        help="directory holding (or caching) the UCD source files",
+    )
+    args = parser.parse_args()
+
+    gen_general_category(args.ucd_dir)
+    gen_names(args.ucd_dir)
+    gen_normalization(args.ucd_dir)
+    gen_case_folding(args.ucd_dir)
+    gen_grapheme(args.ucd_dir)
+    gen_bidi(args.ucd_dir)
+    gen_identifier_type(args.ucd_dir)
+    gen_scripts(args.ucd_dir)
+    gen_confusables(args.ucd_dir)
+    gen_emoji_sequences(args.ucd_dir)
+    gen_age(args.ucd_dir)
+    gen_property_bool(args.ucd_dir)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,661 @@
+//! Command-line Unicode security scanner
+//!
+//! Walks files and directories (respecting `.gitignore`), skips binary files
+//! and reports dangerous Unicode characters. Exits with status 1 when a finding
+//! reaches the `--fail-on` level.
+//!
+//! With `--diff` it reads a unified diff from stdin and only checks added lines:
+//!
+//! ```text
+//! git diff origin/main... | unicode-rs --diff
+//! ```
+//!
+//! As a pre-commit hook, this checks what is staged rather than the working
+//! tree, and passes when nothing is:
+//!
+//! ```text
+//! git diff --cached --diff-filter=ACM | unicode-rs --diff --fail-on high
+//! ```
+//!
+//! Accepted findings can be suppressed with `unicode-rs: allow <rules>`
+//! comments, an allowlist of path patterns (`.unicode-rs-allow` by default)
+//! and a baseline written with `--write-baseline`, so that only new findings
+//! fail:
+//!
+//! ```text
+//! unicode-rs --write-baseline .unicode-rs-baseline
+//! unicode-rs --baseline .unicode-rs-baseline
+//! ```
+//!
+//! File associations can be extended with a rules file (`.unicode-rs-types`
+//! by default), e.g. `*.tpl language=go`, which `--lang` and the backup file
+//! filter then honor.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use ignore::WalkBuilder;
+use unicode_rs::file_types::BINARY_SNIFF_LEN;
+use unicode_rs::security::{
+    render_compact, render_json, render_sarif, scan_diff_with_config, Allowlist, Baseline,
+    FileReport, FindingKind, RiskLevel, SecurityConfig, UnicodeVersion,
+};
+use unicode_rs::{is_binary_content, FileClassifier, FileType, LanguageType};
+
+const USAGE: &str = "\
+Usage: unicode-rs [OPTIONS] [PATH]...
+       unicode-rs [OPTIONS] --diff < changes.diff
+
+Scan files and directories for dangerous Unicode characters.
+PATH defaults to the current directory.
+
+Options:
+      --format <FORMAT>      Output format: compact, sarif or json [default: compact]
+      --severity <LEVEL>     Report findings at or above LEVEL [default: low]
+      --fail-on <LEVEL>      Exit with status 1 if a finding is at or above LEVEL [default: high]
+      --fix                  Remove unsuppressed invisible and bidi control characters in place
+      --diff                 Read a unified diff from stdin and scan only added lines
+      --lang <LANGUAGE>      Only scan files of this language, e.g. rust or c++ (repeatable)
+      --unicode-version <V>  Treat code points assigned after V as unassigned, e.g. 15.1
+      --allowlist <FILE>     Suppress findings allowed for their path [default: .unicode-rs-allow]
+      --baseline <FILE>      Suppress findings recorded in a baseline file
+      --file-types <FILE>    Read file association rules [default: .unicode-rs-types]
+      --write-baseline <FILE>
+                             Record the current findings as a baseline and exit
+      --hidden               Scan hidden files and directories
+      --no-ignore            Do not respect .gitignore and other ignore files
+  -h, --help                 Print help
+  -V, --version              Print version
+
+LEVEL is one of low, medium, high or critical. Findings are critical for bidi
+controls, high for invisible characters and noncharacters, medium for mixed
+scripts, confusables and unassigned code points, and low otherwise.
+--fail-on considers every finding, including those --severity hides.
+Suppressed findings are reported as notes with their reason and never fail.
+Exit status: 0 if clean, 1 if findings reached --fail-on, 2 on errors.";
+
+/// Allowlist read when `--allowlist` is not given, if it exists
+const DEFAULT_ALLOWLIST: &str = ".unicode-rs-allow";
+
+/// File association rules read when `--file-types` is not given, if they exist
+const DEFAULT_FILE_TYPES: &str = ".unicode-rs-types";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Compact,
+    Sarif,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Options {
+    paths: Vec<String>,
+    format: Format,
+    severity: RiskLevel,
+    fail_on: RiskLevel,
+    fix: bool,
+    diff: bool,
+    languages: Vec<String>,
+    config: SecurityConfig,
+    allowlist: Option<String>,
+    baseline: Option<String>,
+    write_baseline: Option<String>,
+    file_types: Option<String>,
+    classifier: FileClassifier,
+    hidden: bool,
+    no_ignore: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            paths: Vec::new(),
+            format: Format::Compact,
+            severity: RiskLevel::Low,
+            fail_on: RiskLevel::High,
+            fix: false,
+            diff: false,
+            languages: Vec::new(),
+            config: SecurityConfig::default(),
+            allowlist: None,
+            baseline: None,
+            write_baseline: None,
+            file_types: None,
+            classifier: FileClassifier::new(),
+            hidden: false,
+            no_ignore: false,
+        }
+    }
+}
+
+/// What the command line asks for
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Scan(Options),
+    Help,
+    Version,
+}
+
+fn main() -> ExitCode {
+    let mut options = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Scan(options)) => options,
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("unicode-rs {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("unicode-rs: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    options.classifier = match load_classifier(&options) {
+        Ok(classifier) => classifier,
+        Err(message) => {
+            eprintln!("unicode-rs: {}", message);
+            return ExitCode::from(2);
+        }
+    };
+    let (allowlist, baseline) = match load_suppressions(&options) {
+        Ok(suppressions) => suppressions,
+        Err(message) => {
+            eprintln!("unicode-rs: {}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut had_errors = false;
+    let mut reports = Vec::new();
+    if options.diff {
+        match scan_stdin_diff(&options, &allowlist, &baseline) {
+            Ok(diff_reports) => reports = diff_reports,
+            Err(err) => {
+                eprintln!("unicode-rs: stdin: {}", err);
+                had_errors = true;
+            }
+        }
+    } else {
+        for path in collect_files(&options, &mut had_errors) {
+            match scan_file(&path, &options, &allowlist, &baseline) {
+                Ok(Some(report)) => reports.push(report),
+                Ok(None) => {}
+                Err(err) => {
+                    eprintln!("unicode-rs: {}: {}", path, err);
+                    had_errors = true;
+                }
+            }
+        }
+    }
+
+    let failed = apply_thresholds(&mut reports, &options);
+
+    if let Some(path) = &options.write_baseline {
+        let baseline = Baseline::from_reports(&reports);
+        if let Err(err) = fs::write(path, baseline.to_string()) {
+            eprintln!("unicode-rs: {}: {}", path, err);
+            return ExitCode::from(2);
+        }
+        eprintln!(
+            "unicode-rs: recorded {} findings in {}",
+            baseline.len(),
+            path
+        );
+        return if had_errors {
+            ExitCode::from(2)
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    let output = match options.format {
+        Format::Compact => render_compact(&reports),
+        Format::Sarif => render_sarif(&reports),
+        Format::Json => render_json(&reports),
+    };
+    if io::stdout().write_all(output.as_bytes()).is_err() {
+        return ExitCode::from(2);
+    }
+
+    if had_errors {
+        ExitCode::from(2)
+    } else if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+        if flag == "--" {
+            options.paths.extend(args);
+            break;
+        }
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value for {}", name))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--format" => {
+                options.format = match value("--format")?.as_str() {
+                    "compact" => Format::Compact,
+                    "sarif" => Format::Sarif,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format '{}'", other)),
+                }
+            }
+            "--severity" => options.severity = parse_level(&value("--severity")?)?,
+            "--fail-on" => options.fail_on = parse_level(&value("--fail-on")?)?,
+            "--fix" => options.fix = true,
+            "--diff" => options.diff = true,
+            "--lang" => options.languages.push(value("--lang")?.to_lowercase()),
+            "--unicode-version" => {
+                options.config = SecurityConfig::with_unicode_version(parse_version(&value(
+                    "--unicode-version",
+                )?)?)
+            }
+            "--allowlist" => options.allowlist = Some(value("--allowlist")?),
+            "--baseline" => options.baseline = Some(value("--baseline")?),
+            "--write-baseline" => options.write_baseline = Some(value("--write-baseline")?),
+            "--file-types" => options.file_types = Some(value("--file-types")?),
+            "--hidden" => options.hidden = true,
+            "--no-ignore" => options.no_ignore = true,
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option '{}'", arg))
+            }
+            _ => options.paths.push(arg),
+        }
+    }
+
+    if options.diff {
+        if options.fix {
+            return Err("--fix cannot be used with --diff".to_string());
+        }
+        if !options.paths.is_empty() {
+            return Err("paths cannot be used with --diff".to_string());
+        }
+    } else if options.paths.is_empty() {
+        options.paths.push(".".to_string());
+    }
+    Ok(Command::Scan(options))
+}
+
+fn parse_level(level: &str) -> Result<RiskLevel, String> {
+    match level.to_lowercase().as_str() {
+        "low" => Ok(RiskLevel::Low),
+        "medium" => Ok(RiskLevel::Medium),
+        "high" => Ok(RiskLevel::High),
+        "critical" => Ok(RiskLevel::Critical),
+        _ => Err(format!("unknown level '{}'", level)),
+    }
+}
+
+fn parse_version(version: &str) -> Result<UnicodeVersion, String> {
+    let parts: Result<Vec<u8>, _> = version.split('.').map(str::parse).collect();
+    match parts.as_deref() {
+        Ok([major]) => Ok(UnicodeVersion::new(*major, 0, 0)),
+        Ok([major, minor]) => Ok(UnicodeVersion::new(*major, *minor, 0)),
+        Ok([major, minor, update]) => Ok(UnicodeVersion::new(*major, *minor, *update)),
+        _ => Err(format!("invalid Unicode version '{}'", version)),
+    }
+}
+
+/// Read the allowlist and baseline files
+///
+/// A new baseline is written from scratch, so an existing one is ignored.
+fn load_suppressions(options: &Options) -> Result<(Allowlist, Baseline), String> {
+    let read = |path: &str| fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err));
+
+    let allowlist = match &options.allowlist {
+        Some(path) => Allowlist::parse(&read(path)?),
+        None if Path::new(DEFAULT_ALLOWLIST).is_file() => {
+            Allowlist::parse(&read(DEFAULT_ALLOWLIST)?)
+        }
+        None => Ok(Allowlist::new()),
+    };
+    let allowlist = allowlist.map_err(|err| {
+        let path = options.allowlist.as_deref().unwrap_or(DEFAULT_ALLOWLIST);
+        format!("{}: {}", path, err)
+    })?;
+
+    let baseline = match (&options.baseline, &options.write_baseline) {
+        (Some(path), None) => {
+            Baseline::parse(&read(path)?).map_err(|err| format!("{}: {}", path, err))?
+        }
+        _ => Baseline::new(),
+    };
+    Ok((allowlist, baseline))
+}
+
+/// Read the file association rules
+fn load_classifier(options: &Options) -> Result<FileClassifier, String> {
+    let path = match &options.file_types {
+        Some(path) => path,
+        None if Path::new(DEFAULT_FILE_TYPES).is_file() => DEFAULT_FILE_TYPES,
+        None => return Ok(FileClassifier::new()),
+    };
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+    FileClassifier::parse(&text).map_err(|err| format!("{}: {}", path, err))
+}
+
+/// Expand the requested paths into the files to scan, in walk order
+fn collect_files(options: &Options, had_errors: &mut bool) -> Vec<String> {
+    let mut builder = WalkBuilder::new(&options.paths[0]);
+    for path in &options.paths[1..] {
+        builder.add(path);
+    }
+    builder
+        .hidden(!options.hidden)
+        .require_git(false)
+        .git_ignore(!options.no_ignore)
+        .git_global(!options.no_ignore)
+        .git_exclude(!options.no_ignore)
+        .ignore(!options.no_ignore)
+        .parents(!options.no_ignore)
+        .filter_entry(|entry| entry.file_name() != ".git");
+
+    let mut files = Vec::new();
+    for entry in builder.build() {
+        match entry {
+            Ok(entry) if entry.file_type().is_some_and(|t| t.is_file()) => {
+                let path = entry.path().strip_prefix("./").unwrap_or(entry.path());
+                if is_wanted(path, options) {
+                    files.push(path.to_string_lossy().into_owned());
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("unicode-rs: {}", err);
+                *had_errors = true;
+            }
+        }
+    }
+    files
+}
+
+/// Filter by file type and the `--lang` list
+fn is_wanted(path: &Path, options: &Options) -> bool {
+    let result = options.classifier.classify(&path.to_string_lossy());
+    if is_copy_file_type(result.file_type) {
+        return false;
+    }
+    if options.languages.is_empty() {
+        return true;
+    }
+    let language = result.language.unwrap_or(LanguageType::Code);
+    options
+        .languages
+        .iter()
+        .any(|name| LanguageType::from_name(name) == Some(language))
+}
+
+/// Whether files of this type are copies of files scanned anyway
+///
+/// Other types are not skipped by name: media such as SVG or EPS can be
+/// text, so [`scan_file`] decides by content.
+fn is_copy_file_type(file_type: FileType) -> bool {
+    matches!(file_type, FileType::Temporary | FileType::Backup)
+}
+
+/// Whether any active finding reaches `--fail-on`, then drop the findings
+/// below `--severity`
+///
+/// The exit status does not depend on which findings are shown.
+fn apply_thresholds(reports: &mut [FileReport], options: &Options) -> bool {
+    let failed = reports
+        .iter()
+        .flat_map(|report| report.active_findings())
+        .any(|finding| finding.kind.risk_level() >= options.fail_on);
+    for report in reports {
+        report
+            .findings
+            .retain(|finding| finding.kind.risk_level() >= options.severity);
+    }
+    failed
+}
+
+/// Scan one file, applying `--fix` if requested
+///
+/// Returns `None` for binary files and files that are not valid UTF-8.
+fn scan_file(
+    path: &str,
+    options: &Options,
+    allowlist: &Allowlist,
+    baseline: &Baseline,
+) -> io::Result<Option<FileReport>> {
+    // Sniff the head first so that large binaries are not read whole
+    let mut file = fs::File::open(path)?;
+    let mut bytes = Vec::new();
+    (&mut file)
+        .take(BINARY_SNIFF_LEN as u64)
+        .read_to_end(&mut bytes)?;
+    if is_binary_content(&bytes) {
+        return Ok(None);
+    }
+    file.read_to_end(&mut bytes)?;
+    let Ok(text) = String::from_utf8(bytes) else {
+        return Ok(None);
+    };
+
+    let scan = |text: &str| {
+        let mut report = FileReport::new(path, text, &options.config);
+        allowlist.apply(&mut report);
+        baseline.apply(&mut report);
+        report
+    };
+    let report = scan(&text);
+    if options.fix {
+        if let Some(fixed) = fix_text(&text, &report) {
+            fs::write(path, &fixed)?;
+            eprintln!("unicode-rs: fixed {}", path);
+            return Ok(Some(scan(&fixed)));
+        }
+    }
+    Ok(Some(report))
+}
+
+/// `text` without the invisible and bidi control characters of active
+/// findings, or `None` if there are none
+///
+/// Characters whose findings are suppressed inline, by the allowlist or by
+/// the baseline are kept.
+fn fix_text(text: &str, report: &FileReport) -> Option<String> {
+    let offsets: HashSet<usize> = report
+        .active_findings()
+        .filter(|finding| {
+            matches!(
+                finding.kind,
+                FindingKind::InvisibleCharacter
+                    | FindingKind::BidiOverride
+                    | FindingKind::UnterminatedBidi
+            )
+        })
+        .map(|finding| finding.offset)
+        .collect();
+    if offsets.is_empty() {
+        return None;
+    }
+    let fixed = text
+        .char_indices()
+        .filter(|(pos, _)| !offsets.contains(pos))
+        .map(|(_, ch)| ch)
+        .collect();
+    Some(fixed)
+}
+
+/// Scan the lines added by a unified diff read from stdin
+fn scan_stdin_diff(
+    options: &Options,
+    allowlist: &Allowlist,
+    baseline: &Baseline,
+) -> io::Result<Vec<FileReport>> {
+    let mut bytes = Vec::new();
+    io::stdin().read_to_end(&mut bytes)?;
+    // Diffs may mix encodings across files; undecodable bytes cannot hide
+    // Unicode characters, so a lossy conversion is enough
+    let diff = String::from_utf8_lossy(&bytes);
+
+    let mut reports = scan_diff_with_config(&diff, &options.config);
+    reports.retain(|report| is_wanted(Path::new(&report.path), options));
+    for report in &mut reports {
+        allowlist.apply(report);
+        baseline.apply(report);
+    }
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let Ok(Command::Scan(options)) = parse(&[]) else {
+            panic!("expected scan");
+        };
+        assert_eq!(options.paths, vec!["."]);
+        assert_eq!(options.format, Format::Compact);
+        assert_eq!(options.fail_on, RiskLevel::High);
+        assert!(!options.fix);
+        assert_eq!(options.allowlist, None);
+        assert_eq!(options.baseline, None);
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let Ok(Command::Scan(options)) = parse(&[
+            "--format=sarif",
+            "--fail-on",
+            "critical",
+            "--severity",
+            "Medium",
+            "--fix",
+            "--lang",
+            "Rust",
+            "--unicode-version",
+            "15.1",
+            "--allowlist",
+            "allow.txt",
+            "--baseline=base.txt",
+            "--file-types",
+            "types.txt",
+            "src",
+            "--",
+            "--odd-name",
+        ]) else {
+            panic!("expected scan");
+        };
+        assert_eq!(options.format, Format::Sarif);
+        assert_eq!(options.fail_on, RiskLevel::Critical);
+        assert_eq!(options.severity, RiskLevel::Medium);
+        assert!(options.fix);
+        assert_eq!(options.languages, vec!["rust"]);
+        assert_eq!(
+            options.config.unicode_version,
+            UnicodeVersion::new(15, 1, 0)
+        );
+        assert_eq!(options.allowlist.as_deref(), Some("allow.txt"));
+        assert_eq!(options.baseline.as_deref(), Some("base.txt"));
+        assert_eq!(options.file_types.as_deref(), Some("types.txt"));
+        assert_eq!(options.paths, vec!["src", "--odd-name"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse(&["--help", "--bogus"]), Ok(Command::Help));
+        assert!(parse(&["--bogus"]).is_err());
+        assert!(parse(&["--fail-on"]).is_err());
+        assert!(parse(&["--fail-on", "severe"]).is_err());
+        assert!(parse(&["--unicode-version", "15.x"]).is_err());
+        assert!(parse(&["--diff", "--fix"]).is_err());
+        assert!(parse(&["--diff", "src"]).is_err());
+        assert!(parse(&["--baseline"]).is_err());
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let config = SecurityConfig::default();
+        let scan = |text: &str| vec![FileReport::new("a.rs", text, &config)];
+
+        // A comment in Russian mixes scripts but does not fail the default level
+        let mut reports = scan("let x = 1; // привет мир\n");
+        assert!(!reports[0].findings.is_empty());
+        assert!(!apply_thresholds(&mut reports, &Options::default()));
+
+        // Hiding findings with --severity does not change the exit status
+        let options = Options {
+            severity: RiskLevel::Critical,
+            ..Options::default()
+        };
+        let mut reports = scan("let x\u{200B} = 1;");
+        assert!(apply_thresholds(&mut reports, &options));
+        assert!(reports[0].findings.is_empty());
+    }
+
+    #[test]
+    fn test_fix_keeps_suppressed_characters() {
+        let text = "a = \"\u{200F}x\" // unicode-rs: allow bidi\nb = \"\u{200F}y\u{200B}\"\n";
+        let allowlist = Allowlist::new().with_rule("*.rs", &[FindingKind::Confusable], "");
+        let mut report = FileReport::new("a.rs", text, &SecurityConfig::default());
+        allowlist.apply(&mut report);
+        assert_eq!(
+            fix_text(text, &report).as_deref(),
+            Some("a = \"\u{200F}x\" // unicode-rs: allow bidi\nb = \"y\"\n")
+        );
+
+        let allowlist = Allowlist::new().with_rule("*.rs", &[FindingKind::InvisibleCharacter], "");
+        let mut report = FileReport::new("a.rs", "x\u{200B}", &SecurityConfig::default());
+        allowlist.apply(&mut report);
+        assert_eq!(fix_text("x\u{200B}", &report), None);
+    }
+
+    #[test]
+    fn test_file_filters() {
+        assert!(is_binary_content(b"\x7fELF\0\0"));
+        assert!(!is_binary_content("caf\u{e9}".as_bytes()));
+
+        let options = Options {
+            languages: vec!["rust".to_string()],
+            ..Options::default()
+        };
+        assert!(is_wanted(Path::new("src/lib.rs"), &options));
+        assert!(!is_wanted(Path::new("main.py"), &options));
+        assert!(!is_wanted(Path::new("notes.rs.bak"), &Options::default()));
+
+        let options = Options {
+            classifier: FileClassifier::parse("*.tpl language=rust\nout/** type=temporary")
+                .unwrap(),
+            ..options
+        };
+        assert!(is_wanted(Path::new("templates/page.tpl"), &options));
+        assert!(!is_wanted(Path::new("out/page.rs"), &options));
+        // Images may be text; binary ones are skipped by content
+        assert!(is_wanted(Path::new("logo.svg"), &Options::default()));
+        assert!(is_wanted(Path::new("photo.png"), &Options::default()));
+    }
+}
@@ -74,6 +74,8 @@
 //! - [`editor`] - Editor-specific symbols (cursor, selection)
 //! - [`status`] - Status indicators
 //! - [`security`] - Unicode security utilities for detecting dangerous characters
+//! - `names` - Unicode character names (requires the `names` feature)
+//! - [`normalization`] - Unicode normalization forms (NFC, NFD, NFKC, NFKD)
 
 pub mod unicode;
 
@@ -83,10 +85,11 @@ pub use unicode::*;
 /// Prelude module for convenient imports
 pub mod prelude {
     pub use crate::unicode::{
-        get_char, get_file_type_from_extension, get_file_type_from_filename, get_global_config,
-        get_str, set_global_config, Arrow, Block, Border, Control, Cursor, FileType, GitAction,
-        GitBranch, GitDiff, GitStatus, Indicator, LanguageType, Navigation, Selection, Separator,
-        Shape, Status, Symbol, UnicodeConfig, UnicodeProvider, UnicodeTheme,
+        classify_directory, classify_path, get_char, get_file_type_from_extension,
+        get_file_type_from_filename, get_global_config, get_str, set_global_config, Arrow, Block,
+        Border, Control, Cursor, DirectoryKind, FileClassification, FileType, GitAction, GitBranch,
+        GitDiff, GitStatus, Indicator, LanguageType, Navigation, Selection, Separator, Shape,
+        Status, Symbol, UnicodeConfig, UnicodeProvider, UnicodeTheme,
     };
 }
 
@@ -0,0 +1,420 @@
+//! User-configurable file association rules
+//!
+//! A [`FileClassifier`] layers user rules over the built-in tables, so a
+//! project can declare that `.tpl` files are Go templates or that `.inc`
+//! files are PHP. The free functions such as [`get_file_type_from_filename`]
+//! use the classifier set with [`set_global_classifier`].
+//!
+//! [`get_file_type_from_filename`]: super::get_file_type_from_filename
+
+use std::sync::{OnceLock, RwLock};
+
+use super::{extensions, filenames, languages, lookup, FileType, LanguageType};
+use crate::unicode::glob::{glob_match, normalize_path};
+
+/// How a rule pattern matches a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RuleKind {
+    /// Exact file name, ignoring ASCII case, e.g. `Jenkinsfile`
+    Name,
+    /// `.gitignore` style glob against the whole path, e.g. `templates/**/*.html`
+    Glob,
+    /// File extension without the dot, ignoring case, e.g. `tpl` or `tar.gz`
+    Extension,
+}
+
+/// A user rule assigning a file type, a language or both
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassifierRule {
+    /// How `pattern` matches
+    pub kind: RuleKind,
+    /// Name, glob or extension, depending on `kind`
+    pub pattern: String,
+    /// File type of matching files; the built-in one if `None`
+    pub file_type: Option<FileType>,
+    /// Language of matching files; the built-in one if `None`
+    pub language: Option<LanguageType>,
+    /// Rules with a higher priority are tried first
+    pub priority: i32,
+}
+
+impl ClassifierRule {
+    /// Whether the rule matches a normalized path and its file name
+    fn matches(&self, path: &str, name: &str) -> bool {
+        match self.kind {
+            RuleKind::Name => name.eq_ignore_ascii_case(&self.pattern),
+            RuleKind::Glob => glob_match(&self.pattern, path),
+            RuleKind::Extension => extensions::extensions(&name.to_ascii_lowercase())
+                .any(|extension| extension == self.pattern),
+        }
+    }
+}
+
+/// Which rule decided a file type or language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchedRule {
+    /// A user rule of the classifier
+    User(ClassifierRule),
+    /// A built-in well-known name, e.g. `Cargo.lock`
+    WellKnownName,
+    /// A file name Linguist knows, e.g. `Gemfile`
+    LinguistName,
+    /// A built-in extension
+    Extension,
+    /// A name starting with `.`
+    Hidden,
+    /// No rule matched
+    Default,
+}
+
+/// File type and language of a path, with the rules that decided them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMatch {
+    /// Kind of file
+    pub file_type: FileType,
+    /// Programming language, if known
+    pub language: Option<LanguageType>,
+    /// Rule that decided `file_type`
+    pub file_type_rule: MatchedRule,
+    /// Rule that decided `language`
+    pub language_rule: MatchedRule,
+}
+
+/// Built-in file associations with user rules layered on top
+///
+/// User rules are tried before the built-in tables, highest priority first;
+/// at equal priority exact names come before globs and globs before
+/// extensions, then rules apply in the order they were added. A rule that
+/// only sets a language keeps the built-in file type, and vice versa.
+///
+/// The text format has one rule per line, `<pattern> <key>=<value>...`,
+/// with blank lines and lines starting with `#` ignored. A pattern `*.ext`
+/// is an extension, one containing `*`, `?` or `/` a glob and anything else
+/// an exact name. Keys are `type`, `language` and `priority`:
+///
+/// ```text
+/// # Go templates and legacy PHP includes
+/// *.tpl                language=go
+/// *.inc                language=php
+/// Jenkinsfile          type=build-manifest
+/// templates/**/*.html  language=go priority=10
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// let classifier = FileClassifier::parse("*.inc language=php").unwrap();
+/// let result = classifier.classify("lib/db.inc");
+/// assert_eq!(result.language, Some(LanguageType::Php));
+/// assert!(matches!(result.language_rule, MatchedRule::User(_)));
+/// assert_eq!(result.file_type_rule, MatchedRule::Default);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileClassifier {
+    rules: Vec<ClassifierRule>,
+}
+
+impl FileClassifier {
+    /// Create a classifier with the built-in associations only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a rules file
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut classifier = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let rule = parse_rule(line).map_err(|err| format!("line {}: {}", index + 1, err))?;
+            classifier = classifier.with_rule(rule);
+        }
+        Ok(classifier)
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, mut rule: ClassifierRule) -> Self {
+        if rule.kind == RuleKind::Extension {
+            rule.pattern = rule.pattern.trim_start_matches('.').to_ascii_lowercase();
+        }
+        self.rules.push(rule);
+        // Stable, so equal rules keep the order they were added in
+        self.rules
+            .sort_by_key(|rule| (std::cmp::Reverse(rule.priority), rule.kind));
+        self
+    }
+
+    /// Add all rules of another classifier, e.g. a repository's on top of a user's
+    pub fn with_rules_from(self, other: &FileClassifier) -> Self {
+        other
+            .rules
+            .iter()
+            .cloned()
+            .fold(self, |classifier, rule| classifier.with_rule(rule))
+    }
+
+    /// User rules in the order they are tried
+    pub fn rules(&self) -> &[ClassifierRule] {
+        &self.rules
+    }
+
+    /// Classify a path or file name
+    ///
+    /// Globs match the whole path; names and extensions its last component.
+    pub fn classify(&self, path: &str) -> FileMatch {
+        let path = normalize_path(path);
+        let name = path.rsplit('/').next().unwrap_or_default();
+
+        let (file_type, file_type_rule) = self
+            .matching_rules(&path, name)
+            .find_map(|rule| Some((rule.file_type?, MatchedRule::User(rule.clone()))))
+            .unwrap_or_else(|| builtin_file_type(name));
+        let (language, language_rule) = self
+            .matching_rules(&path, name)
+            .find_map(|rule| Some((Some(rule.language?), MatchedRule::User(rule.clone()))))
+            .unwrap_or_else(|| builtin_language(name));
+
+        FileMatch {
+            file_type,
+            language,
+            file_type_rule,
+            language_rule,
+        }
+    }
+
+    /// First user rule matching a path, if any
+    pub fn user_rule(&self, path: &str) -> Option<&ClassifierRule> {
+        let path = normalize_path(path);
+        let name = path.rsplit('/').next().unwrap_or_default();
+        self.rules.iter().find(|rule| rule.matches(&path, name))
+    }
+
+    /// Language of a single extension, as in [`get_file_type_from_extension`]
+    ///
+    /// [`get_file_type_from_extension`]: super::get_file_type_from_extension
+    pub fn language_from_extension(&self, extension: &str) -> Option<LanguageType> {
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        self.rules
+            .iter()
+            .filter(|rule| rule.kind == RuleKind::Extension && rule.pattern == extension)
+            .find_map(|rule| rule.language)
+            .or_else(|| extensions::language_from_extension(&extension))
+    }
+
+    fn matching_rules<'a>(
+        &'a self,
+        path: &'a str,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a ClassifierRule> {
+        self.rules
+            .iter()
+            .filter(move |rule| rule.matches(path, name))
+    }
+}
+
+/// Built-in file type of a file name
+fn builtin_file_type(name: &str) -> (FileType, MatchedRule) {
+    if let Some((file_type, _)) = filenames::filename_rule(name) {
+        (file_type, MatchedRule::WellKnownName)
+    } else if name.starts_with('.') {
+        (FileType::Hidden, MatchedRule::Hidden)
+    } else if let Some(file_type) = extensions::file_type_from_filename(name) {
+        (file_type, MatchedRule::Extension)
+    } else {
+        (FileType::File, MatchedRule::Default)
+    }
+}
+
+/// Built-in language of a file name
+fn builtin_language(name: &str) -> (Option<LanguageType>, MatchedRule) {
+    if let Some(language) = filenames::filename_rule(name).and_then(|(_, language)| language) {
+        (Some(language), MatchedRule::WellKnownName)
+    } else if let Some(language) = lookup(languages::FILENAMES, &name.to_ascii_lowercase()) {
+        (Some(language), MatchedRule::LinguistName)
+    } else if let Some(language) = extensions::language_from_filename(name) {
+        (Some(language), MatchedRule::Extension)
+    } else {
+        (None, MatchedRule::Default)
+    }
+}
+
+/// Parse one `<pattern> <key>=<value>...` line
+fn parse_rule(line: &str) -> Result<ClassifierRule, String> {
+    let mut words = line.split_whitespace();
+    let pattern = words.next().unwrap_or_default();
+    let (kind, pattern) = match pattern.strip_prefix("*.") {
+        Some(extension) if !extension.contains(['*', '?', '/']) => (RuleKind::Extension, extension),
+        _ if pattern.contains(['*', '?', '/']) => (RuleKind::Glob, pattern),
+        _ => (RuleKind::Name, pattern),
+    };
+
+    let mut rule = ClassifierRule {
+        kind,
+        pattern: pattern.to_string(),
+        file_type: None,
+        language: None,
+        priority: 0,
+    };
+    for word in words {
+        let (key, value) = word
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, found '{}'", word))?;
+        match key {
+            "type" => {
+                rule.file_type = Some(
+                    FileType::from_name(value)
+                        .ok_or_else(|| format!("unknown file type '{}'", value))?,
+                )
+            }
+            "language" => {
+                rule.language = Some(
+                    LanguageType::from_name(value)
+                        .ok_or_else(|| format!("unknown language '{}'", value))?,
+                )
+            }
+            "priority" => {
+                rule.priority = value
+                    .parse()
+                    .map_err(|_| format!("invalid priority '{}'", value))?
+            }
+            _ => return Err(format!("unknown key '{}'", key)),
+        }
+    }
+    if rule.file_type.is_none() && rule.language.is_none() {
+        return Err(format!(
+            "rule for '{}' sets neither type nor language",
+            pattern
+        ));
+    }
+    Ok(rule)
+}
+
+/// Global file classifier, read by every classification and rarely replaced
+static GLOBAL_CLASSIFIER: OnceLock<RwLock<FileClassifier>> = OnceLock::new();
+
+/// Set the classifier used by the free classification functions
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// set_global_classifier(FileClassifier::parse("*.tpl language=go").unwrap());
+/// assert_eq!(get_file_type_from_extension("tpl"), LanguageType::Go);
+/// assert_eq!(get_language_from_filename("page.tpl"), Some(LanguageType::Go));
+/// # set_global_classifier(FileClassifier::new());
+/// ```
+pub fn set_global_classifier(classifier: FileClassifier) {
+    let lock = GLOBAL_CLASSIFIER.get_or_init(|| RwLock::new(FileClassifier::default()));
+    if let Ok(mut guard) = lock.write() {
+        *guard = classifier;
+    }
+}
+
+/// Get a copy of the global classifier
+pub fn get_global_classifier() -> FileClassifier {
+    with_global_classifier(FileClassifier::clone)
+}
+
+/// Run `f` with the global classifier, without copying it
+pub(super) fn with_global_classifier<T>(f: impl FnOnce(&FileClassifier) -> T) -> T {
+    let lock = GLOBAL_CLASSIFIER.get_or_init(|| RwLock::new(FileClassifier::default()));
+    match lock.read() {
+        Ok(guard) => f(&guard),
+        Err(_) => f(&FileClassifier::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_kinds_and_priority() {
+        let classifier = FileClassifier::parse(
+            "\
+# monorepo associations
+*.tpl language=go
+*.inc language=php
+INCLUDE.inc type=config
+legacy/**/*.inc language=c priority=5
+",
+        )
+        .unwrap();
+        let kinds: Vec<_> = classifier.rules().iter().map(|rule| rule.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                RuleKind::Glob,
+                RuleKind::Name,
+                RuleKind::Extension,
+                RuleKind::Extension
+            ]
+        );
+
+        let result = classifier.classify("web/page.TPL");
+        assert_eq!(result.language, Some(LanguageType::Go));
+        assert_eq!(result.file_type, FileType::File);
+
+        let result = classifier.classify("legacy/old/db.inc");
+        assert_eq!(result.language, Some(LanguageType::C));
+        let MatchedRule::User(rule) = result.language_rule else {
+            panic!("expected a user rule");
+        };
+        assert_eq!(rule.priority, 5);
+
+        // The name rule sets the type, the extension rule the language
+        let result = classifier.classify(".\\include.inc");
+        assert_eq!(result.file_type, FileType::Config);
+        assert_eq!(result.language, Some(LanguageType::Php));
+
+        // Built-ins still apply, with their provenance
+        let result = classifier.classify("Cargo.lock");
+        assert_eq!(result.file_type_rule, MatchedRule::WellKnownName);
+        assert_eq!(
+            classifier.classify("Gemfile").language_rule,
+            MatchedRule::LinguistName
+        );
+        assert_eq!(
+            classifier.classify("x.png").file_type_rule,
+            MatchedRule::Extension
+        );
+        assert_eq!(classifier.classify(".envrc").file_type, FileType::Config);
+        assert_eq!(
+            classifier.language_from_extension(".TPL"),
+            Some(LanguageType::Go)
+        );
+    }
+
+    #[test]
+    fn test_extension_case_folding() {
+        // Rules and file names fold ASCII case only, so non-ASCII
+        // extensions match exactly as written
+        let classifier = FileClassifier::parse("*.ÄB language=go\n*.Σ language=rust").unwrap();
+        assert_eq!(classifier.classify("x.ÄB").language, Some(LanguageType::Go));
+        assert_eq!(classifier.classify("x.Äb").language, Some(LanguageType::Go));
+        assert_eq!(
+            classifier.classify("x.Σ").language,
+            Some(LanguageType::Rust)
+        );
+        assert_eq!(classifier.classify("x.σ").language, None);
+        assert_eq!(
+            classifier.language_from_extension("ÄB"),
+            Some(LanguageType::Go)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(FileClassifier::parse("*.tpl").is_err());
+        assert!(FileClassifier::parse("*.tpl language=klingon").is_err());
+        assert!(FileClassifier::parse("*.tpl type=folder").is_err());
+        assert!(FileClassifier::parse("*.tpl language=go priority=high").is_err());
+        let err = FileClassifier::parse("\n*.tpl color=red").unwrap_err();
+        assert_eq!(err, "line 2: unknown key 'color'");
+    }
+}
@@ -0,0 +1,579 @@
+//! Content sniffing
+//!
+//! Classifies a file from its first bytes: magic numbers of common binary
+//! formats, `#!` interpreter lines, and Vim and Emacs modelines. Combined
+//! with the name-based rules in [`classify_file`], every signal becomes a
+//! [`ClassificationCandidate`] and the candidates are ranked by
+//! [`Confidence`], so a PNG saved as `notes.txt` is still an image and
+//! `bin/deploy` starting with `#!/bin/bash` is a shell script.
+
+use std::cmp::Reverse;
+
+use super::{classifier, extensions, filenames, FileType, LanguageType};
+
+/// Bytes inspected when deciding whether content is binary
+pub const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Lines at the start and end of a file searched for modelines
+const MODELINE_LINES: usize = 5;
+
+/// How sure a classification is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Confidence {
+    /// A guess, e.g. text without other signals
+    Low,
+    /// The file extension
+    Medium,
+    /// An explicit declaration: a well-known name, shebang or modeline
+    High,
+    /// A magic number, refined by a consistent extension for container formats
+    Certain,
+}
+
+/// Where a classification came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClassificationSource {
+    /// Magic number at the start of the content
+    MagicBytes,
+    /// `#!` interpreter line
+    Shebang,
+    /// Vim or Emacs modeline
+    Modeline,
+    /// Rule of the global [`FileClassifier`](super::FileClassifier)
+    UserRule,
+    /// Well-known file name such as `Makefile`
+    Filename,
+    /// File extension
+    Extension,
+    /// Text or binary heuristic
+    Heuristic,
+}
+
+/// One possible classification of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassificationCandidate {
+    /// Kind of file
+    pub file_type: FileType,
+    /// Programming language, if the signal implies one
+    pub language: Option<LanguageType>,
+    /// Signal the candidate is based on
+    pub source: ClassificationSource,
+    /// How sure the candidate is
+    pub confidence: Confidence,
+}
+
+/// Content classification with candidates ranked by confidence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentClassification {
+    /// The content looks binary
+    pub is_binary: bool,
+    /// Candidates, most confident first
+    pub candidates: Vec<ClassificationCandidate>,
+}
+
+impl ContentClassification {
+    /// The most confident candidate
+    pub fn best(&self) -> Option<&ClassificationCandidate> {
+        self.candidates.first()
+    }
+
+    /// File type of the most confident candidate
+    pub fn file_type(&self) -> FileType {
+        self.best()
+            .map_or(FileType::File, |candidate| candidate.file_type)
+    }
+
+    /// The most confident language any candidate implies
+    pub fn language(&self) -> Option<LanguageType> {
+        self.candidates
+            .iter()
+            .find_map(|candidate| candidate.language)
+    }
+
+    fn push(
+        &mut self,
+        file_type: FileType,
+        language: Option<LanguageType>,
+        source: ClassificationSource,
+        confidence: Confidence,
+    ) {
+        self.candidates.push(ClassificationCandidate {
+            file_type,
+            language,
+            source,
+            confidence,
+        });
+    }
+
+    /// Order candidates by confidence, keeping the order of equal ones
+    fn rank(&mut self) {
+        self.candidates
+            .sort_by_key(|candidate| Reverse(candidate.confidence));
+    }
+}
+
+/// Magic number: byte offset, signature, file type and, for container
+/// formats, the extensions of formats built on the container, which refine
+/// the file type
+type Magic = (usize, &'static [u8], FileType, &'static [&'static str]);
+
+/// Office documents, e-books, Java archives and image editor files in ZIP
+const ZIP_FORMATS: &[&str] = &[
+    "docx", "docm", "dotx", "xlsx", "xlsm", "xlsb", "pptx", "ppsx", "vsdx", "odt", "ott", "ods",
+    "ots", "odp", "otp", "pages", "numbers", "epub", "xps", "oxps", "jar", "kra", "ora", "sketch",
+];
+
+/// Audio, video and image formats in ISO-BMFF
+const BMFF_FORMATS: &[&str] = &[
+    "mp4", "m4v", "m4a", "m4b", "m4p", "m4r", "mov", "qt", "3gp", "3g2", "f4v", "heic", "heif",
+    "avif", "avifs",
+];
+
+/// Audio and video formats in Ogg
+const OGG_FORMATS: &[&str] = &["ogg", "oga", "ogv", "ogm", "opus", "spx"];
+
+/// Audio and video formats in Matroska
+const MATROSKA_FORMATS: &[&str] = &["mkv", "mk3d", "mka", "webm", "weba"];
+
+/// Magic numbers, more specific ones first
+const MAGIC_NUMBERS: &[Magic] = &[
+    (0, b"\x89PNG\r\n\x1a\n", FileType::Image, &[]),
+    (0, b"\xff\xd8\xff", FileType::Image, &[]),
+    (0, b"GIF87a", FileType::Image, &[]),
+    (0, b"GIF89a", FileType::Image, &[]),
+    (0, b"%PDF-", FileType::Documentation, &[]),
+    (0, b"PK\x03\x04", FileType::Archive, ZIP_FORMATS),
+    (0, b"PK\x05\x06", FileType::Archive, ZIP_FORMATS),
+    (0, b"PK\x07\x08", FileType::Archive, ZIP_FORMATS),
+    (0, b"\x1f\x8b", FileType::Archive, &[]),
+    (0, b"BZh", FileType::Archive, &[]),
+    (0, b"\xfd7zXZ\x00", FileType::Archive, &[]),
+    (0, b"\x28\xb5\x2f\xfd", FileType::Archive, &[]),
+    (0, b"7z\xbc\xaf\x27\x1c", FileType::Archive, &[]),
+    (0, b"Rar!\x1a\x07", FileType::Archive, &[]),
+    (257, b"ustar", FileType::Archive, &[]),
+    (0, b"\x7fELF", FileType::Executable, &[]),
+    (0, b"\0asm", FileType::Executable, &[]),
+    (0, b"MZ", FileType::Executable, &[]),
+    (0, b"\xcf\xfa\xed\xfe", FileType::Executable, &[]),
+    (0, b"\xce\xfa\xed\xfe", FileType::Executable, &[]),
+    (0, b"SQLite format 3\0", FileType::Database, &[]),
+    (0, b"fLaC", FileType::Audio, &[]),
+    (0, b"ID3", FileType::Audio, &[]),
+    // RIFF form types
+    (8, b"WEBP", FileType::Image, &[]),
+    (8, b"WAVE", FileType::Audio, &[]),
+    (8, b"AVI ", FileType::Video, &[]),
+    // ISO-BMFF major brands, then any other `ftyp` box
+    (4, b"ftypM4A ", FileType::Audio, &[]),
+    (4, b"ftypM4B ", FileType::Audio, &[]),
+    (4, b"ftypheic", FileType::Image, &[]),
+    (4, b"ftypheix", FileType::Image, &[]),
+    (4, b"ftypmif1", FileType::Image, &[]),
+    (4, b"ftypavif", FileType::Image, &[]),
+    (4, b"ftyp", FileType::Video, BMFF_FORMATS),
+    (0, b"OggS", FileType::Audio, OGG_FORMATS),
+    (0, b"\x1a\x45\xdf\xa3", FileType::Video, MATROSKA_FORMATS),
+];
+
+/// Check whether content is binary
+///
+/// As in git, a NUL byte in the first [`BINARY_SNIFF_LEN`] bytes marks
+/// content as binary, unless it starts with a UTF-16 byte order mark.
+pub fn is_binary_content(bytes: &[u8]) -> bool {
+    if bytes.starts_with(b"\xff\xfe") || bytes.starts_with(b"\xfe\xff") {
+        return false;
+    }
+    bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+/// Classify content by magic numbers, shebang and modelines
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// let script = classify_content(b"#!/usr/bin/env python3\nprint('hi')\n");
+/// assert_eq!(script.language(), Some(LanguageType::Python));
+/// assert_eq!(script.best().unwrap().source, ClassificationSource::Shebang);
+///
+/// let png = classify_content(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR");
+/// assert!(png.is_binary);
+/// assert_eq!(png.file_type(), FileType::Image);
+/// ```
+pub fn classify_content(bytes: &[u8]) -> ContentClassification {
+    let mut classification = ContentClassification {
+        is_binary: is_binary_content(bytes),
+        candidates: Vec::new(),
+    };
+
+    if let Some(&(_, _, file_type, _)) = magic_number(bytes, classification.is_binary) {
+        classification.push(
+            file_type,
+            None,
+            ClassificationSource::MagicBytes,
+            Confidence::Certain,
+        );
+    }
+
+    if !classification.is_binary {
+        let head = &bytes[..bytes.len().min(BINARY_SNIFF_LEN)];
+        let text = String::from_utf8_lossy(head);
+        if let Some(language) = shebang_language(&text) {
+            classification.push(
+                FileType::File,
+                Some(language),
+                ClassificationSource::Shebang,
+                Confidence::High,
+            );
+        }
+        if let Some(language) = modeline_language(&text, bytes) {
+            classification.push(
+                FileType::File,
+                Some(language),
+                ClassificationSource::Modeline,
+                Confidence::High,
+            );
+        }
+    }
+
+    classification.push(
+        FileType::File,
+        None,
+        ClassificationSource::Heuristic,
+        Confidence::Low,
+    );
+    classification.rank();
+    classification
+}
+
+/// Classify a file by its name and content together
+///
+/// Well-known names rank with shebangs and modelines, extensions below them
+/// and magic numbers above everything, so a mislabeled file is classified by
+/// what it contains. Container formats are the exception: the extension of
+/// a format built on the container refines it, so `report.docx` is
+/// documentation although it starts like any ZIP archive, while a ZIP
+/// archive named `notes.txt` stays an archive. Rules of the
+/// global [`FileClassifier`](super::FileClassifier) rank with magic numbers.
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// let deploy = classify_file("deploy", b"#!/bin/bash\nset -e\n");
+/// assert_eq!(deploy.language(), Some(LanguageType::Shell));
+///
+/// let mislabeled = classify_file("photo.txt", b"GIF89a\x01\0\x01\0");
+/// assert_eq!(mislabeled.file_type(), FileType::Image);
+/// ```
+pub fn classify_file(filename: &str, bytes: &[u8]) -> ContentClassification {
+    let mut classification = classify_content(bytes);
+
+    let user_match = classifier::with_global_classifier(|classifier| {
+        classifier
+            .user_rule(filename)
+            .map(|_| classifier.classify(filename))
+    });
+    if let Some(result) = user_match {
+        classification.push(
+            result.file_type,
+            result.language,
+            ClassificationSource::UserRule,
+            Confidence::Certain,
+        );
+    }
+    if let Some((file_type, language)) = filenames::filename_rule(filename) {
+        classification.push(
+            file_type,
+            language,
+            ClassificationSource::Filename,
+            Confidence::High,
+        );
+    }
+    let file_type = extensions::file_type_from_filename(filename);
+    let language = extensions::language_from_filename(filename);
+    if let Some(&(_, _, _, formats)) = magic_number(bytes, classification.is_binary) {
+        let refined = extensions::extensions(&filename.to_ascii_lowercase())
+            .find_map(|ext| Some((ext, extensions::file_type_from_extension(ext)?)))
+            .filter(|(ext, _)| formats.contains(ext))
+            .map(|(_, file_type)| file_type);
+        if let Some(file_type) = refined {
+            for candidate in &mut classification.candidates {
+                if candidate.source == ClassificationSource::MagicBytes {
+                    candidate.file_type = file_type;
+                }
+            }
+        }
+    }
+    if file_type.is_some() || language.is_some() {
+        classification.push(
+            file_type.unwrap_or(FileType::File),
+            language,
+            ClassificationSource::Extension,
+            Confidence::Medium,
+        );
+    }
+    classification.rank();
+    classification
+}
+
+/// First known magic number the content starts with
+///
+/// Short printable signatures such as `MZ` could start ordinary text, so
+/// they only count in binary content.
+fn magic_number(bytes: &[u8], is_binary: bool) -> Option<&'static Magic> {
+    MAGIC_NUMBERS
+        .iter()
+        .filter(|(_, magic, _, _)| is_binary || magic.len() > 3 || !magic.is_ascii())
+        .find(|(offset, magic, _, _)| {
+            bytes
+                .get(*offset..offset + magic.len())
+                .is_some_and(|found| found == *magic)
+        })
+}
+
+#[cfg(test)]
+fn magic_file_type(bytes: &[u8], is_binary: bool) -> Option<FileType> {
+    magic_number(bytes, is_binary).map(|&(_, _, file_type, _)| file_type)
+}
+
+/// Language of a `#!` interpreter line
+///
+/// `#!/usr/bin/env -S python3 -u` and `#!/usr/local/bin/python3.12` both
+/// name Python; an unknown interpreter gives [`LanguageType::Code`].
+fn shebang_language(text: &str) -> Option<LanguageType> {
+    let line = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let line = line.strip_prefix("#!")?.lines().next()?;
+    let mut words = line.split_whitespace();
+    let mut interpreter = basename(words.next()?);
+    if interpreter == "env" {
+        // Skip env's options and variable assignments
+        interpreter = basename(words.find(|word| !word.starts_with('-') && !word.contains('='))?);
+    }
+    Some(LanguageType::from_interpreter(interpreter).unwrap_or(LanguageType::Code))
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Language named by a Vim or Emacs modeline
+///
+/// Vim modelines (`vim: set ft=python:`) are searched in the first and last
+/// lines, Emacs ones (`-*- mode: python -*-`) in the first two.
+fn modeline_language(head: &str, bytes: &[u8]) -> Option<LanguageType> {
+    let first_lines = head.lines().take(MODELINE_LINES);
+    let tail = String::from_utf8_lossy(&bytes[bytes.len().saturating_sub(BINARY_SNIFF_LEN)..]);
+    let last_lines = tail.lines().rev().take(MODELINE_LINES);
+
+    head.lines()
+        .take(2)
+        .find_map(emacs_mode)
+        .or_else(|| first_lines.chain(last_lines).find_map(vim_filetype))
+        .and_then(language_from_mode)
+}
+
+/// Mode of an Emacs `-*- mode: python -*-` or `-*- python -*-` line
+fn emacs_mode(line: &str) -> Option<&str> {
+    let start = line.find("-*-")? + 3;
+    let end = start + line[start..].find("-*-")?;
+    let vars = line[start..end].trim();
+    if !vars.contains(':') {
+        return Some(vars);
+    }
+    vars.split(';').find_map(|var| {
+        let (name, value) = var.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("mode")
+            .then(|| value.trim())
+    })
+}
+
+/// File type of a Vim modeline such as `vim: ft=rust` or `vi: set filetype=sh:`
+fn vim_filetype(line: &str) -> Option<&str> {
+    let start = ["vim:", "vi:", "ex:"]
+        .iter()
+        .filter_map(|marker| {
+            line.find(marker)
+                // The marker must start the line or follow whitespace
+                .filter(|&i| i == 0 || line[..i].ends_with([' ', '\t']))
+                .map(|i| i + marker.len())
+        })
+        .min()?;
+    line[start..]
+        .split([' ', '\t', ':'])
+        .find_map(|option| {
+            option
+                .strip_prefix("ft=")
+                .or_else(|| option.strip_prefix("filetype="))
+                .or_else(|| option.strip_prefix("syntax="))
+                .or_else(|| option.strip_prefix("syn="))
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// Language of a Vim file type or Emacs mode name
+fn language_from_mode(mode: &str) -> Option<LanguageType> {
+    let mode = mode.to_ascii_lowercase();
+    let mode = mode.strip_suffix("-mode").unwrap_or(&mode);
+    LanguageType::from_name(mode).or_else(|| extensions::language_from_extension(mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_magic_numbers() {
+        let cases: [(&[u8], FileType); 9] = [
+            (b"\x89PNG\r\n\x1a\n\0\0", FileType::Image),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", FileType::Image),
+            (b"GIF87a", FileType::Image),
+            (b"%PDF-1.7\n", FileType::Documentation),
+            (b"PK\x03\x04\x14\0", FileType::Archive),
+            (b"\x1f\x8b\x08\0", FileType::Archive),
+            (b"\x7fELF\x02\x01\x01\0", FileType::Executable),
+            (b"SQLite format 3\0\x10\0", FileType::Database),
+            (b"\0asm\x01\0\0\0", FileType::Executable),
+        ];
+        for (bytes, file_type) in cases {
+            let classification = classify_content(bytes);
+            let best = classification.best().unwrap();
+            assert_eq!(best.file_type, file_type, "{:?}", bytes);
+            assert_eq!(best.confidence, Confidence::Certain);
+        }
+        // Too short to hold the signature
+        assert_eq!(magic_file_type(b"\x89PN", true), None);
+        assert_eq!(magic_file_type(b"MZ is a prefix\n", false), None);
+        assert_eq!(
+            magic_file_type(b"MZ\x90\0", true),
+            Some(FileType::Executable)
+        );
+    }
+
+    #[test]
+    fn test_shebangs() {
+        let cases = [
+            ("#!/bin/bash\n", LanguageType::Shell),
+            ("#!/bin/sh -e\n", LanguageType::Shell),
+            ("#!/usr/bin/env python3\n", LanguageType::Python),
+            ("#!/usr/local/bin/python3.12 -u\n", LanguageType::Python),
+            (
+                "#!/usr/bin/env -S node --no-warnings\n",
+                LanguageType::JavaScript,
+            ),
+            (
+                "#!/usr/bin/env -S cargo +nightly -Zscript\n",
+                LanguageType::Rust,
+            ),
+            ("#! /usr/bin/env FOO=1 bash\n", LanguageType::Shell),
+            ("#!/usr/bin/awk -f\n", LanguageType::Awk),
+            ("#!/usr/bin/env ruby\n", LanguageType::Ruby),
+            ("#!/opt/bin/frobnicate\n", LanguageType::Code),
+        ];
+        for (text, language) in cases {
+            assert_eq!(shebang_language(text), Some(language), "{}", text);
+        }
+        assert_eq!(shebang_language("# comment\n"), None);
+        assert_eq!(shebang_language("#!\n"), None);
+    }
+
+    #[test]
+    fn test_modelines() {
+        let cases = [
+            ("# vim: set ft=python:\nx = 1\n", LanguageType::Python),
+            (
+                "x\ny\n// vim: ts=4 sw=4 filetype=javascript\n",
+                LanguageType::JavaScript,
+            ),
+            ("/* vi:syntax=c */\n", LanguageType::C),
+            (
+                "# -*- mode: sh; indent-tabs-mode: nil -*-\n",
+                LanguageType::Shell,
+            ),
+            ("#!/usr/bin/env foo\n;; -*- Rust -*-\n", LanguageType::Rust),
+            ("-- -*- mode: sql-mode -*-\n", LanguageType::Sql),
+            ("# vim: ft=yml\n", LanguageType::Yaml),
+        ];
+        for (text, language) in cases {
+            let found = modeline_language(text, text.as_bytes());
+            assert_eq!(found, Some(language), "{}", text);
+        }
+        assert_eq!(
+            modeline_language("let avim:ft=c\n", b"let avim:ft=c\n"),
+            None
+        );
+        assert_eq!(modeline_language("plain text\n", b"plain text\n"), None);
+    }
+
+    #[test]
+    fn test_binary_and_ranking() {
+        assert!(is_binary_content(b"ab\0cd"));
+        assert!(!is_binary_content("caf\u{e9}".as_bytes()));
+        assert!(!is_binary_content(b"\xff\xfeh\0i\0"));
+
+        let text = classify_content(b"just some notes\n");
+        assert!(!text.is_binary);
+        assert_eq!(text.file_type(), FileType::File);
+        assert_eq!(text.best().unwrap().confidence, Confidence::Low);
+
+        let unknown = classify_content(b"\x01\x02\0\x03");
+        assert!(unknown.is_binary);
+        assert_eq!(unknown.file_type(), FileType::File);
+
+        // The shebang outranks the extension, the magic number everything
+        let script = classify_file("build.txt", b"#!/bin/sh\nmake\n");
+        let sources: Vec<_> = script.candidates.iter().map(|c| c.source).collect();
+        assert_eq!(
+            sources,
+            vec![
+                ClassificationSource::Shebang,
+                ClassificationSource::Extension,
+                ClassificationSource::Heuristic,
+            ]
+        );
+        assert_eq!(script.language(), Some(LanguageType::Shell));
+
+        let cargo = classify_file("Cargo.lock", b"# This file is generated\n");
+        assert_eq!(cargo.file_type(), FileType::Lockfile);
+        assert_eq!(cargo.language(), Some(LanguageType::Toml));
+    }
+
+    #[test]
+    fn test_container_formats() {
+        let zip: &[u8] = b"PK\x03\x04\x14\0\x06\0";
+        let mp4: &[u8] = b"\0\0\0\x18ftypisom\0\0\x02\0";
+        let cases = [
+            ("report.docx", zip, FileType::Documentation),
+            ("book.epub", zip, FileType::Documentation),
+            ("app.jar", zip, FileType::Executable),
+            ("photos.zip", zip, FileType::Archive),
+            ("unknown.bin", zip, FileType::Archive),
+            // Not formats built on the container: the content wins
+            ("notes.txt", zip, FileType::Archive),
+            ("x.pdf", zip, FileType::Archive),
+            ("evil.bat", zip, FileType::Archive),
+            ("photo.png", mp4, FileType::Video),
+            ("song.m4a", mp4, FileType::Audio),
+            ("clip.mp4", mp4, FileType::Video),
+            ("IMG_0001.heic", mp4, FileType::Image),
+            (
+                "IMG_0001.heic",
+                b"\0\0\0\x18ftypheic\0\0\0\0",
+                FileType::Image,
+            ),
+            ("voice", b"\0\0\0\x20ftypM4A \0\0\0\0", FileType::Audio),
+            ("talk.opus", b"OggS\0\x02\0\0", FileType::Audio),
+            ("movie.ogv", b"OggS\0\x02\0\0", FileType::Video),
+            ("clip.avi", b"RIFF\0\0\0\0AVI LIST", FileType::Video),
+        ];
+        for (name, bytes, file_type) in cases {
+            let classification = classify_file(name, bytes);
+            assert_eq!(classification.file_type(), file_type, "{}", name);
+            let best = classification.best().unwrap();
+            assert_eq!(best.source, ClassificationSource::MagicBytes, "{}", name);
+            assert_eq!(best.confidence, Confidence::Certain);
+        }
+    }
+}
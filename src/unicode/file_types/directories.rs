@@ -0,0 +1,456 @@
+//! Special directory icons
+//!
+//! Well-known directories such as `.git`, `node_modules`, `src` or
+//! `Downloads` get their own glyph, as in eza and lsd. Names are matched
+//! ignoring ASCII case; anything else is a [`DirectoryKind::Generic`]
+//! directory with the same glyph as [`FileType::Directory`].
+
+use super::{FileType, UnicodeProvider, UnicodeTheme};
+use crate::unicode::glob::wildcard_match;
+use crate::unicode::ui::Control;
+
+/// Kind of a directory, derived from its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirectoryKind {
+    /// Any other directory
+    Generic,
+    /// Git repository data, `.git`
+    Git,
+    /// GitHub workflows and templates, `.github`
+    GitHub,
+    /// Visual Studio Code settings, `.vscode`
+    VsCode,
+    /// JetBrains IDE settings, `.idea`
+    Idea,
+    /// Configuration, e.g. `.config` or `etc`
+    Config,
+    /// Source code, e.g. `src`
+    Source,
+    /// Libraries, e.g. `lib`
+    Library,
+    /// Tests, e.g. `tests` or `__tests__`
+    Tests,
+    /// Benchmarks, e.g. `benches`
+    Benchmarks,
+    /// Examples and demos
+    Examples,
+    /// Documentation, e.g. `docs`
+    Docs,
+    /// Scripts and tools, e.g. `bin` or `scripts`
+    Scripts,
+    /// Build output, e.g. `target`, `build` or `dist`
+    Build,
+    /// npm packages, `node_modules`
+    NodeModules,
+    /// Vendored dependencies and virtual environments, e.g. `vendor` or `.venv`
+    Vendor,
+    /// Caches, e.g. `.cache` or `__pycache__`
+    Cache,
+    /// Static assets, e.g. `assets` or `public`
+    Assets,
+    /// Logs
+    Logs,
+    /// Temporary files, e.g. `tmp`
+    Temporary,
+    /// Keys and credentials, e.g. `.ssh` or `.gnupg`
+    Keys,
+    /// Desktop
+    Desktop,
+    /// Documents
+    Documents,
+    /// Downloads
+    Downloads,
+    /// Music
+    Music,
+    /// Pictures
+    Pictures,
+    /// Videos
+    Videos,
+    /// Trash, e.g. `.Trash`
+    Trash,
+}
+
+impl UnicodeProvider for DirectoryKind {
+    fn get_char(&self, theme: UnicodeTheme) -> char {
+        match (self, theme) {
+            (DirectoryKind::Generic, theme) => FileType::Directory.get_char(theme),
+
+            (DirectoryKind::Git, UnicodeTheme::Minimal) => 'G',
+            (DirectoryKind::Git, UnicodeTheme::Basic) => '±',
+            (DirectoryKind::Git, UnicodeTheme::Rich) => '🔀',
+            (DirectoryKind::Git, UnicodeTheme::Fancy) => '🌳',
+
+            (DirectoryKind::GitHub, UnicodeTheme::Minimal) => 'H',
+            (DirectoryKind::GitHub, UnicodeTheme::Basic) => '±',
+            (DirectoryKind::GitHub, UnicodeTheme::Rich) => '🐙',
+            (DirectoryKind::GitHub, UnicodeTheme::Fancy) => '🐙',
+
+            (DirectoryKind::VsCode, UnicodeTheme::Minimal) => 'V',
+            (DirectoryKind::VsCode, UnicodeTheme::Basic) => '#',
+            (DirectoryKind::VsCode, UnicodeTheme::Rich) => '🔷',
+            (DirectoryKind::VsCode, UnicodeTheme::Fancy) => '🆚',
+
+            (DirectoryKind::Idea, UnicodeTheme::Minimal) => 'I',
+            (DirectoryKind::Idea, UnicodeTheme::Basic) => '#',
+            (DirectoryKind::Idea, UnicodeTheme::Rich) => '💡',
+            (DirectoryKind::Idea, UnicodeTheme::Fancy) => '💡',
+
+            (DirectoryKind::Config, UnicodeTheme::Minimal) => 'C',
+            (DirectoryKind::Config, UnicodeTheme::Basic) => '#',
+            (DirectoryKind::Config, UnicodeTheme::Rich) => '⚙',
+            (DirectoryKind::Config, UnicodeTheme::Fancy) => '🔧',
+
+            (DirectoryKind::Source, UnicodeTheme::Minimal) => 'S',
+            (DirectoryKind::Source, UnicodeTheme::Basic) => '<',
+            (DirectoryKind::Source, UnicodeTheme::Rich) => '💻',
+            (DirectoryKind::Source, UnicodeTheme::Fancy) => '🧬',
+
+            (DirectoryKind::Library, UnicodeTheme::Minimal) => 'L',
+            (DirectoryKind::Library, UnicodeTheme::Basic) => '<',
+            (DirectoryKind::Library, UnicodeTheme::Rich) => '📚',
+            (DirectoryKind::Library, UnicodeTheme::Fancy) => '🏛',
+
+            (DirectoryKind::Tests, UnicodeTheme::Minimal) => 'T',
+            (DirectoryKind::Tests, UnicodeTheme::Basic) => '?',
+            (DirectoryKind::Tests, UnicodeTheme::Rich) => '🧪',
+            (DirectoryKind::Tests, UnicodeTheme::Fancy) => '🔬',
+
+            (DirectoryKind::Benchmarks, UnicodeTheme::Minimal) => 'K',
+            (DirectoryKind::Benchmarks, UnicodeTheme::Basic) => '?',
+            (DirectoryKind::Benchmarks, UnicodeTheme::Rich) => '⏱',
+            (DirectoryKind::Benchmarks, UnicodeTheme::Fancy) => '🏎',
+
+            (DirectoryKind::Examples, UnicodeTheme::Minimal) => 'E',
+            (DirectoryKind::Examples, UnicodeTheme::Basic) => '?',
+            (DirectoryKind::Examples, UnicodeTheme::Rich) => '🧭',
+            (DirectoryKind::Examples, UnicodeTheme::Fancy) => '🎓',
+
+            (DirectoryKind::Docs, UnicodeTheme::Minimal) => 'D',
+            (DirectoryKind::Docs, UnicodeTheme::Basic) => '?',
+            (DirectoryKind::Docs, UnicodeTheme::Rich) => '📖',
+            (DirectoryKind::Docs, UnicodeTheme::Fancy) => '📚',
+
+            (DirectoryKind::Scripts, UnicodeTheme::Minimal) => 'X',
+            (DirectoryKind::Scripts, UnicodeTheme::Basic) => '*',
+            (DirectoryKind::Scripts, UnicodeTheme::Rich) => '⚡',
+            (DirectoryKind::Scripts, UnicodeTheme::Fancy) => '🔧',
+
+            (DirectoryKind::Build, UnicodeTheme::Minimal) => 'B',
+            (DirectoryKind::Build, UnicodeTheme::Basic) => '^',
+            (DirectoryKind::Build, UnicodeTheme::Rich) => '🏗',
+            (DirectoryKind::Build, UnicodeTheme::Fancy) => '🔨',
+
+            (DirectoryKind::NodeModules, UnicodeTheme::Minimal) => 'N',
+            (DirectoryKind::NodeModules, UnicodeTheme::Basic) => '$',
+            (DirectoryKind::NodeModules, UnicodeTheme::Rich) => '📦',
+            (DirectoryKind::NodeModules, UnicodeTheme::Fancy) => '🧩',
+
+            (DirectoryKind::Vendor, UnicodeTheme::Minimal) => 'P',
+            (DirectoryKind::Vendor, UnicodeTheme::Basic) => '$',
+            (DirectoryKind::Vendor, UnicodeTheme::Rich) => '📦',
+            (DirectoryKind::Vendor, UnicodeTheme::Fancy) => '🗃',
+
+            (DirectoryKind::Cache, UnicodeTheme::Minimal) => 'Z',
+            (DirectoryKind::Cache, UnicodeTheme::Basic) => '~',
+            (DirectoryKind::Cache, UnicodeTheme::Rich) => '🗄',
+            (DirectoryKind::Cache, UnicodeTheme::Fancy) => '♻',
+
+            (DirectoryKind::Assets, UnicodeTheme::Minimal) => 'A',
+            (DirectoryKind::Assets, UnicodeTheme::Basic) => '%',
+            (DirectoryKind::Assets, UnicodeTheme::Rich) => '🎨',
+            (DirectoryKind::Assets, UnicodeTheme::Fancy) => '🖌',
+
+            (DirectoryKind::Logs, UnicodeTheme::Minimal) => 'L',
+            (DirectoryKind::Logs, UnicodeTheme::Basic) => '|',
+            (DirectoryKind::Logs, UnicodeTheme::Rich) => '📜',
+            (DirectoryKind::Logs, UnicodeTheme::Fancy) => '📋',
+
+            (DirectoryKind::Temporary, UnicodeTheme::Minimal) => 'T',
+            (DirectoryKind::Temporary, UnicodeTheme::Basic) => '~',
+            (DirectoryKind::Temporary, UnicodeTheme::Rich) => '⏳',
+            (DirectoryKind::Temporary, UnicodeTheme::Fancy) => '⌛',
+
+            (DirectoryKind::Keys, UnicodeTheme::Minimal) => 'K',
+            (DirectoryKind::Keys, UnicodeTheme::Basic) => '!',
+            (DirectoryKind::Keys, UnicodeTheme::Rich) => '🔑',
+            (DirectoryKind::Keys, UnicodeTheme::Fancy) => '🔐',
+
+            (DirectoryKind::Desktop, UnicodeTheme::Minimal) => 'D',
+            (DirectoryKind::Desktop, UnicodeTheme::Basic) => '@',
+            (DirectoryKind::Desktop, UnicodeTheme::Rich) => '🖥',
+            (DirectoryKind::Desktop, UnicodeTheme::Fancy) => '💻',
+
+            (DirectoryKind::Documents, UnicodeTheme::Minimal) => 'D',
+            (DirectoryKind::Documents, UnicodeTheme::Basic) => '@',
+            (DirectoryKind::Documents, UnicodeTheme::Rich) => '🗎',
+            (DirectoryKind::Documents, UnicodeTheme::Fancy) => '🗃',
+
+            (DirectoryKind::Downloads, UnicodeTheme::Minimal) => 'v',
+            (DirectoryKind::Downloads, UnicodeTheme::Basic) => '↓',
+            (DirectoryKind::Downloads, UnicodeTheme::Rich) => '📥',
+            (DirectoryKind::Downloads, UnicodeTheme::Fancy) => '⏬',
+
+            (DirectoryKind::Music, UnicodeTheme::Minimal) => 'A',
+            (DirectoryKind::Music, UnicodeTheme::Basic) => '~',
+            (DirectoryKind::Music, UnicodeTheme::Rich) => '🎵',
+            (DirectoryKind::Music, UnicodeTheme::Fancy) => '🎶',
+
+            (DirectoryKind::Pictures, UnicodeTheme::Minimal) => 'I',
+            (DirectoryKind::Pictures, UnicodeTheme::Basic) => '%',
+            (DirectoryKind::Pictures, UnicodeTheme::Rich) => '🖼',
+            (DirectoryKind::Pictures, UnicodeTheme::Fancy) => '📷',
+
+            (DirectoryKind::Videos, UnicodeTheme::Minimal) => 'V',
+            (DirectoryKind::Videos, UnicodeTheme::Basic) => '&',
+            (DirectoryKind::Videos, UnicodeTheme::Rich) => '🎬',
+            (DirectoryKind::Videos, UnicodeTheme::Fancy) => '📹',
+
+            (DirectoryKind::Trash, UnicodeTheme::Minimal) => 'R',
+            (DirectoryKind::Trash, UnicodeTheme::Basic) => 'x',
+            (DirectoryKind::Trash, UnicodeTheme::Rich) => '🗑',
+            (DirectoryKind::Trash, UnicodeTheme::Fancy) => '♻',
+        }
+    }
+}
+
+impl DirectoryKind {
+    /// Icon of the directory when collapsed in a tree view
+    pub fn closed(self) -> DirectoryIcon {
+        DirectoryIcon {
+            kind: self,
+            open: false,
+        }
+    }
+
+    /// Icon of the directory when expanded in a tree view
+    pub fn open(self) -> DirectoryIcon {
+        DirectoryIcon {
+            kind: self,
+            open: true,
+        }
+    }
+}
+
+/// Directory icon in a tree view, open or closed
+///
+/// Plain directories switch to an open folder when expanded in the Rich and
+/// Fancy themes. Special directories keep their own glyph, which has no open
+/// form, and the Minimal and Basic themes use the same glyph in both states,
+/// so [`tree_label`](DirectoryIcon::tree_label) puts the expand/collapse
+/// marker in front, which tells the states apart in every theme.
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+/// use unicode_rs::UnicodeProvider;
+/// use unicode_rs::UnicodeTheme;
+///
+/// assert_eq!(DirectoryKind::Generic.closed().get_char(UnicodeTheme::Rich), '📁');
+/// assert_eq!(DirectoryKind::Generic.open().get_char(UnicodeTheme::Rich), '📂');
+/// assert_eq!(DirectoryKind::Git.open().get_char(UnicodeTheme::Rich), '🔀');
+/// assert_eq!(DirectoryKind::Git.open().tree_label(UnicodeTheme::Minimal), "vG");
+/// assert_eq!(DirectoryKind::Git.closed().tree_label(UnicodeTheme::Minimal), ">G");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectoryIcon {
+    /// Kind of directory
+    pub kind: DirectoryKind,
+    /// The directory is expanded
+    pub open: bool,
+}
+
+impl DirectoryIcon {
+    /// Expand/collapse marker for the directory's state
+    pub fn expander(&self) -> Control {
+        if self.open {
+            Control::ExpandExpanded
+        } else {
+            Control::ExpandCollapsed
+        }
+    }
+
+    /// Expand/collapse marker followed by the directory glyph
+    pub fn tree_label(&self, theme: UnicodeTheme) -> String {
+        format!(
+            "{}{}",
+            self.expander().get_char(theme),
+            self.get_char(theme)
+        )
+    }
+}
+
+impl UnicodeProvider for DirectoryIcon {
+    fn get_char(&self, theme: UnicodeTheme) -> char {
+        match (self.kind, self.open, theme) {
+            (DirectoryKind::Generic, true, UnicodeTheme::Rich | UnicodeTheme::Fancy) => '📂',
+            (kind, _, theme) => kind.get_char(theme),
+        }
+    }
+}
+
+/// Classify a directory by its name
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// assert_eq!(classify_directory(".git"), DirectoryKind::Git);
+/// assert_eq!(classify_directory("node_modules"), DirectoryKind::NodeModules);
+/// assert_eq!(classify_directory("Downloads"), DirectoryKind::Downloads);
+/// assert_eq!(classify_directory("cmake-build-debug"), DirectoryKind::Build);
+/// assert_eq!(classify_directory("utils"), DirectoryKind::Generic);
+/// ```
+pub fn classify_directory(name: &str) -> DirectoryKind {
+    use DirectoryKind::*;
+
+    let name = name.to_ascii_lowercase();
+    match name.as_str() {
+        ".git" => Git,
+        ".github" => GitHub,
+        ".vscode" => VsCode,
+        ".idea" | ".fleet" => Idea,
+        ".config" | "config" | "configs" | "conf" | "etc" | "settings" => Config,
+        "src" | "source" | "sources" | "app" | "pkg" | "internal" | "cmd" => Source,
+        "lib" | "libs" | "include" | "crates" | "packages" => Library,
+        "test" | "tests" | "__tests__" | "spec" | "specs" | "testing" | "testdata" | "fixtures" => {
+            Tests
+        }
+        "bench" | "benches" | "benchmark" | "benchmarks" => Benchmarks,
+        "example" | "examples" | "demo" | "demos" | "sample" | "samples" => Examples,
+        "doc" | "docs" | "documentation" | "man" | "wiki" => Docs,
+        "bin" | "sbin" | "script" | "scripts" | "tools" | "xtask" => Scripts,
+        "target" | "build" | "builds" | "dist" | "out" | "_build" | "obj" => Build,
+        "node_modules" | "bower_components" | "jspm_packages" => NodeModules,
+        "vendor" | "vendors" | "third_party" | "third-party" | "thirdparty" | "external"
+        | "deps" | "pods" | ".venv" | "venv" | "site-packages" => Vendor,
+        ".cache" | "cache" | "__pycache__" | ".pytest_cache" | ".mypy_cache" | ".ruff_cache"
+        | ".gradle" | ".next" | ".nuxt" | ".parcel-cache" | ".turbo" => Cache,
+        "assets" | "static" | "public" | "resources" | "res" | "images" | "img" | "icons"
+        | "fonts" | "media" => Assets,
+        "log" | "logs" => Logs,
+        "tmp" | "temp" | ".tmp" => Temporary,
+        ".ssh" | ".gnupg" | "keys" | "certs" | "secrets" => Keys,
+        "desktop" => Desktop,
+        "documents" => Documents,
+        "downloads" => Downloads,
+        "music" => Music,
+        "pictures" | "photos" => Pictures,
+        "videos" | "movies" => Videos,
+        ".trash" | "$recycle.bin" => Trash,
+        _ => DIRECTORY_PATTERNS
+            .iter()
+            .find(|(pattern, _)| wildcard_match(pattern, &name))
+            .map_or(Generic, |&(_, kind)| kind),
+    }
+}
+
+/// Wildcard rules, tried in order after the exact names
+const DIRECTORY_PATTERNS: &[(&str, DirectoryKind)] = &[
+    ("cmake-build-*", DirectoryKind::Build),
+    ("bazel-*", DirectoryKind::Build),
+    (".trash-*", DirectoryKind::Trash),
+    (".venv*", DirectoryKind::Vendor),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_directory() {
+        let cases = [
+            (".git", DirectoryKind::Git),
+            (".GitHub", DirectoryKind::GitHub),
+            ("src", DirectoryKind::Source),
+            ("tests", DirectoryKind::Tests),
+            ("__tests__", DirectoryKind::Tests),
+            ("target", DirectoryKind::Build),
+            ("bazel-out", DirectoryKind::Build),
+            ("__pycache__", DirectoryKind::Cache),
+            (".Trash-1000", DirectoryKind::Trash),
+            ("Pictures", DirectoryKind::Pictures),
+            ("src2", DirectoryKind::Generic),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(classify_directory(name), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_open_and_closed_glyphs() {
+        for theme in [
+            UnicodeTheme::Minimal,
+            UnicodeTheme::Basic,
+            UnicodeTheme::Rich,
+            UnicodeTheme::Fancy,
+        ] {
+            let closed = DirectoryKind::Generic.closed().get_char(theme);
+            assert_eq!(closed, FileType::Directory.get_char(theme));
+            assert_eq!(
+                DirectoryKind::Tests.open().get_char(theme),
+                DirectoryKind::Tests.get_char(theme)
+            );
+        }
+        assert_eq!(
+            DirectoryKind::Generic.open().get_char(UnicodeTheme::Fancy),
+            '📂'
+        );
+        assert_eq!(
+            DirectoryKind::Generic
+                .open()
+                .get_char(UnicodeTheme::Minimal),
+            'D'
+        );
+    }
+
+    #[test]
+    fn test_tree_labels() {
+        use DirectoryKind::*;
+
+        let kinds = [
+            Generic,
+            Git,
+            GitHub,
+            VsCode,
+            Idea,
+            Config,
+            Source,
+            Library,
+            Tests,
+            Benchmarks,
+            Examples,
+            Docs,
+            Scripts,
+            Build,
+            NodeModules,
+            Vendor,
+            Cache,
+            Assets,
+            Logs,
+            Temporary,
+            Keys,
+            Desktop,
+            Documents,
+            Downloads,
+            Music,
+            Pictures,
+            Videos,
+            Trash,
+        ];
+        for kind in kinds {
+            for theme in [
+                UnicodeTheme::Minimal,
+                UnicodeTheme::Basic,
+                UnicodeTheme::Rich,
+                UnicodeTheme::Fancy,
+            ] {
+                let open = kind.open().tree_label(theme);
+                let closed = kind.closed().tree_label(theme);
+                assert_ne!(open, closed, "{:?} {:?}", kind, theme);
+                assert!(closed.ends_with(kind.get_char(theme)));
+            }
+        }
+        assert_eq!(Generic.open().tree_label(UnicodeTheme::Rich), "▼📂");
+        assert_eq!(Generic.closed().tree_label(UnicodeTheme::Basic), "▶/");
+    }
+}
@@ -0,0 +1,112 @@
+//! File extension tables
+//!
+//! Extensions are matched in lowercase and without the leading dot. A file
+//! name is looked up by its longest known extension first, so compound
+//! extensions such as `tar.gz` or `rs.in` take precedence over `gz` or `in`.
+
+use super::{FileType, LanguageType};
+
+/// Candidate extensions of a file name, longest first
+///
+/// `"backup.2024.tar.gz"` yields `"2024.tar.gz"`, `"tar.gz"` and `"gz"`. A
+/// leading dot does not start an extension, so `".bashrc"` yields nothing.
+pub(super) fn extensions(filename: &str) -> impl Iterator<Item = &str> {
+    filename
+        .char_indices()
+        .skip(1)
+        .filter(|&(_, c)| c == '.')
+        .map(move |(i, _)| &filename[i + 1..])
+        .filter(|ext| !ext.is_empty())
+}
+
+/// Look up the file type of a file name by its longest known extension
+pub(super) fn file_type_from_filename(filename: &str) -> Option<FileType> {
+    extensions(&filename.to_ascii_lowercase()).find_map(file_type_from_extension)
+}
+
+/// Look up the language of a file name by its longest known extension
+pub(super) fn language_from_filename(filename: &str) -> Option<LanguageType> {
+    extensions(&filename.to_ascii_lowercase()).find_map(language_from_extension)
+}
+
+/// Language of a single extension
+pub(super) fn language_from_extension(extension: &str) -> Option<LanguageType> {
+    super::lookup(super::languages::EXTENSIONS, &extension.to_lowercase())
+}
+
+/// File type of a single lowercase extension
+pub(super) fn file_type_from_extension(extension: &str) -> Option<FileType> {
+    let file_type = match extension {
+        // Raster, vector and camera raw images
+        "png" | "apng" | "jpg" | "jpeg" | "jpe" | "jfif" | "pjpeg" | "pjp" | "gif" | "bmp"
+        | "dib" | "tif" | "tiff" | "webp" | "avif" | "avifs" | "heic" | "heif" | "jxl" | "jp2"
+        | "j2k" | "jpf" | "jpx" | "jpm" | "ico" | "icns" | "cur" | "ani" | "svg" | "svgz"
+        | "eps" | "ai" | "psd" | "psb" | "xcf" | "kra" | "ora" | "sketch" | "fig" | "xd"
+        | "cdr" | "afdesign" | "afphoto" | "tga" | "pcx" | "ppm" | "pgm" | "pbm" | "pnm"
+        | "pam" | "pfm" | "hdr" | "exr" | "dds" | "ktx" | "ktx2" | "qoi" | "wbmp" | "xbm"
+        | "xpm" | "emf" | "wmf" | "flif" | "bpg" | "jng" | "mng" | "raw" | "cr2" | "cr3"
+        | "crw" | "nef" | "nrw" | "arw" | "srf" | "sr2" | "orf" | "rw2" | "raf" | "dng" | "pef"
+        | "x3f" | "kdc" | "dcr" | "mrw" | "erf" | "3fr" | "mos" | "iiq" | "srw" => FileType::Image,
+
+        // Video containers and raw streams; `ts` is left to TypeScript
+        "mp4" | "m4v" | "mkv" | "mk3d" | "webm" | "mov" | "qt" | "avi" | "wmv" | "asf" | "flv"
+        | "f4v" | "swf" | "mpg" | "mpeg" | "mpe" | "mpv" | "m1v" | "m2v" | "m2ts" | "m2t"
+        | "vob" | "ogv" | "ogm" | "3gp" | "3g2" | "rm" | "rmvb" | "divx" | "xvid" | "mxf"
+        | "dv" | "yuv" | "y4m" | "h264" | "264" | "h265" | "265" | "hevc" | "av1" | "ivf"
+        | "nut" | "roq" | "bik" | "gifv" | "drc" => FileType::Video,
+
+        // Audio; `mod` is left to Go modules
+        "mp3" | "mp2" | "wav" | "wave" | "flac" | "aac" | "m4a" | "m4b" | "m4p" | "m4r" | "ogg"
+        | "oga" | "opus" | "spx" | "wma" | "aiff" | "aif" | "aifc" | "alac" | "ape" | "mka"
+        | "mid" | "midi" | "kar" | "rmi" | "amr" | "awb" | "au" | "snd" | "ra" | "ram" | "caf"
+        | "dsf" | "dff" | "wv" | "tta" | "ac3" | "eac3" | "dts" | "mpc" | "voc" | "s3m" | "xm"
+        | "it" | "sf2" | "gsm" | "weba" | "aup3" | "mmf" => FileType::Audio,
+
+        // Archives, compressed files, packages and disk images
+        "zip" | "zipx" | "rar" | "7z" | "tar" | "gz" | "gzip" | "tgz" | "bz2" | "bzip2" | "tbz"
+        | "tbz2" | "tb2" | "xz" | "txz" | "lz" | "tlz" | "lzma" | "lz4" | "zst" | "zstd"
+        | "tzst" | "z" | "taz" | "br" | "sz" | "lzo" | "lzh" | "lha" | "arj" | "ace" | "cab"
+        | "cpio" | "ar" | "shar" | "sit" | "sitx" | "cbz" | "cbr" | "cb7" | "cbt" | "pea"
+        | "wim" | "swm" | "iso" | "img" | "dmg" | "vhd" | "vhdx" | "vmdk" | "qcow2"
+        | "squashfs" | "pkg" | "deb" | "udeb" | "rpm" | "apk" | "aab" | "ipa" | "xapk" | "appx"
+        | "appxbundle" | "msix" | "snap" | "flatpak" | "war" | "ear" | "aar" | "whl" | "egg"
+        | "gem" | "nupkg" | "crate" | "crx" | "xpi" | "vsix" | "tar.gz" | "tar.bz2" | "tar.xz"
+        | "tar.zst" | "tar.lz" | "tar.lz4" | "tar.lzma" | "tar.lzo" | "tar.br" | "tar.z"
+        | "tar.sz" => FileType::Archive,
+
+        // Database files and columnar data stores
+        "db" | "db3" | "sqlite" | "sqlite3" | "s3db" | "sl3" | "sdb" | "mdb" | "accdb"
+        | "accde" | "frm" | "ibd" | "myd" | "myi" | "dbf" | "mdf" | "ndf" | "ldf" | "fdb"
+        | "gdb" | "odb" | "kdb" | "kdbx" | "realm" | "duckdb" | "rdb" | "lmdb" | "nsf"
+        | "parquet" | "orc" | "avro" | "arrow" | "feather" | "h5" | "hdf5" | "mdbx" | "ldb" => {
+            FileType::Database
+        }
+
+        // Documents, e-books and office files
+        "txt" | "text" | "rst" | "adoc" | "asciidoc" | "org" | "pod" | "rdoc" | "textile"
+        | "man" | "info" | "texi" | "texinfo" | "pdf" | "ps" | "xps" | "oxps" | "djvu" | "djv"
+        | "epub" | "mobi" | "azw" | "azw3" | "fb2" | "chm" | "doc" | "docx" | "docm" | "dotx"
+        | "odt" | "ott" | "rtf" | "pages" | "wpd" | "wps" | "xls" | "xlsx" | "xlsm" | "xlsb"
+        | "ods" | "ots" | "numbers" | "ppt" | "pptx" | "pps" | "ppsx" | "odp" | "otp" | "one"
+        | "vsd" | "vsdx" => FileType::Documentation,
+
+        // Configuration formats without a dedicated language
+        "conf" | "cfg" | "cnf" | "ini" | "config" | "properties" | "prefs" | "plist" | "reg"
+        | "desktop" | "service" | "socket" | "timer" | "mount" | "rules" | "env" => {
+            FileType::Config
+        }
+
+        // Executables and installers
+        "exe" | "msi" | "com" | "scr" | "appimage" | "run" | "elf" | "bat" | "cmd" | "jar" => {
+            FileType::Executable
+        }
+
+        "log" | "logs" => FileType::Log,
+        "tmp" | "temp" | "swp" | "swo" | "part" | "partial" | "crdownload" | "download" => {
+            FileType::Temporary
+        }
+        "bak" | "backup" | "bkp" | "old" | "orig" | "sav" => FileType::Backup,
+        _ => return None,
+    };
+    Some(file_type)
+}
@@ -0,0 +1,264 @@
+//! Well-known file names
+//!
+//! Rules for names that say more than their extension: build manifests,
+//! lockfiles, tool configuration and project documentation. Exact names are
+//! looked up first, then wildcard patterns in table order. Matching ignores
+//! ASCII case, so `Makefile`, `makefile` and `MAKEFILE` are the same.
+
+use super::{FileType, LanguageType};
+use crate::unicode::glob::wildcard_match;
+
+/// File type and optional language override of a well-known name
+pub(super) type FilenameRule = (FileType, Option<LanguageType>);
+
+/// Look up a file name in the exact and wildcard rules
+pub(super) fn filename_rule(filename: &str) -> Option<FilenameRule> {
+    let filename = filename.to_ascii_lowercase();
+    exact_rule(&filename).or_else(|| {
+        PATTERN_RULES
+            .iter()
+            .find(|(pattern, _, _)| wildcard_match(pattern, &filename))
+            .map(|&(_, file_type, language)| (file_type, language))
+    })
+}
+
+fn exact_rule(filename: &str) -> Option<FilenameRule> {
+    use FileType::*;
+    use LanguageType as L;
+
+    let rule = match filename {
+        // Build manifests
+        "makefile"
+        | "gnumakefile"
+        | "bsdmakefile"
+        | "makefile.am"
+        | "makefile.in"
+        | "cmakelists.txt"
+        | "meson.build"
+        | "meson_options.txt"
+        | "justfile"
+        | ".justfile"
+        | "rakefile"
+        | "gemfile"
+        | "build.gradle"
+        | "build.gradle.kts"
+        | "settings.gradle"
+        | "settings.gradle.kts"
+        | "build.sbt"
+        | "build.zig"
+        | "package.swift"
+        | "mix.exs"
+        | "project.clj"
+        | "deps.edn"
+        | "cabal.project"
+        | "pubspec.yaml"
+        | "podfile"
+        | "vcpkg.json"
+        | "conanfile.txt"
+        | "flake.nix"
+        | "default.nix"
+        | "shell.nix"
+        | "build.bazel"
+        | "workspace"
+        | "module.bazel"
+        | "sconstruct"
+        | "snakefile"
+        | "requirements.txt" => (BuildManifest, None),
+        "cargo.toml" | "pyproject.toml" | "pipfile" => (BuildManifest, Some(L::Toml)),
+        "package.json" | "composer.json" | "deno.json" | "deno.jsonc" | "bower.json" => {
+            (BuildManifest, Some(L::Json))
+        }
+        "pom.xml" | "build.xml" | "ivy.xml" => (BuildManifest, Some(L::Xml)),
+        "setup.py" | "noxfile.py" | "fabfile.py" => (BuildManifest, Some(L::Python)),
+        "go.mod" | "go.work" => (BuildManifest, Some(L::Go)),
+        "dockerfile" | "containerfile" => (BuildManifest, Some(L::Docker)),
+
+        // Lockfiles
+        "cargo.lock" | "poetry.lock" | "uv.lock" | "pdm.lock" => (Lockfile, Some(L::Toml)),
+        "package-lock.json"
+        | "npm-shrinkwrap.json"
+        | "composer.lock"
+        | "pipfile.lock"
+        | "flake.lock"
+        | "deno.lock"
+        | "packages.lock.json"
+        | "bun.lock" => (Lockfile, Some(L::Json)),
+        "pnpm-lock.yaml" | "pubspec.lock" | "podfile.lock" => (Lockfile, Some(L::Yaml)),
+        "go.sum" | "go.work.sum" => (Lockfile, Some(L::Go)),
+        "yarn.lock" | "bun.lockb" | "gemfile.lock" | "mix.lock" | "gradle.lockfile"
+        | "cartfile.resolved" | "package.resolved" | "mod.lock" => (Lockfile, None),
+
+        // Tool and environment configuration
+        ".gitignore"
+        | ".gitattributes"
+        | ".gitmodules"
+        | ".gitconfig"
+        | ".mailmap"
+        | ".git-blame-ignore-revs" => (Config, Some(L::Git)),
+        ".dockerignore" => (Config, Some(L::Docker)),
+        "docker-compose.yml" | "docker-compose.yaml" | "compose.yml" | "compose.yaml" => {
+            (Config, Some(L::Docker))
+        }
+        ".travis.yml"
+        | ".gitlab-ci.yml"
+        | "azure-pipelines.yml"
+        | "bitbucket-pipelines.yml"
+        | "appveyor.yml"
+        | ".pre-commit-config.yaml"
+        | ".clang-format"
+        | ".clang-tidy"
+        | ".yamllint"
+        | "mkdocs.yml" => (Config, Some(L::Yaml)),
+        "rust-toolchain"
+        | "rust-toolchain.toml"
+        | "rustfmt.toml"
+        | ".rustfmt.toml"
+        | "clippy.toml"
+        | ".clippy.toml"
+        | "deny.toml"
+        | "netlify.toml" => (Config, Some(L::Toml)),
+        "tsconfig.json" | "jsconfig.json" | ".babelrc" | ".prettierrc" | ".eslintrc"
+        | ".stylelintrc" | ".swcrc" | "renovate.json" | "vercel.json" | "turbo.json" => {
+            (Config, Some(L::Json))
+        }
+        ".bashrc" | ".bash_profile" | ".bash_logout" | ".profile" | ".zshrc" | ".zprofile"
+        | ".zshenv" | ".envrc" => (Config, Some(L::Shell)),
+        ".editorconfig" | ".env" | ".npmrc" | ".yarnrc" | ".nvmrc" | ".node-version"
+        | ".python-version" | ".ruby-version" | ".tool-versions" | ".htaccess" | ".inputrc"
+        | ".vimrc" | ".tmux.conf" | ".wgetrc" | ".curlrc" | "jenkinsfile" | "vagrantfile"
+        | "procfile" | "brewfile" | "config" | "configuration" | "settings" | "preferences" => {
+            (Config, None)
+        }
+
+        // Project documentation
+        "readme" | "license" | "licence" | "copying" | "copyright" | "notice" | "authors"
+        | "contributors" | "maintainers" | "codeowners" | "changelog" | "changes" | "history"
+        | "news" | "contributing" | "install" | "todo" | "doc" | "docs" => (Documentation, None),
+        _ => return None,
+    };
+    Some(rule)
+}
+
+/// Wildcard rules, tried in order after the exact names
+const PATTERN_RULES: &[(&str, FileType, Option<LanguageType>)] = &[
+    // Prefixes
+    (
+        "dockerfile.*",
+        FileType::BuildManifest,
+        Some(LanguageType::Docker),
+    ),
+    (
+        "containerfile.*",
+        FileType::BuildManifest,
+        Some(LanguageType::Docker),
+    ),
+    ("requirements*.txt", FileType::BuildManifest, None),
+    (
+        "docker-compose.*",
+        FileType::Config,
+        Some(LanguageType::Docker),
+    ),
+    (
+        "compose.*.yml",
+        FileType::Config,
+        Some(LanguageType::Docker),
+    ),
+    (
+        "compose.*.yaml",
+        FileType::Config,
+        Some(LanguageType::Docker),
+    ),
+    (".env.*", FileType::Config, None),
+    (
+        "tsconfig.*.json",
+        FileType::Config,
+        Some(LanguageType::Json),
+    ),
+    (".eslintrc.*", FileType::Config, None),
+    (".prettierrc.*", FileType::Config, None),
+    (".stylelintrc.*", FileType::Config, None),
+    ("jenkinsfile.*", FileType::Config, None),
+    ("readme.*", FileType::Documentation, None),
+    ("license.*", FileType::Documentation, None),
+    ("license-*", FileType::Documentation, None),
+    ("licence.*", FileType::Documentation, None),
+    ("copying.*", FileType::Documentation, None),
+    ("notice.*", FileType::Documentation, None),
+    ("authors.*", FileType::Documentation, None),
+    ("changelog.*", FileType::Documentation, None),
+    ("changes.*", FileType::Documentation, None),
+    ("history.*", FileType::Documentation, None),
+    ("contributing.*", FileType::Documentation, None),
+    ("code_of_conduct.*", FileType::Documentation, None),
+    ("security.md", FileType::Documentation, None),
+    // Globs
+    (
+        "*.dockerfile",
+        FileType::BuildManifest,
+        Some(LanguageType::Docker),
+    ),
+    ("*.gemspec", FileType::BuildManifest, None),
+    ("*.csproj", FileType::BuildManifest, Some(LanguageType::Xml)),
+    ("*.fsproj", FileType::BuildManifest, Some(LanguageType::Xml)),
+    ("*.vbproj", FileType::BuildManifest, Some(LanguageType::Xml)),
+    (
+        "*.vcxproj",
+        FileType::BuildManifest,
+        Some(LanguageType::Xml),
+    ),
+    ("*.sln", FileType::BuildManifest, None),
+    ("*.cabal", FileType::BuildManifest, None),
+    ("*.nimble", FileType::BuildManifest, None),
+    ("*.podspec", FileType::BuildManifest, None),
+    ("*.mk", FileType::BuildManifest, None),
+    ("*.cmake", FileType::BuildManifest, None),
+    ("*-lock.json", FileType::Lockfile, Some(LanguageType::Json)),
+    ("*.lock.json", FileType::Lockfile, Some(LanguageType::Json)),
+    ("*-lock.yaml", FileType::Lockfile, Some(LanguageType::Yaml)),
+    ("*.lock", FileType::Lockfile, None),
+    ("*.lockfile", FileType::Lockfile, None),
+    (
+        "*.config.js",
+        FileType::Config,
+        Some(LanguageType::JavaScript),
+    ),
+    (
+        "*.config.cjs",
+        FileType::Config,
+        Some(LanguageType::JavaScript),
+    ),
+    (
+        "*.config.mjs",
+        FileType::Config,
+        Some(LanguageType::JavaScript),
+    ),
+    (
+        "*.config.ts",
+        FileType::Config,
+        Some(LanguageType::TypeScript),
+    ),
+    (
+        "*.config.mts",
+        FileType::Config,
+        Some(LanguageType::TypeScript),
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_names_win_over_patterns() {
+        // `*.lock` would otherwise give no language
+        assert_eq!(
+            filename_rule("Cargo.lock"),
+            Some((FileType::Lockfile, Some(LanguageType::Toml)))
+        );
+        assert_eq!(
+            filename_rule("Gemfile.lock"),
+            Some((FileType::Lockfile, None))
+        );
+        assert_eq!(filename_rule("main.rs"), None);
+    }
+}
@@ -0,0 +1,3191 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/languages.py
+//
+// Linguist version: v9.2.0.
+
+use super::LanguageInfo;
+
+/// Programming language file types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LanguageType {
+    /// Rust files
+    Rust,
+    /// JavaScript files
+    JavaScript,
+    /// Python files
+    Python,
+    /// C files
+    C,
+    /// Java files
+    Java,
+    /// Go, Go Module, Go Checksums and Go Workspace files
+    Go,
+    /// HTML files
+    Html,
+    /// CSS files
+    Css,
+    /// JSON, JSON with Comments and JSON5 files
+    Json,
+    /// XML files
+    Xml,
+    /// YAML files
+    Yaml,
+    /// TOML files
+    Toml,
+    /// Markdown files
+    Markdown,
+    /// Shell files
+    Shell,
+    /// SQL files
+    Sql,
+    /// Dockerfile files
+    Docker,
+    /// Git Config, Git Attributes and Ignore List files
+    Git,
+    /// Ada files
+    Ada,
+    /// Agda files
+    Agda,
+    /// AsciiDoc files
+    AsciiDoc,
+    /// Assembly files
+    Assembly,
+    /// Astro files
+    Astro,
+    /// Awk files
+    Awk,
+    /// Batchfile files
+    Batchfile,
+    /// CMake files
+    CMake,
+    /// C# files
+    CSharp,
+    /// Clojure files
+    Clojure,
+    /// CoffeeScript files
+    CoffeeScript,
+    /// Common Lisp files
+    CommonLisp,
+    /// C++ files
+    Cpp,
+    /// Crystal files
+    Crystal,
+    /// CSV files
+    Csv,
+    /// CUDA files
+    Cuda,
+    /// Cue files
+    Cue,
+    /// D files
+    D,
+    /// Dart files
+    Dart,
+    /// Dhall files
+    Dhall,
+    /// EditorConfig files
+    EditorConfig,
+    /// Elixir files
+    Elixir,
+    /// Elm files
+    Elm,
+    /// Emacs Lisp files
+    EmacsLisp,
+    /// Erlang files
+    Erlang,
+    /// F# files
+    FSharp,
+    /// Fortran and Fortran Free Form files
+    Fortran,
+    /// Gherkin files
+    Gherkin,
+    /// Gleam files
+    Gleam,
+    /// GLSL files
+    Glsl,
+    /// GraphQL files
+    GraphQl,
+    /// Groovy files
+    Groovy,
+    /// Handlebars files
+    Handlebars,
+    /// Haskell files
+    Haskell,
+    /// Haxe files
+    Haxe,
+    /// HCL files
+    Hcl,
+    /// HLSL files
+    Hlsl,
+    /// Idris files
+    Idris,
+    /// INI files
+    Ini,
+    /// Jsonnet files
+    Jsonnet,
+    /// Julia files
+    Julia,
+    /// Jupyter Notebook files
+    JupyterNotebook,
+    /// Just files
+    Just,
+    /// Kotlin files
+    Kotlin,
+    /// Less files
+    Less,
+    /// Lua files
+    Lua,
+    /// Makefile files
+    Makefile,
+    /// MATLAB files
+    Matlab,
+    /// MDX files
+    Mdx,
+    /// Meson files
+    Meson,
+    /// Mojo files
+    Mojo,
+    /// Nginx files
+    Nginx,
+    /// Nim files
+    Nim,
+    /// Nix files
+    Nix,
+    /// Nushell files
+    Nushell,
+    /// Objective-C files
+    ObjectiveC,
+    /// Objective-C++ files
+    ObjectiveCpp,
+    /// OCaml files
+    Ocaml,
+    /// Odin files
+    Odin,
+    /// Org files
+    Org,
+    /// Pascal files
+    Pascal,
+    /// Perl files
+    Perl,
+    /// PHP files
+    Php,
+    /// PowerShell files
+    PowerShell,
+    /// Prisma files
+    Prisma,
+    /// Prolog files
+    Prolog,
+    /// Protocol Buffer files
+    Protobuf,
+    /// Pug files
+    Pug,
+    /// Puppet files
+    Puppet,
+    /// PureScript files
+    PureScript,
+    /// R files
+    R,
+    /// Racket files
+    Racket,
+    /// Raku files
+    Raku,
+    /// reStructuredText files
+    ReStructuredText,
+    /// Ruby files
+    Ruby,
+    /// Sass files
+    Sass,
+    /// Scala files
+    Scala,
+    /// Scheme files
+    Scheme,
+    /// SCSS files
+    Scss,
+    /// Solidity files
+    Solidity,
+    /// Starlark files
+    Starlark,
+    /// Svelte files
+    Svelte,
+    /// SVG files
+    Svg,
+    /// Swift files
+    Swift,
+    /// SystemVerilog files
+    SystemVerilog,
+    /// Tcl files
+    Tcl,
+    /// TeX files
+    Tex,
+    /// Thrift files
+    Thrift,
+    /// TypeScript and TSX files
+    TypeScript,
+    /// Typst files
+    Typst,
+    /// V files
+    V,
+    /// Vala files
+    Vala,
+    /// Verilog files
+    Verilog,
+    /// VHDL files
+    Vhdl,
+    /// Vim Script files
+    VimScript,
+    /// Visual Basic .NET files
+    VisualBasic,
+    /// Vue files
+    Vue,
+    /// WebAssembly files
+    WebAssembly,
+    /// XSLT files
+    Xslt,
+    /// Zig files
+    Zig,
+    /// Unknown/generic code file
+    Code,
+}
+
+/// Language data, indexed by `LanguageType as usize`
+pub(super) const LANGUAGES: &[LanguageInfo] = &[
+    LanguageInfo {
+        language: LanguageType::Rust,
+        name: "Rust",
+        color: Some("#dea584"),
+        aliases: &["rs"],
+        extensions: &["rs", "rs.in"],
+        filenames: &[],
+        interpreters: &["rust-script", "cargo"],
+        mime_type: "text/rust",
+        glyphs: ['R', 'R', '🦀', '⚙'],
+    },
+    LanguageInfo {
+        language: LanguageType::JavaScript,
+        name: "JavaScript",
+        color: Some("#f1e05a"),
+        aliases: &["js", "node"],
+        extensions: &[
+            "js",
+            "_js",
+            "bones",
+            "cjs",
+            "es",
+            "es6",
+            "frag",
+            "gs",
+            "jake",
+            "javascript",
+            "jsb",
+            "jscad",
+            "jsfl",
+            "jslib",
+            "jsm",
+            "jspre",
+            "jss",
+            "jsx",
+            "mjs",
+            "njs",
+            "pac",
+            "sjs",
+            "ssjs",
+            "xsjs",
+            "xsjslib",
+            "min.js",
+        ],
+        filenames: &["jakefile"],
+        interpreters: &[
+            "chakra", "d8", "gjs", "js", "node", "nodejs", "qjs", "rhino", "v8", "v8-shell",
+        ],
+        mime_type: "text/javascript",
+        glyphs: ['J', 'J', '⚡', '📜'],
+    },
+    LanguageInfo {
+        language: LanguageType::Python,
+        name: "Python",
+        color: Some("#3572A5"),
+        aliases: &["python3", "rusthon"],
+        extensions: &[
+            "py", "cgi", "fcgi", "gyp", "gypi", "lmi", "py3", "pyde", "pyi", "pyp", "pyt", "pyw",
+            "rpy", "spec", "tac", "wsgi", "xpy", "pyc", "pyo", "pyd",
+        ],
+        filenames: &[".gclient", "deps", "sconscript", "sconstruct", "wscript"],
+        interpreters: &["python", "python2", "python3", "py", "pypy", "pypy3", "uv"],
+        mime_type: "text/x-python",
+        glyphs: ['P', 'P', '🐍', '🐍'],
+    },
+    LanguageInfo {
+        language: LanguageType::C,
+        name: "C",
+        color: Some("#555555"),
+        aliases: &[],
+        extensions: &["c", "cats", "h", "idc"],
+        filenames: &[],
+        interpreters: &["tcc"],
+        mime_type: "text/x-csrc",
+        glyphs: ['C', 'C', '⚡', '🔧'],
+    },
+    LanguageInfo {
+        language: LanguageType::Java,
+        name: "Java",
+        color: Some("#b07219"),
+        aliases: &[],
+        extensions: &["java", "jav", "jsh", "class", "jar"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-java",
+        glyphs: ['J', 'J', '☕', '☕'],
+    },
+    LanguageInfo {
+        language: LanguageType::Go,
+        name: "Go",
+        color: Some("#00ADD8"),
+        aliases: &[
+            "golang",
+            "go.mod",
+            "go mod",
+            "go.sum",
+            "go sum",
+            "go.work.sum",
+            "go work sum",
+            "go.work",
+            "go work",
+        ],
+        extensions: &["go", "mod", "sum"],
+        filenames: &["go.mod", "go.sum", "go.work.sum", "go.work"],
+        interpreters: &[],
+        mime_type: "text/x-go",
+        glyphs: ['G', 'G', '🐹', '🚀'],
+    },
+    LanguageInfo {
+        language: LanguageType::Html,
+        name: "HTML",
+        color: Some("#e34c26"),
+        aliases: &["xhtml"],
+        extensions: &["html", "hta", "htm", "html.hl", "inc", "xht", "xhtml"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/html",
+        glyphs: ['H', '<', '🌐', '📄'],
+    },
+    LanguageInfo {
+        language: LanguageType::Css,
+        name: "CSS",
+        color: Some("#663399"),
+        aliases: &[],
+        extensions: &["css", "min.css"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/css",
+        glyphs: ['S', '#', '🎨', '✨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Json,
+        name: "JSON",
+        color: Some("#292929"),
+        aliases: &["geojson", "jsonl", "sarif", "topojson", "jsonc"],
+        extensions: &[
+            "json",
+            "4dform",
+            "4dproject",
+            "avsc",
+            "geojson",
+            "gltf",
+            "har",
+            "ice",
+            "json-tmlanguage",
+            "jsonl",
+            "mcmeta",
+            "sarif",
+            "tact",
+            "tfstate",
+            "tfstate.backup",
+            "topojson",
+            "webapp",
+            "webmanifest",
+            "yy",
+            "yyp",
+            "jsonc",
+            "code-snippets",
+            "code-workspace",
+            "sublime-build",
+            "sublime-commands",
+            "sublime-completions",
+            "sublime-keymap",
+            "sublime-macro",
+            "sublime-menu",
+            "sublime-mousemap",
+            "sublime-project",
+            "sublime-settings",
+            "sublime-theme",
+            "sublime-workspace",
+            "sublime_metrics",
+            "sublime_session",
+            "json5",
+        ],
+        filenames: &[
+            ".all-contributorsrc",
+            ".arcconfig",
+            ".auto-changelog",
+            ".c8rc",
+            ".htmlhintrc",
+            ".imgbotconfig",
+            ".nycrc",
+            ".tern-config",
+            ".tern-project",
+            ".watchmanconfig",
+            "module.bazel.lock",
+            "pipfile.lock",
+            "composer.lock",
+            "deno.lock",
+            "flake.lock",
+            "mcmod.info",
+            ".babelrc",
+            ".devcontainer.json",
+            ".eslintrc.json",
+            ".jscsrc",
+            ".jshintrc",
+            ".jslintrc",
+            ".swcrc",
+            "api-extractor.json",
+            "devcontainer.json",
+            "jsconfig.json",
+            "language-configuration.json",
+            "tsconfig.json",
+            "tslint.json",
+        ],
+        interpreters: &[],
+        mime_type: "application/json",
+        glyphs: ['{', '{', '📋', '🗂'],
+    },
+    LanguageInfo {
+        language: LanguageType::Xml,
+        name: "XML",
+        color: Some("#0060ac"),
+        aliases: &["rss", "xsd", "wsdl"],
+        extensions: &[
+            "xml",
+            "adml",
+            "admx",
+            "ant",
+            "axaml",
+            "axml",
+            "builds",
+            "ccproj",
+            "ccxml",
+            "clixml",
+            "cproject",
+            "cscfg",
+            "csdef",
+            "csl",
+            "csproj",
+            "ct",
+            "depproj",
+            "dita",
+            "ditamap",
+            "ditaval",
+            "dll.config",
+            "dotsettings",
+            "filters",
+            "fsproj",
+            "fxml",
+            "glade",
+            "gml",
+            "gmx",
+            "gpx",
+            "grxml",
+            "hzp",
+            "iml",
+            "ivy",
+            "jelly",
+            "jsproj",
+            "kml",
+            "launch",
+            "mdpolicy",
+            "mjml",
+            "mxml",
+            "natvis",
+            "ndproj",
+            "nproj",
+            "nuspec",
+            "odd",
+            "osm",
+            "pkgproj",
+            "plist",
+            "proj",
+            "props",
+            "ps1xml",
+            "psc1",
+            "pt",
+            "qhelp",
+            "rdf",
+            "resx",
+            "rss",
+            "sch",
+            "scxml",
+            "sfproj",
+            "shproj",
+            "srdf",
+            "storyboard",
+            "sublime-snippet",
+            "targets",
+            "tml",
+            "ui",
+            "urdf",
+            "ux",
+            "vbproj",
+            "vcxproj",
+            "vsixmanifest",
+            "vssettings",
+            "vstemplate",
+            "vxml",
+            "wixproj",
+            "workflow",
+            "wsdl",
+            "wsf",
+            "wxi",
+            "wxl",
+            "wxs",
+            "x3d",
+            "xacro",
+            "xaml",
+            "xib",
+            "xlf",
+            "xliff",
+            "xmi",
+            "xml.dist",
+            "xmp",
+            "xproj",
+            "xsd",
+            "xspec",
+            "xul",
+            "zcml",
+        ],
+        filenames: &[
+            ".classpath",
+            ".cproject",
+            ".project",
+            "app.config",
+            "nuget.config",
+            "settings.stylecop",
+            "web.debug.config",
+            "web.release.config",
+            "web.config",
+            "packages.config",
+        ],
+        interpreters: &[],
+        mime_type: "application/xml",
+        glyphs: ['<', '<', '📄', '🗃'],
+    },
+    LanguageInfo {
+        language: LanguageType::Yaml,
+        name: "YAML",
+        color: Some("#cb171e"),
+        aliases: &["yml"],
+        extensions: &[
+            "yml",
+            "mir",
+            "reek",
+            "rviz",
+            "sublime-syntax",
+            "syntax",
+            "yaml",
+            "yaml-tmlanguage",
+            "yaml.sed",
+            "yml.mysql",
+        ],
+        filenames: &[
+            ".clang-format",
+            ".clang-tidy",
+            ".clangd",
+            ".gemrc",
+            "citation.cff",
+            "glide.lock",
+            "pixi.lock",
+            "yarn.lock",
+        ],
+        interpreters: &[],
+        mime_type: "application/yaml",
+        glyphs: ['Y', ':', '📝', '⚙'],
+    },
+    LanguageInfo {
+        language: LanguageType::Toml,
+        name: "TOML",
+        color: Some("#9c4221"),
+        aliases: &[],
+        extensions: &["toml"],
+        filenames: &[
+            "cargo.lock",
+            "cargo.toml.orig",
+            "gopkg.lock",
+            "pipfile",
+            "pdm.lock",
+            "poetry.lock",
+            "uv.lock",
+        ],
+        interpreters: &[],
+        mime_type: "application/toml",
+        glyphs: ['T', '=', '⚙', '🔧'],
+    },
+    LanguageInfo {
+        language: LanguageType::Markdown,
+        name: "Markdown",
+        color: Some("#083fa1"),
+        aliases: &["md", "pandoc"],
+        extensions: &[
+            "md", "livemd", "markdown", "mdown", "mdwn", "mkd", "mkdn", "mkdown", "ronn", "scd",
+            "workbook",
+        ],
+        filenames: &["contents.lr"],
+        interpreters: &[],
+        mime_type: "text/markdown",
+        glyphs: ['M', '#', '📝', '📖'],
+    },
+    LanguageInfo {
+        language: LanguageType::Shell,
+        name: "Shell",
+        color: Some("#89e051"),
+        aliases: &["sh", "shell-script", "bash", "zsh", "envrc"],
+        extensions: &[
+            "sh",
+            "bash",
+            "bats",
+            "cgi",
+            "command",
+            "env",
+            "fcgi",
+            "ksh",
+            "sh.in",
+            "tmux",
+            "tool",
+            "trigger",
+            "zsh",
+            "zsh-theme",
+            "fish",
+            "csh",
+            "tcsh",
+        ],
+        filenames: &[
+            ".bash_aliases",
+            ".bash_functions",
+            ".bash_history",
+            ".bash_logout",
+            ".bash_profile",
+            ".bashrc",
+            ".cshrc",
+            ".envrc",
+            ".flaskenv",
+            ".kshrc",
+            ".login",
+            ".profile",
+            ".tmux.conf",
+            ".zlogin",
+            ".zlogout",
+            ".zprofile",
+            ".zshenv",
+            ".zshrc",
+            "9fs",
+            "pkgbuild",
+            "bash_aliases",
+            "bash_logout",
+            "bash_profile",
+            "bashrc",
+            "cshrc",
+            "gradlew",
+            "kshrc",
+            "login",
+            "man",
+            "profile",
+            "tmux.conf",
+            "zlogin",
+            "zlogout",
+            "zprofile",
+            "zshenv",
+            "zshrc",
+        ],
+        interpreters: &[
+            "ash", "bash", "dash", "ksh", "mksh", "pdksh", "rc", "sh", "zsh", "fish", "csh", "tcsh",
+        ],
+        mime_type: "application/x-shellscript",
+        glyphs: ['$', '$', '🐚', '⚡'],
+    },
+    LanguageInfo {
+        language: LanguageType::Sql,
+        name: "SQL",
+        color: Some("#e38c00"),
+        aliases: &[],
+        extensions: &[
+            "sql", "cql", "ddl", "inc", "mysql", "prc", "tab", "udf", "viw", "pgsql", "sqlite",
+        ],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "application/sql",
+        glyphs: ['Q', 'Q', '🗄', '💾'],
+    },
+    LanguageInfo {
+        language: LanguageType::Docker,
+        name: "Dockerfile",
+        color: Some("#384d54"),
+        aliases: &["containerfile"],
+        extensions: &["dockerfile", "containerfile"],
+        filenames: &["containerfile", "dockerfile"],
+        interpreters: &[],
+        mime_type: "text/x-dockerfile",
+        glyphs: ['D', '□', '🐳', '📦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Git,
+        name: "Git Config",
+        color: Some("#F44D27"),
+        aliases: &[
+            "gitconfig",
+            "gitmodules",
+            "gitattributes",
+            "ignore",
+            "gitignore",
+            "git-ignore",
+        ],
+        extensions: &["gitconfig", "gitignore", "gitattributes", "gitmodules"],
+        filenames: &[
+            ".gitconfig",
+            ".gitmodules",
+            ".gitattributes",
+            ".atomignore",
+            ".babelignore",
+            ".bzrignore",
+            ".coffeelintignore",
+            ".cvsignore",
+            ".dockerignore",
+            ".eleventyignore",
+            ".eslintignore",
+            ".gitignore",
+            ".markdownlintignore",
+            ".nodemonignore",
+            ".npmignore",
+            ".prettierignore",
+            ".stylelintignore",
+            ".vercelignore",
+            ".vscodeignore",
+            "gitignore-global",
+            "gitignore_global",
+        ],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['G', '*', '🌿', '🔀'],
+    },
+    LanguageInfo {
+        language: LanguageType::Ada,
+        name: "Ada",
+        color: Some("#02f88c"),
+        aliases: &["ada95", "ada2005"],
+        extensions: &["adb", "ada", "ads"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-adasrc",
+        glyphs: ['A', 'A', '🟢', '🟩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Agda,
+        name: "Agda",
+        color: Some("#315665"),
+        aliases: &[],
+        extensions: &["agda"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['A', 'A', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::AsciiDoc,
+        name: "AsciiDoc",
+        color: Some("#73a0c5"),
+        aliases: &[],
+        extensions: &["asciidoc", "adoc", "asc"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['A', '=', '📝', '📖'],
+    },
+    LanguageInfo {
+        language: LanguageType::Assembly,
+        name: "Assembly",
+        color: Some("#6E4C13"),
+        aliases: &["asm", "nasm"],
+        extensions: &["asm", "a51", "i", "inc", "nas", "nasm", "s"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-asm",
+        glyphs: ['A', ';', '⚙', '🔩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Astro,
+        name: "Astro",
+        color: Some("#ff5a03"),
+        aliases: &[],
+        extensions: &["astro"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['A', '<', '🚀', '🌠'],
+    },
+    LanguageInfo {
+        language: LanguageType::Awk,
+        name: "Awk",
+        color: Some("#c30e9b"),
+        aliases: &[],
+        extensions: &["awk", "auk", "gawk", "mawk", "nawk"],
+        filenames: &[],
+        interpreters: &["awk", "gawk", "mawk", "nawk"],
+        mime_type: "application/x-awk",
+        glyphs: ['A', 'A', '🟣', '🟪'],
+    },
+    LanguageInfo {
+        language: LanguageType::Batchfile,
+        name: "Batchfile",
+        color: Some("#C1F12E"),
+        aliases: &["bat", "batch", "dosbatch", "winbatch"],
+        extensions: &["bat", "cmd"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "application/x-bat",
+        glyphs: ['B', '>', '🦇', '🦇'],
+    },
+    LanguageInfo {
+        language: LanguageType::CMake,
+        name: "CMake",
+        color: Some("#DA3434"),
+        aliases: &[],
+        extensions: &["cmake", "cmake.in"],
+        filenames: &["cmakelists.txt"],
+        interpreters: &[],
+        mime_type: "text/x-cmake",
+        glyphs: ['C', '▲', '🔺', '🏗'],
+    },
+    LanguageInfo {
+        language: LanguageType::CSharp,
+        name: "C#",
+        color: Some("#178600"),
+        aliases: &["csharp", "cake", "cakescript"],
+        extensions: &["cs", "cake", "cs.pp", "csx", "linq"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-csharp",
+        glyphs: ['C', '#', '🎼', '🎼'],
+    },
+    LanguageInfo {
+        language: LanguageType::Clojure,
+        name: "Clojure",
+        color: Some("#db5855"),
+        aliases: &[],
+        extensions: &[
+            "clj", "bb", "boot", "cl2", "cljc", "cljs", "cljs.hl", "cljscm", "cljx", "hic",
+        ],
+        filenames: &["riemann.config"],
+        interpreters: &["bb"],
+        mime_type: "text/x-clojure",
+        glyphs: ['C', 'λ', '🟢', '🌀'],
+    },
+    LanguageInfo {
+        language: LanguageType::CoffeeScript,
+        name: "CoffeeScript",
+        color: Some("#244776"),
+        aliases: &["coffee", "coffee-script"],
+        extensions: &["coffee", "_coffee", "cake", "cjsx", "iced"],
+        filenames: &["cakefile"],
+        interpreters: &["coffee"],
+        mime_type: "application/vnd.coffeescript",
+        glyphs: ['C', '~', '☕', '☕'],
+    },
+    LanguageInfo {
+        language: LanguageType::CommonLisp,
+        name: "Common Lisp",
+        color: Some("#3fb68b"),
+        aliases: &["lisp"],
+        extensions: &["lisp", "asd", "cl", "l", "lsp", "ny", "podsl", "sexp"],
+        filenames: &[],
+        interpreters: &["lisp", "sbcl", "ccl", "clisp", "ecl"],
+        mime_type: "text/x-common-lisp",
+        glyphs: ['L', '(', '🟢', '👽'],
+    },
+    LanguageInfo {
+        language: LanguageType::Cpp,
+        name: "C++",
+        color: Some("#f34b7d"),
+        aliases: &["cpp"],
+        extensions: &[
+            "cpp", "c++", "cc", "cp", "cppm", "cxx", "h", "h++", "hh", "hpp", "hxx", "inl", "ino",
+            "ipp", "ixx", "tcc", "tpp", "txx",
+        ],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-c++src",
+        glyphs: ['C', '+', '⚙', '🔩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Crystal,
+        name: "Crystal",
+        color: Some("#000100"),
+        aliases: &[],
+        extensions: &["cr"],
+        filenames: &[],
+        interpreters: &["crystal"],
+        mime_type: "text/x-crystal",
+        glyphs: ['C', '◆', '🔮', '🔮'],
+    },
+    LanguageInfo {
+        language: LanguageType::Csv,
+        name: "CSV",
+        color: Some("#237346"),
+        aliases: &[],
+        extensions: &["csv"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/csv",
+        glyphs: ['C', ',', '📊', '📈'],
+    },
+    LanguageInfo {
+        language: LanguageType::Cuda,
+        name: "CUDA",
+        color: Some("#3A4E3A"),
+        aliases: &[],
+        extensions: &["cu", "cuh"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['C', 'C', '⚫', '⬛'],
+    },
+    LanguageInfo {
+        language: LanguageType::Cue,
+        name: "Cue",
+        color: Some("#5886E1"),
+        aliases: &[],
+        extensions: &["cue"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['C', 'C', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::D,
+        name: "D",
+        color: Some("#ba595e"),
+        aliases: &["dlang"],
+        extensions: &["d", "di"],
+        filenames: &[],
+        interpreters: &["rdmd"],
+        mime_type: "text/x-dsrc",
+        glyphs: ['D', 'D', '🔴', '🟥'],
+    },
+    LanguageInfo {
+        language: LanguageType::Dart,
+        name: "Dart",
+        color: Some("#00B4AB"),
+        aliases: &[],
+        extensions: &["dart"],
+        filenames: &[],
+        interpreters: &["dart"],
+        mime_type: "application/vnd.dart",
+        glyphs: ['D', '◎', '🎯', '🎯'],
+    },
+    LanguageInfo {
+        language: LanguageType::Dhall,
+        name: "Dhall",
+        color: Some("#dfafff"),
+        aliases: &[],
+        extensions: &["dhall"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['D', 'D', '🟣', '🟪'],
+    },
+    LanguageInfo {
+        language: LanguageType::EditorConfig,
+        name: "EditorConfig",
+        color: Some("#fff1f2"),
+        aliases: &["editor-config"],
+        extensions: &["editorconfig"],
+        filenames: &[".editorconfig"],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['E', '=', '⚙', '🔧'],
+    },
+    LanguageInfo {
+        language: LanguageType::Elixir,
+        name: "Elixir",
+        color: Some("#6e4a7e"),
+        aliases: &[],
+        extensions: &["ex", "exs"],
+        filenames: &["mix.lock"],
+        interpreters: &["elixir"],
+        mime_type: "text/x-elixir",
+        glyphs: ['E', '◇', '💧', '🧪'],
+    },
+    LanguageInfo {
+        language: LanguageType::Elm,
+        name: "Elm",
+        color: Some("#60B5CC"),
+        aliases: &[],
+        extensions: &["elm"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-elm",
+        glyphs: ['E', '◇', '🌳', '🌳'],
+    },
+    LanguageInfo {
+        language: LanguageType::EmacsLisp,
+        name: "Emacs Lisp",
+        color: Some("#c065db"),
+        aliases: &["elisp", "emacs"],
+        extensions: &["el", "emacs", "emacs.desktop"],
+        filenames: &[
+            ".abbrev_defs",
+            ".emacs",
+            ".emacs.desktop",
+            ".gnus",
+            ".spacemacs",
+            ".viper",
+            "cask",
+            "project.ede",
+            "_emacs",
+            "abbrev_defs",
+        ],
+        interpreters: &[],
+        mime_type: "text/x-emacs-lisp",
+        glyphs: ['E', '(', '🟣', '🐃'],
+    },
+    LanguageInfo {
+        language: LanguageType::Erlang,
+        name: "Erlang",
+        color: Some("#B83998"),
+        aliases: &[],
+        extensions: &[
+            "erl", "app", "app.src", "es", "escript", "hrl", "xrl", "yrl",
+        ],
+        filenames: &[
+            "emakefile",
+            "rebar.config",
+            "rebar.config.lock",
+            "rebar.lock",
+        ],
+        interpreters: &["escript"],
+        mime_type: "text/x-erlang",
+        glyphs: ['E', '%', '📞', '📞'],
+    },
+    LanguageInfo {
+        language: LanguageType::FSharp,
+        name: "F#",
+        color: Some("#b845fc"),
+        aliases: &["fsharp"],
+        extensions: &["fs", "fsi", "fsx"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-fsharp",
+        glyphs: ['F', '#', '🔷', '🎵'],
+    },
+    LanguageInfo {
+        language: LanguageType::Fortran,
+        name: "Fortran",
+        color: Some("#4d41b1"),
+        aliases: &[],
+        extensions: &["f", "f77", "for", "fpp", "f90", "f03", "f08", "f95"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-fortran",
+        glyphs: ['F', 'F', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Gherkin,
+        name: "Gherkin",
+        color: Some("#5B2063"),
+        aliases: &["cucumber"],
+        extensions: &["feature", "story"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-gherkin",
+        glyphs: ['G', '~', '🥒', '🥒'],
+    },
+    LanguageInfo {
+        language: LanguageType::Gleam,
+        name: "Gleam",
+        color: Some("#ffaff3"),
+        aliases: &[],
+        extensions: &["gleam"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['G', '★', '⭐', '🌟'],
+    },
+    LanguageInfo {
+        language: LanguageType::Glsl,
+        name: "GLSL",
+        color: Some("#5686a5"),
+        aliases: &[],
+        extensions: &[
+            "glsl", "frag", "frg", "fs", "fsh", "geom", "glslf", "glslv", "gs", "gshader", "rchit",
+            "rmiss", "shader", "tesc", "tese", "vert", "vrx", "vs", "vsh", "vshader",
+        ],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-glsl",
+        glyphs: ['G', '◐', '🔵', '🌈'],
+    },
+    LanguageInfo {
+        language: LanguageType::GraphQl,
+        name: "GraphQL",
+        color: Some("#e10098"),
+        aliases: &[],
+        extensions: &["graphql", "gql", "graphqls"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "application/graphql",
+        glyphs: ['G', '◈', '🔺', '🕸'],
+    },
+    LanguageInfo {
+        language: LanguageType::Groovy,
+        name: "Groovy",
+        color: Some("#4298b8"),
+        aliases: &[],
+        extensions: &["groovy", "grt", "gtpl", "gvy"],
+        filenames: &["jenkinsfile"],
+        interpreters: &["groovy"],
+        mime_type: "text/x-groovy",
+        glyphs: ['G', 'G', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Handlebars,
+        name: "Handlebars",
+        color: Some("#f7931e"),
+        aliases: &["hbs", "htmlbars"],
+        extensions: &["handlebars", "hbs"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-handlebars-template",
+        glyphs: ['H', '{', '🟠', '👨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Haskell,
+        name: "Haskell",
+        color: Some("#5e5086"),
+        aliases: &[],
+        extensions: &["hs", "hs-boot", "hsc"],
+        filenames: &[],
+        interpreters: &["runghc", "runhaskell", "runhugs"],
+        mime_type: "text/x-haskell",
+        glyphs: ['H', 'λ', '🟣', '🎩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Haxe,
+        name: "Haxe",
+        color: Some("#df7900"),
+        aliases: &[],
+        extensions: &["hx", "hxsl"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-haxe",
+        glyphs: ['H', 'H', '🟠', '🟧'],
+    },
+    LanguageInfo {
+        language: LanguageType::Hcl,
+        name: "HCL",
+        color: Some("#844FBA"),
+        aliases: &["hashicorp configuration language", "terraform"],
+        extensions: &["hcl", "nomad", "tf", "tfvars", "workflow"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['T', '◇', '🏗', '🌍'],
+    },
+    LanguageInfo {
+        language: LanguageType::Hlsl,
+        name: "HLSL",
+        color: Some("#aace60"),
+        aliases: &[],
+        extensions: &["hlsl", "cginc", "fx", "fxh", "hlsli"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['H', '◐', '🟢', '🌈'],
+    },
+    LanguageInfo {
+        language: LanguageType::Idris,
+        name: "Idris",
+        color: Some("#b30000"),
+        aliases: &[],
+        extensions: &["idr", "lidr"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['I', 'I', '🔴', '🟥'],
+    },
+    LanguageInfo {
+        language: LanguageType::Ini,
+        name: "INI",
+        color: Some("#d1dbe0"),
+        aliases: &["dosini"],
+        extensions: &[
+            "ini",
+            "cfg",
+            "cnf",
+            "dof",
+            "frm",
+            "lektorproject",
+            "prefs",
+            "pro",
+            "properties",
+            "url",
+        ],
+        filenames: &[
+            ".coveragerc",
+            ".flake8",
+            ".pylintrc",
+            "hosts",
+            "buildozer.spec",
+            "pylintrc",
+            "vlcrc",
+        ],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['I', '=', '⚙', '🔧'],
+    },
+    LanguageInfo {
+        language: LanguageType::Jsonnet,
+        name: "Jsonnet",
+        color: Some("#0064bd"),
+        aliases: &[],
+        extensions: &["jsonnet", "libsonnet"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['J', 'J', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Julia,
+        name: "Julia",
+        color: Some("#a270ba"),
+        aliases: &[],
+        extensions: &["jl"],
+        filenames: &[],
+        interpreters: &["julia"],
+        mime_type: "text/x-julia",
+        glyphs: ['J', '∴', '🟣', '🔮'],
+    },
+    LanguageInfo {
+        language: LanguageType::JupyterNotebook,
+        name: "Jupyter Notebook",
+        color: Some("#DA5B0B"),
+        aliases: &["ipython notebook"],
+        extensions: &["ipynb"],
+        filenames: &["notebook"],
+        interpreters: &[],
+        mime_type: "application/x-ipynb+json",
+        glyphs: ['J', '◎', '📓', '📓'],
+    },
+    LanguageInfo {
+        language: LanguageType::Just,
+        name: "Just",
+        color: Some("#384d54"),
+        aliases: &["justfile"],
+        extensions: &["just"],
+        filenames: &[".justfile", "justfile"],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['J', '>', '🤖', '🤖'],
+    },
+    LanguageInfo {
+        language: LanguageType::Kotlin,
+        name: "Kotlin",
+        color: Some("#A97BFF"),
+        aliases: &[],
+        extensions: &["kt", "ktm", "kts"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-kotlin",
+        glyphs: ['K', '◆', '🟣', '🏝'],
+    },
+    LanguageInfo {
+        language: LanguageType::Less,
+        name: "Less",
+        color: Some("#1d365d"),
+        aliases: &["less-css"],
+        extensions: &["less"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-less",
+        glyphs: ['L', '#', '🎨', '✨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Lua,
+        name: "Lua",
+        color: Some("#000080"),
+        aliases: &[],
+        extensions: &[
+            "lua", "fcgi", "nse", "p8", "pd_lua", "rbxs", "rockspec", "wlua",
+        ],
+        filenames: &[".luacheckrc"],
+        interpreters: &["lua"],
+        mime_type: "text/x-lua",
+        glyphs: ['L', '☾', '🌙', '🌙'],
+    },
+    LanguageInfo {
+        language: LanguageType::Makefile,
+        name: "Makefile",
+        color: Some("#427819"),
+        aliases: &["bsdmake", "make", "mf"],
+        extensions: &["mak", "d", "make", "makefile", "mk", "mkfile"],
+        filenames: &[
+            "bsdmakefile",
+            "gnumakefile",
+            "kbuild",
+            "makefile",
+            "makefile.am",
+            "makefile.boot",
+            "makefile.frag",
+            "makefile.in",
+            "makefile.inc",
+            "makefile.wat",
+            "makefile.sco",
+            "mkfile",
+        ],
+        interpreters: &["make"],
+        mime_type: "text/x-makefile",
+        glyphs: ['M', '>', '🔨', '🏗'],
+    },
+    LanguageInfo {
+        language: LanguageType::Matlab,
+        name: "MATLAB",
+        color: Some("#e16737"),
+        aliases: &["octave"],
+        extensions: &["matlab", "m"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-matlab",
+        glyphs: ['M', '∑', '📐', '📈'],
+    },
+    LanguageInfo {
+        language: LanguageType::Mdx,
+        name: "MDX",
+        color: Some("#fcb32c"),
+        aliases: &[],
+        extensions: &["mdx"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/mdx",
+        glyphs: ['M', '#', '📝', '📖'],
+    },
+    LanguageInfo {
+        language: LanguageType::Meson,
+        name: "Meson",
+        color: Some("#007800"),
+        aliases: &[],
+        extensions: &["wrap"],
+        filenames: &["meson.build", "meson_options.txt", "meson.options"],
+        interpreters: &[],
+        mime_type: "text/x-meson",
+        glyphs: ['M', 'M', '🟢', '🟩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Mojo,
+        name: "Mojo",
+        color: Some("#ff4c1f"),
+        aliases: &[],
+        extensions: &["mojo"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['M', '*', '🔥', '🔥'],
+    },
+    LanguageInfo {
+        language: LanguageType::Nginx,
+        name: "Nginx",
+        color: Some("#009639"),
+        aliases: &["nginx configuration file"],
+        extensions: &["nginx", "nginxconf", "vhost"],
+        filenames: &["nginx.conf"],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['N', '=', '🟢', '🌐'],
+    },
+    LanguageInfo {
+        language: LanguageType::Nim,
+        name: "Nim",
+        color: Some("#ffc200"),
+        aliases: &[],
+        extensions: &["nim", "nim.cfg", "nimble", "nimrod", "nims"],
+        filenames: &["nim.cfg"],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['N', '♛', '👑', '👑'],
+    },
+    LanguageInfo {
+        language: LanguageType::Nix,
+        name: "Nix",
+        color: Some("#7e7eff"),
+        aliases: &["nixos"],
+        extensions: &["nix"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-nix",
+        glyphs: ['N', '*', '❄', '❄'],
+    },
+    LanguageInfo {
+        language: LanguageType::Nushell,
+        name: "Nushell",
+        color: Some("#4E9906"),
+        aliases: &["nu-script", "nushell-script"],
+        extensions: &["nu"],
+        filenames: &[],
+        interpreters: &["nu"],
+        mime_type: "text/plain",
+        glyphs: ['N', '>', '🐚', '⚡'],
+    },
+    LanguageInfo {
+        language: LanguageType::ObjectiveC,
+        name: "Objective-C",
+        color: Some("#438eff"),
+        aliases: &["obj-c", "objc", "objectivec"],
+        extensions: &["m", "h"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-objcsrc",
+        glyphs: ['O', '@', '🍎', '🍏'],
+    },
+    LanguageInfo {
+        language: LanguageType::ObjectiveCpp,
+        name: "Objective-C++",
+        color: Some("#6866fb"),
+        aliases: &["obj-c++", "objc++", "objectivec++"],
+        extensions: &["mm"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-objc++src",
+        glyphs: ['O', '@', '🍎', '🍏'],
+    },
+    LanguageInfo {
+        language: LanguageType::Ocaml,
+        name: "OCaml",
+        color: Some("#ef7a08"),
+        aliases: &[],
+        extensions: &["ml", "eliom", "eliomi", "ml4", "mli", "mll", "mly"],
+        filenames: &[],
+        interpreters: &["ocaml", "ocamlrun", "ocamlscript"],
+        mime_type: "text/x-ocaml",
+        glyphs: ['O', '~', '🐫', '🐫'],
+    },
+    LanguageInfo {
+        language: LanguageType::Odin,
+        name: "Odin",
+        color: Some("#60AFFE"),
+        aliases: &["odinlang", "odin-lang"],
+        extensions: &["odin"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['O', 'O', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Org,
+        name: "Org",
+        color: Some("#77aa99"),
+        aliases: &[],
+        extensions: &["org"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/org",
+        glyphs: ['O', '*', '🦄', '🦄'],
+    },
+    LanguageInfo {
+        language: LanguageType::Pascal,
+        name: "Pascal",
+        color: Some("#E3F171"),
+        aliases: &["delphi", "objectpascal"],
+        extensions: &["pas", "dfm", "dpr", "inc", "lpr", "pascal", "pp"],
+        filenames: &[],
+        interpreters: &["instantfpc"],
+        mime_type: "text/x-pascal",
+        glyphs: ['P', 'P', '🟡', '🟨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Perl,
+        name: "Perl",
+        color: Some("#0298c3"),
+        aliases: &["cperl"],
+        extensions: &[
+            "pl", "al", "cgi", "fcgi", "perl", "ph", "plx", "pm", "psgi", "t",
+        ],
+        filenames: &[
+            ".latexmkrc",
+            "makefile.pl",
+            "rexfile",
+            "ack",
+            "cpanfile",
+            "latexmkrc",
+        ],
+        interpreters: &["cperl", "perl"],
+        mime_type: "application/x-perl",
+        glyphs: ['P', '$', '🐪', '🐪'],
+    },
+    LanguageInfo {
+        language: LanguageType::Php,
+        name: "PHP",
+        color: Some("#4F5D95"),
+        aliases: &["inc"],
+        extensions: &[
+            "php", "aw", "ctp", "fcgi", "inc", "php3", "php4", "php5", "phps", "phpt",
+        ],
+        filenames: &[".php", ".php_cs", ".php_cs.dist", "phakefile"],
+        interpreters: &["php"],
+        mime_type: "application/x-php",
+        glyphs: ['P', '$', '🐘', '🐘'],
+    },
+    LanguageInfo {
+        language: LanguageType::PowerShell,
+        name: "PowerShell",
+        color: Some("#012456"),
+        aliases: &["posh", "pwsh"],
+        extensions: &["ps1", "psd1", "psm1"],
+        filenames: &[],
+        interpreters: &["pwsh"],
+        mime_type: "application/x-powershell",
+        glyphs: ['P', '>', '🔷', '⚡'],
+    },
+    LanguageInfo {
+        language: LanguageType::Prisma,
+        name: "Prisma",
+        color: Some("#0c344b"),
+        aliases: &[],
+        extensions: &["prisma"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['P', '◭', '🔺', '💎'],
+    },
+    LanguageInfo {
+        language: LanguageType::Prolog,
+        name: "Prolog",
+        color: Some("#74283c"),
+        aliases: &[],
+        extensions: &["pl", "plt", "pro", "prolog", "yap"],
+        filenames: &[],
+        interpreters: &["swipl", "yap"],
+        mime_type: "text/plain",
+        glyphs: ['P', 'P', '🔴', '🟥'],
+    },
+    LanguageInfo {
+        language: LanguageType::Protobuf,
+        name: "Protocol Buffer",
+        color: None,
+        aliases: &["proto", "protobuf", "protocol buffers"],
+        extensions: &["proto"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-protobuf",
+        glyphs: ['P', '{', '📦', '📨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Pug,
+        name: "Pug",
+        color: Some("#a86454"),
+        aliases: &[],
+        extensions: &["jade", "pug"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-pug",
+        glyphs: ['P', '<', '🐶', '🐶'],
+    },
+    LanguageInfo {
+        language: LanguageType::Puppet,
+        name: "Puppet",
+        color: Some("#302B6D"),
+        aliases: &[],
+        extensions: &["pp"],
+        filenames: &["modulefile"],
+        interpreters: &[],
+        mime_type: "text/x-puppet",
+        glyphs: ['P', '◇', '🎭', '🎭'],
+    },
+    LanguageInfo {
+        language: LanguageType::PureScript,
+        name: "PureScript",
+        color: Some("#1D222D"),
+        aliases: &[],
+        extensions: &["purs"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['P', 'P', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::R,
+        name: "R",
+        color: Some("#198CE7"),
+        aliases: &["r", "rscript", "splus"],
+        extensions: &["r", "rd", "rsx"],
+        filenames: &[".rprofile", "expr-dist"],
+        interpreters: &["rscript"],
+        mime_type: "text/x-rsrc",
+        glyphs: ['R', '®', '📊', '📈'],
+    },
+    LanguageInfo {
+        language: LanguageType::Racket,
+        name: "Racket",
+        color: Some("#3c5caa"),
+        aliases: &[],
+        extensions: &["rkt", "rktd", "rktl", "scrbl"],
+        filenames: &[],
+        interpreters: &["racket"],
+        mime_type: "text/plain",
+        glyphs: ['R', 'λ', '🎾', '🎾'],
+    },
+    LanguageInfo {
+        language: LanguageType::Raku,
+        name: "Raku",
+        color: Some("#0000fb"),
+        aliases: &["perl6", "perl-6"],
+        extensions: &[
+            "6pl", "6pm", "nqp", "p6", "p6l", "p6m", "pl6", "pm6", "raku", "rakumod", "t",
+        ],
+        filenames: &[],
+        interpreters: &["perl6", "raku", "rakudo"],
+        mime_type: "text/plain",
+        glyphs: ['R', '»', '🦋', '🦋'],
+    },
+    LanguageInfo {
+        language: LanguageType::ReStructuredText,
+        name: "reStructuredText",
+        color: Some("#141414"),
+        aliases: &["rst"],
+        extensions: &["rst", "rest", "rest.txt", "rst.txt"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-rst",
+        glyphs: ['R', '=', '📝', '📖'],
+    },
+    LanguageInfo {
+        language: LanguageType::Ruby,
+        name: "Ruby",
+        color: Some("#701516"),
+        aliases: &["jruby", "macruby", "rake", "rb", "rbx"],
+        extensions: &[
+            "rb",
+            "builder",
+            "eye",
+            "fcgi",
+            "gemspec",
+            "god",
+            "jbuilder",
+            "mspec",
+            "pluginspec",
+            "podspec",
+            "prawn",
+            "rabl",
+            "rake",
+            "rbi",
+            "rbuild",
+            "rbw",
+            "rbx",
+            "ru",
+            "ruby",
+            "spec",
+            "thor",
+            "watchr",
+        ],
+        filenames: &[
+            ".irbrc",
+            ".pryrc",
+            ".simplecov",
+            "appraisals",
+            "berksfile",
+            "brewfile",
+            "buildfile",
+            "capfile",
+            "dangerfile",
+            "deliverfile",
+            "fastfile",
+            "gemfile",
+            "guardfile",
+            "jarfile",
+            "mavenfile",
+            "podfile",
+            "puppetfile",
+            "rakefile",
+            "snapfile",
+            "steepfile",
+            "thorfile",
+            "vagrantfile",
+        ],
+        interpreters: &["jruby", "macruby", "rake", "rbx", "ruby"],
+        mime_type: "application/x-ruby",
+        glyphs: ['R', '◆', '💎', '💎'],
+    },
+    LanguageInfo {
+        language: LanguageType::Sass,
+        name: "Sass",
+        color: Some("#a53b70"),
+        aliases: &[],
+        extensions: &["sass"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-sass",
+        glyphs: ['S', '#', '🎨', '✨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Scala,
+        name: "Scala",
+        color: Some("#c22d40"),
+        aliases: &[],
+        extensions: &["scala", "kojo", "sbt", "sc"],
+        filenames: &[],
+        interpreters: &["scala"],
+        mime_type: "text/x-scala",
+        glyphs: ['S', '≡', '🔴', '🪜'],
+    },
+    LanguageInfo {
+        language: LanguageType::Scheme,
+        name: "Scheme",
+        color: Some("#1e4aec"),
+        aliases: &[],
+        extensions: &["scm", "kl", "sch", "sld", "sls", "sps", "ss"],
+        filenames: &[],
+        interpreters: &[
+            "scheme", "guile", "bigloo", "chicken", "csi", "gosh", "r6rs",
+        ],
+        mime_type: "text/x-scheme",
+        glyphs: ['S', 'λ', '🔵', '🌀'],
+    },
+    LanguageInfo {
+        language: LanguageType::Scss,
+        name: "SCSS",
+        color: Some("#c6538c"),
+        aliases: &[],
+        extensions: &["scss"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-scss",
+        glyphs: ['S', '#', '🎨', '✨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Solidity,
+        name: "Solidity",
+        color: Some("#AA6746"),
+        aliases: &[],
+        extensions: &["sol"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-solidity",
+        glyphs: ['S', '◆', '💠', '💠'],
+    },
+    LanguageInfo {
+        language: LanguageType::Starlark,
+        name: "Starlark",
+        color: Some("#76d275"),
+        aliases: &["bazel", "bzl"],
+        extensions: &["bzl", "star"],
+        filenames: &[
+            "buck",
+            "build",
+            "build.bazel",
+            "module.bazel",
+            "tiltfile",
+            "workspace",
+            "workspace.bazel",
+            "workspace.bzlmod",
+        ],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['S', '★', '🌟', '🌟'],
+    },
+    LanguageInfo {
+        language: LanguageType::Svelte,
+        name: "Svelte",
+        color: Some("#ff3e00"),
+        aliases: &[],
+        extensions: &["svelte"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['S', '<', '🔥', '🧡'],
+    },
+    LanguageInfo {
+        language: LanguageType::Svg,
+        name: "SVG",
+        color: Some("#ff9900"),
+        aliases: &[],
+        extensions: &["svg"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "image/svg+xml",
+        glyphs: ['S', '<', '🖼', '🎨'],
+    },
+    LanguageInfo {
+        language: LanguageType::Swift,
+        name: "Swift",
+        color: Some("#F05138"),
+        aliases: &[],
+        extensions: &["swift"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-swift",
+        glyphs: ['S', '»', '🐦', '🕊'],
+    },
+    LanguageInfo {
+        language: LanguageType::SystemVerilog,
+        name: "SystemVerilog",
+        color: Some("#DAE1C2"),
+        aliases: &[],
+        extensions: &["sv", "svh", "vh"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-systemverilog",
+        glyphs: ['S', 'S', '🟢', '🟩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Tcl,
+        name: "Tcl",
+        color: Some("#e4cc98"),
+        aliases: &[],
+        extensions: &["tcl", "adp", "sdc", "tcl.in", "tm", "xdc"],
+        filenames: &["owh", "starfield"],
+        interpreters: &["tclsh", "wish"],
+        mime_type: "text/x-tcl",
+        glyphs: ['T', '>', '🪶', '🪶'],
+    },
+    LanguageInfo {
+        language: LanguageType::Tex,
+        name: "TeX",
+        color: Some("#3D6117"),
+        aliases: &["latex"],
+        extensions: &[
+            "tex", "aux", "bbx", "cbx", "cls", "dtx", "ins", "lbx", "ltx", "mkii", "mkiv", "mkvi",
+            "sty", "toc",
+        ],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-tex",
+        glyphs: ['T', '∫', '📐', '📜'],
+    },
+    LanguageInfo {
+        language: LanguageType::Thrift,
+        name: "Thrift",
+        color: Some("#D12127"),
+        aliases: &[],
+        extensions: &["thrift"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['T', 'T', '🔴', '🟥'],
+    },
+    LanguageInfo {
+        language: LanguageType::TypeScript,
+        name: "TypeScript",
+        color: Some("#3178c6"),
+        aliases: &["ts"],
+        extensions: &["ts", "cts", "mts", "tsx", "d.ts", "d.mts", "d.cts"],
+        filenames: &[],
+        interpreters: &["bun", "deno", "ts-node", "tsx"],
+        mime_type: "application/typescript",
+        glyphs: ['T', '◆', '🔷', '📘'],
+    },
+    LanguageInfo {
+        language: LanguageType::Typst,
+        name: "Typst",
+        color: Some("#239dad"),
+        aliases: &["typ"],
+        extensions: &["typ"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['T', '∫', '📐', '📜'],
+    },
+    LanguageInfo {
+        language: LanguageType::V,
+        name: "V",
+        color: Some("#4f87c4"),
+        aliases: &["vlang"],
+        extensions: &["v", "vsh", "vv"],
+        filenames: &["v.mod"],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['V', 'V', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Vala,
+        name: "Vala",
+        color: Some("#a56de2"),
+        aliases: &[],
+        extensions: &["vala", "vapi"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-vala",
+        glyphs: ['V', 'V', '🟣', '🟪'],
+    },
+    LanguageInfo {
+        language: LanguageType::Verilog,
+        name: "Verilog",
+        color: Some("#b2b7f8"),
+        aliases: &[],
+        extensions: &["v", "veo"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-verilog",
+        glyphs: ['V', 'V', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::Vhdl,
+        name: "VHDL",
+        color: Some("#adb2cb"),
+        aliases: &[],
+        extensions: &["vhdl", "vhd", "vhf", "vhi", "vho", "vhs", "vht", "vhw"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-vhdl",
+        glyphs: ['V', 'V', '🔵', '🟦'],
+    },
+    LanguageInfo {
+        language: LanguageType::VimScript,
+        name: "Vim Script",
+        color: Some("#199f4b"),
+        aliases: &["vim", "viml", "nvim", "vimscript"],
+        extensions: &["vim", "vba", "vimrc", "vmb"],
+        filenames: &[
+            ".exrc", ".gvimrc", ".nvimrc", ".vimrc", "_vimrc", "gvimrc", "nvimrc", "vimrc",
+        ],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['V', ':', '📗', '📝'],
+    },
+    LanguageInfo {
+        language: LanguageType::VisualBasic,
+        name: "Visual Basic .NET",
+        color: Some("#945db7"),
+        aliases: &["visual basic", "vbnet", "vb .net", "vb.net"],
+        extensions: &["vb", "vbhtml"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-vb",
+        glyphs: ['V', 'V', '🟣', '🟪'],
+    },
+    LanguageInfo {
+        language: LanguageType::Vue,
+        name: "Vue",
+        color: Some("#41b883"),
+        aliases: &[],
+        extensions: &["vue"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/x-vue",
+        glyphs: ['V', '▼', '🟢', '💚'],
+    },
+    LanguageInfo {
+        language: LanguageType::WebAssembly,
+        name: "WebAssembly",
+        color: Some("#04133b"),
+        aliases: &["wast", "wasm"],
+        extensions: &["wast", "wat"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['W', '◇', '🟦', '🧩'],
+    },
+    LanguageInfo {
+        language: LanguageType::Xslt,
+        name: "XSLT",
+        color: Some("#EB8CEB"),
+        aliases: &["xsl"],
+        extensions: &["xslt", "xsl"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "application/xslt+xml",
+        glyphs: ['X', '<', '📄', '🗃'],
+    },
+    LanguageInfo {
+        language: LanguageType::Zig,
+        name: "Zig",
+        color: Some("#ec915c"),
+        aliases: &[],
+        extensions: &["zig", "zig.zon"],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['Z', '⚡', '⚡', '🦎'],
+    },
+    LanguageInfo {
+        language: LanguageType::Code,
+        name: "Code",
+        color: None,
+        aliases: &[],
+        extensions: &[],
+        filenames: &[],
+        interpreters: &[],
+        mime_type: "text/plain",
+        glyphs: ['C', '<', '💻', '⌨'],
+    },
+];
+
+/// Lowercase extension to language
+pub(super) const EXTENSIONS: &[(&str, LanguageType)] = &[
+    ("4dform", LanguageType::Json),
+    ("4dproject", LanguageType::Json),
+    ("6pl", LanguageType::Raku),
+    ("6pm", LanguageType::Raku),
+    ("_coffee", LanguageType::CoffeeScript),
+    ("_js", LanguageType::JavaScript),
+    ("a51", LanguageType::Assembly),
+    ("ada", LanguageType::Ada),
+    ("adb", LanguageType::Ada),
+    ("adml", LanguageType::Xml),
+    ("admx", LanguageType::Xml),
+    ("adoc", LanguageType::AsciiDoc),
+    ("adp", LanguageType::Tcl),
+    ("ads", LanguageType::Ada),
+    ("agda", LanguageType::Agda),
+    ("al", LanguageType::Perl),
+    ("ant", LanguageType::Xml),
+    ("app", LanguageType::Erlang),
+    ("app.src", LanguageType::Erlang),
+    ("asc", LanguageType::AsciiDoc),
+    ("asciidoc", LanguageType::AsciiDoc),
+    ("asd", LanguageType::CommonLisp),
+    ("asm", LanguageType::Assembly),
+    ("astro", LanguageType::Astro),
+    ("auk", LanguageType::Awk),
+    ("aux", LanguageType::Tex),
+    ("avsc", LanguageType::Json),
+    ("aw", LanguageType::Php),
+    ("awk", LanguageType::Awk),
+    ("axaml", LanguageType::Xml),
+    ("axml", LanguageType::Xml),
+    ("bash", LanguageType::Shell),
+    ("bat", LanguageType::Batchfile),
+    ("bats", LanguageType::Shell),
+    ("bb", LanguageType::Clojure),
+    ("bbx", LanguageType::Tex),
+    ("bones", LanguageType::JavaScript),
+    ("boot", LanguageType::Clojure),
+    ("builder", LanguageType::Ruby),
+    ("builds", LanguageType::Xml),
+    ("bzl", LanguageType::Starlark),
+    ("c", LanguageType::C),
+    ("c++", LanguageType::Cpp),
+    ("cats", LanguageType::C),
+    ("cbx", LanguageType::Tex),
+    ("cc", LanguageType::Cpp),
+    ("ccproj", LanguageType::Xml),
+    ("ccxml", LanguageType::Xml),
+    ("cfg", LanguageType::Ini),
+    ("cginc", LanguageType::Hlsl),
+    ("cjs", LanguageType::JavaScript),
+    ("cjsx", LanguageType::CoffeeScript),
+    ("cl", LanguageType::CommonLisp),
+    ("cl2", LanguageType::Clojure),
+    ("class", LanguageType::Java),
+    ("clixml", LanguageType::Xml),
+    ("clj", LanguageType::Clojure),
+    ("cljc", LanguageType::Clojure),
+    ("cljs", LanguageType::Clojure),
+    ("cljs.hl", LanguageType::Clojure),
+    ("cljscm", LanguageType::Clojure),
+    ("cljx", LanguageType::Clojure),
+    ("cls", LanguageType::Tex),
+    ("cmake", LanguageType::CMake),
+    ("cmake.in", LanguageType::CMake),
+    ("cmd", LanguageType::Batchfile),
+    ("cnf", LanguageType::Ini),
+    ("code-snippets", LanguageType::Json),
+    ("code-workspace", LanguageType::Json),
+    ("coffee", LanguageType::CoffeeScript),
+    ("command", LanguageType::Shell),
+    ("containerfile", LanguageType::Docker),
+    ("cp", LanguageType::Cpp),
+    ("cpp", LanguageType::Cpp),
+    ("cppm", LanguageType::Cpp),
+    ("cproject", LanguageType::Xml),
+    ("cql", LanguageType::Sql),
+    ("cr", LanguageType::Crystal),
+    ("cs", LanguageType::CSharp),
+    ("cs.pp", LanguageType::CSharp),
+    ("cscfg", LanguageType::Xml),
+    ("csdef", LanguageType::Xml),
+    ("csh", LanguageType::Shell),
+    ("csl", LanguageType::Xml),
+    ("csproj", LanguageType::Xml),
+    ("css", LanguageType::Css),
+    ("csv", LanguageType::Csv),
+    ("csx", LanguageType::CSharp),
+    ("ct", LanguageType::Xml),
+    ("ctp", LanguageType::Php),
+    ("cts", LanguageType::TypeScript),
+    ("cu", LanguageType::Cuda),
+    ("cue", LanguageType::Cue),
+    ("cuh", LanguageType::Cuda),
+    ("cxx", LanguageType::Cpp),
+    ("d", LanguageType::D),
+    ("d.cts", LanguageType::TypeScript),
+    ("d.mts", LanguageType::TypeScript),
+    ("d.ts", LanguageType::TypeScript),
+    ("dart", LanguageType::Dart),
+    ("ddl", LanguageType::Sql),
+    ("depproj", LanguageType::Xml),
+    ("dfm", LanguageType::Pascal),
+    ("dhall", LanguageType::Dhall),
+    ("di", LanguageType::D),
+    ("dita", LanguageType::Xml),
+    ("ditamap", LanguageType::Xml),
+    ("ditaval", LanguageType::Xml),
+    ("dll.config", LanguageType::Xml),
+    ("dockerfile", LanguageType::Docker),
+    ("dof", LanguageType::Ini),
+    ("dotsettings", LanguageType::Xml),
+    ("dpr", LanguageType::Pascal),
+    ("dtx", LanguageType::Tex),
+    ("editorconfig", LanguageType::EditorConfig),
+    ("el", LanguageType::EmacsLisp),
+    ("eliom", LanguageType::Ocaml),
+    ("eliomi", LanguageType::Ocaml),
+    ("elm", LanguageType::Elm),
+    ("emacs", LanguageType::EmacsLisp),
+    ("emacs.desktop", LanguageType::EmacsLisp),
+    ("env", LanguageType::Shell),
+    ("erl", LanguageType::Erlang),
+    ("es6", LanguageType::JavaScript),
+    ("escript", LanguageType::Erlang),
+    ("ex", LanguageType::Elixir),
+    ("exs", LanguageType::Elixir),
+    ("eye", LanguageType::Ruby),
+    ("f", LanguageType::Fortran),
+    ("f03", LanguageType::Fortran),
+    ("f08", LanguageType::Fortran),
+    ("f77", LanguageType::Fortran),
+    ("f90", LanguageType::Fortran),
+    ("f95", LanguageType::Fortran),
+    ("feature", LanguageType::Gherkin),
+    ("filters", LanguageType::Xml),
+    ("fish", LanguageType::Shell),
+    ("for", LanguageType::Fortran),
+    ("fpp", LanguageType::Fortran),
+    ("frg", LanguageType::Glsl),
+    ("frm", LanguageType::Ini),
+    ("fs", LanguageType::FSharp),
+    ("fsh", LanguageType::Glsl),
+    ("fsi", LanguageType::FSharp),
+    ("fsproj", LanguageType::Xml),
+    ("fsx", LanguageType::FSharp),
+    ("fx", LanguageType::Hlsl),
+    ("fxh", LanguageType::Hlsl),
+    ("fxml", LanguageType::Xml),
+    ("gawk", LanguageType::Awk),
+    ("gemspec", LanguageType::Ruby),
+    ("geojson", LanguageType::Json),
+    ("geom", LanguageType::Glsl),
+    ("gitattributes", LanguageType::Git),
+    ("gitconfig", LanguageType::Git),
+    ("gitignore", LanguageType::Git),
+    ("gitmodules", LanguageType::Git),
+    ("glade", LanguageType::Xml),
+    ("gleam", LanguageType::Gleam),
+    ("glsl", LanguageType::Glsl),
+    ("glslf", LanguageType::Glsl),
+    ("glslv", LanguageType::Glsl),
+    ("gltf", LanguageType::Json),
+    ("gml", LanguageType::Xml),
+    ("gmx", LanguageType::Xml),
+    ("go", LanguageType::Go),
+    ("god", LanguageType::Ruby),
+    ("gpx", LanguageType::Xml),
+    ("gql", LanguageType::GraphQl),
+    ("graphql", LanguageType::GraphQl),
+    ("graphqls", LanguageType::GraphQl),
+    ("groovy", LanguageType::Groovy),
+    ("grt", LanguageType::Groovy),
+    ("grxml", LanguageType::Xml),
+    ("gshader", LanguageType::Glsl),
+    ("gtpl", LanguageType::Groovy),
+    ("gvy", LanguageType::Groovy),
+    ("gyp", LanguageType::Python),
+    ("gypi", LanguageType::Python),
+    ("h", LanguageType::C),
+    ("h++", LanguageType::Cpp),
+    ("handlebars", LanguageType::Handlebars),
+    ("har", LanguageType::Json),
+    ("hbs", LanguageType::Handlebars),
+    ("hcl", LanguageType::Hcl),
+    ("hh", LanguageType::Cpp),
+    ("hic", LanguageType::Clojure),
+    ("hlsl", LanguageType::Hlsl),
+    ("hlsli", LanguageType::Hlsl),
+    ("hpp", LanguageType::Cpp),
+    ("hrl", LanguageType::Erlang),
+    ("hs", LanguageType::Haskell),
+    ("hs-boot", LanguageType::Haskell),
+    ("hsc", LanguageType::Haskell),
+    ("hta", LanguageType::Html),
+    ("htm", LanguageType::Html),
+    ("html", LanguageType::Html),
+    ("html.hl", LanguageType::Html),
+    ("hx", LanguageType::Haxe),
+    ("hxsl", LanguageType::Haxe),
+    ("hxx", LanguageType::Cpp),
+    ("hzp", LanguageType::Xml),
+    ("i", LanguageType::Assembly),
+    ("ice", LanguageType::Json),
+    ("iced", LanguageType::CoffeeScript),
+    ("idc", LanguageType::C),
+    ("idr", LanguageType::Idris),
+    ("iml", LanguageType::Xml),
+    ("ini", LanguageType::Ini),
+    ("inl", LanguageType::Cpp),
+    ("ino", LanguageType::Cpp),
+    ("ins", LanguageType::Tex),
+    ("ipp", LanguageType::Cpp),
+    ("ipynb", LanguageType::JupyterNotebook),
+    ("ivy", LanguageType::Xml),
+    ("ixx", LanguageType::Cpp),
+    ("jade", LanguageType::Pug),
+    ("jake", LanguageType::JavaScript),
+    ("jar", LanguageType::Java),
+    ("jav", LanguageType::Java),
+    ("java", LanguageType::Java),
+    ("javascript", LanguageType::JavaScript),
+    ("jbuilder", LanguageType::Ruby),
+    ("jelly", LanguageType::Xml),
+    ("jl", LanguageType::Julia),
+    ("js", LanguageType::JavaScript),
+    ("jsb", LanguageType::JavaScript),
+    ("jscad", LanguageType::JavaScript),
+    ("jsfl", LanguageType::JavaScript),
+    ("jsh", LanguageType::Java),
+    ("jslib", LanguageType::JavaScript),
+    ("jsm", LanguageType::JavaScript),
+    ("json", LanguageType::Json),
+    ("json-tmlanguage", LanguageType::Json),
+    ("json5", LanguageType::Json),
+    ("jsonc", LanguageType::Json),
+    ("jsonl", LanguageType::Json),
+    ("jsonnet", LanguageType::Jsonnet),
+    ("jspre", LanguageType::JavaScript),
+    ("jsproj", LanguageType::Xml),
+    ("jss", LanguageType::JavaScript),
+    ("jsx", LanguageType::JavaScript),
+    ("just", LanguageType::Just),
+    ("kl", LanguageType::Scheme),
+    ("kml", LanguageType::Xml),
+    ("kojo", LanguageType::Scala),
+    ("ksh", LanguageType::Shell),
+    ("kt", LanguageType::Kotlin),
+    ("ktm", LanguageType::Kotlin),
+    ("kts", LanguageType::Kotlin),
+    ("l", LanguageType::CommonLisp),
+    ("launch", LanguageType::Xml),
+    ("lbx", LanguageType::Tex),
+    ("lektorproject", LanguageType::Ini),
+    ("less", LanguageType::Less),
+    ("libsonnet", LanguageType::Jsonnet),
+    ("lidr", LanguageType::Idris),
+    ("linq", LanguageType::CSharp),
+    ("lisp", LanguageType::CommonLisp),
+    ("livemd", LanguageType::Markdown),
+    ("lmi", LanguageType::Python),
+    ("lpr", LanguageType::Pascal),
+    ("lsp", LanguageType::CommonLisp),
+    ("ltx", LanguageType::Tex),
+    ("lua", LanguageType::Lua),
+    ("m", LanguageType::ObjectiveC),
+    ("mak", LanguageType::Makefile),
+    ("make", LanguageType::Makefile),
+    ("makefile", LanguageType::Makefile),
+    ("markdown", LanguageType::Markdown),
+    ("matlab", LanguageType::Matlab),
+    ("mawk", LanguageType::Awk),
+    ("mcmeta", LanguageType::Json),
+    ("md", LanguageType::Markdown),
+    ("mdown", LanguageType::Markdown),
+    ("mdpolicy", LanguageType::Xml),
+    ("mdwn", LanguageType::Markdown),
+    ("mdx", LanguageType::Mdx),
+    ("min.css", LanguageType::Css),
+    ("min.js", LanguageType::JavaScript),
+    ("mir", LanguageType::Yaml),
+    ("mjml", LanguageType::Xml),
+    ("mjs", LanguageType::JavaScript),
+    ("mk", LanguageType::Makefile),
+    ("mkd", LanguageType::Markdown),
+    ("mkdn", LanguageType::Markdown),
+    ("mkdown", LanguageType::Markdown),
+    ("mkfile", LanguageType::Makefile),
+    ("mkii", LanguageType::Tex),
+    ("mkiv", LanguageType::Tex),
+    ("mkvi", LanguageType::Tex),
+    ("ml", LanguageType::Ocaml),
+    ("ml4", LanguageType::Ocaml),
+    ("mli", LanguageType::Ocaml),
+    ("mll", LanguageType::Ocaml),
+    ("mly", LanguageType::Ocaml),
+    ("mm", LanguageType::ObjectiveCpp),
+    ("mod", LanguageType::Go),
+    ("mojo", LanguageType::Mojo),
+    ("mspec", LanguageType::Ruby),
+    ("mts", LanguageType::TypeScript),
+    ("mxml", LanguageType::Xml),
+    ("mysql", LanguageType::Sql),
+    ("nas", LanguageType::Assembly),
+    ("nasm", LanguageType::Assembly),
+    ("natvis", LanguageType::Xml),
+    ("nawk", LanguageType::Awk),
+    ("ndproj", LanguageType::Xml),
+    ("nginx", LanguageType::Nginx),
+    ("nginxconf", LanguageType::Nginx),
+    ("nim", LanguageType::Nim),
+    ("nim.cfg", LanguageType::Nim),
+    ("nimble", LanguageType::Nim),
+    ("nimrod", LanguageType::Nim),
+    ("nims", LanguageType::Nim),
+    ("nix", LanguageType::Nix),
+    ("njs", LanguageType::JavaScript),
+    ("nomad", LanguageType::Hcl),
+    ("nproj", LanguageType::Xml),
+    ("nqp", LanguageType::Raku),
+    ("nse", LanguageType::Lua),
+    ("nu", LanguageType::Nushell),
+    ("nuspec", LanguageType::Xml),
+    ("ny", LanguageType::CommonLisp),
+    ("odd", LanguageType::Xml),
+    ("odin", LanguageType::Odin),
+    ("org", LanguageType::Org),
+    ("osm", LanguageType::Xml),
+    ("p6", LanguageType::Raku),
+    ("p6l", LanguageType::Raku),
+    ("p6m", LanguageType::Raku),
+    ("p8", LanguageType::Lua),
+    ("pac", LanguageType::JavaScript),
+    ("pas", LanguageType::Pascal),
+    ("pascal", LanguageType::Pascal),
+    ("pd_lua", LanguageType::Lua),
+    ("perl", LanguageType::Perl),
+    ("pgsql", LanguageType::Sql),
+    ("ph", LanguageType::Perl),
+    ("php", LanguageType::Php),
+    ("php3", LanguageType::Php),
+    ("php4", LanguageType::Php),
+    ("php5", LanguageType::Php),
+    ("phps", LanguageType::Php),
+    ("phpt", LanguageType::Php),
+    ("pkgproj", LanguageType::Xml),
+    ("pl", LanguageType::Perl),
+    ("pl6", LanguageType::Raku),
+    ("plist", LanguageType::Xml),
+    ("plt", LanguageType::Prolog),
+    ("pluginspec", LanguageType::Ruby),
+    ("plx", LanguageType::Perl),
+    ("pm", LanguageType::Perl),
+    ("pm6", LanguageType::Raku),
+    ("podsl", LanguageType::CommonLisp),
+    ("podspec", LanguageType::Ruby),
+    ("pp", LanguageType::Puppet),
+    ("prawn", LanguageType::Ruby),
+    ("prc", LanguageType::Sql),
+    ("prefs", LanguageType::Ini),
+    ("prisma", LanguageType::Prisma),
+    ("proj", LanguageType::Xml),
+    ("prolog", LanguageType::Prolog),
+    ("properties", LanguageType::Ini),
+    ("props", LanguageType::Xml),
+    ("proto", LanguageType::Protobuf),
+    ("ps1", LanguageType::PowerShell),
+    ("ps1xml", LanguageType::Xml),
+    ("psc1", LanguageType::Xml),
+    ("psd1", LanguageType::PowerShell),
+    ("psgi", LanguageType::Perl),
+    ("psm1", LanguageType::PowerShell),
+    ("pt", LanguageType::Xml),
+    ("pug", LanguageType::Pug),
+    ("purs", LanguageType::PureScript),
+    ("py", LanguageType::Python),
+    ("py3", LanguageType::Python),
+    ("pyc", LanguageType::Python),
+    ("pyd", LanguageType::Python),
+    ("pyde", LanguageType::Python),
+    ("pyi", LanguageType::Python),
+    ("pyo", LanguageType::Python),
+    ("pyp", LanguageType::Python),
+    ("pyt", LanguageType::Python),
+    ("pyw", LanguageType::Python),
+    ("qhelp", LanguageType::Xml),
+    ("r", LanguageType::R),
+    ("rabl", LanguageType::Ruby),
+    ("rake", LanguageType::Ruby),
+    ("raku", LanguageType::Raku),
+    ("rakumod", LanguageType::Raku),
+    ("rb", LanguageType::Ruby),
+    ("rbi", LanguageType::Ruby),
+    ("rbuild", LanguageType::Ruby),
+    ("rbw", LanguageType::Ruby),
+    ("rbx", LanguageType::Ruby),
+    ("rbxs", LanguageType::Lua),
+    ("rchit", LanguageType::Glsl),
+    ("rd", LanguageType::R),
+    ("rdf", LanguageType::Xml),
+    ("reek", LanguageType::Yaml),
+    ("rest", LanguageType::ReStructuredText),
+    ("rest.txt", LanguageType::ReStructuredText),
+    ("resx", LanguageType::Xml),
+    ("rkt", LanguageType::Racket),
+    ("rktd", LanguageType::Racket),
+    ("rktl", LanguageType::Racket),
+    ("rmiss", LanguageType::Glsl),
+    ("rockspec", LanguageType::Lua),
+    ("ronn", LanguageType::Markdown),
+    ("rpy", LanguageType::Python),
+    ("rs", LanguageType::Rust),
+    ("rs.in", LanguageType::Rust),
+    ("rss", LanguageType::Xml),
+    ("rst", LanguageType::ReStructuredText),
+    ("rst.txt", LanguageType::ReStructuredText),
+    ("rsx", LanguageType::R),
+    ("ru", LanguageType::Ruby),
+    ("ruby", LanguageType::Ruby),
+    ("rviz", LanguageType::Yaml),
+    ("s", LanguageType::Assembly),
+    ("sarif", LanguageType::Json),
+    ("sass", LanguageType::Sass),
+    ("sbt", LanguageType::Scala),
+    ("sc", LanguageType::Scala),
+    ("scala", LanguageType::Scala),
+    ("scd", LanguageType::Markdown),
+    ("scm", LanguageType::Scheme),
+    ("scrbl", LanguageType::Racket),
+    ("scss", LanguageType::Scss),
+    ("scxml", LanguageType::Xml),
+    ("sdc", LanguageType::Tcl),
+    ("sexp", LanguageType::CommonLisp),
+    ("sfproj", LanguageType::Xml),
+    ("sh", LanguageType::Shell),
+    ("sh.in", LanguageType::Shell),
+    ("shader", LanguageType::Glsl),
+    ("shproj", LanguageType::Xml),
+    ("sjs", LanguageType::JavaScript),
+    ("sld", LanguageType::Scheme),
+    ("sls", LanguageType::Scheme),
+    ("sol", LanguageType::Solidity),
+    ("sps", LanguageType::Scheme),
+    ("sql", LanguageType::Sql),
+    ("sqlite", LanguageType::Sql),
+    ("srdf", LanguageType::Xml),
+    ("ss", LanguageType::Scheme),
+    ("ssjs", LanguageType::JavaScript),
+    ("star", LanguageType::Starlark),
+    ("story", LanguageType::Gherkin),
+    ("storyboard", LanguageType::Xml),
+    ("sty", LanguageType::Tex),
+    ("sublime-build", LanguageType::Json),
+    ("sublime-commands", LanguageType::Json),
+    ("sublime-completions", LanguageType::Json),
+    ("sublime-keymap", LanguageType::Json),
+    ("sublime-macro", LanguageType::Json),
+    ("sublime-menu", LanguageType::Json),
+    ("sublime-mousemap", LanguageType::Json),
+    ("sublime-project", LanguageType::Json),
+    ("sublime-settings", LanguageType::Json),
+    ("sublime-snippet", LanguageType::Xml),
+    ("sublime-syntax", LanguageType::Yaml),
+    ("sublime-theme", LanguageType::Json),
+    ("sublime-workspace", LanguageType::Json),
+    ("sublime_metrics", LanguageType::Json),
+    ("sublime_session", LanguageType::Json),
+    ("sum", LanguageType::Go),
+    ("sv", LanguageType::SystemVerilog),
+    ("svelte", LanguageType::Svelte),
+    ("svg", LanguageType::Svg),
+    ("svh", LanguageType::SystemVerilog),
+    ("swift", LanguageType::Swift),
+    ("syntax", LanguageType::Yaml),
+    ("tab", LanguageType::Sql),
+    ("tac", LanguageType::Python),
+    ("tact", LanguageType::Json),
+    ("targets", LanguageType::Xml),
+    ("tcc", LanguageType::Cpp),
+    ("tcl", LanguageType::Tcl),
+    ("tcl.in", LanguageType::Tcl),
+    ("tcsh", LanguageType::Shell),
+    ("tesc", LanguageType::Glsl),
+    ("tese", LanguageType::Glsl),
+    ("tex", LanguageType::Tex),
+    ("tf", LanguageType::Hcl),
+    ("tfstate", LanguageType::Json),
+    ("tfstate.backup", LanguageType::Json),
+    ("tfvars", LanguageType::Hcl),
+    ("thor", LanguageType::Ruby),
+    ("thrift", LanguageType::Thrift),
+    ("tm", LanguageType::Tcl),
+    ("tml", LanguageType::Xml),
+    ("tmux", LanguageType::Shell),
+    ("toc", LanguageType::Tex),
+    ("toml", LanguageType::Toml),
+    ("tool", LanguageType::Shell),
+    ("topojson", LanguageType::Json),
+    ("tpp", LanguageType::Cpp),
+    ("trigger", LanguageType::Shell),
+    ("ts", LanguageType::TypeScript),
+    ("tsx", LanguageType::TypeScript),
+    ("txx", LanguageType::Cpp),
+    ("typ", LanguageType::Typst),
+    ("udf", LanguageType::Sql),
+    ("ui", LanguageType::Xml),
+    ("urdf", LanguageType::Xml),
+    ("url", LanguageType::Ini),
+    ("ux", LanguageType::Xml),
+    ("vala", LanguageType::Vala),
+    ("vapi", LanguageType::Vala),
+    ("vb", LanguageType::VisualBasic),
+    ("vba", LanguageType::VimScript),
+    ("vbhtml", LanguageType::VisualBasic),
+    ("vbproj", LanguageType::Xml),
+    ("vcxproj", LanguageType::Xml),
+    ("veo", LanguageType::Verilog),
+    ("vert", LanguageType::Glsl),
+    ("vh", LanguageType::SystemVerilog),
+    ("vhd", LanguageType::Vhdl),
+    ("vhdl", LanguageType::Vhdl),
+    ("vhf", LanguageType::Vhdl),
+    ("vhi", LanguageType::Vhdl),
+    ("vho", LanguageType::Vhdl),
+    ("vhost", LanguageType::Nginx),
+    ("vhs", LanguageType::Vhdl),
+    ("vht", LanguageType::Vhdl),
+    ("vhw", LanguageType::Vhdl),
+    ("vim", LanguageType::VimScript),
+    ("vimrc", LanguageType::VimScript),
+    ("viw", LanguageType::Sql),
+    ("vmb", LanguageType::VimScript),
+    ("vrx", LanguageType::Glsl),
+    ("vs", LanguageType::Glsl),
+    ("vshader", LanguageType::Glsl),
+    ("vsixmanifest", LanguageType::Xml),
+    ("vssettings", LanguageType::Xml),
+    ("vstemplate", LanguageType::Xml),
+    ("vue", LanguageType::Vue),
+    ("vv", LanguageType::V),
+    ("vxml", LanguageType::Xml),
+    ("wast", LanguageType::WebAssembly),
+    ("wat", LanguageType::WebAssembly),
+    ("watchr", LanguageType::Ruby),
+    ("webapp", LanguageType::Json),
+    ("webmanifest", LanguageType::Json),
+    ("wixproj", LanguageType::Xml),
+    ("wlua", LanguageType::Lua),
+    ("workbook", LanguageType::Markdown),
+    ("wrap", LanguageType::Meson),
+    ("wsdl", LanguageType::Xml),
+    ("wsf", LanguageType::Xml),
+    ("wsgi", LanguageType::Python),
+    ("wxi", LanguageType::Xml),
+    ("wxl", LanguageType::Xml),
+    ("wxs", LanguageType::Xml),
+    ("x3d", LanguageType::Xml),
+    ("xacro", LanguageType::Xml),
+    ("xaml", LanguageType::Xml),
+    ("xdc", LanguageType::Tcl),
+    ("xht", LanguageType::Html),
+    ("xhtml", LanguageType::Html),
+    ("xib", LanguageType::Xml),
+    ("xlf", LanguageType::Xml),
+    ("xliff", LanguageType::Xml),
+    ("xmi", LanguageType::Xml),
+    ("xml", LanguageType::Xml),
+    ("xml.dist", LanguageType::Xml),
+    ("xmp", LanguageType::Xml),
+    ("xproj", LanguageType::Xml),
+    ("xpy", LanguageType::Python),
+    ("xrl", LanguageType::Erlang),
+    ("xsd", LanguageType::Xml),
+    ("xsjs", LanguageType::JavaScript),
+    ("xsjslib", LanguageType::JavaScript),
+    ("xsl", LanguageType::Xslt),
+    ("xslt", LanguageType::Xslt),
+    ("xspec", LanguageType::Xml),
+    ("xul", LanguageType::Xml),
+    ("yaml", LanguageType::Yaml),
+    ("yaml-tmlanguage", LanguageType::Yaml),
+    ("yaml.sed", LanguageType::Yaml),
+    ("yap", LanguageType::Prolog),
+    ("yml", LanguageType::Yaml),
+    ("yml.mysql", LanguageType::Yaml),
+    ("yrl", LanguageType::Erlang),
+    ("yy", LanguageType::Json),
+    ("yyp", LanguageType::Json),
+    ("zcml", LanguageType::Xml),
+    ("zig", LanguageType::Zig),
+    ("zig.zon", LanguageType::Zig),
+    ("zsh", LanguageType::Shell),
+    ("zsh-theme", LanguageType::Shell),
+];
+
+/// Lowercase file name to language
+pub(super) const FILENAMES: &[(&str, LanguageType)] = &[
+    (".abbrev_defs", LanguageType::EmacsLisp),
+    (".all-contributorsrc", LanguageType::Json),
+    (".arcconfig", LanguageType::Json),
+    (".atomignore", LanguageType::Git),
+    (".auto-changelog", LanguageType::Json),
+    (".babelignore", LanguageType::Git),
+    (".babelrc", LanguageType::Json),
+    (".bash_aliases", LanguageType::Shell),
+    (".bash_functions", LanguageType::Shell),
+    (".bash_history", LanguageType::Shell),
+    (".bash_logout", LanguageType::Shell),
+    (".bash_profile", LanguageType::Shell),
+    (".bashrc", LanguageType::Shell),
+    (".bzrignore", LanguageType::Git),
+    (".c8rc", LanguageType::Json),
+    (".clang-format", LanguageType::Yaml),
+    (".clang-tidy", LanguageType::Yaml),
+    (".clangd", LanguageType::Yaml),
+    (".classpath", LanguageType::Xml),
+    (".coffeelintignore", LanguageType::Git),
+    (".coveragerc", LanguageType::Ini),
+    (".cproject", LanguageType::Xml),
+    (".cshrc", LanguageType::Shell),
+    (".cvsignore", LanguageType::Git),
+    (".devcontainer.json", LanguageType::Json),
+    (".dockerignore", LanguageType::Git),
+    (".editorconfig", LanguageType::EditorConfig),
+    (".eleventyignore", LanguageType::Git),
+    (".emacs", LanguageType::EmacsLisp),
+    (".emacs.desktop", LanguageType::EmacsLisp),
+    (".envrc", LanguageType::Shell),
+    (".eslintignore", LanguageType::Git),
+    (".eslintrc.json", LanguageType::Json),
+    (".exrc", LanguageType::VimScript),
+    (".flake8", LanguageType::Ini),
+    (".flaskenv", LanguageType::Shell),
+    (".gclient", LanguageType::Python),
+    (".gemrc", LanguageType::Yaml),
+    (".gitattributes", LanguageType::Git),
+    (".gitconfig", LanguageType::Git),
+    (".gitignore", LanguageType::Git),
+    (".gitmodules", LanguageType::Git),
+    (".gnus", LanguageType::EmacsLisp),
+    (".gvimrc", LanguageType::VimScript),
+    (".htmlhintrc", LanguageType::Json),
+    (".imgbotconfig", LanguageType::Json),
+    (".irbrc", LanguageType::Ruby),
+    (".jscsrc", LanguageType::Json),
+    (".jshintrc", LanguageType::Json),
+    (".jslintrc", LanguageType::Json),
+    (".justfile", LanguageType::Just),
+    (".kshrc", LanguageType::Shell),
+    (".latexmkrc", LanguageType::Perl),
+    (".login", LanguageType::Shell),
+    (".luacheckrc", LanguageType::Lua),
+    (".markdownlintignore", LanguageType::Git),
+    (".nodemonignore", LanguageType::Git),
+    (".npmignore", LanguageType::Git),
+    (".nvimrc", LanguageType::VimScript),
+    (".nycrc", LanguageType::Json),
+    (".php", LanguageType::Php),
+    (".php_cs", LanguageType::Php),
+    (".php_cs.dist", LanguageType::Php),
+    (".prettierignore", LanguageType::Git),
+    (".profile", LanguageType::Shell),
+    (".project", LanguageType::Xml),
+    (".pryrc", LanguageType::Ruby),
+    (".pylintrc", LanguageType::Ini),
+    (".rprofile", LanguageType::R),
+    (".simplecov", LanguageType::Ruby),
+    (".spacemacs", LanguageType::EmacsLisp),
+    (".stylelintignore", LanguageType::Git),
+    (".swcrc", LanguageType::Json),
+    (".tern-config", LanguageType::Json),
+    (".tern-project", LanguageType::Json),
+    (".tmux.conf", LanguageType::Shell),
+    (".vercelignore", LanguageType::Git),
+    (".vimrc", LanguageType::VimScript),
+    (".viper", LanguageType::EmacsLisp),
+    (".vscodeignore", LanguageType::Git),
+    (".watchmanconfig", LanguageType::Json),
+    (".zlogin", LanguageType::Shell),
+    (".zlogout", LanguageType::Shell),
+    (".zprofile", LanguageType::Shell),
+    (".zshenv", LanguageType::Shell),
+    (".zshrc", LanguageType::Shell),
+    ("9fs", LanguageType::Shell),
+    ("_emacs", LanguageType::EmacsLisp),
+    ("_vimrc", LanguageType::VimScript),
+    ("abbrev_defs", LanguageType::EmacsLisp),
+    ("ack", LanguageType::Perl),
+    ("api-extractor.json", LanguageType::Json),
+    ("app.config", LanguageType::Xml),
+    ("appraisals", LanguageType::Ruby),
+    ("bash_aliases", LanguageType::Shell),
+    ("bash_logout", LanguageType::Shell),
+    ("bash_profile", LanguageType::Shell),
+    ("bashrc", LanguageType::Shell),
+    ("berksfile", LanguageType::Ruby),
+    ("brewfile", LanguageType::Ruby),
+    ("bsdmakefile", LanguageType::Makefile),
+    ("buck", LanguageType::Starlark),
+    ("build", LanguageType::Starlark),
+    ("build.bazel", LanguageType::Starlark),
+    ("buildfile", LanguageType::Ruby),
+    ("buildozer.spec", LanguageType::Ini),
+    ("cakefile", LanguageType::CoffeeScript),
+    ("capfile", LanguageType::Ruby),
+    ("cargo.lock", LanguageType::Toml),
+    ("cargo.toml.orig", LanguageType::Toml),
+    ("cask", LanguageType::EmacsLisp),
+    ("citation.cff", LanguageType::Yaml),
+    ("cmakelists.txt", LanguageType::CMake),
+    ("composer.lock", LanguageType::Json),
+    ("containerfile", LanguageType::Docker),
+    ("contents.lr", LanguageType::Markdown),
+    ("cpanfile", LanguageType::Perl),
+    ("cshrc", LanguageType::Shell),
+    ("dangerfile", LanguageType::Ruby),
+    ("deliverfile", LanguageType::Ruby),
+    ("deno.lock", LanguageType::Json),
+    ("deps", LanguageType::Python),
+    ("devcontainer.json", LanguageType::Json),
+    ("dockerfile", LanguageType::Docker),
+    ("emakefile", LanguageType::Erlang),
+    ("expr-dist", LanguageType::R),
+    ("fastfile", LanguageType::Ruby),
+    ("flake.lock", LanguageType::Json),
+    ("gemfile", LanguageType::Ruby),
+    ("gitignore-global", LanguageType::Git),
+    ("gitignore_global", LanguageType::Git),
+    ("glide.lock", LanguageType::Yaml),
+    ("gnumakefile", LanguageType::Makefile),
+    ("go.mod", LanguageType::Go),
+    ("go.sum", LanguageType::Go),
+    ("go.work", LanguageType::Go),
+    ("go.work.sum", LanguageType::Go),
+    ("gopkg.lock", LanguageType::Toml),
+    ("gradlew", LanguageType::Shell),
+    ("guardfile", LanguageType::Ruby),
+    ("gvimrc", LanguageType::VimScript),
+    ("hosts", LanguageType::Ini),
+    ("jakefile", LanguageType::JavaScript),
+    ("jarfile", LanguageType::Ruby),
+    ("jenkinsfile", LanguageType::Groovy),
+    ("jsconfig.json", LanguageType::Json),
+    ("justfile", LanguageType::Just),
+    ("kbuild", LanguageType::Makefile),
+    ("kshrc", LanguageType::Shell),
+    ("language-configuration.json", LanguageType::Json),
+    ("latexmkrc", LanguageType::Perl),
+    ("login", LanguageType::Shell),
+    ("makefile", LanguageType::Makefile),
+    ("makefile.am", LanguageType::Makefile),
+    ("makefile.boot", LanguageType::Makefile),
+    ("makefile.frag", LanguageType::Makefile),
+    ("makefile.in", LanguageType::Makefile),
+    ("makefile.inc", LanguageType::Makefile),
+    ("makefile.pl", LanguageType::Perl),
+    ("makefile.sco", LanguageType::Makefile),
+    ("makefile.wat", LanguageType::Makefile),
+    ("man", LanguageType::Shell),
+    ("mavenfile", LanguageType::Ruby),
+    ("mcmod.info", LanguageType::Json),
+    ("meson.build", LanguageType::Meson),
+    ("meson.options", LanguageType::Meson),
+    ("meson_options.txt", LanguageType::Meson),
+    ("mix.lock", LanguageType::Elixir),
+    ("mkfile", LanguageType::Makefile),
+    ("module.bazel", LanguageType::Starlark),
+    ("module.bazel.lock", LanguageType::Json),
+    ("modulefile", LanguageType::Puppet),
+    ("nginx.conf", LanguageType::Nginx),
+    ("nim.cfg", LanguageType::Nim),
+    ("notebook", LanguageType::JupyterNotebook),
+    ("nuget.config", LanguageType::Xml),
+    ("nvimrc", LanguageType::VimScript),
+    ("owh", LanguageType::Tcl),
+    ("packages.config", LanguageType::Xml),
+    ("pdm.lock", LanguageType::Toml),
+    ("phakefile", LanguageType::Php),
+    ("pipfile", LanguageType::Toml),
+    ("pipfile.lock", LanguageType::Json),
+    ("pixi.lock", LanguageType::Yaml),
+    ("pkgbuild", LanguageType::Shell),
+    ("podfile", LanguageType::Ruby),
+    ("poetry.lock", LanguageType::Toml),
+    ("profile", LanguageType::Shell),
+    ("project.ede", LanguageType::EmacsLisp),
+    ("puppetfile", LanguageType::Ruby),
+    ("pylintrc", LanguageType::Ini),
+    ("rakefile", LanguageType::Ruby),
+    ("rebar.config", LanguageType::Erlang),
+    ("rebar.config.lock", LanguageType::Erlang),
+    ("rebar.lock", LanguageType::Erlang),
+    ("rexfile", LanguageType::Perl),
+    ("riemann.config", LanguageType::Clojure),
+    ("sconscript", LanguageType::Python),
+    ("sconstruct", LanguageType::Python),
+    ("settings.stylecop", LanguageType::Xml),
+    ("snapfile", LanguageType::Ruby),
+    ("starfield", LanguageType::Tcl),
+    ("steepfile", LanguageType::Ruby),
+    ("thorfile", LanguageType::Ruby),
+    ("tiltfile", LanguageType::Starlark),
+    ("tmux.conf", LanguageType::Shell),
+    ("tsconfig.json", LanguageType::Json),
+    ("tslint.json", LanguageType::Json),
+    ("uv.lock", LanguageType::Toml),
+    ("v.mod", LanguageType::V),
+    ("vagrantfile", LanguageType::Ruby),
+    ("vimrc", LanguageType::VimScript),
+    ("vlcrc", LanguageType::Ini),
+    ("web.config", LanguageType::Xml),
+    ("web.debug.config", LanguageType::Xml),
+    ("web.release.config", LanguageType::Xml),
+    ("workspace", LanguageType::Starlark),
+    ("workspace.bazel", LanguageType::Starlark),
+    ("workspace.bzlmod", LanguageType::Starlark),
+    ("wscript", LanguageType::Python),
+    ("yarn.lock", LanguageType::Yaml),
+    ("zlogin", LanguageType::Shell),
+    ("zlogout", LanguageType::Shell),
+    ("zprofile", LanguageType::Shell),
+    ("zshenv", LanguageType::Shell),
+    ("zshrc", LanguageType::Shell),
+];
+
+/// Interpreter name to language
+pub(super) const INTERPRETERS: &[(&str, LanguageType)] = &[
+    ("ash", LanguageType::Shell),
+    ("awk", LanguageType::Awk),
+    ("bash", LanguageType::Shell),
+    ("bb", LanguageType::Clojure),
+    ("bigloo", LanguageType::Scheme),
+    ("bun", LanguageType::TypeScript),
+    ("cargo", LanguageType::Rust),
+    ("ccl", LanguageType::CommonLisp),
+    ("chakra", LanguageType::JavaScript),
+    ("chicken", LanguageType::Scheme),
+    ("clisp", LanguageType::CommonLisp),
+    ("coffee", LanguageType::CoffeeScript),
+    ("cperl", LanguageType::Perl),
+    ("crystal", LanguageType::Crystal),
+    ("csh", LanguageType::Shell),
+    ("csi", LanguageType::Scheme),
+    ("d8", LanguageType::JavaScript),
+    ("dart", LanguageType::Dart),
+    ("dash", LanguageType::Shell),
+    ("deno", LanguageType::TypeScript),
+    ("ecl", LanguageType::CommonLisp),
+    ("elixir", LanguageType::Elixir),
+    ("escript", LanguageType::Erlang),
+    ("fish", LanguageType::Shell),
+    ("gawk", LanguageType::Awk),
+    ("gjs", LanguageType::JavaScript),
+    ("gosh", LanguageType::Scheme),
+    ("groovy", LanguageType::Groovy),
+    ("guile", LanguageType::Scheme),
+    ("instantfpc", LanguageType::Pascal),
+    ("jruby", LanguageType::Ruby),
+    ("js", LanguageType::JavaScript),
+    ("julia", LanguageType::Julia),
+    ("ksh", LanguageType::Shell),
+    ("lisp", LanguageType::CommonLisp),
+    ("lua", LanguageType::Lua),
+    ("macruby", LanguageType::Ruby),
+    ("make", LanguageType::Makefile),
+    ("mawk", LanguageType::Awk),
+    ("mksh", LanguageType::Shell),
+    ("nawk", LanguageType::Awk),
+    ("node", LanguageType::JavaScript),
+    ("nodejs", LanguageType::JavaScript),
+    ("nu", LanguageType::Nushell),
+    ("ocaml", LanguageType::Ocaml),
+    ("ocamlrun", LanguageType::Ocaml),
+    ("ocamlscript", LanguageType::Ocaml),
+    ("pdksh", LanguageType::Shell),
+    ("perl", LanguageType::Perl),
+    ("perl6", LanguageType::Raku),
+    ("php", LanguageType::Php),
+    ("pwsh", LanguageType::PowerShell),
+    ("py", LanguageType::Python),
+    ("pypy", LanguageType::Python),
+    ("pypy3", LanguageType::Python),
+    ("python", LanguageType::Python),
+    ("python2", LanguageType::Python),
+    ("python3", LanguageType::Python),
+    ("qjs", LanguageType::JavaScript),
+    ("r6rs", LanguageType::Scheme),
+    ("racket", LanguageType::Racket),
+    ("rake", LanguageType::Ruby),
+    ("raku", LanguageType::Raku),
+    ("rakudo", LanguageType::Raku),
+    ("rbx", LanguageType::Ruby),
+    ("rc", LanguageType::Shell),
+    ("rdmd", LanguageType::D),
+    ("rhino", LanguageType::JavaScript),
+    ("rscript", LanguageType::R),
+    ("ruby", LanguageType::Ruby),
+    ("runghc", LanguageType::Haskell),
+    ("runhaskell", LanguageType::Haskell),
+    ("runhugs", LanguageType::Haskell),
+    ("rust-script", LanguageType::Rust),
+    ("sbcl", LanguageType::CommonLisp),
+    ("scala", LanguageType::Scala),
+    ("scheme", LanguageType::Scheme),
+    ("sh", LanguageType::Shell),
+    ("swipl", LanguageType::Prolog),
+    ("tcc", LanguageType::C),
+    ("tclsh", LanguageType::Tcl),
+    ("tcsh", LanguageType::Shell),
+    ("ts-node", LanguageType::TypeScript),
+    ("tsx", LanguageType::TypeScript),
+    ("uv", LanguageType::Python),
+    ("v8", LanguageType::JavaScript),
+    ("v8-shell", LanguageType::JavaScript),
+    ("wish", LanguageType::Tcl),
+    ("yap", LanguageType::Prolog),
+    ("zsh", LanguageType::Shell),
+];
+
+/// Lowercase name or alias to language
+pub(super) const NAMES: &[(&str, LanguageType)] = &[
+    ("ada", LanguageType::Ada),
+    ("ada2005", LanguageType::Ada),
+    ("ada95", LanguageType::Ada),
+    ("agda", LanguageType::Agda),
+    ("asciidoc", LanguageType::AsciiDoc),
+    ("asm", LanguageType::Assembly),
+    ("assembly", LanguageType::Assembly),
+    ("astro", LanguageType::Astro),
+    ("awk", LanguageType::Awk),
+    ("bash", LanguageType::Shell),
+    ("bat", LanguageType::Batchfile),
+    ("batch", LanguageType::Batchfile),
+    ("batchfile", LanguageType::Batchfile),
+    ("bazel", LanguageType::Starlark),
+    ("bsdmake", LanguageType::Makefile),
+    ("bzl", LanguageType::Starlark),
+    ("c", LanguageType::C),
+    ("c#", LanguageType::CSharp),
+    ("c++", LanguageType::Cpp),
+    ("cake", LanguageType::CSharp),
+    ("cakescript", LanguageType::CSharp),
+    ("clojure", LanguageType::Clojure),
+    ("cmake", LanguageType::CMake),
+    ("code", LanguageType::Code),
+    ("coffee", LanguageType::CoffeeScript),
+    ("coffee-script", LanguageType::CoffeeScript),
+    ("coffeescript", LanguageType::CoffeeScript),
+    ("common lisp", LanguageType::CommonLisp),
+    ("commonlisp", LanguageType::CommonLisp),
+    ("containerfile", LanguageType::Docker),
+    ("cperl", LanguageType::Perl),
+    ("cpp", LanguageType::Cpp),
+    ("crystal", LanguageType::Crystal),
+    ("csharp", LanguageType::CSharp),
+    ("css", LanguageType::Css),
+    ("csv", LanguageType::Csv),
+    ("cucumber", LanguageType::Gherkin),
+    ("cuda", LanguageType::Cuda),
+    ("cue", LanguageType::Cue),
+    ("d", LanguageType::D),
+    ("dart", LanguageType::Dart),
+    ("delphi", LanguageType::Pascal),
+    ("dhall", LanguageType::Dhall),
+    ("dlang", LanguageType::D),
+    ("docker", LanguageType::Docker),
+    ("dockerfile", LanguageType::Docker),
+    ("dosbatch", LanguageType::Batchfile),
+    ("dosini", LanguageType::Ini),
+    ("editor-config", LanguageType::EditorConfig),
+    ("editorconfig", LanguageType::EditorConfig),
+    ("elisp", LanguageType::EmacsLisp),
+    ("elixir", LanguageType::Elixir),
+    ("elm", LanguageType::Elm),
+    ("emacs", LanguageType::EmacsLisp),
+    ("emacs lisp", LanguageType::EmacsLisp),
+    ("emacslisp", LanguageType::EmacsLisp),
+    ("envrc", LanguageType::Shell),
+    ("erlang", LanguageType::Erlang),
+    ("f#", LanguageType::FSharp),
+    ("fortran", LanguageType::Fortran),
+    ("fsharp", LanguageType::FSharp),
+    ("geojson", LanguageType::Json),
+    ("gherkin", LanguageType::Gherkin),
+    ("git", LanguageType::Git),
+    ("git config", LanguageType::Git),
+    ("git-ignore", LanguageType::Git),
+    ("gitattributes", LanguageType::Git),
+    ("gitconfig", LanguageType::Git),
+    ("gitignore", LanguageType::Git),
+    ("gitmodules", LanguageType::Git),
+    ("gleam", LanguageType::Gleam),
+    ("glsl", LanguageType::Glsl),
+    ("go", LanguageType::Go),
+    ("go mod", LanguageType::Go),
+    ("go sum", LanguageType::Go),
+    ("go work", LanguageType::Go),
+    ("go work sum", LanguageType::Go),
+    ("go.mod", LanguageType::Go),
+    ("go.sum", LanguageType::Go),
+    ("go.work", LanguageType::Go),
+    ("go.work.sum", LanguageType::Go),
+    ("golang", LanguageType::Go),
+    ("graphql", LanguageType::GraphQl),
+    ("groovy", LanguageType::Groovy),
+    ("handlebars", LanguageType::Handlebars),
+    ("hashicorp configuration language", LanguageType::Hcl),
+    ("haskell", LanguageType::Haskell),
+    ("haxe", LanguageType::Haxe),
+    ("hbs", LanguageType::Handlebars),
+    ("hcl", LanguageType::Hcl),
+    ("hlsl", LanguageType::Hlsl),
+    ("html", LanguageType::Html),
+    ("htmlbars", LanguageType::Handlebars),
+    ("idris", LanguageType::Idris),
+    ("ignore", LanguageType::Git),
+    ("inc", LanguageType::Php),
+    ("ini", LanguageType::Ini),
+    ("ipython notebook", LanguageType::JupyterNotebook),
+    ("java", LanguageType::Java),
+    ("javascript", LanguageType::JavaScript),
+    ("jruby", LanguageType::Ruby),
+    ("js", LanguageType::JavaScript),
+    ("json", LanguageType::Json),
+    ("jsonc", LanguageType::Json),
+    ("jsonl", LanguageType::Json),
+    ("jsonnet", LanguageType::Jsonnet),
+    ("julia", LanguageType::Julia),
+    ("jupyter notebook", LanguageType::JupyterNotebook),
+    ("jupyternotebook", LanguageType::JupyterNotebook),
+    ("just", LanguageType::Just),
+    ("justfile", LanguageType::Just),
+    ("kotlin", LanguageType::Kotlin),
+    ("latex", LanguageType::Tex),
+    ("less", LanguageType::Less),
+    ("less-css", LanguageType::Less),
+    ("lisp", LanguageType::CommonLisp),
+    ("lua", LanguageType::Lua),
+    ("macruby", LanguageType::Ruby),
+    ("make", LanguageType::Makefile),
+    ("makefile", LanguageType::Makefile),
+    ("markdown", LanguageType::Markdown),
+    ("matlab", LanguageType::Matlab),
+    ("md", LanguageType::Markdown),
+    ("mdx", LanguageType::Mdx),
+    ("meson", LanguageType::Meson),
+    ("mf", LanguageType::Makefile),
+    ("mojo", LanguageType::Mojo),
+    ("nasm", LanguageType::Assembly),
+    ("nginx", LanguageType::Nginx),
+    ("nginx configuration file", LanguageType::Nginx),
+    ("nim", LanguageType::Nim),
+    ("nix", LanguageType::Nix),
+    ("nixos", LanguageType::Nix),
+    ("node", LanguageType::JavaScript),
+    ("nu-script", LanguageType::Nushell),
+    ("nushell", LanguageType::Nushell),
+    ("nushell-script", LanguageType::Nushell),
+    ("nvim", LanguageType::VimScript),
+    ("obj-c", LanguageType::ObjectiveC),
+    ("obj-c++", LanguageType::ObjectiveCpp),
+    ("objc", LanguageType::ObjectiveC),
+    ("objc++", LanguageType::ObjectiveCpp),
+    ("objective-c", LanguageType::ObjectiveC),
+    ("objective-c++", LanguageType::ObjectiveCpp),
+    ("objectivec", LanguageType::ObjectiveC),
+    ("objectivec++", LanguageType::ObjectiveCpp),
+    ("objectivecpp", LanguageType::ObjectiveCpp),
+    ("objectpascal", LanguageType::Pascal),
+    ("ocaml", LanguageType::Ocaml),
+    ("octave", LanguageType::Matlab),
+    ("odin", LanguageType::Odin),
+    ("odin-lang", LanguageType::Odin),
+    ("odinlang", LanguageType::Odin),
+    ("org", LanguageType::Org),
+    ("pandoc", LanguageType::Markdown),
+    ("pascal", LanguageType::Pascal),
+    ("perl", LanguageType::Perl),
+    ("perl-6", LanguageType::Raku),
+    ("perl6", LanguageType::Raku),
+    ("php", LanguageType::Php),
+    ("posh", LanguageType::PowerShell),
+    ("powershell", LanguageType::PowerShell),
+    ("prisma", LanguageType::Prisma),
+    ("prolog", LanguageType::Prolog),
+    ("proto", LanguageType::Protobuf),
+    ("protobuf", LanguageType::Protobuf),
+    ("protocol buffer", LanguageType::Protobuf),
+    ("protocol buffers", LanguageType::Protobuf),
+    ("pug", LanguageType::Pug),
+    ("puppet", LanguageType::Puppet),
+    ("purescript", LanguageType::PureScript),
+    ("pwsh", LanguageType::PowerShell),
+    ("python", LanguageType::Python),
+    ("python3", LanguageType::Python),
+    ("r", LanguageType::R),
+    ("racket", LanguageType::Racket),
+    ("rake", LanguageType::Ruby),
+    ("raku", LanguageType::Raku),
+    ("rb", LanguageType::Ruby),
+    ("rbx", LanguageType::Ruby),
+    ("restructuredtext", LanguageType::ReStructuredText),
+    ("rs", LanguageType::Rust),
+    ("rscript", LanguageType::R),
+    ("rss", LanguageType::Xml),
+    ("rst", LanguageType::ReStructuredText),
+    ("ruby", LanguageType::Ruby),
+    ("rust", LanguageType::Rust),
+    ("rusthon", LanguageType::Python),
+    ("sarif", LanguageType::Json),
+    ("sass", LanguageType::Sass),
+    ("scala", LanguageType::Scala),
+    ("scheme", LanguageType::Scheme),
+    ("scss", LanguageType::Scss),
+    ("sh", LanguageType::Shell),
+    ("shell", LanguageType::Shell),
+    ("shell-script", LanguageType::Shell),
+    ("solidity", LanguageType::Solidity),
+    ("splus", LanguageType::R),
+    ("sql", LanguageType::Sql),
+    ("starlark", LanguageType::Starlark),
+    ("svelte", LanguageType::Svelte),
+    ("svg", LanguageType::Svg),
+    ("swift", LanguageType::Swift),
+    ("systemverilog", LanguageType::SystemVerilog),
+    ("tcl", LanguageType::Tcl),
+    ("terraform", LanguageType::Hcl),
+    ("tex", LanguageType::Tex),
+    ("thrift", LanguageType::Thrift),
+    ("toml", LanguageType::Toml),
+    ("topojson", LanguageType::Json),
+    ("ts", LanguageType::TypeScript),
+    ("typ", LanguageType::Typst),
+    ("typescript", LanguageType::TypeScript),
+    ("typst", LanguageType::Typst),
+    ("v", LanguageType::V),
+    ("vala", LanguageType::Vala),
+    ("vb .net", LanguageType::VisualBasic),
+    ("vb.net", LanguageType::VisualBasic),
+    ("vbnet", LanguageType::VisualBasic),
+    ("verilog", LanguageType::Verilog),
+    ("vhdl", LanguageType::Vhdl),
+    ("vim", LanguageType::VimScript),
+    ("vim script", LanguageType::VimScript),
+    ("viml", LanguageType::VimScript),
+    ("vimscript", LanguageType::VimScript),
+    ("visual basic", LanguageType::VisualBasic),
+    ("visual basic .net", LanguageType::VisualBasic),
+    ("visualbasic", LanguageType::VisualBasic),
+    ("vlang", LanguageType::V),
+    ("vue", LanguageType::Vue),
+    ("wasm", LanguageType::WebAssembly),
+    ("wast", LanguageType::WebAssembly),
+    ("webassembly", LanguageType::WebAssembly),
+    ("winbatch", LanguageType::Batchfile),
+    ("wsdl", LanguageType::Xml),
+    ("xhtml", LanguageType::Html),
+    ("xml", LanguageType::Xml),
+    ("xsd", LanguageType::Xml),
+    ("xsl", LanguageType::Xslt),
+    ("xslt", LanguageType::Xslt),
+    ("yaml", LanguageType::Yaml),
+    ("yml", LanguageType::Yaml),
+    ("zig", LanguageType::Zig),
+    ("zsh", LanguageType::Shell),
+];
@@ -0,0 +1,466 @@
+//! `.gitattributes` Linguist overrides and vendored/generated detection
+//!
+//! Repository browsers count languages and pick icons the way GitHub
+//! Linguist does: `linguist-language=…` overrides the detected language,
+//! and vendored, generated and documentation files are left out of the
+//! language statistics. Without attributes, vendored paths such as
+//! `vendor/` or `node_modules/` and generated files such as `*.pb.go` or
+//! sources starting with `// Code generated … DO NOT EDIT.` are recognized
+//! by heuristics.
+
+use super::{
+    classify_directory, get_file_type_from_filename, get_language_from_filename, DirectoryKind,
+    FileType, LanguageType,
+};
+use crate::unicode::glob::{normalize_path, path_match, wildcard_match};
+
+/// Lines at the start of a file searched for generated-code markers
+const GENERATED_HEADER_LINES: usize = 10;
+
+/// Value of a gitattributes attribute
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeValue {
+    /// `attr`
+    Set,
+    /// `-attr`
+    Unset,
+    /// `attr=value`
+    Value(String),
+}
+
+/// One `<pattern> <attributes>...` line
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AttributeRule {
+    pattern: String,
+    /// Attributes in line order; `None` for `!attr`, which makes it unspecified
+    attributes: Vec<(String, Option<AttributeValue>)>,
+}
+
+/// Parsed `.gitattributes` files
+///
+/// Patterns follow git: `*` and `?` stay within one path component, `**`
+/// spans several, a pattern without `/` matches the file name at any depth
+/// and later lines override earlier ones. A pattern naming a directory
+/// does not apply to the files below it; write `dir/**` for that. Lines git
+/// rejects, such as negative patterns, are skipped, as are macro
+/// definitions.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// let attributes = GitAttributes::parse(
+///     "*.pb.go linguist-generated\n\
+///      *.tpl linguist-language=Go\n\
+///      vendor/** -linguist-vendored\n",
+/// );
+/// let page = attributes.classify("web/page.tpl", None);
+/// assert_eq!(page.language, Some(LanguageType::Go));
+/// assert!(attributes.classify("api/service.pb.go", None).generated);
+/// assert!(!attributes.classify("vendor/lib/lib.go", None).vendored);
+/// assert!(attributes.classify("node_modules/left-pad/index.js", None).vendored);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitAttributes {
+    rules: Vec<AttributeRule>,
+}
+
+impl GitAttributes {
+    /// Create empty attributes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the `.gitattributes` file at the root of a repository
+    pub fn parse(text: &str) -> Self {
+        Self::new().with_file("", text)
+    }
+
+    /// Add a `.gitattributes` file found in `directory`, relative to the root
+    ///
+    /// Files deeper in the tree should be added after their parents, so that
+    /// their lines take precedence.
+    pub fn with_file(mut self, directory: &str, text: &str) -> Self {
+        let directory = normalize_path(directory);
+        let directory = directory.trim_end_matches('/');
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let pattern = words.next().unwrap_or_default();
+            if pattern.starts_with("[attr]") {
+                continue;
+            }
+            let attributes = words.map(parse_attribute).collect();
+            self.rules.push(AttributeRule {
+                pattern: scoped_pattern(directory, pattern),
+                attributes,
+            });
+        }
+        self
+    }
+
+    /// Value of an attribute for a path, `None` if unspecified
+    pub fn get(&self, path: &str, attribute: &str) -> Option<&AttributeValue> {
+        let path = normalize_path(path);
+        let mut value = None;
+        for rule in &self.rules {
+            if !path_match(&rule.pattern, &path) {
+                continue;
+            }
+            for (name, rule_value) in &rule.attributes {
+                if name == attribute {
+                    value = rule_value.as_ref();
+                }
+            }
+        }
+        value
+    }
+
+    /// Boolean attribute such as `linguist-vendored`, also accepting
+    /// `=true` and `=false`
+    fn flag(&self, path: &str, attribute: &str) -> Option<bool> {
+        match self.get(path, attribute)? {
+            AttributeValue::Set => Some(true),
+            AttributeValue::Unset => Some(false),
+            AttributeValue::Value(value) => match value.to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" => Some(true),
+                "false" | "0" | "no" => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    /// Language named by `linguist-language`, if set to a known language
+    ///
+    /// Linguist writes spaces in names as `-`, e.g. `Emacs-Lisp`.
+    pub fn language(&self, path: &str) -> Option<LanguageType> {
+        let AttributeValue::Value(name) = self.get(path, "linguist-language")? else {
+            return None;
+        };
+        LanguageType::from_name(name).or_else(|| LanguageType::from_name(&name.replace('-', " ")))
+    }
+
+    /// Classify a path, with its content if available
+    ///
+    /// `linguist-language`, `linguist-vendored`, `linguist-generated` and
+    /// `linguist-documentation` take precedence; otherwise the language
+    /// comes from [`get_language_from_filename`] and the flags from
+    /// [`is_vendored_path`], [`is_generated_path`] and, given the content,
+    /// [`is_generated_content`].
+    pub fn classify(&self, path: &str, content: Option<&[u8]>) -> LinguistClassification {
+        let path = normalize_path(path);
+        let name = path.rsplit('/').next().unwrap_or_default();
+        let generated = self.flag(&path, "linguist-generated").unwrap_or_else(|| {
+            is_generated_path(&path) || content.is_some_and(is_generated_content)
+        });
+        LinguistClassification {
+            file_type: get_file_type_from_filename(&path),
+            language: self
+                .language(&path)
+                .or_else(|| get_language_from_filename(&path)),
+            vendored: self
+                .flag(&path, "linguist-vendored")
+                .unwrap_or_else(|| is_vendored_path(&path)),
+            generated,
+            documentation: self
+                .flag(&path, "linguist-documentation")
+                .unwrap_or_else(|| is_documentation_path(&path, name)),
+        }
+    }
+}
+
+/// File and language type of a path in a repository, with its Linguist flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinguistClassification {
+    /// Kind of file
+    pub file_type: FileType,
+    /// Programming language, `linguist-language` if set
+    pub language: Option<LanguageType>,
+    /// Third-party code checked into the repository
+    pub vendored: bool,
+    /// Produced by a tool rather than written by hand
+    pub generated: bool,
+    /// Documentation or example code
+    pub documentation: bool,
+}
+
+impl LinguistClassification {
+    /// Whether the file counts towards language statistics, as on GitHub
+    pub fn counts_in_stats(&self) -> bool {
+        self.language.is_some() && !self.vendored && !self.generated && !self.documentation
+    }
+}
+
+/// Whether a path looks like vendored third-party code
+///
+/// True below dependency directories such as `vendor/`, `node_modules/`,
+/// `third_party/` or `.venv/`, and for bundled copies of common libraries
+/// and build tool wrappers.
+///
+/// ```rust
+/// use unicode_rs::file_types::is_vendored_path;
+///
+/// assert!(is_vendored_path("web/node_modules/react/index.js"));
+/// assert!(is_vendored_path("vendor/github.com/pkg/errors/errors.go"));
+/// assert!(is_vendored_path("static/jquery-3.7.1.js"));
+/// assert!(!is_vendored_path("src/vendor.rs"));
+/// ```
+pub fn is_vendored_path(path: &str) -> bool {
+    let path = normalize_path(path);
+    let mut components: Vec<&str> = path.split('/').collect();
+    let name = components.pop().unwrap_or_default().to_ascii_lowercase();
+    components.iter().any(|component| {
+        matches!(
+            classify_directory(component),
+            DirectoryKind::NodeModules | DirectoryKind::Vendor
+        )
+    }) || VENDORED_FILES
+        .iter()
+        .any(|pattern| wildcard_match(pattern, &name))
+}
+
+/// Whether a file name or path looks generated
+///
+/// True for lockfiles, minified files, source maps and the output of
+/// common code generators such as protoc, `build_runner` or Visual Studio
+/// designers.
+///
+/// ```rust
+/// use unicode_rs::file_types::is_generated_path;
+///
+/// assert!(is_generated_path("api/v1/service.pb.go"));
+/// assert!(is_generated_path("Cargo.lock"));
+/// assert!(is_generated_path("dist/app.min.js"));
+/// assert!(!is_generated_path("src/main.rs"));
+/// ```
+pub fn is_generated_path(path: &str) -> bool {
+    let path = normalize_path(path);
+    let name = path.rsplit('/').next().unwrap_or_default();
+    get_file_type_from_filename(name) == FileType::Lockfile
+        || path
+            .split('/')
+            .any(|component| component == "__generated__")
+        || GENERATED_FILES
+            .iter()
+            .any(|pattern| wildcard_match(pattern, &name.to_ascii_lowercase()))
+}
+
+/// Whether content starts with a generated-code header
+///
+/// The first lines are searched for markers such as Go's
+/// `// Code generated … DO NOT EDIT.`, `@generated` or
+/// "automatically generated".
+///
+/// ```rust
+/// use unicode_rs::file_types::is_generated_content;
+///
+/// let header = b"// Code generated by protoc-gen-go. DO NOT EDIT.\npackage api\n";
+/// assert!(is_generated_content(header));
+/// assert!(is_generated_content(b"# @generated by uv\n"));
+/// assert!(!is_generated_content(b"fn main() {}\n"));
+/// ```
+pub fn is_generated_content(content: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(content);
+    text.lines().take(GENERATED_HEADER_LINES).any(|line| {
+        let line = line.to_lowercase();
+        [
+            "@generated",
+            "autogenerated",
+            "auto-generated",
+            "automatically generated",
+        ]
+        .iter()
+        .any(|marker| line.contains(marker))
+            || (line.contains("generated") && line.contains("do not edit"))
+            || line.contains("generated by the protocol buffer compiler")
+    })
+}
+
+/// Whether a path looks like documentation, as Linguist's defaults
+fn is_documentation_path(path: &str, name: &str) -> bool {
+    let mut components = path.split('/');
+    components.next_back();
+    components.any(|component| {
+        matches!(
+            classify_directory(component),
+            DirectoryKind::Docs | DirectoryKind::Examples
+        )
+    }) || get_file_type_from_filename(name) == FileType::Documentation
+}
+
+/// Split one attribute of a gitattributes line
+fn parse_attribute(word: &str) -> (String, Option<AttributeValue>) {
+    if let Some(name) = word.strip_prefix('-') {
+        (name.to_string(), Some(AttributeValue::Unset))
+    } else if let Some(name) = word.strip_prefix('!') {
+        (name.to_string(), None)
+    } else if let Some((name, value)) = word.split_once('=') {
+        (
+            name.to_string(),
+            Some(AttributeValue::Value(value.to_string())),
+        )
+    } else {
+        (word.to_string(), Some(AttributeValue::Set))
+    }
+}
+
+/// Pattern of a `.gitattributes` file in `directory`, relative to the root
+fn scoped_pattern(directory: &str, pattern: &str) -> String {
+    if directory.is_empty() {
+        return pattern.to_string();
+    }
+    let relative = pattern.trim_start_matches('/');
+    if pattern.trim_end_matches('/').contains('/') {
+        format!("/{}/{}", directory, relative)
+    } else {
+        format!("/{}/**/{}", directory, relative)
+    }
+}
+
+/// Lowercase names of bundled libraries and tool wrappers
+const VENDORED_FILES: &[&str] = &[
+    "jquery*.js",
+    "bootstrap*.js",
+    "bootstrap*.css",
+    "modernizr*.js",
+    "angular*.min.js",
+    "gradlew",
+    "gradlew.bat",
+    "mvnw",
+    "mvnw.cmd",
+];
+
+/// Lowercase names of generated files
+const GENERATED_FILES: &[&str] = &[
+    "*.min.js",
+    "*.min.css",
+    "*.js.map",
+    "*.css.map",
+    "*.pb.go",
+    "*.pb.cc",
+    "*.pb.h",
+    "*_pb2.py",
+    "*_pb2_grpc.py",
+    "*_pb.js",
+    "*_pb.d.ts",
+    "*_grpc.pb.go",
+    "*.g.dart",
+    "*.freezed.dart",
+    "*.designer.cs",
+    "*.generated.*",
+    "*_generated.*",
+    "zz_generated*.go",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_precedence() {
+        let attributes = GitAttributes::parse(
+            "\
+# generated protobuf code
+*.pb.go linguist-generated=true
+api/legacy.pb.go -linguist-generated
+*.inc linguist-language=PHP
+docs/** linguist-documentation=false
+!*.md linguist-vendored
+[attr]binary -diff -merge -text
+third_party/** !linguist-vendored
+",
+        );
+        assert!(attributes.classify("api/v1/service.pb.go", None).generated);
+        assert!(!attributes.classify("api/legacy.pb.go", None).generated);
+        assert_eq!(attributes.language("lib/db.inc"), Some(LanguageType::Php));
+        let docs = attributes.classify("docs/guide.md", None);
+        assert!(!docs.documentation);
+        assert_eq!(docs.language, Some(LanguageType::Markdown));
+        assert!(docs.counts_in_stats());
+        // `!attr` makes the attribute unspecified, so the heuristic applies
+        assert_eq!(
+            attributes.get("third_party/zlib/zlib.h", "linguist-vendored"),
+            None
+        );
+        assert!(
+            attributes
+                .classify("third_party/zlib/zlib.h", None)
+                .vendored
+        );
+        assert_eq!(attributes.rules.len(), 5);
+    }
+
+    #[test]
+    fn test_nested_attributes() {
+        let attributes = GitAttributes::parse("*.js linguist-vendored\n").with_file(
+            "web",
+            "*.js -linguist-vendored\nstatic/*.js linguist-generated",
+        );
+        assert!(attributes.classify("lib/a.js", None).vendored);
+        assert!(!attributes.classify("web/src/a.js", None).vendored);
+        assert!(attributes.classify("web/static/a.js", None).generated);
+        assert!(!attributes.classify("static/a.js", None).generated);
+        assert_eq!(
+            GitAttributes::parse("*.el linguist-language=Emacs-Lisp").language("init.el"),
+            Some(LanguageType::EmacsLisp)
+        );
+    }
+
+    #[test]
+    fn test_directory_patterns() {
+        let attributes = GitAttributes::parse(
+            "src -linguist-vendored\nlib linguist-language=Go\n*.js linguist-generated\n",
+        );
+        // `git check-attr` reports all three as unspecified
+        assert_eq!(attributes.get("src/main.rs", "linguist-vendored"), None);
+        assert_eq!(attributes.language("lib/util.py"), None);
+        assert_eq!(
+            attributes.get("pkg/app.js/index.ts", "linguist-generated"),
+            None
+        );
+        assert!(attributes.classify("pkg/app.js", None).generated);
+        let recursive =
+            GitAttributes::parse("lib/** linguist-language=Go\nbuild/ linguist-generated");
+        assert_eq!(recursive.language("lib/util.py"), Some(LanguageType::Go));
+        assert_eq!(recursive.get("build/out.js", "linguist-generated"), None);
+    }
+
+    #[test]
+    fn test_heuristics() {
+        assert!(is_vendored_path(
+            ".venv/lib/python3.12/site-packages/six.py"
+        ));
+        assert!(is_vendored_path("Pods/Alamofire/Source/Request.swift"));
+        assert!(!is_vendored_path("node_modules.rs"));
+        assert!(is_generated_path("lib/models/user.freezed.dart"));
+        assert!(is_generated_path("src/__generated__/schema.ts"));
+        assert!(is_generated_path("yarn.lock"));
+        assert!(!is_generated_path("generated.rs"));
+        assert!(is_generated_content(
+            b"// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:\n"
+        ));
+        assert!(!is_generated_content(b"# Do not edit by hand\nkey = 1\n"));
+
+        let classification = GitAttributes::new().classify(
+            "internal/api.go",
+            Some(b"// Code generated by mockgen. DO NOT EDIT.\n"),
+        );
+        assert!(classification.generated);
+        assert!(!classification.counts_in_stats());
+        assert!(GitAttributes::new().classify("README", None).documentation);
+        assert!(
+            GitAttributes::new()
+                .classify("examples/demo.rs", None)
+                .documentation
+        );
+        assert!(
+            !GitAttributes::new()
+                .classify("src/lib.rs", None)
+                .documentation
+        );
+    }
+}
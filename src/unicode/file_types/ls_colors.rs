@@ -0,0 +1,438 @@
+//! `LS_COLORS` and dircolors support
+//!
+//! [`LsColors`] maps the [`FileClassification`] used for icons to the SGR
+//! style `ls` would use, so a file list gets its icon and its color from
+//! one lookup. Styles are looked up like GNU `ls` does: the file kind
+//! first (`di`, `ln`, `or`, `ex`, ...), then suffix patterns such as
+//! `*.tar`, then `fi`. `ln=target` colors a link like the file it points to.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{classify_path, FileClassification, FileType};
+
+/// Built-in indicators, as in the GNU dircolors defaults
+const DEFAULT_INDICATORS: &str = "rs=0:di=01;34:ln=01;36:mh=00:pi=40;33:so=01;35:do=01;35:\
+bd=40;33;01:cd=40;33;01:or=40;31;01:mi=00:su=37;41:sg=30;43:ca=00:tw=30;42:ow=34;42:st=37;44:\
+ex=01;32";
+
+/// Built-in styles for file types that dircolors colors by extension
+const DEFAULT_FILE_TYPES: &[(FileType, &str)] = &[
+    (FileType::Archive, "01;31"),
+    (FileType::Image, "01;35"),
+    (FileType::Video, "01;35"),
+    (FileType::Audio, "00;36"),
+    (FileType::Temporary, "00;90"),
+    (FileType::Backup, "00;90"),
+];
+
+/// dircolors keywords and their `LS_COLORS` indicators
+const DIRCOLORS_KEYWORDS: &[(&str, &str)] = &[
+    ("NORMAL", "no"),
+    ("NORM", "no"),
+    ("FILE", "fi"),
+    ("RESET", "rs"),
+    ("DIR", "di"),
+    ("LNK", "ln"),
+    ("LINK", "ln"),
+    ("SYMLINK", "ln"),
+    ("MULTIHARDLINK", "mh"),
+    ("FIFO", "pi"),
+    ("PIPE", "pi"),
+    ("SOCK", "so"),
+    ("DOOR", "do"),
+    ("BLK", "bd"),
+    ("BLOCK", "bd"),
+    ("CHR", "cd"),
+    ("CHAR", "cd"),
+    ("ORPHAN", "or"),
+    ("MISSING", "mi"),
+    ("SETUID", "su"),
+    ("SETGID", "sg"),
+    ("CAPABILITY", "ca"),
+    ("STICKY_OTHER_WRITABLE", "tw"),
+    ("OTHER_WRITABLE", "ow"),
+    ("STICKY", "st"),
+    ("EXEC", "ex"),
+];
+
+/// dircolors keywords that do not set a color
+const DIRCOLORS_IGNORED: &[&str] = &["TERM", "COLORTERM", "COLOR", "OPTIONS", "EIGHTBIT"];
+
+/// Parsed `LS_COLORS` value or dircolors database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LsColors {
+    /// Two-letter indicators such as `di` and their styles
+    indicators: HashMap<String, String>,
+    /// Lowercase suffixes of `*suffix` patterns, in definition order
+    suffixes: Vec<(String, String)>,
+    /// Styles by file type, used when no suffix matches
+    file_types: HashMap<FileType, String>,
+    /// `ln=target`: color links like the file they point to
+    link_as_target: bool,
+}
+
+impl Default for LsColors {
+    /// Built-in colors close to the GNU defaults
+    fn default() -> Self {
+        let mut colors = Self::parse(DEFAULT_INDICATORS).expect("valid default LS_COLORS");
+        colors.file_types = DEFAULT_FILE_TYPES
+            .iter()
+            .map(|&(file_type, style)| (file_type, style.to_string()))
+            .collect();
+        colors
+    }
+}
+
+impl LsColors {
+    /// Read `LS_COLORS`, falling back to the built-in colors if it is unset
+    ///
+    /// Entries [`LsColors::parse`] would reject are skipped, so one value
+    /// this crate does not understand keeps the rest of the user's colors.
+    pub fn from_env() -> Self {
+        match std::env::var("LS_COLORS") {
+            Ok(value) if !value.is_empty() => Self::parse_lenient(&value),
+            _ => Self::default(),
+        }
+    }
+
+    /// Parse an `LS_COLORS` value, skipping invalid entries
+    fn parse_lenient(ls_colors: &str) -> Self {
+        let mut colors = Self::empty();
+        for entry in ls_colors.split(':') {
+            let _ = colors.insert_entry(entry);
+        }
+        colors
+    }
+
+    /// Parse an `LS_COLORS` value such as `di=01;34:*.tar=01;31`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::*;
+    ///
+    /// let colors = LsColors::parse("di=01;34:ex=01;32:*.tar.gz=01;31").unwrap();
+    /// let archive = FileClassification {
+    ///     file_type: FileType::Archive,
+    ///     language: None,
+    ///     broken_link: false,
+    /// };
+    /// assert_eq!(colors.style("backup.TAR.GZ", &archive), Some("01;31"));
+    /// ```
+    pub fn parse(ls_colors: &str) -> Result<Self, String> {
+        let mut colors = Self::empty();
+        for entry in ls_colors.split(':') {
+            colors.insert_entry(entry)?;
+        }
+        Ok(colors)
+    }
+
+    /// Parse a dircolors database, the format of `dircolors --print-database`
+    ///
+    /// `TERM`, `COLORTERM` and `OPTIONS` lines are ignored, so every
+    /// terminal gets the same colors. As in dircolors, `#` starts a comment
+    /// at the start of a line or after whitespace, so `*#` is a pattern.
+    pub fn parse_dircolors(database: &str) -> Result<Self, String> {
+        let mut colors = Self::empty();
+        for (number, line) in database.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            let mut words = line.split_whitespace();
+            let (Some(keyword), Some(style)) = (words.next(), words.next()) else {
+                if line.is_empty() {
+                    continue;
+                }
+                return Err(format!("Line {}: missing color for '{}'", number + 1, line));
+            };
+            if DIRCOLORS_IGNORED.contains(&keyword.to_ascii_uppercase().as_str()) {
+                continue;
+            }
+            let key = if let Some(extension) = keyword.strip_prefix('.') {
+                format!("*.{}", extension)
+            } else if keyword.starts_with('*') {
+                keyword.to_string()
+            } else {
+                let upper = keyword.to_ascii_uppercase();
+                let Some(&(_, indicator)) = DIRCOLORS_KEYWORDS.iter().find(|(k, _)| *k == upper)
+                else {
+                    return Err(format!(
+                        "Line {}: unknown keyword '{}'",
+                        number + 1,
+                        keyword
+                    ));
+                };
+                indicator.to_string()
+            };
+            colors
+                .insert(&key, style)
+                .map_err(|err| format!("Line {}: {}", number + 1, err))?;
+        }
+        Ok(colors)
+    }
+
+    fn empty() -> Self {
+        Self {
+            indicators: HashMap::new(),
+            suffixes: Vec::new(),
+            file_types: HashMap::new(),
+            link_as_target: false,
+        }
+    }
+
+    /// Insert one `key=style` entry of an `LS_COLORS` value
+    fn insert_entry(&mut self, entry: &str) -> Result<(), String> {
+        if entry.is_empty() {
+            return Ok(());
+        }
+        let (key, style) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid LS_COLORS entry '{}'", entry))?;
+        self.insert(key, style)
+    }
+
+    fn insert(&mut self, key: &str, style: &str) -> Result<(), String> {
+        if key == "ln" && style == "target" {
+            self.indicators.remove(key);
+            self.link_as_target = true;
+            return Ok(());
+        }
+        if !style.bytes().all(|b| b.is_ascii_digit() || b == b';') {
+            return Err(format!("Invalid style '{}' for '{}'", style, key));
+        }
+        if let Some(suffix) = key.strip_prefix('*') {
+            self.suffixes
+                .push((suffix.to_lowercase(), style.to_string()));
+        } else if key.len() == 2 {
+            if key == "ln" {
+                self.link_as_target = false;
+            }
+            self.indicators.insert(key.to_string(), style.to_string());
+        } else {
+            return Err(format!("Invalid LS_COLORS key '{}'", key));
+        }
+        Ok(())
+    }
+
+    /// Style of an indicator such as `di`, if set
+    pub fn indicator(&self, indicator: &str) -> Option<&str> {
+        self.indicators
+            .get(indicator)
+            .map(String::as_str)
+            .filter(|style| !style.is_empty())
+    }
+
+    /// SGR style for a file name and its classification
+    ///
+    /// Directories, links, executables, sockets, FIFOs and devices use their
+    /// indicator; executables win over suffix patterns, as in `ls`. Broken
+    /// links use `or` if set. Other files use the last matching suffix
+    /// pattern, ignoring case, then the style of their file type, then `fi`.
+    ///
+    /// With `ln=target` a link has no style of its own; without a target to
+    /// go by, its name is matched like a file's. [`LsColors::style_path`]
+    /// follows the link instead.
+    pub fn style(&self, name: &str, classification: &FileClassification) -> Option<&str> {
+        let indicator = match classification.file_type {
+            FileType::SymLink if classification.broken_link => {
+                return self.indicator("or").or_else(|| self.indicator("ln"));
+            }
+            FileType::SymLink => Some("ln"),
+            FileType::Directory => Some("di"),
+            FileType::Executable => Some("ex"),
+            FileType::Socket => Some("so"),
+            FileType::Fifo => Some("pi"),
+            FileType::BlockDevice => Some("bd"),
+            FileType::CharDevice => Some("cd"),
+            _ => None,
+        };
+        if let Some(style) = indicator.and_then(|indicator| self.indicator(indicator)) {
+            return Some(style);
+        }
+
+        let name = name.to_lowercase();
+        self.suffixes
+            .iter()
+            .rev()
+            .find(|(suffix, _)| name.ends_with(suffix.as_str()))
+            .map(|(_, style)| style.as_str())
+            .or_else(|| {
+                self.file_types
+                    .get(&classification.file_type)
+                    .map(String::as_str)
+            })
+            .or_else(|| self.indicator("fi"))
+    }
+
+    /// Classify a path and look up its style in one step
+    ///
+    /// With `ln=target`, a link that resolves gets the style of its target.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use unicode_rs::file_types::*;
+    ///
+    /// let colors = LsColors::default();
+    /// let (classification, style) = colors.style_path(Path::new("src"));
+    /// assert_eq!(classification.file_type, FileType::Directory);
+    /// assert_eq!(style, Some("01;34"));
+    /// ```
+    pub fn style_path(&self, path: &Path) -> (FileClassification, Option<&str>) {
+        let classification = classify_path(path);
+        let target = match classification.file_type {
+            FileType::SymLink if self.link_as_target && !classification.broken_link => {
+                std::fs::canonicalize(path).ok()
+            }
+            _ => None,
+        };
+        let styled_path = target.as_deref().unwrap_or(path);
+        let name = styled_path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let style = match &target {
+            Some(target) => self.style(&name, &classify_path(target)),
+            None => self.style(&name, &classification),
+        };
+        (classification, style)
+    }
+
+    /// Wrap text in the SGR escape sequences of a style
+    pub fn paint(&self, text: &str, style: Option<&str>) -> String {
+        match style {
+            Some(style) => {
+                let reset = self.indicator("rs").unwrap_or("0");
+                format!("\x1b[{}m{}\x1b[{}m", style, text, reset)
+            }
+            None => text.to_string(),
+        }
+    }
+}
+
+/// Strip a dircolors comment: `#` at the start of the line or after whitespace
+fn strip_comment(line: &str) -> &str {
+    let comment = line.char_indices().find(|&(i, ch)| {
+        ch == '#'
+            && line[..i]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace)
+    });
+    match comment {
+        Some((i, _)) => &line[..i],
+        None => line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classification(file_type: FileType) -> FileClassification {
+        FileClassification {
+            file_type,
+            language: None,
+            broken_link: false,
+        }
+    }
+
+    #[test]
+    fn test_ls_colors_lookup() {
+        let colors = LsColors::parse(
+            "di=01;34:ln=01;36:or=40;31;01:ex=01;32:*.tar=01;31:*.TAR=00;33:*README=04",
+        )
+        .unwrap();
+        let file = classification(FileType::File);
+        assert_eq!(
+            colors.style("dir", &classification(FileType::Directory)),
+            Some("01;34")
+        );
+        assert_eq!(
+            colors.style("a.tar", &classification(FileType::Executable)),
+            Some("01;32")
+        );
+        // The later pattern wins
+        assert_eq!(colors.style("a.tar", &file), Some("00;33"));
+        assert_eq!(colors.style("README", &file), Some("04"));
+        assert_eq!(colors.style("main.rs", &file), None);
+
+        let broken = FileClassification {
+            broken_link: true,
+            ..classification(FileType::SymLink)
+        };
+        assert_eq!(colors.style("x", &broken), Some("40;31;01"));
+        assert_eq!(colors.paint("x", Some("01;34")), "\x1b[01;34mx\x1b[0m");
+
+        assert!(LsColors::parse("di").is_err());
+        assert!(LsColors::parse("di=bold").is_err());
+    }
+
+    #[test]
+    fn test_link_target() {
+        let colors = LsColors::parse("ln=target:di=01;34:*.tar=01;31").unwrap();
+        assert_eq!(colors.indicator("ln"), None);
+        let link = classification(FileType::SymLink);
+        assert_eq!(colors.style("a.tar", &link), Some("01;31"));
+        assert_eq!(colors.style("plain", &link), None);
+        // A later numeric value replaces `target`
+        let colors = LsColors::parse("ln=target:ln=01;36").unwrap();
+        assert_eq!(colors.style("a.tar", &link), Some("01;36"));
+
+        // `from_env` keeps what it understands
+        let colors = LsColors::parse_lenient("di=01;34:xx=bold:junk:ln=target:*.tar=01;31");
+        assert_eq!(colors.indicator("di"), Some("01;34"));
+        assert!(colors.link_as_target);
+        assert_eq!(colors.style("a.tar", &link), Some("01;31"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_link_target_path() {
+        let dir = std::env::temp_dir().join(format!("unicode-rs-ls-colors-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        std::os::unix::fs::symlink(dir.join("sub"), dir.join("link")).unwrap();
+
+        let colors = LsColors::parse("ln=target:di=01;34").unwrap();
+        let (classification, style) = colors.style_path(&dir.join("link"));
+        assert_eq!(classification.file_type, FileType::SymLink);
+        assert_eq!(style, Some("01;34"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dircolors_database() {
+        // `dircolors --print-database` of GNU coreutils 9.1
+        let database = include_str!("testdata/dircolors.txt");
+        let colors = LsColors::parse_dircolors(database).unwrap();
+        assert_eq!(colors.indicator("di"), Some("01;34"));
+        assert_eq!(colors.indicator("ln"), Some("01;36"));
+        assert_eq!(colors.indicator("ex"), Some("01;32"));
+        let file = classification(FileType::File);
+        assert_eq!(colors.style("a.tar", &file), Some("01;31"));
+        assert_eq!(colors.style("song.FLAC", &file), Some("00;36"));
+        assert_eq!(colors.style("notes~", &file), Some("00;90"));
+        assert_eq!(colors.style("#autosave#", &file), Some("00;90"));
+
+        let colors =
+            LsColors::parse_dircolors("LINK target # as the file\n  # comment\nDIR 01;34").unwrap();
+        assert!(colors.link_as_target);
+        assert_eq!(colors.indicator("di"), Some("01;34"));
+        assert!(LsColors::parse_dircolors("BOGUS 01").is_err());
+        assert!(LsColors::parse_dircolors("DIR").is_err());
+    }
+
+    #[test]
+    fn test_default_colors() {
+        let colors = LsColors::default();
+        assert_eq!(
+            colors.style("x.zip", &classification(FileType::Archive)),
+            Some("01;31")
+        );
+        assert_eq!(
+            colors.style("x.png", &classification(FileType::Image)),
+            Some("01;35")
+        );
+        assert_eq!(colors.style("x.rs", &classification(FileType::File)), None);
+        assert_eq!(colors.indicator("mi"), Some("00"));
+    }
+}
@@ -0,0 +1,345 @@
+//! MIME types
+//!
+//! Media, document and archive formats are looked up by extension in a table
+//! generated from shared-mime-info by `scripts/mime.py`; source files get the
+//! MIME type of their [`LanguageType`]. Parsing ignores case and parameters
+//! such as `charset`, and resolves the aliases shared-mime-info lists as well
+//! as common ones like `image/jpg`.
+
+use super::mime_types::{MIME_ALIASES, MIME_TYPES};
+use super::{
+    extensions, filenames, get_file_type_from_filename, get_language_from_filename,
+    FileClassification, FileType, LanguageType,
+};
+
+/// Fallback for binary files
+const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Fallback for text files
+const TEXT_PLAIN: &str = "text/plain";
+
+/// MIME type of a file name
+///
+/// Known media, document and archive extensions come first, longest first,
+/// then the language of the file, then its [`FileType`]. Compiled artifacts
+/// such as `.class` or `.pyc` get their own type rather than the language's.
+/// Well-known names such as `LICENSE` or `README` are text; any other file
+/// that nothing identifies is `application/octet-stream`.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// assert_eq!(mime_type_from_filename("photo.PNG"), "image/png");
+/// assert_eq!(mime_type_from_filename("backup.tar.gz"), "application/x-compressed-tar");
+/// assert_eq!(mime_type_from_filename("main.rs"), "text/rust");
+/// assert_eq!(mime_type_from_filename("index.d.ts"), "application/typescript");
+/// assert_eq!(mime_type_from_filename("report.pdf"), "application/pdf");
+/// assert_eq!(mime_type_from_filename("LICENSE-MIT"), "text/plain");
+/// assert_eq!(mime_type_from_filename("firmware.hex"), "application/octet-stream");
+/// ```
+pub fn mime_type_from_filename(filename: &str) -> &'static str {
+    let lowercase = filename.to_ascii_lowercase();
+    if let Some(mime) = extensions::extensions(&lowercase).find_map(|ext| lookup(MIME_TYPES, ext)) {
+        return mime;
+    }
+    if let Some(language) = get_language_from_filename(filename) {
+        return language.mime_type();
+    }
+    if filenames::filename_rule(filename).is_some() {
+        return TEXT_PLAIN;
+    }
+    get_file_type_from_filename(filename).mime_type()
+}
+
+/// Lowercase MIME type without parameters, with aliases resolved
+///
+/// ```rust
+/// use unicode_rs::file_types::normalize_mime;
+///
+/// assert_eq!(normalize_mime("Text/HTML; charset=UTF-8"), "text/html");
+/// assert_eq!(normalize_mime("image/jpg"), "image/jpeg");
+/// assert_eq!(normalize_mime("application/x-javascript"), "text/javascript");
+/// ```
+pub fn normalize_mime(mime: &str) -> String {
+    let essence = mime
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    match lookup(MIME_ALIASES, &essence) {
+        Some(canonical) => canonical.to_string(),
+        None => essence,
+    }
+}
+
+/// `Content-Type` header value for a MIME type
+///
+/// Text types get `charset=utf-8` unless `mime` names a charset already.
+///
+/// ```rust
+/// use unicode_rs::file_types::content_type;
+///
+/// assert_eq!(content_type("text/markdown"), "text/markdown; charset=utf-8");
+/// assert_eq!(content_type("text/html; charset=ISO-8859-1"), "text/html; charset=iso-8859-1");
+/// assert_eq!(content_type("image/png"), "image/png");
+/// ```
+pub fn content_type(mime: &str) -> String {
+    let essence = normalize_mime(mime);
+    let charset = mime.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_ascii_lowercase())
+    });
+    match charset {
+        Some(charset) => format!("{}; charset={}", essence, charset),
+        None if essence.starts_with("text/") => format!("{}; charset=utf-8", essence),
+        None => essence,
+    }
+}
+
+impl LanguageType {
+    /// MIME type of the language's files, `text/plain` if it has no specific one
+    pub fn mime_type(self) -> &'static str {
+        self.info().mime_type
+    }
+
+    /// Language of a MIME type, ignoring parameters and resolving aliases
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::LanguageType;
+    ///
+    /// assert_eq!(LanguageType::from_mime("text/x-rustsrc"), Some(LanguageType::Rust));
+    /// let json = LanguageType::from_mime("application/json; charset=utf-8");
+    /// assert_eq!(json, Some(LanguageType::Json));
+    /// assert_eq!(LanguageType::from_mime("text/plain"), None);
+    /// ```
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        let mime = normalize_mime(mime);
+        if mime == TEXT_PLAIN {
+            return None;
+        }
+        LanguageType::all().find(|language| language.mime_type() == mime)
+    }
+}
+
+impl FileType {
+    /// Generic MIME type of the file type
+    ///
+    /// Special files use the `inode/*` types of shared-mime-info. Media,
+    /// archives and documents have no single type, and hidden and unknown
+    /// files may be binary, so they fall back to `application/octet-stream`;
+    /// [`mime_type_from_filename`] knows the exact format and the well-known
+    /// text names.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            FileType::Directory => "inode/directory",
+            FileType::SymLink => "inode/symlink",
+            FileType::Socket => "inode/socket",
+            FileType::Fifo => "inode/fifo",
+            FileType::BlockDevice => "inode/blockdevice",
+            FileType::CharDevice => "inode/chardevice",
+            FileType::Executable => "application/x-executable",
+            FileType::Image
+            | FileType::Video
+            | FileType::Audio
+            | FileType::Archive
+            | FileType::Documentation
+            | FileType::Hidden
+            | FileType::Database
+            | FileType::Temporary
+            | FileType::Backup
+            | FileType::File => OCTET_STREAM,
+            FileType::Config | FileType::Log | FileType::BuildManifest | FileType::Lockfile => {
+                TEXT_PLAIN
+            }
+        }
+    }
+
+    /// File type of a MIME type, ignoring parameters and resolving aliases
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::FileType;
+    ///
+    /// assert_eq!(FileType::from_mime("image/png"), Some(FileType::Image));
+    /// assert_eq!(FileType::from_mime("application/x-zip-compressed"), Some(FileType::Archive));
+    /// assert_eq!(FileType::from_mime("inode/directory"), Some(FileType::Directory));
+    /// assert_eq!(FileType::from_mime("application/x-unknown"), None);
+    /// ```
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        let mime = normalize_mime(mime);
+        let file_type = match mime.as_str() {
+            "inode/directory" => FileType::Directory,
+            "inode/symlink" => FileType::SymLink,
+            "inode/socket" => FileType::Socket,
+            "inode/fifo" => FileType::Fifo,
+            "inode/blockdevice" => FileType::BlockDevice,
+            "inode/chardevice" => FileType::CharDevice,
+            "application/x-executable" => FileType::Executable,
+            _ => {
+                let by_extension = MIME_TYPES
+                    .iter()
+                    .filter(|(_, known)| *known == mime)
+                    .find_map(|(ext, _)| extensions::file_type_from_extension(ext));
+                return by_extension.or_else(|| match mime.split_once('/')?.0 {
+                    "image" => Some(FileType::Image),
+                    "video" => Some(FileType::Video),
+                    "audio" => Some(FileType::Audio),
+                    "text" => Some(FileType::File),
+                    _ => LanguageType::from_mime(&mime).map(|_| FileType::File),
+                });
+            }
+        };
+        Some(file_type)
+    }
+}
+
+impl FileClassification {
+    /// MIME type of the language if known, otherwise of the file type
+    ///
+    /// A classification does not keep the file name, so documents such as a
+    /// PDF get `application/octet-stream`; use [`mime_type_from_filename`]
+    /// for the exact format.
+    pub fn mime_type(&self) -> &'static str {
+        match self.language {
+            Some(language) => language.mime_type(),
+            None => self.file_type.mime_type(),
+        }
+    }
+
+    /// Classification of a MIME type, e.g. from a `Content-Type` header
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::*;
+    ///
+    /// let rust = FileClassification::from_mime("text/rust; charset=utf-8").unwrap();
+    /// assert_eq!(rust.file_type, FileType::File);
+    /// assert_eq!(rust.language, Some(LanguageType::Rust));
+    /// assert_eq!(rust.mime_type(), "text/rust");
+    /// ```
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        let language = LanguageType::from_mime(mime);
+        let file_type = FileType::from_mime(mime)?;
+        Some(FileClassification {
+            file_type,
+            language,
+            broken_link: false,
+        })
+    }
+}
+
+/// Binary search a sorted string table
+fn lookup(table: &[(&str, &'static str)], key: &str) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&key, |&(name, _)| name)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_sorted() {
+        for table in [MIME_TYPES, MIME_ALIASES] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        for &(alias, canonical) in MIME_ALIASES {
+            assert_eq!(lookup(MIME_ALIASES, canonical), None, "{}", alias);
+        }
+    }
+
+    #[test]
+    fn test_mime_round_trip() {
+        for language in LanguageType::all() {
+            let mime = language.mime_type();
+            if mime != TEXT_PLAIN {
+                assert_eq!(LanguageType::from_mime(mime), Some(language), "{}", mime);
+            }
+        }
+        for name in [
+            "a.jpg",
+            "a.mp3",
+            "a.mkv",
+            "a.zip",
+            "a.pdf",
+            "a.sqlite3",
+            "a.sqlite",
+        ] {
+            let mime = mime_type_from_filename(name);
+            let expected = get_file_type_from_filename(name);
+            assert_eq!(FileType::from_mime(mime), Some(expected), "{}", name);
+        }
+        assert_eq!(FileType::Directory.mime_type(), "inode/directory");
+        assert_eq!(FileType::from_mime("audio/x-wav"), Some(FileType::Audio));
+        assert_eq!(FileType::from_mime("font/woff2"), None);
+        assert_eq!(mime_type_from_filename("logo.svg"), "image/svg+xml");
+        assert_eq!(
+            mime_type_from_filename("setup.exe"),
+            "application/x-ms-dos-executable"
+        );
+        assert_eq!(mime_type_from_filename("draft.tmp"), OCTET_STREAM);
+    }
+
+    #[test]
+    fn test_shared_mime_info_table() {
+        assert_eq!(
+            mime_type_from_filename("Letter.DOCX"),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        );
+        assert_eq!(mime_type_from_filename("book.epub"), "application/epub+zip");
+        assert_eq!(mime_type_from_filename("config.yml"), "application/yaml");
+        // Compiled artifacts are not served as source
+        assert_eq!(
+            mime_type_from_filename("app.jar"),
+            "application/x-java-archive"
+        );
+        assert_eq!(mime_type_from_filename("Main.class"), "application/x-java");
+        assert_eq!(
+            mime_type_from_filename("mod.pyc"),
+            "application/x-python-bytecode"
+        );
+        assert_eq!(normalize_mime("application/x-yaml"), "application/yaml");
+        assert_eq!(normalize_mime("audio/x-wav"), "audio/x-wav");
+        assert_eq!(normalize_mime("audio/wav"), "audio/x-wav");
+    }
+
+    #[test]
+    fn test_documents_are_not_text() {
+        assert_eq!(FileType::Documentation.mime_type(), OCTET_STREAM);
+        assert_eq!(FileType::Hidden.mime_type(), OCTET_STREAM);
+        let pdf = FileClassification {
+            file_type: get_file_type_from_filename("report.pdf"),
+            language: get_language_from_filename("report.pdf"),
+            broken_link: false,
+        };
+        assert_eq!(pdf.mime_type(), OCTET_STREAM);
+        let directory = FileClassification::from_mime("inode/directory").unwrap();
+        assert_eq!(directory.mime_type(), "inode/directory");
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        for name in [
+            "LICENSE",
+            "README",
+            "CHANGELOG",
+            "COPYING",
+            "LICENSE-MIT",
+            "readme.txt",
+            "notes.text",
+            "Package.resolved",
+        ] {
+            assert_eq!(mime_type_from_filename(name), TEXT_PLAIN, "{}", name);
+        }
+        for name in ["x.bin", "x.dat", "x.dll", "firmware.hex", "blob"] {
+            assert_eq!(mime_type_from_filename(name), OCTET_STREAM, "{}", name);
+        }
+        assert_eq!(mime_type_from_filename("x.wasm"), "application/wasm");
+        assert_eq!(FileType::File.mime_type(), OCTET_STREAM);
+    }
+}
@@ -0,0 +1,1380 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/mime.py
+//
+// shared-mime-info version: 2.2.
+
+/// Lowercase extension to MIME type, for formats that are not languages
+pub(super) const MIME_TYPES: &[(&str, &str)] = &[
+    ("123", "application/vnd.lotus-1-2-3"),
+    ("32x", "application/x-genesis-32x-rom"),
+    ("3ds", "application/x-nintendo-3ds-rom"),
+    ("3dsx", "application/x-nintendo-3ds-executable"),
+    ("3g2", "video/3gpp2"),
+    ("3ga", "video/3gpp"),
+    ("3gp", "video/3gpp"),
+    ("3gp2", "video/3gpp2"),
+    ("3gpp", "video/3gpp"),
+    ("3gpp2", "video/3gpp2"),
+    ("3mf", "model/3mf"),
+    ("602", "application/x-t602"),
+    ("669", "audio/x-mod"),
+    ("7z", "application/x-7z-compressed"),
+    ("7z.001", "application/x-7z-compressed"),
+    ("a", "application/x-archive"),
+    ("a26", "application/x-atari-2600-rom"),
+    ("a78", "application/x-atari-7800-rom"),
+    ("aa", "audio/x-pn-audibleaudio"),
+    ("aac", "audio/aac"),
+    ("aax", "audio/vnd.audible.aax"),
+    ("abw", "application/x-abiword"),
+    ("abw.crashed", "application/x-abiword"),
+    ("abw.gz", "application/x-abiword"),
+    ("ac3", "audio/ac3"),
+    ("ace", "application/x-ace"),
+    ("adf", "application/x-amiga-disk-format"),
+    ("adts", "audio/aac"),
+    ("afm", "application/x-font-afm"),
+    ("ag", "image/x-applix-graphics"),
+    ("agb", "application/x-gba-rom"),
+    ("ai", "application/illustrator"),
+    ("aif", "audio/x-aiff"),
+    ("aifc", "audio/x-aifc"),
+    ("aiff", "audio/x-aiff"),
+    ("aiffc", "audio/x-aifc"),
+    ("alz", "application/x-alz"),
+    ("amr", "audio/AMR"),
+    ("amz", "audio/x-amzxml"),
+    ("ani", "application/x-navi-animation"),
+    ("anx", "application/annodex"),
+    ("ape", "audio/x-ape"),
+    ("apk", "application/vnd.android.package-archive"),
+    ("appimage", "application/vnd.appimage"),
+    ("ar", "application/x-archive"),
+    ("arj", "application/x-arj"),
+    ("arw", "image/x-sony-arw"),
+    ("as", "application/x-applix-spreadsheet"),
+    ("asar", "application/x-asar"),
+    ("asf", "application/vnd.ms-asf"),
+    ("asp", "application/x-asp"),
+    ("ass", "text/x-ssa"),
+    ("astc", "image/astc"),
+    ("asx", "audio/x-ms-asx"),
+    ("atom", "application/atom+xml"),
+    ("au", "audio/basic"),
+    ("automount", "text/x-systemd-unit"),
+    ("avf", "video/x-msvideo"),
+    ("avi", "video/x-msvideo"),
+    ("avif", "image/avif"),
+    ("avifs", "image/avif"),
+    ("awb", "audio/AMR-WB"),
+    ("axa", "audio/annodex"),
+    ("axv", "video/annodex"),
+    ("azw3", "application/vnd.amazon.mobi8-ebook"),
+    ("bak", "application/x-trash"),
+    ("bcpio", "application/x-bcpio"),
+    ("bdf", "application/x-font-bdf"),
+    ("bdm", "video/mp2t"),
+    ("bdmv", "video/mp2t"),
+    ("bib", "text/x-bibtex"),
+    ("bik", "video/vnd.radgamettools.bink"),
+    ("bk2", "video/vnd.radgamettools.bink"),
+    ("blend", "application/x-blender"),
+    ("blender", "application/x-blender"),
+    ("bmp", "image/bmp"),
+    ("bps", "application/x-bps-patch"),
+    ("bsdiff", "application/x-bsdiff"),
+    ("bz", "application/x-bzip"),
+    ("bz2", "application/x-bzip"),
+    ("cab", "application/vnd.ms-cab-compressed"),
+    ("cap", "application/vnd.tcpdump.pcap"),
+    ("cb7", "application/x-cb7"),
+    ("cbl", "text/x-cobol"),
+    ("cbr", "application/vnd.comicbook-rar"),
+    ("cbt", "application/x-cbt"),
+    ("cbz", "application/vnd.comicbook+zip"),
+    ("cci", "application/x-nintendo-3ds-rom"),
+    ("ccmx", "application/x-ccmx"),
+    ("cdf", "application/x-netcdf"),
+    ("cdi", "application/x-discjuggler-cd-image"),
+    ("cdr", "application/vnd.corel-draw"),
+    ("cer", "application/pkix-cert"),
+    ("cert", "application/x-x509-ca-cert"),
+    ("cgb", "application/x-gameboy-color-rom"),
+    ("cgm", "image/cgm"),
+    ("chd", "application/x-mame-chd"),
+    ("chm", "application/vnd.ms-htmlhelp"),
+    ("chrt", "application/x-kchart"),
+    ("class", "application/x-java"),
+    ("clpi", "video/mp2t"),
+    ("cob", "text/x-cobol"),
+    ("cpi", "video/mp2t"),
+    ("cpio", "application/x-cpio"),
+    ("cpio.gz", "application/x-cpio-compressed"),
+    ("cr2", "image/x-canon-cr2"),
+    ("cr3", "image/x-canon-cr3"),
+    ("crdownload", "application/x-partial-download"),
+    ("crl", "application/pkix-crl"),
+    ("crt", "application/x-x509-ca-cert"),
+    ("crw", "image/x-canon-crw"),
+    ("cso", "application/x-compressed-iso"),
+    ("csvs", "text/csv-schema"),
+    ("cur", "image/x-win-bitmap"),
+    ("cwk", "application/x-appleworks-document"),
+    ("dar", "application/x-dar"),
+    ("dbf", "application/x-dbf"),
+    ("dbk", "application/x-docbook+xml"),
+    ("dcl", "text/x-dcl"),
+    ("dcm", "application/dicom"),
+    ("dcr", "image/x-kodak-dcr"),
+    ("dds", "image/x-dds"),
+    ("deb", "application/vnd.debian.binary-package"),
+    ("der", "application/x-x509-ca-cert"),
+    ("desktop", "application/x-desktop"),
+    ("device", "text/x-systemd-unit"),
+    ("dff", "audio/x-dff"),
+    ("dia", "application/x-dia-diagram"),
+    ("dib", "image/bmp"),
+    ("diff", "text/x-patch"),
+    ("divx", "video/x-msvideo"),
+    ("djv", "image/vnd.djvu"),
+    ("djvu", "image/vnd.djvu"),
+    ("dmg", "application/x-apple-diskimage"),
+    ("dmp", "application/vnd.tcpdump.pcap"),
+    ("dng", "image/x-adobe-dng"),
+    ("doc", "application/msword"),
+    ("docbook", "application/x-docbook+xml"),
+    ("docm", "application/vnd.ms-word.document.macroEnabled.12"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    ("dot", "application/msword-template"),
+    ("dotm", "application/vnd.ms-word.template.macroEnabled.12"),
+    (
+        "dotx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.template",
+    ),
+    ("dsf", "audio/x-dsf"),
+    ("dsl", "text/x-dsl"),
+    ("dtd", "application/xml-dtd"),
+    ("dts", "audio/vnd.dts"),
+    ("dtshd", "audio/vnd.dts.hd"),
+    ("dv", "video/dv"),
+    ("dvi", "application/x-dvi"),
+    ("dvi.bz2", "application/x-bzdvi"),
+    ("dvi.gz", "application/x-gzdvi"),
+    ("dwg", "image/vnd.dwg"),
+    ("dxf", "image/vnd.dxf"),
+    ("e", "text/x-eiffel"),
+    ("egon", "application/x-egon"),
+    ("eif", "text/x-eiffel"),
+    ("emf", "image/emf"),
+    ("eml", "message/rfc822"),
+    ("emp", "application/vnd.emusic-emusic_package"),
+    ("ent", "application/xml-external-parsed-entity"),
+    ("eps", "image/x-eps"),
+    ("eps.bz2", "image/x-bzeps"),
+    ("eps.gz", "image/x-gzeps"),
+    ("epsf", "image/x-eps"),
+    ("epsf.bz2", "image/x-bzeps"),
+    ("epsf.gz", "image/x-gzeps"),
+    ("epsi", "image/x-eps"),
+    ("epsi.bz2", "image/x-bzeps"),
+    ("epsi.gz", "image/x-gzeps"),
+    ("epub", "application/epub+zip"),
+    ("es", "application/ecmascript"),
+    ("escn", "application/x-godot-scene"),
+    ("etheme", "application/x-e-theme"),
+    ("etx", "text/x-setext"),
+    ("exe", "application/x-ms-dos-executable"),
+    ("exr", "image/x-exr"),
+    ("ez", "application/andrew-inset"),
+    ("f4a", "audio/mp4"),
+    ("f4b", "audio/x-m4b"),
+    ("f4v", "video/mp4"),
+    ("fasl", "text/x-common-lisp"),
+    ("fb2", "application/x-fictionbook+xml"),
+    ("fb2.zip", "application/x-zip-compressed-fb2"),
+    ("fd", "application/x-raw-floppy-disk-image"),
+    ("fds", "application/x-fds-disk"),
+    ("fig", "image/x-xfig"),
+    ("fit", "application/fits"),
+    ("fits", "application/fits"),
+    ("fl", "application/x-fluid"),
+    ("flac", "audio/flac"),
+    ("flatpak", "application/vnd.flatpak"),
+    ("flatpakref", "application/vnd.flatpak.ref"),
+    ("flatpakrepo", "application/vnd.flatpak.repo"),
+    ("flc", "video/x-flic"),
+    ("fli", "video/x-flic"),
+    ("flv", "video/x-flv"),
+    ("flw", "application/x-kivio"),
+    ("fm", "application/vnd.framemaker"),
+    ("fo", "text/x-xslfo"),
+    (
+        "fodg",
+        "application/vnd.oasis.opendocument.graphics-flat-xml",
+    ),
+    (
+        "fodp",
+        "application/vnd.oasis.opendocument.presentation-flat-xml",
+    ),
+    (
+        "fods",
+        "application/vnd.oasis.opendocument.spreadsheet-flat-xml",
+    ),
+    ("fodt", "application/vnd.oasis.opendocument.text-flat-xml"),
+    ("fts", "application/fits"),
+    ("fxm", "video/x-javafx"),
+    ("g3", "image/g3fax"),
+    ("gb", "application/x-gameboy-rom"),
+    ("gba", "application/x-gba-rom"),
+    ("gbc", "application/x-gameboy-color-rom"),
+    ("gbr", "image/x-gimp-gbr"),
+    ("gcode", "text/x.gcode"),
+    ("gcrd", "text/vcard"),
+    ("gd", "application/x-gdscript"),
+    ("gdi", "application/x-gd-rom-cue"),
+    ("gdshader", "application/x-godot-shader"),
+    ("ged", "application/x-gedcom"),
+    ("gedcom", "application/x-gedcom"),
+    ("gem", "application/x-tar"),
+    ("gen", "application/x-genesis-rom"),
+    ("geo.json", "application/geo+json"),
+    ("gf", "application/x-tex-gf"),
+    ("gg", "application/x-gamegear-rom"),
+    ("gif", "image/gif"),
+    ("gih", "image/x-gimp-gih"),
+    ("glb", "model/gltf-binary"),
+    ("gmo", "application/x-gettext-translation"),
+    ("gnc", "application/x-gnucash"),
+    ("gnd", "application/gnunet-directory"),
+    ("gnucash", "application/x-gnucash"),
+    ("gnumeric", "application/x-gnumeric"),
+    ("gnuplot", "application/x-gnuplot"),
+    ("gp", "application/x-gnuplot"),
+    ("gpg", "application/pgp-encrypted"),
+    ("gplt", "application/x-gnuplot"),
+    ("gra", "application/x-graphite"),
+    ("gradle", "text/x-gradle"),
+    ("gs", "text/x-genie"),
+    ("gsf", "application/x-font-type1"),
+    ("gsh", "text/x-groovy"),
+    ("gsm", "audio/x-gsm"),
+    ("gtar", "application/x-tar"),
+    ("gv", "text/vnd.graphviz"),
+    ("gvp", "text/x-google-video-pointer"),
+    ("gx", "text/x-gcode-gx"),
+    ("gy", "text/x-groovy"),
+    ("gz", "application/gzip"),
+    ("h4", "application/x-hdf"),
+    ("h5", "application/x-hdf"),
+    ("hdf", "application/x-hdf"),
+    ("hdf4", "application/x-hdf"),
+    ("hdf5", "application/x-hdf"),
+    ("heic", "image/heif"),
+    ("heif", "image/heif"),
+    ("hfe", "application/x-hfe-floppy-image"),
+    ("hif", "image/heif"),
+    ("hlp", "application/winhlp"),
+    ("hp", "text/x-c++hdr"),
+    ("hpgl", "application/vnd.hp-hpgl"),
+    ("hwp", "application/x-hwp"),
+    ("hwt", "application/x-hwt"),
+    ("ica", "application/x-ica"),
+    ("icb", "image/x-tga"),
+    ("icc", "application/vnd.iccprofile"),
+    ("icm", "application/vnd.iccprofile"),
+    ("icns", "image/x-icns"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("ics", "text/calendar"),
+    ("idl", "text/x-idl"),
+    ("ief", "image/ief"),
+    ("iff", "image/x-ilbm"),
+    ("iges", "model/iges"),
+    ("igs", "model/iges"),
+    ("ilbm", "image/x-ilbm"),
+    ("ime", "text/x-iMelody"),
+    ("img", "application/x-raw-disk-image"),
+    ("img.xz", "application/x-raw-disk-image-xz-compressed"),
+    ("imy", "text/x-iMelody"),
+    ("info", "text/plain"),
+    ("ips", "application/x-ips-patch"),
+    ("iptables", "text/x-iptables"),
+    ("iso", "application/x-cd-image"),
+    ("iso9660", "application/x-cd-image"),
+    ("it", "audio/x-it"),
+    ("it87", "application/x-it87"),
+    ("j2c", "image/x-jp2-codestream"),
+    ("j2k", "image/x-jp2-codestream"),
+    ("jad", "text/vnd.sun.j2me.app-descriptor"),
+    ("jar", "application/x-java-archive"),
+    ("jceks", "application/x-java-jce-keystore"),
+    ("jks", "application/x-java-keystore"),
+    ("jng", "image/x-jng"),
+    ("jnlp", "application/x-java-jnlp-file"),
+    ("jp2", "image/jp2"),
+    ("jpc", "image/x-jp2-codestream"),
+    ("jpe", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("jpf", "image/jpx"),
+    ("jpg", "image/jpeg"),
+    ("jpg2", "image/jp2"),
+    ("jpgm", "image/jpm"),
+    ("jpm", "image/jpm"),
+    ("jpr", "application/x-jbuilder-project"),
+    ("jpx", "application/x-jbuilder-project"),
+    ("jrd", "application/jrd+json"),
+    ("json-patch", "application/json-patch+json"),
+    ("jsonld", "application/ld+json"),
+    ("jxl", "image/jxl"),
+    ("k25", "image/x-kodak-k25"),
+    ("k7", "application/x-thomson-cassette"),
+    ("kar", "audio/midi"),
+    ("karbon", "application/x-karbon"),
+    ("kdc", "image/x-kodak-kdc"),
+    ("kdelnk", "application/x-desktop"),
+    ("kexi", "application/x-kexiproject-sqlite2"),
+    ("kexic", "application/x-kexi-connectiondata"),
+    ("kexis", "application/x-kexiproject-shortcut"),
+    ("key", "application/vnd.apple.keynote"),
+    ("kfo", "application/x-kformula"),
+    ("kfx", "application/vnd.amazon.mobi8-ebook"),
+    ("kil", "application/x-killustrator"),
+    ("kino", "application/smil+xml"),
+    ("kmz", "application/vnd.google-earth.kmz"),
+    ("kon", "application/x-kontour"),
+    ("kpm", "application/x-kpovmodeler"),
+    ("kpr", "application/x-kpresenter"),
+    ("kpt", "application/x-kpresenter"),
+    ("kra", "application/x-krita"),
+    ("krz", "application/x-krita"),
+    ("ks", "application/x-java-keystore"),
+    ("ksp", "application/x-kspread"),
+    ("ksy", "text/x-kaitai-struct"),
+    ("ktx", "image/ktx"),
+    ("ktx2", "image/ktx2"),
+    ("kud", "application/x-kugar"),
+    ("kwd", "application/x-kword"),
+    ("kwt", "application/x-kword"),
+    ("la", "application/x-shared-library-la"),
+    ("latex", "text/x-tex"),
+    ("lbm", "image/x-ilbm"),
+    ("ldif", "text/x-ldif"),
+    ("lha", "application/x-lha"),
+    ("lhs", "text/x-literate-haskell"),
+    ("lhz", "application/x-lhz"),
+    ("lnx", "application/x-atari-lynx-rom"),
+    ("loas", "audio/usac"),
+    ("log", "text/x-log"),
+    ("lrv", "video/mp4"),
+    ("lrz", "application/x-lrzip"),
+    ("lwo", "image/x-lwo"),
+    ("lwob", "image/x-lwo"),
+    ("lwp", "application/vnd.lotus-wordpro"),
+    ("lws", "image/x-lws"),
+    ("ly", "text/x-lilypond"),
+    ("lyx", "application/x-lyx"),
+    ("lz", "application/x-lzip"),
+    ("lz4", "application/x-lz4"),
+    ("lzh", "application/x-lha"),
+    ("lzma", "application/x-lzma"),
+    ("lzo", "application/x-lzop"),
+    ("m15", "audio/x-mod"),
+    ("m1u", "video/vnd.mpegurl"),
+    ("m2t", "video/mp2t"),
+    ("m2ts", "video/mp2t"),
+    ("m3u", "audio/x-mpegurl"),
+    ("m3u8", "audio/x-mpegurl"),
+    ("m4", "application/x-m4"),
+    ("m4a", "audio/mp4"),
+    ("m4b", "audio/x-m4b"),
+    ("m4r", "audio/x-m4r"),
+    ("m4u", "video/vnd.mpegurl"),
+    ("m4v", "video/mp4"),
+    ("m7", "application/x-thomson-cartridge-memo7"),
+    ("mab", "application/x-markaby"),
+    ("man", "application/x-troff-man"),
+    ("manifest", "text/cache-manifest"),
+    ("mbox", "application/mbox"),
+    ("mc2", "text/vnd.senx.warpscript"),
+    ("mdb", "application/vnd.ms-access"),
+    ("mdi", "image/vnd.ms-modi"),
+    ("me", "text/x-troff-me"),
+    ("med", "audio/x-mod"),
+    ("meta4", "application/metalink4+xml"),
+    ("metalink", "application/metalink+xml"),
+    ("mgp", "application/x-magicpoint"),
+    ("mht", "application/x-mimearchive"),
+    ("mhtml", "application/x-mimearchive"),
+    ("mid", "audio/midi"),
+    ("midi", "audio/midi"),
+    ("mif", "application/x-mif"),
+    ("minipsf", "audio/x-minipsf"),
+    ("mj2", "video/mj2"),
+    ("mjp2", "video/mj2"),
+    ("mjpeg", "video/x-mjpeg"),
+    ("mjpg", "video/x-mjpeg"),
+    ("mk3d", "video/x-matroska-3d"),
+    ("mka", "audio/x-matroska"),
+    ("mkv", "video/x-matroska"),
+    ("mmf", "application/vnd.smaf"),
+    ("mml", "application/mathml+xml"),
+    ("mng", "video/x-mng"),
+    ("mo", "application/x-gettext-translation"),
+    ("mo3", "audio/x-mo3"),
+    ("mobi", "application/x-mobipocket-ebook"),
+    ("moc", "text/x-moc"),
+    ("mof", "text/x-mof"),
+    ("moov", "video/quicktime"),
+    ("mount", "text/x-systemd-unit"),
+    ("mov", "video/quicktime"),
+    ("movie", "video/x-sgi-movie"),
+    ("mp+", "audio/x-musepack"),
+    ("mp2", "audio/mp2"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("mpc", "audio/x-musepack"),
+    ("mpe", "video/mpeg"),
+    ("mpeg", "video/mpeg"),
+    ("mpg", "video/mpeg"),
+    ("mpga", "audio/mpeg"),
+    ("mpl", "text/x-mpl2"),
+    ("mpls", "video/mp2t"),
+    ("mpp", "audio/x-musepack"),
+    ("mrl", "text/x-mrml"),
+    ("mrml", "text/x-mrml"),
+    ("mrw", "image/x-minolta-mrw"),
+    ("ms", "text/x-troff-ms"),
+    ("msi", "application/x-msi"),
+    ("msod", "image/x-msod"),
+    ("msx", "application/x-msx-rom"),
+    ("mtl", "model/mtl"),
+    ("mtm", "audio/x-mod"),
+    ("mup", "text/x-mup"),
+    ("mxf", "application/mxf"),
+    ("mxmf", "audio/mobile-xmf"),
+    ("mxu", "video/vnd.mpegurl"),
+    ("n64", "application/x-n64-rom"),
+    ("nb", "application/mathematica"),
+    ("nc", "application/x-netcdf"),
+    ("nds", "application/x-nintendo-ds-rom"),
+    ("nef", "image/x-nikon-nef"),
+    ("nes", "application/x-nes-rom"),
+    ("nez", "application/x-nes-rom"),
+    ("nfo", "text/x-nfo"),
+    ("ngc", "application/x-neo-geo-pocket-color-rom"),
+    ("ngp", "application/x-neo-geo-pocket-rom"),
+    ("not", "text/x-mup"),
+    ("nrw", "image/x-nikon-nrw"),
+    ("nsc", "application/x-netshow-channel"),
+    ("nsv", "video/x-nsv"),
+    ("numbers", "application/vnd.apple.numbers"),
+    ("nzb", "application/x-nzb"),
+    ("o", "application/x-object"),
+    ("obj", "application/x-tgif"),
+    ("ocl", "text/x-ocl"),
+    ("oda", "application/oda"),
+    ("odb", "application/vnd.oasis.opendocument.database"),
+    ("odc", "application/vnd.oasis.opendocument.chart"),
+    ("odf", "application/vnd.oasis.opendocument.formula"),
+    ("odg", "application/vnd.oasis.opendocument.graphics"),
+    ("odi", "application/vnd.oasis.opendocument.image"),
+    ("odm", "application/vnd.oasis.opendocument.text-master"),
+    ("odp", "application/vnd.oasis.opendocument.presentation"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("odt", "application/vnd.oasis.opendocument.text"),
+    ("oga", "audio/ogg"),
+    ("ogg", "audio/ogg"),
+    ("ogm", "video/x-ogm+ogg"),
+    ("ogv", "video/ogg"),
+    ("ogx", "application/ogg"),
+    ("old", "application/x-trash"),
+    ("oleo", "application/x-oleo"),
+    ("ooc", "text/x-ooc"),
+    ("opml", "text/x-opml+xml"),
+    ("oprc", "application/vnd.palm"),
+    ("opus", "audio/ogg"),
+    ("ora", "image/openraster"),
+    ("orf", "image/x-olympus-orf"),
+    ("otc", "application/vnd.oasis.opendocument.chart-template"),
+    ("otf", "application/vnd.oasis.opendocument.formula-template"),
+    (
+        "otg",
+        "application/vnd.oasis.opendocument.graphics-template",
+    ),
+    ("oth", "application/vnd.oasis.opendocument.text-web"),
+    (
+        "otp",
+        "application/vnd.oasis.opendocument.presentation-template",
+    ),
+    (
+        "ots",
+        "application/vnd.oasis.opendocument.spreadsheet-template",
+    ),
+    ("ott", "application/vnd.oasis.opendocument.text-template"),
+    ("ova", "application/ovf"),
+    ("owl", "application/rdf+xml"),
+    ("owx", "application/owl+xml"),
+    ("oxps", "application/oxps"),
+    ("oxt", "application/vnd.openofficeorg.extension"),
+    ("p", "text/x-pascal"),
+    ("p10", "application/pkcs10"),
+    ("p12", "application/pkcs12"),
+    ("p65", "application/x-pagemaker"),
+    ("p7b", "application/x-pkcs7-certificates"),
+    ("p7c", "application/pkcs7-mime"),
+    ("p7m", "application/pkcs7-mime"),
+    ("p7s", "application/pkcs7-signature"),
+    ("p8e", "application/pkcs8-encrypted"),
+    ("pack", "application/x-java-pack200"),
+    ("pages", "application/vnd.apple.pages"),
+    ("pak", "application/x-pak"),
+    ("par2", "application/x-par2"),
+    ("part", "application/x-partial-download"),
+    ("pat", "image/x-gimp-pat"),
+    ("patch", "text/x-patch"),
+    ("path", "text/x-systemd-unit"),
+    ("pbm", "image/x-portable-bitmap"),
+    ("pcap", "application/vnd.tcpdump.pcap"),
+    ("pcd", "image/x-photo-cd"),
+    ("pce", "application/x-pc-engine-rom"),
+    ("pcf", "application/x-font-pcf"),
+    ("pcf.gz", "application/x-font-pcf"),
+    ("pcf.z", "application/x-font-pcf"),
+    ("pcl", "application/vnd.hp-pcl"),
+    ("pct", "image/x-pict"),
+    ("pcx", "image/vnd.zbrush.pcx"),
+    ("pdb", "application/x-aportisdoc"),
+    ("pdc", "application/x-aportisdoc"),
+    ("pdf", "application/pdf"),
+    ("pdf.bz2", "application/x-bzpdf"),
+    ("pdf.gz", "application/x-gzpdf"),
+    ("pdf.lz", "application/x-lzpdf"),
+    ("pdf.xz", "application/x-xzpdf"),
+    ("pef", "image/x-pentax-pef"),
+    ("pem", "application/x-x509-ca-cert"),
+    ("pfa", "application/x-font-type1"),
+    ("pfb", "application/x-font-type1"),
+    ("pfx", "application/pkcs12"),
+    ("pgm", "image/x-portable-graymap"),
+    ("pgn", "application/vnd.chess-pgn"),
+    ("pgp", "application/pgp-encrypted"),
+    ("pict", "image/x-pict"),
+    ("pict1", "image/x-pict"),
+    ("pict2", "image/x-pict"),
+    ("pk", "application/x-tex-pk"),
+    ("pkg", "application/x-xar"),
+    ("pkipath", "application/pkix-pkipath"),
+    ("pkpass", "application/vnd.apple.pkpass"),
+    ("pkr", "application/pgp-keys"),
+    ("pla", "audio/x-iriver-pla"),
+    ("pln", "application/x-planperfect"),
+    ("pls", "audio/x-scpls"),
+    ("pmd", "application/x-pagemaker"),
+    ("png", "image/png"),
+    ("pnm", "image/x-portable-anymap"),
+    ("pntg", "image/x-macpaint"),
+    ("po", "text/x-gettext-translation"),
+    ("pod", "application/x-perl"),
+    ("por", "application/x-spss-por"),
+    ("pot", "application/vnd.ms-powerpoint"),
+    (
+        "potm",
+        "application/vnd.ms-powerpoint.template.macroEnabled.12",
+    ),
+    (
+        "potx",
+        "application/vnd.openxmlformats-officedocument.presentationml.template",
+    ),
+    (
+        "ppam",
+        "application/vnd.ms-powerpoint.addin.macroEnabled.12",
+    ),
+    ("ppm", "image/x-portable-pixmap"),
+    ("pps", "application/vnd.ms-powerpoint"),
+    (
+        "ppsm",
+        "application/vnd.ms-powerpoint.slideshow.macroEnabled.12",
+    ),
+    (
+        "ppsx",
+        "application/vnd.openxmlformats-officedocument.presentationml.slideshow",
+    ),
+    ("ppt", "application/vnd.ms-powerpoint"),
+    (
+        "pptm",
+        "application/vnd.ms-powerpoint.presentation.macroEnabled.12",
+    ),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    ),
+    ("ppz", "application/vnd.ms-powerpoint"),
+    ("pqa", "application/vnd.palm"),
+    ("ps", "application/postscript"),
+    ("ps.bz2", "application/x-bzpostscript"),
+    ("ps.gz", "application/x-gzpostscript"),
+    ("psd", "image/vnd.adobe.photoshop"),
+    ("psf", "application/x-font-linux-psf"),
+    ("psf.gz", "application/x-gz-font-linux-psf"),
+    ("psflib", "audio/x-psflib"),
+    ("psid", "audio/prs.sid"),
+    ("psw", "application/x-pocket-word"),
+    ("pub", "application/vnd.ms-publisher"),
+    ("pw", "application/x-pw"),
+    ("py3x", "text/x-python"),
+    ("pyc", "application/x-python-bytecode"),
+    ("pyo", "application/x-python-bytecode"),
+    ("pys", "application/x-pyspread-bz-spreadsheet"),
+    ("pysu", "application/x-pyspread-spreadsheet"),
+    ("pyx", "text/x-python"),
+    ("qcow", "application/x-qemu-disk"),
+    ("qcow2", "application/x-qemu-disk"),
+    ("qd", "application/x-raw-floppy-disk-image"),
+    ("qed", "application/x-qed-disk"),
+    ("qif", "application/x-qw"),
+    ("qml", "text/x-qml"),
+    ("qmlproject", "text/x-qml"),
+    ("qmltypes", "text/x-qml"),
+    ("qp", "application/x-qpress"),
+    ("qs", "application/sparql-query"),
+    ("qt", "video/quicktime"),
+    ("qti", "application/x-qtiplot"),
+    ("qti.gz", "application/x-qtiplot"),
+    ("qtif", "image/x-quicktime"),
+    ("qtl", "application/x-quicktime-media-link"),
+    ("qtvr", "video/quicktime"),
+    ("ra", "audio/vnd.rn-realaudio"),
+    ("raf", "image/x-fuji-raf"),
+    ("ram", "application/ram"),
+    ("raml", "application/raml+yaml"),
+    ("rar", "application/vnd.rar"),
+    ("ras", "image/x-cmu-raster"),
+    ("raw", "image/x-panasonic-rw"),
+    ("raw-disk-image", "application/x-raw-disk-image"),
+    (
+        "raw-disk-image.xz",
+        "application/x-raw-disk-image-xz-compressed",
+    ),
+    ("rax", "audio/vnd.rn-realaudio"),
+    ("rdfs", "application/rdf+xml"),
+    ("rdoc", "text/plain"),
+    ("reg", "text/x-ms-regedit"),
+    ("rej", "text/x-reject"),
+    ("res", "application/x-godot-resource"),
+    ("rgb", "image/x-rgb"),
+    ("rle", "image/rle"),
+    ("rm", "application/vnd.rn-realmedia"),
+    ("rmj", "application/vnd.rn-realmedia"),
+    ("rmm", "application/vnd.rn-realmedia"),
+    ("rms", "application/vnd.rn-realmedia"),
+    ("rmvb", "application/vnd.rn-realmedia"),
+    ("rmx", "application/vnd.rn-realmedia"),
+    ("rnc", "application/relax-ng-compact-syntax"),
+    ("rng", "application/xml"),
+    ("roff", "text/troff"),
+    ("ros", "text/x-common-lisp"),
+    ("rp", "image/vnd.rn-realpix"),
+    ("rpm", "application/x-rpm"),
+    ("rt", "text/vnd.rn-realtext"),
+    ("rtf", "application/rtf"),
+    ("rtx", "text/richtext"),
+    ("rv", "video/vnd.rn-realvideo"),
+    ("rvx", "video/vnd.rn-realvideo"),
+    ("rw2", "image/x-panasonic-rw2"),
+    ("s3m", "audio/x-s3m"),
+    ("sage", "text/x-sagemath"),
+    ("sam", "application/x-amipro"),
+    ("sami", "application/x-sami"),
+    ("sap", "application/x-thomson-sap-image"),
+    ("sav", "application/x-spss-sav"),
+    ("scn", "application/x-godot-scene"),
+    ("scope", "text/x-systemd-unit"),
+    ("sda", "application/vnd.stardivision.draw"),
+    ("sdd", "application/vnd.stardivision.impress"),
+    ("sdp", "application/vnd.stardivision.impress"),
+    ("sds", "application/vnd.stardivision.chart"),
+    ("sdw", "application/vnd.stardivision.writer"),
+    ("service", "text/x-dbus-service"),
+    ("sfc", "application/vnd.nintendo.snes.rom"),
+    ("sg", "application/x-sg1000-rom"),
+    ("sgb", "application/x-gameboy-rom"),
+    ("sgd", "application/x-genesis-rom"),
+    ("sgf", "application/x-go-sgf"),
+    ("sgi", "image/x-sgi"),
+    ("sgl", "application/vnd.stardivision.writer"),
+    ("sgm", "text/sgml"),
+    ("sgml", "text/sgml"),
+    ("shape", "application/x-dia-shape"),
+    ("shar", "application/x-shar"),
+    ("shn", "application/x-shorten"),
+    ("siag", "application/x-siag"),
+    ("sid", "audio/prs.sid"),
+    ("sig", "application/pgp-signature"),
+    ("sik", "application/x-trash"),
+    ("sis", "application/vnd.symbian.install"),
+    ("sisx", "x-epoc/x-sisx-app"),
+    ("sit", "application/x-stuffit"),
+    ("siv", "application/sieve"),
+    ("sk", "image/x-skencil"),
+    ("sk1", "image/x-skencil"),
+    ("skr", "application/pgp-keys"),
+    (
+        "sldm",
+        "application/vnd.ms-powerpoint.slide.macroEnabled.12",
+    ),
+    (
+        "sldx",
+        "application/vnd.openxmlformats-officedocument.presentationml.slide",
+    ),
+    ("slice", "text/x-systemd-unit"),
+    ("slk", "text/spreadsheet"),
+    ("smaf", "application/vnd.smaf"),
+    ("smc", "application/vnd.nintendo.snes.rom"),
+    ("smd", "application/vnd.stardivision.mail"),
+    ("smf", "application/vnd.stardivision.math"),
+    ("smi", "application/smil+xml"),
+    ("smil", "application/smil+xml"),
+    ("smk", "video/vnd.radgamettools.smacker"),
+    ("sml", "application/smil+xml"),
+    ("sms", "application/x-sms-rom"),
+    ("snap", "application/vnd.snap"),
+    ("snd", "audio/basic"),
+    ("so", "application/x-sharedlib"),
+    ("socket", "text/x-systemd-unit"),
+    ("spc", "application/x-pkcs7-certificates"),
+    ("spd", "application/x-font-speedo"),
+    ("spec", "text/x-rpm-spec"),
+    ("spl", "application/vnd.adobe.flash.movie"),
+    ("spm", "application/x-source-rpm"),
+    ("spx", "audio/x-speex+ogg"),
+    ("sqlite", "application/vnd.sqlite3"),
+    ("sqlite2", "application/x-sqlite2"),
+    ("sqlite3", "application/vnd.sqlite3"),
+    ("sqsh", "application/vnd.squashfs"),
+    ("sr2", "image/x-sony-sr2"),
+    ("src", "application/x-wais-source"),
+    ("src.rpm", "application/x-source-rpm"),
+    ("srf", "image/x-sony-srf"),
+    ("srt", "application/x-subrip"),
+    ("srx", "application/sparql-results+xml"),
+    ("ssa", "text/x-ssa"),
+    ("stc", "application/vnd.sun.xml.calc.template"),
+    ("std", "application/vnd.sun.xml.draw.template"),
+    ("sti", "application/vnd.sun.xml.impress.template"),
+    ("stl", "model/stl"),
+    ("stm", "audio/x-stm"),
+    ("stw", "application/vnd.sun.xml.writer.template"),
+    ("sub", "text/x-microdvd"),
+    ("sun", "image/x-sun-raster"),
+    ("sv4cpio", "application/x-sv4cpio"),
+    ("sv4crc", "application/x-sv4crc"),
+    ("svg.gz", "image/svg+xml-compressed"),
+    ("svgz", "image/svg+xml-compressed"),
+    ("swap", "text/x-systemd-unit"),
+    ("swf", "application/vnd.adobe.flash.movie"),
+    ("swm", "application/x-ms-wim"),
+    ("sxc", "application/vnd.sun.xml.calc"),
+    ("sxd", "application/vnd.sun.xml.draw"),
+    ("sxg", "application/vnd.sun.xml.writer.global"),
+    ("sxi", "application/vnd.sun.xml.impress"),
+    ("sxm", "application/vnd.sun.xml.math"),
+    ("sxw", "application/vnd.sun.xml.writer"),
+    ("sylk", "text/spreadsheet"),
+    ("t", "application/x-perl"),
+    ("t2t", "text/x-txt2tags"),
+    ("tar", "application/x-tar"),
+    ("tar.bz", "application/x-bzip-compressed-tar"),
+    ("tar.bz2", "application/x-bzip-compressed-tar"),
+    ("tar.gz", "application/x-compressed-tar"),
+    ("tar.lrz", "application/x-lrzip-compressed-tar"),
+    ("tar.lz", "application/x-lzip-compressed-tar"),
+    ("tar.lz4", "application/x-lz4-compressed-tar"),
+    ("tar.lzma", "application/x-lzma-compressed-tar"),
+    ("tar.lzo", "application/x-tzo"),
+    ("tar.xz", "application/x-xz-compressed-tar"),
+    ("tar.z", "application/x-tarz"),
+    ("tar.zst", "application/x-zstd-compressed-tar"),
+    ("target", "text/x-systemd-unit"),
+    ("taz", "application/x-tarz"),
+    ("tb2", "application/x-bzip-compressed-tar"),
+    ("tbz", "application/x-bzip-compressed-tar"),
+    ("tbz2", "application/x-bzip-compressed-tar"),
+    ("texi", "text/x-texinfo"),
+    ("texinfo", "text/x-texinfo"),
+    ("text", "text/plain"),
+    ("textile", "text/plain"),
+    ("tga", "image/x-tga"),
+    ("tgz", "application/x-compressed-tar"),
+    ("theme", "application/x-theme"),
+    ("themepack", "application/x-windows-themepack"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("timer", "text/x-systemd-unit"),
+    ("tk", "text/x-tcl"),
+    ("tlrz", "application/x-lrzip-compressed-tar"),
+    ("tlz", "application/x-lzma-compressed-tar"),
+    ("tnef", "application/vnd.ms-tnef"),
+    ("tnf", "application/vnd.ms-tnef"),
+    ("torrent", "application/x-bittorrent"),
+    ("tpic", "image/x-tga"),
+    ("tr", "text/troff"),
+    ("tres", "application/x-godot-resource"),
+    ("trig", "application/trig"),
+    ("tscn", "application/x-godot-scene"),
+    ("tsv", "text/tab-separated-values"),
+    ("tta", "audio/x-tta"),
+    ("ttc", "font/collection"),
+    ("ttf", "font/ttf"),
+    ("ttl", "text/turtle"),
+    ("ttx", "application/x-font-ttx"),
+    ("twig", "text/x-twig"),
+    ("txt", "text/plain"),
+    ("txz", "application/x-xz-compressed-tar"),
+    ("tzo", "application/x-tzo"),
+    ("tzst", "application/x-zstd-compressed-tar"),
+    ("udeb", "application/vnd.debian.binary-package"),
+    ("ufraw", "application/x-ufraw"),
+    ("uil", "text/x-uil"),
+    ("ult", "audio/x-mod"),
+    ("unf", "application/x-nes-rom"),
+    ("uni", "audio/x-mod"),
+    ("unif", "application/x-nes-rom"),
+    ("ustar", "application/x-ustar"),
+    ("uue", "text/x-uuencode"),
+    ("v", "text/x-verilog"),
+    ("v64", "application/x-n64-rom"),
+    ("vbs", "text/vbscript"),
+    ("vcard", "text/vcard"),
+    ("vcf", "text/vcard"),
+    ("vcs", "text/calendar"),
+    ("vct", "text/vcard"),
+    ("vda", "image/x-tga"),
+    ("vdi", "application/x-vdi-disk"),
+    ("vhdx", "application/x-vhdx-disk"),
+    ("viv", "video/vnd.vivo"),
+    ("vivo", "video/vnd.vivo"),
+    ("vlc", "audio/x-mpegurl"),
+    ("vmdk", "application/x-vmdk-disk"),
+    ("vob", "video/mpeg"),
+    ("voc", "audio/x-voc"),
+    ("vor", "application/vnd.stardivision.writer"),
+    ("vpc", "application/x-vhd-disk"),
+    ("vrm", "model/vrml"),
+    ("vrml", "model/vrml"),
+    ("vsd", "application/vnd.visio"),
+    (
+        "vsdm",
+        "application/vnd.ms-visio.drawing.macroEnabled.main+xml",
+    ),
+    ("vsdx", "application/vnd.ms-visio.drawing.main+xml"),
+    ("vss", "application/vnd.visio"),
+    (
+        "vssm",
+        "application/vnd.ms-visio.stencil.macroEnabled.main+xml",
+    ),
+    ("vssx", "application/vnd.ms-visio.stencil.main+xml"),
+    ("vst", "application/vnd.visio"),
+    (
+        "vstm",
+        "application/vnd.ms-visio.template.macroEnabled.main+xml",
+    ),
+    ("vstx", "application/vnd.ms-visio.template.main+xml"),
+    ("vsw", "application/vnd.visio"),
+    ("vtt", "text/vtt"),
+    ("wad", "application/x-doom-wad"),
+    ("wasm", "application/wasm"),
+    ("wav", "audio/x-wav"),
+    ("wax", "audio/x-ms-asx"),
+    ("wb1", "application/x-quattropro"),
+    ("wb2", "application/x-quattropro"),
+    ("wb3", "application/x-quattropro"),
+    ("wbmp", "image/vnd.wap.wbmp"),
+    ("wcm", "application/vnd.ms-works"),
+    ("wdb", "application/vnd.ms-works"),
+    ("webm", "video/webm"),
+    ("webp", "image/webp"),
+    ("wim", "application/x-ms-wim"),
+    ("wk1", "application/vnd.lotus-1-2-3"),
+    ("wk3", "application/vnd.lotus-1-2-3"),
+    ("wk4", "application/vnd.lotus-1-2-3"),
+    ("wkdownload", "application/x-partial-download"),
+    ("wks", "application/vnd.lotus-1-2-3"),
+    ("wma", "audio/x-ms-wma"),
+    ("wmf", "image/wmf"),
+    ("wml", "text/vnd.wap.wml"),
+    ("wmls", "text/vnd.wap.wmlscript"),
+    ("wmv", "video/x-ms-wmv"),
+    ("wmx", "audio/x-ms-asx"),
+    ("woff", "font/woff"),
+    ("woff2", "font/woff2"),
+    ("wp", "application/vnd.wordperfect"),
+    ("wp4", "application/vnd.wordperfect"),
+    ("wp5", "application/vnd.wordperfect"),
+    ("wp6", "application/vnd.wordperfect"),
+    ("wpd", "application/vnd.wordperfect"),
+    ("wpg", "application/x-wpg"),
+    ("wpl", "application/vnd.ms-wpl"),
+    ("wpp", "application/vnd.wordperfect"),
+    ("wps", "application/vnd.ms-works"),
+    ("wri", "application/x-mswrite"),
+    ("wrl", "model/vrml"),
+    ("ws", "application/x-wonderswan-rom"),
+    ("wsc", "application/x-wonderswan-color-rom"),
+    ("wv", "audio/x-wavpack"),
+    ("wvc", "audio/x-wavpack-correction"),
+    ("wvp", "audio/x-wavpack"),
+    ("wvx", "audio/x-ms-asx"),
+    ("wwf", "application/x-wwf"),
+    ("x3f", "image/x-sigma-x3f"),
+    ("xac", "application/x-gnucash"),
+    ("xar", "application/x-xar"),
+    ("xbel", "application/x-xbel"),
+    ("xbl", "application/xml"),
+    ("xbm", "image/x-xbitmap"),
+    ("xcf", "image/x-xcf"),
+    ("xcf.bz2", "image/x-compressed-xcf"),
+    ("xcf.gz", "image/x-compressed-xcf"),
+    ("xdgapp", "application/vnd.flatpak"),
+    ("xhe", "audio/usac"),
+    ("xi", "audio/x-xi"),
+    ("xla", "application/vnd.ms-excel"),
+    ("xlam", "application/vnd.ms-excel.addin.macroEnabled.12"),
+    ("xlc", "application/vnd.ms-excel"),
+    ("xld", "application/vnd.ms-excel"),
+    ("xll", "application/vnd.ms-excel"),
+    ("xlm", "application/vnd.ms-excel"),
+    ("xlr", "application/vnd.ms-works"),
+    ("xls", "application/vnd.ms-excel"),
+    (
+        "xlsb",
+        "application/vnd.ms-excel.sheet.binary.macroEnabled.12",
+    ),
+    ("xlsm", "application/vnd.ms-excel.sheet.macroEnabled.12"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    ("xlt", "application/vnd.ms-excel"),
+    ("xltm", "application/vnd.ms-excel.template.macroEnabled.12"),
+    (
+        "xltx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.template",
+    ),
+    ("xlw", "application/vnd.ms-excel"),
+    ("xm", "audio/x-xm"),
+    ("xmf", "audio/x-xmf"),
+    ("xpi", "application/x-xpinstall"),
+    ("xpm", "image/x-xpixmap"),
+    ("xps", "application/vnd.ms-xpsdocument"),
+    ("xslfo", "text/x-xslfo"),
+    ("xspf", "application/xspf+xml"),
+    ("xwd", "image/x-xwindowdump"),
+    ("xz", "application/x-xz"),
+    ("yt", "application/vnd.youtube.yt"),
+    ("z", "application/x-compress"),
+    ("z64", "application/x-n64-rom"),
+    ("zabw", "application/x-abiword"),
+    ("zim", "application/x-openzim"),
+    ("zip", "application/zip"),
+    ("zipx", "application/zip"),
+    ("zoo", "application/x-zoo"),
+    ("zsav", "application/x-spss-sav"),
+    ("zst", "application/zstd"),
+    ("zz", "application/zlib"),
+];
+
+/// Alias to canonical MIME type
+pub(super) const MIME_ALIASES: &[(&str, &str)] = &[
+    ("application/acrobat", "application/pdf"),
+    ("application/bzip2", "application/x-bzip"),
+    ("application/cdr", "application/vnd.corel-draw"),
+    ("application/coreldraw", "application/vnd.corel-draw"),
+    ("application/dbase", "application/x-dbf"),
+    ("application/dbf", "application/x-dbf"),
+    ("application/docbook+xml", "application/x-docbook+xml"),
+    ("application/emf", "image/emf"),
+    ("application/font-woff", "font/woff"),
+    (
+        "application/futuresplash",
+        "application/vnd.adobe.flash.movie",
+    ),
+    ("application/gpx", "application/gpx+xml"),
+    ("application/ico", "image/vnd.microsoft.icon"),
+    ("application/ics", "text/calendar"),
+    ("application/java", "application/x-java"),
+    ("application/java-archive", "application/x-java-archive"),
+    ("application/java-byte-code", "application/x-java"),
+    ("application/java-vm", "application/x-java"),
+    ("application/javascript", "text/javascript"),
+    ("application/lotus123", "application/vnd.lotus-1-2-3"),
+    ("application/m3u", "audio/x-mpegurl"),
+    ("application/mdb", "application/vnd.ms-access"),
+    ("application/ms-tnef", "application/vnd.ms-tnef"),
+    ("application/msaccess", "application/vnd.ms-access"),
+    ("application/msexcel", "application/vnd.ms-excel"),
+    ("application/mspowerpoint", "application/vnd.ms-powerpoint"),
+    ("application/nappdf", "application/pdf"),
+    ("application/pcap", "application/vnd.tcpdump.pcap"),
+    ("application/pgp", "application/pgp-encrypted"),
+    ("application/photoshop", "image/vnd.adobe.photoshop"),
+    ("application/pls", "audio/x-scpls"),
+    ("application/powerpoint", "application/vnd.ms-powerpoint"),
+    ("application/smil", "application/smil+xml"),
+    ("application/stuffit", "application/x-stuffit"),
+    ("application/tga", "image/x-tga"),
+    (
+        "application/vnd.adobe.illustrator",
+        "application/illustrator",
+    ),
+    ("application/vnd.geo+json", "application/geo+json"),
+    ("application/vnd.haansoft-hwp", "application/x-hwp"),
+    ("application/vnd.haansoft-hwt", "application/x-hwt"),
+    ("application/vnd.ms-3mfdocument", "model/3mf"),
+    ("application/vnd.ms-word", "application/msword"),
+    ("application/vnd.msaccess", "application/vnd.ms-access"),
+    (
+        "application/vnd.oasis.docbook+xml",
+        "application/x-docbook+xml",
+    ),
+    (
+        "application/vnd.rn-realmedia-vbr",
+        "application/vnd.rn-realmedia",
+    ),
+    ("application/vnd.sdp", "application/sdp"),
+    (
+        "application/vnd.stardivision.writer-global",
+        "application/vnd.stardivision.writer",
+    ),
+    (
+        "application/vnd.sun.xml.base",
+        "application/vnd.oasis.opendocument.database",
+    ),
+    ("application/vnd.xdgapp", "application/vnd.flatpak"),
+    ("application/wk1", "application/vnd.lotus-1-2-3"),
+    ("application/wmf", "image/wmf"),
+    ("application/wordperfect", "application/vnd.wordperfect"),
+    ("application/wwf", "application/x-wwf"),
+    ("application/x-123", "application/vnd.lotus-1-2-3"),
+    ("application/x-annodex", "application/annodex"),
+    ("application/x-bzip2", "application/x-bzip"),
+    ("application/x-cbr", "application/vnd.comicbook-rar"),
+    ("application/x-cbz", "application/vnd.comicbook+zip"),
+    ("application/x-cdr", "application/vnd.corel-draw"),
+    ("application/x-chess-pgn", "application/vnd.chess-pgn"),
+    ("application/x-chm", "application/vnd.ms-htmlhelp"),
+    ("application/x-coreldraw", "application/vnd.corel-draw"),
+    ("application/x-dbase", "application/x-dbf"),
+    ("application/x-deb", "application/vnd.debian.binary-package"),
+    (
+        "application/x-debian-package",
+        "application/vnd.debian.binary-package",
+    ),
+    ("application/x-emf", "image/emf"),
+    (
+        "application/x-fd-file",
+        "application/x-raw-floppy-disk-image",
+    ),
+    ("application/x-fictionbook", "application/x-fictionbook+xml"),
+    ("application/x-flash-video", "video/x-flv"),
+    ("application/x-font-otf", "font/otf"),
+    ("application/x-font-ttf", "font/ttf"),
+    ("application/x-frame", "application/vnd.framemaker"),
+    (
+        "application/x-gamecube-iso-image",
+        "application/x-gamecube-rom",
+    ),
+    ("application/x-gettext", "text/x-gettext-translation"),
+    ("application/x-gnome-app-info", "application/x-desktop"),
+    ("application/x-gpx", "application/gpx+xml"),
+    ("application/x-gpx+xml", "application/gpx+xml"),
+    ("application/x-gtar", "application/x-tar"),
+    ("application/x-gzip", "application/gzip"),
+    ("application/x-hfe-file", "application/x-hfe-floppy-image"),
+    ("application/x-iso9660-image", "application/x-cd-image"),
+    (
+        "application/x-iwork-keynote-sffkey",
+        "application/vnd.apple.keynote",
+    ),
+    (
+        "application/x-iwork-numbers-sffnumbers",
+        "application/vnd.apple.numbers",
+    ),
+    (
+        "application/x-iwork-pages-sffpages",
+        "application/vnd.apple.pages",
+    ),
+    ("application/x-jar", "application/x-java-archive"),
+    ("application/x-java-class", "application/x-java"),
+    ("application/x-java-vm", "application/x-java"),
+    ("application/x-javascript", "text/javascript"),
+    (
+        "application/x-kexiproject-sqlite",
+        "application/x-kexiproject-sqlite3",
+    ),
+    ("application/x-linguist", "text/vnd.trolltech.linguist"),
+    ("application/x-lotus123", "application/vnd.lotus-1-2-3"),
+    ("application/x-lzh-compressed", "application/x-lha"),
+    ("application/x-mathematica", "application/mathematica"),
+    ("application/x-mdb", "application/vnd.ms-access"),
+    (
+        "application/x-mobi8-ebook",
+        "application/vnd.amazon.mobi8-ebook",
+    ),
+    ("application/x-ms-asx", "audio/x-ms-asx"),
+    ("application/x-msaccess", "application/vnd.ms-access"),
+    ("application/x-msexcel", "application/vnd.ms-excel"),
+    ("application/x-msmetafile", "image/wmf"),
+    (
+        "application/x-mspowerpoint",
+        "application/vnd.ms-powerpoint",
+    ),
+    ("application/x-msword", "application/msword"),
+    (
+        "application/x-netscape-bookmarks",
+        "application/x-mozilla-bookmarks",
+    ),
+    ("application/x-ogg", "application/ogg"),
+    ("application/x-palm-database", "application/vnd.palm"),
+    ("application/x-pcap", "application/vnd.tcpdump.pcap"),
+    ("application/x-pdf", "application/pdf"),
+    ("application/x-photoshop", "image/vnd.adobe.photoshop"),
+    ("application/x-pkcs12", "application/pkcs12"),
+    (
+        "application/x-quicktimeplayer",
+        "application/x-quicktime-media-link",
+    ),
+    ("application/x-rar", "application/vnd.rar"),
+    ("application/x-rar-compressed", "application/vnd.rar"),
+    ("application/x-redhat-package-manager", "application/x-rpm"),
+    ("application/x-reject", "text/x-reject"),
+    ("application/x-rnc", "application/relax-ng-compact-syntax"),
+    ("application/x-sap-file", "application/x-thomson-sap-image"),
+    ("application/x-sdp", "application/sdp"),
+    (
+        "application/x-shockwave-flash",
+        "application/vnd.adobe.flash.movie",
+    ),
+    ("application/x-sit", "application/x-stuffit"),
+    ("application/x-smaf", "application/vnd.smaf"),
+    (
+        "application/x-snes-rom",
+        "application/vnd.nintendo.snes.rom",
+    ),
+    ("application/x-spss-savefile", "application/x-spss-sav"),
+    ("application/x-sqlite3", "application/vnd.sqlite3"),
+    ("application/x-srt", "application/x-subrip"),
+    ("application/x-targa", "image/x-tga"),
+    ("application/x-tex", "text/x-tex"),
+    ("application/x-tga", "image/x-tga"),
+    ("application/x-toml", "application/toml"),
+    ("application/x-trig", "application/trig"),
+    ("application/x-troff", "text/troff"),
+    ("application/x-typescript", "application/typescript"),
+    ("application/x-virtualbox-ova", "application/ovf"),
+    ("application/x-virtualbox-vdi", "application/x-vdi-disk"),
+    ("application/x-virtualbox-vhd", "application/x-vhd-disk"),
+    ("application/x-virtualbox-vhdx", "application/x-vhdx-disk"),
+    ("application/x-virtualbox-vmdk", "application/x-vmdk-disk"),
+    (
+        "application/x-vnd.kde.kexi",
+        "application/x-kexiproject-sqlite3",
+    ),
+    ("application/x-wbfs", "application/x-wii-rom"),
+    ("application/x-wia", "application/x-wii-rom"),
+    ("application/x-wii-iso-image", "application/x-wii-rom"),
+    ("application/x-wmf", "image/wmf"),
+    ("application/x-wordperfect", "application/vnd.wordperfect"),
+    ("application/x-xliff", "application/xliff+xml"),
+    ("application/x-xspf+xml", "application/xspf+xml"),
+    ("application/x-yaml", "application/yaml"),
+    ("application/x-zip", "application/zip"),
+    ("application/x-zip-compressed", "application/zip"),
+    ("application/xps", "application/vnd.ms-xpsdocument"),
+    ("audio/3gpp", "video/3gpp"),
+    ("audio/3gpp-encrypted", "video/3gpp"),
+    ("audio/3gpp2", "video/3gpp2"),
+    ("audio/amr-encrypted", "audio/amr"),
+    ("audio/amr-wb-encrypted", "audio/amr-wb"),
+    ("audio/dff", "audio/x-dff"),
+    ("audio/dsd", "audio/x-dsf"),
+    ("audio/dsf", "audio/x-dsf"),
+    ("audio/imelody", "text/x-imelody"),
+    ("audio/m3u", "audio/x-mpegurl"),
+    ("audio/m4a", "audio/mp4"),
+    ("audio/mp3", "audio/mpeg"),
+    ("audio/mpegurl", "audio/x-mpegurl"),
+    ("audio/scpls", "audio/x-scpls"),
+    ("audio/tta", "audio/x-tta"),
+    ("audio/vnd.audible", "audio/x-pn-audibleaudio"),
+    ("audio/vnd.m-realaudio", "audio/vnd.rn-realaudio"),
+    ("audio/vnd.nokia.mobile-xmf", "audio/mobile-xmf"),
+    ("audio/vnd.wave", "audio/x-wav"),
+    ("audio/vorbis", "audio/x-vorbis+ogg"),
+    ("audio/wav", "audio/x-wav"),
+    ("audio/wma", "audio/x-ms-wma"),
+    ("audio/x-aac", "audio/aac"),
+    ("audio/x-aiffc", "audio/x-aifc"),
+    ("audio/x-annodex", "audio/annodex"),
+    ("audio/x-dsd", "audio/x-dsf"),
+    ("audio/x-dts", "audio/vnd.dts"),
+    ("audio/x-dtshd", "audio/vnd.dts.hd"),
+    ("audio/x-flac", "audio/flac"),
+    ("audio/x-imelody", "text/x-imelody"),
+    ("audio/x-m3u", "audio/x-mpegurl"),
+    ("audio/x-m4a", "audio/mp4"),
+    ("audio/x-midi", "audio/midi"),
+    ("audio/x-mp2", "audio/mp2"),
+    ("audio/x-mp3", "audio/mpeg"),
+    ("audio/x-mp3-playlist", "audio/x-mpegurl"),
+    ("audio/x-mpeg", "audio/mpeg"),
+    ("audio/x-mpg", "audio/mpeg"),
+    ("audio/x-ogg", "audio/ogg"),
+    ("audio/x-oggflac", "audio/x-flac+ogg"),
+    ("audio/x-pn-realaudio", "audio/vnd.rn-realaudio"),
+    ("audio/x-rn-3gpp-amr", "video/3gpp"),
+    ("audio/x-rn-3gpp-amr-encrypted", "video/3gpp"),
+    ("audio/x-rn-3gpp-amr-wb", "video/3gpp"),
+    ("audio/x-rn-3gpp-amr-wb-encrypted", "video/3gpp"),
+    ("audio/x-shorten", "application/x-shorten"),
+    ("audio/x-vorbis", "audio/x-vorbis+ogg"),
+    ("audio/xmf", "audio/x-xmf"),
+    ("flv-application/octet-stream", "video/x-flv"),
+    ("image/avif-sequence", "image/avif"),
+    ("image/cdr", "application/vnd.corel-draw"),
+    ("image/fax-g3", "image/g3fax"),
+    ("image/fits", "application/fits"),
+    ("image/heic", "image/heif"),
+    ("image/heic-sequence", "image/heif"),
+    ("image/heif-sequence", "image/heif"),
+    ("image/ico", "image/vnd.microsoft.icon"),
+    ("image/icon", "image/vnd.microsoft.icon"),
+    ("image/jpeg2000", "image/jp2"),
+    ("image/jpeg2000-image", "image/jp2"),
+    ("image/jpg", "image/jpeg"),
+    ("image/pdf", "application/pdf"),
+    ("image/photoshop", "image/vnd.adobe.photoshop"),
+    ("image/pjpeg", "image/jpeg"),
+    ("image/psd", "image/vnd.adobe.photoshop"),
+    ("image/targa", "image/x-tga"),
+    ("image/tga", "image/x-tga"),
+    ("image/x-bmp", "image/bmp"),
+    ("image/x-cdr", "application/vnd.corel-draw"),
+    ("image/x-djvu", "image/vnd.djvu"),
+    ("image/x-emf", "image/emf"),
+    ("image/x-fits", "application/fits"),
+    ("image/x-icb", "image/x-tga"),
+    ("image/x-ico", "image/vnd.microsoft.icon"),
+    ("image/x-icon", "image/vnd.microsoft.icon"),
+    ("image/x-iff", "image/x-ilbm"),
+    ("image/x-jpeg2000-image", "image/jp2"),
+    ("image/x-ms-bmp", "image/bmp"),
+    ("image/x-panasonic-raw", "image/x-panasonic-rw"),
+    ("image/x-panasonic-raw2", "image/x-panasonic-rw2"),
+    ("image/x-pcx", "image/vnd.zbrush.pcx"),
+    ("image/x-photoshop", "image/vnd.adobe.photoshop"),
+    ("image/x-png", "image/png"),
+    ("image/x-psd", "image/vnd.adobe.photoshop"),
+    ("image/x-targa", "image/x-tga"),
+    ("image/x-win-metafile", "image/wmf"),
+    ("image/x-wmf", "image/wmf"),
+    ("image/x-xpm", "image/x-xpixmap"),
+    ("image/x.djvu", "image/vnd.djvu"),
+    ("model/x.stl-ascii", "model/stl"),
+    ("model/x.stl-binary", "model/stl"),
+    ("text/crystal", "text/x-crystal"),
+    ("text/directory", "text/vcard"),
+    ("text/ecmascript", "application/ecmascript"),
+    ("text/gedcom", "application/x-gedcom"),
+    ("text/google-video-pointer", "text/x-google-video-pointer"),
+    ("text/ico", "image/vnd.microsoft.icon"),
+    ("text/mathml", "application/mathml+xml"),
+    ("text/rdf", "application/rdf+xml"),
+    ("text/rss", "application/rss+xml"),
+    ("text/rtf", "application/rtf"),
+    ("text/tcl", "text/x-tcl"),
+    ("text/typescript", "application/typescript"),
+    ("text/vbs", "text/vbscript"),
+    ("text/vnd.qt.linguist", "text/vnd.trolltech.linguist"),
+    ("text/x-c", "text/x-csrc"),
+    ("text/x-comma-separated-values", "text/csv"),
+    ("text/x-csv", "text/csv"),
+    ("text/x-dart", "application/vnd.dart"),
+    ("text/x-diff", "text/x-patch"),
+    ("text/x-dtd", "application/xml-dtd"),
+    ("text/x-lyx", "application/x-lyx"),
+    ("text/x-markdown", "text/markdown"),
+    ("text/x-octave", "text/x-matlab"),
+    ("text/x-opml", "text/x-opml+xml"),
+    ("text/x-perl", "application/x-perl"),
+    ("text/x-po", "text/x-gettext-translation"),
+    ("text/x-pot", "text/x-gettext-translation-template"),
+    ("text/x-python3", "text/x-python"),
+    ("text/x-rustsrc", "text/rust"),
+    ("text/x-sh", "application/x-shellscript"),
+    ("text/x-sql", "application/sql"),
+    ("text/x-svsrc", "text/x-systemverilog"),
+    ("text/x-troff", "text/troff"),
+    ("text/x-typescript", "application/typescript"),
+    ("text/x-vcalendar", "text/calendar"),
+    ("text/x-vcard", "text/vcard"),
+    ("text/x-yaml", "application/yaml"),
+    ("text/xml", "application/xml"),
+    (
+        "text/xml-external-parsed-entity",
+        "application/xml-external-parsed-entity",
+    ),
+    ("text/yaml", "application/yaml"),
+    ("video/3gp", "video/3gpp"),
+    ("video/3gpp-encrypted", "video/3gpp"),
+    ("video/avi", "video/x-msvideo"),
+    ("video/divx", "video/x-msvideo"),
+    ("video/fli", "video/x-flic"),
+    ("video/flv", "video/x-flv"),
+    ("video/mp4v-es", "video/mp4"),
+    ("video/mpeg-system", "video/mpeg"),
+    ("video/msvideo", "video/x-msvideo"),
+    ("video/vivo", "video/vnd.vivo"),
+    ("video/vnd.divx", "video/x-msvideo"),
+    ("video/x-annodex", "video/annodex"),
+    ("video/x-avi", "video/x-msvideo"),
+    ("video/x-fli", "video/x-flic"),
+    ("video/x-m4v", "video/mp4"),
+    ("video/x-mpeg", "video/mpeg"),
+    ("video/x-mpeg-system", "video/mpeg"),
+    ("video/x-mpeg2", "video/mpeg"),
+    ("video/x-mpegurl", "video/vnd.mpegurl"),
+    ("video/x-ms-asf", "application/vnd.ms-asf"),
+    ("video/x-ms-asf-plugin", "application/vnd.ms-asf"),
+    ("video/x-ms-wax", "audio/x-ms-asx"),
+    ("video/x-ms-wm", "application/vnd.ms-asf"),
+    ("video/x-ms-wmx", "audio/x-ms-asx"),
+    ("video/x-ms-wvx", "audio/x-ms-asx"),
+    ("video/x-ogg", "video/ogg"),
+    ("video/x-ogm", "video/x-ogm+ogg"),
+    ("video/x-real-video", "video/vnd.rn-realvideo"),
+    ("video/x-theora", "video/x-theora+ogg"),
+    ("x-directory/normal", "inode/directory"),
+    (
+        "zz-application/zz-winassoc-123",
+        "application/vnd.lotus-1-2-3",
+    ),
+    (
+        "zz-application/zz-winassoc-cab",
+        "application/vnd.ms-cab-compressed",
+    ),
+    (
+        "zz-application/zz-winassoc-cdr",
+        "application/vnd.corel-draw",
+    ),
+    ("zz-application/zz-winassoc-doc", "application/msword"),
+    ("zz-application/zz-winassoc-hlp", "application/winhlp"),
+    (
+        "zz-application/zz-winassoc-mdb",
+        "application/vnd.ms-access",
+    ),
+    ("zz-application/zz-winassoc-uu", "text/x-uuencode"),
+    ("zz-application/zz-winassoc-xls", "application/vnd.ms-excel"),
+];
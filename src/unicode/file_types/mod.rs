@@ -0,0 +1,719 @@
+//! File type Unicode characters
+//! Icons and indicators for different file types and extensions
+
+mod classifier;
+mod content;
+mod directories;
+mod extensions;
+mod filenames;
+mod languages;
+mod linguist;
+mod ls_colors;
+mod mime;
+mod mime_types;
+
+use std::fs::Metadata;
+use std::path::Path;
+
+use super::{UnicodeProvider, UnicodeTheme};
+
+pub use classifier::{
+    get_global_classifier, set_global_classifier, ClassifierRule, FileClassifier, FileMatch,
+    MatchedRule, RuleKind,
+};
+pub use content::{
+    classify_content, classify_file, is_binary_content, ClassificationCandidate,
+    ClassificationSource, Confidence, ContentClassification, BINARY_SNIFF_LEN,
+};
+pub use directories::{classify_directory, DirectoryIcon, DirectoryKind};
+pub use linguist::{
+    is_generated_content, is_generated_path, is_vendored_path, AttributeValue, GitAttributes,
+    LinguistClassification,
+};
+pub use ls_colors::LsColors;
+pub use mime::{content_type, mime_type_from_filename, normalize_mime};
+
+/// File type indicators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileType {
+    /// Regular file
+    File,
+    /// Directory/folder
+    Directory,
+    /// Executable file
+    Executable,
+    /// Symbolic link
+    SymLink,
+    /// Hidden file
+    Hidden,
+    /// Configuration file
+    Config,
+    /// Documentation file
+    Documentation,
+    /// Image file
+    Image,
+    /// Video file
+    Video,
+    /// Audio file
+    Audio,
+    /// Archive file
+    Archive,
+    /// Database file
+    Database,
+    /// Log file
+    Log,
+    /// Temporary file
+    Temporary,
+    /// Backup file
+    Backup,
+    /// Unix domain socket
+    Socket,
+    /// Named pipe (FIFO)
+    Fifo,
+    /// Block device
+    BlockDevice,
+    /// Character device
+    CharDevice,
+    /// Build manifest, e.g. `Cargo.toml`, `package.json` or `Makefile`
+    BuildManifest,
+    /// Dependency lockfile, e.g. `Cargo.lock` or `yarn.lock`
+    Lockfile,
+}
+
+impl UnicodeProvider for FileType {
+    fn get_char(&self, theme: UnicodeTheme) -> char {
+        match (self, theme) {
+            (FileType::File, UnicodeTheme::Minimal) => '-',
+            (FileType::File, UnicodeTheme::Basic) => '•',
+            (FileType::File, UnicodeTheme::Rich) => '📄',
+            (FileType::File, UnicodeTheme::Fancy) => '📋',
+
+            (FileType::Directory, UnicodeTheme::Minimal) => 'D',
+            (FileType::Directory, UnicodeTheme::Basic) => '/',
+            (FileType::Directory, UnicodeTheme::Rich) => '📁',
+            (FileType::Directory, UnicodeTheme::Fancy) => '🗂',
+
+            (FileType::Executable, UnicodeTheme::Minimal) => 'X',
+            (FileType::Executable, UnicodeTheme::Basic) => '*',
+            (FileType::Executable, UnicodeTheme::Rich) => '⚡',
+            (FileType::Executable, UnicodeTheme::Fancy) => '🔧',
+
+            (FileType::SymLink, UnicodeTheme::Minimal) => 'L',
+            (FileType::SymLink, UnicodeTheme::Basic) => '@',
+            (FileType::SymLink, UnicodeTheme::Rich) => '🔗',
+            (FileType::SymLink, UnicodeTheme::Fancy) => '⛓',
+
+            (FileType::Hidden, UnicodeTheme::Minimal) => '.',
+            (FileType::Hidden, UnicodeTheme::Basic) => '.',
+            (FileType::Hidden, UnicodeTheme::Rich) => '👁',
+            (FileType::Hidden, UnicodeTheme::Fancy) => '🕵',
+
+            (FileType::Config, UnicodeTheme::Minimal) => 'C',
+            (FileType::Config, UnicodeTheme::Basic) => '#',
+            (FileType::Config, UnicodeTheme::Rich) => '⚙',
+            (FileType::Config, UnicodeTheme::Fancy) => '🔧',
+
+            (FileType::Documentation, UnicodeTheme::Minimal) => 'D',
+            (FileType::Documentation, UnicodeTheme::Basic) => '?',
+            (FileType::Documentation, UnicodeTheme::Rich) => '📖',
+            (FileType::Documentation, UnicodeTheme::Fancy) => '📚',
+
+            (FileType::Image, UnicodeTheme::Minimal) => 'I',
+            (FileType::Image, UnicodeTheme::Basic) => '%',
+            (FileType::Image, UnicodeTheme::Rich) => '🖼',
+            (FileType::Image, UnicodeTheme::Fancy) => '🎨',
+
+            (FileType::Video, UnicodeTheme::Minimal) => 'V',
+            (FileType::Video, UnicodeTheme::Basic) => '&',
+            (FileType::Video, UnicodeTheme::Rich) => '🎬',
+            (FileType::Video, UnicodeTheme::Fancy) => '📹',
+
+            (FileType::Audio, UnicodeTheme::Minimal) => 'A',
+            (FileType::Audio, UnicodeTheme::Basic) => '~',
+            (FileType::Audio, UnicodeTheme::Rich) => '🎵',
+            (FileType::Audio, UnicodeTheme::Fancy) => '🎶',
+
+            (FileType::Archive, UnicodeTheme::Minimal) => 'Z',
+            (FileType::Archive, UnicodeTheme::Basic) => '=',
+            (FileType::Archive, UnicodeTheme::Rich) => '📦',
+            (FileType::Archive, UnicodeTheme::Fancy) => '🗜',
+
+            (FileType::Database, UnicodeTheme::Minimal) => 'B',
+            (FileType::Database, UnicodeTheme::Basic) => '#',
+            (FileType::Database, UnicodeTheme::Rich) => '🗄',
+            (FileType::Database, UnicodeTheme::Fancy) => '💾',
+
+            (FileType::Log, UnicodeTheme::Minimal) => 'L',
+            (FileType::Log, UnicodeTheme::Basic) => '|',
+            (FileType::Log, UnicodeTheme::Rich) => '📜',
+            (FileType::Log, UnicodeTheme::Fancy) => '📋',
+
+            (FileType::Temporary, UnicodeTheme::Minimal) => 'T',
+            (FileType::Temporary, UnicodeTheme::Basic) => '~',
+            (FileType::Temporary, UnicodeTheme::Rich) => '⏳',
+            (FileType::Temporary, UnicodeTheme::Fancy) => '🗑',
+
+            (FileType::Backup, UnicodeTheme::Minimal) => 'B',
+            (FileType::Backup, UnicodeTheme::Basic) => '+',
+            (FileType::Backup, UnicodeTheme::Rich) => '💾',
+            (FileType::Backup, UnicodeTheme::Fancy) => '🔄',
+
+            (FileType::Socket, UnicodeTheme::Minimal) => 's',
+            (FileType::Socket, UnicodeTheme::Basic) => '=',
+            (FileType::Socket, UnicodeTheme::Rich) => '🔌',
+            (FileType::Socket, UnicodeTheme::Fancy) => '🔌',
+
+            (FileType::Fifo, UnicodeTheme::Minimal) => 'p',
+            (FileType::Fifo, UnicodeTheme::Basic) => '|',
+            (FileType::Fifo, UnicodeTheme::Rich) => '⇶',
+            (FileType::Fifo, UnicodeTheme::Fancy) => '🚰',
+
+            (FileType::BlockDevice, UnicodeTheme::Minimal) => 'b',
+            (FileType::BlockDevice, UnicodeTheme::Basic) => '#',
+            (FileType::BlockDevice, UnicodeTheme::Rich) => '💽',
+            (FileType::BlockDevice, UnicodeTheme::Fancy) => '🖴',
+
+            (FileType::CharDevice, UnicodeTheme::Minimal) => 'c',
+            (FileType::CharDevice, UnicodeTheme::Basic) => '%',
+            (FileType::CharDevice, UnicodeTheme::Rich) => '⌨',
+            (FileType::CharDevice, UnicodeTheme::Fancy) => '🖨',
+
+            (FileType::BuildManifest, UnicodeTheme::Minimal) => 'M',
+            (FileType::BuildManifest, UnicodeTheme::Basic) => '^',
+            (FileType::BuildManifest, UnicodeTheme::Rich) => '🏗',
+            (FileType::BuildManifest, UnicodeTheme::Fancy) => '🛠',
+
+            (FileType::Lockfile, UnicodeTheme::Minimal) => 'K',
+            (FileType::Lockfile, UnicodeTheme::Basic) => '!',
+            (FileType::Lockfile, UnicodeTheme::Rich) => '🔒',
+            (FileType::Lockfile, UnicodeTheme::Fancy) => '🔐',
+        }
+    }
+}
+
+impl FileType {
+    /// Look up a file type by name, ignoring case, `-` and `_`
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::FileType;
+    ///
+    /// assert_eq!(FileType::from_name("build-manifest"), Some(FileType::BuildManifest));
+    /// assert_eq!(FileType::from_name("Lockfile"), Some(FileType::Lockfile));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|&c| c != '-' && c != '_')
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let file_type = match name.as_str() {
+            "file" => FileType::File,
+            "directory" | "dir" => FileType::Directory,
+            "executable" => FileType::Executable,
+            "symlink" | "link" => FileType::SymLink,
+            "hidden" => FileType::Hidden,
+            "config" => FileType::Config,
+            "documentation" | "docs" => FileType::Documentation,
+            "image" => FileType::Image,
+            "video" => FileType::Video,
+            "audio" => FileType::Audio,
+            "archive" => FileType::Archive,
+            "database" => FileType::Database,
+            "log" => FileType::Log,
+            "temporary" | "temp" => FileType::Temporary,
+            "backup" => FileType::Backup,
+            "socket" => FileType::Socket,
+            "fifo" => FileType::Fifo,
+            "blockdevice" => FileType::BlockDevice,
+            "chardevice" => FileType::CharDevice,
+            "buildmanifest" => FileType::BuildManifest,
+            "lockfile" => FileType::Lockfile,
+            _ => return None,
+        };
+        Some(file_type)
+    }
+}
+
+pub use languages::LanguageType;
+
+/// Linguist data of a language
+///
+/// Generated from GitHub Linguist's `languages.yml` by
+/// `scripts/languages.py`. Extensions, file names and interpreters are
+/// lowercase, extensions without the leading dot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LanguageInfo {
+    /// The language
+    pub language: LanguageType,
+    /// Canonical Linguist name, e.g. `C++`
+    pub name: &'static str,
+    /// Canonical color as `#rrggbb`
+    pub color: Option<&'static str>,
+    /// Alternative names, e.g. `cpp`
+    pub aliases: &'static [&'static str],
+    /// File extensions
+    pub extensions: &'static [&'static str],
+    /// Exact file names, e.g. `makefile`
+    pub filenames: &'static [&'static str],
+    /// Interpreters named in `#!` lines
+    pub interpreters: &'static [&'static str],
+    /// MIME type, `text/plain` if there is no specific one
+    pub mime_type: &'static str,
+    /// Minimal, Basic, Rich and Fancy glyphs
+    glyphs: [char; 4],
+}
+
+impl LanguageType {
+    /// Linguist data of the language
+    pub fn info(self) -> &'static LanguageInfo {
+        &languages::LANGUAGES[self as usize]
+    }
+
+    /// Canonical name, e.g. `C++` for [`LanguageType::Cpp`]
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// Canonical color as `#rrggbb`
+    pub fn color(self) -> Option<&'static str> {
+        self.info().color
+    }
+
+    /// All languages, [`LanguageType::Code`] last
+    pub fn all() -> impl Iterator<Item = LanguageType> {
+        languages::LANGUAGES.iter().map(|info| info.language)
+    }
+
+    /// Look up a language by name or alias, ignoring case
+    ///
+    /// ```rust
+    /// use unicode_rs::file_types::LanguageType;
+    ///
+    /// assert_eq!(LanguageType::from_name("C++"), Some(LanguageType::Cpp));
+    /// assert_eq!(LanguageType::from_name("terraform"), Some(LanguageType::Hcl));
+    /// assert_eq!(LanguageType::from_name("docker"), Some(LanguageType::Docker));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        lookup(languages::NAMES, &name.to_lowercase())
+    }
+
+    /// Look up a language by the interpreter of a `#!` line
+    ///
+    /// A version suffix is ignored, so `python3.12` is Python.
+    pub fn from_interpreter(interpreter: &str) -> Option<Self> {
+        lookup(languages::INTERPRETERS, interpreter).or_else(|| {
+            let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+            lookup(languages::INTERPRETERS, name)
+        })
+    }
+}
+
+impl UnicodeProvider for LanguageType {
+    fn get_char(&self, theme: UnicodeTheme) -> char {
+        let [minimal, basic, rich, fancy] = self.info().glyphs;
+        match theme {
+            UnicodeTheme::Minimal => minimal,
+            UnicodeTheme::Basic => basic,
+            UnicodeTheme::Rich => rich,
+            UnicodeTheme::Fancy => fancy,
+        }
+    }
+}
+
+/// Binary search a sorted generated lookup table
+fn lookup(table: &[(&str, LanguageType)], key: &str) -> Option<LanguageType> {
+    table
+        .binary_search_by_key(&key, |&(name, _)| name)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// File extension to type mapping
+///
+/// Extension rules of the global [`FileClassifier`] take precedence.
+pub fn get_file_type_from_extension(extension: &str) -> LanguageType {
+    classifier::with_global_classifier(|classifier| classifier.language_from_extension(extension))
+        .unwrap_or(LanguageType::Code)
+}
+
+/// Get file type from filename
+///
+/// Rules of the global [`FileClassifier`] are tried first. Then well-known
+/// names such as `Makefile`, `Cargo.lock`, `LICENSE` or `Dockerfile.dev`
+/// are matched. Otherwise hidden files aside, the longest known extension
+/// decides, so `photo.PNG` is an image and `backup.tar.gz` an archive.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// assert_eq!(get_file_type_from_filename("photo.PNG"), FileType::Image);
+/// assert_eq!(get_file_type_from_filename("backup.tar.zst"), FileType::Archive);
+/// assert_eq!(get_file_type_from_filename("Cargo.lock"), FileType::Lockfile);
+/// assert_eq!(get_file_type_from_filename(".env.local"), FileType::Config);
+/// assert_eq!(get_file_type_from_filename("main.rs"), FileType::File);
+/// ```
+pub fn get_file_type_from_filename(filename: &str) -> FileType {
+    classifier::with_global_classifier(|classifier| classifier.classify(filename).file_type)
+}
+
+/// Get the language of a file from its name
+///
+/// Rules of the global [`FileClassifier`] are tried first. Well-known names
+/// and the file names Linguist knows take precedence over the extension, so
+/// `Dockerfile.dev` is [`LanguageType::Docker`] and `Gemfile` is Ruby.
+///
+/// ```rust
+/// use unicode_rs::file_types::*;
+///
+/// assert_eq!(get_language_from_filename("Dockerfile.dev"), Some(LanguageType::Docker));
+/// assert_eq!(get_language_from_filename("Gemfile"), Some(LanguageType::Ruby));
+/// assert_eq!(get_language_from_filename("README.md"), Some(LanguageType::Markdown));
+/// assert_eq!(get_language_from_filename("notes"), None);
+/// ```
+pub fn get_language_from_filename(filename: &str) -> Option<LanguageType> {
+    classifier::with_global_classifier(|classifier| classifier.classify(filename).language)
+}
+
+/// File and language type of a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileClassification {
+    /// Kind of file
+    pub file_type: FileType,
+    /// Programming language, if the name has a known extension
+    pub language: Option<LanguageType>,
+    /// The path is a symbolic link whose target does not exist
+    pub broken_link: bool,
+}
+
+/// Classify a path from its file system metadata
+///
+/// Symbolic links are not followed. If the metadata cannot be read, the path
+/// is classified by name as in [`get_file_type_from_filename`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::Path;
+/// use unicode_rs::file_types::*;
+///
+/// let src = classify_path(Path::new("src"));
+/// assert_eq!(src.file_type, FileType::Directory);
+///
+/// let missing = classify_path(Path::new("does-not-exist.rs"));
+/// assert_eq!(missing.file_type, FileType::File);
+/// assert_eq!(missing.language, Some(LanguageType::Rust));
+/// ```
+pub fn classify_path(path: &Path) -> FileClassification {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) => classify_metadata(path, &metadata),
+        Err(_) => classify_name(path),
+    }
+}
+
+/// Classify a path from metadata the caller already has
+///
+/// `metadata` should come from [`std::fs::symlink_metadata`] or
+/// [`std::fs::DirEntry::metadata`], so that symbolic links are reported as
+/// such; checking whether a link is broken reads its target's metadata.
+pub fn classify_metadata(path: &Path, metadata: &Metadata) -> FileClassification {
+    let kind = metadata.file_type();
+    let special = |file_type| FileClassification {
+        file_type,
+        language: None,
+        broken_link: false,
+    };
+
+    if kind.is_symlink() {
+        return FileClassification {
+            file_type: FileType::SymLink,
+            broken_link: std::fs::metadata(path).is_err(),
+            ..classify_name(path)
+        };
+    }
+    if kind.is_dir() {
+        return special(FileType::Directory);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+        if kind.is_socket() {
+            return special(FileType::Socket);
+        }
+        if kind.is_fifo() {
+            return special(FileType::Fifo);
+        }
+        if kind.is_block_device() {
+            return special(FileType::BlockDevice);
+        }
+        if kind.is_char_device() {
+            return special(FileType::CharDevice);
+        }
+        if metadata.permissions().mode() & 0o111 != 0 {
+            return FileClassification {
+                file_type: FileType::Executable,
+                ..classify_name(path)
+            };
+        }
+    }
+
+    classify_name(path)
+}
+
+/// Classify a path by its name, using the global [`FileClassifier`]
+fn classify_name(path: &Path) -> FileClassification {
+    let result = classifier::with_global_classifier(|classifier| {
+        classifier.classify(&path.to_string_lossy())
+    });
+    FileClassification {
+        file_type: result.file_type,
+        language: result.language,
+        broken_link: false,
+    }
+}
+
+/// Convenience constants for file types
+pub mod chars {
+    use super::*;
+
+    // File types
+    pub const FILE: FileType = FileType::File;
+    pub const DIRECTORY: FileType = FileType::Directory;
+    pub const EXECUTABLE: FileType = FileType::Executable;
+    pub const SYMLINK: FileType = FileType::SymLink;
+    pub const HIDDEN: FileType = FileType::Hidden;
+    pub const CONFIG: FileType = FileType::Config;
+    pub const DOCUMENTATION: FileType = FileType::Documentation;
+    pub const IMAGE: FileType = FileType::Image;
+    pub const VIDEO: FileType = FileType::Video;
+    pub const AUDIO: FileType = FileType::Audio;
+    pub const ARCHIVE: FileType = FileType::Archive;
+    pub const DATABASE: FileType = FileType::Database;
+    pub const LOG: FileType = FileType::Log;
+    pub const TEMPORARY: FileType = FileType::Temporary;
+    pub const BACKUP: FileType = FileType::Backup;
+    pub const SOCKET: FileType = FileType::Socket;
+    pub const FIFO: FileType = FileType::Fifo;
+    pub const BLOCK_DEVICE: FileType = FileType::BlockDevice;
+    pub const CHAR_DEVICE: FileType = FileType::CharDevice;
+    pub const BUILD_MANIFEST: FileType = FileType::BuildManifest;
+    pub const LOCKFILE: FileType = FileType::Lockfile;
+
+    // Language types
+    pub const RUST: LanguageType = LanguageType::Rust;
+    pub const JAVASCRIPT: LanguageType = LanguageType::JavaScript;
+    pub const PYTHON: LanguageType = LanguageType::Python;
+    pub const C: LanguageType = LanguageType::C;
+    pub const JAVA: LanguageType = LanguageType::Java;
+    pub const GO: LanguageType = LanguageType::Go;
+    pub const HTML: LanguageType = LanguageType::Html;
+    pub const CSS: LanguageType = LanguageType::Css;
+    pub const JSON: LanguageType = LanguageType::Json;
+    pub const XML: LanguageType = LanguageType::Xml;
+    pub const YAML: LanguageType = LanguageType::Yaml;
+    pub const TOML: LanguageType = LanguageType::Toml;
+    pub const MARKDOWN: LanguageType = LanguageType::Markdown;
+    pub const SHELL: LanguageType = LanguageType::Shell;
+    pub const SQL: LanguageType = LanguageType::Sql;
+    pub const DOCKER: LanguageType = LanguageType::Docker;
+    pub const GIT: LanguageType = LanguageType::Git;
+    pub const CODE: LanguageType = LanguageType::Code;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_name() {
+        let path = Path::new("no/such/dir/main.rs");
+        let classification = classify_path(path);
+        assert_eq!(classification.file_type, FileType::File);
+        assert_eq!(classification.language, Some(LanguageType::Rust));
+        assert_eq!(classify_path(Path::new("notes.unknown")).language, None);
+        assert_eq!(get_file_type_from_extension("unknown"), LanguageType::Code);
+    }
+
+    #[test]
+    fn test_extension_categories() {
+        let cases = [
+            ("holiday.JPEG", FileType::Image),
+            ("logo.svg", FileType::Image),
+            ("clip.mkv", FileType::Video),
+            ("song.flac", FileType::Audio),
+            ("backup.tar.gz", FileType::Archive),
+            ("release-1.2.tar.zst", FileType::Archive),
+            ("app.sqlite3", FileType::Database),
+            ("cache.sqlite", FileType::Database),
+            ("manual.pdf", FileType::Documentation),
+            ("setup.exe", FileType::Executable),
+            ("server.log", FileType::Log),
+            ("draft.tmp", FileType::Temporary),
+            ("config.yml.bak", FileType::Backup),
+            ("lib.rs", FileType::File),
+            (".hidden.png", FileType::Hidden),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(get_file_type_from_filename(name), expected, "{}", name);
+        }
+
+        let language = |name| classify_path(Path::new(name)).language;
+        assert_eq!(language("index.d.ts"), Some(LanguageType::TypeScript));
+        assert_eq!(language("build.rs.in"), Some(LanguageType::Rust));
+        assert_eq!(language("site.min.css"), Some(LanguageType::Css));
+        assert_eq!(language("data.tar.gz"), None);
+        assert_eq!(language("go.mod"), Some(LanguageType::Go));
+    }
+
+    #[test]
+    fn test_language_table() {
+        for (i, language) in LanguageType::all().enumerate() {
+            assert_eq!(language as usize, i);
+            assert!(language.get_char(UnicodeTheme::Minimal).is_ascii());
+            assert_eq!(LanguageType::from_name(language.name()), Some(language));
+        }
+        for table in [
+            languages::EXTENSIONS,
+            languages::FILENAMES,
+            languages::INTERPRETERS,
+            languages::NAMES,
+        ] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+
+        assert_eq!(LanguageType::Cpp.name(), "C++");
+        assert_eq!(LanguageType::Rust.color(), Some("#dea584"));
+        assert_eq!(LanguageType::from_name("CPP"), Some(LanguageType::Cpp));
+        assert_eq!(
+            LanguageType::from_interpreter("python3.12"),
+            Some(LanguageType::Python)
+        );
+        assert_eq!(
+            LanguageType::from_interpreter("ruby"),
+            Some(LanguageType::Ruby)
+        );
+        assert_eq!(LanguageType::from_interpreter("frobnicate"), None);
+
+        let language = |name| get_language_from_filename(name);
+        assert_eq!(language("main.cpp"), Some(LanguageType::Cpp));
+        assert_eq!(language("main.c"), Some(LanguageType::C));
+        assert_eq!(language("App.kt"), Some(LanguageType::Kotlin));
+        assert_eq!(language("main.tf"), Some(LanguageType::Hcl));
+        assert_eq!(language("schema.graphql"), Some(LanguageType::GraphQl));
+        assert_eq!(language("App.vue"), Some(LanguageType::Vue));
+        assert_eq!(language("Vagrantfile"), Some(LanguageType::Ruby));
+        // Claimed by V and Verilog alike
+        assert_eq!(language("top.v"), None);
+    }
+
+    #[test]
+    fn test_baseline_extensions() {
+        // Mappings from before the table was generated from Linguist
+        let cases = [
+            ("rs", LanguageType::Rust),
+            ("min.js", LanguageType::JavaScript),
+            ("d.ts", LanguageType::TypeScript),
+            ("d.mts", LanguageType::TypeScript),
+            ("pyc", LanguageType::Python),
+            ("pyo", LanguageType::Python),
+            ("pyd", LanguageType::Python),
+            ("class", LanguageType::Java),
+            ("jar", LanguageType::Java),
+            ("mod", LanguageType::Go),
+            ("sum", LanguageType::Go),
+            ("min.css", LanguageType::Css),
+            ("mysql", LanguageType::Sql),
+            ("sqlite", LanguageType::Sql),
+            ("containerfile", LanguageType::Docker),
+            ("gitignore", LanguageType::Git),
+            ("gitattributes", LanguageType::Git),
+            ("gitmodules", LanguageType::Git),
+        ];
+        for (extension, language) in cases {
+            assert_eq!(get_file_type_from_extension(extension), language, "{}", extension);
+        }
+    }
+
+    #[test]
+    fn test_well_known_filenames() {
+        use FileType::*;
+        use LanguageType as L;
+
+        let cases = [
+            ("Makefile", BuildManifest, Some(L::Makefile)),
+            ("CMakeLists.txt", BuildManifest, Some(L::CMake)),
+            ("Cargo.toml", BuildManifest, Some(L::Toml)),
+            ("package.json", BuildManifest, Some(L::Json)),
+            ("Dockerfile", BuildManifest, Some(L::Docker)),
+            ("Dockerfile.prod", BuildManifest, Some(L::Docker)),
+            ("web.Dockerfile", BuildManifest, Some(L::Docker)),
+            ("requirements-dev.txt", BuildManifest, None),
+            ("Cargo.lock", Lockfile, Some(L::Toml)),
+            ("package-lock.json", Lockfile, Some(L::Json)),
+            ("yarn.lock", Lockfile, Some(L::Yaml)),
+            ("go.sum", Lockfile, Some(L::Go)),
+            ("docker-compose.yml", Config, Some(L::Docker)),
+            ("docker-compose.override.yml", Config, Some(L::Docker)),
+            ("Jenkinsfile", Config, Some(L::Groovy)),
+            (".editorconfig", Config, Some(L::EditorConfig)),
+            (".env.production", Config, None),
+            (".gitignore", Config, Some(L::Git)),
+            ("vite.config.ts", Config, Some(L::TypeScript)),
+            ("vitest.config.mts", Config, Some(L::TypeScript)),
+            ("webpack.config.js", Config, Some(L::JavaScript)),
+            ("LICENSE", Documentation, None),
+            ("LICENSE-MIT", Documentation, None),
+            ("README.md", Documentation, Some(L::Markdown)),
+            ("CHANGELOG.md", Documentation, Some(L::Markdown)),
+        ];
+        for (name, file_type, language) in cases {
+            assert_eq!(get_file_type_from_filename(name), file_type, "{}", name);
+            assert_eq!(get_language_from_filename(name), language, "{}", name);
+        }
+        assert_eq!(get_file_type_from_filename(".envrc"), Config);
+        assert_eq!(get_file_type_from_filename("Makefile.bak"), Backup);
+        assert_eq!(get_file_type_from_filename("notes.env.txt"), Documentation);
+        assert_eq!(
+            FileClassifier::new().classify("vitest.config.mts").language,
+            Some(L::TypeScript)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_classify_metadata() {
+        use std::os::unix::fs::{symlink, PermissionsExt};
+
+        let dir = std::env::temp_dir().join(format!("unicode-rs-classify-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("deploy.sh");
+        std::fs::write(&script, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        symlink(&script, dir.join("link.sh")).unwrap();
+        symlink(dir.join("missing"), dir.join("broken")).unwrap();
+
+        assert_eq!(classify_path(&dir).file_type, FileType::Directory);
+        assert_eq!(
+            classify_path(&script),
+            FileClassification {
+                file_type: FileType::Executable,
+                language: Some(LanguageType::Shell),
+                broken_link: false,
+            }
+        );
+        let link = classify_path(&dir.join("link.sh"));
+        assert_eq!(link.file_type, FileType::SymLink);
+        assert_eq!(link.language, Some(LanguageType::Shell));
+        assert!(!link.broken_link);
+        assert!(classify_path(&dir.join("broken")).broken_link);
+        assert_eq!(
+            classify_path(Path::new("/dev/null")).file_type,
+            FileType::CharDevice
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -4,7 +4,7 @@
 use super::{UnicodeProvider, UnicodeTheme};
 
 /// Git file status indicators
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitStatus {
     /// File is modified
     Modified,
@@ -82,7 +82,7 @@ impl UnicodeProvider for GitStatus {
 }
 
 /// Git diff line indicators
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitDiff {
     /// Added line
     Added,
@@ -265,6 +265,291 @@ impl UnicodeProvider for GitAction {
     }
 }
 
+/// A file section of a unified diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFile {
+    /// Path before the change, `None` for added files
+    pub old_path: Option<String>,
+    /// Path after the change, `None` for deleted files
+    pub new_path: Option<String>,
+    /// Kind of change: `Modified`, `Added`, `Deleted`, `Renamed` or `Copied`
+    pub status: GitStatus,
+    /// Hunk lines in diff order
+    pub lines: Vec<DiffLine>,
+}
+
+impl DiffFile {
+    fn new() -> Self {
+        Self {
+            old_path: None,
+            new_path: None,
+            status: GitStatus::Modified,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Path of the file after the change, or before it for deleted files
+    pub fn path(&self) -> Option<&str> {
+        self.new_path.as_deref().or(self.old_path.as_deref())
+    }
+
+    /// Lines added by this diff
+    pub fn added_lines(&self) -> impl Iterator<Item = &DiffLine> {
+        self.lines.iter().filter(|line| line.kind == GitDiff::Added)
+    }
+}
+
+/// A single hunk line of a unified diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// `Added`, `Removed`, `Context` or `NoNewline`
+    pub kind: GitDiff,
+    /// 1-based line number in the old file, for removed and context lines
+    pub old_line: Option<usize>,
+    /// 1-based line number in the new file, for added and context lines
+    pub new_line: Option<usize>,
+    /// Line content without the leading `+`, `-` or space
+    pub text: String,
+}
+
+/// Parse unified diff output, such as `git diff` or `diff -u`, into files
+///
+/// Extended git headers (new, deleted, renamed and copied files) set the
+/// file status, and C-style quoted paths are unquoted. Lines that are not
+/// part of a file header or hunk are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::git::*;
+///
+/// let diff = "\
+/// diff --git a/src/lib.rs b/src/lib.rs
+/// --- a/src/lib.rs
+/// +++ b/src/lib.rs
+/// @@ -10,2 +10,2 @@
+///  fn main() {
+/// -    old();
+/// +    new();
+/// ";
+/// let files = parse_unified_diff(diff);
+/// assert_eq!(files[0].path(), Some("src/lib.rs"));
+/// let added: Vec<_> = files[0].added_lines().map(|l| (l.new_line, l.text.as_str())).collect();
+/// assert_eq!(added, vec![(Some(11), "    new();")]);
+/// ```
+pub fn parse_unified_diff(diff: &str) -> Vec<DiffFile> {
+    let mut files: Vec<DiffFile> = Vec::new();
+    // Set by `diff --git` until the `---` header of the same file is seen
+    let mut in_git_header = false;
+    let (mut old_line, mut new_line) = (0, 0);
+    let (mut old_left, mut new_left) = (0usize, 0usize);
+
+    for line in diff.split_terminator('\n') {
+        if old_left > 0 || new_left > 0 {
+            let hunk_line = match line.as_bytes().first() {
+                Some(b'+') => Some((GitDiff::Added, &line[1..])),
+                Some(b'-') => Some((GitDiff::Removed, &line[1..])),
+                Some(b' ') => Some((GitDiff::Context, &line[1..])),
+                Some(b'\\') => Some((GitDiff::NoNewline, &line[1..])),
+                // Some tools strip the space from empty context lines
+                None => Some((GitDiff::Context, "")),
+                Some(_) => None,
+            };
+            if let (Some((kind, text)), Some(file)) = (hunk_line, files.last_mut()) {
+                let old = matches!(kind, GitDiff::Removed | GitDiff::Context).then_some(old_line);
+                let new = matches!(kind, GitDiff::Added | GitDiff::Context).then_some(new_line);
+                if old.is_some() {
+                    old_line += 1;
+                    old_left = old_left.saturating_sub(1);
+                }
+                if new.is_some() {
+                    new_line += 1;
+                    new_left = new_left.saturating_sub(1);
+                }
+                file.lines.push(DiffLine {
+                    kind,
+                    old_line: old,
+                    new_line: new,
+                    text: text.to_string(),
+                });
+                continue;
+            }
+            // Hunk shorter than its header claimed; treat as a header line
+            old_left = 0;
+            new_left = 0;
+        }
+
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            let mut file = DiffFile::new();
+            if let Some((old, new)) = split_git_paths(rest) {
+                file.old_path = Some(old);
+                file.new_path = Some(new);
+            }
+            files.push(file);
+            in_git_header = true;
+        } else if let Some(rest) = line.strip_prefix("--- ") {
+            if !in_git_header {
+                files.push(DiffFile::new());
+            }
+            in_git_header = false;
+            if let Some(file) = files.last_mut() {
+                file.old_path = parse_diff_path(rest, "a/");
+                if file.old_path.is_none() {
+                    file.status = GitStatus::Added;
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("+++ ") {
+            if let Some(file) = files.last_mut() {
+                file.new_path = parse_diff_path(rest, "b/");
+                if file.new_path.is_none() {
+                    file.status = GitStatus::Deleted;
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("@@ -") {
+            if let Some((old, new)) = parse_hunk_header(rest) {
+                (old_line, old_left) = old;
+                (new_line, new_left) = new;
+            }
+        } else if let Some(file) = files.last_mut() {
+            if let Some(text) = line.strip_prefix('\\') {
+                // "\ No newline at end of file" after the last hunk line
+                file.lines.push(DiffLine {
+                    kind: GitDiff::NoNewline,
+                    old_line: None,
+                    new_line: None,
+                    text: text.to_string(),
+                });
+            } else if line.starts_with("new file mode") {
+                file.status = GitStatus::Added;
+                file.old_path = None;
+            } else if line.starts_with("deleted file mode") {
+                file.status = GitStatus::Deleted;
+                file.new_path = None;
+            } else if let Some(path) = line.strip_prefix("rename from ") {
+                file.status = GitStatus::Renamed;
+                file.old_path = Some(unquote_path(path));
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                file.new_path = Some(unquote_path(path));
+            } else if let Some(path) = line.strip_prefix("copy from ") {
+                file.status = GitStatus::Copied;
+                file.old_path = Some(unquote_path(path));
+            } else if let Some(path) = line.strip_prefix("copy to ") {
+                file.new_path = Some(unquote_path(path));
+            }
+        }
+    }
+
+    files
+}
+
+/// Parse `a,b +c,d @@` into `((a, b), (c, d))`
+fn parse_hunk_header(rest: &str) -> Option<((usize, usize), (usize, usize))> {
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    Some((range(old)?, range(new)?))
+}
+
+/// Path from a `---`/`+++` header; `None` for `/dev/null`
+fn parse_diff_path(rest: &str, prefix: &str) -> Option<String> {
+    // `diff -u` appends a tab and a timestamp
+    let path = if rest.starts_with('"') {
+        unquote_path(rest)
+    } else {
+        rest.split('\t').next().unwrap_or(rest).to_string()
+    };
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(
+        path.strip_prefix(prefix)
+            .map(str::to_string)
+            .unwrap_or(path),
+    )
+}
+
+/// Split the `a/old b/new` part of a `diff --git` line
+fn split_git_paths(rest: &str) -> Option<(String, String)> {
+    let (old, new) = if rest.starts_with('"') {
+        let end = quoted_len(rest)?;
+        (
+            unquote_path(&rest[..end]),
+            unquote_path(rest[end..].trim_start()),
+        )
+    } else if let Some(index) = rest.find(" \"") {
+        (rest[..index].to_string(), unquote_path(&rest[index + 1..]))
+    } else {
+        let (old, new) = rest.split_once(" b/")?;
+        (old.to_string(), format!("b/{}", new))
+    };
+    let old = old.strip_prefix("a/").map(str::to_string).unwrap_or(old);
+    let new = new.strip_prefix("b/").map(str::to_string).unwrap_or(new);
+    Some((old, new))
+}
+
+/// Byte length of the leading quoted string, including both quotes
+fn quoted_len(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (index, ch) in text.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Undo git's C-style path quoting, including octal-escaped UTF-8 bytes
+fn unquote_path(path: &str) -> String {
+    let Some(inner) = path
+        .strip_prefix('"')
+        .and_then(|rest| rest.get(..quoted_len(path)? - 2))
+    else {
+        return path.to_string();
+    };
+
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut iter = inner.bytes().peekable();
+    while let Some(byte) = iter.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+        match iter.next() {
+            Some(b'a') => bytes.push(0x07),
+            Some(b'b') => bytes.push(0x08),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b'v') => bytes.push(0x0B),
+            Some(b'f') => bytes.push(0x0C),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(digit @ b'0'..=b'7') => {
+                let mut value = u32::from(digit - b'0');
+                for _ in 0..2 {
+                    match iter.peek() {
+                        Some(&next @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(next - b'0');
+                            iter.next();
+                        }
+                        _ => break,
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
 /// Convenience constants for common git characters
 pub mod chars {
     use super::*;
@@ -309,3 +594,101 @@ pub mod chars {
     pub const ACTION_STASH: GitAction = GitAction::Stash;
     pub const ACTION_TAG: GitAction = GitAction::Tag;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_diff() {
+        let diff = "\
+diff --git a/README.md b/README.md
+index 1111111..2222222 100644
+--- a/README.md
++++ b/README.md
+@@ -1,3 +1,4 @@
+ # Title
+-old
++new
++more
+ end
+@@ -20 +21 @@ fn x()
+-a
++b
+\\ No newline at end of file
+diff --git a/gone.txt b/gone.txt
+deleted file mode 100644
+--- a/gone.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+diff --git a/old.rs b/new.rs
+similarity index 100%
+rename from old.rs
+rename to new.rs
+diff --git a/img.png b/img.png
+new file mode 100644
+Binary files /dev/null and b/img.png differ
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files.len(), 4);
+
+        let readme = &files[0];
+        assert_eq!(readme.status, GitStatus::Modified);
+        let added: Vec<_> = readme
+            .added_lines()
+            .map(|line| (line.new_line.unwrap(), line.text.as_str()))
+            .collect();
+        assert_eq!(added, vec![(2, "new"), (3, "more"), (21, "b")]);
+        assert_eq!(readme.lines.last().unwrap().kind, GitDiff::NoNewline);
+
+        assert_eq!(files[1].status, GitStatus::Deleted);
+        assert_eq!(files[1].path(), Some("gone.txt"));
+        assert_eq!(files[1].added_lines().count(), 0);
+
+        assert_eq!(files[2].status, GitStatus::Renamed);
+        assert_eq!(files[2].old_path.as_deref(), Some("old.rs"));
+        assert_eq!(files[2].new_path.as_deref(), Some("new.rs"));
+
+        assert_eq!(files[3].status, GitStatus::Added);
+        assert_eq!(files[3].path(), Some("img.png"));
+    }
+
+    #[test]
+    fn test_parse_plain_diff() {
+        let diff = "\
+--- a.txt\t2024-01-01 00:00:00
++++ a.txt\t2024-01-02 00:00:00
+@@ -1,2 +1,2 @@
+--- not a header
++++ not a header either
+ x
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path(), Some("a.txt"));
+        assert_eq!(files[0].lines[0].kind, GitDiff::Removed);
+        assert_eq!(files[0].lines[1].text, "++ not a header either");
+    }
+
+    #[test]
+    fn test_quoted_paths() {
+        assert_eq!(
+            unquote_path("\"caf\\303\\251 \\\"x\\\".txt\""),
+            "caf\u{e9} \"x\".txt"
+        );
+        assert_eq!(unquote_path("plain.txt"), "plain.txt");
+
+        let diff = "\
+diff --git \"a/\\342\\200\\256.txt\" \"b/\\342\\200\\256.txt\"
+new file mode 100644
+--- /dev/null
++++ \"b/\\342\\200\\256.txt\"
+@@ -0,0 +1 @@
++hi
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files[0].path(), Some("\u{202E}.txt"));
+        assert_eq!(files[0].status, GitStatus::Added);
+    }
+}
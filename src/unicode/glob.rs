@@ -0,0 +1,116 @@
+//! Glob matching shared by suppressions and file classification
+//!
+//! Patterns follow `.gitignore`: `*` and `?` match within one path component,
+//! `**` matches any number of components, a pattern without `/` matches the
+//! file name at any depth and a leading `/` anchors it at the root.
+
+/// Match a path against a `.gitignore` style glob
+pub(crate) fn glob_match(pattern: &str, path: &str) -> bool {
+    let mut segments = pattern_segments(pattern.trim_end_matches('/'));
+    // A matching directory covers everything below it
+    segments.push("**");
+
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&segments, &path)
+}
+
+/// Match a file path against a `.gitattributes` style glob
+///
+/// Unlike [`glob_match`], a pattern naming a directory does not cover the
+/// files below it, and a pattern ending in `/` matches no file; `dir/**`
+/// is the way to match a whole directory.
+pub(crate) fn path_match(pattern: &str, path: &str) -> bool {
+    if pattern.ends_with('/') {
+        return false;
+    }
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&pattern_segments(pattern), &path)
+}
+
+/// Components of a pattern, starting with `**` unless it is anchored
+fn pattern_segments(pattern: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    if !pattern.trim_start_matches('/').contains('/') {
+        segments.push("**");
+    }
+    segments.extend(pattern.trim_start_matches('/').split('/'));
+    segments
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                match_segment(segment.as_bytes(), name.as_bytes())
+                    && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Match one path component against `*` and `?` wildcards
+fn match_segment(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| match_segment(rest, &name[skip..])),
+        Some((b'?', rest)) => {
+            // `?` matches one character, which may span several bytes
+            let len = std::str::from_utf8(name)
+                .ok()
+                .and_then(|name| name.chars().next())
+                .map_or(1, char::len_utf8);
+            !name.is_empty() && match_segment(rest, &name[len.min(name.len())..])
+        }
+        Some((byte, rest)) => name.first() == Some(byte) && match_segment(rest, &name[1..]),
+    }
+}
+
+/// Paths are compared with `/` separators and without a leading `./`
+pub(crate) fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+/// Match a file name against `*` and `?` wildcards
+pub(crate) fn wildcard_match(pattern: &str, name: &str) -> bool {
+    match_segment(pattern.as_bytes(), name.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.rs", "src/lib.rs"));
+        assert!(glob_match("/src/*.rs", "src/lib.rs"));
+        assert!(!glob_match("/src/*.rs", "crates/src/lib.rs"));
+        assert!(!glob_match("src/*.rs", "src/unicode/mod.rs"));
+        assert!(glob_match("src/**/*.rs", "src/unicode/mod.rs"));
+        assert!(glob_match("src/**/*.rs", "src/lib.rs"));
+        assert!(glob_match("locales", "locales/he/ui.json"));
+        assert!(glob_match("?é.txt", "aé.txt"));
+        assert!(!glob_match("?.txt", "ab.txt"));
+    }
+
+    #[test]
+    fn test_path_match() {
+        assert!(path_match("*.js", "pkg/app.js"));
+        assert!(!path_match("*.js", "pkg/app.js/index.ts"));
+        assert!(!path_match("src", "src/main.rs"));
+        assert!(path_match("src", "lib/src"));
+        assert!(path_match("vendor/**", "vendor/lib/lib.go"));
+        assert!(!path_match("vendor/", "vendor/lib.go"));
+        assert!(!path_match("/docs/*.md", "docs/api/index.md"));
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("Dockerfile.*", "Dockerfile.dev"));
+        assert!(!wildcard_match("Dockerfile.*", "Dockerfile"));
+        assert!(wildcard_match("*.config.?s", "vite.config.ts"));
+    }
+}
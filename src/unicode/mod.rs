@@ -6,10 +6,15 @@ pub mod blocks;
 pub mod editor;
 pub mod file_types;
 pub mod git;
+mod glob;
+#[cfg(feature = "names")]
+pub mod names;
+pub mod normalization;
 pub mod security;
 pub mod shapes;
 pub mod status;
 pub mod symbols;
+mod tables;
 pub mod ui;
 
 // Re-export main types for convenience
@@ -17,9 +22,12 @@ pub use arrows::{Arrow, Navigation};
 pub use blocks::Block;
 pub use editor::{Cursor, Selection};
 pub use file_types::{
-    get_file_type_from_extension, get_file_type_from_filename, FileType, LanguageType,
+    classify_content, classify_directory, classify_file, classify_metadata, classify_path,
+    get_file_type_from_extension, get_file_type_from_filename, get_language_from_filename,
+    is_binary_content, mime_type_from_filename, ContentClassification, DirectoryIcon,
+    DirectoryKind, FileClassification, FileClassifier, FileType, LanguageType, LsColors,
 };
-pub use git::{GitAction, GitBranch, GitDiff, GitStatus};
+pub use git::{parse_unified_diff, DiffFile, DiffLine, GitAction, GitBranch, GitDiff, GitStatus};
 pub use shapes::Shape;
 pub use status::Status;
 pub use symbols::Symbol;
@@ -0,0 +1,258 @@
+//! Unicode character names
+//!
+//! Lookups in the Unicode Name property and the formal name aliases, backed by
+//! a compressed copy of the Unicode Character Database embedded in the binary.
+//! The table adds a few hundred kilobytes, so this module is only available
+//! with the `names` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::names::*;
+//!
+//! assert_eq!(char_name('\u{2064}').as_deref(), Some("INVISIBLE PLUS"));
+//! assert_eq!(char_from_name("ZERO WIDTH SPACE"), Some('\u{200B}'));
+//! assert_eq!(char_from_name("zero_width_space"), Some('\u{200B}'));
+//! ```
+
+use crate::unicode::tables::{self, names::*};
+
+const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+const HANGUL_SYLLABLE_COUNT: u32 = 11172;
+const HANGUL_SYLLABLE_PREFIX: &str = "HANGUL SYLLABLE ";
+
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO",
+    "WE", "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// Get the Unicode name of a character
+///
+/// Returns the value of the Name property, e.g. `"ZERO WIDTH SPACE"`.
+/// Control characters have no Name; for those the control name alias is
+/// returned instead (`'\n'` gives `"LINE FEED"`). Unassigned code points,
+/// private-use characters and noncharacters return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::names::char_name;
+///
+/// assert_eq!(char_name('a').as_deref(), Some("LATIN SMALL LETTER A"));
+/// assert_eq!(char_name('\u{1D173}').as_deref(), Some("MUSICAL SYMBOL BEGIN BEAM"));
+/// assert_eq!(char_name('\u{4E16}').as_deref(), Some("CJK UNIFIED IDEOGRAPH-4E16"));
+/// assert_eq!(char_name('\u{E000}'), None);
+/// ```
+pub fn char_name(ch: char) -> Option<String> {
+    let cp = ch as u32;
+
+    if let Some(index) = cp.checked_sub(HANGUL_SYLLABLE_BASE) {
+        if index < HANGUL_SYLLABLE_COUNT {
+            return Some(hangul_syllable_name(index));
+        }
+    }
+
+    if let Some(prefix) = tables::bsearch_range_value_table(ch, ALGORITHMIC_NAMES) {
+        return Some(format!("{}{:04X}", prefix, cp));
+    }
+
+    if let Some((lo, start)) = find_name_range(ch) {
+        let mut pos = start as usize;
+        for _ in lo as u32..cp {
+            pos += 1 + NAME_BYTES[pos] as usize;
+        }
+        let len = NAME_BYTES[pos] as usize;
+        return Some(decode_name(&NAME_BYTES[pos + 1..pos + 1 + len]));
+    }
+
+    NAME_ALIASES
+        .iter()
+        .find(|&&(c, _, kind)| c == ch && kind == "control")
+        .map(|&(_, alias, _)| alias.to_string())
+}
+
+/// Look up a character by its Unicode name or formal name alias
+///
+/// Matching ignores case and treats underscores like spaces, so
+/// `"zero_width_space"` finds U+200B. Algorithmic names such as
+/// `"CJK UNIFIED IDEOGRAPH-4E16"` and Hangul syllable names are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::names::char_from_name;
+///
+/// assert_eq!(char_from_name("RIGHT-TO-LEFT OVERRIDE"), Some('\u{202E}'));
+/// assert_eq!(char_from_name("BYTE ORDER MARK"), Some('\u{FEFF}')); // alias
+/// assert_eq!(char_from_name("HANGUL SYLLABLE GAG"), Some('\u{AC01}'));
+/// assert_eq!(char_from_name("NOT A CHARACTER NAME"), None);
+/// ```
+pub fn char_from_name(name: &str) -> Option<char> {
+    let query = normalize_query(name);
+
+    if let Some(syllable) = query.strip_prefix(HANGUL_SYLLABLE_PREFIX) {
+        if let Some(ch) = hangul_syllable_from_name(syllable) {
+            return Some(ch);
+        }
+    }
+
+    for &(lo, hi, prefix) in ALGORITHMIC_NAMES {
+        if let Some(hex) = query.strip_prefix(prefix) {
+            let ch = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+            if let Some(ch) = ch.filter(|&ch| ch >= lo && ch <= hi) {
+                return Some(ch);
+            }
+        }
+    }
+
+    if let Some(encoded) = encode_name(&query) {
+        for &(lo, hi, start) in NAME_RANGES {
+            let mut pos = start as usize;
+            for cp in lo as u32..=hi as u32 {
+                let len = NAME_BYTES[pos] as usize;
+                if NAME_BYTES[pos + 1..pos + 1 + len] == encoded[..] {
+                    return char::from_u32(cp);
+                }
+                pos += 1 + len;
+            }
+        }
+    }
+
+    NAME_ALIASES
+        .iter()
+        .find(|&&(_, alias, _)| alias == query)
+        .map(|&(ch, _, _)| ch)
+}
+
+fn find_name_range(ch: char) -> Option<(char, u32)> {
+    let i = NAME_RANGES
+        .binary_search_by(|&(lo, hi, _)| {
+            if ch < lo {
+                std::cmp::Ordering::Greater
+            } else if ch > hi {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+    Some((NAME_RANGES[i].0, NAME_RANGES[i].2))
+}
+
+fn word(n: usize) -> &'static str {
+    &LEXICON[LEXICON_OFFSETS[n] as usize..LEXICON_OFFSETS[n + 1] as usize]
+}
+
+fn decode_name(bytes: &[u8]) -> String {
+    let mut name = String::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let n = if bytes[i] < 0x80 {
+            i += 1;
+            bytes[i - 1] as usize
+        } else {
+            i += 2;
+            ((bytes[i - 2] as usize & 0x7F) << 8) | bytes[i - 1] as usize
+        };
+        if !name.is_empty() {
+            name.push(' ');
+        }
+        name.push_str(word(n));
+    }
+    name
+}
+
+fn encode_name(name: &str) -> Option<Vec<u8>> {
+    let mut encoded = Vec::new();
+    for part in name.split(' ') {
+        let n = (0..LEXICON_OFFSETS.len() - 1).find(|&n| word(n) == part)?;
+        if n < 0x80 {
+            encoded.push(n as u8);
+        } else {
+            encoded.push(0x80 | (n >> 8) as u8);
+            encoded.push(n as u8);
+        }
+    }
+    Some(encoded)
+}
+
+fn normalize_query(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase()
+}
+
+fn hangul_syllable_name(index: u32) -> String {
+    let l = (index / (21 * 28)) as usize;
+    let v = ((index % (21 * 28)) / 28) as usize;
+    let t = (index % 28) as usize;
+    format!("{}{}{}{}", HANGUL_SYLLABLE_PREFIX, JAMO_L[l], JAMO_V[v], JAMO_T[t])
+}
+
+fn hangul_syllable_from_name(syllable: &str) -> Option<char> {
+    for (l, lead) in JAMO_L.iter().enumerate() {
+        let Some(rest) = syllable.strip_prefix(lead) else {
+            continue;
+        };
+        for (v, vowel) in JAMO_V.iter().enumerate() {
+            let Some(tail) = rest.strip_prefix(vowel) else {
+                continue;
+            };
+            if let Some(t) = JAMO_T.iter().position(|&t| t == tail) {
+                let index = (l * 21 * 28 + v * 28 + t) as u32;
+                return char::from_u32(HANGUL_SYLLABLE_BASE + index);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_names() {
+        assert_eq!(char_name('A').as_deref(), Some("LATIN CAPITAL LETTER A"));
+        assert_eq!(char_name('\u{200D}').as_deref(), Some("ZERO WIDTH JOINER"));
+        assert_eq!(char_name('\u{1F600}').as_deref(), Some("GRINNING FACE"));
+        assert_eq!(char_name('\u{10FFFF}'), None);
+        assert_eq!(char_name('\u{0378}'), None);
+    }
+
+    #[test]
+    fn test_control_aliases() {
+        assert_eq!(char_name('\0').as_deref(), Some("NULL"));
+        assert_eq!(char_name('\n').as_deref(), Some("LINE FEED"));
+        assert_eq!(char_from_name("line feed"), Some('\n'));
+    }
+
+    #[test]
+    fn test_algorithmic_names() {
+        assert_eq!(char_name('\u{AC00}').as_deref(), Some("HANGUL SYLLABLE GA"));
+        assert_eq!(char_name('\u{D7A3}').as_deref(), Some("HANGUL SYLLABLE HIH"));
+        assert_eq!(char_name('\u{F900}').as_deref(), Some("CJK COMPATIBILITY IDEOGRAPH-F900"));
+        assert_eq!(char_name('\u{17000}').as_deref(), Some("TANGUT IDEOGRAPH-17000"));
+        assert_eq!(char_from_name("HANGUL SYLLABLE HIH"), Some('\u{D7A3}'));
+        assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-4E00"), Some('\u{4E00}'));
+        assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-0041"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for ch in ['\u{00AD}', '\u{034F}', '\u{2066}', '\u{E0001}', '\u{1D17A}', 'ж'] {
+            let name = char_name(ch).unwrap();
+            assert_eq!(char_from_name(&name), Some(ch), "{}", name);
+        }
+    }
+}
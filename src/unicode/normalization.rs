@@ -0,0 +1,258 @@
+//! Unicode normalization
+//!
+//! The four normalization forms of UAX #15 (NFD, NFC, NFKD and NFKC), backed
+//! by the decomposition and composition data of the Unicode Character
+//! Database, and full case folding for caseless matching.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::normalization::*;
+//!
+//! assert_eq!(nfd("é"), "e\u{0301}");
+//! assert_eq!(nfc("e\u{0301}"), "é");
+//! assert_eq!(nfkc("ﬁ"), "fi");
+//! assert!(is_nfc("café"));
+//! assert_eq!(casefold("Straße"), "strasse");
+//! assert_eq!(nfkc_casefold("Ｓｔｒａßｅ"), "strasse");
+//! ```
+
+use crate::unicode::tables::{self, case_folding::CASE_FOLDING, normalization::*, property_bool};
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Normalize to Normalization Form D (canonical decomposition)
+pub fn nfd(text: &str) -> String {
+    normalize(text, false, false)
+}
+
+/// Normalize to Normalization Form C (canonical decomposition, then composition)
+pub fn nfc(text: &str) -> String {
+    normalize(text, false, true)
+}
+
+/// Normalize to Normalization Form KD (compatibility decomposition)
+pub fn nfkd(text: &str) -> String {
+    normalize(text, true, false)
+}
+
+/// Normalize to Normalization Form KC (compatibility decomposition, then composition)
+pub fn nfkc(text: &str) -> String {
+    normalize(text, true, true)
+}
+
+/// Check if text is in Normalization Form C
+pub fn is_nfc(text: &str) -> bool {
+    text.is_ascii() || nfc(text) == text
+}
+
+/// Check if text is in Normalization Form KC
+pub fn is_nfkc(text: &str) -> bool {
+    text.is_ascii() || nfkc(text) == text
+}
+
+/// Apply full case folding (`CaseFolding.txt` statuses C and F)
+///
+/// Folding is locale-independent: `İ` folds to `i` followed by a combining
+/// dot and dotless `ı` is left alone.
+pub fn casefold(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_ascii_lowercase();
+    }
+    let mut folded = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match CASE_FOLDING.binary_search_by_key(&ch, |&(c, _)| c) {
+            Ok(i) => folded.push_str(CASE_FOLDING[i].1),
+            Err(_) => folded.push(ch),
+        }
+    }
+    folded
+}
+
+/// Normalize to the NFKC_Casefold form used for caseless identifier matching
+///
+/// Removes default ignorable code points, then applies case folding and
+/// compatibility normalization until the result is stable under both, so
+/// that fullwidth and case variants of a string share one form.
+pub fn nfkc_casefold(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_ascii_lowercase();
+    }
+    let visible: String = nfd(text)
+        .chars()
+        .filter(|&ch| !tables::bsearch_range_table(ch, property_bool::DEFAULT_IGNORABLE_CODE_POINT))
+        .collect();
+    nfkc(&casefold(&nfkd(&casefold(&visible))))
+}
+
+/// Get the Canonical_Combining_Class of a character
+pub fn canonical_combining_class(ch: char) -> u8 {
+    tables::bsearch_range_value_table(ch, CANONICAL_COMBINING_CLASS).unwrap_or(0)
+}
+
+fn normalize(text: &str, compat: bool, compose: bool) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+
+    let mut chars = Vec::with_capacity(text.len());
+    for ch in text.chars() {
+        decompose(ch, compat, &mut chars);
+    }
+    canonical_order(&mut chars);
+    if compose {
+        chars = canonical_compose(chars);
+    }
+    chars.into_iter().collect()
+}
+
+fn decompose(ch: char, compat: bool, out: &mut Vec<char>) {
+    let cp = ch as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let s_index = cp - S_BASE;
+        let l = L_BASE + s_index / N_COUNT;
+        let v = V_BASE + (s_index % N_COUNT) / T_COUNT;
+        let t = T_BASE + s_index % T_COUNT;
+        out.extend(char::from_u32(l));
+        out.extend(char::from_u32(v));
+        if t != T_BASE {
+            out.extend(char::from_u32(t));
+        }
+        return;
+    }
+
+    if let Ok(i) = DECOMPOSITION.binary_search_by_key(&ch, |&(c, _, _)| c) {
+        let (_, is_compat, mapping) = DECOMPOSITION[i];
+        if compat || !is_compat {
+            for &c in mapping {
+                decompose(c, compat, out);
+            }
+            return;
+        }
+    }
+    out.push(ch);
+}
+
+/// Apply the canonical ordering algorithm: stable-sort each run of non-starters
+fn canonical_order(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if canonical_combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len() && canonical_combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        chars[start..end].sort_by_key(|&c| canonical_combining_class(c));
+        start = end;
+    }
+}
+
+fn canonical_compose(chars: Vec<char>) -> Vec<char> {
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    // Combining class of the last character appended after the starter
+    let mut last_class: Option<u8> = None;
+
+    for ch in chars {
+        let class = canonical_combining_class(ch);
+        if let Some(pos) = starter {
+            let blocked = matches!(last_class, Some(last) if last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(out[pos], ch) {
+                    out[pos] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(ch);
+    }
+    out
+}
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a_cp, b_cp) = (a as u32, b as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&a_cp) && (V_BASE..V_BASE + V_COUNT).contains(&b_cp) {
+        let lv = S_BASE + ((a_cp - L_BASE) * V_COUNT + (b_cp - V_BASE)) * T_COUNT;
+        return char::from_u32(lv);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a_cp)
+        && (a_cp - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b_cp)
+    {
+        return char::from_u32(a_cp + (b_cp - T_BASE));
+    }
+    COMPOSITION
+        .binary_search_by_key(&(a, b), |&(pair, _)| pair)
+        .ok()
+        .map(|i| COMPOSITION[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_forms() {
+        assert_eq!(nfd("\u{00C5}"), "A\u{030A}");
+        assert_eq!(nfc("A\u{030A}"), "\u{00C5}");
+        // Angstrom sign is a singleton decomposition and never recomposes
+        assert_eq!(nfc("\u{212B}"), "\u{00C5}");
+        // Combining marks are reordered by combining class
+        assert_eq!(nfd("a\u{0301}\u{0323}"), "a\u{0323}\u{0301}");
+        assert_eq!(nfc("a\u{0301}\u{0323}"), "\u{1EA1}\u{0301}");
+    }
+
+    #[test]
+    fn test_compatibility_forms() {
+        assert_eq!(nfkc("\u{FF21}\u{FF22}"), "AB");
+        assert_eq!(nfkd("\u{2460}"), "1");
+        assert_eq!(nfc("\u{FB01}"), "\u{FB01}");
+        assert_eq!(nfkc("\u{FB01}"), "fi");
+    }
+
+    #[test]
+    fn test_hangul() {
+        assert_eq!(nfd("\u{AC01}"), "\u{1100}\u{1161}\u{11A8}");
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+    }
+
+    #[test]
+    fn test_composition_exclusions() {
+        // U+0958 DEVANAGARI LETTER QA is excluded from composition
+        assert_eq!(nfc("\u{0958}"), "\u{0915}\u{093C}");
+        assert!(!is_nfc("\u{0958}"));
+        assert!(is_nfc("plain ascii"));
+    }
+
+    #[test]
+    fn test_case_folding() {
+        assert_eq!(casefold("HeLLo"), "hello");
+        assert_eq!(casefold("ΣΊΣΥΦΟΣ"), "σίσυφοσ");
+        assert_eq!(casefold("\u{0130}"), "i\u{0307}");
+        assert_eq!(casefold("\u{0131}"), "\u{0131}");
+        assert_eq!(casefold("\u{FB03}"), "ffi");
+        // Folding alone keeps compatibility characters
+        assert_eq!(casefold("\u{FF21}"), "\u{FF41}");
+        assert_eq!(nfkc_casefold("\u{FF21}\u{212B}"), "a\u{00E5}");
+        assert_eq!(nfkc_casefold("A\u{00AD}B\u{200B}"), "ab");
+        // Folding the compatibility decomposition again: U+2168 ROMAN NUMERAL NINE
+        assert_eq!(nfkc_casefold("\u{2168}"), "ix");
+    }
+}
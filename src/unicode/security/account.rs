@@ -0,0 +1,602 @@
+//! Analysis presets for account fields
+//!
+//! Email addresses and usernames are attacked differently from free text:
+//!
+//! - [`analyze_email`] parses `local@domain` or `Display Name <local@domain>`,
+//!   decodes and checks internationalized domain labels, flags invisible and
+//!   confusable characters in the local part, and detects display names that
+//!   spoof an address
+//! - [`analyze_username`] validates a username or `@handle` as an identifier
+//!   restricted to a single script
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let config = EmailConfig::default().with_trusted_address("ceo@example.com");
+//!
+//! let analysis = analyze_email_with_config("ceo@ex\u{0430}mple.com", &config);
+//! assert_eq!(
+//!     analysis.issues,
+//!     vec![
+//!         EmailIssue::MixedScriptLabel {
+//!             label: "ex\u{0430}mple".to_string(),
+//!             scripts: vec![Script::Latin, Script::Cyrillic],
+//!         },
+//!         EmailIssue::LookalikeAddress {
+//!             trusted: "ceo@example.com".to_string()
+//!         },
+//!     ]
+//! );
+//! assert_eq!(analysis.risk_level, RiskLevel::Critical);
+//!
+//! let analysis = analyze_email_with_config("\"ceo@example.com\" <x@attacker.test>", &config);
+//! assert!(analysis.issues.contains(&EmailIssue::DisplayNameContainsAddress));
+//!
+//! assert!(analyze_username("@jane_doe").is_valid());
+//! assert!(!analyze_username("j\u{0430}ne").is_valid());
+//! ```
+
+use std::fmt;
+
+use super::identifier::ScriptMix;
+use super::{
+    analyze_text, is_identifier_allowed, skeleton, validate_identifier, CollisionMode,
+    IdentifierProfile, IdentifierRejection, RiskLevel, Script, SecurityAnalysis,
+};
+use crate::unicode::normalization::{casefold, nfc, nfkc_casefold};
+
+/// Email analysis configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EmailConfig {
+    /// Addresses that lookalikes and display names must not imitate
+    pub trusted_addresses: Vec<String>,
+}
+
+impl EmailConfig {
+    /// Add a trusted address
+    pub fn with_trusted_address(mut self, address: &str) -> Self {
+        self.trusted_addresses.push(address.to_string());
+        self
+    }
+}
+
+/// Problem found in an email address
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EmailIssue {
+    /// The address could not be parsed
+    Malformed {
+        /// What is wrong
+        reason: &'static str,
+    },
+    /// A domain label is empty, too long or misplaces hyphens
+    InvalidLabel {
+        /// The label as written
+        label: String,
+    },
+    /// An `xn--` label is not valid Punycode
+    InvalidPunycode {
+        /// The label as written
+        label: String,
+    },
+    /// A domain label contains a character not allowed in identifiers (UTS #39)
+    DisallowedDomainChar {
+        /// The decoded label
+        label: String,
+        /// The character
+        ch: char,
+    },
+    /// A domain label mixes scripts beyond the Highly Restrictive level
+    MixedScriptLabel {
+        /// The decoded label
+        label: String,
+        /// Scripts in order of first appearance
+        scripts: Vec<Script>,
+    },
+    /// A non-ASCII domain label looks like an ASCII label
+    WholeScriptConfusable {
+        /// The decoded label
+        label: String,
+        /// Skeleton of the label, the ASCII text it can be mistaken for
+        lookalike: String,
+    },
+    /// The display name contains an address other than the actual one
+    DisplayNameContainsAddress,
+    /// The display name imitates a trusted address
+    DisplayNameLookalike {
+        /// The imitated address
+        trusted: String,
+    },
+    /// The address is confusable with, but not equal to, a trusted address
+    LookalikeAddress {
+        /// The imitated address
+        trusted: String,
+    },
+}
+
+impl EmailIssue {
+    /// Risk level of this issue on its own
+    pub fn risk_level(&self) -> RiskLevel {
+        match self {
+            EmailIssue::Malformed { .. } | EmailIssue::InvalidLabel { .. } => RiskLevel::Medium,
+            EmailIssue::InvalidPunycode { .. }
+            | EmailIssue::DisallowedDomainChar { .. }
+            | EmailIssue::MixedScriptLabel { .. }
+            | EmailIssue::WholeScriptConfusable { .. }
+            | EmailIssue::DisplayNameContainsAddress => RiskLevel::High,
+            EmailIssue::DisplayNameLookalike { .. } | EmailIssue::LookalikeAddress { .. } => {
+                RiskLevel::Critical
+            }
+        }
+    }
+}
+
+impl fmt::Display for EmailIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailIssue::Malformed { reason } => write!(f, "malformed address: {}", reason),
+            EmailIssue::InvalidLabel { label } => write!(f, "invalid domain label {:?}", label),
+            EmailIssue::InvalidPunycode { label } => {
+                write!(f, "invalid Punycode label {:?}", label)
+            }
+            EmailIssue::DisallowedDomainChar { label, ch } => write!(
+                f,
+                "domain label {:?} contains disallowed U+{:04X}",
+                label, *ch as u32
+            ),
+            EmailIssue::MixedScriptLabel { label, scripts } => {
+                let names: Vec<_> = scripts.iter().map(|s| s.name()).collect();
+                write!(
+                    f,
+                    "domain label {:?} mixes scripts {}",
+                    label,
+                    names.join(", ")
+                )
+            }
+            EmailIssue::WholeScriptConfusable { label, lookalike } => {
+                write!(f, "domain label {:?} looks like {:?}", label, lookalike)
+            }
+            EmailIssue::DisplayNameContainsAddress => {
+                write!(f, "display name contains a different address")
+            }
+            EmailIssue::DisplayNameLookalike { trusted } => {
+                write!(f, "display name imitates {}", trusted)
+            }
+            EmailIssue::LookalikeAddress { trusted } => write!(f, "address imitates {}", trusted),
+        }
+    }
+}
+
+/// Email address analysis result
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EmailAnalysis {
+    /// Display name of a `Name <address>` form, without quotes
+    pub display_name: Option<String>,
+    /// Local part as written
+    pub local_part: String,
+    /// Domain as written
+    pub domain: String,
+    /// Domain with `xn--` labels decoded and labels mapped with NFKC_Casefold
+    pub unicode_domain: String,
+    /// Analysis of the local part
+    pub local_part_analysis: SecurityAnalysis,
+    /// Problems with the address, domain and display name
+    pub issues: Vec<EmailIssue>,
+    /// Overall risk level
+    pub risk_level: RiskLevel,
+}
+
+/// Analyze an email address, optionally with a display name
+pub fn analyze_email(text: &str) -> EmailAnalysis {
+    analyze_email_with_config(text, &EmailConfig::default())
+}
+
+/// Analyze an email address using the given configuration
+pub fn analyze_email_with_config(text: &str, config: &EmailConfig) -> EmailAnalysis {
+    let text = text.trim();
+    let (display_name, address) = match (text.rfind('<'), text.ends_with('>')) {
+        (Some(open), true) => {
+            let name = text[..open].trim().trim_matches('"').trim();
+            let name = (!name.is_empty()).then(|| name.to_string());
+            (name, &text[open + 1..text.len() - 1])
+        }
+        _ => (None, text),
+    };
+
+    let mut issues = Vec::new();
+    let (local_part, domain) = match address.rsplit_once('@') {
+        Some(parts) => parts,
+        None => {
+            issues.push(EmailIssue::Malformed {
+                reason: "missing @",
+            });
+            (address, "")
+        }
+    };
+    if local_part.is_empty() {
+        issues.push(EmailIssue::Malformed {
+            reason: "empty local part",
+        });
+    }
+    if domain.is_empty() && address.contains('@') {
+        issues.push(EmailIssue::Malformed {
+            reason: "empty domain",
+        });
+    }
+
+    let mut labels = Vec::new();
+    if !domain.is_empty() {
+        for label in domain.strip_suffix('.').unwrap_or(domain).split('.') {
+            labels.push(check_label(label, &mut issues));
+        }
+    }
+    let unicode_domain = labels.join(".");
+
+    // Compare addresses by their decoded, case-folded form
+    let normalized = casefold(&format!("{}@{}", nfc(local_part), unicode_domain));
+    let key = CollisionMode::Skeleton.key(&normalized);
+    for trusted in &config.trusted_addresses {
+        if casefold(trusted) != normalized && CollisionMode::Skeleton.key(trusted) == key {
+            issues.push(EmailIssue::LookalikeAddress {
+                trusted: trusted.clone(),
+            });
+        }
+    }
+
+    if let Some(name) = &display_name {
+        let folded = nfkc_casefold(name);
+        if folded.contains('@') && !folded.contains(&normalized) {
+            issues.push(EmailIssue::DisplayNameContainsAddress);
+        }
+        let name_key = CollisionMode::Skeleton.key(name);
+        for trusted in &config.trusted_addresses {
+            if casefold(trusted) != normalized
+                && name_key.contains(&CollisionMode::Skeleton.key(trusted))
+            {
+                issues.push(EmailIssue::DisplayNameLookalike {
+                    trusted: trusted.clone(),
+                });
+            }
+        }
+    }
+
+    let local_part_analysis = analyze_text(local_part);
+    let risk_level = issues
+        .iter()
+        .map(EmailIssue::risk_level)
+        .fold(local_part_analysis.risk_level, RiskLevel::max);
+
+    EmailAnalysis {
+        display_name,
+        local_part: local_part.to_string(),
+        domain: domain.to_string(),
+        unicode_domain,
+        local_part_analysis,
+        issues,
+        risk_level,
+    }
+}
+
+/// Decode and check one domain label, returning its Unicode form
+fn check_label(label: &str, issues: &mut Vec<EmailIssue>) -> String {
+    let is_a_label = label
+        .get(..4)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"));
+    let decoded = if is_a_label {
+        match punycode_decode(&label[4..]) {
+            Some(decoded) if !decoded.is_ascii() => decoded,
+            _ => {
+                issues.push(EmailIssue::InvalidPunycode {
+                    label: label.to_string(),
+                });
+                return label.to_ascii_lowercase();
+            }
+        }
+    } else {
+        label.to_string()
+    };
+    let decoded = nfkc_casefold(&decoded);
+
+    if decoded.is_empty()
+        || label.len() > 63
+        || decoded.starts_with('-')
+        || decoded.ends_with('-')
+        // "ab--" is reserved for A-labels
+        || (!is_a_label && decoded.get(2..4) == Some("--"))
+    {
+        issues.push(EmailIssue::InvalidLabel {
+            label: label.to_string(),
+        });
+    }
+    if decoded.is_ascii() {
+        return decoded;
+    }
+
+    if let Some(ch) = decoded.chars().find(|&ch| {
+        if ch.is_ascii() {
+            !(ch.is_ascii_alphanumeric() || ch == '-')
+        } else {
+            !is_identifier_allowed(ch)
+        }
+    }) {
+        issues.push(EmailIssue::DisallowedDomainChar {
+            label: decoded.clone(),
+            ch,
+        });
+    }
+
+    let scripts = ScriptMix::of(&decoded);
+    if !scripts.is_highly_restrictive() {
+        issues.push(EmailIssue::MixedScriptLabel {
+            label: decoded.clone(),
+            scripts: scripts.scripts,
+        });
+    } else {
+        let lookalike = skeleton(&decoded);
+        if lookalike.is_ascii() {
+            issues.push(EmailIssue::WholeScriptConfusable {
+                label: decoded.clone(),
+                lookalike,
+            });
+        }
+    }
+    decoded
+}
+
+/// Decode the part of a Punycode label after `xn--` (RFC 3492)
+fn punycode_decode(input: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+    let (mut n, mut i, mut bias) = (0x80u32, 0u32, 72u32);
+    let mut digits = extended.bytes().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next()? {
+                b @ b'a'..=b'z' => b - b'a',
+                b @ b'A'..=b'Z' => b - b'A',
+                b @ b'0'..=b'9' => b - b'0' + 26,
+                _ => return None,
+            } as u32;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = punycode_adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+fn punycode_adapt(delta: u32, num_points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / 700 } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > 35 * 26 / 2 {
+        delta /= 35;
+        k += 36;
+    }
+    k + 36 * delta / (delta + 38)
+}
+
+/// Username analysis result
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UsernameAnalysis {
+    /// The username without a leading `@`
+    pub username: String,
+    /// Why the username is rejected, empty if it is valid
+    pub rejections: Vec<IdentifierRejection>,
+    /// Overall risk level
+    pub risk_level: RiskLevel,
+}
+
+impl UsernameAnalysis {
+    /// Check whether the username passed every check
+    pub fn is_valid(&self) -> bool {
+        self.rejections.is_empty()
+    }
+}
+
+/// Analyze a username or `@handle`
+///
+/// Usernames are identifiers under the default [`IdentifierProfile`] that may
+/// also start with a digit and contain `-` and `.`, and must be written in a
+/// single script: unlike identifiers, Han mixed with kana or Hangul is rejected.
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// assert!(analyze_username("2fast.4you").is_valid());
+/// assert!(analyze_username("ユーザー").is_valid());
+/// assert!(!analyze_username("東京タワー").is_valid());
+/// assert_eq!(analyze_username("j\u{200D}ane").risk_level, RiskLevel::High);
+/// ```
+pub fn analyze_username(name: &str) -> UsernameAnalysis {
+    let username = name.strip_prefix('@').unwrap_or(name);
+    let profile = IdentifierProfile::default()
+        .with_start_chars("0123456789")
+        .with_continue_chars("-.");
+
+    let mut rejections = validate_identifier(username, &profile)
+        .err()
+        .unwrap_or_default();
+    if !rejections
+        .iter()
+        .any(|r| matches!(r, IdentifierRejection::MixedScripts { .. }))
+    {
+        let scripts = ScriptMix::of(username);
+        // Han with kana or Hangul is one script for identifiers, not here
+        if scripts.scripts.len() > 1 || !scripts.is_single_script() {
+            let scripts = scripts.scripts;
+            rejections.push(IdentifierRejection::MixedScripts { scripts });
+        }
+    }
+
+    let risk_level = rejections
+        .iter()
+        .map(|rejection| match rejection {
+            IdentifierRejection::Restricted { .. } | IdentifierRejection::MixedScripts { .. } => {
+                RiskLevel::High
+            }
+            _ => RiskLevel::Medium,
+        })
+        .max()
+        .unwrap_or(RiskLevel::Low);
+
+    UsernameAnalysis {
+        username: username.to_string(),
+        rejections,
+        risk_level,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punycode() {
+        assert_eq!(punycode_decode("mnchen-3ya").as_deref(), Some("münchen"));
+        assert_eq!(punycode_decode("80ak6aa92e").as_deref(), Some("аррӏе"));
+        assert_eq!(punycode_decode("r8jz45g").as_deref(), Some("例え"));
+        assert_eq!(punycode_decode("99999999999"), None);
+        assert_eq!(punycode_decode("a!b"), None);
+    }
+
+    #[test]
+    fn test_email_domains() {
+        let analysis = analyze_email("Jörg <joerg@XN--MNCHEN-3YA.de>");
+        assert_eq!(analysis.display_name.as_deref(), Some("Jörg"));
+        assert_eq!(analysis.local_part, "joerg");
+        assert_eq!(analysis.unicode_domain, "münchen.de");
+        assert!(analysis.issues.is_empty());
+        assert_eq!(analysis.risk_level, RiskLevel::Low);
+
+        // All-Cyrillic "scope"
+        let analysis = analyze_email("info@xn--e1argc3h.com");
+        assert_eq!(
+            analysis.issues,
+            vec![EmailIssue::WholeScriptConfusable {
+                label: "\u{0455}\u{0441}\u{043E}\u{0440}\u{0435}".to_string(),
+                lookalike: "scope".to_string()
+            }]
+        );
+
+        let analysis = analyze_email("a@xn--zz!.com");
+        assert!(matches!(
+            analysis.issues[0],
+            EmailIssue::InvalidPunycode { .. }
+        ));
+        for address in ["a@-bad.com", "a@ab--cd.com", "a@example..com"] {
+            let analysis = analyze_email(address);
+            assert!(
+                matches!(analysis.issues[..], [EmailIssue::InvalidLabel { .. }]),
+                "{}",
+                address
+            );
+        }
+        let analysis = analyze_email("a@exa\u{2764}mple.com");
+        assert!(matches!(
+            analysis.issues[0],
+            EmailIssue::DisallowedDomainChar { ch: '\u{2764}', .. }
+        ));
+        assert_eq!(
+            analyze_email("nobody").issues,
+            vec![EmailIssue::Malformed {
+                reason: "missing @"
+            }]
+        );
+    }
+
+    #[test]
+    fn test_email_local_part() {
+        let analysis = analyze_email("admin\u{200B}@example.com");
+        assert!(analysis.local_part_analysis.has_invisible_chars);
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+
+        let analysis = analyze_email("\u{0430}dmin@example.com");
+        assert!(analysis.local_part_analysis.has_confusables);
+    }
+
+    #[test]
+    fn test_display_name_spoofing() {
+        let config = EmailConfig::default().with_trusted_address("billing@example.com");
+
+        // Quoting the actual address is fine
+        let analysis = analyze_email_with_config("a@b.test <a@b.test>", &config);
+        assert!(analysis.issues.is_empty());
+
+        let analysis = analyze_email_with_config("Billing@Examp1e.com <x@attacker.test>", &config);
+        assert_eq!(
+            analysis.issues,
+            vec![
+                EmailIssue::DisplayNameContainsAddress,
+                EmailIssue::DisplayNameLookalike {
+                    trusted: "billing@example.com".to_string()
+                }
+            ]
+        );
+        assert_eq!(analysis.risk_level, RiskLevel::Critical);
+
+        // The trusted sender itself may use its address as display name
+        let analysis =
+            analyze_email_with_config("billing@example.com <Billing@Example.com>", &config);
+        assert!(analysis.issues.is_empty());
+    }
+
+    #[test]
+    fn test_username() {
+        let analysis = analyze_username("@alice");
+        assert_eq!(analysis.username, "alice");
+        assert_eq!(analysis.risk_level, RiskLevel::Low);
+
+        let analysis = analyze_username("\u{0430}lice");
+        assert_eq!(
+            analysis.rejections,
+            vec![IdentifierRejection::MixedScripts {
+                scripts: vec![Script::Cyrillic, Script::Latin]
+            }]
+        );
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+
+        // Allowed in identifiers, but not in a single-script username
+        let analysis = analyze_username("한국日本");
+        assert!(matches!(
+            analysis.rejections[..],
+            [IdentifierRejection::MixedScripts { .. }]
+        ));
+
+        // Scripts without a variant of their own are told apart too
+        let analysis = analyze_username("g\u{0585}\u{0585}gle");
+        assert!(!analysis.is_valid());
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+        assert!(analyze_username("\u{0562}\u{0561}\u{0580}\u{056B}").is_valid());
+
+        assert_eq!(analyze_username("-x").risk_level, RiskLevel::Medium);
+        assert!(!analyze_username("").is_valid());
+    }
+}
@@ -0,0 +1,1047 @@
+//! Unicode Bidirectional Algorithm (UAX #9)
+//!
+//! [`is_bidi_char`](super::is_bidi_char) only says that bidi controls are
+//! present. This module resolves embedding levels and the visual order of
+//! each paragraph, so reviewers can see how such text is actually displayed.
+//!
+//! Rules P2–P3, X1–X10, W1–W7, N0–N2, I1–I2 and L1–L2 are implemented.
+//! Paragraphs are not wrapped, so each one is reordered as a single line, and
+//! mirrored glyphs (L4) are substituted for paired brackets only.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! // A right-to-left override makes "nimda" display as "admin"
+//! assert_eq!(visual_order("user = \u{202E}nimda\u{202C};"), "user = admin;");
+//!
+//! let paragraphs = bidi_paragraphs("\u{2067}left open");
+//! assert_eq!(paragraphs[0].unterminated[0].1, '\u{2067}');
+//! ```
+
+use std::ops::Range;
+
+use super::{get_char_description, is_bidi_char};
+use crate::unicode::tables::{
+    self,
+    bidi::{BIDI_BRACKETS, BIDI_CLASS},
+};
+
+/// Bidi_Class property values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// Strong left-to-right (L)
+    LeftToRight,
+    /// Strong right-to-left (R)
+    RightToLeft,
+    /// Strong right-to-left Arabic letter (AL)
+    ArabicLetter,
+    /// European digit (EN)
+    EuropeanNumber,
+    /// Plus and minus signs (ES)
+    EuropeanSeparator,
+    /// Currency and percent signs (ET)
+    EuropeanTerminator,
+    /// Arabic-Indic digit (AN)
+    ArabicNumber,
+    /// Separators within numbers (CS)
+    CommonSeparator,
+    /// Nonspacing mark (NSM)
+    NonspacingMark,
+    /// Default ignorable and control characters (BN)
+    BoundaryNeutral,
+    /// Paragraph separator (B)
+    ParagraphSeparator,
+    /// Segment separator such as tab (S)
+    SegmentSeparator,
+    /// Whitespace (WS)
+    WhiteSpace,
+    /// Other neutral (ON)
+    OtherNeutral,
+    /// Left-to-right embedding (LRE)
+    LeftToRightEmbedding,
+    /// Left-to-right override (LRO)
+    LeftToRightOverride,
+    /// Right-to-left embedding (RLE)
+    RightToLeftEmbedding,
+    /// Right-to-left override (RLO)
+    RightToLeftOverride,
+    /// Pop directional format (PDF)
+    PopDirectionalFormat,
+    /// Left-to-right isolate (LRI)
+    LeftToRightIsolate,
+    /// Right-to-left isolate (RLI)
+    RightToLeftIsolate,
+    /// First strong isolate (FSI)
+    FirstStrongIsolate,
+    /// Pop directional isolate (PDI)
+    PopDirectionalIsolate,
+}
+
+use BidiClass::*;
+
+/// Look up the Bidi_Class of a character
+pub fn bidi_class(ch: char) -> BidiClass {
+    tables::bsearch_range_value_table(ch, BIDI_CLASS).unwrap_or(LeftToRight)
+}
+
+/// Paragraph direction used when resolving embedding levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BaseDirection {
+    /// Direction of the first strong character, left-to-right if there is none
+    #[default]
+    Auto,
+    /// Left-to-right paragraphs, as in most code editors
+    LeftToRight,
+    /// Right-to-left paragraphs
+    RightToLeft,
+}
+
+/// Deepest explicit embedding level (BD2)
+const MAX_DEPTH: u8 = 125;
+
+/// Deepest bracket nesting considered when pairing brackets (BD16)
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// A paragraph with resolved embedding levels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidiParagraph<'a> {
+    /// Paragraph text, including its trailing paragraph separator
+    pub text: &'a str,
+    /// Byte offset of the paragraph in the analyzed text
+    pub offset: usize,
+    /// Paragraph embedding level: 0 for left-to-right, 1 for right-to-left
+    pub base_level: u8,
+    /// Resolved embedding level of every character, in logical order
+    pub levels: Vec<u8>,
+    /// Embeddings, overrides and isolates still open at the end of the
+    /// paragraph, as (byte offset in the analyzed text, character, description)
+    pub unterminated: Vec<(usize, char, &'static str)>,
+}
+
+impl BidiParagraph<'_> {
+    /// Whether the paragraph direction is right-to-left
+    pub fn is_rtl(&self) -> bool {
+        self.base_level % 2 == 1
+    }
+
+    /// Character indices of the paragraph in display order (rule L2)
+    ///
+    /// Nonspacing marks stay after their base character and the paragraph
+    /// separator, if any, comes last.
+    pub fn visual_indices(&self) -> Vec<usize> {
+        let classes: Vec<BidiClass> = self.text.chars().map(bidi_class).collect();
+        let end = classes
+            .iter()
+            .rposition(|&class| class != ParagraphSeparator)
+            .map_or(0, |i| i + 1);
+
+        // Base characters with their following marks, reordered as one unit
+        let mut units: Vec<Range<usize>> = Vec::new();
+        for (i, &class) in classes[..end].iter().enumerate() {
+            match units.last_mut() {
+                Some(unit)
+                    if class == NonspacingMark && self.levels[i] == self.levels[unit.start] =>
+                {
+                    unit.end = i + 1
+                }
+                _ => units.push(i..i + 1),
+            }
+        }
+
+        let unit_level = |unit: &Range<usize>| self.levels[unit.start];
+        let highest = units.iter().map(unit_level).max().unwrap_or(0);
+        let lowest_odd = units.iter().map(unit_level).min().unwrap_or(0) | 1;
+        for level in (lowest_odd..=highest).rev() {
+            let mut i = 0;
+            while i < units.len() {
+                if unit_level(&units[i]) < level {
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < units.len() && unit_level(&units[i]) >= level {
+                    i += 1;
+                }
+                units[start..i].reverse();
+            }
+        }
+
+        units
+            .into_iter()
+            .flatten()
+            .chain(end..classes.len())
+            .collect()
+    }
+
+    /// The paragraph as displayed
+    ///
+    /// Bidi formatting characters, which have no glyph, are left out and
+    /// brackets at right-to-left levels are mirrored.
+    pub fn visual(&self) -> String {
+        let chars: Vec<char> = self.text.chars().collect();
+        self.visual_indices()
+            .into_iter()
+            .filter(|&i| !is_bidi_formatting(chars[i]))
+            .map(|i| match paired_bracket(chars[i]) {
+                Some((pair, _)) if self.levels[i] % 2 == 1 => pair,
+                _ => chars[i],
+            })
+            .collect()
+    }
+}
+
+/// Resolve the embedding levels of every paragraph of `text`
+pub fn bidi_paragraphs(text: &str) -> Vec<BidiParagraph<'_>> {
+    bidi_paragraphs_with_direction(text, BaseDirection::Auto)
+}
+
+/// Resolve the embedding levels of every paragraph using the given direction
+pub fn bidi_paragraphs_with_direction(
+    text: &str,
+    direction: BaseDirection,
+) -> Vec<BidiParagraph<'_>> {
+    paragraph_ranges(text)
+        .into_iter()
+        .map(|range| {
+            let paragraph = &text[range.clone()];
+            let chars: Vec<char> = paragraph.chars().collect();
+            let classes: Vec<BidiClass> = chars.iter().map(|&ch| bidi_class(ch)).collect();
+            let (base_level, levels) = resolve_levels(&chars, &classes, direction);
+            let unterminated = locate_unterminated(paragraph, range.start, &classes);
+            BidiParagraph {
+                text: paragraph,
+                offset: range.start,
+                base_level,
+                levels,
+                unterminated,
+            }
+        })
+        .collect()
+}
+
+/// Reorder text into the order in which it is displayed
+///
+/// Each paragraph is reordered independently with its direction taken from
+/// its first strong character. See [`BidiParagraph::visual`].
+pub fn visual_order(text: &str) -> String {
+    visual_order_with_direction(text, BaseDirection::Auto)
+}
+
+/// Reorder text into display order using the given paragraph direction
+pub fn visual_order_with_direction(text: &str, direction: BaseDirection) -> String {
+    bidi_paragraphs_with_direction(text, direction)
+        .iter()
+        .map(BidiParagraph::visual)
+        .collect()
+}
+
+/// Find embeddings, overrides and isolates not closed by the end of their paragraph
+///
+/// Unterminated controls change the direction of everything after them,
+/// which is how "Trojan Source" attacks hide code inside comments and strings.
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// assert!(unterminated_bidi_controls("\u{202E}closed\u{202C}").is_empty());
+/// assert_eq!(unterminated_bidi_controls("a\u{202E}b\nc")[0].0, 1);
+/// ```
+pub fn unterminated_bidi_controls(text: &str) -> Vec<(usize, char, &'static str)> {
+    paragraph_ranges(text)
+        .into_iter()
+        .flat_map(|range| {
+            let paragraph = &text[range.clone()];
+            let classes: Vec<BidiClass> = paragraph.chars().map(bidi_class).collect();
+            locate_unterminated(paragraph, range.start, &classes)
+        })
+        .collect()
+}
+
+/// Split text after each paragraph separator, keeping CR LF together (BD7)
+fn paragraph_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((pos, ch)) = chars.next() {
+        if bidi_class(ch) != ParagraphSeparator {
+            continue;
+        }
+        let mut end = pos + ch.len_utf8();
+        if ch == '\r' && chars.next_if(|&(_, next)| next == '\n').is_some() {
+            end += 1;
+        }
+        ranges.push(start..end);
+        start = end;
+    }
+    if start < text.len() {
+        ranges.push(start..text.len());
+    }
+    ranges
+}
+
+/// Unterminated controls of a paragraph starting at byte `offset`
+fn locate_unterminated(
+    paragraph: &str,
+    offset: usize,
+    classes: &[BidiClass],
+) -> Vec<(usize, char, &'static str)> {
+    let open = unterminated_controls(classes);
+    paragraph
+        .char_indices()
+        .enumerate()
+        .filter(|(i, _)| open.contains(i))
+        .map(|(_, (pos, ch))| (offset + pos, ch, get_char_description(ch)))
+        .collect()
+}
+
+/// Character indices of embedding and isolate initiators left open
+fn unterminated_controls(classes: &[BidiClass]) -> Vec<usize> {
+    // (index, is isolate)
+    let mut open: Vec<(usize, bool)> = Vec::new();
+    for (i, &class) in classes.iter().enumerate() {
+        match class {
+            LeftToRightEmbedding | RightToLeftEmbedding | LeftToRightOverride
+            | RightToLeftOverride => open.push((i, false)),
+            PopDirectionalFormat if open.last().is_some_and(|&(_, isolate)| !isolate) => {
+                open.pop();
+            }
+            LeftToRightIsolate | RightToLeftIsolate | FirstStrongIsolate => open.push((i, true)),
+            PopDirectionalIsolate => {
+                // Closes the innermost isolate and any embeddings inside it
+                if let Some(isolate) = open.iter().rposition(|&(_, isolate)| isolate) {
+                    open.truncate(isolate);
+                }
+            }
+            _ => {}
+        }
+    }
+    open.into_iter().map(|(i, _)| i).collect()
+}
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(
+        class,
+        LeftToRightIsolate | RightToLeftIsolate | FirstStrongIsolate
+    )
+}
+
+/// Characters removed from level resolution by rule X9
+fn is_removed_by_x9(class: BidiClass) -> bool {
+    matches!(
+        class,
+        LeftToRightEmbedding
+            | RightToLeftEmbedding
+            | LeftToRightOverride
+            | RightToLeftOverride
+            | PopDirectionalFormat
+            | BoundaryNeutral
+    )
+}
+
+/// Bidi controls and other boundary neutrals, which are not displayed
+fn is_bidi_formatting(ch: char) -> bool {
+    let class = bidi_class(ch);
+    is_bidi_char(ch)
+        || is_removed_by_x9(class)
+        || is_isolate_initiator(class)
+        || class == PopDirectionalIsolate
+}
+
+/// The paired bracket of `ch` and whether `ch` is an opening bracket
+fn paired_bracket(ch: char) -> Option<(char, bool)> {
+    BIDI_BRACKETS
+        .binary_search_by_key(&ch, |&(bracket, _, _)| bracket)
+        .ok()
+        .map(|i| (BIDI_BRACKETS[i].1, BIDI_BRACKETS[i].2))
+}
+
+/// Map angle brackets to their canonical equivalents for pairing (BD16)
+fn canonical_bracket(ch: char) -> char {
+    match ch {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        _ => ch,
+    }
+}
+
+fn direction_of_level(level: u8) -> BidiClass {
+    if level.is_multiple_of(2) {
+        LeftToRight
+    } else {
+        RightToLeft
+    }
+}
+
+/// The least odd (right-to-left) or even level greater than `level`
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Matching PDI of every isolate initiator (BD9)
+fn matching_pdis(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; classes.len()];
+    let mut open = Vec::new();
+    for (i, &class) in classes.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            open.push(i);
+        } else if class == PopDirectionalIsolate {
+            if let Some(initiator) = open.pop() {
+                matches[initiator] = Some(i);
+            }
+        }
+    }
+    matches
+}
+
+/// Level of the first strong character, skipping isolates (P2, P3)
+fn first_strong_level(
+    classes: &[BidiClass],
+    range: Range<usize>,
+    matches: &[Option<usize>],
+) -> Option<u8> {
+    let mut i = range.start;
+    while i < range.end {
+        match classes[i] {
+            LeftToRight => return Some(0),
+            RightToLeft | ArabicLetter => return Some(1),
+            class if is_isolate_initiator(class) => {
+                i = matches[i].unwrap_or(range.end);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Directional status stack entry (X1)
+#[derive(Clone, Copy)]
+struct Status {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Resolve the paragraph level and the level of every character
+fn resolve_levels(
+    chars: &[char],
+    classes: &[BidiClass],
+    direction: BaseDirection,
+) -> (u8, Vec<u8>) {
+    let n = classes.len();
+    let matches = matching_pdis(classes);
+    let base = match direction {
+        BaseDirection::Auto => first_strong_level(classes, 0..n, &matches).unwrap_or(0),
+        BaseDirection::LeftToRight => 0,
+        BaseDirection::RightToLeft => 1,
+    };
+
+    let mut types = classes.to_vec();
+    let mut levels = vec![base; n];
+    explicit_levels(classes, &matches, base, &mut types, &mut levels);
+
+    // X10: isolating run sequences over the characters X9 keeps
+    let kept: Vec<usize> = (0..n).filter(|&i| !is_removed_by_x9(classes[i])).collect();
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    for &i in &kept {
+        match runs.last_mut() {
+            Some(run) if levels[run[0]] == levels[i] => run.push(i),
+            _ => runs.push(vec![i]),
+        }
+    }
+    let mut run_of = vec![0; n];
+    for (r, run) in runs.iter().enumerate() {
+        for &i in run {
+            run_of[i] = r;
+        }
+    }
+    let mut kept_position = vec![0; n];
+    for (k, &i) in kept.iter().enumerate() {
+        kept_position[i] = k;
+    }
+
+    // sos and eos come from the embedding levels, not the resolved ones
+    let embedding = levels.clone();
+    let mut visited = vec![false; runs.len()];
+    for r in 0..runs.len() {
+        if visited[r] {
+            continue;
+        }
+        let mut sequence = Vec::new();
+        let mut current = r;
+        loop {
+            visited[current] = true;
+            sequence.extend_from_slice(&runs[current]);
+            let last = sequence[sequence.len() - 1];
+            match matches[last] {
+                Some(pdi) if is_isolate_initiator(classes[last]) && !visited[run_of[pdi]] => {
+                    current = run_of[pdi]
+                }
+                _ => break,
+            }
+        }
+
+        let first = sequence[0];
+        let last = sequence[sequence.len() - 1];
+        let level = embedding[first];
+        let before = match kept_position[first] {
+            0 => base,
+            k => embedding[kept[k - 1]],
+        };
+        let after = match kept.get(kept_position[last] + 1) {
+            Some(&next) if !is_isolate_initiator(classes[last]) => embedding[next],
+            _ => base,
+        };
+        let sos = direction_of_level(level.max(before));
+        let eos = direction_of_level(level.max(after));
+        resolve_sequence(chars, classes, &sequence, sos, eos, &mut types, &mut levels);
+    }
+
+    // Removed characters take the level of the preceding character
+    for i in 0..n {
+        if is_removed_by_x9(classes[i]) {
+            levels[i] = if i == 0 { base } else { levels[i - 1] };
+        }
+    }
+
+    // L1: separators and trailing whitespace revert to the paragraph level
+    let is_trailing = |class: BidiClass| {
+        matches!(class, WhiteSpace | PopDirectionalIsolate)
+            || is_isolate_initiator(class)
+            || is_removed_by_x9(class)
+    };
+    let mut trailing = true;
+    for i in (0..n).rev() {
+        if matches!(classes[i], SegmentSeparator | ParagraphSeparator) {
+            levels[i] = base;
+            trailing = true;
+        } else if trailing && is_trailing(classes[i]) {
+            levels[i] = base;
+        } else {
+            trailing = false;
+        }
+    }
+
+    (base, levels)
+}
+
+/// Rules X1–X8: explicit embeddings, overrides and isolates
+fn explicit_levels(
+    classes: &[BidiClass],
+    matches: &[Option<usize>],
+    base: u8,
+    types: &mut [BidiClass],
+    levels: &mut [u8],
+) {
+    let mut stack = vec![Status {
+        level: base,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+
+    for (i, &class) in classes.iter().enumerate() {
+        let last = stack[stack.len() - 1];
+        match class {
+            LeftToRightEmbedding | RightToLeftEmbedding | LeftToRightOverride
+            | RightToLeftOverride => {
+                levels[i] = last.level;
+                let rtl = matches!(class, RightToLeftEmbedding | RightToLeftOverride);
+                let level = next_level(last.level, rtl);
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(Status {
+                        level,
+                        override_class: match class {
+                            LeftToRightOverride => Some(LeftToRight),
+                            RightToLeftOverride => Some(RightToLeft),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            LeftToRightIsolate | RightToLeftIsolate | FirstStrongIsolate => {
+                levels[i] = last.level;
+                if let Some(class) = last.override_class {
+                    types[i] = class;
+                }
+                let rtl = match class {
+                    RightToLeftIsolate => true,
+                    LeftToRightIsolate => false,
+                    _ => {
+                        let end = matches[i].unwrap_or(classes.len());
+                        first_strong_level(classes, i + 1..end, matches) == Some(1)
+                    }
+                };
+                let level = next_level(last.level, rtl);
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            PopDirectionalIsolate => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while stack.last().is_some_and(|status| !status.isolate) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let last = stack[stack.len() - 1];
+                levels[i] = last.level;
+                if let Some(class) = last.override_class {
+                    types[i] = class;
+                }
+            }
+            PopDirectionalFormat => {
+                levels[i] = last.level;
+                if overflow_isolates == 0 {
+                    if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if !last.isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+            }
+            ParagraphSeparator => levels[i] = base,
+            BoundaryNeutral => levels[i] = last.level,
+            _ => {
+                levels[i] = last.level;
+                if let Some(class) = last.override_class {
+                    types[i] = class;
+                }
+            }
+        }
+    }
+}
+
+/// Rules W1–W7, N0–N2 and I1–I2 for one isolating run sequence
+fn resolve_sequence(
+    chars: &[char],
+    classes: &[BidiClass],
+    sequence: &[usize],
+    sos: BidiClass,
+    eos: BidiClass,
+    types: &mut [BidiClass],
+    levels: &mut [u8],
+) {
+    let len = sequence.len();
+    let mut t: Vec<BidiClass> = sequence.iter().map(|&i| types[i]).collect();
+
+    // W1: nonspacing marks take the type of the previous character
+    for k in 0..len {
+        if t[k] == NonspacingMark {
+            t[k] = match k.checked_sub(1).map(|p| t[p]) {
+                None => sos,
+                Some(prev) if is_isolate_initiator(prev) || prev == PopDirectionalIsolate => {
+                    OtherNeutral
+                }
+                Some(prev) => prev,
+            };
+        }
+    }
+
+    // W2: European numbers after Arabic letters become Arabic numbers
+    let mut last_strong = sos;
+    for class in t.iter_mut() {
+        match *class {
+            LeftToRight | RightToLeft | ArabicLetter => last_strong = *class,
+            EuropeanNumber if last_strong == ArabicLetter => *class = ArabicNumber,
+            _ => {}
+        }
+    }
+
+    // W3
+    for class in t.iter_mut() {
+        if *class == ArabicLetter {
+            *class = RightToLeft;
+        }
+    }
+
+    // W4: single separators between numbers of the same kind
+    for k in 1..len.saturating_sub(1) {
+        let (prev, next) = (t[k - 1], t[k + 1]);
+        if t[k] == EuropeanSeparator && prev == EuropeanNumber && next == EuropeanNumber {
+            t[k] = EuropeanNumber;
+        } else if t[k] == CommonSeparator
+            && prev == next
+            && matches!(prev, EuropeanNumber | ArabicNumber)
+        {
+            t[k] = prev;
+        }
+    }
+
+    // W5: terminators next to European numbers
+    let mut k = 0;
+    while k < len {
+        if t[k] != EuropeanTerminator {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < len && t[k] == EuropeanTerminator {
+            k += 1;
+        }
+        let adjacent =
+            (start > 0 && t[start - 1] == EuropeanNumber) || (k < len && t[k] == EuropeanNumber);
+        if adjacent {
+            t[start..k].fill(EuropeanNumber);
+        }
+    }
+
+    // W6
+    for class in t.iter_mut() {
+        if matches!(
+            *class,
+            EuropeanSeparator | EuropeanTerminator | CommonSeparator
+        ) {
+            *class = OtherNeutral;
+        }
+    }
+
+    // W7: European numbers in left-to-right context become L
+    let mut last_strong = sos;
+    for class in t.iter_mut() {
+        match *class {
+            LeftToRight | RightToLeft => last_strong = *class,
+            EuropeanNumber if last_strong == LeftToRight => *class = LeftToRight,
+            _ => {}
+        }
+    }
+
+    let level = levels[sequence[0]];
+    let embedding = direction_of_level(level);
+    resolve_brackets(chars, classes, sequence, sos, embedding, &mut t);
+
+    // N1, N2: neutrals take the surrounding direction, or the embedding direction
+    let strong = |class: BidiClass| match class {
+        LeftToRight => LeftToRight,
+        _ => RightToLeft,
+    };
+    let is_neutral = |class: BidiClass| {
+        matches!(
+            class,
+            ParagraphSeparator
+                | SegmentSeparator
+                | WhiteSpace
+                | OtherNeutral
+                | PopDirectionalIsolate
+        ) || is_isolate_initiator(class)
+    };
+    let mut k = 0;
+    while k < len {
+        if !is_neutral(t[k]) {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < len && is_neutral(t[k]) {
+            k += 1;
+        }
+        let before = if start == 0 {
+            sos
+        } else {
+            strong(t[start - 1])
+        };
+        let after = if k == len { eos } else { strong(t[k]) };
+        t[start..k].fill(if before == after { before } else { embedding });
+    }
+
+    // I1, I2
+    for (k, &i) in sequence.iter().enumerate() {
+        let level = levels[i];
+        levels[i] = match (level % 2, t[k]) {
+            (0, RightToLeft) => level + 1,
+            (0, ArabicNumber | EuropeanNumber) => level + 2,
+            (1, LeftToRight | EuropeanNumber | ArabicNumber) => level + 1,
+            _ => level,
+        };
+        types[i] = t[k];
+    }
+}
+
+/// Rule N0: paired brackets take the direction of their content or context
+fn resolve_brackets(
+    chars: &[char],
+    classes: &[BidiClass],
+    sequence: &[usize],
+    sos: BidiClass,
+    embedding: BidiClass,
+    t: &mut [BidiClass],
+) {
+    // BD16: pair brackets with a stack of openers
+    let mut openers: Vec<(char, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+    for (k, &i) in sequence.iter().enumerate() {
+        if t[k] != OtherNeutral {
+            continue;
+        }
+        match paired_bracket(chars[i]) {
+            Some((pair, true)) => {
+                if openers.len() == MAX_BRACKET_DEPTH {
+                    break;
+                }
+                openers.push((canonical_bracket(pair), k));
+            }
+            Some((_, false)) => {
+                let close = canonical_bracket(chars[i]);
+                if let Some(pos) = openers.iter().rposition(|&(c, _)| c == close) {
+                    pairs.push((openers[pos].1, k));
+                    openers.truncate(pos);
+                }
+            }
+            None => {}
+        }
+    }
+    pairs.sort_unstable();
+
+    let strong = |class: BidiClass| match class {
+        LeftToRight => Some(LeftToRight),
+        RightToLeft | EuropeanNumber | ArabicNumber => Some(RightToLeft),
+        _ => None,
+    };
+    for (open, close) in pairs {
+        let inside: Vec<BidiClass> = t[open + 1..close]
+            .iter()
+            .filter_map(|&c| strong(c))
+            .collect();
+        let direction = if inside.contains(&embedding) {
+            embedding
+        } else if let Some(&opposite) = inside.first() {
+            let context = t[..open]
+                .iter()
+                .rev()
+                .find_map(|&c| strong(c))
+                .unwrap_or(sos);
+            if context == opposite {
+                opposite
+            } else {
+                embedding
+            }
+        } else {
+            continue;
+        };
+
+        for bracket in [open, close] {
+            t[bracket] = direction;
+            // Marks originally following the bracket follow its new type
+            for k in bracket + 1..t.len() {
+                if classes[sequence[k]] != NonspacingMark {
+                    break;
+                }
+                t[k] = direction;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ICU-generated fallback for [`test_bidi_character_test`]
+    const BIDI_CHARACTER_TEST_SAMPLE: &str = include_str!("testdata/bidi_character_test.txt");
+
+    fn levels(text: &str) -> Vec<u8> {
+        bidi_paragraphs(text).remove(0).levels
+    }
+
+    #[test]
+    fn test_bidi_class() {
+        assert_eq!(bidi_class('a'), LeftToRight);
+        assert_eq!(bidi_class('\u{05D0}'), RightToLeft);
+        assert_eq!(bidi_class('\u{0627}'), ArabicLetter);
+        assert_eq!(bidi_class('1'), EuropeanNumber);
+        assert_eq!(bidi_class('\u{0661}'), ArabicNumber);
+        assert_eq!(bidi_class('\u{0301}'), NonspacingMark);
+        assert_eq!(bidi_class('\n'), ParagraphSeparator);
+        assert_eq!(bidi_class('\u{202E}'), RightToLeftOverride);
+        assert_eq!(bidi_class('\u{2069}'), PopDirectionalIsolate);
+        // Unassigned code points in Hebrew and Arabic blocks default to RTL
+        assert_eq!(bidi_class('\u{05FF}'), RightToLeft);
+        assert_eq!(bidi_class('\u{07BF}'), ArabicLetter);
+    }
+
+    #[test]
+    fn test_levels() {
+        // Hebrew letters and the number after them, which counts as RTL context
+        assert_eq!(
+            levels("ab \u{05D0}\u{05D1} 12"),
+            vec![0, 0, 0, 1, 1, 1, 2, 2]
+        );
+        // RTL paragraph: Latin is raised to level 2
+        let paragraph = bidi_paragraphs("\u{05D0} ab").remove(0);
+        assert!(paragraph.is_rtl());
+        assert_eq!(paragraph.levels, vec![1, 1, 2, 2]);
+        // Arabic context turns European digits into Arabic numbers
+        assert_eq!(levels("\u{0627} 1"), vec![1, 1, 2]);
+        // Forced direction
+        let paragraph = bidi_paragraphs_with_direction("\u{05D0}", BaseDirection::LeftToRight);
+        assert_eq!(paragraph[0].levels, vec![1]);
+        // sos after an embedding comes from its embedding level (1), not the
+        // resolved level of the digit inside it (2)
+        assert_eq!(
+            levels("a\u{202B}\u{05D0}2\u{202C}!1"),
+            vec![0, 0, 1, 2, 2, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_explicit_controls() {
+        // Override forces Latin letters to the right-to-left level
+        assert_eq!(levels("a\u{202E}bc\u{202C}d"), vec![0, 0, 1, 1, 1, 0]);
+        // Isolate content does not affect the surrounding text
+        assert_eq!(levels("a \u{2067}b\u{2069} c"), vec![0, 0, 0, 2, 0, 0, 0]);
+        // FSI takes the direction of its first strong character
+        assert_eq!(levels("a\u{2068}\u{05D0}\u{2069}"), vec![0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_visual_order() {
+        assert_eq!(
+            visual_order("abc \u{05D0}\u{05D1}\u{05D2} def"),
+            "abc \u{05D2}\u{05D1}\u{05D0} def"
+        );
+        assert_eq!(visual_order("a\u{202E}bc\u{202C}d"), "acbd");
+        // Lines are reordered separately and separators stay in place
+        assert_eq!(visual_order("\u{202E}ab\n\u{202E}cd"), "ba\ndc");
+        assert_eq!(visual_order("x\r\ny"), "x\r\ny");
+        // Brackets in RTL text are mirrored and paired
+        assert_eq!(visual_order("\u{05D0}(\u{05D1})"), "(\u{05D1})\u{05D0}");
+        // Marks stay after their base character
+        assert_eq!(visual_order("\u{202E}e\u{0301}x"), "xe\u{0301}");
+        // Trojan Source: the comment appears to close before `if`
+        let line = "/*\u{202E} } \u{2066}if (isAdmin)\u{2069} \u{2066} begin admins only */";
+        assert_eq!(
+            visual_order(line),
+            "/* begin admins only */ if (isAdmin) { "
+        );
+    }
+
+    #[test]
+    fn test_unterminated_controls() {
+        assert!(unterminated_bidi_controls("a\u{202E}b\u{202C}").is_empty());
+        assert!(unterminated_bidi_controls("\u{2066}\u{202A}x\u{2069}").is_empty());
+        let open = unterminated_bidi_controls("ok\n\u{2067}x\u{202B}\ny");
+        assert_eq!(
+            open.iter()
+                .map(|&(pos, ch, _)| (pos, ch))
+                .collect::<Vec<_>>(),
+            vec![(3, '\u{2067}'), (7, '\u{202B}')]
+        );
+        // A PDF cannot close an isolate
+        assert_eq!(unterminated_bidi_controls("\u{2068}\u{202C}").len(), 1);
+    }
+
+    /// Display order of the characters not removed by rule X9 (rule L2
+    /// only, without keeping marks after their base)
+    fn l2_order(levels: &[Option<u8>]) -> Vec<usize> {
+        let mut order: Vec<(usize, u8)> = levels
+            .iter()
+            .enumerate()
+            .filter_map(|(i, level)| Some((i, (*level)?)))
+            .collect();
+        let highest = order.iter().map(|&(_, level)| level).max().unwrap_or(0);
+        let lowest_odd = order.iter().map(|&(_, level)| level).min().unwrap_or(0) | 1;
+        for level in (lowest_odd..=highest).rev() {
+            let mut i = 0;
+            while i < order.len() {
+                let start = i;
+                while i < order.len() && order[i].1 >= level {
+                    i += 1;
+                }
+                order[start..i].reverse();
+                i += 1;
+            }
+        }
+        order.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Conformance with BidiCharacterTest.txt
+    ///
+    /// Uses the official file when `scripts/unicode.py` has downloaded it,
+    /// otherwise the sample generated from ICU by `scripts/bidi_test_sample.py`,
+    /// which keeps only paragraphs ICU resolves in full. Both the levels and
+    /// the visual order are compared.
+    #[test]
+    fn test_bidi_character_test() {
+        let official = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("target/ucd-16.0.0/BidiCharacterTest.txt");
+        let data = std::fs::read_to_string(official)
+            .unwrap_or_else(|_| BIDI_CHARACTER_TEST_SAMPLE.to_string());
+
+        let mut failures = Vec::new();
+        let mut count = 0;
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(';').collect();
+            let text: String = fields[0]
+                .split_whitespace()
+                .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+                .collect();
+            let direction = match fields[1] {
+                "0" => BaseDirection::LeftToRight,
+                "1" => BaseDirection::RightToLeft,
+                _ => BaseDirection::Auto,
+            };
+            let expected_levels: Vec<Option<u8>> = fields[3]
+                .split_whitespace()
+                .map(|level| level.parse().ok())
+                .collect();
+            let expected_order: Vec<usize> = fields[4]
+                .split_whitespace()
+                .map(|i| i.parse().unwrap())
+                .collect();
+
+            let paragraphs = bidi_paragraphs_with_direction(&text, direction);
+            let base_level = paragraphs[0].base_level;
+            let levels: Vec<Option<u8>> = paragraphs
+                .iter()
+                .flat_map(|paragraph| paragraph.levels.iter().copied())
+                .zip(&expected_levels)
+                .map(|(level, expected)| expected.map(|_| level))
+                .collect();
+            let order = l2_order(&levels);
+
+            count += 1;
+            if base_level.to_string() != fields[2]
+                || order != expected_order
+                || levels != expected_levels
+            {
+                failures.push(line.to_string());
+            }
+        }
+        assert!(count > 0);
+        assert!(
+            failures.is_empty(),
+            "{} of {} cases failed, first: {}",
+            failures.len(),
+            count,
+            failures[0]
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Code point classification
+//!
+//! General_Category and Age lookups backed by the Unicode Character Database,
+//! plus the code point classes that matter for security analysis: private-use
+//! characters, noncharacters, unassigned code points and deprecated characters.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! assert_eq!(general_category('A'), GeneralCategory::UppercaseLetter);
+//! assert_eq!(classify_codepoint('\u{E000}', UnicodeVersion::LATEST), Some(CodepointClass::PrivateUse));
+//! assert_eq!(classify_codepoint('\u{FFFF}', UnicodeVersion::LATEST), Some(CodepointClass::Noncharacter));
+//!
+//! // U+1FAE9 (Face with Bags Under Eyes) was added in Unicode 16.0
+//! assert!(is_unassigned_char('\u{1FAE9}', UnicodeVersion::new(15, 1, 0)));
+//! assert!(!is_unassigned_char('\u{1FAE9}', UnicodeVersion::new(16, 0, 0)));
+//! ```
+
+use std::fmt;
+
+use crate::unicode::tables;
+
+/// A Unicode version, used to decide which code points count as assigned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion {
+    /// Major version
+    pub major: u8,
+    /// Minor version
+    pub minor: u8,
+    /// Update version
+    pub update: u8,
+}
+
+impl UnicodeVersion {
+    /// The Unicode version of the character data built into this crate
+    pub const LATEST: UnicodeVersion = UnicodeVersion {
+        major: tables::age::UNICODE_VERSION.0,
+        minor: tables::age::UNICODE_VERSION.1,
+        update: tables::age::UNICODE_VERSION.2,
+    };
+
+    /// Create a new version
+    pub const fn new(major: u8, minor: u8, update: u8) -> Self {
+        Self {
+            major,
+            minor,
+            update,
+        }
+    }
+}
+
+impl Default for UnicodeVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.update)
+    }
+}
+
+/// Unicode General_Category values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// Lu
+    UppercaseLetter,
+    /// Ll
+    LowercaseLetter,
+    /// Lt
+    TitlecaseLetter,
+    /// Lm
+    ModifierLetter,
+    /// Lo
+    OtherLetter,
+    /// Mn
+    NonspacingMark,
+    /// Mc
+    SpacingMark,
+    /// Me
+    EnclosingMark,
+    /// Nd
+    DecimalNumber,
+    /// Nl
+    LetterNumber,
+    /// No
+    OtherNumber,
+    /// Pc
+    ConnectorPunctuation,
+    /// Pd
+    DashPunctuation,
+    /// Ps
+    OpenPunctuation,
+    /// Pe
+    ClosePunctuation,
+    /// Pi
+    InitialPunctuation,
+    /// Pf
+    FinalPunctuation,
+    /// Po
+    OtherPunctuation,
+    /// Sm
+    MathSymbol,
+    /// Sc
+    CurrencySymbol,
+    /// Sk
+    ModifierSymbol,
+    /// So
+    OtherSymbol,
+    /// Zs
+    SpaceSeparator,
+    /// Zl
+    LineSeparator,
+    /// Zp
+    ParagraphSeparator,
+    /// Cc
+    Control,
+    /// Cf
+    Format,
+    /// Cs (never returned for a `char`)
+    Surrogate,
+    /// Co
+    PrivateUse,
+    /// Cn, including noncharacters
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Two-letter abbreviation as used in the Unicode Character Database
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            GeneralCategory::UppercaseLetter => "Lu",
+            GeneralCategory::LowercaseLetter => "Ll",
+            GeneralCategory::TitlecaseLetter => "Lt",
+            GeneralCategory::ModifierLetter => "Lm",
+            GeneralCategory::OtherLetter => "Lo",
+            GeneralCategory::NonspacingMark => "Mn",
+            GeneralCategory::SpacingMark => "Mc",
+            GeneralCategory::EnclosingMark => "Me",
+            GeneralCategory::DecimalNumber => "Nd",
+            GeneralCategory::LetterNumber => "Nl",
+            GeneralCategory::OtherNumber => "No",
+            GeneralCategory::ConnectorPunctuation => "Pc",
+            GeneralCategory::DashPunctuation => "Pd",
+            GeneralCategory::OpenPunctuation => "Ps",
+            GeneralCategory::ClosePunctuation => "Pe",
+            GeneralCategory::InitialPunctuation => "Pi",
+            GeneralCategory::FinalPunctuation => "Pf",
+            GeneralCategory::OtherPunctuation => "Po",
+            GeneralCategory::MathSymbol => "Sm",
+            GeneralCategory::CurrencySymbol => "Sc",
+            GeneralCategory::ModifierSymbol => "Sk",
+            GeneralCategory::OtherSymbol => "So",
+            GeneralCategory::SpaceSeparator => "Zs",
+            GeneralCategory::LineSeparator => "Zl",
+            GeneralCategory::ParagraphSeparator => "Zp",
+            GeneralCategory::Control => "Cc",
+            GeneralCategory::Format => "Cf",
+            GeneralCategory::Surrogate => "Cs",
+            GeneralCategory::PrivateUse => "Co",
+            GeneralCategory::Unassigned => "Cn",
+        }
+    }
+
+    /// Check if this is one of the letter categories (L*)
+    pub fn is_letter(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::UppercaseLetter
+                | GeneralCategory::LowercaseLetter
+                | GeneralCategory::TitlecaseLetter
+                | GeneralCategory::ModifierLetter
+                | GeneralCategory::OtherLetter
+        )
+    }
+
+    /// Check if this is one of the mark categories (M*)
+    pub fn is_mark(self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::NonspacingMark
+                | GeneralCategory::SpacingMark
+                | GeneralCategory::EnclosingMark
+        )
+    }
+}
+
+/// Security-relevant code point classes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodepointClass {
+    /// Private-use character (U+E000–U+F8FF, planes 15 and 16)
+    PrivateUse,
+    /// Noncharacter (U+FDD0–U+FDEF and the last two code points of every plane)
+    Noncharacter,
+    /// Code point not assigned in the selected Unicode version
+    Unassigned,
+    /// Character with the Deprecated property
+    Deprecated,
+}
+
+/// Get the General_Category of a character
+///
+/// Code points that are not assigned in [`UnicodeVersion::LATEST`] return
+/// [`GeneralCategory::Unassigned`].
+pub fn general_category(ch: char) -> GeneralCategory {
+    tables::bsearch_range_value_table(ch, tables::general_category::GENERAL_CATEGORY)
+        .unwrap_or(GeneralCategory::Unassigned)
+}
+
+/// Get the Unicode version in which a code point was assigned
+///
+/// Returns `None` for code points that are unassigned in the built-in data.
+/// Noncharacters have an age, as they were designated in a specific version.
+pub fn char_age(ch: char) -> Option<UnicodeVersion> {
+    tables::bsearch_range_value_table(ch, tables::age::AGE)
+        .map(|(major, minor)| UnicodeVersion::new(major, minor, 0))
+}
+
+/// Check if a character is a private-use character
+pub fn is_private_use_char(ch: char) -> bool {
+    matches!(ch,
+        '\u{E000}'..='\u{F8FF}' |      // Private Use Area
+        '\u{F0000}'..='\u{FFFFD}' |    // Supplementary Private Use Area-A
+        '\u{100000}'..='\u{10FFFD}'    // Supplementary Private Use Area-B
+    )
+}
+
+/// Check if a character is one of the 66 noncharacters
+pub fn is_noncharacter(ch: char) -> bool {
+    let cp = ch as u32;
+    (0xFDD0..=0xFDEF).contains(&cp) || cp & 0xFFFE == 0xFFFE
+}
+
+/// Check if a code point is unassigned as of the given Unicode version
+///
+/// Noncharacters are permanently reserved and are not reported as unassigned;
+/// use [`is_noncharacter`] for those.
+pub fn is_unassigned_char(ch: char, version: UnicodeVersion) -> bool {
+    if is_noncharacter(ch) {
+        return false;
+    }
+    match char_age(ch) {
+        Some(age) => age > version,
+        None => true,
+    }
+}
+
+/// Check if a character has the Deprecated property
+pub fn is_deprecated_char(ch: char) -> bool {
+    tables::bsearch_range_table(ch, tables::property_bool::DEPRECATED)
+}
+
+/// Classify a code point into a security-relevant class
+///
+/// Returns `None` for ordinary assigned characters. When a code point belongs
+/// to several classes the most specific one wins, in the order noncharacter,
+/// private use, unassigned, deprecated.
+pub fn classify_codepoint(ch: char, version: UnicodeVersion) -> Option<CodepointClass> {
+    if is_noncharacter(ch) {
+        Some(CodepointClass::Noncharacter)
+    } else if is_private_use_char(ch) {
+        Some(CodepointClass::PrivateUse)
+    } else if is_unassigned_char(ch, version) {
+        Some(CodepointClass::Unassigned)
+    } else if is_deprecated_char(ch) {
+        Some(CodepointClass::Deprecated)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,221 @@
+//! Collision detection for identifier sets
+//!
+//! A [`CollisionIndex`] holds the identifiers already in use, such as
+//! registered usernames, and answers whether a new candidate collides with
+//! one of them. Each [`CollisionMode`] maps identifiers to a key, and two
+//! identifiers collide when their keys are equal. The modes are nested, each
+//! catching every collision of the one before:
+//!
+//! - [`CollisionMode::CaseFold`]: full case folding, `Straße` and `STRASSE`
+//! - [`CollisionMode::NfkcCaseFold`]: NFKC_Casefold, adding fullwidth and
+//!   other compatibility variants and ignoring default ignorables
+//! - [`CollisionMode::Skeleton`]: the UTS #39 skeleton of the NFKC_Casefold
+//!   form, adding look-alikes such as Turkish dotless `ı` for `i`
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let index: CollisionIndex = ["alice", "Straße"].into_iter().collect();
+//!
+//! assert!(index.collides("STRASSE", CollisionMode::CaseFold));
+//! assert!(!index.collides("ＡＬＩＣＥ", CollisionMode::CaseFold));
+//! assert!(index.collides("ＡＬＩＣＥ", CollisionMode::NfkcCaseFold));
+//!
+//! // Cyrillic "а" and "і"
+//! let collisions = index.collisions("\u{0430}l\u{0456}ce", CollisionMode::Skeleton);
+//! assert_eq!(collisions[0].existing, "alice");
+//! assert_eq!(collisions[0].mode, CollisionMode::Skeleton);
+//! assert_eq!(collisions[0].risk_level(), RiskLevel::High);
+//! ```
+
+use std::collections::HashMap;
+
+use super::{skeleton, RiskLevel};
+use crate::unicode::normalization::{casefold, nfkc_casefold};
+
+/// How identifiers are compared for collisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CollisionMode {
+    /// Equal after full case folding
+    CaseFold,
+    /// Equal after NFKC_Casefold
+    NfkcCaseFold,
+    /// Equal UTS #39 skeletons after NFKC_Casefold
+    Skeleton,
+}
+
+impl CollisionMode {
+    const ALL: [CollisionMode; 3] = [
+        CollisionMode::CaseFold,
+        CollisionMode::NfkcCaseFold,
+        CollisionMode::Skeleton,
+    ];
+
+    /// Map an identifier to the key compared under this mode
+    pub fn key(self, ident: &str) -> String {
+        match self {
+            CollisionMode::CaseFold => casefold(ident),
+            CollisionMode::NfkcCaseFold => nfkc_casefold(ident),
+            CollisionMode::Skeleton => skeleton(&nfkc_casefold(ident)),
+        }
+    }
+}
+
+/// An existing identifier a candidate collides with
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Collision {
+    /// The identifier already in the index
+    pub existing: String,
+    /// The least strict mode under which the two collide
+    pub mode: CollisionMode,
+}
+
+impl Collision {
+    /// Risk of the collision: look-alikes are a spoofing risk, case variants
+    /// mostly a uniqueness problem
+    pub fn risk_level(&self) -> RiskLevel {
+        match self.mode {
+            CollisionMode::CaseFold => RiskLevel::Low,
+            CollisionMode::NfkcCaseFold => RiskLevel::Medium,
+            CollisionMode::Skeleton => RiskLevel::High,
+        }
+    }
+}
+
+/// Index of existing identifiers keyed under every [`CollisionMode`]
+#[derive(Debug, Clone, Default)]
+pub struct CollisionIndex {
+    identifiers: Vec<String>,
+    keys: [HashMap<String, Vec<usize>>; 3],
+}
+
+impl CollisionIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an existing identifier
+    pub fn insert(&mut self, ident: impl Into<String>) {
+        let ident = ident.into();
+        let id = self.identifiers.len();
+        for (mode, keys) in CollisionMode::ALL.iter().zip(&mut self.keys) {
+            keys.entry(mode.key(&ident)).or_default().push(id);
+        }
+        self.identifiers.push(ident);
+    }
+
+    /// Number of identifiers in the index
+    pub fn len(&self) -> usize {
+        self.identifiers.len()
+    }
+
+    /// Check whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.identifiers.is_empty()
+    }
+
+    /// Find the identifiers `candidate` collides with under `mode`
+    ///
+    /// Each collision reports the least strict mode that matches, so a case
+    /// variant found in [`CollisionMode::Skeleton`] mode is still reported as
+    /// [`CollisionMode::CaseFold`]. Results are in insertion order.
+    pub fn collisions(&self, candidate: &str, mode: CollisionMode) -> Vec<Collision> {
+        let mut found: Vec<(usize, CollisionMode)> = Vec::new();
+        for (&m, keys) in CollisionMode::ALL.iter().zip(&self.keys) {
+            if m > mode {
+                break;
+            }
+            for &id in keys.get(&m.key(candidate)).into_iter().flatten() {
+                if !found.iter().any(|&(seen, _)| seen == id) {
+                    found.push((id, m));
+                }
+            }
+        }
+        found.sort_by_key(|&(id, _)| id);
+        found
+            .into_iter()
+            .map(|(id, mode)| Collision {
+                existing: self.identifiers[id].clone(),
+                mode,
+            })
+            .collect()
+    }
+
+    /// Check whether `candidate` collides with any identifier under `mode`
+    pub fn collides(&self, candidate: &str, mode: CollisionMode) -> bool {
+        self.keys[mode as usize].contains_key(&mode.key(candidate))
+    }
+}
+
+impl<S: Into<String>> Extend<S> for CollisionIndex {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for ident in iter {
+            self.insert(ident);
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for CollisionIndex {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut index = Self::new();
+        index.extend(iter);
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collision_modes() {
+        let index: CollisionIndex = ["admin", "Straße", "kiz", "bob"].into_iter().collect();
+        assert_eq!(index.len(), 4);
+
+        let case = |c: &str| index.collisions(c, CollisionMode::Skeleton);
+        assert_eq!(
+            case("ADMIN"),
+            vec![Collision {
+                existing: "admin".to_string(),
+                mode: CollisionMode::CaseFold
+            }]
+        );
+        assert_eq!(case("STRASSE")[0].mode, CollisionMode::CaseFold);
+        assert_eq!(case("ａｄｍｉｎ")[0].mode, CollisionMode::NfkcCaseFold);
+        assert_eq!(case("ad\u{200B}min")[0].mode, CollisionMode::NfkcCaseFold);
+        // Turkish dotless i and Cyrillic о
+        assert_eq!(case("KIZ")[0].mode, CollisionMode::CaseFold);
+        assert_eq!(case("kız")[0].mode, CollisionMode::Skeleton);
+        assert_eq!(case("b\u{043E}b")[0].mode, CollisionMode::Skeleton);
+        assert!(case("carol").is_empty());
+
+        assert!(!index.collides("kız", CollisionMode::NfkcCaseFold));
+        assert!(index
+            .collisions("ａｄｍｉｎ", CollisionMode::CaseFold)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_multiple_collisions() {
+        let mut index = CollisionIndex::new();
+        index.extend(["Alice", "alice", "\u{0430}lice"]);
+        let collisions = index.collisions("ALICE", CollisionMode::Skeleton);
+        let modes: Vec<_> = collisions
+            .iter()
+            .map(|c| (c.existing.as_str(), c.mode))
+            .collect();
+        assert_eq!(
+            modes,
+            vec![
+                ("Alice", CollisionMode::CaseFold),
+                ("alice", CollisionMode::CaseFold),
+                ("\u{0430}lice", CollisionMode::Skeleton),
+            ]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Confusable detection (UTS #39)
+//!
+//! Maps characters to their confusable prototypes from the Unicode
+//! `confusables.txt` data and computes skeletons: two strings are confusable
+//! when their skeletons are equal.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! assert_eq!(confusable_prototype('\u{0430}'), Some("a")); // Cyrillic а
+//! assert_eq!(skeleton("раураl"), skeleton("paypal"));
+//! assert!(are_confusable("paypa1", "paypal"));
+//! assert!(!are_confusable("paypal", "paypa1x"));
+//! ```
+
+use crate::unicode::normalization::nfd;
+use crate::unicode::tables::confusables::CONFUSABLES;
+
+/// Get the confusable prototype of a character
+///
+/// Returns `None` if the character is its own prototype.
+pub fn confusable_prototype(ch: char) -> Option<&'static str> {
+    CONFUSABLES
+        .binary_search_by_key(&ch, |&(c, _)| c)
+        .ok()
+        .map(|i| CONFUSABLES[i].1)
+}
+
+/// Compute the UTS #39 skeleton of a string
+///
+/// The skeleton is `NFD(map(NFD(text)))`, where `map` replaces every
+/// character by its confusable prototype.
+pub fn skeleton(text: &str) -> String {
+    let mut mapped = String::with_capacity(text.len());
+    for ch in nfd(text).chars() {
+        match confusable_prototype(ch) {
+            Some(prototype) => mapped.push_str(prototype),
+            None => mapped.push(ch),
+        }
+    }
+    nfd(&mapped)
+}
+
+/// Check whether two strings are visually confusable
+pub fn are_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
@@ -0,0 +1,480 @@
+//! Explain differences between strings that look alike
+//!
+//! [`explain_difference`] aligns two strings grapheme by grapheme and
+//! classifies every point where they differ, so reviewers can see *why* two
+//! strings that render identically are not equal.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let report = explain_difference("paypal", "p\u{0430}ypal");
+//! assert_eq!(report.differences.len(), 1);
+//! assert_eq!(report.differences[0].kind, DifferenceKind::Confusable);
+//! println!("{}", report); // plain text
+//! println!("{}", report.render_ansi()); // colored for terminals
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+use super::confusables::skeleton;
+use super::grapheme::graphemes;
+use super::{get_script, is_bidi_char, is_invisible_char, GeneralCategory, Script};
+use crate::unicode::normalization::nfkc;
+
+/// Inputs larger than this many grapheme pairs are not aligned with LCS
+const MAX_ALIGNMENT_CELLS: usize = 4_000_000;
+
+/// Why two aligned pieces of text differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DifferenceKind {
+    /// Visually confusable characters, e.g. Latin `a` and Cyrillic `а`
+    Confusable,
+    /// Invisible characters inserted or removed
+    InvisibleCharacter,
+    /// Different but canonically or compatibility-equivalent encodings
+    Normalization,
+    /// Bidirectional control characters change the display order
+    BidiReordering,
+    /// An ordinary, visible edit
+    Edit,
+}
+
+impl DifferenceKind {
+    /// Short human-readable description
+    pub fn description(self) -> &'static str {
+        match self {
+            DifferenceKind::Confusable => "confusable substitution",
+            DifferenceKind::InvisibleCharacter => "invisible character",
+            DifferenceKind::Normalization => "normalization difference",
+            DifferenceKind::BidiReordering => "bidi reordering",
+            DifferenceKind::Edit => "visible edit",
+        }
+    }
+}
+
+/// A single point where two strings differ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Classification of the difference
+    pub kind: DifferenceKind,
+    /// Byte range in the left string
+    pub left: Range<usize>,
+    /// Byte range in the right string
+    pub right: Range<usize>,
+    /// Index of the first grapheme of the difference in the left string
+    pub grapheme: usize,
+}
+
+/// Result of [`explain_difference`]
+#[derive(Debug, Clone)]
+pub struct DifferenceReport<'a> {
+    /// Left input
+    pub left: &'a str,
+    /// Right input
+    pub right: &'a str,
+    /// Differences in left-to-right order
+    pub differences: Vec<Difference>,
+    columns: Vec<Column<'a>>,
+}
+
+/// One grapheme position of the aligned view
+#[derive(Debug, Clone)]
+struct Column<'a> {
+    left: Option<&'a str>,
+    right: Option<&'a str>,
+    differs: bool,
+}
+
+/// Align two strings grapheme by grapheme and explain every difference
+///
+/// Alignment uses the longest common subsequence of graphemes; each
+/// unaligned stretch is then classified as a confusable substitution, an
+/// invisible insertion or removal, a normalization difference, a bidi
+/// reordering or an ordinary edit.
+pub fn explain_difference<'a>(left: &'a str, right: &'a str) -> DifferenceReport<'a> {
+    let a = grapheme_offsets(left);
+    let b = grapheme_offsets(right);
+
+    let mut differences = Vec::new();
+    let mut columns = Vec::new();
+
+    for op in align(&a, &b) {
+        match op {
+            Op::Equal(i, j) => columns.push(Column {
+                left: Some(a[i].1),
+                right: Some(b[j].1),
+                differs: false,
+            }),
+            Op::Hunk(ls, rs) => {
+                push_hunk(&a, &b, ls, rs, &mut differences, &mut columns);
+            }
+        }
+    }
+
+    DifferenceReport {
+        left,
+        right,
+        differences,
+        columns,
+    }
+}
+
+impl DifferenceReport<'_> {
+    /// Check whether the two strings are identical
+    pub fn is_identical(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Render the report as plain text
+    pub fn render_plain(&self) -> String {
+        self.render(false)
+    }
+
+    /// Render the report with ANSI colors for terminals
+    pub fn render_ansi(&self) -> String {
+        self.render(true)
+    }
+
+    fn render(&self, ansi: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if ansi {
+                format!("\x1b[{}m{}\x1b[0m", code, text)
+            } else {
+                text.to_string()
+            }
+        };
+
+        if self.is_identical() {
+            return "The strings are identical.\n".to_string();
+        }
+
+        let mut out = String::new();
+        let count = self.differences.len();
+        out.push_str(&format!(
+            "{} difference{} between the strings:\n",
+            count,
+            if count == 1 { "" } else { "s" }
+        ));
+        for (n, diff) in self.differences.iter().enumerate() {
+            let left = &self.left[diff.left.clone()];
+            let right = &self.right[diff.right.clone()];
+            out.push_str(&format!(
+                "  {}. {} at grapheme {}: {} vs {}{}\n",
+                n + 1,
+                paint("1", diff.kind.description()),
+                diff.grapheme,
+                paint("31", &describe(left)),
+                paint("32", &describe(right)),
+                script_note(diff.kind, left, right),
+            ));
+        }
+
+        let mut left_line = String::from("  left:  ");
+        let mut right_line = String::from("  right: ");
+        let mut marker_line = String::from("         ");
+        for column in &self.columns {
+            let l = column.left.map(visible).unwrap_or_default();
+            let r = column.right.map(visible).unwrap_or_default();
+            let width = display_width(&l).max(display_width(&r)).max(1);
+            let pad = |s: &str| format!("{}{}", s, " ".repeat(width - display_width(s)));
+            if column.differs {
+                left_line.push_str(&paint("31", &pad(&l)));
+                right_line.push_str(&paint("32", &pad(&r)));
+                marker_line.push_str(&paint("33", &"^".repeat(width)));
+            } else {
+                left_line.push_str(&pad(&l));
+                right_line.push_str(&pad(&r));
+                marker_line.push_str(&" ".repeat(width));
+            }
+            left_line.push(' ');
+            right_line.push(' ');
+            marker_line.push(' ');
+        }
+        out.push('\n');
+        for line in [left_line, right_line, marker_line] {
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl fmt::Display for DifferenceReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_plain())
+    }
+}
+
+enum Op {
+    Equal(usize, usize),
+    /// Unaligned grapheme index ranges of the left and right string
+    Hunk(Range<usize>, Range<usize>),
+}
+
+fn grapheme_offsets(text: &str) -> Vec<(usize, &str)> {
+    let mut offset = 0;
+    graphemes(text)
+        .map(|g| {
+            let start = offset;
+            offset += g.len();
+            (start, g)
+        })
+        .collect()
+}
+
+/// Longest-common-subsequence alignment of two grapheme lists
+fn align(a: &[(usize, &str)], b: &[(usize, &str)]) -> Vec<Op> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x.1 == y.1).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x.1 == y.1)
+        .count();
+    let (n, m) = (a.len() - prefix - suffix, b.len() - prefix - suffix);
+
+    let mut ops: Vec<Op> = (0..prefix).map(|i| Op::Equal(i, i)).collect();
+    if n > 0 && m > 0 && n * m <= MAX_ALIGNMENT_CELLS {
+        // lcs[i][j] = LCS length of a[prefix + i..] and b[prefix + j..]
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        let at = |i: usize, j: usize| i * (m + 1) + j;
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[at(i, j)] = if a[prefix + i].1 == b[prefix + j].1 {
+                    lcs[at(i + 1, j + 1)] + 1
+                } else {
+                    lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        let (mut hunk_i, mut hunk_j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && a[prefix + i].1 == b[prefix + j].1 {
+                if hunk_i < i || hunk_j < j {
+                    ops.push(Op::Hunk(prefix + hunk_i..prefix + i, prefix + hunk_j..prefix + j));
+                }
+                ops.push(Op::Equal(prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+                hunk_i = i;
+                hunk_j = j;
+            } else if j >= m || (i < n && lcs[at(i + 1, j)] >= lcs[at(i, j + 1)]) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        if hunk_i < n || hunk_j < m {
+            ops.push(Op::Hunk(prefix + hunk_i..prefix + n, prefix + hunk_j..prefix + m));
+        }
+    } else if n > 0 || m > 0 {
+        ops.push(Op::Hunk(prefix..prefix + n, prefix..prefix + m));
+    }
+    ops.extend((0..suffix).map(|k| Op::Equal(a.len() - suffix + k, b.len() - suffix + k)));
+    ops
+}
+
+fn push_hunk<'a>(
+    a: &[(usize, &'a str)],
+    b: &[(usize, &'a str)],
+    ls: Range<usize>,
+    rs: Range<usize>,
+    differences: &mut Vec<Difference>,
+    columns: &mut Vec<Column<'a>>,
+) {
+    let byte_range = |g: &[(usize, &str)], r: &Range<usize>, total: usize| {
+        let start = g.get(r.start).map_or(total, |x| x.0);
+        let end = g.get(r.end).map_or(total, |x| x.0);
+        start..end
+    };
+    let a_total = a.last().map_or(0, |x| x.0 + x.1.len());
+    let b_total = b.last().map_or(0, |x| x.0 + x.1.len());
+
+    if ls.len() == rs.len() {
+        // Substitution: classify each grapheme pair, merging neighbours of the same kind
+        for (i, j) in ls.clone().zip(rs.clone()) {
+            let kind = classify(a[i].1, b[j].1);
+            columns.push(Column {
+                left: Some(a[i].1),
+                right: Some(b[j].1),
+                differs: true,
+            });
+            match differences.last_mut() {
+                Some(last) if last.kind == kind && last.left.end == a[i].0 && last.right.end == b[j].0 => {
+                    last.left.end = a[i].0 + a[i].1.len();
+                    last.right.end = b[j].0 + b[j].1.len();
+                }
+                _ => differences.push(Difference {
+                    kind,
+                    left: a[i].0..a[i].0 + a[i].1.len(),
+                    right: b[j].0..b[j].0 + b[j].1.len(),
+                    grapheme: i,
+                }),
+            }
+        }
+        return;
+    }
+
+    let left = byte_range(a, &ls, a_total);
+    let right = byte_range(b, &rs, b_total);
+    let left_text: String = a[ls.clone()].iter().map(|x| x.1).collect();
+    let right_text: String = b[rs.clone()].iter().map(|x| x.1).collect();
+    differences.push(Difference {
+        kind: classify(&left_text, &right_text),
+        left,
+        right,
+        grapheme: ls.start,
+    });
+    for k in 0..ls.len().max(rs.len()) {
+        columns.push(Column {
+            left: a[ls.clone()].get(k).map(|x| x.1),
+            right: b[rs.clone()].get(k).map(|x| x.1),
+            differs: true,
+        });
+    }
+}
+
+fn strip_invisible(text: &str) -> String {
+    text.chars().filter(|&ch| !is_invisible_char(ch)).collect()
+}
+
+fn classify(left: &str, right: &str) -> DifferenceKind {
+    if left.chars().chain(right.chars()).any(is_bidi_char) {
+        DifferenceKind::BidiReordering
+    } else if strip_invisible(left) == strip_invisible(right) {
+        DifferenceKind::InvisibleCharacter
+    } else if nfkc(left) == nfkc(right) {
+        DifferenceKind::Normalization
+    } else if skeleton(left) == skeleton(right) {
+        DifferenceKind::Confusable
+    } else {
+        DifferenceKind::Edit
+    }
+}
+
+/// Render a grapheme so that invisible and control characters show up
+fn visible(text: &str) -> String {
+    text.chars()
+        .map(|ch| {
+            let hidden = is_invisible_char(ch)
+                || is_bidi_char(ch)
+                || matches!(
+                    super::general_category(ch),
+                    GeneralCategory::Control | GeneralCategory::Format
+                )
+                || (ch.is_whitespace() && ch != ' ');
+            if hidden {
+                format!("<U+{:04X}>", ch as u32)
+            } else {
+                ch.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Approximate terminal width, ignoring combining marks
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .filter(|&ch| !super::general_category(ch).is_mark())
+        .count()
+}
+
+/// Describe the characters of a piece of text, e.g. `"а" (U+0430)`
+fn describe(text: &str) -> String {
+    if text.is_empty() {
+        return "nothing".to_string();
+    }
+    let codepoints: Vec<String> = text.chars().map(describe_char).collect();
+    format!("\"{}\" ({})", visible(text), codepoints.join(", "))
+}
+
+#[cfg(feature = "names")]
+fn describe_char(ch: char) -> String {
+    match crate::unicode::names::char_name(ch) {
+        Some(name) => format!("U+{:04X} {}", ch as u32, name),
+        None => format!("U+{:04X}", ch as u32),
+    }
+}
+
+#[cfg(not(feature = "names"))]
+fn describe_char(ch: char) -> String {
+    format!("U+{:04X}", ch as u32)
+}
+
+/// For confusables, mention the scripts involved when they differ
+fn script_note(kind: DifferenceKind, left: &str, right: &str) -> String {
+    if kind != DifferenceKind::Confusable {
+        return String::new();
+    }
+    let script = |text: &str| {
+        text.chars()
+            .map(get_script)
+            .find(|s| !matches!(s, Script::Other(_)))
+    };
+    match (script(left), script(right)) {
+        (Some(l), Some(r)) if l != r => format!(" [{:?} vs {:?}]", l, r),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical() {
+        let report = explain_difference("same", "same");
+        assert!(report.is_identical());
+        assert_eq!(report.to_string(), "The strings are identical.\n");
+    }
+
+    #[test]
+    fn test_confusable_substitution() {
+        let report = explain_difference("paypal", "\u{0440}\u{0430}ypal");
+        assert_eq!(report.differences.len(), 1);
+        let diff = &report.differences[0];
+        assert_eq!(diff.kind, DifferenceKind::Confusable);
+        assert_eq!(diff.left, 0..2);
+        assert_eq!(diff.right, 0..4);
+        assert!(report.render_plain().contains("[Latin vs Cyrillic]"));
+    }
+
+    #[test]
+    fn test_invisible_insertion() {
+        let report = explain_difference("admin", "ad\u{200B}min");
+        assert_eq!(report.differences.len(), 1);
+        assert_eq!(report.differences[0].kind, DifferenceKind::InvisibleCharacter);
+        assert_eq!(report.differences[0].left, 2..2);
+        assert!(report.render_plain().contains("<U+200B>"));
+
+        // A joiner attaches to the previous grapheme
+        let report = explain_difference("ab", "a\u{200D}b");
+        assert_eq!(report.differences[0].kind, DifferenceKind::InvisibleCharacter);
+    }
+
+    #[test]
+    fn test_normalization_and_bidi() {
+        let report = explain_difference("caf\u{00E9}", "cafe\u{0301}");
+        assert_eq!(report.differences[0].kind, DifferenceKind::Normalization);
+
+        let report = explain_difference("abc", "\u{202E}cba\u{202C}");
+        assert!(report
+            .differences
+            .iter()
+            .any(|d| d.kind == DifferenceKind::BidiReordering));
+    }
+
+    #[test]
+    fn test_edit_and_ansi() {
+        let report = explain_difference("cat", "cut");
+        assert_eq!(report.differences[0].kind, DifferenceKind::Edit);
+        let ansi = report.render_ansi();
+        assert!(ansi.contains("\x1b[31m"));
+        assert!(!report.render_plain().contains('\x1b'));
+    }
+}
@@ -0,0 +1,262 @@
+//! Emoji sequence recognition (UTS #51)
+//!
+//! Zero width joiners, variation selectors and tag characters are part of
+//! legitimate emoji such as 👨‍👩‍👧 or 🏴󠁧󠁢󠁥󠁮󠁧󠁿. The analyzer treats characters inside an
+//! emoji sequence as benign, while joiners outside one are still reported.
+//!
+//! Single emoji, flags, keycaps and modifier sequences are matched with the
+//! "possible emoji" grammar of UTS #51 section 1.4.9, using
+//! Extended_Pictographic for emoji characters:
+//!
+//! ```text
+//! element := RI RI                       flag
+//!          | [0-9#*] FE0F? 20E3           keycap
+//!          | ExtPict (FE0F | modifier)?   emoji or modifier sequence
+//! ```
+//!
+//! ZWJ and tag sequences hide invisible characters between visible ones, so
+//! only the recommended (RGI) sequences listed in `emoji-zwj-sequences.txt`
+//! and `emoji-sequences.txt` are accepted. ZWJ sequences are compared
+//! without U+FE0F, so that minimally-qualified forms such as `❤‍🔥` without
+//! the variation selector match as well. Any other joiner or tag run is left
+//! outside the sequence and reported.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! assert!(is_emoji_sequence("👨\u{200D}👩\u{200D}👧"));
+//! assert!(!is_emoji_sequence("a\u{200D}b"));
+//! // Well-formed, but not a recommended sequence
+//! assert!(!is_emoji_sequence("©\u{200D}®"));
+//!
+//! // The family emoji is benign, the trailing joiner is not
+//! let analysis = analyze_text("hi 👨\u{200D}👩\u{200D}👧\u{200D}");
+//! assert_eq!(analysis.invisible_chars.len(), 1);
+//! ```
+
+use std::ops::Range;
+
+use super::ascii_run_len;
+use crate::unicode::tables::{self, emoji, property_bool};
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_KEYCAP: char = '\u{20E3}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// Byte ranges of the emoji sequences in `text`, in order
+///
+/// Single emoji characters count as sequences of length one.
+pub fn emoji_sequences(text: &str) -> Vec<Range<usize>> {
+    let mut sequences = Vec::new();
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    while pos < text.len() {
+        // Every sequence contains a non-ASCII character, so ASCII followed
+        // by ASCII cannot start one
+        let run = ascii_run_len(&bytes[pos..]);
+        if pos + run == bytes.len() {
+            break;
+        }
+        if run > 1 {
+            pos += run - 1;
+            continue;
+        }
+        match match_sequence(&text[pos..]) {
+            Some(len) => {
+                sequences.push(pos..pos + len);
+                pos += len;
+            }
+            None => pos += text[pos..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    sequences
+}
+
+/// Whether `text` is exactly one emoji sequence
+pub fn is_emoji_sequence(text: &str) -> bool {
+    !text.is_empty() && match_sequence(text) == Some(text.len())
+}
+
+fn is_extended_pictographic(ch: char) -> bool {
+    tables::bsearch_range_table(ch, property_bool::EXTENDED_PICTOGRAPHIC)
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+fn is_emoji_modifier(ch: char) -> bool {
+    matches!(ch, '\u{1F3FB}'..='\u{1F3FF}')
+}
+
+fn is_tag_spec(ch: char) -> bool {
+    matches!(ch, '\u{E0020}'..='\u{E007E}')
+}
+
+/// Length in bytes of the emoji sequence at the start of `text`
+fn match_sequence(text: &str) -> Option<usize> {
+    if let Some(len) = match_tag_sequence(text) {
+        return Some(len);
+    }
+    let first = match_element(text)?;
+
+    // Ends of the possible ZWJ sequence after each further element
+    let mut ends = Vec::new();
+    let mut len = first;
+    while let Some(rest) = text[len..].strip_prefix(ZWJ) {
+        match match_element(rest) {
+            Some(element) => {
+                len += ZWJ.len_utf8() + element;
+                ends.push(len);
+            }
+            None => break,
+        }
+    }
+    let zwj_len = ends
+        .into_iter()
+        .rev()
+        .find(|&end| is_listed_zwj_sequence(&text[..end]));
+    Some(zwj_len.unwrap_or(first))
+}
+
+/// Length in bytes of the RGI tag sequence at the start of `text`
+fn match_tag_sequence(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    let (_, first) = chars.next()?;
+    if !is_extended_pictographic(first) {
+        return None;
+    }
+    for (pos, ch) in chars {
+        if ch == CANCEL_TAG {
+            let len = pos + ch.len_utf8();
+            return is_listed(emoji::RGI_EMOJI_TAG_SEQUENCES, &text[..len]).then_some(len);
+        }
+        if !is_tag_spec(ch) {
+            break;
+        }
+    }
+    None
+}
+
+fn is_listed(sequences: &[&str], sequence: &str) -> bool {
+    sequences.binary_search(&sequence).is_ok()
+}
+
+/// Whether a ZWJ sequence is RGI, fully or minimally qualified
+fn is_listed_zwj_sequence(sequence: &str) -> bool {
+    let unqualified: String = sequence
+        .chars()
+        .filter(|&ch| ch != VARIATION_SELECTOR_16)
+        .collect();
+    is_listed(emoji::RGI_EMOJI_ZWJ_SEQUENCES, &unqualified)
+}
+
+/// Length in bytes of the emoji element at the start of `text`
+fn match_element(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    let (_, first) = chars.next()?;
+
+    if is_regional_indicator(first) {
+        return match chars.next() {
+            Some((pos, ch)) if is_regional_indicator(ch) => Some(pos + ch.len_utf8()),
+            _ => None,
+        };
+    }
+
+    if matches!(first, '0'..='9' | '#' | '*') {
+        chars.next_if(|&(_, ch)| ch == VARIATION_SELECTOR_16);
+        return match chars.next() {
+            Some((pos, COMBINING_KEYCAP)) => Some(pos + COMBINING_KEYCAP.len_utf8()),
+            _ => None,
+        };
+    }
+
+    if !is_extended_pictographic(first) {
+        return None;
+    }
+    match chars.next() {
+        Some((pos, ch)) if ch == VARIATION_SELECTOR_16 || is_emoji_modifier(ch) => {
+            Some(pos + ch.len_utf8())
+        }
+        _ => Some(first.len_utf8()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_sequences() {
+        for emoji in [
+            "😀",
+            "👍🏽",
+            "❤\u{FE0F}",
+            "1\u{FE0F}\u{20E3}",
+            "#\u{20E3}",
+            "🇺🇦",
+            "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+            "👨\u{200D}👩\u{200D}👧\u{200D}👦",
+            "👩🏾\u{200D}🚀",
+            "🏳\u{FE0F}\u{200D}🌈",
+            "👁\u{FE0F}\u{200D}🗨\u{FE0F}",
+            // Minimally qualified
+            "🏳\u{200D}🌈",
+            "❤\u{200D}🔥",
+            "❤\u{FE0F}\u{200D}🔥",
+            "🏃\u{200D}♂\u{FE0F}",
+            "🏃\u{200D}♂",
+        ] {
+            assert!(is_emoji_sequence(emoji), "{:?}", emoji);
+        }
+    }
+
+    #[test]
+    fn test_malformed_sequences() {
+        for text in [
+            "",
+            "a",
+            "\u{200D}",
+            "👨\u{200D}",
+            "a\u{200D}👩",
+            "🇺",
+            "1\u{FE0F}",
+            "🏴\u{E0067}\u{E0062}",
+            // Well-formed, but not recommended
+            "©\u{200D}®",
+            "‼\u{200D}™",
+            "\u{1FC00}\u{200D}\u{1FC01}",
+            "👨\u{200D}👩",
+            "🏴\u{E0078}\u{E0079}\u{E007F}",
+            "😀\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+        ] {
+            assert!(!is_emoji_sequence(text), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_emoji_sequences() {
+        let text = "ok 👨\u{200D}👩\u{200D}👧 x\u{200D}y 🇺🇦\u{200D} 👨\u{200D}👩";
+        let found: Vec<&str> = emoji_sequences(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(found, vec!["👨\u{200D}👩\u{200D}👧", "🇺🇦", "👨", "👩"]);
+    }
+
+    #[test]
+    fn test_longest_listed_sequence() {
+        // The family is listed, the trailing joiner and robot are not
+        let text = "👨\u{200D}👩\u{200D}👧\u{200D}🤖";
+        assert_eq!(match_sequence(text), Some("👨\u{200D}👩\u{200D}👧".len()));
+        assert!(emoji::RGI_EMOJI_ZWJ_SEQUENCES
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+        assert!(emoji::RGI_EMOJI_TAG_SEQUENCES
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+}
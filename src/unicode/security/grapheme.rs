@@ -0,0 +1,126 @@
+//! Extended grapheme cluster segmentation (UAX #29)
+//!
+//! Implements the default grapheme cluster boundary rules except GB9c
+//! (Indic conjunct breaks), which only affects a handful of Brahmic scripts.
+
+use crate::unicode::tables::{self, grapheme::GRAPHEME_CLUSTER_BREAK, property_bool};
+
+/// Grapheme_Cluster_Break property values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum GraphemeClusterBreak {
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    Other,
+}
+
+fn grapheme_cluster_break(ch: char) -> GraphemeClusterBreak {
+    tables::bsearch_range_value_table(ch, GRAPHEME_CLUSTER_BREAK)
+        .unwrap_or(GraphemeClusterBreak::Other)
+}
+
+fn is_extended_pictographic(ch: char) -> bool {
+    tables::bsearch_range_table(ch, property_bool::EXTENDED_PICTOGRAPHIC)
+}
+
+/// Iterator over the extended grapheme clusters of a string
+pub(crate) struct Graphemes<'a> {
+    text: &'a str,
+}
+
+/// Split text into extended grapheme clusters
+pub(crate) fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { text }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        use GraphemeClusterBreak::*;
+
+        let mut chars = self.text.char_indices();
+        let (_, first) = chars.next()?;
+        let mut prev = grapheme_cluster_break(first);
+        // GB11: inside `ExtPict Extend*`, possibly followed by ZWJ
+        let mut in_pictographic = is_extended_pictographic(first);
+        let mut zwj_after_pictographic = false;
+        // GB12/GB13: number of regional indicators in the current run
+        let mut regional_indicators = usize::from(prev == RegionalIndicator);
+        let mut end = self.text.len();
+
+        for (pos, ch) in chars {
+            let next = grapheme_cluster_break(ch);
+            let pictographic = is_extended_pictographic(ch);
+            let join = match (prev, next) {
+                (CR, LF) => true,
+                (CR | LF | Control, _) | (_, CR | LF | Control) => false,
+                (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => true,
+                (_, Extend | ZWJ | SpacingMark) | (Prepend, _) => true,
+                (ZWJ, _) if pictographic && zwj_after_pictographic => true,
+                (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+                _ => false,
+            };
+            if !join {
+                end = pos;
+                break;
+            }
+
+            zwj_after_pictographic = in_pictographic && next == ZWJ;
+            in_pictographic = pictographic || (in_pictographic && next == Extend);
+            regional_indicators = if next == RegionalIndicator {
+                regional_indicators + 1
+            } else {
+                0
+            };
+            prev = next;
+        }
+
+        let (cluster, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        graphemes(text).collect()
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        assert_eq!(split("e\u{0301}x"), vec!["e\u{0301}", "x"]);
+        assert_eq!(split("\r\n"), vec!["\r\n"]);
+        assert_eq!(split(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_emoji_sequences() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(split(family), vec![family]);
+        let flags = "\u{1F1FA}\u{1F1F8}\u{1F1EC}\u{1F1E7}";
+        assert_eq!(split(flags), vec!["\u{1F1FA}\u{1F1F8}", "\u{1F1EC}\u{1F1E7}"]);
+        // A joiner after a letter attaches to it but does not join the next letter
+        assert_eq!(split("a\u{200D}b"), vec!["a\u{200D}", "b"]);
+    }
+
+    #[test]
+    fn test_hangul_and_controls() {
+        assert_eq!(split("\u{1100}\u{1161}\u{11A8}"), vec!["\u{1100}\u{1161}\u{11A8}"]);
+        assert_eq!(split("a\u{200B}b"), vec!["a", "\u{200B}", "b"]);
+    }
+}
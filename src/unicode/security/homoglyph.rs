@@ -0,0 +1,343 @@
+//! Homoglyph variant generation
+//!
+//! Generates visually confusable variants of a string from the UTS #39
+//! confusables data, e.g. for monitoring lookalike domain or package name
+//! registrations, or as a test corpus for detectors.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let variants: Vec<_> = homoglyph_variants("paypal", 10).collect();
+//! assert_eq!(variants.len(), 10);
+//! assert!(variants.iter().all(|v| are_confusable(&v.text, "paypal")));
+//!
+//! // Only Cyrillic substitutions, at most one per variant
+//! let config = HomoglyphConfig::default()
+//!     .with_scripts(&[Script::Cyrillic])
+//!     .with_max_substitutions(1);
+//! let variants: Vec<_> = homoglyph_variants_with_config("paypal", 100, &config).collect();
+//! assert!(variants.iter().all(|v| v.substitutions == 1));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::OnceLock;
+
+use super::{
+    general_category, get_script, is_bidi_char, is_invisible_char, is_private_use_char, GeneralCategory,
+    Script,
+};
+use crate::unicode::tables::confusables::CONFUSABLES;
+
+/// Invisible characters used for insertion variants
+const INVISIBLE_INSERTIONS: [char; 5] = ['\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{00AD}'];
+
+/// Homoglyph generation configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HomoglyphConfig {
+    /// Only substitute characters of these scripts (`None` allows all)
+    pub scripts: Option<Vec<Script>>,
+    /// Maximum number of substitutions and insertions per variant
+    pub max_substitutions: usize,
+    /// Also generate variants with inserted invisible characters
+    pub include_invisible: bool,
+}
+
+impl Default for HomoglyphConfig {
+    fn default() -> Self {
+        Self {
+            scripts: None,
+            max_substitutions: 2,
+            include_invisible: false,
+        }
+    }
+}
+
+impl HomoglyphConfig {
+    /// Restrict substitutions to characters of the given scripts
+    pub fn with_scripts(mut self, scripts: &[Script]) -> Self {
+        self.scripts = Some(scripts.to_vec());
+        self
+    }
+
+    /// Set the maximum number of substitutions per variant
+    pub fn with_max_substitutions(mut self, max: usize) -> Self {
+        self.max_substitutions = max;
+        self
+    }
+
+    /// Include variants with inserted invisible characters
+    pub fn with_invisible(mut self) -> Self {
+        self.include_invisible = true;
+        self
+    }
+}
+
+/// A generated homoglyph variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HomoglyphVariant {
+    /// Variant text
+    pub text: String,
+    /// Number of substituted or inserted characters
+    pub substitutions: usize,
+    /// Visibility score; lower scores are harder to spot
+    pub score: u32,
+}
+
+/// A single change applied to the source string
+#[derive(Debug, Clone, Copy)]
+enum Edit {
+    /// Replace the character at a char index
+    Replace(usize, char),
+    /// Insert a character before a char index
+    Insert(usize, char),
+}
+
+/// Iterator returned by [`homoglyph_variants`], ordered by ascending score
+#[derive(Debug)]
+pub struct HomoglyphVariants {
+    source: Vec<char>,
+    /// Candidate edits sorted by cost
+    edits: Vec<(u32, Edit)>,
+    /// Pending edit sets, as sorted indices into `edits`
+    queue: BinaryHeap<Reverse<(u32, Vec<usize>)>>,
+    max_substitutions: usize,
+    remaining: usize,
+}
+
+/// Generate up to `limit` confusable variants of `text`, hardest to spot first
+pub fn homoglyph_variants(text: &str, limit: usize) -> HomoglyphVariants {
+    homoglyph_variants_with_config(text, limit, &HomoglyphConfig::default())
+}
+
+/// Generate up to `limit` confusable variants of `text` using the given configuration
+pub fn homoglyph_variants_with_config(text: &str, limit: usize, config: &HomoglyphConfig) -> HomoglyphVariants {
+    let source: Vec<char> = text.chars().collect();
+
+    let mut edits = Vec::new();
+    for (i, &ch) in source.iter().enumerate() {
+        for candidate in lookalikes(ch) {
+            let allowed = match &config.scripts {
+                Some(scripts) => scripts.contains(&get_script(candidate)),
+                None => true,
+            };
+            if allowed {
+                edits.push((visibility_cost(candidate), Edit::Replace(i, candidate)));
+            }
+        }
+    }
+    if config.include_invisible {
+        for i in 1..source.len() {
+            for &ch in &INVISIBLE_INSERTIONS {
+                edits.push((1, Edit::Insert(i, ch)));
+            }
+        }
+    }
+    // Stable sort keeps substitutions ahead of insertions of equal cost
+    edits.sort_by_key(|&(cost, _)| cost);
+
+    let mut queue = BinaryHeap::new();
+    if !edits.is_empty() && config.max_substitutions > 0 {
+        queue.push(Reverse((edits[0].0, vec![0])));
+    }
+
+    HomoglyphVariants {
+        source,
+        edits,
+        queue,
+        max_substitutions: config.max_substitutions,
+        remaining: limit,
+    }
+}
+
+impl HomoglyphVariants {
+    /// Check that no two edits touch the same position
+    fn is_consistent(&self, set: &[usize]) -> bool {
+        let mut replaced = Vec::new();
+        let mut inserted = Vec::new();
+        for &i in set {
+            let (seen, pos) = match self.edits[i].1 {
+                Edit::Replace(pos, _) => (&mut replaced, pos),
+                Edit::Insert(pos, _) => (&mut inserted, pos),
+            };
+            if seen.contains(&pos) {
+                return false;
+            }
+            seen.push(pos);
+        }
+        true
+    }
+
+    fn apply(&self, set: &[usize]) -> String {
+        let mut replacements = vec![None; self.source.len()];
+        let mut insertions = vec![None; self.source.len()];
+        for &i in set {
+            match self.edits[i].1 {
+                Edit::Replace(pos, ch) => replacements[pos] = Some(ch),
+                Edit::Insert(pos, ch) => insertions[pos] = Some(ch),
+            }
+        }
+        let mut text = String::new();
+        for (pos, &ch) in self.source.iter().enumerate() {
+            text.extend(insertions[pos]);
+            text.push(replacements[pos].unwrap_or(ch));
+        }
+        text
+    }
+}
+
+impl Iterator for HomoglyphVariants {
+    type Item = HomoglyphVariant;
+
+    fn next(&mut self) -> Option<HomoglyphVariant> {
+        // Enumerate subsets of the cost-sorted edits in order of total cost:
+        // every subset is reached exactly once, either by appending the next
+        // edit or by advancing the last one, and neither lowers the cost.
+        while self.remaining > 0 {
+            let Reverse((score, set)) = self.queue.pop()?;
+            let last = *set.last()?;
+
+            if last + 1 < self.edits.len() {
+                if set.len() < self.max_substitutions {
+                    let mut appended = set.clone();
+                    appended.push(last + 1);
+                    self.queue.push(Reverse((score + self.edits[last + 1].0, appended)));
+                }
+                let mut advanced = set.clone();
+                *advanced.last_mut()? = last + 1;
+                let cost = score - self.edits[last].0 + self.edits[last + 1].0;
+                self.queue.push(Reverse((cost, advanced)));
+            }
+
+            if self.is_consistent(&set) {
+                self.remaining -= 1;
+                return Some(HomoglyphVariant {
+                    text: self.apply(&set),
+                    substitutions: set.len(),
+                    score,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Reverse confusables index: prototype to the characters that map to it
+fn reverse_index() -> &'static HashMap<&'static str, Vec<char>> {
+    static INDEX: OnceLock<HashMap<&'static str, Vec<char>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index: HashMap<&'static str, Vec<char>> = HashMap::new();
+        for &(ch, prototype) in CONFUSABLES {
+            index.entry(prototype).or_default().push(ch);
+        }
+        index
+    })
+}
+
+/// Visible, single-character lookalikes of `ch`, excluding `ch` itself
+fn lookalikes(ch: char) -> Vec<char> {
+    let mut buf = [0; 4];
+    let own = &*ch.encode_utf8(&mut buf);
+    let prototype = CONFUSABLES
+        .binary_search_by_key(&ch, |&(c, _)| c)
+        .map_or(own, |i| CONFUSABLES[i].1);
+
+    let mut candidates: Vec<char> = reverse_index().get(prototype).cloned().unwrap_or_default();
+    let mut prototype_chars = prototype.chars();
+    if let (Some(single), None) = (prototype_chars.next(), prototype_chars.next()) {
+        candidates.push(single);
+    }
+    candidates.retain(|&c| c != ch && is_visible_lookalike(c));
+    candidates.sort_by_key(|&c| (visibility_cost(c), c));
+    candidates.dedup();
+    candidates
+}
+
+fn is_visible_lookalike(ch: char) -> bool {
+    let category = general_category(ch);
+    !is_invisible_char(ch)
+        && !is_bidi_char(ch)
+        && !is_private_use_char(ch)
+        && !category.is_mark()
+        && !matches!(
+            category,
+            GeneralCategory::Control
+                | GeneralCategory::Format
+                | GeneralCategory::Surrogate
+                | GeneralCategory::Unassigned
+                | GeneralCategory::SpaceSeparator
+                | GeneralCategory::LineSeparator
+                | GeneralCategory::ParagraphSeparator
+        )
+}
+
+/// How easy a substituted character is to notice (1 = hardest)
+fn visibility_cost(ch: char) -> u32 {
+    let styled = matches!(ch as u32, 0x2100..=0x214F | 0xFF00..=0xFFEF | 0x1D400..=0x1D7FF);
+    if styled {
+        4
+    } else if general_category(ch).is_letter() {
+        match get_script(ch) {
+            Script::Latin | Script::Cyrillic | Script::Greek => 1,
+            _ => 2,
+        }
+    } else {
+        3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::security::{analyze_text, are_confusable};
+
+    #[test]
+    fn test_variants_are_confusable_and_ranked() {
+        let variants: Vec<_> = homoglyph_variants("paypal", 50).collect();
+        assert_eq!(variants.len(), 50);
+        for pair in variants.windows(2) {
+            assert!(pair[0].score <= pair[1].score);
+        }
+        for variant in &variants {
+            assert_ne!(variant.text, "paypal");
+            assert!(are_confusable(&variant.text, "paypal"), "{}", variant.text);
+            assert!(variant.substitutions <= 2);
+        }
+        // Cyrillic р is a textbook lookalike and ranks first
+        assert!(variants[..5].iter().any(|v| v.text == "\u{0440}aypal"));
+    }
+
+    #[test]
+    fn test_script_restriction() {
+        let config = HomoglyphConfig::default().with_scripts(&[Script::Cyrillic]);
+        let variants: Vec<_> = homoglyph_variants_with_config("paypal", 1000, &config).collect();
+        assert!(!variants.is_empty());
+        for variant in &variants {
+            assert!(variant
+                .text
+                .chars()
+                .all(|ch| ch.is_ascii() || get_script(ch) == Script::Cyrillic));
+        }
+    }
+
+    #[test]
+    fn test_invisible_insertions() {
+        let config = HomoglyphConfig::default()
+            .with_scripts(&[])
+            .with_invisible()
+            .with_max_substitutions(1);
+        let variants: Vec<_> = homoglyph_variants_with_config("ab", 100, &config).collect();
+        assert_eq!(variants.len(), INVISIBLE_INSERTIONS.len());
+        assert!(variants.iter().all(|v| analyze_text(&v.text).has_invisible_chars));
+    }
+
+    #[test]
+    fn test_no_variants() {
+        assert_eq!(homoglyph_variants("", 10).count(), 0);
+        assert_eq!(homoglyph_variants("paypal", 0).count(), 0);
+        let config = HomoglyphConfig::default().with_max_substitutions(0);
+        assert_eq!(homoglyph_variants_with_config("paypal", 10, &config).count(), 0);
+    }
+}
@@ -0,0 +1,544 @@
+//! Identifier validation (UAX #31, UTS #39)
+//!
+//! User-chosen identifiers such as project or variable names are validated
+//! the way compilers do:
+//!
+//! - UAX #31 Default Identifiers: an XID_Start character followed by
+//!   XID_Continue characters
+//! - the UTS #39 General Security Profile: only characters with
+//!   Identifier_Status=Allowed; rejections name their Identifier_Type
+//! - NFC: identifiers must already be normalized, so that canonically
+//!   equivalent spellings cannot name different things. The character checks
+//!   run on the NFC form, which closes the accepted set under NFC (UAX #31
+//!   section 5.1): Hangul spelled with conjoining jamo or U+0958 DEVANAGARI
+//!   LETTER QA are only rejected for not being normalized
+//! - a Highly Restrictive script mix (UTS #39 section 5.2), checked with the
+//!   Script_Extensions property: one script, or Latin with Han and Japanese
+//!   kana, with Han and Bopomofo or with Han and Hangul. Common and Inherited
+//!   characters such as digits and combining marks go with any script
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let profile = IdentifierProfile::default();
+//! assert!(is_valid_identifier("größe", &profile));
+//! assert!(is_valid_identifier("変数_1", &profile));
+//!
+//! // Cyrillic "а" in an otherwise Latin name
+//! let errors = validate_identifier("p\u{0430}ypal", &profile).unwrap_err();
+//! assert_eq!(
+//!     errors,
+//!     vec![IdentifierRejection::MixedScripts {
+//!         scripts: vec![Script::Latin, Script::Cyrillic]
+//!     }]
+//! );
+//!
+//! // Project names may contain hyphens
+//! let names = IdentifierProfile::default().with_continue_chars("-");
+//! assert!(is_valid_identifier("unicode-rs", &names));
+//! ```
+
+use std::fmt;
+
+use super::{get_script, Script};
+use crate::unicode::normalization::{is_nfc, nfc};
+use crate::unicode::tables::{self, identifier::IDENTIFIER_TYPE, property_bool, script};
+
+/// UTS #39 Identifier_Type value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum IdentifierType {
+    /// Unassigned, private-use, surrogate or noncharacter code point
+    NotCharacter,
+    /// Deprecated character
+    Deprecated,
+    /// Default ignorable character
+    DefaultIgnorable,
+    /// Character that changes under NFKC
+    NotNfkc,
+    /// Character that is not XID_Continue
+    NotXid,
+    /// Character of a script not in customary modern use
+    Exclusion,
+    /// Character no longer in customary modern use
+    Obsolete,
+    /// Character for specialized technical use
+    Technical,
+    /// Character not in common use
+    UncommonUse,
+    /// Character of a script in limited use
+    LimitedUse,
+    /// Punctuation allowed in identifiers, such as `-` or `·`
+    Inclusion,
+    /// Character recommended for identifiers
+    Recommended,
+}
+
+impl IdentifierType {
+    /// Property value name as in `IdentifierType.txt`, e.g. `Not_NFKC`
+    pub fn name(self) -> &'static str {
+        match self {
+            IdentifierType::NotCharacter => "Not_Character",
+            IdentifierType::Deprecated => "Deprecated",
+            IdentifierType::DefaultIgnorable => "Default_Ignorable",
+            IdentifierType::NotNfkc => "Not_NFKC",
+            IdentifierType::NotXid => "Not_XID",
+            IdentifierType::Exclusion => "Exclusion",
+            IdentifierType::Obsolete => "Obsolete",
+            IdentifierType::Technical => "Technical",
+            IdentifierType::UncommonUse => "Uncommon_Use",
+            IdentifierType::LimitedUse => "Limited_Use",
+            IdentifierType::Inclusion => "Inclusion",
+            IdentifierType::Recommended => "Recommended",
+        }
+    }
+}
+
+/// Check whether a character has the XID_Start property
+pub fn is_xid_start(ch: char) -> bool {
+    tables::bsearch_range_table(ch, property_bool::XID_START)
+}
+
+/// Check whether a character has the XID_Continue property
+pub fn is_xid_continue(ch: char) -> bool {
+    tables::bsearch_range_table(ch, property_bool::XID_CONTINUE)
+}
+
+/// Check whether a character has Identifier_Status=Allowed (UTS #39)
+pub fn is_identifier_allowed(ch: char) -> bool {
+    tables::bsearch_range_table(ch, property_bool::IDENTIFIER_ALLOWED)
+}
+
+/// Get the Identifier_Type values of a character (UTS #39)
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// assert_eq!(identifier_type('a'), &[IdentifierType::Recommended]);
+/// assert_eq!(identifier_type('\u{E000}'), &[IdentifierType::NotCharacter]);
+/// ```
+pub fn identifier_type(ch: char) -> &'static [IdentifierType] {
+    tables::bsearch_range_value_table(ch, IDENTIFIER_TYPE)
+        .unwrap_or(&[IdentifierType::NotCharacter])
+}
+
+/// Identifier validation profile
+///
+/// The default profile applies every check. Use
+/// [`IdentifierProfile::default_identifiers`] for plain UAX #31 syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifierProfile {
+    /// Characters accepted anywhere in addition to XID_Start, such as `_`
+    pub start_chars: Vec<char>,
+    /// Characters accepted after the first in addition to XID_Continue, such as `-`
+    pub continue_chars: Vec<char>,
+    /// Only accept characters with Identifier_Status=Allowed
+    pub allowed_only: bool,
+    /// Require identifiers to be in NFC
+    pub require_nfc: bool,
+    /// Reject script mixes beyond Highly Restrictive
+    pub single_script: bool,
+}
+
+impl Default for IdentifierProfile {
+    fn default() -> Self {
+        Self {
+            start_chars: vec!['_'],
+            continue_chars: Vec::new(),
+            allowed_only: true,
+            require_nfc: true,
+            single_script: true,
+        }
+    }
+}
+
+impl IdentifierProfile {
+    /// UAX #31 Default Identifiers without security restrictions
+    pub fn default_identifiers() -> Self {
+        Self {
+            start_chars: Vec::new(),
+            continue_chars: Vec::new(),
+            allowed_only: false,
+            require_nfc: false,
+            single_script: false,
+        }
+    }
+
+    /// Also accept these characters anywhere, e.g. `"_$"`
+    pub fn with_start_chars(mut self, chars: &str) -> Self {
+        self.start_chars.extend(chars.chars());
+        self
+    }
+
+    /// Also accept these characters after the first, e.g. `"-"`
+    pub fn with_continue_chars(mut self, chars: &str) -> Self {
+        self.continue_chars.extend(chars.chars());
+        self
+    }
+}
+
+/// Why an identifier was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum IdentifierRejection {
+    /// The identifier is empty
+    Empty,
+    /// The first character cannot start an identifier
+    InvalidStart {
+        /// Byte offset of the character in the NFC form
+        offset: usize,
+        /// The character
+        ch: char,
+    },
+    /// A character cannot appear in an identifier
+    InvalidContinue {
+        /// Byte offset of the character in the NFC form
+        offset: usize,
+        /// The character
+        ch: char,
+    },
+    /// A character does not have Identifier_Status=Allowed
+    Restricted {
+        /// Byte offset of the character in the NFC form
+        offset: usize,
+        /// The character
+        ch: char,
+        /// Its Identifier_Type values
+        types: &'static [IdentifierType],
+    },
+    /// The identifier is not in NFC
+    NotNfc {
+        /// The NFC form of the identifier
+        normalized: String,
+    },
+    /// The identifier mixes scripts
+    MixedScripts {
+        /// Scripts in order of first appearance
+        scripts: Vec<Script>,
+    },
+}
+
+impl fmt::Display for IdentifierRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentifierRejection::Empty => write!(f, "identifier is empty"),
+            IdentifierRejection::InvalidStart { ch, .. } => {
+                write!(f, "U+{:04X} cannot start an identifier", *ch as u32)
+            }
+            IdentifierRejection::InvalidContinue { ch, .. } => {
+                write!(f, "U+{:04X} cannot appear in an identifier", *ch as u32)
+            }
+            IdentifierRejection::Restricted { ch, types, .. } => {
+                let names: Vec<_> = types.iter().map(|t| t.name()).collect();
+                write!(
+                    f,
+                    "U+{:04X} is restricted in identifiers ({})",
+                    *ch as u32,
+                    names.join(", ")
+                )
+            }
+            IdentifierRejection::NotNfc { normalized } => {
+                write!(f, "identifier is not in NFC, expected {:?}", normalized)
+            }
+            IdentifierRejection::MixedScripts { scripts } => {
+                let names: Vec<_> = scripts.iter().map(|s| s.name()).collect();
+                write!(f, "identifier mixes scripts {}", names.join(", "))
+            }
+        }
+    }
+}
+
+/// Check whether `ident` is a valid identifier under `profile`
+///
+/// See [`validate_identifier`] for the reasons of a rejection.
+pub fn is_valid_identifier(ident: &str, profile: &IdentifierProfile) -> bool {
+    validate_identifier(ident, profile).is_ok()
+}
+
+/// Validate `ident` under `profile`, listing every reason for rejection
+///
+/// Characters are checked in the NFC form of `ident`, so canonically
+/// equivalent identifiers get the same result apart from
+/// [`IdentifierRejection::NotNfc`]. Offsets refer to that form, which is
+/// `ident` itself unless it is not normalized.
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// let errors = validate_identifier("1st\u{00B5}", &IdentifierProfile::default()).unwrap_err();
+/// assert_eq!(errors[0], IdentifierRejection::InvalidStart { offset: 0, ch: '1' });
+/// // MICRO SIGN normalizes to Greek mu under NFKC
+/// assert!(matches!(
+///     errors[1],
+///     IdentifierRejection::Restricted { ch: '\u{00B5}', .. }
+/// ));
+/// ```
+pub fn validate_identifier(
+    ident: &str,
+    profile: &IdentifierProfile,
+) -> Result<(), Vec<IdentifierRejection>> {
+    if ident.is_empty() {
+        return Err(vec![IdentifierRejection::Empty]);
+    }
+
+    let normalized = if is_nfc(ident) {
+        None
+    } else {
+        Some(nfc(ident))
+    };
+    let mut rejections = Vec::new();
+    let mut scripts = ScriptMix::default();
+    for (offset, ch) in normalized.as_deref().unwrap_or(ident).char_indices() {
+        let extra = profile.start_chars.contains(&ch)
+            || (offset > 0 && profile.continue_chars.contains(&ch));
+        if !extra {
+            if offset == 0 && !is_xid_start(ch) {
+                rejections.push(IdentifierRejection::InvalidStart { offset, ch });
+                continue;
+            }
+            if !is_xid_continue(ch) {
+                rejections.push(IdentifierRejection::InvalidContinue { offset, ch });
+                continue;
+            }
+            if profile.allowed_only && !is_identifier_allowed(ch) {
+                let types = identifier_type(ch);
+                rejections.push(IdentifierRejection::Restricted { offset, ch, types });
+            }
+        }
+
+        scripts.push(ch);
+    }
+
+    if let Some(normalized) = normalized.filter(|_| profile.require_nfc) {
+        rejections.push(IdentifierRejection::NotNfc { normalized });
+    }
+    if profile.single_script && !scripts.is_highly_restrictive() {
+        let scripts = scripts.scripts;
+        rejections.push(IdentifierRejection::MixedScripts { scripts });
+    }
+
+    if rejections.is_empty() {
+        Ok(())
+    } else {
+        Err(rejections)
+    }
+}
+
+/// Common and Inherited, which go with any script
+const SHARED_SCRIPTS: [[u8; 4]; 2] = [*b"Zyyy", *b"Zinh"];
+
+/// Scripts of the characters of a string, for the mixed-script check
+#[derive(Debug, Default)]
+pub(super) struct ScriptMix {
+    /// Distinct scripts in order of first appearance
+    pub(super) scripts: Vec<Script>,
+    /// Script_Extensions of every character that is not Common or Inherited
+    extensions: Vec<&'static [[u8; 4]]>,
+}
+
+impl ScriptMix {
+    /// Scripts of every character of `text`
+    pub(super) fn of(text: &str) -> Self {
+        let mut mix = Self::default();
+        text.chars().for_each(|ch| mix.push(ch));
+        mix
+    }
+
+    pub(super) fn push(&mut self, ch: char) {
+        let extensions = script_extensions(ch);
+        if let [code] = extensions {
+            if SHARED_SCRIPTS.contains(code) {
+                return;
+            }
+        }
+        self.extensions.push(extensions);
+        let script = get_script(ch);
+        let shared = SHARED_SCRIPTS.map(|code| Script::Other(u32::from_be_bytes(code)));
+        if !shared.contains(&script) && !self.scripts.contains(&script) {
+            self.scripts.push(script);
+        }
+    }
+
+    /// Whether one script, counting Han with kana or Hangul as Japanese or
+    /// Korean, covers every character (UTS #39 section 5.1)
+    pub(super) fn is_single_script(&self) -> bool {
+        let Some(first) = self.extensions.first() else {
+            return true;
+        };
+        first
+            .iter()
+            .chain(&[*b"Jpan", *b"Kore", *b"Hanb"])
+            .any(|script| {
+                self.extensions
+                    .iter()
+                    .all(|extensions| has_augmented(extensions, script))
+            })
+    }
+
+    /// Whether the mix is allowed by the Highly Restrictive level
+    pub(super) fn is_highly_restrictive(&self) -> bool {
+        const COVERING: [&[[u8; 4]]; 3] = [
+            &[*b"Latn", *b"Hani", *b"Hira", *b"Kana"],
+            &[*b"Latn", *b"Hani", *b"Bopo"],
+            &[*b"Latn", *b"Hani", *b"Hang"],
+        ];
+        self.is_single_script()
+            || COVERING.iter().any(|covering| {
+                self.extensions
+                    .iter()
+                    .all(|extensions| extensions.iter().any(|s| covering.contains(s)))
+            })
+    }
+}
+
+/// Script_Extensions of a character as ISO 15924 codes
+fn script_extensions(ch: char) -> &'static [[u8; 4]] {
+    tables::bsearch_range_value_table(ch, script::SCRIPT_EXTENSIONS).unwrap_or_else(|| {
+        let code = tables::bsearch_range_value_table(ch, script::SCRIPT).unwrap_or(b"Zzzz");
+        std::slice::from_ref(code)
+    })
+}
+
+/// Whether the Augmented Script Set of `extensions` contains `script`
+fn has_augmented(extensions: &[[u8; 4]], script: &[u8; 4]) -> bool {
+    let augments: &[[u8; 4]] = match script {
+        b"Jpan" => &[*b"Hani", *b"Hira", *b"Kana"],
+        b"Kore" => &[*b"Hani", *b"Hang"],
+        b"Hanb" => &[*b"Hani", *b"Bopo"],
+        _ => std::slice::from_ref(script),
+    };
+    extensions.iter().any(|s| augments.contains(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xid_properties() {
+        assert!(is_xid_start('a'));
+        assert!(is_xid_start('ß'));
+        assert!(!is_xid_start('_'));
+        assert!(!is_xid_start('1'));
+        assert!(is_xid_continue('1'));
+        assert!(is_xid_continue('_'));
+        assert!(is_xid_continue('\u{0301}'));
+        assert!(!is_xid_continue('-'));
+        assert!(is_identifier_allowed('-'));
+        assert!(!is_identifier_allowed('\u{200D}'));
+        assert_eq!(identifier_type('-'), &[IdentifierType::Inclusion]);
+    }
+
+    #[test]
+    fn test_default_identifiers() {
+        let profile = IdentifierProfile::default_identifiers();
+        assert!(is_valid_identifier("x1", &profile));
+        assert!(is_valid_identifier("p\u{0430}ypal", &profile));
+        assert!(is_valid_identifier("\u{212B}ngstr\u{00F6}m", &profile));
+        assert_eq!(
+            validate_identifier("_a b", &profile),
+            Err(vec![
+                IdentifierRejection::InvalidStart { offset: 0, ch: '_' },
+                IdentifierRejection::InvalidContinue { offset: 2, ch: ' ' },
+            ])
+        );
+        assert_eq!(
+            validate_identifier("", &profile),
+            Err(vec![IdentifierRejection::Empty])
+        );
+    }
+
+    #[test]
+    fn test_security_profile() {
+        let profile = IdentifierProfile::default();
+        for ident in [
+            "_private",
+            "naïve",
+            "Ελλάδα",
+            "東京タワー",
+            "서울2024",
+            "JSONパーサ",
+        ] {
+            assert_eq!(validate_identifier(ident, &profile), Ok(()), "{}", ident);
+        }
+
+        // Decomposed "é" is not in NFC
+        assert_eq!(
+            validate_identifier("cafe\u{0301}", &profile),
+            Err(vec![IdentifierRejection::NotNfc {
+                normalized: "caf\u{00E9}".to_string()
+            }])
+        );
+
+        // Checked in NFC: the decomposed spellings are only not normalized
+        assert_eq!(
+            validate_identifier("\u{1100}\u{1161}", &profile),
+            Err(vec![IdentifierRejection::NotNfc {
+                normalized: "\u{AC00}".to_string()
+            }])
+        );
+        assert_eq!(
+            validate_identifier("\u{0958}", &profile),
+            Err(vec![IdentifierRejection::NotNfc {
+                normalized: "\u{0915}\u{093C}".to_string()
+            }])
+        );
+        let nfc_optional = IdentifierProfile {
+            require_nfc: false,
+            ..IdentifierProfile::default()
+        };
+        assert!(is_valid_identifier("\u{1100}\u{1161}", &nfc_optional));
+
+        // Zero width joiner is XID_Continue but a default ignorable
+        let errors = validate_identifier("ab\u{200D}c", &profile).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![IdentifierRejection::Restricted {
+                offset: 2,
+                ch: '\u{200D}',
+                types: &[IdentifierType::DefaultIgnorable]
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "U+200D is restricted in identifiers (Default_Ignorable)"
+        );
+
+        assert!(!is_valid_identifier(
+            "Ελλάδα-да",
+            &profile.clone().with_continue_chars("-")
+        ));
+        assert!(is_valid_identifier("한국日本", &profile));
+        assert!(!is_valid_identifier("한국カナ", &profile));
+    }
+
+    #[test]
+    fn test_script_extensions() {
+        let profile = IdentifierProfile::default();
+        // Armenian "օ" in an otherwise Latin name
+        let armenian = Script::Other(u32::from_be_bytes(*b"Armn"));
+        let errors = validate_identifier("g\u{0585}\u{0585}gle", &profile).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![IdentifierRejection::MixedScripts {
+                scripts: vec![Script::Latin, armenian]
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "identifier mixes scripts Latin, Armenian"
+        );
+        assert!(is_valid_identifier(
+            "\u{0562}\u{0561}\u{0580}\u{056B}",
+            &profile
+        ));
+        assert!(is_valid_identifier("ქართული", &profile));
+
+        // The prolonged sound mark goes with both kana, the tatweel with Arabic
+        assert!(is_valid_identifier("カー", &profile));
+        assert!(ScriptMix::of("\u{0643}\u{0640}\u{062A}\u{0627}\u{0628}").is_single_script());
+        assert!(!ScriptMix::of("a\u{0640}").is_highly_restrictive());
+    }
+}
@@ -0,0 +1,305 @@
+//! Log injection detection
+//!
+//! A user-supplied string written into a line-oriented log can end the
+//! current entry and start a forged one. Besides `\n` and `\r`, log viewers
+//! and parsers break lines at the other mandatory breaks of UAX #14: vertical
+//! tab, form feed, NEXT LINE (U+0085) and the LINE and PARAGRAPH SEPARATORs
+//! (U+2028, U+2029). [`analyze_log_safety`] reports these, other control
+//! characters such as terminal escape sequences, and lines that imitate the
+//! start of a log entry. [`sanitize_for_log`] escapes them so the string
+//! stays on one line.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let input = "bob\u{2028}2024-05-01 12:00:00 INFO admin logged in";
+//! let analysis = analyze_log_safety(input);
+//! assert_eq!(analysis.line_breaks, vec![(3, '\u{2028}', "Line Separator")]);
+//! assert_eq!(analysis.forged_entries, vec![6]);
+//! assert_eq!(analysis.risk_level, RiskLevel::Critical);
+//!
+//! assert_eq!(
+//!     sanitize_for_log(input),
+//!     "bob\\u{2028}2024-05-01 12:00:00 INFO admin logged in"
+//! );
+//! ```
+
+use super::{emoji_sequences, is_bidi_char, is_invisible_char, RiskLevel};
+
+/// Log injection analysis result
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LogAnalysis {
+    /// Line break characters with their positions
+    pub line_breaks: Vec<(usize, char, &'static str)>,
+    /// Other control characters with their positions, e.g. ESC
+    pub control_chars: Vec<(usize, char, &'static str)>,
+    /// Byte offsets of lines after a break that look like the start of a log entry
+    pub forged_entries: Vec<usize>,
+    /// Overall risk level
+    pub risk_level: RiskLevel,
+}
+
+/// Options for [`sanitize_for_log_with_config`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogSanitizeConfig {
+    /// Escape `"` for values embedded in quoted fields, e.g. logfmt or JSON
+    pub escape_quotes: bool,
+}
+
+impl LogSanitizeConfig {
+    /// Also escape double quotes
+    pub fn with_escaped_quotes(mut self) -> Self {
+        self.escape_quotes = true;
+        self
+    }
+}
+
+/// Check whether a character is a mandatory line break (UAX #14 classes BK, CR, LF and NL)
+pub fn is_line_break(ch: char) -> bool {
+    matches!(
+        ch,
+        '\n' | '\u{000B}' | '\u{000C}' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+fn line_break_description(ch: char) -> &'static str {
+    match ch {
+        '\n' => "Line Feed",
+        '\u{000B}' => "Vertical Tab",
+        '\u{000C}' => "Form Feed",
+        '\r' => "Carriage Return",
+        '\u{0085}' => "Next Line",
+        '\u{2028}' => "Line Separator",
+        '\u{2029}' => "Paragraph Separator",
+        _ => "Unknown",
+    }
+}
+
+fn control_description(ch: char) -> &'static str {
+    match ch {
+        '\u{0000}' => "Null",
+        '\u{0007}' => "Bell",
+        '\u{0008}' => "Backspace",
+        '\u{001B}' => "Escape",
+        '\u{007F}' => "Delete",
+        '\u{009B}' => "Control Sequence Introducer",
+        _ => "Control Character",
+    }
+}
+
+/// Control characters other than tab and line breaks
+fn is_log_control(ch: char) -> bool {
+    ch.is_control() && ch != '\t' && !is_line_break(ch)
+}
+
+/// Analyze a string that will be written into a log
+pub fn analyze_log_safety(text: &str) -> LogAnalysis {
+    let mut line_breaks = Vec::new();
+    let mut control_chars = Vec::new();
+    let mut forged_entries = Vec::new();
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((offset, ch)) = chars.next() {
+        if is_line_break(ch) {
+            line_breaks.push((offset, ch, line_break_description(ch)));
+            // CR LF is a single break
+            if ch == '\r' && chars.peek().map(|&(_, next)| next) == Some('\n') {
+                continue;
+            }
+            let start = offset + ch.len_utf8();
+            if looks_like_log_entry(&text[start..]) {
+                forged_entries.push(start);
+            }
+        } else if is_log_control(ch) {
+            control_chars.push((offset, ch, control_description(ch)));
+        }
+    }
+
+    let risk_level = if !forged_entries.is_empty() {
+        RiskLevel::Critical
+    } else if !line_breaks.is_empty() || !control_chars.is_empty() {
+        RiskLevel::High
+    } else {
+        RiskLevel::Low
+    };
+
+    LogAnalysis {
+        line_breaks,
+        control_chars,
+        forged_entries,
+        risk_level,
+    }
+}
+
+/// Escape a string so it can be written into a log as a single line
+///
+/// Backslashes, line breaks, other control characters, and the invisible and
+/// bidirectional characters removed by [`sanitize_text`](super::sanitize_text)
+/// are escaped rather than removed, so the log still shows what was sent.
+/// `\n`, `\r` and `\\` use their short forms, everything else is written as
+/// `\u{XXXX}`. Emoji sequences are kept intact.
+pub fn sanitize_for_log(text: &str) -> String {
+    sanitize_for_log_with_config(text, &LogSanitizeConfig::default())
+}
+
+/// Escape a string for a log using the given configuration
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// let config = LogSanitizeConfig::default().with_escaped_quotes();
+/// assert_eq!(
+///     sanitize_for_log_with_config("x\" level=\"admin", &config),
+///     "x\\\" level=\\\"admin"
+/// );
+/// ```
+pub fn sanitize_for_log_with_config(text: &str, config: &LogSanitizeConfig) -> String {
+    let escape = |part: &str, out: &mut String| {
+        for ch in part.chars() {
+            match ch {
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '"' if config.escape_quotes => out.push_str("\\\""),
+                _ if is_line_break(ch)
+                    || is_log_control(ch)
+                    || is_invisible_char(ch)
+                    || is_bidi_char(ch) =>
+                {
+                    out.push_str(&format!("\\u{{{:04X}}}", ch as u32))
+                }
+                _ => out.push(ch),
+            }
+        }
+    };
+
+    let mut sanitized = String::with_capacity(text.len());
+    let mut pos = 0;
+    for range in emoji_sequences(text) {
+        escape(&text[pos..range.start], &mut sanitized);
+        sanitized.push_str(&text[range.clone()]);
+        pos = range.end;
+    }
+    escape(&text[pos..], &mut sanitized);
+    sanitized
+}
+
+const LOG_LEVELS: &[&str] = &[
+    "TRACE", "DEBUG", "INFO", "NOTICE", "WARN", "WARNING", "ERROR", "FATAL", "CRITICAL",
+];
+
+const MONTHS: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Whether a line starts like a log entry: a timestamp, a syslog priority or month, or a level
+fn looks_like_log_entry(line: &str) -> bool {
+    // Syslog priority such as <13>
+    if let Some(rest) = line.strip_prefix('<') {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if (1..=3).contains(&digits) && rest[digits..].starts_with('>') {
+            return true;
+        }
+    }
+
+    let line = line.trim_start_matches(['[', ' ']);
+    let bytes = line.as_bytes();
+    // 2024-05-01, 2024/05/01 or 12:00:00
+    let date = matches_pattern(bytes, b"dddd-dd-dd") || matches_pattern(bytes, b"dddd/dd/dd");
+    let time = matches_pattern(bytes, b"dd:dd:dd");
+    // Syslog "May  1" or "May 12"
+    let month = MONTHS.iter().any(|month| {
+        line.strip_prefix(month)
+            .and_then(|rest| rest.strip_prefix(' '))
+            .is_some_and(|rest| rest.trim_start().starts_with(|c: char| c.is_ascii_digit()))
+    });
+    let level = LOG_LEVELS.iter().any(|level| {
+        line.strip_prefix(level)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', ':', ']', '|', '\t']))
+    });
+
+    date || time || month || level
+}
+
+/// Match `bytes` against a pattern where `d` stands for an ASCII digit
+fn matches_pattern(bytes: &[u8], pattern: &[u8]) -> bool {
+    bytes.len() >= pattern.len()
+        && pattern.iter().zip(bytes).all(|(&p, &b)| match p {
+            b'd' => b.is_ascii_digit(),
+            _ => p == b,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_breaks() {
+        for ch in [
+            '\n', '\r', '\u{000B}', '\u{000C}', '\u{0085}', '\u{2028}', '\u{2029}',
+        ] {
+            assert!(is_line_break(ch), "{:?}", ch);
+            let analysis = analyze_log_safety(&format!("a{}b", ch));
+            assert_eq!(analysis.line_breaks.len(), 1);
+            assert_eq!(analysis.risk_level, RiskLevel::High);
+        }
+        assert!(!is_line_break('\t'));
+        assert_eq!(analyze_log_safety("plain value").risk_level, RiskLevel::Low);
+    }
+
+    #[test]
+    fn test_forged_entries() {
+        let forged = [
+            "x\r\n2024-05-01T12:00:00Z user=admin",
+            "x\n[12:00:00] ok",
+            "x\nMay  1 12:00:00 host sshd[1]: Accepted",
+            "x\n<13>May 1 host",
+            "x\nERROR: disk full",
+            "x\u{0085}[INFO] done",
+        ];
+        for input in forged {
+            let analysis = analyze_log_safety(input);
+            assert_eq!(analysis.forged_entries.len(), 1, "{:?}", input);
+            assert_eq!(analysis.risk_level, RiskLevel::Critical);
+        }
+        assert_eq!(analyze_log_safety("x\r\nINFO").forged_entries, vec![3]);
+
+        for input in [
+            "x\nhello",
+            "x\nInformation",
+            "x\n2024 was a good year",
+            "x\n3> 2",
+        ] {
+            assert!(
+                analyze_log_safety(input).forged_entries.is_empty(),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_sanitize_for_log() {
+        assert_eq!(sanitize_for_log("a\r\nb"), "a\\r\\nb");
+        assert_eq!(sanitize_for_log("C:\\temp\n"), "C:\\\\temp\\n");
+        assert_eq!(sanitize_for_log("\u{001B}[31mred"), "\\u{001B}[31mred");
+        assert_eq!(
+            sanitize_for_log("a\u{202E}b\u{200B}"),
+            "a\\u{202E}b\\u{200B}"
+        );
+        assert_eq!(sanitize_for_log("say \"hi\"\tnow"), "say \"hi\"\tnow");
+        assert_eq!(
+            sanitize_for_log("👨\u{200D}👩\u{200D}👧"),
+            "👨\u{200D}👩\u{200D}👧"
+        );
+        assert_eq!(sanitize_for_log("©\u{200D}®"), "©\\u{200D}®");
+
+        let analysis = analyze_log_safety("\u{001B}[2J");
+        assert_eq!(analysis.control_chars, vec![(0, '\u{001B}', "Escape")]);
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+    }
+}
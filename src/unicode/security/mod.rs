@@ -0,0 +1,1074 @@
+//! Unicode security utilities
+//!
+//! This module provides utilities to detect and analyze potentially dangerous Unicode characters
+//! that could be used in security attacks such as:
+//! - Homograph attacks (visually similar characters)
+//! - Invisible character injection
+//! - Bidirectional text attacks
+//! - Mixed script attacks
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! // Check for suspicious characters
+//! let result = analyze_text("Hello\u{200B}World"); // Zero-width space
+//! assert!(result.has_invisible_chars);
+//!
+//! // Detect homograph attacks
+//! let suspicious = "раураӏ.com"; // Cyrillic characters that look like "paypal.com"
+//! let analysis = analyze_text(suspicious);
+//! assert!(analysis.has_mixed_scripts);
+//! ```
+
+mod account;
+mod bidi;
+mod codepoint;
+mod collision;
+mod confusables;
+mod difference;
+mod emoji;
+pub(crate) mod grapheme;
+mod homoglyph;
+mod identifier;
+mod log_injection;
+mod patch;
+mod report;
+mod suppress;
+
+pub use account::{
+    analyze_email, analyze_email_with_config, analyze_username, EmailAnalysis, EmailConfig,
+    EmailIssue, UsernameAnalysis,
+};
+pub use bidi::{
+    bidi_class, bidi_paragraphs, bidi_paragraphs_with_direction, unterminated_bidi_controls,
+    visual_order, visual_order_with_direction, BaseDirection, BidiClass, BidiParagraph,
+};
+pub use codepoint::{
+    char_age, classify_codepoint, general_category, is_deprecated_char, is_noncharacter,
+    is_private_use_char, is_unassigned_char, CodepointClass, GeneralCategory, UnicodeVersion,
+};
+pub use collision::{Collision, CollisionIndex, CollisionMode};
+pub use confusables::{are_confusable, confusable_prototype, skeleton};
+pub use difference::{explain_difference, Difference, DifferenceKind, DifferenceReport};
+pub use emoji::{emoji_sequences, is_emoji_sequence};
+pub use homoglyph::{
+    homoglyph_variants, homoglyph_variants_with_config, HomoglyphConfig, HomoglyphVariant,
+    HomoglyphVariants,
+};
+pub use identifier::{
+    identifier_type, is_identifier_allowed, is_valid_identifier, is_xid_continue, is_xid_start,
+    validate_identifier, IdentifierProfile, IdentifierRejection, IdentifierType,
+};
+pub use log_injection::{
+    analyze_log_safety, is_line_break, sanitize_for_log, sanitize_for_log_with_config,
+    LogAnalysis, LogSanitizeConfig,
+};
+pub use patch::{scan_diff, scan_diff_with_config};
+#[cfg(feature = "serde")]
+pub use report::{render_json, render_sarif};
+pub use report::{render_compact, FileReport, Finding, FindingKind};
+pub use suppress::{AllowRule, Allowlist, Baseline, Suppression, SuppressionSource};
+#[cfg(feature = "names")]
+pub use crate::unicode::names::char_name;
+
+use std::collections::HashSet;
+
+use crate::unicode::tables::{self, script};
+
+/// Security analysis result for Unicode text
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SecurityAnalysis {
+    /// Contains invisible or zero-width characters
+    pub has_invisible_chars: bool,
+    /// Contains bidirectional override characters
+    pub has_bidi_overrides: bool,
+    /// Contains mixed scripts (potential homograph attack)
+    pub has_mixed_scripts: bool,
+    /// Contains confusable characters
+    pub has_confusables: bool,
+    /// List of detected invisible characters with their positions
+    pub invisible_chars: Vec<(usize, char, &'static str)>,
+    /// List of detected bidirectional characters with their positions
+    pub bidi_chars: Vec<(usize, char, &'static str)>,
+    /// Contains private-use characters
+    pub has_private_use: bool,
+    /// Contains noncharacters
+    pub has_noncharacters: bool,
+    /// Contains code points unassigned in the configured Unicode version
+    pub has_unassigned: bool,
+    /// Contains deprecated characters
+    pub has_deprecated: bool,
+    /// List of detected private-use characters with their positions
+    pub private_use_chars: Vec<(usize, char, &'static str)>,
+    /// List of detected noncharacters with their positions
+    pub noncharacters: Vec<(usize, char, &'static str)>,
+    /// List of detected unassigned code points with their positions
+    pub unassigned_chars: Vec<(usize, char, &'static str)>,
+    /// List of detected deprecated characters with their positions
+    pub deprecated_chars: Vec<(usize, char, &'static str)>,
+    /// Contains embeddings, overrides or isolates left open at the end of a paragraph
+    pub has_unterminated_bidi: bool,
+    /// List of unterminated bidirectional controls with their positions
+    pub unterminated_bidi: Vec<(usize, char, &'static str)>,
+    /// Set of detected scripts
+    pub scripts: HashSet<Script>,
+    /// Overall risk level
+    pub risk_level: RiskLevel,
+}
+
+/// Unicode script categories
+///
+/// Scripts without a variant of their own are `Other`, holding the ISO 15924
+/// code of the Unicode Script property as big-endian bytes, such as
+/// `u32::from_be_bytes(*b"Armn")`. Characters shared by all scripts are
+/// `Other` with the codes `Zyyy` (Common) and `Zinh` (Inherited).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Chinese,
+    Japanese,
+    Korean,
+    Thai,
+    Devanagari,
+    Other(u32), // Unicode script code
+}
+
+impl Script {
+    /// Script of an ISO 15924 code from the Script property
+    pub(crate) fn from_code(code: &[u8; 4]) -> Self {
+        match code {
+            b"Latn" => Script::Latin,
+            b"Cyrl" => Script::Cyrillic,
+            b"Grek" => Script::Greek,
+            b"Arab" => Script::Arabic,
+            b"Hebr" => Script::Hebrew,
+            b"Hani" => Script::Chinese,
+            b"Hira" | b"Kana" => Script::Japanese,
+            b"Hang" => Script::Korean,
+            b"Thai" => Script::Thai,
+            b"Deva" => Script::Devanagari,
+            _ => Script::Other(u32::from_be_bytes(*code)),
+        }
+    }
+
+    /// English name of the script, such as `Latin` or `Armenian`
+    ///
+    /// ```rust
+    /// use unicode_rs::security::*;
+    ///
+    /// assert_eq!(get_script('ա').name(), "Armenian");
+    /// assert_eq!(get_script('世').name(), "Chinese");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Script::Latin => "Latin",
+            Script::Cyrillic => "Cyrillic",
+            Script::Greek => "Greek",
+            Script::Arabic => "Arabic",
+            Script::Hebrew => "Hebrew",
+            Script::Chinese => "Chinese",
+            Script::Japanese => "Japanese",
+            Script::Korean => "Korean",
+            Script::Thai => "Thai",
+            Script::Devanagari => "Devanagari",
+            Script::Other(code) => script::SCRIPT_NAMES
+                .binary_search_by_key(&code.to_be_bytes(), |&(code, _)| *code)
+                .map_or("Unknown", |i| script::SCRIPT_NAMES[i].1),
+        }
+    }
+}
+
+/// Risk level assessment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RiskLevel {
+    /// No security concerns detected
+    Low,
+    /// Minor concerns, likely benign
+    Medium,
+    /// Significant security concerns
+    High,
+    /// Critical security risk
+    Critical,
+}
+
+/// Security analysis configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecurityConfig {
+    /// Unicode version that defines which code points count as assigned
+    pub unicode_version: UnicodeVersion,
+}
+
+impl SecurityConfig {
+    /// Create new config for the given Unicode version
+    pub fn with_unicode_version(version: UnicodeVersion) -> Self {
+        Self {
+            unicode_version: version,
+        }
+    }
+}
+
+/// Analyze text for Unicode security issues
+///
+/// This function performs a comprehensive analysis of the input text to detect
+/// various Unicode-based security vulnerabilities.
+///
+/// # Arguments
+///
+/// * `text` - The text to analyze
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// // Safe text
+/// let safe = analyze_text("Hello World");
+/// assert_eq!(safe.risk_level, RiskLevel::Low);
+///
+/// // Text with invisible characters
+/// let suspicious = analyze_text("Hello\u{200B}World");
+/// assert_eq!(suspicious.risk_level, RiskLevel::High);
+/// ```
+pub fn analyze_text(text: &str) -> SecurityAnalysis {
+    analyze_text_with_config(text, &SecurityConfig::default())
+}
+
+/// Analyze text for Unicode security issues using the given configuration
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// // U+1FAE9 was assigned in Unicode 16.0
+/// let config = SecurityConfig::with_unicode_version(UnicodeVersion::new(15, 1, 0));
+/// let analysis = analyze_text_with_config("face \u{1FAE9}", &config);
+/// assert!(analysis.has_unassigned);
+/// ```
+pub fn analyze_text_with_config(text: &str, config: &SecurityConfig) -> SecurityAnalysis {
+    let mut analysis = SecurityAnalysis {
+        has_invisible_chars: false,
+        has_bidi_overrides: false,
+        has_mixed_scripts: false,
+        has_confusables: false,
+        invisible_chars: Vec::new(),
+        bidi_chars: Vec::new(),
+        has_private_use: false,
+        has_noncharacters: false,
+        has_unassigned: false,
+        has_deprecated: false,
+        private_use_chars: Vec::new(),
+        noncharacters: Vec::new(),
+        unassigned_chars: Vec::new(),
+        deprecated_chars: Vec::new(),
+        has_unterminated_bidi: false,
+        unterminated_bidi: Vec::new(),
+        scripts: HashSet::new(),
+        risk_level: RiskLevel::Low,
+    };
+
+    // Joiners and selectors inside well-formed emoji sequences are benign
+    let emoji = emoji_sequences(text);
+    let mut next_emoji = emoji.iter().peekable();
+
+    let bytes = text.as_bytes();
+    let mut ascii_seen = [false; 256];
+    let mut last_script = None;
+    let mut mixing = HashSet::new();
+    let mut last_mixing = None;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        // ASCII characters can only contribute their script, so runs of them
+        // are just recorded. The one before a non-ASCII character may start
+        // an emoji keycap and takes the full path
+        let run = ascii_run_len(&bytes[pos..]);
+        let skip = if pos + run < bytes.len() {
+            run.saturating_sub(1)
+        } else {
+            run
+        };
+        for &byte in &bytes[pos..pos + skip] {
+            ascii_seen[usize::from(byte)] = true;
+        }
+        pos += skip;
+        let Some(ch) = text[pos..].chars().next() else {
+            break;
+        };
+
+        while next_emoji.next_if(|range| range.end <= pos).is_some() {}
+        let in_emoji = next_emoji.peek().is_some_and(|range| range.start <= pos);
+
+        // Check for invisible characters
+        if is_invisible_char(ch) && !in_emoji {
+            analysis.has_invisible_chars = true;
+            analysis.invisible_chars.push((pos, ch, get_char_description(ch)));
+        }
+
+        // Check for bidirectional override characters
+        if is_bidi_char(ch) {
+            analysis.has_bidi_overrides = true;
+            analysis.bidi_chars.push((pos, ch, get_char_description(ch)));
+        }
+
+        // Check for private-use, noncharacter, unassigned and deprecated code points
+        match classify_codepoint(ch, config.unicode_version) {
+            Some(CodepointClass::PrivateUse) => {
+                analysis.has_private_use = true;
+                analysis.private_use_chars.push((pos, ch, get_char_description(ch)));
+            }
+            Some(CodepointClass::Noncharacter) => {
+                analysis.has_noncharacters = true;
+                analysis.noncharacters.push((pos, ch, get_char_description(ch)));
+            }
+            Some(CodepointClass::Unassigned) => {
+                analysis.has_unassigned = true;
+                analysis.unassigned_chars.push((pos, ch, get_char_description(ch)));
+            }
+            Some(CodepointClass::Deprecated) => {
+                analysis.has_deprecated = true;
+                analysis.deprecated_chars.push((pos, ch, get_char_description(ch)));
+            }
+            None => {}
+        }
+
+        // Collect scripts, emoji do not belong to any
+        if !in_emoji {
+            let script = get_script(ch);
+            if last_script != Some(script) {
+                analysis.scripts.insert(script);
+                last_script = Some(script);
+            }
+            if is_mixing_script(ch, script) && last_mixing != Some(script) {
+                mixing.insert(script);
+                last_mixing = Some(script);
+            }
+        }
+
+        // Check for confusable characters
+        if is_confusable_char(ch) {
+            analysis.has_confusables = true;
+        }
+
+        pos += ch.len_utf8();
+    }
+    for (byte, _) in ascii_seen.iter().enumerate().filter(|&(_, &seen)| seen) {
+        let ch = char::from(byte as u8);
+        analysis.scripts.insert(get_script(ch));
+        if ch.is_ascii_alphabetic() {
+            mixing.insert(Script::Latin);
+        }
+    }
+
+    // Unterminated controls reorder everything up to the end of the paragraph
+    if analysis.has_bidi_overrides {
+        analysis.unterminated_bidi = unterminated_bidi_controls(text);
+        analysis.has_unterminated_bidi = !analysis.unterminated_bidi.is_empty();
+    }
+
+    // Determine if mixed scripts (potential homograph attack)
+    // Only letters of a named script count, so Latin text with a "<" or Hebrew
+    // text with ASCII spaces is not mixed
+    analysis.has_mixed_scripts = mixing.len() > 1;
+
+    // Calculate risk level
+    analysis.risk_level = calculate_risk_level(&analysis);
+
+    analysis
+}
+
+/// Length of the run of ASCII bytes at the start of `bytes`
+///
+/// Bytes are checked a machine word at a time.
+pub(crate) fn ascii_run_len(bytes: &[u8]) -> usize {
+    const WORD: usize = std::mem::size_of::<usize>();
+    const HIGH_BITS: usize = usize::from_ne_bytes([0x80; WORD]);
+
+    let mut len = 0;
+    for chunk in bytes.chunks_exact(WORD) {
+        let Ok(word) = <[u8; WORD]>::try_from(chunk) else {
+            break;
+        };
+        if usize::from_ne_bytes(word) & HIGH_BITS != 0 {
+            break;
+        }
+        len += WORD;
+    }
+    len + bytes[len..].iter().take_while(|byte| byte.is_ascii()).count()
+}
+
+/// Invisible and zero-width characters, as sorted ranges
+const INVISIBLE_CHARS: &[(char, char)] = &[
+    ('\u{00AD}', '\u{00AD}'),   // Soft hyphen
+    ('\u{034F}', '\u{034F}'),   // Combining grapheme joiner
+    ('\u{061C}', '\u{061C}'),   // Arabic letter mark
+    ('\u{115F}', '\u{1160}'),   // Hangul choseong and jungseong fillers
+    ('\u{17B4}', '\u{17B5}'),   // Khmer vowels inherent AQ and AA
+    ('\u{180E}', '\u{180E}'),   // Mongolian vowel separator
+    ('\u{200B}', '\u{200F}'),   // Zero width space, non-joiner and joiner, LRM and RLM
+    ('\u{202A}', '\u{202E}'),   // Bidi embeddings, overrides and pop
+    ('\u{2060}', '\u{2064}'),   // Word joiner and invisible operators
+    ('\u{206A}', '\u{206F}'),   // Deprecated format characters
+    ('\u{3164}', '\u{3164}'),   // Hangul filler
+    ('\u{FEFF}', '\u{FEFF}'),   // Zero width no-break space (BOM)
+    ('\u{FFA0}', '\u{FFA0}'),   // Halfwidth Hangul filler
+    ('\u{1D159}', '\u{1D159}'), // Musical symbol null notehead
+    ('\u{1D173}', '\u{1D17A}'), // Musical symbol beam, tie, slur and phrase controls
+    ('\u{E0020}', '\u{E007F}'), // Tag characters, outside RGI emoji tag sequences
+];
+
+/// Bidirectional control characters, as sorted ranges
+const BIDI_CHARS: &[(char, char)] = &[
+    ('\u{061C}', '\u{061C}'), // Arabic letter mark
+    ('\u{200E}', '\u{200F}'), // Left-to-right and right-to-left marks
+    ('\u{202A}', '\u{202E}'), // Embeddings, overrides and pop directional formatting
+    ('\u{2066}', '\u{2069}'), // Isolates and pop directional isolate
+];
+
+/// Characters commonly used in confusable attacks, as sorted ranges
+///
+/// This is a simplified set; see [`skeleton`] for the full UTS #39 data.
+const CONFUSABLE_CHARS: &[(char, char)] = &[
+    // Greek that look like Latin
+    ('α', 'ρ'),
+    ('σ', 'ω'),
+    // Cyrillic that look like Latin
+    ('А', 'А'),
+    ('В', 'В'),
+    ('Е', 'Е'),
+    ('К', 'К'),
+    ('М', 'О'),
+    ('Р', 'У'),
+    ('Х', 'Х'),
+    ('а', 'а'),
+    ('е', 'е'),
+    ('о', 'о'),
+    ('р', 'с'),
+    ('у', 'у'),
+    ('х', 'х'),
+    // Mathematical symbols that look like Latin
+    ('𝐀', '𝐙'),
+];
+
+/// ASCII characters counted as Latin, one bit per character
+///
+/// Common punctuation, digits and whitespace are not counted as separate
+/// scripts.
+const ASCII_LATIN: u128 = ascii_mask(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789 \t\n\r!?.,;:\"'()[]{}<>-_=+*/\\|@#$%^&~`",
+);
+
+/// Scripts shared by all others: Common, Inherited and Unknown
+const SHARED_SCRIPTS: [[u8; 4]; 3] = [*b"Zyyy", *b"Zinh", *b"Zzzz"];
+
+/// Whether `ch`, of script `script`, decides if text mixes scripts
+///
+/// ASCII digits and punctuation, and characters of the Common and Inherited
+/// scripts, go with text of any script.
+fn is_mixing_script(ch: char, script: Script) -> bool {
+    match script {
+        Script::Latin => !ch.is_ascii() || ch.is_ascii_alphabetic(),
+        Script::Other(code) => !SHARED_SCRIPTS.contains(&code.to_be_bytes()),
+        _ => true,
+    }
+}
+
+const fn ascii_mask(chars: &[u8]) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        mask |= 1 << chars[i];
+        i += 1;
+    }
+    mask
+}
+
+/// Check if a character is invisible or zero-width
+pub fn is_invisible_char(ch: char) -> bool {
+    !ch.is_ascii() && tables::bsearch_range_table(ch, INVISIBLE_CHARS)
+}
+
+/// Check if a character is a bidirectional override character
+pub fn is_bidi_char(ch: char) -> bool {
+    !ch.is_ascii() && tables::bsearch_range_table(ch, BIDI_CHARS)
+}
+
+/// Check if a character is commonly used in confusable attacks
+pub fn is_confusable_char(ch: char) -> bool {
+    !ch.is_ascii() && tables::bsearch_range_table(ch, CONFUSABLE_CHARS)
+}
+
+/// Get the script category for a character
+///
+/// Non-ASCII characters follow the Unicode Script property.
+pub fn get_script(ch: char) -> Script {
+    if ch.is_ascii() && ASCII_LATIN & (1 << ch as u32) != 0 {
+        return Script::Latin;
+    }
+    let code = tables::bsearch_range_value_table(ch, script::SCRIPT).unwrap_or(b"Zzzz");
+    Script::from_code(code)
+}
+
+/// Get a human-readable description of a character
+///
+/// Every character reported by [`is_invisible_char`], [`is_bidi_char`] and
+/// [`is_deprecated_char`] has a description here, the same with or without
+/// the `names` feature. For the official Unicode name of arbitrary
+/// characters enable the `names` feature and use `char_name`.
+pub fn get_char_description(ch: char) -> &'static str {
+    match ch {
+        '\u{00AD}' => "Soft Hyphen",
+        '\u{034F}' => "Combining Grapheme Joiner",
+        '\u{061C}' => "Arabic Letter Mark",
+        '\u{115F}' => "Hangul Choseong Filler",
+        '\u{1160}' => "Hangul Jungseong Filler",
+        '\u{17B4}' => "Khmer Vowel Inherent AQ",
+        '\u{17B5}' => "Khmer Vowel Inherent AA",
+        '\u{180E}' => "Mongolian Vowel Separator",
+        '\u{200B}' => "Zero Width Space",
+        '\u{200C}' => "Zero Width Non-Joiner",
+        '\u{200D}' => "Zero Width Joiner",
+        '\u{200E}' => "Left-to-Right Mark",
+        '\u{200F}' => "Right-to-Left Mark",
+        '\u{202A}' => "Left-to-Right Embedding",
+        '\u{202B}' => "Right-to-Left Embedding",
+        '\u{202C}' => "Pop Directional Formatting",
+        '\u{202D}' => "Left-to-Right Override",
+        '\u{202E}' => "Right-to-Left Override",
+        '\u{2060}' => "Word Joiner",
+        '\u{2061}' => "Function Application",
+        '\u{2062}' => "Invisible Times",
+        '\u{2063}' => "Invisible Separator",
+        '\u{2064}' => "Invisible Plus",
+        '\u{2066}' => "Left-to-Right Isolate",
+        '\u{2067}' => "Right-to-Left Isolate",
+        '\u{2068}' => "First Strong Isolate",
+        '\u{2069}' => "Pop Directional Isolate",
+        '\u{3164}' => "Hangul Filler",
+        '\u{FEFF}' => "Zero Width No-Break Space (BOM)",
+        '\u{FFA0}' => "Halfwidth Hangul Filler",
+        '\u{1D159}' => "Musical Symbol Null Notehead",
+        '\u{1D173}' => "Musical Symbol Begin Beam",
+        '\u{1D174}' => "Musical Symbol End Beam",
+        '\u{1D175}' => "Musical Symbol Begin Tie",
+        '\u{1D176}' => "Musical Symbol End Tie",
+        '\u{1D177}' => "Musical Symbol Begin Slur",
+        '\u{1D178}' => "Musical Symbol End Slur",
+        '\u{1D179}' => "Musical Symbol Begin Phrase",
+        '\u{1D17A}' => "Musical Symbol End Phrase",
+        '\u{0149}' => "Latin Small Letter N Preceded by Apostrophe",
+        '\u{0673}' => "Arabic Letter Alef with Wavy Hamza Below",
+        '\u{0F77}' => "Tibetan Vowel Sign Vocalic RR",
+        '\u{0F79}' => "Tibetan Vowel Sign Vocalic LL",
+        '\u{17A3}' => "Khmer Independent Vowel QAQ",
+        '\u{17A4}' => "Khmer Independent Vowel QAA",
+        '\u{206A}' => "Inhibit Symmetric Swapping",
+        '\u{206B}' => "Activate Symmetric Swapping",
+        '\u{206C}' => "Inhibit Arabic Form Shaping",
+        '\u{206D}' => "Activate Arabic Form Shaping",
+        '\u{206E}' => "National Digit Shapes",
+        '\u{206F}' => "Nominal Digit Shapes",
+        '\u{2329}' => "Left-Pointing Angle Bracket",
+        '\u{232A}' => "Right-Pointing Angle Bracket",
+        '\u{E0001}' => "Language Tag",
+        '\u{E0020}'..='\u{E007E}' => "Tag Character",
+        '\u{E007F}' => "Cancel Tag",
+        _ if is_noncharacter(ch) => "Noncharacter",
+        _ if is_private_use_char(ch) => "Private Use Character",
+        _ if general_category(ch) == GeneralCategory::Unassigned => "Unassigned Code Point",
+        _ => "Unknown Special Character",
+    }
+}
+
+/// Calculate the overall risk level based on analysis results
+fn calculate_risk_level(analysis: &SecurityAnalysis) -> RiskLevel {
+    risk_level_from_counts(|kind| match kind {
+        FindingKind::InvisibleCharacter => analysis.invisible_chars.len(),
+        FindingKind::BidiOverride => analysis.bidi_chars.len(),
+        FindingKind::UnterminatedBidi => analysis.unterminated_bidi.len(),
+        FindingKind::MixedScripts => usize::from(analysis.has_mixed_scripts),
+        FindingKind::Confusable => usize::from(analysis.has_confusables),
+        FindingKind::PrivateUse => analysis.private_use_chars.len(),
+        FindingKind::Noncharacter => analysis.noncharacters.len(),
+        FindingKind::Unassigned => analysis.unassigned_chars.len(),
+        FindingKind::Deprecated => analysis.deprecated_chars.len(),
+    })
+}
+
+/// Risk level given the number of findings of each kind
+pub(crate) fn risk_level_from_counts(count: impl Fn(FindingKind) -> usize) -> RiskLevel {
+    let mut score: u32 = FindingKind::ALL
+        .iter()
+        .filter(|&&kind| count(kind) > 0)
+        .map(|kind| match kind {
+            // A single invisible character is High risk on its own
+            FindingKind::BidiOverride | FindingKind::InvisibleCharacter => 4,
+            FindingKind::UnterminatedBidi | FindingKind::Noncharacter => 3,
+            FindingKind::MixedScripts | FindingKind::Confusable | FindingKind::Unassigned => 2,
+            FindingKind::PrivateUse | FindingKind::Deprecated => 1,
+        })
+        .sum();
+
+    // Additional scoring based on quantity
+    if count(FindingKind::InvisibleCharacter) > 3 {
+        score += 2;
+    }
+    if count(FindingKind::BidiOverride) > 1 {
+        score += 2;
+    }
+
+    match score {
+        0 => RiskLevel::Low,
+        1..=3 => RiskLevel::Medium,
+        4..=6 => RiskLevel::High,
+        _ => RiskLevel::Critical,
+    }
+}
+
+/// Logical and displayed forms of every line containing bidi characters
+fn bidi_preview(text: &str) -> String {
+    let rows: Vec<(usize, String, String)> = bidi_paragraphs(text)
+        .iter()
+        .enumerate()
+        .filter(|(_, paragraph)| paragraph.text.chars().any(is_bidi_char))
+        .map(|(line, paragraph)| {
+            let logical = paragraph
+                .text
+                .trim_end_matches(['\r', '\n'])
+                .chars()
+                .map(|ch| match bidi_control_abbreviation(ch) {
+                    Some(name) => format!("⟨{}⟩", name),
+                    None => ch.to_string(),
+                })
+                .collect();
+            let displayed = paragraph.visual().trim_end_matches(['\r', '\n']).to_string();
+            (line + 1, logical, displayed)
+        })
+        .collect();
+
+    let width = rows
+        .iter()
+        .map(|(_, logical, _)| logical.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut preview = String::new();
+    for (line, logical, displayed) in rows {
+        let padding = " ".repeat(width - logical.chars().count());
+        preview.push_str(&format!(
+            "  Line {}: {}{} │ {}\n",
+            line, logical, padding, displayed
+        ));
+    }
+    preview
+}
+
+/// Short name of a bidirectional control character, e.g. `RLO`
+fn bidi_control_abbreviation(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        '\u{061C}' => "ALM",
+        '\u{200E}' => "LRM",
+        '\u{200F}' => "RLM",
+        '\u{202A}' => "LRE",
+        '\u{202B}' => "RLE",
+        '\u{202C}' => "PDF",
+        '\u{202D}' => "LRO",
+        '\u{202E}' => "RLO",
+        '\u{2066}' => "LRI",
+        '\u{2067}' => "RLI",
+        '\u{2068}' => "FSI",
+        '\u{2069}' => "PDI",
+        _ => return None,
+    })
+}
+
+/// Sanitize text by removing dangerous Unicode characters
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// let dangerous = "Hello\u{200B}World\u{202E}";
+/// let safe = sanitize_text(dangerous);
+/// assert_eq!(safe, "HelloWorld");
+/// ```
+pub fn sanitize_text(text: &str) -> String {
+    let strip = |part: &str| {
+        part.chars()
+            .filter(|&ch| !is_invisible_char(ch) && !is_bidi_char(ch))
+            .collect::<String>()
+    };
+
+    if text.is_ascii() {
+        return text.to_string();
+    }
+
+    // Joiners and selectors inside well-formed emoji sequences are kept
+    let mut sanitized = String::with_capacity(text.len());
+    let mut pos = 0;
+    for range in emoji_sequences(text) {
+        sanitized.push_str(&strip(&text[pos..range.start]));
+        sanitized.push_str(&text[range.clone()]);
+        pos = range.end;
+    }
+    sanitized.push_str(&strip(&text[pos..]));
+    sanitized
+}
+
+/// Generate a security report for the given text
+///
+/// # Examples
+///
+/// ```rust
+/// use unicode_rs::security::*;
+///
+/// let report = generate_security_report("Hello\u{200B}World");
+/// println!("{}", report);
+/// ```
+pub fn generate_security_report(text: &str) -> String {
+    generate_security_report_with_config(text, &SecurityConfig::default())
+}
+
+/// Generate a security report for the given text using the given configuration
+pub fn generate_security_report_with_config(text: &str, config: &SecurityConfig) -> String {
+    let analysis = analyze_text_with_config(text, config);
+    let mut report = String::new();
+
+    report.push_str("Unicode Security Analysis\n");
+    report.push_str("========================\n\n");
+    report.push_str(&format!("Risk Level: {:?}\n\n", analysis.risk_level));
+
+    if analysis.has_invisible_chars {
+        report.push_str("⚠️  INVISIBLE CHARACTERS DETECTED:\n");
+        for (pos, ch, desc) in &analysis.invisible_chars {
+            report.push_str(&format!("  Position {}: U+{:04X} ({})\n", pos, *ch as u32, desc));
+        }
+        report.push('\n');
+    }
+
+    if analysis.has_bidi_overrides {
+        report.push_str("⚠️  BIDIRECTIONAL OVERRIDE CHARACTERS DETECTED:\n");
+        for (pos, ch, desc) in &analysis.bidi_chars {
+            report.push_str(&format!("  Position {}: U+{:04X} ({})\n", pos, *ch as u32, desc));
+        }
+        report.push('\n');
+    }
+
+    if analysis.has_unterminated_bidi {
+        report.push_str("⚠️  UNTERMINATED BIDIRECTIONAL CONTROLS DETECTED:\n");
+        for (pos, ch, desc) in &analysis.unterminated_bidi {
+            report.push_str(&format!("  Position {}: U+{:04X} ({})\n", pos, *ch as u32, desc));
+        }
+        report.push('\n');
+    }
+
+    if analysis.has_bidi_overrides {
+        report.push_str("🔀 BIDI PREVIEW (logical │ displayed):\n");
+        report.push_str(&bidi_preview(text));
+        report.push('\n');
+    }
+
+    if analysis.has_mixed_scripts {
+        report.push_str("⚠️  MIXED SCRIPTS DETECTED (Potential Homograph Attack):\n");
+        for script in &analysis.scripts {
+            report.push_str(&format!("  {:?}\n", script));
+        }
+        report.push('\n');
+    }
+
+    if analysis.has_confusables {
+        report.push_str("⚠️  CONFUSABLE CHARACTERS DETECTED\n\n");
+    }
+
+    let unassigned_title = format!("UNASSIGNED CODE POINTS (Unicode {})", config.unicode_version);
+    let classes = [
+        ("NONCHARACTERS", &analysis.noncharacters),
+        ("PRIVATE-USE CHARACTERS", &analysis.private_use_chars),
+        (unassigned_title.as_str(), &analysis.unassigned_chars),
+        ("DEPRECATED CHARACTERS", &analysis.deprecated_chars),
+    ];
+    for (title, chars) in classes {
+        if chars.is_empty() {
+            continue;
+        }
+        report.push_str(&format!("⚠️  {} DETECTED:\n", title));
+        for (pos, ch, desc) in chars {
+            report.push_str(&format!("  Position {}: U+{:04X} ({})\n", pos, *ch as u32, desc));
+        }
+        report.push('\n');
+    }
+
+    if analysis.risk_level == RiskLevel::Low {
+        report.push_str("✅ No security concerns detected.\n");
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_text() {
+        let analysis = analyze_text("Hello World");
+        assert_eq!(analysis.risk_level, RiskLevel::Low);
+        assert!(!analysis.has_invisible_chars);
+        assert!(!analysis.has_bidi_overrides);
+        assert!(!analysis.has_confusables);
+    }
+
+    #[test]
+    fn test_invisible_characters() {
+        let text = "Hello\u{200B}World";
+        let analysis = analyze_text(text);
+        assert!(analysis.has_invisible_chars);
+        assert_eq!(analysis.invisible_chars.len(), 1);
+        assert_eq!(analysis.invisible_chars[0].1, '\u{200B}');
+        assert!(analysis.risk_level >= RiskLevel::High);
+    }
+
+    #[test]
+    fn test_bidi_override() {
+        let text = "filename\u{202E}gpj.exe";
+        let analysis = analyze_text(text);
+        assert!(analysis.has_bidi_overrides);
+        assert_eq!(analysis.bidi_chars.len(), 1);
+        assert_eq!(analysis.bidi_chars[0].1, '\u{202E}');
+        assert_eq!(analysis.risk_level, RiskLevel::Critical);
+    }
+
+    #[test]
+    fn test_unterminated_bidi() {
+        let analysis = analyze_text("a\u{2067}b\u{2069} \u{202E}open");
+        assert!(analysis.has_unterminated_bidi);
+        assert_eq!(analysis.unterminated_bidi.len(), 1);
+        assert_eq!(analysis.unterminated_bidi[0].1, '\u{202E}');
+        assert!(!analyze_text("\u{202E}closed\u{202C}").has_unterminated_bidi);
+
+        let report = generate_security_report("x = \"\u{202E}nimda\u{202C}\";");
+        assert!(report.contains("Line 1: x = \"⟨RLO⟩nimda⟨PDF⟩\"; │ x = \"admin\";"));
+    }
+
+    #[test]
+    fn test_emoji_sequences_are_benign() {
+        for text in [
+            "👨\u{200D}👩\u{200D}👧",
+            "hi 👍🏽 1\u{FE0F}\u{20E3} 🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+            // Minimally-qualified ZWJ sequences, without U+FE0F
+            "pride 🏳\u{200D}🌈 and ❤\u{200D}🔥",
+        ] {
+            let analysis = analyze_text(text);
+            assert!(!analysis.has_invisible_chars, "{:?}", text);
+            assert!(!analysis.has_mixed_scripts, "{:?}", text);
+            assert_eq!(analysis.risk_level, RiskLevel::Low);
+            assert_eq!(sanitize_text(text), text);
+        }
+
+        // Stray and dangling joiners are still reported
+        let analysis = analyze_text("a\u{200D}b 👨\u{200D}");
+        assert_eq!(analysis.invisible_chars.len(), 2);
+        assert_eq!(sanitize_text("a\u{200D}b 👨\u{200D}"), "ab 👨");
+
+        // Joiners between pictographs outside RGI sequences are reported
+        for (text, sanitized) in [
+            ("admin©\u{200D}®", "admin©®"),
+            ("x‼\u{200D}™y", "x‼™y"),
+            ("\u{1FC00}\u{200D}\u{1FC01}", "\u{1FC00}\u{1FC01}"),
+            ("🏴\u{E0078}\u{E0079}\u{E007F}", "🏴"),
+        ] {
+            let analysis = analyze_text(text);
+            assert!(analysis.has_invisible_chars, "{:?}", text);
+            assert_ne!(analysis.risk_level, RiskLevel::Low, "{:?}", text);
+            assert_eq!(sanitize_text(text), sanitized);
+        }
+    }
+
+    #[test]
+    fn test_ascii_fast_path() {
+        assert_eq!(ascii_run_len(b""), 0);
+        assert_eq!(ascii_run_len(b"plain ascii text"), 16);
+        assert_eq!(ascii_run_len("0123456789abcdef\u{200B}".as_bytes()), 16);
+        assert_eq!(ascii_run_len("abc\u{e9}".as_bytes()), 3);
+
+        // ASCII outside the Latin set is Common
+        let analysis = analyze_text("if a < b { return; }\x0C");
+        let common = Script::Other(u32::from_be_bytes(*b"Zyyy"));
+        assert!(analysis.scripts.contains(&common));
+        assert!(!analysis.has_mixed_scripts);
+
+        // Findings after long ASCII runs keep their offsets
+        let text = format!("{}\u{200B}", "x".repeat(100));
+        assert_eq!(analyze_text(&text).invisible_chars[0].0, 100);
+
+        // The ASCII character before a non-ASCII one may start a keycap
+        let analysis = analyze_text("press 1\u{FE0F}\u{20E3} then #\u{20E3}");
+        assert!(!analysis.has_mixed_scripts);
+        assert_eq!(analysis.risk_level, RiskLevel::Low);
+    }
+
+    #[test]
+    fn test_mixed_scripts() {
+        let text = "раураӏ.com"; // Cyrillic that looks like "paypal.com"
+        let analysis = analyze_text(text);
+        assert!(analysis.has_mixed_scripts);
+        assert!(analysis.scripts.len() > 1);
+        assert!(analysis.risk_level >= RiskLevel::High);
+
+        // Punctuation, Latin-1 letters and marks do not mix scripts
+        for text in [
+            "fn f(x: u8) -> char { x < 1 }",
+            "caf\u{e9} na\u{ef}ve \u{2014} \u{201C}ok\u{201D}",
+            "\u{05E9}\u{05DC}\u{05D5}\u{05DD}\u{200F} \u{05E2}\u{05D5}\u{05DC}\u{05DD}!",
+        ] {
+            assert!(!analyze_text(text).has_mixed_scripts, "{}", text);
+        }
+        // Scripts without a variant of their own still mix with Latin
+        assert!(analyze_text("g\u{0585}\u{0585}gle").has_mixed_scripts);
+    }
+
+    #[test]
+    fn test_sanitization() {
+        let dangerous = "Hello\u{200B}World\u{202E}Test";
+        let sanitized = sanitize_text(dangerous);
+        assert_eq!(sanitized, "HelloWorldTest");
+
+        let analysis = analyze_text(&sanitized);
+        assert_eq!(analysis.risk_level, RiskLevel::Low);
+    }
+
+    #[test]
+    fn test_character_detection() {
+        assert!(is_invisible_char('\u{200B}')); // Zero width space
+        assert!(is_invisible_char('\u{FEFF}')); // BOM
+        assert!(!is_invisible_char('a'));
+
+        assert!(is_bidi_char('\u{202E}')); // Right-to-left override
+        assert!(is_bidi_char('\u{200F}')); // Right-to-left mark
+        assert!(!is_bidi_char('a'));
+
+        assert!(is_confusable_char('а')); // Cyrillic 'a'
+        assert!(is_confusable_char('α')); // Greek alpha
+        assert!(!is_confusable_char('a')); // Latin 'a'
+    }
+
+    #[test]
+    fn test_script_detection() {
+        assert_eq!(get_script('a'), Script::Latin);
+        assert_eq!(get_script('А'), Script::Cyrillic);
+        assert_eq!(get_script('α'), Script::Greek);
+        assert_eq!(get_script('世'), Script::Chinese);
+        assert_eq!(get_script('é'), Script::Latin);
+        assert_eq!(get_script('ｶ'), Script::Japanese);
+        let armenian = get_script('\u{0585}');
+        assert_eq!(armenian, Script::Other(u32::from_be_bytes(*b"Armn")));
+        assert_eq!(armenian.name(), "Armenian");
+        assert_eq!(get_script('\u{10FFFD}').name(), "Unknown");
+    }
+
+    #[test]
+    fn test_risk_calculation() {
+        // Low risk
+        let safe = analyze_text("Hello World");
+        assert_eq!(safe.risk_level, RiskLevel::Low);
+
+        // High risk - invisible chars
+        let invisible = analyze_text("Hello\u{200B}World");
+        assert!(invisible.risk_level >= RiskLevel::High);
+
+        // Critical risk - bidi override
+        let bidi = analyze_text("test\u{202E}evil");
+        assert_eq!(bidi.risk_level, RiskLevel::Critical);
+    }
+
+    #[test]
+    fn test_security_report() {
+        let text = "Hello\u{200B}World";
+        let report = generate_security_report(text);
+        assert!(report.contains("INVISIBLE CHARACTERS DETECTED"));
+        assert!(report.contains("U+200B"));
+        assert!(report.contains("Zero Width Space"));
+    }
+
+    #[test]
+    fn test_general_category() {
+        assert_eq!(general_category('A'), GeneralCategory::UppercaseLetter);
+        assert_eq!(general_category('a'), GeneralCategory::LowercaseLetter);
+        assert_eq!(general_category('\u{200B}'), GeneralCategory::Format);
+        assert_eq!(general_category('\u{E000}'), GeneralCategory::PrivateUse);
+        assert_eq!(general_category('\u{4E00}'), GeneralCategory::OtherLetter);
+        assert_eq!(general_category('\u{0378}'), GeneralCategory::Unassigned);
+        assert_eq!(general_category('\u{FFFF}'), GeneralCategory::Unassigned);
+    }
+
+    #[test]
+    fn test_codepoint_classes() {
+        let latest = UnicodeVersion::LATEST;
+        assert_eq!(classify_codepoint('a', latest), None);
+        assert_eq!(classify_codepoint('\u{F8FF}', latest), Some(CodepointClass::PrivateUse));
+        assert_eq!(classify_codepoint('\u{10FFFD}', latest), Some(CodepointClass::PrivateUse));
+        assert_eq!(classify_codepoint('\u{FDD0}', latest), Some(CodepointClass::Noncharacter));
+        assert_eq!(classify_codepoint('\u{10FFFF}', latest), Some(CodepointClass::Noncharacter));
+        assert_eq!(classify_codepoint('\u{0378}', latest), Some(CodepointClass::Unassigned));
+        assert_eq!(classify_codepoint('\u{0149}', latest), Some(CodepointClass::Deprecated));
+    }
+
+    #[test]
+    fn test_unicode_version_setting() {
+        assert_eq!(char_age('A'), Some(UnicodeVersion::new(1, 1, 0)));
+        assert_eq!(char_age('\u{0378}'), None);
+
+        // U+1FAE9 was added in 16.0, U+1FAE8 in 15.0
+        let text = "\u{1FAE8}\u{1FAE9}";
+        let config = SecurityConfig::with_unicode_version(UnicodeVersion::new(15, 0, 0));
+        let old = analyze_text_with_config(text, &config);
+        assert_eq!(old.unassigned_chars.len(), 1);
+        assert_eq!(old.unassigned_chars[0].1, '\u{1FAE9}');
+
+        let current = analyze_text(text);
+        assert!(!current.has_unassigned);
+    }
+
+    #[test]
+    fn test_codepoint_class_findings() {
+        let analysis = analyze_text("id\u{E000}\u{FFFE}\u{0149}");
+        assert!(analysis.has_private_use);
+        assert!(analysis.has_noncharacters);
+        assert!(analysis.has_deprecated);
+        assert_eq!(analysis.noncharacters[0], (5, '\u{FFFE}', "Noncharacter"));
+        assert!(analysis.risk_level >= RiskLevel::High);
+
+        let report = generate_security_report("id\u{E000}");
+        assert!(report.contains("PRIVATE-USE CHARACTERS DETECTED"));
+        assert!(report.contains("U+E000"));
+    }
+
+    #[test]
+    fn test_flagged_chars_have_descriptions() {
+        for cp in 0..=0x10FFFF {
+            let Some(ch) = char::from_u32(cp) else { continue };
+            if is_invisible_char(ch) || is_bidi_char(ch) || is_deprecated_char(ch) {
+                assert_ne!(get_char_description(ch), "Unknown Special Character", "U+{:04X}", cp);
+            }
+        }
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_descriptions_match_names() {
+        for ch in ['\u{034F}', '\u{2064}', '\u{2068}', '\u{1D173}', '\u{E0001}'] {
+            let name = char_name(ch).unwrap();
+            assert!(name.eq_ignore_ascii_case(get_char_description(ch)), "{}", name);
+        }
+        assert_eq!(get_char_description('\u{200B}'), "Zero Width Space");
+        assert_eq!(char_name('\u{200B}').as_deref(), Some("ZERO WIDTH SPACE"));
+    }
+}
@@ -0,0 +1,170 @@
+//! Security analysis of unified diffs
+//!
+//! Only lines added by a diff are analyzed, so a change is judged by the
+//! characters it introduces rather than by existing content. Findings are
+//! reported against the new file's path and line numbers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let diff = "\
+//! --- a/src/auth.rs
+//! +++ b/src/auth.rs
+//! @@ -7,2 +7,2 @@
+//!  fn check(user: &str) -> bool {
+//! -    user == \"admin\u{200B}\"
+//! +    user == \"admin\u{202E}\"
+//! ";
+//! let reports = scan_diff(diff);
+//! assert_eq!(reports[0].path, "src/auth.rs");
+//! // The removed zero width space is not reported, the added override is
+//! let findings = &reports[0].findings;
+//! assert!(findings.iter().any(|f| f.kind == FindingKind::BidiOverride));
+//! assert!(findings.iter().all(|f| f.line == 8));
+//! ```
+
+use crate::unicode::git::{parse_unified_diff, DiffFile};
+
+use super::suppress::inline_suppressions;
+use super::{analyze_text_with_config, FileReport, SecurityConfig};
+
+/// Analyze the lines added by a unified diff
+///
+/// Returns one report per file that exists after the change, in diff order.
+pub fn scan_diff(diff: &str) -> Vec<FileReport> {
+    scan_diff_with_config(diff, &SecurityConfig::default())
+}
+
+/// Analyze the lines added by a unified diff using the given configuration
+pub fn scan_diff_with_config(diff: &str, config: &SecurityConfig) -> Vec<FileReport> {
+    parse_unified_diff(diff)
+        .iter()
+        .filter_map(|file| FileReport::from_diff_file(file, config))
+        .collect()
+}
+
+impl FileReport {
+    /// Analyze the lines added to one file of a diff
+    ///
+    /// Finding lines are new-file line numbers and offsets are byte offsets
+    /// within that line. Returns `None` for deleted files.
+    pub fn from_diff_file(file: &DiffFile, config: &SecurityConfig) -> Option<Self> {
+        let path = file.new_path.as_deref()?;
+        let added: Vec<_> = file.added_lines().collect();
+
+        // Added lines are analyzed together so that mixed-script detection
+        // sees the whole change, then mapped back to their own lines
+        let text = added
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut line_starts = Vec::with_capacity(added.len());
+        let mut start = 0;
+        for line in &added {
+            line_starts.push(start);
+            start += line.text.len() + 1;
+        }
+
+        let analysis = analyze_text_with_config(&text, config);
+        let mut report = FileReport::without_suppressions(path, &text, &analysis);
+        for finding in &mut report.findings {
+            let index = finding.line - 1;
+            finding.offset -= line_starts[index];
+            finding.line = added[index].new_line.unwrap_or(finding.line);
+        }
+
+        // Comments on context lines count too, and `allow-next-line` refers
+        // to the next line of the new file
+        let suppressions = file
+            .lines
+            .iter()
+            .filter_map(|line| Some((line.new_line?, line)))
+            .flat_map(|(new_line, line)| {
+                inline_suppressions(&line.text)
+                    .into_iter()
+                    .map(move |(target, kinds, reason)| (new_line + target - 1, kinds, reason))
+            })
+            .collect();
+        report.suppress_inline(suppressions);
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::security::FindingKind;
+
+    #[test]
+    fn test_only_added_lines() {
+        let diff = "\
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1,3 +1,3 @@
+ keep\u{200B}
+-old\u{202E}
++new
+@@ -40,1 +40,2 @@
+ x
++cafe \u{200D}ok
+diff --git a/b.txt b/b.txt
+deleted file mode 100644
+--- a/b.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-\u{202E}
+";
+        let reports = scan_diff(diff);
+        assert_eq!(reports.len(), 1);
+        let findings: Vec<_> = reports[0]
+            .findings
+            .iter()
+            .map(|f| (f.kind, f.line, f.column, f.offset))
+            .collect();
+        assert_eq!(findings, vec![(FindingKind::InvisibleCharacter, 41, 6, 5)]);
+    }
+
+    #[test]
+    fn test_inline_suppressions_use_new_file_lines() {
+        let diff = "\
+--- a/a.rs
++++ b/a.rs
+@@ -10,4 +10,6 @@
+ // unicode-rs: allow-next-line invisible generated
++let a = \"\u{200B}\";
+ x();
++// unicode-rs: allow-next-line invisible
+ y();
++let b = \"\u{200B}\";
+ let c = \"\u{200B}\"; // unicode-rs: allow invisible
+";
+        let reports = scan_diff(diff);
+        let findings: Vec<_> = reports[0]
+            .findings
+            .iter()
+            .map(|f| (f.line, f.suppression.as_ref().map(|s| s.reason.as_str())))
+            .collect();
+        // The comment on the context line covers line 11; the one on line 13
+        // covers line 14, a context line, not the next added line 15
+        assert_eq!(findings, vec![(11, Some("generated")), (15, None)]);
+    }
+
+    #[test]
+    fn test_clean_and_new_files() {
+        let diff = "\
+--- /dev/null
++++ b/new.rs
+@@ -0,0 +1,2 @@
++fn main() {}
++
+";
+        let reports = scan_diff(diff);
+        assert_eq!(reports[0].path, "new.rs");
+        assert!(reports[0].findings.is_empty());
+        assert!(scan_diff("").is_empty());
+    }
+}
@@ -0,0 +1,746 @@
+//! Machine-readable security reports
+//!
+//! [`generate_security_report`](super::generate_security_report) is meant for
+//! humans. The renderers here turn analysis results into formats tools
+//! understand:
+//!
+//! - [`render_compact`] - one `file:line:col: level: message` line per finding,
+//!   as understood by editors and grep-style tools
+//! - `render_sarif` - SARIF 2.1.0 for code scanning dashboards (requires the
+//!   `serde` feature)
+//! - `render_json` - JSON (requires the `serde` feature)
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let report = FileReport::new("src/main.rs", "let a\u{200B} = 1;", &SecurityConfig::default());
+//! assert_eq!(report.findings[0].kind, FindingKind::InvisibleCharacter);
+//! assert_eq!(
+//!     render_compact(&[report]),
+//!     "src/main.rs:1:6: error: invisible character U+200B (Zero Width Space) [invisible-character]\n"
+//! );
+//! ```
+
+use std::collections::HashSet;
+
+use super::suppress::{allows, inline_suppressions};
+use super::{
+    analyze_text_with_config, confusable_prototype, emoji_sequences, get_char_description,
+    get_script, is_confusable_char, is_mixing_script, risk_level_from_counts, RiskLevel, Script,
+    SecurityAnalysis, SecurityConfig, Suppression, SuppressionSource,
+};
+
+/// Category of a security finding
+///
+/// Serializes as its [`rule_id`](Self::rule_id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum FindingKind {
+    /// Invisible or zero-width character
+    InvisibleCharacter,
+    /// Bidirectional control character
+    BidiOverride,
+    /// Embedding, override or isolate left open at the end of a paragraph
+    UnterminatedBidi,
+    /// Character from a different script than the surrounding text
+    MixedScripts,
+    /// Character commonly confused with another
+    Confusable,
+    /// Private-use character
+    PrivateUse,
+    /// Noncharacter code point
+    Noncharacter,
+    /// Code point unassigned in the configured Unicode version
+    Unassigned,
+    /// Deprecated character
+    Deprecated,
+}
+
+impl FindingKind {
+    /// All finding kinds, in rule order
+    pub const ALL: [FindingKind; 9] = [
+        FindingKind::InvisibleCharacter,
+        FindingKind::BidiOverride,
+        FindingKind::UnterminatedBidi,
+        FindingKind::MixedScripts,
+        FindingKind::Confusable,
+        FindingKind::PrivateUse,
+        FindingKind::Noncharacter,
+        FindingKind::Unassigned,
+        FindingKind::Deprecated,
+    ];
+
+    /// Stable rule identifier, e.g. `invisible-character`
+    pub fn rule_id(self) -> &'static str {
+        match self {
+            FindingKind::InvisibleCharacter => "invisible-character",
+            FindingKind::BidiOverride => "bidi-override",
+            FindingKind::UnterminatedBidi => "unterminated-bidi",
+            FindingKind::MixedScripts => "mixed-scripts",
+            FindingKind::Confusable => "confusable",
+            FindingKind::PrivateUse => "private-use",
+            FindingKind::Noncharacter => "noncharacter",
+            FindingKind::Unassigned => "unassigned",
+            FindingKind::Deprecated => "deprecated",
+        }
+    }
+
+    /// Look up a finding kind by its [`rule_id`](Self::rule_id)
+    pub fn from_rule_id(rule_id: &str) -> Option<Self> {
+        FindingKind::ALL
+            .into_iter()
+            .find(|kind| kind.rule_id() == rule_id)
+    }
+
+    /// Short human-readable description of the rule
+    pub fn description(self) -> &'static str {
+        match self {
+            FindingKind::InvisibleCharacter => "invisible character",
+            FindingKind::BidiOverride => "bidirectional control character",
+            FindingKind::UnterminatedBidi => "unterminated bidirectional control",
+            FindingKind::MixedScripts => "mixed scripts",
+            FindingKind::Confusable => "confusable character",
+            FindingKind::PrivateUse => "private-use character",
+            FindingKind::Noncharacter => "noncharacter",
+            FindingKind::Unassigned => "unassigned code point",
+            FindingKind::Deprecated => "deprecated character",
+        }
+    }
+
+    /// Risk level of a single finding of this kind
+    ///
+    /// Mixed scripts are common in multilingual text, so on their own they
+    /// rank with confusables rather than with hidden characters.
+    pub fn risk_level(self) -> RiskLevel {
+        match self {
+            FindingKind::BidiOverride | FindingKind::UnterminatedBidi => RiskLevel::Critical,
+            FindingKind::InvisibleCharacter | FindingKind::Noncharacter => RiskLevel::High,
+            FindingKind::MixedScripts | FindingKind::Confusable | FindingKind::Unassigned => {
+                RiskLevel::Medium
+            }
+            FindingKind::PrivateUse | FindingKind::Deprecated => RiskLevel::Low,
+        }
+    }
+
+    /// Severity name used by compiler-style and SARIF output
+    pub fn level(self) -> &'static str {
+        match self.risk_level() {
+            RiskLevel::Critical | RiskLevel::High => "error",
+            RiskLevel::Medium => "warning",
+            RiskLevel::Low => "note",
+        }
+    }
+}
+
+/// A single finding with its location in the analyzed text
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Finding {
+    /// Finding category
+    pub kind: FindingKind,
+    /// Byte offset of the character
+    pub offset: usize,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending character
+    pub ch: char,
+    /// Human-readable message
+    pub message: String,
+    /// Why the finding is accepted, if it is suppressed
+    pub suppression: Option<Suppression>,
+}
+
+impl Finding {
+    /// Whether the finding is suppressed
+    pub fn is_suppressed(&self) -> bool {
+        self.suppression.is_some()
+    }
+}
+
+impl SecurityAnalysis {
+    /// List every finding of this analysis with line and column information
+    ///
+    /// `text` must be the text the analysis was computed from.
+    pub fn findings(&self, text: &str) -> Vec<Finding> {
+        let mut located: Vec<(FindingKind, usize, char, String)> = Vec::new();
+
+        let lists = [
+            (FindingKind::InvisibleCharacter, &self.invisible_chars),
+            (FindingKind::BidiOverride, &self.bidi_chars),
+            (FindingKind::UnterminatedBidi, &self.unterminated_bidi),
+            (FindingKind::PrivateUse, &self.private_use_chars),
+            (FindingKind::Noncharacter, &self.noncharacters),
+            (FindingKind::Unassigned, &self.unassigned_chars),
+            (FindingKind::Deprecated, &self.deprecated_chars),
+        ];
+        for (kind, chars) in lists {
+            for &(pos, ch, desc) in chars {
+                let message = format!("{} U+{:04X} ({})", kind.description(), ch as u32, desc);
+                located.push((kind, pos, ch, message));
+            }
+        }
+
+        // Point at the first non-Latin letter not already reported by another
+        // check. Only letters of a named script decide whether the text is
+        // mixed, and characters reported by other checks do not make it
+        // mixed on their own
+        if self.has_mixed_scripts {
+            let reported: HashSet<usize> = located.iter().map(|&(_, pos, _, _)| pos).collect();
+            let emoji = emoji_sequences(text);
+            let letters: Vec<_> = text
+                .char_indices()
+                .filter(|(pos, _)| !reported.contains(pos))
+                .filter(|(pos, _)| !emoji.iter().any(|range| range.contains(pos)))
+                .map(|(pos, ch)| (pos, ch, get_script(ch)))
+                .filter(|&(_, ch, script)| is_mixing_script(ch, script))
+                .collect();
+            let scripts: HashSet<Script> = letters.iter().map(|&(_, _, script)| script).collect();
+            let first = letters
+                .iter()
+                .find(|&&(_, _, script)| script != Script::Latin);
+            if let Some(&(pos, ch, script)) = first.filter(|_| scripts.len() > 1) {
+                let message = format!(
+                    "{} character U+{:04X} in mixed-script text",
+                    script.name(),
+                    ch as u32
+                );
+                located.push((FindingKind::MixedScripts, pos, ch, message));
+            }
+        }
+
+        if self.has_confusables {
+            for (pos, ch) in text
+                .char_indices()
+                .filter(|&(_, ch)| is_confusable_char(ch))
+            {
+                let message = match confusable_prototype(ch) {
+                    Some(prototype) => format!(
+                        "{} U+{:04X} looks like \"{}\"",
+                        FindingKind::Confusable.description(),
+                        ch as u32,
+                        prototype
+                    ),
+                    None => format!(
+                        "{} U+{:04X} ({})",
+                        FindingKind::Confusable.description(),
+                        ch as u32,
+                        get_char_description(ch)
+                    ),
+                };
+                located.push((FindingKind::Confusable, pos, ch, message));
+            }
+        }
+
+        located.sort_by_key(|&(kind, pos, _, _)| (pos, kind as u8));
+        let lines = LineIndex::new(text);
+        located
+            .into_iter()
+            .map(|(kind, offset, ch, message)| {
+                let (line, column) = lines.line_col(text, offset);
+                Finding {
+                    kind,
+                    offset,
+                    line,
+                    column,
+                    ch,
+                    message,
+                    suppression: None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Findings for one analyzed file
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FileReport {
+    /// Path of the file, as it should appear in the output
+    pub path: String,
+    /// Overall risk level of the file, ignoring suppressed findings
+    pub risk_level: RiskLevel,
+    /// Findings in order of appearance, including suppressed ones
+    pub findings: Vec<Finding>,
+}
+
+impl FileReport {
+    /// Analyze `text` and collect its findings
+    pub fn new(path: impl Into<String>, text: &str, config: &SecurityConfig) -> Self {
+        let analysis = analyze_text_with_config(text, config);
+        Self::from_analysis(path, text, &analysis)
+    }
+
+    /// Build a report from an existing analysis of `text`
+    ///
+    /// Findings on lines carrying a `unicode-rs: allow <rules>` comment, or
+    /// following a `unicode-rs: allow-next-line <rules>` comment, are
+    /// suppressed.
+    pub fn from_analysis(path: impl Into<String>, text: &str, analysis: &SecurityAnalysis) -> Self {
+        let mut report = Self::without_suppressions(path, text, analysis);
+        report.suppress_inline(inline_suppressions(text));
+        report
+    }
+
+    /// Build a report from an analysis, ignoring inline comments
+    pub(super) fn without_suppressions(
+        path: impl Into<String>,
+        text: &str,
+        analysis: &SecurityAnalysis,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            risk_level: analysis.risk_level,
+            findings: analysis.findings(text),
+        }
+    }
+
+    /// Suppress findings by `(line, rules, reason)` inline comments
+    pub(super) fn suppress_inline(&mut self, suppressions: Vec<(usize, Vec<FindingKind>, String)>) {
+        for (line, kinds, reason) in suppressions {
+            let suppression = Suppression {
+                source: SuppressionSource::Inline,
+                reason,
+            };
+            self.suppress(&suppression, |finding| {
+                finding.line == line && allows(&kinds, finding.kind, Some(finding.ch))
+            });
+        }
+    }
+
+    /// Findings that are not suppressed
+    pub fn active_findings(&self) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(|finding| !finding.is_suppressed())
+    }
+
+    /// Suppress the active findings matching `predicate`
+    ///
+    /// The risk level is recomputed from the findings left active. Returns the
+    /// number of findings suppressed.
+    pub fn suppress(
+        &mut self,
+        suppression: &Suppression,
+        mut predicate: impl FnMut(&Finding) -> bool,
+    ) -> usize {
+        let mut suppressed = 0;
+        for finding in &mut self.findings {
+            if !finding.is_suppressed() && predicate(finding) {
+                finding.suppression = Some(suppression.clone());
+                suppressed += 1;
+            }
+        }
+        if suppressed > 0 {
+            self.risk_level = risk_level_from_counts(|kind| {
+                self.active_findings()
+                    .filter(|finding| finding.kind == kind)
+                    .count()
+            });
+        }
+        suppressed
+    }
+}
+
+/// Render findings as `file:line:col: level: message [rule]` lines
+///
+/// Suppressed findings are rendered as notes followed by the reason.
+pub fn render_compact(reports: &[FileReport]) -> String {
+    let mut out = String::new();
+    for report in reports {
+        for finding in &report.findings {
+            let (level, suffix) = match &finding.suppression {
+                Some(suppression) => ("note", format!(" (suppressed: {})", suppression.reason)),
+                None => (finding.kind.level(), String::new()),
+            };
+            out.push_str(&format!(
+                "{}:{}:{}: {}: {} [{}]{}\n",
+                report.path,
+                finding.line,
+                finding.column,
+                level,
+                finding.message,
+                finding.kind.rule_id(),
+                suffix
+            ));
+        }
+    }
+    out
+}
+
+/// Render findings as a SARIF 2.1.0 log (requires the `serde` feature)
+///
+/// Every [`FindingKind`] is a rule; columns are counted in Unicode code points.
+/// Relative paths become percent-encoded URIs relative to `%SRCROOT%`, and
+/// absolute paths `file://` URIs. Suppressed findings carry a SARIF
+/// `suppressions` entry with the reason.
+#[cfg(feature = "serde")]
+pub fn render_sarif(reports: &[FileReport]) -> String {
+    use sarif::*;
+
+    let rules = FindingKind::ALL
+        .iter()
+        .map(|kind| Rule {
+            id: kind.rule_id(),
+            short_description: Message {
+                text: kind.description(),
+            },
+            default_configuration: Configuration {
+                level: kind.level(),
+            },
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for report in reports {
+        let (uri, uri_base_id) = artifact_uri(&report.path);
+        for finding in &report.findings {
+            let rule_index = FindingKind::ALL
+                .iter()
+                .position(|&k| k == finding.kind)
+                .unwrap_or(0);
+            let suppressions = finding
+                .suppression
+                .iter()
+                .map(|suppression| SuppressionEntry {
+                    kind: match suppression.source {
+                        SuppressionSource::Inline => "inSource",
+                        SuppressionSource::Allowlist | SuppressionSource::Baseline => "external",
+                    },
+                    justification: &suppression.reason,
+                })
+                .collect();
+            results.push(SarifResult {
+                rule_id: finding.kind.rule_id(),
+                rule_index,
+                level: finding.kind.level(),
+                message: Message {
+                    text: &finding.message,
+                },
+                suppressions,
+                locations: vec![Location {
+                    physical_location: PhysicalLocation {
+                        artifact_location: ArtifactLocation {
+                            uri: uri.clone(),
+                            uri_base_id,
+                        },
+                        region: Region {
+                            start_line: finding.line,
+                            start_column: finding.column,
+                            end_column: finding.column + 1,
+                        },
+                    },
+                }],
+            });
+        }
+    }
+
+    let log = Log {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "unicode-rs",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://github.com/entrepeneur4lyf/unicode-rs",
+                    rules,
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).unwrap_or_default() + "\n"
+}
+
+/// SARIF URI of a path, with its `uriBaseId` if relative
+///
+/// Backslashes become slashes, and everything but unreserved characters and
+/// `/` is percent-encoded, as RFC 8089 and SARIF §3.10 require.
+#[cfg(feature = "serde")]
+fn artifact_uri(path: &str) -> (String, Option<&'static str>) {
+    let path = path.replace('\\', "/");
+    let is_drive = |path: &str| {
+        let bytes = path.as_bytes();
+        bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+    };
+    if path.starts_with('/') {
+        (format!("file://{}", percent_encode(&path)), None)
+    } else if is_drive(&path) {
+        // Keep the colon of the drive letter, e.g. file:///C:/src/main.rs
+        let (drive, rest) = path.split_at(2);
+        (format!("file:///{}{}", drive, percent_encode(rest)), None)
+    } else {
+        let relative = path.trim_start_matches("./");
+        (percent_encode(relative), Some("%SRCROOT%"))
+    }
+}
+
+/// Percent-encode the UTF-8 bytes of a path, keeping unreserved characters and `/`
+#[cfg(feature = "serde")]
+fn percent_encode(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                out.push(char::from(byte))
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// SARIF 2.1.0 log objects, limited to the properties `render_sarif` emits
+#[cfg(feature = "serde")]
+mod sarif {
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    pub(super) struct Log<'a> {
+        #[serde(rename = "$schema")]
+        pub schema: &'static str,
+        pub version: &'static str,
+        pub runs: Vec<Run<'a>>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Run<'a> {
+        pub tool: Tool,
+        pub column_kind: &'static str,
+        pub results: Vec<SarifResult<'a>>,
+    }
+
+    #[derive(Serialize)]
+    pub(super) struct Tool {
+        pub driver: Driver,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Driver {
+        pub name: &'static str,
+        pub version: &'static str,
+        pub information_uri: &'static str,
+        pub rules: Vec<Rule>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Rule {
+        pub id: &'static str,
+        pub short_description: Message<'static>,
+        pub default_configuration: Configuration,
+    }
+
+    #[derive(Serialize)]
+    pub(super) struct Configuration {
+        pub level: &'static str,
+    }
+
+    #[derive(Serialize)]
+    pub(super) struct Message<'a> {
+        pub text: &'a str,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct SarifResult<'a> {
+        pub rule_id: &'static str,
+        pub rule_index: usize,
+        pub level: &'static str,
+        pub message: Message<'a>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub suppressions: Vec<SuppressionEntry<'a>>,
+        pub locations: Vec<Location>,
+    }
+
+    #[derive(Serialize)]
+    pub(super) struct SuppressionEntry<'a> {
+        pub kind: &'static str,
+        pub justification: &'a str,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Location {
+        pub physical_location: PhysicalLocation,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct PhysicalLocation {
+        pub artifact_location: ArtifactLocation,
+        pub region: Region,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct ArtifactLocation {
+        pub uri: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uri_base_id: Option<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Region {
+        pub start_line: usize,
+        pub start_column: usize,
+        pub end_column: usize,
+    }
+}
+
+/// Render reports as pretty-printed JSON
+#[cfg(feature = "serde")]
+pub fn render_json(reports: &[FileReport]) -> String {
+    serde_json::to_string_pretty(reports).unwrap_or_default() + "\n"
+}
+
+/// Byte offsets of line starts, for offset to line/column conversion
+struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(text: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { starts }
+    }
+
+    /// 1-based line and character column of a byte offset
+    fn line_col(&self, text: &str, offset: usize) -> (usize, usize) {
+        let line = self.starts.partition_point(|&start| start <= offset);
+        let start = self.starts[line - 1];
+        (line, text[start..offset].chars().count() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(text: &str) -> FileReport {
+        FileReport::new("input.txt", text, &SecurityConfig::default())
+    }
+
+    #[test]
+    fn test_finding_locations() {
+        let report = report("ok\nx = \"\u{202E}\"; // \u{0430}");
+        let kinds: Vec<_> = report
+            .findings
+            .iter()
+            .map(|f| (f.kind, f.line, f.column))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (FindingKind::InvisibleCharacter, 2, 6),
+                (FindingKind::BidiOverride, 2, 6),
+                (FindingKind::UnterminatedBidi, 2, 6),
+                (FindingKind::MixedScripts, 2, 13),
+                (FindingKind::Confusable, 2, 13),
+            ]
+        );
+        assert_eq!(report.risk_level, RiskLevel::Critical);
+    }
+
+    #[test]
+    fn test_mixed_scripts_finding() {
+        // Comparison operators and a Hebrew right-to-left mark mix nothing
+        for text in [
+            "let b: bool = x < y && z > 2;",
+            "\u{05E9}\u{05DC}\u{05D5}\u{05DD}\u{200F} \u{05E2}\u{05D5}\u{05DC}\u{05DD}",
+        ] {
+            let report = report(text);
+            let mixed = report
+                .findings
+                .iter()
+                .any(|f| f.kind == FindingKind::MixedScripts);
+            assert!(!mixed, "{}", text);
+        }
+
+        let report = report("g\u{0585}\u{0585}gle");
+        let finding = &report.findings[0];
+        assert_eq!(
+            (finding.kind, finding.column),
+            (FindingKind::MixedScripts, 2)
+        );
+        assert_eq!(
+            finding.message,
+            "Armenian character U+0585 in mixed-script text"
+        );
+    }
+
+    #[test]
+    fn test_compact_format() {
+        let out = render_compact(&[report("\u{E000}")]);
+        assert_eq!(
+            out,
+            "input.txt:1:1: note: private-use character U+E000 (Private Use Character) [private-use]\n"
+        );
+        assert_eq!(render_compact(&[report("clean")]), "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sarif_format() {
+        let sarif = render_sarif(&[report("tab\t\"\u{200B}\"")]);
+        let log: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "invisible-character");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["region"]["startLine"], 1);
+        assert_eq!(location["region"]["startColumn"], 6);
+        assert_eq!(location["artifactLocation"]["uri"], "input.txt");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        // Empty runs are still valid
+        let empty: serde_json::Value = serde_json::from_str(&render_sarif(&[])).unwrap();
+        assert_eq!(empty["runs"][0]["results"], serde_json::json!([]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sarif_uris() {
+        assert_eq!(
+            artifact_uri("src\\my dir\\main.rs"),
+            ("src/my%20dir/main.rs".to_string(), Some("%SRCROOT%"))
+        );
+        assert_eq!(
+            artifact_uri("./docs/café#1.md"),
+            ("docs/caf%C3%A9%231.md".to_string(), Some("%SRCROOT%"))
+        );
+        assert_eq!(
+            artifact_uri("/home/me/a b.rs"),
+            ("file:///home/me/a%20b.rs".to_string(), None)
+        );
+        assert_eq!(
+            artifact_uri("C:\\src\\main.rs"),
+            ("file:///C:/src/main.rs".to_string(), None)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_format() {
+        let json = render_json(&[report("x\u{200B}")]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["path"], "input.txt");
+        assert_eq!(value[0]["findings"][0]["kind"], "invisible-character");
+        assert_eq!(value[0]["findings"][0]["column"], 2);
+
+        // Every format names findings by their rule id
+        for kind in FindingKind::ALL {
+            let json = serde_json::to_value(kind).unwrap();
+            assert_eq!(json, kind.rule_id());
+        }
+    }
+}
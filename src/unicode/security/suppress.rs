@@ -0,0 +1,473 @@
+//! Suppressing accepted security findings
+//!
+//! Some files legitimately contain characters the analyzer flags, such as RLM
+//! marks in Hebrew UI strings. Findings can be accepted in three ways:
+//!
+//! - inline comments in the scanned text: `unicode-rs: allow <rules> [reason]`
+//!   suppresses findings on the same line, `unicode-rs: allow-next-line
+//!   <rules> [reason]` on the following line
+//! - an [`Allowlist`] of path patterns and the rules allowed in them
+//! - a [`Baseline`] recording the findings accepted so far, so that only new
+//!   ones fail
+//!
+//! `<rules>` is a comma separated list of rule ids such as `bidi-override`,
+//! or `bidi` (bidi overrides and unterminated controls), `invisible` and
+//! `all`. Bidi controls are also invisible, so allowing `bidi-override`
+//! covers their invisible-character findings too. Suppressed findings stay in
+//! the report with their reason but no longer count towards its risk level.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use unicode_rs::security::*;
+//!
+//! let text = "label = \"\u{200F}שלום\" // unicode-rs: allow bidi,mixed-scripts Hebrew UI string\n";
+//! let report = FileReport::new("ui.rs", text, &SecurityConfig::default());
+//! assert_eq!(report.active_findings().count(), 0);
+//! let suppression = report.findings[0].suppression.as_ref().unwrap();
+//! assert_eq!(suppression.reason, "Hebrew UI string");
+//!
+//! let allowlist = Allowlist::parse("locales/** mixed-scripts,confusable translations").unwrap();
+//! let mut report = FileReport::new("locales/ru.txt", "file = файл", &SecurityConfig::default());
+//! allowlist.apply(&mut report);
+//! assert_eq!(report.risk_level, RiskLevel::Low);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::{is_bidi_char, FileReport, Finding, FindingKind};
+use crate::unicode::glob::{glob_match, normalize_path};
+
+/// Marker that starts an inline suppression comment
+const INLINE_MARKER: &str = "unicode-rs: allow";
+
+/// Where a suppression comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SuppressionSource {
+    /// A `unicode-rs: allow` comment in the scanned text
+    Inline,
+    /// A rule of an [`Allowlist`]
+    Allowlist,
+    /// An entry of a [`Baseline`]
+    Baseline,
+}
+
+/// Why a finding is accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Suppression {
+    /// Where the suppression comes from
+    pub source: SuppressionSource,
+    /// Human-readable reason
+    pub reason: String,
+}
+
+/// A path pattern and the rules allowed in matching files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowRule {
+    /// Glob pattern, see [`Allowlist`]
+    pub pattern: String,
+    /// Finding kinds allowed in matching files
+    pub kinds: Vec<FindingKind>,
+    /// Reason recorded on suppressed findings
+    pub reason: String,
+}
+
+impl AllowRule {
+    /// Whether the rule allows `finding`, regardless of its path
+    pub fn allows(&self, finding: &Finding) -> bool {
+        allows(&self.kinds, finding.kind, Some(finding.ch))
+    }
+}
+
+/// Per-path allowlist of finding kinds
+///
+/// Patterns follow `.gitignore` conventions: `*` and `?` match within a path
+/// component, `**` matches any number of components, a pattern without `/`
+/// matches a file or directory name at any depth, and a pattern matching a
+/// directory covers everything below it.
+///
+/// The text format has one rule per line, `<pattern> <rules> [reason]`, with
+/// blank lines and lines starting with `#` ignored:
+///
+/// ```text
+/// # Hebrew UI strings use RLM marks
+/// locales/he/** bidi right-to-left UI strings
+/// src/transliterate.rs mixed-scripts,confusable
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Allowlist {
+    rules: Vec<AllowRule>,
+}
+
+impl Allowlist {
+    /// Create an empty allowlist
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse an allowlist file
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut allowlist = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(3, char::is_whitespace);
+            let pattern = parts.next().unwrap_or_default();
+            let rules = parts
+                .next()
+                .ok_or_else(|| format!("line {}: missing rules for '{}'", index + 1, pattern))?;
+            let kinds = parse_rules(rules).map_err(|err| format!("line {}: {}", index + 1, err))?;
+            let reason = parts.next().map(str::trim).unwrap_or_default();
+            allowlist = allowlist.with_rule(pattern, &kinds, reason);
+        }
+        Ok(allowlist)
+    }
+
+    /// Allow `kinds` in files matching `pattern`
+    ///
+    /// An empty reason is replaced by one naming the pattern.
+    pub fn with_rule(mut self, pattern: &str, kinds: &[FindingKind], reason: &str) -> Self {
+        let reason = if reason.is_empty() {
+            format!("allowed in {}", pattern)
+        } else {
+            reason.to_string()
+        };
+        self.rules.push(AllowRule {
+            pattern: pattern.to_string(),
+            kinds: kinds.to_vec(),
+            reason,
+        });
+        self
+    }
+
+    /// Rules in the order they were added
+    pub fn rules(&self) -> &[AllowRule] {
+        &self.rules
+    }
+
+    /// First rule allowing findings of `kind` in `path`
+    pub fn matching_rule(&self, path: &str, kind: FindingKind) -> Option<&AllowRule> {
+        let path = normalize_path(path);
+        self.rules
+            .iter()
+            .find(|rule| allows(&rule.kinds, kind, None) && glob_match(&rule.pattern, &path))
+    }
+
+    /// Suppress the findings of `report` allowed for its path
+    ///
+    /// Returns the number of findings suppressed.
+    pub fn apply(&self, report: &mut FileReport) -> usize {
+        let mut suppressed = 0;
+        for rule in &self.rules {
+            if !glob_match(&rule.pattern, &normalize_path(&report.path)) {
+                continue;
+            }
+            let suppression = Suppression {
+                source: SuppressionSource::Allowlist,
+                reason: rule.reason.clone(),
+            };
+            suppressed += report.suppress(&suppression, |finding| rule.allows(finding));
+        }
+        suppressed
+    }
+}
+
+/// Findings accepted at some point, so that only new ones are reported
+///
+/// Entries count findings per path, rule and character rather than by line,
+/// so unrelated edits that move lines do not invalidate the baseline. A
+/// finding is new once a file has more of that kind than recorded.
+///
+/// The text format written by [`Display`](fmt::Display) and read by
+/// [`Baseline::parse`] has one tab separated `path rule U+XXXX count` entry
+/// per line:
+///
+/// ```text
+/// # unicode-rs baseline
+/// locales/he.json  bidi-override  U+200F  12
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeMap<(String, &'static str, char), usize>,
+}
+
+impl Baseline {
+    /// Create an empty baseline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the active findings of `reports`
+    pub fn from_reports(reports: &[FileReport]) -> Self {
+        let mut baseline = Self::new();
+        for report in reports {
+            let path = normalize_path(&report.path);
+            for finding in report.active_findings() {
+                *baseline
+                    .entries
+                    .entry((path.clone(), finding.kind.rule_id(), finding.ch))
+                    .or_default() += 1;
+            }
+        }
+        baseline
+    }
+
+    /// Parse a baseline file
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut baseline = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = match line.split('\t').collect::<Vec<_>>()[..] {
+                [path, rule, ch, count] => parse_entry(path, rule, ch, count),
+                _ => None,
+            };
+            let (key, count) =
+                entry.ok_or_else(|| format!("line {}: invalid baseline entry", index + 1))?;
+            *baseline.entries.entry(key).or_default() += count;
+        }
+        Ok(baseline)
+    }
+
+    /// Number of accepted findings
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    /// Whether no findings are recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Suppress the findings of `report` recorded in the baseline
+    ///
+    /// Findings are matched in order of appearance. Returns the number of
+    /// findings suppressed.
+    pub fn apply(&self, report: &mut FileReport) -> usize {
+        let path = normalize_path(&report.path);
+        let mut remaining: HashMap<(&'static str, char), usize> = self
+            .entries
+            .iter()
+            .filter(|((entry_path, _, _), _)| *entry_path == path)
+            .map(|(&(_, rule, ch), &count)| ((rule, ch), count))
+            .collect();
+        if remaining.is_empty() {
+            return 0;
+        }
+
+        let suppression = Suppression {
+            source: SuppressionSource::Baseline,
+            reason: "recorded in baseline".to_string(),
+        };
+        report.suppress(&suppression, |finding| {
+            match remaining.get_mut(&(finding.kind.rule_id(), finding.ch)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# unicode-rs baseline")?;
+        for ((path, rule, ch), count) in &self.entries {
+            writeln!(f, "{}\t{}\tU+{:04X}\t{}", path, rule, *ch as u32, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Inline suppressions of `text` as (line, kinds, reason)
+pub(super) fn inline_suppressions(text: &str) -> Vec<(usize, Vec<FindingKind>, String)> {
+    let mut suppressions = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let Some(start) = line.find(INLINE_MARKER) else {
+            continue;
+        };
+        let rest = &line[start + INLINE_MARKER.len()..];
+        let (target, rest) = match rest.strip_prefix("-next-line") {
+            Some(rest) => (index + 2, rest),
+            None => (index + 1, rest),
+        };
+        if !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let mut parts = rest.trim_start().splitn(2, char::is_whitespace);
+        let Ok(kinds) = parse_rules(parts.next().unwrap_or_default()) else {
+            continue;
+        };
+        // Drop the end of block comments from the reason
+        let reason = parts
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches("*/")
+            .trim_end_matches("-->")
+            .trim();
+        let reason = if reason.is_empty() {
+            "allowed by inline comment".to_string()
+        } else {
+            reason.to_string()
+        };
+        suppressions.push((target, kinds, reason));
+    }
+    suppressions
+}
+
+/// Whether allowing `kinds` covers a finding of `kind` on `ch`
+pub(super) fn allows(kinds: &[FindingKind], kind: FindingKind, ch: Option<char>) -> bool {
+    kinds.contains(&kind)
+        || (kind == FindingKind::InvisibleCharacter
+            && ch.is_some_and(is_bidi_char)
+            && kinds.contains(&FindingKind::BidiOverride))
+}
+
+/// Parse a comma separated list of rule ids and aliases
+fn parse_rules(rules: &str) -> Result<Vec<FindingKind>, String> {
+    let mut kinds = Vec::new();
+    for rule in rules.split(',').map(str::trim) {
+        match rule {
+            "all" => kinds.extend(FindingKind::ALL),
+            "bidi" => kinds.extend([FindingKind::BidiOverride, FindingKind::UnterminatedBidi]),
+            "invisible" => kinds.push(FindingKind::InvisibleCharacter),
+            _ => kinds.push(
+                FindingKind::from_rule_id(rule)
+                    .ok_or_else(|| format!("unknown rule '{}'", rule))?,
+            ),
+        }
+    }
+    Ok(kinds)
+}
+
+fn parse_entry(
+    path: &str,
+    rule: &str,
+    ch: &str,
+    count: &str,
+) -> Option<((String, &'static str, char), usize)> {
+    let rule = FindingKind::from_rule_id(rule)?.rule_id();
+    let ch = char::from_u32(u32::from_str_radix(ch.strip_prefix("U+")?, 16).ok()?)?;
+    Some(((normalize_path(path), rule, ch), count.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::security::{RiskLevel, SecurityConfig};
+
+    fn report(path: &str, text: &str) -> FileReport {
+        FileReport::new(path, text, &SecurityConfig::default())
+    }
+
+    #[test]
+    fn test_inline_suppressions() {
+        let text = "\
+a\u{200B} // unicode-rs: allow invisible
+/* unicode-rs: allow-next-line bidi,confusable RTL label */
+b = \"\u{200F}\u{0430}\"
+c\u{200B}
+";
+        let report = report("x.rs", text);
+        let active: Vec<_> = report.active_findings().map(|f| (f.kind, f.line)).collect();
+        assert_eq!(
+            active,
+            vec![
+                (FindingKind::MixedScripts, 3),
+                (FindingKind::InvisibleCharacter, 4)
+            ]
+        );
+        let reasons: Vec<_> = report
+            .findings
+            .iter()
+            .filter_map(|f| f.suppression.as_ref().map(|s| s.reason.as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                "allowed by inline comment",
+                "RTL label",
+                "RTL label",
+                "RTL label"
+            ]
+        );
+
+        // Unknown rules and other text do not suppress anything
+        let report = self::report("x.rs", "a\u{200B} // unicode-rs: allow sometimes\n");
+        assert_eq!(report.active_findings().count(), 1);
+    }
+
+    #[test]
+    fn test_suppression_lowers_risk() {
+        let mut report = report("he.txt", "\u{200F}שלום");
+        assert!(report.risk_level >= RiskLevel::High);
+        let allowlist = Allowlist::new().with_rule("*.txt", &[FindingKind::BidiOverride], "");
+        // The RLM is reported as both a bidi control and an invisible character
+        assert_eq!(allowlist.apply(&mut report), 2);
+        assert_eq!(report.risk_level, RiskLevel::Low);
+        assert_eq!(
+            report.findings[0].suppression,
+            Some(Suppression {
+                source: SuppressionSource::Allowlist,
+                reason: "allowed in *.txt".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_allowlist_parse() {
+        let allowlist =
+            Allowlist::parse("# comment\n\nlocales/he/** bidi RTL strings\nvendor all\n").unwrap();
+        assert_eq!(allowlist.rules().len(), 2);
+        assert_eq!(allowlist.rules()[0].reason, "RTL strings");
+        assert!(allowlist
+            .matching_rule("./locales/he/ui.json", FindingKind::UnterminatedBidi)
+            .is_some());
+        assert!(allowlist
+            .matching_rule("locales/he/ui.json", FindingKind::InvisibleCharacter)
+            .is_none());
+        assert!(allowlist
+            .matching_rule("third_party/vendor/lib.c", FindingKind::Confusable)
+            .is_some());
+
+        assert!(Allowlist::parse("src/**").is_err());
+        assert!(Allowlist::parse("src/** bidi,typo").is_err());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let old = [
+            report("./a.txt", "x\u{200B}y\u{200B}"),
+            report("b.txt", "clean"),
+        ];
+        let baseline = Baseline::from_reports(&old);
+        assert_eq!(baseline.len(), 2);
+        let text = baseline.to_string();
+        assert_eq!(
+            text,
+            "# unicode-rs baseline\na.txt\tinvisible-character\tU+200B\t2\n"
+        );
+        assert_eq!(Baseline::parse(&text).unwrap(), baseline);
+        assert!(Baseline::parse("a.txt\tinvisible-character\t200B\t1").is_err());
+
+        // A third zero width space is new
+        let mut new = report("a.txt", "\u{200B}x\u{200B}y\u{200B}");
+        assert_eq!(baseline.apply(&mut new), 2);
+        let active: Vec<_> = new.active_findings().map(|f| f.column).collect();
+        assert_eq!(active, vec![5]);
+        assert_eq!(new.risk_level, RiskLevel::High);
+
+        let mut other = report("b.txt", "\u{200B}");
+        assert_eq!(baseline.apply(&mut other), 0);
+    }
+}
@@ -0,0 +1,1750 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
+
+pub(crate) const AGE: &[(char, char, (u8, u8))] = &[
+    ('\u{0}', '\u{1F5}', (1, 1)),
+    ('\u{1F6}', '\u{1F9}', (3, 0)),
+    ('\u{1FA}', '\u{217}', (1, 1)),
+    ('\u{218}', '\u{21F}', (3, 0)),
+    ('\u{220}', '\u{220}', (3, 2)),
+    ('\u{221}', '\u{221}', (4, 0)),
+    ('\u{222}', '\u{233}', (3, 0)),
+    ('\u{234}', '\u{236}', (4, 0)),
+    ('\u{237}', '\u{241}', (4, 1)),
+    ('\u{242}', '\u{24F}', (5, 0)),
+    ('\u{250}', '\u{2A8}', (1, 1)),
+    ('\u{2A9}', '\u{2AD}', (3, 0)),
+    ('\u{2AE}', '\u{2AF}', (4, 0)),
+    ('\u{2B0}', '\u{2DE}', (1, 1)),
+    ('\u{2DF}', '\u{2DF}', (3, 0)),
+    ('\u{2E0}', '\u{2E9}', (1, 1)),
+    ('\u{2EA}', '\u{2EE}', (3, 0)),
+    ('\u{2EF}', '\u{2FF}', (4, 0)),
+    ('\u{300}', '\u{345}', (1, 1)),
+    ('\u{346}', '\u{34E}', (3, 0)),
+    ('\u{34F}', '\u{34F}', (3, 2)),
+    ('\u{350}', '\u{357}', (4, 0)),
+    ('\u{358}', '\u{35C}', (4, 1)),
+    ('\u{35D}', '\u{35F}', (4, 0)),
+    ('\u{360}', '\u{361}', (1, 1)),
+    ('\u{362}', '\u{362}', (3, 0)),
+    ('\u{363}', '\u{36F}', (3, 2)),
+    ('\u{370}', '\u{373}', (5, 1)),
+    ('\u{374}', '\u{375}', (1, 1)),
+    ('\u{376}', '\u{377}', (5, 1)),
+    ('\u{37A}', '\u{37A}', (1, 1)),
+    ('\u{37B}', '\u{37D}', (5, 0)),
+    ('\u{37E}', '\u{37E}', (1, 1)),
+    ('\u{37F}', '\u{37F}', (7, 0)),
+    ('\u{384}', '\u{38A}', (1, 1)),
+    ('\u{38C}', '\u{38C}', (1, 1)),
+    ('\u{38E}', '\u{3A1}', (1, 1)),
+    ('\u{3A3}', '\u{3CE}', (1, 1)),
+    ('\u{3CF}', '\u{3CF}', (5, 1)),
+    ('\u{3D0}', '\u{3D6}', (1, 1)),
+    ('\u{3D7}', '\u{3D7}', (3, 0)),
+    ('\u{3D8}', '\u{3D9}', (3, 2)),
+    ('\u{3DA}', '\u{3DA}', (1, 1)),
+    ('\u{3DB}', '\u{3DB}', (3, 0)),
+    ('\u{3DC}', '\u{3DC}', (1, 1)),
+    ('\u{3DD}', '\u{3DD}', (3, 0)),
+    ('\u{3DE}', '\u{3DE}', (1, 1)),
+    ('\u{3DF}', '\u{3DF}', (3, 0)),
+    ('\u{3E0}', '\u{3E0}', (1, 1)),
+    ('\u{3E1}', '\u{3E1}', (3, 0)),
+    ('\u{3E2}', '\u{3F3}', (1, 1)),
+    ('\u{3F4}', '\u{3F5}', (3, 1)),
+    ('\u{3F6}', '\u{3F6}', (3, 2)),
+    ('\u{3F7}', '\u{3FB}', (4, 0)),
+    ('\u{3FC}', '\u{3FF}', (4, 1)),
+    ('\u{400}', '\u{400}', (3, 0)),
+    ('\u{401}', '\u{40C}', (1, 1)),
+    ('\u{40D}', '\u{40D}', (3, 0)),
+    ('\u{40E}', '\u{44F}', (1, 1)),
+    ('\u{450}', '\u{450}', (3, 0)),
+    ('\u{451}', '\u{45C}', (1, 1)),
+    ('\u{45D}', '\u{45D}', (3, 0)),
+    ('\u{45E}', '\u{486}', (1, 1)),
+    ('\u{487}', '\u{487}', (5, 1)),
+    ('\u{488}', '\u{489}', (3, 0)),
+    ('\u{48A}', '\u{48B}', (3, 2)),
+    ('\u{48C}', '\u{48F}', (3, 0)),
+    ('\u{490}', '\u{4C4}', (1, 1)),
+    ('\u{4C5}', '\u{4C6}', (3, 2)),
+    ('\u{4C7}', '\u{4C8}', (1, 1)),
+    ('\u{4C9}', '\u{4CA}', (3, 2)),
+    ('\u{4CB}', '\u{4CC}', (1, 1)),
+    ('\u{4CD}', '\u{4CE}', (3, 2)),
+    ('\u{4CF}', '\u{4CF}', (5, 0)),
+    ('\u{4D0}', '\u{4EB}', (1, 1)),
+    ('\u{4EC}', '\u{4ED}', (3, 0)),
+    ('\u{4EE}', '\u{4F5}', (1, 1)),
+    ('\u{4F6}', '\u{4F7}', (4, 1)),
+    ('\u{4F8}', '\u{4F9}', (1, 1)),
+    ('\u{4FA}', '\u{4FF}', (5, 0)),
+    ('\u{500}', '\u{50F}', (3, 2)),
+    ('\u{510}', '\u{513}', (5, 0)),
+    ('\u{514}', '\u{523}', (5, 1)),
+    ('\u{524}', '\u{525}', (5, 2)),
+    ('\u{526}', '\u{527}', (6, 0)),
+    ('\u{528}', '\u{52F}', (7, 0)),
+    ('\u{531}', '\u{556}', (1, 1)),
+    ('\u{559}', '\u{55F}', (1, 1)),
+    ('\u{560}', '\u{560}', (11, 0)),
+    ('\u{561}', '\u{587}', (1, 1)),
+    ('\u{588}', '\u{588}', (11, 0)),
+    ('\u{589}', '\u{589}', (1, 1)),
+    ('\u{58A}', '\u{58A}', (3, 0)),
+    ('\u{58D}', '\u{58E}', (7, 0)),
+    ('\u{58F}', '\u{58F}', (6, 1)),
+    ('\u{591}', '\u{5A1}', (2, 0)),
+    ('\u{5A2}', '\u{5A2}', (4, 1)),
+    ('\u{5A3}', '\u{5AF}', (2, 0)),
+    ('\u{5B0}', '\u{5B9}', (1, 1)),
+    ('\u{5BA}', '\u{5BA}', (5, 0)),
+    ('\u{5BB}', '\u{5C3}', (1, 1)),
+    ('\u{5C4}', '\u{5C4}', (2, 0)),
+    ('\u{5C5}', '\u{5C7}', (4, 1)),
+    ('\u{5D0}', '\u{5EA}', (1, 1)),
+    ('\u{5EF}', '\u{5EF}', (11, 0)),
+    ('\u{5F0}', '\u{5F4}', (1, 1)),
+    ('\u{600}', '\u{603}', (4, 0)),
+    ('\u{604}', '\u{604}', (6, 1)),
+    ('\u{605}', '\u{605}', (7, 0)),
+    ('\u{606}', '\u{60A}', (5, 1)),
+    ('\u{60B}', '\u{60B}', (4, 1)),
+    ('\u{60C}', '\u{60C}', (1, 1)),
+    ('\u{60D}', '\u{615}', (4, 0)),
+    ('\u{616}', '\u{61A}', (5, 1)),
+    ('\u{61B}', '\u{61B}', (1, 1)),
+    ('\u{61C}', '\u{61C}', (6, 3)),
+    ('\u{61D}', '\u{61D}', (14, 0)),
+    ('\u{61E}', '\u{61E}', (4, 1)),
+    ('\u{61F}', '\u{61F}', (1, 1)),
+    ('\u{620}', '\u{620}', (6, 0)),
+    ('\u{621}', '\u{63A}', (1, 1)),
+    ('\u{63B}', '\u{63F}', (5, 1)),
+    ('\u{640}', '\u{652}', (1, 1)),
+    ('\u{653}', '\u{655}', (3, 0)),
+    ('\u{656}', '\u{658}', (4, 0)),
+    ('\u{659}', '\u{65E}', (4, 1)),
+    ('\u{65F}', '\u{65F}', (6, 0)),
+    ('\u{660}', '\u{66D}', (1, 1)),
+    ('\u{66E}', '\u{66F}', (3, 2)),
+    ('\u{670}', '\u{6B7}', (1, 1)),
+    ('\u{6B8}', '\u{6B9}', (3, 0)),
+    ('\u{6BA}', '\u{6BE}', (1, 1)),
+    ('\u{6BF}', '\u{6BF}', (3, 0)),
+    ('\u{6C0}', '\u{6CE}', (1, 1)),
+    ('\u{6CF}', '\u{6CF}', (3, 0)),
+    ('\u{6D0}', '\u{6ED}', (1, 1)),
+    ('\u{6EE}', '\u{6EF}', (4, 0)),
+    ('\u{6F0}', '\u{6F9}', (1, 1)),
+    ('\u{6FA}', '\u{6FE}', (3, 0)),
+    ('\u{6FF}', '\u{6FF}', (4, 0)),
+    ('\u{700}', '\u{70D}', (3, 0)),
+    ('\u{70F}', '\u{72C}', (3, 0)),
+    ('\u{72D}', '\u{72F}', (4, 0)),
+    ('\u{730}', '\u{74A}', (3, 0)),
+    ('\u{74D}', '\u{74F}', (4, 0)),
+    ('\u{750}', '\u{76D}', (4, 1)),
+    ('\u{76E}', '\u{77F}', (5, 1)),
+    ('\u{780}', '\u{7B0}', (3, 0)),
+    ('\u{7B1}', '\u{7B1}', (3, 2)),
+    ('\u{7C0}', '\u{7FA}', (5, 0)),
+    ('\u{7FD}', '\u{7FF}', (11, 0)),
+    ('\u{800}', '\u{82D}', (5, 2)),
+    ('\u{830}', '\u{83E}', (5, 2)),
+    ('\u{840}', '\u{85B}', (6, 0)),
+    ('\u{85E}', '\u{85E}', (6, 0)),
+    ('\u{860}', '\u{86A}', (10, 0)),
+    ('\u{870}', '\u{88E}', (14, 0)),
+    ('\u{890}', '\u{891}', (14, 0)),
+    ('\u{897}', '\u{897}', (16, 0)),
+    ('\u{898}', '\u{89F}', (14, 0)),
+    ('\u{8A0}', '\u{8A0}', (6, 1)),
+    ('\u{8A1}', '\u{8A1}', (7, 0)),
+    ('\u{8A2}', '\u{8AC}', (6, 1)),
+    ('\u{8AD}', '\u{8B2}', (7, 0)),
+    ('\u{8B3}', '\u{8B4}', (8, 0)),
+    ('\u{8B5}', '\u{8B5}', (14, 0)),
+    ('\u{8B6}', '\u{8BD}', (9, 0)),
+    ('\u{8BE}', '\u{8C7}', (13, 0)),
+    ('\u{8C8}', '\u{8D2}', (14, 0)),
+    ('\u{8D3}', '\u{8D3}', (11, 0)),
+    ('\u{8D4}', '\u{8E2}', (9, 0)),
+    ('\u{8E3}', '\u{8E3}', (8, 0)),
+    ('\u{8E4}', '\u{8FE}', (6, 1)),
+    ('\u{8FF}', '\u{8FF}', (7, 0)),
+    ('\u{900}', '\u{900}', (5, 2)),
+    ('\u{901}', '\u{903}', (1, 1)),
+    ('\u{904}', '\u{904}', (4, 0)),
+    ('\u{905}', '\u{939}', (1, 1)),
+    ('\u{93A}', '\u{93B}', (6, 0)),
+    ('\u{93C}', '\u{94D}', (1, 1)),
+    ('\u{94E}', '\u{94E}', (5, 2)),
+    ('\u{94F}', '\u{94F}', (6, 0)),
+    ('\u{950}', '\u{954}', (1, 1)),
+    ('\u{955}', '\u{955}', (5, 2)),
+    ('\u{956}', '\u{957}', (6, 0)),
+    ('\u{958}', '\u{970}', (1, 1)),
+    ('\u{971}', '\u{972}', (5, 1)),
+    ('\u{973}', '\u{977}', (6, 0)),
+    ('\u{978}', '\u{978}', (7, 0)),
+    ('\u{979}', '\u{97A}', (5, 2)),
+    ('\u{97B}', '\u{97C}', (5, 0)),
+    ('\u{97D}', '\u{97D}', (4, 1)),
+    ('\u{97E}', '\u{97F}', (5, 0)),
+    ('\u{980}', '\u{980}', (7, 0)),
+    ('\u{981}', '\u{983}', (1, 1)),
+    ('\u{985}', '\u{98C}', (1, 1)),
+    ('\u{98F}', '\u{990}', (1, 1)),
+    ('\u{993}', '\u{9A8}', (1, 1)),
+    ('\u{9AA}', '\u{9B0}', (1, 1)),
+    ('\u{9B2}', '\u{9B2}', (1, 1)),
+    ('\u{9B6}', '\u{9B9}', (1, 1)),
+    ('\u{9BC}', '\u{9BC}', (1, 1)),
+    ('\u{9BD}', '\u{9BD}', (4, 0)),
+    ('\u{9BE}', '\u{9C4}', (1, 1)),
+    ('\u{9C7}', '\u{9C8}', (1, 1)),
+    ('\u{9CB}', '\u{9CD}', (1, 1)),
+    ('\u{9CE}', '\u{9CE}', (4, 1)),
+    ('\u{9D7}', '\u{9D7}', (1, 1)),
+    ('\u{9DC}', '\u{9DD}', (1, 1)),
+    ('\u{9DF}', '\u{9E3}', (1, 1)),
+    ('\u{9E6}', '\u{9FA}', (1, 1)),
+    ('\u{9FB}', '\u{9FB}', (5, 2)),
+    ('\u{9FC}', '\u{9FD}', (10, 0)),
+    ('\u{9FE}', '\u{9FE}', (11, 0)),
+    ('\u{A01}', '\u{A01}', (4, 0)),
+    ('\u{A02}', '\u{A02}', (1, 1)),
+    ('\u{A03}', '\u{A03}', (4, 0)),
+    ('\u{A05}', '\u{A0A}', (1, 1)),
+    ('\u{A0F}', '\u{A10}', (1, 1)),
+    ('\u{A13}', '\u{A28}', (1, 1)),
+    ('\u{A2A}', '\u{A30}', (1, 1)),
+    ('\u{A32}', '\u{A33}', (1, 1)),
+    ('\u{A35}', '\u{A36}', (1, 1)),
+    ('\u{A38}', '\u{A39}', (1, 1)),
+    ('\u{A3C}', '\u{A3C}', (1, 1)),
+    ('\u{A3E}', '\u{A42}', (1, 1)),
+    ('\u{A47}', '\u{A48}', (1, 1)),
+    ('\u{A4B}', '\u{A4D}', (1, 1)),
+    ('\u{A51}', '\u{A51}', (5, 1)),
+    ('\u{A59}', '\u{A5C}', (1, 1)),
+    ('\u{A5E}', '\u{A5E}', (1, 1)),
+    ('\u{A66}', '\u{A74}', (1, 1)),
+    ('\u{A75}', '\u{A75}', (5, 1)),
+    ('\u{A76}', '\u{A76}', (11, 0)),
+    ('\u{A81}', '\u{A83}', (1, 1)),
+    ('\u{A85}', '\u{A8B}', (1, 1)),
+    ('\u{A8C}', '\u{A8C}', (4, 0)),
+    ('\u{A8D}', '\u{A8D}', (1, 1)),
+    ('\u{A8F}', '\u{A91}', (1, 1)),
+    ('\u{A93}', '\u{AA8}', (1, 1)),
+    ('\u{AAA}', '\u{AB0}', (1, 1)),
+    ('\u{AB2}', '\u{AB3}', (1, 1)),
+    ('\u{AB5}', '\u{AB9}', (1, 1)),
+    ('\u{ABC}', '\u{AC5}', (1, 1)),
+    ('\u{AC7}', '\u{AC9}', (1, 1)),
+    ('\u{ACB}', '\u{ACD}', (1, 1)),
+    ('\u{AD0}', '\u{AD0}', (1, 1)),
+    ('\u{AE0}', '\u{AE0}', (1, 1)),
+    ('\u{AE1}', '\u{AE3}', (4, 0)),
+    ('\u{AE6}', '\u{AEF}', (1, 1)),
+    ('\u{AF0}', '\u{AF0}', (6, 1)),
+    ('\u{AF1}', '\u{AF1}', (4, 0)),
+    ('\u{AF9}', '\u{AF9}', (8, 0)),
+    ('\u{AFA}', '\u{AFF}', (10, 0)),
+    ('\u{B01}', '\u{B03}', (1, 1)),
+    ('\u{B05}', '\u{B0C}', (1, 1)),
+    ('\u{B0F}', '\u{B10}', (1, 1)),
+    ('\u{B13}', '\u{B28}', (1, 1)),
+    ('\u{B2A}', '\u{B30}', (1, 1)),
+    ('\u{B32}', '\u{B33}', (1, 1)),
+    ('\u{B35}', '\u{B35}', (4, 0)),
+    ('\u{B36}', '\u{B39}', (1, 1)),
+    ('\u{B3C}', '\u{B43}', (1, 1)),
+    ('\u{B44}', '\u{B44}', (5, 1)),
+    ('\u{B47}', '\u{B48}', (1, 1)),
+    ('\u{B4B}', '\u{B4D}', (1, 1)),
+    ('\u{B55}', '\u{B55}', (13, 0)),
+    ('\u{B56}', '\u{B57}', (1, 1)),
+    ('\u{B5C}', '\u{B5D}', (1, 1)),
+    ('\u{B5F}', '\u{B61}', (1, 1)),
+    ('\u{B62}', '\u{B63}', (5, 1)),
+    ('\u{B66}', '\u{B70}', (1, 1)),
+    ('\u{B71}', '\u{B71}', (4, 0)),
+    ('\u{B72}', '\u{B77}', (6, 0)),
+    ('\u{B82}', '\u{B83}', (1, 1)),
+    ('\u{B85}', '\u{B8A}', (1, 1)),
+    ('\u{B8E}', '\u{B90}', (1, 1)),
+    ('\u{B92}', '\u{B95}', (1, 1)),
+    ('\u{B99}', '\u{B9A}', (1, 1)),
+    ('\u{B9C}', '\u{B9C}', (1, 1)),
+    ('\u{B9E}', '\u{B9F}', (1, 1)),
+    ('\u{BA3}', '\u{BA4}', (1, 1)),
+    ('\u{BA8}', '\u{BAA}', (1, 1)),
+    ('\u{BAE}', '\u{BB5}', (1, 1)),
+    ('\u{BB6}', '\u{BB6}', (4, 1)),
+    ('\u{BB7}', '\u{BB9}', (1, 1)),
+    ('\u{BBE}', '\u{BC2}', (1, 1)),
+    ('\u{BC6}', '\u{BC8}', (1, 1)),
+    ('\u{BCA}', '\u{BCD}', (1, 1)),
+    ('\u{BD0}', '\u{BD0}', (5, 1)),
+    ('\u{BD7}', '\u{BD7}', (1, 1)),
+    ('\u{BE6}', '\u{BE6}', (4, 1)),
+    ('\u{BE7}', '\u{BF2}', (1, 1)),
+    ('\u{BF3}', '\u{BFA}', (4, 0)),
+    ('\u{C00}', '\u{C00}', (7, 0)),
+    ('\u{C01}', '\u{C03}', (1, 1)),
+    ('\u{C04}', '\u{C04}', (11, 0)),
+    ('\u{C05}', '\u{C0C}', (1, 1)),
+    ('\u{C0E}', '\u{C10}', (1, 1)),
+    ('\u{C12}', '\u{C28}', (1, 1)),
+    ('\u{C2A}', '\u{C33}', (1, 1)),
+    ('\u{C34}', '\u{C34}', (7, 0)),
+    ('\u{C35}', '\u{C39}', (1, 1)),
+    ('\u{C3C}', '\u{C3C}', (14, 0)),
+    ('\u{C3D}', '\u{C3D}', (5, 1)),
+    ('\u{C3E}', '\u{C44}', (1, 1)),
+    ('\u{C46}', '\u{C48}', (1, 1)),
+    ('\u{C4A}', '\u{C4D}', (1, 1)),
+    ('\u{C55}', '\u{C56}', (1, 1)),
+    ('\u{C58}', '\u{C59}', (5, 1)),
+    ('\u{C5A}', '\u{C5A}', (8, 0)),
+    ('\u{C5D}', '\u{C5D}', (14, 0)),
+    ('\u{C60}', '\u{C61}', (1, 1)),
+    ('\u{C62}', '\u{C63}', (5, 1)),
+    ('\u{C66}', '\u{C6F}', (1, 1)),
+    ('\u{C77}', '\u{C77}', (12, 0)),
+    ('\u{C78}', '\u{C7F}', (5, 1)),
+    ('\u{C80}', '\u{C80}', (9, 0)),
+    ('\u{C81}', '\u{C81}', (7, 0)),
+    ('\u{C82}', '\u{C83}', (1, 1)),
+    ('\u{C84}', '\u{C84}', (11, 0)),
+    ('\u{C85}', '\u{C8C}', (1, 1)),
+    ('\u{C8E}', '\u{C90}', (1, 1)),
+    ('\u{C92}', '\u{CA8}', (1, 1)),
+    ('\u{CAA}', '\u{CB3}', (1, 1)),
+    ('\u{CB5}', '\u{CB9}', (1, 1)),
+    ('\u{CBC}', '\u{CBD}', (4, 0)),
+    ('\u{CBE}', '\u{CC4}', (1, 1)),
+    ('\u{CC6}', '\u{CC8}', (1, 1)),
+    ('\u{CCA}', '\u{CCD}', (1, 1)),
+    ('\u{CD5}', '\u{CD6}', (1, 1)),
+    ('\u{CDD}', '\u{CDD}', (14, 0)),
+    ('\u{CDE}', '\u{CDE}', (1, 1)),
+    ('\u{CE0}', '\u{CE1}', (1, 1)),
+    ('\u{CE2}', '\u{CE3}', (5, 0)),
+    ('\u{CE6}', '\u{CEF}', (1, 1)),
+    ('\u{CF1}', '\u{CF2}', (5, 0)),
+    ('\u{CF3}', '\u{CF3}', (15, 0)),
+    ('\u{D00}', '\u{D00}', (10, 0)),
+    ('\u{D01}', '\u{D01}', (7, 0)),
+    ('\u{D02}', '\u{D03}', (1, 1)),
+    ('\u{D04}', '\u{D04}', (13, 0)),
+    ('\u{D05}', '\u{D0C}', (1, 1)),
+    ('\u{D0E}', '\u{D10}', (1, 1)),
+    ('\u{D12}', '\u{D28}', (1, 1)),
+    ('\u{D29}', '\u{D29}', (6, 0)),
+    ('\u{D2A}', '\u{D39}', (1, 1)),
+    ('\u{D3A}', '\u{D3A}', (6, 0)),
+    ('\u{D3B}', '\u{D3C}', (10, 0)),
+    ('\u{D3D}', '\u{D3D}', (5, 1)),
+    ('\u{D3E}', '\u{D43}', (1, 1)),
+    ('\u{D44}', '\u{D44}', (5, 1)),
+    ('\u{D46}', '\u{D48}', (1, 1)),
+    ('\u{D4A}', '\u{D4D}', (1, 1)),
+    ('\u{D4E}', '\u{D4E}', (6, 0)),
+    ('\u{D4F}', '\u{D4F}', (9, 0)),
+    ('\u{D54}', '\u{D56}', (9, 0)),
+    ('\u{D57}', '\u{D57}', (1, 1)),
+    ('\u{D58}', '\u{D5E}', (9, 0)),
+    ('\u{D5F}', '\u{D5F}', (8, 0)),
+    ('\u{D60}', '\u{D61}', (1, 1)),
+    ('\u{D62}', '\u{D63}', (5, 1)),
+    ('\u{D66}', '\u{D6F}', (1, 1)),
+    ('\u{D70}', '\u{D75}', (5, 1)),
+    ('\u{D76}', '\u{D78}', (9, 0)),
+    ('\u{D79}', '\u{D7F}', (5, 1)),
+    ('\u{D81}', '\u{D81}', (13, 0)),
+    ('\u{D82}', '\u{D83}', (3, 0)),
+    ('\u{D85}', '\u{D96}', (3, 0)),
+    ('\u{D9A}', '\u{DB1}', (3, 0)),
+    ('\u{DB3}', '\u{DBB}', (3, 0)),
+    ('\u{DBD}', '\u{DBD}', (3, 0)),
+    ('\u{DC0}', '\u{DC6}', (3, 0)),
+    ('\u{DCA}', '\u{DCA}', (3, 0)),
+    ('\u{DCF}', '\u{DD4}', (3, 0)),
+    ('\u{DD6}', '\u{DD6}', (3, 0)),
+    ('\u{DD8}', '\u{DDF}', (3, 0)),
+    ('\u{DE6}', '\u{DEF}', (7, 0)),
+    ('\u{DF2}', '\u{DF4}', (3, 0)),
+    ('\u{E01}', '\u{E3A}', (1, 1)),
+    ('\u{E3F}', '\u{E5B}', (1, 1)),
+    ('\u{E81}', '\u{E82}', (1, 1)),
+    ('\u{E84}', '\u{E84}', (1, 1)),
+    ('\u{E86}', '\u{E86}', (12, 0)),
+    ('\u{E87}', '\u{E88}', (1, 1)),
+    ('\u{E89}', '\u{E89}', (12, 0)),
+    ('\u{E8A}', '\u{E8A}', (1, 1)),
+    ('\u{E8C}', '\u{E8C}', (12, 0)),
+    ('\u{E8D}', '\u{E8D}', (1, 1)),
+    ('\u{E8E}', '\u{E93}', (12, 0)),
+    ('\u{E94}', '\u{E97}', (1, 1)),
+    ('\u{E98}', '\u{E98}', (12, 0)),
+    ('\u{E99}', '\u{E9F}', (1, 1)),
+    ('\u{EA0}', '\u{EA0}', (12, 0)),
+    ('\u{EA1}', '\u{EA3}', (1, 1)),
+    ('\u{EA5}', '\u{EA5}', (1, 1)),
+    ('\u{EA7}', '\u{EA7}', (1, 1)),
+    ('\u{EA8}', '\u{EA9}', (12, 0)),
+    ('\u{EAA}', '\u{EAB}', (1, 1)),
+    ('\u{EAC}', '\u{EAC}', (12, 0)),
+    ('\u{EAD}', '\u{EB9}', (1, 1)),
+    ('\u{EBA}', '\u{EBA}', (12, 0)),
+    ('\u{EBB}', '\u{EBD}', (1, 1)),
+    ('\u{EC0}', '\u{EC4}', (1, 1)),
+    ('\u{EC6}', '\u{EC6}', (1, 1)),
+    ('\u{EC8}', '\u{ECD}', (1, 1)),
+    ('\u{ECE}', '\u{ECE}', (15, 0)),
+    ('\u{ED0}', '\u{ED9}', (1, 1)),
+    ('\u{EDC}', '\u{EDD}', (1, 1)),
+    ('\u{EDE}', '\u{EDF}', (6, 1)),
+    ('\u{F00}', '\u{F47}', (2, 0)),
+    ('\u{F49}', '\u{F69}', (2, 0)),
+    ('\u{F6A}', '\u{F6A}', (3, 0)),
+    ('\u{F6B}', '\u{F6C}', (5, 1)),
+    ('\u{F71}', '\u{F8B}', (2, 0)),
+    ('\u{F8C}', '\u{F8F}', (6, 0)),
+    ('\u{F90}', '\u{F95}', (2, 0)),
+    ('\u{F96}', '\u{F96}', (3, 0)),
+    ('\u{F97}', '\u{F97}', (2, 0)),
+    ('\u{F99}', '\u{FAD}', (2, 0)),
+    ('\u{FAE}', '\u{FB0}', (3, 0)),
+    ('\u{FB1}', '\u{FB7}', (2, 0)),
+    ('\u{FB8}', '\u{FB8}', (3, 0)),
+    ('\u{FB9}', '\u{FB9}', (2, 0)),
+    ('\u{FBA}', '\u{FBC}', (3, 0)),
+    ('\u{FBE}', '\u{FCC}', (3, 0)),
+    ('\u{FCE}', '\u{FCE}', (5, 1)),
+    ('\u{FCF}', '\u{FCF}', (3, 0)),
+    ('\u{FD0}', '\u{FD1}', (4, 1)),
+    ('\u{FD2}', '\u{FD4}', (5, 1)),
+    ('\u{FD5}', '\u{FD8}', (5, 2)),
+    ('\u{FD9}', '\u{FDA}', (6, 0)),
+    ('\u{1000}', '\u{1021}', (3, 0)),
+    ('\u{1022}', '\u{1022}', (5, 1)),
+    ('\u{1023}', '\u{1027}', (3, 0)),
+    ('\u{1028}', '\u{1028}', (5, 1)),
+    ('\u{1029}', '\u{102A}', (3, 0)),
+    ('\u{102B}', '\u{102B}', (5, 1)),
+    ('\u{102C}', '\u{1032}', (3, 0)),
+    ('\u{1033}', '\u{1035}', (5, 1)),
+    ('\u{1036}', '\u{1039}', (3, 0)),
+    ('\u{103A}', '\u{103F}', (5, 1)),
+    ('\u{1040}', '\u{1059}', (3, 0)),
+    ('\u{105A}', '\u{1099}', (5, 1)),
+    ('\u{109A}', '\u{109D}', (5, 2)),
+    ('\u{109E}', '\u{109F}', (5, 1)),
+    ('\u{10A0}', '\u{10C5}', (1, 1)),
+    ('\u{10C7}', '\u{10C7}', (6, 1)),
+    ('\u{10CD}', '\u{10CD}', (6, 1)),
+    ('\u{10D0}', '\u{10F6}', (1, 1)),
+    ('\u{10F7}', '\u{10F8}', (3, 2)),
+    ('\u{10F9}', '\u{10FA}', (4, 1)),
+    ('\u{10FB}', '\u{10FB}', (1, 1)),
+    ('\u{10FC}', '\u{10FC}', (4, 1)),
+    ('\u{10FD}', '\u{10FF}', (6, 1)),
+    ('\u{1100}', '\u{1159}', (1, 1)),
+    ('\u{115A}', '\u{115E}', (5, 2)),
+    ('\u{115F}', '\u{11A2}', (1, 1)),
+    ('\u{11A3}', '\u{11A7}', (5, 2)),
+    ('\u{11A8}', '\u{11F9}', (1, 1)),
+    ('\u{11FA}', '\u{11FF}', (5, 2)),
+    ('\u{1200}', '\u{1206}', (3, 0)),
+    ('\u{1207}', '\u{1207}', (4, 1)),
+    ('\u{1208}', '\u{1246}', (3, 0)),
+    ('\u{1247}', '\u{1247}', (4, 1)),
+    ('\u{1248}', '\u{1248}', (3, 0)),
+    ('\u{124A}', '\u{124D}', (3, 0)),
+    ('\u{1250}', '\u{1256}', (3, 0)),
+    ('\u{1258}', '\u{1258}', (3, 0)),
+    ('\u{125A}', '\u{125D}', (3, 0)),
+    ('\u{1260}', '\u{1286}', (3, 0)),
+    ('\u{1287}', '\u{1287}', (4, 1)),
+    ('\u{1288}', '\u{1288}', (3, 0)),
+    ('\u{128A}', '\u{128D}', (3, 0)),
+    ('\u{1290}', '\u{12AE}', (3, 0)),
+    ('\u{12AF}', '\u{12AF}', (4, 1)),
+    ('\u{12B0}', '\u{12B0}', (3, 0)),
+    ('\u{12B2}', '\u{12B5}', (3, 0)),
+    ('\u{12B8}', '\u{12BE}', (3, 0)),
+    ('\u{12C0}', '\u{12C0}', (3, 0)),
+    ('\u{12C2}', '\u{12C5}', (3, 0)),
+    ('\u{12C8}', '\u{12CE}', (3, 0)),
+    ('\u{12CF}', '\u{12CF}', (4, 1)),
+    ('\u{12D0}', '\u{12D6}', (3, 0)),
+    ('\u{12D8}', '\u{12EE}', (3, 0)),
+    ('\u{12EF}', '\u{12EF}', (4, 1)),
+    ('\u{12F0}', '\u{130E}', (3, 0)),
+    ('\u{130F}', '\u{130F}', (4, 1)),
+    ('\u{1310}', '\u{1310}', (3, 0)),
+    ('\u{1312}', '\u{1315}', (3, 0)),
+    ('\u{1318}', '\u{131E}', (3, 0)),
+    ('\u{131F}', '\u{131F}', (4, 1)),
+    ('\u{1320}', '\u{1346}', (3, 0)),
+    ('\u{1347}', '\u{1347}', (4, 1)),
+    ('\u{1348}', '\u{135A}', (3, 0)),
+    ('\u{135D}', '\u{135E}', (6, 0)),
+    ('\u{135F}', '\u{1360}', (4, 1)),
+    ('\u{1361}', '\u{137C}', (3, 0)),
+    ('\u{1380}', '\u{1399}', (4, 1)),
+    ('\u{13A0}', '\u{13F4}', (3, 0)),
+    ('\u{13F5}', '\u{13F5}', (8, 0)),
+    ('\u{13F8}', '\u{13FD}', (8, 0)),
+    ('\u{1400}', '\u{1400}', (5, 2)),
+    ('\u{1401}', '\u{1676}', (3, 0)),
+    ('\u{1677}', '\u{167F}', (5, 2)),
+    ('\u{1680}', '\u{169C}', (3, 0)),
+    ('\u{16A0}', '\u{16F0}', (3, 0)),
+    ('\u{16F1}', '\u{16F8}', (7, 0)),
+    ('\u{1700}', '\u{170C}', (3, 2)),
+    ('\u{170D}', '\u{170D}', (14, 0)),
+    ('\u{170E}', '\u{1714}', (3, 2)),
+    ('\u{1715}', '\u{1715}', (14, 0)),
+    ('\u{171F}', '\u{171F}', (14, 0)),
+    ('\u{1720}', '\u{1736}', (3, 2)),
+    ('\u{1740}', '\u{1753}', (3, 2)),
+    ('\u{1760}', '\u{176C}', (3, 2)),
+    ('\u{176E}', '\u{1770}', (3, 2)),
+    ('\u{1772}', '\u{1773}', (3, 2)),
+    ('\u{1780}', '\u{17DC}', (3, 0)),
+    ('\u{17DD}', '\u{17DD}', (4, 0)),
+    ('\u{17E0}', '\u{17E9}', (3, 0)),
+    ('\u{17F0}', '\u{17F9}', (4, 0)),
+    ('\u{1800}', '\u{180E}', (3, 0)),
+    ('\u{180F}', '\u{180F}', (14, 0)),
+    ('\u{1810}', '\u{1819}', (3, 0)),
+    ('\u{1820}', '\u{1877}', (3, 0)),
+    ('\u{1878}', '\u{1878}', (11, 0)),
+    ('\u{1880}', '\u{18A9}', (3, 0)),
+    ('\u{18AA}', '\u{18AA}', (5, 1)),
+    ('\u{18B0}', '\u{18F5}', (5, 2)),
+    ('\u{1900}', '\u{191C}', (4, 0)),
+    ('\u{191D}', '\u{191E}', (7, 0)),
+    ('\u{1920}', '\u{192B}', (4, 0)),
+    ('\u{1930}', '\u{193B}', (4, 0)),
+    ('\u{1940}', '\u{1940}', (4, 0)),
+    ('\u{1944}', '\u{196D}', (4, 0)),
+    ('\u{1970}', '\u{1974}', (4, 0)),
+    ('\u{1980}', '\u{19A9}', (4, 1)),
+    ('\u{19AA}', '\u{19AB}', (5, 2)),
+    ('\u{19B0}', '\u{19C9}', (4, 1)),
+    ('\u{19D0}', '\u{19D9}', (4, 1)),
+    ('\u{19DA}', '\u{19DA}', (5, 2)),
+    ('\u{19DE}', '\u{19DF}', (4, 1)),
+    ('\u{19E0}', '\u{19FF}', (4, 0)),
+    ('\u{1A00}', '\u{1A1B}', (4, 1)),
+    ('\u{1A1E}', '\u{1A1F}', (4, 1)),
+    ('\u{1A20}', '\u{1A5E}', (5, 2)),
+    ('\u{1A60}', '\u{1A7C}', (5, 2)),
+    ('\u{1A7F}', '\u{1A89}', (5, 2)),
+    ('\u{1A90}', '\u{1A99}', (5, 2)),
+    ('\u{1AA0}', '\u{1AAD}', (5, 2)),
+    ('\u{1AB0}', '\u{1ABE}', (7, 0)),
+    ('\u{1ABF}', '\u{1AC0}', (13, 0)),
+    ('\u{1AC1}', '\u{1ACE}', (14, 0)),
+    ('\u{1B00}', '\u{1B4B}', (5, 0)),
+    ('\u{1B4C}', '\u{1B4C}', (14, 0)),
+    ('\u{1B4E}', '\u{1B4F}', (16, 0)),
+    ('\u{1B50}', '\u{1B7C}', (5, 0)),
+    ('\u{1B7D}', '\u{1B7E}', (14, 0)),
+    ('\u{1B7F}', '\u{1B7F}', (16, 0)),
+    ('\u{1B80}', '\u{1BAA}', (5, 1)),
+    ('\u{1BAB}', '\u{1BAD}', (6, 1)),
+    ('\u{1BAE}', '\u{1BB9}', (5, 1)),
+    ('\u{1BBA}', '\u{1BBF}', (6, 1)),
+    ('\u{1BC0}', '\u{1BF3}', (6, 0)),
+    ('\u{1BFC}', '\u{1BFF}', (6, 0)),
+    ('\u{1C00}', '\u{1C37}', (5, 1)),
+    ('\u{1C3B}', '\u{1C49}', (5, 1)),
+    ('\u{1C4D}', '\u{1C7F}', (5, 1)),
+    ('\u{1C80}', '\u{1C88}', (9, 0)),
+    ('\u{1C89}', '\u{1C8A}', (16, 0)),
+    ('\u{1C90}', '\u{1CBA}', (11, 0)),
+    ('\u{1CBD}', '\u{1CBF}', (11, 0)),
+    ('\u{1CC0}', '\u{1CC7}', (6, 1)),
+    ('\u{1CD0}', '\u{1CF2}', (5, 2)),
+    ('\u{1CF3}', '\u{1CF6}', (6, 1)),
+    ('\u{1CF7}', '\u{1CF7}', (10, 0)),
+    ('\u{1CF8}', '\u{1CF9}', (7, 0)),
+    ('\u{1CFA}', '\u{1CFA}', (12, 0)),
+    ('\u{1D00}', '\u{1D6B}', (4, 0)),
+    ('\u{1D6C}', '\u{1DC3}', (4, 1)),
+    ('\u{1DC4}', '\u{1DCA}', (5, 0)),
+    ('\u{1DCB}', '\u{1DE6}', (5, 1)),
+    ('\u{1DE7}', '\u{1DF5}', (7, 0)),
+    ('\u{1DF6}', '\u{1DF9}', (10, 0)),
+    ('\u{1DFA}', '\u{1DFA}', (14, 0)),
+    ('\u{1DFB}', '\u{1DFB}', (9, 0)),
+    ('\u{1DFC}', '\u{1DFC}', (6, 0)),
+    ('\u{1DFD}', '\u{1DFD}', (5, 2)),
+    ('\u{1DFE}', '\u{1DFF}', (5, 0)),
+    ('\u{1E00}', '\u{1E9A}', (1, 1)),
+    ('\u{1E9B}', '\u{1E9B}', (2, 0)),
+    ('\u{1E9C}', '\u{1E9F}', (5, 1)),
+    ('\u{1EA0}', '\u{1EF9}', (1, 1)),
+    ('\u{1EFA}', '\u{1EFF}', (5, 1)),
+    ('\u{1F00}', '\u{1F15}', (1, 1)),
+    ('\u{1F18}', '\u{1F1D}', (1, 1)),
+    ('\u{1F20}', '\u{1F45}', (1, 1)),
+    ('\u{1F48}', '\u{1F4D}', (1, 1)),
+    ('\u{1F50}', '\u{1F57}', (1, 1)),
+    ('\u{1F59}', '\u{1F59}', (1, 1)),
+    ('\u{1F5B}', '\u{1F5B}', (1, 1)),
+    ('\u{1F5D}', '\u{1F5D}', (1, 1)),
+    ('\u{1F5F}', '\u{1F7D}', (1, 1)),
+    ('\u{1F80}', '\u{1FB4}', (1, 1)),
+    ('\u{1FB6}', '\u{1FC4}', (1, 1)),
+    ('\u{1FC6}', '\u{1FD3}', (1, 1)),
+    ('\u{1FD6}', '\u{1FDB}', (1, 1)),
+    ('\u{1FDD}', '\u{1FEF}', (1, 1)),
+    ('\u{1FF2}', '\u{1FF4}', (1, 1)),
+    ('\u{1FF6}', '\u{1FFE}', (1, 1)),
+    ('\u{2000}', '\u{202E}', (1, 1)),
+    ('\u{202F}', '\u{202F}', (3, 0)),
+    ('\u{2030}', '\u{2046}', (1, 1)),
+    ('\u{2047}', '\u{2047}', (3, 2)),
+    ('\u{2048}', '\u{204D}', (3, 0)),
+    ('\u{204E}', '\u{2052}', (3, 2)),
+    ('\u{2053}', '\u{2054}', (4, 0)),
+    ('\u{2055}', '\u{2056}', (4, 1)),
+    ('\u{2057}', '\u{2057}', (3, 2)),
+    ('\u{2058}', '\u{205E}', (4, 1)),
+    ('\u{205F}', '\u{2063}', (3, 2)),
+    ('\u{2064}', '\u{2064}', (5, 1)),
+    ('\u{2066}', '\u{2069}', (6, 3)),
+    ('\u{206A}', '\u{2070}', (1, 1)),
+    ('\u{2071}', '\u{2071}', (3, 2)),
+    ('\u{2074}', '\u{208E}', (1, 1)),
+    ('\u{2090}', '\u{2094}', (4, 1)),
+    ('\u{2095}', '\u{209C}', (6, 0)),
+    ('\u{20A0}', '\u{20AA}', (1, 1)),
+    ('\u{20AB}', '\u{20AB}', (2, 0)),
+    ('\u{20AC}', '\u{20AC}', (2, 1)),
+    ('\u{20AD}', '\u{20AF}', (3, 0)),
+    ('\u{20B0}', '\u{20B1}', (3, 2)),
+    ('\u{20B2}', '\u{20B5}', (4, 1)),
+    ('\u{20B6}', '\u{20B8}', (5, 2)),
+    ('\u{20B9}', '\u{20B9}', (6, 0)),
+    ('\u{20BA}', '\u{20BA}', (6, 2)),
+    ('\u{20BB}', '\u{20BD}', (7, 0)),
+    ('\u{20BE}', '\u{20BE}', (8, 0)),
+    ('\u{20BF}', '\u{20BF}', (10, 0)),
+    ('\u{20C0}', '\u{20C0}', (14, 0)),
+    ('\u{20D0}', '\u{20E1}', (1, 1)),
+    ('\u{20E2}', '\u{20E3}', (3, 0)),
+    ('\u{20E4}', '\u{20EA}', (3, 2)),
+    ('\u{20EB}', '\u{20EB}', (4, 1)),
+    ('\u{20EC}', '\u{20EF}', (5, 0)),
+    ('\u{20F0}', '\u{20F0}', (5, 1)),
+    ('\u{2100}', '\u{2138}', (1, 1)),
+    ('\u{2139}', '\u{213A}', (3, 0)),
+    ('\u{213B}', '\u{213B}', (4, 0)),
+    ('\u{213C}', '\u{213C}', (4, 1)),
+    ('\u{213D}', '\u{214B}', (3, 2)),
+    ('\u{214C}', '\u{214C}', (4, 1)),
+    ('\u{214D}', '\u{214E}', (5, 0)),
+    ('\u{214F}', '\u{214F}', (5, 1)),
+    ('\u{2150}', '\u{2152}', (5, 2)),
+    ('\u{2153}', '\u{2182}', (1, 1)),
+    ('\u{2183}', '\u{2183}', (3, 0)),
+    ('\u{2184}', '\u{2184}', (5, 0)),
+    ('\u{2185}', '\u{2188}', (5, 1)),
+    ('\u{2189}', '\u{2189}', (5, 2)),
+    ('\u{218A}', '\u{218B}', (8, 0)),
+    ('\u{2190}', '\u{21EA}', (1, 1)),
+    ('\u{21EB}', '\u{21F3}', (3, 0)),
+    ('\u{21F4}', '\u{21FF}', (3, 2)),
+    ('\u{2200}', '\u{22F1}', (1, 1)),
+    ('\u{22F2}', '\u{22FF}', (3, 2)),
+    ('\u{2300}', '\u{2300}', (1, 1)),
+    ('\u{2301}', '\u{2301}', (3, 0)),
+    ('\u{2302}', '\u{237A}', (1, 1)),
+    ('\u{237B}', '\u{237B}', (3, 0)),
+    ('\u{237C}', '\u{237C}', (3, 2)),
+    ('\u{237D}', '\u{239A}', (3, 0)),
+    ('\u{239B}', '\u{23CE}', (3, 2)),
+    ('\u{23CF}', '\u{23D0}', (4, 0)),
+    ('\u{23D1}', '\u{23DB}', (4, 1)),
+    ('\u{23DC}', '\u{23E7}', (5, 0)),
+    ('\u{23E8}', '\u{23E8}', (5, 2)),
+    ('\u{23E9}', '\u{23F3}', (6, 0)),
+    ('\u{23F4}', '\u{23FA}', (7, 0)),
+    ('\u{23FB}', '\u{23FE}', (9, 0)),
+    ('\u{23FF}', '\u{23FF}', (10, 0)),
+    ('\u{2400}', '\u{2424}', (1, 1)),
+    ('\u{2425}', '\u{2426}', (3, 0)),
+    ('\u{2427}', '\u{2429}', (16, 0)),
+    ('\u{2440}', '\u{244A}', (1, 1)),
+    ('\u{2460}', '\u{24EA}', (1, 1)),
+    ('\u{24EB}', '\u{24FE}', (3, 2)),
+    ('\u{24FF}', '\u{24FF}', (4, 0)),
+    ('\u{2500}', '\u{2595}', (1, 1)),
+    ('\u{2596}', '\u{259F}', (3, 2)),
+    ('\u{25A0}', '\u{25EF}', (1, 1)),
+    ('\u{25F0}', '\u{25F7}', (3, 0)),
+    ('\u{25F8}', '\u{25FF}', (3, 2)),
+    ('\u{2600}', '\u{2613}', (1, 1)),
+    ('\u{2614}', '\u{2615}', (4, 0)),
+    ('\u{2616}', '\u{2617}', (3, 2)),
+    ('\u{2618}', '\u{2618}', (4, 1)),
+    ('\u{2619}', '\u{2619}', (3, 0)),
+    ('\u{261A}', '\u{266F}', (1, 1)),
+    ('\u{2670}', '\u{2671}', (3, 0)),
+    ('\u{2672}', '\u{267D}', (3, 2)),
+    ('\u{267E}', '\u{267F}', (4, 1)),
+    ('\u{2680}', '\u{2689}', (3, 2)),
+    ('\u{268A}', '\u{2691}', (4, 0)),
+    ('\u{2692}', '\u{269C}', (4, 1)),
+    ('\u{269D}', '\u{269D}', (5, 1)),
+    ('\u{269E}', '\u{269F}', (5, 2)),
+    ('\u{26A0}', '\u{26A1}', (4, 0)),
+    ('\u{26A2}', '\u{26B1}', (4, 1)),
+    ('\u{26B2}', '\u{26B2}', (5, 0)),
+    ('\u{26B3}', '\u{26BC}', (5, 1)),
+    ('\u{26BD}', '\u{26BF}', (5, 2)),
+    ('\u{26C0}', '\u{26C3}', (5, 1)),
+    ('\u{26C4}', '\u{26CD}', (5, 2)),
+    ('\u{26CE}', '\u{26CE}', (6, 0)),
+    ('\u{26CF}', '\u{26E1}', (5, 2)),
+    ('\u{26E2}', '\u{26E2}', (6, 0)),
+    ('\u{26E3}', '\u{26E3}', (5, 2)),
+    ('\u{26E4}', '\u{26E7}', (6, 0)),
+    ('\u{26E8}', '\u{26FF}', (5, 2)),
+    ('\u{2700}', '\u{2700}', (7, 0)),
+    ('\u{2701}', '\u{2704}', (1, 1)),
+    ('\u{2705}', '\u{2705}', (6, 0)),
+    ('\u{2706}', '\u{2709}', (1, 1)),
+    ('\u{270A}', '\u{270B}', (6, 0)),
+    ('\u{270C}', '\u{2727}', (1, 1)),
+    ('\u{2728}', '\u{2728}', (6, 0)),
+    ('\u{2729}', '\u{274B}', (1, 1)),
+    ('\u{274C}', '\u{274C}', (6, 0)),
+    ('\u{274D}', '\u{274D}', (1, 1)),
+    ('\u{274E}', '\u{274E}', (6, 0)),
+    ('\u{274F}', '\u{2752}', (1, 1)),
+    ('\u{2753}', '\u{2755}', (6, 0)),
+    ('\u{2756}', '\u{2756}', (1, 1)),
+    ('\u{2757}', '\u{2757}', (5, 2)),
+    ('\u{2758}', '\u{275E}', (1, 1)),
+    ('\u{275F}', '\u{2760}', (6, 0)),
+    ('\u{2761}', '\u{2767}', (1, 1)),
+    ('\u{2768}', '\u{2775}', (3, 2)),
+    ('\u{2776}', '\u{2794}', (1, 1)),
+    ('\u{2795}', '\u{2797}', (6, 0)),
+    ('\u{2798}', '\u{27AF}', (1, 1)),
+    ('\u{27B0}', '\u{27B0}', (6, 0)),
+    ('\u{27B1}', '\u{27BE}', (1, 1)),
+    ('\u{27BF}', '\u{27BF}', (6, 0)),
+    ('\u{27C0}', '\u{27C6}', (4, 1)),
+    ('\u{27C7}', '\u{27CA}', (5, 0)),
+    ('\u{27CB}', '\u{27CB}', (6, 1)),
+    ('\u{27CC}', '\u{27CC}', (5, 1)),
+    ('\u{27CD}', '\u{27CD}', (6, 1)),
+    ('\u{27CE}', '\u{27CF}', (6, 0)),
+    ('\u{27D0}', '\u{27EB}', (3, 2)),
+    ('\u{27EC}', '\u{27EF}', (5, 1)),
+    ('\u{27F0}', '\u{27FF}', (3, 2)),
+    ('\u{2800}', '\u{28FF}', (3, 0)),
+    ('\u{2900}', '\u{2AFF}', (3, 2)),
+    ('\u{2B00}', '\u{2B0D}', (4, 0)),
+    ('\u{2B0E}', '\u{2B13}', (4, 1)),
+    ('\u{2B14}', '\u{2B1A}', (5, 0)),
+    ('\u{2B1B}', '\u{2B1F}', (5, 1)),
+    ('\u{2B20}', '\u{2B23}', (5, 0)),
+    ('\u{2B24}', '\u{2B4C}', (5, 1)),
+    ('\u{2B4D}', '\u{2B4F}', (7, 0)),
+    ('\u{2B50}', '\u{2B54}', (5, 1)),
+    ('\u{2B55}', '\u{2B59}', (5, 2)),
+    ('\u{2B5A}', '\u{2B73}', (7, 0)),
+    ('\u{2B76}', '\u{2B95}', (7, 0)),
+    ('\u{2B97}', '\u{2B97}', (13, 0)),
+    ('\u{2B98}', '\u{2BB9}', (7, 0)),
+    ('\u{2BBA}', '\u{2BBC}', (11, 0)),
+    ('\u{2BBD}', '\u{2BC8}', (7, 0)),
+    ('\u{2BC9}', '\u{2BC9}', (12, 0)),
+    ('\u{2BCA}', '\u{2BD1}', (7, 0)),
+    ('\u{2BD2}', '\u{2BD2}', (10, 0)),
+    ('\u{2BD3}', '\u{2BEB}', (11, 0)),
+    ('\u{2BEC}', '\u{2BEF}', (8, 0)),
+    ('\u{2BF0}', '\u{2BFE}', (11, 0)),
+    ('\u{2BFF}', '\u{2BFF}', (12, 0)),
+    ('\u{2C00}', '\u{2C2E}', (4, 1)),
+    ('\u{2C2F}', '\u{2C2F}', (14, 0)),
+    ('\u{2C30}', '\u{2C5E}', (4, 1)),
+    ('\u{2C5F}', '\u{2C5F}', (14, 0)),
+    ('\u{2C60}', '\u{2C6C}', (5, 0)),
+    ('\u{2C6D}', '\u{2C6F}', (5, 1)),
+    ('\u{2C70}', '\u{2C70}', (5, 2)),
+    ('\u{2C71}', '\u{2C73}', (5, 1)),
+    ('\u{2C74}', '\u{2C77}', (5, 0)),
+    ('\u{2C78}', '\u{2C7D}', (5, 1)),
+    ('\u{2C7E}', '\u{2C7F}', (5, 2)),
+    ('\u{2C80}', '\u{2CEA}', (4, 1)),
+    ('\u{2CEB}', '\u{2CF1}', (5, 2)),
+    ('\u{2CF2}', '\u{2CF3}', (6, 1)),
+    ('\u{2CF9}', '\u{2D25}', (4, 1)),
+    ('\u{2D27}', '\u{2D27}', (6, 1)),
+    ('\u{2D2D}', '\u{2D2D}', (6, 1)),
+    ('\u{2D30}', '\u{2D65}', (4, 1)),
+    ('\u{2D66}', '\u{2D67}', (6, 1)),
+    ('\u{2D6F}', '\u{2D6F}', (4, 1)),
+    ('\u{2D70}', '\u{2D70}', (6, 0)),
+    ('\u{2D7F}', '\u{2D7F}', (6, 0)),
+    ('\u{2D80}', '\u{2D96}', (4, 1)),
+    ('\u{2DA0}', '\u{2DA6}', (4, 1)),
+    ('\u{2DA8}', '\u{2DAE}', (4, 1)),
+    ('\u{2DB0}', '\u{2DB6}', (4, 1)),
+    ('\u{2DB8}', '\u{2DBE}', (4, 1)),
+    ('\u{2DC0}', '\u{2DC6}', (4, 1)),
+    ('\u{2DC8}', '\u{2DCE}', (4, 1)),
+    ('\u{2DD0}', '\u{2DD6}', (4, 1)),
+    ('\u{2DD8}', '\u{2DDE}', (4, 1)),
+    ('\u{2DE0}', '\u{2DFF}', (5, 1)),
+    ('\u{2E00}', '\u{2E17}', (4, 1)),
+    ('\u{2E18}', '\u{2E1B}', (5, 1)),
+    ('\u{2E1C}', '\u{2E1D}', (4, 1)),
+    ('\u{2E1E}', '\u{2E30}', (5, 1)),
+    ('\u{2E31}', '\u{2E31}', (5, 2)),
+    ('\u{2E32}', '\u{2E3B}', (6, 1)),
+    ('\u{2E3C}', '\u{2E42}', (7, 0)),
+    ('\u{2E43}', '\u{2E44}', (9, 0)),
+    ('\u{2E45}', '\u{2E49}', (10, 0)),
+    ('\u{2E4A}', '\u{2E4E}', (11, 0)),
+    ('\u{2E4F}', '\u{2E4F}', (12, 0)),
+    ('\u{2E50}', '\u{2E52}', (13, 0)),
+    ('\u{2E53}', '\u{2E5D}', (14, 0)),
+    ('\u{2E80}', '\u{2E99}', (3, 0)),
+    ('\u{2E9B}', '\u{2EF3}', (3, 0)),
+    ('\u{2F00}', '\u{2FD5}', (3, 0)),
+    ('\u{2FF0}', '\u{2FFB}', (3, 0)),
+    ('\u{2FFC}', '\u{2FFF}', (15, 1)),
+    ('\u{3000}', '\u{3037}', (1, 1)),
+    ('\u{3038}', '\u{303A}', (3, 0)),
+    ('\u{303B}', '\u{303D}', (3, 2)),
+    ('\u{303E}', '\u{303E}', (3, 0)),
+    ('\u{303F}', '\u{303F}', (1, 1)),
+    ('\u{3041}', '\u{3094}', (1, 1)),
+    ('\u{3095}', '\u{3096}', (3, 2)),
+    ('\u{3099}', '\u{309E}', (1, 1)),
+    ('\u{309F}', '\u{30A0}', (3, 2)),
+    ('\u{30A1}', '\u{30FE}', (1, 1)),
+    ('\u{30FF}', '\u{30FF}', (3, 2)),
+    ('\u{3105}', '\u{312C}', (1, 1)),
+    ('\u{312D}', '\u{312D}', (5, 1)),
+    ('\u{312E}', '\u{312E}', (10, 0)),
+    ('\u{312F}', '\u{312F}', (11, 0)),
+    ('\u{3131}', '\u{318E}', (1, 1)),
+    ('\u{3190}', '\u{319F}', (1, 1)),
+    ('\u{31A0}', '\u{31B7}', (3, 0)),
+    ('\u{31B8}', '\u{31BA}', (6, 0)),
+    ('\u{31BB}', '\u{31BF}', (13, 0)),
+    ('\u{31C0}', '\u{31CF}', (4, 1)),
+    ('\u{31D0}', '\u{31E3}', (5, 1)),
+    ('\u{31E4}', '\u{31E5}', (16, 0)),
+    ('\u{31EF}', '\u{31EF}', (15, 1)),
+    ('\u{31F0}', '\u{31FF}', (3, 2)),
+    ('\u{3200}', '\u{321C}', (1, 1)),
+    ('\u{321D}', '\u{321E}', (4, 0)),
+    ('\u{3220}', '\u{3243}', (1, 1)),
+    ('\u{3244}', '\u{324F}', (5, 2)),
+    ('\u{3250}', '\u{3250}', (4, 0)),
+    ('\u{3251}', '\u{325F}', (3, 2)),
+    ('\u{3260}', '\u{327B}', (1, 1)),
+    ('\u{327C}', '\u{327D}', (4, 0)),
+    ('\u{327E}', '\u{327E}', (4, 1)),
+    ('\u{327F}', '\u{32B0}', (1, 1)),
+    ('\u{32B1}', '\u{32BF}', (3, 2)),
+    ('\u{32C0}', '\u{32CB}', (1, 1)),
+    ('\u{32CC}', '\u{32CF}', (4, 0)),
+    ('\u{32D0}', '\u{32FE}', (1, 1)),
+    ('\u{32FF}', '\u{32FF}', (12, 1)),
+    ('\u{3300}', '\u{3376}', (1, 1)),
+    ('\u{3377}', '\u{337A}', (4, 0)),
+    ('\u{337B}', '\u{33DD}', (1, 1)),
+    ('\u{33DE}', '\u{33DF}', (4, 0)),
+    ('\u{33E0}', '\u{33FE}', (1, 1)),
+    ('\u{33FF}', '\u{33FF}', (4, 0)),
+    ('\u{3400}', '\u{4DB5}', (3, 0)),
+    ('\u{4DB6}', '\u{4DBF}', (13, 0)),
+    ('\u{4DC0}', '\u{4DFF}', (4, 0)),
+    ('\u{4E00}', '\u{9FA5}', (1, 1)),
+    ('\u{9FA6}', '\u{9FBB}', (4, 1)),
+    ('\u{9FBC}', '\u{9FC3}', (5, 1)),
+    ('\u{9FC4}', '\u{9FCB}', (5, 2)),
+    ('\u{9FCC}', '\u{9FCC}', (6, 1)),
+    ('\u{9FCD}', '\u{9FD5}', (8, 0)),
+    ('\u{9FD6}', '\u{9FEA}', (10, 0)),
+    ('\u{9FEB}', '\u{9FEF}', (11, 0)),
+    ('\u{9FF0}', '\u{9FFC}', (13, 0)),
+    ('\u{9FFD}', '\u{9FFF}', (14, 0)),
+    ('\u{A000}', '\u{A48C}', (3, 0)),
+    ('\u{A490}', '\u{A4A1}', (3, 0)),
+    ('\u{A4A2}', '\u{A4A3}', (3, 2)),
+    ('\u{A4A4}', '\u{A4B3}', (3, 0)),
+    ('\u{A4B4}', '\u{A4B4}', (3, 2)),
+    ('\u{A4B5}', '\u{A4C0}', (3, 0)),
+    ('\u{A4C1}', '\u{A4C1}', (3, 2)),
+    ('\u{A4C2}', '\u{A4C4}', (3, 0)),
+    ('\u{A4C5}', '\u{A4C5}', (3, 2)),
+    ('\u{A4C6}', '\u{A4C6}', (3, 0)),
+    ('\u{A4D0}', '\u{A4FF}', (5, 2)),
+    ('\u{A500}', '\u{A62B}', (5, 1)),
+    ('\u{A640}', '\u{A65F}', (5, 1)),
+    ('\u{A660}', '\u{A661}', (6, 0)),
+    ('\u{A662}', '\u{A673}', (5, 1)),
+    ('\u{A674}', '\u{A67B}', (6, 1)),
+    ('\u{A67C}', '\u{A697}', (5, 1)),
+    ('\u{A698}', '\u{A69D}', (7, 0)),
+    ('\u{A69E}', '\u{A69E}', (8, 0)),
+    ('\u{A69F}', '\u{A69F}', (6, 1)),
+    ('\u{A6A0}', '\u{A6F7}', (5, 2)),
+    ('\u{A700}', '\u{A716}', (4, 1)),
+    ('\u{A717}', '\u{A71A}', (5, 0)),
+    ('\u{A71B}', '\u{A71F}', (5, 1)),
+    ('\u{A720}', '\u{A721}', (5, 0)),
+    ('\u{A722}', '\u{A78C}', (5, 1)),
+    ('\u{A78D}', '\u{A78E}', (6, 0)),
+    ('\u{A78F}', '\u{A78F}', (8, 0)),
+    ('\u{A790}', '\u{A791}', (6, 0)),
+    ('\u{A792}', '\u{A793}', (6, 1)),
+    ('\u{A794}', '\u{A79F}', (7, 0)),
+    ('\u{A7A0}', '\u{A7A9}', (6, 0)),
+    ('\u{A7AA}', '\u{A7AA}', (6, 1)),
+    ('\u{A7AB}', '\u{A7AD}', (7, 0)),
+    ('\u{A7AE}', '\u{A7AE}', (9, 0)),
+    ('\u{A7AF}', '\u{A7AF}', (11, 0)),
+    ('\u{A7B0}', '\u{A7B1}', (7, 0)),
+    ('\u{A7B2}', '\u{A7B7}', (8, 0)),
+    ('\u{A7B8}', '\u{A7B9}', (11, 0)),
+    ('\u{A7BA}', '\u{A7BF}', (12, 0)),
+    ('\u{A7C0}', '\u{A7C1}', (14, 0)),
+    ('\u{A7C2}', '\u{A7C6}', (12, 0)),
+    ('\u{A7C7}', '\u{A7CA}', (13, 0)),
+    ('\u{A7CB}', '\u{A7CD}', (16, 0)),
+    ('\u{A7D0}', '\u{A7D1}', (14, 0)),
+    ('\u{A7D3}', '\u{A7D3}', (14, 0)),
+    ('\u{A7D5}', '\u{A7D9}', (14, 0)),
+    ('\u{A7DA}', '\u{A7DC}', (16, 0)),
+    ('\u{A7F2}', '\u{A7F4}', (14, 0)),
+    ('\u{A7F5}', '\u{A7F6}', (13, 0)),
+    ('\u{A7F7}', '\u{A7F7}', (7, 0)),
+    ('\u{A7F8}', '\u{A7F9}', (6, 1)),
+    ('\u{A7FA}', '\u{A7FA}', (6, 0)),
+    ('\u{A7FB}', '\u{A7FF}', (5, 1)),
+    ('\u{A800}', '\u{A82B}', (4, 1)),
+    ('\u{A82C}', '\u{A82C}', (13, 0)),
+    ('\u{A830}', '\u{A839}', (5, 2)),
+    ('\u{A840}', '\u{A877}', (5, 0)),
+    ('\u{A880}', '\u{A8C4}', (5, 1)),
+    ('\u{A8C5}', '\u{A8C5}', (9, 0)),
+    ('\u{A8CE}', '\u{A8D9}', (5, 1)),
+    ('\u{A8E0}', '\u{A8FB}', (5, 2)),
+    ('\u{A8FC}', '\u{A8FD}', (8, 0)),
+    ('\u{A8FE}', '\u{A8FF}', (11, 0)),
+    ('\u{A900}', '\u{A953}', (5, 1)),
+    ('\u{A95F}', '\u{A95F}', (5, 1)),
+    ('\u{A960}', '\u{A97C}', (5, 2)),
+    ('\u{A980}', '\u{A9CD}', (5, 2)),
+    ('\u{A9CF}', '\u{A9D9}', (5, 2)),
+    ('\u{A9DE}', '\u{A9DF}', (5, 2)),
+    ('\u{A9E0}', '\u{A9FE}', (7, 0)),
+    ('\u{AA00}', '\u{AA36}', (5, 1)),
+    ('\u{AA40}', '\u{AA4D}', (5, 1)),
+    ('\u{AA50}', '\u{AA59}', (5, 1)),
+    ('\u{AA5C}', '\u{AA5F}', (5, 1)),
+    ('\u{AA60}', '\u{AA7B}', (5, 2)),
+    ('\u{AA7C}', '\u{AA7F}', (7, 0)),
+    ('\u{AA80}', '\u{AAC2}', (5, 2)),
+    ('\u{AADB}', '\u{AADF}', (5, 2)),
+    ('\u{AAE0}', '\u{AAF6}', (6, 1)),
+    ('\u{AB01}', '\u{AB06}', (6, 0)),
+    ('\u{AB09}', '\u{AB0E}', (6, 0)),
+    ('\u{AB11}', '\u{AB16}', (6, 0)),
+    ('\u{AB20}', '\u{AB26}', (6, 0)),
+    ('\u{AB28}', '\u{AB2E}', (6, 0)),
+    ('\u{AB30}', '\u{AB5F}', (7, 0)),
+    ('\u{AB60}', '\u{AB63}', (8, 0)),
+    ('\u{AB64}', '\u{AB65}', (7, 0)),
+    ('\u{AB66}', '\u{AB67}', (12, 0)),
+    ('\u{AB68}', '\u{AB6B}', (13, 0)),
+    ('\u{AB70}', '\u{ABBF}', (8, 0)),
+    ('\u{ABC0}', '\u{ABED}', (5, 2)),
+    ('\u{ABF0}', '\u{ABF9}', (5, 2)),
+    ('\u{AC00}', '\u{D7A3}', (2, 0)),
+    ('\u{D7B0}', '\u{D7C6}', (5, 2)),
+    ('\u{D7CB}', '\u{D7FB}', (5, 2)),
+    ('\u{E000}', '\u{FA2D}', (1, 1)),
+    ('\u{FA2E}', '\u{FA2F}', (6, 1)),
+    ('\u{FA30}', '\u{FA6A}', (3, 2)),
+    ('\u{FA6B}', '\u{FA6D}', (5, 2)),
+    ('\u{FA70}', '\u{FAD9}', (4, 1)),
+    ('\u{FB00}', '\u{FB06}', (1, 1)),
+    ('\u{FB13}', '\u{FB17}', (1, 1)),
+    ('\u{FB1D}', '\u{FB1D}', (3, 0)),
+    ('\u{FB1E}', '\u{FB36}', (1, 1)),
+    ('\u{FB38}', '\u{FB3C}', (1, 1)),
+    ('\u{FB3E}', '\u{FB3E}', (1, 1)),
+    ('\u{FB40}', '\u{FB41}', (1, 1)),
+    ('\u{FB43}', '\u{FB44}', (1, 1)),
+    ('\u{FB46}', '\u{FBB1}', (1, 1)),
+    ('\u{FBB2}', '\u{FBC1}', (6, 0)),
+    ('\u{FBC2}', '\u{FBC2}', (14, 0)),
+    ('\u{FBD3}', '\u{FD3F}', (1, 1)),
+    ('\u{FD40}', '\u{FD4F}', (14, 0)),
+    ('\u{FD50}', '\u{FD8F}', (1, 1)),
+    ('\u{FD92}', '\u{FDC7}', (1, 1)),
+    ('\u{FDCF}', '\u{FDCF}', (14, 0)),
+    ('\u{FDD0}', '\u{FDEF}', (3, 1)),
+    ('\u{FDF0}', '\u{FDFB}', (1, 1)),
+    ('\u{FDFC}', '\u{FDFC}', (3, 2)),
+    ('\u{FDFD}', '\u{FDFD}', (4, 0)),
+    ('\u{FDFE}', '\u{FDFF}', (14, 0)),
+    ('\u{FE00}', '\u{FE0F}', (3, 2)),
+    ('\u{FE10}', '\u{FE19}', (4, 1)),
+    ('\u{FE20}', '\u{FE23}', (1, 1)),
+    ('\u{FE24}', '\u{FE26}', (5, 1)),
+    ('\u{FE27}', '\u{FE2D}', (7, 0)),
+    ('\u{FE2E}', '\u{FE2F}', (8, 0)),
+    ('\u{FE30}', '\u{FE44}', (1, 1)),
+    ('\u{FE45}', '\u{FE46}', (3, 2)),
+    ('\u{FE47}', '\u{FE48}', (4, 0)),
+    ('\u{FE49}', '\u{FE52}', (1, 1)),
+    ('\u{FE54}', '\u{FE66}', (1, 1)),
+    ('\u{FE68}', '\u{FE6B}', (1, 1)),
+    ('\u{FE70}', '\u{FE72}', (1, 1)),
+    ('\u{FE73}', '\u{FE73}', (3, 2)),
+    ('\u{FE74}', '\u{FE74}', (1, 1)),
+    ('\u{FE76}', '\u{FEFC}', (1, 1)),
+    ('\u{FEFF}', '\u{FEFF}', (1, 1)),
+    ('\u{FF01}', '\u{FF5E}', (1, 1)),
+    ('\u{FF5F}', '\u{FF60}', (3, 2)),
+    ('\u{FF61}', '\u{FFBE}', (1, 1)),
+    ('\u{FFC2}', '\u{FFC7}', (1, 1)),
+    ('\u{FFCA}', '\u{FFCF}', (1, 1)),
+    ('\u{FFD2}', '\u{FFD7}', (1, 1)),
+    ('\u{FFDA}', '\u{FFDC}', (1, 1)),
+    ('\u{FFE0}', '\u{FFE6}', (1, 1)),
+    ('\u{FFE8}', '\u{FFEE}', (1, 1)),
+    ('\u{FFF9}', '\u{FFFB}', (3, 0)),
+    ('\u{FFFC}', '\u{FFFC}', (2, 1)),
+    ('\u{FFFD}', '\u{FFFF}', (1, 1)),
+    ('\u{10000}', '\u{1000B}', (4, 0)),
+    ('\u{1000D}', '\u{10026}', (4, 0)),
+    ('\u{10028}', '\u{1003A}', (4, 0)),
+    ('\u{1003C}', '\u{1003D}', (4, 0)),
+    ('\u{1003F}', '\u{1004D}', (4, 0)),
+    ('\u{10050}', '\u{1005D}', (4, 0)),
+    ('\u{10080}', '\u{100FA}', (4, 0)),
+    ('\u{10100}', '\u{10102}', (4, 0)),
+    ('\u{10107}', '\u{10133}', (4, 0)),
+    ('\u{10137}', '\u{1013F}', (4, 0)),
+    ('\u{10140}', '\u{1018A}', (4, 1)),
+    ('\u{1018B}', '\u{1018C}', (7, 0)),
+    ('\u{1018D}', '\u{1018E}', (9, 0)),
+    ('\u{10190}', '\u{1019B}', (5, 1)),
+    ('\u{1019C}', '\u{1019C}', (13, 0)),
+    ('\u{101A0}', '\u{101A0}', (7, 0)),
+    ('\u{101D0}', '\u{101FD}', (5, 1)),
+    ('\u{10280}', '\u{1029C}', (5, 1)),
+    ('\u{102A0}', '\u{102D0}', (5, 1)),
+    ('\u{102E0}', '\u{102FB}', (7, 0)),
+    ('\u{10300}', '\u{1031E}', (3, 1)),
+    ('\u{1031F}', '\u{1031F}', (7, 0)),
+    ('\u{10320}', '\u{10323}', (3, 1)),
+    ('\u{1032D}', '\u{1032F}', (10, 0)),
+    ('\u{10330}', '\u{1034A}', (3, 1)),
+    ('\u{10350}', '\u{1037A}', (7, 0)),
+    ('\u{10380}', '\u{1039D}', (4, 0)),
+    ('\u{1039F}', '\u{1039F}', (4, 0)),
+    ('\u{103A0}', '\u{103C3}', (4, 1)),
+    ('\u{103C8}', '\u{103D5}', (4, 1)),
+    ('\u{10400}', '\u{10425}', (3, 1)),
+    ('\u{10426}', '\u{10427}', (4, 0)),
+    ('\u{10428}', '\u{1044D}', (3, 1)),
+    ('\u{1044E}', '\u{1049D}', (4, 0)),
+    ('\u{104A0}', '\u{104A9}', (4, 0)),
+    ('\u{104B0}', '\u{104D3}', (9, 0)),
+    ('\u{104D8}', '\u{104FB}', (9, 0)),
+    ('\u{10500}', '\u{10527}', (7, 0)),
+    ('\u{10530}', '\u{10563}', (7, 0)),
+    ('\u{1056F}', '\u{1056F}', (7, 0)),
+    ('\u{10570}', '\u{1057A}', (14, 0)),
+    ('\u{1057C}', '\u{1058A}', (14, 0)),
+    ('\u{1058C}', '\u{10592}', (14, 0)),
+    ('\u{10594}', '\u{10595}', (14, 0)),
+    ('\u{10597}', '\u{105A1}', (14, 0)),
+    ('\u{105A3}', '\u{105B1}', (14, 0)),
+    ('\u{105B3}', '\u{105B9}', (14, 0)),
+    ('\u{105BB}', '\u{105BC}', (14, 0)),
+    ('\u{105C0}', '\u{105F3}', (16, 0)),
+    ('\u{10600}', '\u{10736}', (7, 0)),
+    ('\u{10740}', '\u{10755}', (7, 0)),
+    ('\u{10760}', '\u{10767}', (7, 0)),
+    ('\u{10780}', '\u{10785}', (14, 0)),
+    ('\u{10787}', '\u{107B0}', (14, 0)),
+    ('\u{107B2}', '\u{107BA}', (14, 0)),
+    ('\u{10800}', '\u{10805}', (4, 0)),
+    ('\u{10808}', '\u{10808}', (4, 0)),
+    ('\u{1080A}', '\u{10835}', (4, 0)),
+    ('\u{10837}', '\u{10838}', (4, 0)),
+    ('\u{1083C}', '\u{1083C}', (4, 0)),
+    ('\u{1083F}', '\u{1083F}', (4, 0)),
+    ('\u{10840}', '\u{10855}', (5, 2)),
+    ('\u{10857}', '\u{1085F}', (5, 2)),
+    ('\u{10860}', '\u{1089E}', (7, 0)),
+    ('\u{108A7}', '\u{108AF}', (7, 0)),
+    ('\u{108E0}', '\u{108F2}', (8, 0)),
+    ('\u{108F4}', '\u{108F5}', (8, 0)),
+    ('\u{108FB}', '\u{108FF}', (8, 0)),
+    ('\u{10900}', '\u{10919}', (5, 0)),
+    ('\u{1091A}', '\u{1091B}', (5, 2)),
+    ('\u{1091F}', '\u{1091F}', (5, 0)),
+    ('\u{10920}', '\u{10939}', (5, 1)),
+    ('\u{1093F}', '\u{1093F}', (5, 1)),
+    ('\u{10980}', '\u{109B7}', (6, 1)),
+    ('\u{109BC}', '\u{109BD}', (8, 0)),
+    ('\u{109BE}', '\u{109BF}', (6, 1)),
+    ('\u{109C0}', '\u{109CF}', (8, 0)),
+    ('\u{109D2}', '\u{109FF}', (8, 0)),
+    ('\u{10A00}', '\u{10A03}', (4, 1)),
+    ('\u{10A05}', '\u{10A06}', (4, 1)),
+    ('\u{10A0C}', '\u{10A13}', (4, 1)),
+    ('\u{10A15}', '\u{10A17}', (4, 1)),
+    ('\u{10A19}', '\u{10A33}', (4, 1)),
+    ('\u{10A34}', '\u{10A35}', (11, 0)),
+    ('\u{10A38}', '\u{10A3A}', (4, 1)),
+    ('\u{10A3F}', '\u{10A47}', (4, 1)),
+    ('\u{10A48}', '\u{10A48}', (11, 0)),
+    ('\u{10A50}', '\u{10A58}', (4, 1)),
+    ('\u{10A60}', '\u{10A7F}', (5, 2)),
+    ('\u{10A80}', '\u{10A9F}', (7, 0)),
+    ('\u{10AC0}', '\u{10AE6}', (7, 0)),
+    ('\u{10AEB}', '\u{10AF6}', (7, 0)),
+    ('\u{10B00}', '\u{10B35}', (5, 2)),
+    ('\u{10B39}', '\u{10B55}', (5, 2)),
+    ('\u{10B58}', '\u{10B72}', (5, 2)),
+    ('\u{10B78}', '\u{10B7F}', (5, 2)),
+    ('\u{10B80}', '\u{10B91}', (7, 0)),
+    ('\u{10B99}', '\u{10B9C}', (7, 0)),
+    ('\u{10BA9}', '\u{10BAF}', (7, 0)),
+    ('\u{10C00}', '\u{10C48}', (5, 2)),
+    ('\u{10C80}', '\u{10CB2}', (8, 0)),
+    ('\u{10CC0}', '\u{10CF2}', (8, 0)),
+    ('\u{10CFA}', '\u{10CFF}', (8, 0)),
+    ('\u{10D00}', '\u{10D27}', (11, 0)),
+    ('\u{10D30}', '\u{10D39}', (11, 0)),
+    ('\u{10D40}', '\u{10D65}', (16, 0)),
+    ('\u{10D69}', '\u{10D85}', (16, 0)),
+    ('\u{10D8E}', '\u{10D8F}', (16, 0)),
+    ('\u{10E60}', '\u{10E7E}', (5, 2)),
+    ('\u{10E80}', '\u{10EA9}', (13, 0)),
+    ('\u{10EAB}', '\u{10EAD}', (13, 0)),
+    ('\u{10EB0}', '\u{10EB1}', (13, 0)),
+    ('\u{10EC2}', '\u{10EC4}', (16, 0)),
+    ('\u{10EFC}', '\u{10EFC}', (16, 0)),
+    ('\u{10EFD}', '\u{10EFF}', (15, 0)),
+    ('\u{10F00}', '\u{10F27}', (11, 0)),
+    ('\u{10F30}', '\u{10F59}', (11, 0)),
+    ('\u{10F70}', '\u{10F89}', (14, 0)),
+    ('\u{10FB0}', '\u{10FCB}', (13, 0)),
+    ('\u{10FE0}', '\u{10FF6}', (12, 0)),
+    ('\u{11000}', '\u{1104D}', (6, 0)),
+    ('\u{11052}', '\u{1106F}', (6, 0)),
+    ('\u{11070}', '\u{11075}', (14, 0)),
+    ('\u{1107F}', '\u{1107F}', (7, 0)),
+    ('\u{11080}', '\u{110C1}', (5, 2)),
+    ('\u{110C2}', '\u{110C2}', (14, 0)),
+    ('\u{110CD}', '\u{110CD}', (11, 0)),
+    ('\u{110D0}', '\u{110E8}', (6, 1)),
+    ('\u{110F0}', '\u{110F9}', (6, 1)),
+    ('\u{11100}', '\u{11134}', (6, 1)),
+    ('\u{11136}', '\u{11143}', (6, 1)),
+    ('\u{11144}', '\u{11146}', (11, 0)),
+    ('\u{11147}', '\u{11147}', (13, 0)),
+    ('\u{11150}', '\u{11176}', (7, 0)),
+    ('\u{11180}', '\u{111C8}', (6, 1)),
+    ('\u{111C9}', '\u{111CC}', (8, 0)),
+    ('\u{111CD}', '\u{111CD}', (7, 0)),
+    ('\u{111CE}', '\u{111CF}', (13, 0)),
+    ('\u{111D0}', '\u{111D9}', (6, 1)),
+    ('\u{111DA}', '\u{111DA}', (7, 0)),
+    ('\u{111DB}', '\u{111DF}', (8, 0)),
+    ('\u{111E1}', '\u{111F4}', (7, 0)),
+    ('\u{11200}', '\u{11211}', (7, 0)),
+    ('\u{11213}', '\u{1123D}', (7, 0)),
+    ('\u{1123E}', '\u{1123E}', (9, 0)),
+    ('\u{1123F}', '\u{11241}', (15, 0)),
+    ('\u{11280}', '\u{11286}', (8, 0)),
+    ('\u{11288}', '\u{11288}', (8, 0)),
+    ('\u{1128A}', '\u{1128D}', (8, 0)),
+    ('\u{1128F}', '\u{1129D}', (8, 0)),
+    ('\u{1129F}', '\u{112A9}', (8, 0)),
+    ('\u{112B0}', '\u{112EA}', (7, 0)),
+    ('\u{112F0}', '\u{112F9}', (7, 0)),
+    ('\u{11300}', '\u{11300}', (8, 0)),
+    ('\u{11301}', '\u{11303}', (7, 0)),
+    ('\u{11305}', '\u{1130C}', (7, 0)),
+    ('\u{1130F}', '\u{11310}', (7, 0)),
+    ('\u{11313}', '\u{11328}', (7, 0)),
+    ('\u{1132A}', '\u{11330}', (7, 0)),
+    ('\u{11332}', '\u{11333}', (7, 0)),
+    ('\u{11335}', '\u{11339}', (7, 0)),
+    ('\u{1133B}', '\u{1133B}', (11, 0)),
+    ('\u{1133C}', '\u{11344}', (7, 0)),
+    ('\u{11347}', '\u{11348}', (7, 0)),
+    ('\u{1134B}', '\u{1134D}', (7, 0)),
+    ('\u{11350}', '\u{11350}', (8, 0)),
+    ('\u{11357}', '\u{11357}', (7, 0)),
+    ('\u{1135D}', '\u{11363}', (7, 0)),
+    ('\u{11366}', '\u{1136C}', (7, 0)),
+    ('\u{11370}', '\u{11374}', (7, 0)),
+    ('\u{11380}', '\u{11389}', (16, 0)),
+    ('\u{1138B}', '\u{1138B}', (16, 0)),
+    ('\u{1138E}', '\u{1138E}', (16, 0)),
+    ('\u{11390}', '\u{113B5}', (16, 0)),
+    ('\u{113B7}', '\u{113C0}', (16, 0)),
+    ('\u{113C2}', '\u{113C2}', (16, 0)),
+    ('\u{113C5}', '\u{113C5}', (16, 0)),
+    ('\u{113C7}', '\u{113CA}', (16, 0)),
+    ('\u{113CC}', '\u{113D5}', (16, 0)),
+    ('\u{113D7}', '\u{113D8}', (16, 0)),
+    ('\u{113E1}', '\u{113E2}', (16, 0)),
+    ('\u{11400}', '\u{11459}', (9, 0)),
+    ('\u{1145A}', '\u{1145A}', (13, 0)),
+    ('\u{1145B}', '\u{1145B}', (9, 0)),
+    ('\u{1145D}', '\u{1145D}', (9, 0)),
+    ('\u{1145E}', '\u{1145E}', (11, 0)),
+    ('\u{1145F}', '\u{1145F}', (12, 0)),
+    ('\u{11460}', '\u{11461}', (13, 0)),
+    ('\u{11480}', '\u{114C7}', (7, 0)),
+    ('\u{114D0}', '\u{114D9}', (7, 0)),
+    ('\u{11580}', '\u{115B5}', (7, 0)),
+    ('\u{115B8}', '\u{115C9}', (7, 0)),
+    ('\u{115CA}', '\u{115DD}', (8, 0)),
+    ('\u{11600}', '\u{11644}', (7, 0)),
+    ('\u{11650}', '\u{11659}', (7, 0)),
+    ('\u{11660}', '\u{1166C}', (9, 0)),
+    ('\u{11680}', '\u{116B7}', (6, 1)),
+    ('\u{116B8}', '\u{116B8}', (12, 0)),
+    ('\u{116B9}', '\u{116B9}', (14, 0)),
+    ('\u{116C0}', '\u{116C9}', (6, 1)),
+    ('\u{116D0}', '\u{116E3}', (16, 0)),
+    ('\u{11700}', '\u{11719}', (8, 0)),
+    ('\u{1171A}', '\u{1171A}', (11, 0)),
+    ('\u{1171D}', '\u{1172B}', (8, 0)),
+    ('\u{11730}', '\u{1173F}', (8, 0)),
+    ('\u{11740}', '\u{11746}', (14, 0)),
+    ('\u{11800}', '\u{1183B}', (11, 0)),
+    ('\u{118A0}', '\u{118F2}', (7, 0)),
+    ('\u{118FF}', '\u{118FF}', (7, 0)),
+    ('\u{11900}', '\u{11906}', (13, 0)),
+    ('\u{11909}', '\u{11909}', (13, 0)),
+    ('\u{1190C}', '\u{11913}', (13, 0)),
+    ('\u{11915}', '\u{11916}', (13, 0)),
+    ('\u{11918}', '\u{11935}', (13, 0)),
+    ('\u{11937}', '\u{11938}', (13, 0)),
+    ('\u{1193B}', '\u{11946}', (13, 0)),
+    ('\u{11950}', '\u{11959}', (13, 0)),
+    ('\u{119A0}', '\u{119A7}', (12, 0)),
+    ('\u{119AA}', '\u{119D7}', (12, 0)),
+    ('\u{119DA}', '\u{119E4}', (12, 0)),
+    ('\u{11A00}', '\u{11A47}', (10, 0)),
+    ('\u{11A50}', '\u{11A83}', (10, 0)),
+    ('\u{11A84}', '\u{11A85}', (12, 0)),
+    ('\u{11A86}', '\u{11A9C}', (10, 0)),
+    ('\u{11A9D}', '\u{11A9D}', (11, 0)),
+    ('\u{11A9E}', '\u{11AA2}', (10, 0)),
+    ('\u{11AB0}', '\u{11ABF}', (14, 0)),
+    ('\u{11AC0}', '\u{11AF8}', (7, 0)),
+    ('\u{11B00}', '\u{11B09}', (15, 0)),
+    ('\u{11BC0}', '\u{11BE1}', (16, 0)),
+    ('\u{11BF0}', '\u{11BF9}', (16, 0)),
+    ('\u{11C00}', '\u{11C08}', (9, 0)),
+    ('\u{11C0A}', '\u{11C36}', (9, 0)),
+    ('\u{11C38}', '\u{11C45}', (9, 0)),
+    ('\u{11C50}', '\u{11C6C}', (9, 0)),
+    ('\u{11C70}', '\u{11C8F}', (9, 0)),
+    ('\u{11C92}', '\u{11CA7}', (9, 0)),
+    ('\u{11CA9}', '\u{11CB6}', (9, 0)),
+    ('\u{11D00}', '\u{11D06}', (10, 0)),
+    ('\u{11D08}', '\u{11D09}', (10, 0)),
+    ('\u{11D0B}', '\u{11D36}', (10, 0)),
+    ('\u{11D3A}', '\u{11D3A}', (10, 0)),
+    ('\u{11D3C}', '\u{11D3D}', (10, 0)),
+    ('\u{11D3F}', '\u{11D47}', (10, 0)),
+    ('\u{11D50}', '\u{11D59}', (10, 0)),
+    ('\u{11D60}', '\u{11D65}', (11, 0)),
+    ('\u{11D67}', '\u{11D68}', (11, 0)),
+    ('\u{11D6A}', '\u{11D8E}', (11, 0)),
+    ('\u{11D90}', '\u{11D91}', (11, 0)),
+    ('\u{11D93}', '\u{11D98}', (11, 0)),
+    ('\u{11DA0}', '\u{11DA9}', (11, 0)),
+    ('\u{11EE0}', '\u{11EF8}', (11, 0)),
+    ('\u{11F00}', '\u{11F10}', (15, 0)),
+    ('\u{11F12}', '\u{11F3A}', (15, 0)),
+    ('\u{11F3E}', '\u{11F59}', (15, 0)),
+    ('\u{11F5A}', '\u{11F5A}', (16, 0)),
+    ('\u{11FB0}', '\u{11FB0}', (13, 0)),
+    ('\u{11FC0}', '\u{11FF1}', (12, 0)),
+    ('\u{11FFF}', '\u{11FFF}', (12, 0)),
+    ('\u{12000}', '\u{1236E}', (5, 0)),
+    ('\u{1236F}', '\u{12398}', (7, 0)),
+    ('\u{12399}', '\u{12399}', (8, 0)),
+    ('\u{12400}', '\u{12462}', (5, 0)),
+    ('\u{12463}', '\u{1246E}', (7, 0)),
+    ('\u{12470}', '\u{12473}', (5, 0)),
+    ('\u{12474}', '\u{12474}', (7, 0)),
+    ('\u{12480}', '\u{12543}', (8, 0)),
+    ('\u{12F90}', '\u{12FF2}', (14, 0)),
+    ('\u{13000}', '\u{1342E}', (5, 2)),
+    ('\u{1342F}', '\u{1342F}', (15, 0)),
+    ('\u{13430}', '\u{13438}', (12, 0)),
+    ('\u{13439}', '\u{13455}', (15, 0)),
+    ('\u{13460}', '\u{143FA}', (16, 0)),
+    ('\u{14400}', '\u{14646}', (8, 0)),
+    ('\u{16100}', '\u{16139}', (16, 0)),
+    ('\u{16800}', '\u{16A38}', (6, 0)),
+    ('\u{16A40}', '\u{16A5E}', (7, 0)),
+    ('\u{16A60}', '\u{16A69}', (7, 0)),
+    ('\u{16A6E}', '\u{16A6F}', (7, 0)),
+    ('\u{16A70}', '\u{16ABE}', (14, 0)),
+    ('\u{16AC0}', '\u{16AC9}', (14, 0)),
+    ('\u{16AD0}', '\u{16AED}', (7, 0)),
+    ('\u{16AF0}', '\u{16AF5}', (7, 0)),
+    ('\u{16B00}', '\u{16B45}', (7, 0)),
+    ('\u{16B50}', '\u{16B59}', (7, 0)),
+    ('\u{16B5B}', '\u{16B61}', (7, 0)),
+    ('\u{16B63}', '\u{16B77}', (7, 0)),
+    ('\u{16B7D}', '\u{16B8F}', (7, 0)),
+    ('\u{16D40}', '\u{16D79}', (16, 0)),
+    ('\u{16E40}', '\u{16E9A}', (11, 0)),
+    ('\u{16F00}', '\u{16F44}', (6, 1)),
+    ('\u{16F45}', '\u{16F4A}', (12, 0)),
+    ('\u{16F4F}', '\u{16F4F}', (12, 0)),
+    ('\u{16F50}', '\u{16F7E}', (6, 1)),
+    ('\u{16F7F}', '\u{16F87}', (12, 0)),
+    ('\u{16F8F}', '\u{16F9F}', (6, 1)),
+    ('\u{16FE0}', '\u{16FE0}', (9, 0)),
+    ('\u{16FE1}', '\u{16FE1}', (10, 0)),
+    ('\u{16FE2}', '\u{16FE3}', (12, 0)),
+    ('\u{16FE4}', '\u{16FE4}', (13, 0)),
+    ('\u{16FF0}', '\u{16FF1}', (13, 0)),
+    ('\u{17000}', '\u{187EC}', (9, 0)),
+    ('\u{187ED}', '\u{187F1}', (11, 0)),
+    ('\u{187F2}', '\u{187F7}', (12, 0)),
+    ('\u{18800}', '\u{18AF2}', (9, 0)),
+    ('\u{18AF3}', '\u{18CD5}', (13, 0)),
+    ('\u{18CFF}', '\u{18CFF}', (16, 0)),
+    ('\u{18D00}', '\u{18D08}', (13, 0)),
+    ('\u{1AFF0}', '\u{1AFF3}', (14, 0)),
+    ('\u{1AFF5}', '\u{1AFFB}', (14, 0)),
+    ('\u{1AFFD}', '\u{1AFFE}', (14, 0)),
+    ('\u{1B000}', '\u{1B001}', (6, 0)),
+    ('\u{1B002}', '\u{1B11E}', (10, 0)),
+    ('\u{1B11F}', '\u{1B122}', (14, 0)),
+    ('\u{1B132}', '\u{1B132}', (15, 0)),
+    ('\u{1B150}', '\u{1B152}', (12, 0)),
+    ('\u{1B155}', '\u{1B155}', (15, 0)),
+    ('\u{1B164}', '\u{1B167}', (12, 0)),
+    ('\u{1B170}', '\u{1B2FB}', (10, 0)),
+    ('\u{1BC00}', '\u{1BC6A}', (7, 0)),
+    ('\u{1BC70}', '\u{1BC7C}', (7, 0)),
+    ('\u{1BC80}', '\u{1BC88}', (7, 0)),
+    ('\u{1BC90}', '\u{1BC99}', (7, 0)),
+    ('\u{1BC9C}', '\u{1BCA3}', (7, 0)),
+    ('\u{1CC00}', '\u{1CCF9}', (16, 0)),
+    ('\u{1CD00}', '\u{1CEB3}', (16, 0)),
+    ('\u{1CF00}', '\u{1CF2D}', (14, 0)),
+    ('\u{1CF30}', '\u{1CF46}', (14, 0)),
+    ('\u{1CF50}', '\u{1CFC3}', (14, 0)),
+    ('\u{1D000}', '\u{1D0F5}', (3, 1)),
+    ('\u{1D100}', '\u{1D126}', (3, 1)),
+    ('\u{1D129}', '\u{1D129}', (5, 1)),
+    ('\u{1D12A}', '\u{1D1DD}', (3, 1)),
+    ('\u{1D1DE}', '\u{1D1E8}', (8, 0)),
+    ('\u{1D1E9}', '\u{1D1EA}', (14, 0)),
+    ('\u{1D200}', '\u{1D245}', (4, 1)),
+    ('\u{1D2C0}', '\u{1D2D3}', (15, 0)),
+    ('\u{1D2E0}', '\u{1D2F3}', (11, 0)),
+    ('\u{1D300}', '\u{1D356}', (4, 0)),
+    ('\u{1D360}', '\u{1D371}', (5, 0)),
+    ('\u{1D372}', '\u{1D378}', (11, 0)),
+    ('\u{1D400}', '\u{1D454}', (3, 1)),
+    ('\u{1D456}', '\u{1D49C}', (3, 1)),
+    ('\u{1D49E}', '\u{1D49F}', (3, 1)),
+    ('\u{1D4A2}', '\u{1D4A2}', (3, 1)),
+    ('\u{1D4A5}', '\u{1D4A6}', (3, 1)),
+    ('\u{1D4A9}', '\u{1D4AC}', (3, 1)),
+    ('\u{1D4AE}', '\u{1D4B9}', (3, 1)),
+    ('\u{1D4BB}', '\u{1D4BB}', (3, 1)),
+    ('\u{1D4BD}', '\u{1D4C0}', (3, 1)),
+    ('\u{1D4C1}', '\u{1D4C1}', (4, 0)),
+    ('\u{1D4C2}', '\u{1D4C3}', (3, 1)),
+    ('\u{1D4C5}', '\u{1D505}', (3, 1)),
+    ('\u{1D507}', '\u{1D50A}', (3, 1)),
+    ('\u{1D50D}', '\u{1D514}', (3, 1)),
+    ('\u{1D516}', '\u{1D51C}', (3, 1)),
+    ('\u{1D51E}', '\u{1D539}', (3, 1)),
+    ('\u{1D53B}', '\u{1D53E}', (3, 1)),
+    ('\u{1D540}', '\u{1D544}', (3, 1)),
+    ('\u{1D546}', '\u{1D546}', (3, 1)),
+    ('\u{1D54A}', '\u{1D550}', (3, 1)),
+    ('\u{1D552}', '\u{1D6A3}', (3, 1)),
+    ('\u{1D6A4}', '\u{1D6A5}', (4, 1)),
+    ('\u{1D6A8}', '\u{1D7C9}', (3, 1)),
+    ('\u{1D7CA}', '\u{1D7CB}', (5, 0)),
+    ('\u{1D7CE}', '\u{1D7FF}', (3, 1)),
+    ('\u{1D800}', '\u{1DA8B}', (8, 0)),
+    ('\u{1DA9B}', '\u{1DA9F}', (8, 0)),
+    ('\u{1DAA1}', '\u{1DAAF}', (8, 0)),
+    ('\u{1DF00}', '\u{1DF1E}', (14, 0)),
+    ('\u{1DF25}', '\u{1DF2A}', (15, 0)),
+    ('\u{1E000}', '\u{1E006}', (9, 0)),
+    ('\u{1E008}', '\u{1E018}', (9, 0)),
+    ('\u{1E01B}', '\u{1E021}', (9, 0)),
+    ('\u{1E023}', '\u{1E024}', (9, 0)),
+    ('\u{1E026}', '\u{1E02A}', (9, 0)),
+    ('\u{1E030}', '\u{1E06D}', (15, 0)),
+    ('\u{1E08F}', '\u{1E08F}', (15, 0)),
+    ('\u{1E100}', '\u{1E12C}', (12, 0)),
+    ('\u{1E130}', '\u{1E13D}', (12, 0)),
+    ('\u{1E140}', '\u{1E149}', (12, 0)),
+    ('\u{1E14E}', '\u{1E14F}', (12, 0)),
+    ('\u{1E290}', '\u{1E2AE}', (14, 0)),
+    ('\u{1E2C0}', '\u{1E2F9}', (12, 0)),
+    ('\u{1E2FF}', '\u{1E2FF}', (12, 0)),
+    ('\u{1E4D0}', '\u{1E4F9}', (15, 0)),
+    ('\u{1E5D0}', '\u{1E5FA}', (16, 0)),
+    ('\u{1E5FF}', '\u{1E5FF}', (16, 0)),
+    ('\u{1E7E0}', '\u{1E7E6}', (14, 0)),
+    ('\u{1E7E8}', '\u{1E7EB}', (14, 0)),
+    ('\u{1E7ED}', '\u{1E7EE}', (14, 0)),
+    ('\u{1E7F0}', '\u{1E7FE}', (14, 0)),
+    ('\u{1E800}', '\u{1E8C4}', (7, 0)),
+    ('\u{1E8C7}', '\u{1E8D6}', (7, 0)),
+    ('\u{1E900}', '\u{1E94A}', (9, 0)),
+    ('\u{1E94B}', '\u{1E94B}', (12, 0)),
+    ('\u{1E950}', '\u{1E959}', (9, 0)),
+    ('\u{1E95E}', '\u{1E95F}', (9, 0)),
+    ('\u{1EC71}', '\u{1ECB4}', (11, 0)),
+    ('\u{1ED01}', '\u{1ED3D}', (12, 0)),
+    ('\u{1EE00}', '\u{1EE03}', (6, 1)),
+    ('\u{1EE05}', '\u{1EE1F}', (6, 1)),
+    ('\u{1EE21}', '\u{1EE22}', (6, 1)),
+    ('\u{1EE24}', '\u{1EE24}', (6, 1)),
+    ('\u{1EE27}', '\u{1EE27}', (6, 1)),
+    ('\u{1EE29}', '\u{1EE32}', (6, 1)),
+    ('\u{1EE34}', '\u{1EE37}', (6, 1)),
+    ('\u{1EE39}', '\u{1EE39}', (6, 1)),
+    ('\u{1EE3B}', '\u{1EE3B}', (6, 1)),
+    ('\u{1EE42}', '\u{1EE42}', (6, 1)),
+    ('\u{1EE47}', '\u{1EE47}', (6, 1)),
+    ('\u{1EE49}', '\u{1EE49}', (6, 1)),
+    ('\u{1EE4B}', '\u{1EE4B}', (6, 1)),
+    ('\u{1EE4D}', '\u{1EE4F}', (6, 1)),
+    ('\u{1EE51}', '\u{1EE52}', (6, 1)),
+    ('\u{1EE54}', '\u{1EE54}', (6, 1)),
+    ('\u{1EE57}', '\u{1EE57}', (6, 1)),
+    ('\u{1EE59}', '\u{1EE59}', (6, 1)),
+    ('\u{1EE5B}', '\u{1EE5B}', (6, 1)),
+    ('\u{1EE5D}', '\u{1EE5D}', (6, 1)),
+    ('\u{1EE5F}', '\u{1EE5F}', (6, 1)),
+    ('\u{1EE61}', '\u{1EE62}', (6, 1)),
+    ('\u{1EE64}', '\u{1EE64}', (6, 1)),
+    ('\u{1EE67}', '\u{1EE6A}', (6, 1)),
+    ('\u{1EE6C}', '\u{1EE72}', (6, 1)),
+    ('\u{1EE74}', '\u{1EE77}', (6, 1)),
+    ('\u{1EE79}', '\u{1EE7C}', (6, 1)),
+    ('\u{1EE7E}', '\u{1EE7E}', (6, 1)),
+    ('\u{1EE80}', '\u{1EE89}', (6, 1)),
+    ('\u{1EE8B}', '\u{1EE9B}', (6, 1)),
+    ('\u{1EEA1}', '\u{1EEA3}', (6, 1)),
+    ('\u{1EEA5}', '\u{1EEA9}', (6, 1)),
+    ('\u{1EEAB}', '\u{1EEBB}', (6, 1)),
+    ('\u{1EEF0}', '\u{1EEF1}', (6, 1)),
+    ('\u{1F000}', '\u{1F02B}', (5, 1)),
+    ('\u{1F030}', '\u{1F093}', (5, 1)),
+    ('\u{1F0A0}', '\u{1F0AE}', (6, 0)),
+    ('\u{1F0B1}', '\u{1F0BE}', (6, 0)),
+    ('\u{1F0BF}', '\u{1F0BF}', (7, 0)),
+    ('\u{1F0C1}', '\u{1F0CF}', (6, 0)),
+    ('\u{1F0D1}', '\u{1F0DF}', (6, 0)),
+    ('\u{1F0E0}', '\u{1F0F5}', (7, 0)),
+    ('\u{1F100}', '\u{1F10A}', (5, 2)),
+    ('\u{1F10B}', '\u{1F10C}', (7, 0)),
+    ('\u{1F10D}', '\u{1F10F}', (13, 0)),
+    ('\u{1F110}', '\u{1F12E}', (5, 2)),
+    ('\u{1F12F}', '\u{1F12F}', (11, 0)),
+    ('\u{1F130}', '\u{1F130}', (6, 0)),
+    ('\u{1F131}', '\u{1F131}', (5, 2)),
+    ('\u{1F132}', '\u{1F13C}', (6, 0)),
+    ('\u{1F13D}', '\u{1F13D}', (5, 2)),
+    ('\u{1F13E}', '\u{1F13E}', (6, 0)),
+    ('\u{1F13F}', '\u{1F13F}', (5, 2)),
+    ('\u{1F140}', '\u{1F141}', (6, 0)),
+    ('\u{1F142}', '\u{1F142}', (5, 2)),
+    ('\u{1F143}', '\u{1F145}', (6, 0)),
+    ('\u{1F146}', '\u{1F146}', (5, 2)),
+    ('\u{1F147}', '\u{1F149}', (6, 0)),
+    ('\u{1F14A}', '\u{1F14E}', (5, 2)),
+    ('\u{1F14F}', '\u{1F156}', (6, 0)),
+    ('\u{1F157}', '\u{1F157}', (5, 2)),
+    ('\u{1F158}', '\u{1F15E}', (6, 0)),
+    ('\u{1F15F}', '\u{1F15F}', (5, 2)),
+    ('\u{1F160}', '\u{1F169}', (6, 0)),
+    ('\u{1F16A}', '\u{1F16B}', (6, 1)),
+    ('\u{1F16C}', '\u{1F16C}', (12, 0)),
+    ('\u{1F16D}', '\u{1F16F}', (13, 0)),
+    ('\u{1F170}', '\u{1F178}', (6, 0)),
+    ('\u{1F179}', '\u{1F179}', (5, 2)),
+    ('\u{1F17A}', '\u{1F17A}', (6, 0)),
+    ('\u{1F17B}', '\u{1F17C}', (5, 2)),
+    ('\u{1F17D}', '\u{1F17E}', (6, 0)),
+    ('\u{1F17F}', '\u{1F17F}', (5, 2)),
+    ('\u{1F180}', '\u{1F189}', (6, 0)),
+    ('\u{1F18A}', '\u{1F18D}', (5, 2)),
+    ('\u{1F18E}', '\u{1F18F}', (6, 0)),
+    ('\u{1F190}', '\u{1F190}', (5, 2)),
+    ('\u{1F191}', '\u{1F19A}', (6, 0)),
+    ('\u{1F19B}', '\u{1F1AC}', (9, 0)),
+    ('\u{1F1AD}', '\u{1F1AD}', (13, 0)),
+    ('\u{1F1E6}', '\u{1F1FF}', (6, 0)),
+    ('\u{1F200}', '\u{1F200}', (5, 2)),
+    ('\u{1F201}', '\u{1F202}', (6, 0)),
+    ('\u{1F210}', '\u{1F231}', (5, 2)),
+    ('\u{1F232}', '\u{1F23A}', (6, 0)),
+    ('\u{1F23B}', '\u{1F23B}', (9, 0)),
+    ('\u{1F240}', '\u{1F248}', (5, 2)),
+    ('\u{1F250}', '\u{1F251}', (6, 0)),
+    ('\u{1F260}', '\u{1F265}', (10, 0)),
+    ('\u{1F300}', '\u{1F320}', (6, 0)),
+    ('\u{1F321}', '\u{1F32C}', (7, 0)),
+    ('\u{1F32D}', '\u{1F32F}', (8, 0)),
+    ('\u{1F330}', '\u{1F335}', (6, 0)),
+    ('\u{1F336}', '\u{1F336}', (7, 0)),
+    ('\u{1F337}', '\u{1F37C}', (6, 0)),
+    ('\u{1F37D}', '\u{1F37D}', (7, 0)),
+    ('\u{1F37E}', '\u{1F37F}', (8, 0)),
+    ('\u{1F380}', '\u{1F393}', (6, 0)),
+    ('\u{1F394}', '\u{1F39F}', (7, 0)),
+    ('\u{1F3A0}', '\u{1F3C4}', (6, 0)),
+    ('\u{1F3C5}', '\u{1F3C5}', (7, 0)),
+    ('\u{1F3C6}', '\u{1F3CA}', (6, 0)),
+    ('\u{1F3CB}', '\u{1F3CE}', (7, 0)),
+    ('\u{1F3CF}', '\u{1F3D3}', (8, 0)),
+    ('\u{1F3D4}', '\u{1F3DF}', (7, 0)),
+    ('\u{1F3E0}', '\u{1F3F0}', (6, 0)),
+    ('\u{1F3F1}', '\u{1F3F7}', (7, 0)),
+    ('\u{1F3F8}', '\u{1F3FF}', (8, 0)),
+    ('\u{1F400}', '\u{1F43E}', (6, 0)),
+    ('\u{1F43F}', '\u{1F43F}', (7, 0)),
+    ('\u{1F440}', '\u{1F440}', (6, 0)),
+    ('\u{1F441}', '\u{1F441}', (7, 0)),
+    ('\u{1F442}', '\u{1F4F7}', (6, 0)),
+    ('\u{1F4F8}', '\u{1F4F8}', (7, 0)),
+    ('\u{1F4F9}', '\u{1F4FC}', (6, 0)),
+    ('\u{1F4FD}', '\u{1F4FE}', (7, 0)),
+    ('\u{1F4FF}', '\u{1F4FF}', (8, 0)),
+    ('\u{1F500}', '\u{1F53D}', (6, 0)),
+    ('\u{1F53E}', '\u{1F53F}', (7, 0)),
+    ('\u{1F540}', '\u{1F543}', (6, 1)),
+    ('\u{1F544}', '\u{1F54A}', (7, 0)),
+    ('\u{1F54B}', '\u{1F54F}', (8, 0)),
+    ('\u{1F550}', '\u{1F567}', (6, 0)),
+    ('\u{1F568}', '\u{1F579}', (7, 0)),
+    ('\u{1F57A}', '\u{1F57A}', (9, 0)),
+    ('\u{1F57B}', '\u{1F5A3}', (7, 0)),
+    ('\u{1F5A4}', '\u{1F5A4}', (9, 0)),
+    ('\u{1F5A5}', '\u{1F5FA}', (7, 0)),
+    ('\u{1F5FB}', '\u{1F5FF}', (6, 0)),
+    ('\u{1F600}', '\u{1F600}', (6, 1)),
+    ('\u{1F601}', '\u{1F610}', (6, 0)),
+    ('\u{1F611}', '\u{1F611}', (6, 1)),
+    ('\u{1F612}', '\u{1F614}', (6, 0)),
+    ('\u{1F615}', '\u{1F615}', (6, 1)),
+    ('\u{1F616}', '\u{1F616}', (6, 0)),
+    ('\u{1F617}', '\u{1F617}', (6, 1)),
+    ('\u{1F618}', '\u{1F618}', (6, 0)),
+    ('\u{1F619}', '\u{1F619}', (6, 1)),
+    ('\u{1F61A}', '\u{1F61A}', (6, 0)),
+    ('\u{1F61B}', '\u{1F61B}', (6, 1)),
+    ('\u{1F61C}', '\u{1F61E}', (6, 0)),
+    ('\u{1F61F}', '\u{1F61F}', (6, 1)),
+    ('\u{1F620}', '\u{1F625}', (6, 0)),
+    ('\u{1F626}', '\u{1F627}', (6, 1)),
+    ('\u{1F628}', '\u{1F62B}', (6, 0)),
+    ('\u{1F62C}', '\u{1F62C}', (6, 1)),
+    ('\u{1F62D}', '\u{1F62D}', (6, 0)),
+    ('\u{1F62E}', '\u{1F62F}', (6, 1)),
+    ('\u{1F630}', '\u{1F633}', (6, 0)),
+    ('\u{1F634}', '\u{1F634}', (6, 1)),
+    ('\u{1F635}', '\u{1F640}', (6, 0)),
+    ('\u{1F641}', '\u{1F642}', (7, 0)),
+    ('\u{1F643}', '\u{1F644}', (8, 0)),
+    ('\u{1F645}', '\u{1F64F}', (6, 0)),
+    ('\u{1F650}', '\u{1F67F}', (7, 0)),
+    ('\u{1F680}', '\u{1F6C5}', (6, 0)),
+    ('\u{1F6C6}', '\u{1F6CF}', (7, 0)),
+    ('\u{1F6D0}', '\u{1F6D0}', (8, 0)),
+    ('\u{1F6D1}', '\u{1F6D2}', (9, 0)),
+    ('\u{1F6D3}', '\u{1F6D4}', (10, 0)),
+    ('\u{1F6D5}', '\u{1F6D5}', (12, 0)),
+    ('\u{1F6D6}', '\u{1F6D7}', (13, 0)),
+    ('\u{1F6DC}', '\u{1F6DC}', (15, 0)),
+    ('\u{1F6DD}', '\u{1F6DF}', (14, 0)),
+    ('\u{1F6E0}', '\u{1F6EC}', (7, 0)),
+    ('\u{1F6F0}', '\u{1F6F3}', (7, 0)),
+    ('\u{1F6F4}', '\u{1F6F6}', (9, 0)),
+    ('\u{1F6F7}', '\u{1F6F8}', (10, 0)),
+    ('\u{1F6F9}', '\u{1F6F9}', (11, 0)),
+    ('\u{1F6FA}', '\u{1F6FA}', (12, 0)),
+    ('\u{1F6FB}', '\u{1F6FC}', (13, 0)),
+    ('\u{1F700}', '\u{1F773}', (6, 0)),
+    ('\u{1F774}', '\u{1F776}', (15, 0)),
+    ('\u{1F77B}', '\u{1F77F}', (15, 0)),
+    ('\u{1F780}', '\u{1F7D4}', (7, 0)),
+    ('\u{1F7D5}', '\u{1F7D8}', (11, 0)),
+    ('\u{1F7D9}', '\u{1F7D9}', (15, 0)),
+    ('\u{1F7E0}', '\u{1F7EB}', (12, 0)),
+    ('\u{1F7F0}', '\u{1F7F0}', (14, 0)),
+    ('\u{1F800}', '\u{1F80B}', (7, 0)),
+    ('\u{1F810}', '\u{1F847}', (7, 0)),
+    ('\u{1F850}', '\u{1F859}', (7, 0)),
+    ('\u{1F860}', '\u{1F887}', (7, 0)),
+    ('\u{1F890}', '\u{1F8AD}', (7, 0)),
+    ('\u{1F8B0}', '\u{1F8B1}', (13, 0)),
+    ('\u{1F8B2}', '\u{1F8BB}', (16, 0)),
+    ('\u{1F8C0}', '\u{1F8C1}', (16, 0)),
+    ('\u{1F900}', '\u{1F90B}', (10, 0)),
+    ('\u{1F90C}', '\u{1F90C}', (13, 0)),
+    ('\u{1F90D}', '\u{1F90F}', (12, 0)),
+    ('\u{1F910}', '\u{1F918}', (8, 0)),
+    ('\u{1F919}', '\u{1F91E}', (9, 0)),
+    ('\u{1F91F}', '\u{1F91F}', (10, 0)),
+    ('\u{1F920}', '\u{1F927}', (9, 0)),
+    ('\u{1F928}', '\u{1F92F}', (10, 0)),
+    ('\u{1F930}', '\u{1F930}', (9, 0)),
+    ('\u{1F931}', '\u{1F932}', (10, 0)),
+    ('\u{1F933}', '\u{1F93E}', (9, 0)),
+    ('\u{1F93F}', '\u{1F93F}', (12, 0)),
+    ('\u{1F940}', '\u{1F94B}', (9, 0)),
+    ('\u{1F94C}', '\u{1F94C}', (10, 0)),
+    ('\u{1F94D}', '\u{1F94F}', (11, 0)),
+    ('\u{1F950}', '\u{1F95E}', (9, 0)),
+    ('\u{1F95F}', '\u{1F96B}', (10, 0)),
+    ('\u{1F96C}', '\u{1F970}', (11, 0)),
+    ('\u{1F971}', '\u{1F971}', (12, 0)),
+    ('\u{1F972}', '\u{1F972}', (13, 0)),
+    ('\u{1F973}', '\u{1F976}', (11, 0)),
+    ('\u{1F977}', '\u{1F978}', (13, 0)),
+    ('\u{1F979}', '\u{1F979}', (14, 0)),
+    ('\u{1F97A}', '\u{1F97A}', (11, 0)),
+    ('\u{1F97B}', '\u{1F97B}', (12, 0)),
+    ('\u{1F97C}', '\u{1F97F}', (11, 0)),
+    ('\u{1F980}', '\u{1F984}', (8, 0)),
+    ('\u{1F985}', '\u{1F991}', (9, 0)),
+    ('\u{1F992}', '\u{1F997}', (10, 0)),
+    ('\u{1F998}', '\u{1F9A2}', (11, 0)),
+    ('\u{1F9A3}', '\u{1F9A4}', (13, 0)),
+    ('\u{1F9A5}', '\u{1F9AA}', (12, 0)),
+    ('\u{1F9AB}', '\u{1F9AD}', (13, 0)),
+    ('\u{1F9AE}', '\u{1F9AF}', (12, 0)),
+    ('\u{1F9B0}', '\u{1F9B9}', (11, 0)),
+    ('\u{1F9BA}', '\u{1F9BF}', (12, 0)),
+    ('\u{1F9C0}', '\u{1F9C0}', (8, 0)),
+    ('\u{1F9C1}', '\u{1F9C2}', (11, 0)),
+    ('\u{1F9C3}', '\u{1F9CA}', (12, 0)),
+    ('\u{1F9CB}', '\u{1F9CB}', (13, 0)),
+    ('\u{1F9CC}', '\u{1F9CC}', (14, 0)),
+    ('\u{1F9CD}', '\u{1F9CF}', (12, 0)),
+    ('\u{1F9D0}', '\u{1F9E6}', (10, 0)),
+    ('\u{1F9E7}', '\u{1F9FF}', (11, 0)),
+    ('\u{1FA00}', '\u{1FA53}', (12, 0)),
+    ('\u{1FA60}', '\u{1FA6D}', (11, 0)),
+    ('\u{1FA70}', '\u{1FA73}', (12, 0)),
+    ('\u{1FA74}', '\u{1FA74}', (13, 0)),
+    ('\u{1FA75}', '\u{1FA77}', (15, 0)),
+    ('\u{1FA78}', '\u{1FA7A}', (12, 0)),
+    ('\u{1FA7B}', '\u{1FA7C}', (14, 0)),
+    ('\u{1FA80}', '\u{1FA82}', (12, 0)),
+    ('\u{1FA83}', '\u{1FA86}', (13, 0)),
+    ('\u{1FA87}', '\u{1FA88}', (15, 0)),
+    ('\u{1FA89}', '\u{1FA89}', (16, 0)),
+    ('\u{1FA8F}', '\u{1FA8F}', (16, 0)),
+    ('\u{1FA90}', '\u{1FA95}', (12, 0)),
+    ('\u{1FA96}', '\u{1FAA8}', (13, 0)),
+    ('\u{1FAA9}', '\u{1FAAC}', (14, 0)),
+    ('\u{1FAAD}', '\u{1FAAF}', (15, 0)),
+    ('\u{1FAB0}', '\u{1FAB6}', (13, 0)),
+    ('\u{1FAB7}', '\u{1FABA}', (14, 0)),
+    ('\u{1FABB}', '\u{1FABD}', (15, 0)),
+    ('\u{1FABE}', '\u{1FABE}', (16, 0)),
+    ('\u{1FABF}', '\u{1FABF}', (15, 0)),
+    ('\u{1FAC0}', '\u{1FAC2}', (13, 0)),
+    ('\u{1FAC3}', '\u{1FAC5}', (14, 0)),
+    ('\u{1FAC6}', '\u{1FAC6}', (16, 0)),
+    ('\u{1FACE}', '\u{1FACF}', (15, 0)),
+    ('\u{1FAD0}', '\u{1FAD6}', (13, 0)),
+    ('\u{1FAD7}', '\u{1FAD9}', (14, 0)),
+    ('\u{1FADA}', '\u{1FADB}', (15, 0)),
+    ('\u{1FADC}', '\u{1FADC}', (16, 0)),
+    ('\u{1FADF}', '\u{1FADF}', (16, 0)),
+    ('\u{1FAE0}', '\u{1FAE7}', (14, 0)),
+    ('\u{1FAE8}', '\u{1FAE8}', (15, 0)),
+    ('\u{1FAE9}', '\u{1FAE9}', (16, 0)),
+    ('\u{1FAF0}', '\u{1FAF6}', (14, 0)),
+    ('\u{1FAF7}', '\u{1FAF8}', (15, 0)),
+    ('\u{1FB00}', '\u{1FB92}', (13, 0)),
+    ('\u{1FB94}', '\u{1FBCA}', (13, 0)),
+    ('\u{1FBCB}', '\u{1FBEF}', (16, 0)),
+    ('\u{1FBF0}', '\u{1FBF9}', (13, 0)),
+    ('\u{1FFFE}', '\u{1FFFF}', (2, 0)),
+    ('\u{20000}', '\u{2A6D6}', (3, 1)),
+    ('\u{2A6D7}', '\u{2A6DD}', (13, 0)),
+    ('\u{2A6DE}', '\u{2A6DF}', (14, 0)),
+    ('\u{2A700}', '\u{2B734}', (5, 2)),
+    ('\u{2B735}', '\u{2B738}', (14, 0)),
+    ('\u{2B739}', '\u{2B739}', (15, 0)),
+    ('\u{2B740}', '\u{2B81D}', (6, 0)),
+    ('\u{2B820}', '\u{2CEA1}', (8, 0)),
+    ('\u{2CEB0}', '\u{2EBE0}', (10, 0)),
+    ('\u{2EBF0}', '\u{2EE5D}', (15, 1)),
+    ('\u{2F800}', '\u{2FA1D}', (3, 1)),
+    ('\u{2FFFE}', '\u{2FFFF}', (2, 0)),
+    ('\u{30000}', '\u{3134A}', (13, 0)),
+    ('\u{31350}', '\u{323AF}', (15, 0)),
+    ('\u{3FFFE}', '\u{3FFFF}', (2, 0)),
+    ('\u{4FFFE}', '\u{4FFFF}', (2, 0)),
+    ('\u{5FFFE}', '\u{5FFFF}', (2, 0)),
+    ('\u{6FFFE}', '\u{6FFFF}', (2, 0)),
+    ('\u{7FFFE}', '\u{7FFFF}', (2, 0)),
+    ('\u{8FFFE}', '\u{8FFFF}', (2, 0)),
+    ('\u{9FFFE}', '\u{9FFFF}', (2, 0)),
+    ('\u{AFFFE}', '\u{AFFFF}', (2, 0)),
+    ('\u{BFFFE}', '\u{BFFFF}', (2, 0)),
+    ('\u{CFFFE}', '\u{CFFFF}', (2, 0)),
+    ('\u{DFFFE}', '\u{DFFFF}', (2, 0)),
+    ('\u{E0001}', '\u{E0001}', (3, 1)),
+    ('\u{E0020}', '\u{E007F}', (3, 1)),
+    ('\u{E0100}', '\u{E01EF}', (4, 0)),
+    ('\u{EFFFE}', '\u{10FFFF}', (2, 0)),
+];
@@ -0,0 +1,875 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+use crate::unicode::security::BidiClass::{self, *};
+
+pub(crate) const BIDI_CLASS: &[(char, char, BidiClass)] = &[
+    ('\u{0}', '\u{8}', BoundaryNeutral),
+    ('\u{9}', '\u{9}', SegmentSeparator),
+    ('\u{A}', '\u{A}', ParagraphSeparator),
+    ('\u{B}', '\u{B}', SegmentSeparator),
+    ('\u{C}', '\u{C}', WhiteSpace),
+    ('\u{D}', '\u{D}', ParagraphSeparator),
+    ('\u{E}', '\u{1B}', BoundaryNeutral),
+    ('\u{1C}', '\u{1E}', ParagraphSeparator),
+    ('\u{1F}', '\u{1F}', SegmentSeparator),
+    ('\u{20}', '\u{20}', WhiteSpace),
+    ('\u{21}', '\u{22}', OtherNeutral),
+    ('\u{23}', '\u{25}', EuropeanTerminator),
+    ('\u{26}', '\u{2A}', OtherNeutral),
+    ('\u{2B}', '\u{2B}', EuropeanSeparator),
+    ('\u{2C}', '\u{2C}', CommonSeparator),
+    ('\u{2D}', '\u{2D}', EuropeanSeparator),
+    ('\u{2E}', '\u{2F}', CommonSeparator),
+    ('\u{30}', '\u{39}', EuropeanNumber),
+    ('\u{3A}', '\u{3A}', CommonSeparator),
+    ('\u{3B}', '\u{40}', OtherNeutral),
+    ('\u{5B}', '\u{60}', OtherNeutral),
+    ('\u{7B}', '\u{7E}', OtherNeutral),
+    ('\u{7F}', '\u{84}', BoundaryNeutral),
+    ('\u{85}', '\u{85}', ParagraphSeparator),
+    ('\u{86}', '\u{9F}', BoundaryNeutral),
+    ('\u{A0}', '\u{A0}', CommonSeparator),
+    ('\u{A1}', '\u{A1}', OtherNeutral),
+    ('\u{A2}', '\u{A5}', EuropeanTerminator),
+    ('\u{A6}', '\u{A9}', OtherNeutral),
+    ('\u{AB}', '\u{AC}', OtherNeutral),
+    ('\u{AD}', '\u{AD}', BoundaryNeutral),
+    ('\u{AE}', '\u{AF}', OtherNeutral),
+    ('\u{B0}', '\u{B1}', EuropeanTerminator),
+    ('\u{B2}', '\u{B3}', EuropeanNumber),
+    ('\u{B4}', '\u{B4}', OtherNeutral),
+    ('\u{B6}', '\u{B8}', OtherNeutral),
+    ('\u{B9}', '\u{B9}', EuropeanNumber),
+    ('\u{BB}', '\u{BF}', OtherNeutral),
+    ('\u{D7}', '\u{D7}', OtherNeutral),
+    ('\u{F7}', '\u{F7}', OtherNeutral),
+    ('\u{2B9}', '\u{2BA}', OtherNeutral),
+    ('\u{2C2}', '\u{2CF}', OtherNeutral),
+    ('\u{2D2}', '\u{2DF}', OtherNeutral),
+    ('\u{2E5}', '\u{2ED}', OtherNeutral),
+    ('\u{2EF}', '\u{2FF}', OtherNeutral),
+    ('\u{300}', '\u{36F}', NonspacingMark),
+    ('\u{374}', '\u{375}', OtherNeutral),
+    ('\u{37E}', '\u{37E}', OtherNeutral),
+    ('\u{384}', '\u{385}', OtherNeutral),
+    ('\u{387}', '\u{387}', OtherNeutral),
+    ('\u{3F6}', '\u{3F6}', OtherNeutral),
+    ('\u{483}', '\u{489}', NonspacingMark),
+    ('\u{58A}', '\u{58A}', OtherNeutral),
+    ('\u{58D}', '\u{58E}', OtherNeutral),
+    ('\u{58F}', '\u{58F}', EuropeanTerminator),
+    ('\u{590}', '\u{590}', RightToLeft),
+    ('\u{591}', '\u{5BD}', NonspacingMark),
+    ('\u{5BE}', '\u{5BE}', RightToLeft),
+    ('\u{5BF}', '\u{5BF}', NonspacingMark),
+    ('\u{5C0}', '\u{5C0}', RightToLeft),
+    ('\u{5C1}', '\u{5C2}', NonspacingMark),
+    ('\u{5C3}', '\u{5C3}', RightToLeft),
+    ('\u{5C4}', '\u{5C5}', NonspacingMark),
+    ('\u{5C6}', '\u{5C6}', RightToLeft),
+    ('\u{5C7}', '\u{5C7}', NonspacingMark),
+    ('\u{5C8}', '\u{5FF}', RightToLeft),
+    ('\u{600}', '\u{605}', ArabicNumber),
+    ('\u{606}', '\u{607}', OtherNeutral),
+    ('\u{608}', '\u{608}', ArabicLetter),
+    ('\u{609}', '\u{60A}', EuropeanTerminator),
+    ('\u{60B}', '\u{60B}', ArabicLetter),
+    ('\u{60C}', '\u{60C}', CommonSeparator),
+    ('\u{60D}', '\u{60D}', ArabicLetter),
+    ('\u{60E}', '\u{60F}', OtherNeutral),
+    ('\u{610}', '\u{61A}', NonspacingMark),
+    ('\u{61B}', '\u{64A}', ArabicLetter),
+    ('\u{64B}', '\u{65F}', NonspacingMark),
+    ('\u{660}', '\u{669}', ArabicNumber),
+    ('\u{66A}', '\u{66A}', EuropeanTerminator),
+    ('\u{66B}', '\u{66C}', ArabicNumber),
+    ('\u{66D}', '\u{66F}', ArabicLetter),
+    ('\u{670}', '\u{670}', NonspacingMark),
+    ('\u{671}', '\u{6D5}', ArabicLetter),
+    ('\u{6D6}', '\u{6DC}', NonspacingMark),
+    ('\u{6DD}', '\u{6DD}', ArabicNumber),
+    ('\u{6DE}', '\u{6DE}', OtherNeutral),
+    ('\u{6DF}', '\u{6E4}', NonspacingMark),
+    ('\u{6E5}', '\u{6E6}', ArabicLetter),
+    ('\u{6E7}', '\u{6E8}', NonspacingMark),
+    ('\u{6E9}', '\u{6E9}', OtherNeutral),
+    ('\u{6EA}', '\u{6ED}', NonspacingMark),
+    ('\u{6EE}', '\u{6EF}', ArabicLetter),
+    ('\u{6F0}', '\u{6F9}', EuropeanNumber),
+    ('\u{6FA}', '\u{710}', ArabicLetter),
+    ('\u{711}', '\u{711}', NonspacingMark),
+    ('\u{712}', '\u{72F}', ArabicLetter),
+    ('\u{730}', '\u{74A}', NonspacingMark),
+    ('\u{74B}', '\u{7A5}', ArabicLetter),
+    ('\u{7A6}', '\u{7B0}', NonspacingMark),
+    ('\u{7B1}', '\u{7BF}', ArabicLetter),
+    ('\u{7C0}', '\u{7EA}', RightToLeft),
+    ('\u{7EB}', '\u{7F3}', NonspacingMark),
+    ('\u{7F4}', '\u{7F5}', RightToLeft),
+    ('\u{7F6}', '\u{7F9}', OtherNeutral),
+    ('\u{7FA}', '\u{7FC}', RightToLeft),
+    ('\u{7FD}', '\u{7FD}', NonspacingMark),
+    ('\u{7FE}', '\u{815}', RightToLeft),
+    ('\u{816}', '\u{819}', NonspacingMark),
+    ('\u{81A}', '\u{81A}', RightToLeft),
+    ('\u{81B}', '\u{823}', NonspacingMark),
+    ('\u{824}', '\u{824}', RightToLeft),
+    ('\u{825}', '\u{827}', NonspacingMark),
+    ('\u{828}', '\u{828}', RightToLeft),
+    ('\u{829}', '\u{82D}', NonspacingMark),
+    ('\u{82E}', '\u{858}', RightToLeft),
+    ('\u{859}', '\u{85B}', NonspacingMark),
+    ('\u{85C}', '\u{85F}', RightToLeft),
+    ('\u{860}', '\u{88F}', ArabicLetter),
+    ('\u{890}', '\u{891}', ArabicNumber),
+    ('\u{892}', '\u{896}', ArabicLetter),
+    ('\u{897}', '\u{89F}', NonspacingMark),
+    ('\u{8A0}', '\u{8C9}', ArabicLetter),
+    ('\u{8CA}', '\u{8E1}', NonspacingMark),
+    ('\u{8E2}', '\u{8E2}', ArabicNumber),
+    ('\u{8E3}', '\u{902}', NonspacingMark),
+    ('\u{93A}', '\u{93A}', NonspacingMark),
+    ('\u{93C}', '\u{93C}', NonspacingMark),
+    ('\u{941}', '\u{948}', NonspacingMark),
+    ('\u{94D}', '\u{94D}', NonspacingMark),
+    ('\u{951}', '\u{957}', NonspacingMark),
+    ('\u{962}', '\u{963}', NonspacingMark),
+    ('\u{981}', '\u{981}', NonspacingMark),
+    ('\u{9BC}', '\u{9BC}', NonspacingMark),
+    ('\u{9C1}', '\u{9C4}', NonspacingMark),
+    ('\u{9CD}', '\u{9CD}', NonspacingMark),
+    ('\u{9E2}', '\u{9E3}', NonspacingMark),
+    ('\u{9F2}', '\u{9F3}', EuropeanTerminator),
+    ('\u{9FB}', '\u{9FB}', EuropeanTerminator),
+    ('\u{9FE}', '\u{9FE}', NonspacingMark),
+    ('\u{A01}', '\u{A02}', NonspacingMark),
+    ('\u{A3C}', '\u{A3C}', NonspacingMark),
+    ('\u{A41}', '\u{A42}', NonspacingMark),
+    ('\u{A47}', '\u{A48}', NonspacingMark),
+    ('\u{A4B}', '\u{A4D}', NonspacingMark),
+    ('\u{A51}', '\u{A51}', NonspacingMark),
+    ('\u{A70}', '\u{A71}', NonspacingMark),
+    ('\u{A75}', '\u{A75}', NonspacingMark),
+    ('\u{A81}', '\u{A82}', NonspacingMark),
+    ('\u{ABC}', '\u{ABC}', NonspacingMark),
+    ('\u{AC1}', '\u{AC5}', NonspacingMark),
+    ('\u{AC7}', '\u{AC8}', NonspacingMark),
+    ('\u{ACD}', '\u{ACD}', NonspacingMark),
+    ('\u{AE2}', '\u{AE3}', NonspacingMark),
+    ('\u{AF1}', '\u{AF1}', EuropeanTerminator),
+    ('\u{AFA}', '\u{AFF}', NonspacingMark),
+    ('\u{B01}', '\u{B01}', NonspacingMark),
+    ('\u{B3C}', '\u{B3C}', NonspacingMark),
+    ('\u{B3F}', '\u{B3F}', NonspacingMark),
+    ('\u{B41}', '\u{B44}', NonspacingMark),
+    ('\u{B4D}', '\u{B4D}', NonspacingMark),
+    ('\u{B55}', '\u{B56}', NonspacingMark),
+    ('\u{B62}', '\u{B63}', NonspacingMark),
+    ('\u{B82}', '\u{B82}', NonspacingMark),
+    ('\u{BC0}', '\u{BC0}', NonspacingMark),
+    ('\u{BCD}', '\u{BCD}', NonspacingMark),
+    ('\u{BF3}', '\u{BF8}', OtherNeutral),
+    ('\u{BF9}', '\u{BF9}', EuropeanTerminator),
+    ('\u{BFA}', '\u{BFA}', OtherNeutral),
+    ('\u{C00}', '\u{C00}', NonspacingMark),
+    ('\u{C04}', '\u{C04}', NonspacingMark),
+    ('\u{C3C}', '\u{C3C}', NonspacingMark),
+    ('\u{C3E}', '\u{C40}', NonspacingMark),
+    ('\u{C46}', '\u{C48}', NonspacingMark),
+    ('\u{C4A}', '\u{C4D}', NonspacingMark),
+    ('\u{C55}', '\u{C56}', NonspacingMark),
+    ('\u{C62}', '\u{C63}', NonspacingMark),
+    ('\u{C78}', '\u{C7E}', OtherNeutral),
+    ('\u{C81}', '\u{C81}', NonspacingMark),
+    ('\u{CBC}', '\u{CBC}', NonspacingMark),
+    ('\u{CCC}', '\u{CCD}', NonspacingMark),
+    ('\u{CE2}', '\u{CE3}', NonspacingMark),
+    ('\u{D00}', '\u{D01}', NonspacingMark),
+    ('\u{D3B}', '\u{D3C}', NonspacingMark),
+    ('\u{D41}', '\u{D44}', NonspacingMark),
+    ('\u{D4D}', '\u{D4D}', NonspacingMark),
+    ('\u{D62}', '\u{D63}', NonspacingMark),
+    ('\u{D81}', '\u{D81}', NonspacingMark),
+    ('\u{DCA}', '\u{DCA}', NonspacingMark),
+    ('\u{DD2}', '\u{DD4}', NonspacingMark),
+    ('\u{DD6}', '\u{DD6}', NonspacingMark),
+    ('\u{E31}', '\u{E31}', NonspacingMark),
+    ('\u{E34}', '\u{E3A}', NonspacingMark),
+    ('\u{E3F}', '\u{E3F}', EuropeanTerminator),
+    ('\u{E47}', '\u{E4E}', NonspacingMark),
+    ('\u{EB1}', '\u{EB1}', NonspacingMark),
+    ('\u{EB4}', '\u{EBC}', NonspacingMark),
+    ('\u{EC8}', '\u{ECE}', NonspacingMark),
+    ('\u{F18}', '\u{F19}', NonspacingMark),
+    ('\u{F35}', '\u{F35}', NonspacingMark),
+    ('\u{F37}', '\u{F37}', NonspacingMark),
+    ('\u{F39}', '\u{F39}', NonspacingMark),
+    ('\u{F3A}', '\u{F3D}', OtherNeutral),
+    ('\u{F71}', '\u{F7E}', NonspacingMark),
+    ('\u{F80}', '\u{F84}', NonspacingMark),
+    ('\u{F86}', '\u{F87}', NonspacingMark),
+    ('\u{F8D}', '\u{F97}', NonspacingMark),
+    ('\u{F99}', '\u{FBC}', NonspacingMark),
+    ('\u{FC6}', '\u{FC6}', NonspacingMark),
+    ('\u{102D}', '\u{1030}', NonspacingMark),
+    ('\u{1032}', '\u{1037}', NonspacingMark),
+    ('\u{1039}', '\u{103A}', NonspacingMark),
+    ('\u{103D}', '\u{103E}', NonspacingMark),
+    ('\u{1058}', '\u{1059}', NonspacingMark),
+    ('\u{105E}', '\u{1060}', NonspacingMark),
+    ('\u{1071}', '\u{1074}', NonspacingMark),
+    ('\u{1082}', '\u{1082}', NonspacingMark),
+    ('\u{1085}', '\u{1086}', NonspacingMark),
+    ('\u{108D}', '\u{108D}', NonspacingMark),
+    ('\u{109D}', '\u{109D}', NonspacingMark),
+    ('\u{135D}', '\u{135F}', NonspacingMark),
+    ('\u{1390}', '\u{1399}', OtherNeutral),
+    ('\u{1400}', '\u{1400}', OtherNeutral),
+    ('\u{1680}', '\u{1680}', WhiteSpace),
+    ('\u{169B}', '\u{169C}', OtherNeutral),
+    ('\u{1712}', '\u{1714}', NonspacingMark),
+    ('\u{1732}', '\u{1733}', NonspacingMark),
+    ('\u{1752}', '\u{1753}', NonspacingMark),
+    ('\u{1772}', '\u{1773}', NonspacingMark),
+    ('\u{17B4}', '\u{17B5}', NonspacingMark),
+    ('\u{17B7}', '\u{17BD}', NonspacingMark),
+    ('\u{17C6}', '\u{17C6}', NonspacingMark),
+    ('\u{17C9}', '\u{17D3}', NonspacingMark),
+    ('\u{17DB}', '\u{17DB}', EuropeanTerminator),
+    ('\u{17DD}', '\u{17DD}', NonspacingMark),
+    ('\u{17F0}', '\u{17F9}', OtherNeutral),
+    ('\u{1800}', '\u{180A}', OtherNeutral),
+    ('\u{180B}', '\u{180D}', NonspacingMark),
+    ('\u{180E}', '\u{180E}', BoundaryNeutral),
+    ('\u{180F}', '\u{180F}', NonspacingMark),
+    ('\u{1885}', '\u{1886}', NonspacingMark),
+    ('\u{18A9}', '\u{18A9}', NonspacingMark),
+    ('\u{1920}', '\u{1922}', NonspacingMark),
+    ('\u{1927}', '\u{1928}', NonspacingMark),
+    ('\u{1932}', '\u{1932}', NonspacingMark),
+    ('\u{1939}', '\u{193B}', NonspacingMark),
+    ('\u{1940}', '\u{1940}', OtherNeutral),
+    ('\u{1944}', '\u{1945}', OtherNeutral),
+    ('\u{19DE}', '\u{19FF}', OtherNeutral),
+    ('\u{1A17}', '\u{1A18}', NonspacingMark),
+    ('\u{1A1B}', '\u{1A1B}', NonspacingMark),
+    ('\u{1A56}', '\u{1A56}', NonspacingMark),
+    ('\u{1A58}', '\u{1A5E}', NonspacingMark),
+    ('\u{1A60}', '\u{1A60}', NonspacingMark),
+    ('\u{1A62}', '\u{1A62}', NonspacingMark),
+    ('\u{1A65}', '\u{1A6C}', NonspacingMark),
+    ('\u{1A73}', '\u{1A7C}', NonspacingMark),
+    ('\u{1A7F}', '\u{1A7F}', NonspacingMark),
+    ('\u{1AB0}', '\u{1ACE}', NonspacingMark),
+    ('\u{1B00}', '\u{1B03}', NonspacingMark),
+    ('\u{1B34}', '\u{1B34}', NonspacingMark),
+    ('\u{1B36}', '\u{1B3A}', NonspacingMark),
+    ('\u{1B3C}', '\u{1B3C}', NonspacingMark),
+    ('\u{1B42}', '\u{1B42}', NonspacingMark),
+    ('\u{1B6B}', '\u{1B73}', NonspacingMark),
+    ('\u{1B80}', '\u{1B81}', NonspacingMark),
+    ('\u{1BA2}', '\u{1BA5}', NonspacingMark),
+    ('\u{1BA8}', '\u{1BA9}', NonspacingMark),
+    ('\u{1BAB}', '\u{1BAD}', NonspacingMark),
+    ('\u{1BE6}', '\u{1BE6}', NonspacingMark),
+    ('\u{1BE8}', '\u{1BE9}', NonspacingMark),
+    ('\u{1BED}', '\u{1BED}', NonspacingMark),
+    ('\u{1BEF}', '\u{1BF1}', NonspacingMark),
+    ('\u{1C2C}', '\u{1C33}', NonspacingMark),
+    ('\u{1C36}', '\u{1C37}', NonspacingMark),
+    ('\u{1CD0}', '\u{1CD2}', NonspacingMark),
+    ('\u{1CD4}', '\u{1CE0}', NonspacingMark),
+    ('\u{1CE2}', '\u{1CE8}', NonspacingMark),
+    ('\u{1CED}', '\u{1CED}', NonspacingMark),
+    ('\u{1CF4}', '\u{1CF4}', NonspacingMark),
+    ('\u{1CF8}', '\u{1CF9}', NonspacingMark),
+    ('\u{1DC0}', '\u{1DFF}', NonspacingMark),
+    ('\u{1FBD}', '\u{1FBD}', OtherNeutral),
+    ('\u{1FBF}', '\u{1FC1}', OtherNeutral),
+    ('\u{1FCD}', '\u{1FCF}', OtherNeutral),
+    ('\u{1FDD}', '\u{1FDF}', OtherNeutral),
+    ('\u{1FED}', '\u{1FEF}', OtherNeutral),
+    ('\u{1FFD}', '\u{1FFE}', OtherNeutral),
+    ('\u{2000}', '\u{200A}', WhiteSpace),
+    ('\u{200B}', '\u{200D}', BoundaryNeutral),
+    ('\u{200F}', '\u{200F}', RightToLeft),
+    ('\u{2010}', '\u{2027}', OtherNeutral),
+    ('\u{2028}', '\u{2028}', WhiteSpace),
+    ('\u{2029}', '\u{2029}', ParagraphSeparator),
+    ('\u{202A}', '\u{202A}', LeftToRightEmbedding),
+    ('\u{202B}', '\u{202B}', RightToLeftEmbedding),
+    ('\u{202C}', '\u{202C}', PopDirectionalFormat),
+    ('\u{202D}', '\u{202D}', LeftToRightOverride),
+    ('\u{202E}', '\u{202E}', RightToLeftOverride),
+    ('\u{202F}', '\u{202F}', CommonSeparator),
+    ('\u{2030}', '\u{2034}', EuropeanTerminator),
+    ('\u{2035}', '\u{2043}', OtherNeutral),
+    ('\u{2044}', '\u{2044}', CommonSeparator),
+    ('\u{2045}', '\u{205E}', OtherNeutral),
+    ('\u{205F}', '\u{205F}', WhiteSpace),
+    ('\u{2060}', '\u{2064}', BoundaryNeutral),
+    ('\u{2066}', '\u{2066}', LeftToRightIsolate),
+    ('\u{2067}', '\u{2067}', RightToLeftIsolate),
+    ('\u{2068}', '\u{2068}', FirstStrongIsolate),
+    ('\u{2069}', '\u{2069}', PopDirectionalIsolate),
+    ('\u{206A}', '\u{206F}', BoundaryNeutral),
+    ('\u{2070}', '\u{2070}', EuropeanNumber),
+    ('\u{2074}', '\u{2079}', EuropeanNumber),
+    ('\u{207A}', '\u{207B}', EuropeanSeparator),
+    ('\u{207C}', '\u{207E}', OtherNeutral),
+    ('\u{2080}', '\u{2089}', EuropeanNumber),
+    ('\u{208A}', '\u{208B}', EuropeanSeparator),
+    ('\u{208C}', '\u{208E}', OtherNeutral),
+    ('\u{20A0}', '\u{20CF}', EuropeanTerminator),
+    ('\u{20D0}', '\u{20F0}', NonspacingMark),
+    ('\u{2100}', '\u{2101}', OtherNeutral),
+    ('\u{2103}', '\u{2106}', OtherNeutral),
+    ('\u{2108}', '\u{2109}', OtherNeutral),
+    ('\u{2114}', '\u{2114}', OtherNeutral),
+    ('\u{2116}', '\u{2118}', OtherNeutral),
+    ('\u{211E}', '\u{2123}', OtherNeutral),
+    ('\u{2125}', '\u{2125}', OtherNeutral),
+    ('\u{2127}', '\u{2127}', OtherNeutral),
+    ('\u{2129}', '\u{2129}', OtherNeutral),
+    ('\u{212E}', '\u{212E}', EuropeanTerminator),
+    ('\u{213A}', '\u{213B}', OtherNeutral),
+    ('\u{2140}', '\u{2144}', OtherNeutral),
+    ('\u{214A}', '\u{214D}', OtherNeutral),
+    ('\u{2150}', '\u{215F}', OtherNeutral),
+    ('\u{2189}', '\u{218B}', OtherNeutral),
+    ('\u{2190}', '\u{2211}', OtherNeutral),
+    ('\u{2212}', '\u{2212}', EuropeanSeparator),
+    ('\u{2213}', '\u{2213}', EuropeanTerminator),
+    ('\u{2214}', '\u{2335}', OtherNeutral),
+    ('\u{237B}', '\u{2394}', OtherNeutral),
+    ('\u{2396}', '\u{2426}', OtherNeutral),
+    ('\u{2440}', '\u{244A}', OtherNeutral),
+    ('\u{2460}', '\u{2487}', OtherNeutral),
+    ('\u{2488}', '\u{249B}', EuropeanNumber),
+    ('\u{24EA}', '\u{26AB}', OtherNeutral),
+    ('\u{26AD}', '\u{27FF}', OtherNeutral),
+    ('\u{2900}', '\u{2B73}', OtherNeutral),
+    ('\u{2B76}', '\u{2B95}', OtherNeutral),
+    ('\u{2B97}', '\u{2BFF}', OtherNeutral),
+    ('\u{2CE5}', '\u{2CEA}', OtherNeutral),
+    ('\u{2CEF}', '\u{2CF1}', NonspacingMark),
+    ('\u{2CF9}', '\u{2CFF}', OtherNeutral),
+    ('\u{2D7F}', '\u{2D7F}', NonspacingMark),
+    ('\u{2DE0}', '\u{2DFF}', NonspacingMark),
+    ('\u{2E00}', '\u{2E5D}', OtherNeutral),
+    ('\u{2E80}', '\u{2E99}', OtherNeutral),
+    ('\u{2E9B}', '\u{2EF3}', OtherNeutral),
+    ('\u{2F00}', '\u{2FD5}', OtherNeutral),
+    ('\u{2FF0}', '\u{2FFF}', OtherNeutral),
+    ('\u{3000}', '\u{3000}', WhiteSpace),
+    ('\u{3001}', '\u{3004}', OtherNeutral),
+    ('\u{3008}', '\u{3020}', OtherNeutral),
+    ('\u{302A}', '\u{302D}', NonspacingMark),
+    ('\u{3030}', '\u{3030}', OtherNeutral),
+    ('\u{3036}', '\u{3037}', OtherNeutral),
+    ('\u{303D}', '\u{303F}', OtherNeutral),
+    ('\u{3099}', '\u{309A}', NonspacingMark),
+    ('\u{309B}', '\u{309C}', OtherNeutral),
+    ('\u{30A0}', '\u{30A0}', OtherNeutral),
+    ('\u{30FB}', '\u{30FB}', OtherNeutral),
+    ('\u{31C0}', '\u{31E3}', OtherNeutral),
+    ('\u{31EF}', '\u{31EF}', OtherNeutral),
+    ('\u{321D}', '\u{321E}', OtherNeutral),
+    ('\u{3250}', '\u{325F}', OtherNeutral),
+    ('\u{327C}', '\u{327E}', OtherNeutral),
+    ('\u{32B1}', '\u{32BF}', OtherNeutral),
+    ('\u{32CC}', '\u{32CF}', OtherNeutral),
+    ('\u{3377}', '\u{337A}', OtherNeutral),
+    ('\u{33DE}', '\u{33DF}', OtherNeutral),
+    ('\u{33FF}', '\u{33FF}', OtherNeutral),
+    ('\u{4DC0}', '\u{4DFF}', OtherNeutral),
+    ('\u{A490}', '\u{A4C6}', OtherNeutral),
+    ('\u{A60D}', '\u{A60F}', OtherNeutral),
+    ('\u{A66F}', '\u{A672}', NonspacingMark),
+    ('\u{A673}', '\u{A673}', OtherNeutral),
+    ('\u{A674}', '\u{A67D}', NonspacingMark),
+    ('\u{A67E}', '\u{A67F}', OtherNeutral),
+    ('\u{A69E}', '\u{A69F}', NonspacingMark),
+    ('\u{A6F0}', '\u{A6F1}', NonspacingMark),
+    ('\u{A700}', '\u{A721}', OtherNeutral),
+    ('\u{A788}', '\u{A788}', OtherNeutral),
+    ('\u{A802}', '\u{A802}', NonspacingMark),
+    ('\u{A806}', '\u{A806}', NonspacingMark),
+    ('\u{A80B}', '\u{A80B}', NonspacingMark),
+    ('\u{A825}', '\u{A826}', NonspacingMark),
+    ('\u{A828}', '\u{A82B}', OtherNeutral),
+    ('\u{A82C}', '\u{A82C}', NonspacingMark),
+    ('\u{A838}', '\u{A839}', EuropeanTerminator),
+    ('\u{A874}', '\u{A877}', OtherNeutral),
+    ('\u{A8C4}', '\u{A8C5}', NonspacingMark),
+    ('\u{A8E0}', '\u{A8F1}', NonspacingMark),
+    ('\u{A8FF}', '\u{A8FF}', NonspacingMark),
+    ('\u{A926}', '\u{A92D}', NonspacingMark),
+    ('\u{A947}', '\u{A951}', NonspacingMark),
+    ('\u{A980}', '\u{A982}', NonspacingMark),
+    ('\u{A9B3}', '\u{A9B3}', NonspacingMark),
+    ('\u{A9B6}', '\u{A9B9}', NonspacingMark),
+    ('\u{A9BC}', '\u{A9BD}', NonspacingMark),
+    ('\u{A9E5}', '\u{A9E5}', NonspacingMark),
+    ('\u{AA29}', '\u{AA2E}', NonspacingMark),
+    ('\u{AA31}', '\u{AA32}', NonspacingMark),
+    ('\u{AA35}', '\u{AA36}', NonspacingMark),
+    ('\u{AA43}', '\u{AA43}', NonspacingMark),
+    ('\u{AA4C}', '\u{AA4C}', NonspacingMark),
+    ('\u{AA7C}', '\u{AA7C}', NonspacingMark),
+    ('\u{AAB0}', '\u{AAB0}', NonspacingMark),
+    ('\u{AAB2}', '\u{AAB4}', NonspacingMark),
+    ('\u{AAB7}', '\u{AAB8}', NonspacingMark),
+    ('\u{AABE}', '\u{AABF}', NonspacingMark),
+    ('\u{AAC1}', '\u{AAC1}', NonspacingMark),
+    ('\u{AAEC}', '\u{AAED}', NonspacingMark),
+    ('\u{AAF6}', '\u{AAF6}', NonspacingMark),
+    ('\u{AB6A}', '\u{AB6B}', OtherNeutral),
+    ('\u{ABE5}', '\u{ABE5}', NonspacingMark),
+    ('\u{ABE8}', '\u{ABE8}', NonspacingMark),
+    ('\u{ABED}', '\u{ABED}', NonspacingMark),
+    ('\u{FB1D}', '\u{FB1D}', RightToLeft),
+    ('\u{FB1E}', '\u{FB1E}', NonspacingMark),
+    ('\u{FB1F}', '\u{FB28}', RightToLeft),
+    ('\u{FB29}', '\u{FB29}', EuropeanSeparator),
+    ('\u{FB2A}', '\u{FB4F}', RightToLeft),
+    ('\u{FB50}', '\u{FD3D}', ArabicLetter),
+    ('\u{FD3E}', '\u{FD4F}', OtherNeutral),
+    ('\u{FD50}', '\u{FDCE}', ArabicLetter),
+    ('\u{FDCF}', '\u{FDCF}', OtherNeutral),
+    ('\u{FDD0}', '\u{FDEF}', BoundaryNeutral),
+    ('\u{FDF0}', '\u{FDFC}', ArabicLetter),
+    ('\u{FDFD}', '\u{FDFF}', OtherNeutral),
+    ('\u{FE00}', '\u{FE0F}', NonspacingMark),
+    ('\u{FE10}', '\u{FE19}', OtherNeutral),
+    ('\u{FE20}', '\u{FE2F}', NonspacingMark),
+    ('\u{FE30}', '\u{FE4F}', OtherNeutral),
+    ('\u{FE50}', '\u{FE50}', CommonSeparator),
+    ('\u{FE51}', '\u{FE51}', OtherNeutral),
+    ('\u{FE52}', '\u{FE52}', CommonSeparator),
+    ('\u{FE54}', '\u{FE54}', OtherNeutral),
+    ('\u{FE55}', '\u{FE55}', CommonSeparator),
+    ('\u{FE56}', '\u{FE5E}', OtherNeutral),
+    ('\u{FE5F}', '\u{FE5F}', EuropeanTerminator),
+    ('\u{FE60}', '\u{FE61}', OtherNeutral),
+    ('\u{FE62}', '\u{FE63}', EuropeanSeparator),
+    ('\u{FE64}', '\u{FE66}', OtherNeutral),
+    ('\u{FE68}', '\u{FE68}', OtherNeutral),
+    ('\u{FE69}', '\u{FE6A}', EuropeanTerminator),
+    ('\u{FE6B}', '\u{FE6B}', OtherNeutral),
+    ('\u{FE70}', '\u{FEFE}', ArabicLetter),
+    ('\u{FEFF}', '\u{FEFF}', BoundaryNeutral),
+    ('\u{FF01}', '\u{FF02}', OtherNeutral),
+    ('\u{FF03}', '\u{FF05}', EuropeanTerminator),
+    ('\u{FF06}', '\u{FF0A}', OtherNeutral),
+    ('\u{FF0B}', '\u{FF0B}', EuropeanSeparator),
+    ('\u{FF0C}', '\u{FF0C}', CommonSeparator),
+    ('\u{FF0D}', '\u{FF0D}', EuropeanSeparator),
+    ('\u{FF0E}', '\u{FF0F}', CommonSeparator),
+    ('\u{FF10}', '\u{FF19}', EuropeanNumber),
+    ('\u{FF1A}', '\u{FF1A}', CommonSeparator),
+    ('\u{FF1B}', '\u{FF20}', OtherNeutral),
+    ('\u{FF3B}', '\u{FF40}', OtherNeutral),
+    ('\u{FF5B}', '\u{FF65}', OtherNeutral),
+    ('\u{FFE0}', '\u{FFE1}', EuropeanTerminator),
+    ('\u{FFE2}', '\u{FFE4}', OtherNeutral),
+    ('\u{FFE5}', '\u{FFE6}', EuropeanTerminator),
+    ('\u{FFE8}', '\u{FFEE}', OtherNeutral),
+    ('\u{FFF9}', '\u{FFFD}', OtherNeutral),
+    ('\u{FFFE}', '\u{FFFF}', BoundaryNeutral),
+    ('\u{10101}', '\u{10101}', OtherNeutral),
+    ('\u{10140}', '\u{1018C}', OtherNeutral),
+    ('\u{10190}', '\u{1019C}', OtherNeutral),
+    ('\u{101A0}', '\u{101A0}', OtherNeutral),
+    ('\u{101FD}', '\u{101FD}', NonspacingMark),
+    ('\u{102E0}', '\u{102E0}', NonspacingMark),
+    ('\u{102E1}', '\u{102FB}', EuropeanNumber),
+    ('\u{10376}', '\u{1037A}', NonspacingMark),
+    ('\u{10800}', '\u{1091E}', RightToLeft),
+    ('\u{1091F}', '\u{1091F}', OtherNeutral),
+    ('\u{10920}', '\u{10A00}', RightToLeft),
+    ('\u{10A01}', '\u{10A03}', NonspacingMark),
+    ('\u{10A04}', '\u{10A04}', RightToLeft),
+    ('\u{10A05}', '\u{10A06}', NonspacingMark),
+    ('\u{10A07}', '\u{10A0B}', RightToLeft),
+    ('\u{10A0C}', '\u{10A0F}', NonspacingMark),
+    ('\u{10A10}', '\u{10A37}', RightToLeft),
+    ('\u{10A38}', '\u{10A3A}', NonspacingMark),
+    ('\u{10A3B}', '\u{10A3E}', RightToLeft),
+    ('\u{10A3F}', '\u{10A3F}', NonspacingMark),
+    ('\u{10A40}', '\u{10AE4}', RightToLeft),
+    ('\u{10AE5}', '\u{10AE6}', NonspacingMark),
+    ('\u{10AE7}', '\u{10B38}', RightToLeft),
+    ('\u{10B39}', '\u{10B3F}', OtherNeutral),
+    ('\u{10B40}', '\u{10CFF}', RightToLeft),
+    ('\u{10D00}', '\u{10D23}', ArabicLetter),
+    ('\u{10D24}', '\u{10D27}', NonspacingMark),
+    ('\u{10D28}', '\u{10D2F}', ArabicLetter),
+    ('\u{10D30}', '\u{10D39}', ArabicNumber),
+    ('\u{10D3A}', '\u{10D3F}', ArabicLetter),
+    ('\u{10D40}', '\u{10D68}', RightToLeft),
+    ('\u{10D69}', '\u{10D6D}', NonspacingMark),
+    ('\u{10D6E}', '\u{10E5F}', RightToLeft),
+    ('\u{10E60}', '\u{10E7E}', ArabicNumber),
+    ('\u{10E7F}', '\u{10EAA}', RightToLeft),
+    ('\u{10EAB}', '\u{10EAC}', NonspacingMark),
+    ('\u{10EAD}', '\u{10EBF}', RightToLeft),
+    ('\u{10EC0}', '\u{10EFB}', ArabicLetter),
+    ('\u{10EFC}', '\u{10EFF}', NonspacingMark),
+    ('\u{10F00}', '\u{10F2F}', RightToLeft),
+    ('\u{10F30}', '\u{10F45}', ArabicLetter),
+    ('\u{10F46}', '\u{10F50}', NonspacingMark),
+    ('\u{10F51}', '\u{10F6F}', ArabicLetter),
+    ('\u{10F70}', '\u{10F81}', RightToLeft),
+    ('\u{10F82}', '\u{10F85}', NonspacingMark),
+    ('\u{10F86}', '\u{10FFF}', RightToLeft),
+    ('\u{11001}', '\u{11001}', NonspacingMark),
+    ('\u{11038}', '\u{11046}', NonspacingMark),
+    ('\u{11052}', '\u{11065}', OtherNeutral),
+    ('\u{11070}', '\u{11070}', NonspacingMark),
+    ('\u{11073}', '\u{11074}', NonspacingMark),
+    ('\u{1107F}', '\u{11081}', NonspacingMark),
+    ('\u{110B3}', '\u{110B6}', NonspacingMark),
+    ('\u{110B9}', '\u{110BA}', NonspacingMark),
+    ('\u{110C2}', '\u{110C2}', NonspacingMark),
+    ('\u{11100}', '\u{11102}', NonspacingMark),
+    ('\u{11127}', '\u{1112B}', NonspacingMark),
+    ('\u{1112D}', '\u{11134}', NonspacingMark),
+    ('\u{11173}', '\u{11173}', NonspacingMark),
+    ('\u{11180}', '\u{11181}', NonspacingMark),
+    ('\u{111B6}', '\u{111BE}', NonspacingMark),
+    ('\u{111C9}', '\u{111CC}', NonspacingMark),
+    ('\u{111CF}', '\u{111CF}', NonspacingMark),
+    ('\u{1122F}', '\u{11231}', NonspacingMark),
+    ('\u{11234}', '\u{11234}', NonspacingMark),
+    ('\u{11236}', '\u{11237}', NonspacingMark),
+    ('\u{1123E}', '\u{1123E}', NonspacingMark),
+    ('\u{11241}', '\u{11241}', NonspacingMark),
+    ('\u{112DF}', '\u{112DF}', NonspacingMark),
+    ('\u{112E3}', '\u{112EA}', NonspacingMark),
+    ('\u{11300}', '\u{11301}', NonspacingMark),
+    ('\u{1133B}', '\u{1133C}', NonspacingMark),
+    ('\u{11340}', '\u{11340}', NonspacingMark),
+    ('\u{11366}', '\u{1136C}', NonspacingMark),
+    ('\u{11370}', '\u{11374}', NonspacingMark),
+    ('\u{113BB}', '\u{113C0}', NonspacingMark),
+    ('\u{113CE}', '\u{113CE}', NonspacingMark),
+    ('\u{113D0}', '\u{113D0}', NonspacingMark),
+    ('\u{113D2}', '\u{113D2}', NonspacingMark),
+    ('\u{113E1}', '\u{113E2}', NonspacingMark),
+    ('\u{11438}', '\u{1143F}', NonspacingMark),
+    ('\u{11442}', '\u{11444}', NonspacingMark),
+    ('\u{11446}', '\u{11446}', NonspacingMark),
+    ('\u{1145E}', '\u{1145E}', NonspacingMark),
+    ('\u{114B3}', '\u{114B8}', NonspacingMark),
+    ('\u{114BA}', '\u{114BA}', NonspacingMark),
+    ('\u{114BF}', '\u{114C0}', NonspacingMark),
+    ('\u{114C2}', '\u{114C3}', NonspacingMark),
+    ('\u{115B2}', '\u{115B5}', NonspacingMark),
+    ('\u{115BC}', '\u{115BD}', NonspacingMark),
+    ('\u{115BF}', '\u{115C0}', NonspacingMark),
+    ('\u{115DC}', '\u{115DD}', NonspacingMark),
+    ('\u{11633}', '\u{1163A}', NonspacingMark),
+    ('\u{1163D}', '\u{1163D}', NonspacingMark),
+    ('\u{1163F}', '\u{11640}', NonspacingMark),
+    ('\u{11660}', '\u{1166C}', OtherNeutral),
+    ('\u{116AB}', '\u{116AB}', NonspacingMark),
+    ('\u{116AD}', '\u{116AD}', NonspacingMark),
+    ('\u{116B0}', '\u{116B5}', NonspacingMark),
+    ('\u{116B7}', '\u{116B7}', NonspacingMark),
+    ('\u{1171D}', '\u{1171F}', NonspacingMark),
+    ('\u{11722}', '\u{11725}', NonspacingMark),
+    ('\u{11727}', '\u{1172B}', NonspacingMark),
+    ('\u{1182F}', '\u{11837}', NonspacingMark),
+    ('\u{11839}', '\u{1183A}', NonspacingMark),
+    ('\u{1193B}', '\u{1193C}', NonspacingMark),
+    ('\u{1193E}', '\u{1193E}', NonspacingMark),
+    ('\u{11943}', '\u{11943}', NonspacingMark),
+    ('\u{119D4}', '\u{119D7}', NonspacingMark),
+    ('\u{119DA}', '\u{119DB}', NonspacingMark),
+    ('\u{119E0}', '\u{119E0}', NonspacingMark),
+    ('\u{11A01}', '\u{11A06}', NonspacingMark),
+    ('\u{11A09}', '\u{11A0A}', NonspacingMark),
+    ('\u{11A33}', '\u{11A38}', NonspacingMark),
+    ('\u{11A3B}', '\u{11A3E}', NonspacingMark),
+    ('\u{11A47}', '\u{11A47}', NonspacingMark),
+    ('\u{11A51}', '\u{11A56}', NonspacingMark),
+    ('\u{11A59}', '\u{11A5B}', NonspacingMark),
+    ('\u{11A8A}', '\u{11A96}', NonspacingMark),
+    ('\u{11A98}', '\u{11A99}', NonspacingMark),
+    ('\u{11C30}', '\u{11C36}', NonspacingMark),
+    ('\u{11C38}', '\u{11C3D}', NonspacingMark),
+    ('\u{11C92}', '\u{11CA7}', NonspacingMark),
+    ('\u{11CAA}', '\u{11CB0}', NonspacingMark),
+    ('\u{11CB2}', '\u{11CB3}', NonspacingMark),
+    ('\u{11CB5}', '\u{11CB6}', NonspacingMark),
+    ('\u{11D31}', '\u{11D36}', NonspacingMark),
+    ('\u{11D3A}', '\u{11D3A}', NonspacingMark),
+    ('\u{11D3C}', '\u{11D3D}', NonspacingMark),
+    ('\u{11D3F}', '\u{11D45}', NonspacingMark),
+    ('\u{11D47}', '\u{11D47}', NonspacingMark),
+    ('\u{11D90}', '\u{11D91}', NonspacingMark),
+    ('\u{11D95}', '\u{11D95}', NonspacingMark),
+    ('\u{11D97}', '\u{11D97}', NonspacingMark),
+    ('\u{11EF3}', '\u{11EF4}', NonspacingMark),
+    ('\u{11F00}', '\u{11F01}', NonspacingMark),
+    ('\u{11F36}', '\u{11F3A}', NonspacingMark),
+    ('\u{11F40}', '\u{11F40}', NonspacingMark),
+    ('\u{11F42}', '\u{11F42}', NonspacingMark),
+    ('\u{11F5A}', '\u{11F5A}', NonspacingMark),
+    ('\u{11FD5}', '\u{11FDC}', OtherNeutral),
+    ('\u{11FDD}', '\u{11FE0}', EuropeanTerminator),
+    ('\u{11FE1}', '\u{11FF1}', OtherNeutral),
+    ('\u{13440}', '\u{13440}', NonspacingMark),
+    ('\u{13447}', '\u{13455}', NonspacingMark),
+    ('\u{1611E}', '\u{16129}', NonspacingMark),
+    ('\u{1612D}', '\u{1612F}', NonspacingMark),
+    ('\u{16AF0}', '\u{16AF4}', NonspacingMark),
+    ('\u{16B30}', '\u{16B36}', NonspacingMark),
+    ('\u{16F4F}', '\u{16F4F}', NonspacingMark),
+    ('\u{16F8F}', '\u{16F92}', NonspacingMark),
+    ('\u{16FE2}', '\u{16FE2}', OtherNeutral),
+    ('\u{16FE4}', '\u{16FE4}', NonspacingMark),
+    ('\u{1BC9D}', '\u{1BC9E}', NonspacingMark),
+    ('\u{1BCA0}', '\u{1BCA3}', BoundaryNeutral),
+    ('\u{1CF00}', '\u{1CF2D}', NonspacingMark),
+    ('\u{1CF30}', '\u{1CF46}', NonspacingMark),
+    ('\u{1D167}', '\u{1D169}', NonspacingMark),
+    ('\u{1D173}', '\u{1D17A}', BoundaryNeutral),
+    ('\u{1D17B}', '\u{1D182}', NonspacingMark),
+    ('\u{1D185}', '\u{1D18B}', NonspacingMark),
+    ('\u{1D1AA}', '\u{1D1AD}', NonspacingMark),
+    ('\u{1D1E9}', '\u{1D1EA}', OtherNeutral),
+    ('\u{1D200}', '\u{1D241}', OtherNeutral),
+    ('\u{1D242}', '\u{1D244}', NonspacingMark),
+    ('\u{1D245}', '\u{1D245}', OtherNeutral),
+    ('\u{1D300}', '\u{1D356}', OtherNeutral),
+    ('\u{1D6DB}', '\u{1D6DB}', OtherNeutral),
+    ('\u{1D715}', '\u{1D715}', OtherNeutral),
+    ('\u{1D74F}', '\u{1D74F}', OtherNeutral),
+    ('\u{1D789}', '\u{1D789}', OtherNeutral),
+    ('\u{1D7C3}', '\u{1D7C3}', OtherNeutral),
+    ('\u{1D7CE}', '\u{1D7FF}', EuropeanNumber),
+    ('\u{1DA00}', '\u{1DA36}', NonspacingMark),
+    ('\u{1DA3B}', '\u{1DA6C}', NonspacingMark),
+    ('\u{1DA75}', '\u{1DA75}', NonspacingMark),
+    ('\u{1DA84}', '\u{1DA84}', NonspacingMark),
+    ('\u{1DA9B}', '\u{1DA9F}', NonspacingMark),
+    ('\u{1DAA1}', '\u{1DAAF}', NonspacingMark),
+    ('\u{1E000}', '\u{1E006}', NonspacingMark),
+    ('\u{1E008}', '\u{1E018}', NonspacingMark),
+    ('\u{1E01B}', '\u{1E021}', NonspacingMark),
+    ('\u{1E023}', '\u{1E024}', NonspacingMark),
+    ('\u{1E026}', '\u{1E02A}', NonspacingMark),
+    ('\u{1E08F}', '\u{1E08F}', NonspacingMark),
+    ('\u{1E130}', '\u{1E136}', NonspacingMark),
+    ('\u{1E2AE}', '\u{1E2AE}', NonspacingMark),
+    ('\u{1E2EC}', '\u{1E2EF}', NonspacingMark),
+    ('\u{1E2FF}', '\u{1E2FF}', EuropeanTerminator),
+    ('\u{1E4EC}', '\u{1E4EF}', NonspacingMark),
+    ('\u{1E5EE}', '\u{1E5EF}', NonspacingMark),
+    ('\u{1E800}', '\u{1E8CF}', RightToLeft),
+    ('\u{1E8D0}', '\u{1E8D6}', NonspacingMark),
+    ('\u{1E8D7}', '\u{1E943}', RightToLeft),
+    ('\u{1E944}', '\u{1E94A}', NonspacingMark),
+    ('\u{1E94B}', '\u{1EC6F}', RightToLeft),
+    ('\u{1EC70}', '\u{1ECBF}', ArabicLetter),
+    ('\u{1ECC0}', '\u{1ECFF}', RightToLeft),
+    ('\u{1ED00}', '\u{1ED4F}', ArabicLetter),
+    ('\u{1ED50}', '\u{1EDFF}', RightToLeft),
+    ('\u{1EE00}', '\u{1EEEF}', ArabicLetter),
+    ('\u{1EEF0}', '\u{1EEF1}', OtherNeutral),
+    ('\u{1EEF2}', '\u{1EEFF}', ArabicLetter),
+    ('\u{1EF00}', '\u{1EFFF}', RightToLeft),
+    ('\u{1F000}', '\u{1F02B}', OtherNeutral),
+    ('\u{1F030}', '\u{1F093}', OtherNeutral),
+    ('\u{1F0A0}', '\u{1F0AE}', OtherNeutral),
+    ('\u{1F0B1}', '\u{1F0BF}', OtherNeutral),
+    ('\u{1F0C1}', '\u{1F0CF}', OtherNeutral),
+    ('\u{1F0D1}', '\u{1F0F5}', OtherNeutral),
+    ('\u{1F100}', '\u{1F10A}', EuropeanNumber),
+    ('\u{1F10B}', '\u{1F10F}', OtherNeutral),
+    ('\u{1F12F}', '\u{1F12F}', OtherNeutral),
+    ('\u{1F16A}', '\u{1F16F}', OtherNeutral),
+    ('\u{1F1AD}', '\u{1F1AD}', OtherNeutral),
+    ('\u{1F260}', '\u{1F265}', OtherNeutral),
+    ('\u{1F300}', '\u{1F6D7}', OtherNeutral),
+    ('\u{1F6DC}', '\u{1F6EC}', OtherNeutral),
+    ('\u{1F6F0}', '\u{1F6FC}', OtherNeutral),
+    ('\u{1F700}', '\u{1F776}', OtherNeutral),
+    ('\u{1F77B}', '\u{1F7D9}', OtherNeutral),
+    ('\u{1F7E0}', '\u{1F7EB}', OtherNeutral),
+    ('\u{1F7F0}', '\u{1F7F0}', OtherNeutral),
+    ('\u{1F800}', '\u{1F80B}', OtherNeutral),
+    ('\u{1F810}', '\u{1F847}', OtherNeutral),
+    ('\u{1F850}', '\u{1F859}', OtherNeutral),
+    ('\u{1F860}', '\u{1F887}', OtherNeutral),
+    ('\u{1F890}', '\u{1F8AD}', OtherNeutral),
+    ('\u{1F8B0}', '\u{1F8B1}', OtherNeutral),
+    ('\u{1F900}', '\u{1FA53}', OtherNeutral),
+    ('\u{1FA60}', '\u{1FA6D}', OtherNeutral),
+    ('\u{1FA70}', '\u{1FA7C}', OtherNeutral),
+    ('\u{1FA80}', '\u{1FA88}', OtherNeutral),
+    ('\u{1FA90}', '\u{1FABD}', OtherNeutral),
+    ('\u{1FABF}', '\u{1FAC5}', OtherNeutral),
+    ('\u{1FACE}', '\u{1FADB}', OtherNeutral),
+    ('\u{1FAE0}', '\u{1FAE8}', OtherNeutral),
+    ('\u{1FAF0}', '\u{1FAF8}', OtherNeutral),
+    ('\u{1FB00}', '\u{1FB92}', OtherNeutral),
+    ('\u{1FB94}', '\u{1FBCA}', OtherNeutral),
+    ('\u{1FBF0}', '\u{1FBF9}', EuropeanNumber),
+    ('\u{1FFFE}', '\u{1FFFF}', BoundaryNeutral),
+    ('\u{2FFFE}', '\u{2FFFF}', BoundaryNeutral),
+    ('\u{3FFFE}', '\u{3FFFF}', BoundaryNeutral),
+    ('\u{4FFFE}', '\u{4FFFF}', BoundaryNeutral),
+    ('\u{5FFFE}', '\u{5FFFF}', BoundaryNeutral),
+    ('\u{6FFFE}', '\u{6FFFF}', BoundaryNeutral),
+    ('\u{7FFFE}', '\u{7FFFF}', BoundaryNeutral),
+    ('\u{8FFFE}', '\u{8FFFF}', BoundaryNeutral),
+    ('\u{9FFFE}', '\u{9FFFF}', BoundaryNeutral),
+    ('\u{AFFFE}', '\u{AFFFF}', BoundaryNeutral),
+    ('\u{BFFFE}', '\u{BFFFF}', BoundaryNeutral),
+    ('\u{CFFFE}', '\u{CFFFF}', BoundaryNeutral),
+    ('\u{DFFFE}', '\u{E00FF}', BoundaryNeutral),
+    ('\u{E0100}', '\u{E01EF}', NonspacingMark),
+    ('\u{E01F0}', '\u{E0FFF}', BoundaryNeutral),
+    ('\u{EFFFE}', '\u{EFFFF}', BoundaryNeutral),
+    ('\u{FFFFE}', '\u{FFFFF}', BoundaryNeutral),
+    ('\u{10FFFE}', '\u{10FFFF}', BoundaryNeutral),
+];
+
+/// Bidi paired brackets: (bracket, paired bracket, is opening)
+pub(crate) const BIDI_BRACKETS: &[(char, char, bool)] = &[
+    ('\u{28}', '\u{29}', true),
+    ('\u{29}', '\u{28}', false),
+    ('\u{5B}', '\u{5D}', true),
+    ('\u{5D}', '\u{5B}', false),
+    ('\u{7B}', '\u{7D}', true),
+    ('\u{7D}', '\u{7B}', false),
+    ('\u{F3A}', '\u{F3B}', true),
+    ('\u{F3B}', '\u{F3A}', false),
+    ('\u{F3C}', '\u{F3D}', true),
+    ('\u{F3D}', '\u{F3C}', false),
+    ('\u{169B}', '\u{169C}', true),
+    ('\u{169C}', '\u{169B}', false),
+    ('\u{2045}', '\u{2046}', true),
+    ('\u{2046}', '\u{2045}', false),
+    ('\u{207D}', '\u{207E}', true),
+    ('\u{207E}', '\u{207D}', false),
+    ('\u{208D}', '\u{208E}', true),
+    ('\u{208E}', '\u{208D}', false),
+    ('\u{2308}', '\u{2309}', true),
+    ('\u{2309}', '\u{2308}', false),
+    ('\u{230A}', '\u{230B}', true),
+    ('\u{230B}', '\u{230A}', false),
+    ('\u{2329}', '\u{232A}', true),
+    ('\u{232A}', '\u{2329}', false),
+    ('\u{2768}', '\u{2769}', true),
+    ('\u{2769}', '\u{2768}', false),
+    ('\u{276A}', '\u{276B}', true),
+    ('\u{276B}', '\u{276A}', false),
+    ('\u{276C}', '\u{276D}', true),
+    ('\u{276D}', '\u{276C}', false),
+    ('\u{276E}', '\u{276F}', true),
+    ('\u{276F}', '\u{276E}', false),
+    ('\u{2770}', '\u{2771}', true),
+    ('\u{2771}', '\u{2770}', false),
+    ('\u{2772}', '\u{2773}', true),
+    ('\u{2773}', '\u{2772}', false),
+    ('\u{2774}', '\u{2775}', true),
+    ('\u{2775}', '\u{2774}', false),
+    ('\u{27C5}', '\u{27C6}', true),
+    ('\u{27C6}', '\u{27C5}', false),
+    ('\u{27E6}', '\u{27E7}', true),
+    ('\u{27E7}', '\u{27E6}', false),
+    ('\u{27E8}', '\u{27E9}', true),
+    ('\u{27E9}', '\u{27E8}', false),
+    ('\u{27EA}', '\u{27EB}', true),
+    ('\u{27EB}', '\u{27EA}', false),
+    ('\u{27EC}', '\u{27ED}', true),
+    ('\u{27ED}', '\u{27EC}', false),
+    ('\u{27EE}', '\u{27EF}', true),
+    ('\u{27EF}', '\u{27EE}', false),
+    ('\u{2983}', '\u{2984}', true),
+    ('\u{2984}', '\u{2983}', false),
+    ('\u{2985}', '\u{2986}', true),
+    ('\u{2986}', '\u{2985}', false),
+    ('\u{2987}', '\u{2988}', true),
+    ('\u{2988}', '\u{2987}', false),
+    ('\u{2989}', '\u{298A}', true),
+    ('\u{298A}', '\u{2989}', false),
+    ('\u{298B}', '\u{298C}', true),
+    ('\u{298C}', '\u{298B}', false),
+    ('\u{298D}', '\u{2990}', true),
+    ('\u{298E}', '\u{298F}', false),
+    ('\u{298F}', '\u{298E}', true),
+    ('\u{2990}', '\u{298D}', false),
+    ('\u{2991}', '\u{2992}', true),
+    ('\u{2992}', '\u{2991}', false),
+    ('\u{2993}', '\u{2994}', true),
+    ('\u{2994}', '\u{2993}', false),
+    ('\u{2995}', '\u{2996}', true),
+    ('\u{2996}', '\u{2995}', false),
+    ('\u{2997}', '\u{2998}', true),
+    ('\u{2998}', '\u{2997}', false),
+    ('\u{29D8}', '\u{29D9}', true),
+    ('\u{29D9}', '\u{29D8}', false),
+    ('\u{29DA}', '\u{29DB}', true),
+    ('\u{29DB}', '\u{29DA}', false),
+    ('\u{29FC}', '\u{29FD}', true),
+    ('\u{29FD}', '\u{29FC}', false),
+    ('\u{2E22}', '\u{2E23}', true),
+    ('\u{2E23}', '\u{2E22}', false),
+    ('\u{2E24}', '\u{2E25}', true),
+    ('\u{2E25}', '\u{2E24}', false),
+    ('\u{2E26}', '\u{2E27}', true),
+    ('\u{2E27}', '\u{2E26}', false),
+    ('\u{2E28}', '\u{2E29}', true),
+    ('\u{2E29}', '\u{2E28}', false),
+    ('\u{2E55}', '\u{2E56}', true),
+    ('\u{2E56}', '\u{2E55}', false),
+    ('\u{2E57}', '\u{2E58}', true),
+    ('\u{2E58}', '\u{2E57}', false),
+    ('\u{2E59}', '\u{2E5A}', true),
+    ('\u{2E5A}', '\u{2E59}', false),
+    ('\u{2E5B}', '\u{2E5C}', true),
+    ('\u{2E5C}', '\u{2E5B}', false),
+    ('\u{3008}', '\u{3009}', true),
+    ('\u{3009}', '\u{3008}', false),
+    ('\u{300A}', '\u{300B}', true),
+    ('\u{300B}', '\u{300A}', false),
+    ('\u{300C}', '\u{300D}', true),
+    ('\u{300D}', '\u{300C}', false),
+    ('\u{300E}', '\u{300F}', true),
+    ('\u{300F}', '\u{300E}', false),
+    ('\u{3010}', '\u{3011}', true),
+    ('\u{3011}', '\u{3010}', false),
+    ('\u{3014}', '\u{3015}', true),
+    ('\u{3015}', '\u{3014}', false),
+    ('\u{3016}', '\u{3017}', true),
+    ('\u{3017}', '\u{3016}', false),
+    ('\u{3018}', '\u{3019}', true),
+    ('\u{3019}', '\u{3018}', false),
+    ('\u{301A}', '\u{301B}', true),
+    ('\u{301B}', '\u{301A}', false),
+    ('\u{FE59}', '\u{FE5A}', true),
+    ('\u{FE5A}', '\u{FE59}', false),
+    ('\u{FE5B}', '\u{FE5C}', true),
+    ('\u{FE5C}', '\u{FE5B}', false),
+    ('\u{FE5D}', '\u{FE5E}', true),
+    ('\u{FE5E}', '\u{FE5D}', false),
+    ('\u{FF08}', '\u{FF09}', true),
+    ('\u{FF09}', '\u{FF08}', false),
+    ('\u{FF3B}', '\u{FF3D}', true),
+    ('\u{FF3D}', '\u{FF3B}', false),
+    ('\u{FF5B}', '\u{FF5D}', true),
+    ('\u{FF5D}', '\u{FF5B}', false),
+    ('\u{FF5F}', '\u{FF60}', true),
+    ('\u{FF60}', '\u{FF5F}', false),
+    ('\u{FF62}', '\u{FF63}', true),
+    ('\u{FF63}', '\u{FF62}', false),
+];
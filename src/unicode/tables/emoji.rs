@@ -0,0 +1,1484 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+/// UTS #51 RGI emoji ZWJ sequences without U+FE0F, sorted
+pub(crate) const RGI_EMOJI_ZWJ_SEQUENCES: &[&str] = &[
+    "\u{26D3}\u{200D}\u{1F4A5}",
+    "\u{26F9}\u{200D}\u{2640}",
+    "\u{26F9}\u{200D}\u{2642}",
+    "\u{26F9}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{26F9}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{26F9}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{26F9}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{26F9}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{26F9}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{26F9}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{26F9}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{26F9}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{26F9}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{2764}\u{200D}\u{1F525}",
+    "\u{2764}\u{200D}\u{1FA79}",
+    "\u{1F344}\u{200D}\u{1F7EB}",
+    "\u{1F34B}\u{200D}\u{1F7E9}",
+    "\u{1F3C3}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{27A1}",
+    "\u{1F3C4}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{200D}\u{2642}",
+    "\u{1F3C4}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F3C4}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F3C4}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F3C4}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F3C4}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F3C4}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F3CA}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F3CA}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F3CB}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F3CB}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F3CC}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F3CC}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F3F3}\u{200D}\u{26A7}",
+    "\u{1F3F3}\u{200D}\u{1F308}",
+    "\u{1F3F4}\u{200D}\u{2620}",
+    "\u{1F408}\u{200D}\u{2B1B}",
+    "\u{1F415}\u{200D}\u{1F9BA}",
+    "\u{1F426}\u{200D}\u{2B1B}",
+    "\u{1F426}\u{200D}\u{1F525}",
+    "\u{1F43B}\u{200D}\u{2744}",
+    "\u{1F441}\u{200D}\u{1F5E8}",
+    "\u{1F468}\u{200D}\u{2695}",
+    "\u{1F468}\u{200D}\u{2696}",
+    "\u{1F468}\u{200D}\u{2708}",
+    "\u{1F468}\u{200D}\u{2764}\u{200D}\u{1F468}",
+    "\u{1F468}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    "\u{1F468}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{200D}\u{1F373}",
+    "\u{1F468}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{200D}\u{1F393}",
+    "\u{1F468}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{200D}\u{1F527}",
+    "\u{1F468}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{200D}\u{1F680}",
+    "\u{1F468}\u{200D}\u{1F692}",
+    "\u{1F468}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2695}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2696}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2708}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2695}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2696}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2708}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2695}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2696}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2708}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2695}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2696}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2708}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2695}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2696}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2708}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{200D}\u{2695}",
+    "\u{1F469}\u{200D}\u{2696}",
+    "\u{1F469}\u{200D}\u{2708}",
+    "\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F468}",
+    "\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F469}",
+    "\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    "\u{1F469}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}",
+    "\u{1F469}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{200D}\u{1F373}",
+    "\u{1F469}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{200D}\u{1F393}",
+    "\u{1F469}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{200D}\u{1F527}",
+    "\u{1F469}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{200D}\u{1F680}",
+    "\u{1F469}\u{200D}\u{1F692}",
+    "\u{1F469}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2695}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2696}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2708}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2695}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2696}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2708}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2695}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2696}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2708}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2695}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2696}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2708}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2695}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2696}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2708}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F46E}\u{200D}\u{2640}",
+    "\u{1F46E}\u{200D}\u{2642}",
+    "\u{1F46E}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F46E}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F46E}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F46E}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F46E}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F46E}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F46E}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F46E}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F46E}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F46E}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F46F}\u{200D}\u{2640}",
+    "\u{1F46F}\u{200D}\u{2642}",
+    "\u{1F470}\u{200D}\u{2640}",
+    "\u{1F470}\u{200D}\u{2642}",
+    "\u{1F470}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F470}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F470}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F470}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F470}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F470}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F470}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F470}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F470}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F470}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F471}\u{200D}\u{2640}",
+    "\u{1F471}\u{200D}\u{2642}",
+    "\u{1F471}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F471}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F471}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F471}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F471}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F471}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F471}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F471}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F471}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F471}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F473}\u{200D}\u{2640}",
+    "\u{1F473}\u{200D}\u{2642}",
+    "\u{1F473}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F473}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F473}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F473}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F473}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F473}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F473}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F473}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F473}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F473}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F477}\u{200D}\u{2640}",
+    "\u{1F477}\u{200D}\u{2642}",
+    "\u{1F477}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F477}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F477}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F477}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F477}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F477}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F477}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F477}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F477}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F477}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F481}\u{200D}\u{2640}",
+    "\u{1F481}\u{200D}\u{2642}",
+    "\u{1F481}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F481}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F481}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F481}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F481}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F481}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F481}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F481}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F481}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F481}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F482}\u{200D}\u{2640}",
+    "\u{1F482}\u{200D}\u{2642}",
+    "\u{1F482}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F482}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F482}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F482}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F482}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F482}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F482}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F482}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F482}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F482}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F486}\u{200D}\u{2640}",
+    "\u{1F486}\u{200D}\u{2642}",
+    "\u{1F486}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F486}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F486}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F486}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F486}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F486}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F486}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F486}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F486}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F486}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F487}\u{200D}\u{2640}",
+    "\u{1F487}\u{200D}\u{2642}",
+    "\u{1F487}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F487}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F487}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F487}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F487}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F487}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F487}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F487}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F487}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F487}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F575}\u{200D}\u{2640}",
+    "\u{1F575}\u{200D}\u{2642}",
+    "\u{1F575}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F575}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F575}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F575}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F575}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F575}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F575}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F575}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F575}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F575}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F62E}\u{200D}\u{1F4A8}",
+    "\u{1F635}\u{200D}\u{1F4AB}",
+    "\u{1F636}\u{200D}\u{1F32B}",
+    "\u{1F642}\u{200D}\u{2194}",
+    "\u{1F642}\u{200D}\u{2195}",
+    "\u{1F645}\u{200D}\u{2640}",
+    "\u{1F645}\u{200D}\u{2642}",
+    "\u{1F645}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F645}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F645}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F645}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F645}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F645}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F645}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F645}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F645}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F645}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F646}\u{200D}\u{2640}",
+    "\u{1F646}\u{200D}\u{2642}",
+    "\u{1F646}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F646}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F646}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F646}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F646}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F646}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F646}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F646}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F646}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F646}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F647}\u{200D}\u{2640}",
+    "\u{1F647}\u{200D}\u{2642}",
+    "\u{1F647}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F647}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F647}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F647}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F647}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F647}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F647}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F647}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F647}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F647}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F64B}\u{200D}\u{2640}",
+    "\u{1F64B}\u{200D}\u{2642}",
+    "\u{1F64B}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F64B}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F64B}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F64B}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F64B}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F64B}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F64B}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F64B}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F64B}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F64B}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F64D}\u{200D}\u{2640}",
+    "\u{1F64D}\u{200D}\u{2642}",
+    "\u{1F64D}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F64D}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F64D}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F64D}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F64D}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F64D}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F64D}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F64D}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F64D}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F64D}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F64E}\u{200D}\u{2640}",
+    "\u{1F64E}\u{200D}\u{2642}",
+    "\u{1F64E}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F64E}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F64E}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F64E}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F64E}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F64E}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F64E}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F64E}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F64E}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F64E}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F6A3}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F6A3}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F6B4}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F6B4}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F6B5}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F6B5}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{27A1}",
+    "\u{1F926}\u{200D}\u{2640}",
+    "\u{1F926}\u{200D}\u{2642}",
+    "\u{1F926}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F926}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F926}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F926}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F926}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F926}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F926}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F926}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F926}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F926}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F935}\u{200D}\u{2640}",
+    "\u{1F935}\u{200D}\u{2642}",
+    "\u{1F935}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F935}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F935}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F935}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F935}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F935}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F935}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F935}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F935}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F935}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F937}\u{200D}\u{2640}",
+    "\u{1F937}\u{200D}\u{2642}",
+    "\u{1F937}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F937}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F937}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F937}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F937}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F937}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F937}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F937}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F937}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F937}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F938}\u{200D}\u{2640}",
+    "\u{1F938}\u{200D}\u{2642}",
+    "\u{1F938}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F938}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F938}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F938}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F938}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F938}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F938}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F938}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F938}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F938}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F939}\u{200D}\u{2640}",
+    "\u{1F939}\u{200D}\u{2642}",
+    "\u{1F939}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F939}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F939}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F939}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F939}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F939}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F939}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F939}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F939}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F939}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F93C}\u{200D}\u{2640}",
+    "\u{1F93C}\u{200D}\u{2642}",
+    "\u{1F93D}\u{200D}\u{2640}",
+    "\u{1F93D}\u{200D}\u{2642}",
+    "\u{1F93D}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F93D}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F93D}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F93D}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F93D}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F93D}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F93D}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F93D}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F93D}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F93D}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F93E}\u{200D}\u{2640}",
+    "\u{1F93E}\u{200D}\u{2642}",
+    "\u{1F93E}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F93E}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F93E}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F93E}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F93E}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F93E}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F93E}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F93E}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F93E}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F93E}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9B8}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9B8}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9B9}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9B9}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9CD}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9CD}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}\u{200D}\u{27A1}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{27A1}",
+    "\u{1F9CF}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{200D}\u{2642}",
+    "\u{1F9CF}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9CF}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9CF}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9CF}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9CF}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9CF}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9D1}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}",
+    "\u{1F9D1}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2695}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2696}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2708}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}",
+    "\u{1F9D4}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{200D}\u{2642}",
+    "\u{1F9D4}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9D4}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9D4}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9D4}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9D4}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9D4}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9D6}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9D6}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9D7}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9D7}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9D8}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9D8}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9D9}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9D9}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9DA}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9DA}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9DB}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9DB}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9DC}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9DC}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{1F3FB}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{1F3FB}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{1F3FC}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{1F3FC}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{1F3FD}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{1F3FD}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{1F3FE}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{1F3FE}\u{200D}\u{2642}",
+    "\u{1F9DD}\u{1F3FF}\u{200D}\u{2640}",
+    "\u{1F9DD}\u{1F3FF}\u{200D}\u{2642}",
+    "\u{1F9DE}\u{200D}\u{2640}",
+    "\u{1F9DE}\u{200D}\u{2642}",
+    "\u{1F9DF}\u{200D}\u{2640}",
+    "\u{1F9DF}\u{200D}\u{2642}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FE}",
+];
+
+/// UTS #51 RGI emoji tag sequences, sorted
+pub(crate) const RGI_EMOJI_TAG_SEQUENCES: &[&str] = &[
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+];
@@ -0,0 +1,1386 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+use crate::unicode::security::grapheme::GraphemeClusterBreak::{self, *};
+
+pub(crate) const GRAPHEME_CLUSTER_BREAK: &[(char, char, GraphemeClusterBreak)] = &[
+    ('\u{0}', '\u{9}', Control),
+    ('\u{A}', '\u{A}', LF),
+    ('\u{B}', '\u{C}', Control),
+    ('\u{D}', '\u{D}', CR),
+    ('\u{E}', '\u{1F}', Control),
+    ('\u{7F}', '\u{9F}', Control),
+    ('\u{AD}', '\u{AD}', Control),
+    ('\u{300}', '\u{36F}', Extend),
+    ('\u{483}', '\u{489}', Extend),
+    ('\u{591}', '\u{5BD}', Extend),
+    ('\u{5BF}', '\u{5BF}', Extend),
+    ('\u{5C1}', '\u{5C2}', Extend),
+    ('\u{5C4}', '\u{5C5}', Extend),
+    ('\u{5C7}', '\u{5C7}', Extend),
+    ('\u{600}', '\u{605}', Prepend),
+    ('\u{610}', '\u{61A}', Extend),
+    ('\u{61C}', '\u{61C}', Control),
+    ('\u{64B}', '\u{65F}', Extend),
+    ('\u{670}', '\u{670}', Extend),
+    ('\u{6D6}', '\u{6DC}', Extend),
+    ('\u{6DD}', '\u{6DD}', Prepend),
+    ('\u{6DF}', '\u{6E4}', Extend),
+    ('\u{6E7}', '\u{6E8}', Extend),
+    ('\u{6EA}', '\u{6ED}', Extend),
+    ('\u{70F}', '\u{70F}', Prepend),
+    ('\u{711}', '\u{711}', Extend),
+    ('\u{730}', '\u{74A}', Extend),
+    ('\u{7A6}', '\u{7B0}', Extend),
+    ('\u{7EB}', '\u{7F3}', Extend),
+    ('\u{7FD}', '\u{7FD}', Extend),
+    ('\u{816}', '\u{819}', Extend),
+    ('\u{81B}', '\u{823}', Extend),
+    ('\u{825}', '\u{827}', Extend),
+    ('\u{829}', '\u{82D}', Extend),
+    ('\u{859}', '\u{85B}', Extend),
+    ('\u{890}', '\u{891}', Prepend),
+    ('\u{897}', '\u{89F}', Extend),
+    ('\u{8CA}', '\u{8E1}', Extend),
+    ('\u{8E2}', '\u{8E2}', Prepend),
+    ('\u{8E3}', '\u{902}', Extend),
+    ('\u{903}', '\u{903}', SpacingMark),
+    ('\u{93A}', '\u{93A}', Extend),
+    ('\u{93B}', '\u{93B}', SpacingMark),
+    ('\u{93C}', '\u{93C}', Extend),
+    ('\u{93E}', '\u{940}', SpacingMark),
+    ('\u{941}', '\u{948}', Extend),
+    ('\u{949}', '\u{94C}', SpacingMark),
+    ('\u{94D}', '\u{94D}', Extend),
+    ('\u{94E}', '\u{94F}', SpacingMark),
+    ('\u{951}', '\u{957}', Extend),
+    ('\u{962}', '\u{963}', Extend),
+    ('\u{981}', '\u{981}', Extend),
+    ('\u{982}', '\u{983}', SpacingMark),
+    ('\u{9BC}', '\u{9BC}', Extend),
+    ('\u{9BE}', '\u{9BE}', Extend),
+    ('\u{9BF}', '\u{9C0}', SpacingMark),
+    ('\u{9C1}', '\u{9C4}', Extend),
+    ('\u{9C7}', '\u{9C8}', SpacingMark),
+    ('\u{9CB}', '\u{9CC}', SpacingMark),
+    ('\u{9CD}', '\u{9CD}', Extend),
+    ('\u{9D7}', '\u{9D7}', Extend),
+    ('\u{9E2}', '\u{9E3}', Extend),
+    ('\u{9FE}', '\u{9FE}', Extend),
+    ('\u{A01}', '\u{A02}', Extend),
+    ('\u{A03}', '\u{A03}', SpacingMark),
+    ('\u{A3C}', '\u{A3C}', Extend),
+    ('\u{A3E}', '\u{A40}', SpacingMark),
+    ('\u{A41}', '\u{A42}', Extend),
+    ('\u{A47}', '\u{A48}', Extend),
+    ('\u{A4B}', '\u{A4D}', Extend),
+    ('\u{A51}', '\u{A51}', Extend),
+    ('\u{A70}', '\u{A71}', Extend),
+    ('\u{A75}', '\u{A75}', Extend),
+    ('\u{A81}', '\u{A82}', Extend),
+    ('\u{A83}', '\u{A83}', SpacingMark),
+    ('\u{ABC}', '\u{ABC}', Extend),
+    ('\u{ABE}', '\u{AC0}', SpacingMark),
+    ('\u{AC1}', '\u{AC5}', Extend),
+    ('\u{AC7}', '\u{AC8}', Extend),
+    ('\u{AC9}', '\u{AC9}', SpacingMark),
+    ('\u{ACB}', '\u{ACC}', SpacingMark),
+    ('\u{ACD}', '\u{ACD}', Extend),
+    ('\u{AE2}', '\u{AE3}', Extend),
+    ('\u{AFA}', '\u{AFF}', Extend),
+    ('\u{B01}', '\u{B01}', Extend),
+    ('\u{B02}', '\u{B03}', SpacingMark),
+    ('\u{B3C}', '\u{B3C}', Extend),
+    ('\u{B3E}', '\u{B3F}', Extend),
+    ('\u{B40}', '\u{B40}', SpacingMark),
+    ('\u{B41}', '\u{B44}', Extend),
+    ('\u{B47}', '\u{B48}', SpacingMark),
+    ('\u{B4B}', '\u{B4C}', SpacingMark),
+    ('\u{B4D}', '\u{B4D}', Extend),
+    ('\u{B55}', '\u{B57}', Extend),
+    ('\u{B62}', '\u{B63}', Extend),
+    ('\u{B82}', '\u{B82}', Extend),
+    ('\u{BBE}', '\u{BBE}', Extend),
+    ('\u{BBF}', '\u{BBF}', SpacingMark),
+    ('\u{BC0}', '\u{BC0}', Extend),
+    ('\u{BC1}', '\u{BC2}', SpacingMark),
+    ('\u{BC6}', '\u{BC8}', SpacingMark),
+    ('\u{BCA}', '\u{BCC}', SpacingMark),
+    ('\u{BCD}', '\u{BCD}', Extend),
+    ('\u{BD7}', '\u{BD7}', Extend),
+    ('\u{C00}', '\u{C00}', Extend),
+    ('\u{C01}', '\u{C03}', SpacingMark),
+    ('\u{C04}', '\u{C04}', Extend),
+    ('\u{C3C}', '\u{C3C}', Extend),
+    ('\u{C3E}', '\u{C40}', Extend),
+    ('\u{C41}', '\u{C44}', SpacingMark),
+    ('\u{C46}', '\u{C48}', Extend),
+    ('\u{C4A}', '\u{C4D}', Extend),
+    ('\u{C55}', '\u{C56}', Extend),
+    ('\u{C62}', '\u{C63}', Extend),
+    ('\u{C81}', '\u{C81}', Extend),
+    ('\u{C82}', '\u{C83}', SpacingMark),
+    ('\u{CBC}', '\u{CBC}', Extend),
+    ('\u{CBE}', '\u{CBE}', SpacingMark),
+    ('\u{CBF}', '\u{CC0}', Extend),
+    ('\u{CC1}', '\u{CC1}', SpacingMark),
+    ('\u{CC2}', '\u{CC2}', Extend),
+    ('\u{CC3}', '\u{CC4}', SpacingMark),
+    ('\u{CC6}', '\u{CC8}', Extend),
+    ('\u{CCA}', '\u{CCD}', Extend),
+    ('\u{CD5}', '\u{CD6}', Extend),
+    ('\u{CE2}', '\u{CE3}', Extend),
+    ('\u{CF3}', '\u{CF3}', SpacingMark),
+    ('\u{D00}', '\u{D01}', Extend),
+    ('\u{D02}', '\u{D03}', SpacingMark),
+    ('\u{D3B}', '\u{D3C}', Extend),
+    ('\u{D3E}', '\u{D3E}', Extend),
+    ('\u{D3F}', '\u{D40}', SpacingMark),
+    ('\u{D41}', '\u{D44}', Extend),
+    ('\u{D46}', '\u{D48}', SpacingMark),
+    ('\u{D4A}', '\u{D4C}', SpacingMark),
+    ('\u{D4D}', '\u{D4D}', Extend),
+    ('\u{D4E}', '\u{D4E}', Prepend),
+    ('\u{D57}', '\u{D57}', Extend),
+    ('\u{D62}', '\u{D63}', Extend),
+    ('\u{D81}', '\u{D81}', Extend),
+    ('\u{D82}', '\u{D83}', SpacingMark),
+    ('\u{DCA}', '\u{DCA}', Extend),
+    ('\u{DCF}', '\u{DCF}', Extend),
+    ('\u{DD0}', '\u{DD1}', SpacingMark),
+    ('\u{DD2}', '\u{DD4}', Extend),
+    ('\u{DD6}', '\u{DD6}', Extend),
+    ('\u{DD8}', '\u{DDE}', SpacingMark),
+    ('\u{DDF}', '\u{DDF}', Extend),
+    ('\u{DF2}', '\u{DF3}', SpacingMark),
+    ('\u{E31}', '\u{E31}', Extend),
+    ('\u{E33}', '\u{E33}', SpacingMark),
+    ('\u{E34}', '\u{E3A}', Extend),
+    ('\u{E47}', '\u{E4E}', Extend),
+    ('\u{EB1}', '\u{EB1}', Extend),
+    ('\u{EB3}', '\u{EB3}', SpacingMark),
+    ('\u{EB4}', '\u{EBC}', Extend),
+    ('\u{EC8}', '\u{ECE}', Extend),
+    ('\u{F18}', '\u{F19}', Extend),
+    ('\u{F35}', '\u{F35}', Extend),
+    ('\u{F37}', '\u{F37}', Extend),
+    ('\u{F39}', '\u{F39}', Extend),
+    ('\u{F3E}', '\u{F3F}', SpacingMark),
+    ('\u{F71}', '\u{F7E}', Extend),
+    ('\u{F7F}', '\u{F7F}', SpacingMark),
+    ('\u{F80}', '\u{F84}', Extend),
+    ('\u{F86}', '\u{F87}', Extend),
+    ('\u{F8D}', '\u{F97}', Extend),
+    ('\u{F99}', '\u{FBC}', Extend),
+    ('\u{FC6}', '\u{FC6}', Extend),
+    ('\u{102D}', '\u{1030}', Extend),
+    ('\u{1031}', '\u{1031}', SpacingMark),
+    ('\u{1032}', '\u{1037}', Extend),
+    ('\u{1039}', '\u{103A}', Extend),
+    ('\u{103B}', '\u{103C}', SpacingMark),
+    ('\u{103D}', '\u{103E}', Extend),
+    ('\u{1056}', '\u{1057}', SpacingMark),
+    ('\u{1058}', '\u{1059}', Extend),
+    ('\u{105E}', '\u{1060}', Extend),
+    ('\u{1071}', '\u{1074}', Extend),
+    ('\u{1082}', '\u{1082}', Extend),
+    ('\u{1084}', '\u{1084}', SpacingMark),
+    ('\u{1085}', '\u{1086}', Extend),
+    ('\u{108D}', '\u{108D}', Extend),
+    ('\u{109D}', '\u{109D}', Extend),
+    ('\u{1100}', '\u{115F}', L),
+    ('\u{1160}', '\u{11A7}', V),
+    ('\u{11A8}', '\u{11FF}', T),
+    ('\u{135D}', '\u{135F}', Extend),
+    ('\u{1712}', '\u{1715}', Extend),
+    ('\u{1732}', '\u{1734}', Extend),
+    ('\u{1752}', '\u{1753}', Extend),
+    ('\u{1772}', '\u{1773}', Extend),
+    ('\u{17B4}', '\u{17B5}', Extend),
+    ('\u{17B6}', '\u{17B6}', SpacingMark),
+    ('\u{17B7}', '\u{17BD}', Extend),
+    ('\u{17BE}', '\u{17C5}', SpacingMark),
+    ('\u{17C6}', '\u{17C6}', Extend),
+    ('\u{17C7}', '\u{17C8}', SpacingMark),
+    ('\u{17C9}', '\u{17D3}', Extend),
+    ('\u{17DD}', '\u{17DD}', Extend),
+    ('\u{180B}', '\u{180D}', Extend),
+    ('\u{180E}', '\u{180E}', Control),
+    ('\u{180F}', '\u{180F}', Extend),
+    ('\u{1885}', '\u{1886}', Extend),
+    ('\u{18A9}', '\u{18A9}', Extend),
+    ('\u{1920}', '\u{1922}', Extend),
+    ('\u{1923}', '\u{1926}', SpacingMark),
+    ('\u{1927}', '\u{1928}', Extend),
+    ('\u{1929}', '\u{192B}', SpacingMark),
+    ('\u{1930}', '\u{1931}', SpacingMark),
+    ('\u{1932}', '\u{1932}', Extend),
+    ('\u{1933}', '\u{1938}', SpacingMark),
+    ('\u{1939}', '\u{193B}', Extend),
+    ('\u{1A17}', '\u{1A18}', Extend),
+    ('\u{1A19}', '\u{1A1A}', SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', Extend),
+    ('\u{1A55}', '\u{1A55}', SpacingMark),
+    ('\u{1A56}', '\u{1A56}', Extend),
+    ('\u{1A57}', '\u{1A57}', SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', Extend),
+    ('\u{1A60}', '\u{1A60}', Extend),
+    ('\u{1A62}', '\u{1A62}', Extend),
+    ('\u{1A65}', '\u{1A6C}', Extend),
+    ('\u{1A6D}', '\u{1A72}', SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', Extend),
+    ('\u{1A7F}', '\u{1A7F}', Extend),
+    ('\u{1AB0}', '\u{1ACE}', Extend),
+    ('\u{1B00}', '\u{1B03}', Extend),
+    ('\u{1B04}', '\u{1B04}', SpacingMark),
+    ('\u{1B34}', '\u{1B3D}', Extend),
+    ('\u{1B3E}', '\u{1B41}', SpacingMark),
+    ('\u{1B42}', '\u{1B44}', Extend),
+    ('\u{1B6B}', '\u{1B73}', Extend),
+    ('\u{1B80}', '\u{1B81}', Extend),
+    ('\u{1B82}', '\u{1B82}', SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', Extend),
+    ('\u{1BA6}', '\u{1BA7}', SpacingMark),
+    ('\u{1BA8}', '\u{1BAD}', Extend),
+    ('\u{1BE6}', '\u{1BE6}', Extend),
+    ('\u{1BE7}', '\u{1BE7}', SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', Extend),
+    ('\u{1BEA}', '\u{1BEC}', SpacingMark),
+    ('\u{1BED}', '\u{1BED}', Extend),
+    ('\u{1BEE}', '\u{1BEE}', SpacingMark),
+    ('\u{1BEF}', '\u{1BF3}', Extend),
+    ('\u{1C24}', '\u{1C2B}', SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', Extend),
+    ('\u{1C34}', '\u{1C35}', SpacingMark),
+    ('\u{1C36}', '\u{1C37}', Extend),
+    ('\u{1CD0}', '\u{1CD2}', Extend),
+    ('\u{1CD4}', '\u{1CE0}', Extend),
+    ('\u{1CE1}', '\u{1CE1}', SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', Extend),
+    ('\u{1CED}', '\u{1CED}', Extend),
+    ('\u{1CF4}', '\u{1CF4}', Extend),
+    ('\u{1CF7}', '\u{1CF7}', SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', Extend),
+    ('\u{1DC0}', '\u{1DFF}', Extend),
+    ('\u{200B}', '\u{200B}', Control),
+    ('\u{200C}', '\u{200C}', Extend),
+    ('\u{200D}', '\u{200D}', ZWJ),
+    ('\u{200E}', '\u{200F}', Control),
+    ('\u{2028}', '\u{202E}', Control),
+    ('\u{2060}', '\u{206F}', Control),
+    ('\u{20D0}', '\u{20F0}', Extend),
+    ('\u{2CEF}', '\u{2CF1}', Extend),
+    ('\u{2D7F}', '\u{2D7F}', Extend),
+    ('\u{2DE0}', '\u{2DFF}', Extend),
+    ('\u{302A}', '\u{302F}', Extend),
+    ('\u{3099}', '\u{309A}', Extend),
+    ('\u{A66F}', '\u{A672}', Extend),
+    ('\u{A674}', '\u{A67D}', Extend),
+    ('\u{A69E}', '\u{A69F}', Extend),
+    ('\u{A6F0}', '\u{A6F1}', Extend),
+    ('\u{A802}', '\u{A802}', Extend),
+    ('\u{A806}', '\u{A806}', Extend),
+    ('\u{A80B}', '\u{A80B}', Extend),
+    ('\u{A823}', '\u{A824}', SpacingMark),
+    ('\u{A825}', '\u{A826}', Extend),
+    ('\u{A827}', '\u{A827}', SpacingMark),
+    ('\u{A82C}', '\u{A82C}', Extend),
+    ('\u{A880}', '\u{A881}', SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', Extend),
+    ('\u{A8E0}', '\u{A8F1}', Extend),
+    ('\u{A8FF}', '\u{A8FF}', Extend),
+    ('\u{A926}', '\u{A92D}', Extend),
+    ('\u{A947}', '\u{A951}', Extend),
+    ('\u{A952}', '\u{A952}', SpacingMark),
+    ('\u{A953}', '\u{A953}', Extend),
+    ('\u{A960}', '\u{A97C}', L),
+    ('\u{A980}', '\u{A982}', Extend),
+    ('\u{A983}', '\u{A983}', SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', Extend),
+    ('\u{A9B4}', '\u{A9B5}', SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', Extend),
+    ('\u{A9BA}', '\u{A9BB}', SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', Extend),
+    ('\u{A9BE}', '\u{A9BF}', SpacingMark),
+    ('\u{A9C0}', '\u{A9C0}', Extend),
+    ('\u{A9E5}', '\u{A9E5}', Extend),
+    ('\u{AA29}', '\u{AA2E}', Extend),
+    ('\u{AA2F}', '\u{AA30}', SpacingMark),
+    ('\u{AA31}', '\u{AA32}', Extend),
+    ('\u{AA33}', '\u{AA34}', SpacingMark),
+    ('\u{AA35}', '\u{AA36}', Extend),
+    ('\u{AA43}', '\u{AA43}', Extend),
+    ('\u{AA4C}', '\u{AA4C}', Extend),
+    ('\u{AA4D}', '\u{AA4D}', SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', Extend),
+    ('\u{AAB0}', '\u{AAB0}', Extend),
+    ('\u{AAB2}', '\u{AAB4}', Extend),
+    ('\u{AAB7}', '\u{AAB8}', Extend),
+    ('\u{AABE}', '\u{AABF}', Extend),
+    ('\u{AAC1}', '\u{AAC1}', Extend),
+    ('\u{AAEB}', '\u{AAEB}', SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', Extend),
+    ('\u{AAEE}', '\u{AAEF}', SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', Extend),
+    ('\u{ABE3}', '\u{ABE4}', SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', Extend),
+    ('\u{ABE6}', '\u{ABE7}', SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', Extend),
+    ('\u{ABE9}', '\u{ABEA}', SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', SpacingMark),
+    ('\u{ABED}', '\u{ABED}', Extend),
+    ('\u{AC00}', '\u{AC00}', LV),
+    ('\u{AC01}', '\u{AC1B}', LVT),
+    ('\u{AC1C}', '\u{AC1C}', LV),
+    ('\u{AC1D}', '\u{AC37}', LVT),
+    ('\u{AC38}', '\u{AC38}', LV),
+    ('\u{AC39}', '\u{AC53}', LVT),
+    ('\u{AC54}', '\u{AC54}', LV),
+    ('\u{AC55}', '\u{AC6F}', LVT),
+    ('\u{AC70}', '\u{AC70}', LV),
+    ('\u{AC71}', '\u{AC8B}', LVT),
+    ('\u{AC8C}', '\u{AC8C}', LV),
+    ('\u{AC8D}', '\u{ACA7}', LVT),
+    ('\u{ACA8}', '\u{ACA8}', LV),
+    ('\u{ACA9}', '\u{ACC3}', LVT),
+    ('\u{ACC4}', '\u{ACC4}', LV),
+    ('\u{ACC5}', '\u{ACDF}', LVT),
+    ('\u{ACE0}', '\u{ACE0}', LV),
+    ('\u{ACE1}', '\u{ACFB}', LVT),
+    ('\u{ACFC}', '\u{ACFC}', LV),
+    ('\u{ACFD}', '\u{AD17}', LVT),
+    ('\u{AD18}', '\u{AD18}', LV),
+    ('\u{AD19}', '\u{AD33}', LVT),
+    ('\u{AD34}', '\u{AD34}', LV),
+    ('\u{AD35}', '\u{AD4F}', LVT),
+    ('\u{AD50}', '\u{AD50}', LV),
+    ('\u{AD51}', '\u{AD6B}', LVT),
+    ('\u{AD6C}', '\u{AD6C}', LV),
+    ('\u{AD6D}', '\u{AD87}', LVT),
+    ('\u{AD88}', '\u{AD88}', LV),
+    ('\u{AD89}', '\u{ADA3}', LVT),
+    ('\u{ADA4}', '\u{ADA4}', LV),
+    ('\u{ADA5}', '\u{ADBF}', LVT),
+    ('\u{ADC0}', '\u{ADC0}', LV),
+    ('\u{ADC1}', '\u{ADDB}', LVT),
+    ('\u{ADDC}', '\u{ADDC}', LV),
+    ('\u{ADDD}', '\u{ADF7}', LVT),
+    ('\u{ADF8}', '\u{ADF8}', LV),
+    ('\u{ADF9}', '\u{AE13}', LVT),
+    ('\u{AE14}', '\u{AE14}', LV),
+    ('\u{AE15}', '\u{AE2F}', LVT),
+    ('\u{AE30}', '\u{AE30}', LV),
+    ('\u{AE31}', '\u{AE4B}', LVT),
+    ('\u{AE4C}', '\u{AE4C}', LV),
+    ('\u{AE4D}', '\u{AE67}', LVT),
+    ('\u{AE68}', '\u{AE68}', LV),
+    ('\u{AE69}', '\u{AE83}', LVT),
+    ('\u{AE84}', '\u{AE84}', LV),
+    ('\u{AE85}', '\u{AE9F}', LVT),
+    ('\u{AEA0}', '\u{AEA0}', LV),
+    ('\u{AEA1}', '\u{AEBB}', LVT),
+    ('\u{AEBC}', '\u{AEBC}', LV),
+    ('\u{AEBD}', '\u{AED7}', LVT),
+    ('\u{AED8}', '\u{AED8}', LV),
+    ('\u{AED9}', '\u{AEF3}', LVT),
+    ('\u{AEF4}', '\u{AEF4}', LV),
+    ('\u{AEF5}', '\u{AF0F}', LVT),
+    ('\u{AF10}', '\u{AF10}', LV),
+    ('\u{AF11}', '\u{AF2B}', LVT),
+    ('\u{AF2C}', '\u{AF2C}', LV),
+    ('\u{AF2D}', '\u{AF47}', LVT),
+    ('\u{AF48}', '\u{AF48}', LV),
+    ('\u{AF49}', '\u{AF63}', LVT),
+    ('\u{AF64}', '\u{AF64}', LV),
+    ('\u{AF65}', '\u{AF7F}', LVT),
+    ('\u{AF80}', '\u{AF80}', LV),
+    ('\u{AF81}', '\u{AF9B}', LVT),
+    ('\u{AF9C}', '\u{AF9C}', LV),
+    ('\u{AF9D}', '\u{AFB7}', LVT),
+    ('\u{AFB8}', '\u{AFB8}', LV),
+    ('\u{AFB9}', '\u{AFD3}', LVT),
+    ('\u{AFD4}', '\u{AFD4}', LV),
+    ('\u{AFD5}', '\u{AFEF}', LVT),
+    ('\u{AFF0}', '\u{AFF0}', LV),
+    ('\u{AFF1}', '\u{B00B}', LVT),
+    ('\u{B00C}', '\u{B00C}', LV),
+    ('\u{B00D}', '\u{B027}', LVT),
+    ('\u{B028}', '\u{B028}', LV),
+    ('\u{B029}', '\u{B043}', LVT),
+    ('\u{B044}', '\u{B044}', LV),
+    ('\u{B045}', '\u{B05F}', LVT),
+    ('\u{B060}', '\u{B060}', LV),
+    ('\u{B061}', '\u{B07B}', LVT),
+    ('\u{B07C}', '\u{B07C}', LV),
+    ('\u{B07D}', '\u{B097}', LVT),
+    ('\u{B098}', '\u{B098}', LV),
+    ('\u{B099}', '\u{B0B3}', LVT),
+    ('\u{B0B4}', '\u{B0B4}', LV),
+    ('\u{B0B5}', '\u{B0CF}', LVT),
+    ('\u{B0D0}', '\u{B0D0}', LV),
+    ('\u{B0D1}', '\u{B0EB}', LVT),
+    ('\u{B0EC}', '\u{B0EC}', LV),
+    ('\u{B0ED}', '\u{B107}', LVT),
+    ('\u{B108}', '\u{B108}', LV),
+    ('\u{B109}', '\u{B123}', LVT),
+    ('\u{B124}', '\u{B124}', LV),
+    ('\u{B125}', '\u{B13F}', LVT),
+    ('\u{B140}', '\u{B140}', LV),
+    ('\u{B141}', '\u{B15B}', LVT),
+    ('\u{B15C}', '\u{B15C}', LV),
+    ('\u{B15D}', '\u{B177}', LVT),
+    ('\u{B178}', '\u{B178}', LV),
+    ('\u{B179}', '\u{B193}', LVT),
+    ('\u{B194}', '\u{B194}', LV),
+    ('\u{B195}', '\u{B1AF}', LVT),
+    ('\u{B1B0}', '\u{B1B0}', LV),
+    ('\u{B1B1}', '\u{B1CB}', LVT),
+    ('\u{B1CC}', '\u{B1CC}', LV),
+    ('\u{B1CD}', '\u{B1E7}', LVT),
+    ('\u{B1E8}', '\u{B1E8}', LV),
+    ('\u{B1E9}', '\u{B203}', LVT),
+    ('\u{B204}', '\u{B204}', LV),
+    ('\u{B205}', '\u{B21F}', LVT),
+    ('\u{B220}', '\u{B220}', LV),
+    ('\u{B221}', '\u{B23B}', LVT),
+    ('\u{B23C}', '\u{B23C}', LV),
+    ('\u{B23D}', '\u{B257}', LVT),
+    ('\u{B258}', '\u{B258}', LV),
+    ('\u{B259}', '\u{B273}', LVT),
+    ('\u{B274}', '\u{B274}', LV),
+    ('\u{B275}', '\u{B28F}', LVT),
+    ('\u{B290}', '\u{B290}', LV),
+    ('\u{B291}', '\u{B2AB}', LVT),
+    ('\u{B2AC}', '\u{B2AC}', LV),
+    ('\u{B2AD}', '\u{B2C7}', LVT),
+    ('\u{B2C8}', '\u{B2C8}', LV),
+    ('\u{B2C9}', '\u{B2E3}', LVT),
+    ('\u{B2E4}', '\u{B2E4}', LV),
+    ('\u{B2E5}', '\u{B2FF}', LVT),
+    ('\u{B300}', '\u{B300}', LV),
+    ('\u{B301}', '\u{B31B}', LVT),
+    ('\u{B31C}', '\u{B31C}', LV),
+    ('\u{B31D}', '\u{B337}', LVT),
+    ('\u{B338}', '\u{B338}', LV),
+    ('\u{B339}', '\u{B353}', LVT),
+    ('\u{B354}', '\u{B354}', LV),
+    ('\u{B355}', '\u{B36F}', LVT),
+    ('\u{B370}', '\u{B370}', LV),
+    ('\u{B371}', '\u{B38B}', LVT),
+    ('\u{B38C}', '\u{B38C}', LV),
+    ('\u{B38D}', '\u{B3A7}', LVT),
+    ('\u{B3A8}', '\u{B3A8}', LV),
+    ('\u{B3A9}', '\u{B3C3}', LVT),
+    ('\u{B3C4}', '\u{B3C4}', LV),
+    ('\u{B3C5}', '\u{B3DF}', LVT),
+    ('\u{B3E0}', '\u{B3E0}', LV),
+    ('\u{B3E1}', '\u{B3FB}', LVT),
+    ('\u{B3FC}', '\u{B3FC}', LV),
+    ('\u{B3FD}', '\u{B417}', LVT),
+    ('\u{B418}', '\u{B418}', LV),
+    ('\u{B419}', '\u{B433}', LVT),
+    ('\u{B434}', '\u{B434}', LV),
+    ('\u{B435}', '\u{B44F}', LVT),
+    ('\u{B450}', '\u{B450}', LV),
+    ('\u{B451}', '\u{B46B}', LVT),
+    ('\u{B46C}', '\u{B46C}', LV),
+    ('\u{B46D}', '\u{B487}', LVT),
+    ('\u{B488}', '\u{B488}', LV),
+    ('\u{B489}', '\u{B4A3}', LVT),
+    ('\u{B4A4}', '\u{B4A4}', LV),
+    ('\u{B4A5}', '\u{B4BF}', LVT),
+    ('\u{B4C0}', '\u{B4C0}', LV),
+    ('\u{B4C1}', '\u{B4DB}', LVT),
+    ('\u{B4DC}', '\u{B4DC}', LV),
+    ('\u{B4DD}', '\u{B4F7}', LVT),
+    ('\u{B4F8}', '\u{B4F8}', LV),
+    ('\u{B4F9}', '\u{B513}', LVT),
+    ('\u{B514}', '\u{B514}', LV),
+    ('\u{B515}', '\u{B52F}', LVT),
+    ('\u{B530}', '\u{B530}', LV),
+    ('\u{B531}', '\u{B54B}', LVT),
+    ('\u{B54C}', '\u{B54C}', LV),
+    ('\u{B54D}', '\u{B567}', LVT),
+    ('\u{B568}', '\u{B568}', LV),
+    ('\u{B569}', '\u{B583}', LVT),
+    ('\u{B584}', '\u{B584}', LV),
+    ('\u{B585}', '\u{B59F}', LVT),
+    ('\u{B5A0}', '\u{B5A0}', LV),
+    ('\u{B5A1}', '\u{B5BB}', LVT),
+    ('\u{B5BC}', '\u{B5BC}', LV),
+    ('\u{B5BD}', '\u{B5D7}', LVT),
+    ('\u{B5D8}', '\u{B5D8}', LV),
+    ('\u{B5D9}', '\u{B5F3}', LVT),
+    ('\u{B5F4}', '\u{B5F4}', LV),
+    ('\u{B5F5}', '\u{B60F}', LVT),
+    ('\u{B610}', '\u{B610}', LV),
+    ('\u{B611}', '\u{B62B}', LVT),
+    ('\u{B62C}', '\u{B62C}', LV),
+    ('\u{B62D}', '\u{B647}', LVT),
+    ('\u{B648}', '\u{B648}', LV),
+    ('\u{B649}', '\u{B663}', LVT),
+    ('\u{B664}', '\u{B664}', LV),
+    ('\u{B665}', '\u{B67F}', LVT),
+    ('\u{B680}', '\u{B680}', LV),
+    ('\u{B681}', '\u{B69B}', LVT),
+    ('\u{B69C}', '\u{B69C}', LV),
+    ('\u{B69D}', '\u{B6B7}', LVT),
+    ('\u{B6B8}', '\u{B6B8}', LV),
+    ('\u{B6B9}', '\u{B6D3}', LVT),
+    ('\u{B6D4}', '\u{B6D4}', LV),
+    ('\u{B6D5}', '\u{B6EF}', LVT),
+    ('\u{B6F0}', '\u{B6F0}', LV),
+    ('\u{B6F1}', '\u{B70B}', LVT),
+    ('\u{B70C}', '\u{B70C}', LV),
+    ('\u{B70D}', '\u{B727}', LVT),
+    ('\u{B728}', '\u{B728}', LV),
+    ('\u{B729}', '\u{B743}', LVT),
+    ('\u{B744}', '\u{B744}', LV),
+    ('\u{B745}', '\u{B75F}', LVT),
+    ('\u{B760}', '\u{B760}', LV),
+    ('\u{B761}', '\u{B77B}', LVT),
+    ('\u{B77C}', '\u{B77C}', LV),
+    ('\u{B77D}', '\u{B797}', LVT),
+    ('\u{B798}', '\u{B798}', LV),
+    ('\u{B799}', '\u{B7B3}', LVT),
+    ('\u{B7B4}', '\u{B7B4}', LV),
+    ('\u{B7B5}', '\u{B7CF}', LVT),
+    ('\u{B7D0}', '\u{B7D0}', LV),
+    ('\u{B7D1}', '\u{B7EB}', LVT),
+    ('\u{B7EC}', '\u{B7EC}', LV),
+    ('\u{B7ED}', '\u{B807}', LVT),
+    ('\u{B808}', '\u{B808}', LV),
+    ('\u{B809}', '\u{B823}', LVT),
+    ('\u{B824}', '\u{B824}', LV),
+    ('\u{B825}', '\u{B83F}', LVT),
+    ('\u{B840}', '\u{B840}', LV),
+    ('\u{B841}', '\u{B85B}', LVT),
+    ('\u{B85C}', '\u{B85C}', LV),
+    ('\u{B85D}', '\u{B877}', LVT),
+    ('\u{B878}', '\u{B878}', LV),
+    ('\u{B879}', '\u{B893}', LVT),
+    ('\u{B894}', '\u{B894}', LV),
+    ('\u{B895}', '\u{B8AF}', LVT),
+    ('\u{B8B0}', '\u{B8B0}', LV),
+    ('\u{B8B1}', '\u{B8CB}', LVT),
+    ('\u{B8CC}', '\u{B8CC}', LV),
+    ('\u{B8CD}', '\u{B8E7}', LVT),
+    ('\u{B8E8}', '\u{B8E8}', LV),
+    ('\u{B8E9}', '\u{B903}', LVT),
+    ('\u{B904}', '\u{B904}', LV),
+    ('\u{B905}', '\u{B91F}', LVT),
+    ('\u{B920}', '\u{B920}', LV),
+    ('\u{B921}', '\u{B93B}', LVT),
+    ('\u{B93C}', '\u{B93C}', LV),
+    ('\u{B93D}', '\u{B957}', LVT),
+    ('\u{B958}', '\u{B958}', LV),
+    ('\u{B959}', '\u{B973}', LVT),
+    ('\u{B974}', '\u{B974}', LV),
+    ('\u{B975}', '\u{B98F}', LVT),
+    ('\u{B990}', '\u{B990}', LV),
+    ('\u{B991}', '\u{B9AB}', LVT),
+    ('\u{B9AC}', '\u{B9AC}', LV),
+    ('\u{B9AD}', '\u{B9C7}', LVT),
+    ('\u{B9C8}', '\u{B9C8}', LV),
+    ('\u{B9C9}', '\u{B9E3}', LVT),
+    ('\u{B9E4}', '\u{B9E4}', LV),
+    ('\u{B9E5}', '\u{B9FF}', LVT),
+    ('\u{BA00}', '\u{BA00}', LV),
+    ('\u{BA01}', '\u{BA1B}', LVT),
+    ('\u{BA1C}', '\u{BA1C}', LV),
+    ('\u{BA1D}', '\u{BA37}', LVT),
+    ('\u{BA38}', '\u{BA38}', LV),
+    ('\u{BA39}', '\u{BA53}', LVT),
+    ('\u{BA54}', '\u{BA54}', LV),
+    ('\u{BA55}', '\u{BA6F}', LVT),
+    ('\u{BA70}', '\u{BA70}', LV),
+    ('\u{BA71}', '\u{BA8B}', LVT),
+    ('\u{BA8C}', '\u{BA8C}', LV),
+    ('\u{BA8D}', '\u{BAA7}', LVT),
+    ('\u{BAA8}', '\u{BAA8}', LV),
+    ('\u{BAA9}', '\u{BAC3}', LVT),
+    ('\u{BAC4}', '\u{BAC4}', LV),
+    ('\u{BAC5}', '\u{BADF}', LVT),
+    ('\u{BAE0}', '\u{BAE0}', LV),
+    ('\u{BAE1}', '\u{BAFB}', LVT),
+    ('\u{BAFC}', '\u{BAFC}', LV),
+    ('\u{BAFD}', '\u{BB17}', LVT),
+    ('\u{BB18}', '\u{BB18}', LV),
+    ('\u{BB19}', '\u{BB33}', LVT),
+    ('\u{BB34}', '\u{BB34}', LV),
+    ('\u{BB35}', '\u{BB4F}', LVT),
+    ('\u{BB50}', '\u{BB50}', LV),
+    ('\u{BB51}', '\u{BB6B}', LVT),
+    ('\u{BB6C}', '\u{BB6C}', LV),
+    ('\u{BB6D}', '\u{BB87}', LVT),
+    ('\u{BB88}', '\u{BB88}', LV),
+    ('\u{BB89}', '\u{BBA3}', LVT),
+    ('\u{BBA4}', '\u{BBA4}', LV),
+    ('\u{BBA5}', '\u{BBBF}', LVT),
+    ('\u{BBC0}', '\u{BBC0}', LV),
+    ('\u{BBC1}', '\u{BBDB}', LVT),
+    ('\u{BBDC}', '\u{BBDC}', LV),
+    ('\u{BBDD}', '\u{BBF7}', LVT),
+    ('\u{BBF8}', '\u{BBF8}', LV),
+    ('\u{BBF9}', '\u{BC13}', LVT),
+    ('\u{BC14}', '\u{BC14}', LV),
+    ('\u{BC15}', '\u{BC2F}', LVT),
+    ('\u{BC30}', '\u{BC30}', LV),
+    ('\u{BC31}', '\u{BC4B}', LVT),
+    ('\u{BC4C}', '\u{BC4C}', LV),
+    ('\u{BC4D}', '\u{BC67}', LVT),
+    ('\u{BC68}', '\u{BC68}', LV),
+    ('\u{BC69}', '\u{BC83}', LVT),
+    ('\u{BC84}', '\u{BC84}', LV),
+    ('\u{BC85}', '\u{BC9F}', LVT),
+    ('\u{BCA0}', '\u{BCA0}', LV),
+    ('\u{BCA1}', '\u{BCBB}', LVT),
+    ('\u{BCBC}', '\u{BCBC}', LV),
+    ('\u{BCBD}', '\u{BCD7}', LVT),
+    ('\u{BCD8}', '\u{BCD8}', LV),
+    ('\u{BCD9}', '\u{BCF3}', LVT),
+    ('\u{BCF4}', '\u{BCF4}', LV),
+    ('\u{BCF5}', '\u{BD0F}', LVT),
+    ('\u{BD10}', '\u{BD10}', LV),
+    ('\u{BD11}', '\u{BD2B}', LVT),
+    ('\u{BD2C}', '\u{BD2C}', LV),
+    ('\u{BD2D}', '\u{BD47}', LVT),
+    ('\u{BD48}', '\u{BD48}', LV),
+    ('\u{BD49}', '\u{BD63}', LVT),
+    ('\u{BD64}', '\u{BD64}', LV),
+    ('\u{BD65}', '\u{BD7F}', LVT),
+    ('\u{BD80}', '\u{BD80}', LV),
+    ('\u{BD81}', '\u{BD9B}', LVT),
+    ('\u{BD9C}', '\u{BD9C}', LV),
+    ('\u{BD9D}', '\u{BDB7}', LVT),
+    ('\u{BDB8}', '\u{BDB8}', LV),
+    ('\u{BDB9}', '\u{BDD3}', LVT),
+    ('\u{BDD4}', '\u{BDD4}', LV),
+    ('\u{BDD5}', '\u{BDEF}', LVT),
+    ('\u{BDF0}', '\u{BDF0}', LV),
+    ('\u{BDF1}', '\u{BE0B}', LVT),
+    ('\u{BE0C}', '\u{BE0C}', LV),
+    ('\u{BE0D}', '\u{BE27}', LVT),
+    ('\u{BE28}', '\u{BE28}', LV),
+    ('\u{BE29}', '\u{BE43}', LVT),
+    ('\u{BE44}', '\u{BE44}', LV),
+    ('\u{BE45}', '\u{BE5F}', LVT),
+    ('\u{BE60}', '\u{BE60}', LV),
+    ('\u{BE61}', '\u{BE7B}', LVT),
+    ('\u{BE7C}', '\u{BE7C}', LV),
+    ('\u{BE7D}', '\u{BE97}', LVT),
+    ('\u{BE98}', '\u{BE98}', LV),
+    ('\u{BE99}', '\u{BEB3}', LVT),
+    ('\u{BEB4}', '\u{BEB4}', LV),
+    ('\u{BEB5}', '\u{BECF}', LVT),
+    ('\u{BED0}', '\u{BED0}', LV),
+    ('\u{BED1}', '\u{BEEB}', LVT),
+    ('\u{BEEC}', '\u{BEEC}', LV),
+    ('\u{BEED}', '\u{BF07}', LVT),
+    ('\u{BF08}', '\u{BF08}', LV),
+    ('\u{BF09}', '\u{BF23}', LVT),
+    ('\u{BF24}', '\u{BF24}', LV),
+    ('\u{BF25}', '\u{BF3F}', LVT),
+    ('\u{BF40}', '\u{BF40}', LV),
+    ('\u{BF41}', '\u{BF5B}', LVT),
+    ('\u{BF5C}', '\u{BF5C}', LV),
+    ('\u{BF5D}', '\u{BF77}', LVT),
+    ('\u{BF78}', '\u{BF78}', LV),
+    ('\u{BF79}', '\u{BF93}', LVT),
+    ('\u{BF94}', '\u{BF94}', LV),
+    ('\u{BF95}', '\u{BFAF}', LVT),
+    ('\u{BFB0}', '\u{BFB0}', LV),
+    ('\u{BFB1}', '\u{BFCB}', LVT),
+    ('\u{BFCC}', '\u{BFCC}', LV),
+    ('\u{BFCD}', '\u{BFE7}', LVT),
+    ('\u{BFE8}', '\u{BFE8}', LV),
+    ('\u{BFE9}', '\u{C003}', LVT),
+    ('\u{C004}', '\u{C004}', LV),
+    ('\u{C005}', '\u{C01F}', LVT),
+    ('\u{C020}', '\u{C020}', LV),
+    ('\u{C021}', '\u{C03B}', LVT),
+    ('\u{C03C}', '\u{C03C}', LV),
+    ('\u{C03D}', '\u{C057}', LVT),
+    ('\u{C058}', '\u{C058}', LV),
+    ('\u{C059}', '\u{C073}', LVT),
+    ('\u{C074}', '\u{C074}', LV),
+    ('\u{C075}', '\u{C08F}', LVT),
+    ('\u{C090}', '\u{C090}', LV),
+    ('\u{C091}', '\u{C0AB}', LVT),
+    ('\u{C0AC}', '\u{C0AC}', LV),
+    ('\u{C0AD}', '\u{C0C7}', LVT),
+    ('\u{C0C8}', '\u{C0C8}', LV),
+    ('\u{C0C9}', '\u{C0E3}', LVT),
+    ('\u{C0E4}', '\u{C0E4}', LV),
+    ('\u{C0E5}', '\u{C0FF}', LVT),
+    ('\u{C100}', '\u{C100}', LV),
+    ('\u{C101}', '\u{C11B}', LVT),
+    ('\u{C11C}', '\u{C11C}', LV),
+    ('\u{C11D}', '\u{C137}', LVT),
+    ('\u{C138}', '\u{C138}', LV),
+    ('\u{C139}', '\u{C153}', LVT),
+    ('\u{C154}', '\u{C154}', LV),
+    ('\u{C155}', '\u{C16F}', LVT),
+    ('\u{C170}', '\u{C170}', LV),
+    ('\u{C171}', '\u{C18B}', LVT),
+    ('\u{C18C}', '\u{C18C}', LV),
+    ('\u{C18D}', '\u{C1A7}', LVT),
+    ('\u{C1A8}', '\u{C1A8}', LV),
+    ('\u{C1A9}', '\u{C1C3}', LVT),
+    ('\u{C1C4}', '\u{C1C4}', LV),
+    ('\u{C1C5}', '\u{C1DF}', LVT),
+    ('\u{C1E0}', '\u{C1E0}', LV),
+    ('\u{C1E1}', '\u{C1FB}', LVT),
+    ('\u{C1FC}', '\u{C1FC}', LV),
+    ('\u{C1FD}', '\u{C217}', LVT),
+    ('\u{C218}', '\u{C218}', LV),
+    ('\u{C219}', '\u{C233}', LVT),
+    ('\u{C234}', '\u{C234}', LV),
+    ('\u{C235}', '\u{C24F}', LVT),
+    ('\u{C250}', '\u{C250}', LV),
+    ('\u{C251}', '\u{C26B}', LVT),
+    ('\u{C26C}', '\u{C26C}', LV),
+    ('\u{C26D}', '\u{C287}', LVT),
+    ('\u{C288}', '\u{C288}', LV),
+    ('\u{C289}', '\u{C2A3}', LVT),
+    ('\u{C2A4}', '\u{C2A4}', LV),
+    ('\u{C2A5}', '\u{C2BF}', LVT),
+    ('\u{C2C0}', '\u{C2C0}', LV),
+    ('\u{C2C1}', '\u{C2DB}', LVT),
+    ('\u{C2DC}', '\u{C2DC}', LV),
+    ('\u{C2DD}', '\u{C2F7}', LVT),
+    ('\u{C2F8}', '\u{C2F8}', LV),
+    ('\u{C2F9}', '\u{C313}', LVT),
+    ('\u{C314}', '\u{C314}', LV),
+    ('\u{C315}', '\u{C32F}', LVT),
+    ('\u{C330}', '\u{C330}', LV),
+    ('\u{C331}', '\u{C34B}', LVT),
+    ('\u{C34C}', '\u{C34C}', LV),
+    ('\u{C34D}', '\u{C367}', LVT),
+    ('\u{C368}', '\u{C368}', LV),
+    ('\u{C369}', '\u{C383}', LVT),
+    ('\u{C384}', '\u{C384}', LV),
+    ('\u{C385}', '\u{C39F}', LVT),
+    ('\u{C3A0}', '\u{C3A0}', LV),
+    ('\u{C3A1}', '\u{C3BB}', LVT),
+    ('\u{C3BC}', '\u{C3BC}', LV),
+    ('\u{C3BD}', '\u{C3D7}', LVT),
+    ('\u{C3D8}', '\u{C3D8}', LV),
+    ('\u{C3D9}', '\u{C3F3}', LVT),
+    ('\u{C3F4}', '\u{C3F4}', LV),
+    ('\u{C3F5}', '\u{C40F}', LVT),
+    ('\u{C410}', '\u{C410}', LV),
+    ('\u{C411}', '\u{C42B}', LVT),
+    ('\u{C42C}', '\u{C42C}', LV),
+    ('\u{C42D}', '\u{C447}', LVT),
+    ('\u{C448}', '\u{C448}', LV),
+    ('\u{C449}', '\u{C463}', LVT),
+    ('\u{C464}', '\u{C464}', LV),
+    ('\u{C465}', '\u{C47F}', LVT),
+    ('\u{C480}', '\u{C480}', LV),
+    ('\u{C481}', '\u{C49B}', LVT),
+    ('\u{C49C}', '\u{C49C}', LV),
+    ('\u{C49D}', '\u{C4B7}', LVT),
+    ('\u{C4B8}', '\u{C4B8}', LV),
+    ('\u{C4B9}', '\u{C4D3}', LVT),
+    ('\u{C4D4}', '\u{C4D4}', LV),
+    ('\u{C4D5}', '\u{C4EF}', LVT),
+    ('\u{C4F0}', '\u{C4F0}', LV),
+    ('\u{C4F1}', '\u{C50B}', LVT),
+    ('\u{C50C}', '\u{C50C}', LV),
+    ('\u{C50D}', '\u{C527}', LVT),
+    ('\u{C528}', '\u{C528}', LV),
+    ('\u{C529}', '\u{C543}', LVT),
+    ('\u{C544}', '\u{C544}', LV),
+    ('\u{C545}', '\u{C55F}', LVT),
+    ('\u{C560}', '\u{C560}', LV),
+    ('\u{C561}', '\u{C57B}', LVT),
+    ('\u{C57C}', '\u{C57C}', LV),
+    ('\u{C57D}', '\u{C597}', LVT),
+    ('\u{C598}', '\u{C598}', LV),
+    ('\u{C599}', '\u{C5B3}', LVT),
+    ('\u{C5B4}', '\u{C5B4}', LV),
+    ('\u{C5B5}', '\u{C5CF}', LVT),
+    ('\u{C5D0}', '\u{C5D0}', LV),
+    ('\u{C5D1}', '\u{C5EB}', LVT),
+    ('\u{C5EC}', '\u{C5EC}', LV),
+    ('\u{C5ED}', '\u{C607}', LVT),
+    ('\u{C608}', '\u{C608}', LV),
+    ('\u{C609}', '\u{C623}', LVT),
+    ('\u{C624}', '\u{C624}', LV),
+    ('\u{C625}', '\u{C63F}', LVT),
+    ('\u{C640}', '\u{C640}', LV),
+    ('\u{C641}', '\u{C65B}', LVT),
+    ('\u{C65C}', '\u{C65C}', LV),
+    ('\u{C65D}', '\u{C677}', LVT),
+    ('\u{C678}', '\u{C678}', LV),
+    ('\u{C679}', '\u{C693}', LVT),
+    ('\u{C694}', '\u{C694}', LV),
+    ('\u{C695}', '\u{C6AF}', LVT),
+    ('\u{C6B0}', '\u{C6B0}', LV),
+    ('\u{C6B1}', '\u{C6CB}', LVT),
+    ('\u{C6CC}', '\u{C6CC}', LV),
+    ('\u{C6CD}', '\u{C6E7}', LVT),
+    ('\u{C6E8}', '\u{C6E8}', LV),
+    ('\u{C6E9}', '\u{C703}', LVT),
+    ('\u{C704}', '\u{C704}', LV),
+    ('\u{C705}', '\u{C71F}', LVT),
+    ('\u{C720}', '\u{C720}', LV),
+    ('\u{C721}', '\u{C73B}', LVT),
+    ('\u{C73C}', '\u{C73C}', LV),
+    ('\u{C73D}', '\u{C757}', LVT),
+    ('\u{C758}', '\u{C758}', LV),
+    ('\u{C759}', '\u{C773}', LVT),
+    ('\u{C774}', '\u{C774}', LV),
+    ('\u{C775}', '\u{C78F}', LVT),
+    ('\u{C790}', '\u{C790}', LV),
+    ('\u{C791}', '\u{C7AB}', LVT),
+    ('\u{C7AC}', '\u{C7AC}', LV),
+    ('\u{C7AD}', '\u{C7C7}', LVT),
+    ('\u{C7C8}', '\u{C7C8}', LV),
+    ('\u{C7C9}', '\u{C7E3}', LVT),
+    ('\u{C7E4}', '\u{C7E4}', LV),
+    ('\u{C7E5}', '\u{C7FF}', LVT),
+    ('\u{C800}', '\u{C800}', LV),
+    ('\u{C801}', '\u{C81B}', LVT),
+    ('\u{C81C}', '\u{C81C}', LV),
+    ('\u{C81D}', '\u{C837}', LVT),
+    ('\u{C838}', '\u{C838}', LV),
+    ('\u{C839}', '\u{C853}', LVT),
+    ('\u{C854}', '\u{C854}', LV),
+    ('\u{C855}', '\u{C86F}', LVT),
+    ('\u{C870}', '\u{C870}', LV),
+    ('\u{C871}', '\u{C88B}', LVT),
+    ('\u{C88C}', '\u{C88C}', LV),
+    ('\u{C88D}', '\u{C8A7}', LVT),
+    ('\u{C8A8}', '\u{C8A8}', LV),
+    ('\u{C8A9}', '\u{C8C3}', LVT),
+    ('\u{C8C4}', '\u{C8C4}', LV),
+    ('\u{C8C5}', '\u{C8DF}', LVT),
+    ('\u{C8E0}', '\u{C8E0}', LV),
+    ('\u{C8E1}', '\u{C8FB}', LVT),
+    ('\u{C8FC}', '\u{C8FC}', LV),
+    ('\u{C8FD}', '\u{C917}', LVT),
+    ('\u{C918}', '\u{C918}', LV),
+    ('\u{C919}', '\u{C933}', LVT),
+    ('\u{C934}', '\u{C934}', LV),
+    ('\u{C935}', '\u{C94F}', LVT),
+    ('\u{C950}', '\u{C950}', LV),
+    ('\u{C951}', '\u{C96B}', LVT),
+    ('\u{C96C}', '\u{C96C}', LV),
+    ('\u{C96D}', '\u{C987}', LVT),
+    ('\u{C988}', '\u{C988}', LV),
+    ('\u{C989}', '\u{C9A3}', LVT),
+    ('\u{C9A4}', '\u{C9A4}', LV),
+    ('\u{C9A5}', '\u{C9BF}', LVT),
+    ('\u{C9C0}', '\u{C9C0}', LV),
+    ('\u{C9C1}', '\u{C9DB}', LVT),
+    ('\u{C9DC}', '\u{C9DC}', LV),
+    ('\u{C9DD}', '\u{C9F7}', LVT),
+    ('\u{C9F8}', '\u{C9F8}', LV),
+    ('\u{C9F9}', '\u{CA13}', LVT),
+    ('\u{CA14}', '\u{CA14}', LV),
+    ('\u{CA15}', '\u{CA2F}', LVT),
+    ('\u{CA30}', '\u{CA30}', LV),
+    ('\u{CA31}', '\u{CA4B}', LVT),
+    ('\u{CA4C}', '\u{CA4C}', LV),
+    ('\u{CA4D}', '\u{CA67}', LVT),
+    ('\u{CA68}', '\u{CA68}', LV),
+    ('\u{CA69}', '\u{CA83}', LVT),
+    ('\u{CA84}', '\u{CA84}', LV),
+    ('\u{CA85}', '\u{CA9F}', LVT),
+    ('\u{CAA0}', '\u{CAA0}', LV),
+    ('\u{CAA1}', '\u{CABB}', LVT),
+    ('\u{CABC}', '\u{CABC}', LV),
+    ('\u{CABD}', '\u{CAD7}', LVT),
+    ('\u{CAD8}', '\u{CAD8}', LV),
+    ('\u{CAD9}', '\u{CAF3}', LVT),
+    ('\u{CAF4}', '\u{CAF4}', LV),
+    ('\u{CAF5}', '\u{CB0F}', LVT),
+    ('\u{CB10}', '\u{CB10}', LV),
+    ('\u{CB11}', '\u{CB2B}', LVT),
+    ('\u{CB2C}', '\u{CB2C}', LV),
+    ('\u{CB2D}', '\u{CB47}', LVT),
+    ('\u{CB48}', '\u{CB48}', LV),
+    ('\u{CB49}', '\u{CB63}', LVT),
+    ('\u{CB64}', '\u{CB64}', LV),
+    ('\u{CB65}', '\u{CB7F}', LVT),
+    ('\u{CB80}', '\u{CB80}', LV),
+    ('\u{CB81}', '\u{CB9B}', LVT),
+    ('\u{CB9C}', '\u{CB9C}', LV),
+    ('\u{CB9D}', '\u{CBB7}', LVT),
+    ('\u{CBB8}', '\u{CBB8}', LV),
+    ('\u{CBB9}', '\u{CBD3}', LVT),
+    ('\u{CBD4}', '\u{CBD4}', LV),
+    ('\u{CBD5}', '\u{CBEF}', LVT),
+    ('\u{CBF0}', '\u{CBF0}', LV),
+    ('\u{CBF1}', '\u{CC0B}', LVT),
+    ('\u{CC0C}', '\u{CC0C}', LV),
+    ('\u{CC0D}', '\u{CC27}', LVT),
+    ('\u{CC28}', '\u{CC28}', LV),
+    ('\u{CC29}', '\u{CC43}', LVT),
+    ('\u{CC44}', '\u{CC44}', LV),
+    ('\u{CC45}', '\u{CC5F}', LVT),
+    ('\u{CC60}', '\u{CC60}', LV),
+    ('\u{CC61}', '\u{CC7B}', LVT),
+    ('\u{CC7C}', '\u{CC7C}', LV),
+    ('\u{CC7D}', '\u{CC97}', LVT),
+    ('\u{CC98}', '\u{CC98}', LV),
+    ('\u{CC99}', '\u{CCB3}', LVT),
+    ('\u{CCB4}', '\u{CCB4}', LV),
+    ('\u{CCB5}', '\u{CCCF}', LVT),
+    ('\u{CCD0}', '\u{CCD0}', LV),
+    ('\u{CCD1}', '\u{CCEB}', LVT),
+    ('\u{CCEC}', '\u{CCEC}', LV),
+    ('\u{CCED}', '\u{CD07}', LVT),
+    ('\u{CD08}', '\u{CD08}', LV),
+    ('\u{CD09}', '\u{CD23}', LVT),
+    ('\u{CD24}', '\u{CD24}', LV),
+    ('\u{CD25}', '\u{CD3F}', LVT),
+    ('\u{CD40}', '\u{CD40}', LV),
+    ('\u{CD41}', '\u{CD5B}', LVT),
+    ('\u{CD5C}', '\u{CD5C}', LV),
+    ('\u{CD5D}', '\u{CD77}', LVT),
+    ('\u{CD78}', '\u{CD78}', LV),
+    ('\u{CD79}', '\u{CD93}', LVT),
+    ('\u{CD94}', '\u{CD94}', LV),
+    ('\u{CD95}', '\u{CDAF}', LVT),
+    ('\u{CDB0}', '\u{CDB0}', LV),
+    ('\u{CDB1}', '\u{CDCB}', LVT),
+    ('\u{CDCC}', '\u{CDCC}', LV),
+    ('\u{CDCD}', '\u{CDE7}', LVT),
+    ('\u{CDE8}', '\u{CDE8}', LV),
+    ('\u{CDE9}', '\u{CE03}', LVT),
+    ('\u{CE04}', '\u{CE04}', LV),
+    ('\u{CE05}', '\u{CE1F}', LVT),
+    ('\u{CE20}', '\u{CE20}', LV),
+    ('\u{CE21}', '\u{CE3B}', LVT),
+    ('\u{CE3C}', '\u{CE3C}', LV),
+    ('\u{CE3D}', '\u{CE57}', LVT),
+    ('\u{CE58}', '\u{CE58}', LV),
+    ('\u{CE59}', '\u{CE73}', LVT),
+    ('\u{CE74}', '\u{CE74}', LV),
+    ('\u{CE75}', '\u{CE8F}', LVT),
+    ('\u{CE90}', '\u{CE90}', LV),
+    ('\u{CE91}', '\u{CEAB}', LVT),
+    ('\u{CEAC}', '\u{CEAC}', LV),
+    ('\u{CEAD}', '\u{CEC7}', LVT),
+    ('\u{CEC8}', '\u{CEC8}', LV),
+    ('\u{CEC9}', '\u{CEE3}', LVT),
+    ('\u{CEE4}', '\u{CEE4}', LV),
+    ('\u{CEE5}', '\u{CEFF}', LVT),
+    ('\u{CF00}', '\u{CF00}', LV),
+    ('\u{CF01}', '\u{CF1B}', LVT),
+    ('\u{CF1C}', '\u{CF1C}', LV),
+    ('\u{CF1D}', '\u{CF37}', LVT),
+    ('\u{CF38}', '\u{CF38}', LV),
+    ('\u{CF39}', '\u{CF53}', LVT),
+    ('\u{CF54}', '\u{CF54}', LV),
+    ('\u{CF55}', '\u{CF6F}', LVT),
+    ('\u{CF70}', '\u{CF70}', LV),
+    ('\u{CF71}', '\u{CF8B}', LVT),
+    ('\u{CF8C}', '\u{CF8C}', LV),
+    ('\u{CF8D}', '\u{CFA7}', LVT),
+    ('\u{CFA8}', '\u{CFA8}', LV),
+    ('\u{CFA9}', '\u{CFC3}', LVT),
+    ('\u{CFC4}', '\u{CFC4}', LV),
+    ('\u{CFC5}', '\u{CFDF}', LVT),
+    ('\u{CFE0}', '\u{CFE0}', LV),
+    ('\u{CFE1}', '\u{CFFB}', LVT),
+    ('\u{CFFC}', '\u{CFFC}', LV),
+    ('\u{CFFD}', '\u{D017}', LVT),
+    ('\u{D018}', '\u{D018}', LV),
+    ('\u{D019}', '\u{D033}', LVT),
+    ('\u{D034}', '\u{D034}', LV),
+    ('\u{D035}', '\u{D04F}', LVT),
+    ('\u{D050}', '\u{D050}', LV),
+    ('\u{D051}', '\u{D06B}', LVT),
+    ('\u{D06C}', '\u{D06C}', LV),
+    ('\u{D06D}', '\u{D087}', LVT),
+    ('\u{D088}', '\u{D088}', LV),
+    ('\u{D089}', '\u{D0A3}', LVT),
+    ('\u{D0A4}', '\u{D0A4}', LV),
+    ('\u{D0A5}', '\u{D0BF}', LVT),
+    ('\u{D0C0}', '\u{D0C0}', LV),
+    ('\u{D0C1}', '\u{D0DB}', LVT),
+    ('\u{D0DC}', '\u{D0DC}', LV),
+    ('\u{D0DD}', '\u{D0F7}', LVT),
+    ('\u{D0F8}', '\u{D0F8}', LV),
+    ('\u{D0F9}', '\u{D113}', LVT),
+    ('\u{D114}', '\u{D114}', LV),
+    ('\u{D115}', '\u{D12F}', LVT),
+    ('\u{D130}', '\u{D130}', LV),
+    ('\u{D131}', '\u{D14B}', LVT),
+    ('\u{D14C}', '\u{D14C}', LV),
+    ('\u{D14D}', '\u{D167}', LVT),
+    ('\u{D168}', '\u{D168}', LV),
+    ('\u{D169}', '\u{D183}', LVT),
+    ('\u{D184}', '\u{D184}', LV),
+    ('\u{D185}', '\u{D19F}', LVT),
+    ('\u{D1A0}', '\u{D1A0}', LV),
+    ('\u{D1A1}', '\u{D1BB}', LVT),
+    ('\u{D1BC}', '\u{D1BC}', LV),
+    ('\u{D1BD}', '\u{D1D7}', LVT),
+    ('\u{D1D8}', '\u{D1D8}', LV),
+    ('\u{D1D9}', '\u{D1F3}', LVT),
+    ('\u{D1F4}', '\u{D1F4}', LV),
+    ('\u{D1F5}', '\u{D20F}', LVT),
+    ('\u{D210}', '\u{D210}', LV),
+    ('\u{D211}', '\u{D22B}', LVT),
+    ('\u{D22C}', '\u{D22C}', LV),
+    ('\u{D22D}', '\u{D247}', LVT),
+    ('\u{D248}', '\u{D248}', LV),
+    ('\u{D249}', '\u{D263}', LVT),
+    ('\u{D264}', '\u{D264}', LV),
+    ('\u{D265}', '\u{D27F}', LVT),
+    ('\u{D280}', '\u{D280}', LV),
+    ('\u{D281}', '\u{D29B}', LVT),
+    ('\u{D29C}', '\u{D29C}', LV),
+    ('\u{D29D}', '\u{D2B7}', LVT),
+    ('\u{D2B8}', '\u{D2B8}', LV),
+    ('\u{D2B9}', '\u{D2D3}', LVT),
+    ('\u{D2D4}', '\u{D2D4}', LV),
+    ('\u{D2D5}', '\u{D2EF}', LVT),
+    ('\u{D2F0}', '\u{D2F0}', LV),
+    ('\u{D2F1}', '\u{D30B}', LVT),
+    ('\u{D30C}', '\u{D30C}', LV),
+    ('\u{D30D}', '\u{D327}', LVT),
+    ('\u{D328}', '\u{D328}', LV),
+    ('\u{D329}', '\u{D343}', LVT),
+    ('\u{D344}', '\u{D344}', LV),
+    ('\u{D345}', '\u{D35F}', LVT),
+    ('\u{D360}', '\u{D360}', LV),
+    ('\u{D361}', '\u{D37B}', LVT),
+    ('\u{D37C}', '\u{D37C}', LV),
+    ('\u{D37D}', '\u{D397}', LVT),
+    ('\u{D398}', '\u{D398}', LV),
+    ('\u{D399}', '\u{D3B3}', LVT),
+    ('\u{D3B4}', '\u{D3B4}', LV),
+    ('\u{D3B5}', '\u{D3CF}', LVT),
+    ('\u{D3D0}', '\u{D3D0}', LV),
+    ('\u{D3D1}', '\u{D3EB}', LVT),
+    ('\u{D3EC}', '\u{D3EC}', LV),
+    ('\u{D3ED}', '\u{D407}', LVT),
+    ('\u{D408}', '\u{D408}', LV),
+    ('\u{D409}', '\u{D423}', LVT),
+    ('\u{D424}', '\u{D424}', LV),
+    ('\u{D425}', '\u{D43F}', LVT),
+    ('\u{D440}', '\u{D440}', LV),
+    ('\u{D441}', '\u{D45B}', LVT),
+    ('\u{D45C}', '\u{D45C}', LV),
+    ('\u{D45D}', '\u{D477}', LVT),
+    ('\u{D478}', '\u{D478}', LV),
+    ('\u{D479}', '\u{D493}', LVT),
+    ('\u{D494}', '\u{D494}', LV),
+    ('\u{D495}', '\u{D4AF}', LVT),
+    ('\u{D4B0}', '\u{D4B0}', LV),
+    ('\u{D4B1}', '\u{D4CB}', LVT),
+    ('\u{D4CC}', '\u{D4CC}', LV),
+    ('\u{D4CD}', '\u{D4E7}', LVT),
+    ('\u{D4E8}', '\u{D4E8}', LV),
+    ('\u{D4E9}', '\u{D503}', LVT),
+    ('\u{D504}', '\u{D504}', LV),
+    ('\u{D505}', '\u{D51F}', LVT),
+    ('\u{D520}', '\u{D520}', LV),
+    ('\u{D521}', '\u{D53B}', LVT),
+    ('\u{D53C}', '\u{D53C}', LV),
+    ('\u{D53D}', '\u{D557}', LVT),
+    ('\u{D558}', '\u{D558}', LV),
+    ('\u{D559}', '\u{D573}', LVT),
+    ('\u{D574}', '\u{D574}', LV),
+    ('\u{D575}', '\u{D58F}', LVT),
+    ('\u{D590}', '\u{D590}', LV),
+    ('\u{D591}', '\u{D5AB}', LVT),
+    ('\u{D5AC}', '\u{D5AC}', LV),
+    ('\u{D5AD}', '\u{D5C7}', LVT),
+    ('\u{D5C8}', '\u{D5C8}', LV),
+    ('\u{D5C9}', '\u{D5E3}', LVT),
+    ('\u{D5E4}', '\u{D5E4}', LV),
+    ('\u{D5E5}', '\u{D5FF}', LVT),
+    ('\u{D600}', '\u{D600}', LV),
+    ('\u{D601}', '\u{D61B}', LVT),
+    ('\u{D61C}', '\u{D61C}', LV),
+    ('\u{D61D}', '\u{D637}', LVT),
+    ('\u{D638}', '\u{D638}', LV),
+    ('\u{D639}', '\u{D653}', LVT),
+    ('\u{D654}', '\u{D654}', LV),
+    ('\u{D655}', '\u{D66F}', LVT),
+    ('\u{D670}', '\u{D670}', LV),
+    ('\u{D671}', '\u{D68B}', LVT),
+    ('\u{D68C}', '\u{D68C}', LV),
+    ('\u{D68D}', '\u{D6A7}', LVT),
+    ('\u{D6A8}', '\u{D6A8}', LV),
+    ('\u{D6A9}', '\u{D6C3}', LVT),
+    ('\u{D6C4}', '\u{D6C4}', LV),
+    ('\u{D6C5}', '\u{D6DF}', LVT),
+    ('\u{D6E0}', '\u{D6E0}', LV),
+    ('\u{D6E1}', '\u{D6FB}', LVT),
+    ('\u{D6FC}', '\u{D6FC}', LV),
+    ('\u{D6FD}', '\u{D717}', LVT),
+    ('\u{D718}', '\u{D718}', LV),
+    ('\u{D719}', '\u{D733}', LVT),
+    ('\u{D734}', '\u{D734}', LV),
+    ('\u{D735}', '\u{D74F}', LVT),
+    ('\u{D750}', '\u{D750}', LV),
+    ('\u{D751}', '\u{D76B}', LVT),
+    ('\u{D76C}', '\u{D76C}', LV),
+    ('\u{D76D}', '\u{D787}', LVT),
+    ('\u{D788}', '\u{D788}', LV),
+    ('\u{D789}', '\u{D7A3}', LVT),
+    ('\u{D7B0}', '\u{D7C6}', V),
+    ('\u{D7CB}', '\u{D7FB}', T),
+    ('\u{FB1E}', '\u{FB1E}', Extend),
+    ('\u{FE00}', '\u{FE0F}', Extend),
+    ('\u{FE20}', '\u{FE2F}', Extend),
+    ('\u{FEFF}', '\u{FEFF}', Control),
+    ('\u{FF9E}', '\u{FF9F}', Extend),
+    ('\u{FFF0}', '\u{FFFB}', Control),
+    ('\u{101FD}', '\u{101FD}', Extend),
+    ('\u{102E0}', '\u{102E0}', Extend),
+    ('\u{10376}', '\u{1037A}', Extend),
+    ('\u{10A01}', '\u{10A03}', Extend),
+    ('\u{10A05}', '\u{10A06}', Extend),
+    ('\u{10A0C}', '\u{10A0F}', Extend),
+    ('\u{10A38}', '\u{10A3A}', Extend),
+    ('\u{10A3F}', '\u{10A3F}', Extend),
+    ('\u{10AE5}', '\u{10AE6}', Extend),
+    ('\u{10D24}', '\u{10D27}', Extend),
+    ('\u{10D69}', '\u{10D6D}', Extend),
+    ('\u{10EAB}', '\u{10EAC}', Extend),
+    ('\u{10EFC}', '\u{10EFF}', Extend),
+    ('\u{10F46}', '\u{10F50}', Extend),
+    ('\u{10F82}', '\u{10F85}', Extend),
+    ('\u{11000}', '\u{11000}', SpacingMark),
+    ('\u{11001}', '\u{11001}', Extend),
+    ('\u{11002}', '\u{11002}', SpacingMark),
+    ('\u{11038}', '\u{11046}', Extend),
+    ('\u{11070}', '\u{11070}', Extend),
+    ('\u{11073}', '\u{11074}', Extend),
+    ('\u{1107F}', '\u{11081}', Extend),
+    ('\u{11082}', '\u{11082}', SpacingMark),
+    ('\u{110B0}', '\u{110B2}', SpacingMark),
+    ('\u{110B3}', '\u{110B6}', Extend),
+    ('\u{110B7}', '\u{110B8}', SpacingMark),
+    ('\u{110B9}', '\u{110BA}', Extend),
+    ('\u{110BD}', '\u{110BD}', Prepend),
+    ('\u{110C2}', '\u{110C2}', Extend),
+    ('\u{110CD}', '\u{110CD}', Prepend),
+    ('\u{11100}', '\u{11102}', Extend),
+    ('\u{11127}', '\u{1112B}', Extend),
+    ('\u{1112C}', '\u{1112C}', SpacingMark),
+    ('\u{1112D}', '\u{11134}', Extend),
+    ('\u{11145}', '\u{11146}', SpacingMark),
+    ('\u{11173}', '\u{11173}', Extend),
+    ('\u{11180}', '\u{11181}', Extend),
+    ('\u{11182}', '\u{11182}', SpacingMark),
+    ('\u{111B3}', '\u{111B5}', SpacingMark),
+    ('\u{111B6}', '\u{111BE}', Extend),
+    ('\u{111BF}', '\u{111BF}', SpacingMark),
+    ('\u{111C0}', '\u{111C0}', Extend),
+    ('\u{111C2}', '\u{111C3}', Prepend),
+    ('\u{111C9}', '\u{111CC}', Extend),
+    ('\u{111CE}', '\u{111CE}', SpacingMark),
+    ('\u{111CF}', '\u{111CF}', Extend),
+    ('\u{1122C}', '\u{1122E}', SpacingMark),
+    ('\u{1122F}', '\u{11231}', Extend),
+    ('\u{11232}', '\u{11233}', SpacingMark),
+    ('\u{11234}', '\u{11237}', Extend),
+    ('\u{1123E}', '\u{1123E}', Extend),
+    ('\u{11241}', '\u{11241}', Extend),
+    ('\u{112DF}', '\u{112DF}', Extend),
+    ('\u{112E0}', '\u{112E2}', SpacingMark),
+    ('\u{112E3}', '\u{112EA}', Extend),
+    ('\u{11300}', '\u{11301}', Extend),
+    ('\u{11302}', '\u{11303}', SpacingMark),
+    ('\u{1133B}', '\u{1133C}', Extend),
+    ('\u{1133E}', '\u{1133E}', Extend),
+    ('\u{1133F}', '\u{1133F}', SpacingMark),
+    ('\u{11340}', '\u{11340}', Extend),
+    ('\u{11341}', '\u{11344}', SpacingMark),
+    ('\u{11347}', '\u{11348}', SpacingMark),
+    ('\u{1134B}', '\u{1134C}', SpacingMark),
+    ('\u{1134D}', '\u{1134D}', Extend),
+    ('\u{11357}', '\u{11357}', Extend),
+    ('\u{11362}', '\u{11363}', SpacingMark),
+    ('\u{11366}', '\u{1136C}', Extend),
+    ('\u{11370}', '\u{11374}', Extend),
+    ('\u{113B8}', '\u{113B8}', Extend),
+    ('\u{113B9}', '\u{113BA}', SpacingMark),
+    ('\u{113BB}', '\u{113C0}', Extend),
+    ('\u{113C2}', '\u{113C2}', Extend),
+    ('\u{113C5}', '\u{113C5}', Extend),
+    ('\u{113C7}', '\u{113C9}', Extend),
+    ('\u{113CA}', '\u{113CA}', SpacingMark),
+    ('\u{113CC}', '\u{113CD}', SpacingMark),
+    ('\u{113CE}', '\u{113D0}', Extend),
+    ('\u{113D1}', '\u{113D1}', Prepend),
+    ('\u{113D2}', '\u{113D2}', Extend),
+    ('\u{113E1}', '\u{113E2}', Extend),
+    ('\u{11435}', '\u{11437}', SpacingMark),
+    ('\u{11438}', '\u{1143F}', Extend),
+    ('\u{11440}', '\u{11441}', SpacingMark),
+    ('\u{11442}', '\u{11444}', Extend),
+    ('\u{11445}', '\u{11445}', SpacingMark),
+    ('\u{11446}', '\u{11446}', Extend),
+    ('\u{1145E}', '\u{1145E}', Extend),
+    ('\u{114B0}', '\u{114B0}', Extend),
+    ('\u{114B1}', '\u{114B2}', SpacingMark),
+    ('\u{114B3}', '\u{114B8}', Extend),
+    ('\u{114B9}', '\u{114B9}', SpacingMark),
+    ('\u{114BA}', '\u{114BA}', Extend),
+    ('\u{114BB}', '\u{114BC}', SpacingMark),
+    ('\u{114BD}', '\u{114BD}', Extend),
+    ('\u{114BE}', '\u{114BE}', SpacingMark),
+    ('\u{114BF}', '\u{114C0}', Extend),
+    ('\u{114C1}', '\u{114C1}', SpacingMark),
+    ('\u{114C2}', '\u{114C3}', Extend),
+    ('\u{115AF}', '\u{115AF}', Extend),
+    ('\u{115B0}', '\u{115B1}', SpacingMark),
+    ('\u{115B2}', '\u{115B5}', Extend),
+    ('\u{115B8}', '\u{115BB}', SpacingMark),
+    ('\u{115BC}', '\u{115BD}', Extend),
+    ('\u{115BE}', '\u{115BE}', SpacingMark),
+    ('\u{115BF}', '\u{115C0}', Extend),
+    ('\u{115DC}', '\u{115DD}', Extend),
+    ('\u{11630}', '\u{11632}', SpacingMark),
+    ('\u{11633}', '\u{1163A}', Extend),
+    ('\u{1163B}', '\u{1163C}', SpacingMark),
+    ('\u{1163D}', '\u{1163D}', Extend),
+    ('\u{1163E}', '\u{1163E}', SpacingMark),
+    ('\u{1163F}', '\u{11640}', Extend),
+    ('\u{116AB}', '\u{116AB}', Extend),
+    ('\u{116AC}', '\u{116AC}', SpacingMark),
+    ('\u{116AD}', '\u{116AD}', Extend),
+    ('\u{116AE}', '\u{116AF}', SpacingMark),
+    ('\u{116B0}', '\u{116B7}', Extend),
+    ('\u{1171D}', '\u{1171D}', Extend),
+    ('\u{1171E}', '\u{1171E}', SpacingMark),
+    ('\u{1171F}', '\u{1171F}', Extend),
+    ('\u{11722}', '\u{11725}', Extend),
+    ('\u{11726}', '\u{11726}', SpacingMark),
+    ('\u{11727}', '\u{1172B}', Extend),
+    ('\u{1182C}', '\u{1182E}', SpacingMark),
+    ('\u{1182F}', '\u{11837}', Extend),
+    ('\u{11838}', '\u{11838}', SpacingMark),
+    ('\u{11839}', '\u{1183A}', Extend),
+    ('\u{11930}', '\u{11930}', Extend),
+    ('\u{11931}', '\u{11935}', SpacingMark),
+    ('\u{11937}', '\u{11938}', SpacingMark),
+    ('\u{1193B}', '\u{1193E}', Extend),
+    ('\u{1193F}', '\u{1193F}', Prepend),
+    ('\u{11940}', '\u{11940}', SpacingMark),
+    ('\u{11941}', '\u{11941}', Prepend),
+    ('\u{11942}', '\u{11942}', SpacingMark),
+    ('\u{11943}', '\u{11943}', Extend),
+    ('\u{119D1}', '\u{119D3}', SpacingMark),
+    ('\u{119D4}', '\u{119D7}', Extend),
+    ('\u{119DA}', '\u{119DB}', Extend),
+    ('\u{119DC}', '\u{119DF}', SpacingMark),
+    ('\u{119E0}', '\u{119E0}', Extend),
+    ('\u{119E4}', '\u{119E4}', SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', Extend),
+    ('\u{11A33}', '\u{11A38}', Extend),
+    ('\u{11A39}', '\u{11A39}', SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', Prepend),
+    ('\u{11A3B}', '\u{11A3E}', Extend),
+    ('\u{11A47}', '\u{11A47}', Extend),
+    ('\u{11A51}', '\u{11A56}', Extend),
+    ('\u{11A57}', '\u{11A58}', SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', Extend),
+    ('\u{11A84}', '\u{11A89}', Prepend),
+    ('\u{11A8A}', '\u{11A96}', Extend),
+    ('\u{11A97}', '\u{11A97}', SpacingMark),
+    ('\u{11A98}', '\u{11A99}', Extend),
+    ('\u{11C2F}', '\u{11C2F}', SpacingMark),
+    ('\u{11C30}', '\u{11C36}', Extend),
+    ('\u{11C38}', '\u{11C3D}', Extend),
+    ('\u{11C3E}', '\u{11C3E}', SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', Extend),
+    ('\u{11C92}', '\u{11CA7}', Extend),
+    ('\u{11CA9}', '\u{11CA9}', SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', Extend),
+    ('\u{11CB1}', '\u{11CB1}', SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', Extend),
+    ('\u{11CB4}', '\u{11CB4}', SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', Extend),
+    ('\u{11D31}', '\u{11D36}', Extend),
+    ('\u{11D3A}', '\u{11D3A}', Extend),
+    ('\u{11D3C}', '\u{11D3D}', Extend),
+    ('\u{11D3F}', '\u{11D45}', Extend),
+    ('\u{11D46}', '\u{11D46}', Prepend),
+    ('\u{11D47}', '\u{11D47}', Extend),
+    ('\u{11D8A}', '\u{11D8E}', SpacingMark),
+    ('\u{11D90}', '\u{11D91}', Extend),
+    ('\u{11D93}', '\u{11D94}', SpacingMark),
+    ('\u{11D95}', '\u{11D95}', Extend),
+    ('\u{11D96}', '\u{11D96}', SpacingMark),
+    ('\u{11D97}', '\u{11D97}', Extend),
+    ('\u{11EF3}', '\u{11EF4}', Extend),
+    ('\u{11EF5}', '\u{11EF6}', SpacingMark),
+    ('\u{11F00}', '\u{11F01}', Extend),
+    ('\u{11F02}', '\u{11F02}', Prepend),
+    ('\u{11F03}', '\u{11F03}', SpacingMark),
+    ('\u{11F34}', '\u{11F35}', SpacingMark),
+    ('\u{11F36}', '\u{11F3A}', Extend),
+    ('\u{11F3E}', '\u{11F3F}', SpacingMark),
+    ('\u{11F40}', '\u{11F42}', Extend),
+    ('\u{11F5A}', '\u{11F5A}', Extend),
+    ('\u{13430}', '\u{1343F}', Control),
+    ('\u{13440}', '\u{13440}', Extend),
+    ('\u{13447}', '\u{13455}', Extend),
+    ('\u{1611E}', '\u{16129}', Extend),
+    ('\u{1612A}', '\u{1612C}', SpacingMark),
+    ('\u{1612D}', '\u{1612F}', Extend),
+    ('\u{16AF0}', '\u{16AF4}', Extend),
+    ('\u{16B30}', '\u{16B36}', Extend),
+    ('\u{16D63}', '\u{16D63}', V),
+    ('\u{16D67}', '\u{16D6A}', V),
+    ('\u{16F4F}', '\u{16F4F}', Extend),
+    ('\u{16F51}', '\u{16F87}', SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', Extend),
+    ('\u{16FE4}', '\u{16FE4}', Extend),
+    ('\u{16FF0}', '\u{16FF1}', Extend),
+    ('\u{1BC9D}', '\u{1BC9E}', Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', Control),
+    ('\u{1CF00}', '\u{1CF2D}', Extend),
+    ('\u{1CF30}', '\u{1CF46}', Extend),
+    ('\u{1D165}', '\u{1D169}', Extend),
+    ('\u{1D16D}', '\u{1D172}', Extend),
+    ('\u{1D173}', '\u{1D17A}', Control),
+    ('\u{1D17B}', '\u{1D182}', Extend),
+    ('\u{1D185}', '\u{1D18B}', Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', Extend),
+    ('\u{1D242}', '\u{1D244}', Extend),
+    ('\u{1DA00}', '\u{1DA36}', Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', Extend),
+    ('\u{1DA75}', '\u{1DA75}', Extend),
+    ('\u{1DA84}', '\u{1DA84}', Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', Extend),
+    ('\u{1E000}', '\u{1E006}', Extend),
+    ('\u{1E008}', '\u{1E018}', Extend),
+    ('\u{1E01B}', '\u{1E021}', Extend),
+    ('\u{1E023}', '\u{1E024}', Extend),
+    ('\u{1E026}', '\u{1E02A}', Extend),
+    ('\u{1E08F}', '\u{1E08F}', Extend),
+    ('\u{1E130}', '\u{1E136}', Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', Extend),
+    ('\u{1E4EC}', '\u{1E4EF}', Extend),
+    ('\u{1E5EE}', '\u{1E5EF}', Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', Extend),
+    ('\u{1E944}', '\u{1E94A}', Extend),
+    ('\u{1F1E6}', '\u{1F1FF}', RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', Extend),
+    ('\u{E0000}', '\u{E001F}', Control),
+    ('\u{E0020}', '\u{E007F}', Extend),
+    ('\u{E0080}', '\u{E00FF}', Control),
+    ('\u{E0100}', '\u{E01EF}', Extend),
+    ('\u{E01F0}', '\u{E0FFF}', Control),
+];
@@ -0,0 +1,1505 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+use crate::unicode::security::IdentifierType::{self, *};
+
+pub(crate) const IDENTIFIER_TYPE: &[(char, char, &[IdentifierType])] = &[
+    ('\u{9}', '\u{D}', &[NotXid]),
+    ('\u{20}', '\u{26}', &[NotXid]),
+    ('\u{27}', '\u{27}', &[Inclusion]),
+    ('\u{28}', '\u{2C}', &[NotXid]),
+    ('\u{2D}', '\u{2E}', &[Inclusion]),
+    ('\u{2F}', '\u{2F}', &[NotXid]),
+    ('\u{30}', '\u{39}', &[Recommended]),
+    ('\u{3A}', '\u{3A}', &[Inclusion]),
+    ('\u{3B}', '\u{40}', &[NotXid]),
+    ('\u{41}', '\u{5A}', &[Recommended]),
+    ('\u{5B}', '\u{5E}', &[NotXid]),
+    ('\u{5F}', '\u{5F}', &[Recommended]),
+    ('\u{60}', '\u{60}', &[NotXid]),
+    ('\u{61}', '\u{7A}', &[Recommended]),
+    ('\u{7B}', '\u{7E}', &[NotXid]),
+    ('\u{85}', '\u{85}', &[NotXid]),
+    ('\u{A0}', '\u{A0}', &[NotNfkc]),
+    ('\u{A1}', '\u{A7}', &[NotXid]),
+    ('\u{A8}', '\u{A8}', &[NotNfkc]),
+    ('\u{A9}', '\u{A9}', &[NotXid]),
+    ('\u{AA}', '\u{AA}', &[NotNfkc]),
+    ('\u{AB}', '\u{AC}', &[NotXid]),
+    ('\u{AD}', '\u{AD}', &[DefaultIgnorable]),
+    ('\u{AE}', '\u{AE}', &[NotXid]),
+    ('\u{AF}', '\u{AF}', &[NotNfkc]),
+    ('\u{B0}', '\u{B1}', &[NotXid]),
+    ('\u{B2}', '\u{B5}', &[NotNfkc]),
+    ('\u{B6}', '\u{B6}', &[NotXid]),
+    ('\u{B7}', '\u{B7}', &[Inclusion]),
+    ('\u{B8}', '\u{BA}', &[NotNfkc]),
+    ('\u{BB}', '\u{BB}', &[NotXid]),
+    ('\u{BC}', '\u{BE}', &[NotNfkc]),
+    ('\u{BF}', '\u{BF}', &[NotXid]),
+    ('\u{C0}', '\u{D6}', &[Recommended]),
+    ('\u{D7}', '\u{D7}', &[NotXid]),
+    ('\u{D8}', '\u{F6}', &[Recommended]),
+    ('\u{F7}', '\u{F7}', &[NotXid]),
+    ('\u{F8}', '\u{131}', &[Recommended]),
+    ('\u{132}', '\u{133}', &[NotNfkc]),
+    ('\u{134}', '\u{13E}', &[Recommended]),
+    ('\u{13F}', '\u{140}', &[NotNfkc]),
+    ('\u{141}', '\u{148}', &[Recommended]),
+    ('\u{149}', '\u{149}', &[Deprecated]),
+    ('\u{14A}', '\u{17E}', &[Recommended]),
+    ('\u{17F}', '\u{17F}', &[NotNfkc]),
+    ('\u{180}', '\u{180}', &[Technical]),
+    ('\u{181}', '\u{18C}', &[UncommonUse]),
+    ('\u{18D}', '\u{18D}', &[Technical]),
+    ('\u{18E}', '\u{18E}', &[UncommonUse]),
+    ('\u{18F}', '\u{18F}', &[Recommended]),
+    ('\u{190}', '\u{19F}', &[UncommonUse]),
+    ('\u{1A0}', '\u{1A1}', &[Recommended]),
+    ('\u{1A2}', '\u{1A9}', &[UncommonUse]),
+    ('\u{1AA}', '\u{1AB}', &[Technical]),
+    ('\u{1AC}', '\u{1AE}', &[UncommonUse]),
+    ('\u{1AF}', '\u{1B0}', &[Recommended]),
+    ('\u{1B1}', '\u{1B8}', &[UncommonUse]),
+    ('\u{1B9}', '\u{1B9}', &[Obsolete]),
+    ('\u{1BA}', '\u{1BB}', &[Technical]),
+    ('\u{1BC}', '\u{1BD}', &[UncommonUse]),
+    ('\u{1BE}', '\u{1BE}', &[Technical]),
+    ('\u{1BF}', '\u{1BF}', &[Obsolete]),
+    ('\u{1C0}', '\u{1C3}', &[Technical]),
+    ('\u{1C4}', '\u{1CC}', &[NotNfkc]),
+    ('\u{1CD}', '\u{1DC}', &[Recommended]),
+    ('\u{1DD}', '\u{1DD}', &[UncommonUse]),
+    ('\u{1DE}', '\u{1E3}', &[Recommended]),
+    ('\u{1E4}', '\u{1E5}', &[UncommonUse]),
+    ('\u{1E6}', '\u{1F0}', &[Recommended]),
+    ('\u{1F1}', '\u{1F3}', &[NotNfkc]),
+    ('\u{1F4}', '\u{1F5}', &[Recommended]),
+    ('\u{1F6}', '\u{1F7}', &[Obsolete]),
+    ('\u{1F8}', '\u{21B}', &[Recommended]),
+    ('\u{21C}', '\u{21D}', &[Obsolete]),
+    ('\u{21E}', '\u{21F}', &[Recommended]),
+    ('\u{220}', '\u{225}', &[UncommonUse]),
+    ('\u{226}', '\u{233}', &[Recommended]),
+    ('\u{234}', '\u{236}', &[Technical]),
+    ('\u{237}', '\u{24F}', &[UncommonUse]),
+    ('\u{250}', '\u{252}', &[Technical]),
+    ('\u{253}', '\u{254}', &[UncommonUse]),
+    ('\u{255}', '\u{255}', &[Technical]),
+    ('\u{256}', '\u{257}', &[UncommonUse]),
+    ('\u{258}', '\u{258}', &[Technical]),
+    ('\u{259}', '\u{259}', &[Recommended]),
+    ('\u{25A}', '\u{25A}', &[Technical]),
+    ('\u{25B}', '\u{25B}', &[UncommonUse]),
+    ('\u{25C}', '\u{262}', &[Technical]),
+    ('\u{263}', '\u{263}', &[UncommonUse]),
+    ('\u{264}', '\u{267}', &[Technical]),
+    ('\u{268}', '\u{269}', &[UncommonUse]),
+    ('\u{26A}', '\u{271}', &[Technical]),
+    ('\u{272}', '\u{272}', &[UncommonUse]),
+    ('\u{273}', '\u{288}', &[Technical]),
+    ('\u{289}', '\u{289}', &[UncommonUse]),
+    ('\u{28A}', '\u{291}', &[Technical]),
+    ('\u{292}', '\u{292}', &[UncommonUse]),
+    ('\u{293}', '\u{2AF}', &[Technical]),
+    ('\u{2B0}', '\u{2B8}', &[NotNfkc]),
+    ('\u{2B9}', '\u{2BA}', &[Technical]),
+    ('\u{2BB}', '\u{2BC}', &[Recommended]),
+    ('\u{2BD}', '\u{2C1}', &[Technical]),
+    ('\u{2C2}', '\u{2C5}', &[NotXid]),
+    ('\u{2C6}', '\u{2D1}', &[Technical]),
+    ('\u{2D2}', '\u{2D7}', &[NotXid]),
+    ('\u{2D8}', '\u{2DD}', &[NotNfkc]),
+    ('\u{2DE}', '\u{2DF}', &[NotXid]),
+    ('\u{2E0}', '\u{2E4}', &[NotNfkc]),
+    ('\u{2E5}', '\u{2EB}', &[NotXid]),
+    ('\u{2EC}', '\u{2EC}', &[Recommended]),
+    ('\u{2ED}', '\u{2ED}', &[NotXid]),
+    ('\u{2EE}', '\u{2EE}', &[Technical]),
+    ('\u{2EF}', '\u{2FF}', &[NotXid]),
+    ('\u{300}', '\u{304}', &[Recommended]),
+    ('\u{305}', '\u{305}', &[UncommonUse]),
+    ('\u{306}', '\u{30C}', &[Recommended]),
+    ('\u{30D}', '\u{30D}', &[UncommonUse]),
+    ('\u{30E}', '\u{30E}', &[Technical]),
+    ('\u{30F}', '\u{311}', &[Recommended]),
+    ('\u{312}', '\u{312}', &[Technical]),
+    ('\u{313}', '\u{314}', &[Recommended]),
+    ('\u{315}', '\u{315}', &[Technical]),
+    ('\u{316}', '\u{316}', &[UncommonUse]),
+    ('\u{317}', '\u{31A}', &[Technical]),
+    ('\u{31B}', '\u{31B}', &[Recommended]),
+    ('\u{31C}', '\u{320}', &[Technical]),
+    ('\u{321}', '\u{322}', &[UncommonUse]),
+    ('\u{323}', '\u{328}', &[Recommended]),
+    ('\u{329}', '\u{32C}', &[Technical]),
+    ('\u{32D}', '\u{32E}', &[Recommended]),
+    ('\u{32F}', '\u{32F}', &[Technical]),
+    ('\u{330}', '\u{331}', &[Recommended]),
+    ('\u{332}', '\u{332}', &[UncommonUse]),
+    ('\u{333}', '\u{333}', &[Technical]),
+    ('\u{334}', '\u{334}', &[UncommonUse]),
+    ('\u{335}', '\u{335}', &[Recommended]),
+    ('\u{336}', '\u{336}', &[UncommonUse]),
+    ('\u{337}', '\u{337}', &[Technical]),
+    ('\u{338}', '\u{339}', &[Recommended]),
+    ('\u{33A}', '\u{33F}', &[Technical]),
+    ('\u{340}', '\u{341}', &[NotNfkc]),
+    ('\u{342}', '\u{342}', &[Recommended]),
+    ('\u{343}', '\u{344}', &[NotNfkc]),
+    ('\u{345}', '\u{345}', &[Recommended]),
+    ('\u{346}', '\u{34E}', &[Technical]),
+    ('\u{34F}', '\u{34F}', &[DefaultIgnorable]),
+    ('\u{350}', '\u{357}', &[Technical]),
+    ('\u{358}', '\u{358}', &[UncommonUse]),
+    ('\u{359}', '\u{362}', &[Technical]),
+    ('\u{363}', '\u{373}', &[Obsolete]),
+    ('\u{374}', '\u{374}', &[NotNfkc]),
+    ('\u{375}', '\u{375}', &[Inclusion]),
+    ('\u{376}', '\u{377}', &[Obsolete]),
+    ('\u{37A}', '\u{37A}', &[NotNfkc]),
+    ('\u{37B}', '\u{37D}', &[Recommended]),
+    ('\u{37E}', '\u{37E}', &[NotNfkc]),
+    ('\u{37F}', '\u{37F}', &[Obsolete]),
+    ('\u{384}', '\u{385}', &[NotNfkc]),
+    ('\u{386}', '\u{386}', &[Recommended]),
+    ('\u{387}', '\u{387}', &[NotNfkc]),
+    ('\u{388}', '\u{38A}', &[Recommended]),
+    ('\u{38C}', '\u{38C}', &[Recommended]),
+    ('\u{38E}', '\u{3A1}', &[Recommended]),
+    ('\u{3A3}', '\u{3CE}', &[Recommended]),
+    ('\u{3CF}', '\u{3CF}', &[Technical]),
+    ('\u{3D0}', '\u{3D6}', &[NotNfkc]),
+    ('\u{3D7}', '\u{3D7}', &[Technical]),
+    ('\u{3D8}', '\u{3E1}', &[Obsolete]),
+    ('\u{3E2}', '\u{3EF}', &[Exclusion]),
+    ('\u{3F0}', '\u{3F2}', &[NotNfkc]),
+    ('\u{3F3}', '\u{3F3}', &[Technical]),
+    ('\u{3F4}', '\u{3F5}', &[NotNfkc]),
+    ('\u{3F6}', '\u{3F6}', &[NotXid]),
+    ('\u{3F7}', '\u{3F8}', &[Obsolete]),
+    ('\u{3F9}', '\u{3F9}', &[NotNfkc]),
+    ('\u{3FA}', '\u{3FB}', &[Obsolete]),
+    ('\u{3FC}', '\u{45F}', &[Recommended]),
+    ('\u{460}', '\u{483}', &[Obsolete]),
+    ('\u{484}', '\u{487}', &[Technical]),
+    ('\u{488}', '\u{489}', &[Obsolete]),
+    ('\u{48A}', '\u{4FF}', &[Recommended]),
+    ('\u{500}', '\u{50F}', &[Obsolete]),
+    ('\u{510}', '\u{529}', &[Recommended]),
+    ('\u{52A}', '\u{52D}', &[Obsolete]),
+    ('\u{52E}', '\u{52F}', &[Recommended]),
+    ('\u{531}', '\u{556}', &[Recommended]),
+    ('\u{559}', '\u{559}', &[Recommended]),
+    ('\u{55A}', '\u{55F}', &[NotXid]),
+    ('\u{560}', '\u{560}', &[Technical]),
+    ('\u{561}', '\u{586}', &[Recommended]),
+    ('\u{587}', '\u{587}', &[NotNfkc]),
+    ('\u{588}', '\u{588}', &[Technical]),
+    ('\u{589}', '\u{589}', &[NotXid]),
+    ('\u{58A}', '\u{58A}', &[Inclusion]),
+    ('\u{58D}', '\u{58F}', &[NotXid]),
+    ('\u{591}', '\u{5B3}', &[UncommonUse]),
+    ('\u{5B4}', '\u{5B4}', &[Recommended]),
+    ('\u{5B5}', '\u{5BD}', &[UncommonUse]),
+    ('\u{5BE}', '\u{5BE}', &[NotXid]),
+    ('\u{5BF}', '\u{5BF}', &[UncommonUse]),
+    ('\u{5C0}', '\u{5C0}', &[NotXid]),
+    ('\u{5C1}', '\u{5C2}', &[UncommonUse]),
+    ('\u{5C3}', '\u{5C3}', &[NotXid]),
+    ('\u{5C4}', '\u{5C5}', &[UncommonUse]),
+    ('\u{5C6}', '\u{5C6}', &[Obsolete]),
+    ('\u{5C7}', '\u{5C7}', &[UncommonUse]),
+    ('\u{5D0}', '\u{5EA}', &[Recommended]),
+    ('\u{5EF}', '\u{5F2}', &[Recommended]),
+    ('\u{5F3}', '\u{5F4}', &[Inclusion]),
+    ('\u{600}', '\u{60F}', &[NotXid]),
+    ('\u{610}', '\u{61A}', &[UncommonUse]),
+    ('\u{61B}', '\u{61B}', &[NotXid]),
+    ('\u{61C}', '\u{61C}', &[DefaultIgnorable]),
+    ('\u{61D}', '\u{61F}', &[NotXid]),
+    ('\u{620}', '\u{63F}', &[Recommended]),
+    ('\u{640}', '\u{640}', &[Obsolete]),
+    ('\u{641}', '\u{655}', &[Recommended]),
+    ('\u{656}', '\u{65F}', &[UncommonUse]),
+    ('\u{660}', '\u{669}', &[Recommended]),
+    ('\u{66A}', '\u{66D}', &[NotXid]),
+    ('\u{66E}', '\u{66F}', &[Obsolete]),
+    ('\u{670}', '\u{672}', &[Recommended]),
+    ('\u{673}', '\u{673}', &[Deprecated]),
+    ('\u{674}', '\u{674}', &[Recommended]),
+    ('\u{675}', '\u{678}', &[NotNfkc]),
+    ('\u{679}', '\u{68D}', &[Recommended]),
+    ('\u{68E}', '\u{68E}', &[Obsolete]),
+    ('\u{68F}', '\u{6A0}', &[Recommended]),
+    ('\u{6A1}', '\u{6A1}', &[Obsolete]),
+    ('\u{6A2}', '\u{6D3}', &[Recommended]),
+    ('\u{6D4}', '\u{6D4}', &[NotXid]),
+    ('\u{6D5}', '\u{6D5}', &[Recommended]),
+    ('\u{6D6}', '\u{6DC}', &[UncommonUse]),
+    ('\u{6DD}', '\u{6DE}', &[NotXid]),
+    ('\u{6DF}', '\u{6E4}', &[UncommonUse]),
+    ('\u{6E5}', '\u{6E6}', &[Recommended]),
+    ('\u{6E7}', '\u{6E8}', &[UncommonUse]),
+    ('\u{6E9}', '\u{6E9}', &[NotXid]),
+    ('\u{6EA}', '\u{6ED}', &[UncommonUse]),
+    ('\u{6EE}', '\u{6FC}', &[Recommended]),
+    ('\u{6FD}', '\u{6FE}', &[Inclusion]),
+    ('\u{6FF}', '\u{6FF}', &[Recommended]),
+    ('\u{700}', '\u{70D}', &[LimitedUse]),
+    ('\u{70F}', '\u{74A}', &[LimitedUse]),
+    ('\u{74D}', '\u{74F}', &[LimitedUse]),
+    ('\u{750}', '\u{7B1}', &[Recommended]),
+    ('\u{7C0}', '\u{7FA}', &[LimitedUse]),
+    ('\u{7FD}', '\u{7FF}', &[LimitedUse]),
+    ('\u{800}', '\u{82D}', &[Exclusion]),
+    ('\u{830}', '\u{83E}', &[Exclusion]),
+    ('\u{840}', '\u{85B}', &[LimitedUse]),
+    ('\u{85E}', '\u{85E}', &[LimitedUse]),
+    ('\u{860}', '\u{86A}', &[LimitedUse]),
+    ('\u{870}', '\u{887}', &[Recommended]),
+    ('\u{888}', '\u{888}', &[NotXid]),
+    ('\u{889}', '\u{88E}', &[Recommended]),
+    ('\u{890}', '\u{891}', &[NotXid]),
+    ('\u{897}', '\u{89F}', &[UncommonUse]),
+    ('\u{8A0}', '\u{8AC}', &[Recommended]),
+    ('\u{8AD}', '\u{8B1}', &[Obsolete]),
+    ('\u{8B2}', '\u{8B2}', &[Recommended]),
+    ('\u{8B3}', '\u{8B4}', &[UncommonUse]),
+    ('\u{8B5}', '\u{8C9}', &[Recommended]),
+    ('\u{8CA}', '\u{8E1}', &[UncommonUse]),
+    ('\u{8E2}', '\u{8E2}', &[NotXid]),
+    ('\u{8E3}', '\u{900}', &[UncommonUse]),
+    ('\u{901}', '\u{94D}', &[Recommended]),
+    ('\u{94E}', '\u{94E}', &[Obsolete]),
+    ('\u{94F}', '\u{950}', &[Recommended]),
+    ('\u{951}', '\u{952}', &[Obsolete]),
+    ('\u{953}', '\u{954}', &[Technical]),
+    ('\u{955}', '\u{955}', &[UncommonUse]),
+    ('\u{956}', '\u{957}', &[Recommended]),
+    ('\u{958}', '\u{95F}', &[NotNfkc]),
+    ('\u{960}', '\u{963}', &[Recommended]),
+    ('\u{964}', '\u{965}', &[NotXid]),
+    ('\u{966}', '\u{96F}', &[Recommended]),
+    ('\u{970}', '\u{970}', &[NotXid]),
+    ('\u{971}', '\u{977}', &[Recommended]),
+    ('\u{978}', '\u{978}', &[Obsolete]),
+    ('\u{979}', '\u{97F}', &[Recommended]),
+    ('\u{980}', '\u{980}', &[Obsolete]),
+    ('\u{981}', '\u{983}', &[Recommended]),
+    ('\u{985}', '\u{98C}', &[Recommended]),
+    ('\u{98F}', '\u{990}', &[Recommended]),
+    ('\u{993}', '\u{9A8}', &[Recommended]),
+    ('\u{9AA}', '\u{9B0}', &[Recommended]),
+    ('\u{9B2}', '\u{9B2}', &[Recommended]),
+    ('\u{9B6}', '\u{9B9}', &[Recommended]),
+    ('\u{9BC}', '\u{9C4}', &[Recommended]),
+    ('\u{9C7}', '\u{9C8}', &[Recommended]),
+    ('\u{9CB}', '\u{9CE}', &[Recommended]),
+    ('\u{9D7}', '\u{9D7}', &[Recommended]),
+    ('\u{9DC}', '\u{9DD}', &[NotNfkc]),
+    ('\u{9DF}', '\u{9DF}', &[NotNfkc]),
+    ('\u{9E0}', '\u{9E3}', &[Recommended]),
+    ('\u{9E6}', '\u{9F1}', &[Recommended]),
+    ('\u{9F2}', '\u{9FB}', &[NotXid]),
+    ('\u{9FC}', '\u{9FC}', &[Obsolete]),
+    ('\u{9FD}', '\u{9FD}', &[NotXid]),
+    ('\u{9FE}', '\u{9FE}', &[Recommended]),
+    ('\u{A01}', '\u{A03}', &[Recommended]),
+    ('\u{A05}', '\u{A0A}', &[Recommended]),
+    ('\u{A0F}', '\u{A10}', &[Recommended]),
+    ('\u{A13}', '\u{A28}', &[Recommended]),
+    ('\u{A2A}', '\u{A30}', &[Recommended]),
+    ('\u{A32}', '\u{A32}', &[Recommended]),
+    ('\u{A33}', '\u{A33}', &[NotNfkc]),
+    ('\u{A35}', '\u{A35}', &[Recommended]),
+    ('\u{A36}', '\u{A36}', &[NotNfkc]),
+    ('\u{A38}', '\u{A39}', &[Recommended]),
+    ('\u{A3C}', '\u{A3C}', &[Recommended]),
+    ('\u{A3E}', '\u{A42}', &[Recommended]),
+    ('\u{A47}', '\u{A48}', &[Recommended]),
+    ('\u{A4B}', '\u{A4D}', &[Recommended]),
+    ('\u{A51}', '\u{A51}', &[UncommonUse]),
+    ('\u{A59}', '\u{A5B}', &[NotNfkc]),
+    ('\u{A5C}', '\u{A5C}', &[Recommended]),
+    ('\u{A5E}', '\u{A5E}', &[NotNfkc]),
+    ('\u{A66}', '\u{A74}', &[Recommended]),
+    ('\u{A75}', '\u{A75}', &[UncommonUse]),
+    ('\u{A76}', '\u{A76}', &[NotXid]),
+    ('\u{A81}', '\u{A83}', &[Recommended]),
+    ('\u{A85}', '\u{A8D}', &[Recommended]),
+    ('\u{A8F}', '\u{A91}', &[Recommended]),
+    ('\u{A93}', '\u{AA8}', &[Recommended]),
+    ('\u{AAA}', '\u{AB0}', &[Recommended]),
+    ('\u{AB2}', '\u{AB3}', &[Recommended]),
+    ('\u{AB5}', '\u{AB9}', &[Recommended]),
+    ('\u{ABC}', '\u{AC5}', &[Recommended]),
+    ('\u{AC7}', '\u{AC9}', &[Recommended]),
+    ('\u{ACB}', '\u{ACD}', &[Recommended]),
+    ('\u{AD0}', '\u{AD0}', &[Recommended]),
+    ('\u{AE0}', '\u{AE3}', &[Recommended]),
+    ('\u{AE6}', '\u{AEF}', &[Recommended]),
+    ('\u{AF0}', '\u{AF1}', &[NotXid]),
+    ('\u{AF9}', '\u{AF9}', &[UncommonUse]),
+    ('\u{AFA}', '\u{AFF}', &[Recommended]),
+    ('\u{B01}', '\u{B03}', &[Recommended]),
+    ('\u{B05}', '\u{B0C}', &[Recommended]),
+    ('\u{B0F}', '\u{B10}', &[Recommended]),
+    ('\u{B13}', '\u{B28}', &[Recommended]),
+    ('\u{B2A}', '\u{B30}', &[Recommended]),
+    ('\u{B32}', '\u{B33}', &[Recommended]),
+    ('\u{B35}', '\u{B39}', &[Recommended]),
+    ('\u{B3C}', '\u{B43}', &[Recommended]),
+    ('\u{B44}', '\u{B44}', &[UncommonUse]),
+    ('\u{B47}', '\u{B48}', &[Recommended]),
+    ('\u{B4B}', '\u{B4D}', &[Recommended]),
+    ('\u{B55}', '\u{B57}', &[Recommended]),
+    ('\u{B5C}', '\u{B5D}', &[NotNfkc]),
+    ('\u{B5F}', '\u{B61}', &[Recommended]),
+    ('\u{B62}', '\u{B63}', &[UncommonUse]),
+    ('\u{B66}', '\u{B6F}', &[Recommended]),
+    ('\u{B70}', '\u{B70}', &[NotXid]),
+    ('\u{B71}', '\u{B71}', &[Recommended]),
+    ('\u{B72}', '\u{B77}', &[NotXid]),
+    ('\u{B82}', '\u{B83}', &[Recommended]),
+    ('\u{B85}', '\u{B8A}', &[Recommended]),
+    ('\u{B8E}', '\u{B90}', &[Recommended]),
+    ('\u{B92}', '\u{B95}', &[Recommended]),
+    ('\u{B99}', '\u{B9A}', &[Recommended]),
+    ('\u{B9C}', '\u{B9C}', &[Recommended]),
+    ('\u{B9E}', '\u{B9F}', &[Recommended]),
+    ('\u{BA3}', '\u{BA4}', &[Recommended]),
+    ('\u{BA8}', '\u{BAA}', &[Recommended]),
+    ('\u{BAE}', '\u{BB9}', &[Recommended]),
+    ('\u{BBE}', '\u{BC2}', &[Recommended]),
+    ('\u{BC6}', '\u{BC8}', &[Recommended]),
+    ('\u{BCA}', '\u{BCD}', &[Recommended]),
+    ('\u{BD0}', '\u{BD0}', &[Recommended]),
+    ('\u{BD7}', '\u{BD7}', &[Recommended]),
+    ('\u{BE6}', '\u{BEF}', &[Recommended]),
+    ('\u{BF0}', '\u{BFA}', &[NotXid]),
+    ('\u{C00}', '\u{C00}', &[Obsolete]),
+    ('\u{C01}', '\u{C0C}', &[Recommended]),
+    ('\u{C0E}', '\u{C10}', &[Recommended]),
+    ('\u{C12}', '\u{C28}', &[Recommended]),
+    ('\u{C2A}', '\u{C33}', &[Recommended]),
+    ('\u{C34}', '\u{C34}', &[Obsolete]),
+    ('\u{C35}', '\u{C39}', &[Recommended]),
+    ('\u{C3C}', '\u{C44}', &[Recommended]),
+    ('\u{C46}', '\u{C48}', &[Recommended]),
+    ('\u{C4A}', '\u{C4D}', &[Recommended]),
+    ('\u{C55}', '\u{C56}', &[Recommended]),
+    ('\u{C58}', '\u{C59}', &[Obsolete]),
+    ('\u{C5A}', '\u{C5A}', &[UncommonUse]),
+    ('\u{C5D}', '\u{C5D}', &[Recommended]),
+    ('\u{C60}', '\u{C61}', &[Recommended]),
+    ('\u{C62}', '\u{C63}', &[UncommonUse]),
+    ('\u{C66}', '\u{C6F}', &[Recommended]),
+    ('\u{C77}', '\u{C7F}', &[NotXid]),
+    ('\u{C80}', '\u{C80}', &[Recommended]),
+    ('\u{C81}', '\u{C81}', &[Obsolete]),
+    ('\u{C82}', '\u{C83}', &[Recommended]),
+    ('\u{C84}', '\u{C84}', &[NotXid]),
+    ('\u{C85}', '\u{C8C}', &[Recommended]),
+    ('\u{C8E}', '\u{C90}', &[Recommended]),
+    ('\u{C92}', '\u{CA8}', &[Recommended]),
+    ('\u{CAA}', '\u{CB3}', &[Recommended]),
+    ('\u{CB5}', '\u{CB9}', &[Recommended]),
+    ('\u{CBC}', '\u{CC4}', &[Recommended]),
+    ('\u{CC6}', '\u{CC8}', &[Recommended]),
+    ('\u{CCA}', '\u{CCD}', &[Recommended]),
+    ('\u{CD5}', '\u{CD6}', &[Recommended]),
+    ('\u{CDD}', '\u{CDD}', &[Recommended]),
+    ('\u{CDE}', '\u{CDE}', &[Obsolete]),
+    ('\u{CE0}', '\u{CE3}', &[Recommended]),
+    ('\u{CE6}', '\u{CEF}', &[Recommended]),
+    ('\u{CF1}', '\u{CF3}', &[Recommended]),
+    ('\u{D00}', '\u{D00}', &[Recommended]),
+    ('\u{D01}', '\u{D01}', &[Obsolete]),
+    ('\u{D02}', '\u{D03}', &[Recommended]),
+    ('\u{D04}', '\u{D04}', &[Technical]),
+    ('\u{D05}', '\u{D0C}', &[Recommended]),
+    ('\u{D0E}', '\u{D10}', &[Recommended]),
+    ('\u{D12}', '\u{D3A}', &[Recommended]),
+    ('\u{D3B}', '\u{D3C}', &[Obsolete]),
+    ('\u{D3D}', '\u{D43}', &[Recommended]),
+    ('\u{D44}', '\u{D44}', &[UncommonUse]),
+    ('\u{D46}', '\u{D48}', &[Recommended]),
+    ('\u{D4A}', '\u{D4E}', &[Recommended]),
+    ('\u{D4F}', '\u{D4F}', &[NotXid]),
+    ('\u{D54}', '\u{D57}', &[Recommended]),
+    ('\u{D58}', '\u{D5E}', &[NotXid]),
+    ('\u{D5F}', '\u{D5F}', &[Obsolete]),
+    ('\u{D60}', '\u{D61}', &[Recommended]),
+    ('\u{D62}', '\u{D63}', &[UncommonUse]),
+    ('\u{D66}', '\u{D6F}', &[Recommended]),
+    ('\u{D70}', '\u{D79}', &[NotXid]),
+    ('\u{D7A}', '\u{D7F}', &[Recommended]),
+    ('\u{D81}', '\u{D81}', &[Technical]),
+    ('\u{D82}', '\u{D83}', &[Recommended]),
+    ('\u{D85}', '\u{D8E}', &[Recommended]),
+    ('\u{D8F}', '\u{D90}', &[UncommonUse]),
+    ('\u{D91}', '\u{D96}', &[Recommended]),
+    ('\u{D9A}', '\u{DA5}', &[Recommended]),
+    ('\u{DA6}', '\u{DA6}', &[UncommonUse]),
+    ('\u{DA7}', '\u{DB1}', &[Recommended]),
+    ('\u{DB3}', '\u{DBB}', &[Recommended]),
+    ('\u{DBD}', '\u{DBD}', &[Recommended]),
+    ('\u{DC0}', '\u{DC6}', &[Recommended]),
+    ('\u{DCA}', '\u{DCA}', &[Recommended]),
+    ('\u{DCF}', '\u{DD4}', &[Recommended]),
+    ('\u{DD6}', '\u{DD6}', &[Recommended]),
+    ('\u{DD8}', '\u{DDE}', &[Recommended]),
+    ('\u{DDF}', '\u{DDF}', &[UncommonUse]),
+    ('\u{DE6}', '\u{DEF}', &[Obsolete]),
+    ('\u{DF2}', '\u{DF2}', &[Recommended]),
+    ('\u{DF3}', '\u{DF3}', &[UncommonUse]),
+    ('\u{DF4}', '\u{DF4}', &[NotXid]),
+    ('\u{E01}', '\u{E32}', &[Recommended]),
+    ('\u{E33}', '\u{E33}', &[NotNfkc]),
+    ('\u{E34}', '\u{E3A}', &[Recommended]),
+    ('\u{E3F}', '\u{E3F}', &[NotXid]),
+    ('\u{E40}', '\u{E4E}', &[Recommended]),
+    ('\u{E4F}', '\u{E4F}', &[NotXid]),
+    ('\u{E50}', '\u{E59}', &[Recommended]),
+    ('\u{E5A}', '\u{E5B}', &[NotXid]),
+    ('\u{E81}', '\u{E82}', &[Recommended]),
+    ('\u{E84}', '\u{E84}', &[Recommended]),
+    ('\u{E86}', '\u{E8A}', &[Recommended]),
+    ('\u{E8C}', '\u{EA3}', &[Recommended]),
+    ('\u{EA5}', '\u{EA5}', &[Recommended]),
+    ('\u{EA7}', '\u{EB2}', &[Recommended]),
+    ('\u{EB3}', '\u{EB3}', &[NotNfkc]),
+    ('\u{EB4}', '\u{EBD}', &[Recommended]),
+    ('\u{EC0}', '\u{EC4}', &[Recommended]),
+    ('\u{EC6}', '\u{EC6}', &[Recommended]),
+    ('\u{EC8}', '\u{ECE}', &[Recommended]),
+    ('\u{ED0}', '\u{ED9}', &[Recommended]),
+    ('\u{EDC}', '\u{EDD}', &[NotNfkc]),
+    ('\u{EDE}', '\u{EDF}', &[Recommended]),
+    ('\u{F00}', '\u{F00}', &[Recommended]),
+    ('\u{F01}', '\u{F0A}', &[NotXid]),
+    ('\u{F0B}', '\u{F0B}', &[Inclusion]),
+    ('\u{F0C}', '\u{F0C}', &[NotNfkc]),
+    ('\u{F0D}', '\u{F17}', &[NotXid]),
+    ('\u{F18}', '\u{F19}', &[Technical]),
+    ('\u{F1A}', '\u{F1F}', &[NotXid]),
+    ('\u{F20}', '\u{F29}', &[Recommended]),
+    ('\u{F2A}', '\u{F34}', &[NotXid]),
+    ('\u{F35}', '\u{F35}', &[Recommended]),
+    ('\u{F36}', '\u{F36}', &[NotXid]),
+    ('\u{F37}', '\u{F37}', &[Recommended]),
+    ('\u{F38}', '\u{F38}', &[NotXid]),
+    ('\u{F39}', '\u{F39}', &[UncommonUse]),
+    ('\u{F3A}', '\u{F3D}', &[NotXid]),
+    ('\u{F3E}', '\u{F42}', &[Recommended]),
+    ('\u{F43}', '\u{F43}', &[NotNfkc]),
+    ('\u{F44}', '\u{F47}', &[Recommended]),
+    ('\u{F49}', '\u{F4C}', &[Recommended]),
+    ('\u{F4D}', '\u{F4D}', &[NotNfkc]),
+    ('\u{F4E}', '\u{F51}', &[Recommended]),
+    ('\u{F52}', '\u{F52}', &[NotNfkc]),
+    ('\u{F53}', '\u{F56}', &[Recommended]),
+    ('\u{F57}', '\u{F57}', &[NotNfkc]),
+    ('\u{F58}', '\u{F5B}', &[Recommended]),
+    ('\u{F5C}', '\u{F5C}', &[NotNfkc]),
+    ('\u{F5D}', '\u{F68}', &[Recommended]),
+    ('\u{F69}', '\u{F69}', &[NotNfkc]),
+    ('\u{F6A}', '\u{F6C}', &[Recommended]),
+    ('\u{F71}', '\u{F72}', &[Recommended]),
+    ('\u{F73}', '\u{F73}', &[NotNfkc]),
+    ('\u{F74}', '\u{F74}', &[Recommended]),
+    ('\u{F75}', '\u{F76}', &[NotNfkc]),
+    ('\u{F77}', '\u{F77}', &[Deprecated]),
+    ('\u{F78}', '\u{F78}', &[NotNfkc]),
+    ('\u{F79}', '\u{F79}', &[Deprecated]),
+    ('\u{F7A}', '\u{F80}', &[Recommended]),
+    ('\u{F81}', '\u{F81}', &[NotNfkc]),
+    ('\u{F82}', '\u{F84}', &[Recommended]),
+    ('\u{F85}', '\u{F85}', &[NotXid]),
+    ('\u{F86}', '\u{F92}', &[Recommended]),
+    ('\u{F93}', '\u{F93}', &[NotNfkc]),
+    ('\u{F94}', '\u{F97}', &[Recommended]),
+    ('\u{F99}', '\u{F9C}', &[Recommended]),
+    ('\u{F9D}', '\u{F9D}', &[NotNfkc]),
+    ('\u{F9E}', '\u{FA1}', &[Recommended]),
+    ('\u{FA2}', '\u{FA2}', &[NotNfkc]),
+    ('\u{FA3}', '\u{FA6}', &[Recommended]),
+    ('\u{FA7}', '\u{FA7}', &[NotNfkc]),
+    ('\u{FA8}', '\u{FAB}', &[Recommended]),
+    ('\u{FAC}', '\u{FAC}', &[NotNfkc]),
+    ('\u{FAD}', '\u{FB8}', &[Recommended]),
+    ('\u{FB9}', '\u{FB9}', &[NotNfkc]),
+    ('\u{FBA}', '\u{FBC}', &[Recommended]),
+    ('\u{FBE}', '\u{FC5}', &[NotXid]),
+    ('\u{FC6}', '\u{FC6}', &[Recommended]),
+    ('\u{FC7}', '\u{FCC}', &[NotXid]),
+    ('\u{FCE}', '\u{FDA}', &[NotXid]),
+    ('\u{1000}', '\u{1049}', &[Recommended]),
+    ('\u{104A}', '\u{104F}', &[NotXid]),
+    ('\u{1050}', '\u{109D}', &[Recommended]),
+    ('\u{109E}', '\u{109F}', &[NotXid]),
+    ('\u{10A0}', '\u{10C5}', &[Obsolete]),
+    ('\u{10C7}', '\u{10C7}', &[Recommended]),
+    ('\u{10CD}', '\u{10CD}', &[Recommended]),
+    ('\u{10D0}', '\u{10F0}', &[Recommended]),
+    ('\u{10F1}', '\u{10F6}', &[Obsolete]),
+    ('\u{10F7}', '\u{10FA}', &[Recommended]),
+    ('\u{10FB}', '\u{10FB}', &[NotXid]),
+    ('\u{10FC}', '\u{10FC}', &[NotNfkc]),
+    ('\u{10FD}', '\u{10FF}', &[Recommended]),
+    ('\u{1100}', '\u{115E}', &[Obsolete]),
+    ('\u{115F}', '\u{1160}', &[DefaultIgnorable]),
+    ('\u{1161}', '\u{11FF}', &[Obsolete]),
+    ('\u{1200}', '\u{1248}', &[Recommended]),
+    ('\u{124A}', '\u{124D}', &[Recommended]),
+    ('\u{1250}', '\u{1256}', &[Recommended]),
+    ('\u{1258}', '\u{1258}', &[Recommended]),
+    ('\u{125A}', '\u{125D}', &[Recommended]),
+    ('\u{1260}', '\u{1288}', &[Recommended]),
+    ('\u{128A}', '\u{128D}', &[Recommended]),
+    ('\u{1290}', '\u{12B0}', &[Recommended]),
+    ('\u{12B2}', '\u{12B5}', &[Recommended]),
+    ('\u{12B8}', '\u{12BE}', &[Recommended]),
+    ('\u{12C0}', '\u{12C0}', &[Recommended]),
+    ('\u{12C2}', '\u{12C5}', &[Recommended]),
+    ('\u{12C8}', '\u{12D6}', &[Recommended]),
+    ('\u{12D8}', '\u{1310}', &[Recommended]),
+    ('\u{1312}', '\u{1315}', &[Recommended]),
+    ('\u{1318}', '\u{135A}', &[Recommended]),
+    ('\u{135D}', '\u{135F}', &[Recommended]),
+    ('\u{1360}', '\u{1368}', &[NotXid]),
+    ('\u{1369}', '\u{1371}', &[Obsolete]),
+    ('\u{1372}', '\u{137C}', &[NotXid]),
+    ('\u{1380}', '\u{138F}', &[Recommended]),
+    ('\u{1390}', '\u{1399}', &[NotXid]),
+    ('\u{13A0}', '\u{13F5}', &[LimitedUse]),
+    ('\u{13F8}', '\u{13FD}', &[LimitedUse]),
+    ('\u{1400}', '\u{167F}', &[LimitedUse]),
+    ('\u{1680}', '\u{169C}', &[Exclusion]),
+    ('\u{16A0}', '\u{16F8}', &[Exclusion]),
+    ('\u{1700}', '\u{1715}', &[Exclusion]),
+    ('\u{171F}', '\u{1736}', &[Exclusion]),
+    ('\u{1740}', '\u{1753}', &[Exclusion]),
+    ('\u{1760}', '\u{176C}', &[Exclusion]),
+    ('\u{176E}', '\u{1770}', &[Exclusion]),
+    ('\u{1772}', '\u{1773}', &[Exclusion]),
+    ('\u{1780}', '\u{17A2}', &[Recommended]),
+    ('\u{17A3}', '\u{17A4}', &[Deprecated]),
+    ('\u{17A5}', '\u{17A7}', &[Recommended]),
+    ('\u{17A8}', '\u{17A8}', &[Obsolete]),
+    ('\u{17A9}', '\u{17B3}', &[Recommended]),
+    ('\u{17B4}', '\u{17B5}', &[DefaultIgnorable]),
+    ('\u{17B6}', '\u{17CD}', &[Recommended]),
+    ('\u{17CE}', '\u{17CF}', &[Technical]),
+    ('\u{17D0}', '\u{17D0}', &[Recommended]),
+    ('\u{17D1}', '\u{17D1}', &[Technical]),
+    ('\u{17D2}', '\u{17D2}', &[Recommended]),
+    ('\u{17D3}', '\u{17D3}', &[Obsolete]),
+    ('\u{17D4}', '\u{17D6}', &[NotXid]),
+    ('\u{17D7}', '\u{17D7}', &[Recommended]),
+    ('\u{17D8}', '\u{17D8}', &[Obsolete]),
+    ('\u{17D9}', '\u{17DB}', &[NotXid]),
+    ('\u{17DC}', '\u{17DC}', &[Recommended]),
+    ('\u{17DD}', '\u{17DD}', &[Technical]),
+    ('\u{17E0}', '\u{17E9}', &[Recommended]),
+    ('\u{17F0}', '\u{17F9}', &[NotXid]),
+    ('\u{1800}', '\u{180A}', &[Exclusion]),
+    ('\u{180B}', '\u{180F}', &[DefaultIgnorable]),
+    ('\u{1810}', '\u{1819}', &[Exclusion]),
+    ('\u{1820}', '\u{1878}', &[Exclusion]),
+    ('\u{1880}', '\u{18A8}', &[Exclusion]),
+    ('\u{18A9}', '\u{18A9}', &[UncommonUse]),
+    ('\u{18AA}', '\u{18AA}', &[Exclusion]),
+    ('\u{18B0}', '\u{18F5}', &[LimitedUse]),
+    ('\u{1900}', '\u{191E}', &[LimitedUse]),
+    ('\u{1920}', '\u{192B}', &[LimitedUse]),
+    ('\u{1930}', '\u{193B}', &[LimitedUse]),
+    ('\u{1940}', '\u{1940}', &[LimitedUse]),
+    ('\u{1944}', '\u{196D}', &[LimitedUse]),
+    ('\u{1970}', '\u{1974}', &[LimitedUse]),
+    ('\u{1980}', '\u{19AB}', &[LimitedUse]),
+    ('\u{19B0}', '\u{19C9}', &[LimitedUse]),
+    ('\u{19D0}', '\u{19DA}', &[LimitedUse]),
+    ('\u{19DE}', '\u{19DF}', &[LimitedUse]),
+    ('\u{19E0}', '\u{19FF}', &[NotXid]),
+    ('\u{1A00}', '\u{1A1B}', &[Exclusion]),
+    ('\u{1A1E}', '\u{1A1F}', &[Exclusion]),
+    ('\u{1A20}', '\u{1A5E}', &[LimitedUse]),
+    ('\u{1A60}', '\u{1A7C}', &[LimitedUse]),
+    ('\u{1A7F}', '\u{1A89}', &[LimitedUse]),
+    ('\u{1A90}', '\u{1A99}', &[LimitedUse]),
+    ('\u{1AA0}', '\u{1AAD}', &[LimitedUse]),
+    ('\u{1AB0}', '\u{1ABD}', &[Obsolete]),
+    ('\u{1ABE}', '\u{1ABE}', &[NotXid]),
+    ('\u{1ABF}', '\u{1AC0}', &[Technical]),
+    ('\u{1AC1}', '\u{1ACE}', &[UncommonUse]),
+    ('\u{1B00}', '\u{1B4C}', &[LimitedUse]),
+    ('\u{1B4E}', '\u{1BF3}', &[LimitedUse]),
+    ('\u{1BFC}', '\u{1C37}', &[LimitedUse]),
+    ('\u{1C3B}', '\u{1C49}', &[LimitedUse]),
+    ('\u{1C4D}', '\u{1C7F}', &[LimitedUse]),
+    ('\u{1C80}', '\u{1C88}', &[Obsolete]),
+    ('\u{1C89}', '\u{1C8A}', &[UncommonUse]),
+    ('\u{1C90}', '\u{1CBA}', &[Recommended]),
+    ('\u{1CBD}', '\u{1CBF}', &[Recommended]),
+    ('\u{1CC0}', '\u{1CC7}', &[LimitedUse]),
+    ('\u{1CD0}', '\u{1CF9}', &[Obsolete]),
+    ('\u{1CFA}', '\u{1CFA}', &[Exclusion]),
+    ('\u{1D00}', '\u{1D2B}', &[Technical]),
+    ('\u{1D2C}', '\u{1D2E}', &[NotNfkc]),
+    ('\u{1D2F}', '\u{1D2F}', &[Technical]),
+    ('\u{1D30}', '\u{1D3A}', &[NotNfkc]),
+    ('\u{1D3B}', '\u{1D3B}', &[Technical]),
+    ('\u{1D3C}', '\u{1D4D}', &[NotNfkc]),
+    ('\u{1D4E}', '\u{1D4E}', &[Technical]),
+    ('\u{1D4F}', '\u{1D6A}', &[NotNfkc]),
+    ('\u{1D6B}', '\u{1D77}', &[Technical]),
+    ('\u{1D78}', '\u{1D78}', &[NotNfkc]),
+    ('\u{1D79}', '\u{1D9A}', &[Technical]),
+    ('\u{1D9B}', '\u{1DBF}', &[NotNfkc]),
+    ('\u{1DC0}', '\u{1DF9}', &[Technical]),
+    ('\u{1DFA}', '\u{1DFA}', &[LimitedUse]),
+    ('\u{1DFB}', '\u{1DFF}', &[Technical]),
+    ('\u{1E00}', '\u{1E99}', &[Recommended]),
+    ('\u{1E9A}', '\u{1E9B}', &[NotNfkc]),
+    ('\u{1E9C}', '\u{1E9D}', &[Technical]),
+    ('\u{1E9E}', '\u{1E9E}', &[Recommended]),
+    ('\u{1E9F}', '\u{1E9F}', &[Technical]),
+    ('\u{1EA0}', '\u{1EF9}', &[Recommended]),
+    ('\u{1EFA}', '\u{1EFF}', &[Technical]),
+    ('\u{1F00}', '\u{1F15}', &[Recommended]),
+    ('\u{1F18}', '\u{1F1D}', &[Recommended]),
+    ('\u{1F20}', '\u{1F45}', &[Recommended]),
+    ('\u{1F48}', '\u{1F4D}', &[Recommended]),
+    ('\u{1F50}', '\u{1F57}', &[Recommended]),
+    ('\u{1F59}', '\u{1F59}', &[Recommended]),
+    ('\u{1F5B}', '\u{1F5B}', &[Recommended]),
+    ('\u{1F5D}', '\u{1F5D}', &[Recommended]),
+    ('\u{1F5F}', '\u{1F70}', &[Recommended]),
+    ('\u{1F71}', '\u{1F71}', &[NotNfkc]),
+    ('\u{1F72}', '\u{1F72}', &[Recommended]),
+    ('\u{1F73}', '\u{1F73}', &[NotNfkc]),
+    ('\u{1F74}', '\u{1F74}', &[Recommended]),
+    ('\u{1F75}', '\u{1F75}', &[NotNfkc]),
+    ('\u{1F76}', '\u{1F76}', &[Recommended]),
+    ('\u{1F77}', '\u{1F77}', &[NotNfkc]),
+    ('\u{1F78}', '\u{1F78}', &[Recommended]),
+    ('\u{1F79}', '\u{1F79}', &[NotNfkc]),
+    ('\u{1F7A}', '\u{1F7A}', &[Recommended]),
+    ('\u{1F7B}', '\u{1F7B}', &[NotNfkc]),
+    ('\u{1F7C}', '\u{1F7C}', &[Recommended]),
+    ('\u{1F7D}', '\u{1F7D}', &[NotNfkc]),
+    ('\u{1F80}', '\u{1FB4}', &[Recommended]),
+    ('\u{1FB6}', '\u{1FBA}', &[Recommended]),
+    ('\u{1FBB}', '\u{1FBB}', &[NotNfkc]),
+    ('\u{1FBC}', '\u{1FBC}', &[Recommended]),
+    ('\u{1FBD}', '\u{1FC1}', &[NotNfkc]),
+    ('\u{1FC2}', '\u{1FC4}', &[Recommended]),
+    ('\u{1FC6}', '\u{1FC8}', &[Recommended]),
+    ('\u{1FC9}', '\u{1FC9}', &[NotNfkc]),
+    ('\u{1FCA}', '\u{1FCA}', &[Recommended]),
+    ('\u{1FCB}', '\u{1FCB}', &[NotNfkc]),
+    ('\u{1FCC}', '\u{1FCC}', &[Recommended]),
+    ('\u{1FCD}', '\u{1FCF}', &[NotNfkc]),
+    ('\u{1FD0}', '\u{1FD2}', &[Recommended]),
+    ('\u{1FD3}', '\u{1FD3}', &[NotNfkc]),
+    ('\u{1FD6}', '\u{1FDA}', &[Recommended]),
+    ('\u{1FDB}', '\u{1FDB}', &[NotNfkc]),
+    ('\u{1FDD}', '\u{1FDF}', &[NotNfkc]),
+    ('\u{1FE0}', '\u{1FE2}', &[Recommended]),
+    ('\u{1FE3}', '\u{1FE3}', &[NotNfkc]),
+    ('\u{1FE4}', '\u{1FEA}', &[Recommended]),
+    ('\u{1FEB}', '\u{1FEB}', &[NotNfkc]),
+    ('\u{1FEC}', '\u{1FEC}', &[Recommended]),
+    ('\u{1FED}', '\u{1FEF}', &[NotNfkc]),
+    ('\u{1FF2}', '\u{1FF4}', &[Recommended]),
+    ('\u{1FF6}', '\u{1FF8}', &[Recommended]),
+    ('\u{1FF9}', '\u{1FF9}', &[NotNfkc]),
+    ('\u{1FFA}', '\u{1FFA}', &[Recommended]),
+    ('\u{1FFB}', '\u{1FFB}', &[NotNfkc]),
+    ('\u{1FFC}', '\u{1FFC}', &[Recommended]),
+    ('\u{1FFD}', '\u{1FFE}', &[NotNfkc]),
+    ('\u{2000}', '\u{200A}', &[NotNfkc]),
+    ('\u{200B}', '\u{200F}', &[DefaultIgnorable]),
+    ('\u{2010}', '\u{2010}', &[Inclusion]),
+    ('\u{2011}', '\u{2011}', &[NotNfkc]),
+    ('\u{2012}', '\u{2016}', &[NotXid]),
+    ('\u{2017}', '\u{2017}', &[NotNfkc]),
+    ('\u{2018}', '\u{2018}', &[NotXid]),
+    ('\u{2019}', '\u{2019}', &[Inclusion]),
+    ('\u{201A}', '\u{2023}', &[NotXid]),
+    ('\u{2024}', '\u{2026}', &[NotNfkc]),
+    ('\u{2027}', '\u{2027}', &[Inclusion]),
+    ('\u{2028}', '\u{2029}', &[NotXid]),
+    ('\u{202A}', '\u{202E}', &[DefaultIgnorable]),
+    ('\u{202F}', '\u{202F}', &[NotNfkc]),
+    ('\u{2030}', '\u{2032}', &[NotXid]),
+    ('\u{2033}', '\u{2034}', &[NotNfkc]),
+    ('\u{2035}', '\u{2035}', &[NotXid]),
+    ('\u{2036}', '\u{2037}', &[NotNfkc]),
+    ('\u{2038}', '\u{203B}', &[NotXid]),
+    ('\u{203C}', '\u{203C}', &[NotNfkc]),
+    ('\u{203D}', '\u{203D}', &[NotXid]),
+    ('\u{203E}', '\u{203E}', &[NotNfkc]),
+    ('\u{203F}', '\u{2040}', &[Technical]),
+    ('\u{2041}', '\u{2046}', &[NotXid]),
+    ('\u{2047}', '\u{2049}', &[NotNfkc]),
+    ('\u{204A}', '\u{2053}', &[NotXid]),
+    ('\u{2054}', '\u{2054}', &[UncommonUse]),
+    ('\u{2055}', '\u{2055}', &[NotXid]),
+    ('\u{2056}', '\u{2056}', &[Obsolete]),
+    ('\u{2057}', '\u{2057}', &[NotNfkc]),
+    ('\u{2058}', '\u{205E}', &[Obsolete]),
+    ('\u{205F}', '\u{205F}', &[NotNfkc]),
+    ('\u{2060}', '\u{2064}', &[DefaultIgnorable]),
+    ('\u{2066}', '\u{2069}', &[DefaultIgnorable]),
+    ('\u{206A}', '\u{206F}', &[Deprecated]),
+    ('\u{2070}', '\u{2071}', &[NotNfkc]),
+    ('\u{2074}', '\u{208E}', &[NotNfkc]),
+    ('\u{2090}', '\u{209C}', &[NotNfkc]),
+    ('\u{20A0}', '\u{20A7}', &[NotXid]),
+    ('\u{20A8}', '\u{20A8}', &[NotNfkc]),
+    ('\u{20A9}', '\u{20C0}', &[NotXid]),
+    ('\u{20D0}', '\u{20F0}', &[Technical]),
+    ('\u{2100}', '\u{2103}', &[NotNfkc]),
+    ('\u{2104}', '\u{2104}', &[NotXid]),
+    ('\u{2105}', '\u{2107}', &[NotNfkc]),
+    ('\u{2108}', '\u{2108}', &[NotXid]),
+    ('\u{2109}', '\u{2113}', &[NotNfkc]),
+    ('\u{2114}', '\u{2114}', &[NotXid]),
+    ('\u{2115}', '\u{2116}', &[NotNfkc]),
+    ('\u{2117}', '\u{2117}', &[NotXid]),
+    ('\u{2118}', '\u{2118}', &[Technical]),
+    ('\u{2119}', '\u{211D}', &[NotNfkc]),
+    ('\u{211E}', '\u{211F}', &[NotXid]),
+    ('\u{2120}', '\u{2122}', &[NotNfkc]),
+    ('\u{2123}', '\u{2123}', &[NotXid]),
+    ('\u{2124}', '\u{2124}', &[NotNfkc]),
+    ('\u{2125}', '\u{2125}', &[NotXid]),
+    ('\u{2126}', '\u{2126}', &[NotNfkc]),
+    ('\u{2127}', '\u{2127}', &[Obsolete]),
+    ('\u{2128}', '\u{2128}', &[NotNfkc]),
+    ('\u{2129}', '\u{2129}', &[NotXid]),
+    ('\u{212A}', '\u{212D}', &[NotNfkc]),
+    ('\u{212E}', '\u{212E}', &[Technical]),
+    ('\u{212F}', '\u{2131}', &[NotNfkc]),
+    ('\u{2132}', '\u{2132}', &[Obsolete]),
+    ('\u{2133}', '\u{2139}', &[NotNfkc]),
+    ('\u{213A}', '\u{213A}', &[NotXid]),
+    ('\u{213B}', '\u{2140}', &[NotNfkc]),
+    ('\u{2141}', '\u{2144}', &[NotXid]),
+    ('\u{2145}', '\u{2149}', &[NotNfkc]),
+    ('\u{214A}', '\u{214D}', &[NotXid]),
+    ('\u{214E}', '\u{214F}', &[Obsolete]),
+    ('\u{2150}', '\u{217F}', &[NotNfkc]),
+    ('\u{2180}', '\u{2183}', &[Technical]),
+    ('\u{2184}', '\u{2188}', &[Obsolete]),
+    ('\u{2189}', '\u{2189}', &[NotNfkc]),
+    ('\u{218A}', '\u{218B}', &[UncommonUse]),
+    ('\u{2190}', '\u{222B}', &[NotXid]),
+    ('\u{222C}', '\u{222D}', &[NotNfkc]),
+    ('\u{222E}', '\u{222E}', &[NotXid]),
+    ('\u{222F}', '\u{2230}', &[NotNfkc]),
+    ('\u{2231}', '\u{2328}', &[NotXid]),
+    ('\u{2329}', '\u{232A}', &[Deprecated]),
+    ('\u{232B}', '\u{2429}', &[NotXid]),
+    ('\u{2440}', '\u{244A}', &[NotXid]),
+    ('\u{2460}', '\u{24EA}', &[NotNfkc]),
+    ('\u{24EB}', '\u{24FF}', &[Technical]),
+    ('\u{2500}', '\u{27FF}', &[NotXid]),
+    ('\u{2800}', '\u{28FF}', &[Technical]),
+    ('\u{2900}', '\u{2A0B}', &[NotXid]),
+    ('\u{2A0C}', '\u{2A0C}', &[NotNfkc]),
+    ('\u{2A0D}', '\u{2A73}', &[NotXid]),
+    ('\u{2A74}', '\u{2A76}', &[NotNfkc]),
+    ('\u{2A77}', '\u{2ADB}', &[NotXid]),
+    ('\u{2ADC}', '\u{2ADC}', &[NotNfkc]),
+    ('\u{2ADD}', '\u{2B73}', &[NotXid]),
+    ('\u{2B76}', '\u{2B95}', &[NotXid]),
+    ('\u{2B97}', '\u{2BEB}', &[NotXid]),
+    ('\u{2BEC}', '\u{2BEF}', &[UncommonUse]),
+    ('\u{2BF0}', '\u{2BFF}', &[NotXid]),
+    ('\u{2C00}', '\u{2C5F}', &[Exclusion]),
+    ('\u{2C60}', '\u{2C67}', &[Technical]),
+    ('\u{2C68}', '\u{2C6C}', &[UncommonUse]),
+    ('\u{2C6D}', '\u{2C76}', &[Obsolete]),
+    ('\u{2C77}', '\u{2C7B}', &[Technical]),
+    ('\u{2C7C}', '\u{2C7D}', &[NotNfkc]),
+    ('\u{2C7E}', '\u{2C7F}', &[Obsolete]),
+    ('\u{2C80}', '\u{2CEF}', &[Exclusion]),
+    ('\u{2CF0}', '\u{2CF1}', &[Technical]),
+    ('\u{2CF2}', '\u{2CF3}', &[Exclusion]),
+    ('\u{2CF9}', '\u{2CFF}', &[Exclusion]),
+    ('\u{2D00}', '\u{2D25}', &[Obsolete]),
+    ('\u{2D27}', '\u{2D27}', &[Recommended]),
+    ('\u{2D2D}', '\u{2D2D}', &[Recommended]),
+    ('\u{2D30}', '\u{2D67}', &[LimitedUse]),
+    ('\u{2D6F}', '\u{2D6F}', &[NotNfkc]),
+    ('\u{2D70}', '\u{2D70}', &[LimitedUse]),
+    ('\u{2D7F}', '\u{2D7F}', &[LimitedUse]),
+    ('\u{2D80}', '\u{2D96}', &[Recommended]),
+    ('\u{2DA0}', '\u{2DA6}', &[Recommended]),
+    ('\u{2DA8}', '\u{2DAE}', &[Recommended]),
+    ('\u{2DB0}', '\u{2DB6}', &[Recommended]),
+    ('\u{2DB8}', '\u{2DBE}', &[Recommended]),
+    ('\u{2DC0}', '\u{2DC6}', &[Recommended]),
+    ('\u{2DC8}', '\u{2DCE}', &[Recommended]),
+    ('\u{2DD0}', '\u{2DD6}', &[Recommended]),
+    ('\u{2DD8}', '\u{2DDE}', &[Recommended]),
+    ('\u{2DE0}', '\u{2DFF}', &[Obsolete]),
+    ('\u{2E00}', '\u{2E0D}', &[Technical]),
+    ('\u{2E0E}', '\u{2E16}', &[Obsolete]),
+    ('\u{2E17}', '\u{2E29}', &[NotXid]),
+    ('\u{2E2A}', '\u{2E2F}', &[Obsolete]),
+    ('\u{2E30}', '\u{2E30}', &[Exclusion]),
+    ('\u{2E31}', '\u{2E32}', &[Obsolete]),
+    ('\u{2E33}', '\u{2E34}', &[NotXid]),
+    ('\u{2E35}', '\u{2E35}', &[Obsolete]),
+    ('\u{2E36}', '\u{2E38}', &[NotXid]),
+    ('\u{2E39}', '\u{2E39}', &[Obsolete]),
+    ('\u{2E3A}', '\u{2E3B}', &[NotXid]),
+    ('\u{2E3C}', '\u{2E3C}', &[Exclusion]),
+    ('\u{2E3D}', '\u{2E5D}', &[NotXid]),
+    ('\u{2E80}', '\u{2E99}', &[NotXid]),
+    ('\u{2E9B}', '\u{2E9E}', &[NotXid]),
+    ('\u{2E9F}', '\u{2E9F}', &[NotNfkc]),
+    ('\u{2EA0}', '\u{2EF2}', &[NotXid]),
+    ('\u{2EF3}', '\u{2EF3}', &[NotNfkc]),
+    ('\u{2F00}', '\u{2FD5}', &[NotNfkc]),
+    ('\u{2FF0}', '\u{2FFF}', &[NotXid]),
+    ('\u{3000}', '\u{3000}', &[NotNfkc]),
+    ('\u{3001}', '\u{3004}', &[NotXid]),
+    ('\u{3005}', '\u{3007}', &[Recommended]),
+    ('\u{3008}', '\u{301D}', &[NotXid]),
+    ('\u{301E}', '\u{301E}', &[Obsolete]),
+    ('\u{301F}', '\u{3020}', &[NotXid]),
+    ('\u{3021}', '\u{302F}', &[Technical]),
+    ('\u{3030}', '\u{3030}', &[NotXid]),
+    ('\u{3031}', '\u{3035}', &[Technical]),
+    ('\u{3036}', '\u{3036}', &[NotNfkc]),
+    ('\u{3037}', '\u{3037}', &[NotXid]),
+    ('\u{3038}', '\u{303A}', &[NotNfkc]),
+    ('\u{303B}', '\u{303C}', &[Technical]),
+    ('\u{303D}', '\u{303F}', &[NotXid]),
+    ('\u{3041}', '\u{3096}', &[Recommended]),
+    ('\u{3099}', '\u{309A}', &[Recommended]),
+    ('\u{309B}', '\u{309C}', &[NotNfkc]),
+    ('\u{309D}', '\u{309E}', &[Recommended]),
+    ('\u{309F}', '\u{309F}', &[NotNfkc]),
+    ('\u{30A0}', '\u{30A0}', &[Inclusion]),
+    ('\u{30A1}', '\u{30FA}', &[Recommended]),
+    ('\u{30FB}', '\u{30FB}', &[Inclusion]),
+    ('\u{30FC}', '\u{30FE}', &[Recommended]),
+    ('\u{30FF}', '\u{30FF}', &[NotNfkc]),
+    ('\u{3105}', '\u{312D}', &[Recommended]),
+    ('\u{312E}', '\u{312E}', &[Obsolete]),
+    ('\u{312F}', '\u{312F}', &[Recommended]),
+    ('\u{3131}', '\u{3163}', &[NotNfkc]),
+    ('\u{3164}', '\u{3164}', &[DefaultIgnorable]),
+    ('\u{3165}', '\u{318E}', &[NotNfkc]),
+    ('\u{3190}', '\u{3191}', &[NotXid]),
+    ('\u{3192}', '\u{319F}', &[NotNfkc]),
+    ('\u{31A0}', '\u{31BF}', &[Recommended]),
+    ('\u{31C0}', '\u{31E5}', &[NotXid]),
+    ('\u{31EF}', '\u{31EF}', &[NotXid]),
+    ('\u{31F0}', '\u{31FF}', &[Obsolete]),
+    ('\u{3200}', '\u{321E}', &[NotNfkc]),
+    ('\u{3220}', '\u{3247}', &[NotNfkc]),
+    ('\u{3248}', '\u{324F}', &[NotXid]),
+    ('\u{3250}', '\u{327E}', &[NotNfkc]),
+    ('\u{327F}', '\u{327F}', &[Technical]),
+    ('\u{3280}', '\u{33FF}', &[NotNfkc]),
+    ('\u{3400}', '\u{4DBF}', &[Recommended]),
+    ('\u{4DC0}', '\u{4DFF}', &[Technical]),
+    ('\u{4E00}', '\u{9FFF}', &[Recommended]),
+    ('\u{A000}', '\u{A48C}', &[LimitedUse]),
+    ('\u{A490}', '\u{A4C6}', &[LimitedUse]),
+    ('\u{A4D0}', '\u{A62B}', &[LimitedUse]),
+    ('\u{A640}', '\u{A66E}', &[Obsolete]),
+    ('\u{A66F}', '\u{A66F}', &[UncommonUse]),
+    ('\u{A670}', '\u{A67B}', &[Obsolete]),
+    ('\u{A67C}', '\u{A67D}', &[UncommonUse]),
+    ('\u{A67E}', '\u{A67E}', &[NotXid]),
+    ('\u{A67F}', '\u{A67F}', &[Recommended]),
+    ('\u{A680}', '\u{A69B}', &[Obsolete]),
+    ('\u{A69C}', '\u{A69D}', &[NotNfkc]),
+    ('\u{A69E}', '\u{A69E}', &[UncommonUse]),
+    ('\u{A69F}', '\u{A69F}', &[Obsolete]),
+    ('\u{A6A0}', '\u{A6F7}', &[LimitedUse]),
+    ('\u{A700}', '\u{A707}', &[Obsolete]),
+    ('\u{A708}', '\u{A716}', &[Technical]),
+    ('\u{A717}', '\u{A71F}', &[Recommended]),
+    ('\u{A720}', '\u{A721}', &[NotXid]),
+    ('\u{A722}', '\u{A72F}', &[Technical]),
+    ('\u{A730}', '\u{A76F}', &[Obsolete]),
+    ('\u{A770}', '\u{A770}', &[NotNfkc]),
+    ('\u{A771}', '\u{A787}', &[Obsolete]),
+    ('\u{A788}', '\u{A788}', &[Recommended]),
+    ('\u{A789}', '\u{A78A}', &[NotXid]),
+    ('\u{A78B}', '\u{A78C}', &[UncommonUse]),
+    ('\u{A78D}', '\u{A78D}', &[Recommended]),
+    ('\u{A78E}', '\u{A78E}', &[Technical]),
+    ('\u{A78F}', '\u{A78F}', &[UncommonUse]),
+    ('\u{A790}', '\u{A791}', &[Obsolete]),
+    ('\u{A792}', '\u{A793}', &[Recommended]),
+    ('\u{A794}', '\u{A7A9}', &[Obsolete]),
+    ('\u{A7AA}', '\u{A7AA}', &[Recommended]),
+    ('\u{A7AB}', '\u{A7AD}', &[Obsolete]),
+    ('\u{A7AE}', '\u{A7AF}', &[Technical]),
+    ('\u{A7B0}', '\u{A7B1}', &[Obsolete]),
+    ('\u{A7B2}', '\u{A7B9}', &[UncommonUse]),
+    ('\u{A7BA}', '\u{A7BF}', &[Technical]),
+    ('\u{A7C0}', '\u{A7CA}', &[Recommended]),
+    ('\u{A7CB}', '\u{A7CD}', &[UncommonUse]),
+    ('\u{A7D0}', '\u{A7D1}', &[Recommended]),
+    ('\u{A7D3}', '\u{A7D3}', &[Recommended]),
+    ('\u{A7D5}', '\u{A7D9}', &[Recommended]),
+    ('\u{A7DA}', '\u{A7DC}', &[UncommonUse]),
+    ('\u{A7F2}', '\u{A7F4}', &[NotNfkc]),
+    ('\u{A7F5}', '\u{A7F7}', &[Obsolete]),
+    ('\u{A7F8}', '\u{A7F9}', &[NotNfkc]),
+    ('\u{A7FA}', '\u{A7FA}', &[Technical]),
+    ('\u{A7FB}', '\u{A7FF}', &[Obsolete]),
+    ('\u{A800}', '\u{A82C}', &[LimitedUse]),
+    ('\u{A830}', '\u{A839}', &[NotXid]),
+    ('\u{A840}', '\u{A877}', &[Exclusion]),
+    ('\u{A880}', '\u{A8C5}', &[LimitedUse]),
+    ('\u{A8CE}', '\u{A8D9}', &[LimitedUse]),
+    ('\u{A8E0}', '\u{A8FB}', &[Obsolete]),
+    ('\u{A8FC}', '\u{A8FD}', &[UncommonUse]),
+    ('\u{A8FE}', '\u{A8FF}', &[Obsolete]),
+    ('\u{A900}', '\u{A92D}', &[LimitedUse]),
+    ('\u{A92E}', '\u{A92E}', &[NotXid]),
+    ('\u{A92F}', '\u{A92F}', &[LimitedUse]),
+    ('\u{A930}', '\u{A953}', &[Exclusion]),
+    ('\u{A95F}', '\u{A95F}', &[Exclusion]),
+    ('\u{A960}', '\u{A97C}', &[Obsolete]),
+    ('\u{A980}', '\u{A9CD}', &[LimitedUse]),
+    ('\u{A9CF}', '\u{A9D9}', &[LimitedUse]),
+    ('\u{A9DE}', '\u{A9DF}', &[LimitedUse]),
+    ('\u{A9E0}', '\u{A9E6}', &[Obsolete]),
+    ('\u{A9E7}', '\u{A9FE}', &[Recommended]),
+    ('\u{AA00}', '\u{AA36}', &[LimitedUse]),
+    ('\u{AA40}', '\u{AA4D}', &[LimitedUse]),
+    ('\u{AA50}', '\u{AA59}', &[LimitedUse]),
+    ('\u{AA5C}', '\u{AA5F}', &[LimitedUse]),
+    ('\u{AA60}', '\u{AA76}', &[Recommended]),
+    ('\u{AA77}', '\u{AA79}', &[NotXid]),
+    ('\u{AA7A}', '\u{AA7F}', &[Recommended]),
+    ('\u{AA80}', '\u{AAC2}', &[LimitedUse]),
+    ('\u{AADB}', '\u{AAF6}', &[LimitedUse]),
+    ('\u{AB01}', '\u{AB06}', &[Recommended]),
+    ('\u{AB09}', '\u{AB0E}', &[Recommended]),
+    ('\u{AB11}', '\u{AB16}', &[Recommended]),
+    ('\u{AB20}', '\u{AB26}', &[Recommended]),
+    ('\u{AB28}', '\u{AB2E}', &[Recommended]),
+    ('\u{AB30}', '\u{AB5A}', &[Obsolete]),
+    ('\u{AB5B}', '\u{AB5B}', &[NotXid]),
+    ('\u{AB5C}', '\u{AB5F}', &[NotNfkc]),
+    ('\u{AB60}', '\u{AB63}', &[UncommonUse]),
+    ('\u{AB64}', '\u{AB65}', &[Obsolete]),
+    ('\u{AB66}', '\u{AB67}', &[Recommended]),
+    ('\u{AB68}', '\u{AB68}', &[Technical]),
+    ('\u{AB69}', '\u{AB69}', &[NotNfkc]),
+    ('\u{AB6A}', '\u{AB6B}', &[NotXid]),
+    ('\u{AB70}', '\u{ABED}', &[LimitedUse]),
+    ('\u{ABF0}', '\u{ABF9}', &[LimitedUse]),
+    ('\u{AC00}', '\u{D7A3}', &[Recommended]),
+    ('\u{D7B0}', '\u{D7C6}', &[Obsolete]),
+    ('\u{D7CB}', '\u{D7FB}', &[Obsolete]),
+    ('\u{F900}', '\u{FA0D}', &[NotNfkc]),
+    ('\u{FA0E}', '\u{FA0F}', &[Recommended]),
+    ('\u{FA10}', '\u{FA10}', &[NotNfkc]),
+    ('\u{FA11}', '\u{FA11}', &[Recommended]),
+    ('\u{FA12}', '\u{FA12}', &[NotNfkc]),
+    ('\u{FA13}', '\u{FA14}', &[Recommended]),
+    ('\u{FA15}', '\u{FA1E}', &[NotNfkc]),
+    ('\u{FA1F}', '\u{FA1F}', &[Recommended]),
+    ('\u{FA20}', '\u{FA20}', &[NotNfkc]),
+    ('\u{FA21}', '\u{FA21}', &[Recommended]),
+    ('\u{FA22}', '\u{FA22}', &[NotNfkc]),
+    ('\u{FA23}', '\u{FA24}', &[Recommended]),
+    ('\u{FA25}', '\u{FA26}', &[NotNfkc]),
+    ('\u{FA27}', '\u{FA29}', &[Recommended]),
+    ('\u{FA2A}', '\u{FA6D}', &[NotNfkc]),
+    ('\u{FA70}', '\u{FAD9}', &[NotNfkc]),
+    ('\u{FB00}', '\u{FB06}', &[NotNfkc]),
+    ('\u{FB13}', '\u{FB17}', &[NotNfkc]),
+    ('\u{FB1D}', '\u{FB1D}', &[NotNfkc]),
+    ('\u{FB1E}', '\u{FB1E}', &[UncommonUse]),
+    ('\u{FB1F}', '\u{FB36}', &[NotNfkc]),
+    ('\u{FB38}', '\u{FB3C}', &[NotNfkc]),
+    ('\u{FB3E}', '\u{FB3E}', &[NotNfkc]),
+    ('\u{FB40}', '\u{FB41}', &[NotNfkc]),
+    ('\u{FB43}', '\u{FB44}', &[NotNfkc]),
+    ('\u{FB46}', '\u{FBB1}', &[NotNfkc]),
+    ('\u{FBB2}', '\u{FBC2}', &[Technical]),
+    ('\u{FBD3}', '\u{FD3D}', &[NotNfkc]),
+    ('\u{FD3E}', '\u{FD4F}', &[Technical]),
+    ('\u{FD50}', '\u{FD8F}', &[NotNfkc]),
+    ('\u{FD92}', '\u{FDC7}', &[NotNfkc]),
+    ('\u{FDCF}', '\u{FDCF}', &[Technical]),
+    ('\u{FDF0}', '\u{FDFC}', &[NotNfkc]),
+    ('\u{FDFD}', '\u{FDFF}', &[Technical]),
+    ('\u{FE00}', '\u{FE0F}', &[DefaultIgnorable]),
+    ('\u{FE10}', '\u{FE19}', &[NotNfkc]),
+    ('\u{FE20}', '\u{FE2D}', &[Technical]),
+    ('\u{FE2E}', '\u{FE2F}', &[UncommonUse]),
+    ('\u{FE30}', '\u{FE44}', &[NotNfkc]),
+    ('\u{FE45}', '\u{FE46}', &[Technical]),
+    ('\u{FE47}', '\u{FE52}', &[NotNfkc]),
+    ('\u{FE54}', '\u{FE66}', &[NotNfkc]),
+    ('\u{FE68}', '\u{FE6B}', &[NotNfkc]),
+    ('\u{FE70}', '\u{FE72}', &[NotNfkc]),
+    ('\u{FE73}', '\u{FE73}', &[Technical]),
+    ('\u{FE74}', '\u{FE74}', &[NotNfkc]),
+    ('\u{FE76}', '\u{FEFC}', &[NotNfkc]),
+    ('\u{FEFF}', '\u{FEFF}', &[DefaultIgnorable]),
+    ('\u{FF01}', '\u{FF9F}', &[NotNfkc]),
+    ('\u{FFA0}', '\u{FFA0}', &[DefaultIgnorable]),
+    ('\u{FFA1}', '\u{FFBE}', &[NotNfkc]),
+    ('\u{FFC2}', '\u{FFC7}', &[NotNfkc]),
+    ('\u{FFCA}', '\u{FFCF}', &[NotNfkc]),
+    ('\u{FFD2}', '\u{FFD7}', &[NotNfkc]),
+    ('\u{FFDA}', '\u{FFDC}', &[NotNfkc]),
+    ('\u{FFE0}', '\u{FFE6}', &[NotNfkc]),
+    ('\u{FFE8}', '\u{FFEE}', &[NotNfkc]),
+    ('\u{FFF9}', '\u{FFFD}', &[NotXid]),
+    ('\u{10000}', '\u{1000B}', &[Exclusion]),
+    ('\u{1000D}', '\u{10026}', &[Exclusion]),
+    ('\u{10028}', '\u{1003A}', &[Exclusion]),
+    ('\u{1003C}', '\u{1003D}', &[Exclusion]),
+    ('\u{1003F}', '\u{1004D}', &[Exclusion]),
+    ('\u{10050}', '\u{1005D}', &[Exclusion]),
+    ('\u{10080}', '\u{100FA}', &[Exclusion]),
+    ('\u{10100}', '\u{10102}', &[Exclusion]),
+    ('\u{10107}', '\u{10133}', &[Exclusion]),
+    ('\u{10137}', '\u{1013F}', &[Exclusion]),
+    ('\u{10140}', '\u{10174}', &[Obsolete]),
+    ('\u{10175}', '\u{1018E}', &[NotXid]),
+    ('\u{10190}', '\u{1019C}', &[NotXid]),
+    ('\u{101A0}', '\u{101A0}', &[NotXid]),
+    ('\u{101D0}', '\u{101FD}', &[Obsolete]),
+    ('\u{10280}', '\u{1029C}', &[Exclusion]),
+    ('\u{102A0}', '\u{102D0}', &[Exclusion]),
+    ('\u{102E0}', '\u{102FB}', &[Obsolete]),
+    ('\u{10300}', '\u{10323}', &[Exclusion]),
+    ('\u{1032D}', '\u{1034A}', &[Exclusion]),
+    ('\u{10350}', '\u{1037A}', &[Exclusion]),
+    ('\u{10380}', '\u{1039D}', &[Exclusion]),
+    ('\u{1039F}', '\u{103C3}', &[Exclusion]),
+    ('\u{103C8}', '\u{103D5}', &[Exclusion]),
+    ('\u{10400}', '\u{1049D}', &[Exclusion]),
+    ('\u{104A0}', '\u{104A9}', &[Exclusion]),
+    ('\u{104B0}', '\u{104D3}', &[LimitedUse]),
+    ('\u{104D8}', '\u{104FB}', &[LimitedUse]),
+    ('\u{10500}', '\u{10527}', &[Exclusion]),
+    ('\u{10530}', '\u{10563}', &[Exclusion]),
+    ('\u{1056F}', '\u{1057A}', &[Exclusion]),
+    ('\u{1057C}', '\u{1058A}', &[Exclusion]),
+    ('\u{1058C}', '\u{10592}', &[Exclusion]),
+    ('\u{10594}', '\u{10595}', &[Exclusion]),
+    ('\u{10597}', '\u{105A1}', &[Exclusion]),
+    ('\u{105A3}', '\u{105B1}', &[Exclusion]),
+    ('\u{105B3}', '\u{105B9}', &[Exclusion]),
+    ('\u{105BB}', '\u{105BC}', &[Exclusion]),
+    ('\u{105C0}', '\u{105F3}', &[Exclusion]),
+    ('\u{10600}', '\u{10736}', &[Exclusion]),
+    ('\u{10740}', '\u{10755}', &[Exclusion]),
+    ('\u{10760}', '\u{10767}', &[Exclusion]),
+    ('\u{10780}', '\u{10780}', &[UncommonUse]),
+    ('\u{10781}', '\u{10785}', &[NotNfkc]),
+    ('\u{10787}', '\u{107B0}', &[NotNfkc]),
+    ('\u{107B2}', '\u{107BA}', &[NotNfkc]),
+    ('\u{10800}', '\u{10805}', &[Exclusion]),
+    ('\u{10808}', '\u{10808}', &[Exclusion]),
+    ('\u{1080A}', '\u{10835}', &[Exclusion]),
+    ('\u{10837}', '\u{10838}', &[Exclusion]),
+    ('\u{1083C}', '\u{1083C}', &[Exclusion]),
+    ('\u{1083F}', '\u{10855}', &[Exclusion]),
+    ('\u{10857}', '\u{1089E}', &[Exclusion]),
+    ('\u{108A7}', '\u{108AF}', &[Exclusion]),
+    ('\u{108E0}', '\u{108F2}', &[Exclusion]),
+    ('\u{108F4}', '\u{108F5}', &[Exclusion]),
+    ('\u{108FB}', '\u{1091B}', &[Exclusion]),
+    ('\u{1091F}', '\u{10939}', &[Exclusion]),
+    ('\u{1093F}', '\u{1093F}', &[Exclusion]),
+    ('\u{10980}', '\u{109B7}', &[Exclusion]),
+    ('\u{109BC}', '\u{109CF}', &[Exclusion]),
+    ('\u{109D2}', '\u{10A03}', &[Exclusion]),
+    ('\u{10A05}', '\u{10A06}', &[Exclusion]),
+    ('\u{10A0C}', '\u{10A13}', &[Exclusion]),
+    ('\u{10A15}', '\u{10A17}', &[Exclusion]),
+    ('\u{10A19}', '\u{10A35}', &[Exclusion]),
+    ('\u{10A38}', '\u{10A3A}', &[Exclusion]),
+    ('\u{10A3F}', '\u{10A48}', &[Exclusion]),
+    ('\u{10A50}', '\u{10A58}', &[Exclusion]),
+    ('\u{10A60}', '\u{10A9F}', &[Exclusion]),
+    ('\u{10AC0}', '\u{10AE6}', &[Exclusion]),
+    ('\u{10AEB}', '\u{10AF6}', &[Exclusion]),
+    ('\u{10B00}', '\u{10B35}', &[Exclusion]),
+    ('\u{10B39}', '\u{10B55}', &[Exclusion]),
+    ('\u{10B58}', '\u{10B72}', &[Exclusion]),
+    ('\u{10B78}', '\u{10B91}', &[Exclusion]),
+    ('\u{10B99}', '\u{10B9C}', &[Exclusion]),
+    ('\u{10BA9}', '\u{10BAF}', &[Exclusion]),
+    ('\u{10C00}', '\u{10C48}', &[Exclusion]),
+    ('\u{10C80}', '\u{10CB2}', &[Exclusion]),
+    ('\u{10CC0}', '\u{10CF2}', &[Exclusion]),
+    ('\u{10CFA}', '\u{10CFF}', &[Exclusion]),
+    ('\u{10D00}', '\u{10D27}', &[LimitedUse]),
+    ('\u{10D30}', '\u{10D39}', &[LimitedUse]),
+    ('\u{10D40}', '\u{10D65}', &[Exclusion]),
+    ('\u{10D69}', '\u{10D85}', &[Exclusion]),
+    ('\u{10D8E}', '\u{10D8F}', &[Exclusion]),
+    ('\u{10E60}', '\u{10E7E}', &[NotXid]),
+    ('\u{10E80}', '\u{10EA9}', &[Exclusion]),
+    ('\u{10EAB}', '\u{10EAD}', &[Exclusion]),
+    ('\u{10EB0}', '\u{10EB1}', &[Exclusion]),
+    ('\u{10EC2}', '\u{10EC4}', &[UncommonUse]),
+    ('\u{10EFC}', '\u{10EFF}', &[UncommonUse]),
+    ('\u{10F00}', '\u{10F27}', &[Exclusion]),
+    ('\u{10F30}', '\u{10F59}', &[Exclusion]),
+    ('\u{10F70}', '\u{10F89}', &[Exclusion]),
+    ('\u{10FB0}', '\u{10FCB}', &[Exclusion]),
+    ('\u{10FE0}', '\u{10FF6}', &[Exclusion]),
+    ('\u{11000}', '\u{1104D}', &[Exclusion]),
+    ('\u{11052}', '\u{11075}', &[Exclusion]),
+    ('\u{1107F}', '\u{110C2}', &[Exclusion]),
+    ('\u{110CD}', '\u{110CD}', &[Exclusion]),
+    ('\u{110D0}', '\u{110E8}', &[Exclusion]),
+    ('\u{110F0}', '\u{110F9}', &[Exclusion]),
+    ('\u{11100}', '\u{11134}', &[LimitedUse]),
+    ('\u{11136}', '\u{11147}', &[LimitedUse]),
+    ('\u{11150}', '\u{11176}', &[Exclusion]),
+    ('\u{11180}', '\u{111DF}', &[Exclusion]),
+    ('\u{111E1}', '\u{111F4}', &[NotXid]),
+    ('\u{11200}', '\u{11211}', &[Exclusion]),
+    ('\u{11213}', '\u{11241}', &[Exclusion]),
+    ('\u{11280}', '\u{11286}', &[Exclusion]),
+    ('\u{11288}', '\u{11288}', &[Exclusion]),
+    ('\u{1128A}', '\u{1128D}', &[Exclusion]),
+    ('\u{1128F}', '\u{1129D}', &[Exclusion]),
+    ('\u{1129F}', '\u{112A9}', &[Exclusion]),
+    ('\u{112B0}', '\u{112EA}', &[Exclusion]),
+    ('\u{112F0}', '\u{112F9}', &[Exclusion]),
+    ('\u{11300}', '\u{11300}', &[Exclusion]),
+    ('\u{11301}', '\u{11301}', &[Recommended]),
+    ('\u{11302}', '\u{11302}', &[Exclusion]),
+    ('\u{11303}', '\u{11303}', &[Recommended]),
+    ('\u{11305}', '\u{1130C}', &[Exclusion]),
+    ('\u{1130F}', '\u{11310}', &[Exclusion]),
+    ('\u{11313}', '\u{11328}', &[Exclusion]),
+    ('\u{1132A}', '\u{11330}', &[Exclusion]),
+    ('\u{11332}', '\u{11333}', &[Exclusion]),
+    ('\u{11335}', '\u{11339}', &[Exclusion]),
+    ('\u{1133B}', '\u{1133C}', &[Recommended]),
+    ('\u{1133D}', '\u{11344}', &[Exclusion]),
+    ('\u{11347}', '\u{11348}', &[Exclusion]),
+    ('\u{1134B}', '\u{1134D}', &[Exclusion]),
+    ('\u{11350}', '\u{11350}', &[Exclusion]),
+    ('\u{11357}', '\u{11357}', &[Exclusion]),
+    ('\u{1135D}', '\u{11363}', &[Exclusion]),
+    ('\u{11366}', '\u{1136C}', &[Exclusion]),
+    ('\u{11370}', '\u{11374}', &[Exclusion]),
+    ('\u{11380}', '\u{11389}', &[Exclusion]),
+    ('\u{1138B}', '\u{1138B}', &[Exclusion]),
+    ('\u{1138E}', '\u{1138E}', &[Exclusion]),
+    ('\u{11390}', '\u{113B5}', &[Exclusion]),
+    ('\u{113B7}', '\u{113C0}', &[Exclusion]),
+    ('\u{113C2}', '\u{113C2}', &[Exclusion]),
+    ('\u{113C5}', '\u{113C5}', &[Exclusion]),
+    ('\u{113C7}', '\u{113CA}', &[Exclusion]),
+    ('\u{113CC}', '\u{113D5}', &[Exclusion]),
+    ('\u{113D7}', '\u{113D8}', &[Exclusion]),
+    ('\u{113E1}', '\u{113E2}', &[Exclusion]),
+    ('\u{11400}', '\u{1145B}', &[LimitedUse]),
+    ('\u{1145D}', '\u{11461}', &[LimitedUse]),
+    ('\u{11480}', '\u{114C7}', &[Exclusion]),
+    ('\u{114D0}', '\u{114D9}', &[Exclusion]),
+    ('\u{11580}', '\u{115B5}', &[Exclusion]),
+    ('\u{115B8}', '\u{115DD}', &[Exclusion]),
+    ('\u{11600}', '\u{11644}', &[Exclusion]),
+    ('\u{11650}', '\u{11659}', &[Exclusion]),
+    ('\u{11660}', '\u{1166C}', &[Exclusion]),
+    ('\u{11680}', '\u{116B9}', &[Exclusion]),
+    ('\u{116C0}', '\u{116C9}', &[Exclusion]),
+    ('\u{116D0}', '\u{116E3}', &[UncommonUse]),
+    ('\u{11700}', '\u{1171A}', &[Exclusion]),
+    ('\u{1171D}', '\u{1172B}', &[Exclusion]),
+    ('\u{11730}', '\u{11746}', &[Exclusion]),
+    ('\u{11800}', '\u{1183B}', &[Exclusion]),
+    ('\u{118A0}', '\u{118F2}', &[Exclusion]),
+    ('\u{118FF}', '\u{11906}', &[Exclusion]),
+    ('\u{11909}', '\u{11909}', &[Exclusion]),
+    ('\u{1190C}', '\u{11913}', &[Exclusion]),
+    ('\u{11915}', '\u{11916}', &[Exclusion]),
+    ('\u{11918}', '\u{11935}', &[Exclusion]),
+    ('\u{11937}', '\u{11938}', &[Exclusion]),
+    ('\u{1193B}', '\u{11946}', &[Exclusion]),
+    ('\u{11950}', '\u{11959}', &[Exclusion]),
+    ('\u{119A0}', '\u{119A7}', &[Exclusion]),
+    ('\u{119AA}', '\u{119D7}', &[Exclusion]),
+    ('\u{119DA}', '\u{119E4}', &[Exclusion]),
+    ('\u{11A00}', '\u{11A47}', &[Exclusion]),
+    ('\u{11A50}', '\u{11AA2}', &[Exclusion]),
+    ('\u{11AB0}', '\u{11ABF}', &[LimitedUse]),
+    ('\u{11AC0}', '\u{11AF8}', &[Exclusion]),
+    ('\u{11B00}', '\u{11B09}', &[NotXid]),
+    ('\u{11BC0}', '\u{11BE1}', &[Exclusion]),
+    ('\u{11BF0}', '\u{11BF9}', &[Exclusion]),
+    ('\u{11C00}', '\u{11C08}', &[Exclusion]),
+    ('\u{11C0A}', '\u{11C36}', &[Exclusion]),
+    ('\u{11C38}', '\u{11C45}', &[Exclusion]),
+    ('\u{11C50}', '\u{11C6C}', &[Exclusion]),
+    ('\u{11C70}', '\u{11C8F}', &[Exclusion]),
+    ('\u{11C92}', '\u{11CA7}', &[Exclusion]),
+    ('\u{11CA9}', '\u{11CB6}', &[Exclusion]),
+    ('\u{11D00}', '\u{11D06}', &[Exclusion]),
+    ('\u{11D08}', '\u{11D09}', &[Exclusion]),
+    ('\u{11D0B}', '\u{11D36}', &[Exclusion]),
+    ('\u{11D3A}', '\u{11D3A}', &[Exclusion]),
+    ('\u{11D3C}', '\u{11D3D}', &[Exclusion]),
+    ('\u{11D3F}', '\u{11D47}', &[Exclusion]),
+    ('\u{11D50}', '\u{11D59}', &[Exclusion]),
+    ('\u{11D60}', '\u{11D65}', &[LimitedUse]),
+    ('\u{11D67}', '\u{11D68}', &[LimitedUse]),
+    ('\u{11D6A}', '\u{11D8E}', &[LimitedUse]),
+    ('\u{11D90}', '\u{11D91}', &[LimitedUse]),
+    ('\u{11D93}', '\u{11D98}', &[LimitedUse]),
+    ('\u{11DA0}', '\u{11DA9}', &[LimitedUse]),
+    ('\u{11EE0}', '\u{11EF8}', &[Exclusion]),
+    ('\u{11F00}', '\u{11F10}', &[Exclusion]),
+    ('\u{11F12}', '\u{11F3A}', &[Exclusion]),
+    ('\u{11F3E}', '\u{11F5A}', &[Exclusion]),
+    ('\u{11FB0}', '\u{11FB0}', &[LimitedUse]),
+    ('\u{11FC0}', '\u{11FF1}', &[NotXid]),
+    ('\u{11FFF}', '\u{11FFF}', &[NotXid]),
+    ('\u{12000}', '\u{12399}', &[Exclusion]),
+    ('\u{12400}', '\u{1246E}', &[Exclusion]),
+    ('\u{12470}', '\u{12474}', &[Exclusion]),
+    ('\u{12480}', '\u{12543}', &[Exclusion]),
+    ('\u{12F90}', '\u{12FF2}', &[Exclusion]),
+    ('\u{13000}', '\u{13455}', &[Exclusion]),
+    ('\u{13460}', '\u{143FA}', &[Exclusion]),
+    ('\u{14400}', '\u{14646}', &[Exclusion]),
+    ('\u{16100}', '\u{16139}', &[Exclusion]),
+    ('\u{16800}', '\u{16A38}', &[LimitedUse]),
+    ('\u{16A40}', '\u{16A5E}', &[UncommonUse]),
+    ('\u{16A60}', '\u{16A69}', &[UncommonUse]),
+    ('\u{16A6E}', '\u{16ABE}', &[Exclusion]),
+    ('\u{16AC0}', '\u{16AC9}', &[Exclusion]),
+    ('\u{16AD0}', '\u{16AED}', &[Exclusion]),
+    ('\u{16AF0}', '\u{16AF5}', &[Exclusion]),
+    ('\u{16B00}', '\u{16B45}', &[Exclusion]),
+    ('\u{16B50}', '\u{16B59}', &[Exclusion]),
+    ('\u{16B5B}', '\u{16B61}', &[Exclusion]),
+    ('\u{16B63}', '\u{16B77}', &[Exclusion]),
+    ('\u{16B7D}', '\u{16B8F}', &[Exclusion]),
+    ('\u{16D40}', '\u{16D79}', &[Exclusion]),
+    ('\u{16E40}', '\u{16E9A}', &[Exclusion]),
+    ('\u{16F00}', '\u{16F4A}', &[LimitedUse]),
+    ('\u{16F4F}', '\u{16F87}', &[LimitedUse]),
+    ('\u{16F8F}', '\u{16F9F}', &[LimitedUse]),
+    ('\u{16FE0}', '\u{16FE1}', &[Exclusion]),
+    ('\u{16FE2}', '\u{16FE2}', &[NotXid]),
+    ('\u{16FE3}', '\u{16FE3}', &[Obsolete]),
+    ('\u{16FE4}', '\u{16FE4}', &[Exclusion]),
+    ('\u{16FF0}', '\u{16FF1}', &[Recommended]),
+    ('\u{17000}', '\u{187F7}', &[Exclusion]),
+    ('\u{18800}', '\u{18CD5}', &[Exclusion]),
+    ('\u{18CFF}', '\u{18D08}', &[Exclusion]),
+    ('\u{1AFF0}', '\u{1AFF3}', &[UncommonUse]),
+    ('\u{1AFF5}', '\u{1AFFB}', &[UncommonUse]),
+    ('\u{1AFFD}', '\u{1AFFE}', &[UncommonUse]),
+    ('\u{1B000}', '\u{1B11E}', &[Obsolete]),
+    ('\u{1B11F}', '\u{1B122}', &[Recommended]),
+    ('\u{1B132}', '\u{1B132}', &[Recommended]),
+    ('\u{1B150}', '\u{1B152}', &[Recommended]),
+    ('\u{1B155}', '\u{1B155}', &[Recommended]),
+    ('\u{1B164}', '\u{1B167}', &[Recommended]),
+    ('\u{1B170}', '\u{1B2FB}', &[Exclusion]),
+    ('\u{1BC00}', '\u{1BC6A}', &[Exclusion]),
+    ('\u{1BC70}', '\u{1BC7C}', &[Exclusion]),
+    ('\u{1BC80}', '\u{1BC88}', &[Exclusion]),
+    ('\u{1BC90}', '\u{1BC99}', &[Exclusion]),
+    ('\u{1BC9C}', '\u{1BC9F}', &[Exclusion]),
+    ('\u{1BCA0}', '\u{1BCA3}', &[DefaultIgnorable]),
+    ('\u{1CC00}', '\u{1CCD5}', &[NotXid]),
+    ('\u{1CCD6}', '\u{1CCF9}', &[NotNfkc]),
+    ('\u{1CD00}', '\u{1CEB3}', &[NotXid]),
+    ('\u{1CF00}', '\u{1CF2D}', &[Technical]),
+    ('\u{1CF30}', '\u{1CF46}', &[Technical]),
+    ('\u{1CF50}', '\u{1CFC3}', &[Technical]),
+    ('\u{1D000}', '\u{1D0F5}', &[Technical]),
+    ('\u{1D100}', '\u{1D126}', &[Technical]),
+    ('\u{1D129}', '\u{1D15D}', &[Technical]),
+    ('\u{1D15E}', '\u{1D164}', &[NotNfkc]),
+    ('\u{1D165}', '\u{1D172}', &[Technical]),
+    ('\u{1D173}', '\u{1D17A}', &[DefaultIgnorable]),
+    ('\u{1D17B}', '\u{1D1BA}', &[Technical]),
+    ('\u{1D1BB}', '\u{1D1C0}', &[NotNfkc]),
+    ('\u{1D1C1}', '\u{1D1DD}', &[Technical]),
+    ('\u{1D1DE}', '\u{1D1E8}', &[UncommonUse]),
+    ('\u{1D1E9}', '\u{1D1EA}', &[Technical]),
+    ('\u{1D200}', '\u{1D241}', &[Obsolete]),
+    ('\u{1D242}', '\u{1D244}', &[Technical]),
+    ('\u{1D245}', '\u{1D245}', &[Obsolete]),
+    ('\u{1D2C0}', '\u{1D2D3}', &[NotXid]),
+    ('\u{1D2E0}', '\u{1D2F3}', &[NotXid]),
+    ('\u{1D300}', '\u{1D356}', &[Technical]),
+    ('\u{1D360}', '\u{1D378}', &[NotXid]),
+    ('\u{1D400}', '\u{1D454}', &[NotNfkc]),
+    ('\u{1D456}', '\u{1D49C}', &[NotNfkc]),
+    ('\u{1D49E}', '\u{1D49F}', &[NotNfkc]),
+    ('\u{1D4A2}', '\u{1D4A2}', &[NotNfkc]),
+    ('\u{1D4A5}', '\u{1D4A6}', &[NotNfkc]),
+    ('\u{1D4A9}', '\u{1D4AC}', &[NotNfkc]),
+    ('\u{1D4AE}', '\u{1D4B9}', &[NotNfkc]),
+    ('\u{1D4BB}', '\u{1D4BB}', &[NotNfkc]),
+    ('\u{1D4BD}', '\u{1D4C3}', &[NotNfkc]),
+    ('\u{1D4C5}', '\u{1D505}', &[NotNfkc]),
+    ('\u{1D507}', '\u{1D50A}', &[NotNfkc]),
+    ('\u{1D50D}', '\u{1D514}', &[NotNfkc]),
+    ('\u{1D516}', '\u{1D51C}', &[NotNfkc]),
+    ('\u{1D51E}', '\u{1D539}', &[NotNfkc]),
+    ('\u{1D53B}', '\u{1D53E}', &[NotNfkc]),
+    ('\u{1D540}', '\u{1D544}', &[NotNfkc]),
+    ('\u{1D546}', '\u{1D546}', &[NotNfkc]),
+    ('\u{1D54A}', '\u{1D550}', &[NotNfkc]),
+    ('\u{1D552}', '\u{1D6A5}', &[NotNfkc]),
+    ('\u{1D6A8}', '\u{1D7CB}', &[NotNfkc]),
+    ('\u{1D7CE}', '\u{1D7FF}', &[NotNfkc]),
+    ('\u{1D800}', '\u{1DA8B}', &[Exclusion]),
+    ('\u{1DA9B}', '\u{1DA9F}', &[Exclusion]),
+    ('\u{1DAA1}', '\u{1DAAF}', &[Exclusion]),
+    ('\u{1DF00}', '\u{1DF1E}', &[Recommended]),
+    ('\u{1DF25}', '\u{1DF2A}', &[Recommended]),
+    ('\u{1E000}', '\u{1E006}', &[Exclusion]),
+    ('\u{1E008}', '\u{1E018}', &[Exclusion]),
+    ('\u{1E01B}', '\u{1E021}', &[Exclusion]),
+    ('\u{1E023}', '\u{1E024}', &[Exclusion]),
+    ('\u{1E026}', '\u{1E02A}', &[Exclusion]),
+    ('\u{1E030}', '\u{1E06D}', &[NotNfkc]),
+    ('\u{1E08F}', '\u{1E08F}', &[Recommended]),
+    ('\u{1E100}', '\u{1E12C}', &[LimitedUse]),
+    ('\u{1E130}', '\u{1E13D}', &[LimitedUse]),
+    ('\u{1E140}', '\u{1E149}', &[LimitedUse]),
+    ('\u{1E14E}', '\u{1E14F}', &[LimitedUse]),
+    ('\u{1E290}', '\u{1E2AE}', &[Exclusion]),
+    ('\u{1E2C0}', '\u{1E2F9}', &[LimitedUse]),
+    ('\u{1E2FF}', '\u{1E2FF}', &[LimitedUse]),
+    ('\u{1E4D0}', '\u{1E4F9}', &[Exclusion]),
+    ('\u{1E5D0}', '\u{1E5FA}', &[Exclusion]),
+    ('\u{1E5FF}', '\u{1E5FF}', &[Exclusion]),
+    ('\u{1E7E0}', '\u{1E7E6}', &[Recommended]),
+    ('\u{1E7E8}', '\u{1E7EB}', &[Recommended]),
+    ('\u{1E7ED}', '\u{1E7EE}', &[Recommended]),
+    ('\u{1E7F0}', '\u{1E7FE}', &[Recommended]),
+    ('\u{1E800}', '\u{1E8C4}', &[Exclusion]),
+    ('\u{1E8C7}', '\u{1E8D6}', &[Exclusion]),
+    ('\u{1E900}', '\u{1E94B}', &[LimitedUse]),
+    ('\u{1E950}', '\u{1E959}', &[LimitedUse]),
+    ('\u{1E95E}', '\u{1E95F}', &[LimitedUse]),
+    ('\u{1EC71}', '\u{1ECB4}', &[NotXid]),
+    ('\u{1ED01}', '\u{1ED3D}', &[NotXid]),
+    ('\u{1EE00}', '\u{1EE03}', &[NotNfkc]),
+    ('\u{1EE05}', '\u{1EE1F}', &[NotNfkc]),
+    ('\u{1EE21}', '\u{1EE22}', &[NotNfkc]),
+    ('\u{1EE24}', '\u{1EE24}', &[NotNfkc]),
+    ('\u{1EE27}', '\u{1EE27}', &[NotNfkc]),
+    ('\u{1EE29}', '\u{1EE32}', &[NotNfkc]),
+    ('\u{1EE34}', '\u{1EE37}', &[NotNfkc]),
+    ('\u{1EE39}', '\u{1EE39}', &[NotNfkc]),
+    ('\u{1EE3B}', '\u{1EE3B}', &[NotNfkc]),
+    ('\u{1EE42}', '\u{1EE42}', &[NotNfkc]),
+    ('\u{1EE47}', '\u{1EE47}', &[NotNfkc]),
+    ('\u{1EE49}', '\u{1EE49}', &[NotNfkc]),
+    ('\u{1EE4B}', '\u{1EE4B}', &[NotNfkc]),
+    ('\u{1EE4D}', '\u{1EE4F}', &[NotNfkc]),
+    ('\u{1EE51}', '\u{1EE52}', &[NotNfkc]),
+    ('\u{1EE54}', '\u{1EE54}', &[NotNfkc]),
+    ('\u{1EE57}', '\u{1EE57}', &[NotNfkc]),
+    ('\u{1EE59}', '\u{1EE59}', &[NotNfkc]),
+    ('\u{1EE5B}', '\u{1EE5B}', &[NotNfkc]),
+    ('\u{1EE5D}', '\u{1EE5D}', &[NotNfkc]),
+    ('\u{1EE5F}', '\u{1EE5F}', &[NotNfkc]),
+    ('\u{1EE61}', '\u{1EE62}', &[NotNfkc]),
+    ('\u{1EE64}', '\u{1EE64}', &[NotNfkc]),
+    ('\u{1EE67}', '\u{1EE6A}', &[NotNfkc]),
+    ('\u{1EE6C}', '\u{1EE72}', &[NotNfkc]),
+    ('\u{1EE74}', '\u{1EE77}', &[NotNfkc]),
+    ('\u{1EE79}', '\u{1EE7C}', &[NotNfkc]),
+    ('\u{1EE7E}', '\u{1EE7E}', &[NotNfkc]),
+    ('\u{1EE80}', '\u{1EE89}', &[NotNfkc]),
+    ('\u{1EE8B}', '\u{1EE9B}', &[NotNfkc]),
+    ('\u{1EEA1}', '\u{1EEA3}', &[NotNfkc]),
+    ('\u{1EEA5}', '\u{1EEA9}', &[NotNfkc]),
+    ('\u{1EEAB}', '\u{1EEBB}', &[NotNfkc]),
+    ('\u{1EEF0}', '\u{1EEF1}', &[NotXid]),
+    ('\u{1F000}', '\u{1F02B}', &[NotXid]),
+    ('\u{1F030}', '\u{1F093}', &[NotXid]),
+    ('\u{1F0A0}', '\u{1F0AE}', &[NotXid]),
+    ('\u{1F0B1}', '\u{1F0BF}', &[NotXid]),
+    ('\u{1F0C1}', '\u{1F0CF}', &[NotXid]),
+    ('\u{1F0D1}', '\u{1F0F5}', &[NotXid]),
+    ('\u{1F100}', '\u{1F10A}', &[NotNfkc]),
+    ('\u{1F10B}', '\u{1F10F}', &[NotXid]),
+    ('\u{1F110}', '\u{1F12E}', &[NotNfkc]),
+    ('\u{1F12F}', '\u{1F12F}', &[NotXid]),
+    ('\u{1F130}', '\u{1F14F}', &[NotNfkc]),
+    ('\u{1F150}', '\u{1F169}', &[NotXid]),
+    ('\u{1F16A}', '\u{1F16C}', &[NotNfkc]),
+    ('\u{1F16D}', '\u{1F18F}', &[NotXid]),
+    ('\u{1F190}', '\u{1F190}', &[NotNfkc]),
+    ('\u{1F191}', '\u{1F1AD}', &[NotXid]),
+    ('\u{1F1E6}', '\u{1F1FF}', &[NotXid]),
+    ('\u{1F200}', '\u{1F202}', &[NotNfkc]),
+    ('\u{1F210}', '\u{1F23B}', &[NotNfkc]),
+    ('\u{1F240}', '\u{1F248}', &[NotNfkc]),
+    ('\u{1F250}', '\u{1F251}', &[NotNfkc]),
+    ('\u{1F260}', '\u{1F265}', &[NotXid]),
+    ('\u{1F300}', '\u{1F54E}', &[NotXid]),
+    ('\u{1F54F}', '\u{1F54F}', &[UncommonUse]),
+    ('\u{1F550}', '\u{1F6D7}', &[NotXid]),
+    ('\u{1F6DC}', '\u{1F6EC}', &[NotXid]),
+    ('\u{1F6F0}', '\u{1F6FC}', &[NotXid]),
+    ('\u{1F700}', '\u{1F776}', &[NotXid]),
+    ('\u{1F77B}', '\u{1F7D9}', &[NotXid]),
+    ('\u{1F7E0}', '\u{1F7EB}', &[NotXid]),
+    ('\u{1F7F0}', '\u{1F7F0}', &[NotXid]),
+    ('\u{1F800}', '\u{1F80B}', &[NotXid]),
+    ('\u{1F810}', '\u{1F847}', &[NotXid]),
+    ('\u{1F850}', '\u{1F859}', &[NotXid]),
+    ('\u{1F860}', '\u{1F887}', &[NotXid]),
+    ('\u{1F890}', '\u{1F8AD}', &[NotXid]),
+    ('\u{1F8B0}', '\u{1F8BB}', &[NotXid]),
+    ('\u{1F8C0}', '\u{1F8C1}', &[NotXid]),
+    ('\u{1F900}', '\u{1FA53}', &[NotXid]),
+    ('\u{1FA60}', '\u{1FA6D}', &[NotXid]),
+    ('\u{1FA70}', '\u{1FA7C}', &[NotXid]),
+    ('\u{1FA80}', '\u{1FA89}', &[NotXid]),
+    ('\u{1FA8F}', '\u{1FAC6}', &[NotXid]),
+    ('\u{1FACE}', '\u{1FADC}', &[NotXid]),
+    ('\u{1FADF}', '\u{1FAE9}', &[NotXid]),
+    ('\u{1FAF0}', '\u{1FAF8}', &[NotXid]),
+    ('\u{1FB00}', '\u{1FB92}', &[NotXid]),
+    ('\u{1FB94}', '\u{1FBEF}', &[NotXid]),
+    ('\u{1FBF0}', '\u{1FBF9}', &[NotNfkc]),
+    ('\u{20000}', '\u{2A6DF}', &[Recommended]),
+    ('\u{2A700}', '\u{2B739}', &[Recommended]),
+    ('\u{2B740}', '\u{2B81D}', &[Recommended]),
+    ('\u{2B820}', '\u{2CEA1}', &[Recommended]),
+    ('\u{2CEB0}', '\u{2EBE0}', &[Recommended]),
+    ('\u{2EBF0}', '\u{2EE5D}', &[Recommended]),
+    ('\u{2F800}', '\u{2FA1D}', &[NotNfkc]),
+    ('\u{30000}', '\u{3134A}', &[Recommended]),
+    ('\u{31350}', '\u{323AF}', &[Recommended]),
+    ('\u{E0001}', '\u{E0001}', &[Deprecated]),
+    ('\u{E0020}', '\u{E007F}', &[DefaultIgnorable]),
+    ('\u{E0100}', '\u{E01EF}', &[DefaultIgnorable]),
+];
@@ -0,0 +1,46 @@
+//! Unicode Character Database tables
+//!
+//! The submodules are generated by `scripts/unicode.py` and must not be edited
+//! by hand; re-run the script to move to a newer Unicode version. Only the
+//! lookup helpers below are maintained manually.
+
+pub(crate) mod age;
+pub(crate) mod bidi;
+pub(crate) mod case_folding;
+pub(crate) mod confusables;
+pub(crate) mod emoji;
+pub(crate) mod general_category;
+pub(crate) mod grapheme;
+pub(crate) mod identifier;
+#[cfg(feature = "names")]
+pub(crate) mod names;
+pub(crate) mod normalization;
+pub(crate) mod property_bool;
+pub(crate) mod script;
+
+use std::cmp::Ordering;
+
+fn compare_range(c: char, lo: char, hi: char) -> Ordering {
+    if c < lo {
+        Ordering::Greater
+    } else if c > hi {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Check whether `c` falls into one of the sorted, non-overlapping ranges
+pub(crate) fn bsearch_range_table(c: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| compare_range(c, lo, hi))
+        .is_ok()
+}
+
+/// Look up the value of the sorted, non-overlapping range containing `c`
+pub(crate) fn bsearch_range_value_table<T: Copy>(c: char, table: &[(char, char, T)]) -> Option<T> {
+    table
+        .binary_search_by(|&(lo, hi, _)| compare_range(c, lo, hi))
+        .ok()
+        .map(|i| table[i].2)
+}
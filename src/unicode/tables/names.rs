@@ -0,0 +1,16458 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+pub(crate) const ALGORITHMIC_NAMES: &[(char, char, &str)] = &[
+    ('\u{3400}', '\u{4DBF}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{4E00}', '\u{9FFF}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{F900}', '\u{FA6D}', "CJK COMPATIBILITY IDEOGRAPH-"),
+    ('\u{FA70}', '\u{FAD9}', "CJK COMPATIBILITY IDEOGRAPH-"),
+    ('\u{13460}', '\u{143FA}', "EGYPTIAN HIEROGLYPH-"),
+    ('\u{17000}', '\u{187F7}', "TANGUT IDEOGRAPH-"),
+    ('\u{18B00}', '\u{18CD5}', "KHITAN SMALL SCRIPT CHARACTER-"),
+    ('\u{18CFF}', '\u{18CFF}', "KHITAN SMALL SCRIPT CHARACTER-"),
+    ('\u{18D00}', '\u{18D08}', "TANGUT IDEOGRAPH-"),
+    ('\u{1B170}', '\u{1B2FB}', "NUSHU CHARACTER-"),
+    ('\u{20000}', '\u{2A6DF}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2A700}', '\u{2B739}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2B740}', '\u{2B81D}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2B820}', '\u{2CEA1}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2CEB0}', '\u{2EBE0}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2EBF0}', '\u{2EE5D}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{2F800}', '\u{2FA1D}', "CJK COMPATIBILITY IDEOGRAPH-"),
+    ('\u{30000}', '\u{3134A}', "CJK UNIFIED IDEOGRAPH-"),
+    ('\u{31350}', '\u{323AF}', "CJK UNIFIED IDEOGRAPH-"),
+];
+
+pub(crate) const LEXICON: &str = concat!(
+    "LETTER",
+    "SIGN",
+    "WITH",
+    "SMALL",
+    "SYLLABLE",
+    "CAPITAL",
+    "HIEROGLYPH",
+    "LATIN",
+    "ARABIC",
+    "YI",
+    "CUNEIFORM",
+    "SYMBOL",
+    "MATHEMATICAL",
+    "EGYPTIAN",
+    "DIGIT",
+    "VOWEL",
+    "FORM",
+    "TANGUT",
+    "CANADIAN",
+    "SYLLABICS",
+    "A",
+    "SIGNWRITING",
+    "TIMES",
+    "BAMUM",
+    "AND",
+    "ARROW",
+    "RIGHT",
+    "BOLD",
+    "ANATOLIAN",
+    "LEFT",
+    "HANGUL",
+    "NUMBER",
+    "LINEAR",
+    "COMBINING",
+    "LIGATURE",
+    "GREEK",
+    "ETHIOPIC",
+    "MUSICAL",
+    "CYRILLIC",
+    "BLOCK",
+    "OLD",
+    "ABOVE",
+    "FOR",
+    "SQUARE",
+    "MARK",
+    "ITALIC",
+    "ONE",
+    "U",
+    "CIRCLED",
+    "RADICAL",
+    "SANS-SERIF",
+    "DOUBLE",
+    "FINAL",
+    "MODIFIER",
+    "O",
+    "TWO",
+    "BLACK",
+    "TAI",
+    "E",
+    "I",
+    "BELOW",
+    "THREE",
+    "DOT",
+    "WHITE",
+    "VAI",
+    "TO",
+    "B",
+    "HENTAIGANA",
+    "LOWER",
+    "VARIATION",
+    "PATTERN",
+    "BRAILLE",
+    "MIDDLE",
+    "UPPER",
+    "BYZANTINE",
+    "STROKE",
+    "MYANMAR",
+    "KATAKANA",
+    "FOUR",
+    "ISOLATED",
+    "OF",
+    "HEAVY",
+    "VERTICAL",
+    "HOOK",
+    "HALF",
+    "FIVE",
+    "KANGXI",
+    "KIKAKUI",
+    "MENDE",
+    "TIBETAN",
+    "KA",
+    "CJK",
+    "BOX",
+    "INITIAL",
+    "MEEM",
+    "HMONG",
+    "RIGHTWARDS",
+    "YEH",
+    "COPTIC",
+    "FACE",
+    "CIRCLE",
+    "ZNAMENNY",
+    "R",
+    "CARRIER",
+    "S",
+    "PLUS",
+    "SQUARED",
+    "UP",
+    "GEORGIAN",
+    "LIGHT",
+    "CHEROKEE",
+    "DRAWINGS",
+    "MONGOLIAN",
+    "DEVANAGARI",
+    "INDEX",
+    "EIGHT",
+    "SIX",
+    "LEFTWARDS",
+    "L",
+    "VOCALIC",
+    "PHASE-E",
+    "BAR",
+    "DOWN",
+    "SEVEN",
+    "ALEF",
+    "LARGE",
+    "NINE",
+    "HA",
+    "MIAO",
+    "KHMER",
+    "TILE",
+    "DUPLOYAN",
+    "LOW",
+    "BRACKET",
+    "TONE",
+    "PARENTHESIZED",
+    "THAM",
+    "GONDI",
+    "HUNDRED",
+    "DOTS",
+    "JONGSEONG",
+    "DIAGONAL",
+    "GLAGOLITIC",
+    "HEBREW",
+    "HIGH",
+    "HORIZONTAL",
+    "TAMIL",
+    "PA",
+    "THUMB",
+    "RA",
+    "MALAYALAM",
+    "OVER",
+    "SIYAQ",
+    "BALINESE",
+    "PAHAWH",
+    "CHOSEONG",
+    "IDEOGRAPHIC",
+    "N",
+    "HAND-FIST",
+    "MA",
+    "OPEN",
+    "HALFWIDTH",
+    "MEROITIC",
+    "LA",
+    "NA",
+    "SCRIPT",
+    "CHESS",
+    "RING",
+    "TURNED",
+    "TRIANGLE",
+    "YA",
+    "IDEOGRAM",
+    "PHASE-D",
+    "TA",
+    "ALCHEMICAL",
+    "EQUAL",
+    "HAH",
+    "NEUME",
+    "BRAHMI",
+    "LONG",
+    "M",
+    "SA",
+    "THOUSAND",
+    "NUMERIC",
+    "PUNCTUATION",
+    "DA",
+    "DOWNWARDS",
+    "SINHALA",
+    "GA",
+    "HUNGARIAN",
+    "MEDIUM",
+    "NORTH",
+    "TILDE",
+    "BARB",
+    "UPWARDS",
+    "TAG",
+    "FULLWIDTH",
+    "G",
+    "T",
+    "ZERO",
+    "JEEM",
+    "K",
+    "MEDIAL",
+    "NEGATIVE",
+    "V",
+    "CHARACTER",
+    "DOMINO",
+    "TELUGU",
+    "BA",
+    "CYPRO-MINOAN",
+    "D",
+    "FRAKTUR",
+    "FRACTION",
+    "H",
+    "NUMERAL",
+    "PHASE-C",
+    "SIDDHAM",
+    "BHAIKSUKI",
+    "DOUBLE-STRUCK",
+    "NEWA",
+    "Z",
+    "AA",
+    "ARMENIAN",
+    "BENGALI",
+    "CENTRE",
+    "HIRAGANA",
+    "OR",
+    "SHARADA",
+    "JUNGSEONG",
+    "Y",
+    "ACUTE",
+    "CONSONANT",
+    "OL",
+    "GUJARATI",
+    "IDEOGRAPH",
+    "JAVANESE",
+    "KANNADA",
+    "MEDEFAIDRIN",
+    "ORIYA",
+    "CURSIVE",
+    "SOUTH",
+    "SYRIAC",
+    "WEST-CREE",
+    "RUNIC",
+    "TANGSA",
+    "ADLAM",
+    "C",
+    "KAWI",
+    "AI",
+    "CARD",
+    "NEW",
+    "PHASE-A",
+    "REVERSED",
+    "THAI",
+    "X",
+    "P",
+    "GRANTHA",
+    "II",
+    "SOGDIAN",
+    "CITI",
+    "LUE",
+    "MOVEMENT-WALLPLANE",
+    "SUBJOINED",
+    "TEN",
+    "W",
+    "WARANG",
+    "CHAM",
+    "DASIA",
+    "LAO",
+    "PLAYING",
+    "SOYOMBO",
+    "SAURASHTRA",
+    "TIRHUTA",
+    "CIRCUMFLEX",
+    "JA",
+    "NGA",
+    "SINGLE",
+    "TETRAGRAM",
+    "AU",
+    "CA",
+    "DESERET",
+    "GURMUKHI",
+    "HAMZA",
+    "J",
+    "LINE",
+    "SHA",
+    "TULU-TIGALARI",
+    "MAYEK",
+    "MEETEI",
+    "MODI",
+    "NOTATION",
+    "PSILI",
+    "SEPARATED",
+    "UU",
+    "INDIC",
+    "POINTING",
+    "GA2",
+    "MOVEMENT-FLOORPLANE",
+    "TRIANGLE-HEADED",
+    "ARROWHEAD",
+    "DIAERESIS",
+    "TOP",
+    "WA",
+    "BOPOMOFO",
+    "MASARAM",
+    "SHORT",
+    "LAM",
+    "LEPCHA",
+    "STOP",
+    "F",
+    "OMEGA",
+    "TURKIC",
+    "VIET",
+    "AKURU",
+    "ALPHA",
+    "CHA",
+    "DIVES",
+    "INVERTED",
+    "KHA",
+    "OSAGE",
+    "QUADRANT",
+    "SUNDANESE",
+    "ZANABAZAR",
+    "CHAKMA",
+    "LITTLE",
+    "NYA",
+    "NYIAKENG",
+    "PUACHUE",
+    "APL",
+    "FUNCTIONAL",
+    "GRAVE",
+    "QUARTER",
+    "VITHKUQI",
+    "GARAY",
+    "KHUDAWADI",
+    "MAKSURA",
+    "TELEGRAPH",
+    "ACCENT",
+    "KAITHI",
+    "KHAH",
+    "KHAROSHTHI",
+    "LIMBU",
+    "MACRON",
+    "MARCHEN",
+    "NOON",
+    "ON",
+    "TAKRI",
+    "BOTTOM",
+    "NOT",
+    "SUBSCRIPT",
+    "THA",
+    "AHOM",
+    "EAST",
+    "IN",
+    "KHOJKI",
+    "LI",
+    "NANDINAGARI",
+    "ORNAMENT",
+    "ARABIAN",
+    "HARPOON",
+    "HEXAGRAM",
+    "ROTATED",
+    "ANGLE",
+    "GUNJALA",
+    "LAGAB",
+    "PIECE",
+    "TRIPLE",
+    "MONOSPACE",
+    "NKO",
+    "OXIA",
+    "SAMARITAN",
+    "TAIL",
+    "VARIA",
+    "COMMA",
+    "MOUTH",
+    "OTTOMAN",
+    "Q",
+    "STAR",
+    "DOGRA",
+    "GREATER-THAN",
+    "LESS-THAN",
+    "VA",
+    "NUN",
+    "PHA",
+    "RAI",
+    "TIFINAGH",
+    "TYPE",
+    "WANCHO",
+    "ANUSVARA",
+    "GURUNG",
+    "HAU",
+    "KHEMA",
+    "KIRAT",
+    "AEGEAN",
+    "BREVE",
+    "CIN",
+    "EN",
+    "INSCRIPTIONAL",
+    "PAU",
+    "WEST",
+    "ASH",
+    "BATAK",
+    "ETA",
+    "GUNU",
+    "PAHLAVI",
+    "PHAGS-PA",
+    "PHASE-B",
+    "AVESTAN",
+    "CYPRIOT",
+    "TTA",
+    "DDA",
+    "HITTING",
+    "IOTA",
+    "PHASE-F",
+    "ACROPHONIC",
+    "ALBANIAN",
+    "CAUCASIAN",
+    "CROSS",
+    "DEGREES",
+    "DHA",
+    "FULL",
+    "OO",
+    "PERSIAN",
+    "TEH",
+    "CLOSED",
+    "GHA",
+    "PI",
+    "ROMAN",
+    "TODHRI",
+    "UNIFIED",
+    "KAF",
+    "KAYAH",
+    "MANICHAEAN",
+    "ROHINGYA",
+    "SEEN",
+    "ZA",
+    "HANIFI",
+    "THAANA",
+    "VEDIC",
+    "CARIAN",
+    "DISC",
+    "HEH",
+    "LE",
+    "LISU",
+    "PE",
+    "TWENTY",
+    "BHA",
+    "CHIKI",
+    "DIAMOND",
+    "FROM",
+    "SHAVIAN",
+    "UPSILON",
+    "NAG",
+    "NNA",
+    "OPERATOR",
+    "PERISPOMENI",
+    "YEZIDI",
+    "ASTERISK",
+    "EE",
+    "MTAVRULI",
+    "PARENTHESIS",
+    "PHAISTOS",
+    "SHAN",
+    "SHE",
+    "TTHA",
+    "AIN",
+    "AT",
+    "BEH",
+    "CARON",
+    "NAGRI",
+    "NEUTRAL",
+    "SYLOTI",
+    "CORNER",
+    "DDHA",
+    "FA",
+    "MAHJONG",
+    "OE",
+    "ONAL",
+    "SECTION",
+    "SHEEN",
+    "SUNUWAR",
+    "THE",
+    "VIRAMA",
+    "ALI",
+    "DASH",
+    "GALI",
+    "MRO",
+    "OUTLINED",
+    "PERMIC",
+    "RR",
+    "ALTERNATE",
+    "DOTTED",
+    "MUNDARI",
+    "ORKHON",
+    "RETROFLEX",
+    "SSA",
+    "WAW",
+    "DESCENDER",
+    "HE",
+    "MU",
+    "TAH",
+    "TENU",
+    "VISARGA",
+    "WIDE-HEADED",
+    "YU",
+    "ARCHAIC",
+    "BASSA",
+    "ELBASAN",
+    "END",
+    "JHA",
+    "NABATAEAN",
+    "NO",
+    "OSMANYA",
+    "CURVE",
+    "EIGHTH",
+    "MAHAJANI",
+    "EPSILON",
+    "FEH",
+    "HEAD",
+    "LL",
+    "MULTANI",
+    "NINETY",
+    "POINTED",
+    "REH",
+    "STRAIGHT",
+    "TE",
+    "YPOGEGRAMMENI",
+    "AN",
+    "HI",
+    "INSTRUMENTAL",
+    "REJANG",
+    "VAH",
+    "WAVE",
+    "CURLY",
+    "DANDA",
+    "EQUALS",
+    "KNIGHT",
+    "LU2",
+    "QAF",
+    "RAISED",
+    "RIGHT-POINTING",
+    "SIDE",
+    "ARABIC-INDIC",
+    "CANDRABINDU",
+    "FIFTY",
+    "GLOTTAL",
+    "HORN",
+    "NE",
+    "NI",
+    "SOMPENG",
+    "SORA",
+    "TACK",
+    "DUG",
+    "HAND",
+    "ME",
+    "SAD",
+    "SEVENTY",
+    "STRELA",
+    "AE",
+    "HEART",
+    "INDICATOR",
+    "LEFT-POINTING",
+    "PRESENTATION",
+    "SHIN",
+    "AFFIX",
+    "COLON",
+    "CROSSING",
+    "DAD",
+    "DAY",
+    "DINGBAT",
+    "HIEROGLYPHIC",
+    "IGI",
+    "NU",
+    "PALMYRENE",
+    "SHELL",
+    "VARIANT",
+    "VERY",
+    "YO",
+    "ARAMAIC",
+    "DIGRAPH",
+    "EYES",
+    "EZEN",
+    "FINGERS",
+    "HAND-HINGE",
+    "IMPERIAL",
+    "RUMI",
+    "TOTO",
+    "UGARITIC",
+    "YENISEI",
+    "YUS",
+    "BENT",
+    "BUGINESE",
+    "MI",
+    "MOON",
+    "OMICRON",
+    "PARTHIAN",
+    "QUOTATION",
+    "THIRTY",
+    "TSA",
+    "CEDILLA",
+    "CONJOINED",
+    "GAR",
+    "KISIM5",
+    "LLA",
+    "LOGICAL",
+    "LYCIAN",
+    "MANDAIC",
+    "OGHAM",
+    "PALATAL",
+    "PHOENICIAN",
+    "PSALTER",
+    "QUESTION",
+    "SAG",
+    "TODO",
+    "UD",
+    "VESSEL",
+    "VOCAL",
+    "ARC",
+    "CHORASMIAN",
+    "EPACT",
+    "KAREN",
+    "LOOPED",
+    "PROSGEGRAMMENI",
+    "RHO",
+    "SLANTED",
+    "SUPERSCRIPT",
+    "CLOCK",
+    "DAG",
+    "FORTY",
+    "GOTHIC",
+    "HAND-FLAT",
+    "HO",
+    "KO",
+    "LOOP",
+    "LYDIAN",
+    "NEO",
+    "NINDA2",
+    "NOTE",
+    "POINT",
+    "UP-POINTING",
+    "URU",
+    "BETWEEN",
+    "BU",
+    "CHE",
+    "CHI",
+    "EXCLAMATION",
+    "FILL",
+    "GHAIN",
+    "HATRAN",
+    "HOUR",
+    "LAING",
+    "LU",
+    "MINUS",
+    "NUKTA",
+    "REGIONAL",
+    "RO",
+    "SEPARATOR",
+    "SHAFT",
+    "SIGMA",
+    "SMILING",
+    "SO",
+    "SUBSET",
+    "SUPERSET",
+    "TORTOISE",
+    "UYGHUR",
+    "WE",
+    "WORD",
+    "EL",
+    "INTEGRAL",
+    "KHAMTI",
+    "MAKASAR",
+    "NOTEHEAD",
+    "PHI",
+    "QUAD",
+    "STRETCHED",
+    "THETA",
+    "TU",
+    "UNDERBAR",
+    "ARM",
+    "ATTIC",
+    "DAGESH",
+    "DE",
+    "EQUILATERAL",
+    "EXTENDED",
+    "GI",
+    "INDEPENDENT",
+    "KI",
+    "OVERLAY",
+    "PERSON",
+    "PWO",
+    "SOLIDUS",
+    "THEH",
+    "UE",
+    "WO",
+    "ZE",
+    "AB",
+    "ALTERNATING",
+    "DISH",
+    "ELYMAIC",
+    "QUARTERS",
+    "RU",
+    "SI",
+    "SIBE",
+    "SPACE",
+    "TAGALOG",
+    "ZHA",
+    "C-SIMPLIFIED",
+    "CURL",
+    "DOWN-POINTING",
+    "GAMMA",
+    "GAN2",
+    "GISH",
+    "KING",
+    "KU",
+    "LEAF",
+    "MAI",
+    "MAN",
+    "MO",
+    "QA",
+    "RRA",
+    "SE",
+    "SIXTEENTH",
+    "SU",
+    "TRIANGULAR",
+    "WI",
+    "ANTICLOCKWISE",
+    "ATTACHED",
+    "DOTLESS",
+    "GHE",
+    "HANUNOO",
+    "KE",
+    "MANCHU",
+    "REVERSE",
+    "THO",
+    "TIP",
+    "ABBREVIATION",
+    "BUHID",
+    "CLAN",
+    "CURVED",
+    "DAL",
+    "DELTA",
+    "DO",
+    "EM",
+    "HINGED",
+    "INSULAR",
+    "KAKTOVIK",
+    "LEG",
+    "LO",
+    "MAYAN",
+    "NG",
+    "QUEEN",
+    "UNION",
+    "AB2",
+    "ANNOTATION",
+    "ARCHAION",
+    "BAD",
+    "BETA",
+    "BI",
+    "DI",
+    "DU",
+    "ESH",
+    "IE",
+    "INSIDE",
+    "KUR",
+    "MEM",
+    "RED",
+    "SIDEWAYS",
+    "TI",
+    "TICK",
+    "TONOS",
+    "TWO-HEADED",
+    "VE",
+    "VULGAR",
+    "AL",
+    "BLACKFOOT",
+    "COUNTING",
+    "FLOOR",
+    "FORWARD",
+    "FTHORA",
+    "GURAGE",
+    "HAND-ANGLE",
+    "INTERSECTION",
+    "KAPPA",
+    "LENGTH",
+    "PAP",
+    "PO",
+    "RE",
+    "REST",
+    "ROD",
+    "SPOKED",
+    "STEM",
+    "TAGBANWA",
+    "TAU",
+    "WALL",
+    "XA",
+    "ZHE",
+    "ALEPH",
+    "ARROWS",
+    "AVAGRAHA",
+    "BE",
+    "BETH",
+    "CEILING",
+    "CIRCLES",
+    "DESCRIPTION",
+    "DIALYTIKA",
+    "GU",
+    "GUD",
+    "HAND-CIRCLE",
+    "HEARTS",
+    "MUSH",
+    "N-ARY",
+    "OBLIQUE",
+    "PSI",
+    "ROTATION",
+    "SAYISI",
+    "SIXTY",
+    "SOUND",
+    "SPREAD",
+    "TAW",
+    "THIRD",
+    "TONGUE",
+    "ALMOST",
+    "BISHOP",
+    "CANDRA",
+    "CAR",
+    "CIM",
+    "CLOCKWISE",
+    "EARTH",
+    "ER",
+    "GIMEL",
+    "IOTIFIED",
+    "KAPH",
+    "KOMI",
+    "MOVEMENT-DIAGONAL",
+    "OUT",
+    "PAWN",
+    "RI",
+    "ROOK",
+    "SADHE",
+    "SAMEKH",
+    "SHAD",
+    "SHADE",
+    "SHESHIG",
+    "SLASH",
+    "SUN",
+    "TAILED",
+    "TAK4",
+    "TSE",
+    "TWELVE",
+    "YE",
+    "ZAIN",
+    "AS",
+    "BIG",
+    "DAMAGED",
+    "DENTISTRY",
+    "EIGHTHS",
+    "EIGHTY",
+    "EMPTY",
+    "EQUIVALENT",
+    "ES",
+    "HAND-CUP",
+    "HETH",
+    "IM",
+    "KASKAL",
+    "KOET",
+    "LAGAR",
+    "LAMEDH",
+    "LEFT-FACING",
+    "MGO",
+    "NASKAPI",
+    "OGONEK",
+    "OM",
+    "RIGHT-FACING",
+    "RISING",
+    "ROC",
+    "ROTATION-FLOORPLANE",
+    "ROTATION-WALLPLANE",
+    "ROUNDED",
+    "SHU",
+    "THROUGH",
+    "TSHA",
+    "U2",
+    "XI",
+    "YODH",
+    "AH",
+    "BIRGA",
+    "BULLET",
+    "CAN",
+    "CHECK",
+    "CLUBS",
+    "DIAMONDS",
+    "EASTERN",
+    "FARSI",
+    "FATHA",
+    "FISH",
+    "GO",
+    "HU",
+    "HUMP",
+    "KASRA",
+    "LAMDA",
+    "NOR",
+    "NUNUZ",
+    "NYI",
+    "RUNNER",
+    "SARA",
+    "SHADDA",
+    "SOFT",
+    "SPADES",
+    "TONAL",
+    "WATER",
+    "WRIST",
+    "XIANGQI",
+    "YERU",
+    "ZAH",
+    "ZAYIN",
+    "ANG",
+    "BACK",
+    "BALLOT",
+    "BINDU",
+    "CAT",
+    "CROSSED",
+    "DASHED",
+    "DZA",
+    "ELEMENT",
+    "ELEVEN",
+    "ET",
+    "HYPHEN",
+    "ICHOS",
+    "INVERSE",
+    "JE",
+    "LOZENGE",
+    "MARTYRIA",
+    "MINNAN",
+    "MON",
+    "MULTIPLE",
+    "MULTIPLICATION",
+    "OVERBAR",
+    "PADA",
+    "PALI",
+    "POVYSHE",
+    "PRECEDES",
+    "PRODUCT",
+    "PU",
+    "RAYS",
+    "RESH",
+    "ROUND",
+    "RTAGS",
+    "SHADOWED",
+    "SHIM",
+    "SPIRAL",
+    "SUCCEEDS",
+    "TWELFTH",
+    "WIDE",
+    "WOODS-CREE",
+    "ZETA",
+    "ZIGZAG",
+    "ALIEN",
+    "AY",
+    "AYIN",
+    "BALL",
+    "BO",
+    "BY",
+    "CO",
+    "DOES",
+    "E2",
+    "EF",
+    "ENG",
+    "EZH",
+    "FE",
+    "FIFTEEN",
+    "FLAG",
+    "HANGZHOU",
+    "JUSTIFIED",
+    "KEHEH",
+    "KHO",
+    "KRYZHEM",
+    "LAL",
+    "LUM",
+    "MADDA",
+    "MEASURED",
+    "MURDA",
+    "NATTILIK",
+    "NUBIAN",
+    "OCLOCK",
+    "OJIBWAY",
+    "PRIME",
+    "SCHWA",
+    "SEMICOLON",
+    "SHA3",
+    "SHAR2",
+    "SNAKE",
+    "START",
+    "TEDUNG",
+    "TELEPHONE",
+    "UNIT",
+    "UR2",
+    "USH",
+    "WESTERN",
+    "Y-CREE",
+    "YANG",
+    "YIG",
+    "ZO",
+    "ZU",
+    "AIRPLANE",
+    "BARRED",
+    "BELT",
+    "BESIDE",
+    "CARET",
+    "CHO",
+    "CLICK",
+    "CLOUD",
+    "CONTAINING",
+    "DAMMA",
+    "EH",
+    "FI",
+    "FIGURE",
+    "FIRE",
+    "FO",
+    "HAA",
+    "HARD",
+    "JO",
+    "KIEVAN",
+    "KIRGHIZ",
+    "KLYUCHEVAYA",
+    "KRYUKOVAYA",
+    "LAK-617",
+    "LEK",
+    "MEN",
+    "NOSE",
+    "OCR",
+    "PAO",
+    "PHO",
+    "QUADRUPLE",
+    "RIBBON",
+    "ROCKET",
+    "SALTIRE",
+    "SHO",
+    "SIMALUNGUN",
+    "SIXTEEN",
+    "SKAMEYTSA",
+    "STATYA",
+    "STICK",
+    "SVARITA",
+    "TENS",
+    "TETH",
+    "TREE",
+    "TSHEG",
+    "UNDER",
+    "UR",
+    "VERTICALLY",
+    "WAVY",
+    "WIDTH",
+    "WIND",
+    "WU",
+    "ZI",
+    "ALPAPRAANA",
+    "AM",
+    "ASH2",
+    "ATTAK",
+    "BEGIN",
+    "BRACKETED",
+    "BUR",
+    "BUT",
+    "CHINESE",
+    "CLEF",
+    "CURRENCY",
+    "DALETH",
+    "DOUBLE-LINE",
+    "DYNAMIC",
+    "DZ",
+    "EI",
+    "EIGHTEEN",
+    "ESH2",
+    "EU",
+    "EYE",
+    "FILLER",
+    "FINGER",
+    "FLEX",
+    "GAD",
+    "GAF",
+    "GE",
+    "HAND-HOOK",
+    "HORA",
+    "KAK",
+    "LAK-648",
+    "LEFT-STEM",
+    "LITH",
+    "LUNATE",
+    "MAHAAPRAANA",
+    "MALE",
+    "MARKER",
+    "NORMAL",
+    "NOTCHED",
+    "PAGE",
+    "PARALLEL",
+    "PREFIXED",
+    "RDEL",
+    "RECTANGLE",
+    "RECYCLING",
+    "SAL",
+    "SALT",
+    "SEGMENTED",
+    "SIMILAR",
+    "TAB",
+    "THESPIAN",
+    "THIRDS",
+    "TONE-2",
+    "TONE-5",
+    "TRAVEL-WALLPLANE",
+    "TWELFTHS",
+    "VO",
+    "VOICED",
+    "YAT",
+    "YOD",
+    "APOSTROPHE",
+    "ASTROLOGICAL",
+    "BAMBOOS",
+    "BARREE",
+    "BLUE",
+    "BUBBLE",
+    "CE",
+    "CENTRED",
+    "CHARACTERS",
+    "CHILLU",
+    "CIEUC",
+    "CONTAINS",
+    "CROSSBAR",
+    "CROSSED-TAIL",
+    "CRYPTOGRAMMIC",
+    "DIGRAM",
+    "DZE",
+    "EMOJI",
+    "ENVELOPE",
+    "FIRST",
+    "FOURTEEN",
+    "FRONT",
+    "FU",
+    "GAL",
+    "GESH2",
+    "HANDS",
+    "HHA",
+    "HORSE",
+    "IEUNG",
+    "IMAGE",
+    "ITERATION",
+    "KAVYKA",
+    "KEY",
+    "LARGEST",
+    "MARKS",
+    "METRICAL",
+    "MONOGRAM",
+    "NEITHER",
+    "NINETEEN",
+    "NYO",
+    "PEE",
+    "PEH",
+    "PINWHEEL",
+    "RACING",
+    "REFORMED",
+    "REPEAT",
+    "RHA",
+    "ROBOT",
+    "SANDHI",
+    "SECOND",
+    "SET",
+    "SEVENTEEN",
+    "SHU2",
+    "SLOAN",
+    "SUIT",
+    "SUKUN",
+    "SURROUND",
+    "TEXT",
+    "THEN",
+    "THIRTEEN",
+    "TONE-3",
+    "UM",
+    "UN",
+    "VI",
+    "VINE",
+    "YIN",
+    "ABKHASIAN",
+    "AD",
+    "AGOGI",
+    "AMPERSAND",
+    "ANO",
+    "AWAY",
+    "BACKHAND",
+    "BUD",
+    "CHIEUCH",
+    "COMPRESSED",
+    "CRESCENTS",
+    "CUM",
+    "DIALECT-P",
+    "DIE",
+    "DIVISION",
+    "DODEKATA",
+    "DRAGON",
+    "ELLIPSIS",
+    "ENDING",
+    "ETH",
+    "EXTENSION",
+    "FISHHOOK",
+    "FRAME-1",
+    "FRAME-2",
+    "GATE",
+    "GOAL",
+    "GREEN",
+    "HAIR",
+    "HAND-CLAW",
+    "HAND-OVAL",
+    "HIEUH",
+    "HUB2",
+    "ICE",
+    "JAPANESE",
+    "JI",
+    "JOT",
+    "KHIEUKH",
+    "KIYEOK",
+    "LENTICULAR",
+    "LHA",
+    "LIMB",
+    "LIPS",
+    "LOCATION",
+    "LOGOGRAM",
+    "LONSUM",
+    "MAHAPRANA",
+    "MFON",
+    "MIEUM",
+    "MOUNTAIN",
+    "MOVEMENT",
+    "NAGA",
+    "NAME",
+    "NIEUN",
+    "NIZKO",
+    "NJE",
+    "NUNAVIK",
+    "OPPOSING",
+    "OU",
+    "OVERLINE",
+    "PAIRED",
+    "PHARYNGEAL",
+    "PHIEUPH",
+    "PIEUP",
+    "PROLATIONE",
+    "QOPH",
+    "RIEUL",
+    "RUPEE",
+    "SASAK",
+    "SECANT",
+    "SEGMENT",
+    "SHEI",
+    "SIMANSIS",
+    "SIOS",
+    "STAFF",
+    "STATERS",
+    "SUBGROUP",
+    "SUBUNIT",
+    "SVETLAYA",
+    "TEMPUS",
+    "TH",
+    "TH-CREE",
+    "THAL",
+    "THIEUTH",
+    "THORN",
+    "THOUSANDS",
+    "TIKEUT",
+    "TOWARDS",
+    "TRIGRAM",
+    "TUAREG",
+    "TUG2",
+    "TURNSTILE",
+    "UK",
+    "VEE",
+    "VRAKHIYA",
+    "WOMAN",
+    "ZAPYATAYA",
+    "ANGLED",
+    "ARKTIKO",
+    "BACKSLASH",
+    "BAN2",
+    "BBA",
+    "BELL",
+    "BHALE",
+    "BOARD",
+    "CHEST",
+    "DAGGER",
+    "DIATONIKI",
+    "DIESIS",
+    "DIGA",
+    "DIM",
+    "DIPLI",
+    "DOLLAR",
+    "DUN3",
+    "EAR",
+    "ENCLOSING",
+    "ENCLOSURE",
+    "EYEBROWS",
+    "FALLING",
+    "FATHATAN",
+    "FEE",
+    "FITA",
+    "FLAT",
+    "FLYING",
+    "FOLDER",
+    "FORWARD-FACING",
+    "GAN",
+    "GEMINATION",
+    "GI4",
+    "GIR2",
+    "GU2",
+    "HAT",
+    "HEEL",
+    "INSERT",
+    "IS",
+    "JOINER",
+    "JU",
+    "KATAKANA-HIRAGANA",
+    "KATO",
+    "KET",
+    "LLLA",
+    "LUGAL",
+    "MALO",
+    "MASH",
+    "MEASURE",
+    "MID",
+    "MILLIONS",
+    "MOUSE",
+    "MOVEMENT-HINGE",
+    "NASALIZED",
+    "NDA",
+    "NGO",
+    "ODD",
+    "OPENING",
+    "ORE",
+    "PLACE",
+    "PLASTICS",
+    "POINTER",
+    "POWERS",
+    "RAIN",
+    "ROOT",
+    "SEE",
+    "SH",
+    "SHARP",
+    "SHIMA",
+    "SHIP",
+    "SHOE",
+    "SHWE",
+    "SPEAKER",
+    "SPEECH",
+    "STANDING",
+    "TAA",
+    "TALENTS",
+    "TALL",
+    "TALLY",
+    "TAM",
+    "TAR",
+    "TCHEH",
+    "TEARDROP-SPOKED",
+    "TEETH",
+    "TENSE",
+    "TET",
+    "THI",
+    "TIR",
+    "TONE-4",
+    "TRAVEL-FLOORPLANE",
+    "TSI",
+    "VOS",
+    "VU",
+    "WITHIN",
+    "WRINKLED",
+    "YFESIS",
+    "-A",
+    "AEN",
+    "AIR",
+    "AITON",
+    "AK",
+    "ANTIMONY",
+    "ANUDATTA",
+    "APPROXIMATELY",
+    "AV",
+    "BABY",
+    "BARLINE",
+    "BEE",
+    "BEND",
+    "BET",
+    "BLACK-FEATHERED",
+    "BOOK",
+    "BOW",
+    "BRIDGE",
+    "BROKEN",
+    "BRUSH",
+    "BURU",
+    "BUTTON",
+    "CHECKER",
+    "CHEEKS",
+    "CLOSING",
+    "CONTACT",
+    "CONTROL",
+    "COPPER",
+    "CORNERS",
+    "CRESCENT",
+    "CUPPED",
+    "DEE",
+    "DIFFERENTIAL",
+    "DIGAMMA",
+    "DIVIDER",
+    "DOG",
+    "DRUM",
+    "DUB",
+    "DVOECHELNAYA",
+    "EAT",
+    "EC",
+    "ECH",
+    "EK",
+    "ELLIPSE",
+    "ENY",
+    "EPIGRAPHIC",
+    "EQUIHOPPER",
+    "ERIN2",
+    "ERROR-BARRED",
+    "EVEN",
+    "EXTREMELY",
+    "FEMALE",
+    "FLICK",
+    "FLOWER",
+    "FROWNING",
+    "GANGIA",
+    "GAP",
+    "GGA",
+    "GIR3",
+    "GLASS",
+    "GREAT",
+    "GROMOPOVODNAYA",
+    "HAL",
+    "HAR",
+    "HIP",
+    "HOLDING",
+    "HOOKED",
+    "HORI",
+    "HOUSE",
+    "IMPERFECTA",
+    "INFINITY",
+    "INPUT",
+    "IOTATED",
+    "IZHE",
+    "IZHITSA",
+    "JEE",
+    "JIHVAMULIYA",
+    "KAI",
+    "KAN",
+    "KEN",
+    "KHEI",
+    "KHOKHLOM",
+    "KISS",
+    "KNIFE",
+    "KOPPA",
+    "KU3",
+    "KUSHU2",
+    "LAK-449",
+    "LEIMMA",
+    "LOGIC",
+    "LOO",
+    "LU3",
+    "MBA",
+    "MBAA",
+    "MID-HEIGHT",
+    "MUSH3",
+    "MYA",
+    "NAA",
+    "NABLA",
+    "NARROW",
+    "NET",
+    "NHA",
+    "NJI",
+    "NUMERATOR",
+    "ORANGE",
+    "OSOKA",
+    "OTTAVA",
+    "PALM",
+    "PARTIAL",
+    "PEAKS",
+    "PEN",
+    "PIRIG",
+    "POSITION",
+    "POWER",
+    "RADI",
+    "RAE",
+    "REPA",
+    "RICE",
+    "RUSSIAN",
+    "SAMPI",
+    "SAN",
+    "SAR",
+    "SCISSORS",
+    "SHARU",
+    "SHI",
+    "SHID",
+    "SHIR",
+    "SINGLE-LINE",
+    "SPLIT",
+    "SQUEEZE",
+    "STILE",
+    "SUBSTITUTION",
+    "SUM",
+    "SUNG",
+    "SWASH",
+    "TATWEEL",
+    "TEE",
+    "TERMINAL",
+    "THAN",
+    "THREE-D",
+    "TIE",
+    "TOO",
+    "TROEZENIAN",
+    "TTEH",
+    "TUR",
+    "UA",
+    "UPADHMANIYA",
+    "URUDA",
+    "VAV",
+    "VOLAPUK",
+    "VRACHY",
+    "VYSOKO",
+    "WEIGHT",
+    "WIGGLY",
+    "WITHOUT",
+    "XE",
+    "YAA",
+    "YEE",
+    "YER",
+    "YN",
+    "ZAPYATOY",
+    "ADEG",
+    "AFRICAN",
+    "ALAYHE",
+    "ALL",
+    "ALLAAHU",
+    "APLI",
+    "AQ",
+    "BAHAR2",
+    "BAL",
+    "BARS",
+    "BBE",
+    "BEEH",
+    "BEHEH",
+    "BIRD",
+    "BLACK-LETTER",
+    "BOMB",
+    "BREATHY",
+    "BUILDING",
+    "BYELORUSSIAN-UKRAINIAN",
+    "CARIK",
+    "CASKET",
+    "CHARIOT",
+    "CHART",
+    "CHRIVI",
+    "CIRCULAR",
+    "CLUSTER-INITIAL",
+    "COMMERCIAL",
+    "COW",
+    "CUBED",
+    "CUP",
+    "CURVING",
+    "DAMMATAN",
+    "DELETE",
+    "DHE",
+    "DISK",
+    "DKAR",
+    "DOACHASHMEE",
+    "DOCUMENT",
+    "DOUBLED",
+    "DYEH",
+    "DZHA",
+    "ELAMITE",
+    "ENN",
+    "EO",
+    "ERA",
+    "EYEGAZE-WALLPLANE",
+    "EZ",
+    "FACING",
+    "FENCE",
+    "FILE",
+    "FITZPATRICK",
+    "FLATTENED",
+    "FLOURISH",
+    "FRANKS",
+    "FREE",
+    "FROG",
+    "GAA",
+    "GEE",
+    "GESHU",
+    "GHUNNA",
+    "GOLUBCHIK",
+    "GORAZDO",
+    "GORGON",
+    "GRINNING",
+    "GTER",
+    "GUEH",
+    "HALF-CIRCLE",
+    "HENG",
+    "HIE",
+    "HOO",
+    "HOT",
+    "IDIM",
+    "IMPERFECTUM",
+    "INTERROBANG",
+    "IR",
+    "IT",
+    "IU",
+    "JIL",
+    "JJA",
+    "JOINED",
+    "KAD3",
+    "KAL",
+    "KANA",
+    "KASRATAN",
+    "KENTIMATA",
+    "KEYBOARD",
+    "KOMBUVA",
+    "KOO",
+    "KOREAN",
+    "KPA",
+    "KSSA",
+    "LAA",
+    "LAME",
+    "LEFT-HAND",
+    "LEFT-TO-RIGHT",
+    "LIGHTNING",
+    "MAP",
+    "MARK-1",
+    "MARK-2",
+    "MECHIK",
+    "MEDAL",
+    "MOBILE",
+    "MONKEY",
+    "MONSTER",
+    "MUCH",
+    "MV",
+    "MYSLITE",
+    "NAR",
+    "NASAL",
+    "NDOLE",
+    "NEPOSTOYANNAYA",
+    "NESTED",
+    "NGGA",
+    "NGOEH",
+    "NJEE",
+    "NN",
+    "NNNA",
+    "NOTES",
+    "NYEH",
+    "OA",
+    "OH",
+    "OI",
+    "ONCOMING",
+    "ORIGINAL",
+    "OVAL",
+    "OVERLAPPING",
+    "OW",
+    "OY",
+    "PAD",
+    "PARAGRAPH",
+    "PARESTIGMENON",
+    "PATH",
+    "PEHEH",
+    "PENTAGON",
+    "PER",
+    "PERCENT",
+    "PHAR",
+    "PII",
+    "PLUTO",
+    "POLE",
+    "PON",
+    "POO",
+    "PRIZNAK",
+    "PWA",
+    "QAR",
+    "QI",
+    "QU",
+    "RAA",
+    "RANGE",
+    "REE",
+    "REPETITION",
+    "RETURN",
+    "RIFLE",
+    "RNOON",
+    "ROTUNDA",
+    "RULER",
+    "SAUCER",
+    "SCREEN",
+    "SELECTOR",
+    "SEMICIRCLE",
+    "SEMIDIRECT",
+    "SHAKING",
+    "SHOULDER",
+    "SII",
+    "SIMPLIFIED",
+    "SMILE",
+    "SOO",
+    "STRESS",
+    "SUBLIMATE",
+    "SUMMATION",
+    "SWIRL",
+    "TALING",
+    "TCHEHEH",
+    "TEHEH",
+    "TELEVISION",
+    "THAA",
+    "THEE",
+    "THESEOS",
+    "TINY",
+    "TITLO",
+    "TONE-1",
+    "TRAFFIC",
+    "TRIDENT",
+    "TTE",
+    "TTEHEH",
+    "TUM",
+    "TURTLE",
+    "TWE",
+    "U-SHAPED",
+    "UC",
+    "UIGHUR",
+    "UMBRELLA",
+    "VEH",
+    "VERTEX",
+    "WAA",
+    "WALK",
+    "WAY",
+    "WOO",
+    "YAE",
+    "YAJURVEDIC",
+    "YELLOW",
+    "YEN",
+    "YOT",
+    "ZEE",
+    "ZEN",
+    "AC",
+    "ACE",
+    "AG",
+    "ALAYHI",
+    "ALTA",
+    "AMAR",
+    "AO",
+    "APPROXIMATE",
+    "AQUA",
+    "ARROW-TAIL",
+    "ARROWHEADS",
+    "ARSEOS",
+    "AS-SALAAM",
+    "ATOMIC",
+    "AW",
+    "BAA",
+    "BAG",
+    "BALAG",
+    "BAN",
+    "BANKNOTE",
+    "BASE",
+    "BEAMED",
+    "BHE",
+    "BLANK",
+    "BONE",
+    "BOTTLE",
+    "BOWTIE",
+    "BULUG",
+    "CAMERA",
+    "CANCELLATION",
+    "CANTILLATION",
+    "CAP",
+    "CENTRALIZATION",
+    "CHAR",
+    "CHEE",
+    "CHIN",
+    "CHROMA",
+    "CHU",
+    "CI",
+    "CIL",
+    "CLUSTER-FINAL",
+    "COMPONENT",
+    "COMPUTER",
+    "CROP",
+    "CROSSHATCH",
+    "CROTCH",
+    "CUATRILLO",
+    "CUSP",
+    "DALET",
+    "DANG",
+    "DDDA",
+    "DEGREE",
+    "DEL",
+    "DELIMITER",
+    "DEVICE",
+    "DEZH",
+    "DIASTOLI",
+    "DIGORGON",
+    "DIN",
+    "DIRECTION",
+    "DJE",
+    "DLA",
+    "DON",
+    "DOWNWARD",
+    "DRAUGHTS",
+    "DREAMY",
+    "DYO",
+    "DZELO",
+    "EG",
+    "EGYPTOLOGICAL",
+    "EIE",
+    "EKFONITIKON",
+    "ELECTRIC",
+    "ENC",
+    "ENERGY",
+    "ENT",
+    "EP",
+    "ERR",
+    "EX",
+    "EXPLOSION",
+    "EXTRA-HIGH",
+    "EXTRA-LOW",
+    "EYEGAZE-FLOORPLANE",
+    "FAA",
+    "FEATHER",
+    "FINGER-POST",
+    "FIXED-FORM",
+    "FLOPPY",
+    "FLORETTE",
+    "FOOD",
+    "FOOT",
+    "FORK",
+    "FORMAT",
+    "FOURTH",
+    "FRAME",
+    "FROWN",
+    "GABA",
+    "GBE",
+    "GHAN",
+    "GLOBE",
+    "GOLD",
+    "GRASS",
+    "GROMNAYA",
+    "GROMOKRYZHEVAYA",
+    "GUR",
+    "HAE",
+    "HAMMER",
+    "HAND-CURLICUE",
+    "HEADED",
+    "HEADSTROKE",
+    "HEAVEN",
+    "HEE",
+    "HELMET",
+    "HOE",
+    "HOURGLASS",
+    "HP",
+    "HV",
+    "HYPHEN-MINUS",
+    "IA",
+    "IDENTICAL",
+    "IH",
+    "IMIN",
+    "INTEGRATION",
+    "IRON",
+    "ISOLATE",
+    "ISOSCELES",
+    "J-SIMPLIFIED",
+    "JACK",
+    "JHAN",
+    "JOIN",
+    "JOKER",
+    "KAA",
+    "KANG",
+    "KAPA",
+    "KENTIMA",
+    "KHAR",
+    "KHI",
+    "KID",
+    "KISSING",
+    "KK",
+    "KLASMA",
+    "KM",
+    "KNUCKLE",
+    "KPO",
+    "KRYZH",
+    "KSI",
+    "KUL",
+    "KUT",
+    "KWAA",
+    "KWE",
+    "KWI",
+    "KYEE",
+    "LAI",
+    "LAMBDA",
+    "LAMED",
+    "LAS",
+    "LEE",
+    "LENGA",
+    "LENGTHENER",
+    "LEZH",
+    "LIP",
+    "LJE",
+    "LOCK",
+    "LONGA",
+    "LOQ",
+    "LOST",
+    "LOTUS",
+    "LOW-FALLING",
+    "MAILBOX",
+    "MARBUTA",
+    "MBE",
+    "MBEE",
+    "MCHU",
+    "MEAT",
+    "MEMBER",
+    "MERCURY",
+    "MICROCOMPUTER",
+    "MIDDLE-WELSH",
+    "MIM",
+    "MIN",
+    "MM",
+    "MONOCULAR",
+    "MUG",
+    "MUSIC",
+    "MWA",
+    "MWI",
+    "NAH",
+    "NASALIZATION",
+    "NDAA",
+    "NDE",
+    "NDU",
+    "NGAA",
+    "NGI",
+    "NIEUN-CIEUC",
+    "NIEUN-HIEUH",
+    "NIGHT",
+    "NIKOLSBURG",
+    "NOW",
+    "NTAP",
+    "NTEUM",
+    "NWA",
+    "NYAM",
+    "NYIS",
+    "OBOLS",
+    "OHM",
+    "OIL",
+    "OK",
+    "ONG",
+    "OOU",
+    "OP",
+    "OT",
+    "OX",
+    "PAA",
+    "PALAUNG",
+    "PAPER",
+    "PAR",
+    "PARENTHESES",
+    "PATAH",
+    "PEDESTAL",
+    "PENCIL",
+    "PENTAGRAM",
+    "PEPET",
+    "PERFECTA",
+    "PHONE",
+    "PIEUP-SIOS",
+    "PIG",
+    "PLA",
+    "POETRY",
+    "POLICE",
+    "POSTAL",
+    "POT",
+    "POUND",
+    "POVODNAYA",
+    "PROSTAYA",
+    "PWI",
+    "QAMATS",
+    "QE",
+    "QO",
+    "QUILL",
+    "QUILT",
+    "RAD",
+    "RAFE",
+    "RAILWAY",
+    "RAM",
+    "RAT",
+    "RECEIVER",
+    "REGULUS",
+    "RELATION",
+    "REN",
+    "RIEUL-HIEUH",
+    "RIEUL-KIYEOK",
+    "RIEUL-MIEUM",
+    "RIEUL-PIEUP",
+    "RIEUL-SIOS",
+    "RIGHT-HAND",
+    "RIGHT-SHADOWED",
+    "RIGHT-TO-LEFT",
+    "RII",
+    "ROLLER",
+    "RUB",
+    "RUDIMENTA",
+    "RUM",
+    "SAA",
+    "SANYAKA",
+    "SATANGA",
+    "SCAN",
+    "SEBATBEIT",
+    "SEQUENTIAL",
+    "SERIFS",
+    "SGAW",
+    "SHEE",
+    "SHHA",
+    "SHII",
+    "SHITA",
+    "SHOGI",
+    "SHORTHAND",
+    "SHORTS",
+    "SHTA",
+    "SIG4",
+    "SILHOUETTE",
+    "SIN",
+    "SIXTEENTHS",
+    "SKEWED",
+    "SLIGHTLY",
+    "SLOZHITIE",
+    "SOUTH-SLAVEY",
+    "SPIDER",
+    "SPIRIT",
+    "SQUARES",
+    "SQUIGGLE",
+    "SS",
+    "SSANGCIEUC",
+    "SSANGKIYEOK",
+    "SSANGPIEUP",
+    "SSANGSIOS",
+    "SSANGTIKEUT",
+    "STRIPED",
+    "SUKU",
+    "SUR",
+    "SUU",
+    "SVASTI",
+    "SWEAT",
+    "TAN",
+    "TESH",
+    "THIN",
+    "THUMBS",
+    "TIGER",
+    "TIKHAYA",
+    "TILTING",
+    "TJE",
+    "TOGETHER",
+    "TONE-6",
+    "TONE-8",
+    "TOOTH",
+    "TOPBAR",
+    "TRESVETLAYA",
+    "TROMIKON",
+    "TS",
+    "TSO",
+    "TSU",
+    "TTHO",
+    "TURN",
+    "TWENTY-FIVE",
+    "UDATTA",
+    "UI",
+    "ULU",
+    "UMUM",
+    "UPTURN",
+    "US",
+    "VANE",
+    "VIN",
+    "WASLA",
+    "WEE",
+    "WHEEL",
+    "WINE",
+    "WOMANS",
+    "WYNN",
+    "XAN",
+    "XYEEM",
+    "YAH",
+    "YERI",
+    "YIDDISH",
+    "YOO",
+    "YOQ",
+    "YUT",
+    "YYA",
+    "ZAL",
+    "ZATA",
+    "ZHAR",
+    "ZHEE",
+    "ZZA",
+    "-KHYIL",
+    "A2",
+    "AELA-PILLA",
+    "ALIF",
+    "ALLO",
+    "ALTERNATIVE",
+    "ANGER",
+    "ANJI",
+    "ANSHE",
+    "ANTENNA",
+    "APOSTROFOS",
+    "AR",
+    "ARISTERA",
+    "AROUND",
+    "ASHGAB",
+    "ASSYRIAN",
+    "AXE",
+    "AYB",
+    "AZU",
+    "BACK-TILTED",
+    "BACKSLANTED",
+    "BALLOON",
+    "BALLOON-SPOKED",
+    "BAMBOO",
+    "BANK",
+    "BAT",
+    "BATH",
+    "BAYANNA",
+    "BEAVER",
+    "BEHIND",
+    "BEI",
+    "BEN",
+    "BILABIAL",
+    "BLACKLETTER",
+    "BOLT",
+    "BOWL",
+    "BRANCH",
+    "BREATH",
+    "BRICK",
+    "BROWN",
+    "BUBBLES",
+    "BUKY",
+    "BUON",
+    "BUS",
+    "BZHI",
+    "CAA",
+    "CAKE",
+    "CALENDAR",
+    "CALL",
+    "CASTLE",
+    "CCHE",
+    "CHASHKA",
+    "CHEVRON",
+    "CHICK",
+    "CHILD",
+    "CHRISTMAS",
+    "CHROA",
+    "CHRONON",
+    "CLIFF",
+    "CLOSE",
+    "CLOTH",
+    "CLOTHES",
+    "CLUB",
+    "CM",
+    "COASTER",
+    "COLD",
+    "COMPLETION",
+    "COMPOSITION",
+    "CONCAVE-SIDED",
+    "CONSTANT",
+    "CONSTRUCTION",
+    "CONTAIN",
+    "CONTOUR",
+    "COO",
+    "COUNCIL",
+    "COVER",
+    "COVERING",
+    "CRAB",
+    "CREDIT",
+    "CROCUS",
+    "CRYING",
+    "CU",
+    "CUBE",
+    "CURRENT",
+    "CUT",
+    "CWA",
+    "CWAA",
+    "CWI",
+    "DAHAL",
+    "DANTAJA",
+    "DAP",
+    "DAYANNA",
+    "DDAHAL",
+    "DDAL",
+    "DDAYANNA",
+    "DDO",
+    "DECIMAL",
+    "DEER",
+    "DENE",
+    "DENTAL",
+    "DEPARTING",
+    "DESCENDING",
+    "DIM2",
+    "DIRECT",
+    "DIVIDE",
+    "DIVIDED",
+    "DJERV",
+    "DJERVI",
+    "DM",
+    "DOBRO",
+    "DRACHMA",
+    "DRY",
+    "DUL",
+    "DUN",
+    "DWE",
+    "DZHE",
+    "DZZE",
+    "EA",
+    "EB",
+    "ELEPHANT",
+    "ENTRY",
+    "EPIDAUREAN",
+    "ETERNITY",
+    "EV",
+    "EW",
+    "EXO",
+    "EYELASHES",
+    "FACTOR",
+    "FAN",
+    "FANEROSIS",
+    "FAX",
+    "FEED",
+    "FENG",
+    "FERMATA",
+    "FIFTH",
+    "FIFTHS",
+    "FINGERED",
+    "FIST",
+    "FLEURON",
+    "FLORAL",
+    "FLUTE",
+    "FLY",
+    "FOM",
+    "FOO",
+    "FOREHEAD",
+    "FORMEE",
+    "FORTY-FIVE",
+    "FRITU",
+    "FRONT-TILTED",
+    "GATHERING",
+    "GAYANNA",
+    "GAYANUKITTA",
+    "GBA",
+    "GBU",
+    "GEAR",
+    "GERESH",
+    "GESHTIN",
+    "GGE",
+    "GHHA",
+    "GHOST",
+    "GIM",
+    "GJE",
+    "GLAGOLI",
+    "GO-KART",
+    "GRACE",
+    "GRASP",
+    "GSUM",
+    "GUM",
+    "HALANTA",
+    "HARKLEAN",
+    "HATAF",
+    "HERMIONIAN",
+    "HERU",
+    "HEXAGON",
+    "HLI",
+    "HNA",
+    "HOLAM",
+    "HON",
+    "HORIZONTALLY",
+    "HUMAN",
+    "HUNDREDS",
+    "HWE",
+    "IB",
+    "IC",
+    "ICON",
+    "INCREASE",
+    "INDUCTOR",
+    "INFORMATION",
+    "ING",
+    "INI",
+    "INSERTION",
+    "INTERLINEAR",
+    "INVISIBLE",
+    "IO",
+    "IQ",
+    "ISH",
+    "ISON",
+    "IY",
+    "JAYANNA",
+    "JEGOGAN",
+    "JEH",
+    "JNYA",
+    "JONA",
+    "JOY",
+    "KA2",
+    "KAD5",
+    "KAKO",
+    "KAPYEOUNMIEUM",
+    "KAPYEOUNPHIEUPH",
+    "KAPYEOUNPIEUP",
+    "KARO",
+    "KATHAKA",
+    "KAY",
+    "KEE",
+    "KEH",
+    "KETTI",
+    "KHE",
+    "KHUEN",
+    "KIN",
+    "KIYEOK-SIOS",
+    "KKA",
+    "KNUCKLES",
+    "KOI",
+    "KORONIS",
+    "KRATIMA",
+    "KUOP",
+    "KW",
+    "KWA",
+    "KXA",
+    "KYA",
+    "LAAM",
+    "LADDER",
+    "LAKH",
+    "LAP",
+    "LAST",
+    "LAZY",
+    "LEADER",
+    "LEATHER",
+    "LEERAEWA",
+    "LEFT-SHADED",
+    "LET",
+    "LETTERS",
+    "LH",
+    "LIMMU",
+    "LINES",
+    "LISH",
+    "LJUDIJE",
+    "LOVE",
+    "LUH",
+    "LUL",
+    "LWA",
+    "LY",
+    "MAA",
+    "MACHINE",
+    "MAEM",
+    "MAEMBA",
+    "MANDAILING",
+    "MAPIQ",
+    "MASK",
+    "MB",
+    "MBI",
+    "MBIT",
+    "MBO",
+    "MBOO",
+    "MBU",
+    "MEDIEVAL",
+    "MEE",
+    "MEEEE",
+    "MEGA",
+    "MES",
+    "MEUT",
+    "MID-LEVEL",
+    "MIEUM-PIEUP",
+    "MIEUM-SIOS",
+    "MILITARY",
+    "MINIMA",
+    "MIRROR",
+    "MIT",
+    "MNAS",
+    "MONOGRAPH",
+    "MONTH",
+    "MOO",
+    "MOOD",
+    "MOUND",
+    "MRACHNAYA",
+    "MULTIMAP",
+    "MULTISET",
+    "MUOY",
+    "MUURDHAJA",
+    "MWAA",
+    "MWE",
+    "N-CREE",
+    "NASHI",
+    "NATURAL",
+    "NDAP",
+    "NDEE",
+    "NDI",
+    "NDO",
+    "NEE",
+    "NEN",
+    "NEWLINE",
+    "NGGE",
+    "NGGEN",
+    "NGGO",
+    "NGGOO",
+    "NGGU",
+    "NGOM",
+    "NIEUN-SIOS",
+    "NIEUN-TIKEUT",
+    "NIM",
+    "NIN",
+    "NJAEM",
+    "NJU",
+    "NO-BREAK",
+    "NOO",
+    "NRA",
+    "NSHA",
+    "NSHUT",
+    "NTEE",
+    "NUNG",
+    "NV",
+    "NWAA",
+    "NYE",
+    "NYET",
+    "NYU",
+    "NZA",
+    "OFFICE",
+    "OLDER",
+    "ONU",
+    "OPEN-HEADED",
+    "OPPOSITION",
+    "OQ",
+    "OUTER",
+    "OVERLAID",
+    "OVERLAP",
+    "OXEIA",
+    "PALOCHKA",
+    "PAN",
+    "PANSIOS",
+    "PANTI",
+    "PARAGRAPHOS",
+    "PARAKALESMA",
+    "PARAKLITIKI",
+    "PART",
+    "PARTY",
+    "PAW",
+    "PEACE",
+    "PEDAL",
+    "PERFECTUM",
+    "PERPENDICULAR",
+    "PERSONAL",
+    "PEUX",
+    "PHE",
+    "PICK",
+    "PICTURE",
+    "PIEUP-CIEUC",
+    "PIEUP-SIOS-TIKEUT",
+    "PIEUP-TIKEUT",
+    "PING",
+    "PIP",
+    "PIWR",
+    "PLACEHOLDER",
+    "PLUTA",
+    "PODCHASHIE",
+    "POKOJI",
+    "POLISH",
+    "POMMEE",
+    "POP",
+    "POST",
+    "POUTING",
+    "PRAM",
+    "PRAM-BEI",
+    "PRAM-BUON",
+    "PRAM-MUOY",
+    "PRAM-PII",
+    "PREGNANT",
+    "PRISHTHAMATRA",
+    "PTHAHA",
+    "PUAE",
+    "PURPLE",
+    "PUSHING",
+    "PUSHPIKA",
+    "PUSHPIN",
+    "PUT",
+    "PWAA",
+    "PWE",
+    "QAA",
+    "QAAF",
+    "QOF",
+    "QOO",
+    "QUO",
+    "QUU",
+    "RAMS",
+    "RAP",
+    "RATHA",
+    "RECTANGULAR",
+    "REDUPLICATION",
+    "REPEATED",
+    "REPHA",
+    "REUX",
+    "RIEUL-PHIEUPH",
+    "RIEUL-THIEUTH",
+    "RIEUL-TIKEUT",
+    "RIGHT-SHADED",
+    "RINGS",
+    "RISH",
+    "RITSI",
+    "ROCK",
+    "ROG",
+    "ROSETTE",
+    "RREH",
+    "RUNNING",
+    "SAFETY",
+    "SAM",
+    "SAT",
+    "SAW",
+    "SAYANNA",
+    "SCHEMA",
+    "SCHOOL",
+    "SEAL",
+    "SEGOL",
+    "SEMI-VOICED",
+    "SEMIBREVIS",
+    "SEMICIRCULAR",
+    "SEMIMINIMA",
+    "SEUX",
+    "SHADED",
+    "SHCHA",
+    "SHEEP",
+    "SHESH",
+    "SHIELD",
+    "SHOO",
+    "SHOQ",
+    "SHWA",
+    "SILK",
+    "SIMA",
+    "SIOS-CIEUC",
+    "SIOS-KIYEOK",
+    "SIOS-PIEUP",
+    "SIOS-TIKEUT",
+    "SIXTH",
+    "SIXTY-FOURTH",
+    "SKLIRON",
+    "SKULL",
+    "SLEEPING",
+    "SLOVO",
+    "SNOUT",
+    "SNOW",
+    "SNOWFLAKE",
+    "SNOWMAN",
+    "SOT",
+    "SOURCE",
+    "SPACING",
+    "SPHERICAL",
+    "SPOON",
+    "SQUID",
+    "SSANGIEUNG",
+    "SSANGNIEUN",
+    "SSI",
+    "SSO",
+    "SSU",
+    "ST",
+    "STEP",
+    "STIGMA",
+    "STOCK",
+    "STONE",
+    "STOPITSA",
+    "STRANNO",
+    "STRATUM",
+    "STRIKE",
+    "STROKES",
+    "STUCK-OUT",
+    "SUA",
+    "SUBLINEAR",
+    "SULFUR",
+    "SUPRALINEAR",
+    "SURFACE",
+    "SUSPENSION",
+    "SV",
+    "SWA",
+    "SWAA",
+    "SYMBOLS",
+    "SYNAGMA",
+    "TAALUJA",
+    "TABLE",
+    "TAE",
+    "TARGET",
+    "TAV",
+    "TEARS",
+    "TENT",
+    "TENTH",
+    "TESSERA",
+    "TETARTOS",
+    "THII",
+    "THIRTY-FIVE",
+    "THIRTY-SECOND",
+    "THOO",
+    "THOUGHT",
+    "THREAD",
+    "THU",
+    "THUNDER",
+    "THWA",
+    "THWAA",
+    "TIGHT",
+    "TIL",
+    "TIME",
+    "TLA",
+    "TLE",
+    "TLHA",
+    "TLI",
+    "TLO",
+    "TLU",
+    "TONE-7",
+    "TOP-LIGHTED",
+    "TOUCH",
+    "TRA",
+    "TRAIN",
+    "TRANSPOSITION",
+    "TREND",
+    "TRUCK",
+    "TRUE",
+    "TSADI",
+    "TSATA",
+    "TT",
+    "TTHAA",
+    "TTO",
+    "TVRIDO",
+    "TWA",
+    "TWAA",
+    "TWENTY-TWO",
+    "TWO-CIRCLE",
+    "UH",
+    "UKRAINIAN",
+    "UKU",
+    "UUE",
+    "VAREIA",
+    "VEDE",
+    "VERSE",
+    "VEW",
+    "VY",
+    "WAE",
+    "WAU",
+    "WAVES",
+    "WAVING",
+    "WEO",
+    "WHEAT",
+    "WHEELCHAIR",
+    "WHOLE",
+    "WON",
+    "WOOD",
+    "WORK",
+    "WRINKLES",
+    "WUE",
+    "XEH",
+    "XW",
+    "YAGH",
+    "YAJ",
+    "YAK",
+    "YATI",
+    "YAZH",
+    "YEO",
+    "YEORINHIEUH",
+    "YESIEUNG",
+    "YESTU",
+    "YIT",
+    "YIWN",
+    "YUQ",
+    "YWA",
+    "YWAA",
+    "YY",
+    "ZEMLJA",
+    "ZHI",
+    "ZHIVETE",
+    "ZHO",
+    "ZHU",
+    "ZQAPHA",
+    "ZU5",
+    "-DZUD",
+    "-UM",
+    "A001",
+    "A002",
+    "A003",
+    "A004",
+    "A005",
+    "A006",
+    "A007",
+    "A008",
+    "A009",
+    "A010",
+    "A011",
+    "A012",
+    "A013",
+    "A014",
+    "A015",
+    "A016",
+    "A017",
+    "A018",
+    "A019",
+    "A020",
+    "A021",
+    "A022",
+    "A023",
+    "A024",
+    "A025",
+    "A026",
+    "A027",
+    "A028",
+    "A029",
+    "A030",
+    "A031",
+    "A032",
+    "A033",
+    "A034",
+    "A035",
+    "A036",
+    "A037",
+    "A038",
+    "A039",
+    "A040",
+    "A041",
+    "A042",
+    "A043",
+    "A044",
+    "A045",
+    "A045A",
+    "A046",
+    "A047",
+    "A048",
+    "A049",
+    "A050",
+    "A051",
+    "A052",
+    "A053",
+    "A054",
+    "A055",
+    "A056",
+    "A057",
+    "A058",
+    "A059",
+    "A060",
+    "A061",
+    "A062",
+    "A063",
+    "A064",
+    "A065",
+    "A066",
+    "A067",
+    "A068",
+    "A069",
+    "A070",
+    "A301",
+    "A302",
+    "A303",
+    "A304",
+    "A305",
+    "A306",
+    "A307",
+    "A308",
+    "A309A",
+    "A310",
+    "A311",
+    "A312",
+    "A314",
+    "A315",
+    "A316",
+    "A317",
+    "A318",
+    "A319",
+    "A320",
+    "A321",
+    "A322",
+    "A323",
+    "A324",
+    "A325",
+    "A326",
+    "A327",
+    "A328",
+    "A329",
+    "A330",
+    "A331",
+    "A333",
+    "A334",
+    "A335",
+    "A336",
+    "A337",
+    "A338",
+    "A339",
+    "A340",
+    "A341",
+    "A342",
+    "A343",
+    "A344",
+    "A345",
+    "A346",
+    "A347",
+    "A348",
+    "A349",
+    "A350",
+    "A351",
+    "A352",
+    "A353",
+    "A354",
+    "A355",
+    "A356",
+    "A357",
+    "A358",
+    "A359",
+    "A360",
+    "A361",
+    "A362",
+    "A363",
+    "A364",
+    "A365",
+    "A366",
+    "A367",
+    "A368",
+    "A369",
+    "A370",
+    "A371",
+    "A501",
+    "A502",
+    "A503",
+    "A504",
+    "A505",
+    "A506",
+    "A508",
+    "A509",
+    "A510",
+    "A511",
+    "A512",
+    "A513",
+    "A515",
+    "A516",
+    "A520",
+    "A521",
+    "A523",
+    "A524",
+    "A525",
+    "A526",
+    "A527",
+    "A528",
+    "A529",
+    "A530",
+    "AAI",
+    "AAN",
+    "AAY",
+    "ACADEMY",
+    "ACCOUNT",
+    "ACKNOWLEDGE",
+    "ACTIVATE",
+    "ACTUALLY",
+    "ADULT",
+    "AEB",
+    "AEDA-PILLA",
+    "AEE",
+    "AEG",
+    "AEK",
+    "AESC",
+    "AET",
+    "AEY",
+    "AKARA",
+    "AKHMIMIC",
+    "ALAPH",
+    "ALEMBIC",
+    "ALEUT",
+    "ALFA",
+    "ALIGNED",
+    "ALLAAH",
+    "ALLI",
+    "ALLIANCE",
+    "ALVEOLAR",
+    "AMB",
+    "ANCHOR",
+    "ANCORA",
+    "ANGLICANA",
+    "ANTARGOMUKHA",
+    "ANTIMONIATE",
+    "ANTIRESTRICTION",
+    "AP",
+    "APESO",
+    "APLOUN",
+    "APODERMA",
+    "APOSTROFOI",
+    "APPLE",
+    "ARAD",
+    "ARAEA",
+    "ARCH",
+    "ARDHAVISARGA",
+    "ARGI",
+    "ARGOSYNTHETON",
+    "ARPEGGIATO",
+    "ARTS",
+    "ASCENDING",
+    "ASHES",
+    "ASPIRATION",
+    "ASYMPTOTICALLY",
+    "ATHAPASCAN",
+    "ATIU",
+    "AUE",
+    "AUTOMOBILE",
+    "AWX",
+    "AX",
+    "AYAH",
+    "AYN",
+    "AZ",
+    "B001",
+    "B002",
+    "B003",
+    "B004",
+    "B005",
+    "B006",
+    "B007",
+    "B008",
+    "B009",
+    "BADGER",
+    "BAGS",
+    "BALUDA",
+    "BASELINE",
+    "BASHKIR",
+    "BASSINET",
+    "BATHTUB",
+    "BATTERY",
+    "BBI",
+    "BBO",
+    "BBU",
+    "BBUT",
+    "BCAD",
+    "BEAM",
+    "BEAMS",
+    "BEAR",
+    "BEAT",
+    "BEER",
+    "BEETLE",
+    "BENZENE",
+    "BERBER",
+    "BEVERAGE",
+    "BHETH",
+    "BHO",
+    "BICYCLIST",
+    "BINARY",
+    "BINDI",
+    "BINDING",
+    "BINOCULAR",
+    "BIT",
+    "BITING",
+    "BLADE",
+    "BLENDED",
+    "BLINK",
+    "BLOCK-2",
+    "BLOCK-3",
+    "BLOCK-4",
+    "BLOCK-5",
+    "BLOCK-6",
+    "BLOCK-7",
+    "BLOOD",
+    "BLOSSOM",
+    "BLOWING",
+    "BOAR",
+    "BOAT",
+    "BODY",
+    "BOHAIRIC",
+    "BOO",
+    "BOOKMARK",
+    "BOOMERANG",
+    "BOOSTER",
+    "BOOT",
+    "BOTH",
+    "BOUQUET",
+    "BRA",
+    "BRDA",
+    "BREAD",
+    "BREVIS",
+    "BRIGHTNESS",
+    "BROAD",
+    "BUFFER",
+    "BUILDINGS",
+    "BULLHORN",
+    "BWA",
+    "BWEE",
+    "BWI",
+    "BZUNG",
+    "CADUCEUS",
+    "CAKRA",
+    "CALYA",
+    "CAMEL",
+    "CANCEL",
+    "CANNON",
+    "CAPACITOR",
+    "CARPENTRY",
+    "CART",
+    "CAUDATE",
+    "CAULDRON",
+    "CAYANNA",
+    "CAYN",
+    "CC",
+    "CD",
+    "CECAK",
+    "CEE",
+    "CENT",
+    "CENTRELINE",
+    "CER-WA",
+    "CH",
+    "CHAA",
+    "CHAIR",
+    "CHAT",
+    "CHEH",
+    "CHELYUSTKA",
+    "CHOREVMA",
+    "CHRONOU",
+    "CHURCH",
+    "CIP",
+    "CIRCLING",
+    "CITY",
+    "CITYSCAPE",
+    "CLINKING",
+    "CLUSTER",
+    "COAT",
+    "CON",
+    "CONGRATULATION",
+    "CONJOINER",
+    "CONJOINING",
+    "CONSECUTIVE",
+    "CONVERGING",
+    "COOKIE",
+    "COPRODUCT",
+    "COPYRIGHT",
+    "CREAM",
+    "CRICKET",
+    "CROSSBONES",
+    "CROWN",
+    "CULTIVATION",
+    "CUOP",
+    "CURLICUE",
+    "CWE",
+    "CWII",
+    "CWO",
+    "CWOO",
+    "CY",
+    "CYA",
+    "CYP",
+    "CYT",
+    "DAA",
+    "DAALI",
+    "DALATH",
+    "DALDA",
+    "DAMP",
+    "DAP-BEI",
+    "DAP-BUON",
+    "DAP-MUOY",
+    "DAP-PII",
+    "DAP-PRAM",
+    "DAR",
+    "DARA3",
+    "DARK",
+    "DARKENING",
+    "DAT",
+    "DATE",
+    "DB",
+    "DCHE",
+    "DDAA",
+    "DDDHA",
+    "DDE",
+    "DDHAA",
+    "DDHO",
+    "DDI",
+    "DDU",
+    "DDUR",
+    "DEATH",
+    "DECORATION",
+    "DECREASE",
+    "DEI",
+    "DEMESTVENNY",
+    "DENOMINATOR",
+    "DENSE",
+    "DEPARTURE",
+    "DESERT",
+    "DESKTOP",
+    "DEXIA",
+    "DEYTEROS",
+    "DH",
+    "DHO",
+    "DIB",
+    "DIFONIAS",
+    "DIGRAMMOS",
+    "DIMINUTION-1",
+    "DIODE",
+    "DIRECTIONAL",
+    "DIRECTLY",
+    "DISAPPOINTED",
+    "DISIMOU",
+    "DIVINATION",
+    "DIZZY",
+    "DJA",
+    "DOI",
+    "DOLLS",
+    "DONG",
+    "DOO",
+    "DOOR",
+    "DOUBLE-LINED",
+    "DOVE",
+    "DOWN-FACING",
+    "DRACHMAS",
+    "DRAM",
+    "DRESS",
+    "DRINK",
+    "DRIVE",
+    "DROP-SHADOWED",
+    "DROPLET",
+    "DUNG",
+    "DV",
+    "DVOECHELNOPOVODNAYA",
+    "DZEE",
+    "DZI",
+    "DZJE",
+    "DZO",
+    "DZU",
+    "DZWE",
+    "DZZHE",
+    "EAGLE",
+    "EARS",
+    "EARTHLY",
+    "ECS",
+    "ED",
+    "EGG",
+    "EGY",
+    "EHCHA",
+    "EHKA",
+    "EHPA",
+    "EHTA",
+    "EHTSA",
+    "EIGHTIETHS",
+    "EIN",
+    "EJ",
+    "ELIFI",
+    "ELY",
+    "EMBEDDING",
+    "EMP",
+    "EMPHASIS",
+    "ENCLOSURES",
+    "ENGINE",
+    "ENT-SHAPED",
+    "ENTER",
+    "ENTERPRISE",
+    "EPENTHETIC",
+    "ERASE",
+    "EREN",
+    "ERIS",
+    "ESASA",
+    "ESCAPE",
+    "ESHE3",
+    "ESZ",
+    "ETERON",
+    "ETY",
+    "EURO",
+    "EUROPEAN",
+    "EWE",
+    "EYN",
+    "EZS",
+    "FAMILY",
+    "FATHER",
+    "FEI",
+    "FEMININE",
+    "FIELD",
+    "FILLED",
+    "FILM",
+    "FINANCIAL",
+    "FLAILING",
+    "FLAME",
+    "FLATBREAD",
+    "FLEXUS",
+    "FLIP",
+    "FLUTTERING",
+    "FOLLOWING",
+    "FONT",
+    "FOOTBALL",
+    "FOOTNOTE",
+    "FORKED",
+    "FORTIETH",
+    "FORTUNE",
+    "FOUNTAIN",
+    "FOX",
+    "FRAME-3",
+    "FRENCH",
+    "FRETBOARD",
+    "FUE",
+    "FUNCTION",
+    "FUR",
+    "FUSA",
+    "FWA",
+    "FWAA",
+    "FWEE",
+    "FWI",
+    "GAETTA-PILLA",
+    "GAH",
+    "GAMAL",
+    "GAME",
+    "GAPPED",
+    "GARSHUNI",
+    "GAY",
+    "GBEE",
+    "GBI",
+    "GBO",
+    "GBOO",
+    "GCIG",
+    "GENERAL",
+    "GENIKI",
+    "GEOMETRICALLY",
+    "GEP",
+    "GERSHAYIM",
+    "GESTURE",
+    "GGI",
+    "GGO",
+    "GGOP",
+    "GGU",
+    "GGUO",
+    "GHAA",
+    "GHAD",
+    "GHEUAE",
+    "GHO",
+    "GIBBOUS",
+    "GLISSANDO",
+    "GNYIS",
+    "GORGI",
+    "GORTHMIKON",
+    "GOT",
+    "GRAM",
+    "GREATER",
+    "GREGORIAN",
+    "GROUND",
+    "GROUP",
+    "GRU",
+    "GUARD",
+    "GUG",
+    "GUL",
+    "GUR7",
+    "GV",
+    "GWA",
+    "GWE",
+    "GWEE",
+    "GWI",
+    "GYAS",
+    "GYON",
+    "HAN",
+    "HANDLE",
+    "HASANTA",
+    "HATE",
+    "HAVE",
+    "HBASA",
+    "HEADSTONE",
+    "HEART-SHAPED",
+    "HEI",
+    "HEN",
+    "HET",
+    "HETA",
+    "HG",
+    "HHAA",
+    "HHWA",
+    "HIGH-REVERSED-9",
+    "HIGH-SPEED",
+    "HII",
+    "HIN",
+    "HINGE",
+    "HIRIQ",
+    "HIT",
+    "HIYO",
+    "HLA",
+    "HMO",
+    "HOCKEY",
+    "HOLE",
+    "HOLLOW",
+    "HOLO",
+    "HOMOTHETIC",
+    "HORNS",
+    "HORR",
+    "HOTEL",
+    "HTA",
+    "HUGGING",
+    "HUN",
+    "HWAIR",
+    "HXIT",
+    "HXOP",
+    "HXUO",
+    "HYA",
+    "HYPHENATION",
+    "HZ",
+    "IAN",
+    "IAUDA",
+    "ICHIMATOS",
+    "ID",
+    "IDENTIFICATION",
+    "IDEOGRAPH-4E09",
+    "IDEOGRAPH-4E8C",
+    "IDEOGRAPH-6253",
+    "IEUNG-KIYEOK",
+    "IG",
+    "IJ",
+    "IJE",
+    "IKARA",
+    "IKIR",
+    "IL",
+    "ILIMMU",
+    "INHERENT",
+    "INHIBIT",
+    "INNER",
+    "INTERIOR",
+    "INTERLACED",
+    "INTERPOLATION",
+    "INTERSECTING",
+    "IP",
+    "IS-PILLA",
+    "ISSHAR",
+    "IX",
+    "IYEK",
+    "JAA",
+    "JADE",
+    "JAR",
+    "JAW",
+    "JAWS",
+    "JHEH",
+    "JIIM",
+    "JJI",
+    "JJIE",
+    "JJO",
+    "JJU",
+    "JJUT",
+    "JJY",
+    "JOO",
+    "JWA",
+    "JY",
+    "KAAF",
+    "KAB",
+    "KABA",
+    "KAD2",
+    "KAH",
+    "KAP",
+    "KAPYEOUNRIEUL",
+    "KAPYEOUNSSANGPIEUP",
+    "KASHMIRI",
+    "KAYANNA",
+    "KAZAKH",
+    "KEEPING",
+    "KEMPLI",
+    "KEMPUL",
+    "KESH2",
+    "KEUM",
+    "KEUX",
+    "KEYCAP",
+    "KG",
+    "KHAA",
+    "KHAI",
+    "KHAKASSIAN",
+    "KHANDA",
+    "KHANG",
+    "KHAPH",
+    "KHHA",
+    "KHHO",
+    "KHMU",
+    "KILLER",
+    "KINNA",
+    "KIP",
+    "KIT",
+    "KJE",
+    "KLYUCH",
+    "KLYUCHENEPOSTOYANNAYA",
+    "KNIGHT-BISHOP",
+    "KNIGHT-QUEEN",
+    "KNIGHT-ROOK",
+    "KOK",
+    "KON",
+    "KONTEVMA",
+    "KORANIC",
+    "KOTO",
+    "KP",
+    "KPE",
+    "KPEE",
+    "KPI",
+    "KPOO",
+    "KPU",
+    "KRYUK",
+    "KU4",
+    "KUN",
+    "KVA",
+    "KWEE",
+    "KWII",
+    "KWO",
+    "KWOO",
+    "L-SHAPED",
+    "LABIAL",
+    "LABOR",
+    "LAE",
+    "LAK-050",
+    "LAK-079",
+    "LAK-081",
+    "LAKE",
+    "LAMP",
+    "LAN",
+    "LANE",
+    "LANES",
+    "LANTANG",
+    "LARGER",
+    "LATERAL",
+    "LATINATE",
+    "LAULA",
+    "LAW",
+    "LAYANNA",
+    "LCE",
+    "LEANING",
+    "LEEEE",
+    "LEGETOS",
+    "LELET",
+    "LENIS",
+    "LESH",
+    "LESSER",
+    "LEU",
+    "LEVEL",
+    "LHAA",
+    "LHI",
+    "LHO",
+    "LID",
+    "LIE",
+    "LING",
+    "LINK",
+    "LIQUID",
+    "LIRA",
+    "LITTER",
+    "LIWN",
+    "LJ",
+    "LOCOMOTIVE",
+    "LOGOTYPE",
+    "LOM",
+    "LOOT",
+    "LOT",
+    "LOW-9",
+    "LOWERED",
+    "LS",
+    "LUGGAGE",
+    "LV",
+    "LWAA",
+    "LWE",
+    "LWI",
+    "LWII",
+    "LWO",
+    "LWOO",
+    "LYR",
+    "LZ",
+    "M001",
+    "M002",
+    "M003",
+    "M004",
+    "M005",
+    "M006",
+    "M007",
+    "M008",
+    "M009",
+    "M010",
+    "M011",
+    "M012",
+    "M013",
+    "M014",
+    "M015",
+    "M016",
+    "M017",
+    "M018",
+    "M019",
+    "M020",
+    "M021",
+    "M022",
+    "M023",
+    "M024",
+    "M025",
+    "M026",
+    "M027",
+    "M028",
+    "M029",
+    "M030",
+    "M031",
+    "M032",
+    "M033",
+    "M034",
+    "M035",
+    "M036",
+    "M037",
+    "M038",
+    "M039",
+    "M040",
+    "M041",
+    "M042",
+    "M043",
+    "M044",
+    "MADDAH",
+    "MAE",
+    "MAESI",
+    "MAGNIFYING",
+    "MAH",
+    "MALAKON",
+    "MANDARIN",
+    "MAQ",
+    "MAR",
+    "MARK-3",
+    "MASH2",
+    "MAY",
+    "MBANYI",
+    "MBEN",
+    "MBEUM",
+    "MC",
+    "MCHAN",
+    "MDUN",
+    "MECHANICAL",
+    "MEEJ",
+    "MEET",
+    "MELON",
+    "MEM-QOPH",
+    "MERGE",
+    "MERKHA",
+    "MESH",
+    "MESO",
+    "METAL",
+    "MEUN",
+    "MGBA",
+    "MHA",
+    "MICROPHONE",
+    "MIDLINE",
+    "MIEE",
+    "MIEUM-KIYEOK",
+    "MIEUM-PANSIOS",
+    "MIG",
+    "MIIM",
+    "MILLE",
+    "MLA",
+    "MODIFIER-2",
+    "MODIFIER-3",
+    "MODIFIER-4",
+    "MODIFIER-5",
+    "MODIFIER-6",
+    "MONEY",
+    "MONOFONIAS",
+    "MONOGRAMMOS",
+    "MOP",
+    "MORTAR",
+    "MOTHER",
+    "MOTOR",
+    "MOVE",
+    "MPA",
+    "MUAE",
+    "MUAN",
+    "MUCAAD",
+    "MUSHROOM",
+    "MVOP",
+    "MW",
+    "MWEE",
+    "MWII",
+    "MWO",
+    "MWOO",
+    "MY",
+    "NA-2",
+    "NA2",
+    "NAASIKYAYA",
+    "NAE",
+    "NAKAARA",
+    "NAM",
+    "NAM2",
+    "NANA",
+    "NAQ",
+    "NATIONAL",
+    "NAYANNA",
+    "NBIE",
+    "NDAM",
+    "NDOO",
+    "NE-2",
+    "NECK",
+    "NEGATED",
+    "NENOE",
+    "NEPTUNE",
+    "NEQUDAA",
+    "NEST",
+    "NEWSPAPER",
+    "NGAS",
+    "NGE",
+    "NGEN",
+    "NGGAAM",
+    "NGGEE",
+    "NGGEUAET",
+    "NGGI",
+    "NGGUOQ",
+    "NGHA",
+    "NGKA",
+    "NGKUE",
+    "NGKWAEN",
+    "NGON",
+    "NGOP",
+    "NGOU",
+    "NIB",
+    "NIEUN-KIYEOK",
+    "NIEUN-PANSIOS",
+    "NII",
+    "NINE-LIKE",
+    "NJ",
+    "NJA",
+    "NJAEMLI",
+    "NJAM",
+    "NJEUX",
+    "NJO",
+    "NJOO",
+    "NJUAE",
+    "NJUEQ",
+    "NKAARAE",
+    "NODE",
+    "NORTHERN",
+    "NOTCH",
+    "NOTEBOOK",
+    "NOZHKA",
+    "NSHIEE",
+    "NSIEE",
+    "NTAA",
+    "NTOG",
+    "NTUU",
+    "NU11",
+    "NUAE",
+    "NUENG",
+    "NULL",
+    "NUT",
+    "NUTILLU",
+    "NWE",
+    "NWI",
+    "NWII",
+    "NWO",
+    "NWOO",
+    "NY",
+    "NYAA",
+    "NYEE",
+    "NYHA",
+    "NYIP",
+    "NYIT",
+    "NYOP",
+    "NZUP",
+    "OAY",
+    "OCTAGON",
+    "OEE",
+    "OEK",
+    "OIN",
+    "OKARA",
+    "OKTO",
+    "OLIGON",
+    "OLIVE",
+    "OMET",
+    "OMISSION",
+    "ONE-HUNDRED-AND-SIXTIETH",
+    "ONN",
+    "OOE",
+    "OPEN-CIRCUIT-OUTPUT",
+    "OPEN-O",
+    "OPTICAL",
+    "ORDINAL",
+    "ORNATE",
+    "ORTHOGONAL",
+    "OS",
+    "OTU",
+    "OUNCE",
+    "OV",
+    "OVERLONG",
+    "OVERRIDE",
+    "OVERSTRUCK",
+    "OYRANISMA",
+    "PAA-PILLA",
+    "PAAM",
+    "PAGES",
+    "PAKPAK",
+    "PAMADA",
+    "PAMPHYLIAN",
+    "PAMUDPOD",
+    "PANGOLAT",
+    "PARA",
+    "PARALLELOGRAM",
+    "PARAPHRASE",
+    "PAREREN",
+    "PARTNERSHIP",
+    "PASANGAN",
+    "PATTY",
+    "PAYANNA",
+    "PAYEROK",
+    "PEDESTRIAN",
+    "PELASTON",
+    "PENNANT",
+    "PENTASEME",
+    "PEOPLE",
+    "PEPPER",
+    "PERCUSSIVE",
+    "PEREVODKA",
+    "PERNIN",
+    "PESH2",
+    "PESO",
+    "PETALLED",
+    "PHAA",
+    "PHIEUPH-PIEUP",
+    "PHILIPPINE",
+    "PHRASE",
+    "PHUR",
+    "PIASMA",
+    "PIE",
+    "PIEUP-CHIEUCH",
+    "PIEUP-HIEUH",
+    "PIEUP-KIYEOK",
+    "PIEUP-PHIEUPH",
+    "PIEUP-SIOS-KIYEOK",
+    "PIEUP-THIEUTH",
+    "PILCROW",
+    "PIN",
+    "PIPAEMBA",
+    "PIPAEMGBIEE",
+    "PISELEH",
+    "PIT",
+    "PITCHFORK",
+    "PLAGIOS",
+    "PLANCK",
+    "PLANE",
+    "PLOPHU",
+    "PNEUMATA",
+    "POLI",
+    "POLLU",
+    "PORRECTUS",
+    "POSSESSION",
+    "POTATO",
+    "PRECEDE",
+    "PRECEDING",
+    "PREPONDERANCE",
+    "PRINT",
+    "PRINTER",
+    "PROLONGED",
+    "PROPELLER",
+    "PROPORTION",
+    "PROTOS",
+    "PSIFISTON",
+    "PUE",
+    "PUP",
+    "PWEE",
+    "PWII",
+    "PWOO",
+    "PYT",
+    "QAI",
+    "QATAN",
+    "QII",
+    "QITSA",
+    "QOT",
+    "QUA",
+    "QUE",
+    "QUI",
+    "QUINDICESIMA",
+    "QUOTE",
+    "QUV",
+    "QWE",
+    "QWEE",
+    "QWI",
+    "R-CREE",
+    "RA-2",
+    "RABBIT",
+    "RACQUET",
+    "RADIO",
+    "RAISING",
+    "RANA",
+    "RAY",
+    "RBASA",
+    "RDO",
+    "REACH",
+    "READING",
+    "RECORD",
+    "RECTILINEAR",
+    "RELAXED",
+    "RELIEVED",
+    "REPLACEMENT",
+    "RERENGGAN",
+    "RESISTOR",
+    "RESOURCE",
+    "RESPONSE",
+    "RESTRICTED",
+    "REU",
+    "REVOLVING",
+    "RGYA",
+    "RGYAN",
+    "RGYINGS",
+    "RIEE",
+    "RIEUL-KAPYEOUNPIEUP",
+    "RIEUL-KHIEUKH",
+    "RIEUL-KIYEOK-SIOS",
+    "RIEUL-NIEUN",
+    "RIEUL-PANSIOS",
+    "RIEUL-PIEUP-SIOS",
+    "RIEUL-SSANGKIYEOK",
+    "RIEUL-YEORINHIEUH",
+    "RIKRIK",
+    "RIPPLE",
+    "RJE",
+    "RNAM",
+    "RNYING",
+    "ROLLING",
+    "ROMANIAN",
+    "ROO",
+    "ROTATIONS",
+    "RRE",
+    "RRO",
+    "RUMAI",
+    "RWA",
+    "RWAA",
+    "RY",
+    "S-SHAPED",
+    "SA-2",
+    "SADE",
+    "SAFHA",
+    "SAH",
+    "SAKTA",
+    "SALLA",
+    "SALLALLAHOU",
+    "SALTILLO",
+    "SAMBA",
+    "SANDAL",
+    "SANSKRIT",
+    "SAP",
+    "SARI",
+    "SATELLITE",
+    "SAY",
+    "SCANDICUS",
+    "SCOOTER",
+    "SCOTS",
+    "SEGNO",
+    "SEH",
+    "SEISMA",
+    "SELF",
+    "SEMISOFT",
+    "SEMIVOWEL",
+    "SEMKATH",
+    "SERIF",
+    "SESAME",
+    "SEXTANT-1",
+    "SEXTANT-12",
+    "SEXTANT-123",
+    "SEXTANT-1234",
+    "SEXTANT-12345",
+    "SEXTANT-12346",
+    "SEXTANT-1235",
+    "SEXTANT-12356",
+    "SEXTANT-1236",
+    "SEXTANT-124",
+    "SEXTANT-1245",
+    "SEXTANT-12456",
+    "SEXTANT-1246",
+    "SEXTANT-125",
+    "SEXTANT-1256",
+    "SEXTANT-126",
+    "SEXTANT-13",
+    "SEXTANT-134",
+    "SEXTANT-1345",
+    "SEXTANT-13456",
+    "SEXTANT-1346",
+    "SEXTANT-1356",
+    "SEXTANT-136",
+    "SEXTANT-14",
+    "SEXTANT-145",
+    "SEXTANT-1456",
+    "SEXTANT-146",
+    "SEXTANT-15",
+    "SEXTANT-156",
+    "SEXTANT-16",
+    "SEXTANT-2",
+    "SEXTANT-23",
+    "SEXTANT-234",
+    "SEXTANT-2345",
+    "SEXTANT-23456",
+    "SEXTANT-2346",
+    "SEXTANT-235",
+    "SEXTANT-2356",
+    "SEXTANT-236",
+    "SEXTANT-24",
+    "SEXTANT-245",
+    "SEXTANT-2456",
+    "SEXTANT-25",
+    "SEXTANT-256",
+    "SEXTANT-26",
+    "SEXTANT-3",
+    "SEXTANT-34",
+    "SEXTANT-345",
+    "SEXTANT-3456",
+    "SEXTANT-346",
+    "SEXTANT-35",
+    "SEXTANT-356",
+    "SEXTANT-36",
+    "SEXTANT-4",
+    "SEXTANT-45",
+    "SEXTANT-456",
+    "SEXTANT-46",
+    "SEXTANT-5",
+    "SEXTANT-56",
+    "SEXTANT-6",
+    "SEXTULA",
+    "SGAB",
+    "SHA6",
+    "SHAA",
+    "SHANG",
+    "SHAPES",
+    "SHAPING",
+    "SHAT",
+    "SHELF",
+    "SHET",
+    "SHEUX",
+    "SHIFT",
+    "SHIQ",
+    "SHOG",
+    "SHOP",
+    "SHOPPING",
+    "SHOY",
+    "SHRI",
+    "SHRII",
+    "SHRIMP",
+    "SHTAPIC",
+    "SHUR",
+    "SHWAA",
+    "SHWI",
+    "SHWII",
+    "SHWO",
+    "SHWOO",
+    "SHY",
+    "SIG",
+    "SIGMOID",
+    "SIGNS",
+    "SIK2",
+    "SIKI",
+    "SILA3",
+    "SIMULTANEOUS",
+    "SINDHI",
+    "SINNYIIYHE",
+    "SIOS-CHIEUCH",
+    "SIOS-HIEUH",
+    "SIOS-MIEUM",
+    "SIOS-NIEUN",
+    "SIOS-RIEUL",
+    "SIOS-THIEUTH",
+    "SIXTHS",
+    "SIZE",
+    "SJE",
+    "SKATE",
+    "SKI",
+    "SLICE",
+    "SLOPING",
+    "SLOW",
+    "SLUR",
+    "SMALLER",
+    "SMASH",
+    "SMOKING",
+    "SOAP",
+    "SOCIETY",
+    "SOF",
+    "SOLDIER",
+    "SOLID",
+    "SOM",
+    "SOROCHYA",
+    "SOU",
+    "SOUTHERN",
+    "SPADE",
+    "SPARKLE",
+    "SPARSE",
+    "SPEAR",
+    "SPECIAL",
+    "SPECKLE",
+    "SPIDERY",
+    "SPIRITUS",
+    "SPLAYED",
+    "SQUAT",
+    "SQUIRREL",
+    "SREDNE",
+    "SSANGHIEUH",
+    "SSANGRIEUL",
+    "SSE",
+    "SSUU",
+    "STAMPED",
+    "STANDARD",
+    "STAVROS",
+    "STEAM",
+    "STEPPING",
+    "STICKING",
+    "STORE",
+    "STRONG",
+    "STUDY",
+    "SUBJOINER",
+    "SUBSTITUTE",
+    "SUCCEED",
+    "SUCKED",
+    "SUE",
+    "SUHUR",
+    "SUNGLASSES",
+    "SUNRISE",
+    "SUPER",
+    "SUPERVISE",
+    "SURANG",
+    "SW",
+    "SWAPPING",
+    "SWE",
+    "SWEET",
+    "SWI",
+    "SWII",
+    "SWO",
+    "SWOO",
+    "SYMBOL-1",
+    "SYMBOL-11",
+    "SYMBOL-12",
+    "SYMBOL-13",
+    "SYMBOL-14",
+    "SYMBOL-17",
+    "SYMBOL-18",
+    "SYMBOL-19",
+    "SYMBOL-2",
+    "SYMBOL-23",
+    "SYMBOL-24",
+    "SYMBOL-4",
+    "SYMBOL-5",
+    "SYMBOL-50",
+    "SYMBOL-51",
+    "SYMBOL-52",
+    "SYMBOL-53",
+    "SYMBOL-54",
+    "SYMBOL-7",
+    "SYMBOL-8",
+    "SYMMETRIC",
+    "TA-2",
+    "TA-ROL",
+    "TABULATION",
+    "TAMING",
+    "TANGENT",
+    "TANK",
+    "TANNED",
+    "TAPE",
+    "TAT",
+    "TAXI",
+    "TAYANNA",
+    "TC",
+    "TCHE",
+    "TE-2",
+    "TELEIA",
+    "TELISHA",
+    "TELOUS",
+    "TENNIS",
+    "TENTACLES",
+    "TETARTIMORION",
+    "TETRASEME",
+    "TETRASIMOU",
+    "TEUAEN",
+    "TEUAEQ",
+    "THEMA",
+    "THEMATISMOS",
+    "THERE",
+    "THERMOMETER",
+    "THETHE",
+    "THONG",
+    "THREE-QUARTER",
+    "TIGHTLY-CLOSED",
+    "TIKEUT-CIEUC",
+    "TIKEUT-KIYEOK",
+    "TIKEUT-PIEUP",
+    "TIKEUT-RIEUL",
+    "TIKEUT-SIOS",
+    "TIKHY",
+    "TIRONIAN",
+    "TIT",
+    "TITA",
+    "TIWN",
+    "TLHE",
+    "TLHI",
+    "TLHO",
+    "TLV",
+    "TOQ",
+    "TORCULUS",
+    "TORSO-WALLPLANE",
+    "TOT",
+    "TOTAL",
+    "TOUCHING",
+    "TOUCHTONE",
+    "TRACK",
+    "TRADITIONAL",
+    "TRAM",
+    "TRANSISTOR",
+    "TRANSMISSION",
+    "TRAY",
+    "TREMOLO-1",
+    "TREMOLO-2",
+    "TREMOLO-3",
+    "TRESILLO",
+    "TRIANGLE-ROUND",
+    "TRICOLON",
+    "TRIGRAMMOS",
+    "TRISEME",
+    "TRISIMOU",
+    "TRITIMORION",
+    "TROKUTASTI",
+    "TROPICAL",
+    "TRUNK",
+    "TSEE",
+    "TSHE",
+    "TSHES",
+    "TSHOOK",
+    "TSSE",
+    "TSV",
+    "TSWE",
+    "TTAA",
+    "TTAYANNA",
+    "TTHE",
+    "TTHI",
+    "TTI",
+    "TTTA",
+    "TTUDDAAG",
+    "TUK",
+    "TUOT",
+    "TUP",
+    "TURBAN",
+    "TWENTIETH",
+    "TWENTIETHS",
+    "TWENTY-EIGHTH",
+    "TWENTY-FOUR",
+    "TWENTY-ONE",
+    "TWENTY-THREE",
+    "TWI",
+    "TWII",
+    "TWO-WAY",
+    "TWOO",
+    "TXWV",
+    "TYPE-3",
+    "TYPE-4",
+    "TYPE-5",
+    "TYPE-6",
+    "TZ",
+    "UB",
+    "UBADAMA",
+    "UEX",
+    "UEZ",
+    "UKARA",
+    "UNDERLINE",
+    "UNDERTIE",
+    "UNIVERSAL",
+    "UNK",
+    "UNN",
+    "UO",
+    "UP-FACING",
+    "UPWARD",
+    "URANUS",
+    "USED",
+    "UY",
+    "UZ3",
+    "VAA",
+    "VAMAGOMUKHA",
+    "VEND",
+    "VEP",
+    "VICTORY",
+    "VIDA",
+    "VIDEO",
+    "VIETNAMESE",
+    "VINEGAR",
+    "VISIGOTHIC",
+    "VIYO",
+    "VOICING",
+    "VOO",
+    "VOU",
+    "VUR",
+    "WAITING",
+    "WALLED",
+    "WANING",
+    "WAP",
+    "WASALLAM",
+    "WATCH",
+    "WAW-AYIN-RESH",
+    "WAX",
+    "WAXING",
+    "WC",
+    "WEARY",
+    "WEDGE-TAILED",
+    "WEI",
+    "WHALE",
+    "WII",
+    "WINDOW",
+    "WINDU",
+    "WINKING",
+    "WOLOSO",
+    "WOOL",
+    "WRENCH",
+    "WRITING",
+    "WULU",
+    "WUO",
+    "WV",
+    "XO",
+    "XVA",
+    "XYA",
+    "XYE",
+    "YA-YO",
+    "YAKH",
+    "YAM",
+    "YAMAKKAN",
+    "YAN",
+    "YAP",
+    "YAQ",
+    "YAR",
+    "YAWN",
+    "YAY",
+    "YAZ",
+    "YEAR",
+    "YESIEUNG-PANSIOS",
+    "YESIEUNG-SIOS",
+    "YFEN",
+    "YHE",
+    "YIE",
+    "YO-I",
+    "YO-YA",
+    "YO-YAE",
+    "YOGH",
+    "YPOKRISIS",
+    "YR",
+    "YU-I",
+    "YU-YE",
+    "YU-YEO",
+    "YUDH",
+    "YUJ",
+    "YUM",
+    "YUOP",
+    "YUWOQ",
+    "YV",
+    "YWE",
+    "YWI",
+    "YWII",
+    "YWO",
+    "YWOO",
+    "ZA7",
+    "ZAA",
+    "ZADERZHKA",
+    "ZAKRYTAYA",
+    "ZAQEF",
+    "ZAYN",
+    "ZEMLYA",
+    "ZHWE",
+    "ZIB",
+    "ZIDA",
+    "ZIZ2",
+    "ZJE",
+    "ZLA",
+    "ZLAMA",
+    "ZOO",
+    "ZOT",
+    "ZUP",
+    "ZUR",
+    "ZY",
+    "ZZE",
+    "ZZI",
+    "ZZIET",
+    "ZZO",
+    "ZZU",
+    "-CHAL",
+    "-CHAR",
+    "-KHYUD",
+    "-PHRU",
+    "A-1",
+    "A-2",
+    "A-3",
+    "A-EU",
+    "A-O",
+    "A-U",
+    "A-WO",
+    "A005A",
+    "A006A",
+    "A006B",
+    "A010A",
+    "A014A",
+    "A017A",
+    "A026A",
+    "A028B",
+    "A032A",
+    "A039A",
+    "A040A",
+    "A041A",
+    "A042A",
+    "A043A",
+    "A046A",
+    "A046B",
+    "A066A",
+    "A066B",
+    "A066C",
+    "A071",
+    "A072",
+    "A073",
+    "A074",
+    "A075",
+    "A076",
+    "A077",
+    "A078",
+    "A079",
+    "A080",
+    "A081",
+    "A082",
+    "A083",
+    "A084",
+    "A085",
+    "A086",
+    "A087",
+    "A088",
+    "A089",
+    "A090",
+    "A091",
+    "A092",
+    "A093",
+    "A094",
+    "A095",
+    "A096",
+    "A097",
+    "A097A",
+    "A098",
+    "A098A",
+    "A099",
+    "A100",
+    "A100-102",
+    "A100A",
+    "A101",
+    "A101A",
+    "A102",
+    "A102A",
+    "A103",
+    "A104",
+    "A104A",
+    "A104B",
+    "A104C",
+    "A105",
+    "A105A",
+    "A105B",
+    "A106",
+    "A107",
+    "A107A",
+    "A107B",
+    "A107C",
+    "A108",
+    "A109",
+    "A110",
+    "A110A",
+    "A110B",
+    "A111",
+    "A112",
+    "A113",
+    "A114",
+    "A115",
+    "A115A",
+    "A116",
+    "A117",
+    "A118",
+    "A119",
+    "A120",
+    "A120B",
+    "A121",
+    "A122",
+    "A123",
+    "A124",
+    "A125",
+    "A125A",
+    "A126",
+    "A127",
+    "A128",
+    "A129",
+    "A130",
+    "A131",
+    "A131C",
+    "A132",
+    "A133",
+    "A134",
+    "A135",
+    "A135A",
+    "A136",
+    "A137",
+    "A138",
+    "A139",
+    "A140",
+    "A141",
+    "A142",
+    "A143",
+    "A144",
+    "A145",
+    "A146",
+    "A147",
+    "A148",
+    "A149",
+    "A150",
+    "A151",
+    "A152",
+    "A153",
+    "A154",
+    "A155",
+    "A156",
+    "A157",
+    "A158",
+    "A159",
+    "A160",
+    "A161",
+    "A162",
+    "A163",
+    "A164",
+    "A165",
+    "A166",
+    "A167",
+    "A168",
+    "A169",
+    "A170",
+    "A171",
+    "A172",
+    "A173",
+    "A174",
+    "A175",
+    "A176",
+    "A177",
+    "A178",
+    "A179",
+    "A180",
+    "A181",
+    "A182",
+    "A183",
+    "A184",
+    "A185",
+    "A186",
+    "A187",
+    "A188",
+    "A189",
+    "A190",
+    "A191",
+    "A192",
+    "A193",
+    "A194",
+    "A195",
+    "A196",
+    "A197",
+    "A198",
+    "A199",
+    "A200",
+    "A201",
+    "A202",
+    "A202A",
+    "A202B",
+    "A203",
+    "A204",
+    "A205",
+    "A206",
+    "A207",
+    "A207A",
+    "A208",
+    "A209",
+    "A209A",
+    "A210",
+    "A211",
+    "A212",
+    "A213",
+    "A214",
+    "A215",
+    "A215A",
+    "A216",
+    "A216A",
+    "A217",
+    "A218",
+    "A219",
+    "A220",
+    "A221",
+    "A222",
+    "A223",
+    "A224",
+    "A225",
+    "A226",
+    "A227",
+    "A227A",
+    "A228",
+    "A229",
+    "A230",
+    "A231",
+    "A232",
+    "A233",
+    "A234",
+    "A235",
+    "A236",
+    "A237",
+    "A238",
+    "A239",
+    "A240",
+    "A241",
+    "A242",
+    "A243",
+    "A244",
+    "A245",
+    "A246",
+    "A247",
+    "A248",
+    "A249",
+    "A250",
+    "A251",
+    "A252",
+    "A253",
+    "A254",
+    "A255",
+    "A256",
+    "A257",
+    "A258",
+    "A259",
+    "A260",
+    "A261",
+    "A262",
+    "A263",
+    "A264",
+    "A265",
+    "A266",
+    "A267",
+    "A267A",
+    "A268",
+    "A269",
+    "A270",
+    "A271",
+    "A272",
+    "A273",
+    "A274",
+    "A275",
+    "A276",
+    "A277",
+    "A278",
+    "A279",
+    "A280",
+    "A281",
+    "A282",
+    "A283",
+    "A284",
+    "A285",
+    "A286",
+    "A287",
+    "A288",
+    "A289",
+    "A289A",
+    "A290",
+    "A291",
+    "A292",
+    "A293",
+    "A294",
+    "A294A",
+    "A295",
+    "A296",
+    "A297",
+    "A298",
+    "A299",
+    "A299A",
+    "A3",
+    "A300",
+    "A309",
+    "A309B",
+    "A309C",
+    "A313",
+    "A313A",
+    "A313B",
+    "A313C",
+    "A329A",
+    "A332",
+    "A332A",
+    "A332B",
+    "A332C",
+    "A336A",
+    "A336B",
+    "A336C",
+    "A359A",
+    "A364A",
+    "A368A",
+    "A371A",
+    "A372",
+    "A373",
+    "A374",
+    "A375",
+    "A376",
+    "A377",
+    "A378",
+    "A379",
+    "A380",
+    "A381",
+    "A381A",
+    "A382",
+    "A383",
+    "A383A",
+    "A384",
+    "A385",
+    "A386",
+    "A386A",
+    "A387",
+    "A388",
+    "A389",
+    "A390",
+    "A391",
+    "A392",
+    "A393",
+    "A394",
+    "A395",
+    "A396",
+    "A397",
+    "A398",
+    "A399",
+    "A400",
+    "A400-VAS",
+    "A401",
+    "A401-VAS",
+    "A402",
+    "A402-VAS",
+    "A403",
+    "A403-VAS",
+    "A404",
+    "A404-VAS",
+    "A405",
+    "A405-VAS",
+    "A406",
+    "A406-VAS",
+    "A407",
+    "A407-VAS",
+    "A408",
+    "A408-VAS",
+    "A409",
+    "A409-VAS",
+    "A410",
+    "A410-VAS",
+    "A410A",
+    "A411",
+    "A411-VAS",
+    "A412",
+    "A412-VAS",
+    "A413",
+    "A413-VAS",
+    "A414",
+    "A414-VAS",
+    "A415",
+    "A415-VAS",
+    "A416",
+    "A416-VAS",
+    "A417",
+    "A417-VAS",
+    "A418",
+    "A418-VAS",
+    "A419",
+    "A420",
+    "A421",
+    "A422",
+    "A423",
+    "A424",
+    "A425",
+    "A426",
+    "A427",
+    "A428",
+    "A429",
+    "A430",
+    "A431",
+    "A432",
+    "A433",
+    "A434",
+    "A435",
+    "A436",
+    "A437",
+    "A438",
+    "A439",
+    "A440",
+    "A441",
+    "A442",
+    "A443",
+    "A444",
+    "A445",
+    "A446",
+    "A447",
+    "A448",
+    "A449",
+    "A450",
+    "A450A",
+    "A451",
+    "A452",
+    "A453",
+    "A454",
+    "A455",
+    "A456",
+    "A457",
+    "A457A",
+    "A458",
+    "A459",
+    "A460",
+    "A461",
+    "A462",
+    "A463",
+    "A464",
+    "A465",
+    "A466",
+    "A467",
+    "A468",
+    "A469",
+    "A470",
+    "A471",
+    "A472",
+    "A473",
+    "A474",
+    "A475",
+    "A476",
+    "A477",
+    "A478",
+    "A479",
+    "A480",
+    "A481",
+    "A482",
+    "A483",
+    "A484",
+    "A485",
+    "A486",
+    "A487",
+    "A488",
+    "A489",
+    "A490",
+    "A491",
+    "A492",
+    "A493",
+    "A494",
+    "A495",
+    "A496",
+    "A497",
+    "A507",
+    "A514",
+    "A517",
+    "A518",
+    "A519",
+    "A522",
+    "A531",
+    "A532",
+    "A534",
+    "A535",
+    "A536",
+    "A537",
+    "A538",
+    "A539",
+    "A540",
+    "A541",
+    "A542",
+    "A545",
+    "A547",
+    "A548",
+    "A549",
+    "A550",
+    "A551",
+    "A552",
+    "A553",
+    "A554",
+    "A555",
+    "A556",
+    "A557",
+    "A559",
+    "A563",
+    "A564",
+    "A565",
+    "A566",
+    "A568",
+    "A569",
+    "A570",
+    "A571",
+    "A572",
+    "A573",
+    "A574",
+    "A575",
+    "A576",
+    "A577",
+    "A578",
+    "A579",
+    "A580",
+    "A581",
+    "A582",
+    "A583",
+    "A584",
+    "A585",
+    "A586",
+    "A587",
+    "A588",
+    "A589",
+    "A591",
+    "A592",
+    "A594",
+    "A595",
+    "A596",
+    "A598",
+    "A600",
+    "A601",
+    "A602",
+    "A603",
+    "A604",
+    "A606",
+    "A608",
+    "A609",
+    "A610",
+    "A611",
+    "A612",
+    "A613",
+    "A614",
+    "A615",
+    "A616",
+    "A617",
+    "A618",
+    "A619",
+    "A620",
+    "A621",
+    "A622",
+    "A623",
+    "A624",
+    "A626",
+    "A627",
+    "A628",
+    "A629",
+    "A634",
+    "A637",
+    "A638",
+    "A640",
+    "A642",
+    "A643",
+    "A644",
+    "A645",
+    "A646",
+    "A648",
+    "A649",
+    "A651",
+    "A652",
+    "A653",
+    "A654",
+    "A655",
+    "A656",
+    "A657",
+    "A658",
+    "A659",
+    "A660",
+    "A661",
+    "A662",
+    "A663",
+    "A664",
+    "A701",
+    "A702",
+    "A703",
+    "A704",
+    "A705",
+    "A706",
+    "A707",
+    "A708",
+    "A709",
+    "A709-2",
+    "A709-3",
+    "A709-4",
+    "A709-6",
+    "A710",
+    "A711",
+    "A712",
+    "A713",
+    "A714",
+    "A715",
+    "A717",
+    "A726",
+    "A732",
+    "A800",
+    "A801",
+    "A802",
+    "A803",
+    "A804",
+    "A805",
+    "A806",
+    "A807",
+    "AA001",
+    "AA002",
+    "AA003",
+    "AA004",
+    "AA005",
+    "AA006",
+    "AA007",
+    "AA007A",
+    "AA007B",
+    "AA008",
+    "AA009",
+    "AA010",
+    "AA011",
+    "AA012",
+    "AA013",
+    "AA014",
+    "AA015",
+    "AA016",
+    "AA017",
+    "AA018",
+    "AA019",
+    "AA020",
+    "AA021",
+    "AA022",
+    "AA023",
+    "AA024",
+    "AA025",
+    "AA026",
+    "AA027",
+    "AA028",
+    "AA029",
+    "AA030",
+    "AA031",
+    "AA032",
+    "AABAAFILI",
+    "AAJ",
+    "AAK",
+    "AAL",
+    "AAM",
+    "AANG",
+    "AAO",
+    "AARU",
+    "AAU",
+    "AAW",
+    "AAYANNA",
+    "AAYIN",
+    "AAZHAAKKU",
+    "AB001",
+    "AB002",
+    "AB003",
+    "AB004",
+    "AB005",
+    "AB006",
+    "AB007",
+    "AB008",
+    "AB009",
+    "AB010",
+    "AB011",
+    "AB013",
+    "AB016",
+    "AB017",
+    "AB020",
+    "AB021",
+    "AB021F",
+    "AB021M",
+    "AB022",
+    "AB022F",
+    "AB022M",
+    "AB023",
+    "AB023M",
+    "AB024",
+    "AB026",
+    "AB027",
+    "AB028",
+    "AB029",
+    "AB030",
+    "AB031",
+    "AB034",
+    "AB037",
+    "AB038",
+    "AB039",
+    "AB040",
+    "AB041",
+    "AB044",
+    "AB045",
+    "AB046",
+    "AB047",
+    "AB048",
+    "AB049",
+    "AB050",
+    "AB051",
+    "AB053",
+    "AB054",
+    "AB055",
+    "AB056",
+    "AB057",
+    "AB058",
+    "AB059",
+    "AB060",
+    "AB061",
+    "AB065",
+    "AB066",
+    "AB067",
+    "AB069",
+    "AB070",
+    "AB073",
+    "AB074",
+    "AB076",
+    "AB077",
+    "AB078",
+    "AB079",
+    "AB080",
+    "AB081",
+    "AB082",
+    "AB085",
+    "AB086",
+    "AB087",
+    "AB118",
+    "AB120",
+    "AB122",
+    "AB123",
+    "AB131A",
+    "AB131B",
+    "AB164",
+    "AB171",
+    "AB180",
+    "AB188",
+    "AB191",
+    "ABACUS",
+    "ABAFILI",
+    "ABB",
+    "ABUNDANCE",
+    "ABYSMAL",
+    "ACCENT-STACCATO",
+    "ACCEPT",
+    "ACCOMMODATION",
+    "ACCORDION",
+    "ACCUMULATION",
+    "ACUTE-GRAVE-ACUTE",
+    "ACUTE-MACRON",
+    "ADAK",
+    "ADDAK",
+    "ADDRESS",
+    "ADDRESSED",
+    "ADHESIVE",
+    "ADI",
+    "ADMETOS",
+    "ADMISSION",
+    "ADO",
+    "ADVANCE",
+    "ADVANTAGE",
+    "AED",
+    "AEEYANNA",
+    "AEL",
+    "AENG",
+    "AER",
+    "AERIAL",
+    "AES",
+    "AESCULAPIUS",
+    "AEYANNA",
+    "AFFRICATION",
+    "AFGHANI",
+    "AFOREMENTIONED",
+    "AFSAAQ",
+    "AFTER",
+    "AGAIN",
+    "AGAINST",
+    "AGE",
+    "AGGRAVATED",
+    "AGGRAVATION",
+    "AGUNG",
+    "AHAD",
+    "AHAGGAR",
+    "AHANG",
+    "AHH",
+    "AHSA",
+    "AHSDA",
+    "AID",
+    "AIHVUS",
+    "AIKARA",
+    "AILM",
+    "AINN",
+    "AINU",
+    "AIVA",
+    "AIVILIK",
+    "AIYANNA",
+    "AJ",
+    "AKBAR",
+    "AKSA",
+    "AKTIESELSKAB",
+    "AL-JUZ",
+    "AL-LAKUNA",
+    "ALAF",
+    "ALAN",
+    "ALARM",
+    "ALAYHAA",
+    "ALAYHIM",
+    "ALAYHIMAA",
+    "ALAYNAA",
+    "ALF",
+    "ALGIZ",
+    "ALIFU",
+    "ALKALI",
+    "ALKALI-2",
+    "ALLAH",
+    "ALLAHOU",
+    "ALPA",
+    "ALPAPRANA",
+    "ALT",
+    "ALTERNATION",
+    "ALUM",
+    "AMALGAM",
+    "AMALGAMATION",
+    "AMBA",
+    "AMBULANCE",
+    "AMERICAN",
+    "AMERICAS",
+    "AMOUNT",
+    "AMPHORA",
+    "AMPS",
+    "AMULET",
+    "AN-NISF",
+    "ANAP",
+    "ANATOMICAL",
+    "ANATRICHISMA",
+    "ANDAP",
+    "ANGED",
+    "ANGEL",
+    "ANGKA",
+    "ANGKHANKHU",
+    "ANGRY",
+    "ANGSTROM",
+    "ANGUISHED",
+    "ANGULAR",
+    "ANH",
+    "ANHAA",
+    "ANHU",
+    "ANHUM",
+    "ANHUMAA",
+    "ANHUNNA",
+    "ANIMAL",
+    "ANKH",
+    "ANN",
+    "ANNAAU",
+    "ANNUITY",
+    "ANPEA",
+    "ANSUZ",
+    "ANT",
+    "ANTICLOCKWISE-ROTATED",
+    "ANTIFONIA",
+    "ANTIKENOKYLISMA",
+    "ANTIKENOMA",
+    "ANTIMONY-2",
+    "ANUNASIKA",
+    "ANUSVARAYA",
+    "ANY",
+    "AOR",
+    "AOU",
+    "APAATO",
+    "APART",
+    "APERTURE",
+    "APIN",
+    "APODEXIA",
+    "APOLLON",
+    "APOTHEMA",
+    "APOTHES",
+    "APPHO",
+    "APPLICATION",
+    "APPROACH",
+    "APPROACHES",
+    "APRIL",
+    "APUN",
+    "AQUAFORTIS",
+    "AQUARIUS",
+    "AR-RAHEEM",
+    "AR-RAHMAN",
+    "AR-RUB",
+    "ARAEA-A",
+    "ARAEA-E",
+    "ARAEA-EO",
+    "ARAEA-I",
+    "ARAEA-U",
+    "ARAEAE",
+    "ARDHACANDRA",
+    "ARE",
+    "AREPA",
+    "ARGON",
+    "ARGOTERI",
+    "ARIES",
+    "ARKAANU",
+    "ARKAB",
+    "ARLAUG",
+    "ARMOUR",
+    "ARMS",
+    "ARMY",
+    "AROUND-PROFILE",
+    "AROURA",
+    "AROUSING",
+    "ARRAY",
+    "ARRIVE",
+    "ARRIVING",
+    "ARROWHEAD-SHAPED",
+    "ARSENIC",
+    "ARTA",
+    "ARTABE",
+    "ARTICULATED",
+    "ARTIST",
+    "ARUHUA",
+    "AS-SAJDA",
+    "AS-SALAATU",
+    "ASAL2",
+    "ASAT",
+    "ASCENT",
+    "ASCIA",
+    "ASH3",
+    "ASH9",
+    "ASIA-AUSTRALIA",
+    "ASPER",
+    "ASPIRATED",
+    "ASSALLAM",
+    "ASSERTION",
+    "ASTERISCUS",
+    "ASTERISKS",
+    "ASTERISM",
+    "ASTONISHED",
+    "ASTRAEA",
+    "ASTRONOMICAL",
+    "ASYURA",
+    "ASZ",
+    "ATH-THALATHA",
+    "ATHARVAVEDIC",
+    "ATHLETIC",
+    "ATIKRAMA",
+    "ATIYA",
+    "ATMAAU",
+    "ATNAH",
+    "ATOM",
+    "ATT",
+    "ATTACHING",
+    "ATTENTION",
+    "ATTHACAN",
+    "AUBERGINE",
+    "AUGMENTATION",
+    "AUGUST",
+    "AUNN",
+    "AURAMAZDAA",
+    "AURAMAZDAA-2",
+    "AURAMAZDAAHA",
+    "AURIPIGMENT",
+    "AUSTRAL",
+    "AUTO",
+    "AUTOMATED",
+    "AUTUMN",
+    "AUYANNA",
+    "AVA",
+    "AVAKRAHASANYA",
+    "AVERAGE",
+    "AVOCADO",
+    "AWC",
+    "AWE",
+    "AWQ",
+    "AWZ",
+    "AYANNA",
+    "AYER",
+    "AYIN-DALETH",
+    "AZZA",
+    "B005A",
+    "B010",
+    "B011",
+    "B012",
+    "B013",
+    "B014",
+    "B015",
+    "B016",
+    "B017",
+    "B018",
+    "B019",
+    "B020",
+    "B021",
+    "B022",
+    "B023",
+    "B024",
+    "B025",
+    "B026",
+    "B027",
+    "B028",
+    "B029",
+    "B030",
+    "B031",
+    "B032",
+    "B033",
+    "B034",
+    "B036",
+    "B037",
+    "B038",
+    "B039",
+    "B040",
+    "B041",
+    "B042",
+    "B043",
+    "B044",
+    "B045",
+    "B046",
+    "B047",
+    "B048",
+    "B049",
+    "B050",
+    "B051",
+    "B052",
+    "B053",
+    "B054",
+    "B055",
+    "B056",
+    "B057",
+    "B058",
+    "B059",
+    "B060",
+    "B061",
+    "B062",
+    "B063",
+    "B064",
+    "B065",
+    "B066",
+    "B067",
+    "B068",
+    "B069",
+    "B070",
+    "B071",
+    "B072",
+    "B073",
+    "B074",
+    "B075",
+    "B076",
+    "B077",
+    "B078",
+    "B079",
+    "B080",
+    "B081",
+    "B082",
+    "B083",
+    "B085",
+    "B086",
+    "B087",
+    "B089",
+    "B090",
+    "B091",
+    "B100",
+    "B102",
+    "B104",
+    "B105",
+    "B105F",
+    "B105M",
+    "B106F",
+    "B106M",
+    "B107F",
+    "B107M",
+    "B108F",
+    "B108M",
+    "B109F",
+    "B109M",
+    "B120",
+    "B121",
+    "B122",
+    "B123",
+    "B125",
+    "B127",
+    "B128",
+    "B130",
+    "B131",
+    "B132",
+    "B133",
+    "B135",
+    "B140",
+    "B141",
+    "B142",
+    "B145",
+    "B146",
+    "B150",
+    "B151",
+    "B152",
+    "B153",
+    "B154",
+    "B155",
+    "B156",
+    "B157",
+    "B158",
+    "B159",
+    "B160",
+    "B161",
+    "B162",
+    "B163",
+    "B164",
+    "B165",
+    "B166",
+    "B167",
+    "B168",
+    "B169",
+    "B170",
+    "B171",
+    "B172",
+    "B173",
+    "B174",
+    "B176",
+    "B177",
+    "B178",
+    "B179",
+    "B180",
+    "B181",
+    "B182",
+    "B183",
+    "B184",
+    "B185",
+    "B189",
+    "B190",
+    "B191",
+    "B200",
+    "B201",
+    "B202",
+    "B203",
+    "B204",
+    "B205",
+    "B206",
+    "B207",
+    "B208",
+    "B209",
+    "B210",
+    "B211",
+    "B212",
+    "B213",
+    "B214",
+    "B215",
+    "B216",
+    "B217",
+    "B218",
+    "B219",
+    "B220",
+    "B221",
+    "B222",
+    "B225",
+    "B226",
+    "B227",
+    "B228",
+    "B229",
+    "B230",
+    "B231",
+    "B232",
+    "B233",
+    "B234",
+    "B236",
+    "B240",
+    "B241",
+    "B242",
+    "B243",
+    "B245",
+    "B246",
+    "B247",
+    "B248",
+    "B249",
+    "B250",
+    "B251",
+    "B252",
+    "B253",
+    "B254",
+    "B255",
+    "B256",
+    "B257",
+    "B258",
+    "B259",
+    "B305",
+    "BA-2",
+    "BAARERU",
+    "BACKSPACE",
+    "BACON",
+    "BACTRIAN",
+    "BADGE",
+    "BADMINTON",
+    "BAG3",
+    "BAGA",
+    "BAGEL",
+    "BAGGAGE",
+    "BAGUETTE",
+    "BAH",
+    "BAHIRGOMUKHA",
+    "BAHT",
+    "BAIMAI",
+    "BAIRKAN",
+    "BALD",
+    "BALLET",
+    "BALLPOINT",
+    "BANANA",
+    "BAND",
+    "BANDAGE",
+    "BANG",
+    "BANJO",
+    "BANTOC",
+    "BAP",
+    "BARA2",
+    "BARBER",
+    "BARIYOOSAN",
+    "BARLEY",
+    "BARREKH",
+    "BARRIER",
+    "BASEBALL",
+    "BASH",
+    "BASKET",
+    "BASKETBALL",
+    "BATHAMASAT",
+    "BAU",
+    "BAWAK",
+    "BAX",
+    "BB",
+    "BBAA",
+    "BBAP",
+    "BBAT",
+    "BBAX",
+    "BBEE",
+    "BBEP",
+    "BBEX",
+    "BBIE",
+    "BBIEP",
+    "BBIET",
+    "BBIEX",
+    "BBIP",
+    "BBIT",
+    "BBIX",
+    "BBOP",
+    "BBOT",
+    "BBOX",
+    "BBUO",
+    "BBUOP",
+    "BBUOX",
+    "BBUP",
+    "BBUR",
+    "BBURX",
+    "BBUX",
+    "BBY",
+    "BBYP",
+    "BBYT",
+    "BBYX",
+    "BEACH",
+    "BEADS",
+    "BEAN",
+    "BEANS",
+    "BEARDED",
+    "BEATING",
+    "BECAUSE",
+    "BED",
+    "BEEHIVE",
+    "BEETA",
+    "BEFORE",
+    "BEGINNER",
+    "BEGINNING",
+    "BEITH",
+    "BELGTHOR",
+    "BELLHOP",
+    "BENDE",
+    "BENTO",
+    "BEORC",
+    "BEP",
+    "BERKANAN",
+    "BEX",
+    "BEYYAL",
+    "BH",
+    "BHAA",
+    "BHAM",
+    "BHATTIPROLU",
+    "BHEE",
+    "BHI",
+    "BHOO",
+    "BHU",
+    "BIB",
+    "BIBLE-CREE",
+    "BICEPS",
+    "BICYCLE",
+    "BICYCLES",
+    "BIDENTAL",
+    "BIE",
+    "BIEP",
+    "BIET",
+    "BIEX",
+    "BIKINI",
+    "BILLED",
+    "BILLIARDS",
+    "BILLIONS",
+    "BINOVILE",
+    "BIOHAZARD",
+    "BIP",
+    "BIRTHDAY",
+    "BIRU",
+    "BISAH",
+    "BISECTING",
+    "BISMILLAH",
+    "BISMUTH",
+    "BISON",
+    "BITCOIN",
+    "BITE",
+    "BITTER",
+    "BIX",
+    "BJARKAN",
+    "BKA-",
+    "BLA",
+    "BLOCK-1358",
+    "BLOND",
+    "BLOW",
+    "BLOWFISH",
+    "BLUEBERRIES",
+    "BOA",
+    "BOOKS",
+    "BOOTS",
+    "BOP",
+    "BORAX",
+    "BORAX-2",
+    "BORAX-3",
+    "BORUTO",
+    "BORZAYA",
+    "BORZY",
+    "BOT",
+    "BOTTOM-LIGHTED",
+    "BOTTOM-SHADED",
+    "BOUNDARY",
+    "BOWING",
+    "BOWLING",
+    "BOXING",
+    "BOY",
+    "BOYS",
+    "BQ",
+    "BRACE",
+    "BRACKETS",
+    "BRAIN",
+    "BRAKCET",
+    "BRANCHES",
+    "BRANCHING",
+    "BREAKTHROUGH",
+    "BREAST-FEEDING",
+    "BREVE-MACRON",
+    "BRI",
+    "BRIDE",
+    "BRIEFCASE",
+    "BRIEFS",
+    "BRISTLE",
+    "BROCCOLI",
+    "BRONZE",
+    "BROOM",
+    "BSDUS",
+    "BSKA-",
+    "BSKUR",
+    "BSTAR",
+    "BUCKET",
+    "BUCKLE",
+    "BUFFALO",
+    "BUG",
+    "BULB",
+    "BULL",
+    "BULLS",
+    "BULLSEYE",
+    "BUMPY",
+    "BUNG",
+    "BUNNY",
+    "BUO",
+    "BUOP",
+    "BUOX",
+    "BUOY",
+    "BUP",
+    "BUR2",
+    "BURRITO",
+    "BURX",
+    "BUSINESS",
+    "BUSSYERU",
+    "BUST",
+    "BUSTS",
+    "BUTTER",
+    "BUTTERFLY",
+    "BUUMISH",
+    "BUX",
+    "BWE",
+    "BXG",
+    "BYP",
+    "BYR",
+    "BYRX",
+    "BYT",
+    "BYX",
+    "C-18",
+    "C-39",
+    "C001",
+    "C002",
+    "C002A",
+    "C002B",
+    "C002C",
+    "C003",
+    "C004",
+    "C005",
+    "C006",
+    "C007",
+    "C008",
+    "C009",
+    "C010",
+    "C010A",
+    "C011",
+    "C012",
+    "C013",
+    "C014",
+    "C015",
+    "C016",
+    "C017",
+    "C018",
+    "C019",
+    "C020",
+    "C021",
+    "C022",
+    "C023",
+    "C024",
+    "CAAI",
+    "CAANG",
+    "CABBAGE-TREE",
+    "CABINET",
+    "CABLEWAY",
+    "CACTUS",
+    "CADA",
+    "CAESURA",
+    "CAH",
+    "CAI",
+    "CAL",
+    "CALC",
+    "CALCULATOR",
+    "CALX",
+    "CAMNUC",
+    "CAMPING",
+    "CANCER",
+    "CANDLE",
+    "CANDY",
+    "CANE",
+    "CANG",
+    "CANNED",
+    "CANOE",
+    "CAPITULUM",
+    "CAPO",
+    "CAPPED",
+    "CAPRICORN",
+    "CAPTIVE",
+    "CAPUT",
+    "CARDS",
+    "CARE",
+    "CARMI",
+    "CAROUSEL",
+    "CARP",
+    "CARRIAGE",
+    "CARROT",
+    "CARS",
+    "CARTRIDGE",
+    "CARTWHEEL",
+    "CARYSTIAN",
+    "CATAWA",
+    "CAU",
+    "CAUDA",
+    "CAUTION",
+    "CAVE",
+    "CAX",
+    "CCA",
+    "CCAA",
+    "CCE",
+    "CCEE",
+    "CCHA",
+    "CCHAA",
+    "CCHEE",
+    "CCHHA",
+    "CCHHAA",
+    "CCHHE",
+    "CCHHEE",
+    "CCHHI",
+    "CCHHO",
+    "CCHHU",
+    "CCHI",
+    "CCHO",
+    "CCHU",
+    "CCI",
+    "CCO",
+    "CCU",
+    "CEALC",
+    "CECEK",
+    "CEDI",
+    "CEEB",
+    "CEEV",
+    "CEIRT",
+    "CELEBRATION",
+    "CELSIUS",
+    "CELTIC",
+    "CEN",
+    "CENTURIAL",
+    "CEONGCHIEUMCHIEUCH",
+    "CEONGCHIEUMCIEUC",
+    "CEONGCHIEUMSIOS",
+    "CEONGCHIEUMSSANGCIEUC",
+    "CEONGCHIEUMSSANGSIOS",
+    "CEP",
+    "CEREK",
+    "CEREMONY",
+    "CERES",
+    "CEVITU",
+    "CEX",
+    "CHAD",
+    "CHADA",
+    "CHAINS",
+    "CHAMILI",
+    "CHAMILON",
+    "CHAMKO",
+    "CHAN",
+    "CHANG",
+    "CHANGE",
+    "CHAP",
+    "CHAPTER",
+    "CHATTAWA",
+    "CHAU",
+    "CHAVIYANI",
+    "CHAX",
+    "CHEEK",
+    "CHEEM",
+    "CHEERING",
+    "CHEESE",
+    "CHEIKHAN",
+    "CHEIKHEI",
+    "CHEINAP",
+    "CHELAP",
+    "CHELNU",
+    "CHEN",
+    "CHEP",
+    "CHEQUERED",
+    "CHERRIES",
+    "CHERRY",
+    "CHERY",
+    "CHESTNUT",
+    "CHET",
+    "CHEX",
+    "CHHA",
+    "CHHIM",
+    "CHICKEN",
+    "CHIEUCH-HIEUH",
+    "CHIEUCH-KHIEUKH",
+    "CHIL",
+    "CHILDREN",
+    "CHIM",
+    "CHIME",
+    "CHING",
+    "CHINOOK",
+    "CHIPMUNK",
+    "CHIRET",
+    "CHIRON",
+    "CHITUEUMCHIEUCH",
+    "CHITUEUMCIEUC",
+    "CHITUEUMSIOS",
+    "CHITUEUMSSANGCIEUC",
+    "CHITUEUMSSANGSIOS",
+    "CHOA",
+    "CHOCOLATE",
+    "CHOE",
+    "CHOKE",
+    "CHOP",
+    "CHOPSTICKS",
+    "CHOT",
+    "CHOX",
+    "CHOY",
+    "CHRYSANTHEMUM",
+    "CHULA",
+    "CHUO",
+    "CHUOP",
+    "CHUOT",
+    "CHUOX",
+    "CHUP",
+    "CHUR",
+    "CHURX",
+    "CHUX",
+    "CHWA",
+    "CHWV",
+    "CHY",
+    "CHYP",
+    "CHYR",
+    "CHYRX",
+    "CHYT",
+    "CHYX",
+    "CIE",
+    "CIEP",
+    "CIET",
+    "CIEUC-IEUNG",
+    "CIEUC-PIEUP",
+    "CIEUC-SSANGPIEUP",
+    "CIEX",
+    "CII",
+    "CINEMA",
+    "CINNABAR",
+    "CIRCULATION",
+    "CIRCUS",
+    "CIT",
+    "CITATION",
+    "CIVILIAN",
+    "CIX",
+    "CL",
+    "CLAIM",
+    "CLAMSHELL",
+    "CLAPPER",
+    "CLAPPING",
+    "CLASSICAL",
+    "CLAW",
+    "CLEAR",
+    "CLEAVER",
+    "CLEF-1",
+    "CLEF-2",
+    "CLIMACUS",
+    "CLIMBING",
+    "CLINGING",
+    "CLIPBOARD",
+    "CLIVIS",
+    "CLOSENESS",
+    "CLOSET",
+    "CLOSURE",
+    "CLOVER",
+    "CLOWN",
+    "CLUB-SPOKED",
+    "CM001",
+    "CM002",
+    "CM004",
+    "CM005",
+    "CM006",
+    "CM007",
+    "CM008",
+    "CM009",
+    "CM010",
+    "CM011",
+    "CM012",
+    "CM012B",
+    "CM013",
+    "CM015",
+    "CM017",
+    "CM019",
+    "CM021",
+    "CM023",
+    "CM024",
+    "CM025",
+    "CM026",
+    "CM027",
+    "CM028",
+    "CM029",
+    "CM030",
+    "CM033",
+    "CM034",
+    "CM035",
+    "CM036",
+    "CM037",
+    "CM038",
+    "CM039",
+    "CM040",
+    "CM041",
+    "CM044",
+    "CM046",
+    "CM047",
+    "CM049",
+    "CM050",
+    "CM051",
+    "CM052",
+    "CM053",
+    "CM054",
+    "CM055",
+    "CM056",
+    "CM058",
+    "CM059",
+    "CM060",
+    "CM061",
+    "CM062",
+    "CM063",
+    "CM064",
+    "CM066",
+    "CM067",
+    "CM068",
+    "CM069",
+    "CM070",
+    "CM071",
+    "CM072",
+    "CM073",
+    "CM074",
+    "CM075",
+    "CM075B",
+    "CM076",
+    "CM078",
+    "CM079",
+    "CM080",
+    "CM081",
+    "CM082",
+    "CM083",
+    "CM084",
+    "CM085",
+    "CM086",
+    "CM087",
+    "CM088",
+    "CM089",
+    "CM090",
+    "CM091",
+    "CM092",
+    "CM094",
+    "CM095",
+    "CM096",
+    "CM097",
+    "CM098",
+    "CM099",
+    "CM100",
+    "CM101",
+    "CM102",
+    "CM103",
+    "CM104",
+    "CM105",
+    "CM107",
+    "CM108",
+    "CM109",
+    "CM110",
+    "CM112",
+    "CM114",
+    "CM301",
+    "CM302",
+    "COA",
+    "COCKROACH",
+    "COCKTAIL",
+    "COCONUT",
+    "CODA",
+    "COENG",
+    "COFFIN",
+    "COIN",
+    "COLL",
+    "COLLISION",
+    "COLOR",
+    "COLUMN",
+    "COMB",
+    "COMBINATION",
+    "COMBINED",
+    "COMET",
+    "COMING",
+    "COMMON",
+    "COMPARE",
+    "COMPASS",
+    "COMPLEMENT",
+    "COMPLETED",
+    "COMPLIANCE",
+    "COMPONENT-001",
+    "COMPONENT-002",
+    "COMPONENT-003",
+    "COMPONENT-004",
+    "COMPONENT-005",
+    "COMPONENT-006",
+    "COMPONENT-007",
+    "COMPONENT-008",
+    "COMPONENT-009",
+    "COMPONENT-010",
+    "COMPONENT-011",
+    "COMPONENT-012",
+    "COMPONENT-013",
+    "COMPONENT-014",
+    "COMPONENT-015",
+    "COMPONENT-016",
+    "COMPONENT-017",
+    "COMPONENT-018",
+    "COMPONENT-019",
+    "COMPONENT-020",
+    "COMPONENT-021",
+    "COMPONENT-022",
+    "COMPONENT-023",
+    "COMPONENT-024",
+    "COMPONENT-025",
+    "COMPONENT-026",
+    "COMPONENT-027",
+    "COMPONENT-028",
+    "COMPONENT-029",
+    "COMPONENT-030",
+    "COMPONENT-031",
+    "COMPONENT-032",
+    "COMPONENT-033",
+    "COMPONENT-034",
+    "COMPONENT-035",
+    "COMPONENT-036",
+    "COMPONENT-037",
+    "COMPONENT-038",
+    "COMPONENT-039",
+    "COMPONENT-040",
+    "COMPONENT-041",
+    "COMPONENT-042",
+    "COMPONENT-043",
+    "COMPONENT-044",
+    "COMPONENT-045",
+    "COMPONENT-046",
+    "COMPONENT-047",
+    "COMPONENT-048",
+    "COMPONENT-049",
+    "COMPONENT-050",
+    "COMPONENT-051",
+    "COMPONENT-052",
+    "COMPONENT-053",
+    "COMPONENT-054",
+    "COMPONENT-055",
+    "COMPONENT-056",
+    "COMPONENT-057",
+    "COMPONENT-058",
+    "COMPONENT-059",
+    "COMPONENT-060",
+    "COMPONENT-061",
+    "COMPONENT-062",
+    "COMPONENT-063",
+    "COMPONENT-064",
+    "COMPONENT-065",
+    "COMPONENT-066",
+    "COMPONENT-067",
+    "COMPONENT-068",
+    "COMPONENT-069",
+    "COMPONENT-070",
+    "COMPONENT-071",
+    "COMPONENT-072",
+    "COMPONENT-073",
+    "COMPONENT-074",
+    "COMPONENT-075",
+    "COMPONENT-076",
+    "COMPONENT-077",
+    "COMPONENT-078",
+    "COMPONENT-079",
+    "COMPONENT-080",
+    "COMPONENT-081",
+    "COMPONENT-082",
+    "COMPONENT-083",
+    "COMPONENT-084",
+    "COMPONENT-085",
+    "COMPONENT-086",
+    "COMPONENT-087",
+    "COMPONENT-088",
+    "COMPONENT-089",
+    "COMPONENT-090",
+    "COMPONENT-091",
+    "COMPONENT-092",
+    "COMPONENT-093",
+    "COMPONENT-094",
+    "COMPONENT-095",
+    "COMPONENT-096",
+    "COMPONENT-097",
+    "COMPONENT-098",
+    "COMPONENT-099",
+    "COMPONENT-100",
+    "COMPONENT-101",
+    "COMPONENT-102",
+    "COMPONENT-103",
+    "COMPONENT-104",
+    "COMPONENT-105",
+    "COMPONENT-106",
+    "COMPONENT-107",
+    "COMPONENT-108",
+    "COMPONENT-109",
+    "COMPONENT-110",
+    "COMPONENT-111",
+    "COMPONENT-112",
+    "COMPONENT-113",
+    "COMPONENT-114",
+    "COMPONENT-115",
+    "COMPONENT-116",
+    "COMPONENT-117",
+    "COMPONENT-118",
+    "COMPONENT-119",
+    "COMPONENT-120",
+    "COMPONENT-121",
+    "COMPONENT-122",
+    "COMPONENT-123",
+    "COMPONENT-124",
+    "COMPONENT-125",
+    "COMPONENT-126",
+    "COMPONENT-127",
+    "COMPONENT-128",
+    "COMPONENT-129",
+    "COMPONENT-130",
+    "COMPONENT-131",
+    "COMPONENT-132",
+    "COMPONENT-133",
+    "COMPONENT-134",
+    "COMPONENT-135",
+    "COMPONENT-136",
+    "COMPONENT-137",
+    "COMPONENT-138",
+    "COMPONENT-139",
+    "COMPONENT-140",
+    "COMPONENT-141",
+    "COMPONENT-142",
+    "COMPONENT-143",
+    "COMPONENT-144",
+    "COMPONENT-145",
+    "COMPONENT-146",
+    "COMPONENT-147",
+    "COMPONENT-148",
+    "COMPONENT-149",
+    "COMPONENT-150",
+    "COMPONENT-151",
+    "COMPONENT-152",
+    "COMPONENT-153",
+    "COMPONENT-154",
+    "COMPONENT-155",
+    "COMPONENT-156",
+    "COMPONENT-157",
+    "COMPONENT-158",
+    "COMPONENT-159",
+    "COMPONENT-160",
+    "COMPONENT-161",
+    "COMPONENT-162",
+    "COMPONENT-163",
+    "COMPONENT-164",
+    "COMPONENT-165",
+    "COMPONENT-166",
+    "COMPONENT-167",
+    "COMPONENT-168",
+    "COMPONENT-169",
+    "COMPONENT-170",
+    "COMPONENT-171",
+    "COMPONENT-172",
+    "COMPONENT-173",
+    "COMPONENT-174",
+    "COMPONENT-175",
+    "COMPONENT-176",
+    "COMPONENT-177",
+    "COMPONENT-178",
+    "COMPONENT-179",
+    "COMPONENT-180",
+    "COMPONENT-181",
+    "COMPONENT-182",
+    "COMPONENT-183",
+    "COMPONENT-184",
+    "COMPONENT-185",
+    "COMPONENT-186",
+    "COMPONENT-187",
+    "COMPONENT-188",
+    "COMPONENT-189",
+    "COMPONENT-190",
+    "COMPONENT-191",
+    "COMPONENT-192",
+    "COMPONENT-193",
+    "COMPONENT-194",
+    "COMPONENT-195",
+    "COMPONENT-196",
+    "COMPONENT-197",
+    "COMPONENT-198",
+    "COMPONENT-199",
+    "COMPONENT-200",
+    "COMPONENT-201",
+    "COMPONENT-202",
+    "COMPONENT-203",
+    "COMPONENT-204",
+    "COMPONENT-205",
+    "COMPONENT-206",
+    "COMPONENT-207",
+    "COMPONENT-208",
+    "COMPONENT-209",
+    "COMPONENT-210",
+    "COMPONENT-211",
+    "COMPONENT-212",
+    "COMPONENT-213",
+    "COMPONENT-214",
+    "COMPONENT-215",
+    "COMPONENT-216",
+    "COMPONENT-217",
+    "COMPONENT-218",
+    "COMPONENT-219",
+    "COMPONENT-220",
+    "COMPONENT-221",
+    "COMPONENT-222",
+    "COMPONENT-223",
+    "COMPONENT-224",
+    "COMPONENT-225",
+    "COMPONENT-226",
+    "COMPONENT-227",
+    "COMPONENT-228",
+    "COMPONENT-229",
+    "COMPONENT-230",
+    "COMPONENT-231",
+    "COMPONENT-232",
+    "COMPONENT-233",
+    "COMPONENT-234",
+    "COMPONENT-235",
+    "COMPONENT-236",
+    "COMPONENT-237",
+    "COMPONENT-238",
+    "COMPONENT-239",
+    "COMPONENT-240",
+    "COMPONENT-241",
+    "COMPONENT-242",
+    "COMPONENT-243",
+    "COMPONENT-244",
+    "COMPONENT-245",
+    "COMPONENT-246",
+    "COMPONENT-247",
+    "COMPONENT-248",
+    "COMPONENT-249",
+    "COMPONENT-250",
+    "COMPONENT-251",
+    "COMPONENT-252",
+    "COMPONENT-253",
+    "COMPONENT-254",
+    "COMPONENT-255",
+    "COMPONENT-256",
+    "COMPONENT-257",
+    "COMPONENT-258",
+    "COMPONENT-259",
+    "COMPONENT-260",
+    "COMPONENT-261",
+    "COMPONENT-262",
+    "COMPONENT-263",
+    "COMPONENT-264",
+    "COMPONENT-265",
+    "COMPONENT-266",
+    "COMPONENT-267",
+    "COMPONENT-268",
+    "COMPONENT-269",
+    "COMPONENT-270",
+    "COMPONENT-271",
+    "COMPONENT-272",
+    "COMPONENT-273",
+    "COMPONENT-274",
+    "COMPONENT-275",
+    "COMPONENT-276",
+    "COMPONENT-277",
+    "COMPONENT-278",
+    "COMPONENT-279",
+    "COMPONENT-280",
+    "COMPONENT-281",
+    "COMPONENT-282",
+    "COMPONENT-283",
+    "COMPONENT-284",
+    "COMPONENT-285",
+    "COMPONENT-286",
+    "COMPONENT-287",
+    "COMPONENT-288",
+    "COMPONENT-289",
+    "COMPONENT-290",
+    "COMPONENT-291",
+    "COMPONENT-292",
+    "COMPONENT-293",
+    "COMPONENT-294",
+    "COMPONENT-295",
+    "COMPONENT-296",
+    "COMPONENT-297",
+    "COMPONENT-298",
+    "COMPONENT-299",
+    "COMPONENT-300",
+    "COMPONENT-301",
+    "COMPONENT-302",
+    "COMPONENT-303",
+    "COMPONENT-304",
+    "COMPONENT-305",
+    "COMPONENT-306",
+    "COMPONENT-307",
+    "COMPONENT-308",
+    "COMPONENT-309",
+    "COMPONENT-310",
+    "COMPONENT-311",
+    "COMPONENT-312",
+    "COMPONENT-313",
+    "COMPONENT-314",
+    "COMPONENT-315",
+    "COMPONENT-316",
+    "COMPONENT-317",
+    "COMPONENT-318",
+    "COMPONENT-319",
+    "COMPONENT-320",
+    "COMPONENT-321",
+    "COMPONENT-322",
+    "COMPONENT-323",
+    "COMPONENT-324",
+    "COMPONENT-325",
+    "COMPONENT-326",
+    "COMPONENT-327",
+    "COMPONENT-328",
+    "COMPONENT-329",
+    "COMPONENT-330",
+    "COMPONENT-331",
+    "COMPONENT-332",
+    "COMPONENT-333",
+    "COMPONENT-334",
+    "COMPONENT-335",
+    "COMPONENT-336",
+    "COMPONENT-337",
+    "COMPONENT-338",
+    "COMPONENT-339",
+    "COMPONENT-340",
+    "COMPONENT-341",
+    "COMPONENT-342",
+    "COMPONENT-343",
+    "COMPONENT-344",
+    "COMPONENT-345",
+    "COMPONENT-346",
+    "COMPONENT-347",
+    "COMPONENT-348",
+    "COMPONENT-349",
+    "COMPONENT-350",
+    "COMPONENT-351",
+    "COMPONENT-352",
+    "COMPONENT-353",
+    "COMPONENT-354",
+    "COMPONENT-355",
+    "COMPONENT-356",
+    "COMPONENT-357",
+    "COMPONENT-358",
+    "COMPONENT-359",
+    "COMPONENT-360",
+    "COMPONENT-361",
+    "COMPONENT-362",
+    "COMPONENT-363",
+    "COMPONENT-364",
+    "COMPONENT-365",
+    "COMPONENT-366",
+    "COMPONENT-367",
+    "COMPONENT-368",
+    "COMPONENT-369",
+    "COMPONENT-370",
+    "COMPONENT-371",
+    "COMPONENT-372",
+    "COMPONENT-373",
+    "COMPONENT-374",
+    "COMPONENT-375",
+    "COMPONENT-376",
+    "COMPONENT-377",
+    "COMPONENT-378",
+    "COMPONENT-379",
+    "COMPONENT-380",
+    "COMPONENT-381",
+    "COMPONENT-382",
+    "COMPONENT-383",
+    "COMPONENT-384",
+    "COMPONENT-385",
+    "COMPONENT-386",
+    "COMPONENT-387",
+    "COMPONENT-388",
+    "COMPONENT-389",
+    "COMPONENT-390",
+    "COMPONENT-391",
+    "COMPONENT-392",
+    "COMPONENT-393",
+    "COMPONENT-394",
+    "COMPONENT-395",
+    "COMPONENT-396",
+    "COMPONENT-397",
+    "COMPONENT-398",
+    "COMPONENT-399",
+    "COMPONENT-400",
+    "COMPONENT-401",
+    "COMPONENT-402",
+    "COMPONENT-403",
+    "COMPONENT-404",
+    "COMPONENT-405",
+    "COMPONENT-406",
+    "COMPONENT-407",
+    "COMPONENT-408",
+    "COMPONENT-409",
+    "COMPONENT-410",
+    "COMPONENT-411",
+    "COMPONENT-412",
+    "COMPONENT-413",
+    "COMPONENT-414",
+    "COMPONENT-415",
+    "COMPONENT-416",
+    "COMPONENT-417",
+    "COMPONENT-418",
+    "COMPONENT-419",
+    "COMPONENT-420",
+    "COMPONENT-421",
+    "COMPONENT-422",
+    "COMPONENT-423",
+    "COMPONENT-424",
+    "COMPONENT-425",
+    "COMPONENT-426",
+    "COMPONENT-427",
+    "COMPONENT-428",
+    "COMPONENT-429",
+    "COMPONENT-430",
+    "COMPONENT-431",
+    "COMPONENT-432",
+    "COMPONENT-433",
+    "COMPONENT-434",
+    "COMPONENT-435",
+    "COMPONENT-436",
+    "COMPONENT-437",
+    "COMPONENT-438",
+    "COMPONENT-439",
+    "COMPONENT-440",
+    "COMPONENT-441",
+    "COMPONENT-442",
+    "COMPONENT-443",
+    "COMPONENT-444",
+    "COMPONENT-445",
+    "COMPONENT-446",
+    "COMPONENT-447",
+    "COMPONENT-448",
+    "COMPONENT-449",
+    "COMPONENT-450",
+    "COMPONENT-451",
+    "COMPONENT-452",
+    "COMPONENT-453",
+    "COMPONENT-454",
+    "COMPONENT-455",
+    "COMPONENT-456",
+    "COMPONENT-457",
+    "COMPONENT-458",
+    "COMPONENT-459",
+    "COMPONENT-460",
+    "COMPONENT-461",
+    "COMPONENT-462",
+    "COMPONENT-463",
+    "COMPONENT-464",
+    "COMPONENT-465",
+    "COMPONENT-466",
+    "COMPONENT-467",
+    "COMPONENT-468",
+    "COMPONENT-469",
+    "COMPONENT-470",
+    "COMPONENT-471",
+    "COMPONENT-472",
+    "COMPONENT-473",
+    "COMPONENT-474",
+    "COMPONENT-475",
+    "COMPONENT-476",
+    "COMPONENT-477",
+    "COMPONENT-478",
+    "COMPONENT-479",
+    "COMPONENT-480",
+    "COMPONENT-481",
+    "COMPONENT-482",
+    "COMPONENT-483",
+    "COMPONENT-484",
+    "COMPONENT-485",
+    "COMPONENT-486",
+    "COMPONENT-487",
+    "COMPONENT-488",
+    "COMPONENT-489",
+    "COMPONENT-490",
+    "COMPONENT-491",
+    "COMPONENT-492",
+    "COMPONENT-493",
+    "COMPONENT-494",
+    "COMPONENT-495",
+    "COMPONENT-496",
+    "COMPONENT-497",
+    "COMPONENT-498",
+    "COMPONENT-499",
+    "COMPONENT-500",
+    "COMPONENT-501",
+    "COMPONENT-502",
+    "COMPONENT-503",
+    "COMPONENT-504",
+    "COMPONENT-505",
+    "COMPONENT-506",
+    "COMPONENT-507",
+    "COMPONENT-508",
+    "COMPONENT-509",
+    "COMPONENT-510",
+    "COMPONENT-511",
+    "COMPONENT-512",
+    "COMPONENT-513",
+    "COMPONENT-514",
+    "COMPONENT-515",
+    "COMPONENT-516",
+    "COMPONENT-517",
+    "COMPONENT-518",
+    "COMPONENT-519",
+    "COMPONENT-520",
+    "COMPONENT-521",
+    "COMPONENT-522",
+    "COMPONENT-523",
+    "COMPONENT-524",
+    "COMPONENT-525",
+    "COMPONENT-526",
+    "COMPONENT-527",
+    "COMPONENT-528",
+    "COMPONENT-529",
+    "COMPONENT-530",
+    "COMPONENT-531",
+    "COMPONENT-532",
+    "COMPONENT-533",
+    "COMPONENT-534",
+    "COMPONENT-535",
+    "COMPONENT-536",
+    "COMPONENT-537",
+    "COMPONENT-538",
+    "COMPONENT-539",
+    "COMPONENT-540",
+    "COMPONENT-541",
+    "COMPONENT-542",
+    "COMPONENT-543",
+    "COMPONENT-544",
+    "COMPONENT-545",
+    "COMPONENT-546",
+    "COMPONENT-547",
+    "COMPONENT-548",
+    "COMPONENT-549",
+    "COMPONENT-550",
+    "COMPONENT-551",
+    "COMPONENT-552",
+    "COMPONENT-553",
+    "COMPONENT-554",
+    "COMPONENT-555",
+    "COMPONENT-556",
+    "COMPONENT-557",
+    "COMPONENT-558",
+    "COMPONENT-559",
+    "COMPONENT-560",
+    "COMPONENT-561",
+    "COMPONENT-562",
+    "COMPONENT-563",
+    "COMPONENT-564",
+    "COMPONENT-565",
+    "COMPONENT-566",
+    "COMPONENT-567",
+    "COMPONENT-568",
+    "COMPONENT-569",
+    "COMPONENT-570",
+    "COMPONENT-571",
+    "COMPONENT-572",
+    "COMPONENT-573",
+    "COMPONENT-574",
+    "COMPONENT-575",
+    "COMPONENT-576",
+    "COMPONENT-577",
+    "COMPONENT-578",
+    "COMPONENT-579",
+    "COMPONENT-580",
+    "COMPONENT-581",
+    "COMPONENT-582",
+    "COMPONENT-583",
+    "COMPONENT-584",
+    "COMPONENT-585",
+    "COMPONENT-586",
+    "COMPONENT-587",
+    "COMPONENT-588",
+    "COMPONENT-589",
+    "COMPONENT-590",
+    "COMPONENT-591",
+    "COMPONENT-592",
+    "COMPONENT-593",
+    "COMPONENT-594",
+    "COMPONENT-595",
+    "COMPONENT-596",
+    "COMPONENT-597",
+    "COMPONENT-598",
+    "COMPONENT-599",
+    "COMPONENT-600",
+    "COMPONENT-601",
+    "COMPONENT-602",
+    "COMPONENT-603",
+    "COMPONENT-604",
+    "COMPONENT-605",
+    "COMPONENT-606",
+    "COMPONENT-607",
+    "COMPONENT-608",
+    "COMPONENT-609",
+    "COMPONENT-610",
+    "COMPONENT-611",
+    "COMPONENT-612",
+    "COMPONENT-613",
+    "COMPONENT-614",
+    "COMPONENT-615",
+    "COMPONENT-616",
+    "COMPONENT-617",
+    "COMPONENT-618",
+    "COMPONENT-619",
+    "COMPONENT-620",
+    "COMPONENT-621",
+    "COMPONENT-622",
+    "COMPONENT-623",
+    "COMPONENT-624",
+    "COMPONENT-625",
+    "COMPONENT-626",
+    "COMPONENT-627",
+    "COMPONENT-628",
+    "COMPONENT-629",
+    "COMPONENT-630",
+    "COMPONENT-631",
+    "COMPONENT-632",
+    "COMPONENT-633",
+    "COMPONENT-634",
+    "COMPONENT-635",
+    "COMPONENT-636",
+    "COMPONENT-637",
+    "COMPONENT-638",
+    "COMPONENT-639",
+    "COMPONENT-640",
+    "COMPONENT-641",
+    "COMPONENT-642",
+    "COMPONENT-643",
+    "COMPONENT-644",
+    "COMPONENT-645",
+    "COMPONENT-646",
+    "COMPONENT-647",
+    "COMPONENT-648",
+    "COMPONENT-649",
+    "COMPONENT-650",
+    "COMPONENT-651",
+    "COMPONENT-652",
+    "COMPONENT-653",
+    "COMPONENT-654",
+    "COMPONENT-655",
+    "COMPONENT-656",
+    "COMPONENT-657",
+    "COMPONENT-658",
+    "COMPONENT-659",
+    "COMPONENT-660",
+    "COMPONENT-661",
+    "COMPONENT-662",
+    "COMPONENT-663",
+    "COMPONENT-664",
+    "COMPONENT-665",
+    "COMPONENT-666",
+    "COMPONENT-667",
+    "COMPONENT-668",
+    "COMPONENT-669",
+    "COMPONENT-670",
+    "COMPONENT-671",
+    "COMPONENT-672",
+    "COMPONENT-673",
+    "COMPONENT-674",
+    "COMPONENT-675",
+    "COMPONENT-676",
+    "COMPONENT-677",
+    "COMPONENT-678",
+    "COMPONENT-679",
+    "COMPONENT-680",
+    "COMPONENT-681",
+    "COMPONENT-682",
+    "COMPONENT-683",
+    "COMPONENT-684",
+    "COMPONENT-685",
+    "COMPONENT-686",
+    "COMPONENT-687",
+    "COMPONENT-688",
+    "COMPONENT-689",
+    "COMPONENT-690",
+    "COMPONENT-691",
+    "COMPONENT-692",
+    "COMPONENT-693",
+    "COMPONENT-694",
+    "COMPONENT-695",
+    "COMPONENT-696",
+    "COMPONENT-697",
+    "COMPONENT-698",
+    "COMPONENT-699",
+    "COMPONENT-700",
+    "COMPONENT-701",
+    "COMPONENT-702",
+    "COMPONENT-703",
+    "COMPONENT-704",
+    "COMPONENT-705",
+    "COMPONENT-706",
+    "COMPONENT-707",
+    "COMPONENT-708",
+    "COMPONENT-709",
+    "COMPONENT-710",
+    "COMPONENT-711",
+    "COMPONENT-712",
+    "COMPONENT-713",
+    "COMPONENT-714",
+    "COMPONENT-715",
+    "COMPONENT-716",
+    "COMPONENT-717",
+    "COMPONENT-718",
+    "COMPONENT-719",
+    "COMPONENT-720",
+    "COMPONENT-721",
+    "COMPONENT-722",
+    "COMPONENT-723",
+    "COMPONENT-724",
+    "COMPONENT-725",
+    "COMPONENT-726",
+    "COMPONENT-727",
+    "COMPONENT-728",
+    "COMPONENT-729",
+    "COMPONENT-730",
+    "COMPONENT-731",
+    "COMPONENT-732",
+    "COMPONENT-733",
+    "COMPONENT-734",
+    "COMPONENT-735",
+    "COMPONENT-736",
+    "COMPONENT-737",
+    "COMPONENT-738",
+    "COMPONENT-739",
+    "COMPONENT-740",
+    "COMPONENT-741",
+    "COMPONENT-742",
+    "COMPONENT-743",
+    "COMPONENT-744",
+    "COMPONENT-745",
+    "COMPONENT-746",
+    "COMPONENT-747",
+    "COMPONENT-748",
+    "COMPONENT-749",
+    "COMPONENT-750",
+    "COMPONENT-751",
+    "COMPONENT-752",
+    "COMPONENT-753",
+    "COMPONENT-754",
+    "COMPONENT-755",
+    "COMPONENT-756",
+    "COMPONENT-757",
+    "COMPONENT-758",
+    "COMPONENT-759",
+    "COMPONENT-760",
+    "COMPONENT-761",
+    "COMPONENT-762",
+    "COMPONENT-763",
+    "COMPONENT-764",
+    "COMPONENT-765",
+    "COMPONENT-766",
+    "COMPONENT-767",
+    "COMPONENT-768",
+    "COMPRESSION",
+    "COMPUTERS",
+    "CONCAVE-POINTED",
+    "CONFETTI",
+    "CONFLICT",
+    "CONFOUNDED",
+    "CONFUSED",
+    "CONGRUENT",
+    "CONICAL",
+    "CONJUGATE",
+    "CONJUNCTION",
+    "CONSTANCY",
+    "CONTEMPLATION",
+    "CONTENTION",
+    "CONTINUATION",
+    "CONTINUING",
+    "CONTINUOUS",
+    "CONTOURED",
+    "CONTRACTION",
+    "CONTRARIETY",
+    "CONVENIENCE",
+    "COOKED",
+    "COOKING",
+    "COOL",
+    "COP",
+    "COPPER-2",
+    "COPY",
+    "COPYLEFT",
+    "CORAL",
+    "CORK",
+    "CORNISH",
+    "CORONIS",
+    "CORPORATION",
+    "CORPSE",
+    "CORRECT",
+    "CORRESPONDS",
+    "COT",
+    "COUCH",
+    "COUNTERBORE",
+    "COUNTERSINK",
+    "COUPLE",
+    "COWBOY",
+    "COX",
+    "CRACKER",
+    "CRAYON",
+    "CREATIVE",
+    "CRESCENDO",
+    "CROCODILE",
+    "CROISSANT",
+    "CROIX",
+    "CROUCHING",
+    "CRUCIBLE",
+    "CRUCIBLE-2",
+    "CRUCIBLE-3",
+    "CRUCIBLE-4",
+    "CRUCIBLE-5",
+    "CRUCIFORM",
+    "CRUTCH",
+    "CRUZEIRO",
+    "CRYSTAL",
+    "CUAM",
+    "CUCUMBER",
+    "CUO",
+    "CUOX",
+    "CUPCAKE",
+    "CUPIDO",
+    "CUR",
+    "CURLED",
+    "CURLING",
+    "CURRY",
+    "CURX",
+    "CUSTARD",
+    "CUSTOMER",
+    "CUSTOMS",
+    "CUX",
+    "CWEORTH",
+    "CYAW",
+    "CYAY",
+    "CYCLONE",
+    "CYLINDRICITY",
+    "CYPERUS",
+    "CYR",
+    "CYRENAIC",
+    "CYRX",
+    "CYX",
+    "D001",
+    "D002",
+    "D003",
+    "D004",
+    "D005",
+    "D006",
+    "D007",
+    "D008",
+    "D008A",
+    "D009",
+    "D010",
+    "D011",
+    "D012",
+    "D013",
+    "D014",
+    "D015",
+    "D016",
+    "D017",
+    "D018",
+    "D019",
+    "D020",
+    "D021",
+    "D022",
+    "D023",
+    "D024",
+    "D025",
+    "D026",
+    "D027",
+    "D027A",
+    "D028",
+    "D029",
+    "D030",
+    "D031",
+    "D031A",
+    "D032",
+    "D033",
+    "D034",
+    "D034A",
+    "D035",
+    "D036",
+    "D037",
+    "D038",
+    "D039",
+    "D040",
+    "D041",
+    "D042",
+    "D043",
+    "D044",
+    "D045",
+    "D046",
+    "D046A",
+    "D047",
+    "D048",
+    "D048A",
+    "D049",
+    "D050",
+    "D050A",
+    "D050B",
+    "D050C",
+    "D050D",
+    "D050E",
+    "D050F",
+    "D050G",
+    "D050H",
+    "D050I",
+    "D051",
+    "D052",
+    "D052A",
+    "D053",
+    "D054",
+    "D054A",
+    "D055",
+    "D056",
+    "D057",
+    "D058",
+    "D059",
+    "D060",
+    "D061",
+    "D062",
+    "D063",
+    "D064",
+    "D065",
+    "D066",
+    "D067",
+    "D067A",
+    "D067B",
+    "D067C",
+    "D067D",
+    "D067E",
+    "D067F",
+    "D067G",
+    "D067H",
+    "D2",
+    "DAADHU",
+    "DAASU",
+    "DAEG",
+    "DAENG",
+    "DAG3",
+    "DAGALGA",
+    "DAGAZ",
+    "DAGBASINNA",
+    "DAGS",
+    "DAH",
+    "DAHYAAUSH",
+    "DAHYAAUSH-2",
+    "DAI",
+    "DAING",
+    "DAIR",
+    "DALAT",
+    "DALETH-RESH",
+    "DAM",
+    "DAMARU",
+    "DANCER",
+    "DANCING",
+    "DANGO",
+    "DANTAYALAN",
+    "DARA4",
+    "DARGA",
+    "DART",
+    "DASEIA",
+    "DATA",
+    "DAVID",
+    "DAVIYANI",
+    "DAWB",
+    "DAX",
+    "DAY-NIGHT",
+    "DD",
+    "DDAP",
+    "DDAT",
+    "DDAX",
+    "DDEE",
+    "DDEP",
+    "DDEX",
+    "DDHE",
+    "DDHEE",
+    "DDHI",
+    "DDHU",
+    "DDIE",
+    "DDIEP",
+    "DDIEX",
+    "DDIP",
+    "DDIT",
+    "DDIX",
+    "DDOA",
+    "DDOP",
+    "DDOT",
+    "DDOX",
+    "DDUO",
+    "DDUOP",
+    "DDUOX",
+    "DDUP",
+    "DDURX",
+    "DDUT",
+    "DDUX",
+    "DDWA",
+    "DEAD",
+    "DEAF",
+    "DEBIT",
+    "DECAYED",
+    "DECEMBER",
+    "DECIDUOUS",
+    "DECISIVENESS",
+    "DECORATIVE",
+    "DECRESCENDO",
+    "DEEL",
+    "DEEPLY",
+    "DEFECTIVENESS",
+    "DEFINITION",
+    "DEHI",
+    "DEK",
+    "DEKA",
+    "DELETION",
+    "DELICIOUS",
+    "DELIVERANCE",
+    "DELIVERY",
+    "DELPHIC",
+    "DELT",
+    "DENARIUS",
+    "DENG",
+    "DENNEN",
+    "DEP",
+    "DEPARTMENT",
+    "DEPTH",
+    "DERBITSA",
+    "DERELICT",
+    "DERET",
+    "DERET-HIDET",
+    "DESI",
+    "DESIGN",
+    "DESK",
+    "DEUNG",
+    "DEVELOPMENT",
+    "DEVI",
+    "DEX",
+    "DEYTEROU",
+    "DHAA",
+    "DHAALU",
+    "DHADHE",
+    "DHAL",
+    "DHALATH",
+    "DHALETH",
+    "DHAMEDH",
+    "DHARMA",
+    "DHEE",
+    "DHHA",
+    "DHHE",
+    "DHHEE",
+    "DHHI",
+    "DHHO",
+    "DHHOO",
+    "DHHU",
+    "DHI",
+    "DHII",
+    "DHOO",
+    "DHOU",
+    "DHU",
+    "DIAERESIS-RING",
+    "DIAERESIZED",
+    "DIAGONALS",
+    "DIAMETER",
+    "DIARGON",
+    "DIATONON",
+    "DIEP",
+    "DIESEL",
+    "DIEX",
+    "DIFAT",
+    "DIFFERENCE",
+    "DIFFICULTIES",
+    "DIFFICULTY",
+    "DIFTOGGOS",
+    "DIGITS",
+    "DIGRAMMA",
+    "DIL",
+    "DIMENSION",
+    "DIMENSIONAL",
+    "DIMIDIA",
+    "DIMINISHMENT",
+    "DIMINUTION-2",
+    "DIMINUTION-3",
+    "DIMMING",
+    "DING",
+    "DIP",
+    "DIPLOUN",
+    "DIPPER",
+    "DIPTE",
+    "DIRGA",
+    "DISABLED",
+    "DISCONTINUOUS",
+    "DISGUISED",
+    "DISPERSION",
+    "DISPUTED",
+    "DISSOLVE",
+    "DISSOLVE-2",
+    "DISTILL",
+    "DISTINGUISH",
+    "DISTORTION",
+    "DIT",
+    "DITTO",
+    "DIVERGENCE",
+    "DIVIDERS",
+    "DIVIDES",
+    "DIVING",
+    "DIVORCE",
+    "DIX",
+    "DIYA",
+    "DJ",
+    "DL",
+    "DLE",
+    "DLEE",
+    "DLHA",
+    "DLHYA",
+    "DLI",
+    "DLO",
+    "DLU",
+    "DNA",
+    "DO-O",
+    "DOA",
+    "DODO",
+    "DOING",
+    "DOIT",
+    "DOKMAI",
+    "DOLIUM",
+    "DOLPHIN",
+    "DOMAIN",
+    "DONGA",
+    "DONKEY",
+    "DOONG",
+    "DOP",
+    "DOROME",
+    "DORU",
+    "DOTS-1",
+    "DOTS-12",
+    "DOTS-123",
+    "DOTS-1234",
+    "DOTS-12345",
+    "DOTS-123456",
+    "DOTS-1234567",
+    "DOTS-12345678",
+    "DOTS-1234568",
+    "DOTS-123457",
+    "DOTS-1234578",
+    "DOTS-123458",
+    "DOTS-12346",
+    "DOTS-123467",
+    "DOTS-1234678",
+    "DOTS-123468",
+    "DOTS-12347",
+    "DOTS-123478",
+    "DOTS-12348",
+    "DOTS-1235",
+    "DOTS-12356",
+    "DOTS-123567",
+    "DOTS-1235678",
+    "DOTS-123568",
+    "DOTS-12357",
+    "DOTS-123578",
+    "DOTS-12358",
+    "DOTS-1236",
+    "DOTS-12367",
+    "DOTS-123678",
+    "DOTS-12368",
+    "DOTS-1237",
+    "DOTS-12378",
+    "DOTS-1238",
+    "DOTS-124",
+    "DOTS-1245",
+    "DOTS-12456",
+    "DOTS-124567",
+    "DOTS-1245678",
+    "DOTS-124568",
+    "DOTS-12457",
+    "DOTS-124578",
+    "DOTS-12458",
+    "DOTS-1246",
+    "DOTS-12467",
+    "DOTS-124678",
+    "DOTS-12468",
+    "DOTS-1247",
+    "DOTS-12478",
+    "DOTS-1248",
+    "DOTS-125",
+    "DOTS-1256",
+    "DOTS-12567",
+    "DOTS-125678",
+    "DOTS-12568",
+    "DOTS-1257",
+    "DOTS-12578",
+    "DOTS-1258",
+    "DOTS-126",
+    "DOTS-1267",
+    "DOTS-12678",
+    "DOTS-1268",
+    "DOTS-127",
+    "DOTS-1278",
+    "DOTS-128",
+    "DOTS-13",
+    "DOTS-134",
+    "DOTS-1345",
+    "DOTS-13456",
+    "DOTS-134567",
+    "DOTS-1345678",
+    "DOTS-134568",
+    "DOTS-13457",
+    "DOTS-134578",
+    "DOTS-13458",
+    "DOTS-1346",
+    "DOTS-13467",
+    "DOTS-134678",
+    "DOTS-13468",
+    "DOTS-1347",
+    "DOTS-13478",
+    "DOTS-1348",
+    "DOTS-135",
+    "DOTS-1356",
+    "DOTS-13567",
+    "DOTS-135678",
+    "DOTS-13568",
+    "DOTS-1357",
+    "DOTS-13578",
+    "DOTS-1358",
+    "DOTS-136",
+    "DOTS-1367",
+    "DOTS-13678",
+    "DOTS-1368",
+    "DOTS-137",
+    "DOTS-1378",
+    "DOTS-138",
+    "DOTS-14",
+    "DOTS-145",
+    "DOTS-1456",
+    "DOTS-14567",
+    "DOTS-145678",
+    "DOTS-14568",
+    "DOTS-1457",
+    "DOTS-14578",
+    "DOTS-1458",
+    "DOTS-146",
+    "DOTS-1467",
+    "DOTS-14678",
+    "DOTS-1468",
+    "DOTS-147",
+    "DOTS-1478",
+    "DOTS-148",
+    "DOTS-15",
+    "DOTS-156",
+    "DOTS-1567",
+    "DOTS-15678",
+    "DOTS-1568",
+    "DOTS-157",
+    "DOTS-1578",
+    "DOTS-158",
+    "DOTS-16",
+    "DOTS-167",
+    "DOTS-1678",
+    "DOTS-168",
+    "DOTS-17",
+    "DOTS-178",
+    "DOTS-18",
+    "DOTS-2",
+    "DOTS-23",
+    "DOTS-234",
+    "DOTS-2345",
+    "DOTS-23456",
+    "DOTS-234567",
+    "DOTS-2345678",
+    "DOTS-234568",
+    "DOTS-23457",
+    "DOTS-234578",
+    "DOTS-23458",
+    "DOTS-2346",
+    "DOTS-23467",
+    "DOTS-234678",
+    "DOTS-23468",
+    "DOTS-2347",
+    "DOTS-23478",
+    "DOTS-2348",
+    "DOTS-235",
+    "DOTS-2356",
+    "DOTS-23567",
+    "DOTS-235678",
+    "DOTS-23568",
+    "DOTS-2357",
+    "DOTS-23578",
+    "DOTS-2358",
+    "DOTS-236",
+    "DOTS-2367",
+    "DOTS-23678",
+    "DOTS-2368",
+    "DOTS-237",
+    "DOTS-2378",
+    "DOTS-238",
+    "DOTS-24",
+    "DOTS-245",
+    "DOTS-2456",
+    "DOTS-24567",
+    "DOTS-245678",
+    "DOTS-24568",
+    "DOTS-2457",
+    "DOTS-24578",
+    "DOTS-2458",
+    "DOTS-246",
+    "DOTS-2467",
+    "DOTS-24678",
+    "DOTS-2468",
+    "DOTS-247",
+    "DOTS-2478",
+    "DOTS-248",
+    "DOTS-25",
+    "DOTS-256",
+    "DOTS-2567",
+    "DOTS-25678",
+    "DOTS-2568",
+    "DOTS-257",
+    "DOTS-2578",
+    "DOTS-258",
+    "DOTS-26",
+    "DOTS-267",
+    "DOTS-2678",
+    "DOTS-268",
+    "DOTS-27",
+    "DOTS-278",
+    "DOTS-28",
+    "DOTS-3",
+    "DOTS-34",
+    "DOTS-345",
+    "DOTS-3456",
+    "DOTS-34567",
+    "DOTS-345678",
+    "DOTS-34568",
+    "DOTS-3457",
+    "DOTS-34578",
+    "DOTS-3458",
+    "DOTS-346",
+    "DOTS-3467",
+    "DOTS-34678",
+    "DOTS-3468",
+    "DOTS-347",
+    "DOTS-3478",
+    "DOTS-348",
+    "DOTS-35",
+    "DOTS-356",
+    "DOTS-3567",
+    "DOTS-35678",
+    "DOTS-3568",
+    "DOTS-357",
+    "DOTS-3578",
+    "DOTS-358",
+    "DOTS-36",
+    "DOTS-367",
+    "DOTS-3678",
+    "DOTS-368",
+    "DOTS-37",
+    "DOTS-378",
+    "DOTS-38",
+    "DOTS-4",
+    "DOTS-45",
+    "DOTS-456",
+    "DOTS-4567",
+    "DOTS-45678",
+    "DOTS-4568",
+    "DOTS-457",
+    "DOTS-4578",
+    "DOTS-458",
+    "DOTS-46",
+    "DOTS-467",
+    "DOTS-4678",
+    "DOTS-468",
+    "DOTS-47",
+    "DOTS-478",
+    "DOTS-48",
+    "DOTS-5",
+    "DOTS-56",
+    "DOTS-567",
+    "DOTS-5678",
+    "DOTS-568",
+    "DOTS-57",
+    "DOTS-578",
+    "DOTS-58",
+    "DOTS-6",
+    "DOTS-67",
+    "DOTS-678",
+    "DOTS-68",
+    "DOTS-7",
+    "DOTS-78",
+    "DOTS-8",
+    "DOTTED-L",
+    "DOTTED-N",
+    "DOTTED-P",
+    "DOUBLE-ENDED",
+    "DOUBT",
+    "DOUGHNUT",
+    "DOWNSCALING",
+    "DOX",
+    "DRAFTING",
+    "DRIL",
+    "DROMEDARY",
+    "DROOLING",
+    "DROP",
+    "DROPS",
+    "DRUMSTICKS",
+    "DUB2",
+    "DUCK",
+    "DUDA",
+    "DUGUD",
+    "DUH",
+    "DUM",
+    "DUMPLING",
+    "DUN4",
+    "DUO",
+    "DUOX",
+    "DUP",
+    "DUPONDIUS",
+    "DUR",
+    "DUR2",
+    "DURATION",
+    "DURX",
+    "DUSHENNA",
+    "DUSK",
+    "DUT",
+    "DUTIES",
+    "DUX",
+    "DVA",
+    "DVD",
+    "DVISVARA",
+    "DVOECHELNOKRYZHEVAYA",
+    "DVOETOCHIE",
+    "DVUMYA",
+    "DWA",
+    "DWO",
+    "DYAN",
+    "DZAA",
+    "DZAY",
+    "DZHOI",
+    "DZITA",
+    "DZYAY",
+    "DZYI",
+    "DZZA",
+    "E-2",
+    "E-3",
+    "E-4",
+    "E-5",
+    "E-6",
+    "E-MAIL",
+    "E001",
+    "E002",
+    "E003",
+    "E004",
+    "E005",
+    "E006",
+    "E007",
+    "E008",
+    "E008A",
+    "E009",
+    "E009A",
+    "E010",
+    "E011",
+    "E012",
+    "E013",
+    "E014",
+    "E015",
+    "E016",
+    "E016A",
+    "E017",
+    "E017A",
+    "E018",
+    "E019",
+    "E020",
+    "E020A",
+    "E021",
+    "E022",
+    "E023",
+    "E024",
+    "E025",
+    "E026",
+    "E027",
+    "E028",
+    "E028A",
+    "E029",
+    "E030",
+    "E031",
+    "E032",
+    "E033",
+    "E034",
+    "E034A",
+    "E036",
+    "E037",
+    "E038",
+    "EABHADH",
+    "EADHADH",
+    "EAMHANCHOLL",
+    "EARLY",
+    "EASE",
+    "EBEFILI",
+    "ECLIPSE",
+    "EDD",
+    "EDIN",
+    "EDITORIAL",
+    "EEBEEFILI",
+    "EEH",
+    "EEKAA",
+    "EEN",
+    "EEYANNA",
+    "EGGS",
+    "EGIR",
+    "EHWAZ",
+    "EIGHT-THIRTY",
+    "EIGHTIETH",
+    "EIS",
+    "EJECT",
+    "EKAM",
+    "EKARA",
+    "EKO",
+    "EKS",
+    "EKSTREPTON",
+    "ELAFRON",
+    "ELECTRICAL",
+    "ELECTRONICS",
+    "ELEVATOR",
+    "ELEVATUS",
+    "ELEVEN-THIRTY",
+    "ELF",
+    "ELIF",
+    "ELL",
+    "ELT",
+    "EMBELLISHMENT",
+    "EMBLEM",
+    "EMBROIDERY",
+    "EMPHATIC",
+    "ENARMONIOS",
+    "ENARXIS",
+    "ENCOUNTERS",
+    "ENDEAVOUR",
+    "ENDEP",
+    "ENDOFONON",
+    "ENLARGEMENT",
+    "ENNI",
+    "ENOS",
+    "ENQUIRY",
+    "ENTERING",
+    "ENTHUSIASM",
+    "ENTRY-1",
+    "ENTRY-2",
+    "ENUMERATION",
+    "EO-EU",
+    "EO-O",
+    "EO-U",
+    "EOH",
+    "EOLHX",
+    "EPEGERMA",
+    "EPOCH",
+    "EQ",
+    "EQUIANGULAR",
+    "EQUID",
+    "ERG",
+    "ERS",
+    "ES-1",
+    "ES-2",
+    "ES-3",
+    "ES-TE",
+    "ESH16",
+    "ESH21",
+    "ESO",
+    "ESS",
+    "ESTIMATED",
+    "ESTIMATES",
+    "ESUKUUDO",
+    "ETHEL",
+    "ETNAHTA",
+    "ETT",
+    "EU-A",
+    "EU-E",
+    "EU-EO",
+    "EU-EU",
+    "EU-O",
+    "EU-U",
+    "EULER",
+    "EURO-CURRENCY",
+    "EUROPE-AFRICA",
+    "EVENING",
+    "EVERGREEN",
+    "EVERY",
+    "EXCELLENT",
+    "EXCESS",
+    "EXCHANGE",
+    "EXCITEMENT",
+    "EXHALE",
+    "EXHAUSTION",
+    "EXIST",
+    "EXISTS",
+    "EXPLODING",
+    "EXPONENT",
+    "EXPRESSIONLESS",
+    "EXTINGUISHER",
+    "EXTRA",
+    "EXTRATERRESTRIAL",
+    "EYANNA",
+    "EYBEYFILI",
+    "EYEBROW",
+    "EYEGLASSES",
+    "EYYY",
+    "F001",
+    "F001A",
+    "F002",
+    "F003",
+    "F004",
+    "F005",
+    "F006",
+    "F007",
+    "F008",
+    "F009",
+    "F010",
+    "F011",
+    "F012",
+    "F013",
+    "F013A",
+    "F014",
+    "F015",
+    "F016",
+    "F017",
+    "F018",
+    "F019",
+    "F020",
+    "F021",
+    "F021A",
+    "F022",
+    "F023",
+    "F024",
+    "F025",
+    "F026",
+    "F027",
+    "F028",
+    "F029",
+    "F030",
+    "F031",
+    "F031A",
+    "F032",
+    "F033",
+    "F034",
+    "F035",
+    "F036",
+    "F037",
+    "F037A",
+    "F038",
+    "F038A",
+    "F039",
+    "F040",
+    "F041",
+    "F042",
+    "F043",
+    "F044",
+    "F045",
+    "F045A",
+    "F046",
+    "F046A",
+    "F047",
+    "F047A",
+    "F048",
+    "F049",
+    "F050",
+    "F051",
+    "F051A",
+    "F051B",
+    "F051C",
+    "F052",
+    "F053",
+    "FAAFU",
+    "FAAI",
+    "FAAMAE",
+    "FACE-1",
+    "FACE-2",
+    "FACE-3",
+    "FACE-4",
+    "FACE-5",
+    "FACE-6",
+    "FACINGS",
+    "FACSIMILE",
+    "FACTORY",
+    "FAHRENHEIT",
+    "FAIB",
+    "FAIHU",
+    "FAILURE",
+    "FAIRY",
+    "FAJ",
+    "FALAFEL",
+    "FALLEN",
+    "FAM",
+    "FANG",
+    "FAP",
+    "FAQ",
+    "FAR",
+    "FAST",
+    "FAT",
+    "FATIGUE",
+    "FAYANNA",
+    "FEAR",
+    "FEARFUL",
+    "FEARN",
+    "FEBRUARY",
+    "FEEM",
+    "FEENG",
+    "FEHU",
+    "FELLOWSHIP",
+    "FENCER",
+    "FEOH",
+    "FERRIS",
+    "FERRY",
+    "FESTIVAL",
+    "FETH",
+    "FEUFEUAET",
+    "FEUX",
+    "FF",
+    "FFI",
+    "FFL",
+    "FHTORA",
+    "FIGHT",
+    "FIGURE-1",
+    "FIGURE-2",
+    "FIGURE-3",
+    "FII",
+    "FILLER-1",
+    "FILLER-2",
+    "FINGERNAILS",
+    "FINGERPRINT",
+    "FINITE",
+    "FIP",
+    "FIRECRACKER",
+    "FIREWORK",
+    "FIREWORKS",
+    "FIRI",
+    "FISHEYE",
+    "FISHING",
+    "FISTED",
+    "FIT",
+    "FIVE-LIKE",
+    "FIVE-LINE",
+    "FIVE-THIRTY",
+    "FIX",
+    "FL",
+    "FLA",
+    "FLAG-1",
+    "FLAG-2",
+    "FLAG-3",
+    "FLAG-4",
+    "FLAG-5",
+    "FLAGS",
+    "FLAMINGO",
+    "FLAPPING",
+    "FLASH",
+    "FLATNESS",
+    "FLEUR-DE-LIS",
+    "FLEXED",
+    "FLIGHT",
+    "FLOORPLANE",
+    "FLOWERS",
+    "FLOWING",
+    "FLUSHED",
+    "FM",
+    "FOG",
+    "FOGGY",
+    "FOLDED",
+    "FOLDING",
+    "FOLLY",
+    "FON",
+    "FONDUE",
+    "FONGMAN",
+    "FOOL",
+    "FOOTPRINTS",
+    "FOOTSTOOL",
+    "FOP",
+    "FORCE",
+    "FORCES",
+    "FORKING",
+    "FORMATTING",
+    "FORMS",
+    "FORTE",
+    "FOSTERING",
+    "FOUR-LINE",
+    "FOUR-PER-EM",
+    "FOUR-STRING",
+    "FOUR-THIRTY",
+    "FRAGMENT",
+    "FRAGRANT",
+    "FRAME-4",
+    "FRAMES",
+    "FRANC",
+    "FREEZING",
+    "FRICATIVE",
+    "FRIED",
+    "FRIES",
+    "FRONT-FACING",
+    "FUA",
+    "FUEL",
+    "FUET",
+    "FUJI",
+    "FULLNESS",
+    "FUNERAL",
+    "FUNNEL",
+    "FUP",
+    "FURX",
+    "FUSE",
+    "FUT",
+    "FUX",
+    "FWE",
+    "FY",
+    "FYA",
+    "FYP",
+    "FYT",
+    "FYX",
+    "G001",
+    "G002",
+    "G003",
+    "G004",
+    "G005",
+    "G006",
+    "G006A",
+    "G007",
+    "G007A",
+    "G007B",
+    "G008",
+    "G009",
+    "G010",
+    "G011",
+    "G011A",
+    "G012",
+    "G013",
+    "G014",
+    "G015",
+    "G016",
+    "G017",
+    "G018",
+    "G019",
+    "G020",
+    "G020A",
+    "G021",
+    "G022",
+    "G023",
+    "G024",
+    "G025",
+    "G026",
+    "G026A",
+    "G027",
+    "G028",
+    "G029",
+    "G030",
+    "G031",
+    "G032",
+    "G033",
+    "G034",
+    "G035",
+    "G036",
+    "G036A",
+    "G037",
+    "G037A",
+    "G038",
+    "G039",
+    "G040",
+    "G041",
+    "G042",
+    "G043",
+    "G043A",
+    "G044",
+    "G045",
+    "G045A",
+    "G046",
+    "G047",
+    "G048",
+    "G049",
+    "G050",
+    "G051",
+    "G052",
+    "G053",
+    "G054",
+    "G2",
+    "GAAFU",
+    "GAAHLAA",
+    "GADOL",
+    "GAG",
+    "GAI",
+    "GALAM",
+    "GAM",
+    "GAMAN",
+    "GAML",
+    "GAMLA",
+    "GANDA",
+    "GANMA",
+    "GAR3",
+    "GARDEN",
+    "GARLIC",
+    "GARMENT",
+    "GARON",
+    "GASHAN",
+    "GAT",
+    "GAUNTLET",
+    "GAX",
+    "GB",
+    "GBAKURUNEN",
+    "GBAYI",
+    "GBEN",
+    "GBET",
+    "GBEUX",
+    "GBIEE",
+    "GBON",
+    "GCAN",
+    "GDAN",
+    "GE22",
+    "GEBA",
+    "GEBO",
+    "GEDE",
+    "GEDOLA",
+    "GEEM",
+    "GEM",
+    "GEMINATE",
+    "GEMINI",
+    "GEN",
+    "GENERIC",
+    "GENIE",
+    "GENITIVE",
+    "GENTLE",
+    "GEOMETRIC",
+    "GER",
+    "GERMAN",
+    "GET",
+    "GETA",
+    "GEX",
+    "GG",
+    "GGAA",
+    "GGAP",
+    "GGAT",
+    "GGAX",
+    "GGEE",
+    "GGEP",
+    "GGET",
+    "GGEX",
+    "GGIE",
+    "GGIEP",
+    "GGIEX",
+    "GGIT",
+    "GGIX",
+    "GGOT",
+    "GGOX",
+    "GGUOP",
+    "GGUOT",
+    "GGUOX",
+    "GGUP",
+    "GGUR",
+    "GGURX",
+    "GGUT",
+    "GGUX",
+    "GGWA",
+    "GGWAA",
+    "GGWE",
+    "GGWEE",
+    "GGWI",
+    "GH",
+    "GHAAMAE",
+    "GHAINU",
+    "GHAMAL",
+    "GHAMMA",
+    "GHAP",
+    "GHARAE",
+    "GHAYN",
+    "GHEE",
+    "GHET",
+    "GHEUAEGHEUAE",
+    "GHEUAERAE",
+    "GHEUGHEN",
+    "GHEUGHEUAEM",
+    "GHEUN",
+    "GHEUX",
+    "GHEYS",
+    "GHI",
+    "GHIMEL",
+    "GHOM",
+    "GHOU",
+    "GHU",
+    "GHWA",
+    "GHZ",
+    "GIBA",
+    "GIDIM",
+    "GIE",
+    "GIEP",
+    "GIET",
+    "GIEX",
+    "GIFT",
+    "GIG",
+    "GIGA",
+    "GIL",
+    "GIMEL-HETH",
+    "GINGER",
+    "GINII",
+    "GIP",
+    "GIRAFFE",
+    "GIRL",
+    "GIRLS",
+    "GIRUDAA",
+    "GISAL",
+    "GIT",
+    "GIX",
+    "GJA",
+    "GLA",
+    "GLASSES",
+    "GLEICH",
+    "GLOVE",
+    "GLOVES",
+    "GLOWING",
+    "GN",
+    "GNAVIYANI",
+    "GOA",
+    "GOAT",
+    "GOBLIN",
+    "GOGGLES",
+    "GOING",
+    "GOK",
+    "GOLFER",
+    "GONG",
+    "GONGGONG",
+    "GOO",
+    "GOOD",
+    "GOOSE",
+    "GOP",
+    "GORA",
+    "GORGOSYNTHETON",
+    "GORGOTERI",
+    "GORILLA",
+    "GORT",
+    "GOX",
+    "GPA",
+    "GRADUAL",
+    "GRADUATION",
+    "GRAF",
+    "GRAIN",
+    "GRAMMA",
+    "GRAPES",
+    "GRAPHEME",
+    "GRATER",
+    "GRAVE-ACUTE-GRAVE",
+    "GRAVE-MACRON",
+    "GRAVEYARD",
+    "GREATNESS",
+    "GREY",
+    "GRIMACING",
+    "GRONTHISMATA",
+    "GROWING",
+    "GUA",
+    "GUAN",
+    "GUARANI",
+    "GUARDEDNESS",
+    "GUARDSMAN",
+    "GUEI",
+    "GUIDE",
+    "GUITAR",
+    "GUO",
+    "GUOP",
+    "GUOT",
+    "GUOX",
+    "GUP",
+    "GURAMU",
+    "GURAMUTON",
+    "GURUN",
+    "GURUSH",
+    "GURX",
+    "GUT",
+    "GUX",
+    "GVANG",
+    "GW",
+    "GWAA",
+    "GWU",
+    "GY",
+    "GYA",
+    "GYAA",
+    "GYAN",
+    "GYE",
+    "GYEE",
+    "GYFU",
+    "GYI",
+    "GYO",
+    "GYU",
+    "H-TYPE",
+    "H001",
+    "H002",
+    "H003",
+    "H004",
+    "H005",
+    "H006",
+    "H006A",
+    "H007",
+    "H008",
+    "HA-1",
+    "HA-10",
+    "HA-11",
+    "HA-2",
+    "HA-3",
+    "HA-4",
+    "HA-5",
+    "HA-6",
+    "HA-7",
+    "HA-8",
+    "HA-9",
+    "HA-HA",
+    "HAAM",
+    "HAARU",
+    "HADES",
+    "HAEGL",
+    "HAFUKH",
+    "HAFUKHA",
+    "HAGL",
+    "HAGLAZ",
+    "HAI",
+    "HAIRCUT",
+    "HAIS",
+    "HAITU",
+    "HALBERD",
+    "HALF-1",
+    "HALF-2",
+    "HALO",
+    "HALQA",
+    "HAM",
+    "HAMBURGER",
+    "HAMSA",
+    "HAMSO",
+    "HAMSTER",
+    "HAN-AKAT",
+    "HANDBAG",
+    "HANDBALL",
+    "HANDLES",
+    "HANDSHAKE",
+    "HANG",
+    "HAO",
+    "HAP",
+    "HAPPY",
+    "HARBAHAY",
+    "HARDNESS",
+    "HARMONIC",
+    "HARP",
+    "HASER",
+    "HATCHING",
+    "HATHI",
+    "HAUMEA",
+    "HAUPTSTIMME",
+    "HAWJ",
+    "HAX",
+    "HAY",
+    "HAYANNA",
+    "HBASA-ESASA",
+    "HC",
+    "HDR",
+    "HE-1",
+    "HE-2",
+    "HE-3",
+    "HE-4",
+    "HE-5",
+    "HE-6",
+    "HE-7",
+    "HE-GOAT",
+    "HEAD-BANDAGE",
+    "HEADING",
+    "HEADPHONE",
+    "HEADSCARF",
+    "HEAR-NO-EVIL",
+    "HEARING",
+    "HEAVENLY",
+    "HEDGEHOG",
+    "HEEI",
+    "HEIGHT",
+    "HEISEI",
+    "HEKUTAARU",
+    "HELICOPTER",
+    "HELIX",
+    "HELLSCHREIBER",
+    "HELM",
+    "HEMP",
+    "HEP",
+    "HERAEUM",
+    "HERB",
+    "HERMES",
+    "HERMITIAN",
+    "HERUTU",
+    "HEX",
+    "HEXIFORM",
+    "HEYT",
+    "HHE",
+    "HHEE",
+    "HHI",
+    "HHO",
+    "HHU",
+    "HHWE",
+    "HHWEE",
+    "HHWI",
+    "HHYA",
+    "HHYAA",
+    "HHYE",
+    "HHYEE",
+    "HHYI",
+    "HHYO",
+    "HHYU",
+    "HI-1",
+    "HI-2",
+    "HI-3",
+    "HI-4",
+    "HI-5",
+    "HI-6",
+    "HI-7",
+    "HI-RES",
+    "HIBISCUS",
+    "HIDE",
+    "HIDET",
+    "HIDING",
+    "HIEUH-MIEUM",
+    "HIEUH-NIEUN",
+    "HIEUH-PIEUP",
+    "HIEUH-RIEUL",
+    "HIEUH-SIOS",
+    "HIEX",
+    "HIGH-HEELED",
+    "HIGH-LOW",
+    "HIKING",
+    "HINDU",
+    "HIPPOPOTAMUS",
+    "HISTORIC",
+    "HIZB",
+    "HJA",
+    "HK",
+    "HL",
+    "HLAP",
+    "HLAT",
+    "HLAU",
+    "HLAX",
+    "HLE",
+    "HLEP",
+    "HLEX",
+    "HLIE",
+    "HLIEP",
+    "HLIEX",
+    "HLIP",
+    "HLIT",
+    "HLIX",
+    "HLO",
+    "HLOP",
+    "HLOX",
+    "HLU",
+    "HLUO",
+    "HLUOP",
+    "HLUOX",
+    "HLUP",
+    "HLUR",
+    "HLURX",
+    "HLUT",
+    "HLUX",
+    "HLY",
+    "HLYP",
+    "HLYR",
+    "HLYRX",
+    "HLYT",
+    "HLYX",
+    "HM",
+    "HMA",
+    "HMAP",
+    "HMAT",
+    "HMAX",
+    "HME",
+    "HMI",
+    "HMIE",
+    "HMIEP",
+    "HMIEX",
+    "HMIP",
+    "HMIT",
+    "HMIX",
+    "HMOP",
+    "HMOT",
+    "HMOX",
+    "HMU",
+    "HMUO",
+    "HMUOP",
+    "HMUOX",
+    "HMUP",
+    "HMUR",
+    "HMURX",
+    "HMUT",
+    "HMUX",
+    "HMY",
+    "HMYP",
+    "HMYR",
+    "HMYRX",
+    "HMYX",
+    "HNAP",
+    "HNAT",
+    "HNAU",
+    "HNAX",
+    "HNE",
+    "HNEP",
+    "HNEX",
+    "HNI",
+    "HNIE",
+    "HNIEP",
+    "HNIET",
+    "HNIEX",
+    "HNIP",
+    "HNIT",
+    "HNIX",
+    "HNOP",
+    "HNOT",
+    "HNOX",
+    "HNUB",
+    "HNUO",
+    "HNUOX",
+    "HNUT",
+    "HO-1",
+    "HO-2",
+    "HO-3",
+    "HO-4",
+    "HO-5",
+    "HO-6",
+    "HO-7",
+    "HO-8",
+    "HOA",
+    "HOCHO",
+    "HODDOND",
+    "HOI",
+    "HOKA",
+    "HOM",
+    "HONEY",
+    "HONEYBEE",
+    "HOON",
+    "HOOP",
+    "HOORU",
+    "HOOU",
+    "HOP",
+    "HORIZON",
+    "HORIZONTAL-00-00",
+    "HORIZONTAL-00-01",
+    "HORIZONTAL-00-02",
+    "HORIZONTAL-00-03",
+    "HORIZONTAL-00-04",
+    "HORIZONTAL-00-05",
+    "HORIZONTAL-00-06",
+    "HORIZONTAL-01-00",
+    "HORIZONTAL-01-01",
+    "HORIZONTAL-01-02",
+    "HORIZONTAL-01-03",
+    "HORIZONTAL-01-04",
+    "HORIZONTAL-01-05",
+    "HORIZONTAL-01-06",
+    "HORIZONTAL-02-00",
+    "HORIZONTAL-02-01",
+    "HORIZONTAL-02-02",
+    "HORIZONTAL-02-03",
+    "HORIZONTAL-02-04",
+    "HORIZONTAL-02-05",
+    "HORIZONTAL-02-06",
+    "HORIZONTAL-03-00",
+    "HORIZONTAL-03-01",
+    "HORIZONTAL-03-02",
+    "HORIZONTAL-03-03",
+    "HORIZONTAL-03-04",
+    "HORIZONTAL-03-05",
+    "HORIZONTAL-03-06",
+    "HORIZONTAL-04-00",
+    "HORIZONTAL-04-01",
+    "HORIZONTAL-04-02",
+    "HORIZONTAL-04-03",
+    "HORIZONTAL-04-04",
+    "HORIZONTAL-04-05",
+    "HORIZONTAL-04-06",
+    "HORIZONTAL-05-00",
+    "HORIZONTAL-05-01",
+    "HORIZONTAL-05-02",
+    "HORIZONTAL-05-03",
+    "HORIZONTAL-05-04",
+    "HORIZONTAL-05-05",
+    "HORIZONTAL-05-06",
+    "HORIZONTAL-06-00",
+    "HORIZONTAL-06-01",
+    "HORIZONTAL-06-02",
+    "HORIZONTAL-06-03",
+    "HORIZONTAL-06-04",
+    "HORIZONTAL-06-05",
+    "HORIZONTAL-06-06",
+    "HOSPITAL",
+    "HOTA",
+    "HOX",
+    "HOY",
+    "HPA",
+    "HPWG",
+    "HRYVNIA",
+    "HTTA",
+    "HU-1",
+    "HU-2",
+    "HU-3",
+    "HUAN",
+    "HUARADDO",
+    "HUB",
+    "HUIITO",
+    "HUK",
+    "HUL2",
+    "HUNG",
+    "HUO",
+    "HUOP",
+    "HUOT",
+    "HUOX",
+    "HURAN",
+    "HUSH",
+    "HUSHED",
+    "HUT",
+    "HUVA",
+    "HWA",
+    "HWAH",
+    "HWEE",
+    "HWI",
+    "HWO",
+    "HWU",
+    "HXA",
+    "HXAP",
+    "HXAT",
+    "HXAX",
+    "HXE",
+    "HXEP",
+    "HXEX",
+    "HXG",
+    "HXI",
+    "HXIE",
+    "HXIEP",
+    "HXIET",
+    "HXIEX",
+    "HXIP",
+    "HXIX",
+    "HXO",
+    "HXOT",
+    "HXOX",
+    "HXUOP",
+    "HXUOT",
+    "HXUOX",
+    "HXWG",
+    "HYACINTH",
+    "HYGIEA",
+    "HYGIEIA",
+    "HYPODIASTOLE",
+    "HYSTERESIS",
+    "HZG",
+    "HZT",
+    "HZW",
+    "HZWG",
+    "HZZ",
+    "HZZP",
+    "HZZZ",
+    "HZZZG",
+    "I-1",
+    "I-2",
+    "I-3",
+    "I-4",
+    "I-A",
+    "I-ARAEA",
+    "I-BEAM",
+    "I-EU",
+    "I-I",
+    "I-O",
+    "I-O-I",
+    "I-U",
+    "I-YA",
+    "I-YA-O",
+    "I-YAE",
+    "I-YE",
+    "I-YEO",
+    "I-YO",
+    "I-YU",
+    "I001",
+    "I002",
+    "I003",
+    "I004",
+    "I005",
+    "I005A",
+    "I006",
+    "I007",
+    "I008",
+    "I009",
+    "I009A",
+    "I010",
+    "I010A",
+    "I011",
+    "I011A",
+    "I012",
+    "I013",
+    "I014",
+    "I015",
+    "IANG",
+    "IBIFILI",
+    "ICELANDIC-YR",
+    "ICHADIN",
+    "ICHOU",
+    "IDD",
+    "IDEOGRAPH-4E00",
+    "IDEOGRAPH-4E2D",
+    "IDEOGRAPH-4EA4",
+    "IDEOGRAPH-518D",
+    "IDEOGRAPH-521D",
+    "IDEOGRAPH-524D",
+    "IDEOGRAPH-5272",
+    "IDEOGRAPH-52DD",
+    "IDEOGRAPH-53CC",
+    "IDEOGRAPH-53F3",
+    "IDEOGRAPH-5408",
+    "IDEOGRAPH-5439",
+    "IDEOGRAPH-55B6",
+    "IDEOGRAPH-58F0",
+    "IDEOGRAPH-591A",
+    "IDEOGRAPH-5929",
+    "IDEOGRAPH-5B57",
+    "IDEOGRAPH-5B89",
+    "IDEOGRAPH-5DE6",
+    "IDEOGRAPH-5F8C",
+    "IDEOGRAPH-624B",
+    "IDEOGRAPH-6295",
+    "IDEOGRAPH-6307",
+    "IDEOGRAPH-6355",
+    "IDEOGRAPH-6557",
+    "IDEOGRAPH-6599",
+    "IDEOGRAPH-65B0",
+    "IDEOGRAPH-6620",
+    "IDEOGRAPH-6708",
+    "IDEOGRAPH-6709",
+    "IDEOGRAPH-672C",
+    "IDEOGRAPH-6E80",
+    "IDEOGRAPH-6F14",
+    "IDEOGRAPH-70B9",
+    "IDEOGRAPH-7121",
+    "IDEOGRAPH-751F",
+    "IDEOGRAPH-7533",
+    "IDEOGRAPH-76D7",
+    "IDEOGRAPH-7981",
+    "IDEOGRAPH-7A7A",
+    "IDEOGRAPH-7D42",
+    "IDEOGRAPH-89E3",
+    "IDEOGRAPH-8CA9",
+    "IDEOGRAPH-8D70",
+    "IDEOGRAPH-904A",
+    "IDEOGRAPH-914D",
+    "IDLE",
+    "IEP",
+    "IET",
+    "IEUNG-CHIEUCH",
+    "IEUNG-CIEUC",
+    "IEUNG-HIEUH",
+    "IEUNG-KHIEUKH",
+    "IEUNG-MIEUM",
+    "IEUNG-PANSIOS",
+    "IEUNG-PHIEUPH",
+    "IEUNG-PIEUP",
+    "IEUNG-RIEUL",
+    "IEUNG-SIOS",
+    "IEUNG-SSANGKIYEOK",
+    "IEUNG-THIEUTH",
+    "IEUNG-TIKEUT",
+    "IEX",
+    "IF",
+    "IFIN",
+    "IGGWS",
+    "IIYANNA",
+    "IL2",
+    "ILIMMU3",
+    "ILIMMU4",
+    "ILUT",
+    "ILUUYANNA",
+    "ILUY",
+    "ILUYANNA",
+    "IMAALA",
+    "IMAR",
+    "IMIDIARGON",
+    "IMIFONON",
+    "IMIFTHORA",
+    "IMIFTHORON",
+    "IMIN3",
+    "IMISEOS",
+    "IMN",
+    "IMP",
+    "IN-ALAF",
+    "INAP",
+    "INBOX",
+    "INCH",
+    "INCLUDING",
+    "INCOMING",
+    "INCOMPLETE",
+    "INCREASES",
+    "INCREMENT",
+    "INDIAN",
+    "INDICTION",
+    "INDIRECT",
+    "INDUSTRIAL",
+    "INFLUENCE",
+    "INGWAZ",
+    "INHALE",
+    "ININGU",
+    "INK",
+    "INN",
+    "INNN",
+    "INNOCENCE",
+    "INPUTS",
+    "INSECT",
+    "INTERCALATE",
+    "INTEREST",
+    "INTERLOCKED",
+    "INTERSYLLABIC",
+    "INTI",
+    "INVERTEBRATE",
+    "INWARD",
+    "INY",
+    "INYA",
+    "IODHADH",
+    "IONG",
+    "IOR",
+    "IRB",
+    "IRI",
+    "IRON-COPPER",
+    "IRUUYANNA",
+    "IRUYANNA",
+    "ISAKIA",
+    "ISAZ",
+    "ISEN-ISEN",
+    "ISHMAAM",
+    "ISLAND",
+    "ISS",
+    "ITEM",
+    "ITS",
+    "ITT",
+    "IUJA",
+    "IWAZ",
+    "IWN",
+    "IYANNA",
+    "IZ",
+    "IZAKAYA",
+    "JACK-O-LANTERN",
+    "JACKS",
+    "JAH",
+    "JAIN",
+    "JALL",
+    "JALLAJALALOUHOU",
+    "JANUARY",
+    "JAPAN",
+    "JAVIYANI",
+    "JAYIN",
+    "JAYN",
+    "JEANS",
+    "JELLYFISH",
+    "JER",
+    "JERA",
+    "JERAN",
+    "JERUSALEM",
+    "JEU",
+    "JHAA",
+    "JHAM",
+    "JHAYIN",
+    "JHO",
+    "JHOX",
+    "JIA",
+    "JIE",
+    "JIEP",
+    "JIET",
+    "JIEX",
+    "JIGSAW",
+    "JIP",
+    "JIT",
+    "JIX",
+    "JJE",
+    "JJEE",
+    "JJIEP",
+    "JJIET",
+    "JJIEX",
+    "JJIP",
+    "JJIT",
+    "JJIX",
+    "JJOP",
+    "JJOT",
+    "JJOX",
+    "JJUO",
+    "JJUOP",
+    "JJUOX",
+    "JJUP",
+    "JJUR",
+    "JJURX",
+    "JJUX",
+    "JJYP",
+    "JJYT",
+    "JJYX",
+    "JOA",
+    "JOINT",
+    "JOINTS",
+    "JONG",
+    "JOP",
+    "JOVE",
+    "JOX",
+    "JOYOUS",
+    "JOYSTICK",
+    "JUDEO-SPANISH",
+    "JUDGE",
+    "JUDUL",
+    "JUEUI",
+    "JUGGLING",
+    "JULY",
+    "JUNE",
+    "JUNO",
+    "JUO",
+    "JUOP",
+    "JUOT",
+    "JUOX",
+    "JUP",
+    "JUPITER",
+    "JUR",
+    "JURX",
+    "JUT",
+    "JUU",
+    "JUX",
+    "JYAH",
+    "JYP",
+    "JYR",
+    "JYRX",
+    "JYT",
+    "JYX",
+    "K001",
+    "K002",
+    "K003",
+    "K004",
+    "K005",
+    "K006",
+    "K007",
+    "K008",
+    "K2",
+    "KA-1",
+    "KA-10",
+    "KA-11",
+    "KA-2",
+    "KA-3",
+    "KA-4",
+    "KA-5",
+    "KA-6",
+    "KA-7",
+    "KA-8",
+    "KA-9",
+    "KA-KE",
+    "KAAB",
+    "KAABA",
+    "KAACU",
+    "KAAFU",
+    "KAAI",
+    "KAAN",
+    "KAANKUU",
+    "KAAV",
+    "KACHKA",
+    "KAD",
+    "KAD4",
+    "KAFA",
+    "KAIB",
+    "KAIRI",
+    "KAIV",
+    "KAKABAT",
+    "KAM",
+    "KAM2",
+    "KAM4",
+    "KANAKO",
+    "KANGAROO",
+    "KANTAJA",
+    "KAPAL",
+    "KAPO",
+    "KAQ",
+    "KAR",
+    "KARAN",
+    "KARATTO",
+    "KAROR",
+    "KARORAN",
+    "KARORII",
+    "KARSHANA",
+    "KAT",
+    "KATAVA",
+    "KATAVASMA",
+    "KATHISTI",
+    "KAUB",
+    "KAUN",
+    "KAUNA",
+    "KAUV",
+    "KAV",
+    "KAWB",
+    "KAWV",
+    "KAX",
+    "KB",
+    "KCAL",
+    "KE-1",
+    "KE-2",
+    "KE-3",
+    "KE-4",
+    "KE-5",
+    "KE-6",
+    "KEAAE",
+    "KEB",
+    "KEEB",
+    "KEENG",
+    "KEESU",
+    "KEEV",
+    "KEFULA",
+    "KELVIN",
+    "KEMBANG",
+    "KEMPHRENG",
+    "KENAT",
+    "KEOW",
+    "KEP",
+    "KERET",
+    "KES",
+    "KEUAE",
+    "KEUAEM",
+    "KEUAERI",
+    "KEUAETMEUN",
+    "KEUKAQ",
+    "KEUKEUTNDA",
+    "KEUOT",
+    "KEUP",
+    "KEUPUQ",
+    "KEUSEUX",
+    "KEUSHEUAEP",
+    "KEUYEUX",
+    "KEV",
+    "KEX",
+    "KEYHOLE",
+    "KHAB",
+    "KHAF",
+    "KHAMILO",
+    "KHAN",
+    "KHAV",
+    "KHEE",
+    "KHETH",
+    "KHIT",
+    "KHITAN",
+    "KHOMUT",
+    "KHON",
+    "KHONNA",
+    "KHOT",
+    "KHOU",
+    "KHU",
+    "KHUAT",
+    "KHUDAM",
+    "KHUEN-LUE",
+    "KHWAI",
+    "KHZ",
+    "KI-1",
+    "KI-2",
+    "KI-3",
+    "KI-4",
+    "KI-5",
+    "KI-6",
+    "KI-7",
+    "KI-8",
+    "KIAB",
+    "KIAV",
+    "KIB",
+    "KICK",
+    "KIE",
+    "KIEEM",
+    "KIEP",
+    "KIEX",
+    "KIH",
+    "KII",
+    "KIIZH",
+    "KIK",
+    "KIMONO",
+    "KINDERGARTEN",
+    "KINSHIP",
+    "KIQ",
+    "KIRO",
+    "KIROGURAMU",
+    "KIROMEETORU",
+    "KIROWATTO",
+    "KISAL",
+    "KISH",
+    "KITE",
+    "KIV",
+    "KIW",
+    "KIWIFRUIT",
+    "KIX",
+    "KIYEOK-CHIEUCH",
+    "KIYEOK-HIEUH",
+    "KIYEOK-KHIEUKH",
+    "KIYEOK-NIEUN",
+    "KIYEOK-PIEUP",
+    "KIYEOK-RIEUL",
+    "KIYEOK-SIOS-KIYEOK",
+    "KIYEOK-TIKEUT",
+    "KKE",
+    "KKEE",
+    "KKI",
+    "KKO",
+    "KKU",
+    "KL",
+    "KLA",
+    "KLITON",
+    "KLOKO",
+    "KLYUCHENEPOSTOYANNY",
+    "KLYUCHEPOVODNAYA",
+    "KLYUCHEPOVODNY",
+    "KLYUCHEVOY",
+    "KNEELING",
+    "KNOBS",
+    "KNOT",
+    "KO-1",
+    "KO-2",
+    "KO-3",
+    "KO-KI",
+    "KOA",
+    "KOALA",
+    "KOB",
+    "KOBYLA",
+    "KOGHOM",
+    "KOH",
+    "KOINI",
+    "KOKE",
+    "KOKO",
+    "KOMBU",
+    "KOOB",
+    "KOOMUUT",
+    "KOOPO",
+    "KOOV",
+    "KOP",
+    "KOQNDON",
+    "KORON",
+    "KORUNA",
+    "KOT",
+    "KOUFISMA",
+    "KOV",
+    "KOVUU",
+    "KOX",
+    "KPAH",
+    "KPAN",
+    "KPARAQ",
+    "KPEN",
+    "KPEUX",
+    "KPOQ",
+    "KRA",
+    "KRATIMATA",
+    "KRATIMOKOUFISMA",
+    "KRATIMOYPORROON",
+    "KREMASTI",
+    "KRONOS",
+    "KRYZHEVAYA",
+    "KT",
+    "KU-1",
+    "KU-2",
+    "KU-3",
+    "KU-4",
+    "KU-5",
+    "KU-6",
+    "KU-7",
+    "KU7",
+    "KUA",
+    "KUAB",
+    "KUAV",
+    "KUB",
+    "KUET",
+    "KUFISMA",
+    "KUG",
+    "KUNDDALIYA",
+    "KUNG",
+    "KUO",
+    "KUOM",
+    "KUOQ",
+    "KUOX",
+    "KUP",
+    "KUPNAYA",
+    "KUQ",
+    "KUROONE",
+    "KURT",
+    "KURUNI",
+    "KURUZEIRO",
+    "KURX",
+    "KUSMA",
+    "KUUH",
+    "KUV",
+    "KUX",
+    "KUZHI",
+    "KV",
+    "KWAET",
+    "KWAY",
+    "KWB",
+    "KWM",
+    "KWU318",
+    "KWV",
+    "KXAA",
+    "KXE",
+    "KXEE",
+    "KXI",
+    "KXO",
+    "KXU",
+    "KXWA",
+    "KXWAA",
+    "KXWE",
+    "KXWEE",
+    "KXWI",
+    "KYAA",
+    "KYATHOS",
+    "KYE",
+    "KYI",
+    "KYLISMA",
+    "KYO",
+    "KYU",
+    "KYURII",
+    "L-TYPE",
+    "L001",
+    "L002",
+    "L002A",
+    "L003",
+    "L004",
+    "L005",
+    "L006",
+    "L006A",
+    "L007",
+    "L008",
+    "L2",
+    "L3",
+    "L4",
+    "L6",
+    "LAAI",
+    "LAAMU",
+    "LAAN",
+    "LAANAE",
+    "LAB",
+    "LABAT",
+    "LABEL",
+    "LABIALIZATION",
+    "LABOURING",
+    "LACA",
+    "LACK",
+    "LACROSSE",
+    "LADY",
+    "LAEV",
+    "LAGU",
+    "LAGUS",
+    "LAH",
+    "LAHSHU",
+    "LAJANYALAN",
+    "LAK-003",
+    "LAK-020",
+    "LAK-021",
+    "LAK-025",
+    "LAK-030",
+    "LAK-051",
+    "LAK-062",
+    "LAK-080",
+    "LAK-092",
+    "LAK-130",
+    "LAK-142",
+    "LAK-210",
+    "LAK-219",
+    "LAK-220",
+    "LAK-225",
+    "LAK-228",
+    "LAK-238",
+    "LAK-265",
+    "LAK-266",
+    "LAK-343",
+    "LAK-347",
+    "LAK-348",
+    "LAK-383",
+    "LAK-384",
+    "LAK-390",
+    "LAK-441",
+    "LAK-450",
+    "LAK-457",
+    "LAK-470",
+    "LAK-483",
+    "LAK-490",
+    "LAK-492",
+    "LAK-493",
+    "LAK-495",
+    "LAK-550",
+    "LAK-608",
+    "LAK-636",
+    "LAK-668",
+    "LAK-724",
+    "LAK-749",
+    "LAKHAN",
+    "LAKKHANGYAO",
+    "LAMADH",
+    "LAMD",
+    "LAND",
+    "LANDER",
+    "LANGUAGE",
+    "LANTERN",
+    "LAPAQ",
+    "LAQ",
+    "LARI",
+    "LARYNGEAL",
+    "LAT",
+    "LATE",
+    "LATIK",
+    "LAU",
+    "LAUGHING",
+    "LAUJ",
+    "LAUKAZ",
+    "LAX",
+    "LAY",
+    "LAYAR",
+    "LCI",
+    "LD",
+    "LD2",
+    "LDAN",
+    "LEAD",
+    "LEADING",
+    "LEAFLESS",
+    "LEAFY",
+    "LEDGER",
+    "LEEK",
+    "LEFT-HANDED",
+    "LEFT-LIGHTED",
+    "LEFT-SIDE",
+    "LEGION",
+    "LEGS",
+    "LEI",
+    "LEMOI",
+    "LEMON",
+    "LENGTH-1",
+    "LENGTH-2",
+    "LENGTH-3",
+    "LENGTH-4",
+    "LENGTH-5",
+    "LENGTH-6",
+    "LENGTH-7",
+    "LEO",
+    "LEOPARD",
+    "LEP",
+    "LESS",
+    "LEUAEM",
+    "LEUAEP",
+    "LEUM",
+    "LEVEL-2",
+    "LEVEL-3",
+    "LEVITATING",
+    "LEX",
+    "LHAG",
+    "LHAVIYANI",
+    "LHE",
+    "LHEE",
+    "LHII",
+    "LHOO",
+    "LHU",
+    "LHYA",
+    "LIABILITY",
+    "LIBERTY",
+    "LIBRA",
+    "LICKING",
+    "LIEE",
+    "LIEP",
+    "LIET",
+    "LIEX",
+    "LIFE",
+    "LIFTER",
+    "LIGATING",
+    "LIGHTHOUSE",
+    "LII",
+    "LIL",
+    "LILITH",
+    "LILY",
+    "LIMBS",
+    "LIME",
+    "LIMIT",
+    "LIMITATION",
+    "LIMITED",
+    "LIMMU2",
+    "LIMMU4",
+    "LINE-1",
+    "LINE-3",
+    "LINE-7",
+    "LINE-9",
+    "LINGSA",
+    "LINKED",
+    "LINKING",
+    "LION",
+    "LIPSTICK",
+    "LIQ",
+    "LIS",
+    "LIT",
+    "LITRA",
+    "LIVRE",
+    "LIX",
+    "LIZARD",
+    "LLAMA",
+    "LLE",
+    "LLHA",
+    "LLL",
+    "LM",
+    "LN",
+    "LOA",
+    "LOACHA",
+    "LOBSTER",
+    "LOCATION-FLOORPLANE",
+    "LOCATION-WALLPLANE",
+    "LOCATIVE",
+    "LODESTONE",
+    "LOG",
+    "LOGR",
+    "LOLL",
+    "LOLLIPOP",
+    "LOMKA",
+    "LOMMAE",
+    "LONG-BRANCH-AR",
+    "LONG-BRANCH-HAGALL",
+    "LONG-BRANCH-MADR",
+    "LONG-BRANCH-OSS",
+    "LONG-BRANCH-SOL",
+    "LONG-BRANCH-YR",
+    "LONG-LEGGED",
+    "LOOK",
+    "LOON",
+    "LOP",
+    "LORRAINE",
+    "LORRY",
+    "LOS",
+    "LOSSLESS",
+    "LOTION",
+    "LOUDLY",
+    "LOUDSPEAKER",
+    "LOURE",
+    "LOW-MID",
+    "LOW-REVERSED-9",
+    "LOX",
+    "LUAEP",
+    "LUB",
+    "LUHUR",
+    "LUIS",
+    "LUNAR",
+    "LUNGS",
+    "LUNGSI",
+    "LUO",
+    "LUOP",
+    "LUOT",
+    "LUOX",
+    "LUP",
+    "LUR",
+    "LURX",
+    "LUS",
+    "LUT",
+    "LUX",
+    "LX",
+    "LYA",
+    "LYGISMA",
+    "LYING",
+    "LYIT",
+    "LYP",
+    "LYRX",
+    "LYT",
+    "LYX",
+    "LYY",
+    "M001A",
+    "M001B",
+    "M003A",
+    "M010A",
+    "M012A",
+    "M012B",
+    "M012C",
+    "M012D",
+    "M012E",
+    "M012F",
+    "M012G",
+    "M012H",
+    "M015A",
+    "M016A",
+    "M017A",
+    "M022A",
+    "M024A",
+    "M028A",
+    "M031A",
+    "M033A",
+    "M033B",
+    "M040A",
+    "M045",
+    "M046",
+    "M047",
+    "M048",
+    "M049",
+    "M050",
+    "M051",
+    "M052",
+    "M053",
+    "M054",
+    "M055",
+    "M056",
+    "M057",
+    "M058",
+    "M059",
+    "M060",
+    "M061",
+    "M062",
+    "M063",
+    "M064",
+    "M065",
+    "M066",
+    "M067",
+    "M068",
+    "M069",
+    "M070",
+    "M071",
+    "M072",
+    "M073",
+    "M074",
+    "M075",
+    "M076",
+    "M077",
+    "M078",
+    "M079",
+    "M080",
+    "M081",
+    "M082",
+    "M083",
+    "M084",
+    "M085",
+    "M086",
+    "M087",
+    "M088",
+    "M089",
+    "M090",
+    "M091",
+    "M092",
+    "M093",
+    "M094",
+    "M095",
+    "M096",
+    "M097",
+    "M098",
+    "M099",
+    "M100",
+    "M101",
+    "M102",
+    "M103",
+    "M104",
+    "M105",
+    "M106",
+    "M107",
+    "M108",
+    "M109",
+    "M110",
+    "M111",
+    "M112",
+    "M113",
+    "M114",
+    "M115",
+    "M116",
+    "M117",
+    "M118",
+    "M119",
+    "M120",
+    "M121",
+    "M122",
+    "M123",
+    "M124",
+    "M125",
+    "M126",
+    "M127",
+    "M128",
+    "M129",
+    "M130",
+    "M131",
+    "M132",
+    "M133",
+    "M134",
+    "M135",
+    "M136",
+    "M137",
+    "M138",
+    "M139",
+    "M140",
+    "M141",
+    "M142",
+    "M143",
+    "M144",
+    "M145",
+    "M146",
+    "M147",
+    "M148",
+    "M149",
+    "M150",
+    "M151",
+    "M152",
+    "M153",
+    "M154",
+    "M155",
+    "M156",
+    "M157",
+    "M158",
+    "M159",
+    "M160",
+    "M161",
+    "M162",
+    "M163",
+    "M164",
+    "M165",
+    "M166",
+    "M167",
+    "M168",
+    "M169",
+    "M170",
+    "M171",
+    "M172",
+    "M173",
+    "M174",
+    "M175",
+    "M176",
+    "M177",
+    "M178",
+    "M179",
+    "M180",
+    "M181",
+    "M182",
+    "M183",
+    "M184",
+    "M185",
+    "M186",
+    "M187",
+    "M188",
+    "M189",
+    "M190",
+    "M191",
+    "M192",
+    "M193",
+    "M194",
+    "M195",
+    "M196",
+    "M197",
+    "MA-1",
+    "MA-2",
+    "MA-3",
+    "MA-4",
+    "MA-5",
+    "MA-6",
+    "MA-7",
+    "MA2",
+    "MAAI",
+    "MAAYYAA",
+    "MACRON-ACUTE",
+    "MACRON-BREVE",
+    "MACRON-GRAVE",
+    "MADU",
+    "MADYA",
+    "MAEKEUP",
+    "MAELEE",
+    "MAEMBGBIEE",
+    "MAEMGBIEE",
+    "MAEMKPEN",
+    "MAEMVEUX",
+    "MAENJET",
+    "MAENYI",
+    "MAGE",
+    "MAGIC",
+    "MAGNET",
+    "MAHAPAKH",
+    "MAHHA",
+    "MAIDEN",
+    "MAIKURO",
+    "MAIMALAI",
+    "MAIMUAN",
+    "MAIRU",
+    "MAITAIKHU",
+    "MAIYAMOK",
+    "MAIZE",
+    "MAKEMAKE",
+    "MALEERI",
+    "MALTESE",
+    "MAMMOTH",
+    "MANACLES",
+    "MANAT",
+    "MANGALAM",
+    "MANGO",
+    "MANNA",
+    "MANNAZ",
+    "MANS",
+    "MANSUAE",
+    "MANSYON",
+    "MANTELPIECE",
+    "MANUAL",
+    "MAO",
+    "MAPLE",
+    "MAQAF",
+    "MARACAS",
+    "MARCASITE",
+    "MARCATO",
+    "MARCATO-STACCATO",
+    "MARCH",
+    "MARE",
+    "MARK-4",
+    "MARRATAN",
+    "MARRIAGE",
+    "MARRYING",
+    "MARTIAL",
+    "MARUKU",
+    "MARWARI",
+    "MARY",
+    "MASCULINE",
+    "MASHFAAT",
+    "MASORA",
+    "MASSAGE",
+    "MASSING",
+    "MASU",
+    "MAT",
+    "MATE",
+    "MATERIALS",
+    "MATRIX",
+    "MATTOCK",
+    "MAU",
+    "MAX",
+    "MAXIMA",
+    "MAXIMIZE",
+    "MAYANNA",
+    "MB2",
+    "MB3",
+    "MB4",
+    "MBAAKET",
+    "MBAARAE",
+    "MBAQ",
+    "MBEEKEET",
+    "MBERAE",
+    "MBEURI",
+    "MBEUX",
+    "MBIRIEEN",
+    "MBUAE",
+    "MBUAEM",
+    "MBUE",
+    "MBUO",
+    "MBUOQ",
+    "MBUU",
+    "MD",
+    "ME-1",
+    "ME-2",
+    "ME-MA",
+    "MED",
+    "MEDICAL",
+    "MEDICINE",
+    "MEEMU",
+    "MEETORU",
+    "MEGALI",
+    "MEGAPHONE",
+    "MEGATON",
+    "MEIZI",
+    "MELIK",
+    "MELODIC",
+    "MELTING",
+    "MEMBERSHIP",
+    "MEMO",
+    "MENDUT",
+    "MENOE",
+    "MENORAH",
+    "MENS",
+    "MERI",
+    "MERIDIANS",
+    "MERPERSON",
+    "MESI",
+    "MESSENIAN",
+    "META",
+    "METEG",
+    "METEK",
+    "METOBELUS",
+    "METRETES",
+    "METRIA",
+    "METRO",
+    "MEUNJOMNDEUQ",
+    "MEUQ",
+    "MEX",
+    "MEZZO",
+    "MFAA",
+    "MFEUAE",
+    "MFEUQ",
+    "MFEUT",
+    "MFIEE",
+    "MFIYAQ",
+    "MFO",
+    "MG",
+    "MGA",
+    "MGAP",
+    "MGAT",
+    "MGAX",
+    "MGBASA",
+    "MGBASAQ",
+    "MGBE",
+    "MGBEE",
+    "MGBEN",
+    "MGBEUN",
+    "MGBI",
+    "MGBIEE",
+    "MGBO",
+    "MGBOFUM",
+    "MGBOO",
+    "MGBU",
+    "MGE",
+    "MGEP",
+    "MGEX",
+    "MGIE",
+    "MGIEX",
+    "MGOP",
+    "MGOT",
+    "MGOX",
+    "MGU",
+    "MGUO",
+    "MGUOP",
+    "MGUOX",
+    "MGUP",
+    "MGUR",
+    "MGURX",
+    "MGUT",
+    "MGUX",
+    "MH",
+    "MHZ",
+    "MI-1",
+    "MI-2",
+    "MI-3",
+    "MI-4",
+    "MI-5",
+    "MI-6",
+    "MI-7",
+    "MICRO",
+    "MICROBE",
+    "MICROSCOPE",
+    "MIE",
+    "MIEP",
+    "MIEUM-CHIEUCH",
+    "MIEUM-CIEUC",
+    "MIEUM-HIEUH",
+    "MIEUM-NIEUN",
+    "MIEUM-PIEUP-SIOS",
+    "MIEUM-RIEUL",
+    "MIEUM-SSANGNIEUN",
+    "MIEUM-SSANGSIOS",
+    "MIEUM-TIKEUT",
+    "MIEX",
+    "MII",
+    "MIIN",
+    "MIKRI",
+    "MIKRON",
+    "MIKURON",
+    "MIL",
+    "MILK",
+    "MILKY",
+    "MILL",
+    "MILLET",
+    "MIME",
+    "MINDU",
+    "MINIBUS",
+    "MINIDISC",
+    "MINIMIZE",
+    "MINISTER",
+    "MINUS-OR-PLUS",
+    "MINY",
+    "MIP",
+    "MIRED",
+    "MIRI",
+    "MIRIBAARU",
+    "MISRA",
+    "MIX",
+    "MKPARAQ",
+    "ML",
+    "MNYAM",
+    "MO-1",
+    "MO-2",
+    "MO-3",
+    "MO-4",
+    "MO-5",
+    "MO-6",
+    "MOA",
+    "MODE",
+    "MODEL",
+    "MODELS",
+    "MODEM",
+    "MODERN",
+    "MODESTY",
+    "MODIFIER-10",
+    "MODIFIER-11",
+    "MODIFIER-12",
+    "MODIFIER-13",
+    "MODIFIER-14",
+    "MODIFIER-15",
+    "MODIFIER-16",
+    "MODIFIER-7",
+    "MODIFIER-8",
+    "MODIFIER-9",
+    "MODULO",
+    "MOHAMMAD",
+    "MOKHASSAS",
+    "MOL",
+    "MONEY-MOUTH",
+    "MONGKEUAEQ",
+    "MONI",
+    "MONITOR",
+    "MONOCLE",
+    "MONORAIL",
+    "MONOSTABLE",
+    "MONTIEEN",
+    "MOOMEUT",
+    "MOOMPUQ",
+    "MOOSE",
+    "MOOSE-CREE",
+    "MORNING",
+    "MORPHOLOGICAL",
+    "MORTUUM",
+    "MOSQUE",
+    "MOSQUITO",
+    "MOT",
+    "MOTORCYCLE",
+    "MOTORIZED",
+    "MOTORWAY",
+    "MOUNT",
+    "MOUNTAINS",
+    "MOVED",
+    "MOVES",
+    "MOVIE",
+    "MOX",
+    "MOYAI",
+    "MQ",
+    "MR",
+    "MRACHNO",
+    "MRACHNOTIKHAYA",
+    "MRACHNY",
+    "MS",
+    "MU-1",
+    "MU-2",
+    "MU-3",
+    "MU-4",
+    "MU-GAAHLAA",
+    "MUAS",
+    "MUE",
+    "MUEN",
+    "MUGS",
+    "MUHOR",
+    "MUIN",
+    "MUKHA",
+    "MUKKURUNI",
+    "MUKPHRENG",
+    "MULTI",
+    "MULTIOCULAR",
+    "MUM",
+    "MUN",
+    "MUNAH",
+    "MUNSUB",
+    "MUO",
+    "MUOMAE",
+    "MUOP",
+    "MUOT",
+    "MUOX",
+    "MUP",
+    "MUQDAM",
+    "MUR",
+    "MURE",
+    "MURGU2",
+    "MURX",
+    "MUS",
+    "MUT",
+    "MUTHALIYA",
+    "MUUSIKATOAN",
+    "MUUVUZHAKKU",
+    "MUX",
+    "MVEUAENGAM",
+    "MVI",
+    "MX",
+    "MYP",
+    "MYT",
+    "MYX",
+    "MZ",
+    "N-MU-MO-1",
+    "N-MU-MO-2",
+    "N001",
+    "N002",
+    "N003",
+    "N004",
+    "N005",
+    "N006",
+    "N007",
+    "N008",
+    "N009",
+    "N010",
+    "N011",
+    "N012",
+    "N013",
+    "N014",
+    "N015",
+    "N016",
+    "N017",
+    "N018",
+    "N018A",
+    "N018B",
+    "N019",
+    "N020",
+    "N021",
+    "N022",
+    "N023",
+    "N024",
+    "N025",
+    "N025A",
+    "N026",
+    "N027",
+    "N028",
+    "N029",
+    "N030",
+    "N031",
+    "N032",
+    "N033",
+    "N033A",
+    "N034",
+    "N034A",
+    "N035",
+    "N035A",
+    "N036",
+    "N037",
+    "N037A",
+    "N038",
+    "N039",
+    "N040",
+    "N041",
+    "N042",
+    "NA-1",
+    "NA-3",
+    "NA-4",
+    "NA-5",
+    "NA-6",
+    "NA-7",
+    "NA-8",
+    "NA-9",
+    "NA4",
+    "NAAI",
+    "NAAKSIKYAYA",
+    "NAGAR",
+    "NAIL",
+    "NAIRA",
+    "NAN",
+    "NAND",
+    "NANGMONTHO",
+    "NANO",
+    "NANSANAQ",
+    "NAOS",
+    "NAP",
+    "NAU",
+    "NAUD",
+    "NAUDIZ",
+    "NAUSEATED",
+    "NAUTHS",
+    "NAX",
+    "NAXIAN",
+    "NAY",
+    "NAZAR",
+    "NBA",
+    "NBAP",
+    "NBAT",
+    "NBAX",
+    "NBI",
+    "NBIEP",
+    "NBIEX",
+    "NBIP",
+    "NBIT",
+    "NBIX",
+    "NBO",
+    "NBOP",
+    "NBOT",
+    "NBOX",
+    "NBU",
+    "NBUP",
+    "NBUR",
+    "NBURX",
+    "NBUT",
+    "NBUX",
+    "NBY",
+    "NBYP",
+    "NBYR",
+    "NBYRX",
+    "NBYT",
+    "NBYX",
+    "NCA",
+    "NCHAU",
+    "ND",
+    "NDAANGGEUAET",
+    "NDAT",
+    "NDAX",
+    "NDEP",
+    "NDEUAEREE",
+    "NDEUT",
+    "NDEUX",
+    "NDEX",
+    "NDIAQ",
+    "NDIDA",
+    "NDIE",
+    "NDIEX",
+    "NDIP",
+    "NDIQ",
+    "NDIT",
+    "NDIX",
+    "NDOMBU",
+    "NDON",
+    "NDOP",
+    "NDOT",
+    "NDOX",
+    "NDUN",
+    "NDUP",
+    "NDUR",
+    "NDURX",
+    "NDUT",
+    "NDUX",
+    "NE-1",
+    "NE-3",
+    "NE-4",
+    "NE-5",
+    "NE-6",
+    "NE-KO",
+    "NEBENSTIMME",
+    "NECKTIE",
+    "NEEDLE",
+    "NEGATION",
+    "NEL",
+    "NEMKA",
+    "NENANO",
+    "NEP",
+    "NERD",
+    "NESSUS",
+    "NESTING",
+    "NETWORKED",
+    "NEUTER",
+    "NEX",
+    "NEXT",
+    "NF",
+    "NGAAI",
+    "NGAH",
+    "NGAI",
+    "NGAN",
+    "NGANGU",
+    "NGAP",
+    "NGAQ",
+    "NGAR",
+    "NGAT",
+    "NGAX",
+    "NGEADAL",
+    "NGEP",
+    "NGEUREUT",
+    "NGEX",
+    "NGG",
+    "NGGAA",
+    "NGGAAMAE",
+    "NGGAP",
+    "NGGEEEE",
+    "NGGEET",
+    "NGGEU",
+    "NGGEUAE",
+    "NGGEUX",
+    "NGGUA",
+    "NGGUAEN",
+    "NGGUAESHAE",
+    "NGGUEET",
+    "NGGUM",
+    "NGGUOM",
+    "NGGUON",
+    "NGGUP",
+    "NGGURAE",
+    "NGGWAEN",
+    "NGIE",
+    "NGIEP",
+    "NGIEX",
+    "NGII",
+    "NGJA",
+    "NGKAAMI",
+    "NGKAP",
+    "NGKAQ",
+    "NGKEUAEM",
+    "NGKEUAEQ",
+    "NGKEURI",
+    "NGKEUX",
+    "NGKIEE",
+    "NGKINDI",
+    "NGKUENZEUM",
+    "NGKUM",
+    "NGKUN",
+    "NGKUP",
+    "NGKYEE",
+    "NGOO",
+    "NGOQ",
+    "NGOT",
+    "NGOX",
+    "NGU",
+    "NGUAE",
+    "NGUAET",
+    "NGUAN",
+    "NGUE",
+    "NGUN",
+    "NGUO",
+    "NGUOT",
+    "NGUOX",
+    "NGVE",
+    "NGYE",
+    "NH",
+    "NHAY",
+    "NHJA",
+    "NHUE",
+    "NI-1",
+    "NI-2",
+    "NI-3",
+    "NI-4",
+    "NI-5",
+    "NI-6",
+    "NI-7",
+    "NI-TE",
+    "NI2",
+    "NIA",
+    "NIE",
+    "NIEP",
+    "NIEUN-CHIEUCH",
+    "NIEUN-PIEUP",
+    "NIEUN-RIEUL",
+    "NIEUN-THIEUTH",
+    "NIEX",
+    "NIGGAHITA",
+    "NIGIDAESH",
+    "NIGIDAMIN",
+    "NIHSHVASA",
+    "NIKA",
+    "NIKAHIT",
+    "NIKHAHIT",
+    "NIN9",
+    "NINE-THIRTY",
+    "NINJA",
+    "NINTH",
+    "NION",
+    "NIP",
+    "NIRUGU",
+    "NISAG",
+    "NIT",
+    "NITRE",
+    "NIX",
+    "NJAA",
+    "NJAN",
+    "NJAP",
+    "NJAQ",
+    "NJEEEE",
+    "NJEUAEM",
+    "NJEUAENA",
+    "NJEUT",
+    "NJIE",
+    "NJIEE",
+    "NJIEP",
+    "NJIET",
+    "NJIEX",
+    "NJIP",
+    "NJIT",
+    "NJIX",
+    "NJOP",
+    "NJOT",
+    "NJOX",
+    "NJUO",
+    "NJUOX",
+    "NJUP",
+    "NJUQA",
+    "NJUR",
+    "NJURX",
+    "NJUX",
+    "NJY",
+    "NJYP",
+    "NJYR",
+    "NJYRX",
+    "NJYT",
+    "NJYX",
+    "NKA",
+    "NKAU",
+    "NKINDI",
+    "NKOM",
+    "NL001",
+    "NL002",
+    "NL003",
+    "NL004",
+    "NL005",
+    "NL005A",
+    "NL006",
+    "NL007",
+    "NL008",
+    "NL009",
+    "NL010",
+    "NL011",
+    "NL012",
+    "NL013",
+    "NL014",
+    "NL015",
+    "NL016",
+    "NL017",
+    "NL017A",
+    "NL018",
+    "NL019",
+    "NL020",
+    "NLAU",
+    "NM",
+    "NNAA",
+    "NNE",
+    "NNG",
+    "NNGA",
+    "NNGAA",
+    "NNGI",
+    "NNGII",
+    "NNGO",
+    "NNGOO",
+    "NNHA",
+    "NNO",
+    "NNYA",
+    "NO-1",
+    "NO-2",
+    "NO-3",
+    "NO-4",
+    "NO-5",
+    "NOA",
+    "NOKHUK",
+    "NOMINAL",
+    "NOMISMA",
+    "NON",
+    "NON-BREAKING",
+    "NON-JOINER",
+    "NON-POTABLE",
+    "NONFORKING",
+    "NOONU",
+    "NOP",
+    "NORDIC",
+    "NORTHEAST-POINTING",
+    "NORTHWEST",
+    "NOTTO",
+    "NOVEMBER",
+    "NOVILE",
+    "NOWC",
+    "NOX",
+    "NOY",
+    "NPA",
+    "NPLA",
+    "NPN",
+    "NQA",
+    "NQIG",
+    "NRAP",
+    "NRAT",
+    "NRAX",
+    "NRE",
+    "NREP",
+    "NRES",
+    "NRET",
+    "NREX",
+    "NRO",
+    "NROP",
+    "NROX",
+    "NRU",
+    "NRUA",
+    "NRUP",
+    "NRUR",
+    "NRURX",
+    "NRUT",
+    "NRUX",
+    "NRY",
+    "NRYP",
+    "NRYR",
+    "NRYRX",
+    "NRYT",
+    "NRYX",
+    "NS",
+    "NSA",
+    "NSEN",
+    "NSEUAEN",
+    "NSHAQ",
+    "NSHEE",
+    "NSHUE",
+    "NSHUET",
+    "NSHUOP",
+    "NSIEEP",
+    "NSIEET",
+    "NSOM",
+    "NSUM",
+    "NSUN",
+    "NSUOT",
+    "NTA",
+    "NTEN",
+    "NTEUNGBA",
+    "NTHAU",
+    "NTIEE",
+    "NTOQPEN",
+    "NTSA",
+    "NTSAU",
+    "NTU",
+    "NTUJ",
+    "NTUM",
+    "NTXA",
+    "NTXIV",
+    "NU-1",
+    "NU-2",
+    "NU-3",
+    "NU001",
+    "NU002",
+    "NU003",
+    "NU004",
+    "NU005",
+    "NU006",
+    "NU007",
+    "NU008",
+    "NU009",
+    "NU010",
+    "NU010A",
+    "NU011",
+    "NU011A",
+    "NU012",
+    "NU013",
+    "NU014",
+    "NU015",
+    "NU016",
+    "NU017",
+    "NU018",
+    "NU018A",
+    "NU019",
+    "NU020",
+    "NU021",
+    "NU022",
+    "NU022A",
+    "NUE",
+    "NUM",
+    "NUMBERS",
+    "NUMERO",
+    "NUNAVUT",
+    "NUO",
+    "NUOP",
+    "NUOX",
+    "NUP",
+    "NUR",
+    "NURX",
+    "NUSHU",
+    "NUUN",
+    "NUX",
+    "NW",
+    "NXA",
+    "NXHA",
+    "NYAEMAE",
+    "NYAH",
+    "NYAJ",
+    "NYAN",
+    "NYCA",
+    "NYD",
+    "NYEN",
+    "NYIE",
+    "NYIEP",
+    "NYIET",
+    "NYIEX",
+    "NYIN",
+    "NYIN-DO",
+    "NYIR",
+    "NYIX",
+    "NYJA",
+    "NYOA",
+    "NYON",
+    "NYOO",
+    "NYOT",
+    "NYOX",
+    "NYUE",
+    "NYUN",
+    "NYUO",
+    "NYUOP",
+    "NYUOX",
+    "NYUP",
+    "NYUT",
+    "NYUX",
+    "NYWA",
+    "NZAP",
+    "NZAQ",
+    "NZAT",
+    "NZAX",
+    "NZE",
+    "NZEUM",
+    "NZEX",
+    "NZI",
+    "NZIE",
+    "NZIEP",
+    "NZIEX",
+    "NZIP",
+    "NZIT",
+    "NZIX",
+    "NZOP",
+    "NZOX",
+    "NZU",
+    "NZUN",
+    "NZUO",
+    "NZUOX",
+    "NZUQ",
+    "NZUR",
+    "NZURX",
+    "NZUX",
+    "NZY",
+    "NZYP",
+    "NZYR",
+    "NZYRX",
+    "NZYT",
+    "NZYX",
+    "O-1",
+    "O-2",
+    "O-3",
+    "O-E",
+    "O-EO",
+    "O-O",
+    "O-O-I",
+    "O-U",
+    "O-YA",
+    "O-YAE",
+    "O-YE",
+    "O-YEO",
+    "O001",
+    "O001A",
+    "O002",
+    "O003",
+    "O004",
+    "O005",
+    "O005A",
+    "O006",
+    "O006A",
+    "O006B",
+    "O006C",
+    "O006D",
+    "O006E",
+    "O006F",
+    "O007",
+    "O008",
+    "O009",
+    "O010",
+    "O010A",
+    "O010B",
+    "O010C",
+    "O011",
+    "O012",
+    "O013",
+    "O014",
+    "O015",
+    "O016",
+    "O017",
+    "O018",
+    "O019",
+    "O019A",
+    "O020",
+    "O020A",
+    "O021",
+    "O022",
+    "O023",
+    "O024",
+    "O024A",
+    "O025",
+    "O025A",
+    "O026",
+    "O027",
+    "O028",
+    "O029",
+    "O029A",
+    "O030",
+    "O030A",
+    "O031",
+    "O032",
+    "O033",
+    "O033A",
+    "O034",
+    "O035",
+    "O036",
+    "O036A",
+    "O036B",
+    "O036C",
+    "O036D",
+    "O037",
+    "O038",
+    "O039",
+    "O040",
+    "O041",
+    "O042",
+    "O043",
+    "O044",
+    "O045",
+    "O046",
+    "O047",
+    "O048",
+    "O049",
+    "O050",
+    "O050A",
+    "O050B",
+    "O051",
+    "OABOAFILI",
+    "OAK",
+    "OB",
+    "OBELOS",
+    "OBELUS",
+    "OBJECT",
+    "OBLACHKO",
+    "OBLAKO",
+    "OBOFILI",
+    "OBOL",
+    "OBSERVER",
+    "OBSTRUCTION",
+    "OC",
+    "OCCLUSION",
+    "OCCULTATION",
+    "OCHKOM",
+    "OCTAGONAL",
+    "OCTANT-123",
+    "OCTANT-12345",
+    "OCTANT-1234567",
+    "OCTANT-1234568",
+    "OCTANT-1234578",
+    "OCTANT-123458",
+    "OCTANT-12346",
+    "OCTANT-123467",
+    "OCTANT-1234678",
+    "OCTANT-12347",
+    "OCTANT-123478",
+    "OCTANT-12348",
+    "OCTANT-1235",
+    "OCTANT-12356",
+    "OCTANT-123567",
+    "OCTANT-1235678",
+    "OCTANT-123568",
+    "OCTANT-12357",
+    "OCTANT-123578",
+    "OCTANT-12358",
+    "OCTANT-1236",
+    "OCTANT-12367",
+    "OCTANT-123678",
+    "OCTANT-12368",
+    "OCTANT-1237",
+    "OCTANT-12378",
+    "OCTANT-1238",
+    "OCTANT-124",
+    "OCTANT-1245",
+    "OCTANT-12456",
+    "OCTANT-124567",
+    "OCTANT-1245678",
+    "OCTANT-124568",
+    "OCTANT-12457",
+    "OCTANT-124578",
+    "OCTANT-12458",
+    "OCTANT-1246",
+    "OCTANT-12467",
+    "OCTANT-124678",
+    "OCTANT-12468",
+    "OCTANT-1247",
+    "OCTANT-12478",
+    "OCTANT-1248",
+    "OCTANT-125",
+    "OCTANT-1256",
+    "OCTANT-12567",
+    "OCTANT-125678",
+    "OCTANT-12568",
+    "OCTANT-1257",
+    "OCTANT-12578",
+    "OCTANT-1258",
+    "OCTANT-126",
+    "OCTANT-1267",
+    "OCTANT-12678",
+    "OCTANT-1268",
+    "OCTANT-127",
+    "OCTANT-1278",
+    "OCTANT-128",
+    "OCTANT-134",
+    "OCTANT-1345",
+    "OCTANT-13456",
+    "OCTANT-134567",
+    "OCTANT-1345678",
+    "OCTANT-134568",
+    "OCTANT-13457",
+    "OCTANT-134578",
+    "OCTANT-13458",
+    "OCTANT-1346",
+    "OCTANT-13467",
+    "OCTANT-134678",
+    "OCTANT-13468",
+    "OCTANT-1347",
+    "OCTANT-13478",
+    "OCTANT-1348",
+    "OCTANT-135",
+    "OCTANT-1356",
+    "OCTANT-13567",
+    "OCTANT-13568",
+    "OCTANT-13578",
+    "OCTANT-1358",
+    "OCTANT-136",
+    "OCTANT-1367",
+    "OCTANT-13678",
+    "OCTANT-137",
+    "OCTANT-1378",
+    "OCTANT-138",
+    "OCTANT-14",
+    "OCTANT-145",
+    "OCTANT-1456",
+    "OCTANT-14567",
+    "OCTANT-145678",
+    "OCTANT-14568",
+    "OCTANT-1457",
+    "OCTANT-14578",
+    "OCTANT-1458",
+    "OCTANT-146",
+    "OCTANT-1467",
+    "OCTANT-14678",
+    "OCTANT-1468",
+    "OCTANT-147",
+    "OCTANT-1478",
+    "OCTANT-148",
+    "OCTANT-15",
+    "OCTANT-156",
+    "OCTANT-1567",
+    "OCTANT-15678",
+    "OCTANT-1568",
+    "OCTANT-157",
+    "OCTANT-1578",
+    "OCTANT-158",
+    "OCTANT-16",
+    "OCTANT-167",
+    "OCTANT-1678",
+    "OCTANT-168",
+    "OCTANT-17",
+    "OCTANT-178",
+    "OCTANT-18",
+    "OCTANT-23",
+    "OCTANT-234",
+    "OCTANT-2345",
+    "OCTANT-23456",
+    "OCTANT-234567",
+    "OCTANT-2345678",
+    "OCTANT-234568",
+    "OCTANT-23457",
+    "OCTANT-234578",
+    "OCTANT-23458",
+    "OCTANT-2346",
+    "OCTANT-23467",
+    "OCTANT-234678",
+    "OCTANT-23468",
+    "OCTANT-2347",
+    "OCTANT-23478",
+    "OCTANT-2348",
+    "OCTANT-235",
+    "OCTANT-2356",
+    "OCTANT-23567",
+    "OCTANT-235678",
+    "OCTANT-23568",
+    "OCTANT-2357",
+    "OCTANT-23578",
+    "OCTANT-2358",
+    "OCTANT-236",
+    "OCTANT-2367",
+    "OCTANT-23678",
+    "OCTANT-2368",
+    "OCTANT-237",
+    "OCTANT-2378",
+    "OCTANT-238",
+    "OCTANT-245",
+    "OCTANT-2456",
+    "OCTANT-24567",
+    "OCTANT-24568",
+    "OCTANT-24578",
+    "OCTANT-2458",
+    "OCTANT-246",
+    "OCTANT-2467",
+    "OCTANT-24678",
+    "OCTANT-247",
+    "OCTANT-2478",
+    "OCTANT-248",
+    "OCTANT-25",
+    "OCTANT-256",
+    "OCTANT-2567",
+    "OCTANT-25678",
+    "OCTANT-2568",
+    "OCTANT-257",
+    "OCTANT-2578",
+    "OCTANT-258",
+    "OCTANT-26",
+    "OCTANT-267",
+    "OCTANT-2678",
+    "OCTANT-268",
+    "OCTANT-27",
+    "OCTANT-278",
+    "OCTANT-28",
+    "OCTANT-3",
+    "OCTANT-34",
+    "OCTANT-345",
+    "OCTANT-3456",
+    "OCTANT-34567",
+    "OCTANT-34568",
+    "OCTANT-3457",
+    "OCTANT-34578",
+    "OCTANT-3458",
+    "OCTANT-346",
+    "OCTANT-3467",
+    "OCTANT-34678",
+    "OCTANT-3468",
+    "OCTANT-347",
+    "OCTANT-3478",
+    "OCTANT-348",
+    "OCTANT-356",
+    "OCTANT-3567",
+    "OCTANT-35678",
+    "OCTANT-3568",
+    "OCTANT-357",
+    "OCTANT-3578",
+    "OCTANT-358",
+    "OCTANT-36",
+    "OCTANT-367",
+    "OCTANT-3678",
+    "OCTANT-368",
+    "OCTANT-37",
+    "OCTANT-378",
+    "OCTANT-38",
+    "OCTANT-4",
+    "OCTANT-45",
+    "OCTANT-456",
+    "OCTANT-4567",
+    "OCTANT-45678",
+    "OCTANT-4568",
+    "OCTANT-457",
+    "OCTANT-4578",
+    "OCTANT-458",
+    "OCTANT-467",
+    "OCTANT-4678",
+    "OCTANT-468",
+    "OCTANT-47",
+    "OCTANT-478",
+    "OCTANT-48",
+    "OCTANT-5",
+    "OCTANT-56",
+    "OCTANT-567",
+    "OCTANT-568",
+    "OCTANT-578",
+    "OCTANT-58",
+    "OCTANT-6",
+    "OCTANT-67",
+    "OCTANT-678",
+    "OCTOBER",
+    "OCTOPUS",
+    "ODEN",
+    "OER",
+    "OEY",
+    "OFF",
+    "OFFICER",
+    "OG",
+    "OGRE",
+    "OJEON",
+    "OJOD",
+    "OLE",
+    "OMALON",
+    "ON-OFF",
+    "ONAP",
+    "ONE-LINE",
+    "ONE-PIECE",
+    "ONE-THIRTY",
+    "ONE-WAY",
+    "ONESELF",
+    "ONION",
+    "ONKAR",
+    "ONSU",
+    "OOBOOFILI",
+    "OOH",
+    "OOMU",
+    "OON",
+    "OOYANNA",
+    "OOZE",
+    "OPEN-OUTLINED",
+    "OPEN-P",
+    "OPHIUCHUS",
+    "OPPOSE",
+    "OPPRESSION",
+    "OPTION",
+    "ORANGUTAN",
+    "ORCHID",
+    "ORCUS",
+    "ORE-2",
+    "ORIENTATION",
+    "ORIGIN",
+    "ORNAMENTS",
+    "ORR",
+    "ORTHODOX",
+    "OTHAL",
+    "OTHALAN",
+    "OTHER",
+    "OTHERS",
+    "OTSECHKA",
+    "OTT",
+    "OTTER",
+    "OTTHI",
+    "OUNKIA",
+    "OUTBOX",
+    "OUTLINE",
+    "OUTPUT",
+    "OUTWARD",
+    "OVERHEATED",
+    "OWL",
+    "OXEIAI",
+    "OYANNA",
+    "OYSTER",
+    "OZ",
+    "P001",
+    "P001A",
+    "P002",
+    "P003",
+    "P003A",
+    "P004",
+    "P005",
+    "P006",
+    "P007",
+    "P008",
+    "P009",
+    "P010",
+    "P011",
+    "P2",
+    "PAAI",
+    "PAARAE",
+    "PAARAM",
+    "PAASENTO",
+    "PAATU",
+    "PACKAGE",
+    "PACKING",
+    "PADDLE",
+    "PADMA",
+    "PAGER",
+    "PAGODA",
+    "PAH",
+    "PAINTBRUSH",
+    "PAIRTHRA",
+    "PAIYANNOI",
+    "PALATALIZATION",
+    "PALATALIZED",
+    "PALETTE",
+    "PALKA",
+    "PALLAS",
+    "PALLAWA",
+    "PALMS",
+    "PALUTA",
+    "PAMAAEH",
+    "PAMENENG",
+    "PAMEPET",
+    "PAMINGKAL",
+    "PAMSHAE",
+    "PAMUNGKAH",
+    "PANAELAENG",
+    "PANAM",
+    "PANCAKES",
+    "PANDA",
+    "PANEULEUNG",
+    "PANG",
+    "PANGHULU",
+    "PANGKAT",
+    "PANGKON",
+    "PANGLAYAR",
+    "PANGLONG",
+    "PANGRANGKEP",
+    "PANGWISAD",
+    "PANOLONG",
+    "PANONGONAN",
+    "PANSIOS-KAPYEOUNPIEUP",
+    "PANSIOS-PIEUP",
+    "PANYAKRA",
+    "PANYANGGA",
+    "PANYECEK",
+    "PANYIKU",
+    "PANYUKU",
+    "PAPERCLIP",
+    "PAPERCLIPS",
+    "PAPYRUS",
+    "PARACHUTE",
+    "PARAGRAPHUS",
+    "PARAKLIT",
+    "PARICHON",
+    "PARK",
+    "PARROT",
+    "PARTIALLY-RECYCLED",
+    "PARUM",
+    "PASEQ",
+    "PASHAE",
+    "PASHTA",
+    "PASSED",
+    "PASSENGER",
+    "PASSIMBANG",
+    "PASSIVE-PULL-DOWN-OUTPUT",
+    "PASSIVE-PULL-UP-OUTPUT",
+    "PASSPORT",
+    "PASUQ",
+    "PAT",
+    "PATAK",
+    "PATHAKKU",
+    "PATHAMASAT",
+    "PAUK",
+    "PAUSE",
+    "PAVIYANI",
+    "PAX",
+    "PAY",
+    "PAZER",
+    "PC",
+    "PD",
+    "PEA",
+    "PEACH",
+    "PEACOCK",
+    "PEANUTS",
+    "PEAR",
+    "PEDESTRIANS",
+    "PEEI",
+    "PEEKING",
+    "PEEM",
+    "PEEP",
+    "PEESHI",
+    "PEEZI",
+    "PEITH",
+    "PENETRATION",
+    "PENGKAL",
+    "PENGUIN",
+    "PENIHI",
+    "PENNY",
+    "PENSIVE",
+    "PENSU",
+    "PENTATHLON",
+    "PEORTH",
+    "PERFORMING",
+    "PERMANENT",
+    "PERSEVERING",
+    "PERSPECTIVE",
+    "PERTHO",
+    "PES",
+    "PESETA",
+    "PET",
+    "PETASMA",
+    "PETASTI",
+    "PETASTOKOUFISMA",
+    "PETRI",
+    "PEUT",
+    "PEUTAE",
+    "PF",
+    "PG",
+    "PH",
+    "PHAARKAA",
+    "PHAB",
+    "PHAM",
+    "PHAN",
+    "PHEE",
+    "PHIEUPH-HIEUH",
+    "PHIEUPH-SIOS",
+    "PHIEUPH-THIEUTH",
+    "PHILOSOPHERS",
+    "PHINTHU",
+    "PHNAEK",
+    "PHOA",
+    "PHOLUS",
+    "PHONES",
+    "PHU",
+    "PHUNG",
+    "PHUTHAO",
+    "PHWA",
+    "PIANO",
+    "PIASTRE",
+    "PIASUTORU",
+    "PICKET",
+    "PICKUP",
+    "PIEEQ",
+    "PIEET",
+    "PIEP",
+    "PIET",
+    "PIEUP-KHIEUKH",
+    "PIEUP-MIEUM",
+    "PIEUP-NIEUN",
+    "PIEUP-RIEUL",
+    "PIEUP-RIEUL-PHIEUPH",
+    "PIEUP-SIOS-CIEUC",
+    "PIEUP-SIOS-PIEUP",
+    "PIEUP-SIOS-THIEUTH",
+    "PIEUP-SSANGSIOS",
+    "PIEX",
+    "PIKO",
+    "PIKURU",
+    "PILE",
+    "PILL",
+    "PINARBORAS",
+    "PINATA",
+    "PINCHED",
+    "PINCHING",
+    "PINE",
+    "PINEAPPLE",
+    "PINK",
+    "PIPING",
+    "PIR2",
+    "PIRACY",
+    "PIRIEEN",
+    "PISCES",
+    "PISTOL",
+    "PIX",
+    "PIZZA",
+    "PIZZICATO",
+    "PLACARD",
+    "PLAK",
+    "PLANET",
+    "PLANT",
+    "PLATE",
+    "PLAYGROUND",
+    "PLEADING",
+    "PLETHRON",
+    "PLHAU",
+    "PLOW",
+    "PLUG",
+    "PLUK",
+    "PLUM",
+    "PLUMED",
+    "PLUNGER",
+    "PLURAL",
+    "PLUS-MINUS",
+    "PM",
+    "PNP",
+    "POA",
+    "POCKET",
+    "POD",
+    "PODATUS",
+    "PODCHASHIEM",
+    "PODVERTKA",
+    "POETIC",
+    "POINTO",
+    "POINTS",
+    "POKRYTIE",
+    "POLKULIZMY",
+    "POLNAYA",
+    "POLO",
+    "POLUPOVODNAYA",
+    "PONDO",
+    "POODLE",
+    "POON",
+    "POPCORN",
+    "POPPER",
+    "POPPING",
+    "PORTABLE",
+    "PORTRAIT",
+    "POSEIDON",
+    "POSITIONS",
+    "POSTBOX",
+    "POSTPOSITION",
+    "POTABLE",
+    "POTTED",
+    "POUCH",
+    "POULTRY",
+    "POURING",
+    "POVODNY",
+    "POWDER",
+    "POWDERED",
+    "POX",
+    "POY",
+    "PPA",
+    "PPM",
+    "PPV",
+    "PR",
+    "PRAYER",
+    "PRECEDED",
+    "PRECIPITATE",
+    "PREFACE",
+    "PRENKHA",
+    "PRESCRIPTION",
+    "PRESENT",
+    "PRESSED",
+    "PRETZEL",
+    "PREVIOUS",
+    "PRINCE",
+    "PRINCESS",
+    "PRINTS",
+    "PRIVATE",
+    "PROBING",
+    "PROFOUND",
+    "PROGRESS",
+    "PROHIBITED",
+    "PROJECTION",
+    "PROJECTIVE",
+    "PROJECTOR",
+    "PROOF",
+    "PROPERTY",
+    "PROPORTIONAL",
+    "PROSERPINA",
+    "PROTOVARYS",
+    "PROVE",
+    "PS",
+    "PSA",
+    "PSIFISTOLYGISMA",
+    "PSIFISTOPARAKALESMA",
+    "PSIFISTOSYNAGMA",
+    "PSILON",
+    "PTE",
+    "PU2",
+    "PUAQ",
+    "PUB",
+    "PUBLIC",
+    "PUCK",
+    "PUFFED",
+    "PUM",
+    "PUMP",
+    "PUNCTUS",
+    "PUNG",
+    "PUNGAAM",
+    "PUO",
+    "PUOP",
+    "PUOX",
+    "PUQ",
+    "PUR",
+    "PURIFY",
+    "PURITY",
+    "PURNAMA",
+    "PURSE",
+    "PURX",
+    "PUTNAYA",
+    "PUTREFACTION",
+    "PUUT",
+    "PUX",
+    "PUZZLE",
+    "PV",
+    "PVO",
+    "PW",
+    "PWOY",
+    "PY",
+    "PYP",
+    "PYR",
+    "PYRX",
+    "PYX",
+    "PZ",
+    "Q001",
+    "Q002",
+    "Q003",
+    "Q004",
+    "Q005",
+    "Q006",
+    "Q007",
+    "QAAFU",
+    "QAAI",
+    "QADMA",
+    "QAIRTHRA",
+    "QALA",
+    "QAPH",
+    "QAQ",
+    "QARNEY",
+    "QASR",
+    "QAU",
+    "QAY",
+    "QEE",
+    "QETANA",
+    "QGA",
+    "QHA",
+    "QHAA",
+    "QHAU",
+    "QHE",
+    "QHEE",
+    "QHI",
+    "QHO",
+    "QHOPH",
+    "QHU",
+    "QHWA",
+    "QHWAA",
+    "QHWE",
+    "QHWEE",
+    "QHWI",
+    "QIE",
+    "QIEP",
+    "QIET",
+    "QIEX",
+    "QIF",
+    "QIP",
+    "QIT",
+    "QIX",
+    "QN",
+    "QOA",
+    "QOP",
+    "QOPA",
+    "QOX",
+    "QP",
+    "QUADCOLON",
+    "QUADRANT-1",
+    "QUADRANT-12",
+    "QUADRANT-123",
+    "QUADRANT-1234",
+    "QUADRANT-124",
+    "QUADRANT-13",
+    "QUADRANT-134",
+    "QUADRANT-14",
+    "QUADRANT-2",
+    "QUADRANT-23",
+    "QUADRANT-234",
+    "QUADRANT-24",
+    "QUADRANT-3",
+    "QUADRANT-34",
+    "QUADRANT-4",
+    "QUANTITY",
+    "QUAOAR",
+    "QUATERNION",
+    "QUBUTS",
+    "QUDDISA",
+    "QUESTIONED",
+    "QUF",
+    "QUICK",
+    "QUINARIUS",
+    "QUINCUNX",
+    "QUINTESSENCE",
+    "QUINTILE",
+    "QUK",
+    "QUOP",
+    "QUOT",
+    "QUOX",
+    "QUP",
+    "QUR",
+    "QURX",
+    "QUSHSHAYA",
+    "QUT",
+    "QUUV",
+    "QUX",
+    "QWA",
+    "QWAA",
+    "QY",
+    "QYA",
+    "QYAA",
+    "QYE",
+    "QYEE",
+    "QYI",
+    "QYO",
+    "QYP",
+    "QYR",
+    "QYRX",
+    "QYT",
+    "QYU",
+    "QYX",
+    "R001",
+    "R002",
+    "R002A",
+    "R003",
+    "R003A",
+    "R003B",
+    "R004",
+    "R005",
+    "R006",
+    "R007",
+    "R008",
+    "R009",
+    "R010",
+    "R010A",
+    "R011",
+    "R012",
+    "R013",
+    "R014",
+    "R015",
+    "R016",
+    "R016A",
+    "R017",
+    "R018",
+    "R019",
+    "R020",
+    "R021",
+    "R022",
+    "R023",
+    "R024",
+    "R025",
+    "R026",
+    "R027",
+    "R028",
+    "R029",
+    "RA-1",
+    "RA-3",
+    "RA-4",
+    "RA-KARA",
+    "RA2",
+    "RA3",
+    "RAAI",
+    "RAB",
+    "RACCOON",
+    "RADIOACTIVE",
+    "RAEM",
+    "RAH",
+    "RAHIMAHU",
+    "RAHIMAHUM",
+    "RAHMATULLAH",
+    "RAIDA",
+    "RAIDO",
+    "RAIL",
+    "RAINBOW",
+    "RAKHANG",
+    "RAMBAT",
+    "RAN",
+    "RAPISMA",
+    "RAQ",
+    "RASHA",
+    "RASOUL",
+    "RASWADI",
+    "RATA",
+    "RATIO",
+    "RAU",
+    "RAVNO",
+    "RAX",
+    "RAYANNA",
+    "RAZOR",
+    "RAZSEKA",
+    "RE-1",
+    "RE-2",
+    "RE-3",
+    "RE-4",
+    "REAHMUK",
+    "REALGAR",
+    "REALGAR-2",
+    "RECEIPT",
+    "RECEPTACLE",
+    "RECEPTIVE",
+    "RECITATIVE",
+    "RECORDER",
+    "RECORDING",
+    "RECREATIONAL",
+    "RECYCLED",
+    "REFERENCE",
+    "REFLECTION",
+    "REGIA",
+    "REGIA-2",
+    "REGISTERED",
+    "REGULUS-2",
+    "REGULUS-3",
+    "REGULUS-4",
+    "REI",
+    "REID",
+    "REIWA",
+    "RELAA",
+    "RELATIONAL",
+    "RELEASE",
+    "RELIGION",
+    "REMEDY",
+    "REMINDER",
+    "REMU",
+    "RENTOGEN",
+    "REP",
+    "REPH",
+    "REPRESENT",
+    "REREKAN",
+    "RESH-AYIN",
+    "RESH-AYIN-DALETH",
+    "RESIDENCE",
+    "RESISTANCE",
+    "RESOLUTION",
+    "RESTROOM",
+    "RESUPINUS",
+    "RETORT",
+    "RETREAT",
+    "REVERSED-SCHWA",
+    "REVIA",
+    "REVMA",
+    "REVOLUTION",
+    "REX",
+    "RH",
+    "RHINOCEROS",
+    "RHOTIC",
+    "RI-1",
+    "RI-2",
+    "RI-3",
+    "RI-4",
+    "RI-5",
+    "RI-6",
+    "RI-7",
+    "RIAL",
+    "RICEM",
+    "RICKSHAW",
+    "RIEL",
+    "RIEUL-CIEUC",
+    "RIEUL-KIYEOK-HIEUH",
+    "RIEUL-MIEUM-HIEUH",
+    "RIEUL-MIEUM-KIYEOK",
+    "RIEUL-MIEUM-SIOS",
+    "RIEUL-PIEUP-HIEUH",
+    "RIEUL-PIEUP-PHIEUPH",
+    "RIEUL-PIEUP-TIKEUT",
+    "RIEUL-SSANGPIEUP",
+    "RIEUL-SSANGSIOS",
+    "RIEUL-SSANGTIKEUT",
+    "RIEUL-TIKEUT-HIEUH",
+    "RIEUL-YEORINHIEUH-HIEUH",
+    "RIEUL-YESIEUNG",
+    "RIGHT-HANDED",
+    "RIGHT-LIGHTED",
+    "RIGHT-SIDE",
+    "RIGHTHAND",
+    "RIGVEDIC",
+    "RIM",
+    "RIMGBA",
+    "RIN",
+    "RINFORZANDO",
+    "RINGED",
+    "RINGING",
+    "RIP",
+    "RIRA",
+    "RITTORU",
+    "RITUAL",
+    "RIVER",
+    "RJES",
+    "RMT",
+    "RO-1",
+    "RO-2",
+    "RO-3",
+    "RO-4",
+    "RO-5",
+    "RO-6",
+    "RO2",
+    "ROA",
+    "ROAR",
+    "ROASTED",
+    "ROBAT",
+    "ROGOM",
+    "ROLL",
+    "ROLLED-UP",
+    "ROM",
+    "ROOF",
+    "ROOM",
+    "ROOSTER",
+    "ROP",
+    "ROSE",
+    "ROSH",
+    "ROT",
+    "ROUND-TIPPED",
+    "ROWBOAT",
+    "ROX",
+    "RRAX",
+    "RREP",
+    "RRET",
+    "RREX",
+    "RROP",
+    "RROT",
+    "RROX",
+    "RRRA",
+    "RRU",
+    "RRUO",
+    "RRUOX",
+    "RRUP",
+    "RRUR",
+    "RRURX",
+    "RRUT",
+    "RRUX",
+    "RRY",
+    "RRYP",
+    "RRYR",
+    "RRYRX",
+    "RRYT",
+    "RRYX",
+    "RTE",
+    "RTHANG",
+    "RU-1",
+    "RU-2",
+    "RU-3",
+    "RU-4",
+    "RU-5",
+    "RU-6",
+    "RUA",
+    "RUBLE",
+    "RUE",
+    "RUGBY",
+    "RUIS",
+    "RUKKAKHA",
+    "RULAI",
+    "RULE",
+    "RULE-DELAYED",
+    "RUN",
+    "RUNOUT",
+    "RUO",
+    "RUOP",
+    "RUOX",
+    "RUP",
+    "RUPII",
+    "RUR",
+    "RURX",
+    "RUSI",
+    "RUT",
+    "RUUBURU",
+    "RUX",
+    "RWAHA",
+    "RWE",
+    "RWEE",
+    "RWI",
+    "RWII",
+    "RWO",
+    "RWOO",
+    "RYA",
+    "RYP",
+    "RYR",
+    "RYRX",
+    "RYT",
+    "RYX",
+    "RYY",
+    "S-W",
+    "S001",
+    "S002",
+    "S002A",
+    "S003",
+    "S004",
+    "S005",
+    "S006",
+    "S006A",
+    "S007",
+    "S008",
+    "S009",
+    "S010",
+    "S011",
+    "S012",
+    "S013",
+    "S014",
+    "S014A",
+    "S014B",
+    "S015",
+    "S016",
+    "S017",
+    "S017A",
+    "S018",
+    "S019",
+    "S020",
+    "S021",
+    "S022",
+    "S023",
+    "S024",
+    "S025",
+    "S026",
+    "S026A",
+    "S026B",
+    "S027",
+    "S028",
+    "S029",
+    "S030",
+    "S031",
+    "S032",
+    "S033",
+    "S034",
+    "S035",
+    "S035A",
+    "S036",
+    "S037",
+    "S038",
+    "S039",
+    "S040",
+    "S041",
+    "S042",
+    "S043",
+    "S044",
+    "S045",
+    "S046",
+    "SA-1",
+    "SA-3",
+    "SA-4",
+    "SA-5",
+    "SA-6",
+    "SA-7",
+    "SA-8",
+    "SA-I",
+    "SAADHU",
+    "SAAI",
+    "SAAT",
+    "SACRIFICIAL",
+    "SAGA",
+    "SAGITTARIUS",
+    "SAIKURU",
+    "SAIL",
+    "SAILBOAT",
+    "SAJDAH",
+    "SAKE",
+    "SAKEUAE",
+    "SAKHA",
+    "SAKIN",
+    "SAKOT",
+    "SAL-AMMONIAC",
+    "SALA",
+    "SALAAMUHU",
+    "SALAD",
+    "SALAM",
+    "SALLALLAAHU",
+    "SALLALLAHU",
+    "SALT-2",
+    "SALTIRES",
+    "SALUTING",
+    "SAMKA",
+    "SAMPHAO",
+    "SAMVAT",
+    "SAMYOK",
+    "SANAH",
+    "SAND",
+    "SANDWICH",
+    "SANGA2",
+    "SANNYA",
+    "SANTIIMU",
+    "SANYOOGA",
+    "SAPA",
+    "SAQ",
+    "SASH",
+    "SATCHEL",
+    "SATKAAN",
+    "SATKAANKUU",
+    "SATURN",
+    "SAUIL",
+    "SAUROPOD",
+    "SAVOURING",
+    "SAWAN",
+    "SAX",
+    "SAXIMATA",
+    "SAXOPHONE",
+    "SBRUL",
+    "SBUB",
+    "SCALES",
+    "SCARF",
+    "SCEPTER",
+    "SCHOLAR",
+    "SCHROEDER",
+    "SCORE",
+    "SCORPION",
+    "SCORPIUS",
+    "SCREAMING",
+    "SCREWDRIVER",
+    "SCROLL",
+    "SCRUPLE",
+    "SCWA",
+    "SD",
+    "SDONG",
+    "SE-1",
+    "SE-2",
+    "SE-3",
+    "SE-4",
+    "SE-5",
+    "SEAGULL",
+    "SEAT",
+    "SECRET",
+    "SECTOR",
+    "SEDNA",
+    "SEE-NO-EVIL",
+    "SEEDLING",
+    "SEENU",
+    "SEEV",
+    "SELECTOR-1",
+    "SELECTOR-10",
+    "SELECTOR-100",
+    "SELECTOR-101",
+    "SELECTOR-102",
+    "SELECTOR-103",
+    "SELECTOR-104",
+    "SELECTOR-105",
+    "SELECTOR-106",
+    "SELECTOR-107",
+    "SELECTOR-108",
+    "SELECTOR-109",
+    "SELECTOR-11",
+    "SELECTOR-110",
+    "SELECTOR-111",
+    "SELECTOR-112",
+    "SELECTOR-113",
+    "SELECTOR-114",
+    "SELECTOR-115",
+    "SELECTOR-116",
+    "SELECTOR-117",
+    "SELECTOR-118",
+    "SELECTOR-119",
+    "SELECTOR-12",
+    "SELECTOR-120",
+    "SELECTOR-121",
+    "SELECTOR-122",
+    "SELECTOR-123",
+    "SELECTOR-124",
+    "SELECTOR-125",
+    "SELECTOR-126",
+    "SELECTOR-127",
+    "SELECTOR-128",
+    "SELECTOR-129",
+    "SELECTOR-13",
+    "SELECTOR-130",
+    "SELECTOR-131",
+    "SELECTOR-132",
+    "SELECTOR-133",
+    "SELECTOR-134",
+    "SELECTOR-135",
+    "SELECTOR-136",
+    "SELECTOR-137",
+    "SELECTOR-138",
+    "SELECTOR-139",
+    "SELECTOR-14",
+    "SELECTOR-140",
+    "SELECTOR-141",
+    "SELECTOR-142",
+    "SELECTOR-143",
+    "SELECTOR-144",
+    "SELECTOR-145",
+    "SELECTOR-146",
+    "SELECTOR-147",
+    "SELECTOR-148",
+    "SELECTOR-149",
+    "SELECTOR-15",
+    "SELECTOR-150",
+    "SELECTOR-151",
+    "SELECTOR-152",
+    "SELECTOR-153",
+    "SELECTOR-154",
+    "SELECTOR-155",
+    "SELECTOR-156",
+    "SELECTOR-157",
+    "SELECTOR-158",
+    "SELECTOR-159",
+    "SELECTOR-16",
+    "SELECTOR-160",
+    "SELECTOR-161",
+    "SELECTOR-162",
+    "SELECTOR-163",
+    "SELECTOR-164",
+    "SELECTOR-165",
+    "SELECTOR-166",
+    "SELECTOR-167",
+    "SELECTOR-168",
+    "SELECTOR-169",
+    "SELECTOR-17",
+    "SELECTOR-170",
+    "SELECTOR-171",
+    "SELECTOR-172",
+    "SELECTOR-173",
+    "SELECTOR-174",
+    "SELECTOR-175",
+    "SELECTOR-176",
+    "SELECTOR-177",
+    "SELECTOR-178",
+    "SELECTOR-179",
+    "SELECTOR-18",
+    "SELECTOR-180",
+    "SELECTOR-181",
+    "SELECTOR-182",
+    "SELECTOR-183",
+    "SELECTOR-184",
+    "SELECTOR-185",
+    "SELECTOR-186",
+    "SELECTOR-187",
+    "SELECTOR-188",
+    "SELECTOR-189",
+    "SELECTOR-19",
+    "SELECTOR-190",
+    "SELECTOR-191",
+    "SELECTOR-192",
+    "SELECTOR-193",
+    "SELECTOR-194",
+    "SELECTOR-195",
+    "SELECTOR-196",
+    "SELECTOR-197",
+    "SELECTOR-198",
+    "SELECTOR-199",
+    "SELECTOR-2",
+    "SELECTOR-20",
+    "SELECTOR-200",
+    "SELECTOR-201",
+    "SELECTOR-202",
+    "SELECTOR-203",
+    "SELECTOR-204",
+    "SELECTOR-205",
+    "SELECTOR-206",
+    "SELECTOR-207",
+    "SELECTOR-208",
+    "SELECTOR-209",
+    "SELECTOR-21",
+    "SELECTOR-210",
+    "SELECTOR-211",
+    "SELECTOR-212",
+    "SELECTOR-213",
+    "SELECTOR-214",
+    "SELECTOR-215",
+    "SELECTOR-216",
+    "SELECTOR-217",
+    "SELECTOR-218",
+    "SELECTOR-219",
+    "SELECTOR-22",
+    "SELECTOR-220",
+    "SELECTOR-221",
+    "SELECTOR-222",
+    "SELECTOR-223",
+    "SELECTOR-224",
+    "SELECTOR-225",
+    "SELECTOR-226",
+    "SELECTOR-227",
+    "SELECTOR-228",
+    "SELECTOR-229",
+    "SELECTOR-23",
+    "SELECTOR-230",
+    "SELECTOR-231",
+    "SELECTOR-232",
+    "SELECTOR-233",
+    "SELECTOR-234",
+    "SELECTOR-235",
+    "SELECTOR-236",
+    "SELECTOR-237",
+    "SELECTOR-238",
+    "SELECTOR-239",
+    "SELECTOR-24",
+    "SELECTOR-240",
+    "SELECTOR-241",
+    "SELECTOR-242",
+    "SELECTOR-243",
+    "SELECTOR-244",
+    "SELECTOR-245",
+    "SELECTOR-246",
+    "SELECTOR-247",
+    "SELECTOR-248",
+    "SELECTOR-249",
+    "SELECTOR-25",
+    "SELECTOR-250",
+    "SELECTOR-251",
+    "SELECTOR-252",
+    "SELECTOR-253",
+    "SELECTOR-254",
+    "SELECTOR-255",
+    "SELECTOR-256",
+    "SELECTOR-26",
+    "SELECTOR-27",
+    "SELECTOR-28",
+    "SELECTOR-29",
+    "SELECTOR-3",
+    "SELECTOR-30",
+    "SELECTOR-31",
+    "SELECTOR-32",
+    "SELECTOR-33",
+    "SELECTOR-34",
+    "SELECTOR-35",
+    "SELECTOR-36",
+    "SELECTOR-37",
+    "SELECTOR-38",
+    "SELECTOR-39",
+    "SELECTOR-4",
+    "SELECTOR-40",
+    "SELECTOR-41",
+    "SELECTOR-42",
+    "SELECTOR-43",
+    "SELECTOR-44",
+    "SELECTOR-45",
+    "SELECTOR-46",
+    "SELECTOR-47",
+    "SELECTOR-48",
+    "SELECTOR-49",
+    "SELECTOR-5",
+    "SELECTOR-50",
+    "SELECTOR-51",
+    "SELECTOR-52",
+    "SELECTOR-53",
+    "SELECTOR-54",
+    "SELECTOR-55",
+    "SELECTOR-56",
+    "SELECTOR-57",
+    "SELECTOR-58",
+    "SELECTOR-59",
+    "SELECTOR-6",
+    "SELECTOR-60",
+    "SELECTOR-61",
+    "SELECTOR-62",
+    "SELECTOR-63",
+    "SELECTOR-64",
+    "SELECTOR-65",
+    "SELECTOR-66",
+    "SELECTOR-67",
+    "SELECTOR-68",
+    "SELECTOR-69",
+    "SELECTOR-7",
+    "SELECTOR-70",
+    "SELECTOR-71",
+    "SELECTOR-72",
+    "SELECTOR-73",
+    "SELECTOR-74",
+    "SELECTOR-75",
+    "SELECTOR-76",
+    "SELECTOR-77",
+    "SELECTOR-78",
+    "SELECTOR-79",
+    "SELECTOR-8",
+    "SELECTOR-80",
+    "SELECTOR-81",
+    "SELECTOR-82",
+    "SELECTOR-83",
+    "SELECTOR-84",
+    "SELECTOR-85",
+    "SELECTOR-86",
+    "SELECTOR-87",
+    "SELECTOR-88",
+    "SELECTOR-89",
+    "SELECTOR-9",
+    "SELECTOR-90",
+    "SELECTOR-91",
+    "SELECTOR-92",
+    "SELECTOR-93",
+    "SELECTOR-94",
+    "SELECTOR-95",
+    "SELECTOR-96",
+    "SELECTOR-97",
+    "SELECTOR-98",
+    "SELECTOR-99",
+    "SELENA",
+    "SELFIE",
+    "SEMISEXTILE",
+    "SEMK",
+    "SEMUNCIA",
+    "SENTAGON",
+    "SENTI",
+    "SENTO",
+    "SEP",
+    "SEPTEMBER",
+    "SEPTUPLE",
+    "SERIOUS",
+    "SERVICE",
+    "SESQUIQUADRATE",
+    "SESTERTIUS",
+    "SETFON",
+    "SEUAEQ",
+    "SEUNYAM",
+    "SEVEN-THIRTY",
+    "SEVENTH",
+    "SEVERANCE",
+    "SEWING",
+    "SEX",
+    "SEXTANS",
+    "SEXTANT-123456",
+    "SEXTANT-135",
+    "SEXTANT-246",
+    "SEXTILE",
+    "SEYK",
+    "SG",
+    "SGOR",
+    "SGRA",
+    "SH2",
+    "SHAB6",
+    "SHAK",
+    "SHAKER",
+    "SHAKTI",
+    "SHALLOW",
+    "SHALSHELET",
+    "SHAMROCK",
+    "SHAP",
+    "SHAPE",
+    "SHARA",
+    "SHARK",
+    "SHAVED",
+    "SHAVIYANI",
+    "SHAX",
+    "SHAY",
+    "SHCHOOI",
+    "SHE-GOAT",
+    "SHEENU",
+    "SHEG9",
+    "SHEN",
+    "SHEP",
+    "SHEQEL",
+    "SHESH2",
+    "SHESHLAM",
+    "SHEUAE",
+    "SHEUAEQ",
+    "SHEUAEQTU",
+    "SHEUOQ",
+    "SHEVA",
+    "SHEX",
+    "SHIIN",
+    "SHINDA",
+    "SHINIG",
+    "SHINTO",
+    "SHIRAE",
+    "SHIRT",
+    "SHIYYAALAA",
+    "SHOA",
+    "SHOCKED",
+    "SHOES",
+    "SHOOI",
+    "SHOOT",
+    "SHOOTING",
+    "SHORT-TWIG-AR",
+    "SHORT-TWIG-BJARKAN",
+    "SHORT-TWIG-HAGALL",
+    "SHORT-TWIG-MADR",
+    "SHORT-TWIG-NAUD",
+    "SHORT-TWIG-OSS",
+    "SHORT-TWIG-SOL",
+    "SHORT-TWIG-TYR",
+    "SHORT-TWIG-YR",
+    "SHORTCAKE",
+    "SHORTENER",
+    "SHOT",
+    "SHOU",
+    "SHOULDERED",
+    "SHOVEL",
+    "SHOWER",
+    "SHOX",
+    "SHRA",
+    "SHRAA",
+    "SHRINE",
+    "SHRO",
+    "SHROO",
+    "SHRUG",
+    "SHUANGXI",
+    "SHUBUR",
+    "SHUENSHUET",
+    "SHUEQ",
+    "SHUFFLE",
+    "SHUL",
+    "SHUM",
+    "SHUO",
+    "SHUOP",
+    "SHUOX",
+    "SHUP",
+    "SHURX",
+    "SHUT",
+    "SHUTTLECOCK",
+    "SHUX",
+    "SHV",
+    "SHWOY",
+    "SHYA",
+    "SHYE",
+    "SHYELE",
+    "SHYER",
+    "SHYP",
+    "SHYR",
+    "SHYRX",
+    "SHYT",
+    "SHYX",
+    "SI-1",
+    "SI-2",
+    "SI-3",
+    "SI-4",
+    "SI-5",
+    "SI-6",
+    "SIA",
+    "SICKLE",
+    "SICKNESS",
+    "SIDDHI",
+    "SIE",
+    "SIEE",
+    "SIEP",
+    "SIEX",
+    "SIGEL",
+    "SILIQUA",
+    "SILVER",
+    "SINE",
+    "SINGAAT",
+    "SINKING",
+    "SINOLOGICAL",
+    "SINUSOID",
+    "SIOS-IEUNG",
+    "SIOS-KAPYEOUNPIEUP",
+    "SIOS-KHIEUKH",
+    "SIOS-PANSIOS",
+    "SIOS-PHIEUPH",
+    "SIOS-PIEUP-KIYEOK",
+    "SIOS-SSANGSIOS",
+    "SIP",
+    "SIRINGU",
+    "SIRRAH",
+    "SISA",
+    "SIT",
+    "SITE",
+    "SIX-LINE",
+    "SIX-PER-EM",
+    "SIX-STRING",
+    "SIX-THIRTY",
+    "SIXTEENTH-1",
+    "SIXTEENTH-2",
+    "SIXTY-FOURTHS",
+    "SK",
+    "SKAN",
+    "SKATEBOARD",
+    "SKIER",
+    "SKIN",
+    "SKOBA",
+    "SKUNK",
+    "SKW",
+    "SKWA",
+    "SLAVE",
+    "SLAVONIC",
+    "SLED",
+    "SLEEP",
+    "SLEEPY",
+    "SLEUTH",
+    "SLIDE",
+    "SLIDER",
+    "SLIDING",
+    "SLING",
+    "SLOPE",
+    "SLOT",
+    "SLOTH",
+    "SLOWLY",
+    "SMEAR",
+    "SMIRKING",
+    "SNA",
+    "SNAIL",
+    "SNAP",
+    "SNEEZING",
+    "SNOWBOARDER",
+    "SO-1",
+    "SO-2",
+    "SO-3",
+    "SO-4",
+    "SO-5",
+    "SO-6",
+    "SO-7",
+    "SOA",
+    "SOCCER",
+    "SOCKS",
+    "SOFTBALL",
+    "SOFTNESS",
+    "SOFTWARE-FUNCTION",
+    "SON",
+    "SONG",
+    "SONJAM",
+    "SOON",
+    "SOP",
+    "SOQ",
+    "SORI",
+    "SOS",
+    "SOUNAP",
+    "SOW",
+    "SOWILO",
+    "SOX",
+    "SOY",
+    "SP",
+    "SPA",
+    "SPAGHETTI",
+    "SPARKLER",
+    "SPARKLES",
+    "SPARKLING",
+    "SPATHI",
+    "SPE",
+    "SPEAK-NO-EVIL",
+    "SPEAKING",
+    "SPEEDBOAT",
+    "SPENT",
+    "SPESMILO",
+    "SPI",
+    "SPICE",
+    "SPINE",
+    "SPIRANT",
+    "SPLASHING",
+    "SPLATTER",
+    "SPLITTING",
+    "SPO",
+    "SPONGE",
+    "SPOOL",
+    "SPORTS",
+    "SPOT",
+    "SPOUTING",
+    "SPRECHGESANG",
+    "SPRING",
+    "SPRINGS",
+    "SPROUT",
+    "SPUNGS",
+    "SPWA",
+    "SPY",
+    "SQUEEZED",
+    "SQUISH",
+    "SR",
+    "SSAA",
+    "SSANGARAEA",
+    "SSANGCIEUC-HIEUH",
+    "SSANGMIEUM",
+    "SSANGRIEUL-KHIEUKH",
+    "SSANGSIOS-KIYEOK",
+    "SSANGSIOS-PIEUP",
+    "SSANGSIOS-TIKEUT",
+    "SSANGTHIEUTH",
+    "SSANGTIKEUT-PIEUP",
+    "SSANGYEORINHIEUH",
+    "SSAP",
+    "SSAT",
+    "SSAX",
+    "SSEE",
+    "SSEP",
+    "SSEX",
+    "SSHE",
+    "SSHIN",
+    "SSIE",
+    "SSIEP",
+    "SSIEX",
+    "SSIP",
+    "SSIT",
+    "SSIX",
+    "SSOP",
+    "SSOT",
+    "SSOX",
+    "SSUP",
+    "SSUT",
+    "SSUX",
+    "SSY",
+    "SSYP",
+    "SSYR",
+    "SSYRX",
+    "SSYT",
+    "SSYX",
+    "ST2",
+    "STA",
+    "STACCATISSIMO",
+    "STACCATO",
+    "STACKED",
+    "STADIUM",
+    "STALLION",
+    "STAN",
+    "STAND",
+    "STANDSTILL",
+    "STARK",
+    "STARRED",
+    "STARS",
+    "STARTING",
+    "STATION",
+    "STATUE",
+    "STAUROS",
+    "STAVROU",
+    "STEAMING",
+    "STEAMY",
+    "STEM-12",
+    "STEM-1234",
+    "STEM-2",
+    "STEM-23",
+    "STEM-234",
+    "STEM-2345",
+    "STEM-3",
+    "STEM-34",
+    "STEM-4",
+    "STEM-45",
+    "STENOGRAPHIC",
+    "STEP-1",
+    "STEP-2",
+    "STEREO",
+    "STETHOSCOPE",
+    "STILL",
+    "STIMME",
+    "STIRRUP",
+    "STOPPAGE",
+    "STOPPING",
+    "STOPWATCH",
+    "STOVE",
+    "STRAGGISMATA",
+    "STRAIF",
+    "STRAIGHTNESS",
+    "STRAINER",
+    "STRATIAN",
+    "STRATUM-2",
+    "STRAW",
+    "STRAWBERRY",
+    "STREAMER",
+    "STRENGTH",
+    "STRETCH",
+    "STRICTLY",
+    "STRIDE",
+    "STRIKETHROUGH",
+    "STRIPE",
+    "STROKE-1",
+    "STROKE-10",
+    "STROKE-11",
+    "STROKE-2",
+    "STROKE-3",
+    "STROKE-4",
+    "STROKE-5",
+    "STROKE-6",
+    "STROKE-7",
+    "STROKE-8",
+    "STROKE-9",
+    "STUDIO",
+    "STUFFED",
+    "STUPA",
+    "STWA",
+    "SU-1",
+    "SU-2",
+    "SU-3",
+    "SU-4",
+    "SU-5",
+    "SU-6",
+    "SU-7",
+    "SU-8",
+    "SUAB",
+    "SUAE",
+    "SUAEN",
+    "SUAET",
+    "SUAM",
+    "SUBHAANAHU",
+    "SUBITO",
+    "SUBJECT",
+    "SUBLIMATE-2",
+    "SUBLIMATE-3",
+    "SUBLIMATION",
+    "SUBPUNCTIS",
+    "SUBTRACTION",
+    "SUCK",
+    "SUCKING",
+    "SUD",
+    "SUD2",
+    "SUITABLE",
+    "SUMASH",
+    "SUMMER",
+    "SUNFLOWER",
+    "SUNSET",
+    "SUO",
+    "SUOP",
+    "SUOX",
+    "SUP",
+    "SUPERFIXED",
+    "SUPERHERO",
+    "SUPERIMPOSED",
+    "SUPERVILLAIN",
+    "SUR9",
+    "SURE",
+    "SURFER",
+    "SURX",
+    "SURYA",
+    "SUSHI",
+    "SUT",
+    "SUTRA",
+    "SUTUH",
+    "SUX",
+    "SVETLO",
+    "SVETLY",
+    "SWAN",
+    "SWG",
+    "SWIMMER",
+    "SWIMMING",
+    "SWIMSUIT",
+    "SWORD",
+    "SWORDS",
+    "SWUNG",
+    "SWZ",
+    "SY",
+    "SYA",
+    "SYI",
+    "SYMBOL-10",
+    "SYMBOL-15",
+    "SYMBOL-16",
+    "SYMBOL-20",
+    "SYMBOL-21",
+    "SYMBOL-22",
+    "SYMBOL-25",
+    "SYMBOL-26",
+    "SYMBOL-27",
+    "SYMBOL-29",
+    "SYMBOL-3",
+    "SYMBOL-30",
+    "SYMBOL-32",
+    "SYMBOL-36",
+    "SYMBOL-37",
+    "SYMBOL-38",
+    "SYMBOL-39",
+    "SYMBOL-40",
+    "SYMBOL-42",
+    "SYMBOL-43",
+    "SYMBOL-45",
+    "SYMBOL-47",
+    "SYMBOL-48",
+    "SYMBOL-49",
+    "SYMBOL-6",
+    "SYMBOL-9",
+    "SYMMETRY",
+    "SYNAFI",
+    "SYNAGOGUE",
+    "SYNCHRONOUS",
+    "SYNDESMOS",
+    "SYNEVMA",
+    "SYOUWA",
+    "SYP",
+    "SYR",
+    "SYRINGE",
+    "SYRMA",
+    "SYRMATIKI",
+    "SYRX",
+    "SYT",
+    "SYX",
+    "SZ",
+    "SZA",
+    "SZAA",
+    "SZE",
+    "SZEE",
+    "SZI",
+    "SZO",
+    "SZP",
+    "SZU",
+    "SZWA",
+    "SZWG",
+    "SZZ",
+    "T-REX",
+    "T-SHIRT",
+    "T001",
+    "T002",
+    "T003",
+    "T003A",
+    "T004",
+    "T005",
+    "T006",
+    "T007",
+    "T007A",
+    "T008",
+    "T008A",
+    "T009",
+    "T009A",
+    "T010",
+    "T011",
+    "T011A",
+    "T012",
+    "T013",
+    "T014",
+    "T015",
+    "T016",
+    "T016A",
+    "T017",
+    "T018",
+    "T019",
+    "T020",
+    "T021",
+    "T022",
+    "T023",
+    "T024",
+    "T025",
+    "T026",
+    "T027",
+    "T028",
+    "T029",
+    "T030",
+    "T031",
+    "T032",
+    "T032A",
+    "T033",
+    "T033A",
+    "T034",
+    "T035",
+    "T036",
+    "TA-1",
+    "TA-3",
+    "TA-4",
+    "TA2",
+    "TAAALAA",
+    "TAAF",
+    "TAAI",
+    "TAAM",
+    "TAAQ",
+    "TAASHAE",
+    "TABAARAKA",
+    "TABS",
+    "TACO",
+    "TAEN",
+    "TAHALA",
+    "TAILLESS",
+    "TAISYOU",
+    "TAK",
+    "TAKE",
+    "TAKEOUT",
+    "TAKHALLUS",
+    "TALENT",
+    "TAMALE",
+    "TAMAN",
+    "TANA",
+    "TANABATA",
+    "TANG",
+    "TANGERINE",
+    "TAO",
+    "TAP",
+    "TAPER",
+    "TAQ",
+    "TARTAR",
+    "TARTAR-2",
+    "TARUNG",
+    "TAS",
+    "TASHEEL",
+    "TASLA",
+    "TASSI",
+    "TATTOOED",
+    "TAUM",
+    "TAURUS",
+    "TAVIYANI",
+    "TAWA",
+    "TAWELLEMET",
+    "TAX",
+    "TAY",
+    "TE-1",
+    "TE-3",
+    "TE-4",
+    "TE-5",
+    "TE-6",
+    "TE-7",
+    "TE-8",
+    "TE-9",
+    "TE-U",
+    "TEA",
+    "TEACUP",
+    "TEAPOT",
+    "TEAR",
+    "TEAR-OFF",
+    "TEARDROP-BARBED",
+    "TEARDROP-SHANKED",
+    "TEDDY",
+    "TEEEE",
+    "TEENS",
+    "TEGEH",
+    "TEIWS",
+    "TEK",
+    "TELESCOPE",
+    "TELLER",
+    "TELU",
+    "TEMPLE",
+    "TEN-THIRTY",
+    "TENGE",
+    "TENTU",
+    "TENUTO",
+    "TEP",
+    "TERMINATOR",
+    "TESSARON",
+    "TEST",
+    "TETRAFONIAS",
+    "TETRAPLI",
+    "TEU",
+    "TEUN",
+    "TEUT",
+    "TEUTEUWEN",
+    "TEUTEUX",
+    "TEVIR",
+    "TEX",
+    "THAALU",
+    "THAHAN",
+    "THAJ",
+    "THAMEDH",
+    "THANNA",
+    "THANTHAKHAT",
+    "THARI",
+    "THAW",
+    "THEA",
+    "THELE",
+    "THEREFORE",
+    "THERMODYNAMIC",
+    "THES",
+    "THETH",
+    "THEY",
+    "THIAB",
+    "THICK",
+    "THIGH",
+    "THING",
+    "THINKING",
+    "THIRD-STAGE",
+    "THIRTY-ONE",
+    "THITA",
+    "THIUTH",
+    "THOA",
+    "THOJ",
+    "THOLHOMA",
+    "THOM",
+    "THOU",
+    "THREE-CIRCLE",
+    "THREE-DOT",
+    "THREE-EM",
+    "THREE-HUNDRED-AND-TWENTIETH",
+    "THREE-LEGGED",
+    "THREE-LINE",
+    "THREE-PER-EM",
+    "THREE-THIRTY",
+    "THROWING",
+    "THUNDERSTORM",
+    "THUNG",
+    "THURISAZ",
+    "THURS",
+    "THWE",
+    "THWEE",
+    "THWI",
+    "THWII",
+    "THWO",
+    "THWOO",
+    "THYOOM",
+    "THZ",
+    "TI-1",
+    "TI-2",
+    "TI-3",
+    "TI-4",
+    "TI-5",
+    "TI-6",
+    "TI-7",
+    "TI2",
+    "TIARA",
+    "TICKET",
+    "TICKETS",
+    "TIEP",
+    "TIEX",
+    "TII",
+    "TIKEUT-CHIEUCH",
+    "TIKEUT-MIEUM",
+    "TIKEUT-SIOS-KIYEOK",
+    "TIKEUT-THIEUTH",
+    "TILES",
+    "TILT",
+    "TIMER",
+    "TIN",
+    "TINAGMA",
+    "TINCTURE",
+    "TING",
+    "TINNE",
+    "TIPEHA",
+    "TIPPI",
+    "TIRED",
+    "TIRTA",
+    "TIRYAK",
+    "TITUAEP",
+    "TIWAZ",
+    "TIWR",
+    "TIX",
+    "TLEE",
+    "TLHEE",
+    "TLHOO",
+    "TLHU",
+    "TLHWE",
+    "TLHYA",
+    "TN",
+    "TO-1",
+    "TO-2",
+    "TO-3",
+    "TO-4",
+    "TO-5",
+    "TO-6",
+    "TO-RA",
+    "TOA",
+    "TOANDAKHIAT",
+    "TOCHKA",
+    "TOILET",
+    "TOKYO",
+    "TOLONG",
+    "TOMATO",
+    "TOMPI",
+    "TON",
+    "TONE-B",
+    "TONE-D",
+    "TONE-G",
+    "TONE-J",
+    "TONE-M",
+    "TONE-S",
+    "TONE-V",
+    "TONG",
+    "TONPI",
+    "TOOLBOX",
+    "TOON",
+    "TOOTHBRUSH",
+    "TORCH",
+    "TORNADO",
+    "TORSO",
+    "TORSO-FLOORPLANE",
+    "TOS",
+    "TOUCHES",
+    "TOURNOIS",
+    "TOV",
+    "TOWER",
+    "TOX",
+    "TOYOR",
+    "TR",
+    "TRACKBALL",
+    "TRACTOR",
+    "TRADE",
+    "TRAILING",
+    "TRAMWAY",
+    "TRANSPLUTO",
+    "TRANSVERSAL",
+    "TRAP",
+    "TRAPEZIUM",
+    "TREADING",
+    "TREDECILE",
+    "TRESVETLO",
+    "TRESVETLY",
+    "TRI",
+    "TRIA",
+    "TRIANGLES",
+    "TRIFOLIATE",
+    "TRIFONIAS",
+    "TRIGORGON",
+    "TRIISAP",
+    "TRILL",
+    "TRILLIONS",
+    "TRION",
+    "TRIPLI",
+    "TRIPOD",
+    "TRITOS",
+    "TRIUMPH",
+    "TROLL",
+    "TROLLEY",
+    "TROLLEYBUS",
+    "TROMIKOLYGISMA",
+    "TROMIKOPARAKALESMA",
+    "TROMIKOPSIFISTON",
+    "TROMIKOSYNAGMA",
+    "TROPHY",
+    "TRUMP-1",
+    "TRUMP-10",
+    "TRUMP-11",
+    "TRUMP-12",
+    "TRUMP-13",
+    "TRUMP-14",
+    "TRUMP-15",
+    "TRUMP-16",
+    "TRUMP-17",
+    "TRUMP-18",
+    "TRUMP-19",
+    "TRUMP-2",
+    "TRUMP-20",
+    "TRUMP-21",
+    "TRUMP-3",
+    "TRUMP-4",
+    "TRUMP-5",
+    "TRUMP-6",
+    "TRUMP-7",
+    "TRUMP-8",
+    "TRUMP-9",
+    "TRUMPET",
+    "TRUNCATED",
+    "TRUTH",
+    "TRYASKA",
+    "TRYASOGLASNAYA",
+    "TRYASOPOVODNAYA",
+    "TRYASOSTRELNAYA",
+    "TRYBLION",
+    "TSAA",
+    "TSAADIY",
+    "TSAB",
+    "TSEEB",
+    "TSERE",
+    "TSHAB",
+    "TSHEEJ",
+    "TSHOOJ",
+    "TSHUGS",
+    "TSIU",
+    "TSOV",
+    "TSSA",
+    "TSWA",
+    "TSWB",
+    "TT2",
+    "TTEE",
+    "TTH",
+    "TTHEE",
+    "TTHOO",
+    "TTHU",
+    "TTHWE",
+    "TTSA",
+    "TTSE",
+    "TTSEE",
+    "TTSI",
+    "TTSO",
+    "TTSU",
+    "TTTHA",
+    "TTU",
+    "TTUDDAG",
+    "TU-1",
+    "TU-2",
+    "TU-3",
+    "TU-4",
+    "TU-TO",
+    "TUAE",
+    "TUAEP",
+    "TUB",
+    "TUBE",
+    "TUGRIK",
+    "TUKWENTIS",
+    "TULIP",
+    "TUMAE",
+    "TUMBLER",
+    "TUMETES",
+    "TUNNY",
+    "TUO",
+    "TUOP",
+    "TUOX",
+    "TUPNI",
+    "TURKEY",
+    "TURKISH",
+    "TURO2",
+    "TURU",
+    "TURX",
+    "TUT",
+    "TUTEYASAT",
+    "TUTTY",
+    "TUUMU",
+    "TUX",
+    "TUXEDO",
+    "TVIMADUR",
+    "TWELVE-THIRTY",
+    "TWENTY-EIGHT",
+    "TWENTY-NINE",
+    "TWENTY-SEVEN",
+    "TWENTY-SIX",
+    "TWISTED",
+    "TWISTING",
+    "TWO-EM",
+    "TWO-LINE",
+    "TWO-THIRTY",
+    "TXA",
+    "TXHEEJ",
+    "TYA",
+    "TYAY",
+    "TYE",
+    "TYI",
+    "TYO",
+    "TYPE-1",
+    "TYPE-1-2",
+    "TYPE-2",
+    "TYPE-7",
+    "TYR",
+    "TZA",
+    "TZAA",
+    "TZE",
+    "TZEE",
+    "TZI",
+    "TZIR",
+    "TZO",
+    "TZOA",
+    "TZU",
+    "U-1",
+    "U-2",
+    "U-3",
+    "U-4",
+    "U-5",
+    "U-A",
+    "U-AE",
+    "U-EO-EU",
+    "U-I-I",
+    "U-U",
+    "U-YE",
+    "U-YEO",
+    "U001",
+    "U002",
+    "U003",
+    "U004",
+    "U005",
+    "U006",
+    "U006A",
+    "U006B",
+    "U007",
+    "U008",
+    "U009",
+    "U010",
+    "U011",
+    "U012",
+    "U013",
+    "U014",
+    "U015",
+    "U016",
+    "U017",
+    "U018",
+    "U019",
+    "U020",
+    "U021",
+    "U022",
+    "U023",
+    "U023A",
+    "U024",
+    "U025",
+    "U026",
+    "U027",
+    "U028",
+    "U029",
+    "U029A",
+    "U030",
+    "U031",
+    "U032",
+    "U032A",
+    "U033",
+    "U034",
+    "U035",
+    "U036",
+    "U037",
+    "U038",
+    "U039",
+    "U040",
+    "U041",
+    "U042",
+    "UAN",
+    "UANG",
+    "UATH",
+    "UBHAYATO",
+    "UBUFILI",
+    "UDAAT",
+    "UDARKA",
+    "UDD",
+    "UDUG",
+    "UEA",
+    "UEC",
+    "UEE",
+    "UEI",
+    "UEN",
+    "UEQ",
+    "UEY",
+    "UHD",
+    "UIC",
+    "UILLEANN",
+    "UIQ",
+    "UIUC",
+    "UIUQ",
+    "UIUX",
+    "UIUZ",
+    "UIX",
+    "UIZ",
+    "UJ",
+    "UMBIN",
+    "UNA",
+    "UNAMUSED",
+    "UNAP",
+    "UNASPIRATED",
+    "UNBLENDED",
+    "UNCERTAINTY",
+    "UNCIA",
+    "UNDERDOT",
+    "UNDO",
+    "UNEVEN",
+    "UNG",
+    "UNICORN",
+    "UNIFORM",
+    "UNITED",
+    "UNITY",
+    "UNKNOWN",
+    "UNMARRIED",
+    "UNY",
+    "UOG",
+    "UON",
+    "UOP",
+    "UOX",
+    "UPRIGHT",
+    "UPSIDE-DOWN",
+    "UQ",
+    "UR4",
+    "URA",
+    "URI",
+    "URI3",
+    "URINE",
+    "URN",
+    "URUS",
+    "URUZ",
+    "USE",
+    "USH2",
+    "USHENNA",
+    "USHUMX",
+    "USHX",
+    "USSU",
+    "USSU3",
+    "UTTHI",
+    "UTUKI",
+    "UUU",
+    "UUU2",
+    "UUU3",
+    "UUUU",
+    "UUYANNA",
+    "UWU",
+    "UX",
+    "UYANNA",
+    "UZ",
+    "UZHAKKU",
+    "UZU",
+    "V001",
+    "V001A",
+    "V001B",
+    "V001C",
+    "V001D",
+    "V001E",
+    "V001F",
+    "V001G",
+    "V001H",
+    "V001I",
+    "V002",
+    "V002A",
+    "V003",
+    "V004",
+    "V005",
+    "V006",
+    "V007",
+    "V007A",
+    "V007B",
+    "V008",
+    "V009",
+    "V010",
+    "V011",
+    "V011A",
+    "V011B",
+    "V011C",
+    "V011D",
+    "V012",
+    "V012A",
+    "V012B",
+    "V013",
+    "V014",
+    "V015",
+    "V016",
+    "V017",
+    "V018",
+    "V019",
+    "V020",
+    "V020A",
+    "V020B",
+    "V020C",
+    "V020D",
+    "V020E",
+    "V020F",
+    "V020G",
+    "V020H",
+    "V020I",
+    "V020J",
+    "V020K",
+    "V020L",
+    "V021",
+    "V022",
+    "V023",
+    "V023A",
+    "V024",
+    "V025",
+    "V026",
+    "V027",
+    "V028",
+    "V028A",
+    "V029",
+    "V029A",
+    "V030",
+    "V030A",
+    "V031",
+    "V031A",
+    "V032",
+    "V033",
+    "V033A",
+    "V034",
+    "V035",
+    "V036",
+    "V037",
+    "V037A",
+    "V038",
+    "V039",
+    "V040",
+    "V040A",
+    "VAAVU",
+    "VAJ",
+    "VAKAIYARAA",
+    "VALLEY",
+    "VAMPIRE",
+    "VAP",
+    "VAPOURS",
+    "VARAAKAN",
+    "VARCA",
+    "VAREIAI",
+    "VARIKA",
+    "VARYS",
+    "VASIS",
+    "VASTNESS",
+    "VAT",
+    "VATHY",
+    "VAU",
+    "VAX",
+    "VAYANNA",
+    "VC",
+    "VECTOR",
+    "VEGETABLE",
+    "VEHICLE",
+    "VEIL",
+    "VELI",
+    "VER",
+    "VERDIGRIS",
+    "VERGE",
+    "VERSICLE",
+    "VERTICAL-00-00",
+    "VERTICAL-00-01",
+    "VERTICAL-00-02",
+    "VERTICAL-00-03",
+    "VERTICAL-00-04",
+    "VERTICAL-00-05",
+    "VERTICAL-00-06",
+    "VERTICAL-01-00",
+    "VERTICAL-01-01",
+    "VERTICAL-01-02",
+    "VERTICAL-01-03",
+    "VERTICAL-01-04",
+    "VERTICAL-01-05",
+    "VERTICAL-01-06",
+    "VERTICAL-02-00",
+    "VERTICAL-02-01",
+    "VERTICAL-02-02",
+    "VERTICAL-02-03",
+    "VERTICAL-02-04",
+    "VERTICAL-02-05",
+    "VERTICAL-02-06",
+    "VERTICAL-03-00",
+    "VERTICAL-03-01",
+    "VERTICAL-03-02",
+    "VERTICAL-03-03",
+    "VERTICAL-03-04",
+    "VERTICAL-03-05",
+    "VERTICAL-03-06",
+    "VERTICAL-04-00",
+    "VERTICAL-04-01",
+    "VERTICAL-04-02",
+    "VERTICAL-04-03",
+    "VERTICAL-04-04",
+    "VERTICAL-04-05",
+    "VERTICAL-04-06",
+    "VERTICAL-05-00",
+    "VERTICAL-05-01",
+    "VERTICAL-05-02",
+    "VERTICAL-05-03",
+    "VERTICAL-05-04",
+    "VERTICAL-05-05",
+    "VERTICAL-05-06",
+    "VERTICAL-06-00",
+    "VERTICAL-06-01",
+    "VERTICAL-06-02",
+    "VERTICAL-06-03",
+    "VERTICAL-06-04",
+    "VERTICAL-06-05",
+    "VERTICAL-06-06",
+    "VEST",
+    "VESTA",
+    "VEUAE",
+    "VEUAEPEN",
+    "VEUM",
+    "VEUX",
+    "VEX",
+    "VEYZ",
+    "VFA",
+    "VHA",
+    "VIBRATION",
+    "VIDEOCASSETTE",
+    "VIDJ",
+    "VIDJ-2",
+    "VIE",
+    "VIEP",
+    "VIEWDATA",
+    "VIEWER",
+    "VIEWING",
+    "VIEX",
+    "VIGINTILE",
+    "VILLAGE",
+    "VINEGAR-2",
+    "VINEGAR-3",
+    "VIOLIN",
+    "VIP",
+    "VIRGA",
+    "VIRGO",
+    "VIRIAM",
+    "VISARGAYA",
+    "VIT",
+    "VITAE",
+    "VITAE-2",
+    "VITRIOL",
+    "VITRIOL-2",
+    "VIX",
+    "VOCALIZATION",
+    "VOD",
+    "VOICELESS",
+    "VOID",
+    "VOIDED",
+    "VOLCANO",
+    "VOLLEYBALL",
+    "VOLTAGE",
+    "VOLUME",
+    "VOM",
+    "VOMITING",
+    "VOOI",
+    "VOP",
+    "VOT",
+    "VOW",
+    "VOWEL-CARRIER",
+    "VOX",
+    "VQ",
+    "VS",
+    "VUEQ",
+    "VULCANUS",
+    "VUP",
+    "VURX",
+    "VUT",
+    "VUX",
+    "VW",
+    "VWA",
+    "VWJ",
+    "VX",
+    "VYP",
+    "VYR",
+    "VYRX",
+    "VYT",
+    "VYX",
+    "VZ",
+    "VZMET",
+    "W001",
+    "W002",
+    "W003",
+    "W003A",
+    "W004",
+    "W005",
+    "W006",
+    "W007",
+    "W008",
+    "W009",
+    "W009A",
+    "W010",
+    "W010A",
+    "W011",
+    "W012",
+    "W013",
+    "W014",
+    "W014A",
+    "W015",
+    "W016",
+    "W017",
+    "W017A",
+    "W018",
+    "W018A",
+    "W019",
+    "W020",
+    "W021",
+    "W022",
+    "W023",
+    "W024",
+    "W024A",
+    "W025",
+    "WA-1",
+    "WA-2",
+    "WA-3",
+    "WA-4",
+    "WA-5",
+    "WA-AALIH",
+    "WA-SALLAM",
+    "WA-TAAALAA",
+    "WAAALIHEE",
+    "WAAJIB",
+    "WAAVU",
+    "WADDA",
+    "WAEN",
+    "WAFFLE",
+    "WAI",
+    "WAIST",
+    "WALLPLANE",
+    "WAN",
+    "WAND",
+    "WANDERER",
+    "WANGKUOQ",
+    "WAQFA",
+    "WARNING",
+    "WAS-SALAAM",
+    "WASSALLAM",
+    "WASTEBASKET",
+    "WASTING",
+    "WAT",
+    "WATERMELON",
+    "WATTO",
+    "WB",
+    "WE-1",
+    "WE-2",
+    "WE-3",
+    "WE-4",
+    "WEAPON",
+    "WEB",
+    "WEDDING",
+    "WEDGE",
+    "WEEN",
+    "WELL",
+    "WEN",
+    "WEP",
+    "WET",
+    "WEUX",
+    "WEX",
+    "WG",
+    "WH",
+    "WHEELED",
+    "WHITE-FEATHERED",
+    "WI-1",
+    "WI-2",
+    "WI-3",
+    "WI-4",
+    "WI-5",
+    "WIANG",
+    "WIANGWAAK",
+    "WIDENING",
+    "WIGGLES",
+    "WIGNYAN",
+    "WILTED",
+    "WIN",
+    "WING",
+    "WINGS",
+    "WINJA",
+    "WINK",
+    "WINTER",
+    "WIRED",
+    "WIRELESS",
+    "WO-1",
+    "WO-2",
+    "WO-3",
+    "WO-4",
+    "WO-5",
+    "WO-6",
+    "WO-7",
+    "WOA",
+    "WOE",
+    "WOLF",
+    "WOMEN",
+    "WOMENS",
+    "WOON",
+    "WOP",
+    "WORDSPACE",
+    "WORKER",
+    "WORLD",
+    "WORM",
+    "WORRIED",
+    "WORSHIP",
+    "WOW",
+    "WOX",
+    "WRAP",
+    "WRAPPED",
+    "WREATH",
+    "WRESTLERS",
+    "WRONG",
+    "WRY",
+    "WUAEN",
+    "WUAET",
+    "WUI",
+    "WUN",
+    "WUNJO",
+    "WUOP",
+    "WUOX",
+    "WUP",
+    "WVA",
+    "WVE",
+    "WVI",
+    "WZ",
+    "X-RAY",
+    "X-X",
+    "X001",
+    "X002",
+    "X003",
+    "X004",
+    "X004A",
+    "X004B",
+    "X005",
+    "X006",
+    "X006A",
+    "X007",
+    "X008",
+    "X008A",
+    "XAA",
+    "XAPH",
+    "XAU",
+    "XAUS",
+    "XEE",
+    "XESTES",
+    "XEYN",
+    "XG",
+    "XHA",
+    "XHEYN",
+    "XIAB",
+    "XIE",
+    "XIEP",
+    "XIET",
+    "XIEX",
+    "XIP",
+    "XIRON",
+    "XIT",
+    "XIX",
+    "XOA",
+    "XOP",
+    "XOPH",
+    "XOR",
+    "XOT",
+    "XOX",
+    "XSHAAYATHIYA",
+    "XU",
+    "XUO",
+    "XUOX",
+    "XVE",
+    "XWA",
+    "XWAA",
+    "XWE",
+    "XWEE",
+    "XWI",
+    "XY",
+    "XYAA",
+    "XYEE",
+    "XYI",
+    "XYO",
+    "XYOO",
+    "XYOOJ",
+    "XYP",
+    "XYR",
+    "XYRX",
+    "XYT",
+    "XYU",
+    "XYX",
+    "Y001",
+    "Y001A",
+    "Y002",
+    "Y003",
+    "Y004",
+    "Y005",
+    "Y006",
+    "Y007",
+    "Y008",
+    "YA-1",
+    "YA-2",
+    "YA-3",
+    "YA-4",
+    "YA-5",
+    "YA-O",
+    "YA-U",
+    "YAADO",
+    "YAAI",
+    "YAARU",
+    "YAB",
+    "YABH",
+    "YACH",
+    "YAD",
+    "YADD",
+    "YADDH",
+    "YADH",
+    "YAEMMAE",
+    "YAF",
+    "YAFU",
+    "YAG",
+    "YAGHH",
+    "YAGN",
+    "YAHH",
+    "YAKASH",
+    "YAKHH",
+    "YAL",
+    "YAMOK",
+    "YARN",
+    "YARR",
+    "YAS",
+    "YASH",
+    "YASS",
+    "YATH",
+    "YATT",
+    "YAU",
+    "YAV",
+    "YAW",
+    "YAWNING",
+    "YAYANNA",
+    "YAYD",
+    "YAZZ",
+    "YEA",
+    "YEEG",
+    "YEIN",
+    "YENAP",
+    "YEO-O",
+    "YEO-U",
+    "YEO-YA",
+    "YERAH",
+    "YESIEUNG-HIEUH",
+    "YESIEUNG-MIEUM",
+    "YETIV",
+    "YEUAE",
+    "YEUAET",
+    "YEUM",
+    "YEUQ",
+    "YEURAE",
+    "YEUX",
+    "YEW",
+    "YEY",
+    "YHA",
+    "YI-U",
+    "YIEE",
+    "YIEP",
+    "YIET",
+    "YIEX",
+    "YIH",
+    "YII",
+    "YING",
+    "YIP",
+    "YIX",
+    "YIZET",
+    "YO-1",
+    "YO-2",
+    "YO-3",
+    "YO-4",
+    "YO-5",
+    "YO-6",
+    "YO-A",
+    "YO-AE",
+    "YO-EO",
+    "YO-O",
+    "YO-YEO",
+    "YO-YO",
+    "YOA",
+    "YOMO",
+    "YOP",
+    "YORI",
+    "YOU",
+    "YOUTHFUL",
+    "YOUTHFULNESS",
+    "YOWD",
+    "YOX",
+    "YOY",
+    "YPORROI",
+    "YPSILI",
+    "YRY",
+    "YU-1",
+    "YU-2",
+    "YU-3",
+    "YU-4",
+    "YU-A",
+    "YU-AE",
+    "YU-E",
+    "YU-EO",
+    "YU-O",
+    "YU-U",
+    "YUAEN",
+    "YUAN",
+    "YUE",
+    "YUEQ",
+    "YUI",
+    "YUN",
+    "YUO",
+    "YUOM",
+    "YUOT",
+    "YUOX",
+    "YUP",
+    "YUPI",
+    "YUR",
+    "YURX",
+    "YUUKALEAPINTU",
+    "YUX",
+    "YYAA",
+    "YYE",
+    "YYP",
+    "YYR",
+    "YYRX",
+    "YYT",
+    "YYX",
+    "Z001",
+    "Z002",
+    "Z002A",
+    "Z002B",
+    "Z002C",
+    "Z002D",
+    "Z003",
+    "Z003A",
+    "Z003B",
+    "Z004",
+    "Z004A",
+    "Z005",
+    "Z005A",
+    "Z006",
+    "Z007",
+    "Z008",
+    "Z009",
+    "Z010",
+    "Z011",
+    "Z012",
+    "Z013",
+    "Z014",
+    "Z015",
+    "Z015A",
+    "Z015B",
+    "Z015C",
+    "Z015D",
+    "Z015E",
+    "Z015F",
+    "Z015G",
+    "Z015H",
+    "Z015I",
+    "Z016",
+    "Z016A",
+    "Z016B",
+    "Z016C",
+    "Z016D",
+    "Z016E",
+    "Z016F",
+    "Z016G",
+    "Z016H",
+    "ZAEF",
+    "ZAG",
+    "ZAI",
+    "ZAKRYTOE",
+    "ZAMX",
+    "ZANOZHEK",
+    "ZAP",
+    "ZAPYATYMI",
+    "ZARL",
+    "ZARQA",
+    "ZAT",
+    "ZAVIYANI",
+    "ZAX",
+    "ZAYIN-YODH",
+    "ZE2",
+    "ZEBRA",
+    "ZELO",
+    "ZEP",
+    "ZEUS",
+    "ZEVOK",
+    "ZEX",
+    "ZH",
+    "ZHAA",
+    "ZHAIN",
+    "ZHAP",
+    "ZHAT",
+    "ZHAX",
+    "ZHAYIN",
+    "ZHEP",
+    "ZHET",
+    "ZHEX",
+    "ZHIL",
+    "ZHOI",
+    "ZHOO",
+    "ZHOP",
+    "ZHOT",
+    "ZHOX",
+    "ZHUO",
+    "ZHUOP",
+    "ZHUOX",
+    "ZHUP",
+    "ZHUR",
+    "ZHURX",
+    "ZHUT",
+    "ZHUX",
+    "ZHWA",
+    "ZHY",
+    "ZHYP",
+    "ZHYR",
+    "ZHYRX",
+    "ZHYT",
+    "ZHYX",
+    "ZI3",
+    "ZIE",
+    "ZIEP",
+    "ZIEX",
+    "ZIG",
+    "ZILDE",
+    "ZINOR",
+    "ZIP",
+    "ZIPPER-MOUTH",
+    "ZIQAA",
+    "ZIT",
+    "ZIX",
+    "ZMEYTSA",
+    "ZOA",
+    "ZOMBIE",
+    "ZOP",
+    "ZOX",
+    "ZRA",
+    "ZSA",
+    "ZSHA",
+    "ZUBUR",
+    "ZUM",
+    "ZUO",
+    "ZUOP",
+    "ZUOX",
+    "ZURX",
+    "ZUT",
+    "ZUX",
+    "ZWA",
+    "ZWARAKAY",
+    "ZWJ",
+    "ZYGOS",
+    "ZYP",
+    "ZYR",
+    "ZYRX",
+    "ZYT",
+    "ZYX",
+    "ZZAA",
+    "ZZAP",
+    "ZZAT",
+    "ZZAX",
+    "ZZEE",
+    "ZZEP",
+    "ZZEX",
+    "ZZIE",
+    "ZZIEP",
+    "ZZIEX",
+    "ZZIP",
+    "ZZIT",
+    "ZZIX",
+    "ZZOP",
+    "ZZOX",
+    "ZZSA",
+    "ZZSYA",
+    "ZZUP",
+    "ZZUR",
+    "ZZURX",
+    "ZZUX",
+    "ZZY",
+    "ZZYA",
+    "ZZYP",
+    "ZZYR",
+    "ZZYRX",
+    "ZZYT",
+    "ZZYX",
+);
+
+pub(crate) const LEXICON_OFFSETS: &[u32] = &[
+    0, 6, 10, 14, 19, 27, 34, 44,
+    49, 55, 57, 66, 72, 84, 92, 97,
+    102, 106, 112, 120, 129, 130, 141, 146,
+    151, 154, 159, 164, 168, 177, 181, 187,
+    193, 199, 208, 216, 221, 229, 236, 244,
+    249, 252, 257, 260, 266, 270, 276, 279,
+    280, 287, 294, 304, 310, 315, 323, 324,
+    327, 332, 335, 336, 337, 342, 347, 350,
+    355, 358, 360, 361, 371, 376, 385, 392,
+    399, 405, 410, 419, 425, 432, 440, 444,
+    452, 454, 459, 467, 471, 475, 479, 485,
+    492, 497, 504, 506, 509, 512, 519, 523,
+    528, 538, 541, 547, 551, 557, 565, 566,
+    573, 574, 578, 585, 587, 595, 600, 608,
+    616, 625, 635, 640, 645, 648, 657, 658,
+    665, 672, 675, 679, 684, 688, 693, 697,
+    699, 703, 708, 712, 720, 723, 730, 734,
+    747, 751, 756, 763, 767, 776, 784, 794,
+    800, 804, 814, 819, 821, 826, 828, 837,
+    841, 846, 854, 860, 868, 879, 880, 889,
+    891, 895, 904, 912, 914, 916, 922, 927,
+    931, 937, 945, 947, 955, 962, 964, 974,
+    979, 982, 987, 993, 997, 998, 1000, 1008,
+    1015, 1026, 1028, 1037, 1044, 1046, 1055, 1061,
+    1066, 1071, 1075, 1082, 1085, 1094, 1095, 1096,
+    1100, 1104, 1105, 1111, 1119, 1120, 1129, 1135,
+    1141, 1143, 1155, 1156, 1163, 1171, 1172, 1179,
+    1186, 1193, 1202, 1215, 1219, 1220, 1222, 1230,
+    1237, 1243, 1251, 1253, 1260, 1269, 1270, 1275,
+    1284, 1286, 1294, 1303, 1311, 1318, 1329, 1334,
+    1341, 1346, 1352, 1361, 1366, 1372, 1377, 1378,
+    1382, 1384, 1388, 1391, 1398, 1406, 1410, 1411,
+    1412, 1419, 1421, 1428, 1432, 1435, 1453, 1462,
+    1465, 1466, 1472, 1476, 1481, 1484, 1491, 1498,
+    1508, 1515, 1525, 1527, 1530, 1536, 1545, 1547,
+    1549, 1556, 1564, 1569, 1570, 1574, 1577, 1590,
+    1595, 1601, 1605, 1613, 1618, 1627, 1629, 1634,
+    1642, 1645, 1664, 1679, 1688, 1697, 1700, 1702,
+    1710, 1717, 1722, 1725, 1731, 1735, 1736, 1741,
+    1747, 1751, 1756, 1761, 1764, 1769, 1777, 1780,
+    1785, 1793, 1802, 1811, 1817, 1823, 1826, 1834,
+    1841, 1844, 1854, 1859, 1866, 1874, 1879, 1888,
+    1895, 1904, 1910, 1916, 1920, 1930, 1935, 1941,
+    1948, 1952, 1954, 1959, 1965, 1968, 1977, 1980,
+    1984, 1988, 1990, 1996, 1998, 2009, 2017, 2024,
+    2031, 2039, 2046, 2051, 2058, 2063, 2068, 2074,
+    2083, 2086, 2090, 2099, 2103, 2108, 2113, 2118,
+    2125, 2126, 2130, 2135, 2147, 2156, 2158, 2161,
+    2164, 2167, 2175, 2179, 2185, 2193, 2199, 2202,
+    2207, 2212, 2218, 2223, 2226, 2228, 2241, 2244,
+    2248, 2251, 2256, 2259, 2263, 2270, 2278, 2285,
+    2292, 2299, 2302, 2305, 2312, 2316, 2323, 2333,
+    2341, 2350, 2355, 2362, 2365, 2369, 2371, 2378,
+    2381, 2387, 2390, 2392, 2397, 2403, 2410, 2413,
+    2418, 2428, 2436, 2440, 2442, 2448, 2454, 2459,
+    2465, 2469, 2472, 2474, 2478, 2480, 2486, 2489,
+    2494, 2501, 2505, 2512, 2519, 2522, 2525, 2533,
+    2544, 2550, 2558, 2560, 2568, 2579, 2587, 2591,
+    2594, 2598, 2601, 2603, 2606, 2611, 2616, 2623,
+    2629, 2635, 2639, 2641, 2648, 2650, 2654, 2661,
+    2666, 2673, 2676, 2682, 2685, 2689, 2693, 2696,
+    2704, 2710, 2712, 2721, 2727, 2734, 2740, 2749,
+    2752, 2755, 2764, 2766, 2768, 2771, 2775, 2782,
+    2793, 2795, 2802, 2807, 2814, 2817, 2820, 2829,
+    2831, 2838, 2843, 2849, 2857, 2864, 2867, 2871,
+    2873, 2880, 2886, 2893, 2896, 2904, 2906, 2919,
+    2921, 2923, 2935, 2941, 2944, 2948, 2953, 2958,
+    2964, 2970, 2973, 2976, 2982, 2996, 3000, 3012,
+    3023, 3028, 3035, 3039, 3041, 3043, 3050, 3054,
+    3058, 3061, 3065, 3067, 3070, 3077, 3083, 3085,
+    3090, 3099, 3112, 3124, 3128, 3133, 3138, 3146,
+    3149, 3152, 3159, 3171, 3174, 3176, 3185, 3190,
+    3197, 3201, 3203, 3210, 3217, 3221, 3225, 3232,
+    3242, 3250, 3254, 3258, 3266, 3273, 3276, 3280,
+    3288, 3290, 3294, 3301, 3309, 3318, 3324, 3327,
+    3334, 3343, 3346, 3352, 3355, 3362, 3368, 3375,
+    3380, 3387, 3397, 3404, 3412, 3415, 3419, 3421,
+    3427, 3432, 3435, 3445, 3450, 3455, 3461, 3475,
+    3478, 3485, 3496, 3501, 3504, 3509, 3515, 3524,
+    3526, 3528, 3532, 3538, 3541, 3547, 3551, 3556,
+    3567, 3570, 3577, 3579, 3582, 3585, 3596, 3600,
+    3605, 3611, 3615, 3620, 3622, 3627, 3632, 3640,
+    3642, 3651, 3656, 3661, 3668, 3670, 3676, 3684,
+    3692, 3698, 3700, 3704, 3706, 3714, 3720, 3727,
+    3735, 3738, 3742, 3751, 3756, 3758, 3766, 3769,
+    3774, 3780, 3782, 3793, 3801, 3803, 3814, 3816,
+    3823, 3829, 3832, 3839, 3843, 3845, 3847, 3849,
+    3851, 3862, 3866, 3873, 3881, 3883, 3885, 3889,
+    3894, 3901, 3904, 3916, 3920, 3933, 3938, 3942,
+    3946, 3950, 3952, 3956, 3959, 3962, 3964, 3966,
+    3969, 3971, 3980, 3982, 3992, 3994, 4007, 4015,
+    4022, 4025, 4032, 4034, 4040, 4047, 4050, 4053,
+    4065, 4070, 4074, 4080, 4083, 4088, 4090, 4092,
+    4098, 4105, 4113, 4116, 4118, 4123, 4125, 4130,
+    4135, 4138, 4148, 4156, 4159, 4163, 4165, 4167,
+    4169, 4172, 4174, 4180, 4183, 4186, 4189, 4197,
+    4199, 4203, 4208, 4218, 4220, 4226, 4228, 4237,
+    4245, 4250, 4257, 4263, 4269, 4279, 4291, 4296,
+    4302, 4305, 4307, 4309, 4313, 4316, 4322, 4326,
+    4334, 4337, 4341, 4343, 4346, 4351, 4357, 4365,
+    4367, 4371, 4378, 4385, 4396, 4405, 4407, 4410,
+    4421, 4427, 4431, 4436, 4443, 4446, 4454, 4460,
+    4465, 4470, 4476, 4479, 4484, 4490, 4496, 4502,
+    4508, 4511, 4514, 4523, 4528, 4530, 4535, 4543,
+    4547, 4551, 4568, 4571, 4575, 4577, 4581, 4586,
+    4592, 4596, 4601, 4608, 4613, 4616, 4622, 4626,
+    4629, 4635, 4637, 4641, 4643, 4646, 4653, 4662,
+    4669, 4675, 4680, 4690, 4692, 4700, 4704, 4706,
+    4712, 4716, 4721, 4727, 4738, 4741, 4748, 4754,
+    4756, 4768, 4774, 4777, 4796, 4814, 4821, 4824,
+    4831, 4835, 4837, 4839, 4843, 4845, 4850, 4856,
+    4859, 4864, 4869, 4877, 4884, 4889, 4894, 4898,
+    4900, 4902, 4906, 4911, 4916, 4919, 4924, 4927,
+    4933, 4937, 4943, 4947, 4953, 4958, 4963, 4968,
+    4975, 4979, 4982, 4987, 4990, 4994, 5000, 5005,
+    5008, 5015, 5021, 5024, 5031, 5037, 5039, 5045,
+    5050, 5057, 5059, 5066, 5074, 5080, 5083, 5091,
+    5105, 5112, 5116, 5120, 5127, 5135, 5142, 5144,
+    5148, 5152, 5157, 5162, 5170, 5174, 5180, 5188,
+    5195, 5199, 5209, 5213, 5219, 5224, 5226, 5230,
+    5234, 5236, 5238, 5240, 5244, 5246, 5248, 5251,
+    5254, 5256, 5263, 5267, 5275, 5284, 5289, 5292,
+    5299, 5302, 5305, 5310, 5318, 5323, 5331, 5337,
+    5343, 5350, 5355, 5360, 5369, 5373, 5378, 5383,
+    5388, 5394, 5403, 5407, 5410, 5413, 5420, 5426,
+    5430, 5433, 5435, 5437, 5445, 5451, 5455, 5461,
+    5466, 5469, 5474, 5479, 5489, 5494, 5496, 5498,
+    5504, 5508, 5510, 5513, 5517, 5519, 5525, 5532,
+    5543, 5553, 5560, 5563, 5566, 5570, 5573, 5576,
+    5579, 5588, 5594, 5600, 5607, 5610, 5620, 5627,
+    5636, 5642, 5647, 5654, 5658, 5662, 5666, 5671,
+    5676, 5678, 5688, 5692, 5697, 5701, 5703, 5705,
+    5715, 5717, 5721, 5726, 5731, 5740, 5743, 5746,
+    5753, 5757, 5765, 5771, 5782, 5789, 5791, 5793,
+    5801, 5805, 5807, 5810, 5816, 5822, 5826, 5829,
+    5832, 5834, 5843, 5847, 5850, 5857, 5866, 5870,
+    5876, 5887, 5891, 5897, 5903, 5910, 5914, 5922,
+    5930, 5934, 5943, 5952, 5955, 5959, 5968, 5975,
+    5978, 5986, 5992, 5998, 6004, 6020, 6028, 6030,
+    6036, 6039, 6042, 6052, 6064, 6071, 6077, 6081,
+    6087, 6089, 6096, 6106, 6112, 6117, 6125, 6133,
+    6145, 6158, 6164, 6167, 6172, 6180, 6185, 6193,
+    6198, 6200, 6203, 6208, 6213, 6216, 6221, 6226,
+    6231, 6240, 6246, 6249, 6256, 6261, 6269, 6277,
+    6284, 6292, 6295, 6298, 6301, 6309, 6315, 6323,
+    6329, 6332, 6337, 6343, 6349, 6352, 6361, 6365,
+    6370, 6374, 6379, 6387, 6391, 6395, 6403, 6409,
+    6411, 6413, 6415, 6419, 6422, 6431, 6433, 6438,
+    6447, 6450, 6454, 6462, 6465, 6472, 6482, 6491,
+    6494, 6503, 6506, 6514, 6522, 6528, 6536, 6542,
+    6545, 6554, 6562, 6569, 6576, 6580, 6584, 6589,
+    6593, 6602, 6611, 6616, 6620, 6623, 6631, 6633,
+    6636, 6643, 6649, 6659, 6662, 6666, 6670, 6678,
+    6686, 6692, 6701, 6705, 6710, 6718, 6726, 6730,
+    6734, 6739, 6744, 6747, 6754, 6762, 6764, 6772,
+    6778, 6788, 6795, 6800, 6810, 6814, 6819, 6824,
+    6829, 6835, 6842, 6846, 6854, 6858, 6863, 6870,
+    6878, 6885, 6893, 6899, 6901, 6908, 6912, 6919,
+    6924, 6933, 6939, 6946, 6953, 6959, 6963, 6972,
+    6974, 6977, 6985, 6990, 6999, 7005, 7012, 7021,
+    7025, 7028, 7032, 7037, 7042, 7047, 7053, 7062,
+    7068, 7072, 7075, 7080, 7086, 7090, 7093, 7102,
+    7111, 7119, 7126, 7134, 7137, 7141, 7145, 7151,
+    7157, 7171, 7174, 7184, 7187, 7191, 7194, 7197,
+    7201, 7207, 7209, 7215, 7217, 7234, 7238, 7241,
+    7245, 7250, 7254, 7258, 7265, 7268, 7276, 7281,
+    7295, 7304, 7307, 7310, 7313, 7320, 7323, 7328,
+    7336, 7343, 7349, 7353, 7357, 7360, 7362, 7367,
+    7372, 7376, 7380, 7384, 7391, 7397, 7405, 7408,
+    7415, 7419, 7424, 7427, 7430, 7435, 7450, 7455,
+    7460, 7463, 7466, 7469, 7475, 7492, 7495, 7498,
+    7500, 7506, 7514, 7520, 7522, 7525, 7528, 7533,
+    7535, 7543, 7551, 7564, 7566, 7570, 7577, 7580,
+    7584, 7587, 7602, 7606, 7609, 7615, 7621, 7626,
+    7630, 7636, 7643, 7649, 7656, 7663, 7670, 7676,
+    7683, 7691, 7697, 7700, 7712, 7719, 7726, 7729,
+    7733, 7736, 7748, 7751, 7753, 7756, 7758, 7765,
+    7768, 7778, 7788, 7793, 7805, 7809, 7818, 7824,
+    7829, 7835, 7843, 7849, 7852, 7855, 7859, 7864,
+    7869, 7883, 7886, 7889, 7892, 7899, 7905, 7909,
+    7914, 7924, 7932, 7937, 7944, 7948, 7955, 7958,
+    7969, 7972, 7975, 7978, 7982, 7990, 7994, 7999,
+    8004, 8007, 8013, 8020, 8026, 8031, 8034, 8037,
+    8040, 8044, 8054, 8059, 8062, 8065, 8070, 8076,
+    8079, 8082, 8085, 8094, 8100, 8105, 8111, 8115,
+    8122, 8127, 8130, 8135, 8143, 8148, 8152, 8155,
+    8159, 8163, 8170, 8175, 8178, 8181, 8189, 8194,
+    8197, 8201, 8205, 8216, 8221, 8228, 8233, 8245,
+    8248, 8252, 8257, 8264, 8267, 8275, 8279, 8286,
+    8289, 8292, 8302, 8306, 8309, 8311, 8322, 8327,
+    8330, 8337, 8343, 8349, 8355, 8361, 8368, 8370,
+    8373, 8376, 8379, 8381, 8389, 8393, 8400, 8406,
+    8409, 8416, 8420, 8422, 8428, 8431, 8435, 8438,
+    8442, 8447, 8451, 8463, 8467, 8474, 8482, 8504,
+    8509, 8515, 8522, 8527, 8533, 8541, 8556, 8566,
+    8569, 8574, 8577, 8584, 8592, 8598, 8601, 8605,
+    8609, 8620, 8628, 8635, 8639, 8643, 8650, 8653,
+    8655, 8658, 8675, 8677, 8683, 8688, 8692, 8703,
+    8712, 8720, 8726, 8730, 8734, 8737, 8740, 8745,
+    8751, 8760, 8767, 8773, 8781, 8785, 8789, 8800,
+    8804, 8807, 8810, 8813, 8817, 8828, 8839, 8841,
+    8843, 8845, 8848, 8851, 8857, 8861, 8864, 8868,
+    8876, 8885, 8893, 8900, 8903, 8909, 8912, 8916,
+    8919, 8923, 8932, 8945, 8954, 8957, 8963, 8969,
+    8975, 8980, 8986, 8992, 8999, 9003, 9005, 9012,
+    9015, 9020, 9025, 9039, 9045, 9049, 9054, 9058,
+    9060, 9064, 9069, 9073, 9075, 9077, 9079, 9087,
+    9095, 9099, 9110, 9112, 9114, 9117, 9126, 9139,
+    9143, 9148, 9156, 9159, 9166, 9170, 9173, 9178,
+    9182, 9185, 9188, 9195, 9198, 9201, 9203, 9205,
+    9208, 9213, 9216, 9226, 9232, 9237, 9242, 9249,
+    9254, 9260, 9266, 9274, 9284, 9294, 9301, 9309,
+    9312, 9322, 9327, 9330, 9336, 9345, 9354, 9359,
+    9365, 9372, 9377, 9387, 9391, 9395, 9402, 9406,
+    9411, 9417, 9424, 9431, 9434, 9440, 9443, 9449,
+    9452, 9460, 9462, 9468, 9476, 9479, 9485, 9488,
+    9492, 9495, 9498, 9501, 9511, 9517, 9520, 9523,
+    9526, 9529, 9531, 9534, 9536, 9542, 9546, 9550,
+    9552, 9563, 9567, 9577, 9587, 9593, 9602, 9608,
+    9610, 9613, 9616, 9621, 9624, 9632, 9636, 9642,
+    9645, 9650, 9654, 9660, 9666, 9671, 9677, 9689,
+    9701, 9704, 9718, 9722, 9726, 9730, 9736, 9739,
+    9741, 9744, 9757, 9766, 9774, 9778, 9788, 9794,
+    9803, 9807, 9812, 9816, 9820, 9826, 9829, 9838,
+    9844, 9848, 9856, 9864, 9867, 9876, 9879, 9882,
+    9885, 9893, 9901, 9907, 9910, 9915, 9917, 9930,
+    9933, 9944, 9952, 9955, 9961, 9964, 9966, 9969,
+    9971, 9980, 9990, 9999, 10017, 10020, 10027, 10038,
+    10048, 10054, 10062, 10066, 10070, 10074, 10080, 10086,
+    10091, 10096, 10100, 10103, 10107, 10112, 10116, 10121,
+    10129, 10144, 10147, 10150, 10156, 10169, 10175, 10185,
+    10191, 10194, 10200, 10203, 10212, 10214, 10216, 10228,
+    10230, 10239, 10241, 10245, 10256, 10260, 10267, 10276,
+    10288, 10292, 10296, 10300, 10305, 10308, 10312, 10316,
+    10323, 10327, 10330, 10333, 10340, 10342, 10348, 10350,
+    10357, 10360, 10365, 10368, 10371, 10374, 10378, 10381,
+    10384, 10388, 10391, 10397, 10402, 10405, 10408, 10413,
+    10423, 10427, 10430, 10433, 10437, 10442, 10445, 10449,
+    10454, 10465, 10472, 10479, 10482, 10486, 10490, 10494,
+    10500, 10507, 10520, 10532, 10535, 10538, 10540, 10549,
+    10552, 10557, 10560, 10563, 10566, 10578, 10582, 10585,
+    10588, 10592, 10595, 10606, 10617, 10622, 10632, 10635,
+    10639, 10644, 10647, 10651, 10655, 10660, 10663, 10666,
+    10668, 10671, 10674, 10676, 10678, 10680, 10683, 10690,
+    10695, 10698, 10709, 10714, 10722, 10728, 10737, 10742,
+    10750, 10755, 10765, 10768, 10771, 10777, 10783, 10789,
+    10792, 10797, 10806, 10814, 10817, 10823, 10825, 10827,
+    10832, 10837, 10840, 10844, 10851, 10854, 10857, 10865,
+    10872, 10880, 10883, 10894, 10906, 10917, 10928, 10938,
+    10948, 10962, 10975, 10978, 10984, 10987, 10996, 10999,
+    11002, 11009, 11016, 11020, 11029, 11039, 11045, 11049,
+    11053, 11057, 11061, 11066, 11071, 11080, 11086, 11090,
+    11094, 11104, 11107, 11117, 11123, 11131, 11140, 11152,
+    11158, 11164, 11171, 11179, 11181, 11191, 11202, 11212,
+    11221, 11232, 11239, 11243, 11246, 11249, 11255, 11260,
+    11263, 11267, 11271, 11277, 11282, 11289, 11296, 11299,
+    11307, 11313, 11319, 11324, 11330, 11341, 11349, 11351,
+    11354, 11357, 11361, 11365, 11376, 11382, 11384, 11387,
+    11391, 11397, 11399, 11403, 11406, 11411, 11414, 11419,
+    11423, 11429, 11433, 11436, 11441, 11444, 11448, 11455,
+    11458, 11461, 11464, 11467, 11470, 11474, 11478, 11482,
+    11485, 11491, 11493, 11503, 11507, 11511, 11522, 11527,
+    11531, 11536, 11543, 11553, 11555, 11563, 11569, 11575,
+    11583, 11586, 11589, 11592, 11603, 11614, 11621, 11635,
+    11641, 11645, 11648, 11652, 11659, 11665, 11671, 11674,
+    11677, 11685, 11696, 11700, 11704, 11710, 11716, 11721,
+    11726, 11733, 11737, 11741, 11744, 11748, 11751, 11755,
+    11763, 11767, 11773, 11777, 11784, 11791, 11796, 11801,
+    11810, 11815, 11822, 11827, 11832, 11837, 11844, 11848,
+    11850, 11857, 11861, 11871, 11882, 11895, 11903, 11915,
+    11922, 11929, 11932, 11939, 11944, 11952, 11956, 11962,
+    11968, 11974, 11976, 11980, 11987, 11990, 11993, 11997,
+    12000, 12005, 12012, 12015, 12022, 12028, 12032, 12040,
+    12043, 12050, 12054, 12058, 12064, 12073, 12083, 12087,
+    12093, 12099, 12106, 12111, 12117, 12119, 12124, 12131,
+    12134, 12137, 12140, 12143, 12147, 12151, 12153, 12155,
+    12163, 12168, 12178, 12186, 12188, 12190, 12193, 12202,
+    12208, 12211, 12220, 12223, 12227, 12231, 12238, 12243,
+    12249, 12257, 12261, 12268, 12274, 12279, 12282, 12285,
+    12288, 12296, 12302, 12312, 12317, 12329, 12338, 12345,
+    12356, 12359, 12362, 12366, 12372, 12379, 12382, 12386,
+    12391, 12394, 12397, 12404, 12411, 12416, 12421, 12425,
+    12428, 12435, 12443, 12448, 12458, 12462, 12469, 12472,
+    12475, 12480, 12483, 12495, 12500, 12508, 12511, 12513,
+    12515, 12519, 12527, 12535, 12546, 12549, 12552, 12561,
+    12572, 12581, 12583, 12585, 12588, 12592, 12594, 12601,
+    12608, 12611, 12615, 12619, 12622, 12625, 12629, 12633,
+    12646, 12661, 12674, 12678, 12685, 12688, 12691, 12694,
+    12699, 12702, 12707, 12710, 12721, 12724, 12732, 12735,
+    12742, 12749, 12753, 12755, 12758, 12761, 12764, 12768,
+    12774, 12778, 12781, 12785, 12789, 12795, 12802, 12810,
+    12821, 12824, 12831, 12833, 12838, 12843, 12847, 12854,
+    12858, 12861, 12864, 12867, 12869, 12872, 12879, 12883,
+    12889, 12899, 12904, 12908, 12910, 12913, 12917, 12920,
+    12924, 12927, 12935, 12938, 12943, 12947, 12950, 12954,
+    12963, 12974, 12984, 12992, 12998, 13004, 13007, 13011,
+    13020, 13025, 13028, 13032, 13037, 13046, 13054, 13062,
+    13066, 13075, 13079, 13082, 13088, 13093, 13100, 13104,
+    13108, 13111, 13114, 13117, 13120, 13127, 13131, 13136,
+    13140, 13145, 13149, 13153, 13163, 13175, 13178, 13181,
+    13186, 13189, 13197, 13200, 13203, 13207, 13212, 13216,
+    13220, 13222, 13226, 13229, 13233, 13236, 13239, 13245,
+    13250, 13253, 13264, 13274, 13276, 13281, 13289, 13296,
+    13301, 13309, 13312, 13319, 13324, 13335, 13346, 13357,
+    13361, 13366, 13369, 13374, 13379, 13388, 13401, 13409,
+    13413, 13416, 13420, 13427, 13438, 13455, 13467, 13471,
+    13474, 13478, 13489, 13494, 13504, 13510, 13516, 13522,
+    13525, 13529, 13536, 13540, 13548, 13557, 13566, 13574,
+    13582, 13595, 13601, 13605, 13611, 13618, 13626, 13633,
+    13636, 13640, 13643, 13646, 13650, 13653, 13656, 13659,
+    13662, 13666, 13669, 13674, 13685, 13698, 13706, 13711,
+    13715, 13728, 13741, 13753, 13765, 13770, 13774, 13779,
+    13783, 13786, 13793, 13797, 13804, 13810, 13813, 13816,
+    13819, 13826, 13832, 13838, 13842, 13847, 13858, 13868,
+    13880, 13890, 13894, 13900, 13905, 13910, 13915, 13921,
+    13925, 13929, 13933, 13937, 13941, 13951, 13962, 13972,
+    13983, 13988, 14000, 14007, 14012, 14020, 14025, 14030,
+    14034, 14043, 14050, 14053, 14059, 14066, 14075, 14080,
+    14085, 14095, 14105, 14108, 14111, 14114, 14116, 14120,
+    14126, 14131, 14136, 14144, 14151, 14158, 14164, 14171,
+    14180, 14183, 14192, 14198, 14209, 14216, 14226, 14228,
+    14231, 14235, 14242, 14249, 14256, 14261, 14264, 14270,
+    14273, 14278, 14282, 14287, 14294, 14302, 14306, 14317,
+    14330, 14334, 14341, 14347, 14350, 14357, 14361, 14366,
+    14371, 14374, 14378, 14381, 14384, 14388, 14391, 14394,
+    14397, 14403, 14414, 14419, 14422, 14427, 14440, 14445,
+    14450, 14454, 14459, 14464, 14466, 14471, 14474, 14480,
+    14483, 14487, 14497, 14507, 14509, 14518, 14521, 14524,
+    14530, 14534, 14539, 14542, 14544, 14547, 14550, 14555,
+    14561, 14564, 14569, 14579, 14584, 14587, 14591, 14595,
+    14603, 14606, 14609, 14611, 14615, 14618, 14621, 14625,
+    14629, 14632, 14643, 14651, 14656, 14659, 14663, 14666,
+    14669, 14673, 14675, 14681, 14684, 14691, 14694, 14697,
+    14703, 14706, 14711, 14714, 14718, 14722, 14726, 14730,
+    14734, 14738, 14742, 14746, 14750, 14754, 14758, 14762,
+    14766, 14770, 14774, 14778, 14782, 14786, 14790, 14794,
+    14798, 14802, 14806, 14810, 14814, 14818, 14822, 14826,
+    14830, 14834, 14838, 14842, 14846, 14850, 14854, 14858,
+    14862, 14866, 14870, 14874, 14878, 14882, 14886, 14890,
+    14894, 14899, 14903, 14907, 14911, 14915, 14919, 14923,
+    14927, 14931, 14935, 14939, 14943, 14947, 14951, 14955,
+    14959, 14963, 14967, 14971, 14975, 14979, 14983, 14987,
+    14991, 14995, 14999, 15003, 15007, 15011, 15015, 15019,
+    15023, 15027, 15031, 15036, 15040, 15044, 15048, 15052,
+    15056, 15060, 15064, 15068, 15072, 15076, 15080, 15084,
+    15088, 15092, 15096, 15100, 15104, 15108, 15112, 15116,
+    15120, 15124, 15128, 15132, 15136, 15140, 15144, 15148,
+    15152, 15156, 15160, 15164, 15168, 15172, 15176, 15180,
+    15184, 15188, 15192, 15196, 15200, 15204, 15208, 15212,
+    15216, 15220, 15224, 15228, 15232, 15236, 15240, 15244,
+    15248, 15252, 15256, 15260, 15264, 15268, 15272, 15276,
+    15280, 15284, 15288, 15292, 15296, 15300, 15304, 15308,
+    15312, 15316, 15320, 15324, 15328, 15332, 15336, 15340,
+    15344, 15348, 15352, 15356, 15360, 15364, 15368, 15372,
+    15375, 15378, 15381, 15388, 15395, 15406, 15414, 15422,
+    15427, 15430, 15440, 15443, 15446, 15449, 15453, 15456,
+    15459, 15464, 15472, 15477, 15484, 15489, 15493, 15500,
+    15506, 15510, 15518, 15526, 15529, 15535, 15541, 15550,
+    15562, 15573, 15588, 15590, 15595, 15601, 15609, 15619,
+    15624, 15628, 15633, 15637, 15649, 15653, 15666, 15676,
+    15680, 15689, 15694, 15704, 15718, 15728, 15732, 15735,
+    15745, 15748, 15750, 15754, 15757, 15759, 15763, 15767,
+    15771, 15775, 15779, 15783, 15787, 15791, 15795, 15801,
+    15805, 15811, 15819, 15826, 15834, 15841, 15848, 15851,
+    15854, 15857, 15861, 15865, 15869, 15874, 15878, 15882,
+    15886, 15892, 15899, 15905, 15913, 15918, 15921, 15930,
+    15936, 15941, 15948, 15957, 15960, 15966, 15971, 15978,
+    15983, 15990, 15997, 16004, 16011, 16018, 16025, 16030,
+    16037, 16044, 16048, 16052, 16056, 16064, 16067, 16075,
+    16084, 16091, 16095, 16099, 16106, 16109, 16113, 16118,
+    16124, 16134, 16139, 16145, 16154, 16162, 16165, 16169,
+    16172, 16177, 16185, 16190, 16195, 16200, 16206, 16212,
+    16221, 16230, 16234, 16241, 16249, 16256, 16260, 16262,
+    16264, 16269, 16272, 16276, 16286, 16292, 16294, 16298,
+    16303, 16307, 16311, 16321, 16329, 16336, 16342, 16345,
+    16353, 16357, 16366, 16374, 16381, 16385, 16388, 16402,
+    16411, 16421, 16432, 16442, 16448, 16457, 16466, 16471,
+    16478, 16488, 16493, 16504, 16508, 16516, 16519, 16523,
+    16526, 16530, 16532, 16535, 16538, 16541, 16544, 16549,
+    16555, 16560, 16564, 16571, 16579, 16587, 16594, 16602,
+    16605, 16610, 16614, 16623, 16626, 16630, 16632, 16636,
+    16640, 16645, 16648, 16653, 16657, 16660, 16663, 16667,
+    16672, 16682, 16690, 16693, 16704, 16715, 16720, 16729,
+    16735, 16742, 16747, 16755, 16757, 16760, 16763, 16771,
+    16780, 16792, 16797, 16808, 16816, 16828, 16835, 16845,
+    16850, 16853, 16856, 16861, 16865, 16868, 16872, 16884,
+    16888, 16899, 16907, 16911, 16916, 16921, 16926, 16939,
+    16946, 16950, 16952, 16971, 16975, 16978, 16982, 16985,
+    16988, 16992, 16997, 17002, 17006, 17013, 17016, 17018,
+    17021, 17024, 17029, 17033, 17037, 17041, 17046, 17056,
+    17059, 17061, 17066, 17069, 17078, 17081, 17089, 17099,
+    17105, 17115, 17120, 17130, 17140, 17145, 17149, 17153,
+    17158, 17164, 17169, 17172, 17178, 17181, 17185, 17193,
+    17196, 17199, 17202, 17208, 17214, 17217, 17225, 17230,
+    17236, 17240, 17249, 17257, 17262, 17271, 17277, 17281,
+    17291, 17300, 17304, 17312, 17320, 17326, 17334, 17341,
+    17349, 17352, 17359, 17365, 17374, 17377, 17385, 17388,
+    17392, 17395, 17399, 17403, 17406, 17418, 17421, 17426,
+    17430, 17436, 17444, 17447, 17451, 17454, 17457, 17461,
+    17465, 17472, 17478, 17491, 17494, 17503, 17510, 17513,
+    17516, 17520, 17523, 17527, 17531, 17535, 17541, 17544,
+    17551, 17560, 17565, 17570, 17580, 17583, 17587, 17594,
+    17603, 17609, 17614, 17617, 17622, 17625, 17628, 17632,
+    17634, 17637, 17640, 17644, 17647, 17651, 17655, 17658,
+    17664, 17671, 17675, 17679, 17684, 17693, 17705, 17708,
+    17711, 17714, 17718, 17720, 17724, 17728, 17743, 17753,
+    17756, 17759, 17764, 17769, 17772, 17776, 17779, 17782,
+    17788, 17792, 17798, 17802, 17812, 17817, 17821, 17826,
+    17829, 17836, 17839, 17844, 17848, 17852, 17856, 17859,
+    17870, 17872, 17875, 17880, 17889, 17891, 17905, 17919,
+    17933, 17947, 17959, 17961, 17963, 17966, 17971, 17975,
+    17977, 17983, 17991, 17998, 18003, 18011, 18021, 18034,
+    18046, 18048, 18056, 18062, 18064, 18068, 18071, 18075,
+    18078, 18081, 18085, 18089, 18093, 18096, 18100, 18103,
+    18106, 18110, 18113, 18116, 18119, 18121, 18125, 18128,
+    18132, 18136, 18139, 18142, 18155, 18173, 18181, 18188,
+    18194, 18201, 18207, 18213, 18218, 18222, 18226, 18232,
+    18234, 18238, 18242, 18252, 18258, 18263, 18268, 18272,
+    18276, 18280, 18286, 18291, 18294, 18297, 18300, 18306,
+    18327, 18340, 18352, 18363, 18366, 18369, 18377, 18384,
+    18388, 18390, 18393, 18397, 18400, 18404, 18407, 18412,
+    18415, 18418, 18421, 18425, 18429, 18432, 18436, 18444,
+    18450, 18455, 18458, 18465, 18472, 18479, 18483, 18487,
+    18490, 18494, 18499, 18506, 18512, 18519, 18527, 18532,
+    18535, 18542, 18545, 18552, 18557, 18564, 18569, 18574,
+    18578, 18584, 18587, 18592, 18596, 18599, 18602, 18605,
+    18608, 18612, 18616, 18622, 18626, 18632, 18636, 18638,
+    18648, 18656, 18659, 18663, 18666, 18671, 18678, 18680,
+    18687, 18689, 18693, 18696, 18699, 18703, 18706, 18710,
+    18713, 18715, 18719, 18723, 18727, 18731, 18735, 18739,
+    18743, 18747, 18751, 18755, 18759, 18763, 18767, 18771,
+    18775, 18779, 18783, 18787, 18791, 18795, 18799, 18803,
+    18807, 18811, 18815, 18819, 18823, 18827, 18831, 18835,
+    18839, 18843, 18847, 18851, 18855, 18859, 18863, 18867,
+    18871, 18875, 18879, 18883, 18887, 18891, 18897, 18900,
+    18905, 18915, 18918, 18925, 18933, 18936, 18939, 18945,
+    18950, 18953, 18959, 18963, 18968, 18970, 18975, 18979,
+    18989, 18993, 18997, 19002, 19010, 19015, 19021, 19025,
+    19029, 19034, 19038, 19042, 19045, 19055, 19062, 19066,
+    19078, 19091, 19094, 19098, 19103, 19106, 19116, 19126,
+    19136, 19146, 19156, 19161, 19171, 19182, 19185, 19191,
+    19197, 19202, 19206, 19209, 19213, 19217, 19223, 19231,
+    19235, 19237, 19241, 19245, 19248, 19252, 19254, 19258,
+    19261, 19271, 19274, 19281, 19284, 19288, 19292, 19295,
+    19303, 19310, 19314, 19318, 19322, 19326, 19330, 19337,
+    19342, 19349, 19356, 19360, 19369, 19373, 19376, 19380,
+    19386, 19391, 19399, 19403, 19409, 19413, 19417, 19422,
+    19429, 19433, 19437, 19441, 19444, 19456, 19469, 19472,
+    19481, 19483, 19486, 19493, 19497, 19502, 19505, 19509,
+    19514, 19519, 19526, 19530, 19538, 19543, 19551, 19557,
+    19563, 19568, 19572, 19576, 19580, 19584, 19588, 19593,
+    19597, 19600, 19607, 19610, 19613, 19617, 19620, 19624,
+    19626, 19630, 19634, 19638, 19642, 19646, 19650, 19654,
+    19657, 19664, 19667, 19670, 19673, 19678, 19682, 19688,
+    19693, 19697, 19705, 19729, 19732, 19735, 19754, 19760,
+    19767, 19774, 19780, 19790, 19792, 19795, 19800, 19802,
+    19810, 19818, 19828, 19837, 19846, 19850, 19855, 19861,
+    19867, 19877, 19885, 19893, 19897, 19910, 19920, 19927,
+    19938, 19946, 19951, 19958, 19965, 19975, 19983, 19990,
+    19999, 20005, 20011, 20021, 20030, 20036, 20041, 20045,
+    20053, 20057, 20070, 20080, 20086, 20090, 20096, 20099,
+    20112, 20123, 20135, 20148, 20165, 20178, 20185, 20188,
+    20196, 20207, 20214, 20217, 20226, 20233, 20239, 20244,
+    20250, 20258, 20262, 20267, 20276, 20286, 20292, 20299,
+    20308, 20321, 20326, 20333, 20342, 20351, 20361, 20367,
+    20376, 20379, 20382, 20386, 20390, 20394, 20397, 20400,
+    20405, 20408, 20413, 20416, 20419, 20422, 20425, 20437,
+    20442, 20445, 20448, 20452, 20455, 20461, 20465, 20471,
+    20478, 20483, 20490, 20494, 20497, 20502, 20505, 20510,
+    20517, 20523, 20534, 20541, 20549, 20560, 20569, 20577,
+    20585, 20593, 20603, 20606, 20615, 20619, 20624, 20631,
+    20635, 20654, 20667, 20684, 20695, 20708, 20724, 20741,
+    20758, 20764, 20770, 20773, 20777, 20783, 20790, 20798,
+    20801, 20810, 20813, 20816, 20821, 20824, 20828, 20830,
+    20838, 20842, 20846, 20851, 20854, 20859, 20864, 20875,
+    20883, 20888, 20894, 20902, 20905, 20909, 20918, 20921,
+    20930, 20937, 20942, 20947, 20950, 20956, 20960, 20968,
+    20977, 20984, 20989, 20995, 21004, 21014, 21025, 21037,
+    21050, 21063, 21075, 21088, 21100, 21111, 21123, 21136,
+    21148, 21159, 21171, 21182, 21192, 21203, 21215, 21228,
+    21240, 21252, 21263, 21273, 21284, 21296, 21307, 21317,
+    21328, 21338, 21347, 21357, 21368, 21380, 21393, 21405,
+    21416, 21428, 21439, 21449, 21460, 21472, 21482, 21493,
+    21503, 21512, 21522, 21533, 21545, 21556, 21566, 21577,
+    21587, 21596, 21606, 21617, 21627, 21636, 21646, 21655,
+    21662, 21666, 21670, 21674, 21679, 21685, 21692, 21696,
+    21701, 21705, 21710, 21715, 21719, 21723, 21727, 21735,
+    21739, 21743, 21748, 21754, 21761, 21765, 21770, 21774,
+    21779, 21783, 21788, 21791, 21794, 21801, 21806, 21810,
+    21814, 21819, 21831, 21837, 21847, 21859, 21869, 21879,
+    21889, 21899, 21911, 21917, 21921, 21924, 21929, 21932,
+    21937, 21944, 21948, 21952, 21959, 21964, 21971, 21975,
+    21982, 21985, 21992, 21997, 22000, 22008, 22011, 22019,
+    22024, 22031, 22037, 22042, 22049, 22056, 22063, 22071,
+    22078, 22083, 22091, 22097, 22107, 22117, 22120, 22124,
+    22131, 22139, 22146, 22151, 22159, 22167, 22172, 22178,
+    22183, 22192, 22202, 22209, 22215, 22218, 22223, 22233,
+    22240, 22245, 22254, 22260, 22262, 22270, 22273, 22278,
+    22281, 22285, 22288, 22292, 22300, 22309, 22318, 22327,
+    22336, 22345, 22354, 22363, 22371, 22380, 22389, 22397,
+    22405, 22414, 22423, 22432, 22441, 22450, 22458, 22466,
+    22475, 22479, 22485, 22495, 22501, 22508, 22512, 22518,
+    22522, 22525, 22529, 22536, 22538, 22542, 22546, 22552,
+    22559, 22565, 22571, 22580, 22593, 22602, 22612, 22618,
+    22624, 22629, 22640, 22645, 22656, 22662, 22667, 22680,
+    22694, 22706, 22719, 22731, 22743, 22754, 22759, 22767,
+    22770, 22774, 22778, 22782, 22786, 22790, 22793, 22796,
+    22804, 22819, 22822, 22827, 22835, 22844, 22849, 22860,
+    22864, 22874, 22886, 22890, 22899, 22908, 22917, 22925,
+    22939, 22947, 22957, 22964, 22972, 22983, 22993, 23001,
+    23006, 23010, 23014, 23019, 23025, 23029, 23032, 23036,
+    23040, 23048, 23052, 23056, 23059, 23063, 23071, 23074,
+    23078, 23081, 23087, 23096, 23106, 23119, 23130, 23140,
+    23152, 23155, 23159, 23166, 23170, 23174, 23180, 23186,
+    23192, 23198, 23200, 23202, 23209, 23212, 23215, 23220,
+    23229, 23237, 23246, 23249, 23252, 23254, 23263, 23269,
+    23275, 23279, 23281, 23284, 23287, 23298, 23302, 23305,
+    23312, 23316, 23321, 23331, 23338, 23348, 23352, 23359,
+    23362, 23365, 23368, 23375, 23381, 23387, 23390, 23398,
+    23403, 23416, 23419, 23425, 23427, 23432, 23444, 23447,
+    23452, 23455, 23461, 23466, 23473, 23479, 23483, 23489,
+    23496, 23500, 23503, 23505, 23507, 23510, 23513, 23516,
+    23521, 23525, 23528, 23536, 23539, 23542, 23545, 23548,
+    23552, 23555, 23558, 23562, 23578, 23591, 23595, 23598,
+    23601, 23605, 23610, 23616, 23620, 23629, 23631, 23635,
+    23640, 23646, 23650, 23653, 23656, 23660, 23665, 23667,
+    23670, 23673, 23677, 23680, 23684, 23687, 23690, 23699,
+    23708, 23713, 23717, 23723, 23727, 23730, 23734, 23738,
+    23741, 23744, 23749, 23752, 23755, 23758, 23761, 23763,
+    23766, 23769, 23774, 23777, 23780, 23785, 23790, 23796,
+    23801, 23804, 23807, 23810, 23814, 23817, 23820, 23824,
+    23829, 23834, 23839, 23844, 23849, 23854, 23859, 23864,
+    23869, 23874, 23879, 23884, 23889, 23894, 23899, 23904,
+    23909, 23914, 23919, 23923, 23927, 23931, 23935, 23939,
+    23943, 23947, 23951, 23955, 23959, 23963, 23967, 23971,
+    23975, 23979, 23983, 23987, 23991, 23995, 23999, 24003,
+    24007, 24011, 24015, 24019, 24023, 24027, 24032, 24036,
+    24041, 24045, 24049, 24057, 24062, 24066, 24071, 24075,
+    24080, 24084, 24088, 24093, 24098, 24103, 24107, 24112,
+    24117, 24121, 24125, 24130, 24135, 24140, 24144, 24148,
+    24152, 24157, 24162, 24166, 24170, 24174, 24178, 24182,
+    24187, 24191, 24195, 24199, 24203, 24207, 24212, 24216,
+    24220, 24224, 24228, 24232, 24237, 24241, 24245, 24249,
+    24253, 24257, 24261, 24266, 24270, 24274, 24278, 24282,
+    24287, 24291, 24295, 24299, 24303, 24307, 24311, 24315,
+    24319, 24323, 24327, 24331, 24335, 24339, 24343, 24347,
+    24351, 24355, 24359, 24363, 24367, 24371, 24375, 24379,
+    24383, 24387, 24391, 24395, 24399, 24403, 24407, 24411,
+    24415, 24419, 24423, 24427, 24431, 24435, 24439, 24443,
+    24447, 24451, 24455, 24459, 24463, 24467, 24471, 24475,
+    24479, 24483, 24487, 24491, 24495, 24499, 24503, 24507,
+    24511, 24515, 24519, 24523, 24527, 24531, 24535, 24539,
+    24543, 24547, 24551, 24555, 24560, 24565, 24569, 24573,
+    24577, 24581, 24585, 24590, 24594, 24598, 24603, 24607,
+    24611, 24615, 24619, 24623, 24627, 24632, 24636, 24641,
+    24645, 24649, 24653, 24657, 24661, 24665, 24669, 24673,
+    24677, 24681, 24685, 24690, 24694, 24698, 24702, 24706,
+    24710, 24714, 24718, 24722, 24726, 24730, 24734, 24738,
+    24742, 24746, 24750, 24754, 24758, 24762, 24766, 24770,
+    24774, 24778, 24782, 24786, 24790, 24794, 24798, 24802,
+    24806, 24810, 24814, 24818, 24822, 24826, 24830, 24834,
+    24838, 24842, 24846, 24850, 24855, 24859, 24863, 24867,
+    24871, 24875, 24879, 24883, 24887, 24891, 24895, 24899,
+    24903, 24907, 24911, 24915, 24919, 24923, 24927, 24931,
+    24935, 24939, 24943, 24948, 24952, 24956, 24960, 24964,
+    24968, 24973, 24977, 24981, 24985, 24989, 24993, 24998,
+    25000, 25004, 25008, 25013, 25018, 25022, 25027, 25032,
+    25037, 25042, 25046, 25051, 25056, 25061, 25066, 25071,
+    25076, 25081, 25086, 25091, 25096, 25100, 25104, 25108,
+    25112, 25116, 25120, 25124, 25128, 25132, 25136, 25141,
+    25145, 25149, 25154, 25158, 25162, 25166, 25171, 25175,
+    25179, 25183, 25187, 25191, 25195, 25199, 25203, 25207,
+    25211, 25215, 25219, 25223, 25227, 25235, 25239, 25247,
+    25251, 25259, 25263, 25271, 25275, 25283, 25287, 25295,
+    25299, 25307, 25311, 25319, 25323, 25331, 25335, 25343,
+    25347, 25355, 25360, 25364, 25372, 25376, 25384, 25388,
+    25396, 25400, 25408, 25412, 25420, 25424, 25432, 25436,
+    25444, 25448, 25456, 25460, 25464, 25468, 25472, 25476,
+    25480, 25484, 25488, 25492, 25496, 25500, 25504, 25508,
+    25512, 25516, 25520, 25524, 25528, 25532, 25536, 25540,
+    25544, 25548, 25552, 25556, 25560, 25564, 25568, 25572,
+    25576, 25580, 25584, 25589, 25593, 25597, 25601, 25605,
+    25609, 25613, 25617, 25622, 25626, 25630, 25634, 25638,
+    25642, 25646, 25650, 25654, 25658, 25662, 25666, 25670,
+    25674, 25678, 25682, 25686, 25690, 25694, 25698, 25702,
+    25706, 25710, 25714, 25718, 25722, 25726, 25730, 25734,
+    25738, 25742, 25746, 25750, 25754, 25758, 25762, 25766,
+    25770, 25774, 25778, 25782, 25786, 25790, 25794, 25798,
+    25802, 25806, 25810, 25814, 25818, 25822, 25826, 25830,
+    25834, 25838, 25842, 25846, 25850, 25854, 25858, 25862,
+    25866, 25870, 25874, 25878, 25882, 25886, 25890, 25894,
+    25898, 25902, 25906, 25910, 25914, 25918, 25922, 25926,
+    25930, 25934, 25938, 25942, 25946, 25950, 25954, 25958,
+    25962, 25966, 25970, 25974, 25978, 25982, 25986, 25990,
+    25994, 25998, 26002, 26006, 26010, 26014, 26018, 26022,
+    26026, 26030, 26034, 26038, 26042, 26046, 26050, 26054,
+    26058, 26062, 26066, 26070, 26074, 26078, 26082, 26086,
+    26090, 26094, 26098, 26102, 26106, 26110, 26114, 26118,
+    26122, 26126, 26130, 26134, 26138, 26142, 26146, 26150,
+    26154, 26158, 26162, 26166, 26170, 26174, 26178, 26182,
+    26186, 26190, 26194, 26198, 26202, 26206, 26210, 26214,
+    26218, 26222, 26226, 26230, 26234, 26238, 26242, 26246,
+    26250, 26254, 26258, 26262, 26266, 26270, 26274, 26280,
+    26286, 26292, 26298, 26302, 26306, 26310, 26314, 26318,
+    26322, 26326, 26330, 26334, 26338, 26342, 26346, 26350,
+    26354, 26358, 26362, 26366, 26371, 26376, 26381, 26386,
+    26391, 26396, 26401, 26407, 26413, 26418, 26423, 26428,
+    26433, 26438, 26443, 26448, 26453, 26458, 26463, 26468,
+    26473, 26478, 26483, 26488, 26493, 26498, 26503, 26508,
+    26513, 26518, 26523, 26528, 26533, 26538, 26547, 26550,
+    26553, 26556, 26559, 26563, 26566, 26570, 26573, 26576,
+    26583, 26588, 26597, 26602, 26607, 26612, 26617, 26622,
+    26627, 26632, 26637, 26642, 26647, 26652, 26657, 26662,
+    26667, 26672, 26677, 26683, 26689, 26694, 26700, 26706,
+    26711, 26717, 26722, 26727, 26732, 26737, 26742, 26747,
+    26752, 26757, 26762, 26767, 26772, 26777, 26782, 26787,
+    26792, 26797, 26802, 26807, 26812, 26817, 26822, 26827,
+    26832, 26837, 26842, 26847, 26852, 26857, 26862, 26867,
+    26872, 26877, 26882, 26887, 26892, 26897, 26902, 26907,
+    26912, 26917, 26922, 26927, 26932, 26937, 26942, 26947,
+    26952, 26957, 26962, 26967, 26972, 26978, 26984, 26989,
+    26994, 26999, 27004, 27009, 27015, 27022, 27025, 27034,
+    27041, 27056, 27062, 27075, 27084, 27096, 27113, 27125,
+    27129, 27134, 27141, 27150, 27158, 27161, 27168, 27177,
+    27180, 27187, 27196, 27199, 27207, 27210, 27214, 27217,
+    27223, 27226, 27237, 27244, 27255, 27262, 27276, 27282,
+    27287, 27292, 27299, 27302, 27312, 27323, 27328, 27332,
+    27339, 27344, 27347, 27351, 27356, 27359, 27365, 27371,
+    27375, 27379, 27383, 27387, 27394, 27401, 27403, 27408,
+    27412, 27424, 27430, 27439, 27443, 27447, 27452, 27459,
+    27466, 27475, 27482, 27485, 27490, 27495, 27501, 27509,
+    27514, 27521, 27525, 27534, 27537, 27548, 27552, 27559,
+    27571, 27575, 27584, 27592, 27600, 27606, 27613, 27617,
+    27623, 27630, 27634, 27644, 27656, 27661, 27666, 27671,
+    27676, 27686, 27691, 27699, 27708, 27715, 27718, 27723,
+    27727, 27732, 27739, 27746, 27752, 27756, 27759, 27765,
+    27772, 27777, 27782, 27785, 27806, 27815, 27830, 27840,
+    27850, 27859, 27869, 27872, 27875, 27878, 27884, 27889,
+    27897, 27901, 27909, 27916, 27924, 27931, 27936, 27947,
+    27955, 27965, 27970, 27974, 27984, 27992, 28001, 28010,
+    28016, 28023, 28030, 28038, 28045, 28052, 28058, 28069,
+    28072, 28077, 28082, 28090, 28095, 28102, 28107, 28113,
+    28119, 28123, 28127, 28141, 28147, 28155, 28160, 28166,
+    28174, 28190, 28197, 28201, 28207, 28218, 28224, 28230,
+    28238, 28248, 28253, 28257, 28263, 28268, 28272, 28276,
+    28290, 28295, 28304, 28312, 28321, 28331, 28340, 28348,
+    28358, 28365, 28377, 28383, 28386, 28398, 28410, 28418,
+    28426, 28431, 28437, 28442, 28446, 28449, 28458, 28467,
+    28475, 28484, 28496, 28502, 28506, 28516, 28528, 28540,
+    28551, 28558, 28562, 28571, 28577, 28584, 28587, 28600,
+    28607, 28614, 28617, 28620, 28623, 28626, 28632, 28636,
+    28647, 28651, 28656, 28660, 28664, 28668, 28672, 28676,
+    28680, 28684, 28688, 28692, 28696, 28700, 28704, 28708,
+    28712, 28716, 28720, 28724, 28728, 28732, 28736, 28740,
+    28744, 28748, 28752, 28756, 28760, 28764, 28768, 28772,
+    28776, 28780, 28784, 28788, 28792, 28796, 28800, 28804,
+    28808, 28812, 28816, 28820, 28824, 28828, 28832, 28836,
+    28840, 28844, 28848, 28852, 28856, 28860, 28864, 28868,
+    28872, 28876, 28880, 28884, 28888, 28892, 28896, 28900,
+    28904, 28908, 28912, 28916, 28920, 28924, 28928, 28932,
+    28936, 28940, 28944, 28948, 28952, 28956, 28960, 28964,
+    28968, 28972, 28976, 28980, 28984, 28988, 28993, 28998,
+    29003, 29008, 29013, 29018, 29023, 29028, 29033, 29038,
+    29042, 29046, 29050, 29054, 29058, 29062, 29066, 29070,
+    29074, 29078, 29082, 29086, 29090, 29094, 29098, 29102,
+    29106, 29110, 29114, 29118, 29122, 29126, 29130, 29134,
+    29138, 29142, 29146, 29150, 29154, 29158, 29162, 29166,
+    29170, 29174, 29178, 29182, 29186, 29190, 29194, 29198,
+    29202, 29206, 29210, 29214, 29218, 29222, 29226, 29230,
+    29234, 29238, 29242, 29246, 29250, 29254, 29258, 29262,
+    29266, 29270, 29274, 29278, 29282, 29286, 29290, 29294,
+    29298, 29302, 29306, 29310, 29314, 29318, 29322, 29326,
+    29330, 29334, 29338, 29342, 29346, 29350, 29354, 29358,
+    29362, 29366, 29370, 29374, 29378, 29382, 29386, 29390,
+    29394, 29398, 29402, 29406, 29410, 29414, 29418, 29422,
+    29426, 29430, 29434, 29438, 29442, 29446, 29450, 29454,
+    29458, 29462, 29466, 29470, 29474, 29478, 29485, 29494,
+    29499, 29507, 29512, 29521, 29525, 29529, 29534, 29541,
+    29549, 29552, 29564, 29568, 29574, 29581, 29585, 29591,
+    29600, 29606, 29610, 29617, 29621, 29626, 29632, 29635,
+    29640, 29646, 29656, 29662, 29669, 29676, 29684, 29688,
+    29694, 29704, 29714, 29717, 29722, 29725, 29727, 29731,
+    29735, 29739, 29743, 29747, 29751, 29755, 29759, 29764,
+    29769, 29774, 29778, 29782, 29786, 29790, 29794, 29798,
+    29802, 29807, 29812, 29816, 29820, 29825, 29829, 29832,
+    29836, 29840, 29844, 29849, 29854, 29858, 29863, 29870,
+    29877, 29884, 29887, 29894, 29899, 29905, 29913, 29922,
+    29927, 29935, 29942, 29947, 29952, 29957, 29960, 29968,
+    29971, 29977, 29979, 29983, 29987, 29998, 30002, 30005,
+    30009, 30012, 30015, 30025, 30031, 30038, 30046, 30054,
+    30057, 30061, 30065, 30069, 30075, 30081, 30090, 30098,
+    30106, 30115, 30118, 30126, 30130, 30135, 30144, 30153,
+    30160, 30165, 30172, 30176, 30182, 30185, 30192, 30196,
+    30199, 30209, 30214, 30218, 30226, 30237, 30240, 30245,
+    30250, 30253, 30258, 30265, 30272, 30278, 30285, 30290,
+    30293, 30307, 30320, 30328, 30334, 30341, 30347, 30350,
+    30354, 30356, 30361, 30369, 30374, 30381, 30389, 30398,
+    30410, 30424, 30436, 30439, 30444, 30453, 30459, 30466,
+    30474, 30480, 30485, 30490, 30495, 30500, 30505, 30511,
+    30517, 30524, 30527, 30531, 30535, 30540, 30548, 30553,
+    30557, 30562, 30565, 30569, 30573, 30577, 30580, 30584,
+    30591, 30595, 30603, 30611, 30615, 30620, 30626, 30635,
+    30642, 30645, 30648, 30651, 30654, 30657, 30661, 30664,
+    30667, 30671, 30675, 30679, 30683, 30688, 30693, 30698,
+    30702, 30706, 30710, 30714, 30718, 30722, 30726, 30730,
+    30735, 30739, 30743, 30747, 30751, 30755, 30759, 30763,
+    30767, 30771, 30775, 30779, 30783, 30787, 30791, 30795,
+    30800, 30812, 30819, 30827, 30833, 30837, 30844, 30847,
+    30850, 30853, 30857, 30867, 30871, 30877, 30884, 30890,
+    30896, 30901, 30905, 30909, 30915, 30920, 30929, 30933,
+    30939, 30948, 30955, 30960, 30965, 30969, 30974, 30982,
+    30986, 30994, 31000, 31004, 31013, 31022, 31031, 31037,
+    31040, 31045, 31052, 31056, 31059, 31062, 31066, 31069,
+    31073, 31077, 31082, 31087, 31092, 31098, 31103, 31109,
+    31114, 31119, 31124, 31128, 31132, 31136, 31139, 31142,
+    31145, 31150, 31155, 31159, 31163, 31167, 31172, 31183,
+    31190, 31196, 31199, 31208, 31226, 31242, 31257, 31278,
+    31298, 31301, 31306, 31314, 31319, 31325, 31328, 31332,
+    31337, 31343, 31350, 31358, 31364, 31368, 31373, 31379,
+    31383, 31390, 31398, 31402, 31411, 31415, 31420, 31425,
+    31433, 31439, 31447, 31455, 31462, 31468, 31474, 31478,
+    31482, 31491, 31499, 31505, 31510, 31518, 31522, 31526,
+    31530, 31535, 31542, 31555, 31570, 31574, 31582, 31586,
+    31591, 31596, 31603, 31611, 31617, 31623, 31638, 31651,
+    31663, 31681, 31698, 31702, 31711, 31715, 31720, 31724,
+    31734, 31738, 31742, 31746, 31759, 31764, 31768, 31773,
+    31778, 31783, 31787, 31791, 31796, 31800, 31804, 31808,
+    31811, 31815, 31819, 31824, 31828, 31832, 31835, 31839,
+    31843, 31854, 31865, 31881, 31885, 31888, 31894, 31902,
+    31913, 31919, 31922, 31930, 31938, 31941, 31943, 31948,
+    31957, 31964, 31972, 31981, 31985, 31990, 31997, 32003,
+    32009, 32017, 32025, 32033, 32042, 32048, 32057, 32063,
+    32070, 32076, 32081, 32092, 32097, 32102, 32107, 32112,
+    32117, 32122, 32127, 32132, 32137, 32142, 32147, 32153,
+    32158, 32163, 32168, 32173, 32178, 32183, 32188, 32193,
+    32198, 32203, 32208, 32213, 32218, 32223, 32228, 32233,
+    32238, 32243, 32248, 32253, 32258, 32263, 32268, 32273,
+    32278, 32283, 32288, 32293, 32298, 32303, 32308, 32313,
+    32318, 32323, 32328, 32333, 32338, 32343, 32348, 32353,
+    32358, 32363, 32368, 32373, 32378, 32383, 32388, 32393,
+    32398, 32403, 32409, 32414, 32419, 32424, 32429, 32434,
+    32439, 32444, 32449, 32454, 32459, 32464, 32469, 32474,
+    32479, 32484, 32489, 32494, 32499, 32504, 32509, 32514,
+    32519, 32524, 32529, 32534, 32539, 32544, 32549, 32554,
+    32559, 32564, 32569, 32574, 32579, 32584, 32589, 32592,
+    32601, 32609, 32616, 32620, 32625, 32631, 32635, 32639,
+    32648, 32653, 32659, 32663, 32674, 32682, 32687, 32693,
+    32699, 32706, 32713, 32723, 32732, 32742, 32755, 32768,
+    32781, 32794, 32807, 32820, 32833, 32846, 32859, 32872,
+    32885, 32898, 32911, 32924, 32937, 32950, 32963, 32976,
+    32989, 33002, 33015, 33028, 33041, 33054, 33067, 33080,
+    33093, 33106, 33119, 33132, 33145, 33158, 33171, 33184,
+    33197, 33210, 33223, 33236, 33249, 33262, 33275, 33288,
+    33301, 33314, 33327, 33340, 33353, 33366, 33379, 33392,
+    33405, 33418, 33431, 33444, 33457, 33470, 33483, 33496,
+    33509, 33522, 33535, 33548, 33561, 33574, 33587, 33600,
+    33613, 33626, 33639, 33652, 33665, 33678, 33691, 33704,
+    33717, 33730, 33743, 33756, 33769, 33782, 33795, 33808,
+    33821, 33834, 33847, 33860, 33873, 33886, 33899, 33912,
+    33925, 33938, 33951, 33964, 33977, 33990, 34003, 34016,
+    34029, 34042, 34055, 34068, 34081, 34094, 34107, 34120,
+    34133, 34146, 34159, 34172, 34185, 34198, 34211, 34224,
+    34237, 34250, 34263, 34276, 34289, 34302, 34315, 34328,
+    34341, 34354, 34367, 34380, 34393, 34406, 34419, 34432,
+    34445, 34458, 34471, 34484, 34497, 34510, 34523, 34536,
+    34549, 34562, 34575, 34588, 34601, 34614, 34627, 34640,
+    34653, 34666, 34679, 34692, 34705, 34718, 34731, 34744,
+    34757, 34770, 34783, 34796, 34809, 34822, 34835, 34848,
+    34861, 34874, 34887, 34900, 34913, 34926, 34939, 34952,
+    34965, 34978, 34991, 35004, 35017, 35030, 35043, 35056,
+    35069, 35082, 35095, 35108, 35121, 35134, 35147, 35160,
+    35173, 35186, 35199, 35212, 35225, 35238, 35251, 35264,
+    35277, 35290, 35303, 35316, 35329, 35342, 35355, 35368,
+    35381, 35394, 35407, 35420, 35433, 35446, 35459, 35472,
+    35485, 35498, 35511, 35524, 35537, 35550, 35563, 35576,
+    35589, 35602, 35615, 35628, 35641, 35654, 35667, 35680,
+    35693, 35706, 35719, 35732, 35745, 35758, 35771, 35784,
+    35797, 35810, 35823, 35836, 35849, 35862, 35875, 35888,
+    35901, 35914, 35927, 35940, 35953, 35966, 35979, 35992,
+    36005, 36018, 36031, 36044, 36057, 36070, 36083, 36096,
+    36109, 36122, 36135, 36148, 36161, 36174, 36187, 36200,
+    36213, 36226, 36239, 36252, 36265, 36278, 36291, 36304,
+    36317, 36330, 36343, 36356, 36369, 36382, 36395, 36408,
+    36421, 36434, 36447, 36460, 36473, 36486, 36499, 36512,
+    36525, 36538, 36551, 36564, 36577, 36590, 36603, 36616,
+    36629, 36642, 36655, 36668, 36681, 36694, 36707, 36720,
+    36733, 36746, 36759, 36772, 36785, 36798, 36811, 36824,
+    36837, 36850, 36863, 36876, 36889, 36902, 36915, 36928,
+    36941, 36954, 36967, 36980, 36993, 37006, 37019, 37032,
+    37045, 37058, 37071, 37084, 37097, 37110, 37123, 37136,
+    37149, 37162, 37175, 37188, 37201, 37214, 37227, 37240,
+    37253, 37266, 37279, 37292, 37305, 37318, 37331, 37344,
+    37357, 37370, 37383, 37396, 37409, 37422, 37435, 37448,
+    37461, 37474, 37487, 37500, 37513, 37526, 37539, 37552,
+    37565, 37578, 37591, 37604, 37617, 37630, 37643, 37656,
+    37669, 37682, 37695, 37708, 37721, 37734, 37747, 37760,
+    37773, 37786, 37799, 37812, 37825, 37838, 37851, 37864,
+    37877, 37890, 37903, 37916, 37929, 37942, 37955, 37968,
+    37981, 37994, 38007, 38020, 38033, 38046, 38059, 38072,
+    38085, 38098, 38111, 38124, 38137, 38150, 38163, 38176,
+    38189, 38202, 38215, 38228, 38241, 38254, 38267, 38280,
+    38293, 38306, 38319, 38332, 38345, 38358, 38371, 38384,
+    38397, 38410, 38423, 38436, 38449, 38462, 38475, 38488,
+    38501, 38514, 38527, 38540, 38553, 38566, 38579, 38592,
+    38605, 38618, 38631, 38644, 38657, 38670, 38683, 38696,
+    38709, 38722, 38735, 38748, 38761, 38774, 38787, 38800,
+    38813, 38826, 38839, 38852, 38865, 38878, 38891, 38904,
+    38917, 38930, 38943, 38956, 38969, 38982, 38995, 39008,
+    39021, 39034, 39047, 39060, 39073, 39086, 39099, 39112,
+    39125, 39138, 39151, 39164, 39177, 39190, 39203, 39216,
+    39229, 39242, 39255, 39268, 39281, 39294, 39307, 39320,
+    39333, 39346, 39359, 39372, 39385, 39398, 39411, 39424,
+    39437, 39450, 39463, 39476, 39489, 39502, 39515, 39528,
+    39541, 39554, 39567, 39580, 39593, 39606, 39619, 39632,
+    39645, 39658, 39671, 39684, 39697, 39710, 39723, 39736,
+    39749, 39762, 39775, 39788, 39801, 39814, 39827, 39840,
+    39853, 39866, 39879, 39892, 39905, 39918, 39931, 39944,
+    39957, 39970, 39983, 39996, 40009, 40022, 40035, 40048,
+    40061, 40074, 40087, 40100, 40113, 40126, 40139, 40152,
+    40165, 40178, 40191, 40204, 40217, 40230, 40243, 40256,
+    40269, 40282, 40295, 40308, 40321, 40334, 40347, 40360,
+    40373, 40386, 40399, 40412, 40425, 40438, 40451, 40464,
+    40477, 40490, 40503, 40516, 40529, 40542, 40555, 40568,
+    40581, 40594, 40607, 40620, 40633, 40646, 40659, 40672,
+    40685, 40698, 40711, 40724, 40737, 40750, 40763, 40776,
+    40789, 40802, 40815, 40828, 40841, 40854, 40867, 40880,
+    40893, 40906, 40919, 40932, 40945, 40958, 40971, 40984,
+    40997, 41010, 41023, 41036, 41049, 41062, 41075, 41088,
+    41101, 41114, 41127, 41140, 41153, 41166, 41179, 41192,
+    41205, 41218, 41231, 41244, 41257, 41270, 41283, 41296,
+    41309, 41322, 41335, 41348, 41361, 41374, 41387, 41400,
+    41413, 41426, 41439, 41452, 41465, 41478, 41491, 41504,
+    41517, 41530, 41543, 41556, 41569, 41582, 41595, 41608,
+    41621, 41634, 41647, 41660, 41673, 41686, 41699, 41712,
+    41725, 41738, 41751, 41764, 41777, 41790, 41803, 41816,
+    41829, 41842, 41855, 41868, 41881, 41894, 41907, 41920,
+    41933, 41946, 41959, 41972, 41985, 41998, 42011, 42024,
+    42037, 42050, 42063, 42076, 42089, 42102, 42115, 42128,
+    42141, 42154, 42167, 42180, 42193, 42206, 42219, 42232,
+    42245, 42258, 42271, 42284, 42297, 42310, 42323, 42336,
+    42349, 42362, 42375, 42388, 42401, 42414, 42427, 42440,
+    42453, 42466, 42479, 42492, 42505, 42518, 42531, 42544,
+    42557, 42570, 42583, 42596, 42609, 42622, 42635, 42648,
+    42661, 42674, 42687, 42700, 42713, 42726, 42737, 42746,
+    42761, 42769, 42777, 42787, 42795, 42804, 42811, 42820,
+    42831, 42840, 42853, 42863, 42875, 42885, 42895, 42904,
+    42915, 42926, 42937, 42943, 42950, 42954, 42957, 42965,
+    42969, 42977, 42982, 42986, 42993, 43000, 43011, 43017,
+    43024, 43035, 43038, 43043, 43054, 43065, 43071, 43077,
+    43080, 43087, 43093, 43101, 43110, 43119, 43128, 43133,
+    43142, 43150, 43160, 43170, 43180, 43190, 43199, 43205,
+    43213, 43220, 43224, 43232, 43235, 43239, 43246, 43252,
+    43255, 43261, 43268, 43273, 43277, 43284, 43292, 43299,
+    43302, 43309, 43313, 43317, 43324, 43336, 43343, 43346,
+    43354, 43358, 43361, 43365, 43369, 43373, 43377, 43381,
+    43385, 43389, 43393, 43398, 43402, 43406, 43410, 43414,
+    43418, 43422, 43426, 43430, 43434, 43438, 43442, 43446,
+    43450, 43454, 43458, 43462, 43466, 43470, 43474, 43479,
+    43483, 43487, 43491, 43495, 43500, 43504, 43508, 43512,
+    43517, 43521, 43525, 43529, 43533, 43537, 43541, 43545,
+    43549, 43553, 43557, 43561, 43565, 43570, 43574, 43578,
+    43583, 43587, 43591, 43596, 43601, 43606, 43611, 43616,
+    43621, 43626, 43631, 43636, 43640, 43644, 43649, 43653,
+    43657, 43662, 43666, 43670, 43674, 43678, 43682, 43686,
+    43690, 43694, 43698, 43702, 43706, 43710, 43714, 43719,
+    43724, 43729, 43734, 43739, 43744, 43749, 43754, 43756,
+    43762, 43767, 43771, 43776, 43780, 43787, 43792, 43802,
+    43806, 43809, 43818, 43829, 43832, 43837, 43841, 43846,
+    43857, 43860, 43866, 43872, 43879, 43884, 43894, 43899,
+    43904, 43908, 43914, 43918, 43923, 43931, 43935, 43938,
+    43947, 43949, 43953, 43957, 43961, 43965, 43969, 43973,
+    43977, 43982, 43986, 43990, 43994, 43999, 44004, 44008,
+    44012, 44016, 44020, 44024, 44028, 44032, 44036, 44041,
+    44046, 44050, 44055, 44059, 44063, 44067, 44071, 44075,
+    44080, 44087, 44095, 44104, 44116, 44126, 44137, 44141,
+    44147, 44160, 44170, 44174, 44177, 44181, 44189, 44198,
+    44209, 44217, 44224, 44228, 44236, 44240, 44246, 44249,
+    44259, 44264, 44272, 44280, 44285, 44296, 44300, 44306,
+    44310, 44315, 44326, 44330, 44333, 44341, 44345, 44351,
+    44357, 44361, 44368, 44375, 44382, 44388, 44392, 44396,
+    44400, 44405, 44409, 44413, 44418, 44422, 44425, 44429,
+    44433, 44437, 44440, 44454, 44465, 44474, 44482, 44489,
+    44497, 44501, 44507, 44511, 44516, 44526, 44538, 44548,
+    44557, 44563, 44571, 44574, 44583, 44594, 44601, 44613,
+    44625, 44637, 44644, 44648, 44651, 44658, 44664, 44669,
+    44674, 44682, 44695, 44704, 44714, 44722, 44730, 44740,
+    44747, 44758, 44768, 44771, 44776, 44786, 44794, 44801,
+    44807, 44814, 44817, 44821, 44823, 44825, 44828, 44832,
+    44836, 44841, 44844, 44847, 44850, 44853, 44857, 44860,
+    44864, 44869, 44873, 44879, 44885, 44892, 44898, 44903,
+    44909, 44914, 44917, 44923, 44927, 44933, 44940, 44948,
+    44957, 44967, 44978, 44990, 45003, 45015, 45026, 45038,
+    45049, 45059, 45070, 45082, 45093, 45103, 45114, 45124,
+    45133, 45143, 45154, 45166, 45177, 45187, 45198, 45208,
+    45217, 45227, 45238, 45248, 45257, 45267, 45276, 45284,
+    45293, 45303, 45314, 45326, 45337, 45347, 45358, 45368,
+    45377, 45387, 45398, 45408, 45417, 45427, 45436, 45444,
+    45453, 45463, 45474, 45484, 45493, 45503, 45512, 45520,
+    45529, 45539, 45548, 45556, 45565, 45573, 45580, 45588,
+    45597, 45607, 45618, 45630, 45641, 45651, 45662, 45672,
+    45681, 45691, 45702, 45712, 45721, 45731, 45740, 45748,
+    45757, 45767, 45778, 45788, 45797, 45807, 45816, 45824,
+    45833, 45843, 45852, 45860, 45869, 45877, 45884, 45892,
+    45901, 45911, 45922, 45932, 45941, 45951, 45960, 45968,
+    45977, 45987, 45996, 46004, 46013, 46021, 46028, 46036,
+    46045, 46055, 46064, 46072, 46081, 46089, 46096, 46104,
+    46113, 46121, 46128, 46136, 46143, 46149, 46156, 46164,
+    46173, 46183, 46194, 46206, 46217, 46227, 46238, 46248,
+    46257, 46267, 46278, 46288, 46297, 46307, 46316, 46324,
+    46333, 46343, 46354, 46364, 46373, 46383, 46392, 46400,
+    46409, 46419, 46428, 46436, 46445, 46453, 46460, 46468,
+    46477, 46487, 46498, 46508, 46517, 46527, 46536, 46544,
+    46553, 46563, 46572, 46580, 46589, 46597, 46604, 46612,
+    46621, 46631, 46640, 46648, 46657, 46665, 46672, 46680,
+    46689, 46697, 46704, 46712, 46719, 46725, 46732, 46740,
+    46749, 46759, 46770, 46780, 46789, 46799, 46808, 46816,
+    46825, 46835, 46844, 46852, 46861, 46869, 46876, 46884,
+    46893, 46903, 46912, 46920, 46929, 46937, 46944, 46952,
+    46961, 46969, 46976, 46984, 46991, 46997, 47004, 47012,
+    47021, 47031, 47040, 47048, 47057, 47065, 47072, 47080,
+    47089, 47097, 47104, 47112, 47119, 47125, 47132, 47140,
+    47149, 47157, 47164, 47172, 47179, 47185, 47192, 47200,
+    47207, 47213, 47220, 47226, 47234, 47242, 47250, 47262,
+    47267, 47275, 47286, 47289, 47297, 47301, 47310, 47318,
+    47322, 47327, 47337, 47341, 47345, 47349, 47354, 47357,
+    47360, 47368, 47372, 47375, 47379, 47382, 47391, 47394,
+    47398, 47406, 47410, 47418, 47422, 47425, 47431, 47434,
+    47437, 47440, 47448, 47468, 47478, 47484, 47487, 47490,
+    47494, 47498, 47502, 47507, 47512, 47517, 47521, 47525,
+    47528, 47531, 47534, 47537, 47540, 47546, 47550, 47554,
+    47558, 47562, 47566, 47570, 47574, 47578, 47583, 47587,
+    47592, 47596, 47600, 47604, 47608, 47612, 47616, 47620,
+    47625, 47629, 47634, 47638, 47642, 47646, 47651, 47655,
+    47659, 47663, 47667, 47671, 47675, 47679, 47683, 47688,
+    47692, 47696, 47700, 47704, 47708, 47712, 47717, 47721,
+    47725, 47729, 47736, 47743, 47754, 47759, 47763, 47770,
+    47777, 47780, 47784, 47793, 47802, 47805, 47810, 47813,
+    47820, 47824, 47828, 47833, 47845, 47854, 47857, 47862,
+    47866, 47871, 47874, 47877, 47887, 47894, 47904, 47915,
+    47923, 47931, 47944, 47947, 47951, 47954, 47957, 47970,
+    47976, 47986, 47994, 48004, 48011, 48021, 48030, 48035,
+    48044, 48055, 48059, 48063, 48070, 48078, 48088, 48095,
+    48102, 48113, 48118, 48122, 48126, 48129, 48134, 48142,
+    48147, 48149, 48160, 48165, 48168, 48171, 48175, 48179,
+    48183, 48188, 48193, 48198, 48201, 48204, 48213, 48222,
+    48230, 48235, 48242, 48245, 48249, 48253, 48258, 48263,
+    48267, 48271, 48276, 48289, 48302, 48309, 48318, 48323,
+    48332, 48338, 48346, 48356, 48362, 48372, 48377, 48383,
+    48392, 48400, 48414, 48426, 48431, 48447, 48453, 48462,
+    48469, 48479, 48483, 48487, 48492, 48496, 48500, 48504,
+    48508, 48512, 48516, 48520, 48524, 48528, 48532, 48536,
+    48540, 48545, 48549, 48553, 48557, 48561, 48565, 48569,
+    48573, 48577, 48582, 48586, 48590, 48594, 48598, 48602,
+    48606, 48610, 48614, 48618, 48622, 48627, 48631, 48635,
+    48639, 48643, 48647, 48651, 48656, 48660, 48665, 48669,
+    48673, 48677, 48681, 48685, 48689, 48693, 48698, 48702,
+    48707, 48711, 48716, 48720, 48724, 48728, 48732, 48737,
+    48742, 48747, 48751, 48755, 48760, 48764, 48770, 48776,
+    48782, 48788, 48794, 48800, 48806, 48813, 48822, 48829,
+    48839, 48843, 48848, 48855, 48860, 48863, 48870, 48876,
+    48879, 48883, 48886, 48889, 48892, 48896, 48899, 48906,
+    48913, 48917, 48924, 48929, 48937, 48941, 48946, 48950,
+    48960, 48966, 48970, 48976, 48981, 48989, 48993, 49002,
+    49006, 49008, 49011, 49014, 49020, 49025, 49033, 49041,
+    49049, 49052, 49060, 49068, 49079, 49090, 49096, 49099,
+    49110, 49118, 49127, 49131, 49138, 49145, 49151, 49154,
+    49163, 49172, 49183, 49186, 49188, 49191, 49197, 49203,
+    49209, 49215, 49221, 49226, 49234, 49242, 49247, 49255,
+    49267, 49273, 49279, 49289, 49296, 49303, 49310, 49312,
+    49315, 49320, 49326, 49333, 49338, 49341, 49347, 49354,
+    49358, 49368, 49377, 49380, 49385, 49391, 49398, 49408,
+    49413, 49418, 49427, 49436, 49447, 49458, 49469, 49477,
+    49485, 49492, 49498, 49503, 49511, 49520, 49525, 49530,
+    49542, 49545, 49549, 49553, 49557, 49565, 49572, 49578,
+    49581, 49585, 49589, 49592, 49595, 49598, 49600, 49603,
+    49606, 49609, 49612, 49616, 49620, 49624, 49628, 49632,
+    49636, 49641, 49645, 49650, 49655, 49659, 49663, 49667,
+    49671, 49676, 49680, 49684, 49688, 49692, 49696, 49700,
+    49704, 49708, 49712, 49717, 49721, 49725, 49729, 49733,
+    49737, 49741, 49746, 49750, 49754, 49758, 49762, 49766,
+    49770, 49774, 49778, 49782, 49786, 49791, 49795, 49800,
+    49804, 49808, 49812, 49816, 49820, 49824, 49829, 49833,
+    49837, 49842, 49846, 49850, 49854, 49858, 49862, 49866,
+    49870, 49874, 49878, 49880, 49885, 49892, 49897, 49900,
+    49903, 49908, 49911, 49916, 49920, 49925, 49930, 49935,
+    49939, 49945, 49951, 49958, 49963, 49969, 49972, 49980,
+    49983, 49985, 49995, 50000, 50004, 50008, 50013, 50018,
+    50022, 50026, 50030, 50034, 50038, 50042, 50046, 50052,
+    50056, 50059, 50067, 50073, 50076, 50083, 50088, 50096,
+    50102, 50111, 50114, 50120, 50123, 50127, 50130, 50132,
+    50136, 50140, 50144, 50148, 50152, 50156, 50160, 50164,
+    50168, 50173, 50178, 50182, 50186, 50190, 50194, 50199,
+    50204, 50209, 50213, 50217, 50222, 50226, 50230, 50234,
+    50239, 50243, 50248, 50252, 50254, 50261, 50267, 50273,
+    50279, 50283, 50289, 50294, 50298, 50302, 50314, 50323,
+    50331, 50342, 50347, 50352, 50357, 50360, 50366, 50370,
+    50374, 50377, 50381, 50384, 50388, 50393, 50396, 50400,
+    50404, 50408, 50412, 50415, 50419, 50422, 50432, 50438,
+    50443, 50446, 50453, 50457, 50462, 50469, 50474, 50477,
+    50480, 50483, 50486, 50493, 50499, 50504, 50510, 50517,
+    50519, 50528, 50531, 50535, 50541, 50548, 50553, 50556,
+    50562, 50566, 50574, 50577, 50581, 50586, 50589, 50593,
+    50607, 50616, 50623, 50627, 50630, 50633, 50640, 50650,
+    50654, 50659, 50665, 50671, 50679, 50685, 50702, 50714,
+    50723, 50732, 50736, 50745, 50757, 50764, 50767, 50771,
+    50778, 50789, 50798, 50802, 50807, 50813, 50816, 50820,
+    50824, 50828, 50831, 50837, 50846, 50851, 50857, 50861,
+    50864, 50867, 50872, 50874, 50878, 50881, 50883, 50886,
+    50890, 50894, 50897, 50901, 50905, 50908, 50911, 50914,
+    50920, 50924, 50928, 50932, 50936, 50940, 50944, 50949,
+    50953, 50957, 50961, 50966, 50971, 50975, 50979, 50983,
+    50987, 50991, 50995, 50999, 51003, 51008, 51012, 51017,
+    51022, 51027, 51033, 51040, 51044, 51050, 51053, 51060,
+    51064, 51069, 51076, 51082, 51088, 51092, 51097, 51100,
+    51109, 51114, 51119, 51126, 51134, 51141, 51149, 51156,
+    51165, 51169, 51172, 51175, 51180, 51188, 51196, 51204,
+    51208, 51213, 51221, 51226, 51232, 51243, 51247, 51250,
+    51253, 51260, 51271, 51273, 51276, 51280, 51284, 51288,
+    51292, 51296, 51300, 51304, 51311, 51323, 51330, 51339,
+    51348, 51360, 51367, 51375, 51383, 51387, 51393, 51399,
+    51408, 51418, 51423, 51436, 51440, 51444, 51447, 51454,
+    51458, 51464, 51473, 51479, 51482, 51490, 51494, 51497,
+    51501, 51504, 51507, 51510, 51514, 51519, 51523, 51527,
+    51532, 51536, 51541, 51545, 51549, 51553, 51557, 51561,
+    51565, 51569, 51573, 51577, 51581, 51587, 51595, 51599,
+    51604, 51610, 51621, 51632, 51643, 51654, 51664, 51668,
+    51679, 51687, 51693, 51698, 51710, 51718, 51722, 51725,
+    51727, 51729, 51733, 51737, 51741, 51745, 51748, 51752,
+    51756, 51760, 51765, 51770, 51774, 51778, 51782, 51785,
+    51789, 51793, 51796, 51800, 51805, 51810, 51814, 51818,
+    51823, 51827, 51831, 51834, 51838, 51842, 51847, 51851,
+    51855, 51857, 51860, 51864, 51868, 51872, 51875, 51878,
+    51882, 51887, 51892, 51896, 51900, 51904, 51908, 51912,
+    51916, 51919, 51923, 51928, 51933, 51937, 51941, 51946,
+    51950, 51954, 51957, 51961, 51965, 51970, 51974, 51978,
+    51982, 51986, 51990, 51993, 51997, 52001, 52004, 52008,
+    52013, 52018, 52023, 52027, 52031, 52035, 52039, 52043,
+    52047, 52051, 52055, 52060, 52064, 52068, 52072, 52076,
+    52080, 52084, 52088, 52092, 52096, 52099, 52104, 52111,
+    52114, 52118, 52121, 52126, 52134, 52138, 52142, 52147,
+    52151, 52154, 52161, 52177, 52193, 52209, 52225, 52241,
+    52257, 52273, 52289, 52305, 52321, 52337, 52353, 52369,
+    52385, 52401, 52417, 52433, 52449, 52465, 52481, 52497,
+    52513, 52529, 52545, 52561, 52577, 52593, 52609, 52625,
+    52641, 52657, 52673, 52689, 52705, 52721, 52737, 52753,
+    52769, 52785, 52801, 52817, 52833, 52849, 52865, 52881,
+    52897, 52913, 52929, 52945, 52953, 52957, 52960, 52963,
+    52966, 52970, 52977, 52981, 52985, 52989, 52993, 52997,
+    53005, 53008, 53014, 53017, 53021, 53025, 53028, 53032,
+    53036, 53040, 53045, 53049, 53055, 53058, 53062, 53065,
+    53069, 53073, 53076, 53079, 53082, 53085, 53089, 53093,
+    53097, 53100, 53104, 53108, 53111, 53114, 53118, 53123,
+    53128, 53133, 53137, 53141, 53144, 53148, 53152, 53157,
+    53162, 53167, 53171, 53179, 53185, 53192, 53204, 53214,
+    53217, 53220, 53223, 53227, 53230, 53234, 53238, 53243,
+    53246, 53249, 53252, 53255, 53258, 53265, 53271, 53275,
+    53278, 53281, 53286, 53289, 53293, 53299, 53304, 53308,
+    53313, 53317, 53321, 53325, 53329, 53333, 53337, 53341,
+    53346, 53350, 53354, 53358, 53362, 53367, 53371, 53376,
+    53380, 53385, 53389, 53393, 53397, 53401, 53405, 53412,
+    53424, 53431, 53436, 53439, 53453, 53467, 53481, 53495,
+    53509, 53523, 53537, 53551, 53565, 53579, 53593, 53607,
+    53621, 53635, 53649, 53663, 53677, 53691, 53705, 53719,
+    53733, 53747, 53761, 53775, 53789, 53803, 53817, 53831,
+    53845, 53859, 53873, 53887, 53901, 53915, 53929, 53943,
+    53957, 53971, 53985, 53999, 54013, 54027, 54041, 54055,
+    54069, 54083, 54087, 54090, 54093, 54106, 54117, 54128,
+    54141, 54152, 54165, 54178, 54189, 54200, 54210, 54227,
+    54240, 54252, 54255, 54257, 54261, 54266, 54273, 54276,
+    54283, 54290, 54294, 54303, 54307, 54315, 54321, 54325,
+    54335, 54343, 54352, 54362, 54367, 54374, 54377, 54380,
+    54387, 54391, 54396, 54400, 54409, 54417, 54427, 54436,
+    54445, 54451, 54460, 54468, 54478, 54487, 54493, 54499,
+    54505, 54508, 54511, 54515, 54524, 54530, 54536, 54547,
+    54555, 54566, 54579, 54583, 54595, 54601, 54604, 54608,
+    54615, 54619, 54622, 54625, 54628, 54639, 54648, 54656,
+    54662, 54666, 54675, 54682, 54688, 54691, 54695, 54698,
+    54701, 54705, 54709, 54712, 54718, 54720, 54727, 54741,
+    54746, 54749, 54753, 54757, 54772, 54779, 54784, 54792,
+    54797, 54801, 54806, 54815, 54818, 54822, 54827, 54836,
+    54839, 54843, 54847, 54853, 54856, 54860, 54863, 54866,
+    54870, 54874, 54878, 54884, 54887, 54890, 54893, 54896,
+    54900, 54905, 54910, 54915, 54919, 54923, 54927, 54931,
+    54935, 54939, 54943, 54948, 54953, 54957, 54961, 54966,
+    54970, 54974, 54978, 54982, 54985, 54990, 54996, 55000,
+    55003, 55007, 55010, 55016, 55024, 55037, 55042, 55047,
+    55052, 55060, 55064, 55068, 55072, 55075, 55079, 55083,
+    55087, 55090, 55097, 55100, 55104, 55107, 55110, 55113,
+    55117, 55120, 55123, 55127, 55130, 55133, 55137, 55141,
+    55145, 55149, 55153, 55157, 55161, 55165, 55167, 55171,
+    55176, 55181, 55185, 55189, 55193, 55197, 55201, 55205,
+    55209, 55213, 55218, 55222, 55227, 55232, 55237, 55241,
+    55245, 55252, 55256, 55262, 55265, 55269, 55273, 55277,
+    55282, 55286, 55293, 55296, 55300, 55304, 55310, 55318,
+    55325, 55330, 55334, 55337, 55340, 55345, 55352, 55357,
+    55364, 55371, 55379, 55382, 55388, 55397, 55405, 55409,
+    55413, 55418, 55422, 55425, 55429, 55433, 55436, 55438,
+    55442, 55446, 55450, 55454, 55458, 55462, 55466, 55471,
+    55474, 55478, 55483, 55488, 55492, 55498, 55504, 55511,
+    55520, 55525, 55529, 55532, 55537, 55540, 55545, 55551,
+    55558, 55568, 55574, 55584, 55589, 55593, 55599, 55606,
+    55616, 55623, 55626, 55629, 55636, 55640, 55644, 55651,
+    55655, 55659, 55663, 55668, 55672, 55678, 55684, 55688,
+    55694, 55698, 55702, 55705, 55710, 55716, 55725, 55730,
+    55733, 55737, 55741, 55745, 55749, 55753, 55757, 55761,
+    55765, 55769, 55773, 55776, 55780, 55783, 55788, 55792,
+    55796, 55799, 55802, 55807, 55810, 55816, 55828, 55835,
+    55838, 55842, 55852, 55863, 55872, 55877, 55881, 55885,
+    55888, 55891, 55900, 55903, 55917, 55929, 55943, 55955,
+    55967, 55979, 55997, 56010, 56013, 56017, 56020, 56023,
+    56026, 56028, 56031, 56037, 56042, 56061, 56077, 56091,
+    56101, 56109, 56114, 56118, 56122, 56126, 56130, 56135,
+    56138, 56143, 56146, 56152, 56158, 56161, 56166, 56170,
+    56174, 56179, 56183, 56190, 56195, 56199, 56202, 56209,
+    56214, 56220, 56223, 56231, 56234, 56239, 56242, 56246,
+    56250, 56256, 56260, 56265, 56269, 56272, 56281, 56296,
+    56311, 56319, 56325, 56335, 56337, 56341, 56345, 56349,
+    56353, 56357, 56361, 56365, 56368, 56371, 56375, 56379,
+    56382, 56386, 56393, 56396, 56406, 56410, 56413, 56417,
+    56421, 56425, 56428, 56435, 56438, 56445, 56449, 56455,
+    56464, 56468, 56473, 56477, 56480, 56483, 56488, 56490,
+    56495, 56499, 56502, 56505, 56511, 56514, 56518, 56521,
+    56525, 56528, 56531, 56534, 56538, 56543, 56547, 56552,
+    56556, 56560, 56567, 56570, 56573, 56580, 56583, 56586,
+    56592, 56598, 56602, 56606, 56611, 56615, 56619, 56623,
+    56627, 56632, 56636, 56640, 56642, 56644, 56646, 56648,
+    56652, 56657, 56661, 56667, 56670, 56675, 56680, 56693,
+    56702, 56706, 56710, 56718, 56722, 56726, 56730, 56735,
+    56738, 56744, 56754, 56761, 56768, 56775, 56782, 56789,
+    56796, 56803, 56810, 56817, 56824, 56831, 56838, 56845,
+    56852, 56859, 56866, 56873, 56880, 56887, 56894, 56901,
+    56908, 56915, 56922, 56929, 56936, 56943, 56950, 56957,
+    56964, 56971, 56978, 56985, 56992, 56999, 57006, 57013,
+    57020, 57027, 57034, 57040, 57051, 57057, 57061, 57065,
+    57071, 57079, 57086, 57091, 57094, 57098, 57107, 57110,
+    57114, 57119, 57122, 57130, 57134, 57140, 57143, 57146,
+    57151, 57154, 57156, 57159, 57163, 57167, 57174, 57182,
+    57187, 57193, 57197, 57208, 57220, 57229, 57235, 57239,
+    57242, 57247, 57252, 57260, 57268, 57276, 57284, 57292,
+    57300, 57308, 57311, 57318, 57321, 57325, 57331, 57337,
+    57341, 57348, 57355, 57365, 57368, 57372, 57381, 57384,
+    57388, 57392, 57396, 57399, 57403, 57412, 57419, 57424,
+    57431, 57435, 57439, 57443, 57447, 57451, 57457, 57465,
+    57475, 57478, 57481, 57487, 57491, 57496, 57500, 57505,
+    57515, 57522, 57528, 57534, 57540, 57546, 57552, 57558,
+    57564, 57570, 57577, 57581, 57589, 57592, 57595, 57598,
+    57603, 57608, 57611, 57617, 57622, 57625, 57629, 57632,
+    57634, 57636, 57639, 57645, 57652, 57671, 57689, 57697,
+    57706, 57709, 57713, 57717, 57725, 57730, 57736, 57750,
+    57768, 57784, 57799, 57814, 57828, 57839, 57843, 57847,
+    57850, 57858, 57863, 57866, 57874, 57880, 57886, 57897,
+    57902, 57909, 57923, 57926, 57931, 57934, 57939, 57943,
+    57948, 57953, 57959, 57962, 57966, 57970, 57974, 57977,
+    57980, 57984, 57987, 57990, 57993, 57995, 57998, 58005,
+    58010, 58014, 58017, 58021, 58024, 58027, 58030, 58035,
+    58040, 58045, 58050, 58055, 58060, 58065, 58070, 58075,
+    58080, 58085, 58090, 58095, 58100, 58105, 58110, 58115,
+    58120, 58125, 58130, 58135, 58140, 58144, 58148, 58152,
+    58156, 58160, 58164, 58168, 58172, 58176, 58180, 58184,
+    58188, 58192, 58196, 58200, 58204, 58208, 58212, 58216,
+    58220, 58224, 58228, 58232, 58236, 58240, 58244, 58248,
+    58252, 58256, 58260, 58264, 58268, 58272, 58276, 58280,
+    58284, 58288, 58292, 58296, 58300, 58304, 58308, 58312,
+    58316, 58320, 58324, 58328, 58332, 58336, 58340, 58344,
+    58348, 58352, 58356, 58360, 58364, 58368, 58372, 58376,
+    58380, 58384, 58388, 58392, 58396, 58400, 58404, 58408,
+    58412, 58416, 58420, 58424, 58428, 58432, 58436, 58440,
+    58444, 58448, 58452, 58456, 58460, 58464, 58468, 58472,
+    58476, 58480, 58484, 58488, 58492, 58496, 58500, 58504,
+    58508, 58512, 58516, 58520, 58524, 58528, 58532, 58536,
+    58540, 58544, 58548, 58552, 58556, 58560, 58564, 58568,
+    58572, 58576, 58580, 58584, 58588, 58592, 58596, 58600,
+    58604, 58608, 58612, 58616, 58620, 58624, 58628, 58632,
+    58636, 58640, 58644, 58648, 58652, 58656, 58660, 58664,
+    58668, 58672, 58676, 58680, 58684, 58688, 58692, 58696,
+    58700, 58704, 58708, 58712, 58716, 58720, 58724, 58728,
+    58732, 58736, 58740, 58744, 58748, 58752, 58756, 58760,
+    58764, 58768, 58772, 58776, 58780, 58783, 58787, 58794,
+    58806, 58818, 58830, 58834, 58839, 58846, 58852, 58862,
+    58871, 58879, 58887, 58894, 58900, 58904, 58909, 58915,
+    58923, 58928, 58934, 58941, 58949, 58956, 58961, 58970,
+    58978, 58983, 58991, 58998, 59005, 59012, 59020, 59025,
+    59033, 59038, 59043, 59049, 59053, 59060, 59067, 59078,
+    59084, 59087, 59092, 59097, 59104, 59113, 59120, 59136,
+    59141, 59145, 59151, 59159, 59167, 59175, 59182, 59188,
+    59195, 59199, 59208, 59216, 59222, 59229, 59236, 59240,
+    59243, 59247, 59256, 59262, 59269, 59272, 59275, 59281,
+    59289, 59296, 59299, 59302, 59305, 59312, 59319, 59323,
+    59331, 59337, 59343, 59348, 59356, 59361, 59367, 59371,
+    59375, 59380, 59384, 59386, 59390, 59394, 59399, 59402,
+    59409, 59417, 59422, 59429, 59435, 59444, 59451, 59456,
+    59461, 59468, 59475, 59485, 59489, 59495, 59500, 59507,
+    59511, 59515, 59524, 59533, 59537, 59546, 59550, 59555,
+    59560, 59569, 59577, 59583, 59588, 59600, 59604, 59607,
+    59612, 59616, 59622, 59627, 59632, 59637, 59643, 59646,
+    59648, 59651, 59655, 59659, 59663, 59669, 59676, 59680,
+    59685, 59690, 59696, 59700, 59706, 59710, 59717, 59722,
+    59726, 59729, 59733, 59737, 59741, 59746, 59750, 59754,
+    59758, 59761, 59765, 59770, 59775, 59779, 59783, 59788,
+    59792, 59796, 59798, 59801, 59805, 59809, 59813, 59817,
+    59821, 59825, 59829, 59834, 59841, 59851, 59854, 59858,
+    59871, 59882, 59893, 59904, 59920, 59931, 59947, 59962,
+    59974, 59978, 59981, 59985, 59990, 59996, 60003, 60006,
+    60010, 60015, 60019, 60025, 60029, 60034, 60041, 60049,
+    60057, 60065, 60078, 60082, 60085, 60090, 60094, 60103,
+    60108, 60111, 60118, 60120, 60125, 60129, 60133, 60137,
+    60141, 60145, 60149, 60152, 60156, 60161, 60167, 60172,
+    60178, 60185, 60196, 60207, 60218, 60229, 60240, 60251,
+    60262, 60272, 60282, 60292, 60298, 60306, 60315, 60318,
+    60329, 60339, 60343, 60350, 60357, 60365, 60375, 60383,
+    60390, 60397, 60402, 60412, 60419, 60432, 60439, 60445,
+    60453, 60456, 60466, 60475, 60483, 60488, 60497, 60502,
+    60507, 60512, 60515, 60520, 60522, 60524, 60531, 60545,
+    60552, 60554, 60558, 60562, 60566, 60570, 60580, 60584,
+    60587, 60591, 60595, 60600, 60604, 60609, 60618, 60627,
+    60632, 60643, 60646, 60649, 60654, 60660, 60663, 60669,
+    60673, 60677, 60681, 60684, 60690, 60693, 60697, 60703,
+    60707, 60710, 60713, 60722, 60733, 60744, 60747, 60757,
+    60760, 60762, 60765, 60768, 60771, 60773, 60782, 60791,
+    60795, 60799, 60803, 60807, 60811, 60815, 60819, 60823,
+    60827, 60831, 60835, 60839, 60843, 60847, 60851, 60855,
+    60859, 60863, 60868, 60873, 60877, 60881, 60885, 60889,
+    60893, 60897, 60901, 60906, 60910, 60914, 60918, 60922,
+    60926, 60930, 60934, 60938, 60943, 60947, 60952, 60956,
+    60961, 60965, 60969, 60974, 60978, 60982, 60986, 60990,
+    60994, 60998, 61002, 61006, 61010, 61014, 61018, 61022,
+    61026, 61029, 61033, 61044, 61049, 61053, 61058, 61061,
+    61065, 61075, 61079, 61087, 61091, 61094, 61097, 61101,
+    61107, 61116, 61122, 61125, 61131, 61134, 61139, 61142,
+    61146, 61150, 61154, 61157, 61162, 61167, 61171, 61175,
+    61179, 61182, 61186, 61190, 61194, 61197, 61201, 61205,
+    61210, 61214, 61218, 61221, 61225, 61229, 61234, 61238,
+    61242, 61245, 61250, 61252, 61264, 61268, 61272, 61276,
+    61285, 61290, 61295, 61299, 61304, 61309, 61313, 61318,
+    61322, 61326, 61330, 61334, 61340, 61344, 61348, 61352,
+    61356, 61360, 61364, 61368, 61373, 61377, 61381, 61385,
+    61389, 61393, 61397, 61401, 61406, 61417, 61424, 61430,
+    61438, 61441, 61446, 61452, 61455, 61459, 61465, 61472,
+    61481, 61487, 61490, 61494, 61496, 61501, 61505, 61509,
+    61513, 61519, 61523, 61527, 61531, 61535, 61539, 61546,
+    61550, 61558, 61562, 61565, 61570, 61578, 61583, 61590,
+    61596, 61601, 61608, 61614, 61619, 61626, 61636, 61643,
+    61648, 61654, 61660, 61665, 61672, 61679, 61683, 61688,
+    61693, 61697, 61701, 61708, 61713, 61718, 61726, 61734,
+    61741, 61747, 61753, 61760, 61770, 61775, 61780, 61785,
+    61791, 61795, 61799, 61803, 61807, 61810, 61815, 61821,
+    61826, 61830, 61834, 61838, 61843, 61848, 61852, 61856,
+    61858, 61862, 61866, 61870, 61874, 61878, 61882, 61886,
+    61890, 61894, 61898, 61903, 61906, 61909, 61912, 61916,
+    61929, 61940, 61951, 61964, 61968, 61977, 61986, 61995,
+    62004, 62008, 62015, 62023, 62027, 62038, 62043, 62048,
+    62052, 62055, 62061, 62066, 62069, 62074, 62077, 62081,
+    62085, 62089, 62093, 62099, 62106, 62114, 62119, 62123,
+    62128, 62133, 62138, 62143, 62147, 62151, 62155, 62159,
+    62163, 62167, 62171, 62176, 62180, 62185, 62189, 62194,
+    62198, 62201, 62205, 62209, 62214, 62218, 62222, 62225,
+    62229, 62235, 62239, 62244, 62249, 62254, 62259, 62264,
+    62270, 62275, 62280, 62285, 62290, 62295, 62300, 62305,
+    62310, 62315, 62320, 62325, 62330, 62336, 62341, 62346,
+    62351, 62355, 62357, 62361, 62364, 62367, 62371, 62376,
+    62380, 62385, 62389, 62394, 62398, 62401, 62405, 62409,
+    62413, 62417, 62421, 62425, 62428, 62434, 62441, 62448,
+    62451, 62463, 62473, 62484, 62494, 62499, 62502, 62508,
+    62526, 62535, 62540, 62548, 62554, 62558, 62561, 62564,
+    62567, 62571, 62574, 62577, 62581, 62585, 62589, 62593,
+    62596, 62600, 62604, 62608, 62612, 62615, 62619, 62623,
+    62626, 62630, 62634, 62638, 62643, 62647, 62651, 62654,
+    62658, 62662, 62667, 62671, 62675, 62677, 62680, 62684,
+    62691, 62696, 62701, 62706, 62712, 62718, 62724, 62730,
+    62734, 62738, 62742, 62747, 62750, 62754, 62762, 62767,
+    62772, 62779, 62783, 62788, 62791, 62795, 62799, 62803,
+    62808, 62812, 62816, 62820, 62825, 62830, 62835, 62840,
+    62845, 62850, 62855, 62860, 62865, 62870, 62876, 62881,
+    62887, 62892, 62897, 62902, 62907, 62912, 62917, 62922,
+    62928, 62933, 62938, 62943, 62948, 62954, 62957, 62960,
+    62967, 62973, 62980, 62983, 62987, 62991, 62994, 62997,
+    63001, 63006, 63010, 63013, 63015, 63018, 63022, 63029,
+    63033, 63037, 63041, 63045, 63048, 63052, 63056, 63061,
+    63066, 63071, 63075, 63082, 63086, 63090, 63094, 63098,
+    63102, 63106, 63110, 63114, 63118, 63122, 63126, 63131,
+    63136, 63140, 63144, 63148, 63152, 63156, 63160, 63164,
+    63168, 63171, 63176, 63180, 63183, 63187, 63192, 63197,
+    63201, 63205, 63209, 63213, 63217, 63220, 63224, 63228,
+    63233, 63237, 63241, 63246, 63250, 63253, 63257, 63261,
+    63266, 63270, 63274, 63277, 63280, 63283, 63286, 63290,
+    63293, 63298, 63301, 63305, 63310, 63314, 63319, 63323,
+    63328, 63332, 63336, 63340, 63344, 63349, 63353, 63358,
+    63363, 63368, 63373, 63378, 63383, 63387, 63391, 63395,
+    63399, 63404, 63409, 63414, 63418, 63422, 63426, 63430,
+    63434, 63438, 63442, 63446, 63450, 63455, 63459, 63464,
+    63468, 63472, 63476, 63480, 63485, 63489, 63494, 63498,
+    63502, 63506, 63510, 63515, 63519, 63524, 63528, 63532,
+    63536, 63541, 63545, 63549, 63553, 63558, 63563, 63568,
+    63573, 63577, 63581, 63585, 63589, 63593, 63597, 63601,
+    63605, 63609, 63613, 63617, 63621, 63625, 63629, 63634,
+    63639, 63643, 63652, 63655, 63657, 63663, 63669, 63675,
+    63683, 63689, 63696, 63700, 63708, 63719, 63721, 63730,
+    63741, 63747, 63756, 63766, 63778, 63792, 63806, 63820,
+    63833, 63845, 63858, 63872, 63884, 63897, 63909, 63920,
+    63932, 63945, 63959, 63972, 63984, 63997, 64009, 64020,
+    64032, 64045, 64057, 64068, 64080, 64091, 64101, 64112,
+    64124, 64137, 64151, 64164, 64176, 64189, 64201, 64212,
+    64224, 64237, 64249, 64260, 64272, 64283, 64293, 64304,
+    64316, 64329, 64341, 64352, 64364, 64375, 64385, 64396,
+    64408, 64419, 64429, 64440, 64450, 64460, 64471, 64483,
+    64496, 64510, 64523, 64535, 64548, 64560, 64571, 64583,
+    64596, 64608, 64619, 64631, 64642, 64652, 64663, 64675,
+    64687, 64699, 64710, 64720, 64731, 64743, 64753, 64764,
+    64774, 64783, 64793, 64804, 64816, 64829, 64841, 64852,
+    64864, 64875, 64885, 64896, 64908, 64919, 64929, 64940,
+    64950, 64959, 64969, 64980, 64992, 65003, 65013, 65024,
+    65034, 65043, 65053, 65064, 65074, 65083, 65093, 65102,
+    65111, 65121, 65132, 65144, 65157, 65171, 65184, 65196,
+    65209, 65221, 65232, 65244, 65257, 65269, 65280, 65292,
+    65303, 65313, 65324, 65336, 65349, 65361, 65372, 65384,
+    65395, 65405, 65416, 65428, 65439, 65449, 65460, 65470,
+    65480, 65491, 65503, 65515, 65527, 65538, 65548, 65559,
+    65571, 65581, 65592, 65602, 65611, 65621, 65632, 65644,
+    65655, 65665, 65676, 65686, 65695, 65705, 65716, 65726,
+    65735, 65745, 65754, 65762, 65771, 65781, 65792, 65804,
+    65816, 65827, 65839, 65850, 65860, 65871, 65883, 65894,
+    65904, 65915, 65925, 65935, 65946, 65958, 65969, 65979,
+    65990, 66000, 66009, 66019, 66030, 66040, 66049, 66059,
+    66068, 66076, 66085, 66095, 66106, 66118, 66129, 66139,
+    66150, 66160, 66170, 66181, 66191, 66200, 66210, 66219,
+    66227, 66236, 66246, 66256, 66266, 66275, 66283, 66292,
+    66302, 66309, 66316, 66320, 66323, 66326, 66329, 66336,
+    66338, 66342, 66347, 66351, 66354, 66360, 66366, 66370,
+    66378, 66387, 66397, 66404, 66411, 66416, 66421, 66425,
+    66434, 66437, 66441, 66444, 66451, 66455, 66468, 66474,
+    66483, 66489, 66499, 66505, 66514, 66520, 66525, 66530,
+    66541, 66547, 66556, 66559, 66567, 66572, 66579, 66584,
+    66590, 66598, 66601, 66606, 66611, 66617, 66623, 66630,
+    66636, 66643, 66653, 66656, 66662, 66668, 66674, 66676,
+    66680, 66685, 66689, 66693, 66698, 66702, 66706, 66710,
+    66714, 66718, 66722, 66726, 66730, 66732, 66736, 66742,
+    66748, 66756, 66761, 66768, 66775, 66781, 66786, 66791,
+    66797, 66800, 66810, 66818, 66827, 66841, 66852, 66859,
+    66864, 66870, 66877, 66882, 66888, 66895, 66903, 66910,
+    66919, 66926, 66935, 66945, 66950, 66958, 66963, 66973,
+    66977, 66985, 66992, 66999, 67008, 67016, 67027, 67036,
+    67044, 67054, 67075, 67088, 67096, 67105, 67113, 67120,
+    67127, 67136, 67146, 67153, 67162, 67173, 67181, 67189,
+    67193, 67199, 67217, 67222, 67227, 67233, 67239, 67245,
+    67254, 67264, 67288, 67310, 67318, 67323, 67326, 67331,
+    67339, 67349, 67353, 67358, 67366, 67369, 67372, 67377,
+    67379, 67381, 67384, 67389, 67396, 67403, 67407, 67418,
+    67422, 67429, 67433, 67437, 67443, 67448, 67453, 67464,
+    67471, 67478, 67484, 67489, 67496, 67501, 67511, 67517,
+    67527, 67536, 67547, 67558, 67564, 67567, 67573, 67576,
+    67583, 67590, 67605, 67610, 67614, 67620, 67622, 67624,
+    67626, 67634, 67638, 67642, 67646, 67650, 67663, 67675,
+    67690, 67702, 67709, 67715, 67719, 67725, 67731, 67734,
+    67739, 67746, 67750, 67755, 67762, 67771, 67777, 67783,
+    67788, 67793, 67797, 67801, 67814, 67825, 67836, 67847,
+    67866, 67882, 67898, 67916, 67931, 67935, 67939, 67945,
+    67949, 67953, 67963, 67969, 67976, 67984, 67988, 67997,
+    68001, 68007, 68011, 68017, 68024, 68030, 68036, 68039,
+    68044, 68053, 68060, 68064, 68070, 68075, 68080, 68090,
+    68098, 68106, 68111, 68115, 68119, 68123, 68127, 68133,
+    68140, 68146, 68156, 68158, 68161, 68164, 68170, 68173,
+    68180, 68191, 68200, 68206, 68212, 68218, 68226, 68236,
+    68243, 68247, 68260, 68265, 68271, 68275, 68282, 68288,
+    68295, 68303, 68311, 68319, 68328, 68335, 68347, 68354,
+    68360, 68365, 68372, 68379, 68386, 68392, 68400, 68403,
+    68406, 68409, 68412, 68415, 68417, 68423, 68431, 68442,
+    68449, 68456, 68468, 68475, 68482, 68489, 68497, 68503,
+    68511, 68517, 68524, 68531, 68539, 68547, 68557, 68567,
+    68577, 68586, 68591, 68599, 68611, 68621, 68631, 68636,
+    68638, 68641, 68656, 68675, 68690, 68696, 68699, 68702,
+    68706, 68709, 68715, 68719, 68725, 68728, 68732, 68739,
+    68743, 68750, 68753, 68757, 68761, 68764, 68767, 68773,
+    68779, 68786, 68791, 68795, 68802, 68814, 68818, 68821,
+    68827, 68829, 68832, 68834, 68838, 68840, 68843, 68846,
+    68850, 68853, 68855, 68859, 68863, 68867, 68871, 68875,
+    68879, 68883, 68888, 68892, 68897, 68905, 68909, 68913,
+    68916, 68922, 68926, 68929, 68932, 68935, 68941, 68944,
+    68947, 68951, 68955, 68958, 68962, 68965, 68968, 68973,
+    68976, 68980, 68985, 68989, 68994, 68998, 69001, 69005,
+    69009, 69013, 69016, 69019, 69022, 69025, 69027, 69030,
+    69033, 69037, 69040, 69042, 69051, 69061, 69072, 69084,
+    69097, 69109, 69120, 69132, 69143, 69153, 69164, 69176,
+    69187, 69197, 69208, 69218, 69226, 69232, 69242, 69248,
+    69255, 69265, 69268, 69273, 69282, 69290, 69302, 69310,
+    69313, 69317, 69321, 69325, 69328, 69331, 69335, 69344,
+    69347, 69351, 69354, 69357, 69361, 69363, 69366, 69370,
+    69373, 69377, 69380, 69383, 69386, 69389, 69393, 69396,
+    69399, 69402, 69406, 69410, 69415, 69419, 69424, 69429,
+    69433, 69437, 69441, 69445, 69449, 69453, 69457, 69462,
+    69466, 69470, 69474, 69478, 69482, 69486, 69491, 69495,
+    69499, 69503, 69507, 69511, 69515, 69519, 69523, 69527,
+    69531, 69535, 69539, 69543, 69547, 69551, 69555, 69562,
+    69565, 69568, 69572, 69575, 69582, 69593, 69597, 69600,
+    69608, 69617, 69628, 69633, 69638, 69642, 69649, 69656,
+    69662, 69665, 69672, 69675, 69680, 69686, 69693, 69697,
+    69702, 69705, 69710, 69713, 69720, 69725, 69732, 69736,
+    69740, 69744, 69748, 69755, 69762, 69771, 69778, 69788,
+    69797, 69807, 69815, 69824, 69836, 69844, 69853, 69863,
+    69868, 69875, 69885, 69894, 69903, 69912, 69915, 69919,
+    69924, 69929, 69939, 69946, 69954, 69960, 69968, 69972,
+    69980, 69983, 69987, 69996, 70003, 70012, 70028, 70037,
+    70047, 70057, 70065, 70074, 70080, 70087, 70101, 70106,
+    70111, 70121, 70124, 70126, 70136, 70142, 70146, 70150,
+    70154, 70158, 70162, 70166, 70170, 70174, 70179, 70187,
+    70191, 70202, 70220, 70237, 70255, 70271, 70288, 70307,
+    70325, 70341, 70356, 70373, 70391, 70414, 70428, 70440,
+    70453, 70463, 70472, 70480, 70483, 70489, 70492, 70503,
+    70509, 70516, 70519, 70523, 70530, 70536, 70541, 70545,
+    70548, 70552, 70556, 70560, 70564, 70568, 70572, 70575,
+    70578, 70582, 70589, 70594, 70599, 70603, 70612, 70615,
+    70619, 70623, 70630, 70633, 70637, 70641, 70644, 70656,
+    70663, 70666, 70670, 70674, 70678, 70682, 70686, 70690,
+    70694, 70698, 70701, 70705, 70710, 70714, 70718, 70723,
+    70727, 70731, 70734, 70738, 70742, 70747, 70751, 70755,
+    70758, 70764, 70768, 70772, 70776, 70780, 70784, 70788,
+    70791, 70796, 70799, 70804, 70808, 70816, 70821, 70825,
+    70837, 70840, 70846, 70849, 70853, 70857, 70860, 70865,
+    70868, 70872, 70876, 70879, 70886, 70889, 70894, 70897,
+    70901, 70904, 70908, 70911, 70915, 70918, 70921, 70924,
+    70928, 70931, 70934, 70937, 70940, 70944, 70948, 70953,
+    70957, 70961, 70965, 70969, 70974, 70978, 70982, 70986,
+    70990, 70994, 70998, 71002, 71006, 71011, 71016, 71020,
+    71024, 71028, 71033, 71037, 71041, 71045, 71049, 71053,
+    71057, 71061, 71065, 71069, 71074, 71079, 71083, 71087,
+    71091, 71095, 71099, 71103, 71107, 71111, 71115, 71120,
+    71124, 71128, 71132, 71136, 71140, 71144, 71148, 71152,
+    71156, 71160, 71164, 71168, 71172, 71176, 71180, 71184,
+    71188, 71192, 71196, 71202, 71206, 71210, 71221, 71225,
+    71236, 71243, 71247, 71255, 71261, 71265, 71272, 71277,
+    71282, 71287, 71299, 71303, 71312, 71317, 71322, 71333,
+    71343, 71349, 71357, 71365, 71370, 71377, 71383, 71389,
+    71394, 71398, 71406, 71412, 71418, 71426, 71434, 71438,
+    71441, 71445, 71452, 71459, 71469, 71475, 71480, 71488,
+    71497, 71502, 71505, 71513, 71522, 71527, 71531, 71537,
+    71542, 71549, 71556, 71565, 71570, 71578, 71586, 71595,
+    71606, 71612, 71619, 71623, 71625, 71630, 71634, 71638,
+    71642, 71646, 71650, 71657, 71661, 71667, 71673, 71678,
+    71689, 71697, 71702, 71706, 71716, 71727, 71739, 71751,
+    71763, 71775, 71787, 71799, 71811, 71823, 71835, 71847,
+    71858, 71870, 71882, 71894, 71906, 71918, 71930, 71942,
+    71954, 71966, 71978, 71989, 72001, 72013, 72025, 72037,
+    72049, 72061, 72073, 72085, 72097, 72109, 72120, 72132,
+    72144, 72156, 72168, 72180, 72192, 72204, 72216, 72228,
+    72240, 72251, 72263, 72275, 72287, 72299, 72311, 72323,
+    72335, 72347, 72359, 72371, 72382, 72394, 72406, 72418,
+    72430, 72442, 72454, 72466, 72478, 72490, 72502, 72513,
+    72525, 72537, 72549, 72561, 72573, 72585, 72597, 72609,
+    72621, 72633, 72644, 72656, 72668, 72680, 72692, 72704,
+    72716, 72728, 72740, 72752, 72764, 72775, 72787, 72799,
+    72811, 72823, 72835, 72847, 72859, 72871, 72883, 72895,
+    72906, 72918, 72930, 72942, 72954, 72966, 72978, 72990,
+    73002, 73014, 73026, 73036, 73047, 73059, 73071, 73083,
+    73095, 73107, 73119, 73131, 73143, 73155, 73167, 73178,
+    73190, 73202, 73214, 73226, 73238, 73250, 73262, 73274,
+    73286, 73298, 73309, 73321, 73333, 73345, 73357, 73369,
+    73381, 73393, 73405, 73417, 73429, 73440, 73452, 73464,
+    73476, 73488, 73500, 73512, 73524, 73536, 73548, 73560,
+    73571, 73583, 73595, 73607, 73619, 73631, 73643, 73655,
+    73667, 73679, 73691, 73702, 73714, 73726, 73738, 73750,
+    73762, 73774, 73786, 73797, 73808, 73819, 73830, 73840,
+    73851, 73862, 73873, 73884, 73895, 73906, 73917, 73928,
+    73939, 73950, 73960, 73971, 73982, 73993, 74004, 74015,
+    74026, 74037, 74048, 74059, 74070, 74080, 74091, 74102,
+    74113, 74124, 74135, 74146, 74157, 74168, 74179, 74190,
+    74200, 74211, 74222, 74233, 74244, 74255, 74266, 74277,
+    74288, 74299, 74310, 74320, 74331, 74342, 74353, 74364,
+    74375, 74386, 74397, 74408, 74419, 74430, 74440, 74451,
+    74462, 74473, 74484, 74495, 74506, 74517, 74528, 74539,
+    74550, 74560, 74571, 74582, 74593, 74604, 74615, 74626,
+    74637, 74648, 74659, 74670, 74676, 74682, 74693, 74697,
+    74705, 74713, 74718, 74723, 74726, 74735, 74743, 74750,
+    74757, 74771, 74781, 74787, 74793, 74800, 74812, 74819,
+    74828, 74834, 74837, 74844, 74858, 74869, 74880, 74887,
+    74891, 74893, 74897, 74901, 74904, 74909, 74913, 74919,
+    74925, 74932, 74942, 74950, 74954, 74959, 74964, 74969,
+    74975, 74984, 74988, 74992, 74999, 75007, 75013, 75018,
+    75022, 75026, 75032, 75038, 75046, 75052, 75059, 75068,
+    75074, 75079, 75083, 75088, 75094, 75100, 75106, 75112,
+    75117, 75127, 75131, 75138, 75143, 75148, 75153, 75161,
+    75174, 75192, 75209, 75224, 75239, 75253, 75267, 75281,
+    75294, 75303, 75312, 75316, 75320, 75330, 75336, 75342,
+    75346, 75350, 75355, 75361, 75365, 75370, 75375, 75383,
+    75389, 75399, 75404, 75411, 75415, 75419, 75423, 75428,
+    75433, 75437, 75442, 75446, 75457, 75461, 75464, 75469,
+    75473, 75477, 75483, 75488, 75492, 75496, 75501, 75505,
+    75509, 75513, 75517, 75521, 75525, 75529, 75533, 75536,
+    75542, 75550, 75556, 75559, 75563, 75567, 75571, 75576,
+    75583, 75589, 75593, 75600, 75607, 75618, 75626, 75636,
+    75654, 75666, 75678, 75690, 75707, 75721, 75724, 75731,
+    75737, 75741, 75744, 75748, 75756, 75766, 75776, 75786,
+    75797, 75808, 75821, 75823, 75827, 75837, 75842, 75846,
+    75851, 75856, 75859, 75863, 75868, 75876, 75880, 75885,
+    75891, 75897, 75902, 75908, 75915, 75920, 75925, 75929,
+    75934, 75940, 75945, 75953, 75956, 75961, 75965, 75973,
+    75984, 75988, 75992, 75996, 76000, 76004, 76008, 76012,
+    76015, 76021, 76026, 76034, 76042, 76059, 76062, 76066,
+    76072, 76076, 76079, 76082, 76086, 76089, 76095, 76098,
+    76104, 76107, 76110, 76112, 76115, 76124, 76132, 76140,
+    76149, 76155, 76158, 76171, 76179, 76188, 76193, 76201,
+    76204, 76209, 76214, 76221, 76230, 76238, 76247, 76250,
+    76256, 76261, 76267, 76271, 76279, 76291, 76297, 76304,
+    76310, 76316, 76320, 76323, 76331, 76337, 76339, 76343,
+    76353, 76369, 76379, 76397, 76413, 76428, 76444, 76456,
+    76473, 76489, 76493, 76497, 76501, 76505, 76509, 76513,
+    76517, 76522, 76526, 76531, 76536, 76540, 76544, 76548,
+    76552, 76556, 76560, 76564, 76568, 76572, 76575, 76579,
+    76583, 76588, 76592, 76596, 76599, 76602, 76615, 76623,
+    76630, 76637, 76645, 76649, 76654, 76664, 76669, 76676,
+    76681, 76689, 76696, 76702, 76709, 76716, 76724, 76730,
+    76737, 76746, 76752, 76759, 76767, 76776, 76782, 76789,
+    76795, 76802, 76814, 76820, 76826, 76832, 76843, 76848,
+    76854, 76861, 76869, 76877, 76886, 76891, 76903, 76909,
+    76921, 76929, 76937, 76946, 76951, 76961, 76969, 76977,
+    76984, 76992, 76998, 77011, 77017, 77025, 77034, 77043,
+    77051, 77059, 77067, 77075, 77083, 77091, 77099, 77107,
+    77113, 77120, 77125, 77129, 77133, 77137, 77141, 77145,
+    77149, 77153, 77157, 77161, 77165, 77169, 77174, 77179,
+    77183, 77193, 77199, 77206, 77217, 77228, 77239, 77249,
+    77260, 77264, 77271, 77274, 77278, 77286, 77292, 77298,
+    77307, 77313, 77316, 77320, 77324, 77327, 77337, 77346,
+    77358, 77370, 77374, 77378, 77384, 77388, 77393, 77398,
+    77401, 77406, 77411, 77414, 77420, 77426, 77430, 77433,
+    77440, 77448, 77456, 77461, 77467, 77472, 77475, 77477,
+    77480, 77483, 77492, 77501, 77510, 77519, 77528, 77537,
+    77546, 77555, 77564, 77573, 77581, 77590, 77599, 77608,
+    77617, 77626, 77635, 77644, 77653, 77662, 77671, 77680,
+    77689, 77698, 77706, 77714, 77722, 77728, 77737, 77748,
+    77757, 77764, 77770, 77773, 77776, 77783, 77788, 77797,
+    77801, 77804, 77807, 77809, 77812, 77816, 77819, 77823,
+    77826, 77829, 77832, 77835, 77839, 77843, 77846, 77851,
+    77858, 77862, 77866, 77870, 77875, 77879, 77883, 77887,
+    77891, 77896, 77900, 77905, 77909, 77914, 77918, 77922,
+    77927, 77931, 77935, 77939, 77943, 77947, 77952, 77956,
+    77960, 77964, 77968, 77972, 77976, 77980, 77984, 77988,
+    77992, 77996, 78000, 78004, 78008, 78012, 78016, 78021,
+    78025, 78030, 78034, 78038, 78042, 78046, 78050, 78054,
+    78057, 78064, 78068, 78072, 78076, 78080, 78087, 78096,
+    78100, 78104, 78108, 78114, 78122, 78129, 78132, 78136,
+    78143, 78152, 78158, 78164, 78169, 78173, 78181, 78185,
+    78194, 78197, 78200, 78205, 78208, 78214, 78222, 78228,
+    78231, 78238, 78243, 78248, 78256, 78260, 78266, 78274,
+    78278, 78288, 78291, 78294, 78298, 78302, 78306, 78310,
+    78314, 78318, 78322, 78326, 78330, 78333, 78339, 78345,
+    78349, 78357, 78372, 78388, 78393, 78398, 78403, 78408,
+    78413, 78416, 78425, 78431, 78435, 78441, 78451, 78456,
+    78461, 78467, 78470, 78480, 78488, 78492, 78503, 78511,
+    78514, 78518, 78522, 78531, 78538, 78543, 78546, 78552,
+    78558, 78562, 78569, 78575, 78586, 78591, 78595, 78599,
+    78604, 78613, 78626, 78630, 78635, 78639, 78644, 78649,
+    78654, 78659, 78667, 78678, 78688, 78693, 78699, 78703,
+    78707, 78715, 78719, 78723, 78735, 78744, 78752, 78779,
+    78791, 78801, 78813, 78825, 78833, 78845, 78850, 78858,
+    78863, 78867, 78872, 78876, 78881, 78885, 78890, 78896,
+    78899, 78903, 78907, 78911, 78915, 78919, 78923, 78927,
+    78930, 78935, 78941, 78948, 78952, 78956, 78959, 78973,
+    78985, 79003, 79017, 79022, 79026, 79031, 79034, 79041,
+    79049, 79053, 79058, 79064, 79069, 79074, 79079, 79085,
+    79092, 79097, 79101, 79104, 79108, 79113, 79118, 79122,
+    79127, 79132, 79134, 79138, 79142, 79146, 79150, 79154,
+    79158, 79163, 79166, 79177, 79183, 79189, 79194, 79200,
+    79206, 79211, 79214, 79220, 79226, 79232, 79238, 79244,
+    79250, 79256, 79260, 79265, 79272, 79276, 79286, 79291,
+    79298, 79303, 79319, 79322, 79329, 79337, 79340, 79345,
+    79348, 79353, 79355, 79364, 79371, 79376, 79384, 79391,
+    79401, 79412, 79416, 79425, 79433, 79442, 79451, 79460,
+    79463, 79467, 79476, 79486, 79495, 79504, 79511, 79516,
+    79525, 79530, 79536, 79542, 79548, 79555, 79560, 79567,
+    79577, 79591, 79609, 79625, 79639, 79645, 79652, 79660,
+    79668, 79676, 79684, 79692, 79700, 79708, 79716, 79724,
+    79732, 79739, 79747, 79755, 79762, 79769, 79776, 79783,
+    79790, 79797, 79804, 79811, 79820, 79825, 79832, 79846,
+    79861, 79876, 79884, 79888, 79895, 79899, 79904, 79909,
+    79914, 79920, 79926, 79932, 79936, 79940, 79944, 79948,
+    79952, 79955, 79959, 79962, 79967, 79972, 79976, 79981,
+    79985, 79989, 79994, 79998, 80002, 80006, 80011, 80014,
+    80021, 80025, 80029, 80033, 80037, 80042, 80046, 80051,
+    80054, 80058, 80064, 80073, 80078, 80083, 80090, 80097,
+    80102, 80105, 80109, 80113, 80118, 80124, 80131, 80136,
+    80140, 80144, 80147, 80156, 80161, 80166, 80169, 80175,
+    80183, 80196, 80208, 80219, 80231, 80241, 80248, 80256,
+    80262, 80270, 80280, 80283, 80289, 80292, 80296, 80299,
+    80302, 80305, 80311, 80319, 80325, 80331, 80334, 80337,
+    80341, 80344, 80348, 80351, 80355, 80358, 80362, 80365,
+    80368, 80371, 80374, 80377, 80380, 80383, 80387, 80394,
+    80399, 80402, 80406, 80411, 80415, 80419, 80423, 80427,
+    80431, 80435, 80440, 80445, 80449, 80453, 80457, 80461,
+    80465, 80469, 80473, 80477, 80481, 80485, 80489, 80493,
+    80497, 80501, 80505, 80509, 80513, 80518, 80522, 80526,
+    80530, 80534, 80538, 80542, 80547, 80551, 80555, 80559,
+    80564, 80568, 80572, 80576, 80580, 80584, 80588, 80592,
+    80596, 80600, 80604, 80607, 80611, 80615, 80623, 80630,
+    80635, 80641, 80644, 80648, 80651, 80654, 80657, 80660,
+    80663, 80666, 80669, 80672, 80675, 80683, 80686, 80690,
+    80694, 80698, 80702, 80705, 80708, 80710, 80715, 80718,
+    80726, 80730, 80741, 80750, 80761, 80766, 80774, 80778,
+    80784, 80787, 80794, 80801, 80807, 80812, 80819, 80828,
+    80831, 80834, 80837, 80840, 80843, 80850, 80861, 80863,
+    80866, 80869, 80872, 80876, 80881, 80884, 80888, 80892,
+    80895, 80899, 80906, 80912, 80916, 80920, 80925, 80930,
+    80935, 80938, 80942, 80946, 80950, 80957, 80960, 80962,
+    80968, 80970, 80977, 80980, 80984, 80989, 80994, 80999,
+    81004, 81009, 81014, 81019, 81024, 81029, 81033, 81038,
+    81042, 81046, 81050, 81054, 81058, 81063, 81068, 81072,
+    81076, 81080, 81084, 81089, 81094, 81099, 81104, 81108,
+    81113, 81118, 81122, 81126, 81130, 81134, 81138, 81142,
+    81146, 81150, 81155, 81160, 81165, 81170, 81175, 81180,
+    81185, 81190, 81195, 81200, 81205, 81210, 81214, 81218,
+    81222, 81227, 81231, 81235, 81239, 81243, 81247, 81252,
+    81256, 81261, 81265, 81270, 81274, 81279, 81283, 81287,
+    81292, 81296, 81300, 81304, 81308, 81313, 81317, 81321,
+    81325, 81330, 81335, 81338, 81348, 81354, 81361, 81364,
+    81371, 81379, 81384, 81391, 81397, 81402, 81407, 81415,
+    81418, 81423, 81426, 81429, 81436, 81438, 81444, 81453,
+    81460, 81464, 81468, 81471, 81480, 81485, 81493, 81507,
+    81521, 81535, 81549, 81563, 81577, 81591, 81605, 81619,
+    81633, 81647, 81661, 81675, 81689, 81703, 81717, 81731,
+    81745, 81759, 81773, 81787, 81801, 81815, 81829, 81843,
+    81857, 81871, 81885, 81899, 81913, 81927, 81941, 81955,
+    81969, 81983, 81997, 82011, 82025, 82039, 82053, 82067,
+    82081, 82095, 82109, 82123, 82137, 82151, 82165, 82179,
+    82183, 82188, 82193, 82201, 82205, 82209, 82212, 82216,
+    82219, 82222, 82231, 82244, 82248, 82254, 82257, 82261,
+    82269, 82275, 82282, 82286, 82295, 82302, 82311, 82320,
+    82326, 82329, 82334, 82339, 82345, 82354, 82357, 82362,
+    82369, 82376, 82385, 82388, 82400, 82403, 82412, 82416,
+    82422, 82429, 82439, 82446, 82452, 82455, 82463, 82467,
+    82470, 82473, 82476, 82489, 82492, 82494, 82496, 82500,
+    82508, 82511, 82515, 82518, 82521, 82523, 82526, 82529,
+    82531, 82534, 82537, 82541, 82544, 82547, 82549, 82554,
+    82558, 82562, 82566, 82571, 82575, 82579, 82583, 82587,
+    82591, 82595, 82600, 82604, 82609, 82613, 82617, 82621,
+    82625, 82630, 82634, 82638, 82642, 82647, 82651, 82656,
+    82660, 82664, 82668, 82672, 82676, 82680, 82685, 82689,
+    82693, 82697, 82701, 82705, 82709, 82717, 82726, 82736,
+    82745, 82751, 82756, 82761, 82765, 82771, 82774, 82779,
+    82788, 82791, 82795, 82803, 82811, 82816, 82823, 82833,
+    82842, 82853, 82860, 82863, 82873, 82878, 82880, 82884,
+    82888, 82892, 82896, 82902, 82905, 82912, 82917, 82921,
+    82925, 82928, 82931, 82934, 82938, 82941, 82943, 82945,
+    82952, 82967, 82971, 82975, 82979, 82983, 82987, 82992,
+    83001, 83009, 83016, 83023, 83029, 83032, 83036, 83041,
+    83046, 83050, 83056, 83061, 83069, 83073, 83077, 83081,
+    83085, 83089, 83093, 83097, 83100, 83103, 83107, 83112,
+    83118, 83122, 83125, 83134, 83140, 83145, 83149, 83156,
+    83163, 83166, 83169, 83173, 83180, 83186, 83195, 83200,
+    83203, 83208, 83213, 83216, 83219, 83224, 83228, 83232,
+    83235, 83238, 83241, 83244, 83246, 83251, 83254, 83258,
+    83262, 83266, 83270, 83275, 83280, 83284, 83288, 83293,
+    83297, 83301, 83306, 83309, 83313, 83316, 83320, 83323,
+    83329, 83333, 83335, 83338, 83343, 83347, 83350, 83354,
+    83358, 83362, 83365, 83370, 83373, 83376, 83379, 83382,
+    83386, 83389, 83392, 83395, 83407, 83409, 83412, 83416,
+    83419, 83422, 83426, 83429, 83433, 83436, 83438, 83442,
+    83446, 83449, 83452, 83456, 83461, 83464, 83467, 83471,
+    83474, 83477, 83480, 83484, 83489, 83493, 83497, 83501,
+    83505, 83509, 83513, 83517, 83521, 83525, 83529, 83533,
+    83537, 83541, 83545, 83550, 83554, 83559, 83562, 83566,
+    83570, 83573, 83577, 83582, 83586, 83593, 83596, 83600,
+    83603, 83608, 83612, 83616, 83622, 83627, 83630, 83635,
+    83639, 83643, 83646, 83650, 83654, 83658, 83662, 83665,
+    83668, 83671, 83678, 83685, 83689, 83693, 83696, 83700,
+    83704, 83709, 83714, 83719, 83725, 83730, 83744, 83758,
+    83763, 83768, 83774, 83778, 83782, 83788, 83792, 83795,
+    83798, 83801, 83805, 83809, 83813, 83817, 83821, 83824,
+    83827, 83831, 83834, 83837, 83842, 83846, 83850, 83854,
+    83858, 83862, 83866, 83870, 83875, 83880, 83884, 83890,
+    83895, 83898, 83902, 83905, 83909, 83912, 83920, 83932,
+    83936, 83939, 83942, 83949, 83955, 83958, 83962, 83966,
+    83970, 83974, 83978, 83983, 83987, 83992, 83996, 84000,
+    84005, 84009, 84012, 84016, 84019, 84022, 84025, 84029,
+    84033, 84037, 84040, 84044, 84047, 84051, 84064, 84067,
+    84071, 84074, 84077, 84080, 84084, 84087, 84090, 84094,
+    84098, 84103, 84108, 84113, 84118, 84122, 84127, 84132,
+    84136, 84141, 84145, 84150, 84154, 84158, 84162, 84166,
+    84170, 84174, 84178, 84182, 84186, 84190, 84195, 84200,
+    84205, 84210, 84215, 84220, 84225, 84230, 84235, 84239,
+    84244, 84249, 84254, 84259, 84264, 84269, 84274, 84279,
+    84283, 84286, 84289, 84297, 84301, 84309, 84312, 84321,
+    84325, 84330, 84333, 84341, 84344, 84354, 84357, 84362,
+    84366, 84369, 84373, 84378, 84381, 84383, 84387, 84392,
+    84396, 84400, 84404, 84410, 84414, 84418, 84422, 84426,
+    84430, 84434, 84438, 84442, 84446, 84450, 84455, 84460,
+    84464, 84468, 84473, 84477, 84481, 84485, 84488, 84492,
+    84496, 84501, 84505, 84509, 84512, 84515, 84519, 84523,
+    84526, 84531, 84536, 84539, 84551, 84556, 84559, 84562,
+    84569, 84572, 84578, 84581, 84584, 84587, 84590, 84594,
+    84599, 84602, 84605, 84609, 84613, 84617, 84620, 84623,
+    84626, 84634, 84637, 84642, 84645, 84648, 84652, 84655,
+    84658, 84662, 84666, 84670, 84674, 84678, 84682, 84686,
+    84690, 84695, 84700, 84704, 84708, 84712, 84716, 84720,
+    84724, 84729, 84733, 84737, 84742, 84746, 84749, 84753,
+    84757, 84761, 84766, 84770, 84774,
+];
+
+pub(crate) const NAME_RANGES: &[(char, char, u32)] = &[
+    ('\u{20}', '\u{7E}', 0),
+    ('\u{A0}', '\u{377}', 457),
+    ('\u{37A}', '\u{37F}', 5993),
+    ('\u{384}', '\u{38A}', 6040),
+    ('\u{38C}', '\u{38C}', 6092),
+    ('\u{38E}', '\u{3A1}', 6101),
+    ('\u{3A3}', '\u{52F}', 6233),
+    ('\u{531}', '\u{556}', 9080),
+    ('\u{559}', '\u{58A}', 9344),
+    ('\u{58D}', '\u{58F}', 9689),
+    ('\u{591}', '\u{5C7}', 9711),
+    ('\u{5D0}', '\u{5EA}', 10137),
+    ('\u{5EF}', '\u{5F4}', 10303),
+    ('\u{600}', '\u{70D}', 10352),
+    ('\u{70F}', '\u{74A}', 12259),
+    ('\u{74D}', '\u{7B1}', 12653),
+    ('\u{7C0}', '\u{7FA}', 13535),
+    ('\u{7FD}', '\u{82D}', 13919),
+    ('\u{830}', '\u{83E}', 14239),
+    ('\u{840}', '\u{85B}', 14347),
+    ('\u{85E}', '\u{85E}', 14515),
+    ('\u{860}', '\u{86A}', 14520),
+    ('\u{870}', '\u{88E}', 14608),
+    ('\u{890}', '\u{891}', 14927),
+    ('\u{897}', '\u{983}', 14939),
+    ('\u{985}', '\u{98C}', 16503),
+    ('\u{98F}', '\u{990}', 16548),
+    ('\u{993}', '\u{9A8}', 16559),
+    ('\u{9AA}', '\u{9B0}', 16689),
+    ('\u{9B2}', '\u{9B2}', 16731),
+    ('\u{9B6}', '\u{9B9}', 16737),
+    ('\u{9BC}', '\u{9C4}', 16760),
+    ('\u{9C7}', '\u{9C8}', 16820),
+    ('\u{9CB}', '\u{9CE}', 16833),
+    ('\u{9D7}', '\u{9D7}', 16860),
+    ('\u{9DC}', '\u{9DD}', 16868),
+    ('\u{9DF}', '\u{9E3}', 16880),
+    ('\u{9E6}', '\u{9FE}', 16915),
+    ('\u{A01}', '\u{A03}', 17086),
+    ('\u{A05}', '\u{A0A}', 17106),
+    ('\u{A0F}', '\u{A10}', 17139),
+    ('\u{A13}', '\u{A28}', 17151),
+    ('\u{A2A}', '\u{A30}', 17282),
+    ('\u{A32}', '\u{A33}', 17324),
+    ('\u{A35}', '\u{A36}', 17336),
+    ('\u{A38}', '\u{A39}', 17348),
+    ('\u{A3C}', '\u{A3C}', 17359),
+    ('\u{A3E}', '\u{A42}', 17365),
+    ('\u{A47}', '\u{A48}', 17398),
+    ('\u{A4B}', '\u{A4D}', 17412),
+    ('\u{A51}', '\u{A51}', 17432),
+    ('\u{A59}', '\u{A5C}', 17438),
+    ('\u{A5E}', '\u{A5E}', 17462),
+    ('\u{A66}', '\u{A76}', 17468),
+    ('\u{A81}', '\u{A83}', 17558),
+    ('\u{A85}', '\u{A8D}', 17576),
+    ('\u{A8F}', '\u{A91}', 17628),
+    ('\u{A93}', '\u{AA8}', 17646),
+    ('\u{AAA}', '\u{AB0}', 17776),
+    ('\u{AB2}', '\u{AB3}', 17818),
+    ('\u{AB5}', '\u{AB9}', 17830),
+    ('\u{ABC}', '\u{AC5}', 17859),
+    ('\u{AC7}', '\u{AC9}', 17927),
+    ('\u{ACB}', '\u{ACD}', 17948),
+    ('\u{AD0}', '\u{AD0}', 17967),
+    ('\u{AE0}', '\u{AE3}', 17972),
+    ('\u{AE6}', '\u{AF1}', 18001),
+    ('\u{AF9}', '\u{AFF}', 18064),
+    ('\u{B01}', '\u{B03}', 18114),
+    ('\u{B05}', '\u{B0C}', 18132),
+    ('\u{B0F}', '\u{B10}', 18177),
+    ('\u{B13}', '\u{B28}', 18188),
+    ('\u{B2A}', '\u{B30}', 18318),
+    ('\u{B32}', '\u{B33}', 18360),
+    ('\u{B35}', '\u{B39}', 18372),
+    ('\u{B3C}', '\u{B44}', 18401),
+    ('\u{B47}', '\u{B48}', 18461),
+    ('\u{B4B}', '\u{B4D}', 18474),
+    ('\u{B55}', '\u{B57}', 18493),
+    ('\u{B5C}', '\u{B5D}', 18515),
+    ('\u{B5F}', '\u{B63}', 18527),
+    ('\u{B66}', '\u{B77}', 18562),
+    ('\u{B82}', '\u{B83}', 18671),
+    ('\u{B85}', '\u{B8A}', 18683),
+    ('\u{B8E}', '\u{B90}', 18716),
+    ('\u{B92}', '\u{B95}', 18733),
+    ('\u{B99}', '\u{B9A}', 18755),
+    ('\u{B9C}', '\u{B9C}', 18767),
+    ('\u{B9E}', '\u{B9F}', 18773),
+    ('\u{BA3}', '\u{BA4}', 18785),
+    ('\u{BA8}', '\u{BAA}', 18797),
+    ('\u{BAE}', '\u{BB9}', 18815),
+    ('\u{BBE}', '\u{BC2}', 18886),
+    ('\u{BC6}', '\u{BC8}', 18919),
+    ('\u{BCA}', '\u{BCD}', 18939),
+    ('\u{BD0}', '\u{BD0}', 18965),
+    ('\u{BD7}', '\u{BD7}', 18970),
+    ('\u{BE6}', '\u{BFA}', 18978),
+    ('\u{C00}', '\u{C0C}', 19097),
+    ('\u{C0E}', '\u{C10}', 19176),
+    ('\u{C12}', '\u{C28}', 19193),
+    ('\u{C2A}', '\u{C39}', 19329),
+    ('\u{C3C}', '\u{C44}', 19424),
+    ('\u{C46}', '\u{C48}', 19484),
+    ('\u{C4A}', '\u{C4D}', 19504),
+    ('\u{C55}', '\u{C56}', 19530),
+    ('\u{C58}', '\u{C5A}', 19544),
+    ('\u{C5D}', '\u{C5D}', 19562),
+    ('\u{C60}', '\u{C63}', 19570),
+    ('\u{C66}', '\u{C6F}', 19599),
+    ('\u{C77}', '\u{C8C}', 19650),
+    ('\u{C8E}', '\u{C90}', 19838),
+    ('\u{C92}', '\u{CA8}', 19855),
+    ('\u{CAA}', '\u{CB3}', 19991),
+    ('\u{CB5}', '\u{CB9}', 20051),
+    ('\u{CBC}', '\u{CC4}', 20080),
+    ('\u{CC6}', '\u{CC8}', 20140),
+    ('\u{CCA}', '\u{CCD}', 20160),
+    ('\u{CD5}', '\u{CD6}', 20186),
+    ('\u{CDD}', '\u{CDE}', 20200),
+    ('\u{CE0}', '\u{CE3}', 20214),
+    ('\u{CE6}', '\u{CEF}', 20243),
+    ('\u{CF1}', '\u{CF3}', 20294),
+    ('\u{D00}', '\u{D0C}', 20315),
+    ('\u{D0E}', '\u{D10}', 20394),
+    ('\u{D12}', '\u{D44}', 20411),
+    ('\u{D46}', '\u{D48}', 20724),
+    ('\u{D4A}', '\u{D4F}', 20744),
+    ('\u{D54}', '\u{D63}', 20783),
+    ('\u{D66}', '\u{D7F}', 20908),
+    ('\u{D81}', '\u{D83}', 21079),
+    ('\u{D85}', '\u{D96}', 21097),
+    ('\u{D9A}', '\u{DB1}', 21205),
+    ('\u{DB3}', '\u{DBB}', 21399),
+    ('\u{DBD}', '\u{DBD}', 21465),
+    ('\u{DC0}', '\u{DC6}', 21473),
+    ('\u{DCA}', '\u{DCA}', 21523),
+    ('\u{DCF}', '\u{DD4}', 21529),
+    ('\u{DD6}', '\u{DD6}', 21581),
+    ('\u{DD8}', '\u{DDF}', 21590),
+    ('\u{DE6}', '\u{DEF}', 21664),
+    ('\u{DF2}', '\u{DF4}', 21735),
+    ('\u{E01}', '\u{E3A}', 21760),
+    ('\u{E3F}', '\u{E5B}', 22268),
+    ('\u{E81}', '\u{E82}', 22473),
+    ('\u{E84}', '\u{E84}', 22487),
+    ('\u{E86}', '\u{E8A}', 22495),
+    ('\u{E8C}', '\u{EA3}', 22531),
+    ('\u{EA5}', '\u{EA5}', 22706),
+    ('\u{EA7}', '\u{EBD}', 22714),
+    ('\u{EC0}', '\u{EC4}', 22880),
+    ('\u{EC6}', '\u{EC6}', 22913),
+    ('\u{EC8}', '\u{ECE}', 22920),
+    ('\u{ED0}', '\u{ED9}', 22972),
+    ('\u{EDC}', '\u{EDF}', 23023),
+    ('\u{F00}', '\u{F47}', 23053),
+    ('\u{F49}', '\u{F6C}', 23614),
+    ('\u{F71}', '\u{F97}', 23794),
+    ('\u{F99}', '\u{FBC}', 24074),
+    ('\u{FBE}', '\u{FCC}', 24330),
+    ('\u{FCE}', '\u{FDA}', 24465),
+    ('\u{1000}', '\u{10C5}', 24599),
+    ('\u{10C7}', '\u{10C7}', 25883),
+    ('\u{10CD}', '\u{10CD}', 25889),
+    ('\u{10D0}', '\u{1248}', 25895),
+    ('\u{124A}', '\u{124D}', 28035),
+    ('\u{1250}', '\u{1256}', 28055),
+    ('\u{1258}', '\u{1258}', 28090),
+    ('\u{125A}', '\u{125D}', 28095),
+    ('\u{1260}', '\u{1288}', 28115),
+    ('\u{128A}', '\u{128D}', 28319),
+    ('\u{1290}', '\u{12B0}', 28339),
+    ('\u{12B2}', '\u{12B5}', 28514),
+    ('\u{12B8}', '\u{12BE}', 28534),
+    ('\u{12C0}', '\u{12C0}', 28569),
+    ('\u{12C2}', '\u{12C5}', 28574),
+    ('\u{12C8}', '\u{12D6}', 28594),
+    ('\u{12D8}', '\u{1310}', 28678),
+    ('\u{1312}', '\u{1315}', 28962),
+    ('\u{1318}', '\u{135A}', 28982),
+    ('\u{135D}', '\u{137C}', 29317),
+    ('\u{1380}', '\u{1399}', 29477),
+    ('\u{13A0}', '\u{13F5}', 29637),
+    ('\u{13F8}', '\u{13FD}', 30058),
+    ('\u{1400}', '\u{169C}', 30093),
+    ('\u{16A0}', '\u{16F8}', 34032),
+    ('\u{1700}', '\u{1715}', 34696),
+    ('\u{171F}', '\u{1736}', 34823),
+    ('\u{1740}', '\u{1753}', 34965),
+    ('\u{1760}', '\u{176C}', 35080),
+    ('\u{176E}', '\u{1770}', 35154),
+    ('\u{1772}', '\u{1773}', 35172),
+    ('\u{1780}', '\u{17DD}', 35184),
+    ('\u{17E0}', '\u{17E9}', 35814),
+    ('\u{17F0}', '\u{17F9}', 35865),
+    ('\u{1800}', '\u{1819}', 35965),
+    ('\u{1820}', '\u{1878}', 36105),
+    ('\u{1880}', '\u{18AA}', 36644),
+    ('\u{18B0}', '\u{18F5}', 37066),
+    ('\u{1900}', '\u{191E}', 37476),
+    ('\u{1920}', '\u{192B}', 37660),
+    ('\u{1930}', '\u{193B}', 37742),
+    ('\u{1940}', '\u{1940}', 37822),
+    ('\u{1944}', '\u{196D}', 37828),
+    ('\u{1970}', '\u{1974}', 38094),
+    ('\u{1980}', '\u{19AB}', 38129),
+    ('\u{19B0}', '\u{19C9}', 38609),
+    ('\u{19D0}', '\u{19DA}', 38866),
+    ('\u{19DE}', '\u{1A1B}', 38957),
+    ('\u{1A1E}', '\u{1A5E}', 39393),
+    ('\u{1A60}', '\u{1A7C}', 39943),
+    ('\u{1A7F}', '\u{1A89}', 40182),
+    ('\u{1A90}', '\u{1A99}', 40271),
+    ('\u{1AA0}', '\u{1AAD}', 40352),
+    ('\u{1AB0}', '\u{1ACE}', 40456),
+    ('\u{1B00}', '\u{1B4C}', 40660),
+    ('\u{1B4E}', '\u{1BF3}', 41237),
+    ('\u{1BFC}', '\u{1C37}', 42361),
+    ('\u{1C3B}', '\u{1C49}', 42751),
+    ('\u{1C4D}', '\u{1C8A}', 42843),
+    ('\u{1C90}', '\u{1CBA}', 43323),
+    ('\u{1CBD}', '\u{1CC7}', 43669),
+    ('\u{1CD0}', '\u{1CFA}', 43774),
+    ('\u{1D00}', '\u{1F15}', 44157),
+    ('\u{1F18}', '\u{1F1D}', 48777),
+    ('\u{1F20}', '\u{1F45}', 48843),
+    ('\u{1F48}', '\u{1F4D}', 49269),
+    ('\u{1F50}', '\u{1F57}', 49335),
+    ('\u{1F59}', '\u{1F59}', 49425),
+    ('\u{1F5B}', '\u{1F5B}', 49434),
+    ('\u{1F5D}', '\u{1F5D}', 49446),
+    ('\u{1F5F}', '\u{1F7D}', 49458),
+    ('\u{1F80}', '\u{1FB4}', 49776),
+    ('\u{1FB6}', '\u{1FC4}', 50511),
+    ('\u{1FC6}', '\u{1FD3}', 50633),
+    ('\u{1FD6}', '\u{1FDB}', 50762),
+    ('\u{1FDD}', '\u{1FEF}', 50819),
+    ('\u{1FF2}', '\u{1FF4}', 50984),
+    ('\u{1FF6}', '\u{1FFE}', 51017),
+    ('\u{2000}', '\u{2064}', 51091),
+    ('\u{2066}', '\u{2071}', 51628),
+    ('\u{2074}', '\u{208E}', 51704),
+    ('\u{2090}', '\u{209C}', 51833),
+    ('\u{20A0}', '\u{20C0}', 51932),
+    ('\u{20D0}', '\u{20F0}', 52077),
+    ('\u{2100}', '\u{218B}', 52297),
+    ('\u{2190}', '\u{2429}', 53184),
+    ('\u{2440}', '\u{244A}', 57712),
+    ('\u{2460}', '\u{2B73}', 57783),
+    ('\u{2B76}', '\u{2B95}', 70322),
+    ('\u{2B97}', '\u{2CF3}', 70593),
+    ('\u{2CF9}', '\u{2D25}', 73120),
+    ('\u{2D27}', '\u{2D27}', 73404),
+    ('\u{2D2D}', '\u{2D2D}', 73410),
+    ('\u{2D30}', '\u{2D67}', 73416),
+    ('\u{2D6F}', '\u{2D70}', 73781),
+    ('\u{2D7F}', '\u{2D96}', 73795),
+    ('\u{2DA0}', '\u{2DA6}', 73919),
+    ('\u{2DA8}', '\u{2DAE}', 73954),
+    ('\u{2DB0}', '\u{2DB6}', 73989),
+    ('\u{2DB8}', '\u{2DBE}', 74024),
+    ('\u{2DC0}', '\u{2DC6}', 74059),
+    ('\u{2DC8}', '\u{2DCE}', 74094),
+    ('\u{2DD0}', '\u{2DD6}', 74129),
+    ('\u{2DD8}', '\u{2DDE}', 74164),
+    ('\u{2DE0}', '\u{2E5D}', 74199),
+    ('\u{2E80}', '\u{2E99}', 74991),
+    ('\u{2E9B}', '\u{2EF3}', 75133),
+    ('\u{2F00}', '\u{2FD5}', 75673),
+    ('\u{2FF0}', '\u{303F}', 76765),
+    ('\u{3041}', '\u{3096}', 77391),
+    ('\u{3099}', '\u{30FF}', 77905),
+    ('\u{3105}', '\u{312F}', 78444),
+    ('\u{3131}', '\u{318E}', 78695),
+    ('\u{3190}', '\u{31E5}', 79158),
+    ('\u{31EF}', '\u{321E}', 79667),
+    ('\u{3220}', '\u{33FF}', 79978),
+    ('\u{4DC0}', '\u{4DFF}', 82800),
+    ('\u{A000}', '\u{A48C}', 83281),
+    ('\u{A490}', '\u{A4C6}', 89095),
+    ('\u{A4D0}', '\u{A62B}', 89370),
+    ('\u{A640}', '\u{A6F7}', 91170),
+    ('\u{A700}', '\u{A7CD}', 92274),
+    ('\u{A7D0}', '\u{A7D1}', 93988),
+    ('\u{A7D3}', '\u{A7D3}', 94008),
+    ('\u{A7D5}', '\u{A7DC}', 94015),
+    ('\u{A7F2}', '\u{A82C}', 94072),
+    ('\u{A830}', '\u{A839}', 94545),
+    ('\u{A840}', '\u{A877}', 94636),
+    ('\u{A880}', '\u{A8C5}', 94984),
+    ('\u{A8CE}', '\u{A8D9}', 95414),
+    ('\u{A8E0}', '\u{A953}', 95476),
+    ('\u{A95F}', '\u{A97C}', 96250),
+    ('\u{A980}', '\u{A9CD}', 96430),
+    ('\u{A9CF}', '\u{A9D9}', 96967),
+    ('\u{A9DE}', '\u{A9FE}', 97023),
+    ('\u{AA00}', '\u{AA36}', 97272),
+    ('\u{AA40}', '\u{AA4D}', 97610),
+    ('\u{AA50}', '\u{AA59}', 97712),
+    ('\u{AA5C}', '\u{AAC2}', 97763),
+    ('\u{AADB}', '\u{AAF6}', 98598),
+    ('\u{AB01}', '\u{AB06}', 98826),
+    ('\u{AB09}', '\u{AB0E}', 98856),
+    ('\u{AB11}', '\u{AB16}', 98886),
+    ('\u{AB20}', '\u{AB26}', 98916),
+    ('\u{AB28}', '\u{AB2E}', 98951),
+    ('\u{AB30}', '\u{AB6B}', 98986),
+    ('\u{AB70}', '\u{ABED}', 99534),
+    ('\u{ABF0}', '\u{ABF9}', 100397),
+    ('\u{D7B0}', '\u{D7C6}', 100468),
+    ('\u{D7CB}', '\u{D7FB}', 100606),
+    ('\u{FB00}', '\u{FB06}', 100900),
+    ('\u{FB13}', '\u{FB17}', 100945),
+    ('\u{FB1D}', '\u{FB36}', 100990),
+    ('\u{FB38}', '\u{FB3C}', 101224),
+    ('\u{FB3E}', '\u{FB3E}', 101270),
+    ('\u{FB40}', '\u{FB41}', 101279),
+    ('\u{FB43}', '\u{FB44}', 101297),
+    ('\u{FB46}', '\u{FBC2}', 101316),
+    ('\u{FBD3}', '\u{FD8F}', 102273),
+    ('\u{FD92}', '\u{FDC7}', 106809),
+    ('\u{FDCF}', '\u{FDCF}', 107450),
+    ('\u{FDF0}', '\u{FE19}', 107457),
+    ('\u{FE20}', '\u{FE52}', 107752),
+    ('\u{FE54}', '\u{FE66}', 108176),
+    ('\u{FE68}', '\u{FE6B}', 108277),
+    ('\u{FE70}', '\u{FE74}', 108299),
+    ('\u{FE76}', '\u{FEFC}', 108331),
+    ('\u{FEFF}', '\u{FEFF}', 109368),
+    ('\u{FF01}', '\u{FFBE}', 109377),
+    ('\u{FFC2}', '\u{FFC7}', 110696),
+    ('\u{FFCA}', '\u{FFCF}', 110736),
+    ('\u{FFD2}', '\u{FFD7}', 110777),
+    ('\u{FFDA}', '\u{FFDC}', 110818),
+    ('\u{FFE0}', '\u{FFE6}', 110837),
+    ('\u{FFE8}', '\u{FFEE}', 110878),
+    ('\u{FFF9}', '\u{FFFD}', 110917),
+    ('\u{10000}', '\u{1000B}', 110950),
+    ('\u{1000D}', '\u{10026}', 111041),
+    ('\u{10028}', '\u{1003A}', 111248),
+    ('\u{1003C}', '\u{1003D}', 111399),
+    ('\u{1003F}', '\u{1004D}', 111415),
+    ('\u{10050}', '\u{1005D}', 111534),
+    ('\u{10080}', '\u{100FA}', 111618),
+    ('\u{10100}', '\u{10102}', 112634),
+    ('\u{10107}', '\u{10133}', 112657),
+    ('\u{10137}', '\u{1018E}', 112954),
+    ('\u{10190}', '\u{1019C}', 113668),
+    ('\u{101A0}', '\u{101A0}', 113746),
+    ('\u{101D0}', '\u{101FD}', 113753),
+    ('\u{10280}', '\u{1029C}', 114137),
+    ('\u{102A0}', '\u{102D0}', 114303),
+    ('\u{102E0}', '\u{102FB}', 114588),
+    ('\u{10300}', '\u{10323}', 114784),
+    ('\u{1032D}', '\u{1034A}', 114996),
+    ('\u{10350}', '\u{1037A}', 115181),
+    ('\u{10380}', '\u{1039D}', 115481),
+    ('\u{1039F}', '\u{103C3}', 115658),
+    ('\u{103C8}', '\u{103D5}', 115912),
+    ('\u{10400}', '\u{1049D}', 116009),
+    ('\u{104A0}', '\u{104A9}', 117061),
+    ('\u{104B0}', '\u{104D3}', 117112),
+    ('\u{104D8}', '\u{104FB}', 117357),
+    ('\u{10500}', '\u{10527}', 117602),
+    ('\u{10530}', '\u{10563}', 117837),
+    ('\u{1056F}', '\u{1057A}', 118253),
+    ('\u{1057C}', '\u{1058A}', 118336),
+    ('\u{1058C}', '\u{10592}', 118437),
+    ('\u{10594}', '\u{10595}', 118485),
+    ('\u{10597}', '\u{105A1}', 118499),
+    ('\u{105A3}', '\u{105B1}', 118574),
+    ('\u{105B3}', '\u{105B9}', 118675),
+    ('\u{105BB}', '\u{105BC}', 118723),
+    ('\u{105C0}', '\u{105F3}', 118737),
+    ('\u{10600}', '\u{10736}', 119042),
+    ('\u{10740}', '\u{10755}', 120908),
+    ('\u{10760}', '\u{10767}', 121080),
+    ('\u{10780}', '\u{10785}', 121128),
+    ('\u{10787}', '\u{107B0}', 121173),
+    ('\u{107B2}', '\u{107BA}', 121549),
+    ('\u{10800}', '\u{10805}', 121623),
+    ('\u{10808}', '\u{10808}', 121654),
+    ('\u{1080A}', '\u{10835}', 121660),
+    ('\u{10837}', '\u{10838}', 121922),
+    ('\u{1083C}', '\u{1083C}', 121934),
+    ('\u{1083F}', '\u{10855}', 121940),
+    ('\u{10857}', '\u{1089E}', 122122),
+    ('\u{108A7}', '\u{108AF}', 122580),
+    ('\u{108E0}', '\u{108F2}', 122631),
+    ('\u{108F4}', '\u{108F5}', 122745),
+    ('\u{108FB}', '\u{1091B}', 122757),
+    ('\u{1091F}', '\u{10939}', 122952),
+    ('\u{1093F}', '\u{1093F}', 123106),
+    ('\u{10980}', '\u{109B7}', 123112),
+    ('\u{109BC}', '\u{109CF}', 123550),
+    ('\u{109D2}', '\u{10A03}', 123707),
+    ('\u{10A05}', '\u{10A06}', 124183),
+    ('\u{10A0C}', '\u{10A13}', 124195),
+    ('\u{10A15}', '\u{10A17}', 124245),
+    ('\u{10A19}', '\u{10A35}', 124263),
+    ('\u{10A38}', '\u{10A3A}', 124436),
+    ('\u{10A3F}', '\u{10A48}', 124454),
+    ('\u{10A50}', '\u{10A58}', 124512),
+    ('\u{10A60}', '\u{10A9F}', 124575),
+    ('\u{10AC0}', '\u{10AE6}', 125146),
+    ('\u{10AEB}', '\u{10AF6}', 125382),
+    ('\u{10B00}', '\u{10B35}', 125467),
+    ('\u{10B39}', '\u{10B55}', 125786),
+    ('\u{10B58}', '\u{10B72}', 126037),
+    ('\u{10B78}', '\u{10B91}', 126251),
+    ('\u{10B99}', '\u{10B9C}', 126457),
+    ('\u{10BA9}', '\u{10BAF}', 126496),
+    ('\u{10C00}', '\u{10C48}', 126549),
+    ('\u{10C80}', '\u{10CB2}', 127200),
+    ('\u{10CC0}', '\u{10CF2}', 127614),
+    ('\u{10CFA}', '\u{10D27}', 128028),
+    ('\u{10D30}', '\u{10D39}', 128388),
+    ('\u{10D40}', '\u{10D65}', 128459),
+    ('\u{10D69}', '\u{10D85}', 128699),
+    ('\u{10D8E}', '\u{10D8F}', 128897),
+    ('\u{10E60}', '\u{10E7E}', 128908),
+    ('\u{10E80}', '\u{10EA9}', 129101),
+    ('\u{10EAB}', '\u{10EAD}', 129356),
+    ('\u{10EB0}', '\u{10EB1}', 129376),
+    ('\u{10EC2}', '\u{10EC4}', 129395),
+    ('\u{10EFC}', '\u{10F27}', 129431),
+    ('\u{10F30}', '\u{10F59}', 129764),
+    ('\u{10F70}', '\u{10F89}', 130045),
+    ('\u{10FB0}', '\u{10FCB}', 130238),
+    ('\u{10FE0}', '\u{10FF6}', 130406),
+    ('\u{11000}', '\u{1104D}', 130544),
+    ('\u{11052}', '\u{11075}', 131033),
+    ('\u{1107F}', '\u{110C2}', 131257),
+    ('\u{110CD}', '\u{110CD}', 131663),
+    ('\u{110D0}', '\u{110E8}', 131669),
+    ('\u{110F0}', '\u{110F9}', 131869),
+    ('\u{11100}', '\u{11134}', 131940),
+    ('\u{11136}', '\u{11147}', 132257),
+    ('\u{11150}', '\u{11176}', 132357),
+    ('\u{11180}', '\u{111DF}', 132584),
+    ('\u{111E1}', '\u{111F4}', 133161),
+    ('\u{11200}', '\u{11211}', 133314),
+    ('\u{11213}', '\u{11241}', 133416),
+    ('\u{11280}', '\u{11286}', 133704),
+    ('\u{11288}', '\u{11288}', 133741),
+    ('\u{1128A}', '\u{1128D}', 133747),
+    ('\u{1128F}', '\u{1129D}', 133771),
+    ('\u{1129F}', '\u{112A9}', 133861),
+    ('\u{112B0}', '\u{112EA}', 133926),
+    ('\u{112F0}', '\u{112F9}', 134278),
+    ('\u{11300}', '\u{11303}', 134329),
+    ('\u{11305}', '\u{1130C}', 134355),
+    ('\u{1130F}', '\u{11310}', 134400),
+    ('\u{11313}', '\u{11328}', 134412),
+    ('\u{1132A}', '\u{11330}', 134543),
+    ('\u{11332}', '\u{11333}', 134585),
+    ('\u{11335}', '\u{11339}', 134597),
+    ('\u{1133B}', '\u{11344}', 134626),
+    ('\u{11347}', '\u{11348}', 134691),
+    ('\u{1134B}', '\u{1134D}', 134705),
+    ('\u{11350}', '\u{11350}', 134725),
+    ('\u{11357}', '\u{11357}', 134730),
+    ('\u{1135D}', '\u{11363}', 134738),
+    ('\u{11366}', '\u{1136C}', 134790),
+    ('\u{11370}', '\u{11374}', 134833),
+    ('\u{11380}', '\u{11389}', 134866),
+    ('\u{1138B}', '\u{1138B}', 134925),
+    ('\u{1138E}', '\u{1138E}', 134931),
+    ('\u{11390}', '\u{113B5}', 134937),
+    ('\u{113B7}', '\u{113C0}', 135163),
+    ('\u{113C2}', '\u{113C2}', 135232),
+    ('\u{113C5}', '\u{113C5}', 135239),
+    ('\u{113C7}', '\u{113CA}', 135246),
+    ('\u{113CC}', '\u{113D5}', 135276),
+    ('\u{113D7}', '\u{113D8}', 135335),
+    ('\u{113E1}', '\u{113E2}', 135351),
+    ('\u{11400}', '\u{1145B}', 135369),
+    ('\u{1145D}', '\u{11461}', 135916),
+    ('\u{11480}', '\u{114C7}', 135948),
+    ('\u{114D0}', '\u{114D9}', 136387),
+    ('\u{11580}', '\u{115B5}', 136438),
+    ('\u{115B8}', '\u{115DD}', 136763),
+    ('\u{11600}', '\u{11644}', 137105),
+    ('\u{11650}', '\u{11659}', 137524),
+    ('\u{11660}', '\u{1166C}', 137575),
+    ('\u{11680}', '\u{116B9}', 137685),
+    ('\u{116C0}', '\u{116C9}', 138033),
+    ('\u{116D0}', '\u{116E3}', 138084),
+    ('\u{11700}', '\u{1171A}', 138246),
+    ('\u{1171D}', '\u{1172B}', 138411),
+    ('\u{11730}', '\u{11746}', 138521),
+    ('\u{11800}', '\u{1183B}', 138651),
+    ('\u{118A0}', '\u{118F2}', 139012),
+    ('\u{118FF}', '\u{11906}', 139725),
+    ('\u{11909}', '\u{11909}', 139784),
+    ('\u{1190C}', '\u{11913}', 139791),
+    ('\u{11915}', '\u{11916}', 139854),
+    ('\u{11918}', '\u{11935}', 139870),
+    ('\u{11937}', '\u{11938}', 140112),
+    ('\u{1193B}', '\u{11946}', 140129),
+    ('\u{11950}', '\u{11959}', 140232),
+    ('\u{119A0}', '\u{119A7}', 140303),
+    ('\u{119AA}', '\u{119D7}', 140349),
+    ('\u{119DA}', '\u{119E4}', 140627),
+    ('\u{11A00}', '\u{11A47}', 140696),
+    ('\u{11A50}', '\u{11AA2}', 141233),
+    ('\u{11AB0}', '\u{11AF8}', 141803),
+    ('\u{11B00}', '\u{11B09}', 142527),
+    ('\u{11BC0}', '\u{11BE1}', 142600),
+    ('\u{11BF0}', '\u{11BF9}', 142804),
+    ('\u{11C00}', '\u{11C08}', 142855),
+    ('\u{11C0A}', '\u{11C36}', 142907),
+    ('\u{11C38}', '\u{11C45}', 143180),
+    ('\u{11C50}', '\u{11C6C}', 143268),
+    ('\u{11C70}', '\u{11C8F}', 143426),
+    ('\u{11C92}', '\u{11CA7}', 143615),
+    ('\u{11CA9}', '\u{11CB6}', 143790),
+    ('\u{11D00}', '\u{11D06}', 143887),
+    ('\u{11D08}', '\u{11D09}', 143939),
+    ('\u{11D0B}', '\u{11D36}', 143954),
+    ('\u{11D3A}', '\u{11D3A}', 144308),
+    ('\u{11D3C}', '\u{11D3D}', 144316),
+    ('\u{11D3F}', '\u{11D47}', 144333),
+    ('\u{11D50}', '\u{11D59}', 144403),
+    ('\u{11D60}', '\u{11D65}', 144474),
+    ('\u{11D67}', '\u{11D68}', 144519),
+    ('\u{11D6A}', '\u{11D8E}', 144535),
+    ('\u{11D90}', '\u{11D91}', 144832),
+    ('\u{11D93}', '\u{11D98}', 144850),
+    ('\u{11DA0}', '\u{11DA9}', 144898),
+    ('\u{11EE0}', '\u{11EF8}', 144969),
+    ('\u{11F00}', '\u{11F10}', 145117),
+    ('\u{11F12}', '\u{11F3A}', 145216),
+    ('\u{11F3E}', '\u{11F5A}', 145467),
+    ('\u{11FB0}', '\u{11FB0}', 145653),
+    ('\u{11FC0}', '\u{11FF1}', 145659),
+    ('\u{11FFF}', '\u{12399}', 146018),
+    ('\u{12400}', '\u{1246E}', 153093),
+    ('\u{12470}', '\u{12474}', 154106),
+    ('\u{12480}', '\u{12543}', 154153),
+    ('\u{12F90}', '\u{12FF2}', 155754),
+    ('\u{13000}', '\u{13455}', 156348),
+    ('\u{14400}', '\u{14646}', 162072),
+    ('\u{16100}', '\u{16139}', 165004),
+    ('\u{16800}', '\u{16A38}', 165479),
+    ('\u{16A40}', '\u{16A5E}', 169465),
+    ('\u{16A60}', '\u{16A69}', 169648),
+    ('\u{16A6E}', '\u{16ABE}', 169699),
+    ('\u{16AC0}', '\u{16AC9}', 170189),
+    ('\u{16AD0}', '\u{16AED}', 170240),
+    ('\u{16AF0}', '\u{16AF5}', 170473),
+    ('\u{16B00}', '\u{16B45}', 170532),
+    ('\u{16B50}', '\u{16B59}', 171088),
+    ('\u{16B5B}', '\u{16B61}', 171149),
+    ('\u{16B63}', '\u{16B77}', 171204),
+    ('\u{16B7D}', '\u{16B8F}', 171387),
+    ('\u{16D40}', '\u{16D79}', 171558),
+    ('\u{16E40}', '\u{16E9A}', 172013),
+    ('\u{16F00}', '\u{16F4A}', 172603),
+    ('\u{16F4F}', '\u{16F87}', 173068),
+    ('\u{16F8F}', '\u{16F9F}', 173466),
+    ('\u{16FE0}', '\u{16FE4}', 173582),
+    ('\u{16FF0}', '\u{16FF1}', 173614),
+    ('\u{18800}', '\u{18AFF}', 173634),
+    ('\u{1AFF0}', '\u{1AFF3}', 176706),
+    ('\u{1AFF5}', '\u{1AFFB}', 176734),
+    ('\u{1AFFD}', '\u{1AFFE}', 176793),
+    ('\u{1B000}', '\u{1B122}', 176811),
+    ('\u{1B132}', '\u{1B132}', 178278),
+    ('\u{1B150}', '\u{1B152}', 178285),
+    ('\u{1B155}', '\u{1B155}', 178306),
+    ('\u{1B164}', '\u{1B167}', 178312),
+    ('\u{1BC00}', '\u{1BC6A}', 178336),
+    ('\u{1BC70}', '\u{1BC7C}', 179072),
+    ('\u{1BC80}', '\u{1BC88}', 179199),
+    ('\u{1BC90}', '\u{1BC99}', 179281),
+    ('\u{1BC9C}', '\u{1BCA3}', 179371),
+    ('\u{1CC00}', '\u{1CCF9}', 179436),
+    ('\u{1CD00}', '\u{1CEB3}', 181309),
+    ('\u{1CF00}', '\u{1CF2D}', 183945),
+    ('\u{1CF30}', '\u{1CF46}', 184368),
+    ('\u{1CF50}', '\u{1CFC3}', 184542),
+    ('\u{1D000}', '\u{1D0F5}', 185524),
+    ('\u{1D100}', '\u{1D126}', 187521),
+    ('\u{1D129}', '\u{1D1EA}', 187795),
+    ('\u{1D200}', '\u{1D245}', 189194),
+    ('\u{1D2C0}', '\u{1D2D3}', 189745),
+    ('\u{1D2E0}', '\u{1D2F3}', 189876),
+    ('\u{1D300}', '\u{1D356}', 190007),
+    ('\u{1D360}', '\u{1D378}', 190566),
+    ('\u{1D400}', '\u{1D454}', 190773),
+    ('\u{1D456}', '\u{1D49C}', 191253),
+    ('\u{1D49E}', '\u{1D49F}', 191707),
+    ('\u{1D4A2}', '\u{1D4A2}', 191721),
+    ('\u{1D4A5}', '\u{1D4A6}', 191728),
+    ('\u{1D4A9}', '\u{1D4AC}', 191742),
+    ('\u{1D4AE}', '\u{1D4B9}', 191769),
+    ('\u{1D4BB}', '\u{1D4BB}', 191849),
+    ('\u{1D4BD}', '\u{1D4C3}', 191856),
+    ('\u{1D4C5}', '\u{1D505}', 191903),
+    ('\u{1D507}', '\u{1D50A}', 192387),
+    ('\u{1D50D}', '\u{1D514}', 192414),
+    ('\u{1D516}', '\u{1D51C}', 192468),
+    ('\u{1D51E}', '\u{1D539}', 192515),
+    ('\u{1D53B}', '\u{1D53E}', 192700),
+    ('\u{1D540}', '\u{1D544}', 192727),
+    ('\u{1D546}', '\u{1D546}', 192760),
+    ('\u{1D54A}', '\u{1D550}', 192766),
+    ('\u{1D552}', '\u{1D6A5}', 192813),
+    ('\u{1D6A8}', '\u{1D7CB}', 195129),
+    ('\u{1D7CE}', '\u{1DA8B}', 197123),
+    ('\u{1DA9B}', '\u{1DA9F}', 203076),
+    ('\u{1DAA1}', '\u{1DAAF}', 203106),
+    ('\u{1DF00}', '\u{1DF1E}', 203196),
+    ('\u{1DF25}', '\u{1DF2A}', 203523),
+    ('\u{1E000}', '\u{1E006}', 203586),
+    ('\u{1E008}', '\u{1E018}', 203635),
+    ('\u{1E01B}', '\u{1E021}', 203754),
+    ('\u{1E023}', '\u{1E024}', 203803),
+    ('\u{1E026}', '\u{1E02A}', 203818),
+    ('\u{1E030}', '\u{1E06D}', 203862),
+    ('\u{1E08F}', '\u{1E08F}', 204328),
+    ('\u{1E100}', '\u{1E12C}', 204336),
+    ('\u{1E130}', '\u{1E13D}', 204734),
+    ('\u{1E140}', '\u{1E149}', 204860),
+    ('\u{1E14E}', '\u{1E14F}', 204941),
+    ('\u{1E290}', '\u{1E2AE}', 204960),
+    ('\u{1E2C0}', '\u{1E2F9}', 205149),
+    ('\u{1E2FF}', '\u{1E2FF}', 205485),
+    ('\u{1E4D0}', '\u{1E4F9}', 205491),
+    ('\u{1E5D0}', '\u{1E5FA}', 205813),
+    ('\u{1E5FF}', '\u{1E5FF}', 206143),
+    ('\u{1E7E0}', '\u{1E7E6}', 206151),
+    ('\u{1E7E8}', '\u{1E7EB}', 206186),
+    ('\u{1E7ED}', '\u{1E7EE}', 206208),
+    ('\u{1E7F0}', '\u{1E7FE}', 206222),
+    ('\u{1E800}', '\u{1E8C4}', 206327),
+    ('\u{1E8C7}', '\u{1E8D6}', 207910),
+    ('\u{1E900}', '\u{1E94B}', 208008),
+    ('\u{1E950}', '\u{1E959}', 208523),
+    ('\u{1E95E}', '\u{1E95F}', 208574),
+    ('\u{1EC71}', '\u{1ECB4}', 208588),
+    ('\u{1ED01}', '\u{1ED3D}', 209180),
+    ('\u{1EE00}', '\u{1EE03}', 209720),
+    ('\u{1EE05}', '\u{1EE1F}', 209739),
+    ('\u{1EE21}', '\u{1EE22}', 209880),
+    ('\u{1EE24}', '\u{1EE24}', 209892),
+    ('\u{1EE27}', '\u{1EE27}', 209898),
+    ('\u{1EE29}', '\u{1EE32}', 209904),
+    ('\u{1EE34}', '\u{1EE37}', 209962),
+    ('\u{1EE39}', '\u{1EE39}', 209986),
+    ('\u{1EE3B}', '\u{1EE3B}', 209992),
+    ('\u{1EE42}', '\u{1EE42}', 209998),
+    ('\u{1EE47}', '\u{1EE47}', 210005),
+    ('\u{1EE49}', '\u{1EE49}', 210012),
+    ('\u{1EE4B}', '\u{1EE4B}', 210018),
+    ('\u{1EE4D}', '\u{1EE4F}', 210025),
+    ('\u{1EE51}', '\u{1EE52}', 210046),
+    ('\u{1EE54}', '\u{1EE54}', 210060),
+    ('\u{1EE57}', '\u{1EE57}', 210067),
+    ('\u{1EE59}', '\u{1EE59}', 210074),
+    ('\u{1EE5B}', '\u{1EE5B}', 210081),
+    ('\u{1EE5D}', '\u{1EE5D}', 210088),
+    ('\u{1EE5F}', '\u{1EE5F}', 210097),
+    ('\u{1EE61}', '\u{1EE62}', 210106),
+    ('\u{1EE64}', '\u{1EE64}', 210120),
+    ('\u{1EE67}', '\u{1EE6A}', 210127),
+    ('\u{1EE6C}', '\u{1EE72}', 210154),
+    ('\u{1EE74}', '\u{1EE77}', 210202),
+    ('\u{1EE79}', '\u{1EE7C}', 210230),
+    ('\u{1EE7E}', '\u{1EE7E}', 210260),
+    ('\u{1EE80}', '\u{1EE89}', 210269),
+    ('\u{1EE8B}', '\u{1EE9B}', 210337),
+    ('\u{1EEA1}', '\u{1EEA3}', 210455),
+    ('\u{1EEA5}', '\u{1EEA9}', 210476),
+    ('\u{1EEAB}', '\u{1EEBB}', 210510),
+    ('\u{1EEF0}', '\u{1EEF1}', 210628),
+    ('\u{1F000}', '\u{1F02B}', 210650),
+    ('\u{1F030}', '\u{1F093}', 211025),
+    ('\u{1F0A0}', '\u{1F0AE}', 211728),
+    ('\u{1F0B1}', '\u{1F0BF}', 211867),
+    ('\u{1F0C1}', '\u{1F0CF}', 212008),
+    ('\u{1F0D1}', '\u{1F0F5}', 212148),
+    ('\u{1F100}', '\u{1F1AD}', 212442),
+    ('\u{1F1E6}', '\u{1F202}', 213651),
+    ('\u{1F210}', '\u{1F23B}', 213892),
+    ('\u{1F240}', '\u{1F248}', 214198),
+    ('\u{1F250}', '\u{1F251}', 214306),
+    ('\u{1F260}', '\u{1F265}', 214318),
+    ('\u{1F300}', '\u{1F6D7}', 214360),
+    ('\u{1F6DC}', '\u{1F6EC}', 219817),
+    ('\u{1F6F0}', '\u{1F6FC}', 219897),
+    ('\u{1F700}', '\u{1F776}', 219954),
+    ('\u{1F77B}', '\u{1F7D9}', 220897),
+    ('\u{1F7E0}', '\u{1F7EB}', 221552),
+    ('\u{1F7F0}', '\u{1F7F0}', 221612),
+    ('\u{1F800}', '\u{1F80B}', 221617),
+    ('\u{1F810}', '\u{1F847}', 221735),
+    ('\u{1F850}', '\u{1F859}', 222199),
+    ('\u{1F860}', '\u{1F887}', 222255),
+    ('\u{1F890}', '\u{1F8AD}', 222661),
+    ('\u{1F8B0}', '\u{1F8BB}', 222904),
+    ('\u{1F8C0}', '\u{1F8C1}', 223004),
+    ('\u{1F900}', '\u{1FA53}', 223020),
+    ('\u{1FA60}', '\u{1FA6D}', 225195),
+    ('\u{1FA70}', '\u{1FA7C}', 225286),
+    ('\u{1FA80}', '\u{1FA89}', 225343),
+    ('\u{1FA8F}', '\u{1FAC6}', 225377),
+    ('\u{1FACE}', '\u{1FADC}', 225590),
+    ('\u{1FADF}', '\u{1FAE9}', 225645),
+    ('\u{1FAF0}', '\u{1FAF8}', 225711),
+    ('\u{1FB00}', '\u{1FB92}', 225770),
+    ('\u{1FB94}', '\u{1FBF9}', 226897),
+    ('\u{E0001}', '\u{E0001}', 227893),
+    ('\u{E0020}', '\u{E007F}', 227898),
+    ('\u{E0100}', '\u{E01EF}', 228550),
+];
+
+pub(crate) const NAME_BYTES: &[u8] = include_bytes!("names.bin");
+
+pub(crate) const NAME_ALIASES: &[(char, &str, &str)] = &[
+    ('\u{0}', "NULL", "control"),
+    ('\u{0}', "NUL", "abbreviation"),
+    ('\u{1}', "START OF HEADING", "control"),
+    ('\u{1}', "SOH", "abbreviation"),
+    ('\u{2}', "START OF TEXT", "control"),
+    ('\u{2}', "STX", "abbreviation"),
+    ('\u{3}', "END OF TEXT", "control"),
+    ('\u{3}', "ETX", "abbreviation"),
+    ('\u{4}', "END OF TRANSMISSION", "control"),
+    ('\u{4}', "EOT", "abbreviation"),
+    ('\u{5}', "ENQUIRY", "control"),
+    ('\u{5}', "ENQ", "abbreviation"),
+    ('\u{6}', "ACKNOWLEDGE", "control"),
+    ('\u{6}', "ACK", "abbreviation"),
+    ('\u{7}', "ALERT", "control"),
+    ('\u{7}', "BEL", "abbreviation"),
+    ('\u{8}', "BACKSPACE", "control"),
+    ('\u{8}', "BS", "abbreviation"),
+    ('\u{9}', "CHARACTER TABULATION", "control"),
+    ('\u{9}', "HORIZONTAL TABULATION", "control"),
+    ('\u{9}', "HT", "abbreviation"),
+    ('\u{9}', "TAB", "abbreviation"),
+    ('\u{A}', "LINE FEED", "control"),
+    ('\u{A}', "NEW LINE", "control"),
+    ('\u{A}', "END OF LINE", "control"),
+    ('\u{A}', "LF", "abbreviation"),
+    ('\u{A}', "NL", "abbreviation"),
+    ('\u{A}', "EOL", "abbreviation"),
+    ('\u{B}', "LINE TABULATION", "control"),
+    ('\u{B}', "VERTICAL TABULATION", "control"),
+    ('\u{B}', "VT", "abbreviation"),
+    ('\u{C}', "FORM FEED", "control"),
+    ('\u{C}', "FF", "abbreviation"),
+    ('\u{D}', "CARRIAGE RETURN", "control"),
+    ('\u{D}', "CR", "abbreviation"),
+    ('\u{E}', "SHIFT OUT", "control"),
+    ('\u{E}', "LOCKING-SHIFT ONE", "control"),
+    ('\u{E}', "SO", "abbreviation"),
+    ('\u{F}', "SHIFT IN", "control"),
+    ('\u{F}', "LOCKING-SHIFT ZERO", "control"),
+    ('\u{F}', "SI", "abbreviation"),
+    ('\u{10}', "DATA LINK ESCAPE", "control"),
+    ('\u{10}', "DLE", "abbreviation"),
+    ('\u{11}', "DEVICE CONTROL ONE", "control"),
+    ('\u{11}', "DC1", "abbreviation"),
+    ('\u{12}', "DEVICE CONTROL TWO", "control"),
+    ('\u{12}', "DC2", "abbreviation"),
+    ('\u{13}', "DEVICE CONTROL THREE", "control"),
+    ('\u{13}', "DC3", "abbreviation"),
+    ('\u{14}', "DEVICE CONTROL FOUR", "control"),
+    ('\u{14}', "DC4", "abbreviation"),
+    ('\u{15}', "NEGATIVE ACKNOWLEDGE", "control"),
+    ('\u{15}', "NAK", "abbreviation"),
+    ('\u{16}', "SYNCHRONOUS IDLE", "control"),
+    ('\u{16}', "SYN", "abbreviation"),
+    ('\u{17}', "END OF TRANSMISSION BLOCK", "control"),
+    ('\u{17}', "ETB", "abbreviation"),
+    ('\u{18}', "CANCEL", "control"),
+    ('\u{18}', "CAN", "abbreviation"),
+    ('\u{19}', "END OF MEDIUM", "control"),
+    ('\u{19}', "EOM", "abbreviation"),
+    ('\u{19}', "EM", "abbreviation"),
+    ('\u{1A}', "SUBSTITUTE", "control"),
+    ('\u{1A}', "SUB", "abbreviation"),
+    ('\u{1B}', "ESCAPE", "control"),
+    ('\u{1B}', "ESC", "abbreviation"),
+    ('\u{1C}', "INFORMATION SEPARATOR FOUR", "control"),
+    ('\u{1C}', "FILE SEPARATOR", "control"),
+    ('\u{1C}', "FS", "abbreviation"),
+    ('\u{1D}', "INFORMATION SEPARATOR THREE", "control"),
+    ('\u{1D}', "GROUP SEPARATOR", "control"),
+    ('\u{1D}', "GS", "abbreviation"),
+    ('\u{1E}', "INFORMATION SEPARATOR TWO", "control"),
+    ('\u{1E}', "RECORD SEPARATOR", "control"),
+    ('\u{1E}', "RS", "abbreviation"),
+    ('\u{1F}', "INFORMATION SEPARATOR ONE", "control"),
+    ('\u{1F}', "UNIT SEPARATOR", "control"),
+    ('\u{1F}', "US", "abbreviation"),
+    ('\u{20}', "SP", "abbreviation"),
+    ('\u{7F}', "DELETE", "control"),
+    ('\u{7F}', "DEL", "abbreviation"),
+    ('\u{80}', "PADDING CHARACTER", "figment"),
+    ('\u{80}', "PAD", "abbreviation"),
+    ('\u{81}', "HIGH OCTET PRESET", "figment"),
+    ('\u{81}', "HOP", "abbreviation"),
+    ('\u{82}', "BREAK PERMITTED HERE", "control"),
+    ('\u{82}', "BPH", "abbreviation"),
+    ('\u{83}', "NO BREAK HERE", "control"),
+    ('\u{83}', "NBH", "abbreviation"),
+    ('\u{84}', "INDEX", "control"),
+    ('\u{84}', "IND", "abbreviation"),
+    ('\u{85}', "NEXT LINE", "control"),
+    ('\u{85}', "NEL", "abbreviation"),
+    ('\u{86}', "START OF SELECTED AREA", "control"),
+    ('\u{86}', "SSA", "abbreviation"),
+    ('\u{87}', "END OF SELECTED AREA", "control"),
+    ('\u{87}', "ESA", "abbreviation"),
+    ('\u{88}', "CHARACTER TABULATION SET", "control"),
+    ('\u{88}', "HORIZONTAL TABULATION SET", "control"),
+    ('\u{88}', "HTS", "abbreviation"),
+    ('\u{89}', "CHARACTER TABULATION WITH JUSTIFICATION", "control"),
+    ('\u{89}', "HORIZONTAL TABULATION WITH JUSTIFICATION", "control"),
+    ('\u{89}', "HTJ", "abbreviation"),
+    ('\u{8A}', "LINE TABULATION SET", "control"),
+    ('\u{8A}', "VERTICAL TABULATION SET", "control"),
+    ('\u{8A}', "VTS", "abbreviation"),
+    ('\u{8B}', "PARTIAL LINE FORWARD", "control"),
+    ('\u{8B}', "PARTIAL LINE DOWN", "control"),
+    ('\u{8B}', "PLD", "abbreviation"),
+    ('\u{8C}', "PARTIAL LINE BACKWARD", "control"),
+    ('\u{8C}', "PARTIAL LINE UP", "control"),
+    ('\u{8C}', "PLU", "abbreviation"),
+    ('\u{8D}', "REVERSE LINE FEED", "control"),
+    ('\u{8D}', "REVERSE INDEX", "control"),
+    ('\u{8D}', "RI", "abbreviation"),
+    ('\u{8E}', "SINGLE SHIFT TWO", "control"),
+    ('\u{8E}', "SINGLE-SHIFT-2", "control"),
+    ('\u{8E}', "SS2", "abbreviation"),
+    ('\u{8F}', "SINGLE SHIFT THREE", "control"),
+    ('\u{8F}', "SINGLE-SHIFT-3", "control"),
+    ('\u{8F}', "SS3", "abbreviation"),
+    ('\u{90}', "DEVICE CONTROL STRING", "control"),
+    ('\u{90}', "DCS", "abbreviation"),
+    ('\u{91}', "PRIVATE USE ONE", "control"),
+    ('\u{91}', "PRIVATE USE-1", "control"),
+    ('\u{91}', "PU1", "abbreviation"),
+    ('\u{92}', "PRIVATE USE TWO", "control"),
+    ('\u{92}', "PRIVATE USE-2", "control"),
+    ('\u{92}', "PU2", "abbreviation"),
+    ('\u{93}', "SET TRANSMIT STATE", "control"),
+    ('\u{93}', "STS", "abbreviation"),
+    ('\u{94}', "CANCEL CHARACTER", "control"),
+    ('\u{94}', "CCH", "abbreviation"),
+    ('\u{95}', "MESSAGE WAITING", "control"),
+    ('\u{95}', "MW", "abbreviation"),
+    ('\u{96}', "START OF GUARDED AREA", "control"),
+    ('\u{96}', "START OF PROTECTED AREA", "control"),
+    ('\u{96}', "SPA", "abbreviation"),
+    ('\u{97}', "END OF GUARDED AREA", "control"),
+    ('\u{97}', "END OF PROTECTED AREA", "control"),
+    ('\u{97}', "EPA", "abbreviation"),
+    ('\u{98}', "START OF STRING", "control"),
+    ('\u{98}', "SOS", "abbreviation"),
+    ('\u{99}', "SINGLE GRAPHIC CHARACTER INTRODUCER", "figment"),
+    ('\u{99}', "SGC", "abbreviation"),
+    ('\u{9A}', "SINGLE CHARACTER INTRODUCER", "control"),
+    ('\u{9A}', "SCI", "abbreviation"),
+    ('\u{9B}', "CONTROL SEQUENCE INTRODUCER", "control"),
+    ('\u{9B}', "CSI", "abbreviation"),
+    ('\u{9C}', "STRING TERMINATOR", "control"),
+    ('\u{9C}', "ST", "abbreviation"),
+    ('\u{9D}', "OPERATING SYSTEM COMMAND", "control"),
+    ('\u{9D}', "OSC", "abbreviation"),
+    ('\u{9E}', "PRIVACY MESSAGE", "control"),
+    ('\u{9E}', "PM", "abbreviation"),
+    ('\u{9F}', "APPLICATION PROGRAM COMMAND", "control"),
+    ('\u{9F}', "APC", "abbreviation"),
+    ('\u{A0}', "NBSP", "abbreviation"),
+    ('\u{AD}', "SHY", "abbreviation"),
+    ('\u{1A2}', "LATIN CAPITAL LETTER GHA", "correction"),
+    ('\u{1A3}', "LATIN SMALL LETTER GHA", "correction"),
+    ('\u{34F}', "CGJ", "abbreviation"),
+    ('\u{616}', "ARABIC SMALL HIGH LIGATURE ALEF WITH YEH BARREE", "correction"),
+    ('\u{61C}', "ALM", "abbreviation"),
+    ('\u{709}', "SYRIAC SUBLINEAR COLON SKEWED LEFT", "correction"),
+    ('\u{CDE}', "KANNADA LETTER LLLA", "correction"),
+    ('\u{E9D}', "LAO LETTER FO FON", "correction"),
+    ('\u{E9F}', "LAO LETTER FO FAY", "correction"),
+    ('\u{EA3}', "LAO LETTER RO", "correction"),
+    ('\u{EA5}', "LAO LETTER LO", "correction"),
+    ('\u{FD0}', "TIBETAN MARK BKA- SHOG GI MGO RGYAN", "correction"),
+    ('\u{11EC}', "HANGUL JONGSEONG YESIEUNG-KIYEOK", "correction"),
+    ('\u{11ED}', "HANGUL JONGSEONG YESIEUNG-SSANGKIYEOK", "correction"),
+    ('\u{11EE}', "HANGUL JONGSEONG SSANGYESIEUNG", "correction"),
+    ('\u{11EF}', "HANGUL JONGSEONG YESIEUNG-KHIEUKH", "correction"),
+    ('\u{180B}', "FVS1", "abbreviation"),
+    ('\u{180C}', "FVS2", "abbreviation"),
+    ('\u{180D}', "FVS3", "abbreviation"),
+    ('\u{180E}', "MVS", "abbreviation"),
+    ('\u{180F}', "FVS4", "abbreviation"),
+    ('\u{1BBD}', "SUNDANESE LETTER ARCHAIC I", "correction"),
+    ('\u{200B}', "ZWSP", "abbreviation"),
+    ('\u{200C}', "ZWNJ", "abbreviation"),
+    ('\u{200D}', "ZWJ", "abbreviation"),
+    ('\u{200E}', "LRM", "abbreviation"),
+    ('\u{200F}', "RLM", "abbreviation"),
+    ('\u{202A}', "LRE", "abbreviation"),
+    ('\u{202B}', "RLE", "abbreviation"),
+    ('\u{202C}', "PDF", "abbreviation"),
+    ('\u{202D}', "LRO", "abbreviation"),
+    ('\u{202E}', "RLO", "abbreviation"),
+    ('\u{202F}', "NNBSP", "abbreviation"),
+    ('\u{205F}', "MMSP", "abbreviation"),
+    ('\u{2060}', "WJ", "abbreviation"),
+    ('\u{2066}', "LRI", "abbreviation"),
+    ('\u{2067}', "RLI", "abbreviation"),
+    ('\u{2068}', "FSI", "abbreviation"),
+    ('\u{2069}', "PDI", "abbreviation"),
+    ('\u{2118}', "WEIERSTRASS ELLIPTIC FUNCTION", "correction"),
+    ('\u{2448}', "MICR ON US SYMBOL", "correction"),
+    ('\u{2449}', "MICR DASH SYMBOL", "correction"),
+    ('\u{2B7A}', "LEFTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", "correction"),
+    ('\u{2B7C}', "RIGHTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", "correction"),
+    ('\u{A015}', "YI SYLLABLE ITERATION MARK", "correction"),
+    ('\u{AA6E}', "MYANMAR LETTER KHAMTI LLA", "correction"),
+    ('\u{FE00}', "VS1", "abbreviation"),
+    ('\u{FE01}', "VS2", "abbreviation"),
+    ('\u{FE02}', "VS3", "abbreviation"),
+    ('\u{FE03}', "VS4", "abbreviation"),
+    ('\u{FE04}', "VS5", "abbreviation"),
+    ('\u{FE05}', "VS6", "abbreviation"),
+    ('\u{FE06}', "VS7", "abbreviation"),
+    ('\u{FE07}', "VS8", "abbreviation"),
+    ('\u{FE08}', "VS9", "abbreviation"),
+    ('\u{FE09}', "VS10", "abbreviation"),
+    ('\u{FE0A}', "VS11", "abbreviation"),
+    ('\u{FE0B}', "VS12", "abbreviation"),
+    ('\u{FE0C}', "VS13", "abbreviation"),
+    ('\u{FE0D}', "VS14", "abbreviation"),
+    ('\u{FE0E}', "VS15", "abbreviation"),
+    ('\u{FE0F}', "VS16", "abbreviation"),
+    ('\u{FE18}', "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET", "correction"),
+    ('\u{FEFF}', "BYTE ORDER MARK", "alternate"),
+    ('\u{FEFF}', "BOM", "abbreviation"),
+    ('\u{FEFF}', "ZWNBSP", "abbreviation"),
+    ('\u{122D4}', "CUNEIFORM SIGN NU11 TENU", "correction"),
+    ('\u{122D5}', "CUNEIFORM SIGN NU11 OVER NU11 BUR OVER BUR", "correction"),
+    ('\u{12327}', "CUNEIFORM SIGN KALAM", "correction"),
+    ('\u{1680B}', "BAMUM LETTER PHASE-A MAEMGBIEE", "correction"),
+    ('\u{16E56}', "MEDEFAIDRIN CAPITAL LETTER H", "correction"),
+    ('\u{16E57}', "MEDEFAIDRIN CAPITAL LETTER NG", "correction"),
+    ('\u{16E76}', "MEDEFAIDRIN SMALL LETTER H", "correction"),
+    ('\u{16E77}', "MEDEFAIDRIN SMALL LETTER NG", "correction"),
+    ('\u{1B001}', "HENTAIGANA LETTER E-1", "correction"),
+    ('\u{1D0C5}', "BYZANTINE MUSICAL SYMBOL FTHORA SKLIRON CHROMA VASIS", "correction"),
+    ('\u{1E899}', "MENDE KIKAKUI SYLLABLE M172 MBO", "correction"),
+    ('\u{1E89A}', "MENDE KIKAKUI SYLLABLE M174 MBOO", "correction"),
+    ('\u{E0100}', "VS17", "abbreviation"),
+    ('\u{E0101}', "VS18", "abbreviation"),
+    ('\u{E0102}', "VS19", "abbreviation"),
+    ('\u{E0103}', "VS20", "abbreviation"),
+    ('\u{E0104}', "VS21", "abbreviation"),
+    ('\u{E0105}', "VS22", "abbreviation"),
+    ('\u{E0106}', "VS23", "abbreviation"),
+    ('\u{E0107}', "VS24", "abbreviation"),
+    ('\u{E0108}', "VS25", "abbreviation"),
+    ('\u{E0109}', "VS26", "abbreviation"),
+    ('\u{E010A}', "VS27", "abbreviation"),
+    ('\u{E010B}', "VS28", "abbreviation"),
+    ('\u{E010C}', "VS29", "abbreviation"),
+    ('\u{E010D}', "VS30", "abbreviation"),
+    ('\u{E010E}', "VS31", "abbreviation"),
+    ('\u{E010F}', "VS32", "abbreviation"),
+    ('\u{E0110}', "VS33", "abbreviation"),
+    ('\u{E0111}', "VS34", "abbreviation"),
+    ('\u{E0112}', "VS35", "abbreviation"),
+    ('\u{E0113}', "VS36", "abbreviation"),
+    ('\u{E0114}', "VS37", "abbreviation"),
+    ('\u{E0115}', "VS38", "abbreviation"),
+    ('\u{E0116}', "VS39", "abbreviation"),
+    ('\u{E0117}', "VS40", "abbreviation"),
+    ('\u{E0118}', "VS41", "abbreviation"),
+    ('\u{E0119}', "VS42", "abbreviation"),
+    ('\u{E011A}', "VS43", "abbreviation"),
+    ('\u{E011B}', "VS44", "abbreviation"),
+    ('\u{E011C}', "VS45", "abbreviation"),
+    ('\u{E011D}', "VS46", "abbreviation"),
+    ('\u{E011E}', "VS47", "abbreviation"),
+    ('\u{E011F}', "VS48", "abbreviation"),
+    ('\u{E0120}', "VS49", "abbreviation"),
+    ('\u{E0121}', "VS50", "abbreviation"),
+    ('\u{E0122}', "VS51", "abbreviation"),
+    ('\u{E0123}', "VS52", "abbreviation"),
+    ('\u{E0124}', "VS53", "abbreviation"),
+    ('\u{E0125}', "VS54", "abbreviation"),
+    ('\u{E0126}', "VS55", "abbreviation"),
+    ('\u{E0127}', "VS56", "abbreviation"),
+    ('\u{E0128}', "VS57", "abbreviation"),
+    ('\u{E0129}', "VS58", "abbreviation"),
+    ('\u{E012A}', "VS59", "abbreviation"),
+    ('\u{E012B}', "VS60", "abbreviation"),
+    ('\u{E012C}', "VS61", "abbreviation"),
+    ('\u{E012D}', "VS62", "abbreviation"),
+    ('\u{E012E}', "VS63", "abbreviation"),
+    ('\u{E012F}', "VS64", "abbreviation"),
+    ('\u{E0130}', "VS65", "abbreviation"),
+    ('\u{E0131}', "VS66", "abbreviation"),
+    ('\u{E0132}', "VS67", "abbreviation"),
+    ('\u{E0133}', "VS68", "abbreviation"),
+    ('\u{E0134}', "VS69", "abbreviation"),
+    ('\u{E0135}', "VS70", "abbreviation"),
+    ('\u{E0136}', "VS71", "abbreviation"),
+    ('\u{E0137}', "VS72", "abbreviation"),
+    ('\u{E0138}', "VS73", "abbreviation"),
+    ('\u{E0139}', "VS74", "abbreviation"),
+    ('\u{E013A}', "VS75", "abbreviation"),
+    ('\u{E013B}', "VS76", "abbreviation"),
+    ('\u{E013C}', "VS77", "abbreviation"),
+    ('\u{E013D}', "VS78", "abbreviation"),
+    ('\u{E013E}', "VS79", "abbreviation"),
+    ('\u{E013F}', "VS80", "abbreviation"),
+    ('\u{E0140}', "VS81", "abbreviation"),
+    ('\u{E0141}', "VS82", "abbreviation"),
+    ('\u{E0142}', "VS83", "abbreviation"),
+    ('\u{E0143}', "VS84", "abbreviation"),
+    ('\u{E0144}', "VS85", "abbreviation"),
+    ('\u{E0145}', "VS86", "abbreviation"),
+    ('\u{E0146}', "VS87", "abbreviation"),
+    ('\u{E0147}', "VS88", "abbreviation"),
+    ('\u{E0148}', "VS89", "abbreviation"),
+    ('\u{E0149}', "VS90", "abbreviation"),
+    ('\u{E014A}', "VS91", "abbreviation"),
+    ('\u{E014B}', "VS92", "abbreviation"),
+    ('\u{E014C}', "VS93", "abbreviation"),
+    ('\u{E014D}', "VS94", "abbreviation"),
+    ('\u{E014E}', "VS95", "abbreviation"),
+    ('\u{E014F}', "VS96", "abbreviation"),
+    ('\u{E0150}', "VS97", "abbreviation"),
+    ('\u{E0151}', "VS98", "abbreviation"),
+    ('\u{E0152}', "VS99", "abbreviation"),
+    ('\u{E0153}', "VS100", "abbreviation"),
+    ('\u{E0154}', "VS101", "abbreviation"),
+    ('\u{E0155}', "VS102", "abbreviation"),
+    ('\u{E0156}', "VS103", "abbreviation"),
+    ('\u{E0157}', "VS104", "abbreviation"),
+    ('\u{E0158}', "VS105", "abbreviation"),
+    ('\u{E0159}', "VS106", "abbreviation"),
+    ('\u{E015A}', "VS107", "abbreviation"),
+    ('\u{E015B}', "VS108", "abbreviation"),
+    ('\u{E015C}', "VS109", "abbreviation"),
+    ('\u{E015D}', "VS110", "abbreviation"),
+    ('\u{E015E}', "VS111", "abbreviation"),
+    ('\u{E015F}', "VS112", "abbreviation"),
+    ('\u{E0160}', "VS113", "abbreviation"),
+    ('\u{E0161}', "VS114", "abbreviation"),
+    ('\u{E0162}', "VS115", "abbreviation"),
+    ('\u{E0163}', "VS116", "abbreviation"),
+    ('\u{E0164}', "VS117", "abbreviation"),
+    ('\u{E0165}', "VS118", "abbreviation"),
+    ('\u{E0166}', "VS119", "abbreviation"),
+    ('\u{E0167}', "VS120", "abbreviation"),
+    ('\u{E0168}', "VS121", "abbreviation"),
+    ('\u{E0169}', "VS122", "abbreviation"),
+    ('\u{E016A}', "VS123", "abbreviation"),
+    ('\u{E016B}', "VS124", "abbreviation"),
+    ('\u{E016C}', "VS125", "abbreviation"),
+    ('\u{E016D}', "VS126", "abbreviation"),
+    ('\u{E016E}', "VS127", "abbreviation"),
+    ('\u{E016F}', "VS128", "abbreviation"),
+    ('\u{E0170}', "VS129", "abbreviation"),
+    ('\u{E0171}', "VS130", "abbreviation"),
+    ('\u{E0172}', "VS131", "abbreviation"),
+    ('\u{E0173}', "VS132", "abbreviation"),
+    ('\u{E0174}', "VS133", "abbreviation"),
+    ('\u{E0175}', "VS134", "abbreviation"),
+    ('\u{E0176}', "VS135", "abbreviation"),
+    ('\u{E0177}', "VS136", "abbreviation"),
+    ('\u{E0178}', "VS137", "abbreviation"),
+    ('\u{E0179}', "VS138", "abbreviation"),
+    ('\u{E017A}', "VS139", "abbreviation"),
+    ('\u{E017B}', "VS140", "abbreviation"),
+    ('\u{E017C}', "VS141", "abbreviation"),
+    ('\u{E017D}', "VS142", "abbreviation"),
+    ('\u{E017E}', "VS143", "abbreviation"),
+    ('\u{E017F}', "VS144", "abbreviation"),
+    ('\u{E0180}', "VS145", "abbreviation"),
+    ('\u{E0181}', "VS146", "abbreviation"),
+    ('\u{E0182}', "VS147", "abbreviation"),
+    ('\u{E0183}', "VS148", "abbreviation"),
+    ('\u{E0184}', "VS149", "abbreviation"),
+    ('\u{E0185}', "VS150", "abbreviation"),
+    ('\u{E0186}', "VS151", "abbreviation"),
+    ('\u{E0187}', "VS152", "abbreviation"),
+    ('\u{E0188}', "VS153", "abbreviation"),
+    ('\u{E0189}', "VS154", "abbreviation"),
+    ('\u{E018A}', "VS155", "abbreviation"),
+    ('\u{E018B}', "VS156", "abbreviation"),
+    ('\u{E018C}', "VS157", "abbreviation"),
+    ('\u{E018D}', "VS158", "abbreviation"),
+    ('\u{E018E}', "VS159", "abbreviation"),
+    ('\u{E018F}', "VS160", "abbreviation"),
+    ('\u{E0190}', "VS161", "abbreviation"),
+    ('\u{E0191}', "VS162", "abbreviation"),
+    ('\u{E0192}', "VS163", "abbreviation"),
+    ('\u{E0193}', "VS164", "abbreviation"),
+    ('\u{E0194}', "VS165", "abbreviation"),
+    ('\u{E0195}', "VS166", "abbreviation"),
+    ('\u{E0196}', "VS167", "abbreviation"),
+    ('\u{E0197}', "VS168", "abbreviation"),
+    ('\u{E0198}', "VS169", "abbreviation"),
+    ('\u{E0199}', "VS170", "abbreviation"),
+    ('\u{E019A}', "VS171", "abbreviation"),
+    ('\u{E019B}', "VS172", "abbreviation"),
+    ('\u{E019C}', "VS173", "abbreviation"),
+    ('\u{E019D}', "VS174", "abbreviation"),
+    ('\u{E019E}', "VS175", "abbreviation"),
+    ('\u{E019F}', "VS176", "abbreviation"),
+    ('\u{E01A0}', "VS177", "abbreviation"),
+    ('\u{E01A1}', "VS178", "abbreviation"),
+    ('\u{E01A2}', "VS179", "abbreviation"),
+    ('\u{E01A3}', "VS180", "abbreviation"),
+    ('\u{E01A4}', "VS181", "abbreviation"),
+    ('\u{E01A5}', "VS182", "abbreviation"),
+    ('\u{E01A6}', "VS183", "abbreviation"),
+    ('\u{E01A7}', "VS184", "abbreviation"),
+    ('\u{E01A8}', "VS185", "abbreviation"),
+    ('\u{E01A9}', "VS186", "abbreviation"),
+    ('\u{E01AA}', "VS187", "abbreviation"),
+    ('\u{E01AB}', "VS188", "abbreviation"),
+    ('\u{E01AC}', "VS189", "abbreviation"),
+    ('\u{E01AD}', "VS190", "abbreviation"),
+    ('\u{E01AE}', "VS191", "abbreviation"),
+    ('\u{E01AF}', "VS192", "abbreviation"),
+    ('\u{E01B0}', "VS193", "abbreviation"),
+    ('\u{E01B1}', "VS194", "abbreviation"),
+    ('\u{E01B2}', "VS195", "abbreviation"),
+    ('\u{E01B3}', "VS196", "abbreviation"),
+    ('\u{E01B4}', "VS197", "abbreviation"),
+    ('\u{E01B5}', "VS198", "abbreviation"),
+    ('\u{E01B6}', "VS199", "abbreviation"),
+    ('\u{E01B7}', "VS200", "abbreviation"),
+    ('\u{E01B8}', "VS201", "abbreviation"),
+    ('\u{E01B9}', "VS202", "abbreviation"),
+    ('\u{E01BA}', "VS203", "abbreviation"),
+    ('\u{E01BB}', "VS204", "abbreviation"),
+    ('\u{E01BC}', "VS205", "abbreviation"),
+    ('\u{E01BD}', "VS206", "abbreviation"),
+    ('\u{E01BE}', "VS207", "abbreviation"),
+    ('\u{E01BF}', "VS208", "abbreviation"),
+    ('\u{E01C0}', "VS209", "abbreviation"),
+    ('\u{E01C1}', "VS210", "abbreviation"),
+    ('\u{E01C2}', "VS211", "abbreviation"),
+    ('\u{E01C3}', "VS212", "abbreviation"),
+    ('\u{E01C4}', "VS213", "abbreviation"),
+    ('\u{E01C5}', "VS214", "abbreviation"),
+    ('\u{E01C6}', "VS215", "abbreviation"),
+    ('\u{E01C7}', "VS216", "abbreviation"),
+    ('\u{E01C8}', "VS217", "abbreviation"),
+    ('\u{E01C9}', "VS218", "abbreviation"),
+    ('\u{E01CA}', "VS219", "abbreviation"),
+    ('\u{E01CB}', "VS220", "abbreviation"),
+    ('\u{E01CC}', "VS221", "abbreviation"),
+    ('\u{E01CD}', "VS222", "abbreviation"),
+    ('\u{E01CE}', "VS223", "abbreviation"),
+    ('\u{E01CF}', "VS224", "abbreviation"),
+    ('\u{E01D0}', "VS225", "abbreviation"),
+    ('\u{E01D1}', "VS226", "abbreviation"),
+    ('\u{E01D2}', "VS227", "abbreviation"),
+    ('\u{E01D3}', "VS228", "abbreviation"),
+    ('\u{E01D4}', "VS229", "abbreviation"),
+    ('\u{E01D5}', "VS230", "abbreviation"),
+    ('\u{E01D6}', "VS231", "abbreviation"),
+    ('\u{E01D7}', "VS232", "abbreviation"),
+    ('\u{E01D8}', "VS233", "abbreviation"),
+    ('\u{E01D9}', "VS234", "abbreviation"),
+    ('\u{E01DA}', "VS235", "abbreviation"),
+    ('\u{E01DB}', "VS236", "abbreviation"),
+    ('\u{E01DC}', "VS237", "abbreviation"),
+    ('\u{E01DD}', "VS238", "abbreviation"),
+    ('\u{E01DE}', "VS239", "abbreviation"),
+    ('\u{E01DF}', "VS240", "abbreviation"),
+    ('\u{E01E0}', "VS241", "abbreviation"),
+    ('\u{E01E1}', "VS242", "abbreviation"),
+    ('\u{E01E2}', "VS243", "abbreviation"),
+    ('\u{E01E3}', "VS244", "abbreviation"),
+    ('\u{E01E4}', "VS245", "abbreviation"),
+    ('\u{E01E5}', "VS246", "abbreviation"),
+    ('\u{E01E6}', "VS247", "abbreviation"),
+    ('\u{E01E7}', "VS248", "abbreviation"),
+    ('\u{E01E8}', "VS249", "abbreviation"),
+    ('\u{E01E9}', "VS250", "abbreviation"),
+    ('\u{E01EA}', "VS251", "abbreviation"),
+    ('\u{E01EB}', "VS252", "abbreviation"),
+    ('\u{E01EC}', "VS253", "abbreviation"),
+    ('\u{E01ED}', "VS254", "abbreviation"),
+    ('\u{E01EE}', "VS255", "abbreviation"),
+    ('\u{E01EF}', "VS256", "abbreviation"),
+];
@@ -0,0 +1,7283 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+pub(crate) const CANONICAL_COMBINING_CLASS: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31A}', '\u{31A}', 232),
+    ('\u{31B}', '\u{31B}', 216),
+    ('\u{31C}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33C}', 220),
+    ('\u{33D}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34A}', '\u{34C}', 230),
+    ('\u{34D}', '\u{34E}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35A}', 220),
+    ('\u{35B}', '\u{35B}', 230),
+    ('\u{35C}', '\u{35C}', 233),
+    ('\u{35D}', '\u{35E}', 234),
+    ('\u{35F}', '\u{35F}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36F}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59A}', '\u{59A}', 222),
+    ('\u{59B}', '\u{59B}', 220),
+    ('\u{59C}', '\u{5A1}', 230),
+    ('\u{5A2}', '\u{5A7}', 220),
+    ('\u{5A8}', '\u{5A9}', 230),
+    ('\u{5AA}', '\u{5AA}', 220),
+    ('\u{5AB}', '\u{5AC}', 230),
+    ('\u{5AD}', '\u{5AD}', 222),
+    ('\u{5AE}', '\u{5AE}', 228),
+    ('\u{5AF}', '\u{5AF}', 230),
+    ('\u{5B0}', '\u{5B0}', 10),
+    ('\u{5B1}', '\u{5B1}', 11),
+    ('\u{5B2}', '\u{5B2}', 12),
+    ('\u{5B3}', '\u{5B3}', 13),
+    ('\u{5B4}', '\u{5B4}', 14),
+    ('\u{5B5}', '\u{5B5}', 15),
+    ('\u{5B6}', '\u{5B6}', 16),
+    ('\u{5B7}', '\u{5B7}', 17),
+    ('\u{5B8}', '\u{5B8}', 18),
+    ('\u{5B9}', '\u{5BA}', 19),
+    ('\u{5BB}', '\u{5BB}', 20),
+    ('\u{5BC}', '\u{5BC}', 21),
+    ('\u{5BD}', '\u{5BD}', 22),
+    ('\u{5BF}', '\u{5BF}', 23),
+    ('\u{5C1}', '\u{5C1}', 24),
+    ('\u{5C2}', '\u{5C2}', 25),
+    ('\u{5C4}', '\u{5C4}', 230),
+    ('\u{5C5}', '\u{5C5}', 220),
+    ('\u{5C7}', '\u{5C7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61A}', '\u{61A}', 32),
+    ('\u{64B}', '\u{64B}', 27),
+    ('\u{64C}', '\u{64C}', 28),
+    ('\u{64D}', '\u{64D}', 29),
+    ('\u{64E}', '\u{64E}', 30),
+    ('\u{64F}', '\u{64F}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65B}', 230),
+    ('\u{65C}', '\u{65C}', 220),
+    ('\u{65D}', '\u{65E}', 230),
+    ('\u{65F}', '\u{65F}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6D6}', '\u{6DC}', 230),
+    ('\u{6DF}', '\u{6E2}', 230),
+    ('\u{6E3}', '\u{6E3}', 220),
+    ('\u{6E4}', '\u{6E4}', 230),
+    ('\u{6E7}', '\u{6E8}', 230),
+    ('\u{6EA}', '\u{6EA}', 220),
+    ('\u{6EB}', '\u{6EC}', 230),
+    ('\u{6ED}', '\u{6ED}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73A}', '\u{73A}', 230),
+    ('\u{73B}', '\u{73C}', 220),
+    ('\u{73D}', '\u{73D}', 230),
+    ('\u{73E}', '\u{73E}', 220),
+    ('\u{73F}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74A}', 230),
+    ('\u{7EB}', '\u{7F1}', 230),
+    ('\u{7F2}', '\u{7F2}', 220),
+    ('\u{7F3}', '\u{7F3}', 230),
+    ('\u{7FD}', '\u{7FD}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81B}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82D}', 230),
+    ('\u{859}', '\u{85B}', 220),
+    ('\u{897}', '\u{898}', 230),
+    ('\u{899}', '\u{89B}', 220),
+    ('\u{89C}', '\u{89F}', 230),
+    ('\u{8CA}', '\u{8CE}', 230),
+    ('\u{8CF}', '\u{8D3}', 220),
+    ('\u{8D4}', '\u{8E1}', 230),
+    ('\u{8E3}', '\u{8E3}', 220),
+    ('\u{8E4}', '\u{8E5}', 230),
+    ('\u{8E6}', '\u{8E6}', 220),
+    ('\u{8E7}', '\u{8E8}', 230),
+    ('\u{8E9}', '\u{8E9}', 220),
+    ('\u{8EA}', '\u{8EC}', 230),
+    ('\u{8ED}', '\u{8EF}', 220),
+    ('\u{8F0}', '\u{8F0}', 27),
+    ('\u{8F1}', '\u{8F1}', 28),
+    ('\u{8F2}', '\u{8F2}', 29),
+    ('\u{8F3}', '\u{8F5}', 230),
+    ('\u{8F6}', '\u{8F6}', 220),
+    ('\u{8F7}', '\u{8F8}', 230),
+    ('\u{8F9}', '\u{8FA}', 220),
+    ('\u{8FB}', '\u{8FF}', 230),
+    ('\u{93C}', '\u{93C}', 7),
+    ('\u{94D}', '\u{94D}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9BC}', '\u{9BC}', 7),
+    ('\u{9CD}', '\u{9CD}', 9),
+    ('\u{9FE}', '\u{9FE}', 230),
+    ('\u{A3C}', '\u{A3C}', 7),
+    ('\u{A4D}', '\u{A4D}', 9),
+    ('\u{ABC}', '\u{ABC}', 7),
+    ('\u{ACD}', '\u{ACD}', 9),
+    ('\u{B3C}', '\u{B3C}', 7),
+    ('\u{B4D}', '\u{B4D}', 9),
+    ('\u{BCD}', '\u{BCD}', 9),
+    ('\u{C3C}', '\u{C3C}', 7),
+    ('\u{C4D}', '\u{C4D}', 9),
+    ('\u{C55}', '\u{C55}', 84),
+    ('\u{C56}', '\u{C56}', 91),
+    ('\u{CBC}', '\u{CBC}', 7),
+    ('\u{CCD}', '\u{CCD}', 9),
+    ('\u{D3B}', '\u{D3C}', 9),
+    ('\u{D4D}', '\u{D4D}', 9),
+    ('\u{DCA}', '\u{DCA}', 9),
+    ('\u{E38}', '\u{E39}', 103),
+    ('\u{E3A}', '\u{E3A}', 9),
+    ('\u{E48}', '\u{E4B}', 107),
+    ('\u{EB8}', '\u{EB9}', 118),
+    ('\u{EBA}', '\u{EBA}', 9),
+    ('\u{EC8}', '\u{ECB}', 122),
+    ('\u{F18}', '\u{F19}', 220),
+    ('\u{F35}', '\u{F35}', 220),
+    ('\u{F37}', '\u{F37}', 220),
+    ('\u{F39}', '\u{F39}', 216),
+    ('\u{F71}', '\u{F71}', 129),
+    ('\u{F72}', '\u{F72}', 130),
+    ('\u{F74}', '\u{F74}', 132),
+    ('\u{F7A}', '\u{F7D}', 130),
+    ('\u{F80}', '\u{F80}', 130),
+    ('\u{F82}', '\u{F83}', 230),
+    ('\u{F84}', '\u{F84}', 9),
+    ('\u{F86}', '\u{F87}', 230),
+    ('\u{FC6}', '\u{FC6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103A}', 9),
+    ('\u{108D}', '\u{108D}', 220),
+    ('\u{135D}', '\u{135F}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17D2}', '\u{17D2}', 9),
+    ('\u{17DD}', '\u{17DD}', 230),
+    ('\u{18A9}', '\u{18A9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193A}', '\u{193A}', 230),
+    ('\u{193B}', '\u{193B}', 220),
+    ('\u{1A17}', '\u{1A17}', 230),
+    ('\u{1A18}', '\u{1A18}', 220),
+    ('\u{1A60}', '\u{1A60}', 9),
+    ('\u{1A75}', '\u{1A7C}', 230),
+    ('\u{1A7F}', '\u{1A7F}', 220),
+    ('\u{1AB0}', '\u{1AB4}', 230),
+    ('\u{1AB5}', '\u{1ABA}', 220),
+    ('\u{1ABB}', '\u{1ABC}', 230),
+    ('\u{1ABD}', '\u{1ABD}', 220),
+    ('\u{1ABF}', '\u{1AC0}', 220),
+    ('\u{1AC1}', '\u{1AC2}', 230),
+    ('\u{1AC3}', '\u{1AC4}', 220),
+    ('\u{1AC5}', '\u{1AC9}', 230),
+    ('\u{1ACA}', '\u{1ACA}', 220),
+    ('\u{1ACB}', '\u{1ACE}', 230),
+    ('\u{1B34}', '\u{1B34}', 7),
+    ('\u{1B44}', '\u{1B44}', 9),
+    ('\u{1B6B}', '\u{1B6B}', 230),
+    ('\u{1B6C}', '\u{1B6C}', 220),
+    ('\u{1B6D}', '\u{1B73}', 230),
+    ('\u{1BAA}', '\u{1BAB}', 9),
+    ('\u{1BE6}', '\u{1BE6}', 7),
+    ('\u{1BF2}', '\u{1BF3}', 9),
+    ('\u{1C37}', '\u{1C37}', 7),
+    ('\u{1CD0}', '\u{1CD2}', 230),
+    ('\u{1CD4}', '\u{1CD4}', 1),
+    ('\u{1CD5}', '\u{1CD9}', 220),
+    ('\u{1CDA}', '\u{1CDB}', 230),
+    ('\u{1CDC}', '\u{1CDF}', 220),
+    ('\u{1CE0}', '\u{1CE0}', 230),
+    ('\u{1CE2}', '\u{1CE8}', 1),
+    ('\u{1CED}', '\u{1CED}', 220),
+    ('\u{1CF4}', '\u{1CF4}', 230),
+    ('\u{1CF8}', '\u{1CF9}', 230),
+    ('\u{1DC0}', '\u{1DC1}', 230),
+    ('\u{1DC2}', '\u{1DC2}', 220),
+    ('\u{1DC3}', '\u{1DC9}', 230),
+    ('\u{1DCA}', '\u{1DCA}', 220),
+    ('\u{1DCB}', '\u{1DCC}', 230),
+    ('\u{1DCD}', '\u{1DCD}', 234),
+    ('\u{1DCE}', '\u{1DCE}', 214),
+    ('\u{1DCF}', '\u{1DCF}', 220),
+    ('\u{1DD0}', '\u{1DD0}', 202),
+    ('\u{1DD1}', '\u{1DF5}', 230),
+    ('\u{1DF6}', '\u{1DF6}', 232),
+    ('\u{1DF7}', '\u{1DF8}', 228),
+    ('\u{1DF9}', '\u{1DF9}', 220),
+    ('\u{1DFA}', '\u{1DFA}', 218),
+    ('\u{1DFB}', '\u{1DFB}', 230),
+    ('\u{1DFC}', '\u{1DFC}', 233),
+    ('\u{1DFD}', '\u{1DFD}', 220),
+    ('\u{1DFE}', '\u{1DFE}', 230),
+    ('\u{1DFF}', '\u{1DFF}', 220),
+    ('\u{20D0}', '\u{20D1}', 230),
+    ('\u{20D2}', '\u{20D3}', 1),
+    ('\u{20D4}', '\u{20D7}', 230),
+    ('\u{20D8}', '\u{20DA}', 1),
+    ('\u{20DB}', '\u{20DC}', 230),
+    ('\u{20E1}', '\u{20E1}', 230),
+    ('\u{20E5}', '\u{20E6}', 1),
+    ('\u{20E7}', '\u{20E7}', 230),
+    ('\u{20E8}', '\u{20E8}', 220),
+    ('\u{20E9}', '\u{20E9}', 230),
+    ('\u{20EA}', '\u{20EB}', 1),
+    ('\u{20EC}', '\u{20EF}', 220),
+    ('\u{20F0}', '\u{20F0}', 230),
+    ('\u{2CEF}', '\u{2CF1}', 230),
+    ('\u{2D7F}', '\u{2D7F}', 9),
+    ('\u{2DE0}', '\u{2DFF}', 230),
+    ('\u{302A}', '\u{302A}', 218),
+    ('\u{302B}', '\u{302B}', 228),
+    ('\u{302C}', '\u{302C}', 232),
+    ('\u{302D}', '\u{302D}', 222),
+    ('\u{302E}', '\u{302F}', 224),
+    ('\u{3099}', '\u{309A}', 8),
+    ('\u{A66F}', '\u{A66F}', 230),
+    ('\u{A674}', '\u{A67D}', 230),
+    ('\u{A69E}', '\u{A69F}', 230),
+    ('\u{A6F0}', '\u{A6F1}', 230),
+    ('\u{A806}', '\u{A806}', 9),
+    ('\u{A82C}', '\u{A82C}', 9),
+    ('\u{A8C4}', '\u{A8C4}', 9),
+    ('\u{A8E0}', '\u{A8F1}', 230),
+    ('\u{A92B}', '\u{A92D}', 220),
+    ('\u{A953}', '\u{A953}', 9),
+    ('\u{A9B3}', '\u{A9B3}', 7),
+    ('\u{A9C0}', '\u{A9C0}', 9),
+    ('\u{AAB0}', '\u{AAB0}', 230),
+    ('\u{AAB2}', '\u{AAB3}', 230),
+    ('\u{AAB4}', '\u{AAB4}', 220),
+    ('\u{AAB7}', '\u{AAB8}', 230),
+    ('\u{AABE}', '\u{AABF}', 230),
+    ('\u{AAC1}', '\u{AAC1}', 230),
+    ('\u{AAF6}', '\u{AAF6}', 9),
+    ('\u{ABED}', '\u{ABED}', 9),
+    ('\u{FB1E}', '\u{FB1E}', 26),
+    ('\u{FE20}', '\u{FE26}', 230),
+    ('\u{FE27}', '\u{FE2D}', 220),
+    ('\u{FE2E}', '\u{FE2F}', 230),
+    ('\u{101FD}', '\u{101FD}', 220),
+    ('\u{102E0}', '\u{102E0}', 220),
+    ('\u{10376}', '\u{1037A}', 230),
+    ('\u{10A0D}', '\u{10A0D}', 220),
+    ('\u{10A0F}', '\u{10A0F}', 230),
+    ('\u{10A38}', '\u{10A38}', 230),
+    ('\u{10A39}', '\u{10A39}', 1),
+    ('\u{10A3A}', '\u{10A3A}', 220),
+    ('\u{10A3F}', '\u{10A3F}', 9),
+    ('\u{10AE5}', '\u{10AE5}', 230),
+    ('\u{10AE6}', '\u{10AE6}', 220),
+    ('\u{10D24}', '\u{10D27}', 230),
+    ('\u{10D69}', '\u{10D6D}', 230),
+    ('\u{10EAB}', '\u{10EAC}', 230),
+    ('\u{10EFD}', '\u{10EFF}', 220),
+    ('\u{10F46}', '\u{10F47}', 220),
+    ('\u{10F48}', '\u{10F4A}', 230),
+    ('\u{10F4B}', '\u{10F4B}', 220),
+    ('\u{10F4C}', '\u{10F4C}', 230),
+    ('\u{10F4D}', '\u{10F50}', 220),
+    ('\u{10F82}', '\u{10F82}', 230),
+    ('\u{10F83}', '\u{10F83}', 220),
+    ('\u{10F84}', '\u{10F84}', 230),
+    ('\u{10F85}', '\u{10F85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107F}', '\u{1107F}', 9),
+    ('\u{110B9}', '\u{110B9}', 9),
+    ('\u{110BA}', '\u{110BA}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111C0}', '\u{111C0}', 9),
+    ('\u{111CA}', '\u{111CA}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112E9}', '\u{112E9}', 7),
+    ('\u{112EA}', '\u{112EA}', 9),
+    ('\u{1133B}', '\u{1133C}', 7),
+    ('\u{1134D}', '\u{1134D}', 9),
+    ('\u{11366}', '\u{1136C}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{113CE}', '\u{113D0}', 9),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145E}', '\u{1145E}', 230),
+    ('\u{114C2}', '\u{114C2}', 9),
+    ('\u{114C3}', '\u{114C3}', 7),
+    ('\u{115BF}', '\u{115BF}', 9),
+    ('\u{115C0}', '\u{115C0}', 7),
+    ('\u{1163F}', '\u{1163F}', 9),
+    ('\u{116B6}', '\u{116B6}', 9),
+    ('\u{116B7}', '\u{116B7}', 7),
+    ('\u{1172B}', '\u{1172B}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183A}', '\u{1183A}', 7),
+    ('\u{1193D}', '\u{1193E}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119E0}', '\u{119E0}', 9),
+    ('\u{11A34}', '\u{11A34}', 9),
+    ('\u{11A47}', '\u{11A47}', 9),
+    ('\u{11A99}', '\u{11A99}', 9),
+    ('\u{11C3F}', '\u{11C3F}', 9),
+    ('\u{11D42}', '\u{11D42}', 7),
+    ('\u{11D44}', '\u{11D45}', 9),
+    ('\u{11D97}', '\u{11D97}', 9),
+    ('\u{11F41}', '\u{11F42}', 9),
+    ('\u{1612F}', '\u{1612F}', 9),
+    ('\u{16AF0}', '\u{16AF4}', 1),
+    ('\u{16B30}', '\u{16B36}', 230),
+    ('\u{16FF0}', '\u{16FF1}', 6),
+    ('\u{1BC9E}', '\u{1BC9E}', 1),
+    ('\u{1D165}', '\u{1D166}', 216),
+    ('\u{1D167}', '\u{1D169}', 1),
+    ('\u{1D16D}', '\u{1D16D}', 226),
+    ('\u{1D16E}', '\u{1D172}', 216),
+    ('\u{1D17B}', '\u{1D182}', 220),
+    ('\u{1D185}', '\u{1D189}', 230),
+    ('\u{1D18A}', '\u{1D18B}', 220),
+    ('\u{1D1AA}', '\u{1D1AD}', 230),
+    ('\u{1D242}', '\u{1D244}', 230),
+    ('\u{1E000}', '\u{1E006}', 230),
+    ('\u{1E008}', '\u{1E018}', 230),
+    ('\u{1E01B}', '\u{1E021}', 230),
+    ('\u{1E023}', '\u{1E024}', 230),
+    ('\u{1E026}', '\u{1E02A}', 230),
+    ('\u{1E08F}', '\u{1E08F}', 230),
+    ('\u{1E130}', '\u{1E136}', 230),
+    ('\u{1E2AE}', '\u{1E2AE}', 230),
+    ('\u{1E2EC}', '\u{1E2EF}', 230),
+    ('\u{1E4EC}', '\u{1E4ED}', 232),
+    ('\u{1E4EE}', '\u{1E4EE}', 220),
+    ('\u{1E4EF}', '\u{1E4EF}', 230),
+    ('\u{1E5EE}', '\u{1E5EE}', 230),
+    ('\u{1E5EF}', '\u{1E5EF}', 220),
+    ('\u{1E8D0}', '\u{1E8D6}', 220),
+    ('\u{1E944}', '\u{1E949}', 230),
+    ('\u{1E94A}', '\u{1E94A}', 7),
+];
+
+/// Single-level decomposition mappings: (character, is compatibility mapping, mapping)
+pub(crate) const DECOMPOSITION: &[(char, bool, &[char])] = &[
+    ('\u{A0}', true, &['\u{20}']),
+    ('\u{A8}', true, &['\u{20}', '\u{308}']),
+    ('\u{AA}', true, &['\u{61}']),
+    ('\u{AF}', true, &['\u{20}', '\u{304}']),
+    ('\u{B2}', true, &['\u{32}']),
+    ('\u{B3}', true, &['\u{33}']),
+    ('\u{B4}', true, &['\u{20}', '\u{301}']),
+    ('\u{B5}', true, &['\u{3BC}']),
+    ('\u{B8}', true, &['\u{20}', '\u{327}']),
+    ('\u{B9}', true, &['\u{31}']),
+    ('\u{BA}', true, &['\u{6F}']),
+    ('\u{BC}', true, &['\u{31}', '\u{2044}', '\u{34}']),
+    ('\u{BD}', true, &['\u{31}', '\u{2044}', '\u{32}']),
+    ('\u{BE}', true, &['\u{33}', '\u{2044}', '\u{34}']),
+    ('\u{C0}', false, &['\u{41}', '\u{300}']),
+    ('\u{C1}', false, &['\u{41}', '\u{301}']),
+    ('\u{C2}', false, &['\u{41}', '\u{302}']),
+    ('\u{C3}', false, &['\u{41}', '\u{303}']),
+    ('\u{C4}', false, &['\u{41}', '\u{308}']),
+    ('\u{C5}', false, &['\u{41}', '\u{30A}']),
+    ('\u{C7}', false, &['\u{43}', '\u{327}']),
+    ('\u{C8}', false, &['\u{45}', '\u{300}']),
+    ('\u{C9}', false, &['\u{45}', '\u{301}']),
+    ('\u{CA}', false, &['\u{45}', '\u{302}']),
+    ('\u{CB}', false, &['\u{45}', '\u{308}']),
+    ('\u{CC}', false, &['\u{49}', '\u{300}']),
+    ('\u{CD}', false, &['\u{49}', '\u{301}']),
+    ('\u{CE}', false, &['\u{49}', '\u{302}']),
+    ('\u{CF}', false, &['\u{49}', '\u{308}']),
+    ('\u{D1}', false, &['\u{4E}', '\u{303}']),
+    ('\u{D2}', false, &['\u{4F}', '\u{300}']),
+    ('\u{D3}', false, &['\u{4F}', '\u{301}']),
+    ('\u{D4}', false, &['\u{4F}', '\u{302}']),
+    ('\u{D5}', false, &['\u{4F}', '\u{303}']),
+    ('\u{D6}', false, &['\u{4F}', '\u{308}']),
+    ('\u{D9}', false, &['\u{55}', '\u{300}']),
+    ('\u{DA}', false, &['\u{55}', '\u{301}']),
+    ('\u{DB}', false, &['\u{55}', '\u{302}']),
+    ('\u{DC}', false, &['\u{55}', '\u{308}']),
+    ('\u{DD}', false, &['\u{59}', '\u{301}']),
+    ('\u{E0}', false, &['\u{61}', '\u{300}']),
+    ('\u{E1}', false, &['\u{61}', '\u{301}']),
+    ('\u{E2}', false, &['\u{61}', '\u{302}']),
+    ('\u{E3}', false, &['\u{61}', '\u{303}']),
+    ('\u{E4}', false, &['\u{61}', '\u{308}']),
+    ('\u{E5}', false, &['\u{61}', '\u{30A}']),
+    ('\u{E7}', false, &['\u{63}', '\u{327}']),
+    ('\u{E8}', false, &['\u{65}', '\u{300}']),
+    ('\u{E9}', false, &['\u{65}', '\u{301}']),
+    ('\u{EA}', false, &['\u{65}', '\u{302}']),
+    ('\u{EB}', false, &['\u{65}', '\u{308}']),
+    ('\u{EC}', false, &['\u{69}', '\u{300}']),
+    ('\u{ED}', false, &['\u{69}', '\u{301}']),
+    ('\u{EE}', false, &['\u{69}', '\u{302}']),
+    ('\u{EF}', false, &['\u{69}', '\u{308}']),
+    ('\u{F1}', false, &['\u{6E}', '\u{303}']),
+    ('\u{F2}', false, &['\u{6F}', '\u{300}']),
+    ('\u{F3}', false, &['\u{6F}', '\u{301}']),
+    ('\u{F4}', false, &['\u{6F}', '\u{302}']),
+    ('\u{F5}', false, &['\u{6F}', '\u{303}']),
+    ('\u{F6}', false, &['\u{6F}', '\u{308}']),
+    ('\u{F9}', false, &['\u{75}', '\u{300}']),
+    ('\u{FA}', false, &['\u{75}', '\u{301}']),
+    ('\u{FB}', false, &['\u{75}', '\u{302}']),
+    ('\u{FC}', false, &['\u{75}', '\u{308}']),
+    ('\u{FD}', false, &['\u{79}', '\u{301}']),
+    ('\u{FF}', false, &['\u{79}', '\u{308}']),
+    ('\u{100}', false, &['\u{41}', '\u{304}']),
+    ('\u{101}', false, &['\u{61}', '\u{304}']),
+    ('\u{102}', false, &['\u{41}', '\u{306}']),
+    ('\u{103}', false, &['\u{61}', '\u{306}']),
+    ('\u{104}', false, &['\u{41}', '\u{328}']),
+    ('\u{105}', false, &['\u{61}', '\u{328}']),
+    ('\u{106}', false, &['\u{43}', '\u{301}']),
+    ('\u{107}', false, &['\u{63}', '\u{301}']),
+    ('\u{108}', false, &['\u{43}', '\u{302}']),
+    ('\u{109}', false, &['\u{63}', '\u{302}']),
+    ('\u{10A}', false, &['\u{43}', '\u{307}']),
+    ('\u{10B}', false, &['\u{63}', '\u{307}']),
+    ('\u{10C}', false, &['\u{43}', '\u{30C}']),
+    ('\u{10D}', false, &['\u{63}', '\u{30C}']),
+    ('\u{10E}', false, &['\u{44}', '\u{30C}']),
+    ('\u{10F}', false, &['\u{64}', '\u{30C}']),
+    ('\u{112}', false, &['\u{45}', '\u{304}']),
+    ('\u{113}', false, &['\u{65}', '\u{304}']),
+    ('\u{114}', false, &['\u{45}', '\u{306}']),
+    ('\u{115}', false, &['\u{65}', '\u{306}']),
+    ('\u{116}', false, &['\u{45}', '\u{307}']),
+    ('\u{117}', false, &['\u{65}', '\u{307}']),
+    ('\u{118}', false, &['\u{45}', '\u{328}']),
+    ('\u{119}', false, &['\u{65}', '\u{328}']),
+    ('\u{11A}', false, &['\u{45}', '\u{30C}']),
+    ('\u{11B}', false, &['\u{65}', '\u{30C}']),
+    ('\u{11C}', false, &['\u{47}', '\u{302}']),
+    ('\u{11D}', false, &['\u{67}', '\u{302}']),
+    ('\u{11E}', false, &['\u{47}', '\u{306}']),
+    ('\u{11F}', false, &['\u{67}', '\u{306}']),
+    ('\u{120}', false, &['\u{47}', '\u{307}']),
+    ('\u{121}', false, &['\u{67}', '\u{307}']),
+    ('\u{122}', false, &['\u{47}', '\u{327}']),
+    ('\u{123}', false, &['\u{67}', '\u{327}']),
+    ('\u{124}', false, &['\u{48}', '\u{302}']),
+    ('\u{125}', false, &['\u{68}', '\u{302}']),
+    ('\u{128}', false, &['\u{49}', '\u{303}']),
+    ('\u{129}', false, &['\u{69}', '\u{303}']),
+    ('\u{12A}', false, &['\u{49}', '\u{304}']),
+    ('\u{12B}', false, &['\u{69}', '\u{304}']),
+    ('\u{12C}', false, &['\u{49}', '\u{306}']),
+    ('\u{12D}', false, &['\u{69}', '\u{306}']),
+    ('\u{12E}', false, &['\u{49}', '\u{328}']),
+    ('\u{12F}', false, &['\u{69}', '\u{328}']),
+    ('\u{130}', false, &['\u{49}', '\u{307}']),
+    ('\u{132}', true, &['\u{49}', '\u{4A}']),
+    ('\u{133}', true, &['\u{69}', '\u{6A}']),
+    ('\u{134}', false, &['\u{4A}', '\u{302}']),
+    ('\u{135}', false, &['\u{6A}', '\u{302}']),
+    ('\u{136}', false, &['\u{4B}', '\u{327}']),
+    ('\u{137}', false, &['\u{6B}', '\u{327}']),
+    ('\u{139}', false, &['\u{4C}', '\u{301}']),
+    ('\u{13A}', false, &['\u{6C}', '\u{301}']),
+    ('\u{13B}', false, &['\u{4C}', '\u{327}']),
+    ('\u{13C}', false, &['\u{6C}', '\u{327}']),
+    ('\u{13D}', false, &['\u{4C}', '\u{30C}']),
+    ('\u{13E}', false, &['\u{6C}', '\u{30C}']),
+    ('\u{13F}', true, &['\u{4C}', '\u{B7}']),
+    ('\u{140}', true, &['\u{6C}', '\u{B7}']),
+    ('\u{143}', false, &['\u{4E}', '\u{301}']),
+    ('\u{144}', false, &['\u{6E}', '\u{301}']),
+    ('\u{145}', false, &['\u{4E}', '\u{327}']),
+    ('\u{146}', false, &['\u{6E}', '\u{327}']),
+    ('\u{147}', false, &['\u{4E}', '\u{30C}']),
+    ('\u{148}', false, &['\u{6E}', '\u{30C}']),
+    ('\u{149}', true, &['\u{2BC}', '\u{6E}']),
+    ('\u{14C}', false, &['\u{4F}', '\u{304}']),
+    ('\u{14D}', false, &['\u{6F}', '\u{304}']),
+    ('\u{14E}', false, &['\u{4F}', '\u{306}']),
+    ('\u{14F}', false, &['\u{6F}', '\u{306}']),
+    ('\u{150}', false, &['\u{4F}', '\u{30B}']),
+    ('\u{151}', false, &['\u{6F}', '\u{30B}']),
+    ('\u{154}', false, &['\u{52}', '\u{301}']),
+    ('\u{155}', false, &['\u{72}', '\u{301}']),
+    ('\u{156}', false, &['\u{52}', '\u{327}']),
+    ('\u{157}', false, &['\u{72}', '\u{327}']),
+    ('\u{158}', false, &['\u{52}', '\u{30C}']),
+    ('\u{159}', false, &['\u{72}', '\u{30C}']),
+    ('\u{15A}', false, &['\u{53}', '\u{301}']),
+    ('\u{15B}', false, &['\u{73}', '\u{301}']),
+    ('\u{15C}', false, &['\u{53}', '\u{302}']),
+    ('\u{15D}', false, &['\u{73}', '\u{302}']),
+    ('\u{15E}', false, &['\u{53}', '\u{327}']),
+    ('\u{15F}', false, &['\u{73}', '\u{327}']),
+    ('\u{160}', false, &['\u{53}', '\u{30C}']),
+    ('\u{161}', false, &['\u{73}', '\u{30C}']),
+    ('\u{162}', false, &['\u{54}', '\u{327}']),
+    ('\u{163}', false, &['\u{74}', '\u{327}']),
+    ('\u{164}', false, &['\u{54}', '\u{30C}']),
+    ('\u{165}', false, &['\u{74}', '\u{30C}']),
+    ('\u{168}', false, &['\u{55}', '\u{303}']),
+    ('\u{169}', false, &['\u{75}', '\u{303}']),
+    ('\u{16A}', false, &['\u{55}', '\u{304}']),
+    ('\u{16B}', false, &['\u{75}', '\u{304}']),
+    ('\u{16C}', false, &['\u{55}', '\u{306}']),
+    ('\u{16D}', false, &['\u{75}', '\u{306}']),
+    ('\u{16E}', false, &['\u{55}', '\u{30A}']),
+    ('\u{16F}', false, &['\u{75}', '\u{30A}']),
+    ('\u{170}', false, &['\u{55}', '\u{30B}']),
+    ('\u{171}', false, &['\u{75}', '\u{30B}']),
+    ('\u{172}', false, &['\u{55}', '\u{328}']),
+    ('\u{173}', false, &['\u{75}', '\u{328}']),
+    ('\u{174}', false, &['\u{57}', '\u{302}']),
+    ('\u{175}', false, &['\u{77}', '\u{302}']),
+    ('\u{176}', false, &['\u{59}', '\u{302}']),
+    ('\u{177}', false, &['\u{79}', '\u{302}']),
+    ('\u{178}', false, &['\u{59}', '\u{308}']),
+    ('\u{179}', false, &['\u{5A}', '\u{301}']),
+    ('\u{17A}', false, &['\u{7A}', '\u{301}']),
+    ('\u{17B}', false, &['\u{5A}', '\u{307}']),
+    ('\u{17C}', false, &['\u{7A}', '\u{307}']),
+    ('\u{17D}', false, &['\u{5A}', '\u{30C}']),
+    ('\u{17E}', false, &['\u{7A}', '\u{30C}']),
+    ('\u{17F}', true, &['\u{73}']),
+    ('\u{1A0}', false, &['\u{4F}', '\u{31B}']),
+    ('\u{1A1}', false, &['\u{6F}', '\u{31B}']),
+    ('\u{1AF}', false, &['\u{55}', '\u{31B}']),
+    ('\u{1B0}', false, &['\u{75}', '\u{31B}']),
+    ('\u{1C4}', true, &['\u{44}', '\u{17D}']),
+    ('\u{1C5}', true, &['\u{44}', '\u{17E}']),
+    ('\u{1C6}', true, &['\u{64}', '\u{17E}']),
+    ('\u{1C7}', true, &['\u{4C}', '\u{4A}']),
+    ('\u{1C8}', true, &['\u{4C}', '\u{6A}']),
+    ('\u{1C9}', true, &['\u{6C}', '\u{6A}']),
+    ('\u{1CA}', true, &['\u{4E}', '\u{4A}']),
+    ('\u{1CB}', true, &['\u{4E}', '\u{6A}']),
+    ('\u{1CC}', true, &['\u{6E}', '\u{6A}']),
+    ('\u{1CD}', false, &['\u{41}', '\u{30C}']),
+    ('\u{1CE}', false, &['\u{61}', '\u{30C}']),
+    ('\u{1CF}', false, &['\u{49}', '\u{30C}']),
+    ('\u{1D0}', false, &['\u{69}', '\u{30C}']),
+    ('\u{1D1}', false, &['\u{4F}', '\u{30C}']),
+    ('\u{1D2}', false, &['\u{6F}', '\u{30C}']),
+    ('\u{1D3}', false, &['\u{55}', '\u{30C}']),
+    ('\u{1D4}', false, &['\u{75}', '\u{30C}']),
+    ('\u{1D5}', false, &['\u{DC}', '\u{304}']),
+    ('\u{1D6}', false, &['\u{FC}', '\u{304}']),
+    ('\u{1D7}', false, &['\u{DC}', '\u{301}']),
+    ('\u{1D8}', false, &['\u{FC}', '\u{301}']),
+    ('\u{1D9}', false, &['\u{DC}', '\u{30C}']),
+    ('\u{1DA}', false, &['\u{FC}', '\u{30C}']),
+    ('\u{1DB}', false, &['\u{DC}', '\u{300}']),
+    ('\u{1DC}', false, &['\u{FC}', '\u{300}']),
+    ('\u{1DE}', false, &['\u{C4}', '\u{304}']),
+    ('\u{1DF}', false, &['\u{E4}', '\u{304}']),
+    ('\u{1E0}', false, &['\u{226}', '\u{304}']),
+    ('\u{1E1}', false, &['\u{227}', '\u{304}']),
+    ('\u{1E2}', false, &['\u{C6}', '\u{304}']),
+    ('\u{1E3}', false, &['\u{E6}', '\u{304}']),
+    ('\u{1E6}', false, &['\u{47}', '\u{30C}']),
+    ('\u{1E7}', false, &['\u{67}', '\u{30C}']),
+    ('\u{1E8}', false, &['\u{4B}', '\u{30C}']),
+    ('\u{1E9}', false, &['\u{6B}', '\u{30C}']),
+    ('\u{1EA}', false, &['\u{4F}', '\u{328}']),
+    ('\u{1EB}', false, &['\u{6F}', '\u{328}']),
+    ('\u{1EC}', false, &['\u{1EA}', '\u{304}']),
+    ('\u{1ED}', false, &['\u{1EB}', '\u{304}']),
+    ('\u{1EE}', false, &['\u{1B7}', '\u{30C}']),
+    ('\u{1EF}', false, &['\u{292}', '\u{30C}']),
+    ('\u{1F0}', false, &['\u{6A}', '\u{30C}']),
+    ('\u{1F1}', true, &['\u{44}', '\u{5A}']),
+    ('\u{1F2}', true, &['\u{44}', '\u{7A}']),
+    ('\u{1F3}', true, &['\u{64}', '\u{7A}']),
+    ('\u{1F4}', false, &['\u{47}', '\u{301}']),
+    ('\u{1F5}', false, &['\u{67}', '\u{301}']),
+    ('\u{1F8}', false, &['\u{4E}', '\u{300}']),
+    ('\u{1F9}', false, &['\u{6E}', '\u{300}']),
+    ('\u{1FA}', false, &['\u{C5}', '\u{301}']),
+    ('\u{1FB}', false, &['\u{E5}', '\u{301}']),
+    ('\u{1FC}', false, &['\u{C6}', '\u{301}']),
+    ('\u{1FD}', false, &['\u{E6}', '\u{301}']),
+    ('\u{1FE}', false, &['\u{D8}', '\u{301}']),
+    ('\u{1FF}', false, &['\u{F8}', '\u{301}']),
+    ('\u{200}', false, &['\u{41}', '\u{30F}']),
+    ('\u{201}', false, &['\u{61}', '\u{30F}']),
+    ('\u{202}', false, &['\u{41}', '\u{311}']),
+    ('\u{203}', false, &['\u{61}', '\u{311}']),
+    ('\u{204}', false, &['\u{45}', '\u{30F}']),
+    ('\u{205}', false, &['\u{65}', '\u{30F}']),
+    ('\u{206}', false, &['\u{45}', '\u{311}']),
+    ('\u{207}', false, &['\u{65}', '\u{311}']),
+    ('\u{208}', false, &['\u{49}', '\u{30F}']),
+    ('\u{209}', false, &['\u{69}', '\u{30F}']),
+    ('\u{20A}', false, &['\u{49}', '\u{311}']),
+    ('\u{20B}', false, &['\u{69}', '\u{311}']),
+    ('\u{20C}', false, &['\u{4F}', '\u{30F}']),
+    ('\u{20D}', false, &['\u{6F}', '\u{30F}']),
+    ('\u{20E}', false, &['\u{4F}', '\u{311}']),
+    ('\u{20F}', false, &['\u{6F}', '\u{311}']),
+    ('\u{210}', false, &['\u{52}', '\u{30F}']),
+    ('\u{211}', false, &['\u{72}', '\u{30F}']),
+    ('\u{212}', false, &['\u{52}', '\u{311}']),
+    ('\u{213}', false, &['\u{72}', '\u{311}']),
+    ('\u{214}', false, &['\u{55}', '\u{30F}']),
+    ('\u{215}', false, &['\u{75}', '\u{30F}']),
+    ('\u{216}', false, &['\u{55}', '\u{311}']),
+    ('\u{217}', false, &['\u{75}', '\u{311}']),
+    ('\u{218}', false, &['\u{53}', '\u{326}']),
+    ('\u{219}', false, &['\u{73}', '\u{326}']),
+    ('\u{21A}', false, &['\u{54}', '\u{326}']),
+    ('\u{21B}', false, &['\u{74}', '\u{326}']),
+    ('\u{21E}', false, &['\u{48}', '\u{30C}']),
+    ('\u{21F}', false, &['\u{68}', '\u{30C}']),
+    ('\u{226}', false, &['\u{41}', '\u{307}']),
+    ('\u{227}', false, &['\u{61}', '\u{307}']),
+    ('\u{228}', false, &['\u{45}', '\u{327}']),
+    ('\u{229}', false, &['\u{65}', '\u{327}']),
+    ('\u{22A}', false, &['\u{D6}', '\u{304}']),
+    ('\u{22B}', false, &['\u{F6}', '\u{304}']),
+    ('\u{22C}', false, &['\u{D5}', '\u{304}']),
+    ('\u{22D}', false, &['\u{F5}', '\u{304}']),
+    ('\u{22E}', false, &['\u{4F}', '\u{307}']),
+    ('\u{22F}', false, &['\u{6F}', '\u{307}']),
+    ('\u{230}', false, &['\u{22E}', '\u{304}']),
+    ('\u{231}', false, &['\u{22F}', '\u{304}']),
+    ('\u{232}', false, &['\u{59}', '\u{304}']),
+    ('\u{233}', false, &['\u{79}', '\u{304}']),
+    ('\u{2B0}', true, &['\u{68}']),
+    ('\u{2B1}', true, &['\u{266}']),
+    ('\u{2B2}', true, &['\u{6A}']),
+    ('\u{2B3}', true, &['\u{72}']),
+    ('\u{2B4}', true, &['\u{279}']),
+    ('\u{2B5}', true, &['\u{27B}']),
+    ('\u{2B6}', true, &['\u{281}']),
+    ('\u{2B7}', true, &['\u{77}']),
+    ('\u{2B8}', true, &['\u{79}']),
+    ('\u{2D8}', true, &['\u{20}', '\u{306}']),
+    ('\u{2D9}', true, &['\u{20}', '\u{307}']),
+    ('\u{2DA}', true, &['\u{20}', '\u{30A}']),
+    ('\u{2DB}', true, &['\u{20}', '\u{328}']),
+    ('\u{2DC}', true, &['\u{20}', '\u{303}']),
+    ('\u{2DD}', true, &['\u{20}', '\u{30B}']),
+    ('\u{2E0}', true, &['\u{263}']),
+    ('\u{2E1}', true, &['\u{6C}']),
+    ('\u{2E2}', true, &['\u{73}']),
+    ('\u{2E3}', true, &['\u{78}']),
+    ('\u{2E4}', true, &['\u{295}']),
+    ('\u{340}', false, &['\u{300}']),
+    ('\u{341}', false, &['\u{301}']),
+    ('\u{343}', false, &['\u{313}']),
+    ('\u{344}', false, &['\u{308}', '\u{301}']),
+    ('\u{374}', false, &['\u{2B9}']),
+    ('\u{37A}', true, &['\u{20}', '\u{345}']),
+    ('\u{37E}', false, &['\u{3B}']),
+    ('\u{384}', true, &['\u{20}', '\u{301}']),
+    ('\u{385}', false, &['\u{A8}', '\u{301}']),
+    ('\u{386}', false, &['\u{391}', '\u{301}']),
+    ('\u{387}', false, &['\u{B7}']),
+    ('\u{388}', false, &['\u{395}', '\u{301}']),
+    ('\u{389}', false, &['\u{397}', '\u{301}']),
+    ('\u{38A}', false, &['\u{399}', '\u{301}']),
+    ('\u{38C}', false, &['\u{39F}', '\u{301}']),
+    ('\u{38E}', false, &['\u{3A5}', '\u{301}']),
+    ('\u{38F}', false, &['\u{3A9}', '\u{301}']),
+    ('\u{390}', false, &['\u{3CA}', '\u{301}']),
+    ('\u{3AA}', false, &['\u{399}', '\u{308}']),
+    ('\u{3AB}', false, &['\u{3A5}', '\u{308}']),
+    ('\u{3AC}', false, &['\u{3B1}', '\u{301}']),
+    ('\u{3AD}', false, &['\u{3B5}', '\u{301}']),
+    ('\u{3AE}', false, &['\u{3B7}', '\u{301}']),
+    ('\u{3AF}', false, &['\u{3B9}', '\u{301}']),
+    ('\u{3B0}', false, &['\u{3CB}', '\u{301}']),
+    ('\u{3CA}', false, &['\u{3B9}', '\u{308}']),
+    ('\u{3CB}', false, &['\u{3C5}', '\u{308}']),
+    ('\u{3CC}', false, &['\u{3BF}', '\u{301}']),
+    ('\u{3CD}', false, &['\u{3C5}', '\u{301}']),
+    ('\u{3CE}', false, &['\u{3C9}', '\u{301}']),
+    ('\u{3D0}', true, &['\u{3B2}']),
+    ('\u{3D1}', true, &['\u{3B8}']),
+    ('\u{3D2}', true, &['\u{3A5}']),
+    ('\u{3D3}', false, &['\u{3D2}', '\u{301}']),
+    ('\u{3D4}', false, &['\u{3D2}', '\u{308}']),
+    ('\u{3D5}', true, &['\u{3C6}']),
+    ('\u{3D6}', true, &['\u{3C0}']),
+    ('\u{3F0}', true, &['\u{3BA}']),
+    ('\u{3F1}', true, &['\u{3C1}']),
+    ('\u{3F2}', true, &['\u{3C2}']),
+    ('\u{3F4}', true, &['\u{398}']),
+    ('\u{3F5}', true, &['\u{3B5}']),
+    ('\u{3F9}', true, &['\u{3A3}']),
+    ('\u{400}', false, &['\u{415}', '\u{300}']),
+    ('\u{401}', false, &['\u{415}', '\u{308}']),
+    ('\u{403}', false, &['\u{413}', '\u{301}']),
+    ('\u{407}', false, &['\u{406}', '\u{308}']),
+    ('\u{40C}', false, &['\u{41A}', '\u{301}']),
+    ('\u{40D}', false, &['\u{418}', '\u{300}']),
+    ('\u{40E}', false, &['\u{423}', '\u{306}']),
+    ('\u{419}', false, &['\u{418}', '\u{306}']),
+    ('\u{439}', false, &['\u{438}', '\u{306}']),
+    ('\u{450}', false, &['\u{435}', '\u{300}']),
+    ('\u{451}', false, &['\u{435}', '\u{308}']),
+    ('\u{453}', false, &['\u{433}', '\u{301}']),
+    ('\u{457}', false, &['\u{456}', '\u{308}']),
+    ('\u{45C}', false, &['\u{43A}', '\u{301}']),
+    ('\u{45D}', false, &['\u{438}', '\u{300}']),
+    ('\u{45E}', false, &['\u{443}', '\u{306}']),
+    ('\u{476}', false, &['\u{474}', '\u{30F}']),
+    ('\u{477}', false, &['\u{475}', '\u{30F}']),
+    ('\u{4C1}', false, &['\u{416}', '\u{306}']),
+    ('\u{4C2}', false, &['\u{436}', '\u{306}']),
+    ('\u{4D0}', false, &['\u{410}', '\u{306}']),
+    ('\u{4D1}', false, &['\u{430}', '\u{306}']),
+    ('\u{4D2}', false, &['\u{410}', '\u{308}']),
+    ('\u{4D3}', false, &['\u{430}', '\u{308}']),
+    ('\u{4D6}', false, &['\u{415}', '\u{306}']),
+    ('\u{4D7}', false, &['\u{435}', '\u{306}']),
+    ('\u{4DA}', false, &['\u{4D8}', '\u{308}']),
+    ('\u{4DB}', false, &['\u{4D9}', '\u{308}']),
+    ('\u{4DC}', false, &['\u{416}', '\u{308}']),
+    ('\u{4DD}', false, &['\u{436}', '\u{308}']),
+    ('\u{4DE}', false, &['\u{417}', '\u{308}']),
+    ('\u{4DF}', false, &['\u{437}', '\u{308}']),
+    ('\u{4E2}', false, &['\u{418}', '\u{304}']),
+    ('\u{4E3}', false, &['\u{438}', '\u{304}']),
+    ('\u{4E4}', false, &['\u{418}', '\u{308}']),
+    ('\u{4E5}', false, &['\u{438}', '\u{308}']),
+    ('\u{4E6}', false, &['\u{41E}', '\u{308}']),
+    ('\u{4E7}', false, &['\u{43E}', '\u{308}']),
+    ('\u{4EA}', false, &['\u{4E8}', '\u{308}']),
+    ('\u{4EB}', false, &['\u{4E9}', '\u{308}']),
+    ('\u{4EC}', false, &['\u{42D}', '\u{308}']),
+    ('\u{4ED}', false, &['\u{44D}', '\u{308}']),
+    ('\u{4EE}', false, &['\u{423}', '\u{304}']),
+    ('\u{4EF}', false, &['\u{443}', '\u{304}']),
+    ('\u{4F0}', false, &['\u{423}', '\u{308}']),
+    ('\u{4F1}', false, &['\u{443}', '\u{308}']),
+    ('\u{4F2}', false, &['\u{423}', '\u{30B}']),
+    ('\u{4F3}', false, &['\u{443}', '\u{30B}']),
+    ('\u{4F4}', false, &['\u{427}', '\u{308}']),
+    ('\u{4F5}', false, &['\u{447}', '\u{308}']),
+    ('\u{4F8}', false, &['\u{42B}', '\u{308}']),
+    ('\u{4F9}', false, &['\u{44B}', '\u{308}']),
+    ('\u{587}', true, &['\u{565}', '\u{582}']),
+    ('\u{622}', false, &['\u{627}', '\u{653}']),
+    ('\u{623}', false, &['\u{627}', '\u{654}']),
+    ('\u{624}', false, &['\u{648}', '\u{654}']),
+    ('\u{625}', false, &['\u{627}', '\u{655}']),
+    ('\u{626}', false, &['\u{64A}', '\u{654}']),
+    ('\u{675}', true, &['\u{627}', '\u{674}']),
+    ('\u{676}', true, &['\u{648}', '\u{674}']),
+    ('\u{677}', true, &['\u{6C7}', '\u{674}']),
+    ('\u{678}', true, &['\u{64A}', '\u{674}']),
+    ('\u{6C0}', false, &['\u{6D5}', '\u{654}']),
+    ('\u{6C2}', false, &['\u{6C1}', '\u{654}']),
+    ('\u{6D3}', false, &['\u{6D2}', '\u{654}']),
+    ('\u{929}', false, &['\u{928}', '\u{93C}']),
+    ('\u{931}', false, &['\u{930}', '\u{93C}']),
+    ('\u{934}', false, &['\u{933}', '\u{93C}']),
+    ('\u{958}', false, &['\u{915}', '\u{93C}']),
+    ('\u{959}', false, &['\u{916}', '\u{93C}']),
+    ('\u{95A}', false, &['\u{917}', '\u{93C}']),
+    ('\u{95B}', false, &['\u{91C}', '\u{93C}']),
+    ('\u{95C}', false, &['\u{921}', '\u{93C}']),
+    ('\u{95D}', false, &['\u{922}', '\u{93C}']),
+    ('\u{95E}', false, &['\u{92B}', '\u{93C}']),
+    ('\u{95F}', false, &['\u{92F}', '\u{93C}']),
+    ('\u{9CB}', false, &['\u{9C7}', '\u{9BE}']),
+    ('\u{9CC}', false, &['\u{9C7}', '\u{9D7}']),
+    ('\u{9DC}', false, &['\u{9A1}', '\u{9BC}']),
+    ('\u{9DD}', false, &['\u{9A2}', '\u{9BC}']),
+    ('\u{9DF}', false, &['\u{9AF}', '\u{9BC}']),
+    ('\u{A33}', false, &['\u{A32}', '\u{A3C}']),
+    ('\u{A36}', false, &['\u{A38}', '\u{A3C}']),
+    ('\u{A59}', false, &['\u{A16}', '\u{A3C}']),
+    ('\u{A5A}', false, &['\u{A17}', '\u{A3C}']),
+    ('\u{A5B}', false, &['\u{A1C}', '\u{A3C}']),
+    ('\u{A5E}', false, &['\u{A2B}', '\u{A3C}']),
+    ('\u{B48}', false, &['\u{B47}', '\u{B56}']),
+    ('\u{B4B}', false, &['\u{B47}', '\u{B3E}']),
+    ('\u{B4C}', false, &['\u{B47}', '\u{B57}']),
+    ('\u{B5C}', false, &['\u{B21}', '\u{B3C}']),
+    ('\u{B5D}', false, &['\u{B22}', '\u{B3C}']),
+    ('\u{B94}', false, &['\u{B92}', '\u{BD7}']),
+    ('\u{BCA}', false, &['\u{BC6}', '\u{BBE}']),
+    ('\u{BCB}', false, &['\u{BC7}', '\u{BBE}']),
+    ('\u{BCC}', false, &['\u{BC6}', '\u{BD7}']),
+    ('\u{C48}', false, &['\u{C46}', '\u{C56}']),
+    ('\u{CC0}', false, &['\u{CBF}', '\u{CD5}']),
+    ('\u{CC7}', false, &['\u{CC6}', '\u{CD5}']),
+    ('\u{CC8}', false, &['\u{CC6}', '\u{CD6}']),
+    ('\u{CCA}', false, &['\u{CC6}', '\u{CC2}']),
+    ('\u{CCB}', false, &['\u{CCA}', '\u{CD5}']),
+    ('\u{D4A}', false, &['\u{D46}', '\u{D3E}']),
+    ('\u{D4B}', false, &['\u{D47}', '\u{D3E}']),
+    ('\u{D4C}', false, &['\u{D46}', '\u{D57}']),
+    ('\u{DDA}', false, &['\u{DD9}', '\u{DCA}']),
+    ('\u{DDC}', false, &['\u{DD9}', '\u{DCF}']),
+    ('\u{DDD}', false, &['\u{DDC}', '\u{DCA}']),
+    ('\u{DDE}', false, &['\u{DD9}', '\u{DDF}']),
+    ('\u{E33}', true, &['\u{E4D}', '\u{E32}']),
+    ('\u{EB3}', true, &['\u{ECD}', '\u{EB2}']),
+    ('\u{EDC}', true, &['\u{EAB}', '\u{E99}']),
+    ('\u{EDD}', true, &['\u{EAB}', '\u{EA1}']),
+    ('\u{F0C}', true, &['\u{F0B}']),
+    ('\u{F43}', false, &['\u{F42}', '\u{FB7}']),
+    ('\u{F4D}', false, &['\u{F4C}', '\u{FB7}']),
+    ('\u{F52}', false, &['\u{F51}', '\u{FB7}']),
+    ('\u{F57}', false, &['\u{F56}', '\u{FB7}']),
+    ('\u{F5C}', false, &['\u{F5B}', '\u{FB7}']),
+    ('\u{F69}', false, &['\u{F40}', '\u{FB5}']),
+    ('\u{F73}', false, &['\u{F71}', '\u{F72}']),
+    ('\u{F75}', false, &['\u{F71}', '\u{F74}']),
+    ('\u{F76}', false, &['\u{FB2}', '\u{F80}']),
+    ('\u{F77}', true, &['\u{FB2}', '\u{F81}']),
+    ('\u{F78}', false, &['\u{FB3}', '\u{F80}']),
+    ('\u{F79}', true, &['\u{FB3}', '\u{F81}']),
+    ('\u{F81}', false, &['\u{F71}', '\u{F80}']),
+    ('\u{F93}', false, &['\u{F92}', '\u{FB7}']),
+    ('\u{F9D}', false, &['\u{F9C}', '\u{FB7}']),
+    ('\u{FA2}', false, &['\u{FA1}', '\u{FB7}']),
+    ('\u{FA7}', false, &['\u{FA6}', '\u{FB7}']),
+    ('\u{FAC}', false, &['\u{FAB}', '\u{FB7}']),
+    ('\u{FB9}', false, &['\u{F90}', '\u{FB5}']),
+    ('\u{1026}', false, &['\u{1025}', '\u{102E}']),
+    ('\u{10FC}', true, &['\u{10DC}']),
+    ('\u{1B06}', false, &['\u{1B05}', '\u{1B35}']),
+    ('\u{1B08}', false, &['\u{1B07}', '\u{1B35}']),
+    ('\u{1B0A}', false, &['\u{1B09}', '\u{1B35}']),
+    ('\u{1B0C}', false, &['\u{1B0B}', '\u{1B35}']),
+    ('\u{1B0E}', false, &['\u{1B0D}', '\u{1B35}']),
+    ('\u{1B12}', false, &['\u{1B11}', '\u{1B35}']),
+    ('\u{1B3B}', false, &['\u{1B3A}', '\u{1B35}']),
+    ('\u{1B3D}', false, &['\u{1B3C}', '\u{1B35}']),
+    ('\u{1B40}', false, &['\u{1B3E}', '\u{1B35}']),
+    ('\u{1B41}', false, &['\u{1B3F}', '\u{1B35}']),
+    ('\u{1B43}', false, &['\u{1B42}', '\u{1B35}']),
+    ('\u{1D2C}', true, &['\u{41}']),
+    ('\u{1D2D}', true, &['\u{C6}']),
+    ('\u{1D2E}', true, &['\u{42}']),
+    ('\u{1D30}', true, &['\u{44}']),
+    ('\u{1D31}', true, &['\u{45}']),
+    ('\u{1D32}', true, &['\u{18E}']),
+    ('\u{1D33}', true, &['\u{47}']),
+    ('\u{1D34}', true, &['\u{48}']),
+    ('\u{1D35}', true, &['\u{49}']),
+    ('\u{1D36}', true, &['\u{4A}']),
+    ('\u{1D37}', true, &['\u{4B}']),
+    ('\u{1D38}', true, &['\u{4C}']),
+    ('\u{1D39}', true, &['\u{4D}']),
+    ('\u{1D3A}', true, &['\u{4E}']),
+    ('\u{1D3C}', true, &['\u{4F}']),
+    ('\u{1D3D}', true, &['\u{222}']),
+    ('\u{1D3E}', true, &['\u{50}']),
+    ('\u{1D3F}', true, &['\u{52}']),
+    ('\u{1D40}', true, &['\u{54}']),
+    ('\u{1D41}', true, &['\u{55}']),
+    ('\u{1D42}', true, &['\u{57}']),
+    ('\u{1D43}', true, &['\u{61}']),
+    ('\u{1D44}', true, &['\u{250}']),
+    ('\u{1D45}', true, &['\u{251}']),
+    ('\u{1D46}', true, &['\u{1D02}']),
+    ('\u{1D47}', true, &['\u{62}']),
+    ('\u{1D48}', true, &['\u{64}']),
+    ('\u{1D49}', true, &['\u{65}']),
+    ('\u{1D4A}', true, &['\u{259}']),
+    ('\u{1D4B}', true, &['\u{25B}']),
+    ('\u{1D4C}', true, &['\u{25C}']),
+    ('\u{1D4D}', true, &['\u{67}']),
+    ('\u{1D4F}', true, &['\u{6B}']),
+    ('\u{1D50}', true, &['\u{6D}']),
+    ('\u{1D51}', true, &['\u{14B}']),
+    ('\u{1D52}', true, &['\u{6F}']),
+    ('\u{1D53}', true, &['\u{254}']),
+    ('\u{1D54}', true, &['\u{1D16}']),
+    ('\u{1D55}', true, &['\u{1D17}']),
+    ('\u{1D56}', true, &['\u{70}']),
+    ('\u{1D57}', true, &['\u{74}']),
+    ('\u{1D58}', true, &['\u{75}']),
+    ('\u{1D59}', true, &['\u{1D1D}']),
+    ('\u{1D5A}', true, &['\u{26F}']),
+    ('\u{1D5B}', true, &['\u{76}']),
+    ('\u{1D5C}', true, &['\u{1D25}']),
+    ('\u{1D5D}', true, &['\u{3B2}']),
+    ('\u{1D5E}', true, &['\u{3B3}']),
+    ('\u{1D5F}', true, &['\u{3B4}']),
+    ('\u{1D60}', true, &['\u{3C6}']),
+    ('\u{1D61}', true, &['\u{3C7}']),
+    ('\u{1D62}', true, &['\u{69}']),
+    ('\u{1D63}', true, &['\u{72}']),
+    ('\u{1D64}', true, &['\u{75}']),
+    ('\u{1D65}', true, &['\u{76}']),
+    ('\u{1D66}', true, &['\u{3B2}']),
+    ('\u{1D67}', true, &['\u{3B3}']),
+    ('\u{1D68}', true, &['\u{3C1}']),
+    ('\u{1D69}', true, &['\u{3C6}']),
+    ('\u{1D6A}', true, &['\u{3C7}']),
+    ('\u{1D78}', true, &['\u{43D}']),
+    ('\u{1D9B}', true, &['\u{252}']),
+    ('\u{1D9C}', true, &['\u{63}']),
+    ('\u{1D9D}', true, &['\u{255}']),
+    ('\u{1D9E}', true, &['\u{F0}']),
+    ('\u{1D9F}', true, &['\u{25C}']),
+    ('\u{1DA0}', true, &['\u{66}']),
+    ('\u{1DA1}', true, &['\u{25F}']),
+    ('\u{1DA2}', true, &['\u{261}']),
+    ('\u{1DA3}', true, &['\u{265}']),
+    ('\u{1DA4}', true, &['\u{268}']),
+    ('\u{1DA5}', true, &['\u{269}']),
+    ('\u{1DA6}', true, &['\u{26A}']),
+    ('\u{1DA7}', true, &['\u{1D7B}']),
+    ('\u{1DA8}', true, &['\u{29D}']),
+    ('\u{1DA9}', true, &['\u{26D}']),
+    ('\u{1DAA}', true, &['\u{1D85}']),
+    ('\u{1DAB}', true, &['\u{29F}']),
+    ('\u{1DAC}', true, &['\u{271}']),
+    ('\u{1DAD}', true, &['\u{270}']),
+    ('\u{1DAE}', true, &['\u{272}']),
+    ('\u{1DAF}', true, &['\u{273}']),
+    ('\u{1DB0}', true, &['\u{274}']),
+    ('\u{1DB1}', true, &['\u{275}']),
+    ('\u{1DB2}', true, &['\u{278}']),
+    ('\u{1DB3}', true, &['\u{282}']),
+    ('\u{1DB4}', true, &['\u{283}']),
+    ('\u{1DB5}', true, &['\u{1AB}']),
+    ('\u{1DB6}', true, &['\u{289}']),
+    ('\u{1DB7}', true, &['\u{28A}']),
+    ('\u{1DB8}', true, &['\u{1D1C}']),
+    ('\u{1DB9}', true, &['\u{28B}']),
+    ('\u{1DBA}', true, &['\u{28C}']),
+    ('\u{1DBB}', true, &['\u{7A}']),
+    ('\u{1DBC}', true, &['\u{290}']),
+    ('\u{1DBD}', true, &['\u{291}']),
+    ('\u{1DBE}', true, &['\u{292}']),
+    ('\u{1DBF}', true, &['\u{3B8}']),
+    ('\u{1E00}', false, &['\u{41}', '\u{325}']),
+    ('\u{1E01}', false, &['\u{61}', '\u{325}']),
+    ('\u{1E02}', false, &['\u{42}', '\u{307}']),
+    ('\u{1E03}', false, &['\u{62}', '\u{307}']),
+    ('\u{1E04}', false, &['\u{42}', '\u{323}']),
+    ('\u{1E05}', false, &['\u{62}', '\u{323}']),
+    ('\u{1E06}', false, &['\u{42}', '\u{331}']),
+    ('\u{1E07}', false, &['\u{62}', '\u{331}']),
+    ('\u{1E08}', false, &['\u{C7}', '\u{301}']),
+    ('\u{1E09}', false, &['\u{E7}', '\u{301}']),
+    ('\u{1E0A}', false, &['\u{44}', '\u{307}']),
+    ('\u{1E0B}', false, &['\u{64}', '\u{307}']),
+    ('\u{1E0C}', false, &['\u{44}', '\u{323}']),
+    ('\u{1E0D}', false, &['\u{64}', '\u{323}']),
+    ('\u{1E0E}', false, &['\u{44}', '\u{331}']),
+    ('\u{1E0F}', false, &['\u{64}', '\u{331}']),
+    ('\u{1E10}', false, &['\u{44}', '\u{327}']),
+    ('\u{1E11}', false, &['\u{64}', '\u{327}']),
+    ('\u{1E12}', false, &['\u{44}', '\u{32D}']),
+    ('\u{1E13}', false, &['\u{64}', '\u{32D}']),
+    ('\u{1E14}', false, &['\u{112}', '\u{300}']),
+    ('\u{1E15}', false, &['\u{113}', '\u{300}']),
+    ('\u{1E16}', false, &['\u{112}', '\u{301}']),
+    ('\u{1E17}', false, &['\u{113}', '\u{301}']),
+    ('\u{1E18}', false, &['\u{45}', '\u{32D}']),
+    ('\u{1E19}', false, &['\u{65}', '\u{32D}']),
+    ('\u{1E1A}', false, &['\u{45}', '\u{330}']),
+    ('\u{1E1B}', false, &['\u{65}', '\u{330}']),
+    ('\u{1E1C}', false, &['\u{228}', '\u{306}']),
+    ('\u{1E1D}', false, &['\u{229}', '\u{306}']),
+    ('\u{1E1E}', false, &['\u{46}', '\u{307}']),
+    ('\u{1E1F}', false, &['\u{66}', '\u{307}']),
+    ('\u{1E20}', false, &['\u{47}', '\u{304}']),
+    ('\u{1E21}', false, &['\u{67}', '\u{304}']),
+    ('\u{1E22}', false, &['\u{48}', '\u{307}']),
+    ('\u{1E23}', false, &['\u{68}', '\u{307}']),
+    ('\u{1E24}', false, &['\u{48}', '\u{323}']),
+    ('\u{1E25}', false, &['\u{68}', '\u{323}']),
+    ('\u{1E26}', false, &['\u{48}', '\u{308}']),
+    ('\u{1E27}', false, &['\u{68}', '\u{308}']),
+    ('\u{1E28}', false, &['\u{48}', '\u{327}']),
+    ('\u{1E29}', false, &['\u{68}', '\u{327}']),
+    ('\u{1E2A}', false, &['\u{48}', '\u{32E}']),
+    ('\u{1E2B}', false, &['\u{68}', '\u{32E}']),
+    ('\u{1E2C}', false, &['\u{49}', '\u{330}']),
+    ('\u{1E2D}', false, &['\u{69}', '\u{330}']),
+    ('\u{1E2E}', false, &['\u{CF}', '\u{301}']),
+    ('\u{1E2F}', false, &['\u{EF}', '\u{301}']),
+    ('\u{1E30}', false, &['\u{4B}', '\u{301}']),
+    ('\u{1E31}', false, &['\u{6B}', '\u{301}']),
+    ('\u{1E32}', false, &['\u{4B}', '\u{323}']),
+    ('\u{1E33}', false, &['\u{6B}', '\u{323}']),
+    ('\u{1E34}', false, &['\u{4B}', '\u{331}']),
+    ('\u{1E35}', false, &['\u{6B}', '\u{331}']),
+    ('\u{1E36}', false, &['\u{4C}', '\u{323}']),
+    ('\u{1E37}', false, &['\u{6C}', '\u{323}']),
+    ('\u{1E38}', false, &['\u{1E36}', '\u{304}']),
+    ('\u{1E39}', false, &['\u{1E37}', '\u{304}']),
+    ('\u{1E3A}', false, &['\u{4C}', '\u{331}']),
+    ('\u{1E3B}', false, &['\u{6C}', '\u{331}']),
+    ('\u{1E3C}', false, &['\u{4C}', '\u{32D}']),
+    ('\u{1E3D}', false, &['\u{6C}', '\u{32D}']),
+    ('\u{1E3E}', false, &['\u{4D}', '\u{301}']),
+    ('\u{1E3F}', false, &['\u{6D}', '\u{301}']),
+    ('\u{1E40}', false, &['\u{4D}', '\u{307}']),
+    ('\u{1E41}', false, &['\u{6D}', '\u{307}']),
+    ('\u{1E42}', false, &['\u{4D}', '\u{323}']),
+    ('\u{1E43}', false, &['\u{6D}', '\u{323}']),
+    ('\u{1E44}', false, &['\u{4E}', '\u{307}']),
+    ('\u{1E45}', false, &['\u{6E}', '\u{307}']),
+    ('\u{1E46}', false, &['\u{4E}', '\u{323}']),
+    ('\u{1E47}', false, &['\u{6E}', '\u{323}']),
+    ('\u{1E48}', false, &['\u{4E}', '\u{331}']),
+    ('\u{1E49}', false, &['\u{6E}', '\u{331}']),
+    ('\u{1E4A}', false, &['\u{4E}', '\u{32D}']),
+    ('\u{1E4B}', false, &['\u{6E}', '\u{32D}']),
+    ('\u{1E4C}', false, &['\u{D5}', '\u{301}']),
+    ('\u{1E4D}', false, &['\u{F5}', '\u{301}']),
+    ('\u{1E4E}', false, &['\u{D5}', '\u{308}']),
+    ('\u{1E4F}', false, &['\u{F5}', '\u{308}']),
+    ('\u{1E50}', false, &['\u{14C}', '\u{300}']),
+    ('\u{1E51}', false, &['\u{14D}', '\u{300}']),
+    ('\u{1E52}', false, &['\u{14C}', '\u{301}']),
+    ('\u{1E53}', false, &['\u{14D}', '\u{301}']),
+    ('\u{1E54}', false, &['\u{50}', '\u{301}']),
+    ('\u{1E55}', false, &['\u{70}', '\u{301}']),
+    ('\u{1E56}', false, &['\u{50}', '\u{307}']),
+    ('\u{1E57}', false, &['\u{70}', '\u{307}']),
+    ('\u{1E58}', false, &['\u{52}', '\u{307}']),
+    ('\u{1E59}', false, &['\u{72}', '\u{307}']),
+    ('\u{1E5A}', false, &['\u{52}', '\u{323}']),
+    ('\u{1E5B}', false, &['\u{72}', '\u{323}']),
+    ('\u{1E5C}', false, &['\u{1E5A}', '\u{304}']),
+    ('\u{1E5D}', false, &['\u{1E5B}', '\u{304}']),
+    ('\u{1E5E}', false, &['\u{52}', '\u{331}']),
+    ('\u{1E5F}', false, &['\u{72}', '\u{331}']),
+    ('\u{1E60}', false, &['\u{53}', '\u{307}']),
+    ('\u{1E61}', false, &['\u{73}', '\u{307}']),
+    ('\u{1E62}', false, &['\u{53}', '\u{323}']),
+    ('\u{1E63}', false, &['\u{73}', '\u{323}']),
+    ('\u{1E64}', false, &['\u{15A}', '\u{307}']),
+    ('\u{1E65}', false, &['\u{15B}', '\u{307}']),
+    ('\u{1E66}', false, &['\u{160}', '\u{307}']),
+    ('\u{1E67}', false, &['\u{161}', '\u{307}']),
+    ('\u{1E68}', false, &['\u{1E62}', '\u{307}']),
+    ('\u{1E69}', false, &['\u{1E63}', '\u{307}']),
+    ('\u{1E6A}', false, &['\u{54}', '\u{307}']),
+    ('\u{1E6B}', false, &['\u{74}', '\u{307}']),
+    ('\u{1E6C}', false, &['\u{54}', '\u{323}']),
+    ('\u{1E6D}', false, &['\u{74}', '\u{323}']),
+    ('\u{1E6E}', false, &['\u{54}', '\u{331}']),
+    ('\u{1E6F}', false, &['\u{74}', '\u{331}']),
+    ('\u{1E70}', false, &['\u{54}', '\u{32D}']),
+    ('\u{1E71}', false, &['\u{74}', '\u{32D}']),
+    ('\u{1E72}', false, &['\u{55}', '\u{324}']),
+    ('\u{1E73}', false, &['\u{75}', '\u{324}']),
+    ('\u{1E74}', false, &['\u{55}', '\u{330}']),
+    ('\u{1E75}', false, &['\u{75}', '\u{330}']),
+    ('\u{1E76}', false, &['\u{55}', '\u{32D}']),
+    ('\u{1E77}', false, &['\u{75}', '\u{32D}']),
+    ('\u{1E78}', false, &['\u{168}', '\u{301}']),
+    ('\u{1E79}', false, &['\u{169}', '\u{301}']),
+    ('\u{1E7A}', false, &['\u{16A}', '\u{308}']),
+    ('\u{1E7B}', false, &['\u{16B}', '\u{308}']),
+    ('\u{1E7C}', false, &['\u{56}', '\u{303}']),
+    ('\u{1E7D}', false, &['\u{76}', '\u{303}']),
+    ('\u{1E7E}', false, &['\u{56}', '\u{323}']),
+    ('\u{1E7F}', false, &['\u{76}', '\u{323}']),
+    ('\u{1E80}', false, &['\u{57}', '\u{300}']),
+    ('\u{1E81}', false, &['\u{77}', '\u{300}']),
+    ('\u{1E82}', false, &['\u{57}', '\u{301}']),
+    ('\u{1E83}', false, &['\u{77}', '\u{301}']),
+    ('\u{1E84}', false, &['\u{57}', '\u{308}']),
+    ('\u{1E85}', false, &['\u{77}', '\u{308}']),
+    ('\u{1E86}', false, &['\u{57}', '\u{307}']),
+    ('\u{1E87}', false, &['\u{77}', '\u{307}']),
+    ('\u{1E88}', false, &['\u{57}', '\u{323}']),
+    ('\u{1E89}', false, &['\u{77}', '\u{323}']),
+    ('\u{1E8A}', false, &['\u{58}', '\u{307}']),
+    ('\u{1E8B}', false, &['\u{78}', '\u{307}']),
+    ('\u{1E8C}', false, &['\u{58}', '\u{308}']),
+    ('\u{1E8D}', false, &['\u{78}', '\u{308}']),
+    ('\u{1E8E}', false, &['\u{59}', '\u{307}']),
+    ('\u{1E8F}', false, &['\u{79}', '\u{307}']),
+    ('\u{1E90}', false, &['\u{5A}', '\u{302}']),
+    ('\u{1E91}', false, &['\u{7A}', '\u{302}']),
+    ('\u{1E92}', false, &['\u{5A}', '\u{323}']),
+    ('\u{1E93}', false, &['\u{7A}', '\u{323}']),
+    ('\u{1E94}', false, &['\u{5A}', '\u{331}']),
+    ('\u{1E95}', false, &['\u{7A}', '\u{331}']),
+    ('\u{1E96}', false, &['\u{68}', '\u{331}']),
+    ('\u{1E97}', false, &['\u{74}', '\u{308}']),
+    ('\u{1E98}', false, &['\u{77}', '\u{30A}']),
+    ('\u{1E99}', false, &['\u{79}', '\u{30A}']),
+    ('\u{1E9A}', true, &['\u{61}', '\u{2BE}']),
+    ('\u{1E9B}', false, &['\u{17F}', '\u{307}']),
+    ('\u{1EA0}', false, &['\u{41}', '\u{323}']),
+    ('\u{1EA1}', false, &['\u{61}', '\u{323}']),
+    ('\u{1EA2}', false, &['\u{41}', '\u{309}']),
+    ('\u{1EA3}', false, &['\u{61}', '\u{309}']),
+    ('\u{1EA4}', false, &['\u{C2}', '\u{301}']),
+    ('\u{1EA5}', false, &['\u{E2}', '\u{301}']),
+    ('\u{1EA6}', false, &['\u{C2}', '\u{300}']),
+    ('\u{1EA7}', false, &['\u{E2}', '\u{300}']),
+    ('\u{1EA8}', false, &['\u{C2}', '\u{309}']),
+    ('\u{1EA9}', false, &['\u{E2}', '\u{309}']),
+    ('\u{1EAA}', false, &['\u{C2}', '\u{303}']),
+    ('\u{1EAB}', false, &['\u{E2}', '\u{303}']),
+    ('\u{1EAC}', false, &['\u{1EA0}', '\u{302}']),
+    ('\u{1EAD}', false, &['\u{1EA1}', '\u{302}']),
+    ('\u{1EAE}', false, &['\u{102}', '\u{301}']),
+    ('\u{1EAF}', false, &['\u{103}', '\u{301}']),
+    ('\u{1EB0}', false, &['\u{102}', '\u{300}']),
+    ('\u{1EB1}', false, &['\u{103}', '\u{300}']),
+    ('\u{1EB2}', false, &['\u{102}', '\u{309}']),
+    ('\u{1EB3}', false, &['\u{103}', '\u{309}']),
+    ('\u{1EB4}', false, &['\u{102}', '\u{303}']),
+    ('\u{1EB5}', false, &['\u{103}', '\u{303}']),
+    ('\u{1EB6}', false, &['\u{1EA0}', '\u{306}']),
+    ('\u{1EB7}', false, &['\u{1EA1}', '\u{306}']),
+    ('\u{1EB8}', false, &['\u{45}', '\u{323}']),
+    ('\u{1EB9}', false, &['\u{65}', '\u{323}']),
+    ('\u{1EBA}', false, &['\u{45}', '\u{309}']),
+    ('\u{1EBB}', false, &['\u{65}', '\u{309}']),
+    ('\u{1EBC}', false, &['\u{45}', '\u{303}']),
+    ('\u{1EBD}', false, &['\u{65}', '\u{303}']),
+    ('\u{1EBE}', false, &['\u{CA}', '\u{301}']),
+    ('\u{1EBF}', false, &['\u{EA}', '\u{301}']),
+    ('\u{1EC0}', false, &['\u{CA}', '\u{300}']),
+    ('\u{1EC1}', false, &['\u{EA}', '\u{300}']),
+    ('\u{1EC2}', false, &['\u{CA}', '\u{309}']),
+    ('\u{1EC3}', false, &['\u{EA}', '\u{309}']),
+    ('\u{1EC4}', false, &['\u{CA}', '\u{303}']),
+    ('\u{1EC5}', false, &['\u{EA}', '\u{303}']),
+    ('\u{1EC6}', false, &['\u{1EB8}', '\u{302}']),
+    ('\u{1EC7}', false, &['\u{1EB9}', '\u{302}']),
+    ('\u{1EC8}', false, &['\u{49}', '\u{309}']),
+    ('\u{1EC9}', false, &['\u{69}', '\u{309}']),
+    ('\u{1ECA}', false, &['\u{49}', '\u{323}']),
+    ('\u{1ECB}', false, &['\u{69}', '\u{323}']),
+    ('\u{1ECC}', false, &['\u{4F}', '\u{323}']),
+    ('\u{1ECD}', false, &['\u{6F}', '\u{323}']),
+    ('\u{1ECE}', false, &['\u{4F}', '\u{309}']),
+    ('\u{1ECF}', false, &['\u{6F}', '\u{309}']),
+    ('\u{1ED0}', false, &['\u{D4}', '\u{301}']),
+    ('\u{1ED1}', false, &['\u{F4}', '\u{301}']),
+    ('\u{1ED2}', false, &['\u{D4}', '\u{300}']),
+    ('\u{1ED3}', false, &['\u{F4}', '\u{300}']),
+    ('\u{1ED4}', false, &['\u{D4}', '\u{309}']),
+    ('\u{1ED5}', false, &['\u{F4}', '\u{309}']),
+    ('\u{1ED6}', false, &['\u{D4}', '\u{303}']),
+    ('\u{1ED7}', false, &['\u{F4}', '\u{303}']),
+    ('\u{1ED8}', false, &['\u{1ECC}', '\u{302}']),
+    ('\u{1ED9}', false, &['\u{1ECD}', '\u{302}']),
+    ('\u{1EDA}', false, &['\u{1A0}', '\u{301}']),
+    ('\u{1EDB}', false, &['\u{1A1}', '\u{301}']),
+    ('\u{1EDC}', false, &['\u{1A0}', '\u{300}']),
+    ('\u{1EDD}', false, &['\u{1A1}', '\u{300}']),
+    ('\u{1EDE}', false, &['\u{1A0}', '\u{309}']),
+    ('\u{1EDF}', false, &['\u{1A1}', '\u{309}']),
+    ('\u{1EE0}', false, &['\u{1A0}', '\u{303}']),
+    ('\u{1EE1}', false, &['\u{1A1}', '\u{303}']),
+    ('\u{1EE2}', false, &['\u{1A0}', '\u{323}']),
+    ('\u{1EE3}', false, &['\u{1A1}', '\u{323}']),
+    ('\u{1EE4}', false, &['\u{55}', '\u{323}']),
+    ('\u{1EE5}', false, &['\u{75}', '\u{323}']),
+    ('\u{1EE6}', false, &['\u{55}', '\u{309}']),
+    ('\u{1EE7}', false, &['\u{75}', '\u{309}']),
+    ('\u{1EE8}', false, &['\u{1AF}', '\u{301}']),
+    ('\u{1EE9}', false, &['\u{1B0}', '\u{301}']),
+    ('\u{1EEA}', false, &['\u{1AF}', '\u{300}']),
+    ('\u{1EEB}', false, &['\u{1B0}', '\u{300}']),
+    ('\u{1EEC}', false, &['\u{1AF}', '\u{309}']),
+    ('\u{1EED}', false, &['\u{1B0}', '\u{309}']),
+    ('\u{1EEE}', false, &['\u{1AF}', '\u{303}']),
+    ('\u{1EEF}', false, &['\u{1B0}', '\u{303}']),
+    ('\u{1EF0}', false, &['\u{1AF}', '\u{323}']),
+    ('\u{1EF1}', false, &['\u{1B0}', '\u{323}']),
+    ('\u{1EF2}', false, &['\u{59}', '\u{300}']),
+    ('\u{1EF3}', false, &['\u{79}', '\u{300}']),
+    ('\u{1EF4}', false, &['\u{59}', '\u{323}']),
+    ('\u{1EF5}', false, &['\u{79}', '\u{323}']),
+    ('\u{1EF6}', false, &['\u{59}', '\u{309}']),
+    ('\u{1EF7}', false, &['\u{79}', '\u{309}']),
+    ('\u{1EF8}', false, &['\u{59}', '\u{303}']),
+    ('\u{1EF9}', false, &['\u{79}', '\u{303}']),
+    ('\u{1F00}', false, &['\u{3B1}', '\u{313}']),
+    ('\u{1F01}', false, &['\u{3B1}', '\u{314}']),
+    ('\u{1F02}', false, &['\u{1F00}', '\u{300}']),
+    ('\u{1F03}', false, &['\u{1F01}', '\u{300}']),
+    ('\u{1F04}', false, &['\u{1F00}', '\u{301}']),
+    ('\u{1F05}', false, &['\u{1F01}', '\u{301}']),
+    ('\u{1F06}', false, &['\u{1F00}', '\u{342}']),
+    ('\u{1F07}', false, &['\u{1F01}', '\u{342}']),
+    ('\u{1F08}', false, &['\u{391}', '\u{313}']),
+    ('\u{1F09}', false, &['\u{391}', '\u{314}']),
+    ('\u{1F0A}', false, &['\u{1F08}', '\u{300}']),
+    ('\u{1F0B}', false, &['\u{1F09}', '\u{300}']),
+    ('\u{1F0C}', false, &['\u{1F08}', '\u{301}']),
+    ('\u{1F0D}', false, &['\u{1F09}', '\u{301}']),
+    ('\u{1F0E}', false, &['\u{1F08}', '\u{342}']),
+    ('\u{1F0F}', false, &['\u{1F09}', '\u{342}']),
+    ('\u{1F10}', false, &['\u{3B5}', '\u{313}']),
+    ('\u{1F11}', false, &['\u{3B5}', '\u{314}']),
+    ('\u{1F12}', false, &['\u{1F10}', '\u{300}']),
+    ('\u{1F13}', false, &['\u{1F11}', '\u{300}']),
+    ('\u{1F14}', false, &['\u{1F10}', '\u{301}']),
+    ('\u{1F15}', false, &['\u{1F11}', '\u{301}']),
+    ('\u{1F18}', false, &['\u{395}', '\u{313}']),
+    ('\u{1F19}', false, &['\u{395}', '\u{314}']),
+    ('\u{1F1A}', false, &['\u{1F18}', '\u{300}']),
+    ('\u{1F1B}', false, &['\u{1F19}', '\u{300}']),
+    ('\u{1F1C}', false, &['\u{1F18}', '\u{301}']),
+    ('\u{1F1D}', false, &['\u{1F19}', '\u{301}']),
+    ('\u{1F20}', false, &['\u{3B7}', '\u{313}']),
+    ('\u{1F21}', false, &['\u{3B7}', '\u{314}']),
+    ('\u{1F22}', false, &['\u{1F20}', '\u{300}']),
+    ('\u{1F23}', false, &['\u{1F21}', '\u{300}']),
+    ('\u{1F24}', false, &['\u{1F20}', '\u{301}']),
+    ('\u{1F25}', false, &['\u{1F21}', '\u{301}']),
+    ('\u{1F26}', false, &['\u{1F20}', '\u{342}']),
+    ('\u{1F27}', false, &['\u{1F21}', '\u{342}']),
+    ('\u{1F28}', false, &['\u{397}', '\u{313}']),
+    ('\u{1F29}', false, &['\u{397}', '\u{314}']),
+    ('\u{1F2A}', false, &['\u{1F28}', '\u{300}']),
+    ('\u{1F2B}', false, &['\u{1F29}', '\u{300}']),
+    ('\u{1F2C}', false, &['\u{1F28}', '\u{301}']),
+    ('\u{1F2D}', false, &['\u{1F29}', '\u{301}']),
+    ('\u{1F2E}', false, &['\u{1F28}', '\u{342}']),
+    ('\u{1F2F}', false, &['\u{1F29}', '\u{342}']),
+    ('\u{1F30}', false, &['\u{3B9}', '\u{313}']),
+    ('\u{1F31}', false, &['\u{3B9}', '\u{314}']),
+    ('\u{1F32}', false, &['\u{1F30}', '\u{300}']),
+    ('\u{1F33}', false, &['\u{1F31}', '\u{300}']),
+    ('\u{1F34}', false, &['\u{1F30}', '\u{301}']),
+    ('\u{1F35}', false, &['\u{1F31}', '\u{301}']),
+    ('\u{1F36}', false, &['\u{1F30}', '\u{342}']),
+    ('\u{1F37}', false, &['\u{1F31}', '\u{342}']),
+    ('\u{1F38}', false, &['\u{399}', '\u{313}']),
+    ('\u{1F39}', false, &['\u{399}', '\u{314}']),
+    ('\u{1F3A}', false, &['\u{1F38}', '\u{300}']),
+    ('\u{1F3B}', false, &['\u{1F39}', '\u{300}']),
+    ('\u{1F3C}', false, &['\u{1F38}', '\u{301}']),
+    ('\u{1F3D}', false, &['\u{1F39}', '\u{301}']),
+    ('\u{1F3E}', false, &['\u{1F38}', '\u{342}']),
+    ('\u{1F3F}', false, &['\u{1F39}', '\u{342}']),
+    ('\u{1F40}', false, &['\u{3BF}', '\u{313}']),
+    ('\u{1F41}', false, &['\u{3BF}', '\u{314}']),
+    ('\u{1F42}', false, &['\u{1F40}', '\u{300}']),
+    ('\u{1F43}', false, &['\u{1F41}', '\u{300}']),
+    ('\u{1F44}', false, &['\u{1F40}', '\u{301}']),
+    ('\u{1F45}', false, &['\u{1F41}', '\u{301}']),
+    ('\u{1F48}', false, &['\u{39F}', '\u{313}']),
+    ('\u{1F49}', false, &['\u{39F}', '\u{314}']),
+    ('\u{1F4A}', false, &['\u{1F48}', '\u{300}']),
+    ('\u{1F4B}', false, &['\u{1F49}', '\u{300}']),
+    ('\u{1F4C}', false, &['\u{1F48}', '\u{301}']),
+    ('\u{1F4D}', false, &['\u{1F49}', '\u{301}']),
+    ('\u{1F50}', false, &['\u{3C5}', '\u{313}']),
+    ('\u{1F51}', false, &['\u{3C5}', '\u{314}']),
+    ('\u{1F52}', false, &['\u{1F50}', '\u{300}']),
+    ('\u{1F53}', false, &['\u{1F51}', '\u{300}']),
+    ('\u{1F54}', false, &['\u{1F50}', '\u{301}']),
+    ('\u{1F55}', false, &['\u{1F51}', '\u{301}']),
+    ('\u{1F56}', false, &['\u{1F50}', '\u{342}']),
+    ('\u{1F57}', false, &['\u{1F51}', '\u{342}']),
+    ('\u{1F59}', false, &['\u{3A5}', '\u{314}']),
+    ('\u{1F5B}', false, &['\u{1F59}', '\u{300}']),
+    ('\u{1F5D}', false, &['\u{1F59}', '\u{301}']),
+    ('\u{1F5F}', false, &['\u{1F59}', '\u{342}']),
+    ('\u{1F60}', false, &['\u{3C9}', '\u{313}']),
+    ('\u{1F61}', false, &['\u{3C9}', '\u{314}']),
+    ('\u{1F62}', false, &['\u{1F60}', '\u{300}']),
+    ('\u{1F63}', false, &['\u{1F61}', '\u{300}']),
+    ('\u{1F64}', false, &['\u{1F60}', '\u{301}']),
+    ('\u{1F65}', false, &['\u{1F61}', '\u{301}']),
+    ('\u{1F66}', false, &['\u{1F60}', '\u{342}']),
+    ('\u{1F67}', false, &['\u{1F61}', '\u{342}']),
+    ('\u{1F68}', false, &['\u{3A9}', '\u{313}']),
+    ('\u{1F69}', false, &['\u{3A9}', '\u{314}']),
+    ('\u{1F6A}', false, &['\u{1F68}', '\u{300}']),
+    ('\u{1F6B}', false, &['\u{1F69}', '\u{300}']),
+    ('\u{1F6C}', false, &['\u{1F68}', '\u{301}']),
+    ('\u{1F6D}', false, &['\u{1F69}', '\u{301}']),
+    ('\u{1F6E}', false, &['\u{1F68}', '\u{342}']),
+    ('\u{1F6F}', false, &['\u{1F69}', '\u{342}']),
+    ('\u{1F70}', false, &['\u{3B1}', '\u{300}']),
+    ('\u{1F71}', false, &['\u{3AC}']),
+    ('\u{1F72}', false, &['\u{3B5}', '\u{300}']),
+    ('\u{1F73}', false, &['\u{3AD}']),
+    ('\u{1F74}', false, &['\u{3B7}', '\u{300}']),
+    ('\u{1F75}', false, &['\u{3AE}']),
+    ('\u{1F76}', false, &['\u{3B9}', '\u{300}']),
+    ('\u{1F77}', false, &['\u{3AF}']),
+    ('\u{1F78}', false, &['\u{3BF}', '\u{300}']),
+    ('\u{1F79}', false, &['\u{3CC}']),
+    ('\u{1F7A}', false, &['\u{3C5}', '\u{300}']),
+    ('\u{1F7B}', false, &['\u{3CD}']),
+    ('\u{1F7C}', false, &['\u{3C9}', '\u{300}']),
+    ('\u{1F7D}', false, &['\u{3CE}']),
+    ('\u{1F80}', false, &['\u{1F00}', '\u{345}']),
+    ('\u{1F81}', false, &['\u{1F01}', '\u{345}']),
+    ('\u{1F82}', false, &['\u{1F02}', '\u{345}']),
+    ('\u{1F83}', false, &['\u{1F03}', '\u{345}']),
+    ('\u{1F84}', false, &['\u{1F04}', '\u{345}']),
+    ('\u{1F85}', false, &['\u{1F05}', '\u{345}']),
+    ('\u{1F86}', false, &['\u{1F06}', '\u{345}']),
+    ('\u{1F87}', false, &['\u{1F07}', '\u{345}']),
+    ('\u{1F88}', false, &['\u{1F08}', '\u{345}']),
+    ('\u{1F89}', false, &['\u{1F09}', '\u{345}']),
+    ('\u{1F8A}', false, &['\u{1F0A}', '\u{345}']),
+    ('\u{1F8B}', false, &['\u{1F0B}', '\u{345}']),
+    ('\u{1F8C}', false, &['\u{1F0C}', '\u{345}']),
+    ('\u{1F8D}', false, &['\u{1F0D}', '\u{345}']),
+    ('\u{1F8E}', false, &['\u{1F0E}', '\u{345}']),
+    ('\u{1F8F}', false, &['\u{1F0F}', '\u{345}']),
+    ('\u{1F90}', false, &['\u{1F20}', '\u{345}']),
+    ('\u{1F91}', false, &['\u{1F21}', '\u{345}']),
+    ('\u{1F92}', false, &['\u{1F22}', '\u{345}']),
+    ('\u{1F93}', false, &['\u{1F23}', '\u{345}']),
+    ('\u{1F94}', false, &['\u{1F24}', '\u{345}']),
+    ('\u{1F95}', false, &['\u{1F25}', '\u{345}']),
+    ('\u{1F96}', false, &['\u{1F26}', '\u{345}']),
+    ('\u{1F97}', false, &['\u{1F27}', '\u{345}']),
+    ('\u{1F98}', false, &['\u{1F28}', '\u{345}']),
+    ('\u{1F99}', false, &['\u{1F29}', '\u{345}']),
+    ('\u{1F9A}', false, &['\u{1F2A}', '\u{345}']),
+    ('\u{1F9B}', false, &['\u{1F2B}', '\u{345}']),
+    ('\u{1F9C}', false, &['\u{1F2C}', '\u{345}']),
+    ('\u{1F9D}', false, &['\u{1F2D}', '\u{345}']),
+    ('\u{1F9E}', false, &['\u{1F2E}', '\u{345}']),
+    ('\u{1F9F}', false, &['\u{1F2F}', '\u{345}']),
+    ('\u{1FA0}', false, &['\u{1F60}', '\u{345}']),
+    ('\u{1FA1}', false, &['\u{1F61}', '\u{345}']),
+    ('\u{1FA2}', false, &['\u{1F62}', '\u{345}']),
+    ('\u{1FA3}', false, &['\u{1F63}', '\u{345}']),
+    ('\u{1FA4}', false, &['\u{1F64}', '\u{345}']),
+    ('\u{1FA5}', false, &['\u{1F65}', '\u{345}']),
+    ('\u{1FA6}', false, &['\u{1F66}', '\u{345}']),
+    ('\u{1FA7}', false, &['\u{1F67}', '\u{345}']),
+    ('\u{1FA8}', false, &['\u{1F68}', '\u{345}']),
+    ('\u{1FA9}', false, &['\u{1F69}', '\u{345}']),
+    ('\u{1FAA}', false, &['\u{1F6A}', '\u{345}']),
+    ('\u{1FAB}', false, &['\u{1F6B}', '\u{345}']),
+    ('\u{1FAC}', false, &['\u{1F6C}', '\u{345}']),
+    ('\u{1FAD}', false, &['\u{1F6D}', '\u{345}']),
+    ('\u{1FAE}', false, &['\u{1F6E}', '\u{345}']),
+    ('\u{1FAF}', false, &['\u{1F6F}', '\u{345}']),
+    ('\u{1FB0}', false, &['\u{3B1}', '\u{306}']),
+    ('\u{1FB1}', false, &['\u{3B1}', '\u{304}']),
+    ('\u{1FB2}', false, &['\u{1F70}', '\u{345}']),
+    ('\u{1FB3}', false, &['\u{3B1}', '\u{345}']),
+    ('\u{1FB4}', false, &['\u{3AC}', '\u{345}']),
+    ('\u{1FB6}', false, &['\u{3B1}', '\u{342}']),
+    ('\u{1FB7}', false, &['\u{1FB6}', '\u{345}']),
+    ('\u{1FB8}', false, &['\u{391}', '\u{306}']),
+    ('\u{1FB9}', false, &['\u{391}', '\u{304}']),
+    ('\u{1FBA}', false, &['\u{391}', '\u{300}']),
+    ('\u{1FBB}', false, &['\u{386}']),
+    ('\u{1FBC}', false, &['\u{391}', '\u{345}']),
+    ('\u{1FBD}', true, &['\u{20}', '\u{313}']),
+    ('\u{1FBE}', false, &['\u{3B9}']),
+    ('\u{1FBF}', true, &['\u{20}', '\u{313}']),
+    ('\u{1FC0}', true, &['\u{20}', '\u{342}']),
+    ('\u{1FC1}', false, &['\u{A8}', '\u{342}']),
+    ('\u{1FC2}', false, &['\u{1F74}', '\u{345}']),
+    ('\u{1FC3}', false, &['\u{3B7}', '\u{345}']),
+    ('\u{1FC4}', false, &['\u{3AE}', '\u{345}']),
+    ('\u{1FC6}', false, &['\u{3B7}', '\u{342}']),
+    ('\u{1FC7}', false, &['\u{1FC6}', '\u{345}']),
+    ('\u{1FC8}', false, &['\u{395}', '\u{300}']),
+    ('\u{1FC9}', false, &['\u{388}']),
+    ('\u{1FCA}', false, &['\u{397}', '\u{300}']),
+    ('\u{1FCB}', false, &['\u{389}']),
+    ('\u{1FCC}', false, &['\u{397}', '\u{345}']),
+    ('\u{1FCD}', false, &['\u{1FBF}', '\u{300}']),
+    ('\u{1FCE}', false, &['\u{1FBF}', '\u{301}']),
+    ('\u{1FCF}', false, &['\u{1FBF}', '\u{342}']),
+    ('\u{1FD0}', false, &['\u{3B9}', '\u{306}']),
+    ('\u{1FD1}', false, &['\u{3B9}', '\u{304}']),
+    ('\u{1FD2}', false, &['\u{3CA}', '\u{300}']),
+    ('\u{1FD3}', false, &['\u{390}']),
+    ('\u{1FD6}', false, &['\u{3B9}', '\u{342}']),
+    ('\u{1FD7}', false, &['\u{3CA}', '\u{342}']),
+    ('\u{1FD8}', false, &['\u{399}', '\u{306}']),
+    ('\u{1FD9}', false, &['\u{399}', '\u{304}']),
+    ('\u{1FDA}', false, &['\u{399}', '\u{300}']),
+    ('\u{1FDB}', false, &['\u{38A}']),
+    ('\u{1FDD}', false, &['\u{1FFE}', '\u{300}']),
+    ('\u{1FDE}', false, &['\u{1FFE}', '\u{301}']),
+    ('\u{1FDF}', false, &['\u{1FFE}', '\u{342}']),
+    ('\u{1FE0}', false, &['\u{3C5}', '\u{306}']),
+    ('\u{1FE1}', false, &['\u{3C5}', '\u{304}']),
+    ('\u{1FE2}', false, &['\u{3CB}', '\u{300}']),
+    ('\u{1FE3}', false, &['\u{3B0}']),
+    ('\u{1FE4}', false, &['\u{3C1}', '\u{313}']),
+    ('\u{1FE5}', false, &['\u{3C1}', '\u{314}']),
+    ('\u{1FE6}', false, &['\u{3C5}', '\u{342}']),
+    ('\u{1FE7}', false, &['\u{3CB}', '\u{342}']),
+    ('\u{1FE8}', false, &['\u{3A5}', '\u{306}']),
+    ('\u{1FE9}', false, &['\u{3A5}', '\u{304}']),
+    ('\u{1FEA}', false, &['\u{3A5}', '\u{300}']),
+    ('\u{1FEB}', false, &['\u{38E}']),
+    ('\u{1FEC}', false, &['\u{3A1}', '\u{314}']),
+    ('\u{1FED}', false, &['\u{A8}', '\u{300}']),
+    ('\u{1FEE}', false, &['\u{385}']),
+    ('\u{1FEF}', false, &['\u{60}']),
+    ('\u{1FF2}', false, &['\u{1F7C}', '\u{345}']),
+    ('\u{1FF3}', false, &['\u{3C9}', '\u{345}']),
+    ('\u{1FF4}', false, &['\u{3CE}', '\u{345}']),
+    ('\u{1FF6}', false, &['\u{3C9}', '\u{342}']),
+    ('\u{1FF7}', false, &['\u{1FF6}', '\u{345}']),
+    ('\u{1FF8}', false, &['\u{39F}', '\u{300}']),
+    ('\u{1FF9}', false, &['\u{38C}']),
+    ('\u{1FFA}', false, &['\u{3A9}', '\u{300}']),
+    ('\u{1FFB}', false, &['\u{38F}']),
+    ('\u{1FFC}', false, &['\u{3A9}', '\u{345}']),
+    ('\u{1FFD}', false, &['\u{B4}']),
+    ('\u{1FFE}', true, &['\u{20}', '\u{314}']),
+    ('\u{2000}', false, &['\u{2002}']),
+    ('\u{2001}', false, &['\u{2003}']),
+    ('\u{2002}', true, &['\u{20}']),
+    ('\u{2003}', true, &['\u{20}']),
+    ('\u{2004}', true, &['\u{20}']),
+    ('\u{2005}', true, &['\u{20}']),
+    ('\u{2006}', true, &['\u{20}']),
+    ('\u{2007}', true, &['\u{20}']),
+    ('\u{2008}', true, &['\u{20}']),
+    ('\u{2009}', true, &['\u{20}']),
+    ('\u{200A}', true, &['\u{20}']),
+    ('\u{2011}', true, &['\u{2010}']),
+    ('\u{2017}', true, &['\u{20}', '\u{333}']),
+    ('\u{2024}', true, &['\u{2E}']),
+    ('\u{2025}', true, &['\u{2E}', '\u{2E}']),
+    ('\u{2026}', true, &['\u{2E}', '\u{2E}', '\u{2E}']),
+    ('\u{202F}', true, &['\u{20}']),
+    ('\u{2033}', true, &['\u{2032}', '\u{2032}']),
+    ('\u{2034}', true, &['\u{2032}', '\u{2032}', '\u{2032}']),
+    ('\u{2036}', true, &['\u{2035}', '\u{2035}']),
+    ('\u{2037}', true, &['\u{2035}', '\u{2035}', '\u{2035}']),
+    ('\u{203C}', true, &['\u{21}', '\u{21}']),
+    ('\u{203E}', true, &['\u{20}', '\u{305}']),
+    ('\u{2047}', true, &['\u{3F}', '\u{3F}']),
+    ('\u{2048}', true, &['\u{3F}', '\u{21}']),
+    ('\u{2049}', true, &['\u{21}', '\u{3F}']),
+    ('\u{2057}', true, &['\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}']),
+    ('\u{205F}', true, &['\u{20}']),
+    ('\u{2070}', true, &['\u{30}']),
+    ('\u{2071}', true, &['\u{69}']),
+    ('\u{2074}', true, &['\u{34}']),
+    ('\u{2075}', true, &['\u{35}']),
+    ('\u{2076}', true, &['\u{36}']),
+    ('\u{2077}', true, &['\u{37}']),
+    ('\u{2078}', true, &['\u{38}']),
+    ('\u{2079}', true, &['\u{39}']),
+    ('\u{207A}', true, &['\u{2B}']),
+    ('\u{207B}', true, &['\u{2212}']),
+    ('\u{207C}', true, &['\u{3D}']),
+    ('\u{207D}', true, &['\u{28}']),
+    ('\u{207E}', true, &['\u{29}']),
+    ('\u{207F}', true, &['\u{6E}']),
+    ('\u{2080}', true, &['\u{30}']),
+    ('\u{2081}', true, &['\u{31}']),
+    ('\u{2082}', true, &['\u{32}']),
+    ('\u{2083}', true, &['\u{33}']),
+    ('\u{2084}', true, &['\u{34}']),
+    ('\u{2085}', true, &['\u{35}']),
+    ('\u{2086}', true, &['\u{36}']),
+    ('\u{2087}', true, &['\u{37}']),
+    ('\u{2088}', true, &['\u{38}']),
+    ('\u{2089}', true, &['\u{39}']),
+    ('\u{208A}', true, &['\u{2B}']),
+    ('\u{208B}', true, &['\u{2212}']),
+    ('\u{208C}', true, &['\u{3D}']),
+    ('\u{208D}', true, &['\u{28}']),
+    ('\u{208E}', true, &['\u{29}']),
+    ('\u{2090}', true, &['\u{61}']),
+    ('\u{2091}', true, &['\u{65}']),
+    ('\u{2092}', true, &['\u{6F}']),
+    ('\u{2093}', true, &['\u{78}']),
+    ('\u{2094}', true, &['\u{259}']),
+    ('\u{2095}', true, &['\u{68}']),
+    ('\u{2096}', true, &['\u{6B}']),
+    ('\u{2097}', true, &['\u{6C}']),
+    ('\u{2098}', true, &['\u{6D}']),
+    ('\u{2099}', true, &['\u{6E}']),
+    ('\u{209A}', true, &['\u{70}']),
+    ('\u{209B}', true, &['\u{73}']),
+    ('\u{209C}', true, &['\u{74}']),
+    ('\u{20A8}', true, &['\u{52}', '\u{73}']),
+    ('\u{2100}', true, &['\u{61}', '\u{2F}', '\u{63}']),
+    ('\u{2101}', true, &['\u{61}', '\u{2F}', '\u{73}']),
+    ('\u{2102}', true, &['\u{43}']),
+    ('\u{2103}', true, &['\u{B0}', '\u{43}']),
+    ('\u{2105}', true, &['\u{63}', '\u{2F}', '\u{6F}']),
+    ('\u{2106}', true, &['\u{63}', '\u{2F}', '\u{75}']),
+    ('\u{2107}', true, &['\u{190}']),
+    ('\u{2109}', true, &['\u{B0}', '\u{46}']),
+    ('\u{210A}', true, &['\u{67}']),
+    ('\u{210B}', true, &['\u{48}']),
+    ('\u{210C}', true, &['\u{48}']),
+    ('\u{210D}', true, &['\u{48}']),
+    ('\u{210E}', true, &['\u{68}']),
+    ('\u{210F}', true, &['\u{127}']),
+    ('\u{2110}', true, &['\u{49}']),
+    ('\u{2111}', true, &['\u{49}']),
+    ('\u{2112}', true, &['\u{4C}']),
+    ('\u{2113}', true, &['\u{6C}']),
+    ('\u{2115}', true, &['\u{4E}']),
+    ('\u{2116}', true, &['\u{4E}', '\u{6F}']),
+    ('\u{2119}', true, &['\u{50}']),
+    ('\u{211A}', true, &['\u{51}']),
+    ('\u{211B}', true, &['\u{52}']),
+    ('\u{211C}', true, &['\u{52}']),
+    ('\u{211D}', true, &['\u{52}']),
+    ('\u{2120}', true, &['\u{53}', '\u{4D}']),
+    ('\u{2121}', true, &['\u{54}', '\u{45}', '\u{4C}']),
+    ('\u{2122}', true, &['\u{54}', '\u{4D}']),
+    ('\u{2124}', true, &['\u{5A}']),
+    ('\u{2126}', false, &['\u{3A9}']),
+    ('\u{2128}', true, &['\u{5A}']),
+    ('\u{212A}', false, &['\u{4B}']),
+    ('\u{212B}', false, &['\u{C5}']),
+    ('\u{212C}', true, &['\u{42}']),
+    ('\u{212D}', true, &['\u{43}']),
+    ('\u{212F}', true, &['\u{65}']),
+    ('\u{2130}', true, &['\u{45}']),
+    ('\u{2131}', true, &['\u{46}']),
+    ('\u{2133}', true, &['\u{4D}']),
+    ('\u{2134}', true, &['\u{6F}']),
+    ('\u{2135}', true, &['\u{5D0}']),
+    ('\u{2136}', true, &['\u{5D1}']),
+    ('\u{2137}', true, &['\u{5D2}']),
+    ('\u{2138}', true, &['\u{5D3}']),
+    ('\u{2139}', true, &['\u{69}']),
+    ('\u{213B}', true, &['\u{46}', '\u{41}', '\u{58}']),
+    ('\u{213C}', true, &['\u{3C0}']),
+    ('\u{213D}', true, &['\u{3B3}']),
+    ('\u{213E}', true, &['\u{393}']),
+    ('\u{213F}', true, &['\u{3A0}']),
+    ('\u{2140}', true, &['\u{2211}']),
+    ('\u{2145}', true, &['\u{44}']),
+    ('\u{2146}', true, &['\u{64}']),
+    ('\u{2147}', true, &['\u{65}']),
+    ('\u{2148}', true, &['\u{69}']),
+    ('\u{2149}', true, &['\u{6A}']),
+    ('\u{2150}', true, &['\u{31}', '\u{2044}', '\u{37}']),
+    ('\u{2151}', true, &['\u{31}', '\u{2044}', '\u{39}']),
+    ('\u{2152}', true, &['\u{31}', '\u{2044}', '\u{31}', '\u{30}']),
+    ('\u{2153}', true, &['\u{31}', '\u{2044}', '\u{33}']),
+    ('\u{2154}', true, &['\u{32}', '\u{2044}', '\u{33}']),
+    ('\u{2155}', true, &['\u{31}', '\u{2044}', '\u{35}']),
+    ('\u{2156}', true, &['\u{32}', '\u{2044}', '\u{35}']),
+    ('\u{2157}', true, &['\u{33}', '\u{2044}', '\u{35}']),
+    ('\u{2158}', true, &['\u{34}', '\u{2044}', '\u{35}']),
+    ('\u{2159}', true, &['\u{31}', '\u{2044}', '\u{36}']),
+    ('\u{215A}', true, &['\u{35}', '\u{2044}', '\u{36}']),
+    ('\u{215B}', true, &['\u{31}', '\u{2044}', '\u{38}']),
+    ('\u{215C}', true, &['\u{33}', '\u{2044}', '\u{38}']),
+    ('\u{215D}', true, &['\u{35}', '\u{2044}', '\u{38}']),
+    ('\u{215E}', true, &['\u{37}', '\u{2044}', '\u{38}']),
+    ('\u{215F}', true, &['\u{31}', '\u{2044}']),
+    ('\u{2160}', true, &['\u{49}']),
+    ('\u{2161}', true, &['\u{49}', '\u{49}']),
+    ('\u{2162}', true, &['\u{49}', '\u{49}', '\u{49}']),
+    ('\u{2163}', true, &['\u{49}', '\u{56}']),
+    ('\u{2164}', true, &['\u{56}']),
+    ('\u{2165}', true, &['\u{56}', '\u{49}']),
+    ('\u{2166}', true, &['\u{56}', '\u{49}', '\u{49}']),
+    ('\u{2167}', true, &['\u{56}', '\u{49}', '\u{49}', '\u{49}']),
+    ('\u{2168}', true, &['\u{49}', '\u{58}']),
+    ('\u{2169}', true, &['\u{58}']),
+    ('\u{216A}', true, &['\u{58}', '\u{49}']),
+    ('\u{216B}', true, &['\u{58}', '\u{49}', '\u{49}']),
+    ('\u{216C}', true, &['\u{4C}']),
+    ('\u{216D}', true, &['\u{43}']),
+    ('\u{216E}', true, &['\u{44}']),
+    ('\u{216F}', true, &['\u{4D}']),
+    ('\u{2170}', true, &['\u{69}']),
+    ('\u{2171}', true, &['\u{69}', '\u{69}']),
+    ('\u{2172}', true, &['\u{69}', '\u{69}', '\u{69}']),
+    ('\u{2173}', true, &['\u{69}', '\u{76}']),
+    ('\u{2174}', true, &['\u{76}']),
+    ('\u{2175}', true, &['\u{76}', '\u{69}']),
+    ('\u{2176}', true, &['\u{76}', '\u{69}', '\u{69}']),
+    ('\u{2177}', true, &['\u{76}', '\u{69}', '\u{69}', '\u{69}']),
+    ('\u{2178}', true, &['\u{69}', '\u{78}']),
+    ('\u{2179}', true, &['\u{78}']),
+    ('\u{217A}', true, &['\u{78}', '\u{69}']),
+    ('\u{217B}', true, &['\u{78}', '\u{69}', '\u{69}']),
+    ('\u{217C}', true, &['\u{6C}']),
+    ('\u{217D}', true, &['\u{63}']),
+    ('\u{217E}', true, &['\u{64}']),
+    ('\u{217F}', true, &['\u{6D}']),
+    ('\u{2189}', true, &['\u{30}', '\u{2044}', '\u{33}']),
+    ('\u{219A}', false, &['\u{2190}', '\u{338}']),
+    ('\u{219B}', false, &['\u{2192}', '\u{338}']),
+    ('\u{21AE}', false, &['\u{2194}', '\u{338}']),
+    ('\u{21CD}', false, &['\u{21D0}', '\u{338}']),
+    ('\u{21CE}', false, &['\u{21D4}', '\u{338}']),
+    ('\u{21CF}', false, &['\u{21D2}', '\u{338}']),
+    ('\u{2204}', false, &['\u{2203}', '\u{338}']),
+    ('\u{2209}', false, &['\u{2208}', '\u{338}']),
+    ('\u{220C}', false, &['\u{220B}', '\u{338}']),
+    ('\u{2224}', false, &['\u{2223}', '\u{338}']),
+    ('\u{2226}', false, &['\u{2225}', '\u{338}']),
+    ('\u{222C}', true, &['\u{222B}', '\u{222B}']),
+    ('\u{222D}', true, &['\u{222B}', '\u{222B}', '\u{222B}']),
+    ('\u{222F}', true, &['\u{222E}', '\u{222E}']),
+    ('\u{2230}', true, &['\u{222E}', '\u{222E}', '\u{222E}']),
+    ('\u{2241}', false, &['\u{223C}', '\u{338}']),
+    ('\u{2244}', false, &['\u{2243}', '\u{338}']),
+    ('\u{2247}', false, &['\u{2245}', '\u{338}']),
+    ('\u{2249}', false, &['\u{2248}', '\u{338}']),
+    ('\u{2260}', false, &['\u{3D}', '\u{338}']),
+    ('\u{2262}', false, &['\u{2261}', '\u{338}']),
+    ('\u{226D}', false, &['\u{224D}', '\u{338}']),
+    ('\u{226E}', false, &['\u{3C}', '\u{338}']),
+    ('\u{226F}', false, &['\u{3E}', '\u{338}']),
+    ('\u{2270}', false, &['\u{2264}', '\u{338}']),
+    ('\u{2271}', false, &['\u{2265}', '\u{338}']),
+    ('\u{2274}', false, &['\u{2272}', '\u{338}']),
+    ('\u{2275}', false, &['\u{2273}', '\u{338}']),
+    ('\u{2278}', false, &['\u{2276}', '\u{338}']),
+    ('\u{2279}', false, &['\u{2277}', '\u{338}']),
+    ('\u{2280}', false, &['\u{227A}', '\u{338}']),
+    ('\u{2281}', false, &['\u{227B}', '\u{338}']),
+    ('\u{2284}', false, &['\u{2282}', '\u{338}']),
+    ('\u{2285}', false, &['\u{2283}', '\u{338}']),
+    ('\u{2288}', false, &['\u{2286}', '\u{338}']),
+    ('\u{2289}', false, &['\u{2287}', '\u{338}']),
+    ('\u{22AC}', false, &['\u{22A2}', '\u{338}']),
+    ('\u{22AD}', false, &['\u{22A8}', '\u{338}']),
+    ('\u{22AE}', false, &['\u{22A9}', '\u{338}']),
+    ('\u{22AF}', false, &['\u{22AB}', '\u{338}']),
+    ('\u{22E0}', false, &['\u{227C}', '\u{338}']),
+    ('\u{22E1}', false, &['\u{227D}', '\u{338}']),
+    ('\u{22E2}', false, &['\u{2291}', '\u{338}']),
+    ('\u{22E3}', false, &['\u{2292}', '\u{338}']),
+    ('\u{22EA}', false, &['\u{22B2}', '\u{338}']),
+    ('\u{22EB}', false, &['\u{22B3}', '\u{338}']),
+    ('\u{22EC}', false, &['\u{22B4}', '\u{338}']),
+    ('\u{22ED}', false, &['\u{22B5}', '\u{338}']),
+    ('\u{2329}', false, &['\u{3008}']),
+    ('\u{232A}', false, &['\u{3009}']),
+    ('\u{2460}', true, &['\u{31}']),
+    ('\u{2461}', true, &['\u{32}']),
+    ('\u{2462}', true, &['\u{33}']),
+    ('\u{2463}', true, &['\u{34}']),
+    ('\u{2464}', true, &['\u{35}']),
+    ('\u{2465}', true, &['\u{36}']),
+    ('\u{2466}', true, &['\u{37}']),
+    ('\u{2467}', true, &['\u{38}']),
+    ('\u{2468}', true, &['\u{39}']),
+    ('\u{2469}', true, &['\u{31}', '\u{30}']),
+    ('\u{246A}', true, &['\u{31}', '\u{31}']),
+    ('\u{246B}', true, &['\u{31}', '\u{32}']),
+    ('\u{246C}', true, &['\u{31}', '\u{33}']),
+    ('\u{246D}', true, &['\u{31}', '\u{34}']),
+    ('\u{246E}', true, &['\u{31}', '\u{35}']),
+    ('\u{246F}', true, &['\u{31}', '\u{36}']),
+    ('\u{2470}', true, &['\u{31}', '\u{37}']),
+    ('\u{2471}', true, &['\u{31}', '\u{38}']),
+    ('\u{2472}', true, &['\u{31}', '\u{39}']),
+    ('\u{2473}', true, &['\u{32}', '\u{30}']),
+    ('\u{2474}', true, &['\u{28}', '\u{31}', '\u{29}']),
+    ('\u{2475}', true, &['\u{28}', '\u{32}', '\u{29}']),
+    ('\u{2476}', true, &['\u{28}', '\u{33}', '\u{29}']),
+    ('\u{2477}', true, &['\u{28}', '\u{34}', '\u{29}']),
+    ('\u{2478}', true, &['\u{28}', '\u{35}', '\u{29}']),
+    ('\u{2479}', true, &['\u{28}', '\u{36}', '\u{29}']),
+    ('\u{247A}', true, &['\u{28}', '\u{37}', '\u{29}']),
+    ('\u{247B}', true, &['\u{28}', '\u{38}', '\u{29}']),
+    ('\u{247C}', true, &['\u{28}', '\u{39}', '\u{29}']),
+    ('\u{247D}', true, &['\u{28}', '\u{31}', '\u{30}', '\u{29}']),
+    ('\u{247E}', true, &['\u{28}', '\u{31}', '\u{31}', '\u{29}']),
+    ('\u{247F}', true, &['\u{28}', '\u{31}', '\u{32}', '\u{29}']),
+    ('\u{2480}', true, &['\u{28}', '\u{31}', '\u{33}', '\u{29}']),
+    ('\u{2481}', true, &['\u{28}', '\u{31}', '\u{34}', '\u{29}']),
+    ('\u{2482}', true, &['\u{28}', '\u{31}', '\u{35}', '\u{29}']),
+    ('\u{2483}', true, &['\u{28}', '\u{31}', '\u{36}', '\u{29}']),
+    ('\u{2484}', true, &['\u{28}', '\u{31}', '\u{37}', '\u{29}']),
+    ('\u{2485}', true, &['\u{28}', '\u{31}', '\u{38}', '\u{29}']),
+    ('\u{2486}', true, &['\u{28}', '\u{31}', '\u{39}', '\u{29}']),
+    ('\u{2487}', true, &['\u{28}', '\u{32}', '\u{30}', '\u{29}']),
+    ('\u{2488}', true, &['\u{31}', '\u{2E}']),
+    ('\u{2489}', true, &['\u{32}', '\u{2E}']),
+    ('\u{248A}', true, &['\u{33}', '\u{2E}']),
+    ('\u{248B}', true, &['\u{34}', '\u{2E}']),
+    ('\u{248C}', true, &['\u{35}', '\u{2E}']),
+    ('\u{248D}', true, &['\u{36}', '\u{2E}']),
+    ('\u{248E}', true, &['\u{37}', '\u{2E}']),
+    ('\u{248F}', true, &['\u{38}', '\u{2E}']),
+    ('\u{2490}', true, &['\u{39}', '\u{2E}']),
+    ('\u{2491}', true, &['\u{31}', '\u{30}', '\u{2E}']),
+    ('\u{2492}', true, &['\u{31}', '\u{31}', '\u{2E}']),
+    ('\u{2493}', true, &['\u{31}', '\u{32}', '\u{2E}']),
+    ('\u{2494}', true, &['\u{31}', '\u{33}', '\u{2E}']),
+    ('\u{2495}', true, &['\u{31}', '\u{34}', '\u{2E}']),
+    ('\u{2496}', true, &['\u{31}', '\u{35}', '\u{2E}']),
+    ('\u{2497}', true, &['\u{31}', '\u{36}', '\u{2E}']),
+    ('\u{2498}', true, &['\u{31}', '\u{37}', '\u{2E}']),
+    ('\u{2499}', true, &['\u{31}', '\u{38}', '\u{2E}']),
+    ('\u{249A}', true, &['\u{31}', '\u{39}', '\u{2E}']),
+    ('\u{249B}', true, &['\u{32}', '\u{30}', '\u{2E}']),
+    ('\u{249C}', true, &['\u{28}', '\u{61}', '\u{29}']),
+    ('\u{249D}', true, &['\u{28}', '\u{62}', '\u{29}']),
+    ('\u{249E}', true, &['\u{28}', '\u{63}', '\u{29}']),
+    ('\u{249F}', true, &['\u{28}', '\u{64}', '\u{29}']),
+    ('\u{24A0}', true, &['\u{28}', '\u{65}', '\u{29}']),
+    ('\u{24A1}', true, &['\u{28}', '\u{66}', '\u{29}']),
+    ('\u{24A2}', true, &['\u{28}', '\u{67}', '\u{29}']),
+    ('\u{24A3}', true, &['\u{28}', '\u{68}', '\u{29}']),
+    ('\u{24A4}', true, &['\u{28}', '\u{69}', '\u{29}']),
+    ('\u{24A5}', true, &['\u{28}', '\u{6A}', '\u{29}']),
+    ('\u{24A6}', true, &['\u{28}', '\u{6B}', '\u{29}']),
+    ('\u{24A7}', true, &['\u{28}', '\u{6C}', '\u{29}']),
+    ('\u{24A8}', true, &['\u{28}', '\u{6D}', '\u{29}']),
+    ('\u{24A9}', true, &['\u{28}', '\u{6E}', '\u{29}']),
+    ('\u{24AA}', true, &['\u{28}', '\u{6F}', '\u{29}']),
+    ('\u{24AB}', true, &['\u{28}', '\u{70}', '\u{29}']),
+    ('\u{24AC}', true, &['\u{28}', '\u{71}', '\u{29}']),
+    ('\u{24AD}', true, &['\u{28}', '\u{72}', '\u{29}']),
+    ('\u{24AE}', true, &['\u{28}', '\u{73}', '\u{29}']),
+    ('\u{24AF}', true, &['\u{28}', '\u{74}', '\u{29}']),
+    ('\u{24B0}', true, &['\u{28}', '\u{75}', '\u{29}']),
+    ('\u{24B1}', true, &['\u{28}', '\u{76}', '\u{29}']),
+    ('\u{24B2}', true, &['\u{28}', '\u{77}', '\u{29}']),
+    ('\u{24B3}', true, &['\u{28}', '\u{78}', '\u{29}']),
+    ('\u{24B4}', true, &['\u{28}', '\u{79}', '\u{29}']),
+    ('\u{24B5}', true, &['\u{28}', '\u{7A}', '\u{29}']),
+    ('\u{24B6}', true, &['\u{41}']),
+    ('\u{24B7}', true, &['\u{42}']),
+    ('\u{24B8}', true, &['\u{43}']),
+    ('\u{24B9}', true, &['\u{44}']),
+    ('\u{24BA}', true, &['\u{45}']),
+    ('\u{24BB}', true, &['\u{46}']),
+    ('\u{24BC}', true, &['\u{47}']),
+    ('\u{24BD}', true, &['\u{48}']),
+    ('\u{24BE}', true, &['\u{49}']),
+    ('\u{24BF}', true, &['\u{4A}']),
+    ('\u{24C0}', true, &['\u{4B}']),
+    ('\u{24C1}', true, &['\u{4C}']),
+    ('\u{24C2}', true, &['\u{4D}']),
+    ('\u{24C3}', true, &['\u{4E}']),
+    ('\u{24C4}', true, &['\u{4F}']),
+    ('\u{24C5}', true, &['\u{50}']),
+    ('\u{24C6}', true, &['\u{51}']),
+    ('\u{24C7}', true, &['\u{52}']),
+    ('\u{24C8}', true, &['\u{53}']),
+    ('\u{24C9}', true, &['\u{54}']),
+    ('\u{24CA}', true, &['\u{55}']),
+    ('\u{24CB}', true, &['\u{56}']),
+    ('\u{24CC}', true, &['\u{57}']),
+    ('\u{24CD}', true, &['\u{58}']),
+    ('\u{24CE}', true, &['\u{59}']),
+    ('\u{24CF}', true, &['\u{5A}']),
+    ('\u{24D0}', true, &['\u{61}']),
+    ('\u{24D1}', true, &['\u{62}']),
+    ('\u{24D2}', true, &['\u{63}']),
+    ('\u{24D3}', true, &['\u{64}']),
+    ('\u{24D4}', true, &['\u{65}']),
+    ('\u{24D5}', true, &['\u{66}']),
+    ('\u{24D6}', true, &['\u{67}']),
+    ('\u{24D7}', true, &['\u{68}']),
+    ('\u{24D8}', true, &['\u{69}']),
+    ('\u{24D9}', true, &['\u{6A}']),
+    ('\u{24DA}', true, &['\u{6B}']),
+    ('\u{24DB}', true, &['\u{6C}']),
+    ('\u{24DC}', true, &['\u{6D}']),
+    ('\u{24DD}', true, &['\u{6E}']),
+    ('\u{24DE}', true, &['\u{6F}']),
+    ('\u{24DF}', true, &['\u{70}']),
+    ('\u{24E0}', true, &['\u{71}']),
+    ('\u{24E1}', true, &['\u{72}']),
+    ('\u{24E2}', true, &['\u{73}']),
+    ('\u{24E3}', true, &['\u{74}']),
+    ('\u{24E4}', true, &['\u{75}']),
+    ('\u{24E5}', true, &['\u{76}']),
+    ('\u{24E6}', true, &['\u{77}']),
+    ('\u{24E7}', true, &['\u{78}']),
+    ('\u{24E8}', true, &['\u{79}']),
+    ('\u{24E9}', true, &['\u{7A}']),
+    ('\u{24EA}', true, &['\u{30}']),
+    ('\u{2A0C}', true, &['\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}']),
+    ('\u{2A74}', true, &['\u{3A}', '\u{3A}', '\u{3D}']),
+    ('\u{2A75}', true, &['\u{3D}', '\u{3D}']),
+    ('\u{2A76}', true, &['\u{3D}', '\u{3D}', '\u{3D}']),
+    ('\u{2ADC}', false, &['\u{2ADD}', '\u{338}']),
+    ('\u{2C7C}', true, &['\u{6A}']),
+    ('\u{2C7D}', true, &['\u{56}']),
+    ('\u{2D6F}', true, &['\u{2D61}']),
+    ('\u{2E9F}', true, &['\u{6BCD}']),
+    ('\u{2EF3}', true, &['\u{9F9F}']),
+    ('\u{2F00}', true, &['\u{4E00}']),
+    ('\u{2F01}', true, &['\u{4E28}']),
+    ('\u{2F02}', true, &['\u{4E36}']),
+    ('\u{2F03}', true, &['\u{4E3F}']),
+    ('\u{2F04}', true, &['\u{4E59}']),
+    ('\u{2F05}', true, &['\u{4E85}']),
+    ('\u{2F06}', true, &['\u{4E8C}']),
+    ('\u{2F07}', true, &['\u{4EA0}']),
+    ('\u{2F08}', true, &['\u{4EBA}']),
+    ('\u{2F09}', true, &['\u{513F}']),
+    ('\u{2F0A}', true, &['\u{5165}']),
+    ('\u{2F0B}', true, &['\u{516B}']),
+    ('\u{2F0C}', true, &['\u{5182}']),
+    ('\u{2F0D}', true, &['\u{5196}']),
+    ('\u{2F0E}', true, &['\u{51AB}']),
+    ('\u{2F0F}', true, &['\u{51E0}']),
+    ('\u{2F10}', true, &['\u{51F5}']),
+    ('\u{2F11}', true, &['\u{5200}']),
+    ('\u{2F12}', true, &['\u{529B}']),
+    ('\u{2F13}', true, &['\u{52F9}']),
+    ('\u{2F14}', true, &['\u{5315}']),
+    ('\u{2F15}', true, &['\u{531A}']),
+    ('\u{2F16}', true, &['\u{5338}']),
+    ('\u{2F17}', true, &['\u{5341}']),
+    ('\u{2F18}', true, &['\u{535C}']),
+    ('\u{2F19}', true, &['\u{5369}']),
+    ('\u{2F1A}', true, &['\u{5382}']),
+    ('\u{2F1B}', true, &['\u{53B6}']),
+    ('\u{2F1C}', true, &['\u{53C8}']),
+    ('\u{2F1D}', true, &['\u{53E3}']),
+    ('\u{2F1E}', true, &['\u{56D7}']),
+    ('\u{2F1F}', true, &['\u{571F}']),
+    ('\u{2F20}', true, &['\u{58EB}']),
+    ('\u{2F21}', true, &['\u{5902}']),
+    ('\u{2F22}', true, &['\u{590A}']),
+    ('\u{2F23}', true, &['\u{5915}']),
+    ('\u{2F24}', true, &['\u{5927}']),
+    ('\u{2F25}', true, &['\u{5973}']),
+    ('\u{2F26}', true, &['\u{5B50}']),
+    ('\u{2F27}', true, &['\u{5B80}']),
+    ('\u{2F28}', true, &['\u{5BF8}']),
+    ('\u{2F29}', true, &['\u{5C0F}']),
+    ('\u{2F2A}', true, &['\u{5C22}']),
+    ('\u{2F2B}', true, &['\u{5C38}']),
+    ('\u{2F2C}', true, &['\u{5C6E}']),
+    ('\u{2F2D}', true, &['\u{5C71}']),
+    ('\u{2F2E}', true, &['\u{5DDB}']),
+    ('\u{2F2F}', true, &['\u{5DE5}']),
+    ('\u{2F30}', true, &['\u{5DF1}']),
+    ('\u{2F31}', true, &['\u{5DFE}']),
+    ('\u{2F32}', true, &['\u{5E72}']),
+    ('\u{2F33}', true, &['\u{5E7A}']),
+    ('\u{2F34}', true, &['\u{5E7F}']),
+    ('\u{2F35}', true, &['\u{5EF4}']),
+    ('\u{2F36}', true, &['\u{5EFE}']),
+    ('\u{2F37}', true, &['\u{5F0B}']),
+    ('\u{2F38}', true, &['\u{5F13}']),
+    ('\u{2F39}', true, &['\u{5F50}']),
+    ('\u{2F3A}', true, &['\u{5F61}']),
+    ('\u{2F3B}', true, &['\u{5F73}']),
+    ('\u{2F3C}', true, &['\u{5FC3}']),
+    ('\u{2F3D}', true, &['\u{6208}']),
+    ('\u{2F3E}', true, &['\u{6236}']),
+    ('\u{2F3F}', true, &['\u{624B}']),
+    ('\u{2F40}', true, &['\u{652F}']),
+    ('\u{2F41}', true, &['\u{6534}']),
+    ('\u{2F42}', true, &['\u{6587}']),
+    ('\u{2F43}', true, &['\u{6597}']),
+    ('\u{2F44}', true, &['\u{65A4}']),
+    ('\u{2F45}', true, &['\u{65B9}']),
+    ('\u{2F46}', true, &['\u{65E0}']),
+    ('\u{2F47}', true, &['\u{65E5}']),
+    ('\u{2F48}', true, &['\u{66F0}']),
+    ('\u{2F49}', true, &['\u{6708}']),
+    ('\u{2F4A}', true, &['\u{6728}']),
+    ('\u{2F4B}', true, &['\u{6B20}']),
+    ('\u{2F4C}', true, &['\u{6B62}']),
+    ('\u{2F4D}', true, &['\u{6B79}']),
+    ('\u{2F4E}', true, &['\u{6BB3}']),
+    ('\u{2F4F}', true, &['\u{6BCB}']),
+    ('\u{2F50}', true, &['\u{6BD4}']),
+    ('\u{2F51}', true, &['\u{6BDB}']),
+    ('\u{2F52}', true, &['\u{6C0F}']),
+    ('\u{2F53}', true, &['\u{6C14}']),
+    ('\u{2F54}', true, &['\u{6C34}']),
+    ('\u{2F55}', true, &['\u{706B}']),
+    ('\u{2F56}', true, &['\u{722A}']),
+    ('\u{2F57}', true, &['\u{7236}']),
+    ('\u{2F58}', true, &['\u{723B}']),
+    ('\u{2F59}', true, &['\u{723F}']),
+    ('\u{2F5A}', true, &['\u{7247}']),
+    ('\u{2F5B}', true, &['\u{7259}']),
+    ('\u{2F5C}', true, &['\u{725B}']),
+    ('\u{2F5D}', true, &['\u{72AC}']),
+    ('\u{2F5E}', true, &['\u{7384}']),
+    ('\u{2F5F}', true, &['\u{7389}']),
+    ('\u{2F60}', true, &['\u{74DC}']),
+    ('\u{2F61}', true, &['\u{74E6}']),
+    ('\u{2F62}', true, &['\u{7518}']),
+    ('\u{2F63}', true, &['\u{751F}']),
+    ('\u{2F64}', true, &['\u{7528}']),
+    ('\u{2F65}', true, &['\u{7530}']),
+    ('\u{2F66}', true, &['\u{758B}']),
+    ('\u{2F67}', true, &['\u{7592}']),
+    ('\u{2F68}', true, &['\u{7676}']),
+    ('\u{2F69}', true, &['\u{767D}']),
+    ('\u{2F6A}', true, &['\u{76AE}']),
+    ('\u{2F6B}', true, &['\u{76BF}']),
+    ('\u{2F6C}', true, &['\u{76EE}']),
+    ('\u{2F6D}', true, &['\u{77DB}']),
+    ('\u{2F6E}', true, &['\u{77E2}']),
+    ('\u{2F6F}', true, &['\u{77F3}']),
+    ('\u{2F70}', true, &['\u{793A}']),
+    ('\u{2F71}', true, &['\u{79B8}']),
+    ('\u{2F72}', true, &['\u{79BE}']),
+    ('\u{2F73}', true, &['\u{7A74}']),
+    ('\u{2F74}', true, &['\u{7ACB}']),
+    ('\u{2F75}', true, &['\u{7AF9}']),
+    ('\u{2F76}', true, &['\u{7C73}']),
+    ('\u{2F77}', true, &['\u{7CF8}']),
+    ('\u{2F78}', true, &['\u{7F36}']),
+    ('\u{2F79}', true, &['\u{7F51}']),
+    ('\u{2F7A}', true, &['\u{7F8A}']),
+    ('\u{2F7B}', true, &['\u{7FBD}']),
+    ('\u{2F7C}', true, &['\u{8001}']),
+    ('\u{2F7D}', true, &['\u{800C}']),
+    ('\u{2F7E}', true, &['\u{8012}']),
+    ('\u{2F7F}', true, &['\u{8033}']),
+    ('\u{2F80}', true, &['\u{807F}']),
+    ('\u{2F81}', true, &['\u{8089}']),
+    ('\u{2F82}', true, &['\u{81E3}']),
+    ('\u{2F83}', true, &['\u{81EA}']),
+    ('\u{2F84}', true, &['\u{81F3}']),
+    ('\u{2F85}', true, &['\u{81FC}']),
+    ('\u{2F86}', true, &['\u{820C}']),
+    ('\u{2F87}', true, &['\u{821B}']),
+    ('\u{2F88}', true, &['\u{821F}']),
+    ('\u{2F89}', true, &['\u{826E}']),
+    ('\u{2F8A}', true, &['\u{8272}']),
+    ('\u{2F8B}', true, &['\u{8278}']),
+    ('\u{2F8C}', true, &['\u{864D}']),
+    ('\u{2F8D}', true, &['\u{866B}']),
+    ('\u{2F8E}', true, &['\u{8840}']),
+    ('\u{2F8F}', true, &['\u{884C}']),
+    ('\u{2F90}', true, &['\u{8863}']),
+    ('\u{2F91}', true, &['\u{897E}']),
+    ('\u{2F92}', true, &['\u{898B}']),
+    ('\u{2F93}', true, &['\u{89D2}']),
+    ('\u{2F94}', true, &['\u{8A00}']),
+    ('\u{2F95}', true, &['\u{8C37}']),
+    ('\u{2F96}', true, &['\u{8C46}']),
+    ('\u{2F97}', true, &['\u{8C55}']),
+    ('\u{2F98}', true, &['\u{8C78}']),
+    ('\u{2F99}', true, &['\u{8C9D}']),
+    ('\u{2F9A}', true, &['\u{8D64}']),
+    ('\u{2F9B}', true, &['\u{8D70}']),
+    ('\u{2F9C}', true, &['\u{8DB3}']),
+    ('\u{2F9D}', true, &['\u{8EAB}']),
+    ('\u{2F9E}', true, &['\u{8ECA}']),
+    ('\u{2F9F}', true, &['\u{8F9B}']),
+    ('\u{2FA0}', true, &['\u{8FB0}']),
+    ('\u{2FA1}', true, &['\u{8FB5}']),
+    ('\u{2FA2}', true, &['\u{9091}']),
+    ('\u{2FA3}', true, &['\u{9149}']),
+    ('\u{2FA4}', true, &['\u{91C6}']),
+    ('\u{2FA5}', true, &['\u{91CC}']),
+    ('\u{2FA6}', true, &['\u{91D1}']),
+    ('\u{2FA7}', true, &['\u{9577}']),
+    ('\u{2FA8}', true, &['\u{9580}']),
+    ('\u{2FA9}', true, &['\u{961C}']),
+    ('\u{2FAA}', true, &['\u{96B6}']),
+    ('\u{2FAB}', true, &['\u{96B9}']),
+    ('\u{2FAC}', true, &['\u{96E8}']),
+    ('\u{2FAD}', true, &['\u{9751}']),
+    ('\u{2FAE}', true, &['\u{975E}']),
+    ('\u{2FAF}', true, &['\u{9762}']),
+    ('\u{2FB0}', true, &['\u{9769}']),
+    ('\u{2FB1}', true, &['\u{97CB}']),
+    ('\u{2FB2}', true, &['\u{97ED}']),
+    ('\u{2FB3}', true, &['\u{97F3}']),
+    ('\u{2FB4}', true, &['\u{9801}']),
+    ('\u{2FB5}', true, &['\u{98A8}']),
+    ('\u{2FB6}', true, &['\u{98DB}']),
+    ('\u{2FB7}', true, &['\u{98DF}']),
+    ('\u{2FB8}', true, &['\u{9996}']),
+    ('\u{2FB9}', true, &['\u{9999}']),
+    ('\u{2FBA}', true, &['\u{99AC}']),
+    ('\u{2FBB}', true, &['\u{9AA8}']),
+    ('\u{2FBC}', true, &['\u{9AD8}']),
+    ('\u{2FBD}', true, &['\u{9ADF}']),
+    ('\u{2FBE}', true, &['\u{9B25}']),
+    ('\u{2FBF}', true, &['\u{9B2F}']),
+    ('\u{2FC0}', true, &['\u{9B32}']),
+    ('\u{2FC1}', true, &['\u{9B3C}']),
+    ('\u{2FC2}', true, &['\u{9B5A}']),
+    ('\u{2FC3}', true, &['\u{9CE5}']),
+    ('\u{2FC4}', true, &['\u{9E75}']),
+    ('\u{2FC5}', true, &['\u{9E7F}']),
+    ('\u{2FC6}', true, &['\u{9EA5}']),
+    ('\u{2FC7}', true, &['\u{9EBB}']),
+    ('\u{2FC8}', true, &['\u{9EC3}']),
+    ('\u{2FC9}', true, &['\u{9ECD}']),
+    ('\u{2FCA}', true, &['\u{9ED1}']),
+    ('\u{2FCB}', true, &['\u{9EF9}']),
+    ('\u{2FCC}', true, &['\u{9EFD}']),
+    ('\u{2FCD}', true, &['\u{9F0E}']),
+    ('\u{2FCE}', true, &['\u{9F13}']),
+    ('\u{2FCF}', true, &['\u{9F20}']),
+    ('\u{2FD0}', true, &['\u{9F3B}']),
+    ('\u{2FD1}', true, &['\u{9F4A}']),
+    ('\u{2FD2}', true, &['\u{9F52}']),
+    ('\u{2FD3}', true, &['\u{9F8D}']),
+    ('\u{2FD4}', true, &['\u{9F9C}']),
+    ('\u{2FD5}', true, &['\u{9FA0}']),
+    ('\u{3000}', true, &['\u{20}']),
+    ('\u{3036}', true, &['\u{3012}']),
+    ('\u{3038}', true, &['\u{5341}']),
+    ('\u{3039}', true, &['\u{5344}']),
+    ('\u{303A}', true, &['\u{5345}']),
+    ('\u{304C}', false, &['\u{304B}', '\u{3099}']),
+    ('\u{304E}', false, &['\u{304D}', '\u{3099}']),
+    ('\u{3050}', false, &['\u{304F}', '\u{3099}']),
+    ('\u{3052}', false, &['\u{3051}', '\u{3099}']),
+    ('\u{3054}', false, &['\u{3053}', '\u{3099}']),
+    ('\u{3056}', false, &['\u{3055}', '\u{3099}']),
+    ('\u{3058}', false, &['\u{3057}', '\u{3099}']),
+    ('\u{305A}', false, &['\u{3059}', '\u{3099}']),
+    ('\u{305C}', false, &['\u{305B}', '\u{3099}']),
+    ('\u{305E}', false, &['\u{305D}', '\u{3099}']),
+    ('\u{3060}', false, &['\u{305F}', '\u{3099}']),
+    ('\u{3062}', false, &['\u{3061}', '\u{3099}']),
+    ('\u{3065}', false, &['\u{3064}', '\u{3099}']),
+    ('\u{3067}', false, &['\u{3066}', '\u{3099}']),
+    ('\u{3069}', false, &['\u{3068}', '\u{3099}']),
+    ('\u{3070}', false, &['\u{306F}', '\u{3099}']),
+    ('\u{3071}', false, &['\u{306F}', '\u{309A}']),
+    ('\u{3073}', false, &['\u{3072}', '\u{3099}']),
+    ('\u{3074}', false, &['\u{3072}', '\u{309A}']),
+    ('\u{3076}', false, &['\u{3075}', '\u{3099}']),
+    ('\u{3077}', false, &['\u{3075}', '\u{309A}']),
+    ('\u{3079}', false, &['\u{3078}', '\u{3099}']),
+    ('\u{307A}', false, &['\u{3078}', '\u{309A}']),
+    ('\u{307C}', false, &['\u{307B}', '\u{3099}']),
+    ('\u{307D}', false, &['\u{307B}', '\u{309A}']),
+    ('\u{3094}', false, &['\u{3046}', '\u{3099}']),
+    ('\u{309B}', true, &['\u{20}', '\u{3099}']),
+    ('\u{309C}', true, &['\u{20}', '\u{309A}']),
+    ('\u{309E}', false, &['\u{309D}', '\u{3099}']),
+    ('\u{309F}', true, &['\u{3088}', '\u{308A}']),
+    ('\u{30AC}', false, &['\u{30AB}', '\u{3099}']),
+    ('\u{30AE}', false, &['\u{30AD}', '\u{3099}']),
+    ('\u{30B0}', false, &['\u{30AF}', '\u{3099}']),
+    ('\u{30B2}', false, &['\u{30B1}', '\u{3099}']),
+    ('\u{30B4}', false, &['\u{30B3}', '\u{3099}']),
+    ('\u{30B6}', false, &['\u{30B5}', '\u{3099}']),
+    ('\u{30B8}', false, &['\u{30B7}', '\u{3099}']),
+    ('\u{30BA}', false, &['\u{30B9}', '\u{3099}']),
+    ('\u{30BC}', false, &['\u{30BB}', '\u{3099}']),
+    ('\u{30BE}', false, &['\u{30BD}', '\u{3099}']),
+    ('\u{30C0}', false, &['\u{30BF}', '\u{3099}']),
+    ('\u{30C2}', false, &['\u{30C1}', '\u{3099}']),
+    ('\u{30C5}', false, &['\u{30C4}', '\u{3099}']),
+    ('\u{30C7}', false, &['\u{30C6}', '\u{3099}']),
+    ('\u{30C9}', false, &['\u{30C8}', '\u{3099}']),
+    ('\u{30D0}', false, &['\u{30CF}', '\u{3099}']),
+    ('\u{30D1}', false, &['\u{30CF}', '\u{309A}']),
+    ('\u{30D3}', false, &['\u{30D2}', '\u{3099}']),
+    ('\u{30D4}', false, &['\u{30D2}', '\u{309A}']),
+    ('\u{30D6}', false, &['\u{30D5}', '\u{3099}']),
+    ('\u{30D7}', false, &['\u{30D5}', '\u{309A}']),
+    ('\u{30D9}', false, &['\u{30D8}', '\u{3099}']),
+    ('\u{30DA}', false, &['\u{30D8}', '\u{309A}']),
+    ('\u{30DC}', false, &['\u{30DB}', '\u{3099}']),
+    ('\u{30DD}', false, &['\u{30DB}', '\u{309A}']),
+    ('\u{30F4}', false, &['\u{30A6}', '\u{3099}']),
+    ('\u{30F7}', false, &['\u{30EF}', '\u{3099}']),
+    ('\u{30F8}', false, &['\u{30F0}', '\u{3099}']),
+    ('\u{30F9}', false, &['\u{30F1}', '\u{3099}']),
+    ('\u{30FA}', false, &['\u{30F2}', '\u{3099}']),
+    ('\u{30FE}', false, &['\u{30FD}', '\u{3099}']),
+    ('\u{30FF}', true, &['\u{30B3}', '\u{30C8}']),
+    ('\u{3131}', true, &['\u{1100}']),
+    ('\u{3132}', true, &['\u{1101}']),
+    ('\u{3133}', true, &['\u{11AA}']),
+    ('\u{3134}', true, &['\u{1102}']),
+    ('\u{3135}', true, &['\u{11AC}']),
+    ('\u{3136}', true, &['\u{11AD}']),
+    ('\u{3137}', true, &['\u{1103}']),
+    ('\u{3138}', true, &['\u{1104}']),
+    ('\u{3139}', true, &['\u{1105}']),
+    ('\u{313A}', true, &['\u{11B0}']),
+    ('\u{313B}', true, &['\u{11B1}']),
+    ('\u{313C}', true, &['\u{11B2}']),
+    ('\u{313D}', true, &['\u{11B3}']),
+    ('\u{313E}', true, &['\u{11B4}']),
+    ('\u{313F}', true, &['\u{11B5}']),
+    ('\u{3140}', true, &['\u{111A}']),
+    ('\u{3141}', true, &['\u{1106}']),
+    ('\u{3142}', true, &['\u{1107}']),
+    ('\u{3143}', true, &['\u{1108}']),
+    ('\u{3144}', true, &['\u{1121}']),
+    ('\u{3145}', true, &['\u{1109}']),
+    ('\u{3146}', true, &['\u{110A}']),
+    ('\u{3147}', true, &['\u{110B}']),
+    ('\u{3148}', true, &['\u{110C}']),
+    ('\u{3149}', true, &['\u{110D}']),
+    ('\u{314A}', true, &['\u{110E}']),
+    ('\u{314B}', true, &['\u{110F}']),
+    ('\u{314C}', true, &['\u{1110}']),
+    ('\u{314D}', true, &['\u{1111}']),
+    ('\u{314E}', true, &['\u{1112}']),
+    ('\u{314F}', true, &['\u{1161}']),
+    ('\u{3150}', true, &['\u{1162}']),
+    ('\u{3151}', true, &['\u{1163}']),
+    ('\u{3152}', true, &['\u{1164}']),
+    ('\u{3153}', true, &['\u{1165}']),
+    ('\u{3154}', true, &['\u{1166}']),
+    ('\u{3155}', true, &['\u{1167}']),
+    ('\u{3156}', true, &['\u{1168}']),
+    ('\u{3157}', true, &['\u{1169}']),
+    ('\u{3158}', true, &['\u{116A}']),
+    ('\u{3159}', true, &['\u{116B}']),
+    ('\u{315A}', true, &['\u{116C}']),
+    ('\u{315B}', true, &['\u{116D}']),
+    ('\u{315C}', true, &['\u{116E}']),
+    ('\u{315D}', true, &['\u{116F}']),
+    ('\u{315E}', true, &['\u{1170}']),
+    ('\u{315F}', true, &['\u{1171}']),
+    ('\u{3160}', true, &['\u{1172}']),
+    ('\u{3161}', true, &['\u{1173}']),
+    ('\u{3162}', true, &['\u{1174}']),
+    ('\u{3163}', true, &['\u{1175}']),
+    ('\u{3164}', true, &['\u{1160}']),
+    ('\u{3165}', true, &['\u{1114}']),
+    ('\u{3166}', true, &['\u{1115}']),
+    ('\u{3167}', true, &['\u{11C7}']),
+    ('\u{3168}', true, &['\u{11C8}']),
+    ('\u{3169}', true, &['\u{11CC}']),
+    ('\u{316A}', true, &['\u{11CE}']),
+    ('\u{316B}', true, &['\u{11D3}']),
+    ('\u{316C}', true, &['\u{11D7}']),
+    ('\u{316D}', true, &['\u{11D9}']),
+    ('\u{316E}', true, &['\u{111C}']),
+    ('\u{316F}', true, &['\u{11DD}']),
+    ('\u{3170}', true, &['\u{11DF}']),
+    ('\u{3171}', true, &['\u{111D}']),
+    ('\u{3172}', true, &['\u{111E}']),
+    ('\u{3173}', true, &['\u{1120}']),
+    ('\u{3174}', true, &['\u{1122}']),
+    ('\u{3175}', true, &['\u{1123}']),
+    ('\u{3176}', true, &['\u{1127}']),
+    ('\u{3177}', true, &['\u{1129}']),
+    ('\u{3178}', true, &['\u{112B}']),
+    ('\u{3179}', true, &['\u{112C}']),
+    ('\u{317A}', true, &['\u{112D}']),
+    ('\u{317B}', true, &['\u{112E}']),
+    ('\u{317C}', true, &['\u{112F}']),
+    ('\u{317D}', true, &['\u{1132}']),
+    ('\u{317E}', true, &['\u{1136}']),
+    ('\u{317F}', true, &['\u{1140}']),
+    ('\u{3180}', true, &['\u{1147}']),
+    ('\u{3181}', true, &['\u{114C}']),
+    ('\u{3182}', true, &['\u{11F1}']),
+    ('\u{3183}', true, &['\u{11F2}']),
+    ('\u{3184}', true, &['\u{1157}']),
+    ('\u{3185}', true, &['\u{1158}']),
+    ('\u{3186}', true, &['\u{1159}']),
+    ('\u{3187}', true, &['\u{1184}']),
+    ('\u{3188}', true, &['\u{1185}']),
+    ('\u{3189}', true, &['\u{1188}']),
+    ('\u{318A}', true, &['\u{1191}']),
+    ('\u{318B}', true, &['\u{1192}']),
+    ('\u{318C}', true, &['\u{1194}']),
+    ('\u{318D}', true, &['\u{119E}']),
+    ('\u{318E}', true, &['\u{11A1}']),
+    ('\u{3192}', true, &['\u{4E00}']),
+    ('\u{3193}', true, &['\u{4E8C}']),
+    ('\u{3194}', true, &['\u{4E09}']),
+    ('\u{3195}', true, &['\u{56DB}']),
+    ('\u{3196}', true, &['\u{4E0A}']),
+    ('\u{3197}', true, &['\u{4E2D}']),
+    ('\u{3198}', true, &['\u{4E0B}']),
+    ('\u{3199}', true, &['\u{7532}']),
+    ('\u{319A}', true, &['\u{4E59}']),
+    ('\u{319B}', true, &['\u{4E19}']),
+    ('\u{319C}', true, &['\u{4E01}']),
+    ('\u{319D}', true, &['\u{5929}']),
+    ('\u{319E}', true, &['\u{5730}']),
+    ('\u{319F}', true, &['\u{4EBA}']),
+    ('\u{3200}', true, &['\u{28}', '\u{1100}', '\u{29}']),
+    ('\u{3201}', true, &['\u{28}', '\u{1102}', '\u{29}']),
+    ('\u{3202}', true, &['\u{28}', '\u{1103}', '\u{29}']),
+    ('\u{3203}', true, &['\u{28}', '\u{1105}', '\u{29}']),
+    ('\u{3204}', true, &['\u{28}', '\u{1106}', '\u{29}']),
+    ('\u{3205}', true, &['\u{28}', '\u{1107}', '\u{29}']),
+    ('\u{3206}', true, &['\u{28}', '\u{1109}', '\u{29}']),
+    ('\u{3207}', true, &['\u{28}', '\u{110B}', '\u{29}']),
+    ('\u{3208}', true, &['\u{28}', '\u{110C}', '\u{29}']),
+    ('\u{3209}', true, &['\u{28}', '\u{110E}', '\u{29}']),
+    ('\u{320A}', true, &['\u{28}', '\u{110F}', '\u{29}']),
+    ('\u{320B}', true, &['\u{28}', '\u{1110}', '\u{29}']),
+    ('\u{320C}', true, &['\u{28}', '\u{1111}', '\u{29}']),
+    ('\u{320D}', true, &['\u{28}', '\u{1112}', '\u{29}']),
+    ('\u{320E}', true, &['\u{28}', '\u{1100}', '\u{1161}', '\u{29}']),
+    ('\u{320F}', true, &['\u{28}', '\u{1102}', '\u{1161}', '\u{29}']),
+    ('\u{3210}', true, &['\u{28}', '\u{1103}', '\u{1161}', '\u{29}']),
+    ('\u{3211}', true, &['\u{28}', '\u{1105}', '\u{1161}', '\u{29}']),
+    ('\u{3212}', true, &['\u{28}', '\u{1106}', '\u{1161}', '\u{29}']),
+    ('\u{3213}', true, &['\u{28}', '\u{1107}', '\u{1161}', '\u{29}']),
+    ('\u{3214}', true, &['\u{28}', '\u{1109}', '\u{1161}', '\u{29}']),
+    ('\u{3215}', true, &['\u{28}', '\u{110B}', '\u{1161}', '\u{29}']),
+    ('\u{3216}', true, &['\u{28}', '\u{110C}', '\u{1161}', '\u{29}']),
+    ('\u{3217}', true, &['\u{28}', '\u{110E}', '\u{1161}', '\u{29}']),
+    ('\u{3218}', true, &['\u{28}', '\u{110F}', '\u{1161}', '\u{29}']),
+    ('\u{3219}', true, &['\u{28}', '\u{1110}', '\u{1161}', '\u{29}']),
+    ('\u{321A}', true, &['\u{28}', '\u{1111}', '\u{1161}', '\u{29}']),
+    ('\u{321B}', true, &['\u{28}', '\u{1112}', '\u{1161}', '\u{29}']),
+    ('\u{321C}', true, &['\u{28}', '\u{110C}', '\u{116E}', '\u{29}']),
+    ('\u{321D}', true, &['\u{28}', '\u{110B}', '\u{1169}', '\u{110C}', '\u{1165}', '\u{11AB}', '\u{29}']),
+    ('\u{321E}', true, &['\u{28}', '\u{110B}', '\u{1169}', '\u{1112}', '\u{116E}', '\u{29}']),
+    ('\u{3220}', true, &['\u{28}', '\u{4E00}', '\u{29}']),
+    ('\u{3221}', true, &['\u{28}', '\u{4E8C}', '\u{29}']),
+    ('\u{3222}', true, &['\u{28}', '\u{4E09}', '\u{29}']),
+    ('\u{3223}', true, &['\u{28}', '\u{56DB}', '\u{29}']),
+    ('\u{3224}', true, &['\u{28}', '\u{4E94}', '\u{29}']),
+    ('\u{3225}', true, &['\u{28}', '\u{516D}', '\u{29}']),
+    ('\u{3226}', true, &['\u{28}', '\u{4E03}', '\u{29}']),
+    ('\u{3227}', true, &['\u{28}', '\u{516B}', '\u{29}']),
+    ('\u{3228}', true, &['\u{28}', '\u{4E5D}', '\u{29}']),
+    ('\u{3229}', true, &['\u{28}', '\u{5341}', '\u{29}']),
+    ('\u{322A}', true, &['\u{28}', '\u{6708}', '\u{29}']),
+    ('\u{322B}', true, &['\u{28}', '\u{706B}', '\u{29}']),
+    ('\u{322C}', true, &['\u{28}', '\u{6C34}', '\u{29}']),
+    ('\u{322D}', true, &['\u{28}', '\u{6728}', '\u{29}']),
+    ('\u{322E}', true, &['\u{28}', '\u{91D1}', '\u{29}']),
+    ('\u{322F}', true, &['\u{28}', '\u{571F}', '\u{29}']),
+    ('\u{3230}', true, &['\u{28}', '\u{65E5}', '\u{29}']),
+    ('\u{3231}', true, &['\u{28}', '\u{682A}', '\u{29}']),
+    ('\u{3232}', true, &['\u{28}', '\u{6709}', '\u{29}']),
+    ('\u{3233}', true, &['\u{28}', '\u{793E}', '\u{29}']),
+    ('\u{3234}', true, &['\u{28}', '\u{540D}', '\u{29}']),
+    ('\u{3235}', true, &['\u{28}', '\u{7279}', '\u{29}']),
+    ('\u{3236}', true, &['\u{28}', '\u{8CA1}', '\u{29}']),
+    ('\u{3237}', true, &['\u{28}', '\u{795D}', '\u{29}']),
+    ('\u{3238}', true, &['\u{28}', '\u{52B4}', '\u{29}']),
+    ('\u{3239}', true, &['\u{28}', '\u{4EE3}', '\u{29}']),
+    ('\u{323A}', true, &['\u{28}', '\u{547C}', '\u{29}']),
+    ('\u{323B}', true, &['\u{28}', '\u{5B66}', '\u{29}']),
+    ('\u{323C}', true, &['\u{28}', '\u{76E3}', '\u{29}']),
+    ('\u{323D}', true, &['\u{28}', '\u{4F01}', '\u{29}']),
+    ('\u{323E}', true, &['\u{28}', '\u{8CC7}', '\u{29}']),
+    ('\u{323F}', true, &['\u{28}', '\u{5354}', '\u{29}']),
+    ('\u{3240}', true, &['\u{28}', '\u{796D}', '\u{29}']),
+    ('\u{3241}', true, &['\u{28}', '\u{4F11}', '\u{29}']),
+    ('\u{3242}', true, &['\u{28}', '\u{81EA}', '\u{29}']),
+    ('\u{3243}', true, &['\u{28}', '\u{81F3}', '\u{29}']),
+    ('\u{3244}', true, &['\u{554F}']),
+    ('\u{3245}', true, &['\u{5E7C}']),
+    ('\u{3246}', true, &['\u{6587}']),
+    ('\u{3247}', true, &['\u{7B8F}']),
+    ('\u{3250}', true, &['\u{50}', '\u{54}', '\u{45}']),
+    ('\u{3251}', true, &['\u{32}', '\u{31}']),
+    ('\u{3252}', true, &['\u{32}', '\u{32}']),
+    ('\u{3253}', true, &['\u{32}', '\u{33}']),
+    ('\u{3254}', true, &['\u{32}', '\u{34}']),
+    ('\u{3255}', true, &['\u{32}', '\u{35}']),
+    ('\u{3256}', true, &['\u{32}', '\u{36}']),
+    ('\u{3257}', true, &['\u{32}', '\u{37}']),
+    ('\u{3258}', true, &['\u{32}', '\u{38}']),
+    ('\u{3259}', true, &['\u{32}', '\u{39}']),
+    ('\u{325A}', true, &['\u{33}', '\u{30}']),
+    ('\u{325B}', true, &['\u{33}', '\u{31}']),
+    ('\u{325C}', true, &['\u{33}', '\u{32}']),
+    ('\u{325D}', true, &['\u{33}', '\u{33}']),
+    ('\u{325E}', true, &['\u{33}', '\u{34}']),
+    ('\u{325F}', true, &['\u{33}', '\u{35}']),
+    ('\u{3260}', true, &['\u{1100}']),
+    ('\u{3261}', true, &['\u{1102}']),
+    ('\u{3262}', true, &['\u{1103}']),
+    ('\u{3263}', true, &['\u{1105}']),
+    ('\u{3264}', true, &['\u{1106}']),
+    ('\u{3265}', true, &['\u{1107}']),
+    ('\u{3266}', true, &['\u{1109}']),
+    ('\u{3267}', true, &['\u{110B}']),
+    ('\u{3268}', true, &['\u{110C}']),
+    ('\u{3269}', true, &['\u{110E}']),
+    ('\u{326A}', true, &['\u{110F}']),
+    ('\u{326B}', true, &['\u{1110}']),
+    ('\u{326C}', true, &['\u{1111}']),
+    ('\u{326D}', true, &['\u{1112}']),
+    ('\u{326E}', true, &['\u{1100}', '\u{1161}']),
+    ('\u{326F}', true, &['\u{1102}', '\u{1161}']),
+    ('\u{3270}', true, &['\u{1103}', '\u{1161}']),
+    ('\u{3271}', true, &['\u{1105}', '\u{1161}']),
+    ('\u{3272}', true, &['\u{1106}', '\u{1161}']),
+    ('\u{3273}', true, &['\u{1107}', '\u{1161}']),
+    ('\u{3274}', true, &['\u{1109}', '\u{1161}']),
+    ('\u{3275}', true, &['\u{110B}', '\u{1161}']),
+    ('\u{3276}', true, &['\u{110C}', '\u{1161}']),
+    ('\u{3277}', true, &['\u{110E}', '\u{1161}']),
+    ('\u{3278}', true, &['\u{110F}', '\u{1161}']),
+    ('\u{3279}', true, &['\u{1110}', '\u{1161}']),
+    ('\u{327A}', true, &['\u{1111}', '\u{1161}']),
+    ('\u{327B}', true, &['\u{1112}', '\u{1161}']),
+    ('\u{327C}', true, &['\u{110E}', '\u{1161}', '\u{11B7}', '\u{1100}', '\u{1169}']),
+    ('\u{327D}', true, &['\u{110C}', '\u{116E}', '\u{110B}', '\u{1174}']),
+    ('\u{327E}', true, &['\u{110B}', '\u{116E}']),
+    ('\u{3280}', true, &['\u{4E00}']),
+    ('\u{3281}', true, &['\u{4E8C}']),
+    ('\u{3282}', true, &['\u{4E09}']),
+    ('\u{3283}', true, &['\u{56DB}']),
+    ('\u{3284}', true, &['\u{4E94}']),
+    ('\u{3285}', true, &['\u{516D}']),
+    ('\u{3286}', true, &['\u{4E03}']),
+    ('\u{3287}', true, &['\u{516B}']),
+    ('\u{3288}', true, &['\u{4E5D}']),
+    ('\u{3289}', true, &['\u{5341}']),
+    ('\u{328A}', true, &['\u{6708}']),
+    ('\u{328B}', true, &['\u{706B}']),
+    ('\u{328C}', true, &['\u{6C34}']),
+    ('\u{328D}', true, &['\u{6728}']),
+    ('\u{328E}', true, &['\u{91D1}']),
+    ('\u{328F}', true, &['\u{571F}']),
+    ('\u{3290}', true, &['\u{65E5}']),
+    ('\u{3291}', true, &['\u{682A}']),
+    ('\u{3292}', true, &['\u{6709}']),
+    ('\u{3293}', true, &['\u{793E}']),
+    ('\u{3294}', true, &['\u{540D}']),
+    ('\u{3295}', true, &['\u{7279}']),
+    ('\u{3296}', true, &['\u{8CA1}']),
+    ('\u{3297}', true, &['\u{795D}']),
+    ('\u{3298}', true, &['\u{52B4}']),
+    ('\u{3299}', true, &['\u{79D8}']),
+    ('\u{329A}', true, &['\u{7537}']),
+    ('\u{329B}', true, &['\u{5973}']),
+    ('\u{329C}', true, &['\u{9069}']),
+    ('\u{329D}', true, &['\u{512A}']),
+    ('\u{329E}', true, &['\u{5370}']),
+    ('\u{329F}', true, &['\u{6CE8}']),
+    ('\u{32A0}', true, &['\u{9805}']),
+    ('\u{32A1}', true, &['\u{4F11}']),
+    ('\u{32A2}', true, &['\u{5199}']),
+    ('\u{32A3}', true, &['\u{6B63}']),
+    ('\u{32A4}', true, &['\u{4E0A}']),
+    ('\u{32A5}', true, &['\u{4E2D}']),
+    ('\u{32A6}', true, &['\u{4E0B}']),
+    ('\u{32A7}', true, &['\u{5DE6}']),
+    ('\u{32A8}', true, &['\u{53F3}']),
+    ('\u{32A9}', true, &['\u{533B}']),
+    ('\u{32AA}', true, &['\u{5B97}']),
+    ('\u{32AB}', true, &['\u{5B66}']),
+    ('\u{32AC}', true, &['\u{76E3}']),
+    ('\u{32AD}', true, &['\u{4F01}']),
+    ('\u{32AE}', true, &['\u{8CC7}']),
+    ('\u{32AF}', true, &['\u{5354}']),
+    ('\u{32B0}', true, &['\u{591C}']),
+    ('\u{32B1}', true, &['\u{33}', '\u{36}']),
+    ('\u{32B2}', true, &['\u{33}', '\u{37}']),
+    ('\u{32B3}', true, &['\u{33}', '\u{38}']),
+    ('\u{32B4}', true, &['\u{33}', '\u{39}']),
+    ('\u{32B5}', true, &['\u{34}', '\u{30}']),
+    ('\u{32B6}', true, &['\u{34}', '\u{31}']),
+    ('\u{32B7}', true, &['\u{34}', '\u{32}']),
+    ('\u{32B8}', true, &['\u{34}', '\u{33}']),
+    ('\u{32B9}', true, &['\u{34}', '\u{34}']),
+    ('\u{32BA}', true, &['\u{34}', '\u{35}']),
+    ('\u{32BB}', true, &['\u{34}', '\u{36}']),
+    ('\u{32BC}', true, &['\u{34}', '\u{37}']),
+    ('\u{32BD}', true, &['\u{34}', '\u{38}']),
+    ('\u{32BE}', true, &['\u{34}', '\u{39}']),
+    ('\u{32BF}', true, &['\u{35}', '\u{30}']),
+    ('\u{32C0}', true, &['\u{31}', '\u{6708}']),
+    ('\u{32C1}', true, &['\u{32}', '\u{6708}']),
+    ('\u{32C2}', true, &['\u{33}', '\u{6708}']),
+    ('\u{32C3}', true, &['\u{34}', '\u{6708}']),
+    ('\u{32C4}', true, &['\u{35}', '\u{6708}']),
+    ('\u{32C5}', true, &['\u{36}', '\u{6708}']),
+    ('\u{32C6}', true, &['\u{37}', '\u{6708}']),
+    ('\u{32C7}', true, &['\u{38}', '\u{6708}']),
+    ('\u{32C8}', true, &['\u{39}', '\u{6708}']),
+    ('\u{32C9}', true, &['\u{31}', '\u{30}', '\u{6708}']),
+    ('\u{32CA}', true, &['\u{31}', '\u{31}', '\u{6708}']),
+    ('\u{32CB}', true, &['\u{31}', '\u{32}', '\u{6708}']),
+    ('\u{32CC}', true, &['\u{48}', '\u{67}']),
+    ('\u{32CD}', true, &['\u{65}', '\u{72}', '\u{67}']),
+    ('\u{32CE}', true, &['\u{65}', '\u{56}']),
+    ('\u{32CF}', true, &['\u{4C}', '\u{54}', '\u{44}']),
+    ('\u{32D0}', true, &['\u{30A2}']),
+    ('\u{32D1}', true, &['\u{30A4}']),
+    ('\u{32D2}', true, &['\u{30A6}']),
+    ('\u{32D3}', true, &['\u{30A8}']),
+    ('\u{32D4}', true, &['\u{30AA}']),
+    ('\u{32D5}', true, &['\u{30AB}']),
+    ('\u{32D6}', true, &['\u{30AD}']),
+    ('\u{32D7}', true, &['\u{30AF}']),
+    ('\u{32D8}', true, &['\u{30B1}']),
+    ('\u{32D9}', true, &['\u{30B3}']),
+    ('\u{32DA}', true, &['\u{30B5}']),
+    ('\u{32DB}', true, &['\u{30B7}']),
+    ('\u{32DC}', true, &['\u{30B9}']),
+    ('\u{32DD}', true, &['\u{30BB}']),
+    ('\u{32DE}', true, &['\u{30BD}']),
+    ('\u{32DF}', true, &['\u{30BF}']),
+    ('\u{32E0}', true, &['\u{30C1}']),
+    ('\u{32E1}', true, &['\u{30C4}']),
+    ('\u{32E2}', true, &['\u{30C6}']),
+    ('\u{32E3}', true, &['\u{30C8}']),
+    ('\u{32E4}', true, &['\u{30CA}']),
+    ('\u{32E5}', true, &['\u{30CB}']),
+    ('\u{32E6}', true, &['\u{30CC}']),
+    ('\u{32E7}', true, &['\u{30CD}']),
+    ('\u{32E8}', true, &['\u{30CE}']),
+    ('\u{32E9}', true, &['\u{30CF}']),
+    ('\u{32EA}', true, &['\u{30D2}']),
+    ('\u{32EB}', true, &['\u{30D5}']),
+    ('\u{32EC}', true, &['\u{30D8}']),
+    ('\u{32ED}', true, &['\u{30DB}']),
+    ('\u{32EE}', true, &['\u{30DE}']),
+    ('\u{32EF}', true, &['\u{30DF}']),
+    ('\u{32F0}', true, &['\u{30E0}']),
+    ('\u{32F1}', true, &['\u{30E1}']),
+    ('\u{32F2}', true, &['\u{30E2}']),
+    ('\u{32F3}', true, &['\u{30E4}']),
+    ('\u{32F4}', true, &['\u{30E6}']),
+    ('\u{32F5}', true, &['\u{30E8}']),
+    ('\u{32F6}', true, &['\u{30E9}']),
+    ('\u{32F7}', true, &['\u{30EA}']),
+    ('\u{32F8}', true, &['\u{30EB}']),
+    ('\u{32F9}', true, &['\u{30EC}']),
+    ('\u{32FA}', true, &['\u{30ED}']),
+    ('\u{32FB}', true, &['\u{30EF}']),
+    ('\u{32FC}', true, &['\u{30F0}']),
+    ('\u{32FD}', true, &['\u{30F1}']),
+    ('\u{32FE}', true, &['\u{30F2}']),
+    ('\u{32FF}', true, &['\u{4EE4}', '\u{548C}']),
+    ('\u{3300}', true, &['\u{30A2}', '\u{30D1}', '\u{30FC}', '\u{30C8}']),
+    ('\u{3301}', true, &['\u{30A2}', '\u{30EB}', '\u{30D5}', '\u{30A1}']),
+    ('\u{3302}', true, &['\u{30A2}', '\u{30F3}', '\u{30DA}', '\u{30A2}']),
+    ('\u{3303}', true, &['\u{30A2}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3304}', true, &['\u{30A4}', '\u{30CB}', '\u{30F3}', '\u{30B0}']),
+    ('\u{3305}', true, &['\u{30A4}', '\u{30F3}', '\u{30C1}']),
+    ('\u{3306}', true, &['\u{30A6}', '\u{30A9}', '\u{30F3}']),
+    ('\u{3307}', true, &['\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C9}']),
+    ('\u{3308}', true, &['\u{30A8}', '\u{30FC}', '\u{30AB}', '\u{30FC}']),
+    ('\u{3309}', true, &['\u{30AA}', '\u{30F3}', '\u{30B9}']),
+    ('\u{330A}', true, &['\u{30AA}', '\u{30FC}', '\u{30E0}']),
+    ('\u{330B}', true, &['\u{30AB}', '\u{30A4}', '\u{30EA}']),
+    ('\u{330C}', true, &['\u{30AB}', '\u{30E9}', '\u{30C3}', '\u{30C8}']),
+    ('\u{330D}', true, &['\u{30AB}', '\u{30ED}', '\u{30EA}', '\u{30FC}']),
+    ('\u{330E}', true, &['\u{30AC}', '\u{30ED}', '\u{30F3}']),
+    ('\u{330F}', true, &['\u{30AC}', '\u{30F3}', '\u{30DE}']),
+    ('\u{3310}', true, &['\u{30AE}', '\u{30AC}']),
+    ('\u{3311}', true, &['\u{30AE}', '\u{30CB}', '\u{30FC}']),
+    ('\u{3312}', true, &['\u{30AD}', '\u{30E5}', '\u{30EA}', '\u{30FC}']),
+    ('\u{3313}', true, &['\u{30AE}', '\u{30EB}', '\u{30C0}', '\u{30FC}']),
+    ('\u{3314}', true, &['\u{30AD}', '\u{30ED}']),
+    ('\u{3315}', true, &['\u{30AD}', '\u{30ED}', '\u{30B0}', '\u{30E9}', '\u{30E0}']),
+    ('\u{3316}', true, &['\u{30AD}', '\u{30ED}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}']),
+    ('\u{3317}', true, &['\u{30AD}', '\u{30ED}', '\u{30EF}', '\u{30C3}', '\u{30C8}']),
+    ('\u{3318}', true, &['\u{30B0}', '\u{30E9}', '\u{30E0}']),
+    ('\u{3319}', true, &['\u{30B0}', '\u{30E9}', '\u{30E0}', '\u{30C8}', '\u{30F3}']),
+    ('\u{331A}', true, &['\u{30AF}', '\u{30EB}', '\u{30BC}', '\u{30A4}', '\u{30ED}']),
+    ('\u{331B}', true, &['\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}']),
+    ('\u{331C}', true, &['\u{30B1}', '\u{30FC}', '\u{30B9}']),
+    ('\u{331D}', true, &['\u{30B3}', '\u{30EB}', '\u{30CA}']),
+    ('\u{331E}', true, &['\u{30B3}', '\u{30FC}', '\u{30DD}']),
+    ('\u{331F}', true, &['\u{30B5}', '\u{30A4}', '\u{30AF}', '\u{30EB}']),
+    ('\u{3320}', true, &['\u{30B5}', '\u{30F3}', '\u{30C1}', '\u{30FC}', '\u{30E0}']),
+    ('\u{3321}', true, &['\u{30B7}', '\u{30EA}', '\u{30F3}', '\u{30B0}']),
+    ('\u{3322}', true, &['\u{30BB}', '\u{30F3}', '\u{30C1}']),
+    ('\u{3323}', true, &['\u{30BB}', '\u{30F3}', '\u{30C8}']),
+    ('\u{3324}', true, &['\u{30C0}', '\u{30FC}', '\u{30B9}']),
+    ('\u{3325}', true, &['\u{30C7}', '\u{30B7}']),
+    ('\u{3326}', true, &['\u{30C9}', '\u{30EB}']),
+    ('\u{3327}', true, &['\u{30C8}', '\u{30F3}']),
+    ('\u{3328}', true, &['\u{30CA}', '\u{30CE}']),
+    ('\u{3329}', true, &['\u{30CE}', '\u{30C3}', '\u{30C8}']),
+    ('\u{332A}', true, &['\u{30CF}', '\u{30A4}', '\u{30C4}']),
+    ('\u{332B}', true, &['\u{30D1}', '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}']),
+    ('\u{332C}', true, &['\u{30D1}', '\u{30FC}', '\u{30C4}']),
+    ('\u{332D}', true, &['\u{30D0}', '\u{30FC}', '\u{30EC}', '\u{30EB}']),
+    ('\u{332E}', true, &['\u{30D4}', '\u{30A2}', '\u{30B9}', '\u{30C8}', '\u{30EB}']),
+    ('\u{332F}', true, &['\u{30D4}', '\u{30AF}', '\u{30EB}']),
+    ('\u{3330}', true, &['\u{30D4}', '\u{30B3}']),
+    ('\u{3331}', true, &['\u{30D3}', '\u{30EB}']),
+    ('\u{3332}', true, &['\u{30D5}', '\u{30A1}', '\u{30E9}', '\u{30C3}', '\u{30C9}']),
+    ('\u{3333}', true, &['\u{30D5}', '\u{30A3}', '\u{30FC}', '\u{30C8}']),
+    ('\u{3334}', true, &['\u{30D6}', '\u{30C3}', '\u{30B7}', '\u{30A7}', '\u{30EB}']),
+    ('\u{3335}', true, &['\u{30D5}', '\u{30E9}', '\u{30F3}']),
+    ('\u{3336}', true, &['\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3337}', true, &['\u{30DA}', '\u{30BD}']),
+    ('\u{3338}', true, &['\u{30DA}', '\u{30CB}', '\u{30D2}']),
+    ('\u{3339}', true, &['\u{30D8}', '\u{30EB}', '\u{30C4}']),
+    ('\u{333A}', true, &['\u{30DA}', '\u{30F3}', '\u{30B9}']),
+    ('\u{333B}', true, &['\u{30DA}', '\u{30FC}', '\u{30B8}']),
+    ('\u{333C}', true, &['\u{30D9}', '\u{30FC}', '\u{30BF}']),
+    ('\u{333D}', true, &['\u{30DD}', '\u{30A4}', '\u{30F3}', '\u{30C8}']),
+    ('\u{333E}', true, &['\u{30DC}', '\u{30EB}', '\u{30C8}']),
+    ('\u{333F}', true, &['\u{30DB}', '\u{30F3}']),
+    ('\u{3340}', true, &['\u{30DD}', '\u{30F3}', '\u{30C9}']),
+    ('\u{3341}', true, &['\u{30DB}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3342}', true, &['\u{30DB}', '\u{30FC}', '\u{30F3}']),
+    ('\u{3343}', true, &['\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}']),
+    ('\u{3344}', true, &['\u{30DE}', '\u{30A4}', '\u{30EB}']),
+    ('\u{3345}', true, &['\u{30DE}', '\u{30C3}', '\u{30CF}']),
+    ('\u{3346}', true, &['\u{30DE}', '\u{30EB}', '\u{30AF}']),
+    ('\u{3347}', true, &['\u{30DE}', '\u{30F3}', '\u{30B7}', '\u{30E7}', '\u{30F3}']),
+    ('\u{3348}', true, &['\u{30DF}', '\u{30AF}', '\u{30ED}', '\u{30F3}']),
+    ('\u{3349}', true, &['\u{30DF}', '\u{30EA}']),
+    ('\u{334A}', true, &['\u{30DF}', '\u{30EA}', '\u{30D0}', '\u{30FC}', '\u{30EB}']),
+    ('\u{334B}', true, &['\u{30E1}', '\u{30AC}']),
+    ('\u{334C}', true, &['\u{30E1}', '\u{30AC}', '\u{30C8}', '\u{30F3}']),
+    ('\u{334D}', true, &['\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}']),
+    ('\u{334E}', true, &['\u{30E4}', '\u{30FC}', '\u{30C9}']),
+    ('\u{334F}', true, &['\u{30E4}', '\u{30FC}', '\u{30EB}']),
+    ('\u{3350}', true, &['\u{30E6}', '\u{30A2}', '\u{30F3}']),
+    ('\u{3351}', true, &['\u{30EA}', '\u{30C3}', '\u{30C8}', '\u{30EB}']),
+    ('\u{3352}', true, &['\u{30EA}', '\u{30E9}']),
+    ('\u{3353}', true, &['\u{30EB}', '\u{30D4}', '\u{30FC}']),
+    ('\u{3354}', true, &['\u{30EB}', '\u{30FC}', '\u{30D6}', '\u{30EB}']),
+    ('\u{3355}', true, &['\u{30EC}', '\u{30E0}']),
+    ('\u{3356}', true, &['\u{30EC}', '\u{30F3}', '\u{30C8}', '\u{30B2}', '\u{30F3}']),
+    ('\u{3357}', true, &['\u{30EF}', '\u{30C3}', '\u{30C8}']),
+    ('\u{3358}', true, &['\u{30}', '\u{70B9}']),
+    ('\u{3359}', true, &['\u{31}', '\u{70B9}']),
+    ('\u{335A}', true, &['\u{32}', '\u{70B9}']),
+    ('\u{335B}', true, &['\u{33}', '\u{70B9}']),
+    ('\u{335C}', true, &['\u{34}', '\u{70B9}']),
+    ('\u{335D}', true, &['\u{35}', '\u{70B9}']),
+    ('\u{335E}', true, &['\u{36}', '\u{70B9}']),
+    ('\u{335F}', true, &['\u{37}', '\u{70B9}']),
+    ('\u{3360}', true, &['\u{38}', '\u{70B9}']),
+    ('\u{3361}', true, &['\u{39}', '\u{70B9}']),
+    ('\u{3362}', true, &['\u{31}', '\u{30}', '\u{70B9}']),
+    ('\u{3363}', true, &['\u{31}', '\u{31}', '\u{70B9}']),
+    ('\u{3364}', true, &['\u{31}', '\u{32}', '\u{70B9}']),
+    ('\u{3365}', true, &['\u{31}', '\u{33}', '\u{70B9}']),
+    ('\u{3366}', true, &['\u{31}', '\u{34}', '\u{70B9}']),
+    ('\u{3367}', true, &['\u{31}', '\u{35}', '\u{70B9}']),
+    ('\u{3368}', true, &['\u{31}', '\u{36}', '\u{70B9}']),
+    ('\u{3369}', true, &['\u{31}', '\u{37}', '\u{70B9}']),
+    ('\u{336A}', true, &['\u{31}', '\u{38}', '\u{70B9}']),
+    ('\u{336B}', true, &['\u{31}', '\u{39}', '\u{70B9}']),
+    ('\u{336C}', true, &['\u{32}', '\u{30}', '\u{70B9}']),
+    ('\u{336D}', true, &['\u{32}', '\u{31}', '\u{70B9}']),
+    ('\u{336E}', true, &['\u{32}', '\u{32}', '\u{70B9}']),
+    ('\u{336F}', true, &['\u{32}', '\u{33}', '\u{70B9}']),
+    ('\u{3370}', true, &['\u{32}', '\u{34}', '\u{70B9}']),
+    ('\u{3371}', true, &['\u{68}', '\u{50}', '\u{61}']),
+    ('\u{3372}', true, &['\u{64}', '\u{61}']),
+    ('\u{3373}', true, &['\u{41}', '\u{55}']),
+    ('\u{3374}', true, &['\u{62}', '\u{61}', '\u{72}']),
+    ('\u{3375}', true, &['\u{6F}', '\u{56}']),
+    ('\u{3376}', true, &['\u{70}', '\u{63}']),
+    ('\u{3377}', true, &['\u{64}', '\u{6D}']),
+    ('\u{3378}', true, &['\u{64}', '\u{6D}', '\u{B2}']),
+    ('\u{3379}', true, &['\u{64}', '\u{6D}', '\u{B3}']),
+    ('\u{337A}', true, &['\u{49}', '\u{55}']),
+    ('\u{337B}', true, &['\u{5E73}', '\u{6210}']),
+    ('\u{337C}', true, &['\u{662D}', '\u{548C}']),
+    ('\u{337D}', true, &['\u{5927}', '\u{6B63}']),
+    ('\u{337E}', true, &['\u{660E}', '\u{6CBB}']),
+    ('\u{337F}', true, &['\u{682A}', '\u{5F0F}', '\u{4F1A}', '\u{793E}']),
+    ('\u{3380}', true, &['\u{70}', '\u{41}']),
+    ('\u{3381}', true, &['\u{6E}', '\u{41}']),
+    ('\u{3382}', true, &['\u{3BC}', '\u{41}']),
+    ('\u{3383}', true, &['\u{6D}', '\u{41}']),
+    ('\u{3384}', true, &['\u{6B}', '\u{41}']),
+    ('\u{3385}', true, &['\u{4B}', '\u{42}']),
+    ('\u{3386}', true, &['\u{4D}', '\u{42}']),
+    ('\u{3387}', true, &['\u{47}', '\u{42}']),
+    ('\u{3388}', true, &['\u{63}', '\u{61}', '\u{6C}']),
+    ('\u{3389}', true, &['\u{6B}', '\u{63}', '\u{61}', '\u{6C}']),
+    ('\u{338A}', true, &['\u{70}', '\u{46}']),
+    ('\u{338B}', true, &['\u{6E}', '\u{46}']),
+    ('\u{338C}', true, &['\u{3BC}', '\u{46}']),
+    ('\u{338D}', true, &['\u{3BC}', '\u{67}']),
+    ('\u{338E}', true, &['\u{6D}', '\u{67}']),
+    ('\u{338F}', true, &['\u{6B}', '\u{67}']),
+    ('\u{3390}', true, &['\u{48}', '\u{7A}']),
+    ('\u{3391}', true, &['\u{6B}', '\u{48}', '\u{7A}']),
+    ('\u{3392}', true, &['\u{4D}', '\u{48}', '\u{7A}']),
+    ('\u{3393}', true, &['\u{47}', '\u{48}', '\u{7A}']),
+    ('\u{3394}', true, &['\u{54}', '\u{48}', '\u{7A}']),
+    ('\u{3395}', true, &['\u{3BC}', '\u{2113}']),
+    ('\u{3396}', true, &['\u{6D}', '\u{2113}']),
+    ('\u{3397}', true, &['\u{64}', '\u{2113}']),
+    ('\u{3398}', true, &['\u{6B}', '\u{2113}']),
+    ('\u{3399}', true, &['\u{66}', '\u{6D}']),
+    ('\u{339A}', true, &['\u{6E}', '\u{6D}']),
+    ('\u{339B}', true, &['\u{3BC}', '\u{6D}']),
+    ('\u{339C}', true, &['\u{6D}', '\u{6D}']),
+    ('\u{339D}', true, &['\u{63}', '\u{6D}']),
+    ('\u{339E}', true, &['\u{6B}', '\u{6D}']),
+    ('\u{339F}', true, &['\u{6D}', '\u{6D}', '\u{B2}']),
+    ('\u{33A0}', true, &['\u{63}', '\u{6D}', '\u{B2}']),
+    ('\u{33A1}', true, &['\u{6D}', '\u{B2}']),
+    ('\u{33A2}', true, &['\u{6B}', '\u{6D}', '\u{B2}']),
+    ('\u{33A3}', true, &['\u{6D}', '\u{6D}', '\u{B3}']),
+    ('\u{33A4}', true, &['\u{63}', '\u{6D}', '\u{B3}']),
+    ('\u{33A5}', true, &['\u{6D}', '\u{B3}']),
+    ('\u{33A6}', true, &['\u{6B}', '\u{6D}', '\u{B3}']),
+    ('\u{33A7}', true, &['\u{6D}', '\u{2215}', '\u{73}']),
+    ('\u{33A8}', true, &['\u{6D}', '\u{2215}', '\u{73}', '\u{B2}']),
+    ('\u{33A9}', true, &['\u{50}', '\u{61}']),
+    ('\u{33AA}', true, &['\u{6B}', '\u{50}', '\u{61}']),
+    ('\u{33AB}', true, &['\u{4D}', '\u{50}', '\u{61}']),
+    ('\u{33AC}', true, &['\u{47}', '\u{50}', '\u{61}']),
+    ('\u{33AD}', true, &['\u{72}', '\u{61}', '\u{64}']),
+    ('\u{33AE}', true, &['\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}']),
+    ('\u{33AF}', true, &['\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}', '\u{B2}']),
+    ('\u{33B0}', true, &['\u{70}', '\u{73}']),
+    ('\u{33B1}', true, &['\u{6E}', '\u{73}']),
+    ('\u{33B2}', true, &['\u{3BC}', '\u{73}']),
+    ('\u{33B3}', true, &['\u{6D}', '\u{73}']),
+    ('\u{33B4}', true, &['\u{70}', '\u{56}']),
+    ('\u{33B5}', true, &['\u{6E}', '\u{56}']),
+    ('\u{33B6}', true, &['\u{3BC}', '\u{56}']),
+    ('\u{33B7}', true, &['\u{6D}', '\u{56}']),
+    ('\u{33B8}', true, &['\u{6B}', '\u{56}']),
+    ('\u{33B9}', true, &['\u{4D}', '\u{56}']),
+    ('\u{33BA}', true, &['\u{70}', '\u{57}']),
+    ('\u{33BB}', true, &['\u{6E}', '\u{57}']),
+    ('\u{33BC}', true, &['\u{3BC}', '\u{57}']),
+    ('\u{33BD}', true, &['\u{6D}', '\u{57}']),
+    ('\u{33BE}', true, &['\u{6B}', '\u{57}']),
+    ('\u{33BF}', true, &['\u{4D}', '\u{57}']),
+    ('\u{33C0}', true, &['\u{6B}', '\u{3A9}']),
+    ('\u{33C1}', true, &['\u{4D}', '\u{3A9}']),
+    ('\u{33C2}', true, &['\u{61}', '\u{2E}', '\u{6D}', '\u{2E}']),
+    ('\u{33C3}', true, &['\u{42}', '\u{71}']),
+    ('\u{33C4}', true, &['\u{63}', '\u{63}']),
+    ('\u{33C5}', true, &['\u{63}', '\u{64}']),
+    ('\u{33C6}', true, &['\u{43}', '\u{2215}', '\u{6B}', '\u{67}']),
+    ('\u{33C7}', true, &['\u{43}', '\u{6F}', '\u{2E}']),
+    ('\u{33C8}', true, &['\u{64}', '\u{42}']),
+    ('\u{33C9}', true, &['\u{47}', '\u{79}']),
+    ('\u{33CA}', true, &['\u{68}', '\u{61}']),
+    ('\u{33CB}', true, &['\u{48}', '\u{50}']),
+    ('\u{33CC}', true, &['\u{69}', '\u{6E}']),
+    ('\u{33CD}', true, &['\u{4B}', '\u{4B}']),
+    ('\u{33CE}', true, &['\u{4B}', '\u{4D}']),
+    ('\u{33CF}', true, &['\u{6B}', '\u{74}']),
+    ('\u{33D0}', true, &['\u{6C}', '\u{6D}']),
+    ('\u{33D1}', true, &['\u{6C}', '\u{6E}']),
+    ('\u{33D2}', true, &['\u{6C}', '\u{6F}', '\u{67}']),
+    ('\u{33D3}', true, &['\u{6C}', '\u{78}']),
+    ('\u{33D4}', true, &['\u{6D}', '\u{62}']),
+    ('\u{33D5}', true, &['\u{6D}', '\u{69}', '\u{6C}']),
+    ('\u{33D6}', true, &['\u{6D}', '\u{6F}', '\u{6C}']),
+    ('\u{33D7}', true, &['\u{50}', '\u{48}']),
+    ('\u{33D8}', true, &['\u{70}', '\u{2E}', '\u{6D}', '\u{2E}']),
+    ('\u{33D9}', true, &['\u{50}', '\u{50}', '\u{4D}']),
+    ('\u{33DA}', true, &['\u{50}', '\u{52}']),
+    ('\u{33DB}', true, &['\u{73}', '\u{72}']),
+    ('\u{33DC}', true, &['\u{53}', '\u{76}']),
+    ('\u{33DD}', true, &['\u{57}', '\u{62}']),
+    ('\u{33DE}', true, &['\u{56}', '\u{2215}', '\u{6D}']),
+    ('\u{33DF}', true, &['\u{41}', '\u{2215}', '\u{6D}']),
+    ('\u{33E0}', true, &['\u{31}', '\u{65E5}']),
+    ('\u{33E1}', true, &['\u{32}', '\u{65E5}']),
+    ('\u{33E2}', true, &['\u{33}', '\u{65E5}']),
+    ('\u{33E3}', true, &['\u{34}', '\u{65E5}']),
+    ('\u{33E4}', true, &['\u{35}', '\u{65E5}']),
+    ('\u{33E5}', true, &['\u{36}', '\u{65E5}']),
+    ('\u{33E6}', true, &['\u{37}', '\u{65E5}']),
+    ('\u{33E7}', true, &['\u{38}', '\u{65E5}']),
+    ('\u{33E8}', true, &['\u{39}', '\u{65E5}']),
+    ('\u{33E9}', true, &['\u{31}', '\u{30}', '\u{65E5}']),
+    ('\u{33EA}', true, &['\u{31}', '\u{31}', '\u{65E5}']),
+    ('\u{33EB}', true, &['\u{31}', '\u{32}', '\u{65E5}']),
+    ('\u{33EC}', true, &['\u{31}', '\u{33}', '\u{65E5}']),
+    ('\u{33ED}', true, &['\u{31}', '\u{34}', '\u{65E5}']),
+    ('\u{33EE}', true, &['\u{31}', '\u{35}', '\u{65E5}']),
+    ('\u{33EF}', true, &['\u{31}', '\u{36}', '\u{65E5}']),
+    ('\u{33F0}', true, &['\u{31}', '\u{37}', '\u{65E5}']),
+    ('\u{33F1}', true, &['\u{31}', '\u{38}', '\u{65E5}']),
+    ('\u{33F2}', true, &['\u{31}', '\u{39}', '\u{65E5}']),
+    ('\u{33F3}', true, &['\u{32}', '\u{30}', '\u{65E5}']),
+    ('\u{33F4}', true, &['\u{32}', '\u{31}', '\u{65E5}']),
+    ('\u{33F5}', true, &['\u{32}', '\u{32}', '\u{65E5}']),
+    ('\u{33F6}', true, &['\u{32}', '\u{33}', '\u{65E5}']),
+    ('\u{33F7}', true, &['\u{32}', '\u{34}', '\u{65E5}']),
+    ('\u{33F8}', true, &['\u{32}', '\u{35}', '\u{65E5}']),
+    ('\u{33F9}', true, &['\u{32}', '\u{36}', '\u{65E5}']),
+    ('\u{33FA}', true, &['\u{32}', '\u{37}', '\u{65E5}']),
+    ('\u{33FB}', true, &['\u{32}', '\u{38}', '\u{65E5}']),
+    ('\u{33FC}', true, &['\u{32}', '\u{39}', '\u{65E5}']),
+    ('\u{33FD}', true, &['\u{33}', '\u{30}', '\u{65E5}']),
+    ('\u{33FE}', true, &['\u{33}', '\u{31}', '\u{65E5}']),
+    ('\u{33FF}', true, &['\u{67}', '\u{61}', '\u{6C}']),
+    ('\u{A69C}', true, &['\u{44A}']),
+    ('\u{A69D}', true, &['\u{44C}']),
+    ('\u{A770}', true, &['\u{A76F}']),
+    ('\u{A7F2}', true, &['\u{43}']),
+    ('\u{A7F3}', true, &['\u{46}']),
+    ('\u{A7F4}', true, &['\u{51}']),
+    ('\u{A7F8}', true, &['\u{126}']),
+    ('\u{A7F9}', true, &['\u{153}']),
+    ('\u{AB5C}', true, &['\u{A727}']),
+    ('\u{AB5D}', true, &['\u{AB37}']),
+    ('\u{AB5E}', true, &['\u{26B}']),
+    ('\u{AB5F}', true, &['\u{AB52}']),
+    ('\u{AB69}', true, &['\u{28D}']),
+    ('\u{F900}', false, &['\u{8C48}']),
+    ('\u{F901}', false, &['\u{66F4}']),
+    ('\u{F902}', false, &['\u{8ECA}']),
+    ('\u{F903}', false, &['\u{8CC8}']),
+    ('\u{F904}', false, &['\u{6ED1}']),
+    ('\u{F905}', false, &['\u{4E32}']),
+    ('\u{F906}', false, &['\u{53E5}']),
+    ('\u{F907}', false, &['\u{9F9C}']),
+    ('\u{F908}', false, &['\u{9F9C}']),
+    ('\u{F909}', false, &['\u{5951}']),
+    ('\u{F90A}', false, &['\u{91D1}']),
+    ('\u{F90B}', false, &['\u{5587}']),
+    ('\u{F90C}', false, &['\u{5948}']),
+    ('\u{F90D}', false, &['\u{61F6}']),
+    ('\u{F90E}', false, &['\u{7669}']),
+    ('\u{F90F}', false, &['\u{7F85}']),
+    ('\u{F910}', false, &['\u{863F}']),
+    ('\u{F911}', false, &['\u{87BA}']),
+    ('\u{F912}', false, &['\u{88F8}']),
+    ('\u{F913}', false, &['\u{908F}']),
+    ('\u{F914}', false, &['\u{6A02}']),
+    ('\u{F915}', false, &['\u{6D1B}']),
+    ('\u{F916}', false, &['\u{70D9}']),
+    ('\u{F917}', false, &['\u{73DE}']),
+    ('\u{F918}', false, &['\u{843D}']),
+    ('\u{F919}', false, &['\u{916A}']),
+    ('\u{F91A}', false, &['\u{99F1}']),
+    ('\u{F91B}', false, &['\u{4E82}']),
+    ('\u{F91C}', false, &['\u{5375}']),
+    ('\u{F91D}', false, &['\u{6B04}']),
+    ('\u{F91E}', false, &['\u{721B}']),
+    ('\u{F91F}', false, &['\u{862D}']),
+    ('\u{F920}', false, &['\u{9E1E}']),
+    ('\u{F921}', false, &['\u{5D50}']),
+    ('\u{F922}', false, &['\u{6FEB}']),
+    ('\u{F923}', false, &['\u{85CD}']),
+    ('\u{F924}', false, &['\u{8964}']),
+    ('\u{F925}', false, &['\u{62C9}']),
+    ('\u{F926}', false, &['\u{81D8}']),
+    ('\u{F927}', false, &['\u{881F}']),
+    ('\u{F928}', false, &['\u{5ECA}']),
+    ('\u{F929}', false, &['\u{6717}']),
+    ('\u{F92A}', false, &['\u{6D6A}']),
+    ('\u{F92B}', false, &['\u{72FC}']),
+    ('\u{F92C}', false, &['\u{90CE}']),
+    ('\u{F92D}', false, &['\u{4F86}']),
+    ('\u{F92E}', false, &['\u{51B7}']),
+    ('\u{F92F}', false, &['\u{52DE}']),
+    ('\u{F930}', false, &['\u{64C4}']),
+    ('\u{F931}', false, &['\u{6AD3}']),
+    ('\u{F932}', false, &['\u{7210}']),
+    ('\u{F933}', false, &['\u{76E7}']),
+    ('\u{F934}', false, &['\u{8001}']),
+    ('\u{F935}', false, &['\u{8606}']),
+    ('\u{F936}', false, &['\u{865C}']),
+    ('\u{F937}', false, &['\u{8DEF}']),
+    ('\u{F938}', false, &['\u{9732}']),
+    ('\u{F939}', false, &['\u{9B6F}']),
+    ('\u{F93A}', false, &['\u{9DFA}']),
+    ('\u{F93B}', false, &['\u{788C}']),
+    ('\u{F93C}', false, &['\u{797F}']),
+    ('\u{F93D}', false, &['\u{7DA0}']),
+    ('\u{F93E}', false, &['\u{83C9}']),
+    ('\u{F93F}', false, &['\u{9304}']),
+    ('\u{F940}', false, &['\u{9E7F}']),
+    ('\u{F941}', false, &['\u{8AD6}']),
+    ('\u{F942}', false, &['\u{58DF}']),
+    ('\u{F943}', false, &['\u{5F04}']),
+    ('\u{F944}', false, &['\u{7C60}']),
+    ('\u{F945}', false, &['\u{807E}']),
+    ('\u{F946}', false, &['\u{7262}']),
+    ('\u{F947}', false, &['\u{78CA}']),
+    ('\u{F948}', false, &['\u{8CC2}']),
+    ('\u{F949}', false, &['\u{96F7}']),
+    ('\u{F94A}', false, &['\u{58D8}']),
+    ('\u{F94B}', false, &['\u{5C62}']),
+    ('\u{F94C}', false, &['\u{6A13}']),
+    ('\u{F94D}', false, &['\u{6DDA}']),
+    ('\u{F94E}', false, &['\u{6F0F}']),
+    ('\u{F94F}', false, &['\u{7D2F}']),
+    ('\u{F950}', false, &['\u{7E37}']),
+    ('\u{F951}', false, &['\u{964B}']),
+    ('\u{F952}', false, &['\u{52D2}']),
+    ('\u{F953}', false, &['\u{808B}']),
+    ('\u{F954}', false, &['\u{51DC}']),
+    ('\u{F955}', false, &['\u{51CC}']),
+    ('\u{F956}', false, &['\u{7A1C}']),
+    ('\u{F957}', false, &['\u{7DBE}']),
+    ('\u{F958}', false, &['\u{83F1}']),
+    ('\u{F959}', false, &['\u{9675}']),
+    ('\u{F95A}', false, &['\u{8B80}']),
+    ('\u{F95B}', false, &['\u{62CF}']),
+    ('\u{F95C}', false, &['\u{6A02}']),
+    ('\u{F95D}', false, &['\u{8AFE}']),
+    ('\u{F95E}', false, &['\u{4E39}']),
+    ('\u{F95F}', false, &['\u{5BE7}']),
+    ('\u{F960}', false, &['\u{6012}']),
+    ('\u{F961}', false, &['\u{7387}']),
+    ('\u{F962}', false, &['\u{7570}']),
+    ('\u{F963}', false, &['\u{5317}']),
+    ('\u{F964}', false, &['\u{78FB}']),
+    ('\u{F965}', false, &['\u{4FBF}']),
+    ('\u{F966}', false, &['\u{5FA9}']),
+    ('\u{F967}', false, &['\u{4E0D}']),
+    ('\u{F968}', false, &['\u{6CCC}']),
+    ('\u{F969}', false, &['\u{6578}']),
+    ('\u{F96A}', false, &['\u{7D22}']),
+    ('\u{F96B}', false, &['\u{53C3}']),
+    ('\u{F96C}', false, &['\u{585E}']),
+    ('\u{F96D}', false, &['\u{7701}']),
+    ('\u{F96E}', false, &['\u{8449}']),
+    ('\u{F96F}', false, &['\u{8AAA}']),
+    ('\u{F970}', false, &['\u{6BBA}']),
+    ('\u{F971}', false, &['\u{8FB0}']),
+    ('\u{F972}', false, &['\u{6C88}']),
+    ('\u{F973}', false, &['\u{62FE}']),
+    ('\u{F974}', false, &['\u{82E5}']),
+    ('\u{F975}', false, &['\u{63A0}']),
+    ('\u{F976}', false, &['\u{7565}']),
+    ('\u{F977}', false, &['\u{4EAE}']),
+    ('\u{F978}', false, &['\u{5169}']),
+    ('\u{F979}', false, &['\u{51C9}']),
+    ('\u{F97A}', false, &['\u{6881}']),
+    ('\u{F97B}', false, &['\u{7CE7}']),
+    ('\u{F97C}', false, &['\u{826F}']),
+    ('\u{F97D}', false, &['\u{8AD2}']),
+    ('\u{F97E}', false, &['\u{91CF}']),
+    ('\u{F97F}', false, &['\u{52F5}']),
+    ('\u{F980}', false, &['\u{5442}']),
+    ('\u{F981}', false, &['\u{5973}']),
+    ('\u{F982}', false, &['\u{5EEC}']),
+    ('\u{F983}', false, &['\u{65C5}']),
+    ('\u{F984}', false, &['\u{6FFE}']),
+    ('\u{F985}', false, &['\u{792A}']),
+    ('\u{F986}', false, &['\u{95AD}']),
+    ('\u{F987}', false, &['\u{9A6A}']),
+    ('\u{F988}', false, &['\u{9E97}']),
+    ('\u{F989}', false, &['\u{9ECE}']),
+    ('\u{F98A}', false, &['\u{529B}']),
+    ('\u{F98B}', false, &['\u{66C6}']),
+    ('\u{F98C}', false, &['\u{6B77}']),
+    ('\u{F98D}', false, &['\u{8F62}']),
+    ('\u{F98E}', false, &['\u{5E74}']),
+    ('\u{F98F}', false, &['\u{6190}']),
+    ('\u{F990}', false, &['\u{6200}']),
+    ('\u{F991}', false, &['\u{649A}']),
+    ('\u{F992}', false, &['\u{6F23}']),
+    ('\u{F993}', false, &['\u{7149}']),
+    ('\u{F994}', false, &['\u{7489}']),
+    ('\u{F995}', false, &['\u{79CA}']),
+    ('\u{F996}', false, &['\u{7DF4}']),
+    ('\u{F997}', false, &['\u{806F}']),
+    ('\u{F998}', false, &['\u{8F26}']),
+    ('\u{F999}', false, &['\u{84EE}']),
+    ('\u{F99A}', false, &['\u{9023}']),
+    ('\u{F99B}', false, &['\u{934A}']),
+    ('\u{F99C}', false, &['\u{5217}']),
+    ('\u{F99D}', false, &['\u{52A3}']),
+    ('\u{F99E}', false, &['\u{54BD}']),
+    ('\u{F99F}', false, &['\u{70C8}']),
+    ('\u{F9A0}', false, &['\u{88C2}']),
+    ('\u{F9A1}', false, &['\u{8AAA}']),
+    ('\u{F9A2}', false, &['\u{5EC9}']),
+    ('\u{F9A3}', false, &['\u{5FF5}']),
+    ('\u{F9A4}', false, &['\u{637B}']),
+    ('\u{F9A5}', false, &['\u{6BAE}']),
+    ('\u{F9A6}', false, &['\u{7C3E}']),
+    ('\u{F9A7}', false, &['\u{7375}']),
+    ('\u{F9A8}', false, &['\u{4EE4}']),
+    ('\u{F9A9}', false, &['\u{56F9}']),
+    ('\u{F9AA}', false, &['\u{5BE7}']),
+    ('\u{F9AB}', false, &['\u{5DBA}']),
+    ('\u{F9AC}', false, &['\u{601C}']),
+    ('\u{F9AD}', false, &['\u{73B2}']),
+    ('\u{F9AE}', false, &['\u{7469}']),
+    ('\u{F9AF}', false, &['\u{7F9A}']),
+    ('\u{F9B0}', false, &['\u{8046}']),
+    ('\u{F9B1}', false, &['\u{9234}']),
+    ('\u{F9B2}', false, &['\u{96F6}']),
+    ('\u{F9B3}', false, &['\u{9748}']),
+    ('\u{F9B4}', false, &['\u{9818}']),
+    ('\u{F9B5}', false, &['\u{4F8B}']),
+    ('\u{F9B6}', false, &['\u{79AE}']),
+    ('\u{F9B7}', false, &['\u{91B4}']),
+    ('\u{F9B8}', false, &['\u{96B8}']),
+    ('\u{F9B9}', false, &['\u{60E1}']),
+    ('\u{F9BA}', false, &['\u{4E86}']),
+    ('\u{F9BB}', false, &['\u{50DA}']),
+    ('\u{F9BC}', false, &['\u{5BEE}']),
+    ('\u{F9BD}', false, &['\u{5C3F}']),
+    ('\u{F9BE}', false, &['\u{6599}']),
+    ('\u{F9BF}', false, &['\u{6A02}']),
+    ('\u{F9C0}', false, &['\u{71CE}']),
+    ('\u{F9C1}', false, &['\u{7642}']),
+    ('\u{F9C2}', false, &['\u{84FC}']),
+    ('\u{F9C3}', false, &['\u{907C}']),
+    ('\u{F9C4}', false, &['\u{9F8D}']),
+    ('\u{F9C5}', false, &['\u{6688}']),
+    ('\u{F9C6}', false, &['\u{962E}']),
+    ('\u{F9C7}', false, &['\u{5289}']),
+    ('\u{F9C8}', false, &['\u{677B}']),
+    ('\u{F9C9}', false, &['\u{67F3}']),
+    ('\u{F9CA}', false, &['\u{6D41}']),
+    ('\u{F9CB}', false, &['\u{6E9C}']),
+    ('\u{F9CC}', false, &['\u{7409}']),
+    ('\u{F9CD}', false, &['\u{7559}']),
+    ('\u{F9CE}', false, &['\u{786B}']),
+    ('\u{F9CF}', false, &['\u{7D10}']),
+    ('\u{F9D0}', false, &['\u{985E}']),
+    ('\u{F9D1}', false, &['\u{516D}']),
+    ('\u{F9D2}', false, &['\u{622E}']),
+    ('\u{F9D3}', false, &['\u{9678}']),
+    ('\u{F9D4}', false, &['\u{502B}']),
+    ('\u{F9D5}', false, &['\u{5D19}']),
+    ('\u{F9D6}', false, &['\u{6DEA}']),
+    ('\u{F9D7}', false, &['\u{8F2A}']),
+    ('\u{F9D8}', false, &['\u{5F8B}']),
+    ('\u{F9D9}', false, &['\u{6144}']),
+    ('\u{F9DA}', false, &['\u{6817}']),
+    ('\u{F9DB}', false, &['\u{7387}']),
+    ('\u{F9DC}', false, &['\u{9686}']),
+    ('\u{F9DD}', false, &['\u{5229}']),
+    ('\u{F9DE}', false, &['\u{540F}']),
+    ('\u{F9DF}', false, &['\u{5C65}']),
+    ('\u{F9E0}', false, &['\u{6613}']),
+    ('\u{F9E1}', false, &['\u{674E}']),
+    ('\u{F9E2}', false, &['\u{68A8}']),
+    ('\u{F9E3}', false, &['\u{6CE5}']),
+    ('\u{F9E4}', false, &['\u{7406}']),
+    ('\u{F9E5}', false, &['\u{75E2}']),
+    ('\u{F9E6}', false, &['\u{7F79}']),
+    ('\u{F9E7}', false, &['\u{88CF}']),
+    ('\u{F9E8}', false, &['\u{88E1}']),
+    ('\u{F9E9}', false, &['\u{91CC}']),
+    ('\u{F9EA}', false, &['\u{96E2}']),
+    ('\u{F9EB}', false, &['\u{533F}']),
+    ('\u{F9EC}', false, &['\u{6EBA}']),
+    ('\u{F9ED}', false, &['\u{541D}']),
+    ('\u{F9EE}', false, &['\u{71D0}']),
+    ('\u{F9EF}', false, &['\u{7498}']),
+    ('\u{F9F0}', false, &['\u{85FA}']),
+    ('\u{F9F1}', false, &['\u{96A3}']),
+    ('\u{F9F2}', false, &['\u{9C57}']),
+    ('\u{F9F3}', false, &['\u{9E9F}']),
+    ('\u{F9F4}', false, &['\u{6797}']),
+    ('\u{F9F5}', false, &['\u{6DCB}']),
+    ('\u{F9F6}', false, &['\u{81E8}']),
+    ('\u{F9F7}', false, &['\u{7ACB}']),
+    ('\u{F9F8}', false, &['\u{7B20}']),
+    ('\u{F9F9}', false, &['\u{7C92}']),
+    ('\u{F9FA}', false, &['\u{72C0}']),
+    ('\u{F9FB}', false, &['\u{7099}']),
+    ('\u{F9FC}', false, &['\u{8B58}']),
+    ('\u{F9FD}', false, &['\u{4EC0}']),
+    ('\u{F9FE}', false, &['\u{8336}']),
+    ('\u{F9FF}', false, &['\u{523A}']),
+    ('\u{FA00}', false, &['\u{5207}']),
+    ('\u{FA01}', false, &['\u{5EA6}']),
+    ('\u{FA02}', false, &['\u{62D3}']),
+    ('\u{FA03}', false, &['\u{7CD6}']),
+    ('\u{FA04}', false, &['\u{5B85}']),
+    ('\u{FA05}', false, &['\u{6D1E}']),
+    ('\u{FA06}', false, &['\u{66B4}']),
+    ('\u{FA07}', false, &['\u{8F3B}']),
+    ('\u{FA08}', false, &['\u{884C}']),
+    ('\u{FA09}', false, &['\u{964D}']),
+    ('\u{FA0A}', false, &['\u{898B}']),
+    ('\u{FA0B}', false, &['\u{5ED3}']),
+    ('\u{FA0C}', false, &['\u{5140}']),
+    ('\u{FA0D}', false, &['\u{55C0}']),
+    ('\u{FA10}', false, &['\u{585A}']),
+    ('\u{FA12}', false, &['\u{6674}']),
+    ('\u{FA15}', false, &['\u{51DE}']),
+    ('\u{FA16}', false, &['\u{732A}']),
+    ('\u{FA17}', false, &['\u{76CA}']),
+    ('\u{FA18}', false, &['\u{793C}']),
+    ('\u{FA19}', false, &['\u{795E}']),
+    ('\u{FA1A}', false, &['\u{7965}']),
+    ('\u{FA1B}', false, &['\u{798F}']),
+    ('\u{FA1C}', false, &['\u{9756}']),
+    ('\u{FA1D}', false, &['\u{7CBE}']),
+    ('\u{FA1E}', false, &['\u{7FBD}']),
+    ('\u{FA20}', false, &['\u{8612}']),
+    ('\u{FA22}', false, &['\u{8AF8}']),
+    ('\u{FA25}', false, &['\u{9038}']),
+    ('\u{FA26}', false, &['\u{90FD}']),
+    ('\u{FA2A}', false, &['\u{98EF}']),
+    ('\u{FA2B}', false, &['\u{98FC}']),
+    ('\u{FA2C}', false, &['\u{9928}']),
+    ('\u{FA2D}', false, &['\u{9DB4}']),
+    ('\u{FA2E}', false, &['\u{90DE}']),
+    ('\u{FA2F}', false, &['\u{96B7}']),
+    ('\u{FA30}', false, &['\u{4FAE}']),
+    ('\u{FA31}', false, &['\u{50E7}']),
+    ('\u{FA32}', false, &['\u{514D}']),
+    ('\u{FA33}', false, &['\u{52C9}']),
+    ('\u{FA34}', false, &['\u{52E4}']),
+    ('\u{FA35}', false, &['\u{5351}']),
+    ('\u{FA36}', false, &['\u{559D}']),
+    ('\u{FA37}', false, &['\u{5606}']),
+    ('\u{FA38}', false, &['\u{5668}']),
+    ('\u{FA39}', false, &['\u{5840}']),
+    ('\u{FA3A}', false, &['\u{58A8}']),
+    ('\u{FA3B}', false, &['\u{5C64}']),
+    ('\u{FA3C}', false, &['\u{5C6E}']),
+    ('\u{FA3D}', false, &['\u{6094}']),
+    ('\u{FA3E}', false, &['\u{6168}']),
+    ('\u{FA3F}', false, &['\u{618E}']),
+    ('\u{FA40}', false, &['\u{61F2}']),
+    ('\u{FA41}', false, &['\u{654F}']),
+    ('\u{FA42}', false, &['\u{65E2}']),
+    ('\u{FA43}', false, &['\u{6691}']),
+    ('\u{FA44}', false, &['\u{6885}']),
+    ('\u{FA45}', false, &['\u{6D77}']),
+    ('\u{FA46}', false, &['\u{6E1A}']),
+    ('\u{FA47}', false, &['\u{6F22}']),
+    ('\u{FA48}', false, &['\u{716E}']),
+    ('\u{FA49}', false, &['\u{722B}']),
+    ('\u{FA4A}', false, &['\u{7422}']),
+    ('\u{FA4B}', false, &['\u{7891}']),
+    ('\u{FA4C}', false, &['\u{793E}']),
+    ('\u{FA4D}', false, &['\u{7949}']),
+    ('\u{FA4E}', false, &['\u{7948}']),
+    ('\u{FA4F}', false, &['\u{7950}']),
+    ('\u{FA50}', false, &['\u{7956}']),
+    ('\u{FA51}', false, &['\u{795D}']),
+    ('\u{FA52}', false, &['\u{798D}']),
+    ('\u{FA53}', false, &['\u{798E}']),
+    ('\u{FA54}', false, &['\u{7A40}']),
+    ('\u{FA55}', false, &['\u{7A81}']),
+    ('\u{FA56}', false, &['\u{7BC0}']),
+    ('\u{FA57}', false, &['\u{7DF4}']),
+    ('\u{FA58}', false, &['\u{7E09}']),
+    ('\u{FA59}', false, &['\u{7E41}']),
+    ('\u{FA5A}', false, &['\u{7F72}']),
+    ('\u{FA5B}', false, &['\u{8005}']),
+    ('\u{FA5C}', false, &['\u{81ED}']),
+    ('\u{FA5D}', false, &['\u{8279}']),
+    ('\u{FA5E}', false, &['\u{8279}']),
+    ('\u{FA5F}', false, &['\u{8457}']),
+    ('\u{FA60}', false, &['\u{8910}']),
+    ('\u{FA61}', false, &['\u{8996}']),
+    ('\u{FA62}', false, &['\u{8B01}']),
+    ('\u{FA63}', false, &['\u{8B39}']),
+    ('\u{FA64}', false, &['\u{8CD3}']),
+    ('\u{FA65}', false, &['\u{8D08}']),
+    ('\u{FA66}', false, &['\u{8FB6}']),
+    ('\u{FA67}', false, &['\u{9038}']),
+    ('\u{FA68}', false, &['\u{96E3}']),
+    ('\u{FA69}', false, &['\u{97FF}']),
+    ('\u{FA6A}', false, &['\u{983B}']),
+    ('\u{FA6B}', false, &['\u{6075}']),
+    ('\u{FA6C}', false, &['\u{242EE}']),
+    ('\u{FA6D}', false, &['\u{8218}']),
+    ('\u{FA70}', false, &['\u{4E26}']),
+    ('\u{FA71}', false, &['\u{51B5}']),
+    ('\u{FA72}', false, &['\u{5168}']),
+    ('\u{FA73}', false, &['\u{4F80}']),
+    ('\u{FA74}', false, &['\u{5145}']),
+    ('\u{FA75}', false, &['\u{5180}']),
+    ('\u{FA76}', false, &['\u{52C7}']),
+    ('\u{FA77}', false, &['\u{52FA}']),
+    ('\u{FA78}', false, &['\u{559D}']),
+    ('\u{FA79}', false, &['\u{5555}']),
+    ('\u{FA7A}', false, &['\u{5599}']),
+    ('\u{FA7B}', false, &['\u{55E2}']),
+    ('\u{FA7C}', false, &['\u{585A}']),
+    ('\u{FA7D}', false, &['\u{58B3}']),
+    ('\u{FA7E}', false, &['\u{5944}']),
+    ('\u{FA7F}', false, &['\u{5954}']),
+    ('\u{FA80}', false, &['\u{5A62}']),
+    ('\u{FA81}', false, &['\u{5B28}']),
+    ('\u{FA82}', false, &['\u{5ED2}']),
+    ('\u{FA83}', false, &['\u{5ED9}']),
+    ('\u{FA84}', false, &['\u{5F69}']),
+    ('\u{FA85}', false, &['\u{5FAD}']),
+    ('\u{FA86}', false, &['\u{60D8}']),
+    ('\u{FA87}', false, &['\u{614E}']),
+    ('\u{FA88}', false, &['\u{6108}']),
+    ('\u{FA89}', false, &['\u{618E}']),
+    ('\u{FA8A}', false, &['\u{6160}']),
+    ('\u{FA8B}', false, &['\u{61F2}']),
+    ('\u{FA8C}', false, &['\u{6234}']),
+    ('\u{FA8D}', false, &['\u{63C4}']),
+    ('\u{FA8E}', false, &['\u{641C}']),
+    ('\u{FA8F}', false, &['\u{6452}']),
+    ('\u{FA90}', false, &['\u{6556}']),
+    ('\u{FA91}', false, &['\u{6674}']),
+    ('\u{FA92}', false, &['\u{6717}']),
+    ('\u{FA93}', false, &['\u{671B}']),
+    ('\u{FA94}', false, &['\u{6756}']),
+    ('\u{FA95}', false, &['\u{6B79}']),
+    ('\u{FA96}', false, &['\u{6BBA}']),
+    ('\u{FA97}', false, &['\u{6D41}']),
+    ('\u{FA98}', false, &['\u{6EDB}']),
+    ('\u{FA99}', false, &['\u{6ECB}']),
+    ('\u{FA9A}', false, &['\u{6F22}']),
+    ('\u{FA9B}', false, &['\u{701E}']),
+    ('\u{FA9C}', false, &['\u{716E}']),
+    ('\u{FA9D}', false, &['\u{77A7}']),
+    ('\u{FA9E}', false, &['\u{7235}']),
+    ('\u{FA9F}', false, &['\u{72AF}']),
+    ('\u{FAA0}', false, &['\u{732A}']),
+    ('\u{FAA1}', false, &['\u{7471}']),
+    ('\u{FAA2}', false, &['\u{7506}']),
+    ('\u{FAA3}', false, &['\u{753B}']),
+    ('\u{FAA4}', false, &['\u{761D}']),
+    ('\u{FAA5}', false, &['\u{761F}']),
+    ('\u{FAA6}', false, &['\u{76CA}']),
+    ('\u{FAA7}', false, &['\u{76DB}']),
+    ('\u{FAA8}', false, &['\u{76F4}']),
+    ('\u{FAA9}', false, &['\u{774A}']),
+    ('\u{FAAA}', false, &['\u{7740}']),
+    ('\u{FAAB}', false, &['\u{78CC}']),
+    ('\u{FAAC}', false, &['\u{7AB1}']),
+    ('\u{FAAD}', false, &['\u{7BC0}']),
+    ('\u{FAAE}', false, &['\u{7C7B}']),
+    ('\u{FAAF}', false, &['\u{7D5B}']),
+    ('\u{FAB0}', false, &['\u{7DF4}']),
+    ('\u{FAB1}', false, &['\u{7F3E}']),
+    ('\u{FAB2}', false, &['\u{8005}']),
+    ('\u{FAB3}', false, &['\u{8352}']),
+    ('\u{FAB4}', false, &['\u{83EF}']),
+    ('\u{FAB5}', false, &['\u{8779}']),
+    ('\u{FAB6}', false, &['\u{8941}']),
+    ('\u{FAB7}', false, &['\u{8986}']),
+    ('\u{FAB8}', false, &['\u{8996}']),
+    ('\u{FAB9}', false, &['\u{8ABF}']),
+    ('\u{FABA}', false, &['\u{8AF8}']),
+    ('\u{FABB}', false, &['\u{8ACB}']),
+    ('\u{FABC}', false, &['\u{8B01}']),
+    ('\u{FABD}', false, &['\u{8AFE}']),
+    ('\u{FABE}', false, &['\u{8AED}']),
+    ('\u{FABF}', false, &['\u{8B39}']),
+    ('\u{FAC0}', false, &['\u{8B8A}']),
+    ('\u{FAC1}', false, &['\u{8D08}']),
+    ('\u{FAC2}', false, &['\u{8F38}']),
+    ('\u{FAC3}', false, &['\u{9072}']),
+    ('\u{FAC4}', false, &['\u{9199}']),
+    ('\u{FAC5}', false, &['\u{9276}']),
+    ('\u{FAC6}', false, &['\u{967C}']),
+    ('\u{FAC7}', false, &['\u{96E3}']),
+    ('\u{FAC8}', false, &['\u{9756}']),
+    ('\u{FAC9}', false, &['\u{97DB}']),
+    ('\u{FACA}', false, &['\u{97FF}']),
+    ('\u{FACB}', false, &['\u{980B}']),
+    ('\u{FACC}', false, &['\u{983B}']),
+    ('\u{FACD}', false, &['\u{9B12}']),
+    ('\u{FACE}', false, &['\u{9F9C}']),
+    ('\u{FACF}', false, &['\u{2284A}']),
+    ('\u{FAD0}', false, &['\u{22844}']),
+    ('\u{FAD1}', false, &['\u{233D5}']),
+    ('\u{FAD2}', false, &['\u{3B9D}']),
+    ('\u{FAD3}', false, &['\u{4018}']),
+    ('\u{FAD4}', false, &['\u{4039}']),
+    ('\u{FAD5}', false, &['\u{25249}']),
+    ('\u{FAD6}', false, &['\u{25CD0}']),
+    ('\u{FAD7}', false, &['\u{27ED3}']),
+    ('\u{FAD8}', false, &['\u{9F43}']),
+    ('\u{FAD9}', false, &['\u{9F8E}']),
+    ('\u{FB00}', true, &['\u{66}', '\u{66}']),
+    ('\u{FB01}', true, &['\u{66}', '\u{69}']),
+    ('\u{FB02}', true, &['\u{66}', '\u{6C}']),
+    ('\u{FB03}', true, &['\u{66}', '\u{66}', '\u{69}']),
+    ('\u{FB04}', true, &['\u{66}', '\u{66}', '\u{6C}']),
+    ('\u{FB05}', true, &['\u{17F}', '\u{74}']),
+    ('\u{FB06}', true, &['\u{73}', '\u{74}']),
+    ('\u{FB13}', true, &['\u{574}', '\u{576}']),
+    ('\u{FB14}', true, &['\u{574}', '\u{565}']),
+    ('\u{FB15}', true, &['\u{574}', '\u{56B}']),
+    ('\u{FB16}', true, &['\u{57E}', '\u{576}']),
+    ('\u{FB17}', true, &['\u{574}', '\u{56D}']),
+    ('\u{FB1D}', false, &['\u{5D9}', '\u{5B4}']),
+    ('\u{FB1F}', false, &['\u{5F2}', '\u{5B7}']),
+    ('\u{FB20}', true, &['\u{5E2}']),
+    ('\u{FB21}', true, &['\u{5D0}']),
+    ('\u{FB22}', true, &['\u{5D3}']),
+    ('\u{FB23}', true, &['\u{5D4}']),
+    ('\u{FB24}', true, &['\u{5DB}']),
+    ('\u{FB25}', true, &['\u{5DC}']),
+    ('\u{FB26}', true, &['\u{5DD}']),
+    ('\u{FB27}', true, &['\u{5E8}']),
+    ('\u{FB28}', true, &['\u{5EA}']),
+    ('\u{FB29}', true, &['\u{2B}']),
+    ('\u{FB2A}', false, &['\u{5E9}', '\u{5C1}']),
+    ('\u{FB2B}', false, &['\u{5E9}', '\u{5C2}']),
+    ('\u{FB2C}', false, &['\u{FB49}', '\u{5C1}']),
+    ('\u{FB2D}', false, &['\u{FB49}', '\u{5C2}']),
+    ('\u{FB2E}', false, &['\u{5D0}', '\u{5B7}']),
+    ('\u{FB2F}', false, &['\u{5D0}', '\u{5B8}']),
+    ('\u{FB30}', false, &['\u{5D0}', '\u{5BC}']),
+    ('\u{FB31}', false, &['\u{5D1}', '\u{5BC}']),
+    ('\u{FB32}', false, &['\u{5D2}', '\u{5BC}']),
+    ('\u{FB33}', false, &['\u{5D3}', '\u{5BC}']),
+    ('\u{FB34}', false, &['\u{5D4}', '\u{5BC}']),
+    ('\u{FB35}', false, &['\u{5D5}', '\u{5BC}']),
+    ('\u{FB36}', false, &['\u{5D6}', '\u{5BC}']),
+    ('\u{FB38}', false, &['\u{5D8}', '\u{5BC}']),
+    ('\u{FB39}', false, &['\u{5D9}', '\u{5BC}']),
+    ('\u{FB3A}', false, &['\u{5DA}', '\u{5BC}']),
+    ('\u{FB3B}', false, &['\u{5DB}', '\u{5BC}']),
+    ('\u{FB3C}', false, &['\u{5DC}', '\u{5BC}']),
+    ('\u{FB3E}', false, &['\u{5DE}', '\u{5BC}']),
+    ('\u{FB40}', false, &['\u{5E0}', '\u{5BC}']),
+    ('\u{FB41}', false, &['\u{5E1}', '\u{5BC}']),
+    ('\u{FB43}', false, &['\u{5E3}', '\u{5BC}']),
+    ('\u{FB44}', false, &['\u{5E4}', '\u{5BC}']),
+    ('\u{FB46}', false, &['\u{5E6}', '\u{5BC}']),
+    ('\u{FB47}', false, &['\u{5E7}', '\u{5BC}']),
+    ('\u{FB48}', false, &['\u{5E8}', '\u{5BC}']),
+    ('\u{FB49}', false, &['\u{5E9}', '\u{5BC}']),
+    ('\u{FB4A}', false, &['\u{5EA}', '\u{5BC}']),
+    ('\u{FB4B}', false, &['\u{5D5}', '\u{5B9}']),
+    ('\u{FB4C}', false, &['\u{5D1}', '\u{5BF}']),
+    ('\u{FB4D}', false, &['\u{5DB}', '\u{5BF}']),
+    ('\u{FB4E}', false, &['\u{5E4}', '\u{5BF}']),
+    ('\u{FB4F}', true, &['\u{5D0}', '\u{5DC}']),
+    ('\u{FB50}', true, &['\u{671}']),
+    ('\u{FB51}', true, &['\u{671}']),
+    ('\u{FB52}', true, &['\u{67B}']),
+    ('\u{FB53}', true, &['\u{67B}']),
+    ('\u{FB54}', true, &['\u{67B}']),
+    ('\u{FB55}', true, &['\u{67B}']),
+    ('\u{FB56}', true, &['\u{67E}']),
+    ('\u{FB57}', true, &['\u{67E}']),
+    ('\u{FB58}', true, &['\u{67E}']),
+    ('\u{FB59}', true, &['\u{67E}']),
+    ('\u{FB5A}', true, &['\u{680}']),
+    ('\u{FB5B}', true, &['\u{680}']),
+    ('\u{FB5C}', true, &['\u{680}']),
+    ('\u{FB5D}', true, &['\u{680}']),
+    ('\u{FB5E}', true, &['\u{67A}']),
+    ('\u{FB5F}', true, &['\u{67A}']),
+    ('\u{FB60}', true, &['\u{67A}']),
+    ('\u{FB61}', true, &['\u{67A}']),
+    ('\u{FB62}', true, &['\u{67F}']),
+    ('\u{FB63}', true, &['\u{67F}']),
+    ('\u{FB64}', true, &['\u{67F}']),
+    ('\u{FB65}', true, &['\u{67F}']),
+    ('\u{FB66}', true, &['\u{679}']),
+    ('\u{FB67}', true, &['\u{679}']),
+    ('\u{FB68}', true, &['\u{679}']),
+    ('\u{FB69}', true, &['\u{679}']),
+    ('\u{FB6A}', true, &['\u{6A4}']),
+    ('\u{FB6B}', true, &['\u{6A4}']),
+    ('\u{FB6C}', true, &['\u{6A4}']),
+    ('\u{FB6D}', true, &['\u{6A4}']),
+    ('\u{FB6E}', true, &['\u{6A6}']),
+    ('\u{FB6F}', true, &['\u{6A6}']),
+    ('\u{FB70}', true, &['\u{6A6}']),
+    ('\u{FB71}', true, &['\u{6A6}']),
+    ('\u{FB72}', true, &['\u{684}']),
+    ('\u{FB73}', true, &['\u{684}']),
+    ('\u{FB74}', true, &['\u{684}']),
+    ('\u{FB75}', true, &['\u{684}']),
+    ('\u{FB76}', true, &['\u{683}']),
+    ('\u{FB77}', true, &['\u{683}']),
+    ('\u{FB78}', true, &['\u{683}']),
+    ('\u{FB79}', true, &['\u{683}']),
+    ('\u{FB7A}', true, &['\u{686}']),
+    ('\u{FB7B}', true, &['\u{686}']),
+    ('\u{FB7C}', true, &['\u{686}']),
+    ('\u{FB7D}', true, &['\u{686}']),
+    ('\u{FB7E}', true, &['\u{687}']),
+    ('\u{FB7F}', true, &['\u{687}']),
+    ('\u{FB80}', true, &['\u{687}']),
+    ('\u{FB81}', true, &['\u{687}']),
+    ('\u{FB82}', true, &['\u{68D}']),
+    ('\u{FB83}', true, &['\u{68D}']),
+    ('\u{FB84}', true, &['\u{68C}']),
+    ('\u{FB85}', true, &['\u{68C}']),
+    ('\u{FB86}', true, &['\u{68E}']),
+    ('\u{FB87}', true, &['\u{68E}']),
+    ('\u{FB88}', true, &['\u{688}']),
+    ('\u{FB89}', true, &['\u{688}']),
+    ('\u{FB8A}', true, &['\u{698}']),
+    ('\u{FB8B}', true, &['\u{698}']),
+    ('\u{FB8C}', true, &['\u{691}']),
+    ('\u{FB8D}', true, &['\u{691}']),
+    ('\u{FB8E}', true, &['\u{6A9}']),
+    ('\u{FB8F}', true, &['\u{6A9}']),
+    ('\u{FB90}', true, &['\u{6A9}']),
+    ('\u{FB91}', true, &['\u{6A9}']),
+    ('\u{FB92}', true, &['\u{6AF}']),
+    ('\u{FB93}', true, &['\u{6AF}']),
+    ('\u{FB94}', true, &['\u{6AF}']),
+    ('\u{FB95}', true, &['\u{6AF}']),
+    ('\u{FB96}', true, &['\u{6B3}']),
+    ('\u{FB97}', true, &['\u{6B3}']),
+    ('\u{FB98}', true, &['\u{6B3}']),
+    ('\u{FB99}', true, &['\u{6B3}']),
+    ('\u{FB9A}', true, &['\u{6B1}']),
+    ('\u{FB9B}', true, &['\u{6B1}']),
+    ('\u{FB9C}', true, &['\u{6B1}']),
+    ('\u{FB9D}', true, &['\u{6B1}']),
+    ('\u{FB9E}', true, &['\u{6BA}']),
+    ('\u{FB9F}', true, &['\u{6BA}']),
+    ('\u{FBA0}', true, &['\u{6BB}']),
+    ('\u{FBA1}', true, &['\u{6BB}']),
+    ('\u{FBA2}', true, &['\u{6BB}']),
+    ('\u{FBA3}', true, &['\u{6BB}']),
+    ('\u{FBA4}', true, &['\u{6C0}']),
+    ('\u{FBA5}', true, &['\u{6C0}']),
+    ('\u{FBA6}', true, &['\u{6C1}']),
+    ('\u{FBA7}', true, &['\u{6C1}']),
+    ('\u{FBA8}', true, &['\u{6C1}']),
+    ('\u{FBA9}', true, &['\u{6C1}']),
+    ('\u{FBAA}', true, &['\u{6BE}']),
+    ('\u{FBAB}', true, &['\u{6BE}']),
+    ('\u{FBAC}', true, &['\u{6BE}']),
+    ('\u{FBAD}', true, &['\u{6BE}']),
+    ('\u{FBAE}', true, &['\u{6D2}']),
+    ('\u{FBAF}', true, &['\u{6D2}']),
+    ('\u{FBB0}', true, &['\u{6D3}']),
+    ('\u{FBB1}', true, &['\u{6D3}']),
+    ('\u{FBD3}', true, &['\u{6AD}']),
+    ('\u{FBD4}', true, &['\u{6AD}']),
+    ('\u{FBD5}', true, &['\u{6AD}']),
+    ('\u{FBD6}', true, &['\u{6AD}']),
+    ('\u{FBD7}', true, &['\u{6C7}']),
+    ('\u{FBD8}', true, &['\u{6C7}']),
+    ('\u{FBD9}', true, &['\u{6C6}']),
+    ('\u{FBDA}', true, &['\u{6C6}']),
+    ('\u{FBDB}', true, &['\u{6C8}']),
+    ('\u{FBDC}', true, &['\u{6C8}']),
+    ('\u{FBDD}', true, &['\u{677}']),
+    ('\u{FBDE}', true, &['\u{6CB}']),
+    ('\u{FBDF}', true, &['\u{6CB}']),
+    ('\u{FBE0}', true, &['\u{6C5}']),
+    ('\u{FBE1}', true, &['\u{6C5}']),
+    ('\u{FBE2}', true, &['\u{6C9}']),
+    ('\u{FBE3}', true, &['\u{6C9}']),
+    ('\u{FBE4}', true, &['\u{6D0}']),
+    ('\u{FBE5}', true, &['\u{6D0}']),
+    ('\u{FBE6}', true, &['\u{6D0}']),
+    ('\u{FBE7}', true, &['\u{6D0}']),
+    ('\u{FBE8}', true, &['\u{649}']),
+    ('\u{FBE9}', true, &['\u{649}']),
+    ('\u{FBEA}', true, &['\u{626}', '\u{627}']),
+    ('\u{FBEB}', true, &['\u{626}', '\u{627}']),
+    ('\u{FBEC}', true, &['\u{626}', '\u{6D5}']),
+    ('\u{FBED}', true, &['\u{626}', '\u{6D5}']),
+    ('\u{FBEE}', true, &['\u{626}', '\u{648}']),
+    ('\u{FBEF}', true, &['\u{626}', '\u{648}']),
+    ('\u{FBF0}', true, &['\u{626}', '\u{6C7}']),
+    ('\u{FBF1}', true, &['\u{626}', '\u{6C7}']),
+    ('\u{FBF2}', true, &['\u{626}', '\u{6C6}']),
+    ('\u{FBF3}', true, &['\u{626}', '\u{6C6}']),
+    ('\u{FBF4}', true, &['\u{626}', '\u{6C8}']),
+    ('\u{FBF5}', true, &['\u{626}', '\u{6C8}']),
+    ('\u{FBF6}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF7}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF8}', true, &['\u{626}', '\u{6D0}']),
+    ('\u{FBF9}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFA}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFB}', true, &['\u{626}', '\u{649}']),
+    ('\u{FBFC}', true, &['\u{6CC}']),
+    ('\u{FBFD}', true, &['\u{6CC}']),
+    ('\u{FBFE}', true, &['\u{6CC}']),
+    ('\u{FBFF}', true, &['\u{6CC}']),
+    ('\u{FC00}', true, &['\u{626}', '\u{62C}']),
+    ('\u{FC01}', true, &['\u{626}', '\u{62D}']),
+    ('\u{FC02}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC03}', true, &['\u{626}', '\u{649}']),
+    ('\u{FC04}', true, &['\u{626}', '\u{64A}']),
+    ('\u{FC05}', true, &['\u{628}', '\u{62C}']),
+    ('\u{FC06}', true, &['\u{628}', '\u{62D}']),
+    ('\u{FC07}', true, &['\u{628}', '\u{62E}']),
+    ('\u{FC08}', true, &['\u{628}', '\u{645}']),
+    ('\u{FC09}', true, &['\u{628}', '\u{649}']),
+    ('\u{FC0A}', true, &['\u{628}', '\u{64A}']),
+    ('\u{FC0B}', true, &['\u{62A}', '\u{62C}']),
+    ('\u{FC0C}', true, &['\u{62A}', '\u{62D}']),
+    ('\u{FC0D}', true, &['\u{62A}', '\u{62E}']),
+    ('\u{FC0E}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FC0F}', true, &['\u{62A}', '\u{649}']),
+    ('\u{FC10}', true, &['\u{62A}', '\u{64A}']),
+    ('\u{FC11}', true, &['\u{62B}', '\u{62C}']),
+    ('\u{FC12}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FC13}', true, &['\u{62B}', '\u{649}']),
+    ('\u{FC14}', true, &['\u{62B}', '\u{64A}']),
+    ('\u{FC15}', true, &['\u{62C}', '\u{62D}']),
+    ('\u{FC16}', true, &['\u{62C}', '\u{645}']),
+    ('\u{FC17}', true, &['\u{62D}', '\u{62C}']),
+    ('\u{FC18}', true, &['\u{62D}', '\u{645}']),
+    ('\u{FC19}', true, &['\u{62E}', '\u{62C}']),
+    ('\u{FC1A}', true, &['\u{62E}', '\u{62D}']),
+    ('\u{FC1B}', true, &['\u{62E}', '\u{645}']),
+    ('\u{FC1C}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FC1D}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FC1E}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FC1F}', true, &['\u{633}', '\u{645}']),
+    ('\u{FC20}', true, &['\u{635}', '\u{62D}']),
+    ('\u{FC21}', true, &['\u{635}', '\u{645}']),
+    ('\u{FC22}', true, &['\u{636}', '\u{62C}']),
+    ('\u{FC23}', true, &['\u{636}', '\u{62D}']),
+    ('\u{FC24}', true, &['\u{636}', '\u{62E}']),
+    ('\u{FC25}', true, &['\u{636}', '\u{645}']),
+    ('\u{FC26}', true, &['\u{637}', '\u{62D}']),
+    ('\u{FC27}', true, &['\u{637}', '\u{645}']),
+    ('\u{FC28}', true, &['\u{638}', '\u{645}']),
+    ('\u{FC29}', true, &['\u{639}', '\u{62C}']),
+    ('\u{FC2A}', true, &['\u{639}', '\u{645}']),
+    ('\u{FC2B}', true, &['\u{63A}', '\u{62C}']),
+    ('\u{FC2C}', true, &['\u{63A}', '\u{645}']),
+    ('\u{FC2D}', true, &['\u{641}', '\u{62C}']),
+    ('\u{FC2E}', true, &['\u{641}', '\u{62D}']),
+    ('\u{FC2F}', true, &['\u{641}', '\u{62E}']),
+    ('\u{FC30}', true, &['\u{641}', '\u{645}']),
+    ('\u{FC31}', true, &['\u{641}', '\u{649}']),
+    ('\u{FC32}', true, &['\u{641}', '\u{64A}']),
+    ('\u{FC33}', true, &['\u{642}', '\u{62D}']),
+    ('\u{FC34}', true, &['\u{642}', '\u{645}']),
+    ('\u{FC35}', true, &['\u{642}', '\u{649}']),
+    ('\u{FC36}', true, &['\u{642}', '\u{64A}']),
+    ('\u{FC37}', true, &['\u{643}', '\u{627}']),
+    ('\u{FC38}', true, &['\u{643}', '\u{62C}']),
+    ('\u{FC39}', true, &['\u{643}', '\u{62D}']),
+    ('\u{FC3A}', true, &['\u{643}', '\u{62E}']),
+    ('\u{FC3B}', true, &['\u{643}', '\u{644}']),
+    ('\u{FC3C}', true, &['\u{643}', '\u{645}']),
+    ('\u{FC3D}', true, &['\u{643}', '\u{649}']),
+    ('\u{FC3E}', true, &['\u{643}', '\u{64A}']),
+    ('\u{FC3F}', true, &['\u{644}', '\u{62C}']),
+    ('\u{FC40}', true, &['\u{644}', '\u{62D}']),
+    ('\u{FC41}', true, &['\u{644}', '\u{62E}']),
+    ('\u{FC42}', true, &['\u{644}', '\u{645}']),
+    ('\u{FC43}', true, &['\u{644}', '\u{649}']),
+    ('\u{FC44}', true, &['\u{644}', '\u{64A}']),
+    ('\u{FC45}', true, &['\u{645}', '\u{62C}']),
+    ('\u{FC46}', true, &['\u{645}', '\u{62D}']),
+    ('\u{FC47}', true, &['\u{645}', '\u{62E}']),
+    ('\u{FC48}', true, &['\u{645}', '\u{645}']),
+    ('\u{FC49}', true, &['\u{645}', '\u{649}']),
+    ('\u{FC4A}', true, &['\u{645}', '\u{64A}']),
+    ('\u{FC4B}', true, &['\u{646}', '\u{62C}']),
+    ('\u{FC4C}', true, &['\u{646}', '\u{62D}']),
+    ('\u{FC4D}', true, &['\u{646}', '\u{62E}']),
+    ('\u{FC4E}', true, &['\u{646}', '\u{645}']),
+    ('\u{FC4F}', true, &['\u{646}', '\u{649}']),
+    ('\u{FC50}', true, &['\u{646}', '\u{64A}']),
+    ('\u{FC51}', true, &['\u{647}', '\u{62C}']),
+    ('\u{FC52}', true, &['\u{647}', '\u{645}']),
+    ('\u{FC53}', true, &['\u{647}', '\u{649}']),
+    ('\u{FC54}', true, &['\u{647}', '\u{64A}']),
+    ('\u{FC55}', true, &['\u{64A}', '\u{62C}']),
+    ('\u{FC56}', true, &['\u{64A}', '\u{62D}']),
+    ('\u{FC57}', true, &['\u{64A}', '\u{62E}']),
+    ('\u{FC58}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FC59}', true, &['\u{64A}', '\u{649}']),
+    ('\u{FC5A}', true, &['\u{64A}', '\u{64A}']),
+    ('\u{FC5B}', true, &['\u{630}', '\u{670}']),
+    ('\u{FC5C}', true, &['\u{631}', '\u{670}']),
+    ('\u{FC5D}', true, &['\u{649}', '\u{670}']),
+    ('\u{FC5E}', true, &['\u{20}', '\u{64C}', '\u{651}']),
+    ('\u{FC5F}', true, &['\u{20}', '\u{64D}', '\u{651}']),
+    ('\u{FC60}', true, &['\u{20}', '\u{64E}', '\u{651}']),
+    ('\u{FC61}', true, &['\u{20}', '\u{64F}', '\u{651}']),
+    ('\u{FC62}', true, &['\u{20}', '\u{650}', '\u{651}']),
+    ('\u{FC63}', true, &['\u{20}', '\u{651}', '\u{670}']),
+    ('\u{FC64}', true, &['\u{626}', '\u{631}']),
+    ('\u{FC65}', true, &['\u{626}', '\u{632}']),
+    ('\u{FC66}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC67}', true, &['\u{626}', '\u{646}']),
+    ('\u{FC68}', true, &['\u{626}', '\u{649}']),
+    ('\u{FC69}', true, &['\u{626}', '\u{64A}']),
+    ('\u{FC6A}', true, &['\u{628}', '\u{631}']),
+    ('\u{FC6B}', true, &['\u{628}', '\u{632}']),
+    ('\u{FC6C}', true, &['\u{628}', '\u{645}']),
+    ('\u{FC6D}', true, &['\u{628}', '\u{646}']),
+    ('\u{FC6E}', true, &['\u{628}', '\u{649}']),
+    ('\u{FC6F}', true, &['\u{628}', '\u{64A}']),
+    ('\u{FC70}', true, &['\u{62A}', '\u{631}']),
+    ('\u{FC71}', true, &['\u{62A}', '\u{632}']),
+    ('\u{FC72}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FC73}', true, &['\u{62A}', '\u{646}']),
+    ('\u{FC74}', true, &['\u{62A}', '\u{649}']),
+    ('\u{FC75}', true, &['\u{62A}', '\u{64A}']),
+    ('\u{FC76}', true, &['\u{62B}', '\u{631}']),
+    ('\u{FC77}', true, &['\u{62B}', '\u{632}']),
+    ('\u{FC78}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FC79}', true, &['\u{62B}', '\u{646}']),
+    ('\u{FC7A}', true, &['\u{62B}', '\u{649}']),
+    ('\u{FC7B}', true, &['\u{62B}', '\u{64A}']),
+    ('\u{FC7C}', true, &['\u{641}', '\u{649}']),
+    ('\u{FC7D}', true, &['\u{641}', '\u{64A}']),
+    ('\u{FC7E}', true, &['\u{642}', '\u{649}']),
+    ('\u{FC7F}', true, &['\u{642}', '\u{64A}']),
+    ('\u{FC80}', true, &['\u{643}', '\u{627}']),
+    ('\u{FC81}', true, &['\u{643}', '\u{644}']),
+    ('\u{FC82}', true, &['\u{643}', '\u{645}']),
+    ('\u{FC83}', true, &['\u{643}', '\u{649}']),
+    ('\u{FC84}', true, &['\u{643}', '\u{64A}']),
+    ('\u{FC85}', true, &['\u{644}', '\u{645}']),
+    ('\u{FC86}', true, &['\u{644}', '\u{649}']),
+    ('\u{FC87}', true, &['\u{644}', '\u{64A}']),
+    ('\u{FC88}', true, &['\u{645}', '\u{627}']),
+    ('\u{FC89}', true, &['\u{645}', '\u{645}']),
+    ('\u{FC8A}', true, &['\u{646}', '\u{631}']),
+    ('\u{FC8B}', true, &['\u{646}', '\u{632}']),
+    ('\u{FC8C}', true, &['\u{646}', '\u{645}']),
+    ('\u{FC8D}', true, &['\u{646}', '\u{646}']),
+    ('\u{FC8E}', true, &['\u{646}', '\u{649}']),
+    ('\u{FC8F}', true, &['\u{646}', '\u{64A}']),
+    ('\u{FC90}', true, &['\u{649}', '\u{670}']),
+    ('\u{FC91}', true, &['\u{64A}', '\u{631}']),
+    ('\u{FC92}', true, &['\u{64A}', '\u{632}']),
+    ('\u{FC93}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FC94}', true, &['\u{64A}', '\u{646}']),
+    ('\u{FC95}', true, &['\u{64A}', '\u{649}']),
+    ('\u{FC96}', true, &['\u{64A}', '\u{64A}']),
+    ('\u{FC97}', true, &['\u{626}', '\u{62C}']),
+    ('\u{FC98}', true, &['\u{626}', '\u{62D}']),
+    ('\u{FC99}', true, &['\u{626}', '\u{62E}']),
+    ('\u{FC9A}', true, &['\u{626}', '\u{645}']),
+    ('\u{FC9B}', true, &['\u{626}', '\u{647}']),
+    ('\u{FC9C}', true, &['\u{628}', '\u{62C}']),
+    ('\u{FC9D}', true, &['\u{628}', '\u{62D}']),
+    ('\u{FC9E}', true, &['\u{628}', '\u{62E}']),
+    ('\u{FC9F}', true, &['\u{628}', '\u{645}']),
+    ('\u{FCA0}', true, &['\u{628}', '\u{647}']),
+    ('\u{FCA1}', true, &['\u{62A}', '\u{62C}']),
+    ('\u{FCA2}', true, &['\u{62A}', '\u{62D}']),
+    ('\u{FCA3}', true, &['\u{62A}', '\u{62E}']),
+    ('\u{FCA4}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FCA5}', true, &['\u{62A}', '\u{647}']),
+    ('\u{FCA6}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FCA7}', true, &['\u{62C}', '\u{62D}']),
+    ('\u{FCA8}', true, &['\u{62C}', '\u{645}']),
+    ('\u{FCA9}', true, &['\u{62D}', '\u{62C}']),
+    ('\u{FCAA}', true, &['\u{62D}', '\u{645}']),
+    ('\u{FCAB}', true, &['\u{62E}', '\u{62C}']),
+    ('\u{FCAC}', true, &['\u{62E}', '\u{645}']),
+    ('\u{FCAD}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FCAE}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FCAF}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FCB0}', true, &['\u{633}', '\u{645}']),
+    ('\u{FCB1}', true, &['\u{635}', '\u{62D}']),
+    ('\u{FCB2}', true, &['\u{635}', '\u{62E}']),
+    ('\u{FCB3}', true, &['\u{635}', '\u{645}']),
+    ('\u{FCB4}', true, &['\u{636}', '\u{62C}']),
+    ('\u{FCB5}', true, &['\u{636}', '\u{62D}']),
+    ('\u{FCB6}', true, &['\u{636}', '\u{62E}']),
+    ('\u{FCB7}', true, &['\u{636}', '\u{645}']),
+    ('\u{FCB8}', true, &['\u{637}', '\u{62D}']),
+    ('\u{FCB9}', true, &['\u{638}', '\u{645}']),
+    ('\u{FCBA}', true, &['\u{639}', '\u{62C}']),
+    ('\u{FCBB}', true, &['\u{639}', '\u{645}']),
+    ('\u{FCBC}', true, &['\u{63A}', '\u{62C}']),
+    ('\u{FCBD}', true, &['\u{63A}', '\u{645}']),
+    ('\u{FCBE}', true, &['\u{641}', '\u{62C}']),
+    ('\u{FCBF}', true, &['\u{641}', '\u{62D}']),
+    ('\u{FCC0}', true, &['\u{641}', '\u{62E}']),
+    ('\u{FCC1}', true, &['\u{641}', '\u{645}']),
+    ('\u{FCC2}', true, &['\u{642}', '\u{62D}']),
+    ('\u{FCC3}', true, &['\u{642}', '\u{645}']),
+    ('\u{FCC4}', true, &['\u{643}', '\u{62C}']),
+    ('\u{FCC5}', true, &['\u{643}', '\u{62D}']),
+    ('\u{FCC6}', true, &['\u{643}', '\u{62E}']),
+    ('\u{FCC7}', true, &['\u{643}', '\u{644}']),
+    ('\u{FCC8}', true, &['\u{643}', '\u{645}']),
+    ('\u{FCC9}', true, &['\u{644}', '\u{62C}']),
+    ('\u{FCCA}', true, &['\u{644}', '\u{62D}']),
+    ('\u{FCCB}', true, &['\u{644}', '\u{62E}']),
+    ('\u{FCCC}', true, &['\u{644}', '\u{645}']),
+    ('\u{FCCD}', true, &['\u{644}', '\u{647}']),
+    ('\u{FCCE}', true, &['\u{645}', '\u{62C}']),
+    ('\u{FCCF}', true, &['\u{645}', '\u{62D}']),
+    ('\u{FCD0}', true, &['\u{645}', '\u{62E}']),
+    ('\u{FCD1}', true, &['\u{645}', '\u{645}']),
+    ('\u{FCD2}', true, &['\u{646}', '\u{62C}']),
+    ('\u{FCD3}', true, &['\u{646}', '\u{62D}']),
+    ('\u{FCD4}', true, &['\u{646}', '\u{62E}']),
+    ('\u{FCD5}', true, &['\u{646}', '\u{645}']),
+    ('\u{FCD6}', true, &['\u{646}', '\u{647}']),
+    ('\u{FCD7}', true, &['\u{647}', '\u{62C}']),
+    ('\u{FCD8}', true, &['\u{647}', '\u{645}']),
+    ('\u{FCD9}', true, &['\u{647}', '\u{670}']),
+    ('\u{FCDA}', true, &['\u{64A}', '\u{62C}']),
+    ('\u{FCDB}', true, &['\u{64A}', '\u{62D}']),
+    ('\u{FCDC}', true, &['\u{64A}', '\u{62E}']),
+    ('\u{FCDD}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FCDE}', true, &['\u{64A}', '\u{647}']),
+    ('\u{FCDF}', true, &['\u{626}', '\u{645}']),
+    ('\u{FCE0}', true, &['\u{626}', '\u{647}']),
+    ('\u{FCE1}', true, &['\u{628}', '\u{645}']),
+    ('\u{FCE2}', true, &['\u{628}', '\u{647}']),
+    ('\u{FCE3}', true, &['\u{62A}', '\u{645}']),
+    ('\u{FCE4}', true, &['\u{62A}', '\u{647}']),
+    ('\u{FCE5}', true, &['\u{62B}', '\u{645}']),
+    ('\u{FCE6}', true, &['\u{62B}', '\u{647}']),
+    ('\u{FCE7}', true, &['\u{633}', '\u{645}']),
+    ('\u{FCE8}', true, &['\u{633}', '\u{647}']),
+    ('\u{FCE9}', true, &['\u{634}', '\u{645}']),
+    ('\u{FCEA}', true, &['\u{634}', '\u{647}']),
+    ('\u{FCEB}', true, &['\u{643}', '\u{644}']),
+    ('\u{FCEC}', true, &['\u{643}', '\u{645}']),
+    ('\u{FCED}', true, &['\u{644}', '\u{645}']),
+    ('\u{FCEE}', true, &['\u{646}', '\u{645}']),
+    ('\u{FCEF}', true, &['\u{646}', '\u{647}']),
+    ('\u{FCF0}', true, &['\u{64A}', '\u{645}']),
+    ('\u{FCF1}', true, &['\u{64A}', '\u{647}']),
+    ('\u{FCF2}', true, &['\u{640}', '\u{64E}', '\u{651}']),
+    ('\u{FCF3}', true, &['\u{640}', '\u{64F}', '\u{651}']),
+    ('\u{FCF4}', true, &['\u{640}', '\u{650}', '\u{651}']),
+    ('\u{FCF5}', true, &['\u{637}', '\u{649}']),
+    ('\u{FCF6}', true, &['\u{637}', '\u{64A}']),
+    ('\u{FCF7}', true, &['\u{639}', '\u{649}']),
+    ('\u{FCF8}', true, &['\u{639}', '\u{64A}']),
+    ('\u{FCF9}', true, &['\u{63A}', '\u{649}']),
+    ('\u{FCFA}', true, &['\u{63A}', '\u{64A}']),
+    ('\u{FCFB}', true, &['\u{633}', '\u{649}']),
+    ('\u{FCFC}', true, &['\u{633}', '\u{64A}']),
+    ('\u{FCFD}', true, &['\u{634}', '\u{649}']),
+    ('\u{FCFE}', true, &['\u{634}', '\u{64A}']),
+    ('\u{FCFF}', true, &['\u{62D}', '\u{649}']),
+    ('\u{FD00}', true, &['\u{62D}', '\u{64A}']),
+    ('\u{FD01}', true, &['\u{62C}', '\u{649}']),
+    ('\u{FD02}', true, &['\u{62C}', '\u{64A}']),
+    ('\u{FD03}', true, &['\u{62E}', '\u{649}']),
+    ('\u{FD04}', true, &['\u{62E}', '\u{64A}']),
+    ('\u{FD05}', true, &['\u{635}', '\u{649}']),
+    ('\u{FD06}', true, &['\u{635}', '\u{64A}']),
+    ('\u{FD07}', true, &['\u{636}', '\u{649}']),
+    ('\u{FD08}', true, &['\u{636}', '\u{64A}']),
+    ('\u{FD09}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD0A}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD0B}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD0C}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD0D}', true, &['\u{634}', '\u{631}']),
+    ('\u{FD0E}', true, &['\u{633}', '\u{631}']),
+    ('\u{FD0F}', true, &['\u{635}', '\u{631}']),
+    ('\u{FD10}', true, &['\u{636}', '\u{631}']),
+    ('\u{FD11}', true, &['\u{637}', '\u{649}']),
+    ('\u{FD12}', true, &['\u{637}', '\u{64A}']),
+    ('\u{FD13}', true, &['\u{639}', '\u{649}']),
+    ('\u{FD14}', true, &['\u{639}', '\u{64A}']),
+    ('\u{FD15}', true, &['\u{63A}', '\u{649}']),
+    ('\u{FD16}', true, &['\u{63A}', '\u{64A}']),
+    ('\u{FD17}', true, &['\u{633}', '\u{649}']),
+    ('\u{FD18}', true, &['\u{633}', '\u{64A}']),
+    ('\u{FD19}', true, &['\u{634}', '\u{649}']),
+    ('\u{FD1A}', true, &['\u{634}', '\u{64A}']),
+    ('\u{FD1B}', true, &['\u{62D}', '\u{649}']),
+    ('\u{FD1C}', true, &['\u{62D}', '\u{64A}']),
+    ('\u{FD1D}', true, &['\u{62C}', '\u{649}']),
+    ('\u{FD1E}', true, &['\u{62C}', '\u{64A}']),
+    ('\u{FD1F}', true, &['\u{62E}', '\u{649}']),
+    ('\u{FD20}', true, &['\u{62E}', '\u{64A}']),
+    ('\u{FD21}', true, &['\u{635}', '\u{649}']),
+    ('\u{FD22}', true, &['\u{635}', '\u{64A}']),
+    ('\u{FD23}', true, &['\u{636}', '\u{649}']),
+    ('\u{FD24}', true, &['\u{636}', '\u{64A}']),
+    ('\u{FD25}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD26}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD27}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD28}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD29}', true, &['\u{634}', '\u{631}']),
+    ('\u{FD2A}', true, &['\u{633}', '\u{631}']),
+    ('\u{FD2B}', true, &['\u{635}', '\u{631}']),
+    ('\u{FD2C}', true, &['\u{636}', '\u{631}']),
+    ('\u{FD2D}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD2E}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD2F}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD30}', true, &['\u{634}', '\u{645}']),
+    ('\u{FD31}', true, &['\u{633}', '\u{647}']),
+    ('\u{FD32}', true, &['\u{634}', '\u{647}']),
+    ('\u{FD33}', true, &['\u{637}', '\u{645}']),
+    ('\u{FD34}', true, &['\u{633}', '\u{62C}']),
+    ('\u{FD35}', true, &['\u{633}', '\u{62D}']),
+    ('\u{FD36}', true, &['\u{633}', '\u{62E}']),
+    ('\u{FD37}', true, &['\u{634}', '\u{62C}']),
+    ('\u{FD38}', true, &['\u{634}', '\u{62D}']),
+    ('\u{FD39}', true, &['\u{634}', '\u{62E}']),
+    ('\u{FD3A}', true, &['\u{637}', '\u{645}']),
+    ('\u{FD3B}', true, &['\u{638}', '\u{645}']),
+    ('\u{FD3C}', true, &['\u{627}', '\u{64B}']),
+    ('\u{FD3D}', true, &['\u{627}', '\u{64B}']),
+    ('\u{FD50}', true, &['\u{62A}', '\u{62C}', '\u{645}']),
+    ('\u{FD51}', true, &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD52}', true, &['\u{62A}', '\u{62D}', '\u{62C}']),
+    ('\u{FD53}', true, &['\u{62A}', '\u{62D}', '\u{645}']),
+    ('\u{FD54}', true, &['\u{62A}', '\u{62E}', '\u{645}']),
+    ('\u{FD55}', true, &['\u{62A}', '\u{645}', '\u{62C}']),
+    ('\u{FD56}', true, &['\u{62A}', '\u{645}', '\u{62D}']),
+    ('\u{FD57}', true, &['\u{62A}', '\u{645}', '\u{62E}']),
+    ('\u{FD58}', true, &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD59}', true, &['\u{62C}', '\u{645}', '\u{62D}']),
+    ('\u{FD5A}', true, &['\u{62D}', '\u{645}', '\u{64A}']),
+    ('\u{FD5B}', true, &['\u{62D}', '\u{645}', '\u{649}']),
+    ('\u{FD5C}', true, &['\u{633}', '\u{62D}', '\u{62C}']),
+    ('\u{FD5D}', true, &['\u{633}', '\u{62C}', '\u{62D}']),
+    ('\u{FD5E}', true, &['\u{633}', '\u{62C}', '\u{649}']),
+    ('\u{FD5F}', true, &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD60}', true, &['\u{633}', '\u{645}', '\u{62D}']),
+    ('\u{FD61}', true, &['\u{633}', '\u{645}', '\u{62C}']),
+    ('\u{FD62}', true, &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD63}', true, &['\u{633}', '\u{645}', '\u{645}']),
+    ('\u{FD64}', true, &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD65}', true, &['\u{635}', '\u{62D}', '\u{62D}']),
+    ('\u{FD66}', true, &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FD67}', true, &['\u{634}', '\u{62D}', '\u{645}']),
+    ('\u{FD68}', true, &['\u{634}', '\u{62D}', '\u{645}']),
+    ('\u{FD69}', true, &['\u{634}', '\u{62C}', '\u{64A}']),
+    ('\u{FD6A}', true, &['\u{634}', '\u{645}', '\u{62E}']),
+    ('\u{FD6B}', true, &['\u{634}', '\u{645}', '\u{62E}']),
+    ('\u{FD6C}', true, &['\u{634}', '\u{645}', '\u{645}']),
+    ('\u{FD6D}', true, &['\u{634}', '\u{645}', '\u{645}']),
+    ('\u{FD6E}', true, &['\u{636}', '\u{62D}', '\u{649}']),
+    ('\u{FD6F}', true, &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD70}', true, &['\u{636}', '\u{62E}', '\u{645}']),
+    ('\u{FD71}', true, &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD72}', true, &['\u{637}', '\u{645}', '\u{62D}']),
+    ('\u{FD73}', true, &['\u{637}', '\u{645}', '\u{645}']),
+    ('\u{FD74}', true, &['\u{637}', '\u{645}', '\u{64A}']),
+    ('\u{FD75}', true, &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FD76}', true, &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD77}', true, &['\u{639}', '\u{645}', '\u{645}']),
+    ('\u{FD78}', true, &['\u{639}', '\u{645}', '\u{649}']),
+    ('\u{FD79}', true, &['\u{63A}', '\u{645}', '\u{645}']),
+    ('\u{FD7A}', true, &['\u{63A}', '\u{645}', '\u{64A}']),
+    ('\u{FD7B}', true, &['\u{63A}', '\u{645}', '\u{649}']),
+    ('\u{FD7C}', true, &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7D}', true, &['\u{641}', '\u{62E}', '\u{645}']),
+    ('\u{FD7E}', true, &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FD7F}', true, &['\u{642}', '\u{645}', '\u{645}']),
+    ('\u{FD80}', true, &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FD81}', true, &['\u{644}', '\u{62D}', '\u{64A}']),
+    ('\u{FD82}', true, &['\u{644}', '\u{62D}', '\u{649}']),
+    ('\u{FD83}', true, &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD84}', true, &['\u{644}', '\u{62C}', '\u{62C}']),
+    ('\u{FD85}', true, &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD86}', true, &['\u{644}', '\u{62E}', '\u{645}']),
+    ('\u{FD87}', true, &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD88}', true, &['\u{644}', '\u{645}', '\u{62D}']),
+    ('\u{FD89}', true, &['\u{645}', '\u{62D}', '\u{62C}']),
+    ('\u{FD8A}', true, &['\u{645}', '\u{62D}', '\u{645}']),
+    ('\u{FD8B}', true, &['\u{645}', '\u{62D}', '\u{64A}']),
+    ('\u{FD8C}', true, &['\u{645}', '\u{62C}', '\u{62D}']),
+    ('\u{FD8D}', true, &['\u{645}', '\u{62C}', '\u{645}']),
+    ('\u{FD8E}', true, &['\u{645}', '\u{62E}', '\u{62C}']),
+    ('\u{FD8F}', true, &['\u{645}', '\u{62E}', '\u{645}']),
+    ('\u{FD92}', true, &['\u{645}', '\u{62C}', '\u{62E}']),
+    ('\u{FD93}', true, &['\u{647}', '\u{645}', '\u{62C}']),
+    ('\u{FD94}', true, &['\u{647}', '\u{645}', '\u{645}']),
+    ('\u{FD95}', true, &['\u{646}', '\u{62D}', '\u{645}']),
+    ('\u{FD96}', true, &['\u{646}', '\u{62D}', '\u{649}']),
+    ('\u{FD97}', true, &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD98}', true, &['\u{646}', '\u{62C}', '\u{645}']),
+    ('\u{FD99}', true, &['\u{646}', '\u{62C}', '\u{649}']),
+    ('\u{FD9A}', true, &['\u{646}', '\u{645}', '\u{64A}']),
+    ('\u{FD9B}', true, &['\u{646}', '\u{645}', '\u{649}']),
+    ('\u{FD9C}', true, &['\u{64A}', '\u{645}', '\u{645}']),
+    ('\u{FD9D}', true, &['\u{64A}', '\u{645}', '\u{645}']),
+    ('\u{FD9E}', true, &['\u{628}', '\u{62E}', '\u{64A}']),
+    ('\u{FD9F}', true, &['\u{62A}', '\u{62C}', '\u{64A}']),
+    ('\u{FDA0}', true, &['\u{62A}', '\u{62C}', '\u{649}']),
+    ('\u{FDA1}', true, &['\u{62A}', '\u{62E}', '\u{64A}']),
+    ('\u{FDA2}', true, &['\u{62A}', '\u{62E}', '\u{649}']),
+    ('\u{FDA3}', true, &['\u{62A}', '\u{645}', '\u{64A}']),
+    ('\u{FDA4}', true, &['\u{62A}', '\u{645}', '\u{649}']),
+    ('\u{FDA5}', true, &['\u{62C}', '\u{645}', '\u{64A}']),
+    ('\u{FDA6}', true, &['\u{62C}', '\u{62D}', '\u{649}']),
+    ('\u{FDA7}', true, &['\u{62C}', '\u{645}', '\u{649}']),
+    ('\u{FDA8}', true, &['\u{633}', '\u{62E}', '\u{649}']),
+    ('\u{FDA9}', true, &['\u{635}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAA}', true, &['\u{634}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAB}', true, &['\u{636}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAC}', true, &['\u{644}', '\u{62C}', '\u{64A}']),
+    ('\u{FDAD}', true, &['\u{644}', '\u{645}', '\u{64A}']),
+    ('\u{FDAE}', true, &['\u{64A}', '\u{62D}', '\u{64A}']),
+    ('\u{FDAF}', true, &['\u{64A}', '\u{62C}', '\u{64A}']),
+    ('\u{FDB0}', true, &['\u{64A}', '\u{645}', '\u{64A}']),
+    ('\u{FDB1}', true, &['\u{645}', '\u{645}', '\u{64A}']),
+    ('\u{FDB2}', true, &['\u{642}', '\u{645}', '\u{64A}']),
+    ('\u{FDB3}', true, &['\u{646}', '\u{62D}', '\u{64A}']),
+    ('\u{FDB4}', true, &['\u{642}', '\u{645}', '\u{62D}']),
+    ('\u{FDB5}', true, &['\u{644}', '\u{62D}', '\u{645}']),
+    ('\u{FDB6}', true, &['\u{639}', '\u{645}', '\u{64A}']),
+    ('\u{FDB7}', true, &['\u{643}', '\u{645}', '\u{64A}']),
+    ('\u{FDB8}', true, &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDB9}', true, &['\u{645}', '\u{62E}', '\u{64A}']),
+    ('\u{FDBA}', true, &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBB}', true, &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDBC}', true, &['\u{644}', '\u{62C}', '\u{645}']),
+    ('\u{FDBD}', true, &['\u{646}', '\u{62C}', '\u{62D}']),
+    ('\u{FDBE}', true, &['\u{62C}', '\u{62D}', '\u{64A}']),
+    ('\u{FDBF}', true, &['\u{62D}', '\u{62C}', '\u{64A}']),
+    ('\u{FDC0}', true, &['\u{645}', '\u{62C}', '\u{64A}']),
+    ('\u{FDC1}', true, &['\u{641}', '\u{645}', '\u{64A}']),
+    ('\u{FDC2}', true, &['\u{628}', '\u{62D}', '\u{64A}']),
+    ('\u{FDC3}', true, &['\u{643}', '\u{645}', '\u{645}']),
+    ('\u{FDC4}', true, &['\u{639}', '\u{62C}', '\u{645}']),
+    ('\u{FDC5}', true, &['\u{635}', '\u{645}', '\u{645}']),
+    ('\u{FDC6}', true, &['\u{633}', '\u{62E}', '\u{64A}']),
+    ('\u{FDC7}', true, &['\u{646}', '\u{62C}', '\u{64A}']),
+    ('\u{FDF0}', true, &['\u{635}', '\u{644}', '\u{6D2}']),
+    ('\u{FDF1}', true, &['\u{642}', '\u{644}', '\u{6D2}']),
+    ('\u{FDF2}', true, &['\u{627}', '\u{644}', '\u{644}', '\u{647}']),
+    ('\u{FDF3}', true, &['\u{627}', '\u{643}', '\u{628}', '\u{631}']),
+    ('\u{FDF4}', true, &['\u{645}', '\u{62D}', '\u{645}', '\u{62F}']),
+    ('\u{FDF5}', true, &['\u{635}', '\u{644}', '\u{639}', '\u{645}']),
+    ('\u{FDF6}', true, &['\u{631}', '\u{633}', '\u{648}', '\u{644}']),
+    ('\u{FDF7}', true, &['\u{639}', '\u{644}', '\u{64A}', '\u{647}']),
+    ('\u{FDF8}', true, &['\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDF9}', true, &['\u{635}', '\u{644}', '\u{649}']),
+    ('\u{FDFA}', true, &['\u{635}', '\u{644}', '\u{649}', '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}', '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}']),
+    ('\u{FDFB}', true, &['\u{62C}', '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{627}', '\u{644}', '\u{647}']),
+    ('\u{FDFC}', true, &['\u{631}', '\u{6CC}', '\u{627}', '\u{644}']),
+    ('\u{FE10}', true, &['\u{2C}']),
+    ('\u{FE11}', true, &['\u{3001}']),
+    ('\u{FE12}', true, &['\u{3002}']),
+    ('\u{FE13}', true, &['\u{3A}']),
+    ('\u{FE14}', true, &['\u{3B}']),
+    ('\u{FE15}', true, &['\u{21}']),
+    ('\u{FE16}', true, &['\u{3F}']),
+    ('\u{FE17}', true, &['\u{3016}']),
+    ('\u{FE18}', true, &['\u{3017}']),
+    ('\u{FE19}', true, &['\u{2026}']),
+    ('\u{FE30}', true, &['\u{2025}']),
+    ('\u{FE31}', true, &['\u{2014}']),
+    ('\u{FE32}', true, &['\u{2013}']),
+    ('\u{FE33}', true, &['\u{5F}']),
+    ('\u{FE34}', true, &['\u{5F}']),
+    ('\u{FE35}', true, &['\u{28}']),
+    ('\u{FE36}', true, &['\u{29}']),
+    ('\u{FE37}', true, &['\u{7B}']),
+    ('\u{FE38}', true, &['\u{7D}']),
+    ('\u{FE39}', true, &['\u{3014}']),
+    ('\u{FE3A}', true, &['\u{3015}']),
+    ('\u{FE3B}', true, &['\u{3010}']),
+    ('\u{FE3C}', true, &['\u{3011}']),
+    ('\u{FE3D}', true, &['\u{300A}']),
+    ('\u{FE3E}', true, &['\u{300B}']),
+    ('\u{FE3F}', true, &['\u{3008}']),
+    ('\u{FE40}', true, &['\u{3009}']),
+    ('\u{FE41}', true, &['\u{300C}']),
+    ('\u{FE42}', true, &['\u{300D}']),
+    ('\u{FE43}', true, &['\u{300E}']),
+    ('\u{FE44}', true, &['\u{300F}']),
+    ('\u{FE47}', true, &['\u{5B}']),
+    ('\u{FE48}', true, &['\u{5D}']),
+    ('\u{FE49}', true, &['\u{203E}']),
+    ('\u{FE4A}', true, &['\u{203E}']),
+    ('\u{FE4B}', true, &['\u{203E}']),
+    ('\u{FE4C}', true, &['\u{203E}']),
+    ('\u{FE4D}', true, &['\u{5F}']),
+    ('\u{FE4E}', true, &['\u{5F}']),
+    ('\u{FE4F}', true, &['\u{5F}']),
+    ('\u{FE50}', true, &['\u{2C}']),
+    ('\u{FE51}', true, &['\u{3001}']),
+    ('\u{FE52}', true, &['\u{2E}']),
+    ('\u{FE54}', true, &['\u{3B}']),
+    ('\u{FE55}', true, &['\u{3A}']),
+    ('\u{FE56}', true, &['\u{3F}']),
+    ('\u{FE57}', true, &['\u{21}']),
+    ('\u{FE58}', true, &['\u{2014}']),
+    ('\u{FE59}', true, &['\u{28}']),
+    ('\u{FE5A}', true, &['\u{29}']),
+    ('\u{FE5B}', true, &['\u{7B}']),
+    ('\u{FE5C}', true, &['\u{7D}']),
+    ('\u{FE5D}', true, &['\u{3014}']),
+    ('\u{FE5E}', true, &['\u{3015}']),
+    ('\u{FE5F}', true, &['\u{23}']),
+    ('\u{FE60}', true, &['\u{26}']),
+    ('\u{FE61}', true, &['\u{2A}']),
+    ('\u{FE62}', true, &['\u{2B}']),
+    ('\u{FE63}', true, &['\u{2D}']),
+    ('\u{FE64}', true, &['\u{3C}']),
+    ('\u{FE65}', true, &['\u{3E}']),
+    ('\u{FE66}', true, &['\u{3D}']),
+    ('\u{FE68}', true, &['\u{5C}']),
+    ('\u{FE69}', true, &['\u{24}']),
+    ('\u{FE6A}', true, &['\u{25}']),
+    ('\u{FE6B}', true, &['\u{40}']),
+    ('\u{FE70}', true, &['\u{20}', '\u{64B}']),
+    ('\u{FE71}', true, &['\u{640}', '\u{64B}']),
+    ('\u{FE72}', true, &['\u{20}', '\u{64C}']),
+    ('\u{FE74}', true, &['\u{20}', '\u{64D}']),
+    ('\u{FE76}', true, &['\u{20}', '\u{64E}']),
+    ('\u{FE77}', true, &['\u{640}', '\u{64E}']),
+    ('\u{FE78}', true, &['\u{20}', '\u{64F}']),
+    ('\u{FE79}', true, &['\u{640}', '\u{64F}']),
+    ('\u{FE7A}', true, &['\u{20}', '\u{650}']),
+    ('\u{FE7B}', true, &['\u{640}', '\u{650}']),
+    ('\u{FE7C}', true, &['\u{20}', '\u{651}']),
+    ('\u{FE7D}', true, &['\u{640}', '\u{651}']),
+    ('\u{FE7E}', true, &['\u{20}', '\u{652}']),
+    ('\u{FE7F}', true, &['\u{640}', '\u{652}']),
+    ('\u{FE80}', true, &['\u{621}']),
+    ('\u{FE81}', true, &['\u{622}']),
+    ('\u{FE82}', true, &['\u{622}']),
+    ('\u{FE83}', true, &['\u{623}']),
+    ('\u{FE84}', true, &['\u{623}']),
+    ('\u{FE85}', true, &['\u{624}']),
+    ('\u{FE86}', true, &['\u{624}']),
+    ('\u{FE87}', true, &['\u{625}']),
+    ('\u{FE88}', true, &['\u{625}']),
+    ('\u{FE89}', true, &['\u{626}']),
+    ('\u{FE8A}', true, &['\u{626}']),
+    ('\u{FE8B}', true, &['\u{626}']),
+    ('\u{FE8C}', true, &['\u{626}']),
+    ('\u{FE8D}', true, &['\u{627}']),
+    ('\u{FE8E}', true, &['\u{627}']),
+    ('\u{FE8F}', true, &['\u{628}']),
+    ('\u{FE90}', true, &['\u{628}']),
+    ('\u{FE91}', true, &['\u{628}']),
+    ('\u{FE92}', true, &['\u{628}']),
+    ('\u{FE93}', true, &['\u{629}']),
+    ('\u{FE94}', true, &['\u{629}']),
+    ('\u{FE95}', true, &['\u{62A}']),
+    ('\u{FE96}', true, &['\u{62A}']),
+    ('\u{FE97}', true, &['\u{62A}']),
+    ('\u{FE98}', true, &['\u{62A}']),
+    ('\u{FE99}', true, &['\u{62B}']),
+    ('\u{FE9A}', true, &['\u{62B}']),
+    ('\u{FE9B}', true, &['\u{62B}']),
+    ('\u{FE9C}', true, &['\u{62B}']),
+    ('\u{FE9D}', true, &['\u{62C}']),
+    ('\u{FE9E}', true, &['\u{62C}']),
+    ('\u{FE9F}', true, &['\u{62C}']),
+    ('\u{FEA0}', true, &['\u{62C}']),
+    ('\u{FEA1}', true, &['\u{62D}']),
+    ('\u{FEA2}', true, &['\u{62D}']),
+    ('\u{FEA3}', true, &['\u{62D}']),
+    ('\u{FEA4}', true, &['\u{62D}']),
+    ('\u{FEA5}', true, &['\u{62E}']),
+    ('\u{FEA6}', true, &['\u{62E}']),
+    ('\u{FEA7}', true, &['\u{62E}']),
+    ('\u{FEA8}', true, &['\u{62E}']),
+    ('\u{FEA9}', true, &['\u{62F}']),
+    ('\u{FEAA}', true, &['\u{62F}']),
+    ('\u{FEAB}', true, &['\u{630}']),
+    ('\u{FEAC}', true, &['\u{630}']),
+    ('\u{FEAD}', true, &['\u{631}']),
+    ('\u{FEAE}', true, &['\u{631}']),
+    ('\u{FEAF}', true, &['\u{632}']),
+    ('\u{FEB0}', true, &['\u{632}']),
+    ('\u{FEB1}', true, &['\u{633}']),
+    ('\u{FEB2}', true, &['\u{633}']),
+    ('\u{FEB3}', true, &['\u{633}']),
+    ('\u{FEB4}', true, &['\u{633}']),
+    ('\u{FEB5}', true, &['\u{634}']),
+    ('\u{FEB6}', true, &['\u{634}']),
+    ('\u{FEB7}', true, &['\u{634}']),
+    ('\u{FEB8}', true, &['\u{634}']),
+    ('\u{FEB9}', true, &['\u{635}']),
+    ('\u{FEBA}', true, &['\u{635}']),
+    ('\u{FEBB}', true, &['\u{635}']),
+    ('\u{FEBC}', true, &['\u{635}']),
+    ('\u{FEBD}', true, &['\u{636}']),
+    ('\u{FEBE}', true, &['\u{636}']),
+    ('\u{FEBF}', true, &['\u{636}']),
+    ('\u{FEC0}', true, &['\u{636}']),
+    ('\u{FEC1}', true, &['\u{637}']),
+    ('\u{FEC2}', true, &['\u{637}']),
+    ('\u{FEC3}', true, &['\u{637}']),
+    ('\u{FEC4}', true, &['\u{637}']),
+    ('\u{FEC5}', true, &['\u{638}']),
+    ('\u{FEC6}', true, &['\u{638}']),
+    ('\u{FEC7}', true, &['\u{638}']),
+    ('\u{FEC8}', true, &['\u{638}']),
+    ('\u{FEC9}', true, &['\u{639}']),
+    ('\u{FECA}', true, &['\u{639}']),
+    ('\u{FECB}', true, &['\u{639}']),
+    ('\u{FECC}', true, &['\u{639}']),
+    ('\u{FECD}', true, &['\u{63A}']),
+    ('\u{FECE}', true, &['\u{63A}']),
+    ('\u{FECF}', true, &['\u{63A}']),
+    ('\u{FED0}', true, &['\u{63A}']),
+    ('\u{FED1}', true, &['\u{641}']),
+    ('\u{FED2}', true, &['\u{641}']),
+    ('\u{FED3}', true, &['\u{641}']),
+    ('\u{FED4}', true, &['\u{641}']),
+    ('\u{FED5}', true, &['\u{642}']),
+    ('\u{FED6}', true, &['\u{642}']),
+    ('\u{FED7}', true, &['\u{642}']),
+    ('\u{FED8}', true, &['\u{642}']),
+    ('\u{FED9}', true, &['\u{643}']),
+    ('\u{FEDA}', true, &['\u{643}']),
+    ('\u{FEDB}', true, &['\u{643}']),
+    ('\u{FEDC}', true, &['\u{643}']),
+    ('\u{FEDD}', true, &['\u{644}']),
+    ('\u{FEDE}', true, &['\u{644}']),
+    ('\u{FEDF}', true, &['\u{644}']),
+    ('\u{FEE0}', true, &['\u{644}']),
+    ('\u{FEE1}', true, &['\u{645}']),
+    ('\u{FEE2}', true, &['\u{645}']),
+    ('\u{FEE3}', true, &['\u{645}']),
+    ('\u{FEE4}', true, &['\u{645}']),
+    ('\u{FEE5}', true, &['\u{646}']),
+    ('\u{FEE6}', true, &['\u{646}']),
+    ('\u{FEE7}', true, &['\u{646}']),
+    ('\u{FEE8}', true, &['\u{646}']),
+    ('\u{FEE9}', true, &['\u{647}']),
+    ('\u{FEEA}', true, &['\u{647}']),
+    ('\u{FEEB}', true, &['\u{647}']),
+    ('\u{FEEC}', true, &['\u{647}']),
+    ('\u{FEED}', true, &['\u{648}']),
+    ('\u{FEEE}', true, &['\u{648}']),
+    ('\u{FEEF}', true, &['\u{649}']),
+    ('\u{FEF0}', true, &['\u{649}']),
+    ('\u{FEF1}', true, &['\u{64A}']),
+    ('\u{FEF2}', true, &['\u{64A}']),
+    ('\u{FEF3}', true, &['\u{64A}']),
+    ('\u{FEF4}', true, &['\u{64A}']),
+    ('\u{FEF5}', true, &['\u{644}', '\u{622}']),
+    ('\u{FEF6}', true, &['\u{644}', '\u{622}']),
+    ('\u{FEF7}', true, &['\u{644}', '\u{623}']),
+    ('\u{FEF8}', true, &['\u{644}', '\u{623}']),
+    ('\u{FEF9}', true, &['\u{644}', '\u{625}']),
+    ('\u{FEFA}', true, &['\u{644}', '\u{625}']),
+    ('\u{FEFB}', true, &['\u{644}', '\u{627}']),
+    ('\u{FEFC}', true, &['\u{644}', '\u{627}']),
+    ('\u{FF01}', true, &['\u{21}']),
+    ('\u{FF02}', true, &['\u{22}']),
+    ('\u{FF03}', true, &['\u{23}']),
+    ('\u{FF04}', true, &['\u{24}']),
+    ('\u{FF05}', true, &['\u{25}']),
+    ('\u{FF06}', true, &['\u{26}']),
+    ('\u{FF07}', true, &['\u{27}']),
+    ('\u{FF08}', true, &['\u{28}']),
+    ('\u{FF09}', true, &['\u{29}']),
+    ('\u{FF0A}', true, &['\u{2A}']),
+    ('\u{FF0B}', true, &['\u{2B}']),
+    ('\u{FF0C}', true, &['\u{2C}']),
+    ('\u{FF0D}', true, &['\u{2D}']),
+    ('\u{FF0E}', true, &['\u{2E}']),
+    ('\u{FF0F}', true, &['\u{2F}']),
+    ('\u{FF10}', true, &['\u{30}']),
+    ('\u{FF11}', true, &['\u{31}']),
+    ('\u{FF12}', true, &['\u{32}']),
+    ('\u{FF13}', true, &['\u{33}']),
+    ('\u{FF14}', true, &['\u{34}']),
+    ('\u{FF15}', true, &['\u{35}']),
+    ('\u{FF16}', true, &['\u{36}']),
+    ('\u{FF17}', true, &['\u{37}']),
+    ('\u{FF18}', true, &['\u{38}']),
+    ('\u{FF19}', true, &['\u{39}']),
+    ('\u{FF1A}', true, &['\u{3A}']),
+    ('\u{FF1B}', true, &['\u{3B}']),
+    ('\u{FF1C}', true, &['\u{3C}']),
+    ('\u{FF1D}', true, &['\u{3D}']),
+    ('\u{FF1E}', true, &['\u{3E}']),
+    ('\u{FF1F}', true, &['\u{3F}']),
+    ('\u{FF20}', true, &['\u{40}']),
+    ('\u{FF21}', true, &['\u{41}']),
+    ('\u{FF22}', true, &['\u{42}']),
+    ('\u{FF23}', true, &['\u{43}']),
+    ('\u{FF24}', true, &['\u{44}']),
+    ('\u{FF25}', true, &['\u{45}']),
+    ('\u{FF26}', true, &['\u{46}']),
+    ('\u{FF27}', true, &['\u{47}']),
+    ('\u{FF28}', true, &['\u{48}']),
+    ('\u{FF29}', true, &['\u{49}']),
+    ('\u{FF2A}', true, &['\u{4A}']),
+    ('\u{FF2B}', true, &['\u{4B}']),
+    ('\u{FF2C}', true, &['\u{4C}']),
+    ('\u{FF2D}', true, &['\u{4D}']),
+    ('\u{FF2E}', true, &['\u{4E}']),
+    ('\u{FF2F}', true, &['\u{4F}']),
+    ('\u{FF30}', true, &['\u{50}']),
+    ('\u{FF31}', true, &['\u{51}']),
+    ('\u{FF32}', true, &['\u{52}']),
+    ('\u{FF33}', true, &['\u{53}']),
+    ('\u{FF34}', true, &['\u{54}']),
+    ('\u{FF35}', true, &['\u{55}']),
+    ('\u{FF36}', true, &['\u{56}']),
+    ('\u{FF37}', true, &['\u{57}']),
+    ('\u{FF38}', true, &['\u{58}']),
+    ('\u{FF39}', true, &['\u{59}']),
+    ('\u{FF3A}', true, &['\u{5A}']),
+    ('\u{FF3B}', true, &['\u{5B}']),
+    ('\u{FF3C}', true, &['\u{5C}']),
+    ('\u{FF3D}', true, &['\u{5D}']),
+    ('\u{FF3E}', true, &['\u{5E}']),
+    ('\u{FF3F}', true, &['\u{5F}']),
+    ('\u{FF40}', true, &['\u{60}']),
+    ('\u{FF41}', true, &['\u{61}']),
+    ('\u{FF42}', true, &['\u{62}']),
+    ('\u{FF43}', true, &['\u{63}']),
+    ('\u{FF44}', true, &['\u{64}']),
+    ('\u{FF45}', true, &['\u{65}']),
+    ('\u{FF46}', true, &['\u{66}']),
+    ('\u{FF47}', true, &['\u{67}']),
+    ('\u{FF48}', true, &['\u{68}']),
+    ('\u{FF49}', true, &['\u{69}']),
+    ('\u{FF4A}', true, &['\u{6A}']),
+    ('\u{FF4B}', true, &['\u{6B}']),
+    ('\u{FF4C}', true, &['\u{6C}']),
+    ('\u{FF4D}', true, &['\u{6D}']),
+    ('\u{FF4E}', true, &['\u{6E}']),
+    ('\u{FF4F}', true, &['\u{6F}']),
+    ('\u{FF50}', true, &['\u{70}']),
+    ('\u{FF51}', true, &['\u{71}']),
+    ('\u{FF52}', true, &['\u{72}']),
+    ('\u{FF53}', true, &['\u{73}']),
+    ('\u{FF54}', true, &['\u{74}']),
+    ('\u{FF55}', true, &['\u{75}']),
+    ('\u{FF56}', true, &['\u{76}']),
+    ('\u{FF57}', true, &['\u{77}']),
+    ('\u{FF58}', true, &['\u{78}']),
+    ('\u{FF59}', true, &['\u{79}']),
+    ('\u{FF5A}', true, &['\u{7A}']),
+    ('\u{FF5B}', true, &['\u{7B}']),
+    ('\u{FF5C}', true, &['\u{7C}']),
+    ('\u{FF5D}', true, &['\u{7D}']),
+    ('\u{FF5E}', true, &['\u{7E}']),
+    ('\u{FF5F}', true, &['\u{2985}']),
+    ('\u{FF60}', true, &['\u{2986}']),
+    ('\u{FF61}', true, &['\u{3002}']),
+    ('\u{FF62}', true, &['\u{300C}']),
+    ('\u{FF63}', true, &['\u{300D}']),
+    ('\u{FF64}', true, &['\u{3001}']),
+    ('\u{FF65}', true, &['\u{30FB}']),
+    ('\u{FF66}', true, &['\u{30F2}']),
+    ('\u{FF67}', true, &['\u{30A1}']),
+    ('\u{FF68}', true, &['\u{30A3}']),
+    ('\u{FF69}', true, &['\u{30A5}']),
+    ('\u{FF6A}', true, &['\u{30A7}']),
+    ('\u{FF6B}', true, &['\u{30A9}']),
+    ('\u{FF6C}', true, &['\u{30E3}']),
+    ('\u{FF6D}', true, &['\u{30E5}']),
+    ('\u{FF6E}', true, &['\u{30E7}']),
+    ('\u{FF6F}', true, &['\u{30C3}']),
+    ('\u{FF70}', true, &['\u{30FC}']),
+    ('\u{FF71}', true, &['\u{30A2}']),
+    ('\u{FF72}', true, &['\u{30A4}']),
+    ('\u{FF73}', true, &['\u{30A6}']),
+    ('\u{FF74}', true, &['\u{30A8}']),
+    ('\u{FF75}', true, &['\u{30AA}']),
+    ('\u{FF76}', true, &['\u{30AB}']),
+    ('\u{FF77}', true, &['\u{30AD}']),
+    ('\u{FF78}', true, &['\u{30AF}']),
+    ('\u{FF79}', true, &['\u{30B1}']),
+    ('\u{FF7A}', true, &['\u{30B3}']),
+    ('\u{FF7B}', true, &['\u{30B5}']),
+    ('\u{FF7C}', true, &['\u{30B7}']),
+    ('\u{FF7D}', true, &['\u{30B9}']),
+    ('\u{FF7E}', true, &['\u{30BB}']),
+    ('\u{FF7F}', true, &['\u{30BD}']),
+    ('\u{FF80}', true, &['\u{30BF}']),
+    ('\u{FF81}', true, &['\u{30C1}']),
+    ('\u{FF82}', true, &['\u{30C4}']),
+    ('\u{FF83}', true, &['\u{30C6}']),
+    ('\u{FF84}', true, &['\u{30C8}']),
+    ('\u{FF85}', true, &['\u{30CA}']),
+    ('\u{FF86}', true, &['\u{30CB}']),
+    ('\u{FF87}', true, &['\u{30CC}']),
+    ('\u{FF88}', true, &['\u{30CD}']),
+    ('\u{FF89}', true, &['\u{30CE}']),
+    ('\u{FF8A}', true, &['\u{30CF}']),
+    ('\u{FF8B}', true, &['\u{30D2}']),
+    ('\u{FF8C}', true, &['\u{30D5}']),
+    ('\u{FF8D}', true, &['\u{30D8}']),
+    ('\u{FF8E}', true, &['\u{30DB}']),
+    ('\u{FF8F}', true, &['\u{30DE}']),
+    ('\u{FF90}', true, &['\u{30DF}']),
+    ('\u{FF91}', true, &['\u{30E0}']),
+    ('\u{FF92}', true, &['\u{30E1}']),
+    ('\u{FF93}', true, &['\u{30E2}']),
+    ('\u{FF94}', true, &['\u{30E4}']),
+    ('\u{FF95}', true, &['\u{30E6}']),
+    ('\u{FF96}', true, &['\u{30E8}']),
+    ('\u{FF97}', true, &['\u{30E9}']),
+    ('\u{FF98}', true, &['\u{30EA}']),
+    ('\u{FF99}', true, &['\u{30EB}']),
+    ('\u{FF9A}', true, &['\u{30EC}']),
+    ('\u{FF9B}', true, &['\u{30ED}']),
+    ('\u{FF9C}', true, &['\u{30EF}']),
+    ('\u{FF9D}', true, &['\u{30F3}']),
+    ('\u{FF9E}', true, &['\u{3099}']),
+    ('\u{FF9F}', true, &['\u{309A}']),
+    ('\u{FFA0}', true, &['\u{3164}']),
+    ('\u{FFA1}', true, &['\u{3131}']),
+    ('\u{FFA2}', true, &['\u{3132}']),
+    ('\u{FFA3}', true, &['\u{3133}']),
+    ('\u{FFA4}', true, &['\u{3134}']),
+    ('\u{FFA5}', true, &['\u{3135}']),
+    ('\u{FFA6}', true, &['\u{3136}']),
+    ('\u{FFA7}', true, &['\u{3137}']),
+    ('\u{FFA8}', true, &['\u{3138}']),
+    ('\u{FFA9}', true, &['\u{3139}']),
+    ('\u{FFAA}', true, &['\u{313A}']),
+    ('\u{FFAB}', true, &['\u{313B}']),
+    ('\u{FFAC}', true, &['\u{313C}']),
+    ('\u{FFAD}', true, &['\u{313D}']),
+    ('\u{FFAE}', true, &['\u{313E}']),
+    ('\u{FFAF}', true, &['\u{313F}']),
+    ('\u{FFB0}', true, &['\u{3140}']),
+    ('\u{FFB1}', true, &['\u{3141}']),
+    ('\u{FFB2}', true, &['\u{3142}']),
+    ('\u{FFB3}', true, &['\u{3143}']),
+    ('\u{FFB4}', true, &['\u{3144}']),
+    ('\u{FFB5}', true, &['\u{3145}']),
+    ('\u{FFB6}', true, &['\u{3146}']),
+    ('\u{FFB7}', true, &['\u{3147}']),
+    ('\u{FFB8}', true, &['\u{3148}']),
+    ('\u{FFB9}', true, &['\u{3149}']),
+    ('\u{FFBA}', true, &['\u{314A}']),
+    ('\u{FFBB}', true, &['\u{314B}']),
+    ('\u{FFBC}', true, &['\u{314C}']),
+    ('\u{FFBD}', true, &['\u{314D}']),
+    ('\u{FFBE}', true, &['\u{314E}']),
+    ('\u{FFC2}', true, &['\u{314F}']),
+    ('\u{FFC3}', true, &['\u{3150}']),
+    ('\u{FFC4}', true, &['\u{3151}']),
+    ('\u{FFC5}', true, &['\u{3152}']),
+    ('\u{FFC6}', true, &['\u{3153}']),
+    ('\u{FFC7}', true, &['\u{3154}']),
+    ('\u{FFCA}', true, &['\u{3155}']),
+    ('\u{FFCB}', true, &['\u{3156}']),
+    ('\u{FFCC}', true, &['\u{3157}']),
+    ('\u{FFCD}', true, &['\u{3158}']),
+    ('\u{FFCE}', true, &['\u{3159}']),
+    ('\u{FFCF}', true, &['\u{315A}']),
+    ('\u{FFD2}', true, &['\u{315B}']),
+    ('\u{FFD3}', true, &['\u{315C}']),
+    ('\u{FFD4}', true, &['\u{315D}']),
+    ('\u{FFD5}', true, &['\u{315E}']),
+    ('\u{FFD6}', true, &['\u{315F}']),
+    ('\u{FFD7}', true, &['\u{3160}']),
+    ('\u{FFDA}', true, &['\u{3161}']),
+    ('\u{FFDB}', true, &['\u{3162}']),
+    ('\u{FFDC}', true, &['\u{3163}']),
+    ('\u{FFE0}', true, &['\u{A2}']),
+    ('\u{FFE1}', true, &['\u{A3}']),
+    ('\u{FFE2}', true, &['\u{AC}']),
+    ('\u{FFE3}', true, &['\u{AF}']),
+    ('\u{FFE4}', true, &['\u{A6}']),
+    ('\u{FFE5}', true, &['\u{A5}']),
+    ('\u{FFE6}', true, &['\u{20A9}']),
+    ('\u{FFE8}', true, &['\u{2502}']),
+    ('\u{FFE9}', true, &['\u{2190}']),
+    ('\u{FFEA}', true, &['\u{2191}']),
+    ('\u{FFEB}', true, &['\u{2192}']),
+    ('\u{FFEC}', true, &['\u{2193}']),
+    ('\u{FFED}', true, &['\u{25A0}']),
+    ('\u{FFEE}', true, &['\u{25CB}']),
+    ('\u{105C9}', false, &['\u{105D2}', '\u{307}']),
+    ('\u{105E4}', false, &['\u{105DA}', '\u{307}']),
+    ('\u{10781}', true, &['\u{2D0}']),
+    ('\u{10782}', true, &['\u{2D1}']),
+    ('\u{10783}', true, &['\u{E6}']),
+    ('\u{10784}', true, &['\u{299}']),
+    ('\u{10785}', true, &['\u{253}']),
+    ('\u{10787}', true, &['\u{2A3}']),
+    ('\u{10788}', true, &['\u{AB66}']),
+    ('\u{10789}', true, &['\u{2A5}']),
+    ('\u{1078A}', true, &['\u{2A4}']),
+    ('\u{1078B}', true, &['\u{256}']),
+    ('\u{1078C}', true, &['\u{257}']),
+    ('\u{1078D}', true, &['\u{1D91}']),
+    ('\u{1078E}', true, &['\u{258}']),
+    ('\u{1078F}', true, &['\u{25E}']),
+    ('\u{10790}', true, &['\u{2A9}']),
+    ('\u{10791}', true, &['\u{264}']),
+    ('\u{10792}', true, &['\u{262}']),
+    ('\u{10793}', true, &['\u{260}']),
+    ('\u{10794}', true, &['\u{29B}']),
+    ('\u{10795}', true, &['\u{127}']),
+    ('\u{10796}', true, &['\u{29C}']),
+    ('\u{10797}', true, &['\u{267}']),
+    ('\u{10798}', true, &['\u{284}']),
+    ('\u{10799}', true, &['\u{2AA}']),
+    ('\u{1079A}', true, &['\u{2AB}']),
+    ('\u{1079B}', true, &['\u{26C}']),
+    ('\u{1079C}', true, &['\u{1DF04}']),
+    ('\u{1079D}', true, &['\u{A78E}']),
+    ('\u{1079E}', true, &['\u{26E}']),
+    ('\u{1079F}', true, &['\u{1DF05}']),
+    ('\u{107A0}', true, &['\u{28E}']),
+    ('\u{107A1}', true, &['\u{1DF06}']),
+    ('\u{107A2}', true, &['\u{F8}']),
+    ('\u{107A3}', true, &['\u{276}']),
+    ('\u{107A4}', true, &['\u{277}']),
+    ('\u{107A5}', true, &['\u{71}']),
+    ('\u{107A6}', true, &['\u{27A}']),
+    ('\u{107A7}', true, &['\u{1DF08}']),
+    ('\u{107A8}', true, &['\u{27D}']),
+    ('\u{107A9}', true, &['\u{27E}']),
+    ('\u{107AA}', true, &['\u{280}']),
+    ('\u{107AB}', true, &['\u{2A8}']),
+    ('\u{107AC}', true, &['\u{2A6}']),
+    ('\u{107AD}', true, &['\u{AB67}']),
+    ('\u{107AE}', true, &['\u{2A7}']),
+    ('\u{107AF}', true, &['\u{288}']),
+    ('\u{107B0}', true, &['\u{2C71}']),
+    ('\u{107B2}', true, &['\u{28F}']),
+    ('\u{107B3}', true, &['\u{2A1}']),
+    ('\u{107B4}', true, &['\u{2A2}']),
+    ('\u{107B5}', true, &['\u{298}']),
+    ('\u{107B6}', true, &['\u{1C0}']),
+    ('\u{107B7}', true, &['\u{1C1}']),
+    ('\u{107B8}', true, &['\u{1C2}']),
+    ('\u{107B9}', true, &['\u{1DF0A}']),
+    ('\u{107BA}', true, &['\u{1DF1E}']),
+    ('\u{1109A}', false, &['\u{11099}', '\u{110BA}']),
+    ('\u{1109C}', false, &['\u{1109B}', '\u{110BA}']),
+    ('\u{110AB}', false, &['\u{110A5}', '\u{110BA}']),
+    ('\u{1112E}', false, &['\u{11131}', '\u{11127}']),
+    ('\u{1112F}', false, &['\u{11132}', '\u{11127}']),
+    ('\u{1134B}', false, &['\u{11347}', '\u{1133E}']),
+    ('\u{1134C}', false, &['\u{11347}', '\u{11357}']),
+    ('\u{11383}', false, &['\u{11382}', '\u{113C9}']),
+    ('\u{11385}', false, &['\u{11384}', '\u{113BB}']),
+    ('\u{1138E}', false, &['\u{1138B}', '\u{113C2}']),
+    ('\u{11391}', false, &['\u{11390}', '\u{113C9}']),
+    ('\u{113C5}', false, &['\u{113C2}', '\u{113C2}']),
+    ('\u{113C7}', false, &['\u{113C2}', '\u{113B8}']),
+    ('\u{113C8}', false, &['\u{113C2}', '\u{113C9}']),
+    ('\u{114BB}', false, &['\u{114B9}', '\u{114BA}']),
+    ('\u{114BC}', false, &['\u{114B9}', '\u{114B0}']),
+    ('\u{114BE}', false, &['\u{114B9}', '\u{114BD}']),
+    ('\u{115BA}', false, &['\u{115B8}', '\u{115AF}']),
+    ('\u{115BB}', false, &['\u{115B9}', '\u{115AF}']),
+    ('\u{11938}', false, &['\u{11935}', '\u{11930}']),
+    ('\u{16121}', false, &['\u{1611E}', '\u{1611E}']),
+    ('\u{16122}', false, &['\u{1611E}', '\u{16129}']),
+    ('\u{16123}', false, &['\u{1611E}', '\u{1611F}']),
+    ('\u{16124}', false, &['\u{16129}', '\u{1611F}']),
+    ('\u{16125}', false, &['\u{1611E}', '\u{16120}']),
+    ('\u{16126}', false, &['\u{16121}', '\u{1611F}']),
+    ('\u{16127}', false, &['\u{16122}', '\u{1611F}']),
+    ('\u{16128}', false, &['\u{16121}', '\u{16120}']),
+    ('\u{16D68}', false, &['\u{16D67}', '\u{16D67}']),
+    ('\u{16D69}', false, &['\u{16D63}', '\u{16D67}']),
+    ('\u{16D6A}', false, &['\u{16D69}', '\u{16D67}']),
+    ('\u{1CCD6}', true, &['\u{41}']),
+    ('\u{1CCD7}', true, &['\u{42}']),
+    ('\u{1CCD8}', true, &['\u{43}']),
+    ('\u{1CCD9}', true, &['\u{44}']),
+    ('\u{1CCDA}', true, &['\u{45}']),
+    ('\u{1CCDB}', true, &['\u{46}']),
+    ('\u{1CCDC}', true, &['\u{47}']),
+    ('\u{1CCDD}', true, &['\u{48}']),
+    ('\u{1CCDE}', true, &['\u{49}']),
+    ('\u{1CCDF}', true, &['\u{4A}']),
+    ('\u{1CCE0}', true, &['\u{4B}']),
+    ('\u{1CCE1}', true, &['\u{4C}']),
+    ('\u{1CCE2}', true, &['\u{4D}']),
+    ('\u{1CCE3}', true, &['\u{4E}']),
+    ('\u{1CCE4}', true, &['\u{4F}']),
+    ('\u{1CCE5}', true, &['\u{50}']),
+    ('\u{1CCE6}', true, &['\u{51}']),
+    ('\u{1CCE7}', true, &['\u{52}']),
+    ('\u{1CCE8}', true, &['\u{53}']),
+    ('\u{1CCE9}', true, &['\u{54}']),
+    ('\u{1CCEA}', true, &['\u{55}']),
+    ('\u{1CCEB}', true, &['\u{56}']),
+    ('\u{1CCEC}', true, &['\u{57}']),
+    ('\u{1CCED}', true, &['\u{58}']),
+    ('\u{1CCEE}', true, &['\u{59}']),
+    ('\u{1CCEF}', true, &['\u{5A}']),
+    ('\u{1CCF0}', true, &['\u{30}']),
+    ('\u{1CCF1}', true, &['\u{31}']),
+    ('\u{1CCF2}', true, &['\u{32}']),
+    ('\u{1CCF3}', true, &['\u{33}']),
+    ('\u{1CCF4}', true, &['\u{34}']),
+    ('\u{1CCF5}', true, &['\u{35}']),
+    ('\u{1CCF6}', true, &['\u{36}']),
+    ('\u{1CCF7}', true, &['\u{37}']),
+    ('\u{1CCF8}', true, &['\u{38}']),
+    ('\u{1CCF9}', true, &['\u{39}']),
+    ('\u{1D15E}', false, &['\u{1D157}', '\u{1D165}']),
+    ('\u{1D15F}', false, &['\u{1D158}', '\u{1D165}']),
+    ('\u{1D160}', false, &['\u{1D15F}', '\u{1D16E}']),
+    ('\u{1D161}', false, &['\u{1D15F}', '\u{1D16F}']),
+    ('\u{1D162}', false, &['\u{1D15F}', '\u{1D170}']),
+    ('\u{1D163}', false, &['\u{1D15F}', '\u{1D171}']),
+    ('\u{1D164}', false, &['\u{1D15F}', '\u{1D172}']),
+    ('\u{1D1BB}', false, &['\u{1D1B9}', '\u{1D165}']),
+    ('\u{1D1BC}', false, &['\u{1D1BA}', '\u{1D165}']),
+    ('\u{1D1BD}', false, &['\u{1D1BB}', '\u{1D16E}']),
+    ('\u{1D1BE}', false, &['\u{1D1BC}', '\u{1D16E}']),
+    ('\u{1D1BF}', false, &['\u{1D1BB}', '\u{1D16F}']),
+    ('\u{1D1C0}', false, &['\u{1D1BC}', '\u{1D16F}']),
+    ('\u{1D400}', true, &['\u{41}']),
+    ('\u{1D401}', true, &['\u{42}']),
+    ('\u{1D402}', true, &['\u{43}']),
+    ('\u{1D403}', true, &['\u{44}']),
+    ('\u{1D404}', true, &['\u{45}']),
+    ('\u{1D405}', true, &['\u{46}']),
+    ('\u{1D406}', true, &['\u{47}']),
+    ('\u{1D407}', true, &['\u{48}']),
+    ('\u{1D408}', true, &['\u{49}']),
+    ('\u{1D409}', true, &['\u{4A}']),
+    ('\u{1D40A}', true, &['\u{4B}']),
+    ('\u{1D40B}', true, &['\u{4C}']),
+    ('\u{1D40C}', true, &['\u{4D}']),
+    ('\u{1D40D}', true, &['\u{4E}']),
+    ('\u{1D40E}', true, &['\u{4F}']),
+    ('\u{1D40F}', true, &['\u{50}']),
+    ('\u{1D410}', true, &['\u{51}']),
+    ('\u{1D411}', true, &['\u{52}']),
+    ('\u{1D412}', true, &['\u{53}']),
+    ('\u{1D413}', true, &['\u{54}']),
+    ('\u{1D414}', true, &['\u{55}']),
+    ('\u{1D415}', true, &['\u{56}']),
+    ('\u{1D416}', true, &['\u{57}']),
+    ('\u{1D417}', true, &['\u{58}']),
+    ('\u{1D418}', true, &['\u{59}']),
+    ('\u{1D419}', true, &['\u{5A}']),
+    ('\u{1D41A}', true, &['\u{61}']),
+    ('\u{1D41B}', true, &['\u{62}']),
+    ('\u{1D41C}', true, &['\u{63}']),
+    ('\u{1D41D}', true, &['\u{64}']),
+    ('\u{1D41E}', true, &['\u{65}']),
+    ('\u{1D41F}', true, &['\u{66}']),
+    ('\u{1D420}', true, &['\u{67}']),
+    ('\u{1D421}', true, &['\u{68}']),
+    ('\u{1D422}', true, &['\u{69}']),
+    ('\u{1D423}', true, &['\u{6A}']),
+    ('\u{1D424}', true, &['\u{6B}']),
+    ('\u{1D425}', true, &['\u{6C}']),
+    ('\u{1D426}', true, &['\u{6D}']),
+    ('\u{1D427}', true, &['\u{6E}']),
+    ('\u{1D428}', true, &['\u{6F}']),
+    ('\u{1D429}', true, &['\u{70}']),
+    ('\u{1D42A}', true, &['\u{71}']),
+    ('\u{1D42B}', true, &['\u{72}']),
+    ('\u{1D42C}', true, &['\u{73}']),
+    ('\u{1D42D}', true, &['\u{74}']),
+    ('\u{1D42E}', true, &['\u{75}']),
+    ('\u{1D42F}', true, &['\u{76}']),
+    ('\u{1D430}', true, &['\u{77}']),
+    ('\u{1D431}', true, &['\u{78}']),
+    ('\u{1D432}', true, &['\u{79}']),
+    ('\u{1D433}', true, &['\u{7A}']),
+    ('\u{1D434}', true, &['\u{41}']),
+    ('\u{1D435}', true, &['\u{42}']),
+    ('\u{1D436}', true, &['\u{43}']),
+    ('\u{1D437}', true, &['\u{44}']),
+    ('\u{1D438}', true, &['\u{45}']),
+    ('\u{1D439}', true, &['\u{46}']),
+    ('\u{1D43A}', true, &['\u{47}']),
+    ('\u{1D43B}', true, &['\u{48}']),
+    ('\u{1D43C}', true, &['\u{49}']),
+    ('\u{1D43D}', true, &['\u{4A}']),
+    ('\u{1D43E}', true, &['\u{4B}']),
+    ('\u{1D43F}', true, &['\u{4C}']),
+    ('\u{1D440}', true, &['\u{4D}']),
+    ('\u{1D441}', true, &['\u{4E}']),
+    ('\u{1D442}', true, &['\u{4F}']),
+    ('\u{1D443}', true, &['\u{50}']),
+    ('\u{1D444}', true, &['\u{51}']),
+    ('\u{1D445}', true, &['\u{52}']),
+    ('\u{1D446}', true, &['\u{53}']),
+    ('\u{1D447}', true, &['\u{54}']),
+    ('\u{1D448}', true, &['\u{55}']),
+    ('\u{1D449}', true, &['\u{56}']),
+    ('\u{1D44A}', true, &['\u{57}']),
+    ('\u{1D44B}', true, &['\u{58}']),
+    ('\u{1D44C}', true, &['\u{59}']),
+    ('\u{1D44D}', true, &['\u{5A}']),
+    ('\u{1D44E}', true, &['\u{61}']),
+    ('\u{1D44F}', true, &['\u{62}']),
+    ('\u{1D450}', true, &['\u{63}']),
+    ('\u{1D451}', true, &['\u{64}']),
+    ('\u{1D452}', true, &['\u{65}']),
+    ('\u{1D453}', true, &['\u{66}']),
+    ('\u{1D454}', true, &['\u{67}']),
+    ('\u{1D456}', true, &['\u{69}']),
+    ('\u{1D457}', true, &['\u{6A}']),
+    ('\u{1D458}', true, &['\u{6B}']),
+    ('\u{1D459}', true, &['\u{6C}']),
+    ('\u{1D45A}', true, &['\u{6D}']),
+    ('\u{1D45B}', true, &['\u{6E}']),
+    ('\u{1D45C}', true, &['\u{6F}']),
+    ('\u{1D45D}', true, &['\u{70}']),
+    ('\u{1D45E}', true, &['\u{71}']),
+    ('\u{1D45F}', true, &['\u{72}']),
+    ('\u{1D460}', true, &['\u{73}']),
+    ('\u{1D461}', true, &['\u{74}']),
+    ('\u{1D462}', true, &['\u{75}']),
+    ('\u{1D463}', true, &['\u{76}']),
+    ('\u{1D464}', true, &['\u{77}']),
+    ('\u{1D465}', true, &['\u{78}']),
+    ('\u{1D466}', true, &['\u{79}']),
+    ('\u{1D467}', true, &['\u{7A}']),
+    ('\u{1D468}', true, &['\u{41}']),
+    ('\u{1D469}', true, &['\u{42}']),
+    ('\u{1D46A}', true, &['\u{43}']),
+    ('\u{1D46B}', true, &['\u{44}']),
+    ('\u{1D46C}', true, &['\u{45}']),
+    ('\u{1D46D}', true, &['\u{46}']),
+    ('\u{1D46E}', true, &['\u{47}']),
+    ('\u{1D46F}', true, &['\u{48}']),
+    ('\u{1D470}', true, &['\u{49}']),
+    ('\u{1D471}', true, &['\u{4A}']),
+    ('\u{1D472}', true, &['\u{4B}']),
+    ('\u{1D473}', true, &['\u{4C}']),
+    ('\u{1D474}', true, &['\u{4D}']),
+    ('\u{1D475}', true, &['\u{4E}']),
+    ('\u{1D476}', true, &['\u{4F}']),
+    ('\u{1D477}', true, &['\u{50}']),
+    ('\u{1D478}', true, &['\u{51}']),
+    ('\u{1D479}', true, &['\u{52}']),
+    ('\u{1D47A}', true, &['\u{53}']),
+    ('\u{1D47B}', true, &['\u{54}']),
+    ('\u{1D47C}', true, &['\u{55}']),
+    ('\u{1D47D}', true, &['\u{56}']),
+    ('\u{1D47E}', true, &['\u{57}']),
+    ('\u{1D47F}', true, &['\u{58}']),
+    ('\u{1D480}', true, &['\u{59}']),
+    ('\u{1D481}', true, &['\u{5A}']),
+    ('\u{1D482}', true, &['\u{61}']),
+    ('\u{1D483}', true, &['\u{62}']),
+    ('\u{1D484}', true, &['\u{63}']),
+    ('\u{1D485}', true, &['\u{64}']),
+    ('\u{1D486}', true, &['\u{65}']),
+    ('\u{1D487}', true, &['\u{66}']),
+    ('\u{1D488}', true, &['\u{67}']),
+    ('\u{1D489}', true, &['\u{68}']),
+    ('\u{1D48A}', true, &['\u{69}']),
+    ('\u{1D48B}', true, &['\u{6A}']),
+    ('\u{1D48C}', true, &['\u{6B}']),
+    ('\u{1D48D}', true, &['\u{6C}']),
+    ('\u{1D48E}', true, &['\u{6D}']),
+    ('\u{1D48F}', true, &['\u{6E}']),
+    ('\u{1D490}', true, &['\u{6F}']),
+    ('\u{1D491}', true, &['\u{70}']),
+    ('\u{1D492}', true, &['\u{71}']),
+    ('\u{1D493}', true, &['\u{72}']),
+    ('\u{1D494}', true, &['\u{73}']),
+    ('\u{1D495}', true, &['\u{74}']),
+    ('\u{1D496}', true, &['\u{75}']),
+    ('\u{1D497}', true, &['\u{76}']),
+    ('\u{1D498}', true, &['\u{77}']),
+    ('\u{1D499}', true, &['\u{78}']),
+    ('\u{1D49A}', true, &['\u{79}']),
+    ('\u{1D49B}', true, &['\u{7A}']),
+    ('\u{1D49C}', true, &['\u{41}']),
+    ('\u{1D49E}', true, &['\u{43}']),
+    ('\u{1D49F}', true, &['\u{44}']),
+    ('\u{1D4A2}', true, &['\u{47}']),
+    ('\u{1D4A5}', true, &['\u{4A}']),
+    ('\u{1D4A6}', true, &['\u{4B}']),
+    ('\u{1D4A9}', true, &['\u{4E}']),
+    ('\u{1D4AA}', true, &['\u{4F}']),
+    ('\u{1D4AB}', true, &['\u{50}']),
+    ('\u{1D4AC}', true, &['\u{51}']),
+    ('\u{1D4AE}', true, &['\u{53}']),
+    ('\u{1D4AF}', true, &['\u{54}']),
+    ('\u{1D4B0}', true, &['\u{55}']),
+    ('\u{1D4B1}', true, &['\u{56}']),
+    ('\u{1D4B2}', true, &['\u{57}']),
+    ('\u{1D4B3}', true, &['\u{58}']),
+    ('\u{1D4B4}', true, &['\u{59}']),
+    ('\u{1D4B5}', true, &['\u{5A}']),
+    ('\u{1D4B6}', true, &['\u{61}']),
+    ('\u{1D4B7}', true, &['\u{62}']),
+    ('\u{1D4B8}', true, &['\u{63}']),
+    ('\u{1D4B9}', true, &['\u{64}']),
+    ('\u{1D4BB}', true, &['\u{66}']),
+    ('\u{1D4BD}', true, &['\u{68}']),
+    ('\u{1D4BE}', true, &['\u{69}']),
+    ('\u{1D4BF}', true, &['\u{6A}']),
+    ('\u{1D4C0}', true, &['\u{6B}']),
+    ('\u{1D4C1}', true, &['\u{6C}']),
+    ('\u{1D4C2}', true, &['\u{6D}']),
+    ('\u{1D4C3}', true, &['\u{6E}']),
+    ('\u{1D4C5}', true, &['\u{70}']),
+    ('\u{1D4C6}', true, &['\u{71}']),
+    ('\u{1D4C7}', true, &['\u{72}']),
+    ('\u{1D4C8}', true, &['\u{73}']),
+    ('\u{1D4C9}', true, &['\u{74}']),
+    ('\u{1D4CA}', true, &['\u{75}']),
+    ('\u{1D4CB}', true, &['\u{76}']),
+    ('\u{1D4CC}', true, &['\u{77}']),
+    ('\u{1D4CD}', true, &['\u{78}']),
+    ('\u{1D4CE}', true, &['\u{79}']),
+    ('\u{1D4CF}', true, &['\u{7A}']),
+    ('\u{1D4D0}', true, &['\u{41}']),
+    ('\u{1D4D1}', true, &['\u{42}']),
+    ('\u{1D4D2}', true, &['\u{43}']),
+    ('\u{1D4D3}', true, &['\u{44}']),
+    ('\u{1D4D4}', true, &['\u{45}']),
+    ('\u{1D4D5}', true, &['\u{46}']),
+    ('\u{1D4D6}', true, &['\u{47}']),
+    ('\u{1D4D7}', true, &['\u{48}']),
+    ('\u{1D4D8}', true, &['\u{49}']),
+    ('\u{1D4D9}', true, &['\u{4A}']),
+    ('\u{1D4DA}', true, &['\u{4B}']),
+    ('\u{1D4DB}', true, &['\u{4C}']),
+    ('\u{1D4DC}', true, &['\u{4D}']),
+    ('\u{1D4DD}', true, &['\u{4E}']),
+    ('\u{1D4DE}', true, &['\u{4F}']),
+    ('\u{1D4DF}', true, &['\u{50}']),
+    ('\u{1D4E0}', true, &['\u{51}']),
+    ('\u{1D4E1}', true, &['\u{52}']),
+    ('\u{1D4E2}', true, &['\u{53}']),
+    ('\u{1D4E3}', true, &['\u{54}']),
+    ('\u{1D4E4}', true, &['\u{55}']),
+    ('\u{1D4E5}', true, &['\u{56}']),
+    ('\u{1D4E6}', true, &['\u{57}']),
+    ('\u{1D4E7}', true, &['\u{58}']),
+    ('\u{1D4E8}', true, &['\u{59}']),
+    ('\u{1D4E9}', true, &['\u{5A}']),
+    ('\u{1D4EA}', true, &['\u{61}']),
+    ('\u{1D4EB}', true, &['\u{62}']),
+    ('\u{1D4EC}', true, &['\u{63}']),
+    ('\u{1D4ED}', true, &['\u{64}']),
+    ('\u{1D4EE}', true, &['\u{65}']),
+    ('\u{1D4EF}', true, &['\u{66}']),
+    ('\u{1D4F0}', true, &['\u{67}']),
+    ('\u{1D4F1}', true, &['\u{68}']),
+    ('\u{1D4F2}', true, &['\u{69}']),
+    ('\u{1D4F3}', true, &['\u{6A}']),
+    ('\u{1D4F4}', true, &['\u{6B}']),
+    ('\u{1D4F5}', true, &['\u{6C}']),
+    ('\u{1D4F6}', true, &['\u{6D}']),
+    ('\u{1D4F7}', true, &['\u{6E}']),
+    ('\u{1D4F8}', true, &['\u{6F}']),
+    ('\u{1D4F9}', true, &['\u{70}']),
+    ('\u{1D4FA}', true, &['\u{71}']),
+    ('\u{1D4FB}', true, &['\u{72}']),
+    ('\u{1D4FC}', true, &['\u{73}']),
+    ('\u{1D4FD}', true, &['\u{74}']),
+    ('\u{1D4FE}', true, &['\u{75}']),
+    ('\u{1D4FF}', true, &['\u{76}']),
+    ('\u{1D500}', true, &['\u{77}']),
+    ('\u{1D501}', true, &['\u{78}']),
+    ('\u{1D502}', true, &['\u{79}']),
+    ('\u{1D503}', true, &['\u{7A}']),
+    ('\u{1D504}', true, &['\u{41}']),
+    ('\u{1D505}', true, &['\u{42}']),
+    ('\u{1D507}', true, &['\u{44}']),
+    ('\u{1D508}', true, &['\u{45}']),
+    ('\u{1D509}', true, &['\u{46}']),
+    ('\u{1D50A}', true, &['\u{47}']),
+    ('\u{1D50D}', true, &['\u{4A}']),
+    ('\u{1D50E}', true, &['\u{4B}']),
+    ('\u{1D50F}', true, &['\u{4C}']),
+    ('\u{1D510}', true, &['\u{4D}']),
+    ('\u{1D511}', true, &['\u{4E}']),
+    ('\u{1D512}', true, &['\u{4F}']),
+    ('\u{1D513}', true, &['\u{50}']),
+    ('\u{1D514}', true, &['\u{51}']),
+    ('\u{1D516}', true, &['\u{53}']),
+    ('\u{1D517}', true, &['\u{54}']),
+    ('\u{1D518}', true, &['\u{55}']),
+    ('\u{1D519}', true, &['\u{56}']),
+    ('\u{1D51A}', true, &['\u{57}']),
+    ('\u{1D51B}', true, &['\u{58}']),
+    ('\u{1D51C}', true, &['\u{59}']),
+    ('\u{1D51E}', true, &['\u{61}']),
+    ('\u{1D51F}', true, &['\u{62}']),
+    ('\u{1D520}', true, &['\u{63}']),
+    ('\u{1D521}', true, &['\u{64}']),
+    ('\u{1D522}', true, &['\u{65}']),
+    ('\u{1D523}', true, &['\u{66}']),
+    ('\u{1D524}', true, &['\u{67}']),
+    ('\u{1D525}', true, &['\u{68}']),
+    ('\u{1D526}', true, &['\u{69}']),
+    ('\u{1D527}', true, &['\u{6A}']),
+    ('\u{1D528}', true, &['\u{6B}']),
+    ('\u{1D529}', true, &['\u{6C}']),
+    ('\u{1D52A}', true, &['\u{6D}']),
+    ('\u{1D52B}', true, &['\u{6E}']),
+    ('\u{1D52C}', true, &['\u{6F}']),
+    ('\u{1D52D}', true, &['\u{70}']),
+    ('\u{1D52E}', true, &['\u{71}']),
+    ('\u{1D52F}', true, &['\u{72}']),
+    ('\u{1D530}', true, &['\u{73}']),
+    ('\u{1D531}', true, &['\u{74}']),
+    ('\u{1D532}', true, &['\u{75}']),
+    ('\u{1D533}', true, &['\u{76}']),
+    ('\u{1D534}', true, &['\u{77}']),
+    ('\u{1D535}', true, &['\u{78}']),
+    ('\u{1D536}', true, &['\u{79}']),
+    ('\u{1D537}', true, &['\u{7A}']),
+    ('\u{1D538}', true, &['\u{41}']),
+    ('\u{1D539}', true, &['\u{42}']),
+    ('\u{1D53B}', true, &['\u{44}']),
+    ('\u{1D53C}', true, &['\u{45}']),
+    ('\u{1D53D}', true, &['\u{46}']),
+    ('\u{1D53E}', true, &['\u{47}']),
+    ('\u{1D540}', true, &['\u{49}']),
+    ('\u{1D541}', true, &['\u{4A}']),
+    ('\u{1D542}', true, &['\u{4B}']),
+    ('\u{1D543}', true, &['\u{4C}']),
+    ('\u{1D544}', true, &['\u{4D}']),
+    ('\u{1D546}', true, &['\u{4F}']),
+    ('\u{1D54A}', true, &['\u{53}']),
+    ('\u{1D54B}', true, &['\u{54}']),
+    ('\u{1D54C}', true, &['\u{55}']),
+    ('\u{1D54D}', true, &['\u{56}']),
+    ('\u{1D54E}', true, &['\u{57}']),
+    ('\u{1D54F}', true, &['\u{58}']),
+    ('\u{1D550}', true, &['\u{59}']),
+    ('\u{1D552}', true, &['\u{61}']),
+    ('\u{1D553}', true, &['\u{62}']),
+    ('\u{1D554}', true, &['\u{63}']),
+    ('\u{1D555}', true, &['\u{64}']),
+    ('\u{1D556}', true, &['\u{65}']),
+    ('\u{1D557}', true, &['\u{66}']),
+    ('\u{1D558}', true, &['\u{67}']),
+    ('\u{1D559}', true, &['\u{68}']),
+    ('\u{1D55A}', true, &['\u{69}']),
+    ('\u{1D55B}', true, &['\u{6A}']),
+    ('\u{1D55C}', true, &['\u{6B}']),
+    ('\u{1D55D}', true, &['\u{6C}']),
+    ('\u{1D55E}', true, &['\u{6D}']),
+    ('\u{1D55F}', true, &['\u{6E}']),
+    ('\u{1D560}', true, &['\u{6F}']),
+    ('\u{1D561}', true, &['\u{70}']),
+    ('\u{1D562}', true, &['\u{71}']),
+    ('\u{1D563}', true, &['\u{72}']),
+    ('\u{1D564}', true, &['\u{73}']),
+    ('\u{1D565}', true, &['\u{74}']),
+    ('\u{1D566}', true, &['\u{75}']),
+    ('\u{1D567}', true, &['\u{76}']),
+    ('\u{1D568}', true, &['\u{77}']),
+    ('\u{1D569}', true, &['\u{78}']),
+    ('\u{1D56A}', true, &['\u{79}']),
+    ('\u{1D56B}', true, &['\u{7A}']),
+    ('\u{1D56C}', true, &['\u{41}']),
+    ('\u{1D56D}', true, &['\u{42}']),
+    ('\u{1D56E}', true, &['\u{43}']),
+    ('\u{1D56F}', true, &['\u{44}']),
+    ('\u{1D570}', true, &['\u{45}']),
+    ('\u{1D571}', true, &['\u{46}']),
+    ('\u{1D572}', true, &['\u{47}']),
+    ('\u{1D573}', true, &['\u{48}']),
+    ('\u{1D574}', true, &['\u{49}']),
+    ('\u{1D575}', true, &['\u{4A}']),
+    ('\u{1D576}', true, &['\u{4B}']),
+    ('\u{1D577}', true, &['\u{4C}']),
+    ('\u{1D578}', true, &['\u{4D}']),
+    ('\u{1D579}', true, &['\u{4E}']),
+    ('\u{1D57A}', true, &['\u{4F}']),
+    ('\u{1D57B}', true, &['\u{50}']),
+    ('\u{1D57C}', true, &['\u{51}']),
+    ('\u{1D57D}', true, &['\u{52}']),
+    ('\u{1D57E}', true, &['\u{53}']),
+    ('\u{1D57F}', true, &['\u{54}']),
+    ('\u{1D580}', true, &['\u{55}']),
+    ('\u{1D581}', true, &['\u{56}']),
+    ('\u{1D582}', true, &['\u{57}']),
+    ('\u{1D583}', true, &['\u{58}']),
+    ('\u{1D584}', true, &['\u{59}']),
+    ('\u{1D585}', true, &['\u{5A}']),
+    ('\u{1D586}', true, &['\u{61}']),
+    ('\u{1D587}', true, &['\u{62}']),
+    ('\u{1D588}', true, &['\u{63}']),
+    ('\u{1D589}', true, &['\u{64}']),
+    ('\u{1D58A}', true, &['\u{65}']),
+    ('\u{1D58B}', true, &['\u{66}']),
+    ('\u{1D58C}', true, &['\u{67}']),
+    ('\u{1D58D}', true, &['\u{68}']),
+    ('\u{1D58E}', true, &['\u{69}']),
+    ('\u{1D58F}', true, &['\u{6A}']),
+    ('\u{1D590}', true, &['\u{6B}']),
+    ('\u{1D591}', true, &['\u{6C}']),
+    ('\u{1D592}', true, &['\u{6D}']),
+    ('\u{1D593}', true, &['\u{6E}']),
+    ('\u{1D594}', true, &['\u{6F}']),
+    ('\u{1D595}', true, &['\u{70}']),
+    ('\u{1D596}', true, &['\u{71}']),
+    ('\u{1D597}', true, &['\u{72}']),
+    ('\u{1D598}', true, &['\u{73}']),
+    ('\u{1D599}', true, &['\u{74}']),
+    ('\u{1D59A}', true, &['\u{75}']),
+    ('\u{1D59B}', true, &['\u{76}']),
+    ('\u{1D59C}', true, &['\u{77}']),
+    ('\u{1D59D}', true, &['\u{78}']),
+    ('\u{1D59E}', true, &['\u{79}']),
+    ('\u{1D59F}', true, &['\u{7A}']),
+    ('\u{1D5A0}', true, &['\u{41}']),
+    ('\u{1D5A1}', true, &['\u{42}']),
+    ('\u{1D5A2}', true, &['\u{43}']),
+    ('\u{1D5A3}', true, &['\u{44}']),
+    ('\u{1D5A4}', true, &['\u{45}']),
+    ('\u{1D5A5}', true, &['\u{46}']),
+    ('\u{1D5A6}', true, &['\u{47}']),
+    ('\u{1D5A7}', true, &['\u{48}']),
+    ('\u{1D5A8}', true, &['\u{49}']),
+    ('\u{1D5A9}', true, &['\u{4A}']),
+    ('\u{1D5AA}', true, &['\u{4B}']),
+    ('\u{1D5AB}', true, &['\u{4C}']),
+    ('\u{1D5AC}', true, &['\u{4D}']),
+    ('\u{1D5AD}', true, &['\u{4E}']),
+    ('\u{1D5AE}', true, &['\u{4F}']),
+    ('\u{1D5AF}', true, &['\u{50}']),
+    ('\u{1D5B0}', true, &['\u{51}']),
+    ('\u{1D5B1}', true, &['\u{52}']),
+    ('\u{1D5B2}', true, &['\u{53}']),
+    ('\u{1D5B3}', true, &['\u{54}']),
+    ('\u{1D5B4}', true, &['\u{55}']),
+    ('\u{1D5B5}', true, &['\u{56}']),
+    ('\u{1D5B6}', true, &['\u{57}']),
+    ('\u{1D5B7}', true, &['\u{58}']),
+    ('\u{1D5B8}', true, &['\u{59}']),
+    ('\u{1D5B9}', true, &['\u{5A}']),
+    ('\u{1D5BA}', true, &['\u{61}']),
+    ('\u{1D5BB}', true, &['\u{62}']),
+    ('\u{1D5BC}', true, &['\u{63}']),
+    ('\u{1D5BD}', true, &['\u{64}']),
+    ('\u{1D5BE}', true, &['\u{65}']),
+    ('\u{1D5BF}', true, &['\u{66}']),
+    ('\u{1D5C0}', true, &['\u{67}']),
+    ('\u{1D5C1}', true, &['\u{68}']),
+    ('\u{1D5C2}', true, &['\u{69}']),
+    ('\u{1D5C3}', true, &['\u{6A}']),
+    ('\u{1D5C4}', true, &['\u{6B}']),
+    ('\u{1D5C5}', true, &['\u{6C}']),
+    ('\u{1D5C6}', true, &['\u{6D}']),
+    ('\u{1D5C7}', true, &['\u{6E}']),
+    ('\u{1D5C8}', true, &['\u{6F}']),
+    ('\u{1D5C9}', true, &['\u{70}']),
+    ('\u{1D5CA}', true, &['\u{71}']),
+    ('\u{1D5CB}', true, &['\u{72}']),
+    ('\u{1D5CC}', true, &['\u{73}']),
+    ('\u{1D5CD}', true, &['\u{74}']),
+    ('\u{1D5CE}', true, &['\u{75}']),
+    ('\u{1D5CF}', true, &['\u{76}']),
+    ('\u{1D5D0}', true, &['\u{77}']),
+    ('\u{1D5D1}', true, &['\u{78}']),
+    ('\u{1D5D2}', true, &['\u{79}']),
+    ('\u{1D5D3}', true, &['\u{7A}']),
+    ('\u{1D5D4}', true, &['\u{41}']),
+    ('\u{1D5D5}', true, &['\u{42}']),
+    ('\u{1D5D6}', true, &['\u{43}']),
+    ('\u{1D5D7}', true, &['\u{44}']),
+    ('\u{1D5D8}', true, &['\u{45}']),
+    ('\u{1D5D9}', true, &['\u{46}']),
+    ('\u{1D5DA}', true, &['\u{47}']),
+    ('\u{1D5DB}', true, &['\u{48}']),
+    ('\u{1D5DC}', true, &['\u{49}']),
+    ('\u{1D5DD}', true, &['\u{4A}']),
+    ('\u{1D5DE}', true, &['\u{4B}']),
+    ('\u{1D5DF}', true, &['\u{4C}']),
+    ('\u{1D5E0}', true, &['\u{4D}']),
+    ('\u{1D5E1}', true, &['\u{4E}']),
+    ('\u{1D5E2}', true, &['\u{4F}']),
+    ('\u{1D5E3}', true, &['\u{50}']),
+    ('\u{1D5E4}', true, &['\u{51}']),
+    ('\u{1D5E5}', true, &['\u{52}']),
+    ('\u{1D5E6}', true, &['\u{53}']),
+    ('\u{1D5E7}', true, &['\u{54}']),
+    ('\u{1D5E8}', true, &['\u{55}']),
+    ('\u{1D5E9}', true, &['\u{56}']),
+    ('\u{1D5EA}', true, &['\u{57}']),
+    ('\u{1D5EB}', true, &['\u{58}']),
+    ('\u{1D5EC}', true, &['\u{59}']),
+    ('\u{1D5ED}', true, &['\u{5A}']),
+    ('\u{1D5EE}', true, &['\u{61}']),
+    ('\u{1D5EF}', true, &['\u{62}']),
+    ('\u{1D5F0}', true, &['\u{63}']),
+    ('\u{1D5F1}', true, &['\u{64}']),
+    ('\u{1D5F2}', true, &['\u{65}']),
+    ('\u{1D5F3}', true, &['\u{66}']),
+    ('\u{1D5F4}', true, &['\u{67}']),
+    ('\u{1D5F5}', true, &['\u{68}']),
+    ('\u{1D5F6}', true, &['\u{69}']),
+    ('\u{1D5F7}', true, &['\u{6A}']),
+    ('\u{1D5F8}', true, &['\u{6B}']),
+    ('\u{1D5F9}', true, &['\u{6C}']),
+    ('\u{1D5FA}', true, &['\u{6D}']),
+    ('\u{1D5FB}', true, &['\u{6E}']),
+    ('\u{1D5FC}', true, &['\u{6F}']),
+    ('\u{1D5FD}', true, &['\u{70}']),
+    ('\u{1D5FE}', true, &['\u{71}']),
+    ('\u{1D5FF}', true, &['\u{72}']),
+    ('\u{1D600}', true, &['\u{73}']),
+    ('\u{1D601}', true, &['\u{74}']),
+    ('\u{1D602}', true, &['\u{75}']),
+    ('\u{1D603}', true, &['\u{76}']),
+    ('\u{1D604}', true, &['\u{77}']),
+    ('\u{1D605}', true, &['\u{78}']),
+    ('\u{1D606}', true, &['\u{79}']),
+    ('\u{1D607}', true, &['\u{7A}']),
+    ('\u{1D608}', true, &['\u{41}']),
+    ('\u{1D609}', true, &['\u{42}']),
+    ('\u{1D60A}', true, &['\u{43}']),
+    ('\u{1D60B}', true, &['\u{44}']),
+    ('\u{1D60C}', true, &['\u{45}']),
+    ('\u{1D60D}', true, &['\u{46}']),
+    ('\u{1D60E}', true, &['\u{47}']),
+    ('\u{1D60F}', true, &['\u{48}']),
+    ('\u{1D610}', true, &['\u{49}']),
+    ('\u{1D611}', true, &['\u{4A}']),
+    ('\u{1D612}', true, &['\u{4B}']),
+    ('\u{1D613}', true, &['\u{4C}']),
+    ('\u{1D614}', true, &['\u{4D}']),
+    ('\u{1D615}', true, &['\u{4E}']),
+    ('\u{1D616}', true, &['\u{4F}']),
+    ('\u{1D617}', true, &['\u{50}']),
+    ('\u{1D618}', true, &['\u{51}']),
+    ('\u{1D619}', true, &['\u{52}']),
+    ('\u{1D61A}', true, &['\u{53}']),
+    ('\u{1D61B}', true, &['\u{54}']),
+    ('\u{1D61C}', true, &['\u{55}']),
+    ('\u{1D61D}', true, &['\u{56}']),
+    ('\u{1D61E}', true, &['\u{57}']),
+    ('\u{1D61F}', true, &['\u{58}']),
+    ('\u{1D620}', true, &['\u{59}']),
+    ('\u{1D621}', true, &['\u{5A}']),
+    ('\u{1D622}', true, &['\u{61}']),
+    ('\u{1D623}', true, &['\u{62}']),
+    ('\u{1D624}', true, &['\u{63}']),
+    ('\u{1D625}', true, &['\u{64}']),
+    ('\u{1D626}', true, &['\u{65}']),
+    ('\u{1D627}', true, &['\u{66}']),
+    ('\u{1D628}', true, &['\u{67}']),
+    ('\u{1D629}', true, &['\u{68}']),
+    ('\u{1D62A}', true, &['\u{69}']),
+    ('\u{1D62B}', true, &['\u{6A}']),
+    ('\u{1D62C}', true, &['\u{6B}']),
+    ('\u{1D62D}', true, &['\u{6C}']),
+    ('\u{1D62E}', true, &['\u{6D}']),
+    ('\u{1D62F}', true, &['\u{6E}']),
+    ('\u{1D630}', true, &['\u{6F}']),
+    ('\u{1D631}', true, &['\u{70}']),
+    ('\u{1D632}', true, &['\u{71}']),
+    ('\u{1D633}', true, &['\u{72}']),
+    ('\u{1D634}', true, &['\u{73}']),
+    ('\u{1D635}', true, &['\u{74}']),
+    ('\u{1D636}', true, &['\u{75}']),
+    ('\u{1D637}', true, &['\u{76}']),
+    ('\u{1D638}', true, &['\u{77}']),
+    ('\u{1D639}', true, &['\u{78}']),
+    ('\u{1D63A}', true, &['\u{79}']),
+    ('\u{1D63B}', true, &['\u{7A}']),
+    ('\u{1D63C}', true, &['\u{41}']),
+    ('\u{1D63D}', true, &['\u{42}']),
+    ('\u{1D63E}', true, &['\u{43}']),
+    ('\u{1D63F}', true, &['\u{44}']),
+    ('\u{1D640}', true, &['\u{45}']),
+    ('\u{1D641}', true, &['\u{46}']),
+    ('\u{1D642}', true, &['\u{47}']),
+    ('\u{1D643}', true, &['\u{48}']),
+    ('\u{1D644}', true, &['\u{49}']),
+    ('\u{1D645}', true, &['\u{4A}']),
+    ('\u{1D646}', true, &['\u{4B}']),
+    ('\u{1D647}', true, &['\u{4C}']),
+    ('\u{1D648}', true, &['\u{4D}']),
+    ('\u{1D649}', true, &['\u{4E}']),
+    ('\u{1D64A}', true, &['\u{4F}']),
+    ('\u{1D64B}', true, &['\u{50}']),
+    ('\u{1D64C}', true, &['\u{51}']),
+    ('\u{1D64D}', true, &['\u{52}']),
+    ('\u{1D64E}', true, &['\u{53}']),
+    ('\u{1D64F}', true, &['\u{54}']),
+    ('\u{1D650}', true, &['\u{55}']),
+    ('\u{1D651}', true, &['\u{56}']),
+    ('\u{1D652}', true, &['\u{57}']),
+    ('\u{1D653}', true, &['\u{58}']),
+    ('\u{1D654}', true, &['\u{59}']),
+    ('\u{1D655}', true, &['\u{5A}']),
+    ('\u{1D656}', true, &['\u{61}']),
+    ('\u{1D657}', true, &['\u{62}']),
+    ('\u{1D658}', true, &['\u{63}']),
+    ('\u{1D659}', true, &['\u{64}']),
+    ('\u{1D65A}', true, &['\u{65}']),
+    ('\u{1D65B}', true, &['\u{66}']),
+    ('\u{1D65C}', true, &['\u{67}']),
+    ('\u{1D65D}', true, &['\u{68}']),
+    ('\u{1D65E}', true, &['\u{69}']),
+    ('\u{1D65F}', true, &['\u{6A}']),
+    ('\u{1D660}', true, &['\u{6B}']),
+    ('\u{1D661}', true, &['\u{6C}']),
+    ('\u{1D662}', true, &['\u{6D}']),
+    ('\u{1D663}', true, &['\u{6E}']),
+    ('\u{1D664}', true, &['\u{6F}']),
+    ('\u{1D665}', true, &['\u{70}']),
+    ('\u{1D666}', true, &['\u{71}']),
+    ('\u{1D667}', true, &['\u{72}']),
+    ('\u{1D668}', true, &['\u{73}']),
+    ('\u{1D669}', true, &['\u{74}']),
+    ('\u{1D66A}', true, &['\u{75}']),
+    ('\u{1D66B}', true, &['\u{76}']),
+    ('\u{1D66C}', true, &['\u{77}']),
+    ('\u{1D66D}', true, &['\u{78}']),
+    ('\u{1D66E}', true, &['\u{79}']),
+    ('\u{1D66F}', true, &['\u{7A}']),
+    ('\u{1D670}', true, &['\u{41}']),
+    ('\u{1D671}', true, &['\u{42}']),
+    ('\u{1D672}', true, &['\u{43}']),
+    ('\u{1D673}', true, &['\u{44}']),
+    ('\u{1D674}', true, &['\u{45}']),
+    ('\u{1D675}', true, &['\u{46}']),
+    ('\u{1D676}', true, &['\u{47}']),
+    ('\u{1D677}', true, &['\u{48}']),
+    ('\u{1D678}', true, &['\u{49}']),
+    ('\u{1D679}', true, &['\u{4A}']),
+    ('\u{1D67A}', true, &['\u{4B}']),
+    ('\u{1D67B}', true, &['\u{4C}']),
+    ('\u{1D67C}', true, &['\u{4D}']),
+    ('\u{1D67D}', true, &['\u{4E}']),
+    ('\u{1D67E}', true, &['\u{4F}']),
+    ('\u{1D67F}', true, &['\u{50}']),
+    ('\u{1D680}', true, &['\u{51}']),
+    ('\u{1D681}', true, &['\u{52}']),
+    ('\u{1D682}', true, &['\u{53}']),
+    ('\u{1D683}', true, &['\u{54}']),
+    ('\u{1D684}', true, &['\u{55}']),
+    ('\u{1D685}', true, &['\u{56}']),
+    ('\u{1D686}', true, &['\u{57}']),
+    ('\u{1D687}', true, &['\u{58}']),
+    ('\u{1D688}', true, &['\u{59}']),
+    ('\u{1D689}', true, &['\u{5A}']),
+    ('\u{1D68A}', true, &['\u{61}']),
+    ('\u{1D68B}', true, &['\u{62}']),
+    ('\u{1D68C}', true, &['\u{63}']),
+    ('\u{1D68D}', true, &['\u{64}']),
+    ('\u{1D68E}', true, &['\u{65}']),
+    ('\u{1D68F}', true, &['\u{66}']),
+    ('\u{1D690}', true, &['\u{67}']),
+    ('\u{1D691}', true, &['\u{68}']),
+    ('\u{1D692}', true, &['\u{69}']),
+    ('\u{1D693}', true, &['\u{6A}']),
+    ('\u{1D694}', true, &['\u{6B}']),
+    ('\u{1D695}', true, &['\u{6C}']),
+    ('\u{1D696}', true, &['\u{6D}']),
+    ('\u{1D697}', true, &['\u{6E}']),
+    ('\u{1D698}', true, &['\u{6F}']),
+    ('\u{1D699}', true, &['\u{70}']),
+    ('\u{1D69A}', true, &['\u{71}']),
+    ('\u{1D69B}', true, &['\u{72}']),
+    ('\u{1D69C}', true, &['\u{73}']),
+    ('\u{1D69D}', true, &['\u{74}']),
+    ('\u{1D69E}', true, &['\u{75}']),
+    ('\u{1D69F}', true, &['\u{76}']),
+    ('\u{1D6A0}', true, &['\u{77}']),
+    ('\u{1D6A1}', true, &['\u{78}']),
+    ('\u{1D6A2}', true, &['\u{79}']),
+    ('\u{1D6A3}', true, &['\u{7A}']),
+    ('\u{1D6A4}', true, &['\u{131}']),
+    ('\u{1D6A5}', true, &['\u{237}']),
+    ('\u{1D6A8}', true, &['\u{391}']),
+    ('\u{1D6A9}', true, &['\u{392}']),
+    ('\u{1D6AA}', true, &['\u{393}']),
+    ('\u{1D6AB}', true, &['\u{394}']),
+    ('\u{1D6AC}', true, &['\u{395}']),
+    ('\u{1D6AD}', true, &['\u{396}']),
+    ('\u{1D6AE}', true, &['\u{397}']),
+    ('\u{1D6AF}', true, &['\u{398}']),
+    ('\u{1D6B0}', true, &['\u{399}']),
+    ('\u{1D6B1}', true, &['\u{39A}']),
+    ('\u{1D6B2}', true, &['\u{39B}']),
+    ('\u{1D6B3}', true, &['\u{39C}']),
+    ('\u{1D6B4}', true, &['\u{39D}']),
+    ('\u{1D6B5}', true, &['\u{39E}']),
+    ('\u{1D6B6}', true, &['\u{39F}']),
+    ('\u{1D6B7}', true, &['\u{3A0}']),
+    ('\u{1D6B8}', true, &['\u{3A1}']),
+    ('\u{1D6B9}', true, &['\u{3F4}']),
+    ('\u{1D6BA}', true, &['\u{3A3}']),
+    ('\u{1D6BB}', true, &['\u{3A4}']),
+    ('\u{1D6BC}', true, &['\u{3A5}']),
+    ('\u{1D6BD}', true, &['\u{3A6}']),
+    ('\u{1D6BE}', true, &['\u{3A7}']),
+    ('\u{1D6BF}', true, &['\u{3A8}']),
+    ('\u{1D6C0}', true, &['\u{3A9}']),
+    ('\u{1D6C1}', true, &['\u{2207}']),
+    ('\u{1D6C2}', true, &['\u{3B1}']),
+    ('\u{1D6C3}', true, &['\u{3B2}']),
+    ('\u{1D6C4}', true, &['\u{3B3}']),
+    ('\u{1D6C5}', true, &['\u{3B4}']),
+    ('\u{1D6C6}', true, &['\u{3B5}']),
+    ('\u{1D6C7}', true, &['\u{3B6}']),
+    ('\u{1D6C8}', true, &['\u{3B7}']),
+    ('\u{1D6C9}', true, &['\u{3B8}']),
+    ('\u{1D6CA}', true, &['\u{3B9}']),
+    ('\u{1D6CB}', true, &['\u{3BA}']),
+    ('\u{1D6CC}', true, &['\u{3BB}']),
+    ('\u{1D6CD}', true, &['\u{3BC}']),
+    ('\u{1D6CE}', true, &['\u{3BD}']),
+    ('\u{1D6CF}', true, &['\u{3BE}']),
+    ('\u{1D6D0}', true, &['\u{3BF}']),
+    ('\u{1D6D1}', true, &['\u{3C0}']),
+    ('\u{1D6D2}', true, &['\u{3C1}']),
+    ('\u{1D6D3}', true, &['\u{3C2}']),
+    ('\u{1D6D4}', true, &['\u{3C3}']),
+    ('\u{1D6D5}', true, &['\u{3C4}']),
+    ('\u{1D6D6}', true, &['\u{3C5}']),
+    ('\u{1D6D7}', true, &['\u{3C6}']),
+    ('\u{1D6D8}', true, &['\u{3C7}']),
+    ('\u{1D6D9}', true, &['\u{3C8}']),
+    ('\u{1D6DA}', true, &['\u{3C9}']),
+    ('\u{1D6DB}', true, &['\u{2202}']),
+    ('\u{1D6DC}', true, &['\u{3F5}']),
+    ('\u{1D6DD}', true, &['\u{3D1}']),
+    ('\u{1D6DE}', true, &['\u{3F0}']),
+    ('\u{1D6DF}', true, &['\u{3D5}']),
+    ('\u{1D6E0}', true, &['\u{3F1}']),
+    ('\u{1D6E1}', true, &['\u{3D6}']),
+    ('\u{1D6E2}', true, &['\u{391}']),
+    ('\u{1D6E3}', true, &['\u{392}']),
+    ('\u{1D6E4}', true, &['\u{393}']),
+    ('\u{1D6E5}', true, &['\u{394}']),
+    ('\u{1D6E6}', true, &['\u{395}']),
+    ('\u{1D6E7}', true, &['\u{396}']),
+    ('\u{1D6E8}', true, &['\u{397}']),
+    ('\u{1D6E9}', true, &['\u{398}']),
+    ('\u{1D6EA}', true, &['\u{399}']),
+    ('\u{1D6EB}', true, &['\u{39A}']),
+    ('\u{1D6EC}', true, &['\u{39B}']),
+    ('\u{1D6ED}', true, &['\u{39C}']),
+    ('\u{1D6EE}', true, &['\u{39D}']),
+    ('\u{1D6EF}', true, &['\u{39E}']),
+    ('\u{1D6F0}', true, &['\u{39F}']),
+    ('\u{1D6F1}', true, &['\u{3A0}']),
+    ('\u{1D6F2}', true, &['\u{3A1}']),
+    ('\u{1D6F3}', true, &['\u{3F4}']),
+    ('\u{1D6F4}', true, &['\u{3A3}']),
+    ('\u{1D6F5}', true, &['\u{3A4}']),
+    ('\u{1D6F6}', true, &['\u{3A5}']),
+    ('\u{1D6F7}', true, &['\u{3A6}']),
+    ('\u{1D6F8}', true, &['\u{3A7}']),
+    ('\u{1D6F9}', true, &['\u{3A8}']),
+    ('\u{1D6FA}', true, &['\u{3A9}']),
+    ('\u{1D6FB}', true, &['\u{2207}']),
+    ('\u{1D6FC}', true, &['\u{3B1}']),
+    ('\u{1D6FD}', true, &['\u{3B2}']),
+    ('\u{1D6FE}', true, &['\u{3B3}']),
+    ('\u{1D6FF}', true, &['\u{3B4}']),
+    ('\u{1D700}', true, &['\u{3B5}']),
+    ('\u{1D701}', true, &['\u{3B6}']),
+    ('\u{1D702}', true, &['\u{3B7}']),
+    ('\u{1D703}', true, &['\u{3B8}']),
+    ('\u{1D704}', true, &['\u{3B9}']),
+    ('\u{1D705}', true, &['\u{3BA}']),
+    ('\u{1D706}', true, &['\u{3BB}']),
+    ('\u{1D707}', true, &['\u{3BC}']),
+    ('\u{1D708}', true, &['\u{3BD}']),
+    ('\u{1D709}', true, &['\u{3BE}']),
+    ('\u{1D70A}', true, &['\u{3BF}']),
+    ('\u{1D70B}', true, &['\u{3C0}']),
+    ('\u{1D70C}', true, &['\u{3C1}']),
+    ('\u{1D70D}', true, &['\u{3C2}']),
+    ('\u{1D70E}', true, &['\u{3C3}']),
+    ('\u{1D70F}', true, &['\u{3C4}']),
+    ('\u{1D710}', true, &['\u{3C5}']),
+    ('\u{1D711}', true, &['\u{3C6}']),
+    ('\u{1D712}', true, &['\u{3C7}']),
+    ('\u{1D713}', true, &['\u{3C8}']),
+    ('\u{1D714}', true, &['\u{3C9}']),
+    ('\u{1D715}', true, &['\u{2202}']),
+    ('\u{1D716}', true, &['\u{3F5}']),
+    ('\u{1D717}', true, &['\u{3D1}']),
+    ('\u{1D718}', true, &['\u{3F0}']),
+    ('\u{1D719}', true, &['\u{3D5}']),
+    ('\u{1D71A}', true, &['\u{3F1}']),
+    ('\u{1D71B}', true, &['\u{3D6}']),
+    ('\u{1D71C}', true, &['\u{391}']),
+    ('\u{1D71D}', true, &['\u{392}']),
+    ('\u{1D71E}', true, &['\u{393}']),
+    ('\u{1D71F}', true, &['\u{394}']),
+    ('\u{1D720}', true, &['\u{395}']),
+    ('\u{1D721}', true, &['\u{396}']),
+    ('\u{1D722}', true, &['\u{397}']),
+    ('\u{1D723}', true, &['\u{398}']),
+    ('\u{1D724}', true, &['\u{399}']),
+    ('\u{1D725}', true, &['\u{39A}']),
+    ('\u{1D726}', true, &['\u{39B}']),
+    ('\u{1D727}', true, &['\u{39C}']),
+    ('\u{1D728}', true, &['\u{39D}']),
+    ('\u{1D729}', true, &['\u{39E}']),
+    ('\u{1D72A}', true, &['\u{39F}']),
+    ('\u{1D72B}', true, &['\u{3A0}']),
+    ('\u{1D72C}', true, &['\u{3A1}']),
+    ('\u{1D72D}', true, &['\u{3F4}']),
+    ('\u{1D72E}', true, &['\u{3A3}']),
+    ('\u{1D72F}', true, &['\u{3A4}']),
+    ('\u{1D730}', true, &['\u{3A5}']),
+    ('\u{1D731}', true, &['\u{3A6}']),
+    ('\u{1D732}', true, &['\u{3A7}']),
+    ('\u{1D733}', true, &['\u{3A8}']),
+    ('\u{1D734}', true, &['\u{3A9}']),
+    ('\u{1D735}', true, &['\u{2207}']),
+    ('\u{1D736}', true, &['\u{3B1}']),
+    ('\u{1D737}', true, &['\u{3B2}']),
+    ('\u{1D738}', true, &['\u{3B3}']),
+    ('\u{1D739}', true, &['\u{3B4}']),
+    ('\u{1D73A}', true, &['\u{3B5}']),
+    ('\u{1D73B}', true, &['\u{3B6}']),
+    ('\u{1D73C}', true, &['\u{3B7}']),
+    ('\u{1D73D}', true, &['\u{3B8}']),
+    ('\u{1D73E}', true, &['\u{3B9}']),
+    ('\u{1D73F}', true, &['\u{3BA}']),
+    ('\u{1D740}', true, &['\u{3BB}']),
+    ('\u{1D741}', true, &['\u{3BC}']),
+    ('\u{1D742}', true, &['\u{3BD}']),
+    ('\u{1D743}', true, &['\u{3BE}']),
+    ('\u{1D744}', true, &['\u{3BF}']),
+    ('\u{1D745}', true, &['\u{3C0}']),
+    ('\u{1D746}', true, &['\u{3C1}']),
+    ('\u{1D747}', true, &['\u{3C2}']),
+    ('\u{1D748}', true, &['\u{3C3}']),
+    ('\u{1D749}', true, &['\u{3C4}']),
+    ('\u{1D74A}', true, &['\u{3C5}']),
+    ('\u{1D74B}', true, &['\u{3C6}']),
+    ('\u{1D74C}', true, &['\u{3C7}']),
+    ('\u{1D74D}', true, &['\u{3C8}']),
+    ('\u{1D74E}', true, &['\u{3C9}']),
+    ('\u{1D74F}', true, &['\u{2202}']),
+    ('\u{1D750}', true, &['\u{3F5}']),
+    ('\u{1D751}', true, &['\u{3D1}']),
+    ('\u{1D752}', true, &['\u{3F0}']),
+    ('\u{1D753}', true, &['\u{3D5}']),
+    ('\u{1D754}', true, &['\u{3F1}']),
+    ('\u{1D755}', true, &['\u{3D6}']),
+    ('\u{1D756}', true, &['\u{391}']),
+    ('\u{1D757}', true, &['\u{392}']),
+    ('\u{1D758}', true, &['\u{393}']),
+    ('\u{1D759}', true, &['\u{394}']),
+    ('\u{1D75A}', true, &['\u{395}']),
+    ('\u{1D75B}', true, &['\u{396}']),
+    ('\u{1D75C}', true, &['\u{397}']),
+    ('\u{1D75D}', true, &['\u{398}']),
+    ('\u{1D75E}', true, &['\u{399}']),
+    ('\u{1D75F}', true, &['\u{39A}']),
+    ('\u{1D760}', true, &['\u{39B}']),
+    ('\u{1D761}', true, &['\u{39C}']),
+    ('\u{1D762}', true, &['\u{39D}']),
+    ('\u{1D763}', true, &['\u{39E}']),
+    ('\u{1D764}', true, &['\u{39F}']),
+    ('\u{1D765}', true, &['\u{3A0}']),
+    ('\u{1D766}', true, &['\u{3A1}']),
+    ('\u{1D767}', true, &['\u{3F4}']),
+    ('\u{1D768}', true, &['\u{3A3}']),
+    ('\u{1D769}', true, &['\u{3A4}']),
+    ('\u{1D76A}', true, &['\u{3A5}']),
+    ('\u{1D76B}', true, &['\u{3A6}']),
+    ('\u{1D76C}', true, &['\u{3A7}']),
+    ('\u{1D76D}', true, &['\u{3A8}']),
+    ('\u{1D76E}', true, &['\u{3A9}']),
+    ('\u{1D76F}', true, &['\u{2207}']),
+    ('\u{1D770}', true, &['\u{3B1}']),
+    ('\u{1D771}', true, &['\u{3B2}']),
+    ('\u{1D772}', true, &['\u{3B3}']),
+    ('\u{1D773}', true, &['\u{3B4}']),
+    ('\u{1D774}', true, &['\u{3B5}']),
+    ('\u{1D775}', true, &['\u{3B6}']),
+    ('\u{1D776}', true, &['\u{3B7}']),
+    ('\u{1D777}', true, &['\u{3B8}']),
+    ('\u{1D778}', true, &['\u{3B9}']),
+    ('\u{1D779}', true, &['\u{3BA}']),
+    ('\u{1D77A}', true, &['\u{3BB}']),
+    ('\u{1D77B}', true, &['\u{3BC}']),
+    ('\u{1D77C}', true, &['\u{3BD}']),
+    ('\u{1D77D}', true, &['\u{3BE}']),
+    ('\u{1D77E}', true, &['\u{3BF}']),
+    ('\u{1D77F}', true, &['\u{3C0}']),
+    ('\u{1D780}', true, &['\u{3C1}']),
+    ('\u{1D781}', true, &['\u{3C2}']),
+    ('\u{1D782}', true, &['\u{3C3}']),
+    ('\u{1D783}', true, &['\u{3C4}']),
+    ('\u{1D784}', true, &['\u{3C5}']),
+    ('\u{1D785}', true, &['\u{3C6}']),
+    ('\u{1D786}', true, &['\u{3C7}']),
+    ('\u{1D787}', true, &['\u{3C8}']),
+    ('\u{1D788}', true, &['\u{3C9}']),
+    ('\u{1D789}', true, &['\u{2202}']),
+    ('\u{1D78A}', true, &['\u{3F5}']),
+    ('\u{1D78B}', true, &['\u{3D1}']),
+    ('\u{1D78C}', true, &['\u{3F0}']),
+    ('\u{1D78D}', true, &['\u{3D5}']),
+    ('\u{1D78E}', true, &['\u{3F1}']),
+    ('\u{1D78F}', true, &['\u{3D6}']),
+    ('\u{1D790}', true, &['\u{391}']),
+    ('\u{1D791}', true, &['\u{392}']),
+    ('\u{1D792}', true, &['\u{393}']),
+    ('\u{1D793}', true, &['\u{394}']),
+    ('\u{1D794}', true, &['\u{395}']),
+    ('\u{1D795}', true, &['\u{396}']),
+    ('\u{1D796}', true, &['\u{397}']),
+    ('\u{1D797}', true, &['\u{398}']),
+    ('\u{1D798}', true, &['\u{399}']),
+    ('\u{1D799}', true, &['\u{39A}']),
+    ('\u{1D79A}', true, &['\u{39B}']),
+    ('\u{1D79B}', true, &['\u{39C}']),
+    ('\u{1D79C}', true, &['\u{39D}']),
+    ('\u{1D79D}', true, &['\u{39E}']),
+    ('\u{1D79E}', true, &['\u{39F}']),
+    ('\u{1D79F}', true, &['\u{3A0}']),
+    ('\u{1D7A0}', true, &['\u{3A1}']),
+    ('\u{1D7A1}', true, &['\u{3F4}']),
+    ('\u{1D7A2}', true, &['\u{3A3}']),
+    ('\u{1D7A3}', true, &['\u{3A4}']),
+    ('\u{1D7A4}', true, &['\u{3A5}']),
+    ('\u{1D7A5}', true, &['\u{3A6}']),
+    ('\u{1D7A6}', true, &['\u{3A7}']),
+    ('\u{1D7A7}', true, &['\u{3A8}']),
+    ('\u{1D7A8}', true, &['\u{3A9}']),
+    ('\u{1D7A9}', true, &['\u{2207}']),
+    ('\u{1D7AA}', true, &['\u{3B1}']),
+    ('\u{1D7AB}', true, &['\u{3B2}']),
+    ('\u{1D7AC}', true, &['\u{3B3}']),
+    ('\u{1D7AD}', true, &['\u{3B4}']),
+    ('\u{1D7AE}', true, &['\u{3B5}']),
+    ('\u{1D7AF}', true, &['\u{3B6}']),
+    ('\u{1D7B0}', true, &['\u{3B7}']),
+    ('\u{1D7B1}', true, &['\u{3B8}']),
+    ('\u{1D7B2}', true, &['\u{3B9}']),
+    ('\u{1D7B3}', true, &['\u{3BA}']),
+    ('\u{1D7B4}', true, &['\u{3BB}']),
+    ('\u{1D7B5}', true, &['\u{3BC}']),
+    ('\u{1D7B6}', true, &['\u{3BD}']),
+    ('\u{1D7B7}', true, &['\u{3BE}']),
+    ('\u{1D7B8}', true, &['\u{3BF}']),
+    ('\u{1D7B9}', true, &['\u{3C0}']),
+    ('\u{1D7BA}', true, &['\u{3C1}']),
+    ('\u{1D7BB}', true, &['\u{3C2}']),
+    ('\u{1D7BC}', true, &['\u{3C3}']),
+    ('\u{1D7BD}', true, &['\u{3C4}']),
+    ('\u{1D7BE}', true, &['\u{3C5}']),
+    ('\u{1D7BF}', true, &['\u{3C6}']),
+    ('\u{1D7C0}', true, &['\u{3C7}']),
+    ('\u{1D7C1}', true, &['\u{3C8}']),
+    ('\u{1D7C2}', true, &['\u{3C9}']),
+    ('\u{1D7C3}', true, &['\u{2202}']),
+    ('\u{1D7C4}', true, &['\u{3F5}']),
+    ('\u{1D7C5}', true, &['\u{3D1}']),
+    ('\u{1D7C6}', true, &['\u{3F0}']),
+    ('\u{1D7C7}', true, &['\u{3D5}']),
+    ('\u{1D7C8}', true, &['\u{3F1}']),
+    ('\u{1D7C9}', true, &['\u{3D6}']),
+    ('\u{1D7CA}', true, &['\u{3DC}']),
+    ('\u{1D7CB}', true, &['\u{3DD}']),
+    ('\u{1D7CE}', true, &['\u{30}']),
+    ('\u{1D7CF}', true, &['\u{31}']),
+    ('\u{1D7D0}', true, &['\u{32}']),
+    ('\u{1D7D1}', true, &['\u{33}']),
+    ('\u{1D7D2}', true, &['\u{34}']),
+    ('\u{1D7D3}', true, &['\u{35}']),
+    ('\u{1D7D4}', true, &['\u{36}']),
+    ('\u{1D7D5}', true, &['\u{37}']),
+    ('\u{1D7D6}', true, &['\u{38}']),
+    ('\u{1D7D7}', true, &['\u{39}']),
+    ('\u{1D7D8}', true, &['\u{30}']),
+    ('\u{1D7D9}', true, &['\u{31}']),
+    ('\u{1D7DA}', true, &['\u{32}']),
+    ('\u{1D7DB}', true, &['\u{33}']),
+    ('\u{1D7DC}', true, &['\u{34}']),
+    ('\u{1D7DD}', true, &['\u{35}']),
+    ('\u{1D7DE}', true, &['\u{36}']),
+    ('\u{1D7DF}', true, &['\u{37}']),
+    ('\u{1D7E0}', true, &['\u{38}']),
+    ('\u{1D7E1}', true, &['\u{39}']),
+    ('\u{1D7E2}', true, &['\u{30}']),
+    ('\u{1D7E3}', true, &['\u{31}']),
+    ('\u{1D7E4}', true, &['\u{32}']),
+    ('\u{1D7E5}', true, &['\u{33}']),
+    ('\u{1D7E6}', true, &['\u{34}']),
+    ('\u{1D7E7}', true, &['\u{35}']),
+    ('\u{1D7E8}', true, &['\u{36}']),
+    ('\u{1D7E9}', true, &['\u{37}']),
+    ('\u{1D7EA}', true, &['\u{38}']),
+    ('\u{1D7EB}', true, &['\u{39}']),
+    ('\u{1D7EC}', true, &['\u{30}']),
+    ('\u{1D7ED}', true, &['\u{31}']),
+    ('\u{1D7EE}', true, &['\u{32}']),
+    ('\u{1D7EF}', true, &['\u{33}']),
+    ('\u{1D7F0}', true, &['\u{34}']),
+    ('\u{1D7F1}', true, &['\u{35}']),
+    ('\u{1D7F2}', true, &['\u{36}']),
+    ('\u{1D7F3}', true, &['\u{37}']),
+    ('\u{1D7F4}', true, &['\u{38}']),
+    ('\u{1D7F5}', true, &['\u{39}']),
+    ('\u{1D7F6}', true, &['\u{30}']),
+    ('\u{1D7F7}', true, &['\u{31}']),
+    ('\u{1D7F8}', true, &['\u{32}']),
+    ('\u{1D7F9}', true, &['\u{33}']),
+    ('\u{1D7FA}', true, &['\u{34}']),
+    ('\u{1D7FB}', true, &['\u{35}']),
+    ('\u{1D7FC}', true, &['\u{36}']),
+    ('\u{1D7FD}', true, &['\u{37}']),
+    ('\u{1D7FE}', true, &['\u{38}']),
+    ('\u{1D7FF}', true, &['\u{39}']),
+    ('\u{1E030}', true, &['\u{430}']),
+    ('\u{1E031}', true, &['\u{431}']),
+    ('\u{1E032}', true, &['\u{432}']),
+    ('\u{1E033}', true, &['\u{433}']),
+    ('\u{1E034}', true, &['\u{434}']),
+    ('\u{1E035}', true, &['\u{435}']),
+    ('\u{1E036}', true, &['\u{436}']),
+    ('\u{1E037}', true, &['\u{437}']),
+    ('\u{1E038}', true, &['\u{438}']),
+    ('\u{1E039}', true, &['\u{43A}']),
+    ('\u{1E03A}', true, &['\u{43B}']),
+    ('\u{1E03B}', true, &['\u{43C}']),
+    ('\u{1E03C}', true, &['\u{43E}']),
+    ('\u{1E03D}', true, &['\u{43F}']),
+    ('\u{1E03E}', true, &['\u{440}']),
+    ('\u{1E03F}', true, &['\u{441}']),
+    ('\u{1E040}', true, &['\u{442}']),
+    ('\u{1E041}', true, &['\u{443}']),
+    ('\u{1E042}', true, &['\u{444}']),
+    ('\u{1E043}', true, &['\u{445}']),
+    ('\u{1E044}', true, &['\u{446}']),
+    ('\u{1E045}', true, &['\u{447}']),
+    ('\u{1E046}', true, &['\u{448}']),
+    ('\u{1E047}', true, &['\u{44B}']),
+    ('\u{1E048}', true, &['\u{44D}']),
+    ('\u{1E049}', true, &['\u{44E}']),
+    ('\u{1E04A}', true, &['\u{A689}']),
+    ('\u{1E04B}', true, &['\u{4D9}']),
+    ('\u{1E04C}', true, &['\u{456}']),
+    ('\u{1E04D}', true, &['\u{458}']),
+    ('\u{1E04E}', true, &['\u{4E9}']),
+    ('\u{1E04F}', true, &['\u{4AF}']),
+    ('\u{1E050}', true, &['\u{4CF}']),
+    ('\u{1E051}', true, &['\u{430}']),
+    ('\u{1E052}', true, &['\u{431}']),
+    ('\u{1E053}', true, &['\u{432}']),
+    ('\u{1E054}', true, &['\u{433}']),
+    ('\u{1E055}', true, &['\u{434}']),
+    ('\u{1E056}', true, &['\u{435}']),
+    ('\u{1E057}', true, &['\u{436}']),
+    ('\u{1E058}', true, &['\u{437}']),
+    ('\u{1E059}', true, &['\u{438}']),
+    ('\u{1E05A}', true, &['\u{43A}']),
+    ('\u{1E05B}', true, &['\u{43B}']),
+    ('\u{1E05C}', true, &['\u{43E}']),
+    ('\u{1E05D}', true, &['\u{43F}']),
+    ('\u{1E05E}', true, &['\u{441}']),
+    ('\u{1E05F}', true, &['\u{443}']),
+    ('\u{1E060}', true, &['\u{444}']),
+    ('\u{1E061}', true, &['\u{445}']),
+    ('\u{1E062}', true, &['\u{446}']),
+    ('\u{1E063}', true, &['\u{447}']),
+    ('\u{1E064}', true, &['\u{448}']),
+    ('\u{1E065}', true, &['\u{44A}']),
+    ('\u{1E066}', true, &['\u{44B}']),
+    ('\u{1E067}', true, &['\u{491}']),
+    ('\u{1E068}', true, &['\u{456}']),
+    ('\u{1E069}', true, &['\u{455}']),
+    ('\u{1E06A}', true, &['\u{45F}']),
+    ('\u{1E06B}', true, &['\u{4AB}']),
+    ('\u{1E06C}', true, &['\u{A651}']),
+    ('\u{1E06D}', true, &['\u{4B1}']),
+    ('\u{1EE00}', true, &['\u{627}']),
+    ('\u{1EE01}', true, &['\u{628}']),
+    ('\u{1EE02}', true, &['\u{62C}']),
+    ('\u{1EE03}', true, &['\u{62F}']),
+    ('\u{1EE05}', true, &['\u{648}']),
+    ('\u{1EE06}', true, &['\u{632}']),
+    ('\u{1EE07}', true, &['\u{62D}']),
+    ('\u{1EE08}', true, &['\u{637}']),
+    ('\u{1EE09}', true, &['\u{64A}']),
+    ('\u{1EE0A}', true, &['\u{643}']),
+    ('\u{1EE0B}', true, &['\u{644}']),
+    ('\u{1EE0C}', true, &['\u{645}']),
+    ('\u{1EE0D}', true, &['\u{646}']),
+    ('\u{1EE0E}', true, &['\u{633}']),
+    ('\u{1EE0F}', true, &['\u{639}']),
+    ('\u{1EE10}', true, &['\u{641}']),
+    ('\u{1EE11}', true, &['\u{635}']),
+    ('\u{1EE12}', true, &['\u{642}']),
+    ('\u{1EE13}', true, &['\u{631}']),
+    ('\u{1EE14}', true, &['\u{634}']),
+    ('\u{1EE15}', true, &['\u{62A}']),
+    ('\u{1EE16}', true, &['\u{62B}']),
+    ('\u{1EE17}', true, &['\u{62E}']),
+    ('\u{1EE18}', true, &['\u{630}']),
+    ('\u{1EE19}', true, &['\u{636}']),
+    ('\u{1EE1A}', true, &['\u{638}']),
+    ('\u{1EE1B}', true, &['\u{63A}']),
+    ('\u{1EE1C}', true, &['\u{66E}']),
+    ('\u{1EE1D}', true, &['\u{6BA}']),
+    ('\u{1EE1E}', true, &['\u{6A1}']),
+    ('\u{1EE1F}', true, &['\u{66F}']),
+    ('\u{1EE21}', true, &['\u{628}']),
+    ('\u{1EE22}', true, &['\u{62C}']),
+    ('\u{1EE24}', true, &['\u{647}']),
+    ('\u{1EE27}', true, &['\u{62D}']),
+    ('\u{1EE29}', true, &['\u{64A}']),
+    ('\u{1EE2A}', true, &['\u{643}']),
+    ('\u{1EE2B}', true, &['\u{644}']),
+    ('\u{1EE2C}', true, &['\u{645}']),
+    ('\u{1EE2D}', true, &['\u{646}']),
+    ('\u{1EE2E}', true, &['\u{633}']),
+    ('\u{1EE2F}', true, &['\u{639}']),
+    ('\u{1EE30}', true, &['\u{641}']),
+    ('\u{1EE31}', true, &['\u{635}']),
+    ('\u{1EE32}', true, &['\u{642}']),
+    ('\u{1EE34}', true, &['\u{634}']),
+    ('\u{1EE35}', true, &['\u{62A}']),
+    ('\u{1EE36}', true, &['\u{62B}']),
+    ('\u{1EE37}', true, &['\u{62E}']),
+    ('\u{1EE39}', true, &['\u{636}']),
+    ('\u{1EE3B}', true, &['\u{63A}']),
+    ('\u{1EE42}', true, &['\u{62C}']),
+    ('\u{1EE47}', true, &['\u{62D}']),
+    ('\u{1EE49}', true, &['\u{64A}']),
+    ('\u{1EE4B}', true, &['\u{644}']),
+    ('\u{1EE4D}', true, &['\u{646}']),
+    ('\u{1EE4E}', true, &['\u{633}']),
+    ('\u{1EE4F}', true, &['\u{639}']),
+    ('\u{1EE51}', true, &['\u{635}']),
+    ('\u{1EE52}', true, &['\u{642}']),
+    ('\u{1EE54}', true, &['\u{634}']),
+    ('\u{1EE57}', true, &['\u{62E}']),
+    ('\u{1EE59}', true, &['\u{636}']),
+    ('\u{1EE5B}', true, &['\u{63A}']),
+    ('\u{1EE5D}', true, &['\u{6BA}']),
+    ('\u{1EE5F}', true, &['\u{66F}']),
+    ('\u{1EE61}', true, &['\u{628}']),
+    ('\u{1EE62}', true, &['\u{62C}']),
+    ('\u{1EE64}', true, &['\u{647}']),
+    ('\u{1EE67}', true, &['\u{62D}']),
+    ('\u{1EE68}', true, &['\u{637}']),
+    ('\u{1EE69}', true, &['\u{64A}']),
+    ('\u{1EE6A}', true, &['\u{643}']),
+    ('\u{1EE6C}', true, &['\u{645}']),
+    ('\u{1EE6D}', true, &['\u{646}']),
+    ('\u{1EE6E}', true, &['\u{633}']),
+    ('\u{1EE6F}', true, &['\u{639}']),
+    ('\u{1EE70}', true, &['\u{641}']),
+    ('\u{1EE71}', true, &['\u{635}']),
+    ('\u{1EE72}', true, &['\u{642}']),
+    ('\u{1EE74}', true, &['\u{634}']),
+    ('\u{1EE75}', true, &['\u{62A}']),
+    ('\u{1EE76}', true, &['\u{62B}']),
+    ('\u{1EE77}', true, &['\u{62E}']),
+    ('\u{1EE79}', true, &['\u{636}']),
+    ('\u{1EE7A}', true, &['\u{638}']),
+    ('\u{1EE7B}', true, &['\u{63A}']),
+    ('\u{1EE7C}', true, &['\u{66E}']),
+    ('\u{1EE7E}', true, &['\u{6A1}']),
+    ('\u{1EE80}', true, &['\u{627}']),
+    ('\u{1EE81}', true, &['\u{628}']),
+    ('\u{1EE82}', true, &['\u{62C}']),
+    ('\u{1EE83}', true, &['\u{62F}']),
+    ('\u{1EE84}', true, &['\u{647}']),
+    ('\u{1EE85}', true, &['\u{648}']),
+    ('\u{1EE86}', true, &['\u{632}']),
+    ('\u{1EE87}', true, &['\u{62D}']),
+    ('\u{1EE88}', true, &['\u{637}']),
+    ('\u{1EE89}', true, &['\u{64A}']),
+    ('\u{1EE8B}', true, &['\u{644}']),
+    ('\u{1EE8C}', true, &['\u{645}']),
+    ('\u{1EE8D}', true, &['\u{646}']),
+    ('\u{1EE8E}', true, &['\u{633}']),
+    ('\u{1EE8F}', true, &['\u{639}']),
+    ('\u{1EE90}', true, &['\u{641}']),
+    ('\u{1EE91}', true, &['\u{635}']),
+    ('\u{1EE92}', true, &['\u{642}']),
+    ('\u{1EE93}', true, &['\u{631}']),
+    ('\u{1EE94}', true, &['\u{634}']),
+    ('\u{1EE95}', true, &['\u{62A}']),
+    ('\u{1EE96}', true, &['\u{62B}']),
+    ('\u{1EE97}', true, &['\u{62E}']),
+    ('\u{1EE98}', true, &['\u{630}']),
+    ('\u{1EE99}', true, &['\u{636}']),
+    ('\u{1EE9A}', true, &['\u{638}']),
+    ('\u{1EE9B}', true, &['\u{63A}']),
+    ('\u{1EEA1}', true, &['\u{628}']),
+    ('\u{1EEA2}', true, &['\u{62C}']),
+    ('\u{1EEA3}', true, &['\u{62F}']),
+    ('\u{1EEA5}', true, &['\u{648}']),
+    ('\u{1EEA6}', true, &['\u{632}']),
+    ('\u{1EEA7}', true, &['\u{62D}']),
+    ('\u{1EEA8}', true, &['\u{637}']),
+    ('\u{1EEA9}', true, &['\u{64A}']),
+    ('\u{1EEAB}', true, &['\u{644}']),
+    ('\u{1EEAC}', true, &['\u{645}']),
+    ('\u{1EEAD}', true, &['\u{646}']),
+    ('\u{1EEAE}', true, &['\u{633}']),
+    ('\u{1EEAF}', true, &['\u{639}']),
+    ('\u{1EEB0}', true, &['\u{641}']),
+    ('\u{1EEB1}', true, &['\u{635}']),
+    ('\u{1EEB2}', true, &['\u{642}']),
+    ('\u{1EEB3}', true, &['\u{631}']),
+    ('\u{1EEB4}', true, &['\u{634}']),
+    ('\u{1EEB5}', true, &['\u{62A}']),
+    ('\u{1EEB6}', true, &['\u{62B}']),
+    ('\u{1EEB7}', true, &['\u{62E}']),
+    ('\u{1EEB8}', true, &['\u{630}']),
+    ('\u{1EEB9}', true, &['\u{636}']),
+    ('\u{1EEBA}', true, &['\u{638}']),
+    ('\u{1EEBB}', true, &['\u{63A}']),
+    ('\u{1F100}', true, &['\u{30}', '\u{2E}']),
+    ('\u{1F101}', true, &['\u{30}', '\u{2C}']),
+    ('\u{1F102}', true, &['\u{31}', '\u{2C}']),
+    ('\u{1F103}', true, &['\u{32}', '\u{2C}']),
+    ('\u{1F104}', true, &['\u{33}', '\u{2C}']),
+    ('\u{1F105}', true, &['\u{34}', '\u{2C}']),
+    ('\u{1F106}', true, &['\u{35}', '\u{2C}']),
+    ('\u{1F107}', true, &['\u{36}', '\u{2C}']),
+    ('\u{1F108}', true, &['\u{37}', '\u{2C}']),
+    ('\u{1F109}', true, &['\u{38}', '\u{2C}']),
+    ('\u{1F10A}', true, &['\u{39}', '\u{2C}']),
+    ('\u{1F110}', true, &['\u{28}', '\u{41}', '\u{29}']),
+    ('\u{1F111}', true, &['\u{28}', '\u{42}', '\u{29}']),
+    ('\u{1F112}', true, &['\u{28}', '\u{43}', '\u{29}']),
+    ('\u{1F113}', true, &['\u{28}', '\u{44}', '\u{29}']),
+    ('\u{1F114}', true, &['\u{28}', '\u{45}', '\u{29}']),
+    ('\u{1F115}', true, &['\u{28}', '\u{46}', '\u{29}']),
+    ('\u{1F116}', true, &['\u{28}', '\u{47}', '\u{29}']),
+    ('\u{1F117}', true, &['\u{28}', '\u{48}', '\u{29}']),
+    ('\u{1F118}', true, &['\u{28}', '\u{49}', '\u{29}']),
+    ('\u{1F119}', true, &['\u{28}', '\u{4A}', '\u{29}']),
+    ('\u{1F11A}', true, &['\u{28}', '\u{4B}', '\u{29}']),
+    ('\u{1F11B}', true, &['\u{28}', '\u{4C}', '\u{29}']),
+    ('\u{1F11C}', true, &['\u{28}', '\u{4D}', '\u{29}']),
+    ('\u{1F11D}', true, &['\u{28}', '\u{4E}', '\u{29}']),
+    ('\u{1F11E}', true, &['\u{28}', '\u{4F}', '\u{29}']),
+    ('\u{1F11F}', true, &['\u{28}', '\u{50}', '\u{29}']),
+    ('\u{1F120}', true, &['\u{28}', '\u{51}', '\u{29}']),
+    ('\u{1F121}', true, &['\u{28}', '\u{52}', '\u{29}']),
+    ('\u{1F122}', true, &['\u{28}', '\u{53}', '\u{29}']),
+    ('\u{1F123}', true, &['\u{28}', '\u{54}', '\u{29}']),
+    ('\u{1F124}', true, &['\u{28}', '\u{55}', '\u{29}']),
+    ('\u{1F125}', true, &['\u{28}', '\u{56}', '\u{29}']),
+    ('\u{1F126}', true, &['\u{28}', '\u{57}', '\u{29}']),
+    ('\u{1F127}', true, &['\u{28}', '\u{58}', '\u{29}']),
+    ('\u{1F128}', true, &['\u{28}', '\u{59}', '\u{29}']),
+    ('\u{1F129}', true, &['\u{28}', '\u{5A}', '\u{29}']),
+    ('\u{1F12A}', true, &['\u{3014}', '\u{53}', '\u{3015}']),
+    ('\u{1F12B}', true, &['\u{43}']),
+    ('\u{1F12C}', true, &['\u{52}']),
+    ('\u{1F12D}', true, &['\u{43}', '\u{44}']),
+    ('\u{1F12E}', true, &['\u{57}', '\u{5A}']),
+    ('\u{1F130}', true, &['\u{41}']),
+    ('\u{1F131}', true, &['\u{42}']),
+    ('\u{1F132}', true, &['\u{43}']),
+    ('\u{1F133}', true, &['\u{44}']),
+    ('\u{1F134}', true, &['\u{45}']),
+    ('\u{1F135}', true, &['\u{46}']),
+    ('\u{1F136}', true, &['\u{47}']),
+    ('\u{1F137}', true, &['\u{48}']),
+    ('\u{1F138}', true, &['\u{49}']),
+    ('\u{1F139}', true, &['\u{4A}']),
+    ('\u{1F13A}', true, &['\u{4B}']),
+    ('\u{1F13B}', true, &['\u{4C}']),
+    ('\u{1F13C}', true, &['\u{4D}']),
+    ('\u{1F13D}', true, &['\u{4E}']),
+    ('\u{1F13E}', true, &['\u{4F}']),
+    ('\u{1F13F}', true, &['\u{50}']),
+    ('\u{1F140}', true, &['\u{51}']),
+    ('\u{1F141}', true, &['\u{52}']),
+    ('\u{1F142}', true, &['\u{53}']),
+    ('\u{1F143}', true, &['\u{54}']),
+    ('\u{1F144}', true, &['\u{55}']),
+    ('\u{1F145}', true, &['\u{56}']),
+    ('\u{1F146}', true, &['\u{57}']),
+    ('\u{1F147}', true, &['\u{58}']),
+    ('\u{1F148}', true, &['\u{59}']),
+    ('\u{1F149}', true, &['\u{5A}']),
+    ('\u{1F14A}', true, &['\u{48}', '\u{56}']),
+    ('\u{1F14B}', true, &['\u{4D}', '\u{56}']),
+    ('\u{1F14C}', true, &['\u{53}', '\u{44}']),
+    ('\u{1F14D}', true, &['\u{53}', '\u{53}']),
+    ('\u{1F14E}', true, &['\u{50}', '\u{50}', '\u{56}']),
+    ('\u{1F14F}', true, &['\u{57}', '\u{43}']),
+    ('\u{1F16A}', true, &['\u{4D}', '\u{43}']),
+    ('\u{1F16B}', true, &['\u{4D}', '\u{44}']),
+    ('\u{1F16C}', true, &['\u{4D}', '\u{52}']),
+    ('\u{1F190}', true, &['\u{44}', '\u{4A}']),
+    ('\u{1F200}', true, &['\u{307B}', '\u{304B}']),
+    ('\u{1F201}', true, &['\u{30B3}', '\u{30B3}']),
+    ('\u{1F202}', true, &['\u{30B5}']),
+    ('\u{1F210}', true, &['\u{624B}']),
+    ('\u{1F211}', true, &['\u{5B57}']),
+    ('\u{1F212}', true, &['\u{53CC}']),
+    ('\u{1F213}', true, &['\u{30C7}']),
+    ('\u{1F214}', true, &['\u{4E8C}']),
+    ('\u{1F215}', true, &['\u{591A}']),
+    ('\u{1F216}', true, &['\u{89E3}']),
+    ('\u{1F217}', true, &['\u{5929}']),
+    ('\u{1F218}', true, &['\u{4EA4}']),
+    ('\u{1F219}', true, &['\u{6620}']),
+    ('\u{1F21A}', true, &['\u{7121}']),
+    ('\u{1F21B}', true, &['\u{6599}']),
+    ('\u{1F21C}', true, &['\u{524D}']),
+    ('\u{1F21D}', true, &['\u{5F8C}']),
+    ('\u{1F21E}', true, &['\u{518D}']),
+    ('\u{1F21F}', true, &['\u{65B0}']),
+    ('\u{1F220}', true, &['\u{521D}']),
+    ('\u{1F221}', true, &['\u{7D42}']),
+    ('\u{1F222}', true, &['\u{751F}']),
+    ('\u{1F223}', true, &['\u{8CA9}']),
+    ('\u{1F224}', true, &['\u{58F0}']),
+    ('\u{1F225}', true, &['\u{5439}']),
+    ('\u{1F226}', true, &['\u{6F14}']),
+    ('\u{1F227}', true, &['\u{6295}']),
+    ('\u{1F228}', true, &['\u{6355}']),
+    ('\u{1F229}', true, &['\u{4E00}']),
+    ('\u{1F22A}', true, &['\u{4E09}']),
+    ('\u{1F22B}', true, &['\u{904A}']),
+    ('\u{1F22C}', true, &['\u{5DE6}']),
+    ('\u{1F22D}', true, &['\u{4E2D}']),
+    ('\u{1F22E}', true, &['\u{53F3}']),
+    ('\u{1F22F}', true, &['\u{6307}']),
+    ('\u{1F230}', true, &['\u{8D70}']),
+    ('\u{1F231}', true, &['\u{6253}']),
+    ('\u{1F232}', true, &['\u{7981}']),
+    ('\u{1F233}', true, &['\u{7A7A}']),
+    ('\u{1F234}', true, &['\u{5408}']),
+    ('\u{1F235}', true, &['\u{6E80}']),
+    ('\u{1F236}', true, &['\u{6709}']),
+    ('\u{1F237}', true, &['\u{6708}']),
+    ('\u{1F238}', true, &['\u{7533}']),
+    ('\u{1F239}', true, &['\u{5272}']),
+    ('\u{1F23A}', true, &['\u{55B6}']),
+    ('\u{1F23B}', true, &['\u{914D}']),
+    ('\u{1F240}', true, &['\u{3014}', '\u{672C}', '\u{3015}']),
+    ('\u{1F241}', true, &['\u{3014}', '\u{4E09}', '\u{3015}']),
+    ('\u{1F242}', true, &['\u{3014}', '\u{4E8C}', '\u{3015}']),
+    ('\u{1F243}', true, &['\u{3014}', '\u{5B89}', '\u{3015}']),
+    ('\u{1F244}', true, &['\u{3014}', '\u{70B9}', '\u{3015}']),
+    ('\u{1F245}', true, &['\u{3014}', '\u{6253}', '\u{3015}']),
+    ('\u{1F246}', true, &['\u{3014}', '\u{76D7}', '\u{3015}']),
+    ('\u{1F247}', true, &['\u{3014}', '\u{52DD}', '\u{3015}']),
+    ('\u{1F248}', true, &['\u{3014}', '\u{6557}', '\u{3015}']),
+    ('\u{1F250}', true, &['\u{5F97}']),
+    ('\u{1F251}', true, &['\u{53EF}']),
+    ('\u{1FBF0}', true, &['\u{30}']),
+    ('\u{1FBF1}', true, &['\u{31}']),
+    ('\u{1FBF2}', true, &['\u{32}']),
+    ('\u{1FBF3}', true, &['\u{33}']),
+    ('\u{1FBF4}', true, &['\u{34}']),
+    ('\u{1FBF5}', true, &['\u{35}']),
+    ('\u{1FBF6}', true, &['\u{36}']),
+    ('\u{1FBF7}', true, &['\u{37}']),
+    ('\u{1FBF8}', true, &['\u{38}']),
+    ('\u{1FBF9}', true, &['\u{39}']),
+    ('\u{2F800}', false, &['\u{4E3D}']),
+    ('\u{2F801}', false, &['\u{4E38}']),
+    ('\u{2F802}', false, &['\u{4E41}']),
+    ('\u{2F803}', false, &['\u{20122}']),
+    ('\u{2F804}', false, &['\u{4F60}']),
+    ('\u{2F805}', false, &['\u{4FAE}']),
+    ('\u{2F806}', false, &['\u{4FBB}']),
+    ('\u{2F807}', false, &['\u{5002}']),
+    ('\u{2F808}', false, &['\u{507A}']),
+    ('\u{2F809}', false, &['\u{5099}']),
+    ('\u{2F80A}', false, &['\u{50E7}']),
+    ('\u{2F80B}', false, &['\u{50CF}']),
+    ('\u{2F80C}', false, &['\u{349E}']),
+    ('\u{2F80D}', false, &['\u{2063A}']),
+    ('\u{2F80E}', false, &['\u{514D}']),
+    ('\u{2F80F}', false, &['\u{5154}']),
+    ('\u{2F810}', false, &['\u{5164}']),
+    ('\u{2F811}', false, &['\u{5177}']),
+    ('\u{2F812}', false, &['\u{2051C}']),
+    ('\u{2F813}', false, &['\u{34B9}']),
+    ('\u{2F814}', false, &['\u{5167}']),
+    ('\u{2F815}', false, &['\u{518D}']),
+    ('\u{2F816}', false, &['\u{2054B}']),
+    ('\u{2F817}', false, &['\u{5197}']),
+    ('\u{2F818}', false, &['\u{51A4}']),
+    ('\u{2F819}', false, &['\u{4ECC}']),
+    ('\u{2F81A}', false, &['\u{51AC}']),
+    ('\u{2F81B}', false, &['\u{51B5}']),
+    ('\u{2F81C}', false, &['\u{291DF}']),
+    ('\u{2F81D}', false, &['\u{51F5}']),
+    ('\u{2F81E}', false, &['\u{5203}']),
+    ('\u{2F81F}', false, &['\u{34DF}']),
+    ('\u{2F820}', false, &['\u{523B}']),
+    ('\u{2F821}', false, &['\u{5246}']),
+    ('\u{2F822}', false, &['\u{5272}']),
+    ('\u{2F823}', false, &['\u{5277}']),
+    ('\u{2F824}', false, &['\u{3515}']),
+    ('\u{2F825}', false, &['\u{52C7}']),
+    ('\u{2F826}', false, &['\u{52C9}']),
+    ('\u{2F827}', false, &['\u{52E4}']),
+    ('\u{2F828}', false, &['\u{52FA}']),
+    ('\u{2F829}', false, &['\u{5305}']),
+    ('\u{2F82A}', false, &['\u{5306}']),
+    ('\u{2F82B}', false, &['\u{5317}']),
+    ('\u{2F82C}', false, &['\u{5349}']),
+    ('\u{2F82D}', false, &['\u{5351}']),
+    ('\u{2F82E}', false, &['\u{535A}']),
+    ('\u{2F82F}', false, &['\u{5373}']),
+    ('\u{2F830}', false, &['\u{537D}']),
+    ('\u{2F831}', false, &['\u{537F}']),
+    ('\u{2F832}', false, &['\u{537F}']),
+    ('\u{2F833}', false, &['\u{537F}']),
+    ('\u{2F834}', false, &['\u{20A2C}']),
+    ('\u{2F835}', false, &['\u{7070}']),
+    ('\u{2F836}', false, &['\u{53CA}']),
+    ('\u{2F837}', false, &['\u{53DF}']),
+    ('\u{2F838}', false, &['\u{20B63}']),
+    ('\u{2F839}', false, &['\u{53EB}']),
+    ('\u{2F83A}', false, &['\u{53F1}']),
+    ('\u{2F83B}', false, &['\u{5406}']),
+    ('\u{2F83C}', false, &['\u{549E}']),
+    ('\u{2F83D}', false, &['\u{5438}']),
+    ('\u{2F83E}', false, &['\u{5448}']),
+    ('\u{2F83F}', false, &['\u{5468}']),
+    ('\u{2F840}', false, &['\u{54A2}']),
+    ('\u{2F841}', false, &['\u{54F6}']),
+    ('\u{2F842}', false, &['\u{5510}']),
+    ('\u{2F843}', false, &['\u{5553}']),
+    ('\u{2F844}', false, &['\u{5563}']),
+    ('\u{2F845}', false, &['\u{5584}']),
+    ('\u{2F846}', false, &['\u{5584}']),
+    ('\u{2F847}', false, &['\u{5599}']),
+    ('\u{2F848}', false, &['\u{55AB}']),
+    ('\u{2F849}', false, &['\u{55B3}']),
+    ('\u{2F84A}', false, &['\u{55C2}']),
+    ('\u{2F84B}', false, &['\u{5716}']),
+    ('\u{2F84C}', false, &['\u{5606}']),
+    ('\u{2F84D}', false, &['\u{5717}']),
+    ('\u{2F84E}', false, &['\u{5651}']),
+    ('\u{2F84F}', false, &['\u{5674}']),
+    ('\u{2F850}', false, &['\u{5207}']),
+    ('\u{2F851}', false, &['\u{58EE}']),
+    ('\u{2F852}', false, &['\u{57CE}']),
+    ('\u{2F853}', false, &['\u{57F4}']),
+    ('\u{2F854}', false, &['\u{580D}']),
+    ('\u{2F855}', false, &['\u{578B}']),
+    ('\u{2F856}', false, &['\u{5832}']),
+    ('\u{2F857}', false, &['\u{5831}']),
+    ('\u{2F858}', false, &['\u{58AC}']),
+    ('\u{2F859}', false, &['\u{214E4}']),
+    ('\u{2F85A}', false, &['\u{58F2}']),
+    ('\u{2F85B}', false, &['\u{58F7}']),
+    ('\u{2F85C}', false, &['\u{5906}']),
+    ('\u{2F85D}', false, &['\u{591A}']),
+    ('\u{2F85E}', false, &['\u{5922}']),
+    ('\u{2F85F}', false, &['\u{5962}']),
+    ('\u{2F860}', false, &['\u{216A8}']),
+    ('\u{2F861}', false, &['\u{216EA}']),
+    ('\u{2F862}', false, &['\u{59EC}']),
+    ('\u{2F863}', false, &['\u{5A1B}']),
+    ('\u{2F864}', false, &['\u{5A27}']),
+    ('\u{2F865}', false, &['\u{59D8}']),
+    ('\u{2F866}', false, &['\u{5A66}']),
+    ('\u{2F867}', false, &['\u{36EE}']),
+    ('\u{2F868}', false, &['\u{36FC}']),
+    ('\u{2F869}', false, &['\u{5B08}']),
+    ('\u{2F86A}', false, &['\u{5B3E}']),
+    ('\u{2F86B}', false, &['\u{5B3E}']),
+    ('\u{2F86C}', false, &['\u{219C8}']),
+    ('\u{2F86D}', false, &['\u{5BC3}']),
+    ('\u{2F86E}', false, &['\u{5BD8}']),
+    ('\u{2F86F}', false, &['\u{5BE7}']),
+    ('\u{2F870}', false, &['\u{5BF3}']),
+    ('\u{2F871}', false, &['\u{21B18}']),
+    ('\u{2F872}', false, &['\u{5BFF}']),
+    ('\u{2F873}', false, &['\u{5C06}']),
+    ('\u{2F874}', false, &['\u{5F53}']),
+    ('\u{2F875}', false, &['\u{5C22}']),
+    ('\u{2F876}', false, &['\u{3781}']),
+    ('\u{2F877}', false, &['\u{5C60}']),
+    ('\u{2F878}', false, &['\u{5C6E}']),
+    ('\u{2F879}', false, &['\u{5CC0}']),
+    ('\u{2F87A}', false, &['\u{5C8D}']),
+    ('\u{2F87B}', false, &['\u{21DE4}']),
+    ('\u{2F87C}', false, &['\u{5D43}']),
+    ('\u{2F87D}', false, &['\u{21DE6}']),
+    ('\u{2F87E}', false, &['\u{5D6E}']),
+    ('\u{2F87F}', false, &['\u{5D6B}']),
+    ('\u{2F880}', false, &['\u{5D7C}']),
+    ('\u{2F881}', false, &['\u{5DE1}']),
+    ('\u{2F882}', false, &['\u{5DE2}']),
+    ('\u{2F883}', false, &['\u{382F}']),
+    ('\u{2F884}', false, &['\u{5DFD}']),
+    ('\u{2F885}', false, &['\u{5E28}']),
+    ('\u{2F886}', false, &['\u{5E3D}']),
+    ('\u{2F887}', false, &['\u{5E69}']),
+    ('\u{2F888}', false, &['\u{3862}']),
+    ('\u{2F889}', false, &['\u{22183}']),
+    ('\u{2F88A}', false, &['\u{387C}']),
+    ('\u{2F88B}', false, &['\u{5EB0}']),
+    ('\u{2F88C}', false, &['\u{5EB3}']),
+    ('\u{2F88D}', false, &['\u{5EB6}']),
+    ('\u{2F88E}', false, &['\u{5ECA}']),
+    ('\u{2F88F}', false, &['\u{2A392}']),
+    ('\u{2F890}', false, &['\u{5EFE}']),
+    ('\u{2F891}', false, &['\u{22331}']),
+    ('\u{2F892}', false, &['\u{22331}']),
+    ('\u{2F893}', false, &['\u{8201}']),
+    ('\u{2F894}', false, &['\u{5F22}']),
+    ('\u{2F895}', false, &['\u{5F22}']),
+    ('\u{2F896}', false, &['\u{38C7}']),
+    ('\u{2F897}', false, &['\u{232B8}']),
+    ('\u{2F898}', false, &['\u{261DA}']),
+    ('\u{2F899}', false, &['\u{5F62}']),
+    ('\u{2F89A}', false, &['\u{5F6B}']),
+    ('\u{2F89B}', false, &['\u{38E3}']),
+    ('\u{2F89C}', false, &['\u{5F9A}']),
+    ('\u{2F89D}', false, &['\u{5FCD}']),
+    ('\u{2F89E}', false, &['\u{5FD7}']),
+    ('\u{2F89F}', false, &['\u{5FF9}']),
+    ('\u{2F8A0}', false, &['\u{6081}']),
+    ('\u{2F8A1}', false, &['\u{393A}']),
+    ('\u{2F8A2}', false, &['\u{391C}']),
+    ('\u{2F8A3}', false, &['\u{6094}']),
+    ('\u{2F8A4}', false, &['\u{226D4}']),
+    ('\u{2F8A5}', false, &['\u{60C7}']),
+    ('\u{2F8A6}', false, &['\u{6148}']),
+    ('\u{2F8A7}', false, &['\u{614C}']),
+    ('\u{2F8A8}', false, &['\u{614E}']),
+    ('\u{2F8A9}', false, &['\u{614C}']),
+    ('\u{2F8AA}', false, &['\u{617A}']),
+    ('\u{2F8AB}', false, &['\u{618E}']),
+    ('\u{2F8AC}', false, &['\u{61B2}']),
+    ('\u{2F8AD}', false, &['\u{61A4}']),
+    ('\u{2F8AE}', false, &['\u{61AF}']),
+    ('\u{2F8AF}', false, &['\u{61DE}']),
+    ('\u{2F8B0}', false, &['\u{61F2}']),
+    ('\u{2F8B1}', false, &['\u{61F6}']),
+    ('\u{2F8B2}', false, &['\u{6210}']),
+    ('\u{2F8B3}', false, &['\u{621B}']),
+    ('\u{2F8B4}', false, &['\u{625D}']),
+    ('\u{2F8B5}', false, &['\u{62B1}']),
+    ('\u{2F8B6}', false, &['\u{62D4}']),
+    ('\u{2F8B7}', false, &['\u{6350}']),
+    ('\u{2F8B8}', false, &['\u{22B0C}']),
+    ('\u{2F8B9}', false, &['\u{633D}']),
+    ('\u{2F8BA}', false, &['\u{62FC}']),
+    ('\u{2F8BB}', false, &['\u{6368}']),
+    ('\u{2F8BC}', false, &['\u{6383}']),
+    ('\u{2F8BD}', false, &['\u{63E4}']),
+    ('\u{2F8BE}', false, &['\u{22BF1}']),
+    ('\u{2F8BF}', false, &['\u{6422}']),
+    ('\u{2F8C0}', false, &['\u{63C5}']),
+    ('\u{2F8C1}', false, &['\u{63A9}']),
+    ('\u{2F8C2}', false, &['\u{3A2E}']),
+    ('\u{2F8C3}', false, &['\u{6469}']),
+    ('\u{2F8C4}', false, &['\u{647E}']),
+    ('\u{2F8C5}', false, &['\u{649D}']),
+    ('\u{2F8C6}', false, &['\u{6477}']),
+    ('\u{2F8C7}', false, &['\u{3A6C}']),
+    ('\u{2F8C8}', false, &['\u{654F}']),
+    ('\u{2F8C9}', false, &['\u{656C}']),
+    ('\u{2F8CA}', false, &['\u{2300A}']),
+    ('\u{2F8CB}', false, &['\u{65E3}']),
+    ('\u{2F8CC}', false, &['\u{66F8}']),
+    ('\u{2F8CD}', false, &['\u{6649}']),
+    ('\u{2F8CE}', false, &['\u{3B19}']),
+    ('\u{2F8CF}', false, &['\u{6691}']),
+    ('\u{2F8D0}', false, &['\u{3B08}']),
+    ('\u{2F8D1}', false, &['\u{3AE4}']),
+    ('\u{2F8D2}', false, &['\u{5192}']),
+    ('\u{2F8D3}', false, &['\u{5195}']),
+    ('\u{2F8D4}', false, &['\u{6700}']),
+    ('\u{2F8D5}', false, &['\u{669C}']),
+    ('\u{2F8D6}', false, &['\u{80AD}']),
+    ('\u{2F8D7}', false, &['\u{43D9}']),
+    ('\u{2F8D8}', false, &['\u{6717}']),
+    ('\u{2F8D9}', false, &['\u{671B}']),
+    ('\u{2F8DA}', false, &['\u{6721}']),
+    ('\u{2F8DB}', false, &['\u{675E}']),
+    ('\u{2F8DC}', false, &['\u{6753}']),
+    ('\u{2F8DD}', false, &['\u{233C3}']),
+    ('\u{2F8DE}', false, &['\u{3B49}']),
+    ('\u{2F8DF}', false, &['\u{67FA}']),
+    ('\u{2F8E0}', false, &['\u{6785}']),
+    ('\u{2F8E1}', false, &['\u{6852}']),
+    ('\u{2F8E2}', false, &['\u{6885}']),
+    ('\u{2F8E3}', false, &['\u{2346D}']),
+    ('\u{2F8E4}', false, &['\u{688E}']),
+    ('\u{2F8E5}', false, &['\u{681F}']),
+    ('\u{2F8E6}', false, &['\u{6914}']),
+    ('\u{2F8E7}', false, &['\u{3B9D}']),
+    ('\u{2F8E8}', false, &['\u{6942}']),
+    ('\u{2F8E9}', false, &['\u{69A3}']),
+    ('\u{2F8EA}', false, &['\u{69EA}']),
+    ('\u{2F8EB}', false, &['\u{6AA8}']),
+    ('\u{2F8EC}', false, &['\u{236A3}']),
+    ('\u{2F8ED}', false, &['\u{6ADB}']),
+    ('\u{2F8EE}', false, &['\u{3C18}']),
+    ('\u{2F8EF}', false, &['\u{6B21}']),
+    ('\u{2F8F0}', false, &['\u{238A7}']),
+    ('\u{2F8F1}', false, &['\u{6B54}']),
+    ('\u{2F8F2}', false, &['\u{3C4E}']),
+    ('\u{2F8F3}', false, &['\u{6B72}']),
+    ('\u{2F8F4}', false, &['\u{6B9F}']),
+    ('\u{2F8F5}', false, &['\u{6BBA}']),
+    ('\u{2F8F6}', false, &['\u{6BBB}']),
+    ('\u{2F8F7}', false, &['\u{23A8D}']),
+    ('\u{2F8F8}', false, &['\u{21D0B}']),
+    ('\u{2F8F9}', false, &['\u{23AFA}']),
+    ('\u{2F8FA}', false, &['\u{6C4E}']),
+    ('\u{2F8FB}', false, &['\u{23CBC}']),
+    ('\u{2F8FC}', false, &['\u{6CBF}']),
+    ('\u{2F8FD}', false, &['\u{6CCD}']),
+    ('\u{2F8FE}', false, &['\u{6C67}']),
+    ('\u{2F8FF}', false, &['\u{6D16}']),
+    ('\u{2F900}', false, &['\u{6D3E}']),
+    ('\u{2F901}', false, &['\u{6D77}']),
+    ('\u{2F902}', false, &['\u{6D41}']),
+    ('\u{2F903}', false, &['\u{6D69}']),
+    ('\u{2F904}', false, &['\u{6D78}']),
+    ('\u{2F905}', false, &['\u{6D85}']),
+    ('\u{2F906}', false, &['\u{23D1E}']),
+    ('\u{2F907}', false, &['\u{6D34}']),
+    ('\u{2F908}', false, &['\u{6E2F}']),
+    ('\u{2F909}', false, &['\u{6E6E}']),
+    ('\u{2F90A}', false, &['\u{3D33}']),
+    ('\u{2F90B}', false, &['\u{6ECB}']),
+    ('\u{2F90C}', false, &['\u{6EC7}']),
+    ('\u{2F90D}', false, &['\u{23ED1}']),
+    ('\u{2F90E}', false, &['\u{6DF9}']),
+    ('\u{2F90F}', false, &['\u{6F6E}']),
+    ('\u{2F910}', false, &['\u{23F5E}']),
+    ('\u{2F911}', false, &['\u{23F8E}']),
+    ('\u{2F912}', false, &['\u{6FC6}']),
+    ('\u{2F913}', false, &['\u{7039}']),
+    ('\u{2F914}', false, &['\u{701E}']),
+    ('\u{2F915}', false, &['\u{701B}']),
+    ('\u{2F916}', false, &['\u{3D96}']),
+    ('\u{2F917}', false, &['\u{704A}']),
+    ('\u{2F918}', false, &['\u{707D}']),
+    ('\u{2F919}', false, &['\u{7077}']),
+    ('\u{2F91A}', false, &['\u{70AD}']),
+    ('\u{2F91B}', false, &['\u{20525}']),
+    ('\u{2F91C}', false, &['\u{7145}']),
+    ('\u{2F91D}', false, &['\u{24263}']),
+    ('\u{2F91E}', false, &['\u{719C}']),
+    ('\u{2F91F}', false, &['\u{243AB}']),
+    ('\u{2F920}', false, &['\u{7228}']),
+    ('\u{2F921}', false, &['\u{7235}']),
+    ('\u{2F922}', false, &['\u{7250}']),
+    ('\u{2F923}', false, &['\u{24608}']),
+    ('\u{2F924}', false, &['\u{7280}']),
+    ('\u{2F925}', false, &['\u{7295}']),
+    ('\u{2F926}', false, &['\u{24735}']),
+    ('\u{2F927}', false, &['\u{24814}']),
+    ('\u{2F928}', false, &['\u{737A}']),
+    ('\u{2F929}', false, &['\u{738B}']),
+    ('\u{2F92A}', false, &['\u{3EAC}']),
+    ('\u{2F92B}', false, &['\u{73A5}']),
+    ('\u{2F92C}', false, &['\u{3EB8}']),
+    ('\u{2F92D}', false, &['\u{3EB8}']),
+    ('\u{2F92E}', false, &['\u{7447}']),
+    ('\u{2F92F}', false, &['\u{745C}']),
+    ('\u{2F930}', false, &['\u{7471}']),
+    ('\u{2F931}', false, &['\u{7485}']),
+    ('\u{2F932}', false, &['\u{74CA}']),
+    ('\u{2F933}', false, &['\u{3F1B}']),
+    ('\u{2F934}', false, &['\u{7524}']),
+    ('\u{2F935}', false, &['\u{24C36}']),
+    ('\u{2F936}', false, &['\u{753E}']),
+    ('\u{2F937}', false, &['\u{24C92}']),
+    ('\u{2F938}', false, &['\u{7570}']),
+    ('\u{2F939}', false, &['\u{2219F}']),
+    ('\u{2F93A}', false, &['\u{7610}']),
+    ('\u{2F93B}', false, &['\u{24FA1}']),
+    ('\u{2F93C}', false, &['\u{24FB8}']),
+    ('\u{2F93D}', false, &['\u{25044}']),
+    ('\u{2F93E}', false, &['\u{3FFC}']),
+    ('\u{2F93F}', false, &['\u{4008}']),
+    ('\u{2F940}', false, &['\u{76F4}']),
+    ('\u{2F941}', false, &['\u{250F3}']),
+    ('\u{2F942}', false, &['\u{250F2}']),
+    ('\u{2F943}', false, &['\u{25119}']),
+    ('\u{2F944}', false, &['\u{25133}']),
+    ('\u{2F945}', false, &['\u{771E}']),
+    ('\u{2F946}', false, &['\u{771F}']),
+    ('\u{2F947}', false, &['\u{771F}']),
+    ('\u{2F948}', false, &['\u{774A}']),
+    ('\u{2F949}', false, &['\u{4039}']),
+    ('\u{2F94A}', false, &['\u{778B}']),
+    ('\u{2F94B}', false, &['\u{4046}']),
+    ('\u{2F94C}', false, &['\u{4096}']),
+    ('\u{2F94D}', false, &['\u{2541D}']),
+    ('\u{2F94E}', false, &['\u{784E}']),
+    ('\u{2F94F}', false, &['\u{788C}']),
+    ('\u{2F950}', false, &['\u{78CC}']),
+    ('\u{2F951}', false, &['\u{40E3}']),
+    ('\u{2F952}', false, &['\u{25626}']),
+    ('\u{2F953}', false, &['\u{7956}']),
+    ('\u{2F954}', false, &['\u{2569A}']),
+    ('\u{2F955}', false, &['\u{256C5}']),
+    ('\u{2F956}', false, &['\u{798F}']),
+    ('\u{2F957}', false, &['\u{79EB}']),
+    ('\u{2F958}', false, &['\u{412F}']),
+    ('\u{2F959}', false, &['\u{7A40}']),
+    ('\u{2F95A}', false, &['\u{7A4A}']),
+    ('\u{2F95B}', false, &['\u{7A4F}']),
+    ('\u{2F95C}', false, &['\u{2597C}']),
+    ('\u{2F95D}', false, &['\u{25AA7}']),
+    ('\u{2F95E}', false, &['\u{25AA7}']),
+    ('\u{2F95F}', false, &['\u{7AEE}']),
+    ('\u{2F960}', false, &['\u{4202}']),
+    ('\u{2F961}', false, &['\u{25BAB}']),
+    ('\u{2F962}', false, &['\u{7BC6}']),
+    ('\u{2F963}', false, &['\u{7BC9}']),
+    ('\u{2F964}', false, &['\u{4227}']),
+    ('\u{2F965}', false, &['\u{25C80}']),
+    ('\u{2F966}', false, &['\u{7CD2}']),
+    ('\u{2F967}', false, &['\u{42A0}']),
+    ('\u{2F968}', false, &['\u{7CE8}']),
+    ('\u{2F969}', false, &['\u{7CE3}']),
+    ('\u{2F96A}', false, &['\u{7D00}']),
+    ('\u{2F96B}', false, &['\u{25F86}']),
+    ('\u{2F96C}', false, &['\u{7D63}']),
+    ('\u{2F96D}', false, &['\u{4301}']),
+    ('\u{2F96E}', false, &['\u{7DC7}']),
+    ('\u{2F96F}', false, &['\u{7E02}']),
+    ('\u{2F970}', false, &['\u{7E45}']),
+    ('\u{2F971}', false, &['\u{4334}']),
+    ('\u{2F972}', false, &['\u{26228}']),
+    ('\u{2F973}', false, &['\u{26247}']),
+    ('\u{2F974}', false, &['\u{4359}']),
+    ('\u{2F975}', false, &['\u{262D9}']),
+    ('\u{2F976}', false, &['\u{7F7A}']),
+    ('\u{2F977}', false, &['\u{2633E}']),
+    ('\u{2F978}', false, &['\u{7F95}']),
+    ('\u{2F979}', false, &['\u{7FFA}']),
+    ('\u{2F97A}', false, &['\u{8005}']),
+    ('\u{2F97B}', false, &['\u{264DA}']),
+    ('\u{2F97C}', false, &['\u{26523}']),
+    ('\u{2F97D}', false, &['\u{8060}']),
+    ('\u{2F97E}', false, &['\u{265A8}']),
+    ('\u{2F97F}', false, &['\u{8070}']),
+    ('\u{2F980}', false, &['\u{2335F}']),
+    ('\u{2F981}', false, &['\u{43D5}']),
+    ('\u{2F982}', false, &['\u{80B2}']),
+    ('\u{2F983}', false, &['\u{8103}']),
+    ('\u{2F984}', false, &['\u{440B}']),
+    ('\u{2F985}', false, &['\u{813E}']),
+    ('\u{2F986}', false, &['\u{5AB5}']),
+    ('\u{2F987}', false, &['\u{267A7}']),
+    ('\u{2F988}', false, &['\u{267B5}']),
+    ('\u{2F989}', false, &['\u{23393}']),
+    ('\u{2F98A}', false, &['\u{2339C}']),
+    ('\u{2F98B}', false, &['\u{8201}']),
+    ('\u{2F98C}', false, &['\u{8204}']),
+    ('\u{2F98D}', false, &['\u{8F9E}']),
+    ('\u{2F98E}', false, &['\u{446B}']),
+    ('\u{2F98F}', false, &['\u{8291}']),
+    ('\u{2F990}', false, &['\u{828B}']),
+    ('\u{2F991}', false, &['\u{829D}']),
+    ('\u{2F992}', false, &['\u{52B3}']),
+    ('\u{2F993}', false, &['\u{82B1}']),
+    ('\u{2F994}', false, &['\u{82B3}']),
+    ('\u{2F995}', false, &['\u{82BD}']),
+    ('\u{2F996}', false, &['\u{82E6}']),
+    ('\u{2F997}', false, &['\u{26B3C}']),
+    ('\u{2F998}', false, &['\u{82E5}']),
+    ('\u{2F999}', false, &['\u{831D}']),
+    ('\u{2F99A}', false, &['\u{8363}']),
+    ('\u{2F99B}', false, &['\u{83AD}']),
+    ('\u{2F99C}', false, &['\u{8323}']),
+    ('\u{2F99D}', false, &['\u{83BD}']),
+    ('\u{2F99E}', false, &['\u{83E7}']),
+    ('\u{2F99F}', false, &['\u{8457}']),
+    ('\u{2F9A0}', false, &['\u{8353}']),
+    ('\u{2F9A1}', false, &['\u{83CA}']),
+    ('\u{2F9A2}', false, &['\u{83CC}']),
+    ('\u{2F9A3}', false, &['\u{83DC}']),
+    ('\u{2F9A4}', false, &['\u{26C36}']),
+    ('\u{2F9A5}', false, &['\u{26D6B}']),
+    ('\u{2F9A6}', false, &['\u{26CD5}']),
+    ('\u{2F9A7}', false, &['\u{452B}']),
+    ('\u{2F9A8}', false, &['\u{84F1}']),
+    ('\u{2F9A9}', false, &['\u{84F3}']),
+    ('\u{2F9AA}', false, &['\u{8516}']),
+    ('\u{2F9AB}', false, &['\u{273CA}']),
+    ('\u{2F9AC}', false, &['\u{8564}']),
+    ('\u{2F9AD}', false, &['\u{26F2C}']),
+    ('\u{2F9AE}', false, &['\u{455D}']),
+    ('\u{2F9AF}', false, &['\u{4561}']),
+    ('\u{2F9B0}', false, &['\u{26FB1}']),
+    ('\u{2F9B1}', false, &['\u{270D2}']),
+    ('\u{2F9B2}', false, &['\u{456B}']),
+    ('\u{2F9B3}', false, &['\u{8650}']),
+    ('\u{2F9B4}', false, &['\u{865C}']),
+    ('\u{2F9B5}', false, &['\u{8667}']),
+    ('\u{2F9B6}', false, &['\u{8669}']),
+    ('\u{2F9B7}', false, &['\u{86A9}']),
+    ('\u{2F9B8}', false, &['\u{8688}']),
+    ('\u{2F9B9}', false, &['\u{870E}']),
+    ('\u{2F9BA}', false, &['\u{86E2}']),
+    ('\u{2F9BB}', false, &['\u{8779}']),
+    ('\u{2F9BC}', false, &['\u{8728}']),
+    ('\u{2F9BD}', false, &['\u{876B}']),
+    ('\u{2F9BE}', false, &['\u{8786}']),
+    ('\u{2F9BF}', false, &['\u{45D7}']),
+    ('\u{2F9C0}', false, &['\u{87E1}']),
+    ('\u{2F9C1}', false, &['\u{8801}']),
+    ('\u{2F9C2}', false, &['\u{45F9}']),
+    ('\u{2F9C3}', false, &['\u{8860}']),
+    ('\u{2F9C4}', false, &['\u{8863}']),
+    ('\u{2F9C5}', false, &['\u{27667}']),
+    ('\u{2F9C6}', false, &['\u{88D7}']),
+    ('\u{2F9C7}', false, &['\u{88DE}']),
+    ('\u{2F9C8}', false, &['\u{4635}']),
+    ('\u{2F9C9}', false, &['\u{88FA}']),
+    ('\u{2F9CA}', false, &['\u{34BB}']),
+    ('\u{2F9CB}', false, &['\u{278AE}']),
+    ('\u{2F9CC}', false, &['\u{27966}']),
+    ('\u{2F9CD}', false, &['\u{46BE}']),
+    ('\u{2F9CE}', false, &['\u{46C7}']),
+    ('\u{2F9CF}', false, &['\u{8AA0}']),
+    ('\u{2F9D0}', false, &['\u{8AED}']),
+    ('\u{2F9D1}', false, &['\u{8B8A}']),
+    ('\u{2F9D2}', false, &['\u{8C55}']),
+    ('\u{2F9D3}', false, &['\u{27CA8}']),
+    ('\u{2F9D4}', false, &['\u{8CAB}']),
+    ('\u{2F9D5}', false, &['\u{8CC1}']),
+    ('\u{2F9D6}', false, &['\u{8D1B}']),
+    ('\u{2F9D7}', false, &['\u{8D77}']),
+    ('\u{2F9D8}', false, &['\u{27F2F}']),
+    ('\u{2F9D9}', false, &['\u{20804}']),
+    ('\u{2F9DA}', false, &['\u{8DCB}']),
+    ('\u{2F9DB}', false, &['\u{8DBC}']),
+    ('\u{2F9DC}', false, &['\u{8DF0}']),
+    ('\u{2F9DD}', false, &['\u{208DE}']),
+    ('\u{2F9DE}', false, &['\u{8ED4}']),
+    ('\u{2F9DF}', false, &['\u{8F38}']),
+    ('\u{2F9E0}', false, &['\u{285D2}']),
+    ('\u{2F9E1}', false, &['\u{285ED}']),
+    ('\u{2F9E2}', false, &['\u{9094}']),
+    ('\u{2F9E3}', false, &['\u{90F1}']),
+    ('\u{2F9E4}', false, &['\u{9111}']),
+    ('\u{2F9E5}', false, &['\u{2872E}']),
+    ('\u{2F9E6}', false, &['\u{911B}']),
+    ('\u{2F9E7}', false, &['\u{9238}']),
+    ('\u{2F9E8}', false, &['\u{92D7}']),
+    ('\u{2F9E9}', false, &['\u{92D8}']),
+    ('\u{2F9EA}', false, &['\u{927C}']),
+    ('\u{2F9EB}', false, &['\u{93F9}']),
+    ('\u{2F9EC}', false, &['\u{9415}']),
+    ('\u{2F9ED}', false, &['\u{28BFA}']),
+    ('\u{2F9EE}', false, &['\u{958B}']),
+    ('\u{2F9EF}', false, &['\u{4995}']),
+    ('\u{2F9F0}', false, &['\u{95B7}']),
+    ('\u{2F9F1}', false, &['\u{28D77}']),
+    ('\u{2F9F2}', false, &['\u{49E6}']),
+    ('\u{2F9F3}', false, &['\u{96C3}']),
+    ('\u{2F9F4}', false, &['\u{5DB2}']),
+    ('\u{2F9F5}', false, &['\u{9723}']),
+    ('\u{2F9F6}', false, &['\u{29145}']),
+    ('\u{2F9F7}', false, &['\u{2921A}']),
+    ('\u{2F9F8}', false, &['\u{4A6E}']),
+    ('\u{2F9F9}', false, &['\u{4A76}']),
+    ('\u{2F9FA}', false, &['\u{97E0}']),
+    ('\u{2F9FB}', false, &['\u{2940A}']),
+    ('\u{2F9FC}', false, &['\u{4AB2}']),
+    ('\u{2F9FD}', false, &['\u{29496}']),
+    ('\u{2F9FE}', false, &['\u{980B}']),
+    ('\u{2F9FF}', false, &['\u{980B}']),
+    ('\u{2FA00}', false, &['\u{9829}']),
+    ('\u{2FA01}', false, &['\u{295B6}']),
+    ('\u{2FA02}', false, &['\u{98E2}']),
+    ('\u{2FA03}', false, &['\u{4B33}']),
+    ('\u{2FA04}', false, &['\u{9929}']),
+    ('\u{2FA05}', false, &['\u{99A7}']),
+    ('\u{2FA06}', false, &['\u{99C2}']),
+    ('\u{2FA07}', false, &['\u{99FE}']),
+    ('\u{2FA08}', false, &['\u{4BCE}']),
+    ('\u{2FA09}', false, &['\u{29B30}']),
+    ('\u{2FA0A}', false, &['\u{9B12}']),
+    ('\u{2FA0B}', false, &['\u{9C40}']),
+    ('\u{2FA0C}', false, &['\u{9CFD}']),
+    ('\u{2FA0D}', false, &['\u{4CCE}']),
+    ('\u{2FA0E}', false, &['\u{4CED}']),
+    ('\u{2FA0F}', false, &['\u{9D67}']),
+    ('\u{2FA10}', false, &['\u{2A0CE}']),
+    ('\u{2FA11}', false, &['\u{4CF8}']),
+    ('\u{2FA12}', false, &['\u{2A105}']),
+    ('\u{2FA13}', false, &['\u{2A20E}']),
+    ('\u{2FA14}', false, &['\u{2A291}']),
+    ('\u{2FA15}', false, &['\u{9EBB}']),
+    ('\u{2FA16}', false, &['\u{4D56}']),
+    ('\u{2FA17}', false, &['\u{9EF9}']),
+    ('\u{2FA18}', false, &['\u{9EFE}']),
+    ('\u{2FA19}', false, &['\u{9F05}']),
+    ('\u{2FA1A}', false, &['\u{9F0F}']),
+    ('\u{2FA1B}', false, &['\u{9F16}']),
+    ('\u{2FA1C}', false, &['\u{9F3B}']),
+    ('\u{2FA1D}', false, &['\u{2A600}']),
+];
+
+/// Primary composites, sorted by the pair they compose from
+pub(crate) const COMPOSITION: &[((char, char), char)] = &[
+    (('\u{3C}', '\u{338}'), '\u{226E}'),
+    (('\u{3D}', '\u{338}'), '\u{2260}'),
+    (('\u{3E}', '\u{338}'), '\u{226F}'),
+    (('\u{41}', '\u{300}'), '\u{C0}'),
+    (('\u{41}', '\u{301}'), '\u{C1}'),
+    (('\u{41}', '\u{302}'), '\u{C2}'),
+    (('\u{41}', '\u{303}'), '\u{C3}'),
+    (('\u{41}', '\u{304}'), '\u{100}'),
+    (('\u{41}', '\u{306}'), '\u{102}'),
+    (('\u{41}', '\u{307}'), '\u{226}'),
+    (('\u{41}', '\u{308}'), '\u{C4}'),
+    (('\u{41}', '\u{309}'), '\u{1EA2}'),
+    (('\u{41}', '\u{30A}'), '\u{C5}'),
+    (('\u{41}', '\u{30C}'), '\u{1CD}'),
+    (('\u{41}', '\u{30F}'), '\u{200}'),
+    (('\u{41}', '\u{311}'), '\u{202}'),
+    (('\u{41}', '\u{323}'), '\u{1EA0}'),
+    (('\u{41}', '\u{325}'), '\u{1E00}'),
+    (('\u{41}', '\u{328}'), '\u{104}'),
+    (('\u{42}', '\u{307}'), '\u{1E02}'),
+    (('\u{42}', '\u{323}'), '\u{1E04}'),
+    (('\u{42}', '\u{331}'), '\u{1E06}'),
+    (('\u{43}', '\u{301}'), '\u{106}'),
+    (('\u{43}', '\u{302}'), '\u{108}'),
+    (('\u{43}', '\u{307}'), '\u{10A}'),
+    (('\u{43}', '\u{30C}'), '\u{10C}'),
+    (('\u{43}', '\u{327}'), '\u{C7}'),
+    (('\u{44}', '\u{307}'), '\u{1E0A}'),
+    (('\u{44}', '\u{30C}'), '\u{10E}'),
+    (('\u{44}', '\u{323}'), '\u{1E0C}'),
+    (('\u{44}', '\u{327}'), '\u{1E10}'),
+    (('\u{44}', '\u{32D}'), '\u{1E12}'),
+    (('\u{44}', '\u{331}'), '\u{1E0E}'),
+    (('\u{45}', '\u{300}'), '\u{C8}'),
+    (('\u{45}', '\u{301}'), '\u{C9}'),
+    (('\u{45}', '\u{302}'), '\u{CA}'),
+    (('\u{45}', '\u{303}'), '\u{1EBC}'),
+    (('\u{45}', '\u{304}'), '\u{112}'),
+    (('\u{45}', '\u{306}'), '\u{114}'),
+    (('\u{45}', '\u{307}'), '\u{116}'),
+    (('\u{45}', '\u{308}'), '\u{CB}'),
+    (('\u{45}', '\u{309}'), '\u{1EBA}'),
+    (('\u{45}', '\u{30C}'), '\u{11A}'),
+    (('\u{45}', '\u{30F}'), '\u{204}'),
+    (('\u{45}', '\u{311}'), '\u{206}'),
+    (('\u{45}', '\u{323}'), '\u{1EB8}'),
+    (('\u{45}', '\u{327}'), '\u{228}'),
+    (('\u{45}', '\u{328}'), '\u{118}'),
+    (('\u{45}', '\u{32D}'), '\u{1E18}'),
+    (('\u{45}', '\u{330}'), '\u{1E1A}'),
+    (('\u{46}', '\u{307}'), '\u{1E1E}'),
+    (('\u{47}', '\u{301}'), '\u{1F4}'),
+    (('\u{47}', '\u{302}'), '\u{11C}'),
+    (('\u{47}', '\u{304}'), '\u{1E20}'),
+    (('\u{47}', '\u{306}'), '\u{11E}'),
+    (('\u{47}', '\u{307}'), '\u{120}'),
+    (('\u{47}', '\u{30C}'), '\u{1E6}'),
+    (('\u{47}', '\u{327}'), '\u{122}'),
+    (('\u{48}', '\u{302}'), '\u{124}'),
+    (('\u{48}', '\u{307}'), '\u{1E22}'),
+    (('\u{48}', '\u{308}'), '\u{1E26}'),
+    (('\u{48}', '\u{30C}'), '\u{21E}'),
+    (('\u{48}', '\u{323}'), '\u{1E24}'),
+    (('\u{48}', '\u{327}'), '\u{1E28}'),
+    (('\u{48}', '\u{32E}'), '\u{1E2A}'),
+    (('\u{49}', '\u{300}'), '\u{CC}'),
+    (('\u{49}', '\u{301}'), '\u{CD}'),
+    (('\u{49}', '\u{302}'), '\u{CE}'),
+    (('\u{49}', '\u{303}'), '\u{128}'),
+    (('\u{49}', '\u{304}'), '\u{12A}'),
+    (('\u{49}', '\u{306}'), '\u{12C}'),
+    (('\u{49}', '\u{307}'), '\u{130}'),
+    (('\u{49}', '\u{308}'), '\u{CF}'),
+    (('\u{49}', '\u{309}'), '\u{1EC8}'),
+    (('\u{49}', '\u{30C}'), '\u{1CF}'),
+    (('\u{49}', '\u{30F}'), '\u{208}'),
+    (('\u{49}', '\u{311}'), '\u{20A}'),
+    (('\u{49}', '\u{323}'), '\u{1ECA}'),
+    (('\u{49}', '\u{328}'), '\u{12E}'),
+    (('\u{49}', '\u{330}'), '\u{1E2C}'),
+    (('\u{4A}', '\u{302}'), '\u{134}'),
+    (('\u{4B}', '\u{301}'), '\u{1E30}'),
+    (('\u{4B}', '\u{30C}'), '\u{1E8}'),
+    (('\u{4B}', '\u{323}'), '\u{1E32}'),
+    (('\u{4B}', '\u{327}'), '\u{136}'),
+    (('\u{4B}', '\u{331}'), '\u{1E34}'),
+    (('\u{4C}', '\u{301}'), '\u{139}'),
+    (('\u{4C}', '\u{30C}'), '\u{13D}'),
+    (('\u{4C}', '\u{323}'), '\u{1E36}'),
+    (('\u{4C}', '\u{327}'), '\u{13B}'),
+    (('\u{4C}', '\u{32D}'), '\u{1E3C}'),
+    (('\u{4C}', '\u{331}'), '\u{1E3A}'),
+    (('\u{4D}', '\u{301}'), '\u{1E3E}'),
+    (('\u{4D}', '\u{307}'), '\u{1E40}'),
+    (('\u{4D}', '\u{323}'), '\u{1E42}'),
+    (('\u{4E}', '\u{300}'), '\u{1F8}'),
+    (('\u{4E}', '\u{301}'), '\u{143}'),
+    (('\u{4E}', '\u{303}'), '\u{D1}'),
+    (('\u{4E}', '\u{307}'), '\u{1E44}'),
+    (('\u{4E}', '\u{30C}'), '\u{147}'),
+    (('\u{4E}', '\u{323}'), '\u{1E46}'),
+    (('\u{4E}', '\u{327}'), '\u{145}'),
+    (('\u{4E}', '\u{32D}'), '\u{1E4A}'),
+    (('\u{4E}', '\u{331}'), '\u{1E48}'),
+    (('\u{4F}', '\u{300}'), '\u{D2}'),
+    (('\u{4F}', '\u{301}'), '\u{D3}'),
+    (('\u{4F}', '\u{302}'), '\u{D4}'),
+    (('\u{4F}', '\u{303}'), '\u{D5}'),
+    (('\u{4F}', '\u{304}'), '\u{14C}'),
+    (('\u{4F}', '\u{306}'), '\u{14E}'),
+    (('\u{4F}', '\u{307}'), '\u{22E}'),
+    (('\u{4F}', '\u{308}'), '\u{D6}'),
+    (('\u{4F}', '\u{309}'), '\u{1ECE}'),
+    (('\u{4F}', '\u{30B}'), '\u{150}'),
+    (('\u{4F}', '\u{30C}'), '\u{1D1}'),
+    (('\u{4F}', '\u{30F}'), '\u{20C}'),
+    (('\u{4F}', '\u{311}'), '\u{20E}'),
+    (('\u{4F}', '\u{31B}'), '\u{1A0}'),
+    (('\u{4F}', '\u{323}'), '\u{1ECC}'),
+    (('\u{4F}', '\u{328}'), '\u{1EA}'),
+    (('\u{50}', '\u{301}'), '\u{1E54}'),
+    (('\u{50}', '\u{307}'), '\u{1E56}'),
+    (('\u{52}', '\u{301}'), '\u{154}'),
+    (('\u{52}', '\u{307}'), '\u{1E58}'),
+    (('\u{52}', '\u{30C}'), '\u{158}'),
+    (('\u{52}', '\u{30F}'), '\u{210}'),
+    (('\u{52}', '\u{311}'), '\u{212}'),
+    (('\u{52}', '\u{323}'), '\u{1E5A}'),
+    (('\u{52}', '\u{327}'), '\u{156}'),
+    (('\u{52}', '\u{331}'), '\u{1E5E}'),
+    (('\u{53}', '\u{301}'), '\u{15A}'),
+    (('\u{53}', '\u{302}'), '\u{15C}'),
+    (('\u{53}', '\u{307}'), '\u{1E60}'),
+    (('\u{53}', '\u{30C}'), '\u{160}'),
+    (('\u{53}', '\u{323}'), '\u{1E62}'),
+    (('\u{53}', '\u{326}'), '\u{218}'),
+    (('\u{53}', '\u{327}'), '\u{15E}'),
+    (('\u{54}', '\u{307}'), '\u{1E6A}'),
+    (('\u{54}', '\u{30C}'), '\u{164}'),
+    (('\u{54}', '\u{323}'), '\u{1E6C}'),
+    (('\u{54}', '\u{326}'), '\u{21A}'),
+    (('\u{54}', '\u{327}'), '\u{162}'),
+    (('\u{54}', '\u{32D}'), '\u{1E70}'),
+    (('\u{54}', '\u{331}'), '\u{1E6E}'),
+    (('\u{55}', '\u{300}'), '\u{D9}'),
+    (('\u{55}', '\u{301}'), '\u{DA}'),
+    (('\u{55}', '\u{302}'), '\u{DB}'),
+    (('\u{55}', '\u{303}'), '\u{168}'),
+    (('\u{55}', '\u{304}'), '\u{16A}'),
+    (('\u{55}', '\u{306}'), '\u{16C}'),
+    (('\u{55}', '\u{308}'), '\u{DC}'),
+    (('\u{55}', '\u{309}'), '\u{1EE6}'),
+    (('\u{55}', '\u{30A}'), '\u{16E}'),
+    (('\u{55}', '\u{30B}'), '\u{170}'),
+    (('\u{55}', '\u{30C}'), '\u{1D3}'),
+    (('\u{55}', '\u{30F}'), '\u{214}'),
+    (('\u{55}', '\u{311}'), '\u{216}'),
+    (('\u{55}', '\u{31B}'), '\u{1AF}'),
+    (('\u{55}', '\u{323}'), '\u{1EE4}'),
+    (('\u{55}', '\u{324}'), '\u{1E72}'),
+    (('\u{55}', '\u{328}'), '\u{172}'),
+    (('\u{55}', '\u{32D}'), '\u{1E76}'),
+    (('\u{55}', '\u{330}'), '\u{1E74}'),
+    (('\u{56}', '\u{303}'), '\u{1E7C}'),
+    (('\u{56}', '\u{323}'), '\u{1E7E}'),
+    (('\u{57}', '\u{300}'), '\u{1E80}'),
+    (('\u{57}', '\u{301}'), '\u{1E82}'),
+    (('\u{57}', '\u{302}'), '\u{174}'),
+    (('\u{57}', '\u{307}'), '\u{1E86}'),
+    (('\u{57}', '\u{308}'), '\u{1E84}'),
+    (('\u{57}', '\u{323}'), '\u{1E88}'),
+    (('\u{58}', '\u{307}'), '\u{1E8A}'),
+    (('\u{58}', '\u{308}'), '\u{1E8C}'),
+    (('\u{59}', '\u{300}'), '\u{1EF2}'),
+    (('\u{59}', '\u{301}'), '\u{DD}'),
+    (('\u{59}', '\u{302}'), '\u{176}'),
+    (('\u{59}', '\u{303}'), '\u{1EF8}'),
+    (('\u{59}', '\u{304}'), '\u{232}'),
+    (('\u{59}', '\u{307}'), '\u{1E8E}'),
+    (('\u{59}', '\u{308}'), '\u{178}'),
+    (('\u{59}', '\u{309}'), '\u{1EF6}'),
+    (('\u{59}', '\u{323}'), '\u{1EF4}'),
+    (('\u{5A}', '\u{301}'), '\u{179}'),
+    (('\u{5A}', '\u{302}'), '\u{1E90}'),
+    (('\u{5A}', '\u{307}'), '\u{17B}'),
+    (('\u{5A}', '\u{30C}'), '\u{17D}'),
+    (('\u{5A}', '\u{323}'), '\u{1E92}'),
+    (('\u{5A}', '\u{331}'), '\u{1E94}'),
+    (('\u{61}', '\u{300}'), '\u{E0}'),
+    (('\u{61}', '\u{301}'), '\u{E1}'),
+    (('\u{61}', '\u{302}'), '\u{E2}'),
+    (('\u{61}', '\u{303}'), '\u{E3}'),
+    (('\u{61}', '\u{304}'), '\u{101}'),
+    (('\u{61}', '\u{306}'), '\u{103}'),
+    (('\u{61}', '\u{307}'), '\u{227}'),
+    (('\u{61}', '\u{308}'), '\u{E4}'),
+    (('\u{61}', '\u{309}'), '\u{1EA3}'),
+    (('\u{61}', '\u{30A}'), '\u{E5}'),
+    (('\u{61}', '\u{30C}'), '\u{1CE}'),
+    (('\u{61}', '\u{30F}'), '\u{201}'),
+    (('\u{61}', '\u{311}'), '\u{203}'),
+    (('\u{61}', '\u{323}'), '\u{1EA1}'),
+    (('\u{61}', '\u{325}'), '\u{1E01}'),
+    (('\u{61}', '\u{328}'), '\u{105}'),
+    (('\u{62}', '\u{307}'), '\u{1E03}'),
+    (('\u{62}', '\u{323}'), '\u{1E05}'),
+    (('\u{62}', '\u{331}'), '\u{1E07}'),
+    (('\u{63}', '\u{301}'), '\u{107}'),
+    (('\u{63}', '\u{302}'), '\u{109}'),
+    (('\u{63}', '\u{307}'), '\u{10B}'),
+    (('\u{63}', '\u{30C}'), '\u{10D}'),
+    (('\u{63}', '\u{327}'), '\u{E7}'),
+    (('\u{64}', '\u{307}'), '\u{1E0B}'),
+    (('\u{64}', '\u{30C}'), '\u{10F}'),
+    (('\u{64}', '\u{323}'), '\u{1E0D}'),
+    (('\u{64}', '\u{327}'), '\u{1E11}'),
+    (('\u{64}', '\u{32D}'), '\u{1E13}'),
+    (('\u{64}', '\u{331}'), '\u{1E0F}'),
+    (('\u{65}', '\u{300}'), '\u{E8}'),
+    (('\u{65}', '\u{301}'), '\u{E9}'),
+    (('\u{65}', '\u{302}'), '\u{EA}'),
+    (('\u{65}', '\u{303}'), '\u{1EBD}'),
+    (('\u{65}', '\u{304}'), '\u{113}'),
+    (('\u{65}', '\u{306}'), '\u{115}'),
+    (('\u{65}', '\u{307}'), '\u{117}'),
+    (('\u{65}', '\u{308}'), '\u{EB}'),
+    (('\u{65}', '\u{309}'), '\u{1EBB}'),
+    (('\u{65}', '\u{30C}'), '\u{11B}'),
+    (('\u{65}', '\u{30F}'), '\u{205}'),
+    (('\u{65}', '\u{311}'), '\u{207}'),
+    (('\u{65}', '\u{323}'), '\u{1EB9}'),
+    (('\u{65}', '\u{327}'), '\u{229}'),
+    (('\u{65}', '\u{328}'), '\u{119}'),
+    (('\u{65}', '\u{32D}'), '\u{1E19}'),
+    (('\u{65}', '\u{330}'), '\u{1E1B}'),
+    (('\u{66}', '\u{307}'), '\u{1E1F}'),
+    (('\u{67}', '\u{301}'), '\u{1F5}'),
+    (('\u{67}', '\u{302}'), '\u{11D}'),
+    (('\u{67}', '\u{304}'), '\u{1E21}'),
+    (('\u{67}', '\u{306}'), '\u{11F}'),
+    (('\u{67}', '\u{307}'), '\u{121}'),
+    (('\u{67}', '\u{30C}'), '\u{1E7}'),
+    (('\u{67}', '\u{327}'), '\u{123}'),
+    (('\u{68}', '\u{302}'), '\u{125}'),
+    (('\u{68}', '\u{307}'), '\u{1E23}'),
+    (('\u{68}', '\u{308}'), '\u{1E27}'),
+    (('\u{68}', '\u{30C}'), '\u{21F}'),
+    (('\u{68}', '\u{323}'), '\u{1E25}'),
+    (('\u{68}', '\u{327}'), '\u{1E29}'),
+    (('\u{68}', '\u{32E}'), '\u{1E2B}'),
+    (('\u{68}', '\u{331}'), '\u{1E96}'),
+    (('\u{69}', '\u{300}'), '\u{EC}'),
+    (('\u{69}', '\u{301}'), '\u{ED}'),
+    (('\u{69}', '\u{302}'), '\u{EE}'),
+    (('\u{69}', '\u{303}'), '\u{129}'),
+    (('\u{69}', '\u{304}'), '\u{12B}'),
+    (('\u{69}', '\u{306}'), '\u{12D}'),
+    (('\u{69}', '\u{308}'), '\u{EF}'),
+    (('\u{69}', '\u{309}'), '\u{1EC9}'),
+    (('\u{69}', '\u{30C}'), '\u{1D0}'),
+    (('\u{69}', '\u{30F}'), '\u{209}'),
+    (('\u{69}', '\u{311}'), '\u{20B}'),
+    (('\u{69}', '\u{323}'), '\u{1ECB}'),
+    (('\u{69}', '\u{328}'), '\u{12F}'),
+    (('\u{69}', '\u{330}'), '\u{1E2D}'),
+    (('\u{6A}', '\u{302}'), '\u{135}'),
+    (('\u{6A}', '\u{30C}'), '\u{1F0}'),
+    (('\u{6B}', '\u{301}'), '\u{1E31}'),
+    (('\u{6B}', '\u{30C}'), '\u{1E9}'),
+    (('\u{6B}', '\u{323}'), '\u{1E33}'),
+    (('\u{6B}', '\u{327}'), '\u{137}'),
+    (('\u{6B}', '\u{331}'), '\u{1E35}'),
+    (('\u{6C}', '\u{301}'), '\u{13A}'),
+    (('\u{6C}', '\u{30C}'), '\u{13E}'),
+    (('\u{6C}', '\u{323}'), '\u{1E37}'),
+    (('\u{6C}', '\u{327}'), '\u{13C}'),
+    (('\u{6C}', '\u{32D}'), '\u{1E3D}'),
+    (('\u{6C}', '\u{331}'), '\u{1E3B}'),
+    (('\u{6D}', '\u{301}'), '\u{1E3F}'),
+    (('\u{6D}', '\u{307}'), '\u{1E41}'),
+    (('\u{6D}', '\u{323}'), '\u{1E43}'),
+    (('\u{6E}', '\u{300}'), '\u{1F9}'),
+    (('\u{6E}', '\u{301}'), '\u{144}'),
+    (('\u{6E}', '\u{303}'), '\u{F1}'),
+    (('\u{6E}', '\u{307}'), '\u{1E45}'),
+    (('\u{6E}', '\u{30C}'), '\u{148}'),
+    (('\u{6E}', '\u{323}'), '\u{1E47}'),
+    (('\u{6E}', '\u{327}'), '\u{146}'),
+    (('\u{6E}', '\u{32D}'), '\u{1E4B}'),
+    (('\u{6E}', '\u{331}'), '\u{1E49}'),
+    (('\u{6F}', '\u{300}'), '\u{F2}'),
+    (('\u{6F}', '\u{301}'), '\u{F3}'),
+    (('\u{6F}', '\u{302}'), '\u{F4}'),
+    (('\u{6F}', '\u{303}'), '\u{F5}'),
+    (('\u{6F}', '\u{304}'), '\u{14D}'),
+    (('\u{6F}', '\u{306}'), '\u{14F}'),
+    (('\u{6F}', '\u{307}'), '\u{22F}'),
+    (('\u{6F}', '\u{308}'), '\u{F6}'),
+    (('\u{6F}', '\u{309}'), '\u{1ECF}'),
+    (('\u{6F}', '\u{30B}'), '\u{151}'),
+    (('\u{6F}', '\u{30C}'), '\u{1D2}'),
+    (('\u{6F}', '\u{30F}'), '\u{20D}'),
+    (('\u{6F}', '\u{311}'), '\u{20F}'),
+    (('\u{6F}', '\u{31B}'), '\u{1A1}'),
+    (('\u{6F}', '\u{323}'), '\u{1ECD}'),
+    (('\u{6F}', '\u{328}'), '\u{1EB}'),
+    (('\u{70}', '\u{301}'), '\u{1E55}'),
+    (('\u{70}', '\u{307}'), '\u{1E57}'),
+    (('\u{72}', '\u{301}'), '\u{155}'),
+    (('\u{72}', '\u{307}'), '\u{1E59}'),
+    (('\u{72}', '\u{30C}'), '\u{159}'),
+    (('\u{72}', '\u{30F}'), '\u{211}'),
+    (('\u{72}', '\u{311}'), '\u{213}'),
+    (('\u{72}', '\u{323}'), '\u{1E5B}'),
+    (('\u{72}', '\u{327}'), '\u{157}'),
+    (('\u{72}', '\u{331}'), '\u{1E5F}'),
+    (('\u{73}', '\u{301}'), '\u{15B}'),
+    (('\u{73}', '\u{302}'), '\u{15D}'),
+    (('\u{73}', '\u{307}'), '\u{1E61}'),
+    (('\u{73}', '\u{30C}'), '\u{161}'),
+    (('\u{73}', '\u{323}'), '\u{1E63}'),
+    (('\u{73}', '\u{326}'), '\u{219}'),
+    (('\u{73}', '\u{327}'), '\u{15F}'),
+    (('\u{74}', '\u{307}'), '\u{1E6B}'),
+    (('\u{74}', '\u{308}'), '\u{1E97}'),
+    (('\u{74}', '\u{30C}'), '\u{165}'),
+    (('\u{74}', '\u{323}'), '\u{1E6D}'),
+    (('\u{74}', '\u{326}'), '\u{21B}'),
+    (('\u{74}', '\u{327}'), '\u{163}'),
+    (('\u{74}', '\u{32D}'), '\u{1E71}'),
+    (('\u{74}', '\u{331}'), '\u{1E6F}'),
+    (('\u{75}', '\u{300}'), '\u{F9}'),
+    (('\u{75}', '\u{301}'), '\u{FA}'),
+    (('\u{75}', '\u{302}'), '\u{FB}'),
+    (('\u{75}', '\u{303}'), '\u{169}'),
+    (('\u{75}', '\u{304}'), '\u{16B}'),
+    (('\u{75}', '\u{306}'), '\u{16D}'),
+    (('\u{75}', '\u{308}'), '\u{FC}'),
+    (('\u{75}', '\u{309}'), '\u{1EE7}'),
+    (('\u{75}', '\u{30A}'), '\u{16F}'),
+    (('\u{75}', '\u{30B}'), '\u{171}'),
+    (('\u{75}', '\u{30C}'), '\u{1D4}'),
+    (('\u{75}', '\u{30F}'), '\u{215}'),
+    (('\u{75}', '\u{311}'), '\u{217}'),
+    (('\u{75}', '\u{31B}'), '\u{1B0}'),
+    (('\u{75}', '\u{323}'), '\u{1EE5}'),
+    (('\u{75}', '\u{324}'), '\u{1E73}'),
+    (('\u{75}', '\u{328}'), '\u{173}'),
+    (('\u{75}', '\u{32D}'), '\u{1E77}'),
+    (('\u{75}', '\u{330}'), '\u{1E75}'),
+    (('\u{76}', '\u{303}'), '\u{1E7D}'),
+    (('\u{76}', '\u{323}'), '\u{1E7F}'),
+    (('\u{77}', '\u{300}'), '\u{1E81}'),
+    (('\u{77}', '\u{301}'), '\u{1E83}'),
+    (('\u{77}', '\u{302}'), '\u{175}'),
+    (('\u{77}', '\u{307}'), '\u{1E87}'),
+    (('\u{77}', '\u{308}'), '\u{1E85}'),
+    (('\u{77}', '\u{30A}'), '\u{1E98}'),
+    (('\u{77}', '\u{323}'), '\u{1E89}'),
+    (('\u{78}', '\u{307}'), '\u{1E8B}'),
+    (('\u{78}', '\u{308}'), '\u{1E8D}'),
+    (('\u{79}', '\u{300}'), '\u{1EF3}'),
+    (('\u{79}', '\u{301}'), '\u{FD}'),
+    (('\u{79}', '\u{302}'), '\u{177}'),
+    (('\u{79}', '\u{303}'), '\u{1EF9}'),
+    (('\u{79}', '\u{304}'), '\u{233}'),
+    (('\u{79}', '\u{307}'), '\u{1E8F}'),
+    (('\u{79}', '\u{308}'), '\u{FF}'),
+    (('\u{79}', '\u{309}'), '\u{1EF7}'),
+    (('\u{79}', '\u{30A}'), '\u{1E99}'),
+    (('\u{79}', '\u{323}'), '\u{1EF5}'),
+    (('\u{7A}', '\u{301}'), '\u{17A}'),
+    (('\u{7A}', '\u{302}'), '\u{1E91}'),
+    (('\u{7A}', '\u{307}'), '\u{17C}'),
+    (('\u{7A}', '\u{30C}'), '\u{17E}'),
+    (('\u{7A}', '\u{323}'), '\u{1E93}'),
+    (('\u{7A}', '\u{331}'), '\u{1E95}'),
+    (('\u{A8}', '\u{300}'), '\u{1FED}'),
+    (('\u{A8}', '\u{301}'), '\u{385}'),
+    (('\u{A8}', '\u{342}'), '\u{1FC1}'),
+    (('\u{C2}', '\u{300}'), '\u{1EA6}'),
+    (('\u{C2}', '\u{301}'), '\u{1EA4}'),
+    (('\u{C2}', '\u{303}'), '\u{1EAA}'),
+    (('\u{C2}', '\u{309}'), '\u{1EA8}'),
+    (('\u{C4}', '\u{304}'), '\u{1DE}'),
+    (('\u{C5}', '\u{301}'), '\u{1FA}'),
+    (('\u{C6}', '\u{301}'), '\u{1FC}'),
+    (('\u{C6}', '\u{304}'), '\u{1E2}'),
+    (('\u{C7}', '\u{301}'), '\u{1E08}'),
+    (('\u{CA}', '\u{300}'), '\u{1EC0}'),
+    (('\u{CA}', '\u{301}'), '\u{1EBE}'),
+    (('\u{CA}', '\u{303}'), '\u{1EC4}'),
+    (('\u{CA}', '\u{309}'), '\u{1EC2}'),
+    (('\u{CF}', '\u{301}'), '\u{1E2E}'),
+    (('\u{D4}', '\u{300}'), '\u{1ED2}'),
+    (('\u{D4}', '\u{301}'), '\u{1ED0}'),
+    (('\u{D4}', '\u{303}'), '\u{1ED6}'),
+    (('\u{D4}', '\u{309}'), '\u{1ED4}'),
+    (('\u{D5}', '\u{301}'), '\u{1E4C}'),
+    (('\u{D5}', '\u{304}'), '\u{22C}'),
+    (('\u{D5}', '\u{308}'), '\u{1E4E}'),
+    (('\u{D6}', '\u{304}'), '\u{22A}'),
+    (('\u{D8}', '\u{301}'), '\u{1FE}'),
+    (('\u{DC}', '\u{300}'), '\u{1DB}'),
+    (('\u{DC}', '\u{301}'), '\u{1D7}'),
+    (('\u{DC}', '\u{304}'), '\u{1D5}'),
+    (('\u{DC}', '\u{30C}'), '\u{1D9}'),
+    (('\u{E2}', '\u{300}'), '\u{1EA7}'),
+    (('\u{E2}', '\u{301}'), '\u{1EA5}'),
+    (('\u{E2}', '\u{303}'), '\u{1EAB}'),
+    (('\u{E2}', '\u{309}'), '\u{1EA9}'),
+    (('\u{E4}', '\u{304}'), '\u{1DF}'),
+    (('\u{E5}', '\u{301}'), '\u{1FB}'),
+    (('\u{E6}', '\u{301}'), '\u{1FD}'),
+    (('\u{E6}', '\u{304}'), '\u{1E3}'),
+    (('\u{E7}', '\u{301}'), '\u{1E09}'),
+    (('\u{EA}', '\u{300}'), '\u{1EC1}'),
+    (('\u{EA}', '\u{301}'), '\u{1EBF}'),
+    (('\u{EA}', '\u{303}'), '\u{1EC5}'),
+    (('\u{EA}', '\u{309}'), '\u{1EC3}'),
+    (('\u{EF}', '\u{301}'), '\u{1E2F}'),
+    (('\u{F4}', '\u{300}'), '\u{1ED3}'),
+    (('\u{F4}', '\u{301}'), '\u{1ED1}'),
+    (('\u{F4}', '\u{303}'), '\u{1ED7}'),
+    (('\u{F4}', '\u{309}'), '\u{1ED5}'),
+    (('\u{F5}', '\u{301}'), '\u{1E4D}'),
+    (('\u{F5}', '\u{304}'), '\u{22D}'),
+    (('\u{F5}', '\u{308}'), '\u{1E4F}'),
+    (('\u{F6}', '\u{304}'), '\u{22B}'),
+    (('\u{F8}', '\u{301}'), '\u{1FF}'),
+    (('\u{FC}', '\u{300}'), '\u{1DC}'),
+    (('\u{FC}', '\u{301}'), '\u{1D8}'),
+    (('\u{FC}', '\u{304}'), '\u{1D6}'),
+    (('\u{FC}', '\u{30C}'), '\u{1DA}'),
+    (('\u{102}', '\u{300}'), '\u{1EB0}'),
+    (('\u{102}', '\u{301}'), '\u{1EAE}'),
+    (('\u{102}', '\u{303}'), '\u{1EB4}'),
+    (('\u{102}', '\u{309}'), '\u{1EB2}'),
+    (('\u{103}', '\u{300}'), '\u{1EB1}'),
+    (('\u{103}', '\u{301}'), '\u{1EAF}'),
+    (('\u{103}', '\u{303}'), '\u{1EB5}'),
+    (('\u{103}', '\u{309}'), '\u{1EB3}'),
+    (('\u{112}', '\u{300}'), '\u{1E14}'),
+    (('\u{112}', '\u{301}'), '\u{1E16}'),
+    (('\u{113}', '\u{300}'), '\u{1E15}'),
+    (('\u{113}', '\u{301}'), '\u{1E17}'),
+    (('\u{14C}', '\u{300}'), '\u{1E50}'),
+    (('\u{14C}', '\u{301}'), '\u{1E52}'),
+    (('\u{14D}', '\u{300}'), '\u{1E51}'),
+    (('\u{14D}', '\u{301}'), '\u{1E53}'),
+    (('\u{15A}', '\u{307}'), '\u{1E64}'),
+    (('\u{15B}', '\u{307}'), '\u{1E65}'),
+    (('\u{160}', '\u{307}'), '\u{1E66}'),
+    (('\u{161}', '\u{307}'), '\u{1E67}'),
+    (('\u{168}', '\u{301}'), '\u{1E78}'),
+    (('\u{169}', '\u{301}'), '\u{1E79}'),
+    (('\u{16A}', '\u{308}'), '\u{1E7A}'),
+    (('\u{16B}', '\u{308}'), '\u{1E7B}'),
+    (('\u{17F}', '\u{307}'), '\u{1E9B}'),
+    (('\u{1A0}', '\u{300}'), '\u{1EDC}'),
+    (('\u{1A0}', '\u{301}'), '\u{1EDA}'),
+    (('\u{1A0}', '\u{303}'), '\u{1EE0}'),
+    (('\u{1A0}', '\u{309}'), '\u{1EDE}'),
+    (('\u{1A0}', '\u{323}'), '\u{1EE2}'),
+    (('\u{1A1}', '\u{300}'), '\u{1EDD}'),
+    (('\u{1A1}', '\u{301}'), '\u{1EDB}'),
+    (('\u{1A1}', '\u{303}'), '\u{1EE1}'),
+    (('\u{1A1}', '\u{309}'), '\u{1EDF}'),
+    (('\u{1A1}', '\u{323}'), '\u{1EE3}'),
+    (('\u{1AF}', '\u{300}'), '\u{1EEA}'),
+    (('\u{1AF}', '\u{301}'), '\u{1EE8}'),
+    (('\u{1AF}', '\u{303}'), '\u{1EEE}'),
+    (('\u{1AF}', '\u{309}'), '\u{1EEC}'),
+    (('\u{1AF}', '\u{323}'), '\u{1EF0}'),
+    (('\u{1B0}', '\u{300}'), '\u{1EEB}'),
+    (('\u{1B0}', '\u{301}'), '\u{1EE9}'),
+    (('\u{1B0}', '\u{303}'), '\u{1EEF}'),
+    (('\u{1B0}', '\u{309}'), '\u{1EED}'),
+    (('\u{1B0}', '\u{323}'), '\u{1EF1}'),
+    (('\u{1B7}', '\u{30C}'), '\u{1EE}'),
+    (('\u{1EA}', '\u{304}'), '\u{1EC}'),
+    (('\u{1EB}', '\u{304}'), '\u{1ED}'),
+    (('\u{226}', '\u{304}'), '\u{1E0}'),
+    (('\u{227}', '\u{304}'), '\u{1E1}'),
+    (('\u{228}', '\u{306}'), '\u{1E1C}'),
+    (('\u{229}', '\u{306}'), '\u{1E1D}'),
+    (('\u{22E}', '\u{304}'), '\u{230}'),
+    (('\u{22F}', '\u{304}'), '\u{231}'),
+    (('\u{292}', '\u{30C}'), '\u{1EF}'),
+    (('\u{391}', '\u{300}'), '\u{1FBA}'),
+    (('\u{391}', '\u{301}'), '\u{386}'),
+    (('\u{391}', '\u{304}'), '\u{1FB9}'),
+    (('\u{391}', '\u{306}'), '\u{1FB8}'),
+    (('\u{391}', '\u{313}'), '\u{1F08}'),
+    (('\u{391}', '\u{314}'), '\u{1F09}'),
+    (('\u{391}', '\u{345}'), '\u{1FBC}'),
+    (('\u{395}', '\u{300}'), '\u{1FC8}'),
+    (('\u{395}', '\u{301}'), '\u{388}'),
+    (('\u{395}', '\u{313}'), '\u{1F18}'),
+    (('\u{395}', '\u{314}'), '\u{1F19}'),
+    (('\u{397}', '\u{300}'), '\u{1FCA}'),
+    (('\u{397}', '\u{301}'), '\u{389}'),
+    (('\u{397}', '\u{313}'), '\u{1F28}'),
+    (('\u{397}', '\u{314}'), '\u{1F29}'),
+    (('\u{397}', '\u{345}'), '\u{1FCC}'),
+    (('\u{399}', '\u{300}'), '\u{1FDA}'),
+    (('\u{399}', '\u{301}'), '\u{38A}'),
+    (('\u{399}', '\u{304}'), '\u{1FD9}'),
+    (('\u{399}', '\u{306}'), '\u{1FD8}'),
+    (('\u{399}', '\u{308}'), '\u{3AA}'),
+    (('\u{399}', '\u{313}'), '\u{1F38}'),
+    (('\u{399}', '\u{314}'), '\u{1F39}'),
+    (('\u{39F}', '\u{300}'), '\u{1FF8}'),
+    (('\u{39F}', '\u{301}'), '\u{38C}'),
+    (('\u{39F}', '\u{313}'), '\u{1F48}'),
+    (('\u{39F}', '\u{314}'), '\u{1F49}'),
+    (('\u{3A1}', '\u{314}'), '\u{1FEC}'),
+    (('\u{3A5}', '\u{300}'), '\u{1FEA}'),
+    (('\u{3A5}', '\u{301}'), '\u{38E}'),
+    (('\u{3A5}', '\u{304}'), '\u{1FE9}'),
+    (('\u{3A5}', '\u{306}'), '\u{1FE8}'),
+    (('\u{3A5}', '\u{308}'), '\u{3AB}'),
+    (('\u{3A5}', '\u{314}'), '\u{1F59}'),
+    (('\u{3A9}', '\u{300}'), '\u{1FFA}'),
+    (('\u{3A9}', '\u{301}'), '\u{38F}'),
+    (('\u{3A9}', '\u{313}'), '\u{1F68}'),
+    (('\u{3A9}', '\u{314}'), '\u{1F69}'),
+    (('\u{3A9}', '\u{345}'), '\u{1FFC}'),
+    (('\u{3AC}', '\u{345}'), '\u{1FB4}'),
+    (('\u{3AE}', '\u{345}'), '\u{1FC4}'),
+    (('\u{3B1}', '\u{300}'), '\u{1F70}'),
+    (('\u{3B1}', '\u{301}'), '\u{3AC}'),
+    (('\u{3B1}', '\u{304}'), '\u{1FB1}'),
+    (('\u{3B1}', '\u{306}'), '\u{1FB0}'),
+    (('\u{3B1}', '\u{313}'), '\u{1F00}'),
+    (('\u{3B1}', '\u{314}'), '\u{1F01}'),
+    (('\u{3B1}', '\u{342}'), '\u{1FB6}'),
+    (('\u{3B1}', '\u{345}'), '\u{1FB3}'),
+    (('\u{3B5}', '\u{300}'), '\u{1F72}'),
+    (('\u{3B5}', '\u{301}'), '\u{3AD}'),
+    (('\u{3B5}', '\u{313}'), '\u{1F10}'),
+    (('\u{3B5}', '\u{314}'), '\u{1F11}'),
+    (('\u{3B7}', '\u{300}'), '\u{1F74}'),
+    (('\u{3B7}', '\u{301}'), '\u{3AE}'),
+    (('\u{3B7}', '\u{313}'), '\u{1F20}'),
+    (('\u{3B7}', '\u{314}'), '\u{1F21}'),
+    (('\u{3B7}', '\u{342}'), '\u{1FC6}'),
+    (('\u{3B7}', '\u{345}'), '\u{1FC3}'),
+    (('\u{3B9}', '\u{300}'), '\u{1F76}'),
+    (('\u{3B9}', '\u{301}'), '\u{3AF}'),
+    (('\u{3B9}', '\u{304}'), '\u{1FD1}'),
+    (('\u{3B9}', '\u{306}'), '\u{1FD0}'),
+    (('\u{3B9}', '\u{308}'), '\u{3CA}'),
+    (('\u{3B9}', '\u{313}'), '\u{1F30}'),
+    (('\u{3B9}', '\u{314}'), '\u{1F31}'),
+    (('\u{3B9}', '\u{342}'), '\u{1FD6}'),
+    (('\u{3BF}', '\u{300}'), '\u{1F78}'),
+    (('\u{3BF}', '\u{301}'), '\u{3CC}'),
+    (('\u{3BF}', '\u{313}'), '\u{1F40}'),
+    (('\u{3BF}', '\u{314}'), '\u{1F41}'),
+    (('\u{3C1}', '\u{313}'), '\u{1FE4}'),
+    (('\u{3C1}', '\u{314}'), '\u{1FE5}'),
+    (('\u{3C5}', '\u{300}'), '\u{1F7A}'),
+    (('\u{3C5}', '\u{301}'), '\u{3CD}'),
+    (('\u{3C5}', '\u{304}'), '\u{1FE1}'),
+    (('\u{3C5}', '\u{306}'), '\u{1FE0}'),
+    (('\u{3C5}', '\u{308}'), '\u{3CB}'),
+    (('\u{3C5}', '\u{313}'), '\u{1F50}'),
+    (('\u{3C5}', '\u{314}'), '\u{1F51}'),
+    (('\u{3C5}', '\u{342}'), '\u{1FE6}'),
+    (('\u{3C9}', '\u{300}'), '\u{1F7C}'),
+    (('\u{3C9}', '\u{301}'), '\u{3CE}'),
+    (('\u{3C9}', '\u{313}'), '\u{1F60}'),
+    (('\u{3C9}', '\u{314}'), '\u{1F61}'),
+    (('\u{3C9}', '\u{342}'), '\u{1FF6}'),
+    (('\u{3C9}', '\u{345}'), '\u{1FF3}'),
+    (('\u{3CA}', '\u{300}'), '\u{1FD2}'),
+    (('\u{3CA}', '\u{301}'), '\u{390}'),
+    (('\u{3CA}', '\u{342}'), '\u{1FD7}'),
+    (('\u{3CB}', '\u{300}'), '\u{1FE2}'),
+    (('\u{3CB}', '\u{301}'), '\u{3B0}'),
+    (('\u{3CB}', '\u{342}'), '\u{1FE7}'),
+    (('\u{3CE}', '\u{345}'), '\u{1FF4}'),
+    (('\u{3D2}', '\u{301}'), '\u{3D3}'),
+    (('\u{3D2}', '\u{308}'), '\u{3D4}'),
+    (('\u{406}', '\u{308}'), '\u{407}'),
+    (('\u{410}', '\u{306}'), '\u{4D0}'),
+    (('\u{410}', '\u{308}'), '\u{4D2}'),
+    (('\u{413}', '\u{301}'), '\u{403}'),
+    (('\u{415}', '\u{300}'), '\u{400}'),
+    (('\u{415}', '\u{306}'), '\u{4D6}'),
+    (('\u{415}', '\u{308}'), '\u{401}'),
+    (('\u{416}', '\u{306}'), '\u{4C1}'),
+    (('\u{416}', '\u{308}'), '\u{4DC}'),
+    (('\u{417}', '\u{308}'), '\u{4DE}'),
+    (('\u{418}', '\u{300}'), '\u{40D}'),
+    (('\u{418}', '\u{304}'), '\u{4E2}'),
+    (('\u{418}', '\u{306}'), '\u{419}'),
+    (('\u{418}', '\u{308}'), '\u{4E4}'),
+    (('\u{41A}', '\u{301}'), '\u{40C}'),
+    (('\u{41E}', '\u{308}'), '\u{4E6}'),
+    (('\u{423}', '\u{304}'), '\u{4EE}'),
+    (('\u{423}', '\u{306}'), '\u{40E}'),
+    (('\u{423}', '\u{308}'), '\u{4F0}'),
+    (('\u{423}', '\u{30B}'), '\u{4F2}'),
+    (('\u{427}', '\u{308}'), '\u{4F4}'),
+    (('\u{42B}', '\u{308}'), '\u{4F8}'),
+    (('\u{42D}', '\u{308}'), '\u{4EC}'),
+    (('\u{430}', '\u{306}'), '\u{4D1}'),
+    (('\u{430}', '\u{308}'), '\u{4D3}'),
+    (('\u{433}', '\u{301}'), '\u{453}'),
+    (('\u{435}', '\u{300}'), '\u{450}'),
+    (('\u{435}', '\u{306}'), '\u{4D7}'),
+    (('\u{435}', '\u{308}'), '\u{451}'),
+    (('\u{436}', '\u{306}'), '\u{4C2}'),
+    (('\u{436}', '\u{308}'), '\u{4DD}'),
+    (('\u{437}', '\u{308}'), '\u{4DF}'),
+    (('\u{438}', '\u{300}'), '\u{45D}'),
+    (('\u{438}', '\u{304}'), '\u{4E3}'),
+    (('\u{438}', '\u{306}'), '\u{439}'),
+    (('\u{438}', '\u{308}'), '\u{4E5}'),
+    (('\u{43A}', '\u{301}'), '\u{45C}'),
+    (('\u{43E}', '\u{308}'), '\u{4E7}'),
+    (('\u{443}', '\u{304}'), '\u{4EF}'),
+    (('\u{443}', '\u{306}'), '\u{45E}'),
+    (('\u{443}', '\u{308}'), '\u{4F1}'),
+    (('\u{443}', '\u{30B}'), '\u{4F3}'),
+    (('\u{447}', '\u{308}'), '\u{4F5}'),
+    (('\u{44B}', '\u{308}'), '\u{4F9}'),
+    (('\u{44D}', '\u{308}'), '\u{4ED}'),
+    (('\u{456}', '\u{308}'), '\u{457}'),
+    (('\u{474}', '\u{30F}'), '\u{476}'),
+    (('\u{475}', '\u{30F}'), '\u{477}'),
+    (('\u{4D8}', '\u{308}'), '\u{4DA}'),
+    (('\u{4D9}', '\u{308}'), '\u{4DB}'),
+    (('\u{4E8}', '\u{308}'), '\u{4EA}'),
+    (('\u{4E9}', '\u{308}'), '\u{4EB}'),
+    (('\u{627}', '\u{653}'), '\u{622}'),
+    (('\u{627}', '\u{654}'), '\u{623}'),
+    (('\u{627}', '\u{655}'), '\u{625}'),
+    (('\u{648}', '\u{654}'), '\u{624}'),
+    (('\u{64A}', '\u{654}'), '\u{626}'),
+    (('\u{6C1}', '\u{654}'), '\u{6C2}'),
+    (('\u{6D2}', '\u{654}'), '\u{6D3}'),
+    (('\u{6D5}', '\u{654}'), '\u{6C0}'),
+    (('\u{928}', '\u{93C}'), '\u{929}'),
+    (('\u{930}', '\u{93C}'), '\u{931}'),
+    (('\u{933}', '\u{93C}'), '\u{934}'),
+    (('\u{9C7}', '\u{9BE}'), '\u{9CB}'),
+    (('\u{9C7}', '\u{9D7}'), '\u{9CC}'),
+    (('\u{B47}', '\u{B3E}'), '\u{B4B}'),
+    (('\u{B47}', '\u{B56}'), '\u{B48}'),
+    (('\u{B47}', '\u{B57}'), '\u{B4C}'),
+    (('\u{B92}', '\u{BD7}'), '\u{B94}'),
+    (('\u{BC6}', '\u{BBE}'), '\u{BCA}'),
+    (('\u{BC6}', '\u{BD7}'), '\u{BCC}'),
+    (('\u{BC7}', '\u{BBE}'), '\u{BCB}'),
+    (('\u{C46}', '\u{C56}'), '\u{C48}'),
+    (('\u{CBF}', '\u{CD5}'), '\u{CC0}'),
+    (('\u{CC6}', '\u{CC2}'), '\u{CCA}'),
+    (('\u{CC6}', '\u{CD5}'), '\u{CC7}'),
+    (('\u{CC6}', '\u{CD6}'), '\u{CC8}'),
+    (('\u{CCA}', '\u{CD5}'), '\u{CCB}'),
+    (('\u{D46}', '\u{D3E}'), '\u{D4A}'),
+    (('\u{D46}', '\u{D57}'), '\u{D4C}'),
+    (('\u{D47}', '\u{D3E}'), '\u{D4B}'),
+    (('\u{DD9}', '\u{DCA}'), '\u{DDA}'),
+    (('\u{DD9}', '\u{DCF}'), '\u{DDC}'),
+    (('\u{DD9}', '\u{DDF}'), '\u{DDE}'),
+    (('\u{DDC}', '\u{DCA}'), '\u{DDD}'),
+    (('\u{1025}', '\u{102E}'), '\u{1026}'),
+    (('\u{1B05}', '\u{1B35}'), '\u{1B06}'),
+    (('\u{1B07}', '\u{1B35}'), '\u{1B08}'),
+    (('\u{1B09}', '\u{1B35}'), '\u{1B0A}'),
+    (('\u{1B0B}', '\u{1B35}'), '\u{1B0C}'),
+    (('\u{1B0D}', '\u{1B35}'), '\u{1B0E}'),
+    (('\u{1B11}', '\u{1B35}'), '\u{1B12}'),
+    (('\u{1B3A}', '\u{1B35}'), '\u{1B3B}'),
+    (('\u{1B3C}', '\u{1B35}'), '\u{1B3D}'),
+    (('\u{1B3E}', '\u{1B35}'), '\u{1B40}'),
+    (('\u{1B3F}', '\u{1B35}'), '\u{1B41}'),
+    (('\u{1B42}', '\u{1B35}'), '\u{1B43}'),
+    (('\u{1E36}', '\u{304}'), '\u{1E38}'),
+    (('\u{1E37}', '\u{304}'), '\u{1E39}'),
+    (('\u{1E5A}', '\u{304}'), '\u{1E5C}'),
+    (('\u{1E5B}', '\u{304}'), '\u{1E5D}'),
+    (('\u{1E62}', '\u{307}'), '\u{1E68}'),
+    (('\u{1E63}', '\u{307}'), '\u{1E69}'),
+    (('\u{1EA0}', '\u{302}'), '\u{1EAC}'),
+    (('\u{1EA0}', '\u{306}'), '\u{1EB6}'),
+    (('\u{1EA1}', '\u{302}'), '\u{1EAD}'),
+    (('\u{1EA1}', '\u{306}'), '\u{1EB7}'),
+    (('\u{1EB8}', '\u{302}'), '\u{1EC6}'),
+    (('\u{1EB9}', '\u{302}'), '\u{1EC7}'),
+    (('\u{1ECC}', '\u{302}'), '\u{1ED8}'),
+    (('\u{1ECD}', '\u{302}'), '\u{1ED9}'),
+    (('\u{1F00}', '\u{300}'), '\u{1F02}'),
+    (('\u{1F00}', '\u{301}'), '\u{1F04}'),
+    (('\u{1F00}', '\u{342}'), '\u{1F06}'),
+    (('\u{1F00}', '\u{345}'), '\u{1F80}'),
+    (('\u{1F01}', '\u{300}'), '\u{1F03}'),
+    (('\u{1F01}', '\u{301}'), '\u{1F05}'),
+    (('\u{1F01}', '\u{342}'), '\u{1F07}'),
+    (('\u{1F01}', '\u{345}'), '\u{1F81}'),
+    (('\u{1F02}', '\u{345}'), '\u{1F82}'),
+    (('\u{1F03}', '\u{345}'), '\u{1F83}'),
+    (('\u{1F04}', '\u{345}'), '\u{1F84}'),
+    (('\u{1F05}', '\u{345}'), '\u{1F85}'),
+    (('\u{1F06}', '\u{345}'), '\u{1F86}'),
+    (('\u{1F07}', '\u{345}'), '\u{1F87}'),
+    (('\u{1F08}', '\u{300}'), '\u{1F0A}'),
+    (('\u{1F08}', '\u{301}'), '\u{1F0C}'),
+    (('\u{1F08}', '\u{342}'), '\u{1F0E}'),
+    (('\u{1F08}', '\u{345}'), '\u{1F88}'),
+    (('\u{1F09}', '\u{300}'), '\u{1F0B}'),
+    (('\u{1F09}', '\u{301}'), '\u{1F0D}'),
+    (('\u{1F09}', '\u{342}'), '\u{1F0F}'),
+    (('\u{1F09}', '\u{345}'), '\u{1F89}'),
+    (('\u{1F0A}', '\u{345}'), '\u{1F8A}'),
+    (('\u{1F0B}', '\u{345}'), '\u{1F8B}'),
+    (('\u{1F0C}', '\u{345}'), '\u{1F8C}'),
+    (('\u{1F0D}', '\u{345}'), '\u{1F8D}'),
+    (('\u{1F0E}', '\u{345}'), '\u{1F8E}'),
+    (('\u{1F0F}', '\u{345}'), '\u{1F8F}'),
+    (('\u{1F10}', '\u{300}'), '\u{1F12}'),
+    (('\u{1F10}', '\u{301}'), '\u{1F14}'),
+    (('\u{1F11}', '\u{300}'), '\u{1F13}'),
+    (('\u{1F11}', '\u{301}'), '\u{1F15}'),
+    (('\u{1F18}', '\u{300}'), '\u{1F1A}'),
+    (('\u{1F18}', '\u{301}'), '\u{1F1C}'),
+    (('\u{1F19}', '\u{300}'), '\u{1F1B}'),
+    (('\u{1F19}', '\u{301}'), '\u{1F1D}'),
+    (('\u{1F20}', '\u{300}'), '\u{1F22}'),
+    (('\u{1F20}', '\u{301}'), '\u{1F24}'),
+    (('\u{1F20}', '\u{342}'), '\u{1F26}'),
+    (('\u{1F20}', '\u{345}'), '\u{1F90}'),
+    (('\u{1F21}', '\u{300}'), '\u{1F23}'),
+    (('\u{1F21}', '\u{301}'), '\u{1F25}'),
+    (('\u{1F21}', '\u{342}'), '\u{1F27}'),
+    (('\u{1F21}', '\u{345}'), '\u{1F91}'),
+    (('\u{1F22}', '\u{345}'), '\u{1F92}'),
+    (('\u{1F23}', '\u{345}'), '\u{1F93}'),
+    (('\u{1F24}', '\u{345}'), '\u{1F94}'),
+    (('\u{1F25}', '\u{345}'), '\u{1F95}'),
+    (('\u{1F26}', '\u{345}'), '\u{1F96}'),
+    (('\u{1F27}', '\u{345}'), '\u{1F97}'),
+    (('\u{1F28}', '\u{300}'), '\u{1F2A}'),
+    (('\u{1F28}', '\u{301}'), '\u{1F2C}'),
+    (('\u{1F28}', '\u{342}'), '\u{1F2E}'),
+    (('\u{1F28}', '\u{345}'), '\u{1F98}'),
+    (('\u{1F29}', '\u{300}'), '\u{1F2B}'),
+    (('\u{1F29}', '\u{301}'), '\u{1F2D}'),
+    (('\u{1F29}', '\u{342}'), '\u{1F2F}'),
+    (('\u{1F29}', '\u{345}'), '\u{1F99}'),
+    (('\u{1F2A}', '\u{345}'), '\u{1F9A}'),
+    (('\u{1F2B}', '\u{345}'), '\u{1F9B}'),
+    (('\u{1F2C}', '\u{345}'), '\u{1F9C}'),
+    (('\u{1F2D}', '\u{345}'), '\u{1F9D}'),
+    (('\u{1F2E}', '\u{345}'), '\u{1F9E}'),
+    (('\u{1F2F}', '\u{345}'), '\u{1F9F}'),
+    (('\u{1F30}', '\u{300}'), '\u{1F32}'),
+    (('\u{1F30}', '\u{301}'), '\u{1F34}'),
+    (('\u{1F30}', '\u{342}'), '\u{1F36}'),
+    (('\u{1F31}', '\u{300}'), '\u{1F33}'),
+    (('\u{1F31}', '\u{301}'), '\u{1F35}'),
+    (('\u{1F31}', '\u{342}'), '\u{1F37}'),
+    (('\u{1F38}', '\u{300}'), '\u{1F3A}'),
+    (('\u{1F38}', '\u{301}'), '\u{1F3C}'),
+    (('\u{1F38}', '\u{342}'), '\u{1F3E}'),
+    (('\u{1F39}', '\u{300}'), '\u{1F3B}'),
+    (('\u{1F39}', '\u{301}'), '\u{1F3D}'),
+    (('\u{1F39}', '\u{342}'), '\u{1F3F}'),
+    (('\u{1F40}', '\u{300}'), '\u{1F42}'),
+    (('\u{1F40}', '\u{301}'), '\u{1F44}'),
+    (('\u{1F41}', '\u{300}'), '\u{1F43}'),
+    (('\u{1F41}', '\u{301}'), '\u{1F45}'),
+    (('\u{1F48}', '\u{300}'), '\u{1F4A}'),
+    (('\u{1F48}', '\u{301}'), '\u{1F4C}'),
+    (('\u{1F49}', '\u{300}'), '\u{1F4B}'),
+    (('\u{1F49}', '\u{301}'), '\u{1F4D}'),
+    (('\u{1F50}', '\u{300}'), '\u{1F52}'),
+    (('\u{1F50}', '\u{301}'), '\u{1F54}'),
+    (('\u{1F50}', '\u{342}'), '\u{1F56}'),
+    (('\u{1F51}', '\u{300}'), '\u{1F53}'),
+    (('\u{1F51}', '\u{301}'), '\u{1F55}'),
+    (('\u{1F51}', '\u{342}'), '\u{1F57}'),
+    (('\u{1F59}', '\u{300}'), '\u{1F5B}'),
+    (('\u{1F59}', '\u{301}'), '\u{1F5D}'),
+    (('\u{1F59}', '\u{342}'), '\u{1F5F}'),
+    (('\u{1F60}', '\u{300}'), '\u{1F62}'),
+    (('\u{1F60}', '\u{301}'), '\u{1F64}'),
+    (('\u{1F60}', '\u{342}'), '\u{1F66}'),
+    (('\u{1F60}', '\u{345}'), '\u{1FA0}'),
+    (('\u{1F61}', '\u{300}'), '\u{1F63}'),
+    (('\u{1F61}', '\u{301}'), '\u{1F65}'),
+    (('\u{1F61}', '\u{342}'), '\u{1F67}'),
+    (('\u{1F61}', '\u{345}'), '\u{1FA1}'),
+    (('\u{1F62}', '\u{345}'), '\u{1FA2}'),
+    (('\u{1F63}', '\u{345}'), '\u{1FA3}'),
+    (('\u{1F64}', '\u{345}'), '\u{1FA4}'),
+    (('\u{1F65}', '\u{345}'), '\u{1FA5}'),
+    (('\u{1F66}', '\u{345}'), '\u{1FA6}'),
+    (('\u{1F67}', '\u{345}'), '\u{1FA7}'),
+    (('\u{1F68}', '\u{300}'), '\u{1F6A}'),
+    (('\u{1F68}', '\u{301}'), '\u{1F6C}'),
+    (('\u{1F68}', '\u{342}'), '\u{1F6E}'),
+    (('\u{1F68}', '\u{345}'), '\u{1FA8}'),
+    (('\u{1F69}', '\u{300}'), '\u{1F6B}'),
+    (('\u{1F69}', '\u{301}'), '\u{1F6D}'),
+    (('\u{1F69}', '\u{342}'), '\u{1F6F}'),
+    (('\u{1F69}', '\u{345}'), '\u{1FA9}'),
+    (('\u{1F6A}', '\u{345}'), '\u{1FAA}'),
+    (('\u{1F6B}', '\u{345}'), '\u{1FAB}'),
+    (('\u{1F6C}', '\u{345}'), '\u{1FAC}'),
+    (('\u{1F6D}', '\u{345}'), '\u{1FAD}'),
+    (('\u{1F6E}', '\u{345}'), '\u{1FAE}'),
+    (('\u{1F6F}', '\u{345}'), '\u{1FAF}'),
+    (('\u{1F70}', '\u{345}'), '\u{1FB2}'),
+    (('\u{1F74}', '\u{345}'), '\u{1FC2}'),
+    (('\u{1F7C}', '\u{345}'), '\u{1FF2}'),
+    (('\u{1FB6}', '\u{345}'), '\u{1FB7}'),
+    (('\u{1FBF}', '\u{300}'), '\u{1FCD}'),
+    (('\u{1FBF}', '\u{301}'), '\u{1FCE}'),
+    (('\u{1FBF}', '\u{342}'), '\u{1FCF}'),
+    (('\u{1FC6}', '\u{345}'), '\u{1FC7}'),
+    (('\u{1FF6}', '\u{345}'), '\u{1FF7}'),
+    (('\u{1FFE}', '\u{300}'), '\u{1FDD}'),
+    (('\u{1FFE}', '\u{301}'), '\u{1FDE}'),
+    (('\u{1FFE}', '\u{342}'), '\u{1FDF}'),
+    (('\u{2190}', '\u{338}'), '\u{219A}'),
+    (('\u{2192}', '\u{338}'), '\u{219B}'),
+    (('\u{2194}', '\u{338}'), '\u{21AE}'),
+    (('\u{21D0}', '\u{338}'), '\u{21CD}'),
+    (('\u{21D2}', '\u{338}'), '\u{21CF}'),
+    (('\u{21D4}', '\u{338}'), '\u{21CE}'),
+    (('\u{2203}', '\u{338}'), '\u{2204}'),
+    (('\u{2208}', '\u{338}'), '\u{2209}'),
+    (('\u{220B}', '\u{338}'), '\u{220C}'),
+    (('\u{2223}', '\u{338}'), '\u{2224}'),
+    (('\u{2225}', '\u{338}'), '\u{2226}'),
+    (('\u{223C}', '\u{338}'), '\u{2241}'),
+    (('\u{2243}', '\u{338}'), '\u{2244}'),
+    (('\u{2245}', '\u{338}'), '\u{2247}'),
+    (('\u{2248}', '\u{338}'), '\u{2249}'),
+    (('\u{224D}', '\u{338}'), '\u{226D}'),
+    (('\u{2261}', '\u{338}'), '\u{2262}'),
+    (('\u{2264}', '\u{338}'), '\u{2270}'),
+    (('\u{2265}', '\u{338}'), '\u{2271}'),
+    (('\u{2272}', '\u{338}'), '\u{2274}'),
+    (('\u{2273}', '\u{338}'), '\u{2275}'),
+    (('\u{2276}', '\u{338}'), '\u{2278}'),
+    (('\u{2277}', '\u{338}'), '\u{2279}'),
+    (('\u{227A}', '\u{338}'), '\u{2280}'),
+    (('\u{227B}', '\u{338}'), '\u{2281}'),
+    (('\u{227C}', '\u{338}'), '\u{22E0}'),
+    (('\u{227D}', '\u{338}'), '\u{22E1}'),
+    (('\u{2282}', '\u{338}'), '\u{2284}'),
+    (('\u{2283}', '\u{338}'), '\u{2285}'),
+    (('\u{2286}', '\u{338}'), '\u{2288}'),
+    (('\u{2287}', '\u{338}'), '\u{2289}'),
+    (('\u{2291}', '\u{338}'), '\u{22E2}'),
+    (('\u{2292}', '\u{338}'), '\u{22E3}'),
+    (('\u{22A2}', '\u{338}'), '\u{22AC}'),
+    (('\u{22A8}', '\u{338}'), '\u{22AD}'),
+    (('\u{22A9}', '\u{338}'), '\u{22AE}'),
+    (('\u{22AB}', '\u{338}'), '\u{22AF}'),
+    (('\u{22B2}', '\u{338}'), '\u{22EA}'),
+    (('\u{22B3}', '\u{338}'), '\u{22EB}'),
+    (('\u{22B4}', '\u{338}'), '\u{22EC}'),
+    (('\u{22B5}', '\u{338}'), '\u{22ED}'),
+    (('\u{3046}', '\u{3099}'), '\u{3094}'),
+    (('\u{304B}', '\u{3099}'), '\u{304C}'),
+    (('\u{304D}', '\u{3099}'), '\u{304E}'),
+    (('\u{304F}', '\u{3099}'), '\u{3050}'),
+    (('\u{3051}', '\u{3099}'), '\u{3052}'),
+    (('\u{3053}', '\u{3099}'), '\u{3054}'),
+    (('\u{3055}', '\u{3099}'), '\u{3056}'),
+    (('\u{3057}', '\u{3099}'), '\u{3058}'),
+    (('\u{3059}', '\u{3099}'), '\u{305A}'),
+    (('\u{305B}', '\u{3099}'), '\u{305C}'),
+    (('\u{305D}', '\u{3099}'), '\u{305E}'),
+    (('\u{305F}', '\u{3099}'), '\u{3060}'),
+    (('\u{3061}', '\u{3099}'), '\u{3062}'),
+    (('\u{3064}', '\u{3099}'), '\u{3065}'),
+    (('\u{3066}', '\u{3099}'), '\u{3067}'),
+    (('\u{3068}', '\u{3099}'), '\u{3069}'),
+    (('\u{306F}', '\u{3099}'), '\u{3070}'),
+    (('\u{306F}', '\u{309A}'), '\u{3071}'),
+    (('\u{3072}', '\u{3099}'), '\u{3073}'),
+    (('\u{3072}', '\u{309A}'), '\u{3074}'),
+    (('\u{3075}', '\u{3099}'), '\u{3076}'),
+    (('\u{3075}', '\u{309A}'), '\u{3077}'),
+    (('\u{3078}', '\u{3099}'), '\u{3079}'),
+    (('\u{3078}', '\u{309A}'), '\u{307A}'),
+    (('\u{307B}', '\u{3099}'), '\u{307C}'),
+    (('\u{307B}', '\u{309A}'), '\u{307D}'),
+    (('\u{309D}', '\u{3099}'), '\u{309E}'),
+    (('\u{30A6}', '\u{3099}'), '\u{30F4}'),
+    (('\u{30AB}', '\u{3099}'), '\u{30AC}'),
+    (('\u{30AD}', '\u{3099}'), '\u{30AE}'),
+    (('\u{30AF}', '\u{3099}'), '\u{30B0}'),
+    (('\u{30B1}', '\u{3099}'), '\u{30B2}'),
+    (('\u{30B3}', '\u{3099}'), '\u{30B4}'),
+    (('\u{30B5}', '\u{3099}'), '\u{30B6}'),
+    (('\u{30B7}', '\u{3099}'), '\u{30B8}'),
+    (('\u{30B9}', '\u{3099}'), '\u{30BA}'),
+    (('\u{30BB}', '\u{3099}'), '\u{30BC}'),
+    (('\u{30BD}', '\u{3099}'), '\u{30BE}'),
+    (('\u{30BF}', '\u{3099}'), '\u{30C0}'),
+    (('\u{30C1}', '\u{3099}'), '\u{30C2}'),
+    (('\u{30C4}', '\u{3099}'), '\u{30C5}'),
+    (('\u{30C6}', '\u{3099}'), '\u{30C7}'),
+    (('\u{30C8}', '\u{3099}'), '\u{30C9}'),
+    (('\u{30CF}', '\u{3099}'), '\u{30D0}'),
+    (('\u{30CF}', '\u{309A}'), '\u{30D1}'),
+    (('\u{30D2}', '\u{3099}'), '\u{30D3}'),
+    (('\u{30D2}', '\u{309A}'), '\u{30D4}'),
+    (('\u{30D5}', '\u{3099}'), '\u{30D6}'),
+    (('\u{30D5}', '\u{309A}'), '\u{30D7}'),
+    (('\u{30D8}', '\u{3099}'), '\u{30D9}'),
+    (('\u{30D8}', '\u{309A}'), '\u{30DA}'),
+    (('\u{30DB}', '\u{3099}'), '\u{30DC}'),
+    (('\u{30DB}', '\u{309A}'), '\u{30DD}'),
+    (('\u{30EF}', '\u{3099}'), '\u{30F7}'),
+    (('\u{30F0}', '\u{3099}'), '\u{30F8}'),
+    (('\u{30F1}', '\u{3099}'), '\u{30F9}'),
+    (('\u{30F2}', '\u{3099}'), '\u{30FA}'),
+    (('\u{30FD}', '\u{3099}'), '\u{30FE}'),
+    (('\u{105D2}', '\u{307}'), '\u{105C9}'),
+    (('\u{105DA}', '\u{307}'), '\u{105E4}'),
+    (('\u{11099}', '\u{110BA}'), '\u{1109A}'),
+    (('\u{1109B}', '\u{110BA}'), '\u{1109C}'),
+    (('\u{110A5}', '\u{110BA}'), '\u{110AB}'),
+    (('\u{11131}', '\u{11127}'), '\u{1112E}'),
+    (('\u{11132}', '\u{11127}'), '\u{1112F}'),
+    (('\u{11347}', '\u{1133E}'), '\u{1134B}'),
+    (('\u{11347}', '\u{11357}'), '\u{1134C}'),
+    (('\u{11382}', '\u{113C9}'), '\u{11383}'),
+    (('\u{11384}', '\u{113BB}'), '\u{11385}'),
+    (('\u{1138B}', '\u{113C2}'), '\u{1138E}'),
+    (('\u{11390}', '\u{113C9}'), '\u{11391}'),
+    (('\u{113C2}', '\u{113B8}'), '\u{113C7}'),
+    (('\u{113C2}', '\u{113C2}'), '\u{113C5}'),
+    (('\u{113C2}', '\u{113C9}'), '\u{113C8}'),
+    (('\u{114B9}', '\u{114B0}'), '\u{114BC}'),
+    (('\u{114B9}', '\u{114BA}'), '\u{114BB}'),
+    (('\u{114B9}', '\u{114BD}'), '\u{114BE}'),
+    (('\u{115B8}', '\u{115AF}'), '\u{115BA}'),
+    (('\u{115B9}', '\u{115AF}'), '\u{115BB}'),
+    (('\u{11935}', '\u{11930}'), '\u{11938}'),
+    (('\u{1611E}', '\u{1611E}'), '\u{16121}'),
+    (('\u{1611E}', '\u{1611F}'), '\u{16123}'),
+    (('\u{1611E}', '\u{16120}'), '\u{16125}'),
+    (('\u{1611E}', '\u{16129}'), '\u{16122}'),
+    (('\u{16121}', '\u{1611F}'), '\u{16126}'),
+    (('\u{16121}', '\u{16120}'), '\u{16128}'),
+    (('\u{16122}', '\u{1611F}'), '\u{16127}'),
+    (('\u{16129}', '\u{1611F}'), '\u{16124}'),
+    (('\u{16D63}', '\u{16D67}'), '\u{16D69}'),
+    (('\u{16D67}', '\u{16D67}'), '\u{16D68}'),
+    (('\u{16D69}', '\u{16D67}'), '\u{16D6A}'),
+];
@@ -0,0 +1,1339 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   python3 scripts/unicode.py
+//
+// Unicode version: 16.0.0.
+
+pub(crate) const SCRIPT_NAMES: &[(&[u8; 4], &str)] = &[
+    (b"Adlm", "Adlam"),
+    (b"Aghb", "Caucasian Albanian"),
+    (b"Ahom", "Ahom"),
+    (b"Arab", "Arabic"),
+    (b"Armi", "Imperial Aramaic"),
+    (b"Armn", "Armenian"),
+    (b"Avst", "Avestan"),
+    (b"Bali", "Balinese"),
+    (b"Bamu", "Bamum"),
+    (b"Bass", "Bassa Vah"),
+    (b"Batk", "Batak"),
+    (b"Beng", "Bengali"),
+    (b"Bhks", "Bhaiksuki"),
+    (b"Bopo", "Bopomofo"),
+    (b"Brah", "Brahmi"),
+    (b"Brai", "Braille"),
+    (b"Bugi", "Buginese"),
+    (b"Buhd", "Buhid"),
+    (b"Cakm", "Chakma"),
+    (b"Cans", "Canadian Aboriginal"),
+    (b"Cari", "Carian"),
+    (b"Cham", "Cham"),
+    (b"Cher", "Cherokee"),
+    (b"Chrs", "Chorasmian"),
+    (b"Copt", "Coptic"),
+    (b"Cpmn", "Cypro Minoan"),
+    (b"Cprt", "Cypriot"),
+    (b"Cyrl", "Cyrillic"),
+    (b"Deva", "Devanagari"),
+    (b"Diak", "Dives Akuru"),
+    (b"Dogr", "Dogra"),
+    (b"Dsrt", "Deseret"),
+    (b"Dupl", "Duployan"),
+    (b"Egyp", "Egyptian Hieroglyphs"),
+    (b"Elba", "Elbasan"),
+    (b"Elym", "Elymaic"),
+    (b"Ethi", "Ethiopic"),
+    (b"Gara", "Garay"),
+    (b"Geor", "Georgian"),
+    (b"Glag", "Glagolitic"),
+    (b"Gong", "Gunjala Gondi"),
+    (b"Gonm", "Masaram Gondi"),
+    (b"Goth", "Gothic"),
+    (b"Gran", "Grantha"),
+    (b"Grek", "Greek"),
+    (b"Gujr", "Gujarati"),
+    (b"Gukh", "Gurung Khema"),
+    (b"Guru", "Gurmukhi"),
+    (b"Hang", "Hangul"),
+    (b"Hani", "Han"),
+    (b"Hano", "Hanunoo"),
+    (b"Hatr", "Hatran"),
+    (b"Hebr", "Hebrew"),
+    (b"Hira", "Hiragana"),
+    (b"Hluw", "Anatolian Hieroglyphs"),
+    (b"Hmng", "Pahawh Hmong"),
+    (b"Hmnp", "Nyiakeng Puachue Hmong"),
+    (b"Hrkt", "Katakana Or Hiragana"),
+    (b"Hung", "Old Hungarian"),
+    (b"Ital", "Old Italic"),
+    (b"Java", "Javanese"),
+    (b"Kali", "Kayah Li"),
+    (b"Kana", "Katakana"),
+    (b"Kawi", "Kawi"),
+    (b"Khar", "Kharoshthi"),
+    (b"Khmr", "Khmer"),
+    (b"Khoj", "Khojki"),
+    (b"Kits", "Khitan Small Script"),
+    (b"Knda", "Kannada"),
+    (b"Krai", "Kirat Rai"),
+    (b"Kthi", "Kaithi"),
+    (b"Lana", "Tai Tham"),
+    (b"Laoo", "Lao"),
+    (b"Latn", "Latin"),
+    (b"Lepc", "Lepcha"),
+    (b"Limb", "Limbu"),
+    (b"Lina", "Linear A"),
+    (b"Linb", "Linear B"),
+    (b"Lisu", "Lisu"),
+    (b"Lyci", "Lycian"),
+    (b"Lydi", "Lydian"),
+    (b"Mahj", "Mahajani"),
+    (b"Maka", "Makasar"),
+    (b"Mand", "Mandaic"),
+    (b"Mani", "Manichaean"),
+    (b"Marc", "Marchen"),
+    (b"Medf", "Medefaidrin"),
+    (b"Mend", "Mende Kikakui"),
+    (b"Merc", "Meroitic Cursive"),
+    (b"Mero", "Meroitic Hieroglyphs"),
+    (b"Miao", "Miao"),
+    (b"Mlym", "Malayalam"),
+    (b"Modi", "Modi"),
+    (b"Mong", "Mongolian"),
+    (b"Mroo", "Mro"),
+    (b"Mtei", "Meetei Mayek"),
+    (b"Mult", "Multani"),
+    (b"Mymr", "Myanmar"),
+    (b"Nagm", "Nag Mundari"),
+    (b"Nand", "Nandinagari"),
+    (b"Narb", "Old North Arabian"),
+    (b"Nbat", "Nabataean"),
+    (b"Newa", "Newa"),
+    (b"Nkoo", "Nko"),
+    (b"Nshu", "Nushu"),
+    (b"Ogam", "Ogham"),
+    (b"Olck", "Ol Chiki"),
+    (b"Onao", "Ol Onal"),
+    (b"Orkh", "Old Turkic"),
+    (b"Orya", "Oriya"),
+    (b"Osge", "Osage"),
+    (b"Osma", "Osmanya"),
+    (b"Ougr", "Old Uyghur"),
+    (b"Palm", "Palmyrene"),
+    (b"Pauc", "Pau Cin Hau"),
+    (b"Perm", "Old Permic"),
+    (b"Phag", "Phags Pa"),
+    (b"Phli", "Inscriptional Pahlavi"),
+    (b"Phlp", "Psalter Pahlavi"),
+    (b"Phnx", "Phoenician"),
+    (b"Prti", "Inscriptional Parthian"),
+    (b"Rjng", "Rejang"),
+    (b"Rohg", "Hanifi Rohingya"),
+    (b"Runr", "Runic"),
+    (b"Samr", "Samaritan"),
+    (b"Sarb", "Old South Arabian"),
+    (b"Saur", "Saurashtra"),
+    (b"Sgnw", "SignWriting"),
+    (b"Shaw", "Shavian"),
+    (b"Shrd", "Sharada"),
+    (b"Sidd", "Siddham"),
+    (b"Sind", "Khudawadi"),
+    (b"Sinh", "Sinhala"),
+    (b"Sogd", "Sogdian"),
+    (b"Sogo", "Old Sogdian"),
+    (b"Sora", "Sora Sompeng"),
+    (b"Soyo", "Soyombo"),
+    (b"Sund", "Sundanese"),
+    (b"Sunu", "Sunuwar"),
+    (b"Sylo", "Syloti Nagri"),
+    (b"Syrc", "Syriac"),
+    (b"Tagb", "Tagbanwa"),
+    (b"Takr", "Takri"),
+    (b"Tale", "Tai Le"),
+    (b"Talu", "New Tai Lue"),
+    (b"Taml", "Tamil"),
+    (b"Tang", "Tangut"),
+    (b"Tavt", "Tai Viet"),
+    (b"Telu", "Telugu"),
+    (b"Tfng", "Tifinagh"),
+    (b"Tglg", "Tagalog"),
+    (b"Thaa", "Thaana"),
+    (b"Thai", "Thai"),
+    (b"Tibt", "Tibetan"),
+    (b"Tirh", "Tirhuta"),
+    (b"Tnsa", "Tangsa"),
+    (b"Todr", "Todhri"),
+    (b"Toto", "Toto"),
+    (b"Tutg", "Tulu Tigalari"),
+    (b"Ugar", "Ugaritic"),
+    (b"Vaii", "Vai"),
+    (b"Vith", "Vithkuqi"),
+    (b"Wara", "Warang Citi"),
+    (b"Wcho", "Wancho"),
+    (b"Xpeo", "Old Persian"),
+    (b"Xsux", "Cuneiform"),
+    (b"Yezi", "Yezidi"),
+    (b"Yiii", "Yi"),
+    (b"Zanb", "Zanabazar Square"),
+    (b"Zinh", "Inherited"),
+    (b"Zyyy", "Common"),
+    (b"Zzzz", "Unknown"),
+];
+
+pub(crate) const SCRIPT: &[(char, char, &[u8; 4])] = &[
+    ('\u{0}', '\u{40}', b"Zyyy"),
+    ('\u{41}', '\u{5A}', b"Latn"),
+    ('\u{5B}', '\u{60}', b"Zyyy"),
+    ('\u{61}', '\u{7A}', b"Latn"),
+    ('\u{7B}', '\u{A9}', b"Zyyy"),
+    ('\u{AA}', '\u{AA}', b"Latn"),
+    ('\u{AB}', '\u{B9}', b"Zyyy"),
+    ('\u{BA}', '\u{BA}', b"Latn"),
+    ('\u{BB}', '\u{BF}', b"Zyyy"),
+    ('\u{C0}', '\u{D6}', b"Latn"),
+    ('\u{D7}', '\u{D7}', b"Zyyy"),
+    ('\u{D8}', '\u{F6}', b"Latn"),
+    ('\u{F7}', '\u{F7}', b"Zyyy"),
+    ('\u{F8}', '\u{2B8}', b"Latn"),
+    ('\u{2B9}', '\u{2DF}', b"Zyyy"),
+    ('\u{2E0}', '\u{2E4}', b"Latn"),
+    ('\u{2E5}', '\u{2E9}', b"Zyyy"),
+    ('\u{2EA}', '\u{2EB}', b"Bopo"),
+    ('\u{2EC}', '\u{2FF}', b"Zyyy"),
+    ('\u{300}', '\u{36F}', b"Zinh"),
+    ('\u{370}', '\u{373}', b"Grek"),
+    ('\u{374}', '\u{374}', b"Zyyy"),
+    ('\u{375}', '\u{377}', b"Grek"),
+    ('\u{37A}', '\u{37D}', b"Grek"),
+    ('\u{37E}', '\u{37E}', b"Zyyy"),
+    ('\u{37F}', '\u{37F}', b"Grek"),
+    ('\u{384}', '\u{384}', b"Grek"),
+    ('\u{385}', '\u{385}', b"Zyyy"),
+    ('\u{386}', '\u{386}', b"Grek"),
+    ('\u{387}', '\u{387}', b"Zyyy"),
+    ('\u{388}', '\u{38A}', b"Grek"),
+    ('\u{38C}', '\u{38C}', b"Grek"),
+    ('\u{38E}', '\u{3A1}', b"Grek"),
+    ('\u{3A3}', '\u{3E1}', b"Grek"),
+    ('\u{3E2}', '\u{3EF}', b"Copt"),
+    ('\u{3F0}', '\u{3FF}', b"Grek"),
+    ('\u{400}', '\u{484}', b"Cyrl"),
+    ('\u{485}', '\u{486}', b"Zinh"),
+    ('\u{487}', '\u{52F}', b"Cyrl"),
+    ('\u{531}', '\u{556}', b"Armn"),
+    ('\u{559}', '\u{58A}', b"Armn"),
+    ('\u{58D}', '\u{58F}', b"Armn"),
+    ('\u{591}', '\u{5C7}', b"Hebr"),
+    ('\u{5D0}', '\u{5EA}', b"Hebr"),
+    ('\u{5EF}', '\u{5F4}', b"Hebr"),
+    ('\u{600}', '\u{604}', b"Arab"),
+    ('\u{605}', '\u{605}', b"Zyyy"),
+    ('\u{606}', '\u{60B}', b"Arab"),
+    ('\u{60C}', '\u{60C}', b"Zyyy"),
+    ('\u{60D}', '\u{61A}', b"Arab"),
+    ('\u{61B}', '\u{61B}', b"Zyyy"),
+    ('\u{61C}', '\u{61E}', b"Arab"),
+    ('\u{61F}', '\u{61F}', b"Zyyy"),
+    ('\u{620}', '\u{63F}', b"Arab"),
+    ('\u{640}', '\u{640}', b"Zyyy"),
+    ('\u{641}', '\u{64A}', b"Arab"),
+    ('\u{64B}', '\u{655}', b"Zinh"),
+    ('\u{656}', '\u{66F}', b"Arab"),
+    ('\u{670}', '\u{670}', b"Zinh"),
+    ('\u{671}', '\u{6DC}', b"Arab"),
+    ('\u{6DD}', '\u{6DD}', b"Zyyy"),
+    ('\u{6DE}', '\u{6FF}', b"Arab"),
+    ('\u{700}', '\u{70D}', b"Syrc"),
+    ('\u{70F}', '\u{74A}', b"Syrc"),
+    ('\u{74D}', '\u{74F}', b"Syrc"),
+    ('\u{750}', '\u{77F}', b"Arab"),
+    ('\u{780}', '\u{7B1}', b"Thaa"),
+    ('\u{7C0}', '\u{7FA}', b"Nkoo"),
+    ('\u{7FD}', '\u{7FF}', b"Nkoo"),
+    ('\u{800}', '\u{82D}', b"Samr"),
+    ('\u{830}', '\u{83E}', b"Samr"),
+    ('\u{840}', '\u{85B}', b"Mand"),
+    ('\u{85E}', '\u{85E}', b"Mand"),
+    ('\u{860}', '\u{86A}', b"Syrc"),
+    ('\u{870}', '\u{88E}', b"Arab"),
+    ('\u{890}', '\u{891}', b"Arab"),
+    ('\u{897}', '\u{8E1}', b"Arab"),
+    ('\u{8E2}', '\u{8E2}', b"Zyyy"),
+    ('\u{8E3}', '\u{8FF}', b"Arab"),
+    ('\u{900}', '\u{950}', b"Deva"),
+    ('\u{951}', '\u{954}', b"Zinh"),
+    ('\u{955}', '\u{963}', b"Deva"),
+    ('\u{964}', '\u{965}', b"Zyyy"),
+    ('\u{966}', '\u{97F}', b"Deva"),
+    ('\u{980}', '\u{983}', b"Beng"),
+    ('\u{985}', '\u{98C}', b"Beng"),
+    ('\u{98F}', '\u{990}', b"Beng"),
+    ('\u{993}', '\u{9A8}', b"Beng"),
+    ('\u{9AA}', '\u{9B0}', b"Beng"),
+    ('\u{9B2}', '\u{9B2}', b"Beng"),
+    ('\u{9B6}', '\u{9B9}', b"Beng"),
+    ('\u{9BC}', '\u{9C4}', b"Beng"),
+    ('\u{9C7}', '\u{9C8}', b"Beng"),
+    ('\u{9CB}', '\u{9CE}', b"Beng"),
+    ('\u{9D7}', '\u{9D7}', b"Beng"),
+    ('\u{9DC}', '\u{9DD}', b"Beng"),
+    ('\u{9DF}', '\u{9E3}', b"Beng"),
+    ('\u{9E6}', '\u{9FE}', b"Beng"),
+    ('\u{A01}', '\u{A03}', b"Guru"),
+    ('\u{A05}', '\u{A0A}', b"Guru"),
+    ('\u{A0F}', '\u{A10}', b"Guru"),
+    ('\u{A13}', '\u{A28}', b"Guru"),
+    ('\u{A2A}', '\u{A30}', b"Guru"),
+    ('\u{A32}', '\u{A33}', b"Guru"),
+    ('\u{A35}', '\u{A36}', b"Guru"),
+    ('\u{A38}', '\u{A39}', b"Guru"),
+    ('\u{A3C}', '\u{A3C}', b"Guru"),
+    ('\u{A3E}', '\u{A42}', b"Guru"),
+    ('\u{A47}', '\u{A48}', b"Guru"),
+    ('\u{A4B}', '\u{A4D}', b"Guru"),
+    ('\u{A51}', '\u{A51}', b"Guru"),
+    ('\u{A59}', '\u{A5C}', b"Guru"),
+    ('\u{A5E}', '\u{A5E}', b"Guru"),
+    ('\u{A66}', '\u{A76}', b"Guru"),
+    ('\u{A81}', '\u{A83}', b"Gujr"),
+    ('\u{A85}', '\u{A8D}', b"Gujr"),
+    ('\u{A8F}', '\u{A91}', b"Gujr"),
+    ('\u{A93}', '\u{AA8}', b"Gujr"),
+    ('\u{AAA}', '\u{AB0}', b"Gujr"),
+    ('\u{AB2}', '\u{AB3}', b"Gujr"),
+    ('\u{AB5}', '\u{AB9}', b"Gujr"),
+    ('\u{ABC}', '\u{AC5}', b"Gujr"),
+    ('\u{AC7}', '\u{AC9}', b"Gujr"),
+    ('\u{ACB}', '\u{ACD}', b"Gujr"),
+    ('\u{AD0}', '\u{AD0}', b"Gujr"),
+    ('\u{AE0}', '\u{AE3}', b"Gujr"),
+    ('\u{AE6}', '\u{AF1}', b"Gujr"),
+    ('\u{AF9}', '\u{AFF}', b"Gujr"),
+    ('\u{B01}', '\u{B03}', b"Orya"),
+    ('\u{B05}', '\u{B0C}', b"Orya"),
+    ('\u{B0F}', '\u{B10}', b"Orya"),
+    ('\u{B13}', '\u{B28}', b"Orya"),
+    ('\u{B2A}', '\u{B30}', b"Orya"),
+    ('\u{B32}', '\u{B33}', b"Orya"),
+    ('\u{B35}', '\u{B39}', b"Orya"),
+    ('\u{B3C}', '\u{B44}', b"Orya"),
+    ('\u{B47}', '\u{B48}', b"Orya"),
+    ('\u{B4B}', '\u{B4D}', b"Orya"),
+    ('\u{B55}', '\u{B57}', b"Orya"),
+    ('\u{B5C}', '\u{B5D}', b"Orya"),
+    ('\u{B5F}', '\u{B63}', b"Orya"),
+    ('\u{B66}', '\u{B77}', b"Orya"),
+    ('\u{B82}', '\u{B83}', b"Taml"),
+    ('\u{B85}', '\u{B8A}', b"Taml"),
+    ('\u{B8E}', '\u{B90}', b"Taml"),
+    ('\u{B92}', '\u{B95}', b"Taml"),
+    ('\u{B99}', '\u{B9A}', b"Taml"),
+    ('\u{B9C}', '\u{B9C}', b"Taml"),
+    ('\u{B9E}', '\u{B9F}', b"Taml"),
+    ('\u{BA3}', '\u{BA4}', b"Taml"),
+    ('\u{BA8}', '\u{BAA}', b"Taml"),
+    ('\u{BAE}', '\u{BB9}', b"Taml"),
+    ('\u{BBE}', '\u{BC2}', b"Taml"),
+    ('\u{BC6}', '\u{BC8}', b"Taml"),
+    ('\u{BCA}', '\u{BCD}', b"Taml"),
+    ('\u{BD0}', '\u{BD0}', b"Taml"),
+    ('\u{BD7}', '\u{BD7}', b"Taml"),
+    ('\u{BE6}', '\u{BFA}', b"Taml"),
+    ('\u{C00}', '\u{C0C}', b"Telu"),
+    ('\u{C0E}', '\u{C10}', b"Telu"),
+    ('\u{C12}', '\u{C28}', b"Telu"),
+    ('\u{C2A}', '\u{C39}', b"Telu"),
+    ('\u{C3C}', '\u{C44}', b"Telu"),
+    ('\u{C46}', '\u{C48}', b"Telu"),
+    ('\u{C4A}', '\u{C4D}', b"Telu"),
+    ('\u{C55}', '\u{C56}', b"Telu"),
+    ('\u{C58}', '\u{C5A}', b"Telu"),
+    ('\u{C5D}', '\u{C5D}', b"Telu"),
+    ('\u{C60}', '\u{C63}', b"Telu"),
+    ('\u{C66}', '\u{C6F}', b"Telu"),
+    ('\u{C77}', '\u{C7F}', b"Telu"),
+    ('\u{C80}', '\u{C8C}', b"Knda"),
+    ('\u{C8E}', '\u{C90}', b"Knda"),
+    ('\u{C92}', '\u{CA8}', b"Knda"),
+    ('\u{CAA}', '\u{CB3}', b"Knda"),
+    ('\u{CB5}', '\u{CB9}', b"Knda"),
+    ('\u{CBC}', '\u{CC4}', b"Knda"),
+    ('\u{CC6}', '\u{CC8}', b"Knda"),
+    ('\u{CCA}', '\u{CCD}', b"Knda"),
+    ('\u{CD5}', '\u{CD6}', b"Knda"),
+    ('\u{CDD}', '\u{CDE}', b"Knda"),
+    ('\u{CE0}', '\u{CE3}', b"Knda"),
+    ('\u{CE6}', '\u{CEF}', b"Knda"),
+    ('\u{CF1}', '\u{CF3}', b"Knda"),
+    ('\u{D00}', '\u{D0C}', b"Mlym"),
+    ('\u{D0E}', '\u{D10}', b"Mlym"),
+    ('\u{D12}', '\u{D44}', b"Mlym"),
+    ('\u{D46}', '\u{D48}', b"Mlym"),
+    ('\u{D4A}', '\u{D4F}', b"Mlym"),
+    ('\u{D54}', '\u{D63}', b"Mlym"),
+    ('\u{D66}', '\u{D7F}', b"Mlym"),
+    ('\u{D81}', '\u{D83}', b"Sinh"),
+    ('\u{D85}', '\u{D96}', b"Sinh"),
+    ('\u{D9A}', '\u{DB1}', b"Sinh"),
+    ('\u{DB3}', '\u{DBB}', b"Sinh"),
+    ('\u{DBD}', '\u{DBD}', b"Sinh"),
+    ('\u{DC0}', '\u{DC6}', b"Sinh"),
+    ('\u{DCA}', '\u{DCA}', b"Sinh"),
+    ('\u{DCF}', '\u{DD4}', b"Sinh"),
+    ('\u{DD6}', '\u{DD6}', b"Sinh"),
+    ('\u{DD8}', '\u{DDF}', b"Sinh"),
+    ('\u{DE6}', '\u{DEF}', b"Sinh"),
+    ('\u{DF2}', '\u{DF4}', b"Sinh"),
+    ('\u{E01}', '\u{E3A}', b"Thai"),
+    ('\u{E3F}', '\u{E3F}', b"Zyyy"),
+    ('\u{E40}', '\u{E5B}', b"Thai"),
+    ('\u{E81}', '\u{E82}', b"Laoo"),
+    ('\u{E84}', '\u{E84}', b"Laoo"),
+    ('\u{E86}', '\u{E8A}', b"Laoo"),
+    ('\u{E8C}', '\u{EA3}', b"Laoo"),
+    ('\u{EA5}', '\u{EA5}', b"Laoo"),
+    ('\u{EA7}', '\u{EBD}', b"Laoo"),
+    ('\u{EC0}', '\u{EC4}', b"Laoo"),
+    ('\u{EC6}', '\u{EC6}', b"Laoo"),
+    ('\u{EC8}', '\u{ECE}', b"Laoo"),
+    ('\u{ED0}', '\u{ED9}', b"Laoo"),
+    ('\u{EDC}', '\u{EDF}', b"Laoo"),
+    ('\u{F00}', '\u{F47}', b"Tibt"),
+    ('\u{F49}', '\u{F6C}', b"Tibt"),
+    ('\u{F71}', '\u{F97}', b"Tibt"),
+    ('\u{F99}', '\u{FBC}', b"Tibt"),
+    ('\u{FBE}', '\u{FCC}', b"Tibt"),
+    ('\u{FCE}', '\u{FD4}', b"Tibt"),
+    ('\u{FD5}', '\u{FD8}', b"Zyyy"),
+    ('\u{FD9}', '\u{FDA}', b"Tibt"),
+    ('\u{1000}', '\u{109F}', b"Mymr"),
+    ('\u{10A0}', '\u{10C5}', b"Geor"),
+    ('\u{10C7}', '\u{10C7}', b"Geor"),
+    ('\u{10CD}', '\u{10CD}', b"Geor"),
+    ('\u{10D0}', '\u{10FA}', b"Geor"),
+    ('\u{10FB}', '\u{10FB}', b"Zyyy"),
+    ('\u{10FC}', '\u{10FF}', b"Geor"),
+    ('\u{1100}', '\u{11FF}', b"Hang"),
+    ('\u{1200}', '\u{1248}', b"Ethi"),
+    ('\u{124A}', '\u{124D}', b"Ethi"),
+    ('\u{1250}', '\u{1256}', b"Ethi"),
+    ('\u{1258}', '\u{1258}', b"Ethi"),
+    ('\u{125A}', '\u{125D}', b"Ethi"),
+    ('\u{1260}', '\u{1288}', b"Ethi"),
+    ('\u{128A}', '\u{128D}', b"Ethi"),
+    ('\u{1290}', '\u{12B0}', b"Ethi"),
+    ('\u{12B2}', '\u{12B5}', b"Ethi"),
+    ('\u{12B8}', '\u{12BE}', b"Ethi"),
+    ('\u{12C0}', '\u{12C0}', b"Ethi"),
+    ('\u{12C2}', '\u{12C5}', b"Ethi"),
+    ('\u{12C8}', '\u{12D6}', b"Ethi"),
+    ('\u{12D8}', '\u{1310}', b"Ethi"),
+    ('\u{1312}', '\u{1315}', b"Ethi"),
+    ('\u{1318}', '\u{135A}', b"Ethi"),
+    ('\u{135D}', '\u{137C}', b"Ethi"),
+    ('\u{1380}', '\u{1399}', b"Ethi"),
+    ('\u{13A0}', '\u{13F5}', b"Cher"),
+    ('\u{13F8}', '\u{13FD}', b"Cher"),
+    ('\u{1400}', '\u{167F}', b"Cans"),
+    ('\u{1680}', '\u{169C}', b"Ogam"),
+    ('\u{16A0}', '\u{16EA}', b"Runr"),
+    ('\u{16EB}', '\u{16ED}', b"Zyyy"),
+    ('\u{16EE}', '\u{16F8}', b"Runr"),
+    ('\u{1700}', '\u{1715}', b"Tglg"),
+    ('\u{171F}', '\u{171F}', b"Tglg"),
+    ('\u{1720}', '\u{1734}', b"Hano"),
+    ('\u{1735}', '\u{1736}', b"Zyyy"),
+    ('\u{1740}', '\u{1753}', b"Buhd"),
+    ('\u{1760}', '\u{176C}', b"Tagb"),
+    ('\u{176E}', '\u{1770}', b"Tagb"),
+    ('\u{1772}', '\u{1773}', b"Tagb"),
+    ('\u{1780}', '\u{17DD}', b"Khmr"),
+    ('\u{17E0}', '\u{17E9}', b"Khmr"),
+    ('\u{17F0}', '\u{17F9}', b"Khmr"),
+    ('\u{1800}', '\u{1801}', b"Mong"),
+    ('\u{1802}', '\u{1803}', b"Zyyy"),
+    ('\u{1804}', '\u{1804}', b"Mong"),
+    ('\u{1805}', '\u{1805}', b"Zyyy"),
+    ('\u{1806}', '\u{1819}', b"Mong"),
+    ('\u{1820}', '\u{1878}', b"Mong"),
+    ('\u{1880}', '\u{18AA}', b"Mong"),
+    ('\u{18B0}', '\u{18F5}', b"Cans"),
+    ('\u{1900}', '\u{191E}', b"Limb"),
+    ('\u{1920}', '\u{192B}', b"Limb"),
+    ('\u{1930}', '\u{193B}', b"Limb"),
+    ('\u{1940}', '\u{1940}', b"Limb"),
+    ('\u{1944}', '\u{194F}', b"Limb"),
+    ('\u{1950}', '\u{196D}', b"Tale"),
+    ('\u{1970}', '\u{1974}', b"Tale"),
+    ('\u{1980}', '\u{19AB}', b"Talu"),
+    ('\u{19B0}', '\u{19C9}', b"Talu"),
+    ('\u{19D0}', '\u{19DA}', b"Talu"),
+    ('\u{19DE}', '\u{19DF}', b"Talu"),
+    ('\u{19E0}', '\u{19FF}', b"Khmr"),
+    ('\u{1A00}', '\u{1A1B}', b"Bugi"),
+    ('\u{1A1E}', '\u{1A1F}', b"Bugi"),
+    ('\u{1A20}', '\u{1A5E}', b"Lana"),
+    ('\u{1A60}', '\u{1A7C}', b"Lana"),
+    ('\u{1A7F}', '\u{1A89}', b"Lana"),
+    ('\u{1A90}', '\u{1A99}', b"Lana"),
+    ('\u{1AA0}', '\u{1AAD}', b"Lana"),
+    ('\u{1AB0}', '\u{1ACE}', b"Zinh"),
+    ('\u{1B00}', '\u{1B4C}', b"Bali"),
+    ('\u{1B4E}', '\u{1B7F}', b"Bali"),
+    ('\u{1B80}', '\u{1BBF}', b"Sund"),
+    ('\u{1BC0}', '\u{1BF3}', b"Batk"),
+    ('\u{1BFC}', '\u{1BFF}', b"Batk"),
+    ('\u{1C00}', '\u{1C37}', b"Lepc"),
+    ('\u{1C3B}', '\u{1C49}', b"Lepc"),
+    ('\u{1C4D}', '\u{1C4F}', b"Lepc"),
+    ('\u{1C50}', '\u{1C7F}', b"Olck"),
+    ('\u{1C80}', '\u{1C8A}', b"Cyrl"),
+    ('\u{1C90}', '\u{1CBA}', b"Geor"),
+    ('\u{1CBD}', '\u{1CBF}', b"Geor"),
+    ('\u{1CC0}', '\u{1CC7}', b"Sund"),
+    ('\u{1CD0}', '\u{1CD2}', b"Zinh"),
+    ('\u{1CD3}', '\u{1CD3}', b"Zyyy"),
+    ('\u{1CD4}', '\u{1CE0}', b"Zinh"),
+    ('\u{1CE1}', '\u{1CE1}', b"Zyyy"),
+    ('\u{1CE2}', '\u{1CE8}', b"Zinh"),
+    ('\u{1CE9}', '\u{1CEC}', b"Zyyy"),
+    ('\u{1CED}', '\u{1CED}', b"Zinh"),
+    ('\u{1CEE}', '\u{1CF3}', b"Zyyy"),
+    ('\u{1CF4}', '\u{1CF4}', b"Zinh"),
+    ('\u{1CF5}', '\u{1CF7}', b"Zyyy"),
+    ('\u{1CF8}', '\u{1CF9}', b"Zinh"),
+    ('\u{1CFA}', '\u{1CFA}', b"Zyyy"),
+    ('\u{1D00}', '\u{1D25}', b"Latn"),
+    ('\u{1D26}', '\u{1D2A}', b"Grek"),
+    ('\u{1D2B}', '\u{1D2B}', b"Cyrl"),
+    ('\u{1D2C}', '\u{1D5C}', b"Latn"),
+    ('\u{1D5D}', '\u{1D61}', b"Grek"),
+    ('\u{1D62}', '\u{1D65}', b"Latn"),
+    ('\u{1D66}', '\u{1D6A}', b"Grek"),
+    ('\u{1D6B}', '\u{1D77}', b"Latn"),
+    ('\u{1D78}', '\u{1D78}', b"Cyrl"),
+    ('\u{1D79}', '\u{1DBE}', b"Latn"),
+    ('\u{1DBF}', '\u{1DBF}', b"Grek"),
+    ('\u{1DC0}', '\u{1DFF}', b"Zinh"),
+    ('\u{1E00}', '\u{1EFF}', b"Latn"),
+    ('\u{1F00}', '\u{1F15}', b"Grek"),
+    ('\u{1F18}', '\u{1F1D}', b"Grek"),
+    ('\u{1F20}', '\u{1F45}', b"Grek"),
+    ('\u{1F48}', '\u{1F4D}', b"Grek"),
+    ('\u{1F50}', '\u{1F57}', b"Grek"),
+    ('\u{1F59}', '\u{1F59}', b"Grek"),
+    ('\u{1F5B}', '\u{1F5B}', b"Grek"),
+    ('\u{1F5D}', '\u{1F5D}', b"Grek"),
+    ('\u{1F5F}', '\u{1F7D}', b"Grek"),
+    ('\u{1F80}', '\u{1FB4}', b"Grek"),
+    ('\u{1FB6}', '\u{1FC4}', b"Grek"),
+    ('\u{1FC6}', '\u{1FD3}', b"Grek"),
+    ('\u{1FD6}', '\u{1FDB}', b"Grek"),
+    ('\u{1FDD}', '\u{1FEF}', b"Grek"),
+    ('\u{1FF2}', '\u{1FF4}', b"Grek"),
+    ('\u{1FF6}', '\u{1FFE}', b"Grek"),
+    ('\u{2000}', '\u{200B}', b"Zyyy"),
+    ('\u{200C}', '\u{200D}', b"Zinh"),
+    ('\u{200E}', '\u{2064}', b"Zyyy"),
+    ('\u{2066}', '\u{2070}', b"Zyyy"),
+    ('\u{2071}', '\u{2071}', b"Latn"),
+    ('\u{2074}', '\u{207E}', b"Zyyy"),
+    ('\u{207F}', '\u{207F}', b"Latn"),
+    ('\u{2080}', '\u{208E}', b"Zyyy"),
+    ('\u{2090}', '\u{209C}', b"Latn"),
+    ('\u{20A0}', '\u{20C0}', b"Zyyy"),
+    ('\u{20D0}', '\u{20F0}', b"Zinh"),
+    ('\u{2100}', '\u{2125}', b"Zyyy"),
+    ('\u{2126}', '\u{2126}', b"Grek"),
+    ('\u{2127}', '\u{2129}', b"Zyyy"),
+    ('\u{212A}', '\u{212B}', b"Latn"),
+    ('\u{212C}', '\u{2131}', b"Zyyy"),
+    ('\u{2132}', '\u{2132}', b"Latn"),
+    ('\u{2133}', '\u{214D}', b"Zyyy"),
+    ('\u{214E}', '\u{214E}', b"Latn"),
+    ('\u{214F}', '\u{215F}', b"Zyyy"),
+    ('\u{2160}', '\u{2188}', b"Latn"),
+    ('\u{2189}', '\u{218B}', b"Zyyy"),
+    ('\u{2190}', '\u{2429}', b"Zyyy"),
+    ('\u{2440}', '\u{244A}', b"Zyyy"),
+    ('\u{2460}', '\u{27FF}', b"Zyyy"),
+    ('\u{2800}', '\u{28FF}', b"Brai"),
+    ('\u{2900}', '\u{2B73}', b"Zyyy"),
+    ('\u{2B76}', '\u{2B95}', b"Zyyy"),
+    ('\u{2B97}', '\u{2BFF}', b"Zyyy"),
+    ('\u{2C00}', '\u{2C5F}', b"Glag"),
+    ('\u{2C60}', '\u{2C7F}', b"Latn"),
+    ('\u{2C80}', '\u{2CF3}', b"Copt"),
+    ('\u{2CF9}', '\u{2CFF}', b"Copt"),
+    ('\u{2D00}', '\u{2D25}', b"Geor"),
+    ('\u{2D27}', '\u{2D27}', b"Geor"),
+    ('\u{2D2D}', '\u{2D2D}', b"Geor"),
+    ('\u{2D30}', '\u{2D67}', b"Tfng"),
+    ('\u{2D6F}', '\u{2D70}', b"Tfng"),
+    ('\u{2D7F}', '\u{2D7F}', b"Tfng"),
+    ('\u{2D80}', '\u{2D96}', b"Ethi"),
+    ('\u{2DA0}', '\u{2DA6}', b"Ethi"),
+    ('\u{2DA8}', '\u{2DAE}', b"Ethi"),
+    ('\u{2DB0}', '\u{2DB6}', b"Ethi"),
+    ('\u{2DB8}', '\u{2DBE}', b"Ethi"),
+    ('\u{2DC0}', '\u{2DC6}', b"Ethi"),
+    ('\u{2DC8}', '\u{2DCE}', b"Ethi"),
+    ('\u{2DD0}', '\u{2DD6}', b"Ethi"),
+    ('\u{2DD8}', '\u{2DDE}', b"Ethi"),
+    ('\u{2DE0}', '\u{2DFF}', b"Cyrl"),
+    ('\u{2E00}', '\u{2E5D}', b"Zyyy"),
+    ('\u{2E80}', '\u{2E99}', b"Hani"),
+    ('\u{2E9B}', '\u{2EF3}', b"Hani"),
+    ('\u{2F00}', '\u{2FD5}', b"Hani"),
+    ('\u{2FF0}', '\u{3004}', b"Zyyy"),
+    ('\u{3005}', '\u{3005}', b"Hani"),
+    ('\u{3006}', '\u{3006}', b"Zyyy"),
+    ('\u{3007}', '\u{3007}', b"Hani"),
+    ('\u{3008}', '\u{3020}', b"Zyyy"),
+    ('\u{3021}', '\u{3029}', b"Hani"),
+    ('\u{302A}', '\u{302D}', b"Zinh"),
+    ('\u{302E}', '\u{302F}', b"Hang"),
+    ('\u{3030}', '\u{3037}', b"Zyyy"),
+    ('\u{3038}', '\u{303B}', b"Hani"),
+    ('\u{303C}', '\u{303F}', b"Zyyy"),
+    ('\u{3041}', '\u{3096}', b"Hira"),
+    ('\u{3099}', '\u{309A}', b"Zinh"),
+    ('\u{309B}', '\u{309C}', b"Zyyy"),
+    ('\u{309D}', '\u{309F}', b"Hira"),
+    ('\u{30A0}', '\u{30A0}', b"Zyyy"),
+    ('\u{30A1}', '\u{30FA}', b"Kana"),
+    ('\u{30FB}', '\u{30FC}', b"Zyyy"),
+    ('\u{30FD}', '\u{30FF}', b"Kana"),
+    ('\u{3105}', '\u{312F}', b"Bopo"),
+    ('\u{3131}', '\u{318E}', b"Hang"),
+    ('\u{3190}', '\u{319F}', b"Zyyy"),
+    ('\u{31A0}', '\u{31BF}', b"Bopo"),
+    ('\u{31C0}', '\u{31E5}', b"Zyyy"),
+    ('\u{31EF}', '\u{31EF}', b"Zyyy"),
+    ('\u{31F0}', '\u{31FF}', b"Kana"),
+    ('\u{3200}', '\u{321E}', b"Hang"),
+    ('\u{3220}', '\u{325F}', b"Zyyy"),
+    ('\u{3260}', '\u{327E}', b"Hang"),
+    ('\u{327F}', '\u{32CF}', b"Zyyy"),
+    ('\u{32D0}', '\u{32FE}', b"Kana"),
+    ('\u{32FF}', '\u{32FF}', b"Zyyy"),
+    ('\u{3300}', '\u{3357}', b"Kana"),
+    ('\u{3358}', '\u{33FF}', b"Zyyy"),
+    ('\u{3400}', '\u{4DBF}', b"Hani"),
+    ('\u{4DC0}', '\u{4DFF}', b"Zyyy"),
+    ('\u{4E00}', '\u{9FFF}', b"Hani"),
+    ('\u{A000}', '\u{A48C}', b"Yiii"),
+    ('\u{A490}', '\u{A4C6}', b"Yiii"),
+    ('\u{A4D0}', '\u{A4FF}', b"Lisu"),
+    ('\u{A500}', '\u{A62B}', b"Vaii"),
+    ('\u{A640}', '\u{A69F}', b"Cyrl"),
+    ('\u{A6A0}', '\u{A6F7}', b"Bamu"),
+    ('\u{A700}', '\u{A721}', b"Zyyy"),
+    ('\u{A722}', '\u{A787}', b"Latn"),
+    ('\u{A788}', '\u{A78A}', b"Zyyy"),
+    ('\u{A78B}', '\u{A7CD}', b"Latn"),
+    ('\u{A7D0}', '\u{A7D1}', b"Latn"),
+    ('\u{A7D3}', '\u{A7D3}', b"Latn"),
+    ('\u{A7D5}', '\u{A7DC}', b"Latn"),
+    ('\u{A7F2}', '\u{A7FF}', b"Latn"),
+    ('\u{A800}', '\u{A82C}', b"Sylo"),
+    ('\u{A830}', '\u{A839}', b"Zyyy"),
+    ('\u{A840}', '\u{A877}', b"Phag"),
+    ('\u{A880}', '\u{A8C5}', b"Saur"),
+    ('\u{A8CE}', '\u{A8D9}', b"Saur"),
+    ('\u{A8E0}', '\u{A8FF}', b"Deva"),
+    ('\u{A900}', '\u{A92D}', b"Kali"),
+    ('\u{A92E}', '\u{A92E}', b"Zyyy"),
+    ('\u{A92F}', '\u{A92F}', b"Kali"),
+    ('\u{A930}', '\u{A953}', b"Rjng"),
+    ('\u{A95F}', '\u{A95F}', b"Rjng"),
+    ('\u{A960}', '\u{A97C}', b"Hang"),
+    ('\u{A980}', '\u{A9CD}', b"Java"),
+    ('\u{A9CF}', '\u{A9CF}', b"Zyyy"),
+    ('\u{A9D0}', '\u{A9D9}', b"Java"),
+    ('\u{A9DE}', '\u{A9DF}', b"Java"),
+    ('\u{A9E0}', '\u{A9FE}', b"Mymr"),
+    ('\u{AA00}', '\u{AA36}', b"Cham"),
+    ('\u{AA40}', '\u{AA4D}', b"Cham"),
+    ('\u{AA50}', '\u{AA59}', b"Cham"),
+    ('\u{AA5C}', '\u{AA5F}', b"Cham"),
+    ('\u{AA60}', '\u{AA7F}', b"Mymr"),
+    ('\u{AA80}', '\u{AAC2}', b"Tavt"),
+    ('\u{AADB}', '\u{AADF}', b"Tavt"),
+    ('\u{AAE0}', '\u{AAF6}', b"Mtei"),
+    ('\u{AB01}', '\u{AB06}', b"Ethi"),
+    ('\u{AB09}', '\u{AB0E}', b"Ethi"),
+    ('\u{AB11}', '\u{AB16}', b"Ethi"),
+    ('\u{AB20}', '\u{AB26}', b"Ethi"),
+    ('\u{AB28}', '\u{AB2E}', b"Ethi"),
+    ('\u{AB30}', '\u{AB5A}', b"Latn"),
+    ('\u{AB5B}', '\u{AB5B}', b"Zyyy"),
+    ('\u{AB5C}', '\u{AB64}', b"Latn"),
+    ('\u{AB65}', '\u{AB65}', b"Grek"),
+    ('\u{AB66}', '\u{AB69}', b"Latn"),
+    ('\u{AB6A}', '\u{AB6B}', b"Zyyy"),
+    ('\u{AB70}', '\u{ABBF}', b"Cher"),
+    ('\u{ABC0}', '\u{ABED}', b"Mtei"),
+    ('\u{ABF0}', '\u{ABF9}', b"Mtei"),
+    ('\u{AC00}', '\u{D7A3}', b"Hang"),
+    ('\u{D7B0}', '\u{D7C6}', b"Hang"),
+    ('\u{D7CB}', '\u{D7FB}', b"Hang"),
+    ('\u{F900}', '\u{FA6D}', b"Hani"),
+    ('\u{FA70}', '\u{FAD9}', b"Hani"),
+    ('\u{FB00}', '\u{FB06}', b"Latn"),
+    ('\u{FB13}', '\u{FB17}', b"Armn"),
+    ('\u{FB1D}', '\u{FB36}', b"Hebr"),
+    ('\u{FB38}', '\u{FB3C}', b"Hebr"),
+    ('\u{FB3E}', '\u{FB3E}', b"Hebr"),
+    ('\u{FB40}', '\u{FB41}', b"Hebr"),
+    ('\u{FB43}', '\u{FB44}', b"Hebr"),
+    ('\u{FB46}', '\u{FB4F}', b"Hebr"),
+    ('\u{FB50}', '\u{FBC2}', b"Arab"),
+    ('\u{FBD3}', '\u{FD3D}', b"Arab"),
+    ('\u{FD3E}', '\u{FD3F}', b"Zyyy"),
+    ('\u{FD40}', '\u{FD8F}', b"Arab"),
+    ('\u{FD92}', '\u{FDC7}', b"Arab"),
+    ('\u{FDCF}', '\u{FDCF}', b"Arab"),
+    ('\u{FDF0}', '\u{FDFF}', b"Arab"),
+    ('\u{FE00}', '\u{FE0F}', b"Zinh"),
+    ('\u{FE10}', '\u{FE19}', b"Zyyy"),
+    ('\u{FE20}', '\u{FE2D}', b"Zinh"),
+    ('\u{FE2E}', '\u{FE2F}', b"Cyrl"),
+    ('\u{FE30}', '\u{FE52}', b"Zyyy"),
+    ('\u{FE54}', '\u{FE66}', b"Zyyy"),
+    ('\u{FE68}', '\u{FE6B}', b"Zyyy"),
+    ('\u{FE70}', '\u{FE74}', b"Arab"),
+    ('\u{FE76}', '\u{FEFC}', b"Arab"),
+    ('\u{FEFF}', '\u{FEFF}', b"Zyyy"),
+    ('\u{FF01}', '\u{FF20}', b"Zyyy"),
+    ('\u{FF21}', '\u{FF3A}', b"Latn"),
+    ('\u{FF3B}', '\u{FF40}', b"Zyyy"),
+    ('\u{FF41}', '\u{FF5A}', b"Latn"),
+    ('\u{FF5B}', '\u{FF65}', b"Zyyy"),
+    ('\u{FF66}', '\u{FF6F}', b"Kana"),
+    ('\u{FF70}', '\u{FF70}', b"Zyyy"),
+    ('\u{FF71}', '\u{FF9D}', b"Kana"),
+    ('\u{FF9E}', '\u{FF9F}', b"Zyyy"),
+    ('\u{FFA0}', '\u{FFBE}', b"Hang"),
+    ('\u{FFC2}', '\u{FFC7}', b"Hang"),
+    ('\u{FFCA}', '\u{FFCF}', b"Hang"),
+    ('\u{FFD2}', '\u{FFD7}', b"Hang"),
+    ('\u{FFDA}', '\u{FFDC}', b"Hang"),
+    ('\u{FFE0}', '\u{FFE6}', b"Zyyy"),
+    ('\u{FFE8}', '\u{FFEE}', b"Zyyy"),
+    ('\u{FFF9}', '\u{FFFD}', b"Zyyy"),
+    ('\u{10000}', '\u{1000B}', b"Linb"),
+    ('\u{1000D}', '\u{10026}', b"Linb"),
+    ('\u{10028}', '\u{1003A}', b"Linb"),
+    ('\u{1003C}', '\u{1003D}', b"Linb"),
+    ('\u{1003F}', '\u{1004D}', b"Linb"),
+    ('\u{10050}', '\u{1005D}', b"Linb"),
+    ('\u{10080}', '\u{100FA}', b"Linb"),
+    ('\u{10100}', '\u{10102}', b"Zyyy"),
+    ('\u{10107}', '\u{10133}', b"Zyyy"),
+    ('\u{10137}', '\u{1013F}', b"Zyyy"),
+    ('\u{10140}', '\u{1018E}', b"Grek"),
+    ('\u{10190}', '\u{1019C}', b"Zyyy"),
+    ('\u{101A0}', '\u{101A0}', b"Grek"),
+    ('\u{101D0}', '\u{101FC}', b"Zyyy"),
+    ('\u{101FD}', '\u{101FD}', b"Zinh"),
+    ('\u{10280}', '\u{1029C}', b"Lyci"),
+    ('\u{102A0}', '\u{102D0}', b"Cari"),
+    ('\u{102E0}', '\u{102E0}', b"Zinh"),
+    ('\u{102E1}', '\u{102FB}', b"Zyyy"),
+    ('\u{10300}', '\u{10323}', b"Ital"),
+    ('\u{1032D}', '\u{1032F}', b"Ital"),
+    ('\u{10330}', '\u{1034A}', b"Goth"),
+    ('\u{10350}', '\u{1037A}', b"Perm"),
+    ('\u{10380}', '\u{1039D}', b"Ugar"),
+    ('\u{1039F}', '\u{1039F}', b"Ugar"),
+    ('\u{103A0}', '\u{103C3}', b"Xpeo"),
+    ('\u{103C8}', '\u{103D5}', b"Xpeo"),
+    ('\u{10400}', '\u{1044F}', b"Dsrt"),
+    ('\u{10450}', '\u{1047F}', b"Shaw"),
+    ('\u{10480}', '\u{1049D}', b"Osma"),
+    ('\u{104A0}', '\u{104A9}', b"Osma"),
+    ('\u{104B0}', '\u{104D3}', b"Osge"),
+    ('\u{104D8}', '\u{104FB}', b"Osge"),
+    ('\u{10500}', '\u{10527}', b"Elba"),
+    ('\u{10530}', '\u{10563}', b"Aghb"),
+    ('\u{1056F}', '\u{1056F}', b"Aghb"),
+    ('\u{10570}', '\u{1057A}', b"Vith"),
+    ('\u{1057C}', '\u{1058A}', b"Vith"),
+    ('\u{1058C}', '\u{10592}', b"Vith"),
+    ('\u{10594}', '\u{10595}', b"Vith"),
+    ('\u{10597}', '\u{105A1}', b"Vith"),
+    ('\u{105A3}', '\u{105B1}', b"Vith"),
+    ('\u{105B3}', '\u{105B9}', b"Vith"),
+    ('\u{105BB}', '\u{105BC}', b"Vith"),
+    ('\u{105C0}', '\u{105F3}', b"Todr"),
+    ('\u{10600}', '\u{10736}', b"Lina"),
+    ('\u{10740}', '\u{10755}', b"Lina"),
+    ('\u{10760}', '\u{10767}', b"Lina"),
+    ('\u{10780}', '\u{10785}', b"Latn"),
+    ('\u{10787}', '\u{107B0}', b"Latn"),
+    ('\u{107B2}', '\u{107BA}', b"Latn"),
+    ('\u{10800}', '\u{10805}', b"Cprt"),
+    ('\u{10808}', '\u{10808}', b"Cprt"),
+    ('\u{1080A}', '\u{10835}', b"Cprt"),
+    ('\u{10837}', '\u{10838}', b"Cprt"),
+    ('\u{1083C}', '\u{1083C}', b"Cprt"),
+    ('\u{1083F}', '\u{1083F}', b"Cprt"),
+    ('\u{10840}', '\u{10855}', b"Armi"),
+    ('\u{10857}', '\u{1085F}', b"Armi"),
+    ('\u{10860}', '\u{1087F}', b"Palm"),
+    ('\u{10880}', '\u{1089E}', b"Nbat"),
+    ('\u{108A7}', '\u{108AF}', b"Nbat"),
+    ('\u{108E0}', '\u{108F2}', b"Hatr"),
+    ('\u{108F4}', '\u{108F5}', b"Hatr"),
+    ('\u{108FB}', '\u{108FF}', b"Hatr"),
+    ('\u{10900}', '\u{1091B}', b"Phnx"),
+    ('\u{1091F}', '\u{1091F}', b"Phnx"),
+    ('\u{10920}', '\u{10939}', b"Lydi"),
+    ('\u{1093F}', '\u{1093F}', b"Lydi"),
+    ('\u{10980}', '\u{1099F}', b"Mero"),
+    ('\u{109A0}', '\u{109B7}', b"Merc"),
+    ('\u{109BC}', '\u{109CF}', b"Merc"),
+    ('\u{109D2}', '\u{109FF}', b"Merc"),
+    ('\u{10A00}', '\u{10A03}', b"Khar"),
+    ('\u{10A05}', '\u{10A06}', b"Khar"),
+    ('\u{10A0C}', '\u{10A13}', b"Khar"),
+    ('\u{10A15}', '\u{10A17}', b"Khar"),
+    ('\u{10A19}', '\u{10A35}', b"Khar"),
+    ('\u{10A38}', '\u{10A3A}', b"Khar"),
+    ('\u{10A3F}', '\u{10A48}', b"Khar"),
+    ('\u{10A50}', '\u{10A58}', b"Khar"),
+    ('\u{10A60}', '\u{10A7F}', b"Sarb"),
+    ('\u{10A80}', '\u{10A9F}', b"Narb"),
+    ('\u{10AC0}', '\u{10AE6}', b"Mani"),
+    ('\u{10AEB}', '\u{10AF6}', b"Mani"),
+    ('\u{10B00}', '\u{10B35}', b"Avst"),
+    ('\u{10B39}', '\u{10B3F}', b"Avst"),
+    ('\u{10B40}', '\u{10B55}', b"Prti"),
+    ('\u{10B58}', '\u{10B5F}', b"Prti"),
+    ('\u{10B60}', '\u{10B72}', b"Phli"),
+    ('\u{10B78}', '\u{10B7F}', b"Phli"),
+    ('\u{10B80}', '\u{10B91}', b"Phlp"),
+    ('\u{10B99}', '\u{10B9C}', b"Phlp"),
+    ('\u{10BA9}', '\u{10BAF}', b"Phlp"),
+    ('\u{10C00}', '\u{10C48}', b"Orkh"),
+    ('\u{10C80}', '\u{10CB2}', b"Hung"),
+    ('\u{10CC0}', '\u{10CF2}', b"Hung"),
+    ('\u{10CFA}', '\u{10CFF}', b"Hung"),
+    ('\u{10D00}', '\u{10D27}', b"Rohg"),
+    ('\u{10D30}', '\u{10D39}', b"Rohg"),
+    ('\u{10D40}', '\u{10D65}', b"Gara"),
+    ('\u{10D69}', '\u{10D85}', b"Gara"),
+    ('\u{10D8E}', '\u{10D8F}', b"Gara"),
+    ('\u{10E60}', '\u{10E7E}', b"Arab"),
+    ('\u{10E80}', '\u{10EA9}', b"Yezi"),
+    ('\u{10EAB}', '\u{10EAD}', b"Yezi"),
+    ('\u{10EB0}', '\u{10EB1}', b"Yezi"),
+    ('\u{10EC2}', '\u{10EC4}', b"Arab"),
+    ('\u{10EFC}', '\u{10EFF}', b"Arab"),
+    ('\u{10F00}', '\u{10F27}', b"Sogo"),
+    ('\u{10F30}', '\u{10F59}', b"Sogd"),
+    ('\u{10F70}', '\u{10F89}', b"Ougr"),
+    ('\u{10FB0}', '\u{10FCB}', b"Chrs"),
+    ('\u{10FE0}', '\u{10FF6}', b"Elym"),
+    ('\u{11000}', '\u{1104D}', b"Brah"),
+    ('\u{11052}', '\u{11075}', b"Brah"),
+    ('\u{1107F}', '\u{1107F}', b"Brah"),
+    ('\u{11080}', '\u{110C2}', b"Kthi"),
+    ('\u{110CD}', '\u{110CD}', b"Kthi"),
+    ('\u{110D0}', '\u{110E8}', b"Sora"),
+    ('\u{110F0}', '\u{110F9}', b"Sora"),
+    ('\u{11100}', '\u{11134}', b"Cakm"),
+    ('\u{11136}', '\u{11147}', b"Cakm"),
+    ('\u{11150}', '\u{11176}', b"Mahj"),
+    ('\u{11180}', '\u{111DF}', b"Shrd"),
+    ('\u{111E1}', '\u{111F4}', b"Sinh"),
+    ('\u{11200}', '\u{11211}', b"Khoj"),
+    ('\u{11213}', '\u{11241}', b"Khoj"),
+    ('\u{11280}', '\u{11286}', b"Mult"),
+    ('\u{11288}', '\u{11288}', b"Mult"),
+    ('\u{1128A}', '\u{1128D}', b"Mult"),
+    ('\u{1128F}', '\u{1129D}', b"Mult"),
+    ('\u{1129F}', '\u{112A9}', b"Mult"),
+    ('\u{112B0}', '\u{112EA}', b"Sind"),
+    ('\u{112F0}', '\u{112F9}', b"Sind"),
+    ('\u{11300}', '\u{11303}', b"Gran"),
+    ('\u{11305}', '\u{1130C}', b"Gran"),
+    ('\u{1130F}', '\u{11310}', b"Gran"),
+    ('\u{11313}', '\u{11328}', b"Gran"),
+    ('\u{1132A}', '\u{11330}', b"Gran"),
+    ('\u{11332}', '\u{11333}', b"Gran"),
+    ('\u{11335}', '\u{11339}', b"Gran"),
+    ('\u{1133B}', '\u{1133B}', b"Zinh"),
+    ('\u{1133C}', '\u{11344}', b"Gran"),
+    ('\u{11347}', '\u{11348}', b"Gran"),
+    ('\u{1134B}', '\u{1134D}', b"Gran"),
+    ('\u{11350}', '\u{11350}', b"Gran"),
+    ('\u{11357}', '\u{11357}', b"Gran"),
+    ('\u{1135D}', '\u{11363}', b"Gran"),
+    ('\u{11366}', '\u{1136C}', b"Gran"),
+    ('\u{11370}', '\u{11374}', b"Gran"),
+    ('\u{11380}', '\u{11389}', b"Tutg"),
+    ('\u{1138B}', '\u{1138B}', b"Tutg"),
+    ('\u{1138E}', '\u{1138E}', b"Tutg"),
+    ('\u{11390}', '\u{113B5}', b"Tutg"),
+    ('\u{113B7}', '\u{113C0}', b"Tutg"),
+    ('\u{113C2}', '\u{113C2}', b"Tutg"),
+    ('\u{113C5}', '\u{113C5}', b"Tutg"),
+    ('\u{113C7}', '\u{113CA}', b"Tutg"),
+    ('\u{113CC}', '\u{113D5}', b"Tutg"),
+    ('\u{113D7}', '\u{113D8}', b"Tutg"),
+    ('\u{113E1}', '\u{113E2}', b"Tutg"),
+    ('\u{11400}', '\u{1145B}', b"Newa"),
+    ('\u{1145D}', '\u{11461}', b"Newa"),
+    ('\u{11480}', '\u{114C7}', b"Tirh"),
+    ('\u{114D0}', '\u{114D9}', b"Tirh"),
+    ('\u{11580}', '\u{115B5}', b"Sidd"),
+    ('\u{115B8}', '\u{115DD}', b"Sidd"),
+    ('\u{11600}', '\u{11644}', b"Modi"),
+    ('\u{11650}', '\u{11659}', b"Modi"),
+    ('\u{11660}', '\u{1166C}', b"Mong"),
+    ('\u{11680}', '\u{116B9}', b"Takr"),
+    ('\u{116C0}', '\u{116C9}', b"Takr"),
+    ('\u{116D0}', '\u{116E3}', b"Mymr"),
+    ('\u{11700}', '\u{1171A}', b"Ahom"),
+    ('\u{1171D}', '\u{1172B}', b"Ahom"),
+    ('\u{11730}', '\u{11746}', b"Ahom"),
+    ('\u{11800}', '\u{1183B}', b"Dogr"),
+    ('\u{118A0}', '\u{118F2}', b"Wara"),
+    ('\u{118FF}', '\u{118FF}', b"Wara"),
+    ('\u{11900}', '\u{11906}', b"Diak"),
+    ('\u{11909}', '\u{11909}', b"Diak"),
+    ('\u{1190C}', '\u{11913}', b"Diak"),
+    ('\u{11915}', '\u{11916}', b"Diak"),
+    ('\u{11918}', '\u{11935}', b"Diak"),
+    ('\u{11937}', '\u{11938}', b"Diak"),
+    ('\u{1193B}', '\u{11946}', b"Diak"),
+    ('\u{11950}', '\u{11959}', b"Diak"),
+    ('\u{119A0}', '\u{119A7}', b"Nand"),
+    ('\u{119AA}', '\u{119D7}', b"Nand"),
+    ('\u{119DA}', '\u{119E4}', b"Nand"),
+    ('\u{11A00}', '\u{11A47}', b"Zanb"),
+    ('\u{11A50}', '\u{11AA2}', b"Soyo"),
+    ('\u{11AB0}', '\u{11ABF}', b"Cans"),
+    ('\u{11AC0}', '\u{11AF8}', b"Pauc"),
+    ('\u{11B00}', '\u{11B09}', b"Deva"),
+    ('\u{11BC0}', '\u{11BE1}', b"Sunu"),
+    ('\u{11BF0}', '\u{11BF9}', b"Sunu"),
+    ('\u{11C00}', '\u{11C08}', b"Bhks"),
+    ('\u{11C0A}', '\u{11C36}', b"Bhks"),
+    ('\u{11C38}', '\u{11C45}', b"Bhks"),
+    ('\u{11C50}', '\u{11C6C}', b"Bhks"),
+    ('\u{11C70}', '\u{11C8F}', b"Marc"),
+    ('\u{11C92}', '\u{11CA7}', b"Marc"),
+    ('\u{11CA9}', '\u{11CB6}', b"Marc"),
+    ('\u{11D00}', '\u{11D06}', b"Gonm"),
+    ('\u{11D08}', '\u{11D09}', b"Gonm"),
+    ('\u{11D0B}', '\u{11D36}', b"Gonm"),
+    ('\u{11D3A}', '\u{11D3A}', b"Gonm"),
+    ('\u{11D3C}', '\u{11D3D}', b"Gonm"),
+    ('\u{11D3F}', '\u{11D47}', b"Gonm"),
+    ('\u{11D50}', '\u{11D59}', b"Gonm"),
+    ('\u{11D60}', '\u{11D65}', b"Gong"),
+    ('\u{11D67}', '\u{11D68}', b"Gong"),
+    ('\u{11D6A}', '\u{11D8E}', b"Gong"),
+    ('\u{11D90}', '\u{11D91}', b"Gong"),
+    ('\u{11D93}', '\u{11D98}', b"Gong"),
+    ('\u{11DA0}', '\u{11DA9}', b"Gong"),
+    ('\u{11EE0}', '\u{11EF8}', b"Maka"),
+    ('\u{11F00}', '\u{11F10}', b"Kawi"),
+    ('\u{11F12}', '\u{11F3A}', b"Kawi"),
+    ('\u{11F3E}', '\u{11F5A}', b"Kawi"),
+    ('\u{11FB0}', '\u{11FB0}', b"Lisu"),
+    ('\u{11FC0}', '\u{11FF1}', b"Taml"),
+    ('\u{11FFF}', '\u{11FFF}', b"Taml"),
+    ('\u{12000}', '\u{12399}', b"Xsux"),
+    ('\u{12400}', '\u{1246E}', b"Xsux"),
+    ('\u{12470}', '\u{12474}', b"Xsux"),
+    ('\u{12480}', '\u{12543}', b"Xsux"),
+    ('\u{12F90}', '\u{12FF2}', b"Cpmn"),
+    ('\u{13000}', '\u{13455}', b"Egyp"),
+    ('\u{13460}', '\u{143FA}', b"Egyp"),
+    ('\u{14400}', '\u{14646}', b"Hluw"),
+    ('\u{16100}', '\u{16139}', b"Gukh"),
+    ('\u{16800}', '\u{16A38}', b"Bamu"),
+    ('\u{16A40}', '\u{16A5E}', b"Mroo"),
+    ('\u{16A60}', '\u{16A69}', b"Mroo"),
+    ('\u{16A6E}', '\u{16A6F}', b"Mroo"),
+    ('\u{16A70}', '\u{16ABE}', b"Tnsa"),
+    ('\u{16AC0}', '\u{16AC9}', b"Tnsa"),
+    ('\u{16AD0}', '\u{16AED}', b"Bass"),
+    ('\u{16AF0}', '\u{16AF5}', b"Bass"),
+    ('\u{16B00}', '\u{16B45}', b"Hmng"),
+    ('\u{16B50}', '\u{16B59}', b"Hmng"),
+    ('\u{16B5B}', '\u{16B61}', b"Hmng"),
+    ('\u{16B63}', '\u{16B77}', b"Hmng"),
+    ('\u{16B7D}', '\u{16B8F}', b"Hmng"),
+    ('\u{16D40}', '\u{16D79}', b"Krai"),
+    ('\u{16E40}', '\u{16E9A}', b"Medf"),
+    ('\u{16F00}', '\u{16F4A}', b"Miao"),
+    ('\u{16F4F}', '\u{16F87}', b"Miao"),
+    ('\u{16F8F}', '\u{16F9F}', b"Miao"),
+    ('\u{16FE0}', '\u{16FE0}', b"Tang"),
+    ('\u{16FE1}', '\u{16FE1}', b"Nshu"),
+    ('\u{16FE2}', '\u{16FE3}', b"Hani"),
+    ('\u{16FE4}', '\u{16FE4}', b"Kits"),
+    ('\u{16FF0}', '\u{16FF1}', b"Hani"),
+    ('\u{17000}', '\u{187F7}', b"Tang"),
+    ('\u{18800}', '\u{18AFF}', b"Tang"),
+    ('\u{18B00}', '\u{18CD5}', b"Kits"),
+    ('\u{18CFF}', '\u{18CFF}', b"Kits"),
+    ('\u{18D00}', '\u{18D08}', b"Tang"),
+    ('\u{1AFF0}', '\u{1AFF3}', b"Kana"),
+    ('\u{1AFF5}', '\u{1AFFB}', b"Kana"),
+    ('\u{1AFFD}', '\u{1AFFE}', b"Kana"),
+    ('\u{1B000}', '\u{1B000}', b"Kana"),
+    ('\u{1B001}', '\u{1B11F}', b"Hira"),
+    ('\u{1B120}', '\u{1B122}', b"Kana"),
+    ('\u{1B132}', '\u{1B132}', b"Hira"),
+    ('\u{1B150}', '\u{1B152}', b"Hira"),
+    ('\u{1B155}', '\u{1B155}', b"Kana"),
+    ('\u{1B164}', '\u{1B167}', b"Kana"),
+    ('\u{1B170}', '\u{1B2FB}', b"Nshu"),
+    ('\u{1BC00}', '\u{1BC6A}', b"Dupl"),
+    ('\u{1BC70}', '\u{1BC7C}', b"Dupl"),
+    ('\u{1BC80}', '\u{1BC88}', b"Dupl"),
+    ('\u{1BC90}', '\u{1BC99}', b"Dupl"),
+    ('\u{1BC9C}', '\u{1BC9F}', b"Dupl"),
+    ('\u{1BCA0}', '\u{1BCA3}', b"Zyyy"),
+    ('\u{1CC00}', '\u{1CCF9}', b"Zyyy"),
+    ('\u{1CD00}', '\u{1CEB3}', b"Zyyy"),
+    ('\u{1CF00}', '\u{1CF2D}', b"Zinh"),
+    ('\u{1CF30}', '\u{1CF46}', b"Zinh"),
+    ('\u{1CF50}', '\u{1CFC3}', b"Zyyy"),
+    ('\u{1D000}', '\u{1D0F5}', b"Zyyy"),
+    ('\u{1D100}', '\u{1D126}', b"Zyyy"),
+    ('\u{1D129}', '\u{1D166}', b"Zyyy"),
+    ('\u{1D167}', '\u{1D169}', b"Zinh"),
+    ('\u{1D16A}', '\u{1D17A}', b"Zyyy"),
+    ('\u{1D17B}', '\u{1D182}', b"Zinh"),
+    ('\u{1D183}', '\u{1D184}', b"Zyyy"),
+    ('\u{1D185}', '\u{1D18B}', b"Zinh"),
+    ('\u{1D18C}', '\u{1D1A9}', b"Zyyy"),
+    ('\u{1D1AA}', '\u{1D1AD}', b"Zinh"),
+    ('\u{1D1AE}', '\u{1D1EA}', b"Zyyy"),
+    ('\u{1D200}', '\u{1D245}', b"Grek"),
+    ('\u{1D2C0}', '\u{1D2D3}', b"Zyyy"),
+    ('\u{1D2E0}', '\u{1D2F3}', b"Zyyy"),
+    ('\u{1D300}', '\u{1D356}', b"Zyyy"),
+    ('\u{1D360}', '\u{1D378}', b"Zyyy"),
+    ('\u{1D400}', '\u{1D454}', b"Zyyy"),
+    ('\u{1D456}', '\u{1D49C}', b"Zyyy"),
+    ('\u{1D49E}', '\u{1D49F}', b"Zyyy"),
+    ('\u{1D4A2}', '\u{1D4A2}', b"Zyyy"),
+    ('\u{1D4A5}', '\u{1D4A6}', b"Zyyy"),
+    ('\u{1D4A9}', '\u{1D4AC}', b"Zyyy"),
+    ('\u{1D4AE}', '\u{1D4B9}', b"Zyyy"),
+    ('\u{1D4BB}', '\u{1D4BB}', b"Zyyy"),
+    ('\u{1D4BD}', '\u{1D4C3}', b"Zyyy"),
+    ('\u{1D4C5}', '\u{1D505}', b"Zyyy"),
+    ('\u{1D507}', '\u{1D50A}', b"Zyyy"),
+    ('\u{1D50D}', '\u{1D514}', b"Zyyy"),
+    ('\u{1D516}', '\u{1D51C}', b"Zyyy"),
+    ('\u{1D51E}', '\u{1D539}', b"Zyyy"),
+    ('\u{1D53B}', '\u{1D53E}', b"Zyyy"),
+    ('\u{1D540}', '\u{1D544}', b"Zyyy"),
+    ('\u{1D546}', '\u{1D546}', b"Zyyy"),
+    ('\u{1D54A}', '\u{1D550}', b"Zyyy"),
+    ('\u{1D552}', '\u{1D6A5}', b"Zyyy"),
+    ('\u{1D6A8}', '\u{1D7CB}', b"Zyyy"),
+    ('\u{1D7CE}', '\u{1D7FF}', b"Zyyy"),
+    ('\u{1D800}', '\u{1DA8B}', b"Sgnw"),
+    ('\u{1DA9B}', '\u{1DA9F}', b"Sgnw"),
+    ('\u{1DAA1}', '\u{1DAAF}', b"Sgnw"),
+    ('\u{1DF00}', '\u{1DF1E}', b"Latn"),
+    ('\u{1DF25}', '\u{1DF2A}', b"Latn"),
+    ('\u{1E000}', '\u{1E006}', b"Glag"),
+    ('\u{1E008}', '\u{1E018}', b"Glag"),
+    ('\u{1E01B}', '\u{1E021}', b"Glag"),
+    ('\u{1E023}', '\u{1E024}', b"Glag"),
+    ('\u{1E026}', '\u{1E02A}', b"Glag"),
+    ('\u{1E030}', '\u{1E06D}', b"Cyrl"),
+    ('\u{1E08F}', '\u{1E08F}', b"Cyrl"),
+    ('\u{1E100}', '\u{1E12C}', b"Hmnp"),
+    ('\u{1E130}', '\u{1E13D}', b"Hmnp"),
+    ('\u{1E140}', '\u{1E149}', b"Hmnp"),
+    ('\u{1E14E}', '\u{1E14F}', b"Hmnp"),
+    ('\u{1E290}', '\u{1E2AE}', b"Toto"),
+    ('\u{1E2C0}', '\u{1E2F9}', b"Wcho"),
+    ('\u{1E2FF}', '\u{1E2FF}', b"Wcho"),
+    ('\u{1E4D0}', '\u{1E4F9}', b"Nagm"),
+    ('\u{1E5D0}', '\u{1E5FA}', b"Onao"),
+    ('\u{1E5FF}', '\u{1E5FF}', b"Onao"),
+    ('\u{1E7E0}', '\u{1E7E6}', b"Ethi"),
+    ('\u{1E7E8}', '\u{1E7EB}', b"Ethi"),
+    ('\u{1E7ED}', '\u{1E7EE}', b"Ethi"),
+    ('\u{1E7F0}', '\u{1E7FE}', b"Ethi"),
+    ('\u{1E800}', '\u{1E8C4}', b"Mend"),
+    ('\u{1E8C7}', '\u{1E8D6}', b"Mend"),
+    ('\u{1E900}', '\u{1E94B}', b"Adlm"),
+    ('\u{1E950}', '\u{1E959}', b"Adlm"),
+    ('\u{1E95E}', '\u{1E95F}', b"Adlm"),
+    ('\u{1EC71}', '\u{1ECB4}', b"Zyyy"),
+    ('\u{1ED01}', '\u{1ED3D}', b"Zyyy"),
+    ('\u{1EE00}', '\u{1EE03}', b"Arab"),
+    ('\u{1EE05}', '\u{1EE1F}', b"Arab"),
+    ('\u{1EE21}', '\u{1EE22}', b"Arab"),
+    ('\u{1EE24}', '\u{1EE24}', b"Arab"),
+    ('\u{1EE27}', '\u{1EE27}', b"Arab"),
+    ('\u{1EE29}', '\u{1EE32}', b"Arab"),
+    ('\u{1EE34}', '\u{1EE37}', b"Arab"),
+    ('\u{1EE39}', '\u{1EE39}', b"Arab"),
+    ('\u{1EE3B}', '\u{1EE3B}', b"Arab"),
+    ('\u{1EE42}', '\u{1EE42}', b"Arab"),
+    ('\u{1EE47}', '\u{1EE47}', b"Arab"),
+    ('\u{1EE49}', '\u{1EE49}', b"Arab"),
+    ('\u{1EE4B}', '\u{1EE4B}', b"Arab"),
+    ('\u{1EE4D}', '\u{1EE4F}', b"Arab"),
+    ('\u{1EE51}', '\u{1EE52}', b"Arab"),
+    ('\u{1EE54}', '\u{1EE54}', b"Arab"),
+    ('\u{1EE57}', '\u{1EE57}', b"Arab"),
+    ('\u{1EE59}', '\u{1EE59}', b"Arab"),
+    ('\u{1EE5B}', '\u{1EE5B}', b"Arab"),
+    ('\u{1EE5D}', '\u{1EE5D}', b"Arab"),
+    ('\u{1EE5F}', '\u{1EE5F}', b"Arab"),
+    ('\u{1EE61}', '\u{1EE62}', b"Arab"),
+    ('\u{1EE64}', '\u{1EE64}', b"Arab"),
+    ('\u{1EE67}', '\u{1EE6A}', b"Arab"),
+    ('\u{1EE6C}', '\u{1EE72}', b"Arab"),
+    ('\u{1EE74}', '\u{1EE77}', b"Arab"),
+    ('\u{1EE79}', '\u{1EE7C}', b"Arab"),
+    ('\u{1EE7E}', '\u{1EE7E}', b"Arab"),
+    ('\u{1EE80}', '\u{1EE89}', b"Arab"),
+    ('\u{1EE8B}', '\u{1EE9B}', b"Arab"),
+    ('\u{1EEA1}', '\u{1EEA3}', b"Arab"),
+    ('\u{1EEA5}', '\u{1EEA9}', b"Arab"),
+    ('\u{1EEAB}', '\u{1EEBB}', b"Arab"),
+    ('\u{1EEF0}', '\u{1EEF1}', b"Arab"),
+    ('\u{1F000}', '\u{1F02B}', b"Zyyy"),
+    ('\u{1F030}', '\u{1F093}', b"Zyyy"),
+    ('\u{1F0A0}', '\u{1F0AE}', b"Zyyy"),
+    ('\u{1F0B1}', '\u{1F0BF}', b"Zyyy"),
+    ('\u{1F0C1}', '\u{1F0CF}', b"Zyyy"),
+    ('\u{1F0D1}', '\u{1F0F5}', b"Zyyy"),
+    ('\u{1F100}', '\u{1F1AD}', b"Zyyy"),
+    ('\u{1F1E6}', '\u{1F1FF}', b"Zyyy"),
+    ('\u{1F200}', '\u{1F200}', b"Hira"),
+    ('\u{1F201}', '\u{1F202}', b"Zyyy"),
+    ('\u{1F210}', '\u{1F23B}', b"Zyyy"),
+    ('\u{1F240}', '\u{1F248}', b"Zyyy"),
+    ('\u{1F250}', '\u{1F251}', b"Zyyy"),
+    ('\u{1F260}', '\u{1F265}', b"Zyyy"),
+    ('\u{1F300}', '\u{1F6D7}', b"Zyyy"),
+    ('\u{1F6DC}', '\u{1F6EC}', b"Zyyy"),
+    ('\u{1F6F0}', '\u{1F6FC}', b"Zyyy"),
+    ('\u{1F700}', '\u{1F776}', b"Zyyy"),
+    ('\u{1F77B}', '\u{1F7D9}', b"Zyyy"),
+    ('\u{1F7E0}', '\u{1F7EB}', b"Zyyy"),
+    ('\u{1F7F0}', '\u{1F7F0}', b"Zyyy"),
+    ('\u{1F800}', '\u{1F80B}', b"Zyyy"),
+    ('\u{1F810}', '\u{1F847}', b"Zyyy"),
+    ('\u{1F850}', '\u{1F859}', b"Zyyy"),
+    ('\u{1F860}', '\u{1F887}', b"Zyyy"),
+    ('\u{1F890}', '\u{1F8AD}', b"Zyyy"),
+    ('\u{1F8B0}', '\u{1F8BB}', b"Zyyy"),
+    ('\u{1F8C0}', '\u{1F8C1}', b"Zyyy"),
+    ('\u{1F900}', '\u{1FA53}', b"Zyyy"),
+    ('\u{1FA60}', '\u{1FA6D}', b"Zyyy"),
+    ('\u{1FA70}', '\u{1FA7C}', b"Zyyy"),
+    ('\u{1FA80}', '\u{1FA89}', b"Zyyy"),
+    ('\u{1FA8F}', '\u{1FAC6}', b"Zyyy"),
+    ('\u{1FACE}', '\u{1FADC}', b"Zyyy"),
+    ('\u{1FADF}', '\u{1FAE9}', b"Zyyy"),
+    ('\u{1FAF0}', '\u{1FAF8}', b"Zyyy"),
+    ('\u{1FB00}', '\u{1FB92}', b"Zyyy"),
+    ('\u{1FB94}', '\u{1FBF9}', b"Zyyy"),
+    ('\u{20000}', '\u{2A6DF}', b"Hani"),
+    ('\u{2A700}', '\u{2B739}', b"Hani"),
+    ('\u{2B740}', '\u{2B81D}', b"Hani"),
+    ('\u{2B820}', '\u{2CEA1}', b"Hani"),
+    ('\u{2CEB0}', '\u{2EBE0}', b"Hani"),
+    ('\u{2EBF0}', '\u{2EE5D}', b"Hani"),
+    ('\u{2F800}', '\u{2FA1D}', b"Hani"),
+    ('\u{30000}', '\u{3134A}', b"Hani"),
+    ('\u{31350}', '\u{323AF}', b"Hani"),
+    ('\u{E0001}', '\u{E0001}', b"Zyyy"),
+    ('\u{E0020}', '\u{E007F}', b"Zyyy"),
+    ('\u{E0100}', '\u{E01EF}', b"Zinh"),
+];
+
+pub(crate) const SCRIPT_EXTENSIONS: &[(char, char, &[[u8; 4]])] = &[
+    ('\u{B7}', '\u{B7}', &[*b"Avst", *b"Cari", *b"Copt", *b"Dupl", *b"Elba", *b"Geor", *b"Glag", *b"Gong", *b"Goth", *b"Grek", *b"Hani", *b"Latn", *b"Lydi", *b"Mahj", *b"Perm", *b"Shaw"]),
+    ('\u{2BC}', '\u{2BC}', &[*b"Beng", *b"Cyrl", *b"Deva", *b"Latn", *b"Lisu", *b"Thai", *b"Toto"]),
+    ('\u{2C7}', '\u{2C7}', &[*b"Bopo", *b"Latn"]),
+    ('\u{2C9}', '\u{2CB}', &[*b"Bopo", *b"Latn"]),
+    ('\u{2CD}', '\u{2CD}', &[*b"Latn", *b"Lisu"]),
+    ('\u{2D7}', '\u{2D7}', &[*b"Latn", *b"Thai"]),
+    ('\u{2D9}', '\u{2D9}', &[*b"Bopo", *b"Latn"]),
+    ('\u{300}', '\u{300}', &[*b"Cher", *b"Copt", *b"Cyrl", *b"Grek", *b"Latn", *b"Perm", *b"Sunu", *b"Tale"]),
+    ('\u{301}', '\u{301}', &[*b"Cher", *b"Cyrl", *b"Grek", *b"Latn", *b"Osge", *b"Sunu", *b"Tale", *b"Todr"]),
+    ('\u{302}', '\u{302}', &[*b"Cher", *b"Cyrl", *b"Latn", *b"Tfng"]),
+    ('\u{303}', '\u{303}', &[*b"Glag", *b"Latn", *b"Sunu", *b"Syrc", *b"Thai"]),
+    ('\u{304}', '\u{304}', &[*b"Aghb", *b"Cher", *b"Copt", *b"Cyrl", *b"Goth", *b"Grek", *b"Latn", *b"Osge", *b"Syrc", *b"Tfng", *b"Todr"]),
+    ('\u{305}', '\u{305}', &[*b"Copt", *b"Elba", *b"Glag", *b"Goth", *b"Kana", *b"Latn"]),
+    ('\u{306}', '\u{306}', &[*b"Cyrl", *b"Grek", *b"Latn", *b"Perm"]),
+    ('\u{307}', '\u{307}', &[*b"Copt", *b"Dupl", *b"Hebr", *b"Latn", *b"Perm", *b"Syrc", *b"Tale", *b"Tfng", *b"Todr"]),
+    ('\u{308}', '\u{308}', &[*b"Armn", *b"Cyrl", *b"Dupl", *b"Goth", *b"Grek", *b"Hebr", *b"Latn", *b"Perm", *b"Syrc", *b"Tale"]),
+    ('\u{309}', '\u{309}', &[*b"Latn", *b"Tfng"]),
+    ('\u{30A}', '\u{30A}', &[*b"Dupl", *b"Latn", *b"Syrc"]),
+    ('\u{30B}', '\u{30B}', &[*b"Cher", *b"Cyrl", *b"Latn", *b"Osge"]),
+    ('\u{30C}', '\u{30C}', &[*b"Cher", *b"Latn", *b"Tale"]),
+    ('\u{30D}', '\u{30D}', &[*b"Latn", *b"Sunu"]),
+    ('\u{30E}', '\u{30E}', &[*b"Ethi", *b"Latn"]),
+    ('\u{310}', '\u{310}', &[*b"Latn", *b"Sunu"]),
+    ('\u{311}', '\u{311}', &[*b"Cyrl", *b"Latn", *b"Todr"]),
+    ('\u{313}', '\u{313}', &[*b"Grek", *b"Latn", *b"Perm", *b"Todr"]),
+    ('\u{320}', '\u{320}', &[*b"Latn", *b"Syrc"]),
+    ('\u{323}', '\u{323}', &[*b"Cher", *b"Dupl", *b"Kana", *b"Latn", *b"Syrc"]),
+    ('\u{324}', '\u{324}', &[*b"Cher", *b"Dupl", *b"Latn", *b"Syrc"]),
+    ('\u{325}', '\u{325}', &[*b"Latn", *b"Syrc"]),
+    ('\u{32D}', '\u{32D}', &[*b"Latn", *b"Sunu", *b"Syrc"]),
+    ('\u{32E}', '\u{32E}', &[*b"Latn", *b"Syrc"]),
+    ('\u{330}', '\u{330}', &[*b"Cher", *b"Latn", *b"Syrc"]),
+    ('\u{331}', '\u{331}', &[*b"Aghb", *b"Cher", *b"Goth", *b"Latn", *b"Sunu", *b"Thai"]),
+    ('\u{342}', '\u{342}', &[*b"Grek"]),
+    ('\u{345}', '\u{345}', &[*b"Grek"]),
+    ('\u{358}', '\u{358}', &[*b"Latn", *b"Osge"]),
+    ('\u{35E}', '\u{35E}', &[*b"Aghb", *b"Latn", *b"Todr"]),
+    ('\u{363}', '\u{36F}', &[*b"Latn"]),
+    ('\u{374}', '\u{375}', &[*b"Copt", *b"Grek"]),
+    ('\u{483}', '\u{483}', &[*b"Cyrl", *b"Perm"]),
+    ('\u{484}', '\u{484}', &[*b"Cyrl", *b"Glag"]),
+    ('\u{485}', '\u{486}', &[*b"Cyrl", *b"Latn"]),
+    ('\u{487}', '\u{487}', &[*b"Cyrl", *b"Glag"]),
+    ('\u{589}', '\u{589}', &[*b"Armn", *b"Geor", *b"Glag"]),
+    ('\u{60C}', '\u{60C}', &[*b"Arab", *b"Gara", *b"Nkoo", *b"Rohg", *b"Syrc", *b"Thaa", *b"Yezi"]),
+    ('\u{61B}', '\u{61B}', &[*b"Arab", *b"Gara", *b"Nkoo", *b"Rohg", *b"Syrc", *b"Thaa", *b"Yezi"]),
+    ('\u{61C}', '\u{61C}', &[*b"Arab", *b"Syrc", *b"Thaa"]),
+    ('\u{61F}', '\u{61F}', &[*b"Adlm", *b"Arab", *b"Gara", *b"Nkoo", *b"Rohg", *b"Syrc", *b"Thaa", *b"Yezi"]),
+    ('\u{640}', '\u{640}', &[*b"Adlm", *b"Arab", *b"Mand", *b"Mani", *b"Ougr", *b"Phlp", *b"Rohg", *b"Sogd", *b"Syrc"]),
+    ('\u{64B}', '\u{655}', &[*b"Arab", *b"Syrc"]),
+    ('\u{660}', '\u{669}', &[*b"Arab", *b"Thaa", *b"Yezi"]),
+    ('\u{670}', '\u{670}', &[*b"Arab", *b"Syrc"]),
+    ('\u{6D4}', '\u{6D4}', &[*b"Arab", *b"Rohg"]),
+    ('\u{951}', '\u{951}', &[*b"Beng", *b"Deva", *b"Gran", *b"Gujr", *b"Guru", *b"Knda", *b"Latn", *b"Mlym", *b"Orya", *b"Shrd", *b"Taml", *b"Telu", *b"Tirh"]),
+    ('\u{952}', '\u{952}', &[*b"Beng", *b"Deva", *b"Gran", *b"Gujr", *b"Guru", *b"Knda", *b"Latn", *b"Mlym", *b"Orya", *b"Taml", *b"Telu", *b"Tirh"]),
+    ('\u{964}', '\u{964}', &[*b"Beng", *b"Deva", *b"Dogr", *b"Gong", *b"Gonm", *b"Gran", *b"Gujr", *b"Guru", *b"Knda", *b"Mahj", *b"Mlym", *b"Nand", *b"Onao", *b"Orya", *b"Sind", *b"Sinh", *b"Sylo", *b"Takr", *b"Taml", *b"Telu", *b"Tirh"]),
+    ('\u{965}', '\u{965}', &[*b"Beng", *b"Deva", *b"Dogr", *b"Gong", *b"Gonm", *b"Gran", *b"Gujr", *b"Gukh", *b"Guru", *b"Knda", *b"Limb", *b"Mahj", *b"Mlym", *b"Nand", *b"Onao", *b"Orya", *b"Sind", *b"Sinh", *b"Sylo", *b"Takr", *b"Taml", *b"Telu", *b"Tirh"]),
+    ('\u{966}', '\u{96F}', &[*b"Deva", *b"Dogr", *b"Kthi", *b"Mahj"]),
+    ('\u{9E6}', '\u{9EF}', &[*b"Beng", *b"Cakm", *b"Sylo"]),
+    ('\u{A66}', '\u{A6F}', &[*b"Guru", *b"Mult"]),
+    ('\u{AE6}', '\u{AEF}', &[*b"Gujr", *b"Khoj"]),
+    ('\u{BE6}', '\u{BF3}', &[*b"Gran", *b"Taml"]),
+    ('\u{CE6}', '\u{CEF}', &[*b"Knda", *b"Nand", *b"Tutg"]),
+    ('\u{1040}', '\u{1049}', &[*b"Cakm", *b"Mymr", *b"Tale"]),
+    ('\u{10FB}', '\u{10FB}', &[*b"Geor", *b"Glag", *b"Latn"]),
+    ('\u{16EB}', '\u{16ED}', &[*b"Runr"]),
+    ('\u{1735}', '\u{1736}', &[*b"Buhd", *b"Hano", *b"Tagb", *b"Tglg"]),
+    ('\u{1802}', '\u{1803}', &[*b"Mong", *b"Phag"]),
+    ('\u{1805}', '\u{1805}', &[*b"Mong", *b"Phag"]),
+    ('\u{1CD0}', '\u{1CD0}', &[*b"Beng", *b"Deva", *b"Gran", *b"Knda"]),
+    ('\u{1CD1}', '\u{1CD1}', &[*b"Deva"]),
+    ('\u{1CD2}', '\u{1CD2}', &[*b"Beng", *b"Deva", *b"Gran", *b"Knda"]),
+    ('\u{1CD3}', '\u{1CD3}', &[*b"Deva", *b"Gran", *b"Knda"]),
+    ('\u{1CD4}', '\u{1CD4}', &[*b"Deva"]),
+    ('\u{1CD5}', '\u{1CD6}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CD7}', '\u{1CD7}', &[*b"Deva", *b"Shrd"]),
+    ('\u{1CD8}', '\u{1CD8}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CD9}', '\u{1CD9}', &[*b"Deva", *b"Shrd"]),
+    ('\u{1CDA}', '\u{1CDA}', &[*b"Deva", *b"Knda", *b"Mlym", *b"Orya", *b"Taml", *b"Telu"]),
+    ('\u{1CDB}', '\u{1CDB}', &[*b"Deva"]),
+    ('\u{1CDC}', '\u{1CDD}', &[*b"Deva", *b"Shrd"]),
+    ('\u{1CDE}', '\u{1CDF}', &[*b"Deva"]),
+    ('\u{1CE0}', '\u{1CE0}', &[*b"Deva", *b"Shrd"]),
+    ('\u{1CE1}', '\u{1CE1}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CE2}', '\u{1CE8}', &[*b"Deva"]),
+    ('\u{1CE9}', '\u{1CE9}', &[*b"Deva", *b"Nand"]),
+    ('\u{1CEA}', '\u{1CEA}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CEB}', '\u{1CEC}', &[*b"Deva"]),
+    ('\u{1CED}', '\u{1CED}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CEE}', '\u{1CF1}', &[*b"Deva"]),
+    ('\u{1CF2}', '\u{1CF2}', &[*b"Beng", *b"Deva", *b"Gran", *b"Knda", *b"Mlym", *b"Nand", *b"Orya", *b"Sinh", *b"Telu", *b"Tirh", *b"Tutg"]),
+    ('\u{1CF3}', '\u{1CF3}', &[*b"Deva", *b"Gran"]),
+    ('\u{1CF4}', '\u{1CF4}', &[*b"Deva", *b"Gran", *b"Knda", *b"Tutg"]),
+    ('\u{1CF5}', '\u{1CF6}', &[*b"Beng", *b"Deva"]),
+    ('\u{1CF7}', '\u{1CF7}', &[*b"Beng"]),
+    ('\u{1CF8}', '\u{1CF9}', &[*b"Deva", *b"Gran"]),
+    ('\u{1CFA}', '\u{1CFA}', &[*b"Nand"]),
+    ('\u{1DC0}', '\u{1DC1}', &[*b"Grek"]),
+    ('\u{1DF8}', '\u{1DF8}', &[*b"Cyrl", *b"Latn", *b"Syrc"]),
+    ('\u{1DFA}', '\u{1DFA}', &[*b"Syrc"]),
+    ('\u{202F}', '\u{202F}', &[*b"Latn", *b"Mong", *b"Phag"]),
+    ('\u{204F}', '\u{204F}', &[*b"Adlm", *b"Arab"]),
+    ('\u{205A}', '\u{205A}', &[*b"Cari", *b"Geor", *b"Glag", *b"Hung", *b"Lyci", *b"Orkh"]),
+    ('\u{205D}', '\u{205D}', &[*b"Cari", *b"Grek", *b"Hung", *b"Mero"]),
+    ('\u{20F0}', '\u{20F0}', &[*b"Deva", *b"Gran", *b"Latn"]),
+    ('\u{2E17}', '\u{2E17}', &[*b"Copt", *b"Latn"]),
+    ('\u{2E30}', '\u{2E30}', &[*b"Avst", *b"Orkh"]),
+    ('\u{2E31}', '\u{2E31}', &[*b"Avst", *b"Cari", *b"Geor", *b"Hung", *b"Kthi", *b"Lydi", *b"Samr"]),
+    ('\u{2E3C}', '\u{2E3C}', &[*b"Dupl"]),
+    ('\u{2E41}', '\u{2E41}', &[*b"Adlm", *b"Arab", *b"Hung"]),
+    ('\u{2E43}', '\u{2E43}', &[*b"Cyrl", *b"Glag"]),
+    ('\u{2FF0}', '\u{2FFF}', &[*b"Hani", *b"Tang"]),
+    ('\u{3001}', '\u{3001}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Mong", *b"Yiii"]),
+    ('\u{3002}', '\u{3002}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Mong", *b"Phag", *b"Yiii"]),
+    ('\u{3003}', '\u{3003}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{3006}', '\u{3006}', &[*b"Hani"]),
+    ('\u{3008}', '\u{3009}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Mong", *b"Tibt", *b"Yiii"]),
+    ('\u{300A}', '\u{300B}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Lisu", *b"Mong", *b"Tibt", *b"Yiii"]),
+    ('\u{300C}', '\u{3011}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Yiii"]),
+    ('\u{3013}', '\u{3013}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{3014}', '\u{301B}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Yiii"]),
+    ('\u{301C}', '\u{301F}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{302A}', '\u{302D}', &[*b"Bopo", *b"Hani"]),
+    ('\u{3030}', '\u{3030}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{3031}', '\u{3035}', &[*b"Hira", *b"Kana"]),
+    ('\u{3037}', '\u{3037}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{303C}', '\u{303D}', &[*b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{303E}', '\u{303F}', &[*b"Hani"]),
+    ('\u{3099}', '\u{309C}', &[*b"Hira", *b"Kana"]),
+    ('\u{30A0}', '\u{30A0}', &[*b"Hira", *b"Kana"]),
+    ('\u{30FB}', '\u{30FB}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Yiii"]),
+    ('\u{30FC}', '\u{30FC}', &[*b"Hira", *b"Kana"]),
+    ('\u{3190}', '\u{319F}', &[*b"Hani"]),
+    ('\u{31C0}', '\u{31E5}', &[*b"Hani"]),
+    ('\u{31EF}', '\u{31EF}', &[*b"Hani", *b"Tang"]),
+    ('\u{3220}', '\u{3247}', &[*b"Hani"]),
+    ('\u{3280}', '\u{32B0}', &[*b"Hani"]),
+    ('\u{32C0}', '\u{32CB}', &[*b"Hani"]),
+    ('\u{32FF}', '\u{32FF}', &[*b"Hani"]),
+    ('\u{3358}', '\u{3370}', &[*b"Hani"]),
+    ('\u{337B}', '\u{337F}', &[*b"Hani"]),
+    ('\u{33E0}', '\u{33FE}', &[*b"Hani"]),
+    ('\u{A66F}', '\u{A66F}', &[*b"Cyrl", *b"Glag"]),
+    ('\u{A700}', '\u{A707}', &[*b"Hani", *b"Latn"]),
+    ('\u{A830}', '\u{A832}', &[*b"Deva", *b"Dogr", *b"Gujr", *b"Guru", *b"Khoj", *b"Knda", *b"Kthi", *b"Mahj", *b"Mlym", *b"Modi", *b"Nand", *b"Shrd", *b"Sind", *b"Takr", *b"Tirh", *b"Tutg"]),
+    ('\u{A833}', '\u{A835}', &[*b"Deva", *b"Dogr", *b"Gujr", *b"Guru", *b"Khoj", *b"Knda", *b"Kthi", *b"Mahj", *b"Modi", *b"Nand", *b"Shrd", *b"Sind", *b"Takr", *b"Tirh", *b"Tutg"]),
+    ('\u{A836}', '\u{A837}', &[*b"Deva", *b"Dogr", *b"Gujr", *b"Guru", *b"Khoj", *b"Kthi", *b"Mahj", *b"Modi", *b"Sind", *b"Takr", *b"Tirh"]),
+    ('\u{A838}', '\u{A838}', &[*b"Deva", *b"Dogr", *b"Gujr", *b"Guru", *b"Khoj", *b"Kthi", *b"Mahj", *b"Modi", *b"Shrd", *b"Sind", *b"Takr", *b"Tirh"]),
+    ('\u{A839}', '\u{A839}', &[*b"Deva", *b"Dogr", *b"Gujr", *b"Guru", *b"Khoj", *b"Kthi", *b"Mahj", *b"Modi", *b"Sind", *b"Takr", *b"Tirh"]),
+    ('\u{A8F1}', '\u{A8F1}', &[*b"Beng", *b"Deva", *b"Tutg"]),
+    ('\u{A8F3}', '\u{A8F3}', &[*b"Deva", *b"Taml"]),
+    ('\u{A92E}', '\u{A92E}', &[*b"Kali", *b"Latn", *b"Mymr"]),
+    ('\u{A9CF}', '\u{A9CF}', &[*b"Bugi", *b"Java"]),
+    ('\u{FD3E}', '\u{FD3F}', &[*b"Arab", *b"Nkoo"]),
+    ('\u{FDF2}', '\u{FDF2}', &[*b"Arab", *b"Thaa"]),
+    ('\u{FDFD}', '\u{FDFD}', &[*b"Arab", *b"Thaa"]),
+    ('\u{FE45}', '\u{FE46}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana"]),
+    ('\u{FF61}', '\u{FF65}', &[*b"Bopo", *b"Hang", *b"Hani", *b"Hira", *b"Kana", *b"Yiii"]),
+    ('\u{FF70}', '\u{FF70}', &[*b"Hira", *b"Kana"]),
+    ('\u{FF9E}', '\u{FF9F}', &[*b"Hira", *b"Kana"]),
+    ('\u{10100}', '\u{10101}', &[*b"Cpmn", *b"Cprt", *b"Linb"]),
+    ('\u{10102}', '\u{10102}', &[*b"Cprt", *b"Linb"]),
+    ('\u{10107}', '\u{10133}', &[*b"Cprt", *b"Lina", *b"Linb"]),
+    ('\u{10137}', '\u{1013F}', &[*b"Cprt", *b"Linb"]),
+    ('\u{102E0}', '\u{102FB}', &[*b"Arab", *b"Copt"]),
+    ('\u{10AF2}', '\u{10AF2}', &[*b"Mani", *b"Ougr"]),
+    ('\u{11301}', '\u{11301}', &[*b"Gran", *b"Taml"]),
+    ('\u{11303}', '\u{11303}', &[*b"Gran", *b"Taml"]),
+    ('\u{1133B}', '\u{1133C}', &[*b"Gran", *b"Taml"]),
+    ('\u{11FD0}', '\u{11FD1}', &[*b"Gran", *b"Taml"]),
+    ('\u{11FD3}', '\u{11FD3}', &[*b"Gran", *b"Taml"]),
+    ('\u{1BCA0}', '\u{1BCA3}', &[*b"Dupl"]),
+    ('\u{1D360}', '\u{1D371}', &[*b"Hani"]),
+    ('\u{1F250}', '\u{1F251}', &[*b"Hani"]),
+];